@@ -0,0 +1,976 @@
+# NotNative - Deutsch
+# Fehlende Schlüssel verwenden den eingebauten englischen Text.
+# Vollständige Schlüsselliste erzeugen: notnative --export-locale-template de.ftl
+
+-language-name = Deutsch
+-app = NotNative
+
+## Allgemein
+app_title = { -app }
+untitled = Ohne Titel
+notes = Notizen
+new_note = Neue Notiz
+search = Suchen
+search_notes = Suchen (Strg+F)
+search_placeholder = Notizen durchsuchen...
+show_hide_notes = Notizliste ein-/ausblenden
+preferences = Einstellungen
+keyboard_shortcuts = Tastenkürzel
+about = Über
+settings = Einstellungen
+tags = Tags
+tags_note = Tags der Notiz
+no_tags = Keine Tags
+search_tag = Notizen mit diesem Tag suchen
+remove_tag = Tag entfernen
+close = Schließen
+
+## Dialoge
+create_note_title = Neue Notiz
+create_folder_title = Neuer Ordner
+note_name_hint = Beispiel: projekte/neue-idee
+create_folder_hint = Mit '/' in Ordnern erstellen
+create = Erstellen
+cancel = Abbrechen
+rename = Umbenennen
+delete = Löschen
+change_icon = Symbol ändern
+view_history = Verlauf anzeigen
+duplicate_note = Notiz duplizieren
+note_duplicated = Kopie erstellt
+merge_into = Zusammenführen in…
+merge = Zusammenführen
+merge_search = Zielnotiz suchen
+merge_delete_source = Ursprüngliche Notiz in den Papierkorb verschieben
+note_merged = Notiz zusammengeführt in
+note_split = Aus den Abschnitten erstellte Notizen
+split_no_sections = Die Notiz hat keine Überschriften dieser Ebene
+pin_note = Oben anheften
+unpin_note = Nicht mehr anheften
+notes_selected = { $count ->
+    [one] { $count } Notiz ausgewählt
+   *[other] { $count } Notizen ausgewählt
+}
+move_to_folder = In Ordner verschieben…
+add_tag_batch = Tag hinzufügen…
+remove_tag_batch = Tag entfernen…
+export_notes = Exportieren…
+root_folder = Stammordner
+move = Verschieben
+apply = Anwenden
+tag_name = Tag-Name
+notes_moved = Notizen verschoben
+tags_updated = Tags aktualisiert
+notes_exported = Notizen exportiert
+folder_properties = Ordnereigenschaften…
+folder_description = Beschreibung
+folder_color = Farbe
+folder_note_icon = Symbol für die Notizen
+folder_inherit = Notizen und Unterordner übernehmen Farbe und Symbol
+folder_ai_system = Anweisungen für die KI
+folder_ai_system_hint = Werden dem Prompt des Assistenten hinzugefügt, wenn mit Notizen aus diesem Ordner gechattet wird; eine Notiz kann mit ai_system im Frontmatter eigene festlegen
+manage_tags = Tags verwalten…
+tag_manager = Tag-Verwaltung
+tag_select_hint = Wähle einen Tag zum Umbenennen oder Zusammenführen
+tag_new_name = Neuer Name
+merge_into_tag = Zusammenführen in
+tag_renamed = Tag umbenannt in
+tag_color = Farbe
+filter_by_tags = Nach Tags filtern
+clear_tag_filter = ✕ Filter entfernen
+suggest_tags = Tags vorschlagen
+suggest_tags_desc = Beim Speichern passende Tags aus dem Vault vorschlagen
+suggested_tags = Vorgeschlagene Tags (zum Hinzufügen klicken)
+inbox_pending = Unverarbeitete Erfassungen
+captured_to_inbox = Zwischenablage im Inbox gespeichert
+clipboard_empty = Die Zwischenablage ist leer
+quick_note_preview = Vorschau (Strg+P)
+quick_note_send_to = Senden an…
+quick_note_send_journal = 📅 Heutiges Journal
+quick_note_send_new = ➕ Neue Notiz
+quick_note_send_search = Notiz suchen…
+quick_note_nothing_to_send = Nichts zu senden
+quick_note_sent = 📤 Gesendet an
+sketch_title = Skizze
+sketch_save = Skizze speichern
+sketch_undo = Strich rückgängig (Strg+Z)
+sketch_clear = Alles löschen
+undo = Rückgängig
+notes_deleted = Notizen gelöscht
+note_moved = Notiz verschoben
+folder_moved = Ordner verschoben
+move_undone = Verschieben rückgängig gemacht
+undo_move_failed = Verschieben konnte nicht rückgängig gemacht werden
+folder_deleted = Ordner gelöscht
+item_restored = Wiederhergestellt
+restore_failed = Wiederherstellen fehlgeschlagen
+new_project_folder = Neuer Projektordner…
+project_name = Projektname
+folder_templates_hint = Vorlagen werden definiert in
+project_created = Projekt erstellt
+project_failed = Projekt konnte nicht erstellt werden
+search_sort = Ergebnisse sortieren
+search_sort_relevance = Relevanz
+search_sort_modified = Geändert
+search_sort_created = Erstellt
+search_sort_title = Titel
+search_all_folders = Alle Ordner
+search_all_tags = Alle Tags
+search_has_todo = Mit Aufgaben
+search_has_reminder = Mit Erinnerung
+search_clear_filters = Filter entfernen
+search_scope = Umfang der Antwort
+scope_vault = Alle Notizen
+scope_attached = Angehängte Notizen
+chat_group_empty = Dieser Ordner oder Tag enthält keine Notizen
+chat_group_notes = { $included }/{ $count } { $count ->
+    [one] Notiz
+   *[other] Notizen
+}
+chat_note_truncated = gekürzt
+chat_note_left_out = passt nicht
+chat_history_summarized = Frühere Nachrichten zusammengefasst
+chat_paste_image = Bild aus der Zwischenablage einfügen
+chat_model_no_vision = Das eingestellte Modell akzeptiert keine Bilder
+chat_image_unsupported = Nur PNG-, JPEG-, GIF- oder WebP-Bilder können angehängt werden
+chat_clipboard_no_image = In der Zwischenablage ist kein Bild
+custom_agents_label = Eigene Agenten
+custom_agents_edit = agents.yaml bearbeiten
+custom_agents_hint = Agenten mit Prompt, Werkzeugen und Modell sowie den auslösenden Wörtern festlegen; Änderungen gelten beim Speichern
+custom_agents_count = { $count ->
+    [one] { $count } Agent
+   *[other] { $count } Agenten
+}
+custom_agents_rules = { $count ->
+    [one] { $count } Regel
+   *[other] { $count } Regeln
+}
+chat_dry_run_tooltip = Probelauf: der Agent schlägt Änderungen vor, du bestätigst sie
+dry_run_activated = Probelauf aktiviert: ohne deine Zustimmung wird nichts geändert
+dry_run_deactivated = Probelauf deaktiviert
+dry_run_plan_title = Geplante Änderungen
+dry_run_apply = Anwenden
+dry_run_discard = Verwerfen
+dry_run_applied = Änderungen angewendet
+dry_run_discarded = Änderungen verworfen
+dry_run_failed = Konnte nicht angewendet werden
+dry_run_lines_skipped = { $count ->
+    [one] { $count } unveränderte Zeile
+   *[other] { $count } unveränderte Zeilen
+}
+dry_run_create_note = Notiz „{ $name }“ erstellen
+dry_run_update_note = „{ $name }“ ändern
+dry_run_append_note = An „{ $name }“ anhängen
+dry_run_delete_note = „{ $name }“ löschen
+agent_plan_title = Plan
+agent_plan_pause = ⏸ Nach diesem Schritt pausieren
+agent_plan_pausing = Wird pausiert…
+chat_branch_tooltip = Gespräch ab hier verzweigen
+chat_branch_created = Neuer Gesprächszweig; das Original bleibt gespeichert
+chat_branch_needs_history = Aktiviere das Speichern des Chatverlaufs, um Gespräche zu verzweigen
+chat_branch_error = Das Gespräch konnte nicht verzweigt werden
+chat_branch_of = Zweig von Gespräch #{ $id }
+chat_agent_invalid_arguments = Ungültige Argumente für { $tool }; das Modell wird um Korrektur gebeten
+ai_status_circuit_open = { $provider } pausiert · { $seconds } s
+ai_status_circuit_open_tooltip = Der KI-Anbieter ist mehrmals hintereinander fehlgeschlagen; nach Ablauf des Countdowns wird es erneut versucht
+offline_indicator = Offline
+offline_indicator_tooltip = Kein Netzwerk: Cloud-KI ist deaktiviert, die Suche nutzt nur Text und Embeddings warten in einer Warteschlange
+offline_agent_disabled = Der Agentenmodus benötigt eine Internetverbindung
+offline_chat_local_search = Offline: stattdessen werden deine Notizen durchsucht
+offline_entered = Offline: Cloud-KI ist pausiert
+offline_left = Wieder online
+offline_left_indexing = { $count ->
+    [one] Wieder online; { $count } ausstehende Notiz wird indiziert
+   *[other] Wieder online; { $count } ausstehende Notizen werden indiziert
+}
+offline_chat_title = Offline · passende Notizen
+offline_chat_no_results = Keine Notiz passt zur Nachricht
+chat_export_tooltip = Unterhaltungen exportieren (JSONL)
+chat_export_done = Unterhaltungen exportiert
+chat_export_error = Die Unterhaltungen konnten nicht exportiert werden
+preview_edit_failed = Die Änderung konnte nicht übernommen werden; nutze den Einfügemodus
+shortcut_split_preview = Editor und Vorschau nebeneinander
+preview_options = Vorschau-Optionen
+preview_density_comfortable = Großzügiger Abstand
+preview_density_compact = Kompakt
+preview_heading_anchors = #-Anker an Überschriften
+preview_print_friendly = Druckfreundlich
+preview_print_friendly_tooltip = Ohne Videos und Bedienelemente, dunkler Text auf weißem Hintergrund
+copy_heading_link = Link zur Überschrift kopieren
+heading_link_copied = Link zur Überschrift kopiert
+duplicate_note_title = Doppelte Notiz?
+duplicate_note_explanation = Es gibt bereits eine sehr ähnliche Notiz. Du kannst sie öffnen, den neuen Inhalt hinzufügen oder die Notiz trotzdem erstellen.
+duplicate_note_new = Neue Notiz
+duplicate_note_imported = Importierter Inhalt
+duplicate_note_title_match = Ähnlicher Titel
+duplicate_note_content_match = Ähnlicher Inhalt
+duplicate_note_open = Vorhandene öffnen
+duplicate_note_merge = Zur vorhandenen hinzufügen
+duplicate_note_create = Trotzdem erstellen
+vault_health = Zustand des Vaults
+vault_health_largest = Größte Notizen
+vault_health_stale = Seit über einem Jahr unverändert
+vault_health_untagged = Ohne Tags
+vault_health_orphans = Verwaist (keine Links)
+vault_health_search_index = Im Suchindex
+vault_health_embeddings = Mit Embeddings
+vault_health_embeddings_disabled = Die semantische Suche ist deaktiviert
+vault_health_empty = Hier gibt es nichts zu prüfen
+backups = Sicherungen
+backups_desc = Komprimierte Kopien der Notizen und der Datenbank. Der Ordner kann auf einer anderen Festplatte oder einem eingehängten Remote liegen.
+backups_interval = Stunden zwischen automatischen Sicherungen
+backups_keep = Behalten
+backups_keep_daily = täglich
+backups_keep_weekly = wöchentlich
+backups_keep_monthly = monatlich
+backups_change_folder = Ordner ändern
+backups_select_folder = Sicherungsordner
+backups_now = Jetzt sichern
+backup_restore = Sicherung wiederherstellen…
+backup_restore_button = Wiederherstellen
+backup_restore_confirm = Sicherung wiederherstellen vom
+backup_restore_confirm_detail = Der aktuelle Notizordner bleibt umbenannt neben dem wiederhergestellten erhalten.
+backup_none = Es gibt noch keine Sicherungen
+backup_created = Sicherung erstellt
+backup_failed = Sicherung fehlgeschlagen
+backup_restored = Sicherung wiederhergestellt
+backup_restored_restart = Sicherung wiederhergestellt. Starte NotNative neu, um die Datenbank fertig wiederherzustellen
+backup_restore_failed = Sicherung konnte nicht wiederhergestellt werden
+read_later = Später lesen
+remove_from_reading_list = Aus der Leseliste entfernen
+reading_list = Leseliste
+reading_list_added = Zur Leseliste hinzugefügt
+reading_list_removed = Aus der Leseliste entfernt
+reading_list_error = Die Leseliste konnte nicht aktualisiert werden
+reading_list_sort = Sortieren nach
+reading_list_sort_added = Hinzugefügt am
+reading_list_sort_length = Länge
+reading_list_empty = Nichts mehr zu lesen
+flashcards_review = Karteikarten wiederholen
+flashcards_show_answer = Antwort zeigen
+flashcards_again = Nochmal
+flashcards_hard = Schwer
+flashcards_good = Gut
+flashcards_easy = Leicht
+flashcards_days = T
+flashcards_remaining = { $count ->
+    [one] { $count } übrig
+   *[other] { $count } übrig
+}
+flashcards_done = Heute keine Karten mehr zu wiederholen
+flashcards_none = Noch keine Karteikarten. Schreibe Q:: Frage und A:: Antwort in eine Notiz oder beende eine Überschrift mit ?
+meeting_note = Besprechungsnotiz…
+meeting_note_title = Neue Besprechungsnotiz
+meeting_title_hint = Titel der Besprechung
+meeting_attendees_hint = Teilnehmende (Ana, Luis…)
+meeting_attendees_help = Durch Kommas getrennt; in der Notiz wird jede Person mit @ erwähnt
+meeting_agenda = Tagesordnung
+meeting_notes = Notizen
+meeting_action_items = Aufgaben
+meeting_extract_actions = Aufgaben extrahieren…
+meeting_actions_empty = Keine offenen Aufgaben in der Notiz. Die KI kann sie aus dem Besprochenen herausziehen.
+meeting_actions_no_due = Ohne Datum (Erinnerung morgen)
+meeting_actions_has_reminder = Hat schon eine Erinnerung
+meeting_actions_suggest = Mit KI vorschlagen
+meeting_actions_create_reminders = Erinnerungen erstellen
+meeting_actions_thinking = Suche nach Aufgaben…
+meeting_actions_ai_error = Die KI konnte keine Aufgaben extrahieren
+weekly_review = Wochenrückblick
+weekly_review_this_week = Diese Woche
+weekly_review_intro = Das ist seit Montag in deinen Notizen passiert. Sieh es dir an und beantworte dann die einzelnen Abschnitte.
+weekly_review_stats = Statistiken
+weekly_review_created = Erstellte Notizen
+weekly_review_modified = Geänderte Notizen
+weekly_review_words = Wörter in diesen Notizen
+weekly_review_journal_days = Tage mit Tagebuch
+weekly_review_open_tasks = Offene Aufgaben
+weekly_review_completed_reminders = Erledigte Erinnerungen
+weekly_review_none = Nichts diese Woche
+weekly_review_summary = Zusammenfassung
+weekly_review_summary_prompt = Wie war die Woche, in wenigen Zeilen?
+weekly_review_wins = Erfolge
+weekly_review_wins_prompt = Was lief gut? Worauf bist du stolz?
+weekly_review_challenges = Hindernisse
+weekly_review_challenges_prompt = Was ist hängen geblieben oder hat länger gedauert als geplant?
+weekly_review_next_week = Nächste Woche
+weekly_review_next_week_prompt = Was sind die drei Prioritäten für nächste Woche?
+weekly_review_ai_draft = Mit KI entwerfen
+weekly_review_ai_error = Die KI konnte die Zusammenfassung nicht schreiben
+weekly_review_back = Zurück
+weekly_review_next = Weiter
+weekly_review_create = Notiz erstellen
+weekly_review_exists = Der Rückblick dieser Woche existiert bereits
+compare_notes = Notizen vergleichen
+compare_with = Vergleichen mit…
+compare_with_current = Mit aktueller Version vergleichen
+compare_swap = Tauschen
+compare_side_by_side = Nebeneinander
+compare_identical = Beide Notizen sind identisch
+compare_changes = geänderte Blöcke
+compare_copy_left = Links kopieren
+compare_copy_right = Rechts kopieren
+compare_copy_diff = Diff kopieren
+folder_ai_excluded = Vor der KI verbergen
+folder_ai_excluded_hint = Notizen in diesem Ordner und seinen Unterordnern werden nicht indexiert, der Agent kann sie nicht lesen und sie werden im Chat nicht vorgeschlagen. Eine einzelne Notiz schließt man mit ai: false im Frontmatter aus
+chat_note_ai_excluded = Diese Notiz ist von der KI ausgeschlossen
+mcp_note_ai_excluded = Die Notiz „{ $name }“ ist von der KI ausgeschlossen und kann nicht gelesen werden
+profiles = Profile
+profiles_hint = Jedes Profil speichert den Notizordner, den KI-Anbieter, das Design und die Sicherungen. Beim Wechseln werden die aktuellen Einstellungen im aktiven Profil gespeichert.
+profiles_empty = Noch keine Profile
+profile_active = Aktiv
+profile_switch = Wechseln
+profile_default_folder = Standardordner
+profile_name_hint = Profilname (Arbeit, Privat...)
+profile_save_current = Aktuelle Einstellungen speichern
+profile_saved = Profil „{ $name }“ gespeichert
+profile_switched = Aktives Profil: { $name }
+mounted_folders = Eingebundene Ordner
+mounted_folders_desc = Ordner außerhalb des Vaults (Docs eines Repositorys, ein synchronisierter Ordner), die als Ordner der obersten Ebene erscheinen: Sie werden indexiert, durchsucht und bearbeitet, bleiben aber an ihrem Ort.
+mount_folder = Ordner einbinden…
+unmount_folder = Aushängen
+mount_unavailable = nicht verfügbar
+folder_mounted = Ordner eingebunden
+folder_unmounted = Ordner ausgehängt
+refactor_invalid = Ungültige Namen zum Umbenennen
+refactor_no_matches = Keine Notizen zu ändern
+refactor_notes = Notizen
+refactor_lines = Zeilen
+refactor_apply = Überall umbenennen
+refactor_applied = In allen Notizen umbenannt
+refactor_failed = Umbenennen fehlgeschlagen, nichts wurde geändert
+refactor_undone = Umbenennen rückgängig gemacht
+refactor_undo_failed = Umbenennen konnte nicht rückgängig gemacht werden
+copied_as_plain_text = Als reiner Text kopiert
+shortcut_copy_as_plain_text = Als reinen Text kopieren (ohne Markdown)
+plain_text_link_urls = Beim Kopieren als reiner Text die Link-URLs behalten
+word_goal_reached = Wortziel erreicht
+word_goal = Wortziel
+word_goals_today = Heute erreichte Ziele
+word_goals_week = Erreichte Ziele (7 Tage)
+scratch = Notizzettel
+shortcut_scratch = Notizzettel öffnen oder schließen (wird nicht gespeichert)
+scratch_backup = Eine Kopie des Notizzettels behalten, um ihn nach einem Absturz wiederherzustellen
+tray_compact_mode = Kompaktmodus
+shortcut_compact_mode = Kleines Fenster nur mit dem Editor (Kompaktmodus)
+file_imported = Datei in den Tresor importiert
+open_file_failed = Die Datei konnte nicht geöffnet werden
+screenshot_title = Bildschirmfoto beschriften
+screenshot_insert = Einfügen
+screenshot_tool_arrow = Pfeil
+screenshot_tool_rect = Rahmen
+screenshot_tool_text = Text (an die Stelle klicken)
+screenshot_text_placeholder = Text und Enter
+screenshot_annotate = Bildschirmfotos vor dem Einfügen beschriften (Pfeile, Rahmen und Text)
+shortcut_screenshot = Bildschirmbereich in die Notiz aufnehmen
+tray_screenshot = Bildschirmfoto in Quick Note
+ocr_offer = Beim Einfügen von Bildern anbieten, ihren Text zu erkennen (OCR), damit die Suche sie findet
+ocr_engine_tesseract = tesseract (lokal)
+ocr_engine_ai = KI-Modell mit Bilderkennung
+ocr_offer_toast = Text aus dem Bild erkennen?
+ocr_extract = Text erkennen
+ocr_running = Text wird aus dem Bild erkannt…
+ocr_done = Bildtext unter dem Bild eingefügt
+ocr_block_title = Bildtext
+ocr_no_text = Kein Text im Bild gefunden
+ocr_no_image = In der Cursorzeile ist kein Bild
+ocr_image_missing = Das Bild ist nicht mehr in der Notiz
+ocr_failed = Text konnte nicht aus dem Bild erkannt werden
+ocr_tesseract_missing = tesseract ist nicht installiert (Paket tesseract)
+ocr_model_no_vision = Das eingestellte KI-Modell unterstützt keine Bilder
+shortcut_ocr = Text des Bildes in der Zeile erkennen (OCR)
+rename_empty = Der Name darf nicht leer sein
+rename_illegal_char = Der Name darf nicht enthalten
+rename_hidden = Der Name darf nicht mit einem Punkt beginnen
+rename_taken = Existiert bereits; Enter verwendet
+renamed_to = Umbenannt in
+rename_failed = Umbenennen fehlgeschlagen
+open_in_file_manager = Im Dateimanager öffnen
+confirm_delete = Wirklich löschen
+
+## Einstellungen
+theme = Design
+theme_sync = Die Anwendung übernimmt automatisch das Omarchy-Design
+markdown_rendering = Markdown-Darstellung
+markdown_enabled = Im Normalmodus standardmäßig aktiv
+language = Sprache
+language_description = Sprache der Benutzeroberfläche wählen
+restart_required = Neustart der Anwendung erforderlich
+start_in_background = Im Hintergrund starten
+start_in_background_desc = Anwendung minimiert im Infobereich starten
+format_toolbar = Formatierungsleiste
+format_toolbar_desc = Formatierungsleiste im Bearbeitungsmodus anzeigen
+preview_adjust_images = Bilder im dunklen Modus
+preview_adjust_images_desc = Invertiert Diagramme und Screenshots mit weißem Hintergrund in der dunklen Vorschau. Mit ![](img.png){"{"}.no-invert{"}"} ein Bild ausnehmen
+recent_notes = Zuletzt geöffnet
+navigation_history_end = Keine weiteren Notizen im Verlauf
+annotations = Anmerkungen
+annotations_search = Markierungen durchsuchen
+annotations_empty = Noch keine Markierungen. Text auswählen und im Normal-Modus = drücken oder ==Text== schreiben
+paste_html_as_markdown = HTML als Markdown einfügen
+paste_html_as_markdown_desc = Wandelt aus einem Browser oder Dokument kopierte Inhalte in sauberes Markdown um und lädt die Bilder in die Anhänge herunter. Strg+Umschalt+V fügt immer reinen Text ein
+url_paste_behavior = Beim Einfügen eines Links
+url_paste_ask = Nach Verwendung des Titels fragen
+url_paste_title = Immer den Seitentitel verwenden
+url_paste_bare = Link unverändert lassen
+large_tap_targets = Größere Schaltflächen (Touch)
+large_tap_targets_desc = Vergrößert Schaltflächen und Zeilen für die Bedienung mit dem Finger. Vom linken Rand wischen öffnet die Notizliste, Zusammenziehen in der Vorschau ändert die Textgröße und langes Drücken auf eine Notiz zeigt ihr Menü
+embed_budget = Eingebettete Inhalte
+embed_budget_desc = Maximale Anzahl gleichzeitig geladener Videos in der Vorschau. Videos außerhalb des sichtbaren Bereichs werden entladen; mit 0 werden sie erst beim Anklicken geladen
+plugins = Plugins
+plugin_error = Plugin-Fehler
+workspace = Arbeitsverzeichnis
+workspace_description = Ort, an dem Notizen und Ressourcen gespeichert werden
+open_workspace_folder = Arbeitsverzeichnis öffnen
+change_workspace = Ort ändern
+workspace_location = Ort
+select_workspace_folder = Arbeitsverzeichnis auswählen
+select = Auswählen
+audio_output = Audioausgabe
+audio_output_description = Audioausgabegerät auswählen
+audio_output_default = Systemstandard
+
+## Einstellungsfenster
+prefs_page_general = Allgemein
+prefs_page_editor = Editor
+prefs_page_ai = KI
+prefs_page_sync = Synchronisierung
+prefs_page_reminders = Erinnerungen
+prefs_page_music = Musik
+prefs_page_advanced = Erweitert
+prefs_search_placeholder = Einstellungen durchsuchen...
+prefs_no_results = Keine Einstellung entspricht der Suche
+prefs_revert = Änderungen zurücksetzen
+prefs_revert_tooltip = Stellt die Einstellungen beim Öffnen dieses Fensters wieder her
+prefs_mcp_desc = Erlaubt anderen Anwendungen und Assistenten den Zugriff auf deine Notizen
+prefs_mcp_details = Details anzeigen
+prefs_reminders_desc = Erinnerungen erscheinen im Panel (Alt+R) und als Systembenachrichtigungen
+prefs_show_reminders = Erinnerungen anzeigen
+prefs_config_file = Konfigurationsdatei
+prefs_open_config_folder = Ordner öffnen
+
+## Statusleiste
+lines = Zeilen
+words = Wörter
+characters = Zeichen
+notes_count = { $count ->
+    [one] { $count } Notiz
+   *[other] { $count } Notizen
+}
+lines_count = { $count ->
+    [one] { $count } Zeile
+   *[other] { $count } Zeilen
+}
+words_count = { $count ->
+    [one] { $count } Wort
+   *[other] { $count } Wörter
+}
+characters_count = { $count ->
+    [one] { $count } Zeichen
+   *[other] { $count } Zeichen
+}
+note_statistics = Notizstatistik
+reading_time = Lesezeit
+characters_no_spaces = Zeichen ohne Leerzeichen
+paragraphs = Absätze
+headings = Überschriften
+links = Links
+code_blocks = Codeblöcke
+images = Bilder
+saved = Gespeichert
+unsaved_changes = Ungespeicherte Änderungen
+note_created = Notiz erstellt
+note_deleted = Notiz gelöscht
+note_renamed = Notiz umbenannt
+copied_as_rich_text = Als formatierter Text kopiert
+error = Fehler
+success = Erfolg
+no_results = Keine Ergebnisse für
+searching = Suche läuft
+
+## TODOs
+todos = TODOs
+todos_note = TODOs der Notiz
+no_todos = Keine TODOs in dieser Notiz
+completed = erledigt
+todo_progress = { $completed }/{ $count } { $count ->
+    [one] erledigt
+   *[other] erledigt
+}
+todo_progress_subtasks = { $completed }/{ $count } { $count ->
+    [one] Unteraufgabe
+   *[other] Unteraufgaben
+}
+no_section = Ohne Abschnitt
+
+## Suche
+searching_ellipsis = 🔄 Suche...
+no_results_found = ❌ Keine Ergebnisse
+found_relevant_notes = { $count ->
+    [one] { $count } relevante Notiz gefunden:
+   *[other] { $count } relevante Notizen gefunden:
+}
+
+## Erinnerungen
+reminders_title = Erinnerungen
+reminders_new = Neue Erinnerung
+reminders_pending = Ausstehend
+reminders_completed = Erledigt
+reminders_all = Alle
+reminder_complete = Erledigen
+reminder_snooze = Schlummern
+reminder_edit = Bearbeiten
+reminder_delete = Löschen
+reminders_empty = Keine Erinnerungen
+reminders_history = Verlauf
+reminders_history_search = Verlauf durchsuchen…
+reminders_history_export = Als CSV exportieren
+reminders_history_empty = Noch keine Erinnerung ausgelöst
+reminders_history_exported = Verlauf exportiert
+reminder_outcome_open = offen
+reminder_outcome_completed = erledigt
+reminder_outcome_dismissed = verworfen
+reminder_outcome_missed = verpasst
+reminder_history_snoozed = geschlummert
+reminder_open_note = Notiz öffnen
+reminder_draft_title = Vorgeschlagene Erinnerung
+reminder_draft_confirm = Erstellen
+reminder_draft_created = ✓ Erinnerung erstellt
+reminder_draft_cancelled = Erinnerung verworfen
+reminder_draft_invalid_date = Datum nicht erkannt (z. B. 2026-10-20 15:00 oder tomorrow 10:00)
+reminder_source_missing = Die Notiz dieser Erinnerung existiert nicht mehr
+reminder_priority_low = Niedrig
+reminder_priority_medium = Mittel
+reminder_priority_high = Hoch
+reminder_priority_urgent = Dringend
+reminder_snooze_5min = 5 Minuten
+reminder_snooze_15min = 15 Minuten
+reminder_snooze_1hour = 1 Stunde
+reminder_snooze_tomorrow = Morgen
+reminder_notification_title = 🔔 Erinnerung
+reminder_create_title = Erinnerung erstellen
+reminder_title_label = Titel
+reminder_description_label = Beschreibung
+reminder_date_label = Datum und Uhrzeit
+reminder_priority_label = Priorität
+reminder_repeat_label = Wiederholen
+reminder_repeat_none = Nicht wiederholen
+reminder_repeat_daily = Täglich
+reminder_repeat_weekly = Wöchentlich
+reminder_repeat_monthly = Monatlich
+no_reminders = Keine Erinnerungen
+reminder_overdue = Überfällig
+reminder_today = Heute
+reminder_tomorrow = Morgen
+reminder_created = Erinnerung erstellt
+reminder_updated = Erinnerung aktualisiert
+reminder_deleted = Erinnerung gelöscht
+reminder_completed_msg = Erinnerung erledigt
+reminder_snoozed = Erinnerung verschoben
+reminder_tooltip = Erinnerungen (Alt+R)
+
+## Schnellnotizen
+quick_notes_title = Schnellnotiz
+quick_note_back_to_list = Zurück zur Liste
+quick_note_new = Neue Schnellnotiz
+quick_note_keep_visible = Sichtbar halten
+quick_note_close = Schließen (Esc)
+quick_note_no_notes = Noch keine Schnellnotizen
+quick_note_press_to_create = Mit + eine neue erstellen
+quick_note_saved = 💾 Gespeichert
+quick_note_unsaved = ● Nicht gespeichert
+quick_note_shortcut_hint = Strg+S: speichern | Esc: schließen
+quick_note_created = Schnellnotiz erstellt
+
+## KI-Chat
+ai_chat = KI-Chat
+chat_input_placeholder = Nachricht hier eingeben...
+chat_send = Senden
+chat_context = Kontext
+chat_attach_note = Aktuelle Notiz anhängen
+chat_attach_button = Anhängen
+chat_clear_context = Kontext leeren
+chat_clear_history = Verlauf löschen
+chat_new_session = Neue Sitzung
+chat_mode_agent = Modus: Agent
+chat_mode_normal = Modus: Normal
+ai_thinking = Denke nach...
+you_label = Du
+
+## Infobereich
+tray_show_window = Fenster anzeigen
+tray_hide_window = Fenster ausblenden
+tray_quit = Beenden
+
+## Tooltips
+tooltip_show_hide_notes = Notizliste ein-/ausblenden
+tooltip_new_note = Neue Notiz
+tooltip_close_esc = Schließen (Esc)
+tooltip_note_tags = Tags der Notiz
+tooltip_note_todos = TODOs der Notiz
+tooltip_music_player = Musikplayer
+tooltip_reminders = Erinnerungen (Alt+R)
+tooltip_settings = Einstellungen
+
+## Datum
+hour_format = Zeitformat
+hour_format_auto = Automatisch (nach Region)
+
+## Protokolle
+logs = Protokolle
+logs_level_desc = Ausführlichkeit des Protokolls. Hilfreich beim Melden von Problemen
+logs_view = Protokolle anzeigen
+logs_empty = Noch keine Protokolle
+logs_follow = Mitverfolgen
+logs_copy = Kopieren
+
+## Zugangsdaten
+api_key_storage_keyring = 🔐 Schlüssel werden im Schlüsselbund des Systems gespeichert
+api_key_storage_file = 🔐 Schlüsselbund nicht verfügbar: Schlüssel werden verschlüsselt im Datenverzeichnis gespeichert
+
+## Audio
+audio_output_detecting = Geräte werden erkannt...
+audio_output_changed = Audioausgabe geändert
+audio_output_error = Fehler beim Ändern der Audioausgabe
+
+## Tastenkürzel
+shortcuts_general = Allgemein
+shortcuts_modes = Bearbeitungsmodi
+shortcuts_navigation = Navigation
+shortcuts_editing = Bearbeiten
+shortcut_new_note = Neue Notiz erstellen
+shortcut_save = Notiz speichern
+shortcut_search = Notizen durchsuchen
+shortcut_toggle_sidebar = Seitenleiste ein-/ausblenden
+shortcut_escape = Zurück zum Editor
+shortcut_insert_mode = Einfügemodus
+shortcut_normal_mode = Normalmodus
+shortcut_command_mode = Befehlsmodus
+shortcut_visual_mode = Visueller Modus
+shortcut_movement = Links/Unten/Oben/Rechts
+shortcut_next_word = Nächstes Wort
+shortcut_prev_word = Vorheriges Wort
+shortcut_line_start = Zeilenanfang
+shortcut_line_end = Zeilenende
+shortcut_doc_start = Dokumentanfang
+shortcut_doc_end = Dokumentende
+shortcut_delete_char = Zeichen löschen
+shortcut_delete_line = Zeile löschen
+shortcut_undo = Rückgängig
+shortcut_cycle_task_state = Aufgabenstatus wechseln: [ ] → [/] → [x] → [-]
+shortcut_redo = Wiederholen
+shortcut_open_note_search = Notizsuche öffnen
+shortcut_toggle_ai_chat = KI-Chat ein-/ausblenden
+shortcut_delete_char_under = Zeichen unter dem Cursor löschen
+shortcut_delete_line_complete = Ganze Zeile löschen
+shortcut_delete_prev_char = Vorheriges Zeichen löschen
+shortcut_delete_next_char = Nächstes Zeichen löschen
+shortcut_new_line = Neue Zeile
+shortcut_search_sidebar = Suche und Seitenleiste
+shortcut_activate_search = Suche aktivieren
+shortcut_close_search = Suche schließen / Zurück zum Editor
+shortcut_open_music = Musikplayer öffnen
+shortcut_open_reminders = Erinnerungen öffnen
+shortcuts_global = 🌍 Global
+shortcuts_quick_notes = 📝 Schnellnotizen
+shortcuts_normal_navigation = 📝 Normalmodus - Navigation
+shortcuts_normal_editing = 📝 Normalmodus - Bearbeiten und Modi
+shortcuts_insert_mode = ✍️ Einfügemodus
+shortcuts_ai_chat = 🤖 KI-Chat-Modus
+shortcuts_sidebar = 📂 Seitenleiste und Listen
+shortcuts_floating_search = 🔍 Schwebende Suche
+shortcut_global_search = Schwebende globale Suche öffnen
+shortcut_note_search = In der aktuellen Notiz suchen
+shortcut_cycle_focus = Fokus zwischen Seitenleiste, Editor und Suche wechseln
+shortcut_enter_ai_chat = KI-Chat-Modus öffnen
+shortcut_back_or_close = Zurück zur Liste / Fenster schließen
+shortcut_left = Cursor nach links
+shortcut_down = Cursor nach unten
+shortcut_up = Cursor nach oben
+shortcut_right = Cursor nach rechts
+shortcut_ai_chat_mode = KI-Chat-Modus öffnen
+shortcut_insert_table = Markdown-Tabelle einfügen
+shortcut_insert_image = Bild einfügen
+shortcut_insert_sketch = Skizze zeichnen
+shortcut_insert_footnote = Fußnote einfügen
+shortcut_saveas = Kopie der Notiz speichern und öffnen
+shortcut_split_by_heading = Jeden Abschnitt in eine eigene Notiz verwandeln
+shortcut_navigate_history = Zurück / vor durch geöffnete Notizen
+shortcut_toggle_highlight = Auswahl markieren (wiederholen, um die Farbe zu ändern)
+shortcut_paste_plain_text = Als reinen Text einfügen
+shortcut_copy_as_html = Als formatierten Text kopieren (HTML)
+shortcut_tab_autocomplete = Tab / Tag oder @ vervollständigen
+shortcut_exit_chat = Chat verlassen (zurück zum Normalmodus)
+shortcut_exit_chat_insert = Chat verlassen und in den Einfügemodus wechseln
+shortcut_send_message = Nachricht senden
+shortcut_navigate_suggestions = Vorschläge durchgehen
+shortcut_accept_suggestion = Vorschlag übernehmen
+shortcut_next_note = Nächste Notiz
+shortcut_prev_note = Vorherige Notiz
+shortcut_open_note = Ausgewählte Notiz oder Ordner öffnen
+shortcut_focus_editor = Fokus zurück zum Editor
+shortcut_toggle_semantic = Semantische Suche (KI) umschalten
+shortcut_navigate_results = Ergebnisse durchgehen
+shortcut_open_selected = Ausgewählte Notiz öffnen
+
+## Über
+app_description = Markdown-Notizeditor im Vim-Stil
+website = Webseite
+authors = Autoren
+version = Version
+license = Lizenz
+
+## Bildbetrachter
+image_viewer = Bildbetrachter
+open_file_location = Speicherort öffnen
+
+## YouTube
+transcribe_youtube = YouTube-Video transkribieren?
+youtube_detected = Ein YouTube-Link wurde erkannt
+only_link = Nur Link
+link_title_found = Titel des Links
+use_page_title = Titel verwenden
+keep_bare_link = Link behalten
+transcribe_and_insert = Transkribieren und einfügen
+downloading_transcript = Transkript wird heruntergeladen...
+loading_transcript = Transkript wird geladen...
+transcript_error = Fehler beim Abrufen des Transkripts
+transcript_unavailable = Für dieses Video ist kein Transkript verfügbar
+transcript_section = 📝 Transkript
+youtube_unavailable = YouTube-Transkription ist derzeit nicht verfügbar...
+
+## Musikplayer
+music_player = Musikplayer
+music_player_title = Musikplayer
+music_search_placeholder = Musik auf YouTube suchen...
+no_music_playing = Keine Musik wird abgespielt
+music_play_pause = Wiedergabe/Pause (Leertaste)
+music_stop = Stopp
+music_seek_back = 5 s zurück
+music_seek_forward = 5 s vor
+music_volume_down = Leiser
+music_volume_up = Lauter
+music_previous_song = Vorheriger Titel
+music_next_song = Nächster Titel
+music_repeat_off = Wiederholen: AUS
+music_repeat_one = Wiederholen: EINS
+music_repeat_all = Wiederholen: ALLE
+music_shuffle_off = Zufall: AUS
+music_shuffle_on = Zufall: AN
+music_manage_playlists = Wiedergabelisten verwalten
+music_playback_queue = Warteschlange
+music_loading = Wird geladen...
+music_add_to_queue = Zur Warteschlange hinzufügen
+music_remove_from_queue = Aus der Warteschlange entfernen
+music_new_playlist = Neue Wiedergabeliste
+music_load_playlist = Wiedergabeliste laden
+music_save_playlist = Wiedergabeliste speichern
+music_playlist_name = Name der Wiedergabeliste
+playlist_new = 🎵 Neu
+playlist_save = 💾 Speichern
+playlist_clear = 🗑️ Leeren
+playlist_queue = Warteschlange
+playlist_queue_empty = Warteschlange leer
+playlist_saved_playlists = Gespeicherte Wiedergabelisten
+playlist_save_title = Wiedergabeliste speichern
+playlist_name_prompt = Name der Wiedergabeliste:
+playlist_name_example = z. B.: Entspannende Musik
+playlist_no_loaded = Keine Wiedergabeliste geladen
+playlist_no_saved = Keine gespeicherten Wiedergabelisten
+playlist_add_to = Zur Wiedergabeliste hinzufügen
+playlist_delete = Wiedergabeliste löschen
+play = Abspielen
+remove = Entfernen
+
+## KI-Chat und Agentenmodus
+chat_model_default = Modell: OpenAI GPT-4
+chat_subtitle = Kombiniere deine Notizen in Echtzeit mit dem Assistenten
+chat_attach_note_dialog_title = Notiz an den Kontext anhängen
+chat_clear_history_confirm_title = Gesamten Chatverlauf löschen?
+chat_clear_history_confirm_message = Dadurch wird der gesamte gespeicherte Gesprächsverlauf dauerhaft gelöscht. Das kann nicht rückgängig gemacht werden.
+chat_history_cleared = Verlauf gelöscht
+chat_history_cleared_message = Der gesamte Gesprächsverlauf wurde gelöscht
+chat_mode_chat = Modus: Chat
+chat_toggle_mode_tooltip = Umschalten: Agentenmodus (mit Werkzeugen) / Normaler Chat (ohne Werkzeuge)
+chat_agent_thinking = 💭 Gedanke
+chat_agent_action = 🔧 Aktion
+chat_agent_observation = 👁️ Beobachtung
+chat_agent_answer = ✅ Antwort
+ai_chat_placeholder = Nachricht an den KI-Assistenten schreiben...
+ai_send_message = Nachricht senden
+ai_model = Modell
+ai_temperature = Temperatur
+ai_api_key = API-Schlüssel
+ai_openai = OpenAI
+ai_openrouter = OpenRouter
+ai_api_key_placeholder = API-Schlüssel eingeben...
+ai_save = Speichern
+ai_cancel = Abbrechen
+ai_no_key_configured = Kein API-Schlüssel konfiguriert
+ai_configure_key = API-Schlüssel in den Einstellungen konfigurieren
+ai_free_models = ═══ KOSTENLOSE MODELLE ═══
+ai_paid_models = ═══ KOSTENPFLICHTIGE MODELLE ═══
+agent_mode_activated =
+    Agentenmodus aktiviert
+    Der Assistent kann Notizen durchsuchen und Aktionen ausführen
+chat_mode_activated =
+    Normaler Chat aktiviert
+    Direktes Gespräch ohne Zugriff auf Werkzeuge
+analyzing_task = Aufgabe wird analysiert...
+no_notes_in_context = Keine Notizen im Kontext
+remove_from_context = Aus dem Kontext entfernen
+
+## KI-Einstellungen
+temperature_label = Temperatur:
+max_tokens_label = Max. Tokens:
+unlimited = Unbegrenzt
+save_history_label = Verlauf speichern:
+model_label = Modell:
+refresh_models_tooltip = Modellliste von OpenRouter aktualisieren
+search_model_placeholder = Modell suchen...
+no_models_found = Keine Modelle gefunden
+semantic_search_title = 🧠 Semantische Suche (Embeddings)
+semantic_search_description = Embeddings für die Bedeutungssuche mit OpenRouter konfigurieren
+enable_embeddings = Embeddings aktivieren:
+index_all_notes = 📄 Alle Notizen indizieren
+indexing = ⏳ Wird indiziert...
+indexing_completed = Indizierung abgeschlossen
+estimated_cost = Geschätzte Kosten: ~0,01 $ pro 10.000 Notizen
+get_api_key_openrouter = API-Schlüssel bei OpenRouter holen
+
+## MCP-Meldungen
+mcp_note_created = ✓ Notiz '{}' erfolgreich erstellt
+mcp_note_read = ✓ Notiz '{}' erfolgreich gelesen
+mcp_note_updated = ✓ Notiz '{}' erfolgreich aktualisiert
+mcp_note_deleted = ✓ Notiz '{}' erfolgreich gelöscht
+mcp_content_appended = ✓ Inhalt erfolgreich an '{}' angehängt
+mcp_notes_found = { $count ->
+    [one] ✓ { $count } Notiz gefunden
+   *[other] ✓ { $count } Notizen gefunden
+}
+mcp_search_results = { $count ->
+    [one] ✓ { $count } Ergebnis für '{ $query }'
+   *[other] ✓ { $count } Ergebnisse für '{ $query }'
+}
+mcp_notes_with_tag = { $count ->
+    [one] ✓ { $count } Notiz mit dem Tag #{ $tag }
+   *[other] ✓ { $count } Notizen mit dem Tag #{ $tag }
+}
+mcp_tags_found = { $count ->
+    [one] ✓ { $count } Tag gefunden
+   *[other] ✓ { $count } Tags gefunden
+}
+mcp_tags_added = ✓ Tags zu '{}' hinzugefügt
+mcp_note_renamed = ✓ Notiz von '{}' in '{}' umbenannt
+mcp_note_duplicated = ✓ Notiz '{}' als '{}' dupliziert
+mcp_folder_created = ✓ Ordner '{}' erfolgreich erstellt
+mcp_project_created = ✓ Projekt '{ $folder }' aus der Vorlage erstellt
+mcp_note_not_found = Notiz '{}' nicht gefunden
+mcp_folders_found = { $count ->
+    [one] ✓ { $count } Ordner gefunden
+   *[other] ✓ { $count } Ordner gefunden
+}
+mcp_create_reminder_desc = Neue Erinnerung mit Datum, Uhrzeit und Priorität erstellen
+mcp_list_reminders_desc = Alle Erinnerungen auflisten oder nach Status filtern
+mcp_update_reminder_desc = Bestehende Erinnerung ändern
+mcp_delete_reminder_desc = Erinnerung löschen
+mcp_snooze_reminder_desc = Erinnerung um eine bestimmte Zeit verschieben
+mcp_complete_reminder_desc = Erinnerung als erledigt markieren
+mcp_reminder_title_desc = Titel der Erinnerung
+mcp_reminder_date_desc = Datum und Uhrzeit der Erinnerung (z. B.: '2025-11-20 15:00', 'today 18:00', 'tomorrow')
+mcp_server_title = MCP-Server - Model Context Protocol
+mcp_server_active = MCP-Server aktiv
+mcp_server_subtitle = Stellt die Werkzeuge von NotNative über HTTP bereit
+mcp_status = Status
+mcp_endpoints_available = Verfügbare Endpunkte
+status_active = 🟢 Aktiv
+copy_url = 📋 URL kopieren
+view_docs = 📖 Dokumentation ansehen
+copied = ✓ Kopiert!
+
+## Erinnerungen und Schnellnotizen
+reminder_linked_note_label = Verknüpfte Notiz
+reminders_count = { $count ->
+    [one] { $count } ausstehend
+   *[other] { $count } ausstehend
+}
+quick_note_autosaved = 💾 Automatisch gespeichert
+
+## Suche und Oberfläche
+semantic_results = Ergebnisse nach semantischer Ähnlichkeit
+ai_analyzing = 🔄 Der KI-Assistent analysiert die Ergebnisse...
+assistant_response = 🧠 Antwort des Assistenten
+tooltip_change_search_mode = Strg zum Wechseln des Modus
+images_filter = Bilder
+unknown_error = Unbekannter Fehler
+operation_success = ✓ Vorgang erfolgreich
+operation_failed = ✗ Vorgang fehlgeschlagen
+adding_content = Inhalt wird hinzugefügt...
+adding_tag = Tag wird hinzugefügt...
+adding_tags = Tags werden hinzugefügt...
+generating_index = Inhaltsverzeichnis wird erstellt...
+extracting_code = Code wird extrahiert...
+
+## Datenbanken
+base_add_filter = Filter hinzufügen
+base_sort = Sortieren
+base_columns = Spalten
+base_columns_config = Spalten konfigurieren
+base_data_source = Datenquellenmodus
+base_formula_rows = Formelzeilen
+base_formula_rows_title = Formelzeilen
+base_add_formula_row = Summenzeile hinzufügen
+base_formula_row_label = Beschriftung
+base_formula_help = Formeln wie in Excel verwenden: =SUM(B:B), =AVG(C1:C10)
+base_export_xlsx = Nach Excel exportieren
+base_export_xlsx_success = Erfolgreich exportiert
+base_export_xlsx_error = Fehler beim Exportieren
+base_show_graph = Beziehungsgraph anzeigen
+base_no_filters = Keine Filter
+base_current_columns = Sichtbare Spalten
+base_available_properties = Verfügbare Eigenschaften
+base_toggle_visibility = Sichtbarkeit umschalten
+base_remove_column = Spalte entfernen
+base_add_column = Verfügbare Eigenschaften
+base_properties_hint = Auf + klicken, um als Spalte hinzuzufügen
+base_add_as_column = Als Spalte hinzufügen
+base_move_up = Nach oben
+base_move_down = Nach unten
+base_data_source_title = Datenquelle
+base_notes_mode = Notizen
+base_grouped_mode = Gruppierte Einträge
+base_grouped_hint =
+    Gruppierte Einträge zeigen Gruppen von Inline-Eigenschaften
+    wie [game::X, bought::Y] als Zeilen
+base_search_placeholder = In der Tabelle suchen...
+base_no_notes = Keine Notizen gefunden
+base_items = Einträge
+base_items_of = von
+base_title = Titel
+base_created = Erstellt
+base_modified = Geändert
+base_tags = Tags
+base_search_properties = Eigenschaften suchen...
+base_no_available_props = Keine weiteren Eigenschaften verfügbar
+base_sort_by = Sortieren nach
+base_no_sorting = Keine Sortierung
+base_sort_ascending = Aufsteigend sortieren
+base_sort_descending = Absteigend sortieren
+base_add_filter_title = Filter hinzufügen
+base_property = Eigenschaft
+base_operator = Operator
+base_value = Wert
+base_filter_value_placeholder = Filterwert...
+base_cancel = Abbrechen
+base_apply_filter = Filter hinzufügen
+filter_op_equals = gleich
+filter_op_not_equals = ungleich
+filter_op_contains = enthält
+filter_op_not_contains = enthält nicht
+filter_op_greater_than = größer als
+filter_op_greater_or_equal = größer oder gleich
+filter_op_less_than = kleiner als
+filter_op_less_or_equal = kleiner oder gleich
+filter_op_starts_with = beginnt mit
+filter_op_ends_with = endet mit
+filter_op_is_empty = ist leer
+filter_op_is_not_empty = ist nicht leer
@@ -0,0 +1,1015 @@
+# NotNative - English
+# Reference catalog: every key the app uses is defined here, and any key
+# missing from another locale falls back to this file.
+
+-language-name = English
+
+## General
+app_title = NotNative
+untitled = Untitled
+notes = Notes
+new_note = New Note
+search = Search
+search_notes = Search (Ctrl+F)
+search_placeholder = Search notes...
+show_hide_notes = Show/hide notes list
+preferences = Preferences
+keyboard_shortcuts = Keyboard Shortcuts
+about = About
+settings = Settings
+tags = Tags
+tags_note = Note tags
+no_tags = No tags
+search_tag = Search notes with this tag
+remove_tag = Remove tag
+close = Close
+
+## Dialogs
+create_note_title = New Note
+create_folder_title = New Folder
+note_name_hint = example: projects/new-idea
+create_folder_hint = Use '/' to create in folders
+create = Create
+cancel = Cancel
+rename = Rename
+delete = Delete
+change_icon = Change icon
+view_history = View history
+duplicate_note = Duplicate note
+note_duplicated = Copy created
+merge_into = Merge into…
+merge = Merge
+merge_search = Search the target note
+merge_delete_source = Move the original note to the trash
+note_merged = Note merged into
+note_split = Notes created from the sections
+split_no_sections = The note has no headings of that level
+folder_properties = Folder properties…
+folder_description = Description
+folder_color = Color
+folder_note_icon = Icon for its notes
+folder_inherit = Notes and subfolders inherit the color and icon
+folder_ai_system = AI instructions
+folder_ai_system_hint = Added to the assistant's prompt when chatting with notes from this folder; a note can set its own with ai_system in its frontmatter
+pin_note = Pin to top
+unpin_note = Unpin
+notes_selected = { $count ->
+    [one] { $count } note selected
+   *[other] { $count } notes selected
+}
+move_to_folder = Move to folder…
+add_tag_batch = Add tag…
+remove_tag_batch = Remove tag…
+export_notes = Export…
+root_folder = Root
+move = Move
+apply = Apply
+tag_name = Tag name
+notes_moved = Notes moved
+tags_updated = Tags updated
+notes_exported = Notes exported
+manage_tags = Manage tags…
+tag_manager = Tag manager
+tag_select_hint = Pick a tag to rename or merge it
+tag_new_name = New name
+merge_into_tag = Merge into
+tag_renamed = Tag renamed in
+tag_color = Color
+filter_by_tags = Filter by tags
+clear_tag_filter = ✕ Clear filter
+suggest_tags = Suggest tags
+suggest_tags_desc = When saving, suggest vault tags that match the note
+suggested_tags = Suggested tags (click to add)
+inbox_pending = Unprocessed captures
+captured_to_inbox = Clipboard saved to the Inbox
+clipboard_empty = The clipboard is empty
+quick_note_preview = Preview (Ctrl+P)
+quick_note_send_to = Send to…
+quick_note_send_journal = 📅 Today's journal
+quick_note_send_new = ➕ New note
+quick_note_send_search = Search note…
+quick_note_nothing_to_send = Nothing to send
+quick_note_sent = 📤 Sent to
+sketch_title = Sketch
+sketch_save = Save sketch
+sketch_undo = Undo stroke (Ctrl+Z)
+sketch_clear = Clear all
+undo = Undo
+notes_deleted = Notes deleted
+note_moved = Note moved
+folder_moved = Folder moved
+move_undone = Move undone
+undo_move_failed = Could not undo the move
+folder_deleted = Folder deleted
+item_restored = Restored
+restore_failed = Could not restore
+new_project_folder = New project folder…
+project_name = Project name
+folder_templates_hint = Templates are defined in
+project_created = Project created
+project_failed = Could not create the project
+search_sort = Sort results
+search_sort_relevance = Relevance
+search_sort_modified = Modified
+search_sort_created = Created
+search_sort_title = Title
+search_all_folders = All folders
+search_all_tags = All tags
+search_has_todo = Has to-dos
+search_has_reminder = Has reminder
+search_clear_filters = Clear filters
+search_scope = Answer scope
+scope_vault = All notes
+scope_attached = Attached notes
+chat_group_empty = That folder or tag has no notes
+chat_group_notes = { $included }/{ $count } { $count ->
+    [one] note
+   *[other] notes
+}
+chat_note_truncated = truncated
+chat_note_left_out = left out
+chat_history_summarized = Earlier messages summarized
+chat_paste_image = Paste image from clipboard
+chat_model_no_vision = The configured model does not accept images
+chat_image_unsupported = Only PNG, JPEG, GIF or WebP images can be attached
+chat_clipboard_no_image = There is no image in the clipboard
+custom_agents_label = Custom agents
+custom_agents_edit = Edit agents.yaml
+custom_agents_hint = Define agents with their prompt, tools and model, and the words that trigger them; changes apply on save
+custom_agents_count = { $count ->
+    [one] { $count } agent
+   *[other] { $count } agents
+}
+custom_agents_rules = { $count ->
+    [one] { $count } rule
+   *[other] { $count } rules
+}
+chat_dry_run_tooltip = Dry run: the agent proposes changes and you approve them
+dry_run_activated = Dry run enabled: nothing will change without your approval
+dry_run_deactivated = Dry run disabled
+dry_run_plan_title = Planned changes
+dry_run_apply = Apply
+dry_run_discard = Discard
+dry_run_applied = Changes applied
+dry_run_discarded = Changes discarded
+dry_run_failed = Could not apply
+dry_run_lines_skipped = { $count ->
+    [one] { $count } unchanged line
+   *[other] { $count } unchanged lines
+}
+dry_run_create_note = Create note “{ $name }”
+dry_run_update_note = Modify “{ $name }”
+dry_run_append_note = Append to “{ $name }”
+dry_run_delete_note = Delete “{ $name }”
+agent_plan_title = Plan
+agent_plan_pause = ⏸ Pause after this step
+agent_plan_pausing = Pausing…
+chat_branch_tooltip = Branch the conversation from here
+chat_branch_created = New conversation branch; the original is still saved
+chat_branch_needs_history = Turn on saving chat history to branch conversations
+chat_branch_error = Could not branch the conversation
+chat_branch_of = Branch of conversation #{ $id }
+chat_agent_invalid_arguments = Invalid arguments for { $tool }; asking the model to fix them
+ai_status_circuit_open = { $provider } paused · { $seconds } s
+ai_status_circuit_open_tooltip = The AI provider failed several times in a row; it will be tried again when the countdown ends
+offline_indicator = Offline
+offline_indicator_tooltip = No network: cloud AI is turned off, search uses text only and embeddings wait in a queue
+offline_agent_disabled = Agent mode needs an internet connection
+offline_chat_local_search = Offline: your notes will be searched instead
+offline_entered = Offline: cloud AI is paused
+offline_left = Back online
+offline_left_indexing = { $count ->
+    [one] Back online; indexing { $count } pending note
+   *[other] Back online; indexing { $count } pending notes
+}
+offline_chat_title = Offline · matching notes
+offline_chat_no_results = No notes match the message
+chat_export_tooltip = Export conversations (JSONL)
+chat_export_done = Conversations exported
+chat_export_error = Could not export the conversations
+preview_edit_failed = Couldn't apply the edit; use Insert mode
+shortcut_split_preview = Editor and preview side by side
+preview_options = Preview options
+preview_density_comfortable = Comfortable spacing
+preview_density_compact = Compact
+preview_heading_anchors = # anchors on headings
+preview_print_friendly = Print-friendly
+preview_print_friendly_tooltip = No videos or controls, dark text on a white background
+copy_heading_link = Copy link to heading
+heading_link_copied = Link to heading copied
+duplicate_note_title = Duplicate note?
+duplicate_note_explanation = A very similar note already exists. You can open it, add the new content to it, or create the note anyway.
+duplicate_note_new = New note
+duplicate_note_imported = Imported content
+duplicate_note_title_match = Similar title
+duplicate_note_content_match = Similar content
+duplicate_note_open = Open existing
+duplicate_note_merge = Add to existing
+duplicate_note_create = Create anyway
+vault_health = Vault health
+vault_health_largest = Largest notes
+vault_health_stale = Not modified in over a year
+vault_health_untagged = Untagged
+vault_health_orphans = Orphans (no links)
+vault_health_search_index = In the search index
+vault_health_embeddings = With embeddings
+vault_health_embeddings_disabled = Semantic search is disabled
+vault_health_empty = Nothing to review here
+backups = Backups
+backups_desc = Compressed snapshots of your notes and database. The folder can be on another disk or a mounted remote.
+backups_interval = Hours between automatic backups
+backups_keep = Keep
+backups_keep_daily = daily
+backups_keep_weekly = weekly
+backups_keep_monthly = monthly
+backups_change_folder = Change folder
+backups_select_folder = Backup folder
+backups_now = Back up now
+backup_restore = Restore backup…
+backup_restore_button = Restore
+backup_restore_confirm = Restore the backup from
+backup_restore_confirm_detail = Your current notes folder is kept, renamed, next to the restored one.
+backup_none = There are no backups yet
+backup_created = Backup created
+backup_failed = Backup failed
+backup_restored = Backup restored
+backup_restored_restart = Backup restored. Restart NotNative to finish restoring the database
+backup_restore_failed = Could not restore the backup
+read_later = Read later
+remove_from_reading_list = Remove from reading list
+reading_list = Reading list
+reading_list_added = Added to reading list
+reading_list_removed = Removed from reading list
+reading_list_error = Could not update the reading list
+reading_list_sort = Sort by
+reading_list_sort_added = Date added
+reading_list_sort_length = Length
+reading_list_empty = Nothing left to read
+flashcards_review = Review flashcards
+flashcards_show_answer = Show answer
+flashcards_again = Again
+flashcards_hard = Hard
+flashcards_good = Good
+flashcards_easy = Easy
+flashcards_days = d
+flashcards_remaining = { $count ->
+    [one] { $count } left
+   *[other] { $count } left
+}
+flashcards_done = No cards left to review today
+flashcards_none = No flashcards yet. Write Q:: question and A:: answer in a note, or end a heading with ?
+meeting_note = Meeting note…
+meeting_note_title = New meeting note
+meeting_title_hint = Meeting title
+meeting_attendees_hint = Attendees (Ana, Luis…)
+meeting_attendees_help = Comma separated; each attendee is @mentioned in the note
+meeting_agenda = Agenda
+meeting_notes = Notes
+meeting_action_items = Action items
+meeting_extract_actions = Extract action items…
+meeting_actions_empty = No open tasks in this note. The AI can pick them out of the discussion.
+meeting_actions_no_due = No due date (reminder tomorrow)
+meeting_actions_has_reminder = Already has a reminder
+meeting_actions_suggest = Suggest with AI
+meeting_actions_create_reminders = Create reminders
+meeting_actions_thinking = Looking for action items…
+meeting_actions_ai_error = The AI could not extract action items
+weekly_review = Weekly review
+weekly_review_this_week = This week
+weekly_review_intro = This is what happened in your notes since Monday. Look it over, then go on to answer each section.
+weekly_review_stats = Stats
+weekly_review_created = Notes created
+weekly_review_modified = Notes modified
+weekly_review_words = Words in those notes
+weekly_review_journal_days = Journal days
+weekly_review_open_tasks = Open tasks
+weekly_review_completed_reminders = Completed reminders
+weekly_review_none = Nothing this week
+weekly_review_summary = Summary
+weekly_review_summary_prompt = How did the week go, in a few lines?
+weekly_review_wins = Wins
+weekly_review_wins_prompt = What went well? What are you proud of?
+weekly_review_challenges = Challenges
+weekly_review_challenges_prompt = What got stuck or took longer than expected?
+weekly_review_next_week = Next week
+weekly_review_next_week_prompt = What are the three priorities for next week?
+weekly_review_ai_draft = Draft with AI
+weekly_review_ai_error = The AI could not write the summary
+weekly_review_back = Back
+weekly_review_next = Next
+weekly_review_create = Create note
+weekly_review_exists = This week's review already exists
+compare_notes = Compare notes
+compare_with = Compare with…
+compare_with_current = Compare with current version
+compare_swap = Swap
+compare_side_by_side = Side by side
+compare_identical = Both notes are identical
+compare_changes = changed blocks
+compare_copy_left = Copy left
+compare_copy_right = Copy right
+compare_copy_diff = Copy diff
+folder_ai_excluded = Hide from AI
+folder_ai_excluded_hint = Notes in this folder and its subfolders are not indexed, the agent can't read them and they aren't suggested in the chat. A single note is excluded with ai: false in its frontmatter
+chat_note_ai_excluded = This note is excluded from AI
+mcp_note_ai_excluded = Note “{ $name }” is excluded from AI and can't be read
+profiles = Profiles
+profiles_hint = Each profile keeps the notes folder, AI provider, theme and backups. When switching, the current settings are saved to the active profile.
+profiles_empty = No profiles yet
+profile_active = Active
+profile_switch = Switch
+profile_default_folder = Default folder
+profile_name_hint = Profile name (Work, Personal...)
+profile_save_current = Save current settings
+profile_saved = Profile “{ $name }” saved
+profile_switched = Active profile: { $name }
+mounted_folders = Mounted folders
+mounted_folders_desc = Folders outside the vault (a repository's docs, a synced folder) shown as top-level folders: they are indexed, searchable and editable, but stay where they are.
+mount_folder = Mount folder…
+unmount_folder = Unmount
+mount_unavailable = unavailable
+folder_mounted = Folder mounted
+folder_unmounted = Folder unmounted
+refactor_invalid = Invalid names for rename
+refactor_no_matches = No notes to change
+refactor_notes = Notes
+refactor_lines = Lines
+refactor_apply = Rename everywhere
+refactor_applied = Renamed across notes
+refactor_failed = Rename failed, nothing was changed
+refactor_undone = Rename undone
+refactor_undo_failed = Could not undo the rename
+copied_as_plain_text = Copied as plain text
+shortcut_copy_as_plain_text = Copy as plain text (no markdown)
+plain_text_link_urls = Keep link URLs when copying as plain text
+word_goal_reached = Word goal reached
+word_goal = Word goal
+word_goals_today = Goals reached today
+word_goals_week = Goals reached (7 days)
+scratch = Scratch
+shortcut_scratch = Open or close the scratch buffer (never saved)
+scratch_backup = Keep a copy of the scratch buffer to recover it after a crash
+tray_compact_mode = Compact mode
+shortcut_compact_mode = Small editor-only window (compact mode)
+file_imported = File imported into the vault
+open_file_failed = Could not open the file
+screenshot_title = Annotate screenshot
+screenshot_insert = Insert
+screenshot_tool_arrow = Arrow
+screenshot_tool_rect = Box
+screenshot_tool_text = Text (click where to place it)
+screenshot_text_placeholder = Text and Enter
+screenshot_annotate = Annotate screenshots (arrows, boxes and text) before inserting them
+shortcut_screenshot = Capture a screen region into the note
+tray_screenshot = Screenshot to Quick Note
+ocr_offer = Offer to extract the text of images when inserting them (OCR), so search finds them
+ocr_engine_tesseract = tesseract (local)
+ocr_engine_ai = AI vision model
+ocr_offer_toast = Extract the text from the image?
+ocr_extract = Extract text
+ocr_running = Extracting the text from the image…
+ocr_done = Image text added below the image
+ocr_block_title = Image text
+ocr_no_text = No text found in the image
+ocr_no_image = There is no image on the cursor line
+ocr_image_missing = The image is no longer in the note
+ocr_failed = Could not extract the text from the image
+ocr_tesseract_missing = tesseract is not installed (tesseract package)
+ocr_model_no_vision = The configured AI model does not accept images
+shortcut_ocr = Extract the text of the image on the line (OCR)
+rename_empty = The name can't be empty
+rename_illegal_char = The name can't contain
+rename_hidden = The name can't start with a dot
+rename_taken = Already exists; Enter will use
+renamed_to = Renamed to
+rename_failed = Could not rename
+open_in_file_manager = Open in file manager
+confirm_delete = Are you sure you want to delete
+
+## Preferences
+theme = Theme
+theme_sync = The app automatically syncs with Omarchy theme
+markdown_rendering = Markdown Rendering
+markdown_enabled = Enabled by default in Normal mode
+language = Language
+language_description = Choose the interface language
+restart_required = Application restart required
+start_in_background = Start in background
+start_in_background_desc = Start the application minimized to the system tray
+
+## Format toolbar
+format_toolbar = Format Toolbar
+format_toolbar_desc = Show formatting toolbar in edit mode
+
+## Preview
+preview_adjust_images = Images in dark mode
+preview_adjust_images_desc = Invert white-background diagrams and screenshots in the dark preview. Use ![](img.png){.no-invert} to opt out per image
+
+## Navigation history and recent notes
+recent_notes = Recent
+navigation_history_end = No more notes in the history
+
+## Annotations (==text== highlights)
+annotations = Annotations
+annotations_search = Search highlights
+annotations_empty = No highlights yet. Select text and press = in Normal mode, or type ==text==
+
+## Paste
+paste_html_as_markdown = Paste HTML as markdown
+paste_html_as_markdown_desc = Convert content copied from a browser or document into clean markdown and download its images to attachments. Ctrl+Shift+V always pastes plain text
+url_paste_behavior = When pasting a link
+url_paste_ask = Ask to use the page title
+url_paste_title = Always use the page title
+url_paste_bare = Keep the bare link
+
+## Touch mode
+large_tap_targets = Larger tap targets
+large_tap_targets_desc = Make buttons and rows bigger for finger use. Swipe from the left edge to open the notes list, pinch the preview to resize its text and long-press a note for its menu
+embed_budget = Embedded content
+embed_budget_desc = Maximum videos loaded at once in the preview. Off-screen videos are unloaded; with 0 they only load when clicked
+
+## Plugins
+plugins = Plugins
+plugin_error = Plugin error
+
+## Dates
+hour_format = Time format
+hour_format_auto = Automatic (by region)
+
+## Logs
+logs = Logs
+logs_level_desc = Log verbosity. Useful when reporting issues
+logs_view = View logs
+logs_empty = No logs yet
+logs_follow = Follow
+logs_copy = Copy
+
+## Credentials
+api_key_storage_keyring = 🔐 Keys are stored in the system keyring
+api_key_storage_file = 🔐 Keyring unavailable: keys are stored encrypted in the data directory
+
+## Preferences window
+prefs_page_general = General
+prefs_page_editor = Editor
+prefs_page_ai = AI
+prefs_page_sync = Sync
+prefs_page_reminders = Reminders
+prefs_page_music = Music
+prefs_page_advanced = Advanced
+prefs_search_placeholder = Search settings...
+prefs_no_results = No settings match your search
+prefs_revert = Revert changes
+prefs_revert_tooltip = Restore the settings from when this window was opened
+prefs_mcp_desc = Lets other apps and assistants access your notes
+prefs_mcp_details = Show details
+prefs_reminders_desc = Reminders appear in the panel (Alt+R) and as system notifications
+prefs_show_reminders = Show reminders
+prefs_config_file = Configuration file
+prefs_open_config_folder = Open folder
+
+## Workspace
+workspace = Workspace
+workspace_description = Location where notes and resources are saved
+open_workspace_folder = Open workspace folder
+change_workspace = Change location
+workspace_location = Location
+select_workspace_folder = Select workspace folder
+select = Select
+
+## Audio
+audio_output = Audio Output
+audio_output_description = Select audio output device
+audio_output_default = System default
+audio_output_detecting = Detecting devices...
+audio_output_changed = Audio output changed
+audio_output_error = Error changing audio output
+
+## Keyboard shortcuts
+shortcuts_general = General
+shortcuts_modes = Editing Modes
+shortcuts_navigation = Navigation
+shortcuts_editing = Editing
+shortcut_new_note = Create new note
+shortcut_save = Save note
+shortcut_search = Search notes
+shortcut_toggle_sidebar = Toggle sidebar
+shortcut_escape = Back to editor
+shortcut_insert_mode = Insert mode
+shortcut_normal_mode = Normal mode
+shortcut_command_mode = Command mode
+shortcut_visual_mode = Visual mode
+shortcut_movement = Left/Down/Up/Right
+shortcut_next_word = Next word
+shortcut_prev_word = Previous word
+shortcut_line_start = Start of line
+shortcut_line_end = End of line
+shortcut_doc_start = Start of document
+shortcut_doc_end = End of document
+shortcut_delete_char = Delete character
+shortcut_delete_line = Delete line
+shortcut_undo = Undo
+shortcut_cycle_task_state = Cycle task: [ ] → [/] → [x] → [-]
+shortcut_redo = Redo
+
+## About
+app_description = Vim-style markdown note editor
+website = Website
+authors = Authors
+version = Version
+license = License
+
+## Search
+no_results = No results found for
+searching = Searching
+
+## Status
+lines = lines
+words = words
+characters = characters
+notes_count = { $count ->
+    [one] { $count } note
+   *[other] { $count } notes
+}
+lines_count = { $count ->
+    [one] { $count } line
+   *[other] { $count } lines
+}
+words_count = { $count ->
+    [one] { $count } word
+   *[other] { $count } words
+}
+characters_count = { $count ->
+    [one] { $count } character
+   *[other] { $count } characters
+}
+note_statistics = Note statistics
+reading_time = Reading time
+characters_no_spaces = Characters without spaces
+paragraphs = Paragraphs
+headings = Headings
+links = Links
+code_blocks = Code blocks
+images = Images
+saved = Saved
+unsaved_changes = Unsaved changes
+
+## Messages
+note_created = Note created
+note_deleted = Note deleted
+note_renamed = Note renamed
+error = Error
+success = Success
+
+## Image viewer
+image_viewer = Image Viewer
+open_file_location = Open Location
+
+## TODOs
+todos = TODOs
+todos_note = Note TODOs
+no_todos = No TODOs in this note
+completed = completed
+todo_progress = { $completed }/{ $count } { $count ->
+    [one] completed
+   *[other] completed
+}
+todo_progress_subtasks = { $completed }/{ $count } { $count ->
+    [one] subtask
+   *[other] subtasks
+}
+no_section = No section
+
+## YouTube
+transcribe_youtube = Transcribe YouTube video?
+youtube_detected = A YouTube link has been detected
+only_link = Only link
+link_title_found = Link title
+use_page_title = Use title
+keep_bare_link = Keep link
+transcribe_and_insert = Transcribe and insert
+downloading_transcript = Downloading transcript...
+loading_transcript = Loading transcript...
+transcript_error = Error fetching transcript
+transcript_unavailable = Transcript unavailable for this video
+transcript_section = 📝 Transcript
+
+## Music player
+music_search_placeholder = Search music on YouTube...
+no_music_playing = No music playing
+music_play_pause = Play/Pause (Space)
+music_stop = Stop
+music_seek_back = Seek back 5s
+music_seek_forward = Seek forward 5s
+music_volume_down = Lower volume
+music_volume_up = Raise volume
+music_previous_song = Previous song
+music_next_song = Next song
+music_repeat_off = Repeat: OFF
+music_repeat_one = Repeat: ONE
+music_repeat_all = Repeat: ALL
+music_shuffle_off = Shuffle: OFF
+music_shuffle_on = Shuffle: ON
+music_manage_playlists = Manage playlists
+music_playback_queue = Playback queue
+music_loading = Loading...
+music_add_to_queue = Add to queue
+music_remove_from_queue = Remove from queue
+music_new_playlist = New playlist
+music_load_playlist = Load playlist
+music_save_playlist = Save playlist
+music_playlist_name = Playlist name
+
+## System tray
+tray_show_window = Show window
+tray_hide_window = Hide window
+tray_quit = Quit
+
+## AI chat
+ai_chat = AI Chat
+chat_input_placeholder = Type your message here...
+chat_send = Send
+chat_model_default = Model: OpenAI GPT-4
+chat_subtitle = Combine your notes with the assistant in real time
+chat_context = Context
+chat_attach_note = Attach current note
+chat_attach_note_dialog_title = Attach note to context
+chat_attach_button = Attach
+chat_clear_context = Clear context
+chat_clear_history = Clear history
+chat_clear_history_confirm_title = Delete all chat history?
+chat_clear_history_confirm_message = This action will permanently delete all saved conversation history. This cannot be undone.
+chat_history_cleared = History cleared
+chat_history_cleared_message = All conversation history has been deleted
+
+## AI chat - Agent mode
+chat_mode_agent = Mode: Agent
+chat_mode_normal = Mode: Normal
+chat_new_session = New Session
+chat_mode_chat = Mode: Chat
+chat_toggle_mode_tooltip = Toggle: Agent Mode (with tools) / Normal Chat (without tools)
+chat_agent_thinking = 💭 Thought
+chat_agent_action = 🔧 Action
+chat_agent_observation = 👁️ Observation
+chat_agent_answer = ✅ Answer
+music_player_title = Music Player
+ai_chat_placeholder = Type a message for the AI assistant...
+ai_send_message = Send message
+ai_thinking = Thinking...
+ai_model = Model
+ai_temperature = Temperature
+ai_api_key = API Key
+ai_openai = OpenAI
+ai_openrouter = OpenRouter
+ai_api_key_placeholder = Enter your API key...
+ai_save = Save
+ai_cancel = Cancel
+ai_no_key_configured = No API key configured
+ai_configure_key = Configure API key in Preferences
+ai_free_models = ═══ FREE MODELS ═══
+ai_paid_models = ═══ PAID MODELS ═══
+
+## MCP messages
+mcp_note_created = ✓ Note '{}' created successfully
+mcp_note_read = ✓ Note '{}' read successfully
+mcp_note_updated = ✓ Note '{}' updated successfully
+mcp_note_deleted = ✓ Note '{}' deleted successfully
+mcp_content_appended = ✓ Content appended to '{}' successfully
+mcp_notes_found = { $count ->
+    [one] ✓ { $count } note found
+   *[other] ✓ { $count } notes found
+}
+mcp_search_results = { $count ->
+    [one] ✓ { $count } result for '{ $query }'
+   *[other] ✓ { $count } results for '{ $query }'
+}
+mcp_notes_with_tag = { $count ->
+    [one] ✓ { $count } note with tag #{ $tag }
+   *[other] ✓ { $count } notes with tag #{ $tag }
+}
+mcp_tags_found = { $count ->
+    [one] ✓ { $count } tag found
+   *[other] ✓ { $count } tags found
+}
+mcp_tags_added = ✓ Tags added to '{}'
+mcp_note_renamed = ✓ Note renamed from '{}' to '{}'
+mcp_note_duplicated = ✓ Note '{}' duplicated as '{}'
+mcp_folder_created = ✓ Folder '{}' created successfully
+mcp_project_created = ✓ Project '{ $folder }' created from the template
+mcp_note_not_found = Note '{}' not found
+mcp_folders_found = { $count ->
+    [one] ✓ { $count } folder found
+   *[other] ✓ { $count } folders found
+}
+
+## Reminders
+reminders_title = Reminders
+reminders_new = New reminder
+reminders_pending = Pending
+reminders_completed = Completed
+reminders_all = All
+reminder_complete = Complete
+reminder_snooze = Snooze
+reminder_edit = Edit
+reminder_delete = Delete
+reminders_empty = No reminders
+reminders_history = History
+reminders_history_search = Search history…
+reminders_history_export = Export to CSV
+reminders_history_empty = No reminder has fired yet
+reminders_history_exported = History exported
+reminder_outcome_open = open
+reminder_outcome_completed = completed
+reminder_outcome_dismissed = dismissed
+reminder_outcome_missed = missed
+reminder_history_snoozed = snoozed
+reminder_open_note = Open note
+reminder_draft_title = Proposed reminder
+reminder_draft_confirm = Create
+reminder_draft_created = ✓ Reminder created
+reminder_draft_cancelled = Reminder discarded
+reminder_draft_invalid_date = Couldn't read the date (e.g. 2026-10-20 15:00 or tomorrow 10:00)
+reminder_source_missing = This reminder's note no longer exists
+reminder_priority_low = Low
+reminder_priority_medium = Medium
+reminder_priority_high = High
+reminder_priority_urgent = Urgent
+reminder_snooze_5min = 5 minutes
+reminder_snooze_15min = 15 minutes
+reminder_snooze_1hour = 1 hour
+reminder_snooze_tomorrow = Tomorrow
+reminder_notification_title = 🔔 Reminder
+reminder_create_title = Create reminder
+reminder_title_label = Title
+reminder_description_label = Description
+reminder_date_label = Date and time
+reminder_priority_label = Priority
+reminder_linked_note_label = Linked note
+reminder_repeat_label = Repeat
+reminder_repeat_none = Don't repeat
+reminder_repeat_daily = Daily
+reminder_repeat_weekly = Weekly
+reminder_repeat_monthly = Monthly
+no_reminders = No reminders
+reminders_count = { $count ->
+    [one] { $count } pending
+   *[other] { $count } pending
+}
+reminder_overdue = Overdue
+reminder_today = Today
+reminder_tomorrow = Tomorrow
+reminder_created = Reminder created
+reminder_updated = Reminder updated
+reminder_deleted = Reminder deleted
+reminder_completed_msg = Reminder completed
+reminder_snoozed = Reminder snoozed
+reminder_tooltip = Reminders (Alt+R)
+
+## MCP reminder tools
+mcp_create_reminder_desc = Create a new reminder with date, time and priority
+mcp_list_reminders_desc = List all reminders or filter by status
+mcp_update_reminder_desc = Update an existing reminder
+mcp_delete_reminder_desc = Delete a reminder
+mcp_snooze_reminder_desc = Snooze a reminder for a specific time
+mcp_complete_reminder_desc = Mark a reminder as completed
+mcp_reminder_title_desc = Reminder title
+mcp_reminder_date_desc = Reminder date and time (e.g: '2025-11-20 15:00', 'today 18:00', 'tomorrow')
+
+## Quick notes
+quick_notes_title = Quick Note
+quick_note_back_to_list = Back to list
+quick_note_new = New quick note
+quick_note_keep_visible = Keep visible
+quick_note_close = Close (Esc)
+quick_note_no_notes = No quick notes yet
+quick_note_press_to_create = Press + to create one
+quick_note_saved = 💾 Saved
+quick_note_unsaved = ● Unsaved
+quick_note_autosaved = 💾 Auto-saved
+quick_note_shortcut_hint = Ctrl+S: save | Esc: close
+quick_note_created = Quick note created
+
+## Playlists
+playlist_new = 🎵 New
+playlist_save = 💾 Save
+playlist_clear = 🗑️ Clear
+playlist_queue = Playback queue
+playlist_queue_empty = Queue empty
+playlist_saved_playlists = Saved playlists
+playlist_save_title = Save Playlist
+playlist_name_prompt = Playlist name:
+playlist_name_example = e.g.: Relaxing music
+playlist_no_loaded = No playlist loaded
+playlist_no_saved = No saved playlists
+playlist_add_to = Add to playlist
+playlist_delete = Delete playlist
+play = Play
+remove = Remove
+
+## Search and results
+searching_ellipsis = 🔄 Searching...
+no_results_found = ❌ No results found
+found_relevant_notes = { $count ->
+    [one] Found { $count } relevant note:
+   *[other] Found { $count } relevant notes:
+}
+semantic_results = Results by semantic similarity
+ai_analyzing = 🔄 The AI assistant is analyzing the results...
+assistant_response = 🧠 Assistant Response
+
+## General UI
+images_filter = Images
+no_models_found = No models found
+semantic_search_title = 🧠 Semantic Search (Embeddings)
+semantic_search_description = Configure embeddings for conceptual meaning search using OpenRouter
+enable_embeddings = Enable embeddings:
+index_all_notes = 📄 Index all notes
+indexing = ⏳ Indexing...
+indexing_completed = Indexing completed
+unknown_error = Unknown error
+estimated_cost = Estimated cost: ~$0.01 per 10,000 notes
+get_api_key_openrouter = Get API key on OpenRouter
+status_active = 🟢 Active
+copy_url = 📋 Copy URL
+view_docs = 📖 View Documentation
+copied = ✓ Copied!
+copied_as_rich_text = Copied as rich text
+you_label = You
+no_notes_in_context = No notes in context
+remove_from_context = Remove from context
+operation_success = ✓ Operation successful
+operation_failed = ✗ Operation failed
+youtube_unavailable = YouTube transcription not currently available...
+music_player = Music Player
+
+## Keyboard shortcuts (extras)
+shortcut_open_note_search = Open note search
+shortcut_toggle_ai_chat = Toggle AI chat
+shortcut_delete_char_under = Delete character under cursor
+shortcut_delete_line_complete = Delete entire line
+shortcut_delete_prev_char = Delete previous character
+shortcut_delete_next_char = Delete next character
+shortcut_new_line = New line
+shortcut_search_sidebar = Search and Sidebar
+shortcut_activate_search = Activate search
+shortcut_close_search = Close search / Back to editor
+shortcut_open_music = Open music player
+shortcut_open_reminders = Open reminders
+
+## Adding content (AI)
+adding_content = Adding content...
+adding_tag = Adding tag...
+adding_tags = Adding tags...
+generating_index = Generating index...
+extracting_code = Extracting code...
+
+## Footer and interface tooltips
+tooltip_show_hide_notes = Show/hide notes list
+tooltip_new_note = New note
+tooltip_change_search_mode = Ctrl to change mode
+tooltip_close_esc = Close (Esc)
+tooltip_note_tags = Note tags
+tooltip_note_todos = Note TODOs
+tooltip_music_player = Music player
+tooltip_reminders = Reminders (Alt+R)
+tooltip_settings = Settings
+
+## AI settings
+temperature_label = Temperature:
+max_tokens_label = Max Tokens:
+unlimited = Unlimited
+save_history_label = Save history:
+model_label = Model:
+refresh_models_tooltip = Refresh model list from OpenRouter
+search_model_placeholder = Search model...
+
+## Agent/chat mode alerts
+agent_mode_activated =
+    Agent Mode activated
+    The assistant can search notes and execute actions
+chat_mode_activated =
+    Normal Chat activated
+    Direct conversation without tool access
+analyzing_task = Analyzing task...
+
+## MCP server dialog
+mcp_server_title = MCP Server - Model Context Protocol
+mcp_server_active = MCP Server Active
+mcp_server_subtitle = Exposing NotNative tools via HTTP
+mcp_status = Status
+mcp_endpoints_available = Available endpoints
+
+## Keyboard shortcut sections
+shortcuts_global = 🌍 Global
+shortcuts_quick_notes = 📝 Quick Notes
+shortcuts_normal_navigation = 📝 Normal Mode - Navigation
+shortcuts_normal_editing = 📝 Normal Mode - Editing and Modes
+shortcuts_insert_mode = ✍️ Insert Mode
+shortcuts_ai_chat = 🤖 AI Chat Mode
+shortcuts_sidebar = 📂 Sidebar and Lists
+shortcuts_floating_search = 🔍 Floating Search
+
+## Keyboard shortcut descriptions
+shortcut_global_search = Open floating global search
+shortcut_note_search = Open search within current note
+shortcut_cycle_focus = Move focus between sidebar, editor and search
+shortcut_enter_ai_chat = Enter AI Chat mode
+shortcut_back_or_close = Back to list / Close window
+shortcut_left = Move cursor left
+shortcut_down = Move cursor down
+shortcut_up = Move cursor up
+shortcut_right = Move cursor right
+shortcut_ai_chat_mode = Enter AI Chat Mode
+shortcut_insert_table = Insert Markdown table
+shortcut_insert_image = Insert image
+shortcut_insert_sketch = Draw a sketch
+shortcut_insert_footnote = Insert footnote
+shortcut_saveas = Save a copy of the note and open it
+shortcut_split_by_heading = Turn each section into its own note
+shortcut_navigate_history = Go back / forward through opened notes
+shortcut_toggle_highlight = Highlight the selection (repeat to change color)
+shortcut_paste_plain_text = Paste as plain text
+shortcut_copy_as_html = Copy as rich text (HTML)
+shortcut_tab_autocomplete = Tab / Autocomplete Tag or @
+shortcut_exit_chat = Exit Chat (back to Normal Mode)
+shortcut_exit_chat_insert = Exit Chat and enter Insert Mode
+shortcut_send_message = Send message
+shortcut_navigate_suggestions = Navigate suggestions
+shortcut_accept_suggestion = Accept suggestion
+shortcut_next_note = Next note
+shortcut_prev_note = Previous note
+shortcut_open_note = Open selected note or folder
+shortcut_focus_editor = Return focus to editor
+shortcut_toggle_semantic = Toggle semantic search (AI)
+shortcut_navigate_results = Navigate results
+shortcut_open_selected = Open selected note
+
+## Databases
+base_add_filter = Add filter
+base_sort = Sort
+base_columns = Columns
+base_columns_config = Configure Columns
+base_data_source = Data source mode
+base_formula_rows = Formula rows
+base_formula_rows_title = Formula Rows
+base_add_formula_row = Add totals row
+base_formula_row_label = Label
+base_formula_help = Use Excel-like formulas: =SUM(B:B), =AVG(C1:C10)
+base_export_xlsx = Export to Excel
+base_export_xlsx_success = Exported successfully
+base_export_xlsx_error = Export error
+base_show_graph = Show relationships graph
+base_no_filters = No filters
+base_current_columns = Visible Columns
+base_available_properties = Available Properties
+base_toggle_visibility = Toggle visibility
+base_remove_column = Remove column
+base_add_column = Available Properties
+base_properties_hint = Click + to add as column
+base_add_as_column = Add as column
+base_move_up = Move up
+base_move_down = Move down
+base_data_source_title = Data Source
+base_notes_mode = Notes
+base_grouped_mode = Grouped Records
+base_grouped_hint =
+    Grouped Records shows inline property
+    groups like [game::X, bought::Y] as rows
+base_search_placeholder = Search in table...
+base_no_notes = No notes found
+base_items = items
+base_items_of = of
+base_title = Title
+base_created = Created
+base_modified = Modified
+base_tags = Tags
+base_search_properties = Search properties...
+base_no_available_props = No more properties available
+
+## Sort popover
+base_sort_by = Sort by
+base_no_sorting = No sorting
+base_sort_ascending = Sort ascending
+base_sort_descending = Sort descending
+
+## Filter popover
+base_add_filter_title = Add Filter
+base_property = Property
+base_operator = Operator
+base_value = Value
+base_filter_value_placeholder = Filter value...
+base_cancel = Cancel
+base_apply_filter = Add Filter
+
+## Filter operators
+filter_op_equals = equals
+filter_op_not_equals = not equals
+filter_op_contains = contains
+filter_op_not_contains = not contains
+filter_op_greater_than = greater than
+filter_op_greater_or_equal = greater or equal
+filter_op_less_than = less than
+filter_op_less_or_equal = less or equal
+filter_op_starts_with = starts with
+filter_op_ends_with = ends with
+filter_op_is_empty = is empty
+filter_op_is_not_empty = is not empty
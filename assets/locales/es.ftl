@@ -0,0 +1,1014 @@
+# NotNative - Español
+# Las claves que falten en este archivo usan el texto en inglés (en.ftl).
+
+-language-name = Español
+
+## General
+app_title = NotNative
+untitled = Sin título
+notes = Notas
+new_note = Nueva nota
+search = Buscar
+search_notes = Buscar (Ctrl+F)
+search_placeholder = Buscar notas...
+show_hide_notes = Mostrar/ocultar lista de notas
+preferences = Preferencias
+keyboard_shortcuts = Atajos de teclado
+about = Acerca de
+settings = Ajustes
+tags = Tags
+tags_note = Tags de la nota
+no_tags = No hay tags
+search_tag = Buscar notas con este tag
+remove_tag = Eliminar tag
+close = Cerrar
+
+## Diálogos
+create_note_title = Nueva nota
+create_folder_title = Nueva carpeta
+note_name_hint = ejemplo: proyectos/nueva-idea
+create_folder_hint = Usa '/' para crear en carpetas
+create = Crear
+cancel = Cancelar
+rename = Renombrar
+delete = Eliminar
+change_icon = Cambiar icono
+view_history = Ver historial
+duplicate_note = Duplicar nota
+note_duplicated = Copia creada
+merge_into = Fusionar en…
+merge = Fusionar
+merge_search = Buscar la nota destino
+merge_delete_source = Enviar la nota original a la papelera
+note_merged = Nota fusionada en
+note_split = Notas creadas a partir de las secciones
+split_no_sections = La nota no tiene encabezados de ese nivel
+folder_properties = Propiedades de la carpeta…
+folder_description = Descripción
+folder_color = Color
+folder_note_icon = Icono de las notas
+folder_inherit = Las notas y subcarpetas heredan el color y el icono
+folder_ai_system = Instrucciones para la IA
+folder_ai_system_hint = Se añaden al prompt del asistente al chatear con notas de esta carpeta; una nota puede definir las suyas con ai_system en el frontmatter
+pin_note = Fijar arriba
+unpin_note = Dejar de fijar
+notes_selected = { $count ->
+    [one] { $count } nota seleccionada
+   *[other] { $count } notas seleccionadas
+}
+move_to_folder = Mover a carpeta…
+add_tag_batch = Añadir tag…
+remove_tag_batch = Quitar tag…
+export_notes = Exportar…
+root_folder = Raíz
+move = Mover
+apply = Aplicar
+tag_name = Nombre del tag
+notes_moved = Notas movidas
+tags_updated = Tags actualizados
+notes_exported = Notas exportadas
+manage_tags = Gestionar tags…
+tag_manager = Gestor de tags
+tag_select_hint = Elige un tag para renombrarlo o fusionarlo
+tag_new_name = Nuevo nombre
+merge_into_tag = Fusionar en
+tag_renamed = Tag renombrado en
+tag_color = Color
+filter_by_tags = Filtrar por tags
+clear_tag_filter = ✕ Quitar filtro
+suggest_tags = Sugerir tags
+suggest_tags_desc = Al guardar, proponer tags del vault que encajen con la nota
+suggested_tags = Tags sugeridos (clic para añadir)
+inbox_pending = Capturas sin procesar
+captured_to_inbox = Portapapeles guardado en la Inbox
+clipboard_empty = El portapapeles está vacío
+quick_note_preview = Vista previa (Ctrl+P)
+quick_note_send_to = Enviar a…
+quick_note_send_journal = 📅 Diario de hoy
+quick_note_send_new = ➕ Nota nueva
+quick_note_send_search = Buscar nota…
+quick_note_nothing_to_send = Nada que enviar
+quick_note_sent = 📤 Enviada a
+sketch_title = Boceto
+sketch_save = Guardar boceto
+sketch_undo = Deshacer trazo (Ctrl+Z)
+sketch_clear = Borrar todo
+undo = Deshacer
+notes_deleted = Notas eliminadas
+note_moved = Nota movida
+folder_moved = Carpeta movida
+move_undone = Movimiento deshecho
+undo_move_failed = No se pudo deshacer el movimiento
+folder_deleted = Carpeta eliminada
+item_restored = Restaurado
+restore_failed = No se pudo restaurar
+new_project_folder = Nueva carpeta de proyecto…
+project_name = Nombre del proyecto
+folder_templates_hint = Las plantillas se definen en
+project_created = Proyecto creado
+project_failed = No se pudo crear el proyecto
+search_sort = Ordenar resultados
+search_sort_relevance = Relevancia
+search_sort_modified = Modificadas
+search_sort_created = Creadas
+search_sort_title = Título
+search_all_folders = Todas las carpetas
+search_all_tags = Todos los tags
+search_has_todo = Con tareas
+search_has_reminder = Con recordatorio
+search_clear_filters = Quitar filtros
+search_scope = Ámbito de la respuesta
+scope_vault = Todas las notas
+scope_attached = Notas adjuntas
+chat_group_empty = No hay notas en esa carpeta o tag
+chat_group_notes = { $included }/{ $count } { $count ->
+    [one] nota
+   *[other] notas
+}
+chat_note_truncated = recortada
+chat_note_left_out = no cabe
+chat_history_summarized = Mensajes anteriores resumidos
+chat_paste_image = Pegar imagen del portapapeles
+chat_model_no_vision = El modelo configurado no admite imágenes
+chat_image_unsupported = Solo se pueden adjuntar imágenes PNG, JPEG, GIF o WebP
+chat_clipboard_no_image = No hay ninguna imagen en el portapapeles
+custom_agents_label = Agentes propios
+custom_agents_edit = Editar agents.yaml
+custom_agents_hint = Define agentes con su prompt, herramientas y modelo, y las palabras que los activan; los cambios se aplican al guardar
+custom_agents_count = { $count ->
+    [one] { $count } agente
+   *[other] { $count } agentes
+}
+custom_agents_rules = { $count ->
+    [one] { $count } regla
+   *[other] { $count } reglas
+}
+chat_dry_run_tooltip = Modo simulación: el agente propone los cambios y tú los apruebas
+dry_run_activated = Modo simulación activado: no se modificará nada sin tu aprobación
+dry_run_deactivated = Modo simulación desactivado
+dry_run_plan_title = Cambios planeados
+dry_run_apply = Aplicar
+dry_run_discard = Descartar
+dry_run_applied = Cambios aplicados
+dry_run_discarded = Cambios descartados
+dry_run_failed = No se pudo aplicar
+dry_run_lines_skipped = { $count ->
+    [one] { $count } línea sin cambios
+   *[other] { $count } líneas sin cambios
+}
+dry_run_create_note = Crear la nota «{ $name }»
+dry_run_update_note = Modificar «{ $name }»
+dry_run_append_note = Añadir al final de «{ $name }»
+dry_run_delete_note = Borrar «{ $name }»
+agent_plan_title = Plan
+agent_plan_pause = ⏸ Pausar tras este paso
+agent_plan_pausing = Pausando…
+chat_branch_tooltip = Ramificar la conversación desde aquí
+chat_branch_created = Nueva rama de la conversación; la original sigue guardada
+chat_branch_needs_history = Activa guardar el historial del chat para ramificar conversaciones
+chat_branch_error = No se pudo crear la rama de la conversación
+chat_branch_of = Rama de la conversación #{ $id }
+chat_agent_invalid_arguments = Argumentos no válidos para { $tool }; se pide al modelo que los corrija
+ai_status_circuit_open = { $provider } en pausa · { $seconds } s
+ai_status_circuit_open_tooltip = El proveedor de IA ha fallado varias veces seguidas; se volverá a probar al acabar la cuenta
+offline_indicator = Sin conexión
+offline_indicator_tooltip = No hay red: la IA en la nube está desactivada, la búsqueda usa solo texto y los embeddings esperan en cola
+offline_agent_disabled = El modo agente necesita conexión a internet
+offline_chat_local_search = Sin conexión: se buscará en tus notas
+offline_entered = Sin conexión: la IA en la nube queda en pausa
+offline_left = Conexión recuperada
+offline_left_indexing = { $count ->
+    [one] Conexión recuperada; indexando { $count } nota pendiente
+   *[other] Conexión recuperada; indexando { $count } notas pendientes
+}
+offline_chat_title = Sin conexión · notas que coinciden
+offline_chat_no_results = Ninguna nota coincide con el mensaje
+chat_export_tooltip = Exportar conversaciones (JSONL)
+chat_export_done = Conversaciones exportadas
+chat_export_error = No se pudieron exportar las conversaciones
+preview_edit_failed = No se pudo aplicar la edición; usa el modo Insertar
+shortcut_split_preview = Editor y preview lado a lado
+preview_options = Opciones del preview
+preview_density_comfortable = Espaciado cómodo
+preview_density_compact = Compacto
+preview_heading_anchors = Anclas # en los encabezados
+preview_print_friendly = Para imprimir
+preview_print_friendly_tooltip = Sin vídeos ni controles, texto oscuro sobre fondo blanco
+copy_heading_link = Copiar enlace al encabezado
+heading_link_copied = Enlace al encabezado copiado
+duplicate_note_title = ¿Nota repetida?
+duplicate_note_explanation = Ya hay una nota muy parecida. Puedes abrirla, añadirle lo nuevo o crear la nota igualmente.
+duplicate_note_new = Nota nueva
+duplicate_note_imported = Contenido importado
+duplicate_note_title_match = Título parecido
+duplicate_note_content_match = Contenido parecido
+duplicate_note_open = Abrir la existente
+duplicate_note_merge = Añadir a la existente
+duplicate_note_create = Crear igualmente
+vault_health = Estado del vault
+vault_health_largest = Notas más grandes
+vault_health_stale = Sin modificar en más de un año
+vault_health_untagged = Sin tags
+vault_health_orphans = Huérfanas (sin enlaces)
+vault_health_search_index = En el índice de búsqueda
+vault_health_embeddings = Con embeddings
+vault_health_embeddings_disabled = La búsqueda semántica está desactivada
+vault_health_empty = Nada que revisar aquí
+backups = Copias de seguridad
+backups_desc = Copias comprimidas de las notas y la base de datos. La carpeta puede estar en otro disco o en un remoto montado.
+backups_interval = Horas entre copias automáticas
+backups_keep = Conservar
+backups_keep_daily = diarias
+backups_keep_weekly = semanales
+backups_keep_monthly = mensuales
+backups_change_folder = Cambiar carpeta
+backups_select_folder = Carpeta de las copias de seguridad
+backups_now = Hacer copia ahora
+backup_restore = Restaurar copia…
+backup_restore_button = Restaurar
+backup_restore_confirm = ¿Restaurar la copia del
+backup_restore_confirm_detail = La carpeta de notas actual se conserva con otro nombre junto a la restaurada.
+backup_none = Todavía no hay copias de seguridad
+backup_created = Copia de seguridad creada
+backup_failed = No se pudo hacer la copia de seguridad
+backup_restored = Copia restaurada
+backup_restored_restart = Copia restaurada. Reinicia NotNative para terminar de restaurar la base de datos
+backup_restore_failed = No se pudo restaurar la copia
+read_later = Leer más tarde
+remove_from_reading_list = Quitar de la lista de lectura
+reading_list = Lista de lectura
+reading_list_added = Añadida a la lista de lectura
+reading_list_removed = Quitada de la lista de lectura
+reading_list_error = No se pudo actualizar la lista de lectura
+reading_list_sort = Ordenar por
+reading_list_sort_added = Fecha de alta
+reading_list_sort_length = Longitud
+reading_list_empty = No hay nada pendiente de leer
+flashcards_review = Repasar tarjetas
+flashcards_show_answer = Mostrar respuesta
+flashcards_again = Otra vez
+flashcards_hard = Difícil
+flashcards_good = Bien
+flashcards_easy = Fácil
+flashcards_days = d
+flashcards_remaining = { $count ->
+    [one] { $count } pendiente
+   *[other] { $count } pendientes
+}
+flashcards_done = No quedan tarjetas por repasar hoy
+flashcards_none = Aún no hay tarjetas. Escribe Q:: pregunta y A:: respuesta en una nota, o termina un encabezado con ?
+meeting_note = Nota de reunión…
+meeting_note_title = Nueva nota de reunión
+meeting_title_hint = Título de la reunión
+meeting_attendees_hint = Asistentes (Ana, Luis…)
+meeting_attendees_help = Separados por comas; en la nota cada asistente queda mencionado con @
+meeting_agenda = Orden del día
+meeting_notes = Notas
+meeting_action_items = Acciones
+meeting_extract_actions = Extraer acciones…
+meeting_actions_empty = No hay tareas pendientes en la nota. La IA puede sacarlas de lo que se habló.
+meeting_actions_no_due = Sin fecha (aviso mañana)
+meeting_actions_has_reminder = Ya tiene recordatorio
+meeting_actions_suggest = Sugerir con IA
+meeting_actions_create_reminders = Crear recordatorios
+meeting_actions_thinking = Buscando acciones en la nota…
+meeting_actions_ai_error = La IA no pudo sacar las acciones
+weekly_review = Revisión semanal
+weekly_review_this_week = Esta semana
+weekly_review_intro = Esto es lo que pasó en tus notas desde el lunes. Repásalo y sigue para responder a cada sección.
+weekly_review_stats = Estadísticas
+weekly_review_created = Notas creadas
+weekly_review_modified = Notas modificadas
+weekly_review_words = Palabras en esas notas
+weekly_review_journal_days = Días con diario
+weekly_review_open_tasks = Tareas abiertas
+weekly_review_completed_reminders = Recordatorios completados
+weekly_review_none = Nada esta semana
+weekly_review_summary = Balance
+weekly_review_summary_prompt = ¿Cómo fue la semana, en pocas líneas?
+weekly_review_wins = Logros
+weekly_review_wins_prompt = ¿Qué salió bien? ¿De qué estás orgulloso?
+weekly_review_challenges = Dificultades
+weekly_review_challenges_prompt = ¿Qué se atascó o llevó más de lo previsto?
+weekly_review_next_week = Próxima semana
+weekly_review_next_week_prompt = ¿Cuáles son las tres prioridades de la próxima semana?
+weekly_review_ai_draft = Borrador con IA
+weekly_review_ai_error = La IA no pudo escribir el balance
+weekly_review_back = Atrás
+weekly_review_next = Siguiente
+weekly_review_create = Crear nota
+weekly_review_exists = La revisión de esta semana ya existe
+compare_notes = Comparar notas
+compare_with = Comparar con…
+compare_with_current = Comparar con la versión actual
+compare_swap = Intercambiar
+compare_side_by_side = En paralelo
+compare_identical = Las dos notas son iguales
+compare_changes = bloques de cambios
+compare_copy_left = Copiar izquierda
+compare_copy_right = Copiar derecha
+compare_copy_diff = Copiar diff
+folder_ai_excluded = Ocultar a la IA
+folder_ai_excluded_hint = Las notas de esta carpeta y de sus subcarpetas no se indexan, el agente no puede leerlas y no se sugieren en el chat. Una nota suelta se excluye con ai: false en el frontmatter
+chat_note_ai_excluded = Esta nota está excluida de la IA
+mcp_note_ai_excluded = La nota «{ $name }» está excluida de la IA y no se puede leer
+profiles = Perfiles
+profiles_hint = Cada perfil guarda la carpeta de notas, el proveedor de IA, el tema y las copias de seguridad. Al cambiar, los ajustes actuales se guardan en el perfil activo.
+profiles_empty = Aún no hay perfiles
+profile_active = Activo
+profile_switch = Cambiar
+profile_default_folder = Carpeta por defecto
+profile_name_hint = Nombre del perfil (Trabajo, Personal...)
+profile_save_current = Guardar ajustes actuales
+profile_saved = Perfil «{ $name }» guardado
+profile_switched = Perfil activo: { $name }
+mounted_folders = Carpetas montadas
+mounted_folders_desc = Carpetas de fuera del vault (docs de un repositorio, una carpeta sincronizada) que aparecen como carpetas de primer nivel: se indexan, se buscan y se editan, pero siguen en su sitio.
+mount_folder = Montar carpeta…
+unmount_folder = Desmontar
+mount_unavailable = no disponible
+folder_mounted = Carpeta montada
+folder_unmounted = Carpeta desmontada
+refactor_invalid = Nombres no válidos para renombrar
+refactor_no_matches = Ninguna nota que cambiar
+refactor_notes = Notas
+refactor_lines = Líneas
+refactor_apply = Renombrar en todas
+refactor_applied = Renombrado en todas las notas
+refactor_failed = No se pudo renombrar, no se ha cambiado nada
+refactor_undone = Renombrado deshecho
+refactor_undo_failed = No se pudo deshacer el renombrado
+copied_as_plain_text = Copiado como texto plano
+shortcut_copy_as_plain_text = Copiar como texto plano (sin markdown)
+plain_text_link_urls = Al copiar como texto plano, mantener la URL de los enlaces
+word_goal_reached = Objetivo de palabras alcanzado
+word_goal = Objetivo de palabras
+word_goals_today = Objetivos alcanzados hoy
+word_goals_week = Objetivos alcanzados (7 días)
+scratch = Borrador
+shortcut_scratch = Abrir o cerrar el borrador (no se guarda)
+scratch_backup = Guardar una copia del borrador para recuperarlo si la app se cierra de golpe
+tray_compact_mode = Modo compacto
+shortcut_compact_mode = Ventana pequeña solo con el editor (modo compacto)
+file_imported = Archivo importado al vault
+open_file_failed = No se pudo abrir el archivo
+screenshot_title = Anotar captura
+screenshot_insert = Insertar
+screenshot_tool_arrow = Flecha
+screenshot_tool_rect = Recuadro
+screenshot_tool_text = Texto (clic donde ponerlo)
+screenshot_text_placeholder = Texto y Enter
+screenshot_annotate = Anotar las capturas de pantalla (flechas, recuadros y textos) antes de insertarlas
+shortcut_screenshot = Capturar una zona de la pantalla en la nota
+tray_screenshot = Captura de pantalla a Quick Note
+ocr_offer = Ofrecer sacar el texto de las imágenes al insertarlas (OCR), para encontrarlas al buscar
+ocr_engine_tesseract = tesseract (local)
+ocr_engine_ai = Modelo de IA con visión
+ocr_offer_toast = ¿Sacar el texto de la imagen?
+ocr_extract = Sacar texto
+ocr_running = Sacando el texto de la imagen…
+ocr_done = Texto de la imagen añadido debajo de ella
+ocr_block_title = Texto de la imagen
+ocr_no_text = No se encontró texto en la imagen
+ocr_no_image = No hay ninguna imagen en la línea del cursor
+ocr_image_missing = La imagen ya no está en la nota
+ocr_failed = No se pudo sacar el texto de la imagen
+ocr_tesseract_missing = tesseract no está instalado (paquete tesseract)
+ocr_model_no_vision = El modelo de IA configurado no admite imágenes
+shortcut_ocr = Sacar el texto de la imagen de la línea (OCR)
+rename_empty = El nombre no puede estar vacío
+rename_illegal_char = El nombre no puede contener
+rename_hidden = El nombre no puede empezar por un punto
+rename_taken = Ya existe; Enter usará
+renamed_to = Renombrado a
+rename_failed = No se pudo renombrar
+open_in_file_manager = Abrir en explorador
+confirm_delete = ¿Estás seguro de eliminar
+
+## Preferencias
+theme = Tema
+theme_sync = La aplicación sincroniza automáticamente con el tema Omarchy
+markdown_rendering = Renderizado Markdown
+markdown_enabled = Activado por defecto en modo Normal
+language = Idioma
+language_description = Elige el idioma de la interfaz
+restart_required = Se requiere reiniciar la aplicación
+start_in_background = Iniciar en segundo plano
+start_in_background_desc = Iniciar la aplicación minimizada en la bandeja del sistema
+
+## Barra de formato
+format_toolbar = Barra de formato
+format_toolbar_desc = Mostrar barra de herramientas de formato en modo edición
+
+## Vista previa
+preview_adjust_images = Imágenes en modo oscuro
+preview_adjust_images_desc = Invierte diagramas y capturas con fondo blanco en el preview oscuro. Usa ![](img.png){.no-invert} para excluir una imagen
+
+## Historial de navegación y notas recientes
+recent_notes = Recientes
+navigation_history_end = No hay más notas en el historial
+
+## Anotaciones (resaltados ==texto==)
+annotations = Anotaciones
+annotations_search = Buscar en los resaltados
+annotations_empty = Aún no hay resaltados. Selecciona texto y pulsa = en modo Normal, o escribe ==texto==
+
+## Pegado
+paste_html_as_markdown = Pegar HTML como markdown
+paste_html_as_markdown_desc = Convierte el contenido copiado de un navegador o documento en markdown limpio y descarga sus imágenes a los adjuntos. Ctrl+Shift+V pega siempre texto plano
+url_paste_behavior = Al pegar un enlace
+url_paste_ask = Preguntar si usar el título
+url_paste_title = Usar siempre el título
+url_paste_bare = Dejar el enlace tal cual
+
+## Modo táctil
+large_tap_targets = Botones grandes (táctil)
+large_tap_targets_desc = Agranda botones y filas para usar la app con el dedo. Desliza desde el borde izquierdo para abrir las notas, pellizca el preview para cambiar el tamaño del texto y mantén pulsada una nota para ver su menú
+embed_budget = Contenido embebido
+embed_budget_desc = Máximo de videos cargados a la vez en el preview. Los videos fuera de pantalla se descargan; con 0 solo se cargan al pulsarlos
+
+## Plugins
+plugins = Plugins
+plugin_error = Error en el plugin
+
+## Fechas
+hour_format = Formato de hora
+hour_format_auto = Automático (según región)
+
+## Registro (logs)
+logs = Registro (logs)
+logs_level_desc = Nivel de detalle del registro. Útil para reportar problemas
+logs_view = Ver registros
+logs_empty = Todavía no hay registros
+logs_follow = Seguir en vivo
+logs_copy = Copiar
+
+## Credenciales
+api_key_storage_keyring = 🔐 Las claves se guardan en el llavero del sistema
+api_key_storage_file = 🔐 Llavero no disponible: las claves se guardan cifradas en el directorio de datos
+
+## Ventana de preferencias
+prefs_page_general = General
+prefs_page_editor = Editor
+prefs_page_ai = IA
+prefs_page_sync = Sincronización
+prefs_page_reminders = Recordatorios
+prefs_page_music = Música
+prefs_page_advanced = Avanzado
+prefs_search_placeholder = Buscar ajustes...
+prefs_no_results = Ningún ajuste coincide con la búsqueda
+prefs_revert = Revertir cambios
+prefs_revert_tooltip = Restaura los ajustes que había al abrir esta ventana
+prefs_mcp_desc = Permite que otras aplicaciones y asistentes accedan a tus notas
+prefs_mcp_details = Ver detalles
+prefs_reminders_desc = Los recordatorios se muestran en el panel (Alt+R) y como notificaciones del sistema
+prefs_show_reminders = Ver recordatorios
+prefs_config_file = Archivo de configuración
+prefs_open_config_folder = Abrir carpeta
+
+## Espacio de trabajo
+workspace = Directorio de trabajo
+workspace_description = Ubicación donde se guardan las notas y recursos
+open_workspace_folder = Abrir carpeta de trabajo
+change_workspace = Cambiar ubicación
+workspace_location = Ubicación
+select_workspace_folder = Seleccionar carpeta de trabajo
+select = Seleccionar
+
+## Audio
+audio_output = Salida de audio
+audio_output_description = Seleccionar dispositivo de salida de audio
+audio_output_default = Por defecto del sistema
+audio_output_detecting = Detectando dispositivos...
+audio_output_changed = Salida de audio cambiada
+audio_output_error = Error cambiando salida de audio
+
+## Atajos de teclado
+shortcuts_general = General
+shortcuts_modes = Modos de edición
+shortcuts_navigation = Navegación
+shortcuts_editing = Edición
+shortcut_new_note = Crear nueva nota
+shortcut_save = Guardar nota
+shortcut_search = Buscar notas
+shortcut_toggle_sidebar = Alternar sidebar
+shortcut_escape = Volver al editor
+shortcut_insert_mode = Modo Insert
+shortcut_normal_mode = Modo Normal
+shortcut_command_mode = Modo Command
+shortcut_visual_mode = Modo Visual
+shortcut_movement = Izquierda/Abajo/Arriba/Derecha
+shortcut_next_word = Siguiente palabra
+shortcut_prev_word = Palabra anterior
+shortcut_line_start = Inicio de línea
+shortcut_line_end = Fin de línea
+shortcut_doc_start = Inicio del documento
+shortcut_doc_end = Fin del documento
+shortcut_delete_char = Eliminar carácter
+shortcut_delete_line = Eliminar línea
+shortcut_undo = Deshacer
+shortcut_cycle_task_state = Ciclar tarea: [ ] → [/] → [x] → [-]
+shortcut_redo = Rehacer
+
+## Acerca de
+app_description = Editor de notas markdown con estilo vim
+website = Sitio web
+authors = Autores
+version = Versión
+license = Licencia
+
+## Búsqueda
+no_results = No se encontraron resultados para
+searching = Buscando
+
+## Estados
+lines = líneas
+words = palabras
+characters = caracteres
+notes_count = { $count ->
+    [one] { $count } nota
+   *[other] { $count } notas
+}
+lines_count = { $count ->
+    [one] { $count } línea
+   *[other] { $count } líneas
+}
+words_count = { $count ->
+    [one] { $count } palabra
+   *[other] { $count } palabras
+}
+characters_count = { $count ->
+    [one] { $count } carácter
+   *[other] { $count } caracteres
+}
+note_statistics = Estadísticas de la nota
+reading_time = Tiempo de lectura
+characters_no_spaces = Caracteres sin espacios
+paragraphs = Párrafos
+headings = Encabezados
+links = Enlaces
+code_blocks = Bloques de código
+images = Imágenes
+saved = Guardado
+unsaved_changes = Cambios sin guardar
+
+## Mensajes
+note_created = Nota creada
+note_deleted = Nota eliminada
+note_renamed = Nota renombrada
+error = Error
+success = Éxito
+
+## Visor de imágenes
+image_viewer = Visor de imagen
+open_file_location = Abrir ubicación
+
+## TODOs
+todos = TODOs
+todos_note = TODOs de la nota
+no_todos = No hay TODOs en esta nota
+completed = completo
+todo_progress = { $completed }/{ $count } { $count ->
+    [one] completada
+   *[other] completadas
+}
+todo_progress_subtasks = { $completed }/{ $count } { $count ->
+    [one] subtarea
+   *[other] subtareas
+}
+no_section = Sin sección
+
+## YouTube
+transcribe_youtube = ¿Transcribir video de YouTube?
+youtube_detected = Se ha detectado un enlace de YouTube
+only_link = Solo enlace
+link_title_found = Título del enlace
+use_page_title = Usar título
+keep_bare_link = Dejar enlace
+transcribe_and_insert = Transcribir e insertar
+downloading_transcript = Descargando transcripción...
+loading_transcript = Cargando transcripción...
+transcript_error = Error al obtener transcripción
+transcript_unavailable = Transcripción no disponible para este video
+transcript_section = 📝 Transcripción
+
+## Reproductor de música
+music_search_placeholder = Buscar música en YouTube...
+no_music_playing = No hay música reproduciéndose
+music_play_pause = Reproducir/Pausar (Espacio)
+music_stop = Detener
+music_seek_back = Retroceder 5s
+music_seek_forward = Avanzar 5s
+music_volume_down = Bajar volumen
+music_volume_up = Subir volumen
+music_previous_song = Canción anterior
+music_next_song = Siguiente canción
+music_repeat_off = Repetir: OFF
+music_repeat_one = Repetir: UNA
+music_repeat_all = Repetir: TODAS
+music_shuffle_off = Aleatorio: OFF
+music_shuffle_on = Aleatorio: ON
+music_manage_playlists = Gestionar playlists
+music_playback_queue = Cola de reproducción
+music_loading = Cargando...
+music_add_to_queue = Añadir a cola
+music_remove_from_queue = Eliminar de cola
+music_new_playlist = Nueva playlist
+music_load_playlist = Cargar playlist
+music_save_playlist = Guardar playlist
+music_playlist_name = Nombre de la playlist
+
+## Bandeja del sistema
+tray_show_window = Mostrar ventana
+tray_hide_window = Ocultar ventana
+tray_quit = Salir
+
+## Chat IA
+ai_chat = Chat IA
+chat_input_placeholder = Escribe tu mensaje aquí...
+chat_send = Enviar
+chat_model_default = Modelo: OpenAI GPT-4
+chat_subtitle = Combina tus notas con el asistente en tiempo real
+chat_context = Contexto
+chat_attach_note = Adjuntar nota actual
+chat_attach_note_dialog_title = Adjuntar nota al contexto
+chat_attach_button = Adjuntar
+chat_clear_context = Limpiar contexto
+chat_clear_history = Borrar historial
+chat_clear_history_confirm_title = ¿Borrar todo el historial de chat?
+chat_clear_history_confirm_message = Esta acción eliminará permanentemente todo el historial de conversaciones guardado. No se puede deshacer.
+chat_history_cleared = Historial borrado
+chat_history_cleared_message = Se ha eliminado todo el historial de conversaciones
+
+## Chat IA - Modo agente
+chat_mode_agent = Modo: Agente
+chat_mode_normal = Modo: Normal
+chat_new_session = Nueva sesión
+chat_mode_chat = Modo: Chat
+chat_toggle_mode_tooltip = Alternar: Modo Agente (con herramientas) / Chat Normal (sin herramientas)
+chat_agent_thinking = 💭 Pensamiento
+chat_agent_action = 🔧 Acción
+chat_agent_observation = 👁️ Observación
+chat_agent_answer = ✅ Respuesta
+music_player_title = Reproductor de Música
+ai_chat_placeholder = Escribe un mensaje para el asistente IA...
+ai_send_message = Enviar mensaje
+ai_thinking = Pensando...
+ai_model = Modelo
+ai_temperature = Temperatura
+ai_api_key = API Key
+ai_openai = OpenAI
+ai_openrouter = OpenRouter
+ai_api_key_placeholder = Ingresa tu API key...
+ai_save = Guardar
+ai_cancel = Cancelar
+ai_no_key_configured = No hay API key configurada
+ai_configure_key = Configurar API key en Preferencias
+ai_free_models = ═══ MODELOS GRATUITOS ═══
+ai_paid_models = ═══ MODELOS DE PAGO ═══
+
+## Mensajes MCP
+mcp_note_created = ✓ Nota '{}' creada exitosamente
+mcp_note_read = ✓ Nota '{}' leída correctamente
+mcp_note_updated = ✓ Nota '{}' actualizada exitosamente
+mcp_note_deleted = ✓ Nota '{}' eliminada exitosamente
+mcp_content_appended = ✓ Contenido agregado a '{}' exitosamente
+mcp_notes_found = { $count ->
+    [one] ✓ { $count } nota encontrada
+   *[other] ✓ { $count } notas encontradas
+}
+mcp_search_results = { $count ->
+    [one] ✓ { $count } resultado para '{ $query }'
+   *[other] ✓ { $count } resultados para '{ $query }'
+}
+mcp_notes_with_tag = { $count ->
+    [one] ✓ { $count } nota con tag #{ $tag }
+   *[other] ✓ { $count } notas con tag #{ $tag }
+}
+mcp_tags_found = { $count ->
+    [one] ✓ { $count } tag encontrado
+   *[other] ✓ { $count } tags encontrados
+}
+mcp_tags_added = ✓ Tags agregados a '{}'
+mcp_note_renamed = ✓ Nota renombrada de '{}' a '{}'
+mcp_note_duplicated = ✓ Nota '{}' duplicada como '{}'
+mcp_folder_created = ✓ Carpeta '{}' creada exitosamente
+mcp_project_created = ✓ Proyecto '{ $folder }' creado a partir de la plantilla
+mcp_note_not_found = Nota '{}' no encontrada
+mcp_folders_found = { $count ->
+    [one] ✓ { $count } carpeta encontrada
+   *[other] ✓ { $count } carpetas encontradas
+}
+
+## Recordatorios
+reminders_title = Recordatorios
+reminders_new = Nuevo recordatorio
+reminders_pending = Pendientes
+reminders_completed = Completados
+reminders_all = Todos
+reminder_complete = Completar
+reminder_snooze = Posponer
+reminder_edit = Editar
+reminder_delete = Eliminar
+reminders_empty = No hay recordatorios
+reminders_history = Historial
+reminders_history_search = Buscar en el historial…
+reminders_history_export = Exportar a CSV
+reminders_history_empty = Todavía no ha saltado ningún recordatorio
+reminders_history_exported = Historial exportado
+reminder_outcome_open = sin resolver
+reminder_outcome_completed = completado
+reminder_outcome_dismissed = descartado
+reminder_outcome_missed = ignorado
+reminder_history_snoozed = pospuesto
+reminder_open_note = Abrir nota
+reminder_draft_title = Recordatorio propuesto
+reminder_draft_confirm = Crear
+reminder_draft_created = ✓ Recordatorio creado
+reminder_draft_cancelled = Recordatorio descartado
+reminder_draft_invalid_date = No se entiende la fecha (p. ej. 2026-10-20 15:00 o mañana 10:00)
+reminder_source_missing = La nota de este recordatorio ya no existe
+reminder_priority_low = Baja
+reminder_priority_medium = Media
+reminder_priority_high = Alta
+reminder_priority_urgent = Urgente
+reminder_snooze_5min = 5 minutos
+reminder_snooze_15min = 15 minutos
+reminder_snooze_1hour = 1 hora
+reminder_snooze_tomorrow = Mañana
+reminder_notification_title = 🔔 Recordatorio
+reminder_create_title = Crear recordatorio
+reminder_title_label = Título
+reminder_description_label = Descripción
+reminder_date_label = Fecha y hora
+reminder_priority_label = Prioridad
+reminder_linked_note_label = Nota vinculada
+reminder_repeat_label = Repetir
+reminder_repeat_none = No repetir
+reminder_repeat_daily = Diariamente
+reminder_repeat_weekly = Semanalmente
+reminder_repeat_monthly = Mensualmente
+no_reminders = No hay recordatorios
+reminders_count = { $count ->
+    [one] { $count } pendiente
+   *[other] { $count } pendientes
+}
+reminder_overdue = Vencido
+reminder_today = Hoy
+reminder_tomorrow = Mañana
+reminder_created = Recordatorio creado
+reminder_updated = Recordatorio actualizado
+reminder_deleted = Recordatorio eliminado
+reminder_completed_msg = Recordatorio completado
+reminder_snoozed = Recordatorio pospuesto
+reminder_tooltip = Recordatorios (Alt+R)
+
+## Herramientas MCP de recordatorios
+mcp_create_reminder_desc = Crea un nuevo recordatorio con fecha, hora y prioridad
+mcp_list_reminders_desc = Lista todos los recordatorios o filtra por estado
+mcp_update_reminder_desc = Actualiza un recordatorio existente
+mcp_delete_reminder_desc = Elimina un recordatorio
+mcp_snooze_reminder_desc = Pospone un recordatorio por un tiempo específico
+mcp_complete_reminder_desc = Marca un recordatorio como completado
+mcp_reminder_title_desc = Título del recordatorio
+mcp_reminder_date_desc = Fecha y hora del recordatorio (ej: '2025-11-20 15:00', 'hoy 18:00', 'mañana')
+
+## Notas rápidas
+quick_notes_title = Quick Note
+quick_note_back_to_list = Volver a la lista
+quick_note_new = Nueva quick note
+quick_note_keep_visible = Mantener visible
+quick_note_close = Cerrar (Esc)
+quick_note_no_notes = No hay quick notes aún
+quick_note_press_to_create = Presiona + para crear una
+quick_note_saved = 💾 Guardado
+quick_note_unsaved = ● Sin guardar
+quick_note_autosaved = 💾 Auto-guardado
+quick_note_shortcut_hint = Ctrl+S: guardar | Esc: cerrar
+quick_note_created = Quick note creada
+
+## Listas de reproducción
+playlist_new = 🎵 Nueva
+playlist_save = 💾 Guardar
+playlist_clear = 🗑️ Limpiar
+playlist_queue = Cola de reproducción
+playlist_queue_empty = Cola vacía
+playlist_saved_playlists = Playlists guardadas
+playlist_save_title = Guardar Playlist
+playlist_name_prompt = Nombre de la playlist:
+playlist_name_example = ej: Música relajante
+playlist_no_loaded = No hay playlist cargada
+playlist_no_saved = No hay playlists guardadas
+playlist_add_to = Agregar a playlist
+playlist_delete = Eliminar playlist
+play = Reproducir
+remove = Eliminar
+
+## Búsqueda y resultados
+searching_ellipsis = 🔄 Buscando...
+no_results_found = ❌ No se encontraron resultados
+found_relevant_notes = { $count ->
+    [one] Encontré { $count } nota relevante:
+   *[other] Encontré { $count } notas relevantes:
+}
+semantic_results = Resultados por similitud semántica
+ai_analyzing = 🔄 El asistente de IA está analizando los resultados...
+assistant_response = 🧠 Respuesta del Asistente
+
+## Interfaz general
+images_filter = Imágenes
+no_models_found = No se encontraron modelos
+semantic_search_title = 🧠 Búsqueda Semántica (Embeddings)
+semantic_search_description = Configura embeddings para búsqueda por significado conceptual usando OpenRouter
+enable_embeddings = Habilitar embeddings:
+index_all_notes = 📄 Indexar todas las notas
+indexing = ⏳ Indexando...
+indexing_completed = Indexación completada
+unknown_error = Error desconocido
+estimated_cost = Costo estimado: ~$0.01 por 10,000 notas
+get_api_key_openrouter = Obtener API key en OpenRouter
+status_active = 🟢 Activo
+copy_url = 📋 Copiar URL
+view_docs = 📖 Ver Documentación
+copied = ✓ Copiado!
+copied_as_rich_text = Copiado como texto enriquecido
+you_label = Tú
+no_notes_in_context = Sin notas en contexto
+remove_from_context = Remover del contexto
+operation_success = ✓ Operación exitosa
+operation_failed = ✗ Operación fallida
+youtube_unavailable = Transcripción de YouTube no disponible actualmente...
+music_player = Reproductor de música
+
+## Atajos de teclado (extras)
+shortcut_open_note_search = Abrir búsqueda de notas
+shortcut_toggle_ai_chat = Alternar chat IA
+shortcut_delete_char_under = Eliminar carácter bajo el cursor
+shortcut_delete_line_complete = Eliminar línea completa
+shortcut_delete_prev_char = Eliminar carácter anterior
+shortcut_delete_next_char = Eliminar carácter siguiente
+shortcut_new_line = Nueva línea
+shortcut_search_sidebar = Búsqueda y Sidebar
+shortcut_activate_search = Activar búsqueda
+shortcut_close_search = Cerrar búsqueda / Volver al editor
+shortcut_open_music = Abrir reproductor de música
+shortcut_open_reminders = Abrir recordatorios
+
+## Añadiendo contenido (IA)
+adding_content = Añadiendo contenido...
+adding_tag = Añadiendo etiqueta...
+adding_tags = Añadiendo etiquetas...
+generating_index = Generando índice...
+extracting_code = Extrayendo código...
+
+## Tooltips del pie e interfaz
+tooltip_show_hide_notes = Mostrar/ocultar lista de notas
+tooltip_new_note = Nueva nota
+tooltip_change_search_mode = Ctrl para cambiar modo
+tooltip_close_esc = Cerrar (Esc)
+tooltip_note_tags = Tags de la nota
+tooltip_note_todos = TODOs de la nota
+tooltip_music_player = Reproductor de música
+tooltip_reminders = Recordatorios (Alt+R)
+tooltip_settings = Ajustes
+
+## Ajustes de IA
+temperature_label = Temperatura:
+max_tokens_label = Max Tokens:
+unlimited = Ilimitado
+save_history_label = Guardar historial:
+model_label = Modelo:
+refresh_models_tooltip = Actualizar lista de modelos desde OpenRouter
+search_model_placeholder = Buscar modelo...
+
+## Avisos de modo agente/chat
+agent_mode_activated =
+    Modo Agente activado
+    El asistente puede buscar en notas y ejecutar acciones
+chat_mode_activated =
+    Chat Normal activado
+    Conversación directa sin acceso a herramientas
+analyzing_task = Analizando tarea...
+
+## Diálogo del servidor MCP
+mcp_server_title = MCP Server - Model Context Protocol
+mcp_server_active = MCP Server Activo
+mcp_server_subtitle = Exponiendo herramientas de NotNative via HTTP
+mcp_status = Estado
+mcp_endpoints_available = Endpoints disponibles
+
+## Secciones de atajos de teclado
+shortcuts_global = 🌍 Globales
+shortcuts_quick_notes = 📝 Quick Notes
+shortcuts_normal_navigation = 📝 Modo Normal - Navegación
+shortcuts_normal_editing = 📝 Modo Normal - Edición y Modos
+shortcuts_insert_mode = ✍️ Modo Insertar
+shortcuts_ai_chat = 🤖 Modo Chat AI
+shortcuts_sidebar = 📂 Sidebar y Listas
+shortcuts_floating_search = 🔍 Búsqueda Flotante
+
+## Descripciones de atajos de teclado
+shortcut_global_search = Abrir búsqueda global flotante
+shortcut_note_search = Abrir búsqueda dentro de la nota actual
+shortcut_cycle_focus = Mover el foco entre sidebar, editor y búsqueda
+shortcut_enter_ai_chat = Entrar al modo Chat AI
+shortcut_back_or_close = Volver a lista / Cerrar ventana
+shortcut_left = Mover cursor a la izquierda
+shortcut_down = Mover cursor abajo
+shortcut_up = Mover cursor arriba
+shortcut_right = Mover cursor a la derecha
+shortcut_ai_chat_mode = Entrar en Modo Chat AI
+shortcut_insert_table = Insertar tabla Markdown
+shortcut_insert_image = Insertar imagen
+shortcut_insert_sketch = Dibujar un boceto
+shortcut_insert_footnote = Insertar nota al pie
+shortcut_saveas = Guardar una copia de la nota y abrirla
+shortcut_split_by_heading = Pasar cada sección a una nota propia
+shortcut_navigate_history = Volver / avanzar por las notas abiertas
+shortcut_toggle_highlight = Resaltar la selección (repetir cambia el color)
+shortcut_paste_plain_text = Pegar como texto plano
+shortcut_copy_as_html = Copiar como texto enriquecido (HTML)
+shortcut_tab_autocomplete = Tabulación / Autocompletar Tag o @
+shortcut_exit_chat = Salir del Chat (volver a Modo Normal)
+shortcut_exit_chat_insert = Salir del Chat y entrar a Modo Insertar
+shortcut_send_message = Enviar mensaje
+shortcut_navigate_suggestions = Navegar sugerencias
+shortcut_accept_suggestion = Aceptar sugerencia
+shortcut_next_note = Siguiente nota
+shortcut_prev_note = Nota anterior
+shortcut_open_note = Abrir nota o carpeta seleccionada
+shortcut_focus_editor = Devolver foco al editor
+shortcut_toggle_semantic = Alternar búsqueda semántica (AI)
+shortcut_navigate_results = Navegar resultados
+shortcut_open_selected = Abrir nota seleccionada
+
+## Bases de datos
+base_add_filter = Añadir filtro
+base_sort = Ordenar
+base_columns = Columnas
+base_columns_config = Configurar Columnas
+base_data_source = Origen de datos
+base_formula_rows = Filas con fórmulas
+base_formula_rows_title = Filas de Fórmulas
+base_add_formula_row = Añadir fila de totales
+base_formula_row_label = Etiqueta
+base_formula_help = Usa fórmulas tipo Excel: =SUM(B:B), =AVG(C1:C10)
+base_export_xlsx = Exportar a Excel
+base_export_xlsx_success = Exportado correctamente
+base_export_xlsx_error = Error al exportar
+base_show_graph = Mostrar grafo de relaciones
+base_no_filters = Sin filtros
+base_current_columns = Columnas visibles
+base_available_properties = Propiedades disponibles
+base_toggle_visibility = Alternar visibilidad
+base_remove_column = Eliminar columna
+base_add_column = Propiedades disponibles
+base_properties_hint = Clic en + para añadir como columna
+base_add_as_column = Añadir como columna
+base_move_up = Mover arriba
+base_move_down = Mover abajo
+base_data_source_title = Origen de datos
+base_notes_mode = Notas
+base_grouped_mode = Registros agrupados
+base_grouped_hint =
+    Los registros agrupados muestran propiedades
+    inline como [game::X, bought::Y] como filas
+base_search_placeholder = Buscar en tabla...
+base_no_notes = No se encontraron notas
+base_items = elementos
+base_items_of = de
+base_title = Título
+base_created = Creado
+base_modified = Modificado
+base_tags = Etiquetas
+base_search_properties = Buscar propiedades...
+base_no_available_props = No hay más propiedades disponibles
+
+## Ordenación
+base_sort_by = Ordenar por
+base_no_sorting = Sin ordenar
+base_sort_ascending = Orden ascendente
+base_sort_descending = Orden descendente
+
+## Filtros
+base_add_filter_title = Añadir filtro
+base_property = Propiedad
+base_operator = Operador
+base_value = Valor
+base_filter_value_placeholder = Valor del filtro...
+base_cancel = Cancelar
+base_apply_filter = Aplicar filtro
+
+## Operadores de filtro
+filter_op_equals = igual a
+filter_op_not_equals = distinto de
+filter_op_contains = contiene
+filter_op_not_contains = no contiene
+filter_op_greater_than = mayor que
+filter_op_greater_or_equal = mayor o igual
+filter_op_less_than = menor que
+filter_op_less_or_equal = menor o igual
+filter_op_starts_with = empieza con
+filter_op_ends_with = termina con
+filter_op_is_empty = está vacío
+filter_op_is_not_empty = no está vacío
@@ -0,0 +1,976 @@
+# NotNative - Français
+# Les clés absentes de ce fichier utilisent le texte anglais intégré.
+# Générer la liste complète des clés : notnative --export-locale-template fr.ftl
+
+-language-name = Français
+-app = NotNative
+
+## Général
+app_title = { -app }
+untitled = Sans titre
+notes = Notes
+new_note = Nouvelle note
+search = Rechercher
+search_notes = Rechercher (Ctrl+F)
+search_placeholder = Rechercher des notes...
+show_hide_notes = Afficher/masquer la liste des notes
+preferences = Préférences
+keyboard_shortcuts = Raccourcis clavier
+about = À propos
+settings = Réglages
+tags = Tags
+tags_note = Tags de la note
+no_tags = Aucun tag
+search_tag = Rechercher les notes avec ce tag
+remove_tag = Supprimer le tag
+close = Fermer
+
+## Dialogues
+create_note_title = Nouvelle note
+create_folder_title = Nouveau dossier
+note_name_hint = exemple : projets/nouvelle-idee
+create_folder_hint = Utilisez '/' pour créer dans des dossiers
+create = Créer
+cancel = Annuler
+rename = Renommer
+delete = Supprimer
+change_icon = Changer l'icône
+view_history = Voir l'historique
+duplicate_note = Dupliquer la note
+note_duplicated = Copie créée
+merge_into = Fusionner dans…
+merge = Fusionner
+merge_search = Rechercher la note de destination
+merge_delete_source = Mettre la note d'origine à la corbeille
+note_merged = Note fusionnée dans
+note_split = Notes créées à partir des sections
+split_no_sections = La note n'a pas de titres de ce niveau
+pin_note = Épingler en haut
+unpin_note = Désépingler
+notes_selected = { $count ->
+    [one] { $count } note sélectionnée
+   *[other] { $count } notes sélectionnées
+}
+move_to_folder = Déplacer vers un dossier…
+add_tag_batch = Ajouter un tag…
+remove_tag_batch = Retirer un tag…
+export_notes = Exporter…
+root_folder = Racine
+move = Déplacer
+apply = Appliquer
+tag_name = Nom du tag
+notes_moved = Notes déplacées
+tags_updated = Tags mis à jour
+notes_exported = Notes exportées
+folder_properties = Propriétés du dossier…
+folder_description = Description
+folder_color = Couleur
+folder_note_icon = Icône des notes
+folder_inherit = Les notes et sous-dossiers héritent de la couleur et de l'icône
+folder_ai_system = Instructions pour l'IA
+folder_ai_system_hint = Ajoutées au prompt de l'assistant quand on discute avec des notes de ce dossier ; une note peut définir les siennes avec ai_system dans son frontmatter
+manage_tags = Gérer les tags…
+tag_manager = Gestionnaire de tags
+tag_select_hint = Choisissez un tag pour le renommer ou le fusionner
+tag_new_name = Nouveau nom
+merge_into_tag = Fusionner dans
+tag_renamed = Tag renommé dans
+tag_color = Couleur
+filter_by_tags = Filtrer par tags
+clear_tag_filter = ✕ Effacer le filtre
+suggest_tags = Suggérer des tags
+suggest_tags_desc = À l'enregistrement, suggérer des tags du coffre adaptés à la note
+suggested_tags = Tags suggérés (cliquer pour ajouter)
+inbox_pending = Captures non traitées
+captured_to_inbox = Presse-papiers enregistré dans l'Inbox
+clipboard_empty = Le presse-papiers est vide
+quick_note_preview = Aperçu (Ctrl+P)
+quick_note_send_to = Envoyer vers…
+quick_note_send_journal = 📅 Journal du jour
+quick_note_send_new = ➕ Nouvelle note
+quick_note_send_search = Rechercher une note…
+quick_note_nothing_to_send = Rien à envoyer
+quick_note_sent = 📤 Envoyée vers
+sketch_title = Croquis
+sketch_save = Enregistrer le croquis
+sketch_undo = Annuler le trait (Ctrl+Z)
+sketch_clear = Tout effacer
+undo = Annuler
+notes_deleted = Notes supprimées
+note_moved = Note déplacée
+folder_moved = Dossier déplacé
+move_undone = Déplacement annulé
+undo_move_failed = Impossible d'annuler le déplacement
+folder_deleted = Dossier supprimé
+item_restored = Restauré
+restore_failed = Impossible de restaurer
+new_project_folder = Nouveau dossier de projet…
+project_name = Nom du projet
+folder_templates_hint = Les modèles sont définis dans
+project_created = Projet créé
+project_failed = Impossible de créer le projet
+search_sort = Trier les résultats
+search_sort_relevance = Pertinence
+search_sort_modified = Modifiées
+search_sort_created = Créées
+search_sort_title = Titre
+search_all_folders = Tous les dossiers
+search_all_tags = Tous les tags
+search_has_todo = Avec tâches
+search_has_reminder = Avec rappel
+search_clear_filters = Effacer les filtres
+search_scope = Portée de la réponse
+scope_vault = Toutes les notes
+scope_attached = Notes jointes
+chat_group_empty = Ce dossier ou ce tag ne contient aucune note
+chat_group_notes = { $included }/{ $count } { $count ->
+    [one] note
+   *[other] notes
+}
+chat_note_truncated = tronquée
+chat_note_left_out = ne rentre pas
+chat_history_summarized = Messages précédents résumés
+chat_paste_image = Coller l'image du presse-papiers
+chat_model_no_vision = Le modèle configuré n'accepte pas les images
+chat_image_unsupported = Seules les images PNG, JPEG, GIF ou WebP peuvent être jointes
+chat_clipboard_no_image = Le presse-papiers ne contient pas d'image
+custom_agents_label = Agents personnalisés
+custom_agents_edit = Modifier agents.yaml
+custom_agents_hint = Définissez des agents avec leur prompt, leurs outils et leur modèle, et les mots qui les déclenchent ; les changements s'appliquent à l'enregistrement
+custom_agents_count = { $count ->
+    [one] { $count } agent
+   *[other] { $count } agents
+}
+custom_agents_rules = { $count ->
+    [one] { $count } règle
+   *[other] { $count } règles
+}
+chat_dry_run_tooltip = Simulation : l'agent propose les modifications et vous les approuvez
+dry_run_activated = Simulation activée : rien ne sera modifié sans votre accord
+dry_run_deactivated = Simulation désactivée
+dry_run_plan_title = Modifications prévues
+dry_run_apply = Appliquer
+dry_run_discard = Ignorer
+dry_run_applied = Modifications appliquées
+dry_run_discarded = Modifications ignorées
+dry_run_failed = Impossible d'appliquer
+dry_run_lines_skipped = { $count ->
+    [one] { $count } ligne inchangée
+   *[other] { $count } lignes inchangées
+}
+dry_run_create_note = Créer la note « { $name } »
+dry_run_update_note = Modifier « { $name } »
+dry_run_append_note = Ajouter à la fin de « { $name } »
+dry_run_delete_note = Supprimer « { $name } »
+agent_plan_title = Plan
+agent_plan_pause = ⏸ Mettre en pause après cette étape
+agent_plan_pausing = Mise en pause…
+chat_branch_tooltip = Créer une branche de la conversation à partir d'ici
+chat_branch_created = Nouvelle branche de la conversation ; l'originale reste enregistrée
+chat_branch_needs_history = Activez l'enregistrement de l'historique du chat pour créer des branches
+chat_branch_error = Impossible de créer la branche de la conversation
+chat_branch_of = Branche de la conversation #{ $id }
+chat_agent_invalid_arguments = Arguments non valides pour { $tool } ; le modèle est invité à les corriger
+ai_status_circuit_open = { $provider } en pause · { $seconds } s
+ai_status_circuit_open_tooltip = Le fournisseur d'IA a échoué plusieurs fois de suite ; il sera réessayé à la fin du compte à rebours
+offline_indicator = Hors ligne
+offline_indicator_tooltip = Pas de réseau : l'IA dans le cloud est désactivée, la recherche utilise uniquement le texte et les embeddings attendent dans une file
+offline_agent_disabled = Le mode agent nécessite une connexion Internet
+offline_chat_local_search = Hors ligne : la recherche se fera dans vos notes
+offline_entered = Hors ligne : l'IA dans le cloud est en pause
+offline_left = Connexion rétablie
+offline_left_indexing = { $count ->
+    [one] Connexion rétablie ; indexation de { $count } note en attente
+   *[other] Connexion rétablie ; indexation de { $count } notes en attente
+}
+offline_chat_title = Hors ligne · notes correspondantes
+offline_chat_no_results = Aucune note ne correspond au message
+chat_export_tooltip = Exporter les conversations (JSONL)
+chat_export_done = Conversations exportées
+chat_export_error = Impossible d'exporter les conversations
+preview_edit_failed = Impossible d'appliquer la modification ; utilisez le mode Insertion
+shortcut_split_preview = Éditeur et aperçu côte à côte
+preview_options = Options de l'aperçu
+preview_density_comfortable = Espacement confortable
+preview_density_compact = Compact
+preview_heading_anchors = Ancres # sur les titres
+preview_print_friendly = Adapté à l'impression
+preview_print_friendly_tooltip = Sans vidéos ni contrôles, texte sombre sur fond blanc
+copy_heading_link = Copier le lien vers le titre
+heading_link_copied = Lien vers le titre copié
+duplicate_note_title = Note en double ?
+duplicate_note_explanation = Une note très similaire existe déjà. Vous pouvez l'ouvrir, y ajouter le nouveau contenu ou créer la note quand même.
+duplicate_note_new = Nouvelle note
+duplicate_note_imported = Contenu importé
+duplicate_note_title_match = Titre similaire
+duplicate_note_content_match = Contenu similaire
+duplicate_note_open = Ouvrir l'existante
+duplicate_note_merge = Ajouter à l'existante
+duplicate_note_create = Créer quand même
+vault_health = État du coffre
+vault_health_largest = Notes les plus longues
+vault_health_stale = Non modifiées depuis plus d'un an
+vault_health_untagged = Sans tags
+vault_health_orphans = Orphelines (sans liens)
+vault_health_search_index = Dans l'index de recherche
+vault_health_embeddings = Avec embeddings
+vault_health_embeddings_disabled = La recherche sémantique est désactivée
+vault_health_empty = Rien à vérifier ici
+backups = Sauvegardes
+backups_desc = Copies compressées des notes et de la base de données. Le dossier peut être sur un autre disque ou un distant monté.
+backups_interval = Heures entre les sauvegardes automatiques
+backups_keep = Conserver
+backups_keep_daily = quotidiennes
+backups_keep_weekly = hebdomadaires
+backups_keep_monthly = mensuelles
+backups_change_folder = Changer de dossier
+backups_select_folder = Dossier des sauvegardes
+backups_now = Sauvegarder maintenant
+backup_restore = Restaurer une sauvegarde…
+backup_restore_button = Restaurer
+backup_restore_confirm = Restaurer la sauvegarde du
+backup_restore_confirm_detail = Le dossier de notes actuel est conservé, renommé, à côté de celui restauré.
+backup_none = Aucune sauvegarde pour l'instant
+backup_created = Sauvegarde créée
+backup_failed = La sauvegarde a échoué
+backup_restored = Sauvegarde restaurée
+backup_restored_restart = Sauvegarde restaurée. Redémarrez NotNative pour terminer la restauration de la base de données
+backup_restore_failed = Impossible de restaurer la sauvegarde
+read_later = Lire plus tard
+remove_from_reading_list = Retirer de la liste de lecture
+reading_list = Liste de lecture
+reading_list_added = Ajoutée à la liste de lecture
+reading_list_removed = Retirée de la liste de lecture
+reading_list_error = Impossible de mettre à jour la liste de lecture
+reading_list_sort = Trier par
+reading_list_sort_added = Date d'ajout
+reading_list_sort_length = Longueur
+reading_list_empty = Rien à lire
+flashcards_review = Réviser les fiches
+flashcards_show_answer = Afficher la réponse
+flashcards_again = À revoir
+flashcards_hard = Difficile
+flashcards_good = Bien
+flashcards_easy = Facile
+flashcards_days = j
+flashcards_remaining = { $count ->
+    [one] { $count } restante
+   *[other] { $count } restantes
+}
+flashcards_done = Plus aucune fiche à réviser aujourd'hui
+flashcards_none = Aucune fiche pour l'instant. Écrivez Q:: question et A:: réponse dans une note, ou terminez un titre par ?
+meeting_note = Note de réunion…
+meeting_note_title = Nouvelle note de réunion
+meeting_title_hint = Titre de la réunion
+meeting_attendees_hint = Participants (Ana, Luis…)
+meeting_attendees_help = Séparés par des virgules ; chaque participant est mentionné avec @ dans la note
+meeting_agenda = Ordre du jour
+meeting_notes = Notes
+meeting_action_items = Actions
+meeting_extract_actions = Extraire les actions…
+meeting_actions_empty = Aucune tâche en cours dans la note. L’IA peut les extraire de la discussion.
+meeting_actions_no_due = Sans date (rappel demain)
+meeting_actions_has_reminder = A déjà un rappel
+meeting_actions_suggest = Suggérer avec l’IA
+meeting_actions_create_reminders = Créer des rappels
+meeting_actions_thinking = Recherche des actions…
+meeting_actions_ai_error = L’IA n’a pas pu extraire les actions
+weekly_review = Bilan hebdomadaire
+weekly_review_this_week = Cette semaine
+weekly_review_intro = Voici ce qui s’est passé dans tes notes depuis lundi. Parcours-le, puis réponds à chaque section.
+weekly_review_stats = Statistiques
+weekly_review_created = Notes créées
+weekly_review_modified = Notes modifiées
+weekly_review_words = Mots dans ces notes
+weekly_review_journal_days = Jours de journal
+weekly_review_open_tasks = Tâches ouvertes
+weekly_review_completed_reminders = Rappels terminés
+weekly_review_none = Rien cette semaine
+weekly_review_summary = Résumé
+weekly_review_summary_prompt = Comment s’est passée la semaine, en quelques lignes ?
+weekly_review_wins = Réussites
+weekly_review_wins_prompt = Qu’est-ce qui a bien marché ? De quoi es-tu fier ?
+weekly_review_challenges = Difficultés
+weekly_review_challenges_prompt = Qu’est-ce qui a bloqué ou pris plus de temps que prévu ?
+weekly_review_next_week = Semaine prochaine
+weekly_review_next_week_prompt = Quelles sont les trois priorités de la semaine prochaine ?
+weekly_review_ai_draft = Brouillon avec l’IA
+weekly_review_ai_error = L’IA n’a pas pu écrire le résumé
+weekly_review_back = Retour
+weekly_review_next = Suivant
+weekly_review_create = Créer la note
+weekly_review_exists = Le bilan de cette semaine existe déjà
+compare_notes = Comparer des notes
+compare_with = Comparer avec…
+compare_with_current = Comparer avec la version actuelle
+compare_swap = Inverser
+compare_side_by_side = Côte à côte
+compare_identical = Les deux notes sont identiques
+compare_changes = blocs modifiés
+compare_copy_left = Copier la gauche
+compare_copy_right = Copier la droite
+compare_copy_diff = Copier le diff
+folder_ai_excluded = Masquer à l’IA
+folder_ai_excluded_hint = Les notes de ce dossier et de ses sous-dossiers ne sont pas indexées, l’agent ne peut pas les lire et elles ne sont pas suggérées dans le chat. Une note seule s’exclut avec ai: false dans son frontmatter
+chat_note_ai_excluded = Cette note est exclue de l’IA
+mcp_note_ai_excluded = La note « { $name } » est exclue de l’IA et ne peut pas être lue
+profiles = Profils
+profiles_hint = Chaque profil conserve le dossier de notes, le fournisseur d'IA, le thème et les sauvegardes. En changeant, les réglages actuels sont enregistrés dans le profil actif.
+profiles_empty = Aucun profil pour l'instant
+profile_active = Actif
+profile_switch = Changer
+profile_default_folder = Dossier par défaut
+profile_name_hint = Nom du profil (Travail, Personnel...)
+profile_save_current = Enregistrer les réglages actuels
+profile_saved = Profil « { $name } » enregistré
+profile_switched = Profil actif : { $name }
+mounted_folders = Dossiers montés
+mounted_folders_desc = Dossiers hors du coffre (docs d'un dépôt, dossier synchronisé) affichés comme dossiers de premier niveau : indexés, consultables et modifiables, mais laissés à leur place.
+mount_folder = Monter un dossier…
+unmount_folder = Démonter
+mount_unavailable = indisponible
+folder_mounted = Dossier monté
+folder_unmounted = Dossier démonté
+refactor_invalid = Noms invalides pour le renommage
+refactor_no_matches = Aucune note à modifier
+refactor_notes = Notes
+refactor_lines = Lignes
+refactor_apply = Renommer partout
+refactor_applied = Renommé dans toutes les notes
+refactor_failed = Échec du renommage, rien n'a été modifié
+refactor_undone = Renommage annulé
+refactor_undo_failed = Impossible d'annuler le renommage
+copied_as_plain_text = Copié en texte brut
+shortcut_copy_as_plain_text = Copier en texte brut (sans markdown)
+plain_text_link_urls = Conserver l'URL des liens lors de la copie en texte brut
+word_goal_reached = Objectif de mots atteint
+word_goal = Objectif de mots
+word_goals_today = Objectifs atteints aujourd'hui
+word_goals_week = Objectifs atteints (7 jours)
+scratch = Brouillon
+shortcut_scratch = Ouvrir ou fermer le brouillon (jamais enregistré)
+scratch_backup = Garder une copie du brouillon pour le récupérer après un plantage
+tray_compact_mode = Mode compact
+shortcut_compact_mode = Petite fenêtre avec l'éditeur seul (mode compact)
+file_imported = Fichier importé dans le coffre
+open_file_failed = Impossible d'ouvrir le fichier
+screenshot_title = Annoter la capture
+screenshot_insert = Insérer
+screenshot_tool_arrow = Flèche
+screenshot_tool_rect = Cadre
+screenshot_tool_text = Texte (cliquer où le placer)
+screenshot_text_placeholder = Texte puis Entrée
+screenshot_annotate = Annoter les captures d'écran (flèches, cadres et texte) avant de les insérer
+shortcut_screenshot = Capturer une zone de l'écran dans la note
+tray_screenshot = Capture d'écran vers Quick Note
+ocr_offer = Proposer d'extraire le texte des images à l'insertion (OCR) pour les retrouver en recherchant
+ocr_engine_tesseract = tesseract (local)
+ocr_engine_ai = Modèle d'IA avec vision
+ocr_offer_toast = Extraire le texte de l'image ?
+ocr_extract = Extraire le texte
+ocr_running = Extraction du texte de l'image…
+ocr_done = Texte de l'image ajouté sous celle-ci
+ocr_block_title = Texte de l'image
+ocr_no_text = Aucun texte trouvé dans l'image
+ocr_no_image = Aucune image sur la ligne du curseur
+ocr_image_missing = L'image n'est plus dans la note
+ocr_failed = Impossible d'extraire le texte de l'image
+ocr_tesseract_missing = tesseract n'est pas installé (paquet tesseract)
+ocr_model_no_vision = Le modèle d'IA configuré n'accepte pas les images
+shortcut_ocr = Extraire le texte de l'image de la ligne (OCR)
+rename_empty = Le nom ne peut pas être vide
+rename_illegal_char = Le nom ne peut pas contenir
+rename_hidden = Le nom ne peut pas commencer par un point
+rename_taken = Existe déjà ; Entrée utilisera
+renamed_to = Renommé en
+rename_failed = Impossible de renommer
+open_in_file_manager = Ouvrir dans le gestionnaire de fichiers
+confirm_delete = Voulez-vous vraiment supprimer
+
+## Préférences
+theme = Thème
+theme_sync = L'application se synchronise automatiquement avec le thème Omarchy
+markdown_rendering = Rendu Markdown
+markdown_enabled = Activé par défaut en mode Normal
+language = Langue
+language_description = Choisissez la langue de l'interface
+restart_required = Redémarrage de l'application requis
+start_in_background = Démarrer en arrière-plan
+start_in_background_desc = Démarrer l'application réduite dans la zone de notification
+format_toolbar = Barre de mise en forme
+format_toolbar_desc = Afficher la barre de mise en forme en mode édition
+preview_adjust_images = Images en mode sombre
+preview_adjust_images_desc = Inverse les schémas et captures à fond blanc dans l'aperçu sombre. Utilisez ![](img.png){"{"}.no-invert{"}"} pour exclure une image
+recent_notes = Récentes
+navigation_history_end = Plus de notes dans l'historique
+annotations = Annotations
+annotations_search = Rechercher dans les surlignages
+annotations_empty = Aucun surlignage. Sélectionnez du texte et appuyez sur = en mode Normal, ou écrivez ==texte==
+paste_html_as_markdown = Coller le HTML en markdown
+paste_html_as_markdown_desc = Convertit le contenu copié depuis un navigateur ou un document en markdown propre et télécharge ses images dans les pièces jointes. Ctrl+Maj+V colle toujours du texte brut
+url_paste_behavior = En collant un lien
+url_paste_ask = Demander s'il faut utiliser le titre
+url_paste_title = Toujours utiliser le titre de la page
+url_paste_bare = Garder le lien brut
+large_tap_targets = Zones tactiles agrandies
+large_tap_targets_desc = Agrandit les boutons et les lignes pour une utilisation au doigt. Balayez depuis le bord gauche pour ouvrir la liste des notes, pincez l'aperçu pour changer la taille du texte et appuyez longuement sur une note pour afficher son menu
+embed_budget = Contenu intégré
+embed_budget_desc = Nombre maximal de vidéos chargées en même temps dans l'aperçu. Les vidéos hors écran sont déchargées ; avec 0, elles ne se chargent qu'au clic
+plugins = Extensions
+plugin_error = Erreur d'extension
+workspace = Espace de travail
+workspace_description = Emplacement où les notes et ressources sont enregistrées
+open_workspace_folder = Ouvrir le dossier de travail
+change_workspace = Changer l'emplacement
+workspace_location = Emplacement
+select_workspace_folder = Choisir le dossier de travail
+select = Choisir
+audio_output = Sortie audio
+audio_output_description = Choisissez le périphérique de sortie audio
+audio_output_default = Par défaut du système
+
+## Fenêtre des préférences
+prefs_page_general = Général
+prefs_page_editor = Éditeur
+prefs_page_ai = IA
+prefs_page_sync = Synchronisation
+prefs_page_reminders = Rappels
+prefs_page_music = Musique
+prefs_page_advanced = Avancé
+prefs_search_placeholder = Rechercher un réglage...
+prefs_no_results = Aucun réglage ne correspond à la recherche
+prefs_revert = Annuler les modifications
+prefs_revert_tooltip = Restaure les réglages présents à l'ouverture de cette fenêtre
+prefs_mcp_desc = Permet à d'autres applications et assistants d'accéder à vos notes
+prefs_mcp_details = Voir les détails
+prefs_reminders_desc = Les rappels s'affichent dans le panneau (Alt+R) et comme notifications système
+prefs_show_reminders = Voir les rappels
+prefs_config_file = Fichier de configuration
+prefs_open_config_folder = Ouvrir le dossier
+
+## Barre d'état
+lines = lignes
+words = mots
+characters = caractères
+notes_count = { $count ->
+    [one] { $count } note
+   *[other] { $count } notes
+}
+lines_count = { $count ->
+    [one] { $count } ligne
+   *[other] { $count } lignes
+}
+words_count = { $count ->
+    [one] { $count } mot
+   *[other] { $count } mots
+}
+characters_count = { $count ->
+    [one] { $count } caractère
+   *[other] { $count } caractères
+}
+note_statistics = Statistiques de la note
+reading_time = Temps de lecture
+characters_no_spaces = Caractères sans espaces
+paragraphs = Paragraphes
+headings = Titres
+links = Liens
+code_blocks = Blocs de code
+images = Images
+saved = Enregistré
+unsaved_changes = Modifications non enregistrées
+note_created = Note créée
+note_deleted = Note supprimée
+note_renamed = Note renommée
+copied_as_rich_text = Copié en texte enrichi
+error = Erreur
+success = Succès
+no_results = Aucun résultat pour
+searching = Recherche en cours
+
+## TODOs
+todos = TODOs
+todos_note = TODOs de la note
+no_todos = Aucun TODO dans cette note
+completed = terminé
+todo_progress = { $completed }/{ $count } { $count ->
+    [one] terminée
+   *[other] terminées
+}
+todo_progress_subtasks = { $completed }/{ $count } { $count ->
+    [one] sous-tâche
+   *[other] sous-tâches
+}
+no_section = Sans section
+
+## Recherche
+searching_ellipsis = 🔄 Recherche...
+no_results_found = ❌ Aucun résultat
+found_relevant_notes = { $count ->
+    [one] { $count } note pertinente trouvée :
+   *[other] { $count } notes pertinentes trouvées :
+}
+
+## Rappels
+reminders_title = Rappels
+reminders_new = Nouveau rappel
+reminders_pending = En attente
+reminders_completed = Terminés
+reminders_all = Tous
+reminder_complete = Terminer
+reminder_snooze = Reporter
+reminder_edit = Modifier
+reminder_delete = Supprimer
+reminders_empty = Aucun rappel
+reminders_history = Historique
+reminders_history_search = Rechercher dans l'historique…
+reminders_history_export = Exporter en CSV
+reminders_history_empty = Aucun rappel ne s'est encore déclenché
+reminders_history_exported = Historique exporté
+reminder_outcome_open = ouvert
+reminder_outcome_completed = terminé
+reminder_outcome_dismissed = écarté
+reminder_outcome_missed = manqué
+reminder_history_snoozed = reporté
+reminder_open_note = Ouvrir la note
+reminder_draft_title = Rappel proposé
+reminder_draft_confirm = Créer
+reminder_draft_created = ✓ Rappel créé
+reminder_draft_cancelled = Rappel abandonné
+reminder_draft_invalid_date = Date non reconnue (p. ex. 2026-10-20 15:00 ou tomorrow 10:00)
+reminder_source_missing = La note de ce rappel n'existe plus
+reminder_priority_low = Basse
+reminder_priority_medium = Moyenne
+reminder_priority_high = Haute
+reminder_priority_urgent = Urgente
+reminder_snooze_5min = 5 minutes
+reminder_snooze_15min = 15 minutes
+reminder_snooze_1hour = 1 heure
+reminder_snooze_tomorrow = Demain
+reminder_notification_title = 🔔 Rappel
+reminder_create_title = Créer un rappel
+reminder_title_label = Titre
+reminder_description_label = Description
+reminder_date_label = Date et heure
+reminder_priority_label = Priorité
+reminder_repeat_label = Répéter
+reminder_repeat_none = Ne pas répéter
+reminder_repeat_daily = Tous les jours
+reminder_repeat_weekly = Toutes les semaines
+reminder_repeat_monthly = Tous les mois
+no_reminders = Aucun rappel
+reminder_overdue = En retard
+reminder_today = Aujourd'hui
+reminder_tomorrow = Demain
+reminder_created = Rappel créé
+reminder_updated = Rappel mis à jour
+reminder_deleted = Rappel supprimé
+reminder_completed_msg = Rappel terminé
+reminder_snoozed = Rappel reporté
+reminder_tooltip = Rappels (Alt+R)
+
+## Notes rapides
+quick_notes_title = Note rapide
+quick_note_back_to_list = Retour à la liste
+quick_note_new = Nouvelle note rapide
+quick_note_keep_visible = Garder visible
+quick_note_close = Fermer (Échap)
+quick_note_no_notes = Aucune note rapide
+quick_note_press_to_create = Appuyez sur + pour en créer une
+quick_note_saved = 💾 Enregistré
+quick_note_unsaved = ● Non enregistré
+quick_note_shortcut_hint = Ctrl+S : enregistrer | Échap : fermer
+quick_note_created = Note rapide créée
+
+## Chat IA
+ai_chat = Chat IA
+chat_input_placeholder = Écrivez votre message ici...
+chat_send = Envoyer
+chat_context = Contexte
+chat_attach_note = Joindre la note actuelle
+chat_attach_button = Joindre
+chat_clear_context = Vider le contexte
+chat_clear_history = Effacer l'historique
+chat_new_session = Nouvelle session
+chat_mode_agent = Mode : Agent
+chat_mode_normal = Mode : Normal
+ai_thinking = Réflexion...
+you_label = Vous
+
+## Zone de notification
+tray_show_window = Afficher la fenêtre
+tray_hide_window = Masquer la fenêtre
+tray_quit = Quitter
+
+## Infobulles
+tooltip_show_hide_notes = Afficher/masquer la liste des notes
+tooltip_new_note = Nouvelle note
+tooltip_close_esc = Fermer (Échap)
+tooltip_note_tags = Tags de la note
+tooltip_note_todos = TODOs de la note
+tooltip_music_player = Lecteur de musique
+tooltip_reminders = Rappels (Alt+R)
+tooltip_settings = Réglages
+
+## Dates
+hour_format = Format de l'heure
+hour_format_auto = Automatique (selon la région)
+
+## Journaux
+logs = Journaux
+logs_level_desc = Niveau de détail du journal. Utile pour signaler un problème
+logs_view = Voir les journaux
+logs_empty = Aucun journal pour l'instant
+logs_follow = Suivre
+logs_copy = Copier
+
+## Identifiants
+api_key_storage_keyring = 🔐 Les clés sont stockées dans le trousseau du système
+api_key_storage_file = 🔐 Trousseau indisponible : les clés sont stockées chiffrées dans le dossier de données
+
+## Audio
+audio_output_detecting = Détection des périphériques...
+audio_output_changed = Sortie audio modifiée
+audio_output_error = Erreur lors du changement de sortie audio
+
+## Raccourcis clavier
+shortcuts_general = Général
+shortcuts_modes = Modes d'édition
+shortcuts_navigation = Navigation
+shortcuts_editing = Édition
+shortcut_new_note = Créer une nouvelle note
+shortcut_save = Enregistrer la note
+shortcut_search = Rechercher des notes
+shortcut_toggle_sidebar = Afficher/masquer le panneau latéral
+shortcut_escape = Revenir à l'éditeur
+shortcut_insert_mode = Mode insertion
+shortcut_normal_mode = Mode normal
+shortcut_command_mode = Mode commande
+shortcut_visual_mode = Mode visuel
+shortcut_movement = Gauche/Bas/Haut/Droite
+shortcut_next_word = Mot suivant
+shortcut_prev_word = Mot précédent
+shortcut_line_start = Début de ligne
+shortcut_line_end = Fin de ligne
+shortcut_doc_start = Début du document
+shortcut_doc_end = Fin du document
+shortcut_delete_char = Supprimer le caractère
+shortcut_delete_line = Supprimer la ligne
+shortcut_undo = Annuler
+shortcut_cycle_task_state = Changer l'état de la tâche : [ ] → [/] → [x] → [-]
+shortcut_redo = Rétablir
+shortcut_open_note_search = Ouvrir la recherche de notes
+shortcut_toggle_ai_chat = Afficher/masquer le chat IA
+shortcut_delete_char_under = Supprimer le caractère sous le curseur
+shortcut_delete_line_complete = Supprimer toute la ligne
+shortcut_delete_prev_char = Supprimer le caractère précédent
+shortcut_delete_next_char = Supprimer le caractère suivant
+shortcut_new_line = Nouvelle ligne
+shortcut_search_sidebar = Recherche et panneau latéral
+shortcut_activate_search = Activer la recherche
+shortcut_close_search = Fermer la recherche / Revenir à l'éditeur
+shortcut_open_music = Ouvrir le lecteur de musique
+shortcut_open_reminders = Ouvrir les rappels
+shortcuts_global = 🌍 Global
+shortcuts_quick_notes = 📝 Notes rapides
+shortcuts_normal_navigation = 📝 Mode normal - Navigation
+shortcuts_normal_editing = 📝 Mode normal - Édition et modes
+shortcuts_insert_mode = ✍️ Mode insertion
+shortcuts_ai_chat = 🤖 Mode chat IA
+shortcuts_sidebar = 📂 Panneau latéral et listes
+shortcuts_floating_search = 🔍 Recherche flottante
+shortcut_global_search = Ouvrir la recherche globale flottante
+shortcut_note_search = Rechercher dans la note actuelle
+shortcut_cycle_focus = Passer le focus entre le panneau latéral, l'éditeur et la recherche
+shortcut_enter_ai_chat = Entrer en mode chat IA
+shortcut_back_or_close = Revenir à la liste / Fermer la fenêtre
+shortcut_left = Déplacer le curseur à gauche
+shortcut_down = Déplacer le curseur vers le bas
+shortcut_up = Déplacer le curseur vers le haut
+shortcut_right = Déplacer le curseur à droite
+shortcut_ai_chat_mode = Entrer en mode chat IA
+shortcut_insert_table = Insérer un tableau Markdown
+shortcut_insert_image = Insérer une image
+shortcut_insert_sketch = Dessiner un croquis
+shortcut_insert_footnote = Insérer une note de bas de page
+shortcut_saveas = Enregistrer une copie de la note et l'ouvrir
+shortcut_split_by_heading = Transformer chaque section en une note
+shortcut_navigate_history = Reculer / avancer dans les notes ouvertes
+shortcut_toggle_highlight = Surligner la sélection (répéter pour changer de couleur)
+shortcut_paste_plain_text = Coller en texte brut
+shortcut_copy_as_html = Copier en texte enrichi (HTML)
+shortcut_tab_autocomplete = Tabulation / Compléter un tag ou @
+shortcut_exit_chat = Quitter le chat (retour au mode normal)
+shortcut_exit_chat_insert = Quitter le chat et passer en mode insertion
+shortcut_send_message = Envoyer le message
+shortcut_navigate_suggestions = Parcourir les suggestions
+shortcut_accept_suggestion = Accepter la suggestion
+shortcut_next_note = Note suivante
+shortcut_prev_note = Note précédente
+shortcut_open_note = Ouvrir la note ou le dossier sélectionné
+shortcut_focus_editor = Rendre le focus à l'éditeur
+shortcut_toggle_semantic = Activer/désactiver la recherche sémantique (IA)
+shortcut_navigate_results = Parcourir les résultats
+shortcut_open_selected = Ouvrir la note sélectionnée
+
+## À propos
+app_description = Éditeur de notes markdown à la Vim
+website = Site web
+authors = Auteurs
+version = Version
+license = Licence
+
+## Visionneuse d'images
+image_viewer = Visionneuse d'images
+open_file_location = Ouvrir l'emplacement
+
+## YouTube
+transcribe_youtube = Transcrire la vidéo YouTube ?
+youtube_detected = Un lien YouTube a été détecté
+only_link = Lien seul
+link_title_found = Titre du lien
+use_page_title = Utiliser le titre
+keep_bare_link = Garder le lien
+transcribe_and_insert = Transcrire et insérer
+downloading_transcript = Téléchargement de la transcription...
+loading_transcript = Chargement de la transcription...
+transcript_error = Erreur lors de la récupération de la transcription
+transcript_unavailable = Transcription indisponible pour cette vidéo
+transcript_section = 📝 Transcription
+youtube_unavailable = La transcription YouTube n'est pas disponible pour le moment...
+
+## Lecteur de musique
+music_player = Lecteur de musique
+music_player_title = Lecteur de musique
+music_search_placeholder = Rechercher de la musique sur YouTube...
+no_music_playing = Aucune musique en cours
+music_play_pause = Lecture/Pause (Espace)
+music_stop = Arrêter
+music_seek_back = Reculer de 5 s
+music_seek_forward = Avancer de 5 s
+music_volume_down = Baisser le volume
+music_volume_up = Monter le volume
+music_previous_song = Morceau précédent
+music_next_song = Morceau suivant
+music_repeat_off = Répétition : NON
+music_repeat_one = Répétition : UN
+music_repeat_all = Répétition : TOUT
+music_shuffle_off = Aléatoire : NON
+music_shuffle_on = Aléatoire : OUI
+music_manage_playlists = Gérer les listes de lecture
+music_playback_queue = File de lecture
+music_loading = Chargement...
+music_add_to_queue = Ajouter à la file
+music_remove_from_queue = Retirer de la file
+music_new_playlist = Nouvelle liste de lecture
+music_load_playlist = Charger une liste de lecture
+music_save_playlist = Enregistrer la liste de lecture
+music_playlist_name = Nom de la liste de lecture
+playlist_new = 🎵 Nouvelle
+playlist_save = 💾 Enregistrer
+playlist_clear = 🗑️ Vider
+playlist_queue = File de lecture
+playlist_queue_empty = File vide
+playlist_saved_playlists = Listes de lecture enregistrées
+playlist_save_title = Enregistrer la liste de lecture
+playlist_name_prompt = Nom de la liste de lecture :
+playlist_name_example = ex. : Musique relaxante
+playlist_no_loaded = Aucune liste de lecture chargée
+playlist_no_saved = Aucune liste de lecture enregistrée
+playlist_add_to = Ajouter à la liste de lecture
+playlist_delete = Supprimer la liste de lecture
+play = Lire
+remove = Retirer
+
+## Chat IA et mode agent
+chat_model_default = Modèle : OpenAI GPT-4
+chat_subtitle = Combinez vos notes avec l'assistant en temps réel
+chat_attach_note_dialog_title = Joindre une note au contexte
+chat_clear_history_confirm_title = Supprimer tout l'historique du chat ?
+chat_clear_history_confirm_message = Cette action supprime définitivement tout l'historique des conversations enregistré. Elle est irréversible.
+chat_history_cleared = Historique supprimé
+chat_history_cleared_message = Tout l'historique des conversations a été supprimé
+chat_mode_chat = Mode : Chat
+chat_toggle_mode_tooltip = Basculer : mode agent (avec outils) / chat normal (sans outils)
+chat_agent_thinking = 💭 Réflexion
+chat_agent_action = 🔧 Action
+chat_agent_observation = 👁️ Observation
+chat_agent_answer = ✅ Réponse
+ai_chat_placeholder = Écrivez un message pour l'assistant IA...
+ai_send_message = Envoyer le message
+ai_model = Modèle
+ai_temperature = Température
+ai_api_key = Clé API
+ai_openai = OpenAI
+ai_openrouter = OpenRouter
+ai_api_key_placeholder = Saisissez votre clé API...
+ai_save = Enregistrer
+ai_cancel = Annuler
+ai_no_key_configured = Aucune clé API configurée
+ai_configure_key = Configurez la clé API dans les Préférences
+ai_free_models = ═══ MODÈLES GRATUITS ═══
+ai_paid_models = ═══ MODÈLES PAYANTS ═══
+agent_mode_activated =
+    Mode agent activé
+    L'assistant peut chercher dans les notes et exécuter des actions
+chat_mode_activated =
+    Chat normal activé
+    Conversation directe sans accès aux outils
+analyzing_task = Analyse de la tâche...
+no_notes_in_context = Aucune note dans le contexte
+remove_from_context = Retirer du contexte
+
+## Réglages IA
+temperature_label = Température :
+max_tokens_label = Tokens max :
+unlimited = Illimité
+save_history_label = Enregistrer l'historique :
+model_label = Modèle :
+refresh_models_tooltip = Actualiser la liste des modèles depuis OpenRouter
+search_model_placeholder = Rechercher un modèle...
+no_models_found = Aucun modèle trouvé
+semantic_search_title = 🧠 Recherche sémantique (embeddings)
+semantic_search_description = Configurez les embeddings pour chercher par sens avec OpenRouter
+enable_embeddings = Activer les embeddings :
+index_all_notes = 📄 Indexer toutes les notes
+indexing = ⏳ Indexation...
+indexing_completed = Indexation terminée
+estimated_cost = Coût estimé : ~0,01 $ pour 10 000 notes
+get_api_key_openrouter = Obtenir une clé API sur OpenRouter
+
+## Messages MCP
+mcp_note_created = ✓ Note '{}' créée avec succès
+mcp_note_read = ✓ Note '{}' lue avec succès
+mcp_note_updated = ✓ Note '{}' mise à jour avec succès
+mcp_note_deleted = ✓ Note '{}' supprimée avec succès
+mcp_content_appended = ✓ Contenu ajouté à '{}' avec succès
+mcp_notes_found = { $count ->
+    [one] ✓ { $count } note trouvée
+   *[other] ✓ { $count } notes trouvées
+}
+mcp_search_results = { $count ->
+    [one] ✓ { $count } résultat pour '{ $query }'
+   *[other] ✓ { $count } résultats pour '{ $query }'
+}
+mcp_notes_with_tag = { $count ->
+    [one] ✓ { $count } note avec le tag #{ $tag }
+   *[other] ✓ { $count } notes avec le tag #{ $tag }
+}
+mcp_tags_found = { $count ->
+    [one] ✓ { $count } tag trouvé
+   *[other] ✓ { $count } tags trouvés
+}
+mcp_tags_added = ✓ Tags ajoutés à '{}'
+mcp_note_renamed = ✓ Note renommée de '{}' en '{}'
+mcp_note_duplicated = ✓ Note '{}' dupliquée sous le nom '{}'
+mcp_folder_created = ✓ Dossier '{}' créé avec succès
+mcp_project_created = ✓ Projet '{ $folder }' créé à partir du modèle
+mcp_note_not_found = Note '{}' introuvable
+mcp_folders_found = { $count ->
+    [one] ✓ { $count } dossier trouvé
+   *[other] ✓ { $count } dossiers trouvés
+}
+mcp_create_reminder_desc = Créer un rappel avec date, heure et priorité
+mcp_list_reminders_desc = Lister tous les rappels ou les filtrer par état
+mcp_update_reminder_desc = Modifier un rappel existant
+mcp_delete_reminder_desc = Supprimer un rappel
+mcp_snooze_reminder_desc = Reporter un rappel d'une durée donnée
+mcp_complete_reminder_desc = Marquer un rappel comme terminé
+mcp_reminder_title_desc = Titre du rappel
+mcp_reminder_date_desc = Date et heure du rappel (ex. : '2025-11-20 15:00', 'today 18:00', 'tomorrow')
+mcp_server_title = Serveur MCP - Model Context Protocol
+mcp_server_active = Serveur MCP actif
+mcp_server_subtitle = Expose les outils de NotNative via HTTP
+mcp_status = État
+mcp_endpoints_available = Points d'accès disponibles
+status_active = 🟢 Actif
+copy_url = 📋 Copier l'URL
+view_docs = 📖 Voir la documentation
+copied = ✓ Copié !
+
+## Rappels et notes rapides
+reminder_linked_note_label = Note liée
+reminders_count = { $count ->
+    [one] { $count } en attente
+   *[other] { $count } en attente
+}
+quick_note_autosaved = 💾 Enregistré automatiquement
+
+## Recherche et interface
+semantic_results = Résultats par similarité sémantique
+ai_analyzing = 🔄 L'assistant IA analyse les résultats...
+assistant_response = 🧠 Réponse de l'assistant
+tooltip_change_search_mode = Ctrl pour changer de mode
+images_filter = Images
+unknown_error = Erreur inconnue
+operation_success = ✓ Opération réussie
+operation_failed = ✗ Échec de l'opération
+adding_content = Ajout du contenu...
+adding_tag = Ajout du tag...
+adding_tags = Ajout des tags...
+generating_index = Génération de l'index...
+extracting_code = Extraction du code...
+
+## Bases de données
+base_add_filter = Ajouter un filtre
+base_sort = Trier
+base_columns = Colonnes
+base_columns_config = Configurer les colonnes
+base_data_source = Mode de source de données
+base_formula_rows = Lignes de formules
+base_formula_rows_title = Lignes de formules
+base_add_formula_row = Ajouter une ligne de totaux
+base_formula_row_label = Libellé
+base_formula_help = Utilisez des formules type Excel : =SUM(B:B), =AVG(C1:C10)
+base_export_xlsx = Exporter vers Excel
+base_export_xlsx_success = Export réussi
+base_export_xlsx_error = Erreur d'exportation
+base_show_graph = Afficher le graphe des relations
+base_no_filters = Aucun filtre
+base_current_columns = Colonnes visibles
+base_available_properties = Propriétés disponibles
+base_toggle_visibility = Afficher/masquer
+base_remove_column = Retirer la colonne
+base_add_column = Propriétés disponibles
+base_properties_hint = Cliquez sur + pour ajouter une colonne
+base_add_as_column = Ajouter comme colonne
+base_move_up = Monter
+base_move_down = Descendre
+base_data_source_title = Source de données
+base_notes_mode = Notes
+base_grouped_mode = Enregistrements groupés
+base_grouped_hint =
+    Les enregistrements groupés affichent les groupes
+    de propriétés inline comme [game::X, bought::Y] en lignes
+base_search_placeholder = Rechercher dans le tableau...
+base_no_notes = Aucune note trouvée
+base_items = éléments
+base_items_of = sur
+base_title = Titre
+base_created = Créé
+base_modified = Modifié
+base_tags = Tags
+base_search_properties = Rechercher des propriétés...
+base_no_available_props = Plus aucune propriété disponible
+base_sort_by = Trier par
+base_no_sorting = Sans tri
+base_sort_ascending = Tri croissant
+base_sort_descending = Tri décroissant
+base_add_filter_title = Ajouter un filtre
+base_property = Propriété
+base_operator = Opérateur
+base_value = Valeur
+base_filter_value_placeholder = Valeur du filtre...
+base_cancel = Annuler
+base_apply_filter = Ajouter le filtre
+filter_op_equals = égal à
+filter_op_not_equals = différent de
+filter_op_contains = contient
+filter_op_not_contains = ne contient pas
+filter_op_greater_than = supérieur à
+filter_op_greater_or_equal = supérieur ou égal à
+filter_op_less_than = inférieur à
+filter_op_less_or_equal = inférieur ou égal à
+filter_op_starts_with = commence par
+filter_op_ends_with = se termine par
+filter_op_is_empty = est vide
+filter_op_is_not_empty = n'est pas vide
@@ -0,0 +1,976 @@
+# NotNative - Italiano
+# Le chiavi assenti in questo file usano il testo inglese integrato.
+# Generare l'elenco completo delle chiavi: notnative --export-locale-template it.ftl
+
+-language-name = Italiano
+-app = NotNative
+
+## Generale
+app_title = { -app }
+untitled = Senza titolo
+notes = Note
+new_note = Nuova nota
+search = Cerca
+search_notes = Cerca (Ctrl+F)
+search_placeholder = Cerca note...
+show_hide_notes = Mostra/nascondi elenco note
+preferences = Preferenze
+keyboard_shortcuts = Scorciatoie da tastiera
+about = Informazioni
+settings = Impostazioni
+tags = Tag
+tags_note = Tag della nota
+no_tags = Nessun tag
+search_tag = Cerca note con questo tag
+remove_tag = Rimuovi tag
+close = Chiudi
+
+## Finestre di dialogo
+create_note_title = Nuova nota
+create_folder_title = Nuova cartella
+note_name_hint = esempio: progetti/nuova-idea
+create_folder_hint = Usa '/' per creare nelle cartelle
+create = Crea
+cancel = Annulla
+rename = Rinomina
+delete = Elimina
+change_icon = Cambia icona
+view_history = Visualizza cronologia
+duplicate_note = Duplica nota
+note_duplicated = Copia creata
+merge_into = Unisci in…
+merge = Unisci
+merge_search = Cerca la nota di destinazione
+merge_delete_source = Sposta la nota originale nel cestino
+note_merged = Nota unita in
+note_split = Note create dalle sezioni
+split_no_sections = La nota non ha titoli di quel livello
+pin_note = Fissa in alto
+unpin_note = Non fissare più
+notes_selected = { $count ->
+    [one] { $count } nota selezionata
+   *[other] { $count } note selezionate
+}
+move_to_folder = Sposta nella cartella…
+add_tag_batch = Aggiungi tag…
+remove_tag_batch = Rimuovi tag…
+export_notes = Esporta…
+root_folder = Radice
+move = Sposta
+apply = Applica
+tag_name = Nome del tag
+notes_moved = Note spostate
+tags_updated = Tag aggiornati
+notes_exported = Note esportate
+folder_properties = Proprietà della cartella…
+folder_description = Descrizione
+folder_color = Colore
+folder_note_icon = Icona delle note
+folder_inherit = Note e sottocartelle ereditano colore e icona
+folder_ai_system = Istruzioni per l'IA
+folder_ai_system_hint = Aggiunte al prompt dell'assistente quando si chatta con note di questa cartella; una nota può definire le proprie con ai_system nel frontmatter
+manage_tags = Gestisci tag…
+tag_manager = Gestione tag
+tag_select_hint = Scegli un tag da rinominare o unire
+tag_new_name = Nuovo nome
+merge_into_tag = Unisci in
+tag_renamed = Tag rinominato in
+tag_color = Colore
+filter_by_tags = Filtra per tag
+clear_tag_filter = ✕ Rimuovi filtro
+suggest_tags = Suggerisci tag
+suggest_tags_desc = Al salvataggio, suggerisci tag del vault adatti alla nota
+suggested_tags = Tag suggeriti (clic per aggiungere)
+inbox_pending = Catture da elaborare
+captured_to_inbox = Appunti salvati nell'Inbox
+clipboard_empty = Gli appunti sono vuoti
+quick_note_preview = Anteprima (Ctrl+P)
+quick_note_send_to = Invia a…
+quick_note_send_journal = 📅 Diario di oggi
+quick_note_send_new = ➕ Nuova nota
+quick_note_send_search = Cerca nota…
+quick_note_nothing_to_send = Niente da inviare
+quick_note_sent = 📤 Inviata a
+sketch_title = Schizzo
+sketch_save = Salva schizzo
+sketch_undo = Annulla tratto (Ctrl+Z)
+sketch_clear = Cancella tutto
+undo = Annulla
+notes_deleted = Note eliminate
+note_moved = Nota spostata
+folder_moved = Cartella spostata
+move_undone = Spostamento annullato
+undo_move_failed = Impossibile annullare lo spostamento
+folder_deleted = Cartella eliminata
+item_restored = Ripristinato
+restore_failed = Impossibile ripristinare
+new_project_folder = Nuova cartella di progetto…
+project_name = Nome del progetto
+folder_templates_hint = I modelli sono definiti in
+project_created = Progetto creato
+project_failed = Impossibile creare il progetto
+search_sort = Ordina risultati
+search_sort_relevance = Rilevanza
+search_sort_modified = Modificate
+search_sort_created = Create
+search_sort_title = Titolo
+search_all_folders = Tutte le cartelle
+search_all_tags = Tutti i tag
+search_has_todo = Con attività
+search_has_reminder = Con promemoria
+search_clear_filters = Rimuovi filtri
+search_scope = Ambito della risposta
+scope_vault = Tutte le note
+scope_attached = Note allegate
+chat_group_empty = Quella cartella o tag non ha note
+chat_group_notes = { $included }/{ $count } { $count ->
+    [one] nota
+   *[other] note
+}
+chat_note_truncated = troncata
+chat_note_left_out = non entra
+chat_history_summarized = Messaggi precedenti riassunti
+chat_paste_image = Incolla immagine dagli appunti
+chat_model_no_vision = Il modello configurato non accetta immagini
+chat_image_unsupported = Si possono allegare solo immagini PNG, JPEG, GIF o WebP
+chat_clipboard_no_image = Negli appunti non c'è nessuna immagine
+custom_agents_label = Agenti personalizzati
+custom_agents_edit = Modifica agents.yaml
+custom_agents_hint = Definisci agenti con prompt, strumenti e modello, e le parole che li attivano; le modifiche si applicano al salvataggio
+custom_agents_count = { $count ->
+    [one] { $count } agente
+   *[other] { $count } agenti
+}
+custom_agents_rules = { $count ->
+    [one] { $count } regola
+   *[other] { $count } regole
+}
+chat_dry_run_tooltip = Simulazione: l'agente propone le modifiche e tu le approvi
+dry_run_activated = Simulazione attivata: nulla verrà modificato senza la tua approvazione
+dry_run_deactivated = Simulazione disattivata
+dry_run_plan_title = Modifiche previste
+dry_run_apply = Applica
+dry_run_discard = Scarta
+dry_run_applied = Modifiche applicate
+dry_run_discarded = Modifiche scartate
+dry_run_failed = Impossibile applicare
+dry_run_lines_skipped = { $count ->
+    [one] { $count } riga invariata
+   *[other] { $count } righe invariate
+}
+dry_run_create_note = Creare la nota «{ $name }»
+dry_run_update_note = Modificare «{ $name }»
+dry_run_append_note = Aggiungere in fondo a «{ $name }»
+dry_run_delete_note = Eliminare «{ $name }»
+agent_plan_title = Piano
+agent_plan_pause = ⏸ Pausa dopo questo passo
+agent_plan_pausing = In pausa…
+chat_branch_tooltip = Crea un ramo della conversazione da qui
+chat_branch_created = Nuovo ramo della conversazione; l'originale resta salvata
+chat_branch_needs_history = Attiva il salvataggio della cronologia della chat per creare rami
+chat_branch_error = Impossibile creare il ramo della conversazione
+chat_branch_of = Ramo della conversazione #{ $id }
+chat_agent_invalid_arguments = Argomenti non validi per { $tool }; si chiede al modello di correggerli
+ai_status_circuit_open = { $provider } in pausa · { $seconds } s
+ai_status_circuit_open_tooltip = Il provider di IA ha fallito più volte di seguito; verrà riprovato al termine del conto alla rovescia
+offline_indicator = Offline
+offline_indicator_tooltip = Nessuna rete: l'IA nel cloud è disattivata, la ricerca usa solo il testo e gli embedding attendono in coda
+offline_agent_disabled = La modalità agente richiede una connessione a Internet
+offline_chat_local_search = Offline: verranno cercate le tue note
+offline_entered = Offline: l'IA nel cloud è in pausa
+offline_left = Connessione ripristinata
+offline_left_indexing = { $count ->
+    [one] Connessione ripristinata; indicizzazione di { $count } nota in sospeso
+   *[other] Connessione ripristinata; indicizzazione di { $count } note in sospeso
+}
+offline_chat_title = Offline · note corrispondenti
+offline_chat_no_results = Nessuna nota corrisponde al messaggio
+chat_export_tooltip = Esporta conversazioni (JSONL)
+chat_export_done = Conversazioni esportate
+chat_export_error = Impossibile esportare le conversazioni
+preview_edit_failed = Impossibile applicare la modifica; usa la modalità Inserimento
+shortcut_split_preview = Editor e anteprima affiancati
+preview_options = Opzioni dell'anteprima
+preview_density_comfortable = Spaziatura comoda
+preview_density_compact = Compatto
+preview_heading_anchors = Ancore # sui titoli
+preview_print_friendly = Adatto alla stampa
+preview_print_friendly_tooltip = Senza video né controlli, testo scuro su sfondo bianco
+copy_heading_link = Copia link al titolo
+heading_link_copied = Link al titolo copiato
+duplicate_note_title = Nota duplicata?
+duplicate_note_explanation = Esiste già una nota molto simile. Puoi aprirla, aggiungerle il nuovo contenuto o creare comunque la nota.
+duplicate_note_new = Nuova nota
+duplicate_note_imported = Contenuto importato
+duplicate_note_title_match = Titolo simile
+duplicate_note_content_match = Contenuto simile
+duplicate_note_open = Apri l'esistente
+duplicate_note_merge = Aggiungi all'esistente
+duplicate_note_create = Crea comunque
+vault_health = Stato del vault
+vault_health_largest = Note più grandi
+vault_health_stale = Non modificate da più di un anno
+vault_health_untagged = Senza tag
+vault_health_orphans = Orfane (senza collegamenti)
+vault_health_search_index = Nell'indice di ricerca
+vault_health_embeddings = Con embedding
+vault_health_embeddings_disabled = La ricerca semantica è disattivata
+vault_health_empty = Niente da controllare qui
+backups = Backup
+backups_desc = Copie compresse delle note e del database. La cartella può stare su un altro disco o su un remoto montato.
+backups_interval = Ore tra i backup automatici
+backups_keep = Conserva
+backups_keep_daily = giornalieri
+backups_keep_weekly = settimanali
+backups_keep_monthly = mensili
+backups_change_folder = Cambia cartella
+backups_select_folder = Cartella dei backup
+backups_now = Esegui backup ora
+backup_restore = Ripristina backup…
+backup_restore_button = Ripristina
+backup_restore_confirm = Ripristinare il backup del
+backup_restore_confirm_detail = La cartella delle note attuale viene conservata, rinominata, accanto a quella ripristinata.
+backup_none = Non ci sono ancora backup
+backup_created = Backup creato
+backup_failed = Backup non riuscito
+backup_restored = Backup ripristinato
+backup_restored_restart = Backup ripristinato. Riavvia NotNative per completare il ripristino del database
+backup_restore_failed = Impossibile ripristinare il backup
+read_later = Leggi più tardi
+remove_from_reading_list = Rimuovi dalla lista di lettura
+reading_list = Lista di lettura
+reading_list_added = Aggiunta alla lista di lettura
+reading_list_removed = Rimossa dalla lista di lettura
+reading_list_error = Impossibile aggiornare la lista di lettura
+reading_list_sort = Ordina per
+reading_list_sort_added = Data di aggiunta
+reading_list_sort_length = Lunghezza
+reading_list_empty = Niente da leggere
+flashcards_review = Ripassa le flashcard
+flashcards_show_answer = Mostra risposta
+flashcards_again = Di nuovo
+flashcards_hard = Difficile
+flashcards_good = Bene
+flashcards_easy = Facile
+flashcards_days = g
+flashcards_remaining = { $count ->
+    [one] { $count } rimanente
+   *[other] { $count } rimanenti
+}
+flashcards_done = Nessuna flashcard da ripassare oggi
+flashcards_none = Ancora nessuna flashcard. Scrivi Q:: domanda e A:: risposta in una nota, o termina un titolo con ?
+meeting_note = Nota di riunione…
+meeting_note_title = Nuova nota di riunione
+meeting_title_hint = Titolo della riunione
+meeting_attendees_hint = Partecipanti (Ana, Luis…)
+meeting_attendees_help = Separati da virgole; nella nota ogni partecipante è menzionato con @
+meeting_agenda = Ordine del giorno
+meeting_notes = Note
+meeting_action_items = Azioni
+meeting_extract_actions = Estrai azioni…
+meeting_actions_empty = Nessuna attività aperta nella nota. L’IA può ricavarle dalla discussione.
+meeting_actions_no_due = Senza data (promemoria domani)
+meeting_actions_has_reminder = Ha già un promemoria
+meeting_actions_suggest = Suggerisci con l’IA
+meeting_actions_create_reminders = Crea promemoria
+meeting_actions_thinking = Ricerca delle azioni…
+meeting_actions_ai_error = L’IA non è riuscita a estrarre le azioni
+weekly_review = Revisione settimanale
+weekly_review_this_week = Questa settimana
+weekly_review_intro = Ecco cosa è successo nelle tue note da lunedì. Dai un’occhiata e poi rispondi a ogni sezione.
+weekly_review_stats = Statistiche
+weekly_review_created = Note create
+weekly_review_modified = Note modificate
+weekly_review_words = Parole in queste note
+weekly_review_journal_days = Giorni di diario
+weekly_review_open_tasks = Attività aperte
+weekly_review_completed_reminders = Promemoria completati
+weekly_review_none = Niente questa settimana
+weekly_review_summary = Riepilogo
+weekly_review_summary_prompt = Com’è andata la settimana, in poche righe?
+weekly_review_wins = Successi
+weekly_review_wins_prompt = Cosa è andato bene? Di cosa sei orgoglioso?
+weekly_review_challenges = Difficoltà
+weekly_review_challenges_prompt = Cosa si è bloccato o ha richiesto più tempo del previsto?
+weekly_review_next_week = Prossima settimana
+weekly_review_next_week_prompt = Quali sono le tre priorità della prossima settimana?
+weekly_review_ai_draft = Bozza con l’IA
+weekly_review_ai_error = L’IA non è riuscita a scrivere il riepilogo
+weekly_review_back = Indietro
+weekly_review_next = Avanti
+weekly_review_create = Crea nota
+weekly_review_exists = La revisione di questa settimana esiste già
+compare_notes = Confronta note
+compare_with = Confronta con…
+compare_with_current = Confronta con la versione attuale
+compare_swap = Scambia
+compare_side_by_side = Affiancato
+compare_identical = Le due note sono identiche
+compare_changes = blocchi modificati
+compare_copy_left = Copia sinistra
+compare_copy_right = Copia destra
+compare_copy_diff = Copia diff
+folder_ai_excluded = Nascondi all’IA
+folder_ai_excluded_hint = Le note di questa cartella e delle sue sottocartelle non vengono indicizzate, l’agente non può leggerle e non vengono suggerite nella chat. Una singola nota si esclude con ai: false nel frontmatter
+chat_note_ai_excluded = Questa nota è esclusa dall’IA
+mcp_note_ai_excluded = La nota «{ $name }» è esclusa dall’IA e non può essere letta
+profiles = Profili
+profiles_hint = Ogni profilo conserva la cartella delle note, il provider di IA, il tema e i backup. Cambiando, le impostazioni attuali vengono salvate nel profilo attivo.
+profiles_empty = Ancora nessun profilo
+profile_active = Attivo
+profile_switch = Passa
+profile_default_folder = Cartella predefinita
+profile_name_hint = Nome del profilo (Lavoro, Personale...)
+profile_save_current = Salva impostazioni attuali
+profile_saved = Profilo «{ $name }» salvato
+profile_switched = Profilo attivo: { $name }
+mounted_folders = Cartelle montate
+mounted_folders_desc = Cartelle fuori dal vault (documentazione di un repository, una cartella sincronizzata) mostrate come cartelle di primo livello: vengono indicizzate, cercate e modificate, ma restano dove sono.
+mount_folder = Monta cartella…
+unmount_folder = Smonta
+mount_unavailable = non disponibile
+folder_mounted = Cartella montata
+folder_unmounted = Cartella smontata
+refactor_invalid = Nomi non validi per la rinomina
+refactor_no_matches = Nessuna nota da modificare
+refactor_notes = Note
+refactor_lines = Righe
+refactor_apply = Rinomina ovunque
+refactor_applied = Rinominato in tutte le note
+refactor_failed = Rinomina non riuscita, nulla è stato modificato
+refactor_undone = Rinomina annullata
+refactor_undo_failed = Impossibile annullare la rinomina
+copied_as_plain_text = Copiato come testo semplice
+shortcut_copy_as_plain_text = Copia come testo semplice (senza markdown)
+plain_text_link_urls = Mantieni gli URL dei link quando copi come testo semplice
+word_goal_reached = Obiettivo di parole raggiunto
+word_goal = Obiettivo di parole
+word_goals_today = Obiettivi raggiunti oggi
+word_goals_week = Obiettivi raggiunti (7 giorni)
+scratch = Bozza
+shortcut_scratch = Apri o chiudi la bozza (non viene salvata)
+scratch_backup = Conserva una copia della bozza per recuperarla dopo un arresto anomalo
+tray_compact_mode = Modalità compatta
+shortcut_compact_mode = Finestra piccola con solo l'editor (modalità compatta)
+file_imported = File importato nel vault
+open_file_failed = Impossibile aprire il file
+screenshot_title = Annota screenshot
+screenshot_insert = Inserisci
+screenshot_tool_arrow = Freccia
+screenshot_tool_rect = Riquadro
+screenshot_tool_text = Testo (clic dove metterlo)
+screenshot_text_placeholder = Testo e Invio
+screenshot_annotate = Annotare gli screenshot (frecce, riquadri e testo) prima di inserirli
+shortcut_screenshot = Catturare una zona dello schermo nella nota
+tray_screenshot = Screenshot in Quick Note
+ocr_offer = Proporre di estrarre il testo delle immagini quando vengono inserite (OCR), per trovarle con la ricerca
+ocr_engine_tesseract = tesseract (locale)
+ocr_engine_ai = Modello IA con visione
+ocr_offer_toast = Estrarre il testo dall'immagine?
+ocr_extract = Estrai testo
+ocr_running = Estrazione del testo dall'immagine…
+ocr_done = Testo dell'immagine aggiunto sotto di essa
+ocr_block_title = Testo dell'immagine
+ocr_no_text = Nessun testo trovato nell'immagine
+ocr_no_image = Nessuna immagine nella riga del cursore
+ocr_image_missing = L'immagine non è più nella nota
+ocr_failed = Impossibile estrarre il testo dall'immagine
+ocr_tesseract_missing = tesseract non è installato (pacchetto tesseract)
+ocr_model_no_vision = Il modello IA configurato non accetta immagini
+shortcut_ocr = Estrarre il testo dell'immagine della riga (OCR)
+rename_empty = Il nome non può essere vuoto
+rename_illegal_char = Il nome non può contenere
+rename_hidden = Il nome non può iniziare con un punto
+rename_taken = Esiste già; Invio userà
+renamed_to = Rinominato in
+rename_failed = Impossibile rinominare
+open_in_file_manager = Apri nel file manager
+confirm_delete = Vuoi davvero eliminare
+
+## Preferenze
+theme = Tema
+theme_sync = L'applicazione si sincronizza automaticamente con il tema Omarchy
+markdown_rendering = Rendering Markdown
+markdown_enabled = Attivo per impostazione predefinita in modalità Normale
+language = Lingua
+language_description = Scegli la lingua dell'interfaccia
+restart_required = È necessario riavviare l'applicazione
+start_in_background = Avvia in background
+start_in_background_desc = Avvia l'applicazione ridotta nell'area di notifica
+format_toolbar = Barra di formattazione
+format_toolbar_desc = Mostra la barra di formattazione in modalità modifica
+preview_adjust_images = Immagini in modalità scura
+preview_adjust_images_desc = Inverte diagrammi e screenshot con sfondo bianco nell'anteprima scura. Usa ![](img.png){"{"}.no-invert{"}"} per escludere un'immagine
+recent_notes = Recenti
+navigation_history_end = Nessun’altra nota nella cronologia
+annotations = Annotazioni
+annotations_search = Cerca nelle evidenziazioni
+annotations_empty = Ancora nessuna evidenziazione. Seleziona del testo e premi = in modalità Normal, oppure scrivi ==testo==
+paste_html_as_markdown = Incolla HTML come markdown
+paste_html_as_markdown_desc = Converte i contenuti copiati da un browser o da un documento in markdown pulito e scarica le immagini negli allegati. Ctrl+Maiusc+V incolla sempre testo semplice
+url_paste_behavior = Quando si incolla un link
+url_paste_ask = Chiedi se usare il titolo
+url_paste_title = Usa sempre il titolo della pagina
+url_paste_bare = Lascia il link così com'è
+large_tap_targets = Pulsanti grandi (touch)
+large_tap_targets_desc = Ingrandisce pulsanti e righe per l'uso con le dita. Scorri dal bordo sinistro per aprire l'elenco delle note, pizzica l'anteprima per cambiare la dimensione del testo e tieni premuta una nota per vederne il menu
+embed_budget = Contenuti incorporati
+embed_budget_desc = Numero massimo di video caricati contemporaneamente nell'anteprima. I video fuori schermo vengono scaricati; con 0 si caricano solo al clic
+plugins = Plugin
+plugin_error = Errore del plugin
+workspace = Cartella di lavoro
+workspace_description = Posizione in cui vengono salvate note e risorse
+open_workspace_folder = Apri cartella di lavoro
+change_workspace = Cambia posizione
+workspace_location = Posizione
+select_workspace_folder = Seleziona cartella di lavoro
+select = Seleziona
+audio_output = Uscita audio
+audio_output_description = Seleziona il dispositivo di uscita audio
+audio_output_default = Predefinito di sistema
+
+## Finestra delle preferenze
+prefs_page_general = Generale
+prefs_page_editor = Editor
+prefs_page_ai = IA
+prefs_page_sync = Sincronizzazione
+prefs_page_reminders = Promemoria
+prefs_page_music = Musica
+prefs_page_advanced = Avanzate
+prefs_search_placeholder = Cerca impostazioni...
+prefs_no_results = Nessuna impostazione corrisponde alla ricerca
+prefs_revert = Annulla modifiche
+prefs_revert_tooltip = Ripristina le impostazioni presenti all'apertura di questa finestra
+prefs_mcp_desc = Consente ad altre applicazioni e assistenti di accedere alle tue note
+prefs_mcp_details = Mostra dettagli
+prefs_reminders_desc = I promemoria compaiono nel pannello (Alt+R) e come notifiche di sistema
+prefs_show_reminders = Mostra promemoria
+prefs_config_file = File di configurazione
+prefs_open_config_folder = Apri cartella
+
+## Barra di stato
+lines = righe
+words = parole
+characters = caratteri
+notes_count = { $count ->
+    [one] { $count } nota
+   *[other] { $count } note
+}
+lines_count = { $count ->
+    [one] { $count } riga
+   *[other] { $count } righe
+}
+words_count = { $count ->
+    [one] { $count } parola
+   *[other] { $count } parole
+}
+characters_count = { $count ->
+    [one] { $count } carattere
+   *[other] { $count } caratteri
+}
+note_statistics = Statistiche della nota
+reading_time = Tempo di lettura
+characters_no_spaces = Caratteri senza spazi
+paragraphs = Paragrafi
+headings = Intestazioni
+links = Link
+code_blocks = Blocchi di codice
+images = Immagini
+saved = Salvato
+unsaved_changes = Modifiche non salvate
+note_created = Nota creata
+note_deleted = Nota eliminata
+note_renamed = Nota rinominata
+copied_as_rich_text = Copiato come testo formattato
+error = Errore
+success = Operazione riuscita
+no_results = Nessun risultato per
+searching = Ricerca in corso
+
+## TODO
+todos = TODO
+todos_note = TODO della nota
+no_todos = Nessun TODO in questa nota
+completed = completato
+todo_progress = { $completed }/{ $count } { $count ->
+    [one] completata
+   *[other] completate
+}
+todo_progress_subtasks = { $completed }/{ $count } { $count ->
+    [one] sottoattività
+   *[other] sottoattività
+}
+no_section = Nessuna sezione
+
+## Ricerca
+searching_ellipsis = 🔄 Ricerca...
+no_results_found = ❌ Nessun risultato
+found_relevant_notes = { $count ->
+    [one] Trovata { $count } nota pertinente:
+   *[other] Trovate { $count } note pertinenti:
+}
+
+## Promemoria
+reminders_title = Promemoria
+reminders_new = Nuovo promemoria
+reminders_pending = In sospeso
+reminders_completed = Completati
+reminders_all = Tutti
+reminder_complete = Completa
+reminder_snooze = Posticipa
+reminder_edit = Modifica
+reminder_delete = Elimina
+reminders_empty = Nessun promemoria
+reminders_history = Cronologia
+reminders_history_search = Cerca nella cronologia…
+reminders_history_export = Esporta in CSV
+reminders_history_empty = Nessun promemoria è ancora scattato
+reminders_history_exported = Cronologia esportata
+reminder_outcome_open = aperto
+reminder_outcome_completed = completato
+reminder_outcome_dismissed = scartato
+reminder_outcome_missed = mancato
+reminder_history_snoozed = posticipato
+reminder_open_note = Apri nota
+reminder_draft_title = Promemoria proposto
+reminder_draft_confirm = Crea
+reminder_draft_created = ✓ Promemoria creato
+reminder_draft_cancelled = Promemoria scartato
+reminder_draft_invalid_date = Data non riconosciuta (es. 2026-10-20 15:00 o tomorrow 10:00)
+reminder_source_missing = La nota di questo promemoria non esiste più
+reminder_priority_low = Bassa
+reminder_priority_medium = Media
+reminder_priority_high = Alta
+reminder_priority_urgent = Urgente
+reminder_snooze_5min = 5 minuti
+reminder_snooze_15min = 15 minuti
+reminder_snooze_1hour = 1 ora
+reminder_snooze_tomorrow = Domani
+reminder_notification_title = 🔔 Promemoria
+reminder_create_title = Crea promemoria
+reminder_title_label = Titolo
+reminder_description_label = Descrizione
+reminder_date_label = Data e ora
+reminder_priority_label = Priorità
+reminder_repeat_label = Ripeti
+reminder_repeat_none = Non ripetere
+reminder_repeat_daily = Ogni giorno
+reminder_repeat_weekly = Ogni settimana
+reminder_repeat_monthly = Ogni mese
+no_reminders = Nessun promemoria
+reminder_overdue = Scaduto
+reminder_today = Oggi
+reminder_tomorrow = Domani
+reminder_created = Promemoria creato
+reminder_updated = Promemoria aggiornato
+reminder_deleted = Promemoria eliminato
+reminder_completed_msg = Promemoria completato
+reminder_snoozed = Promemoria posticipato
+reminder_tooltip = Promemoria (Alt+R)
+
+## Note rapide
+quick_notes_title = Nota rapida
+quick_note_back_to_list = Torna all'elenco
+quick_note_new = Nuova nota rapida
+quick_note_keep_visible = Mantieni visibile
+quick_note_close = Chiudi (Esc)
+quick_note_no_notes = Ancora nessuna nota rapida
+quick_note_press_to_create = Premi + per crearne una
+quick_note_saved = 💾 Salvato
+quick_note_unsaved = ● Non salvato
+quick_note_shortcut_hint = Ctrl+S: salva | Esc: chiudi
+quick_note_created = Nota rapida creata
+
+## Chat IA
+ai_chat = Chat IA
+chat_input_placeholder = Scrivi qui il tuo messaggio...
+chat_send = Invia
+chat_context = Contesto
+chat_attach_note = Allega nota corrente
+chat_attach_button = Allega
+chat_clear_context = Svuota contesto
+chat_clear_history = Cancella cronologia
+chat_new_session = Nuova sessione
+chat_mode_agent = Modalità: Agente
+chat_mode_normal = Modalità: Normale
+ai_thinking = Sto pensando...
+you_label = Tu
+
+## Area di notifica
+tray_show_window = Mostra finestra
+tray_hide_window = Nascondi finestra
+tray_quit = Esci
+
+## Suggerimenti
+tooltip_show_hide_notes = Mostra/nascondi elenco note
+tooltip_new_note = Nuova nota
+tooltip_close_esc = Chiudi (Esc)
+tooltip_note_tags = Tag della nota
+tooltip_note_todos = TODO della nota
+tooltip_music_player = Lettore musicale
+tooltip_reminders = Promemoria (Alt+R)
+tooltip_settings = Impostazioni
+
+## Date
+hour_format = Formato dell'ora
+hour_format_auto = Automatico (in base alla regione)
+
+## Log
+logs = Log
+logs_level_desc = Livello di dettaglio del log. Utile per segnalare problemi
+logs_view = Mostra log
+logs_empty = Ancora nessun log
+logs_follow = Segui
+logs_copy = Copia
+
+## Credenziali
+api_key_storage_keyring = 🔐 Le chiavi sono salvate nel portachiavi di sistema
+api_key_storage_file = 🔐 Portachiavi non disponibile: le chiavi sono salvate cifrate nella cartella dei dati
+
+## Audio
+audio_output_detecting = Rilevamento dei dispositivi...
+audio_output_changed = Uscita audio cambiata
+audio_output_error = Errore durante il cambio dell'uscita audio
+
+## Scorciatoie da tastiera
+shortcuts_general = Generale
+shortcuts_modes = Modalità di modifica
+shortcuts_navigation = Navigazione
+shortcuts_editing = Modifica
+shortcut_new_note = Crea nuova nota
+shortcut_save = Salva nota
+shortcut_search = Cerca note
+shortcut_toggle_sidebar = Mostra/nascondi barra laterale
+shortcut_escape = Torna all'editor
+shortcut_insert_mode = Modalità inserimento
+shortcut_normal_mode = Modalità normale
+shortcut_command_mode = Modalità comando
+shortcut_visual_mode = Modalità visuale
+shortcut_movement = Sinistra/Giù/Su/Destra
+shortcut_next_word = Parola successiva
+shortcut_prev_word = Parola precedente
+shortcut_line_start = Inizio riga
+shortcut_line_end = Fine riga
+shortcut_doc_start = Inizio documento
+shortcut_doc_end = Fine documento
+shortcut_delete_char = Elimina carattere
+shortcut_delete_line = Elimina riga
+shortcut_undo = Annulla
+shortcut_cycle_task_state = Cambia stato attività: [ ] → [/] → [x] → [-]
+shortcut_redo = Ripeti
+shortcut_open_note_search = Apri la ricerca delle note
+shortcut_toggle_ai_chat = Mostra/nascondi chat IA
+shortcut_delete_char_under = Elimina il carattere sotto il cursore
+shortcut_delete_line_complete = Elimina l'intera riga
+shortcut_delete_prev_char = Elimina il carattere precedente
+shortcut_delete_next_char = Elimina il carattere successivo
+shortcut_new_line = Nuova riga
+shortcut_search_sidebar = Ricerca e barra laterale
+shortcut_activate_search = Attiva la ricerca
+shortcut_close_search = Chiudi la ricerca / Torna all'editor
+shortcut_open_music = Apri il lettore musicale
+shortcut_open_reminders = Apri i promemoria
+shortcuts_global = 🌍 Globale
+shortcuts_quick_notes = 📝 Note rapide
+shortcuts_normal_navigation = 📝 Modalità normale - Navigazione
+shortcuts_normal_editing = 📝 Modalità normale - Modifica e modalità
+shortcuts_insert_mode = ✍️ Modalità inserimento
+shortcuts_ai_chat = 🤖 Modalità chat IA
+shortcuts_sidebar = 📂 Barra laterale ed elenchi
+shortcuts_floating_search = 🔍 Ricerca flottante
+shortcut_global_search = Apri la ricerca globale flottante
+shortcut_note_search = Cerca nella nota corrente
+shortcut_cycle_focus = Sposta il focus tra barra laterale, editor e ricerca
+shortcut_enter_ai_chat = Entra in modalità chat IA
+shortcut_back_or_close = Torna all'elenco / Chiudi la finestra
+shortcut_left = Sposta il cursore a sinistra
+shortcut_down = Sposta il cursore in basso
+shortcut_up = Sposta il cursore in alto
+shortcut_right = Sposta il cursore a destra
+shortcut_ai_chat_mode = Entra in modalità chat IA
+shortcut_insert_table = Inserisci tabella Markdown
+shortcut_insert_image = Inserisci immagine
+shortcut_insert_sketch = Disegna uno schizzo
+shortcut_insert_footnote = Inserisci nota a piè di pagina
+shortcut_saveas = Salva una copia della nota e aprila
+shortcut_split_by_heading = Trasforma ogni sezione in una nota
+shortcut_navigate_history = Indietro / avanti tra le note aperte
+shortcut_toggle_highlight = Evidenzia la selezione (ripeti per cambiare colore)
+shortcut_paste_plain_text = Incolla come testo semplice
+shortcut_copy_as_html = Copia come testo formattato (HTML)
+shortcut_tab_autocomplete = Tab / Completa tag o @
+shortcut_exit_chat = Esci dalla chat (torna alla modalità normale)
+shortcut_exit_chat_insert = Esci dalla chat e passa alla modalità inserimento
+shortcut_send_message = Invia messaggio
+shortcut_navigate_suggestions = Scorri i suggerimenti
+shortcut_accept_suggestion = Accetta il suggerimento
+shortcut_next_note = Nota successiva
+shortcut_prev_note = Nota precedente
+shortcut_open_note = Apri la nota o la cartella selezionata
+shortcut_focus_editor = Riporta il focus all'editor
+shortcut_toggle_semantic = Attiva/disattiva la ricerca semantica (IA)
+shortcut_navigate_results = Scorri i risultati
+shortcut_open_selected = Apri la nota selezionata
+
+## Informazioni
+app_description = Editor di note markdown in stile Vim
+website = Sito web
+authors = Autori
+version = Versione
+license = Licenza
+
+## Visualizzatore di immagini
+image_viewer = Visualizzatore di immagini
+open_file_location = Apri posizione
+
+## YouTube
+transcribe_youtube = Trascrivere il video di YouTube?
+youtube_detected = È stato rilevato un link di YouTube
+only_link = Solo link
+link_title_found = Titolo del link
+use_page_title = Usa il titolo
+keep_bare_link = Mantieni il link
+transcribe_and_insert = Trascrivi e inserisci
+downloading_transcript = Download della trascrizione...
+loading_transcript = Caricamento della trascrizione...
+transcript_error = Errore durante il recupero della trascrizione
+transcript_unavailable = Trascrizione non disponibile per questo video
+transcript_section = 📝 Trascrizione
+youtube_unavailable = La trascrizione di YouTube non è al momento disponibile...
+
+## Lettore musicale
+music_player = Lettore musicale
+music_player_title = Lettore musicale
+music_search_placeholder = Cerca musica su YouTube...
+no_music_playing = Nessuna musica in riproduzione
+music_play_pause = Riproduci/Pausa (Spazio)
+music_stop = Ferma
+music_seek_back = Indietro di 5 s
+music_seek_forward = Avanti di 5 s
+music_volume_down = Abbassa il volume
+music_volume_up = Alza il volume
+music_previous_song = Brano precedente
+music_next_song = Brano successivo
+music_repeat_off = Ripeti: NO
+music_repeat_one = Ripeti: UNO
+music_repeat_all = Ripeti: TUTTI
+music_shuffle_off = Casuale: NO
+music_shuffle_on = Casuale: SÌ
+music_manage_playlists = Gestisci playlist
+music_playback_queue = Coda di riproduzione
+music_loading = Caricamento...
+music_add_to_queue = Aggiungi alla coda
+music_remove_from_queue = Rimuovi dalla coda
+music_new_playlist = Nuova playlist
+music_load_playlist = Carica playlist
+music_save_playlist = Salva playlist
+music_playlist_name = Nome della playlist
+playlist_new = 🎵 Nuova
+playlist_save = 💾 Salva
+playlist_clear = 🗑️ Svuota
+playlist_queue = Coda di riproduzione
+playlist_queue_empty = Coda vuota
+playlist_saved_playlists = Playlist salvate
+playlist_save_title = Salva playlist
+playlist_name_prompt = Nome della playlist:
+playlist_name_example = es.: Musica rilassante
+playlist_no_loaded = Nessuna playlist caricata
+playlist_no_saved = Nessuna playlist salvata
+playlist_add_to = Aggiungi alla playlist
+playlist_delete = Elimina playlist
+play = Riproduci
+remove = Rimuovi
+
+## Chat IA e modalità agente
+chat_model_default = Modello: OpenAI GPT-4
+chat_subtitle = Combina le tue note con l'assistente in tempo reale
+chat_attach_note_dialog_title = Allega una nota al contesto
+chat_clear_history_confirm_title = Eliminare tutta la cronologia della chat?
+chat_clear_history_confirm_message = Questa azione elimina definitivamente tutta la cronologia delle conversazioni salvata. Non può essere annullata.
+chat_history_cleared = Cronologia eliminata
+chat_history_cleared_message = Tutta la cronologia delle conversazioni è stata eliminata
+chat_mode_chat = Modalità: Chat
+chat_toggle_mode_tooltip = Cambia: modalità agente (con strumenti) / chat normale (senza strumenti)
+chat_agent_thinking = 💭 Ragionamento
+chat_agent_action = 🔧 Azione
+chat_agent_observation = 👁️ Osservazione
+chat_agent_answer = ✅ Risposta
+ai_chat_placeholder = Scrivi un messaggio per l'assistente IA...
+ai_send_message = Invia messaggio
+ai_model = Modello
+ai_temperature = Temperatura
+ai_api_key = Chiave API
+ai_openai = OpenAI
+ai_openrouter = OpenRouter
+ai_api_key_placeholder = Inserisci la tua chiave API...
+ai_save = Salva
+ai_cancel = Annulla
+ai_no_key_configured = Nessuna chiave API configurata
+ai_configure_key = Configura la chiave API nelle Preferenze
+ai_free_models = ═══ MODELLI GRATUITI ═══
+ai_paid_models = ═══ MODELLI A PAGAMENTO ═══
+agent_mode_activated =
+    Modalità agente attivata
+    L'assistente può cercare nelle note ed eseguire azioni
+chat_mode_activated =
+    Chat normale attivata
+    Conversazione diretta senza accesso agli strumenti
+analyzing_task = Analisi dell'attività...
+no_notes_in_context = Nessuna nota nel contesto
+remove_from_context = Rimuovi dal contesto
+
+## Impostazioni IA
+temperature_label = Temperatura:
+max_tokens_label = Token massimi:
+unlimited = Illimitati
+save_history_label = Salva cronologia:
+model_label = Modello:
+refresh_models_tooltip = Aggiorna l'elenco dei modelli da OpenRouter
+search_model_placeholder = Cerca modello...
+no_models_found = Nessun modello trovato
+semantic_search_title = 🧠 Ricerca semantica (embedding)
+semantic_search_description = Configura gli embedding per cercare per significato con OpenRouter
+enable_embeddings = Attiva embedding:
+index_all_notes = 📄 Indicizza tutte le note
+indexing = ⏳ Indicizzazione...
+indexing_completed = Indicizzazione completata
+estimated_cost = Costo stimato: ~0,01 $ ogni 10.000 note
+get_api_key_openrouter = Ottieni una chiave API su OpenRouter
+
+## Messaggi MCP
+mcp_note_created = ✓ Nota '{}' creata correttamente
+mcp_note_read = ✓ Nota '{}' letta correttamente
+mcp_note_updated = ✓ Nota '{}' aggiornata correttamente
+mcp_note_deleted = ✓ Nota '{}' eliminata correttamente
+mcp_content_appended = ✓ Contenuto aggiunto a '{}' correttamente
+mcp_notes_found = { $count ->
+    [one] ✓ { $count } nota trovata
+   *[other] ✓ { $count } note trovate
+}
+mcp_search_results = { $count ->
+    [one] ✓ { $count } risultato per '{ $query }'
+   *[other] ✓ { $count } risultati per '{ $query }'
+}
+mcp_notes_with_tag = { $count ->
+    [one] ✓ { $count } nota con il tag #{ $tag }
+   *[other] ✓ { $count } note con il tag #{ $tag }
+}
+mcp_tags_found = { $count ->
+    [one] ✓ { $count } tag trovato
+   *[other] ✓ { $count } tag trovati
+}
+mcp_tags_added = ✓ Tag aggiunti a '{}'
+mcp_note_renamed = ✓ Nota rinominata da '{}' a '{}'
+mcp_note_duplicated = ✓ Nota '{}' duplicata come '{}'
+mcp_folder_created = ✓ Cartella '{}' creata correttamente
+mcp_project_created = ✓ Progetto '{ $folder }' creato dal modello
+mcp_note_not_found = Nota '{}' non trovata
+mcp_folders_found = { $count ->
+    [one] ✓ { $count } cartella trovata
+   *[other] ✓ { $count } cartelle trovate
+}
+mcp_create_reminder_desc = Crea un promemoria con data, ora e priorità
+mcp_list_reminders_desc = Elenca tutti i promemoria o filtrali per stato
+mcp_update_reminder_desc = Aggiorna un promemoria esistente
+mcp_delete_reminder_desc = Elimina un promemoria
+mcp_snooze_reminder_desc = Rimanda un promemoria di un tempo specifico
+mcp_complete_reminder_desc = Segna un promemoria come completato
+mcp_reminder_title_desc = Titolo del promemoria
+mcp_reminder_date_desc = Data e ora del promemoria (es.: '2025-11-20 15:00', 'today 18:00', 'tomorrow')
+mcp_server_title = Server MCP - Model Context Protocol
+mcp_server_active = Server MCP attivo
+mcp_server_subtitle = Espone gli strumenti di NotNative via HTTP
+mcp_status = Stato
+mcp_endpoints_available = Endpoint disponibili
+status_active = 🟢 Attivo
+copy_url = 📋 Copia URL
+view_docs = 📖 Vedi la documentazione
+copied = ✓ Copiato!
+
+## Promemoria e note rapide
+reminder_linked_note_label = Nota collegata
+reminders_count = { $count ->
+    [one] { $count } in sospeso
+   *[other] { $count } in sospeso
+}
+quick_note_autosaved = 💾 Salvato automaticamente
+
+## Ricerca e interfaccia
+semantic_results = Risultati per somiglianza semantica
+ai_analyzing = 🔄 L'assistente IA sta analizzando i risultati...
+assistant_response = 🧠 Risposta dell'assistente
+tooltip_change_search_mode = Ctrl per cambiare modalità
+images_filter = Immagini
+unknown_error = Errore sconosciuto
+operation_success = ✓ Operazione riuscita
+operation_failed = ✗ Operazione non riuscita
+adding_content = Aggiunta del contenuto...
+adding_tag = Aggiunta del tag...
+adding_tags = Aggiunta dei tag...
+generating_index = Generazione dell'indice...
+extracting_code = Estrazione del codice...
+
+## Database
+base_add_filter = Aggiungi filtro
+base_sort = Ordina
+base_columns = Colonne
+base_columns_config = Configura colonne
+base_data_source = Modalità origine dati
+base_formula_rows = Righe di formule
+base_formula_rows_title = Righe di formule
+base_add_formula_row = Aggiungi riga dei totali
+base_formula_row_label = Etichetta
+base_formula_help = Usa formule come in Excel: =SUM(B:B), =AVG(C1:C10)
+base_export_xlsx = Esporta in Excel
+base_export_xlsx_success = Esportazione riuscita
+base_export_xlsx_error = Errore di esportazione
+base_show_graph = Mostra il grafo delle relazioni
+base_no_filters = Nessun filtro
+base_current_columns = Colonne visibili
+base_available_properties = Proprietà disponibili
+base_toggle_visibility = Mostra/nascondi
+base_remove_column = Rimuovi colonna
+base_add_column = Proprietà disponibili
+base_properties_hint = Fai clic su + per aggiungere come colonna
+base_add_as_column = Aggiungi come colonna
+base_move_up = Sposta su
+base_move_down = Sposta giù
+base_data_source_title = Origine dati
+base_notes_mode = Note
+base_grouped_mode = Record raggruppati
+base_grouped_hint =
+    I record raggruppati mostrano i gruppi di proprietà
+    inline come [game::X, bought::Y] come righe
+base_search_placeholder = Cerca nella tabella...
+base_no_notes = Nessuna nota trovata
+base_items = elementi
+base_items_of = di
+base_title = Titolo
+base_created = Creato
+base_modified = Modificato
+base_tags = Tag
+base_search_properties = Cerca proprietà...
+base_no_available_props = Nessun'altra proprietà disponibile
+base_sort_by = Ordina per
+base_no_sorting = Nessun ordinamento
+base_sort_ascending = Ordine crescente
+base_sort_descending = Ordine decrescente
+base_add_filter_title = Aggiungi filtro
+base_property = Proprietà
+base_operator = Operatore
+base_value = Valore
+base_filter_value_placeholder = Valore del filtro...
+base_cancel = Annulla
+base_apply_filter = Aggiungi filtro
+filter_op_equals = uguale a
+filter_op_not_equals = diverso da
+filter_op_contains = contiene
+filter_op_not_contains = non contiene
+filter_op_greater_than = maggiore di
+filter_op_greater_or_equal = maggiore o uguale a
+filter_op_less_than = minore di
+filter_op_less_or_equal = minore o uguale a
+filter_op_starts_with = inizia con
+filter_op_ends_with = finisce con
+filter_op_is_empty = è vuoto
+filter_op_is_not_empty = non è vuoto
@@ -0,0 +1,976 @@
+# NotNative - Português
+# As chaves ausentes neste arquivo usam o texto em inglês integrado.
+# Gerar a lista completa de chaves: notnative --export-locale-template pt.ftl
+
+-language-name = Português
+-app = NotNative
+
+## Geral
+app_title = { -app }
+untitled = Sem título
+notes = Notas
+new_note = Nova nota
+search = Pesquisar
+search_notes = Pesquisar (Ctrl+F)
+search_placeholder = Pesquisar notas...
+show_hide_notes = Mostrar/ocultar lista de notas
+preferences = Preferências
+keyboard_shortcuts = Atalhos de teclado
+about = Sobre
+settings = Configurações
+tags = Tags
+tags_note = Tags da nota
+no_tags = Sem tags
+search_tag = Pesquisar notas com esta tag
+remove_tag = Remover tag
+close = Fechar
+
+## Diálogos
+create_note_title = Nova nota
+create_folder_title = Nova pasta
+note_name_hint = exemplo: projetos/nova-ideia
+create_folder_hint = Use '/' para criar em pastas
+create = Criar
+cancel = Cancelar
+rename = Renomear
+delete = Excluir
+change_icon = Alterar ícone
+view_history = Ver histórico
+duplicate_note = Duplicar nota
+note_duplicated = Cópia criada
+merge_into = Mesclar em…
+merge = Mesclar
+merge_search = Pesquisar a nota de destino
+merge_delete_source = Mover a nota original para a lixeira
+note_merged = Nota mesclada em
+note_split = Notas criadas a partir das seções
+split_no_sections = A nota não tem títulos desse nível
+pin_note = Fixar no topo
+unpin_note = Desafixar
+notes_selected = { $count ->
+    [one] { $count } nota selecionada
+   *[other] { $count } notas selecionadas
+}
+move_to_folder = Mover para a pasta…
+add_tag_batch = Adicionar tag…
+remove_tag_batch = Remover tag…
+export_notes = Exportar…
+root_folder = Raiz
+move = Mover
+apply = Aplicar
+tag_name = Nome da tag
+notes_moved = Notas movidas
+tags_updated = Tags atualizadas
+notes_exported = Notas exportadas
+folder_properties = Propriedades da pasta…
+folder_description = Descrição
+folder_color = Cor
+folder_note_icon = Ícone das notas
+folder_inherit = Notas e subpastas herdam a cor e o ícone
+folder_ai_system = Instruções para a IA
+folder_ai_system_hint = Adicionadas ao prompt do assistente ao conversar com notas desta pasta; uma nota pode definir as suas com ai_system no frontmatter
+manage_tags = Gerir tags…
+tag_manager = Gestor de tags
+tag_select_hint = Escolha uma tag para a renomear ou fundir
+tag_new_name = Novo nome
+merge_into_tag = Fundir em
+tag_renamed = Tag renomeada em
+tag_color = Cor
+filter_by_tags = Filtrar por tags
+clear_tag_filter = ✕ Limpar filtro
+suggest_tags = Sugerir tags
+suggest_tags_desc = Ao salvar, sugerir tags do cofre que combinem com a nota
+suggested_tags = Tags sugeridas (clique para adicionar)
+inbox_pending = Capturas por processar
+captured_to_inbox = Área de transferência salva na Inbox
+clipboard_empty = A área de transferência está vazia
+quick_note_preview = Pré-visualização (Ctrl+P)
+quick_note_send_to = Enviar para…
+quick_note_send_journal = 📅 Diário de hoje
+quick_note_send_new = ➕ Nova nota
+quick_note_send_search = Buscar nota…
+quick_note_nothing_to_send = Nada para enviar
+quick_note_sent = 📤 Enviada para
+sketch_title = Esboço
+sketch_save = Salvar esboço
+sketch_undo = Desfazer traço (Ctrl+Z)
+sketch_clear = Limpar tudo
+undo = Desfazer
+notes_deleted = Notas excluídas
+note_moved = Nota movida
+folder_moved = Pasta movida
+move_undone = Movimento desfeito
+undo_move_failed = Não foi possível desfazer o movimento
+folder_deleted = Pasta excluída
+item_restored = Restaurado
+restore_failed = Não foi possível restaurar
+new_project_folder = Nova pasta de projeto…
+project_name = Nome do projeto
+folder_templates_hint = Os modelos são definidos em
+project_created = Projeto criado
+project_failed = Não foi possível criar o projeto
+search_sort = Ordenar resultados
+search_sort_relevance = Relevância
+search_sort_modified = Modificadas
+search_sort_created = Criadas
+search_sort_title = Título
+search_all_folders = Todas as pastas
+search_all_tags = Todas as tags
+search_has_todo = Com tarefas
+search_has_reminder = Com lembrete
+search_clear_filters = Limpar filtros
+search_scope = Âmbito da resposta
+scope_vault = Todas as notas
+scope_attached = Notas anexadas
+chat_group_empty = Essa pasta ou tag não tem notas
+chat_group_notes = { $included }/{ $count } { $count ->
+    [one] nota
+   *[other] notas
+}
+chat_note_truncated = truncada
+chat_note_left_out = não cabe
+chat_history_summarized = Mensagens anteriores resumidas
+chat_paste_image = Colar imagem da área de transferência
+chat_model_no_vision = O modelo configurado não aceita imagens
+chat_image_unsupported = Só é possível anexar imagens PNG, JPEG, GIF ou WebP
+chat_clipboard_no_image = Não há nenhuma imagem na área de transferência
+custom_agents_label = Agentes personalizados
+custom_agents_edit = Editar agents.yaml
+custom_agents_hint = Defina agentes com o seu prompt, ferramentas e modelo, e as palavras que os ativam; as alterações aplicam-se ao guardar
+custom_agents_count = { $count ->
+    [one] { $count } agente
+   *[other] { $count } agentes
+}
+custom_agents_rules = { $count ->
+    [one] { $count } regra
+   *[other] { $count } regras
+}
+chat_dry_run_tooltip = Simulação: o agente propõe as alterações e você as aprova
+dry_run_activated = Simulação ativada: nada será alterado sem a sua aprovação
+dry_run_deactivated = Simulação desativada
+dry_run_plan_title = Alterações planejadas
+dry_run_apply = Aplicar
+dry_run_discard = Descartar
+dry_run_applied = Alterações aplicadas
+dry_run_discarded = Alterações descartadas
+dry_run_failed = Não foi possível aplicar
+dry_run_lines_skipped = { $count ->
+    [one] { $count } linha sem alterações
+   *[other] { $count } linhas sem alterações
+}
+dry_run_create_note = Criar a nota «{ $name }»
+dry_run_update_note = Modificar «{ $name }»
+dry_run_append_note = Adicionar ao final de «{ $name }»
+dry_run_delete_note = Excluir «{ $name }»
+agent_plan_title = Plano
+agent_plan_pause = ⏸ Pausar após este passo
+agent_plan_pausing = Pausando…
+chat_branch_tooltip = Ramificar a conversa a partir daqui
+chat_branch_created = Novo ramo da conversa; a original continua salva
+chat_branch_needs_history = Ative o salvamento do histórico do chat para ramificar conversas
+chat_branch_error = Não foi possível ramificar a conversa
+chat_branch_of = Ramo da conversa #{ $id }
+chat_agent_invalid_arguments = Argumentos inválidos para { $tool }; pedindo ao modelo que os corrija
+ai_status_circuit_open = { $provider } em pausa · { $seconds } s
+ai_status_circuit_open_tooltip = O provedor de IA falhou várias vezes seguidas; será tentado de novo quando a contagem terminar
+offline_indicator = Sem conexão
+offline_indicator_tooltip = Sem rede: a IA na nuvem está desativada, a busca usa apenas texto e os embeddings aguardam na fila
+offline_agent_disabled = O modo agente precisa de conexão com a internet
+offline_chat_local_search = Sem conexão: a busca será feita nas suas notas
+offline_entered = Sem conexão: a IA na nuvem está em pausa
+offline_left = Conexão restabelecida
+offline_left_indexing = { $count ->
+    [one] Conexão restabelecida; indexando { $count } nota pendente
+   *[other] Conexão restabelecida; indexando { $count } notas pendentes
+}
+offline_chat_title = Sem conexão · notas correspondentes
+offline_chat_no_results = Nenhuma nota corresponde à mensagem
+chat_export_tooltip = Exportar conversas (JSONL)
+chat_export_done = Conversas exportadas
+chat_export_error = Não foi possível exportar as conversas
+preview_edit_failed = Não foi possível aplicar a edição; use o modo Inserir
+shortcut_split_preview = Editor e pré-visualização lado a lado
+preview_options = Opções da pré-visualização
+preview_density_comfortable = Espaçamento confortável
+preview_density_compact = Compacto
+preview_heading_anchors = Âncoras # nos títulos
+preview_print_friendly = Adequado para impressão
+preview_print_friendly_tooltip = Sem vídeos nem controles, texto escuro sobre fundo branco
+copy_heading_link = Copiar link para o título
+heading_link_copied = Link para o título copiado
+duplicate_note_title = Nota duplicada?
+duplicate_note_explanation = Já existe uma nota muito parecida. Você pode abri-la, adicionar o novo conteúdo ou criar a nota mesmo assim.
+duplicate_note_new = Nova nota
+duplicate_note_imported = Conteúdo importado
+duplicate_note_title_match = Título parecido
+duplicate_note_content_match = Conteúdo parecido
+duplicate_note_open = Abrir a existente
+duplicate_note_merge = Adicionar à existente
+duplicate_note_create = Criar mesmo assim
+vault_health = Saúde do cofre
+vault_health_largest = Maiores notas
+vault_health_stale = Sem alterações há mais de um ano
+vault_health_untagged = Sem tags
+vault_health_orphans = Órfãs (sem links)
+vault_health_search_index = No índice de busca
+vault_health_embeddings = Com embeddings
+vault_health_embeddings_disabled = A busca semântica está desativada
+vault_health_empty = Nada para revisar aqui
+backups = Backups
+backups_desc = Cópias compactadas das notas e do banco de dados. A pasta pode estar em outro disco ou em um remoto montado.
+backups_interval = Horas entre backups automáticos
+backups_keep = Manter
+backups_keep_daily = diários
+backups_keep_weekly = semanais
+backups_keep_monthly = mensais
+backups_change_folder = Alterar pasta
+backups_select_folder = Pasta dos backups
+backups_now = Fazer backup agora
+backup_restore = Restaurar backup…
+backup_restore_button = Restaurar
+backup_restore_confirm = Restaurar o backup de
+backup_restore_confirm_detail = A pasta de notas atual é mantida, renomeada, ao lado da restaurada.
+backup_none = Ainda não há backups
+backup_created = Backup criado
+backup_failed = Falha no backup
+backup_restored = Backup restaurado
+backup_restored_restart = Backup restaurado. Reinicie o NotNative para concluir a restauração do banco de dados
+backup_restore_failed = Não foi possível restaurar o backup
+read_later = Ler mais tarde
+remove_from_reading_list = Remover da lista de leitura
+reading_list = Lista de leitura
+reading_list_added = Adicionada à lista de leitura
+reading_list_removed = Removida da lista de leitura
+reading_list_error = Não foi possível atualizar a lista de leitura
+reading_list_sort = Ordenar por
+reading_list_sort_added = Data de adição
+reading_list_sort_length = Comprimento
+reading_list_empty = Nada para ler
+flashcards_review = Revisar cartões
+flashcards_show_answer = Mostrar resposta
+flashcards_again = De novo
+flashcards_hard = Difícil
+flashcards_good = Bom
+flashcards_easy = Fácil
+flashcards_days = d
+flashcards_remaining = { $count ->
+    [one] { $count } restante
+   *[other] { $count } restantes
+}
+flashcards_done = Nenhum cartão para revisar hoje
+flashcards_none = Ainda não há cartões. Escreva Q:: pergunta e A:: resposta numa nota, ou termine um título com ?
+meeting_note = Nota de reunião…
+meeting_note_title = Nova nota de reunião
+meeting_title_hint = Título da reunião
+meeting_attendees_hint = Participantes (Ana, Luis…)
+meeting_attendees_help = Separados por vírgulas; na nota cada participante é mencionado com @
+meeting_agenda = Pauta
+meeting_notes = Notas
+meeting_action_items = Ações
+meeting_extract_actions = Extrair ações…
+meeting_actions_empty = Não há tarefas pendentes na nota. A IA pode extraí-las da conversa.
+meeting_actions_no_due = Sem data (lembrete amanhã)
+meeting_actions_has_reminder = Já tem lembrete
+meeting_actions_suggest = Sugerir com IA
+meeting_actions_create_reminders = Criar lembretes
+meeting_actions_thinking = Procurando ações…
+meeting_actions_ai_error = A IA não conseguiu extrair as ações
+weekly_review = Revisão semanal
+weekly_review_this_week = Esta semana
+weekly_review_intro = Isto é o que aconteceu nas tuas notas desde segunda-feira. Revê e depois responde a cada secção.
+weekly_review_stats = Estatísticas
+weekly_review_created = Notas criadas
+weekly_review_modified = Notas modificadas
+weekly_review_words = Palavras nessas notas
+weekly_review_journal_days = Dias com diário
+weekly_review_open_tasks = Tarefas abertas
+weekly_review_completed_reminders = Lembretes concluídos
+weekly_review_none = Nada esta semana
+weekly_review_summary = Resumo
+weekly_review_summary_prompt = Como correu a semana, em poucas linhas?
+weekly_review_wins = Conquistas
+weekly_review_wins_prompt = O que correu bem? De que te orgulhas?
+weekly_review_challenges = Dificuldades
+weekly_review_challenges_prompt = O que ficou bloqueado ou demorou mais do que o previsto?
+weekly_review_next_week = Próxima semana
+weekly_review_next_week_prompt = Quais são as três prioridades da próxima semana?
+weekly_review_ai_draft = Rascunho com IA
+weekly_review_ai_error = A IA não conseguiu escrever o resumo
+weekly_review_back = Voltar
+weekly_review_next = Seguinte
+weekly_review_create = Criar nota
+weekly_review_exists = A revisão desta semana já existe
+compare_notes = Comparar notas
+compare_with = Comparar com…
+compare_with_current = Comparar com a versão atual
+compare_swap = Trocar
+compare_side_by_side = Lado a lado
+compare_identical = As duas notas são iguais
+compare_changes = blocos alterados
+compare_copy_left = Copiar esquerda
+compare_copy_right = Copiar direita
+compare_copy_diff = Copiar diff
+folder_ai_excluded = Ocultar da IA
+folder_ai_excluded_hint = As notas desta pasta e das suas subpastas não são indexadas, o agente não pode lê-las e não são sugeridas no chat. Uma nota isolada exclui-se com ai: false no frontmatter
+chat_note_ai_excluded = Esta nota está excluída da IA
+mcp_note_ai_excluded = A nota «{ $name }» está excluída da IA e não pode ser lida
+profiles = Perfis
+profiles_hint = Cada perfil guarda a pasta de notas, o provedor de IA, o tema e os backups. Ao trocar, as configurações atuais são salvas no perfil ativo.
+profiles_empty = Ainda não há perfis
+profile_active = Ativo
+profile_switch = Trocar
+profile_default_folder = Pasta padrão
+profile_name_hint = Nome do perfil (Trabalho, Pessoal...)
+profile_save_current = Salvar configurações atuais
+profile_saved = Perfil “{ $name }” salvo
+profile_switched = Perfil ativo: { $name }
+mounted_folders = Pastas montadas
+mounted_folders_desc = Pastas fora do cofre (docs de um repositório, uma pasta sincronizada) mostradas como pastas de primeiro nível: são indexadas, pesquisáveis e editáveis, mas ficam onde estão.
+mount_folder = Montar pasta…
+unmount_folder = Desmontar
+mount_unavailable = indisponível
+folder_mounted = Pasta montada
+folder_unmounted = Pasta desmontada
+refactor_invalid = Nomes inválidos para renomear
+refactor_no_matches = Nenhuma nota para alterar
+refactor_notes = Notas
+refactor_lines = Linhas
+refactor_apply = Renomear em todas
+refactor_applied = Renomeado em todas as notas
+refactor_failed = Falha ao renomear, nada foi alterado
+refactor_undone = Renomeação desfeita
+refactor_undo_failed = Não foi possível desfazer a renomeação
+copied_as_plain_text = Copiado como texto simples
+shortcut_copy_as_plain_text = Copiar como texto simples (sem markdown)
+plain_text_link_urls = Manter as URLs dos links ao copiar como texto simples
+word_goal_reached = Meta de palavras alcançada
+word_goal = Meta de palavras
+word_goals_today = Metas alcançadas hoje
+word_goals_week = Metas alcançadas (7 dias)
+scratch = Rascunho
+shortcut_scratch = Abrir ou fechar o rascunho (nunca é guardado)
+scratch_backup = Guardar uma cópia do rascunho para o recuperar após uma falha
+tray_compact_mode = Modo compacto
+shortcut_compact_mode = Janela pequena só com o editor (modo compacto)
+file_imported = Ficheiro importado para o cofre
+open_file_failed = Não foi possível abrir o ficheiro
+screenshot_title = Anotar captura
+screenshot_insert = Inserir
+screenshot_tool_arrow = Seta
+screenshot_tool_rect = Caixa
+screenshot_tool_text = Texto (clique onde colocá-lo)
+screenshot_text_placeholder = Texto e Enter
+screenshot_annotate = Anotar as capturas de ecrã (setas, caixas e texto) antes de as inserir
+shortcut_screenshot = Capturar uma zona do ecrã na nota
+tray_screenshot = Captura de ecrã para Quick Note
+ocr_offer = Oferecer extrair o texto das imagens ao inseri-las (OCR), para as encontrar na pesquisa
+ocr_engine_tesseract = tesseract (local)
+ocr_engine_ai = Modelo de IA com visão
+ocr_offer_toast = Extrair o texto da imagem?
+ocr_extract = Extrair texto
+ocr_running = A extrair o texto da imagem…
+ocr_done = Texto da imagem adicionado por baixo dela
+ocr_block_title = Texto da imagem
+ocr_no_text = Não foi encontrado texto na imagem
+ocr_no_image = Não há nenhuma imagem na linha do cursor
+ocr_image_missing = A imagem já não está na nota
+ocr_failed = Não foi possível extrair o texto da imagem
+ocr_tesseract_missing = O tesseract não está instalado (pacote tesseract)
+ocr_model_no_vision = O modelo de IA configurado não aceita imagens
+shortcut_ocr = Extrair o texto da imagem da linha (OCR)
+rename_empty = O nome não pode estar vazio
+rename_illegal_char = O nome não pode conter
+rename_hidden = O nome não pode começar com um ponto
+rename_taken = Já existe; Enter usará
+renamed_to = Renomeado para
+rename_failed = Não foi possível renomear
+open_in_file_manager = Abrir no gerenciador de arquivos
+confirm_delete = Tem certeza de que deseja excluir
+
+## Preferências
+theme = Tema
+theme_sync = O aplicativo sincroniza automaticamente com o tema Omarchy
+markdown_rendering = Renderização Markdown
+markdown_enabled = Ativado por padrão no modo Normal
+language = Idioma
+language_description = Escolha o idioma da interface
+restart_required = É necessário reiniciar o aplicativo
+start_in_background = Iniciar em segundo plano
+start_in_background_desc = Iniciar o aplicativo minimizado na bandeja do sistema
+format_toolbar = Barra de formatação
+format_toolbar_desc = Mostrar barra de formatação no modo de edição
+preview_adjust_images = Imagens no modo escuro
+preview_adjust_images_desc = Inverte diagramas e capturas com fundo branco na pré-visualização escura. Use ![](img.png){"{"}.no-invert{"}"} para excluir uma imagem
+recent_notes = Recentes
+navigation_history_end = Não há mais notas no histórico
+annotations = Anotações
+annotations_search = Pesquisar nos destaques
+annotations_empty = Ainda não há destaques. Selecione texto e prima = no modo Normal, ou escreva ==texto==
+paste_html_as_markdown = Colar HTML como markdown
+paste_html_as_markdown_desc = Converte o conteúdo copiado de um navegador ou documento em markdown limpo e transfere as imagens para os anexos. Ctrl+Shift+V cola sempre texto simples
+url_paste_behavior = Ao colar um link
+url_paste_ask = Perguntar se deve usar o título
+url_paste_title = Usar sempre o título da página
+url_paste_bare = Manter o link simples
+large_tap_targets = Botões grandes (toque)
+large_tap_targets_desc = Aumenta botões e linhas para usar a app com o dedo. Deslize a partir da margem esquerda para abrir a lista de notas, belisque a pré-visualização para mudar o tamanho do texto e mantenha premida uma nota para ver o seu menu
+embed_budget = Conteúdo incorporado
+embed_budget_desc = Máximo de vídeos carregados ao mesmo tempo na pré-visualização. Os vídeos fora da tela são descarregados; com 0 só carregam ao clicar
+plugins = Plugins
+plugin_error = Erro no plugin
+workspace = Diretório de trabalho
+workspace_description = Local onde as notas e recursos são salvos
+open_workspace_folder = Abrir pasta de trabalho
+change_workspace = Alterar local
+workspace_location = Local
+select_workspace_folder = Selecionar pasta de trabalho
+select = Selecionar
+audio_output = Saída de áudio
+audio_output_description = Selecione o dispositivo de saída de áudio
+audio_output_default = Padrão do sistema
+
+## Janela de preferências
+prefs_page_general = Geral
+prefs_page_editor = Editor
+prefs_page_ai = IA
+prefs_page_sync = Sincronização
+prefs_page_reminders = Lembretes
+prefs_page_music = Música
+prefs_page_advanced = Avançado
+prefs_search_placeholder = Pesquisar configurações...
+prefs_no_results = Nenhuma configuração corresponde à pesquisa
+prefs_revert = Reverter alterações
+prefs_revert_tooltip = Restaura as configurações de quando esta janela foi aberta
+prefs_mcp_desc = Permite que outros aplicativos e assistentes acessem suas notas
+prefs_mcp_details = Ver detalhes
+prefs_reminders_desc = Os lembretes aparecem no painel (Alt+R) e como notificações do sistema
+prefs_show_reminders = Ver lembretes
+prefs_config_file = Arquivo de configuração
+prefs_open_config_folder = Abrir pasta
+
+## Barra de status
+lines = linhas
+words = palavras
+characters = caracteres
+notes_count = { $count ->
+    [one] { $count } nota
+   *[other] { $count } notas
+}
+lines_count = { $count ->
+    [one] { $count } linha
+   *[other] { $count } linhas
+}
+words_count = { $count ->
+    [one] { $count } palavra
+   *[other] { $count } palavras
+}
+characters_count = { $count ->
+    [one] { $count } caractere
+   *[other] { $count } caracteres
+}
+note_statistics = Estatísticas da nota
+reading_time = Tempo de leitura
+characters_no_spaces = Caracteres sem espaços
+paragraphs = Parágrafos
+headings = Cabeçalhos
+links = Ligações
+code_blocks = Blocos de código
+images = Imagens
+saved = Salvo
+unsaved_changes = Alterações não salvas
+note_created = Nota criada
+note_deleted = Nota excluída
+note_renamed = Nota renomeada
+copied_as_rich_text = Copiado como texto formatado
+error = Erro
+success = Sucesso
+no_results = Nenhum resultado para
+searching = Pesquisando
+
+## TODOs
+todos = TODOs
+todos_note = TODOs da nota
+no_todos = Nenhum TODO nesta nota
+completed = concluído
+todo_progress = { $completed }/{ $count } { $count ->
+    [one] concluída
+   *[other] concluídas
+}
+todo_progress_subtasks = { $completed }/{ $count } { $count ->
+    [one] subtarefa
+   *[other] subtarefas
+}
+no_section = Sem seção
+
+## Pesquisa
+searching_ellipsis = 🔄 Pesquisando...
+no_results_found = ❌ Nenhum resultado
+found_relevant_notes = { $count ->
+    [one] { $count } nota relevante encontrada:
+   *[other] { $count } notas relevantes encontradas:
+}
+
+## Lembretes
+reminders_title = Lembretes
+reminders_new = Novo lembrete
+reminders_pending = Pendentes
+reminders_completed = Concluídos
+reminders_all = Todos
+reminder_complete = Concluir
+reminder_snooze = Adiar
+reminder_edit = Editar
+reminder_delete = Excluir
+reminders_empty = Sem lembretes
+reminders_history = Histórico
+reminders_history_search = Pesquisar no histórico…
+reminders_history_export = Exportar para CSV
+reminders_history_empty = Nenhum lembrete disparou ainda
+reminders_history_exported = Histórico exportado
+reminder_outcome_open = em aberto
+reminder_outcome_completed = concluído
+reminder_outcome_dismissed = descartado
+reminder_outcome_missed = perdido
+reminder_history_snoozed = adiado
+reminder_open_note = Abrir nota
+reminder_draft_title = Lembrete proposto
+reminder_draft_confirm = Criar
+reminder_draft_created = ✓ Lembrete criado
+reminder_draft_cancelled = Lembrete descartado
+reminder_draft_invalid_date = Data não reconhecida (p. ex. 2026-10-20 15:00 ou tomorrow 10:00)
+reminder_source_missing = A nota deste lembrete já não existe
+reminder_priority_low = Baixa
+reminder_priority_medium = Média
+reminder_priority_high = Alta
+reminder_priority_urgent = Urgente
+reminder_snooze_5min = 5 minutos
+reminder_snooze_15min = 15 minutos
+reminder_snooze_1hour = 1 hora
+reminder_snooze_tomorrow = Amanhã
+reminder_notification_title = 🔔 Lembrete
+reminder_create_title = Criar lembrete
+reminder_title_label = Título
+reminder_description_label = Descrição
+reminder_date_label = Data e hora
+reminder_priority_label = Prioridade
+reminder_repeat_label = Repetir
+reminder_repeat_none = Não repetir
+reminder_repeat_daily = Diariamente
+reminder_repeat_weekly = Semanalmente
+reminder_repeat_monthly = Mensalmente
+no_reminders = Sem lembretes
+reminder_overdue = Atrasado
+reminder_today = Hoje
+reminder_tomorrow = Amanhã
+reminder_created = Lembrete criado
+reminder_updated = Lembrete atualizado
+reminder_deleted = Lembrete excluído
+reminder_completed_msg = Lembrete concluído
+reminder_snoozed = Lembrete adiado
+reminder_tooltip = Lembretes (Alt+R)
+
+## Notas rápidas
+quick_notes_title = Nota rápida
+quick_note_back_to_list = Voltar à lista
+quick_note_new = Nova nota rápida
+quick_note_keep_visible = Manter visível
+quick_note_close = Fechar (Esc)
+quick_note_no_notes = Nenhuma nota rápida ainda
+quick_note_press_to_create = Pressione + para criar uma
+quick_note_saved = 💾 Salvo
+quick_note_unsaved = ● Não salvo
+quick_note_shortcut_hint = Ctrl+S: salvar | Esc: fechar
+quick_note_created = Nota rápida criada
+
+## Chat IA
+ai_chat = Chat IA
+chat_input_placeholder = Digite sua mensagem aqui...
+chat_send = Enviar
+chat_context = Contexto
+chat_attach_note = Anexar nota atual
+chat_attach_button = Anexar
+chat_clear_context = Limpar contexto
+chat_clear_history = Limpar histórico
+chat_new_session = Nova sessão
+chat_mode_agent = Modo: Agente
+chat_mode_normal = Modo: Normal
+ai_thinking = Pensando...
+you_label = Você
+
+## Bandeja do sistema
+tray_show_window = Mostrar janela
+tray_hide_window = Ocultar janela
+tray_quit = Sair
+
+## Dicas
+tooltip_show_hide_notes = Mostrar/ocultar lista de notas
+tooltip_new_note = Nova nota
+tooltip_close_esc = Fechar (Esc)
+tooltip_note_tags = Tags da nota
+tooltip_note_todos = TODOs da nota
+tooltip_music_player = Reprodutor de música
+tooltip_reminders = Lembretes (Alt+R)
+tooltip_settings = Configurações
+
+## Datas
+hour_format = Formato de hora
+hour_format_auto = Automático (conforme a região)
+
+## Registros
+logs = Registros
+logs_level_desc = Nível de detalhe do registro. Útil ao relatar problemas
+logs_view = Ver registros
+logs_empty = Ainda não há registros
+logs_follow = Acompanhar
+logs_copy = Copiar
+
+## Credenciais
+api_key_storage_keyring = 🔐 As chaves são guardadas no chaveiro do sistema
+api_key_storage_file = 🔐 Chaveiro indisponível: as chaves são guardadas criptografadas no diretório de dados
+
+## Áudio
+audio_output_detecting = Detectando dispositivos...
+audio_output_changed = Saída de áudio alterada
+audio_output_error = Erro ao alterar a saída de áudio
+
+## Atalhos de teclado
+shortcuts_general = Geral
+shortcuts_modes = Modos de edição
+shortcuts_navigation = Navegação
+shortcuts_editing = Edição
+shortcut_new_note = Criar nova nota
+shortcut_save = Salvar nota
+shortcut_search = Pesquisar notas
+shortcut_toggle_sidebar = Mostrar/ocultar barra lateral
+shortcut_escape = Voltar ao editor
+shortcut_insert_mode = Modo de inserção
+shortcut_normal_mode = Modo normal
+shortcut_command_mode = Modo de comando
+shortcut_visual_mode = Modo visual
+shortcut_movement = Esquerda/Baixo/Cima/Direita
+shortcut_next_word = Próxima palavra
+shortcut_prev_word = Palavra anterior
+shortcut_line_start = Início da linha
+shortcut_line_end = Fim da linha
+shortcut_doc_start = Início do documento
+shortcut_doc_end = Fim do documento
+shortcut_delete_char = Excluir caractere
+shortcut_delete_line = Excluir linha
+shortcut_undo = Desfazer
+shortcut_cycle_task_state = Alternar tarefa: [ ] → [/] → [x] → [-]
+shortcut_redo = Refazer
+shortcut_open_note_search = Abrir pesquisa de notas
+shortcut_toggle_ai_chat = Mostrar/ocultar chat de IA
+shortcut_delete_char_under = Excluir caractere sob o cursor
+shortcut_delete_line_complete = Excluir a linha inteira
+shortcut_delete_prev_char = Excluir caractere anterior
+shortcut_delete_next_char = Excluir próximo caractere
+shortcut_new_line = Nova linha
+shortcut_search_sidebar = Pesquisa e barra lateral
+shortcut_activate_search = Ativar pesquisa
+shortcut_close_search = Fechar pesquisa / Voltar ao editor
+shortcut_open_music = Abrir o player de música
+shortcut_open_reminders = Abrir lembretes
+shortcuts_global = 🌍 Global
+shortcuts_quick_notes = 📝 Notas rápidas
+shortcuts_normal_navigation = 📝 Modo normal - Navegação
+shortcuts_normal_editing = 📝 Modo normal - Edição e modos
+shortcuts_insert_mode = ✍️ Modo de inserção
+shortcuts_ai_chat = 🤖 Modo chat de IA
+shortcuts_sidebar = 📂 Barra lateral e listas
+shortcuts_floating_search = 🔍 Pesquisa flutuante
+shortcut_global_search = Abrir a pesquisa global flutuante
+shortcut_note_search = Pesquisar na nota atual
+shortcut_cycle_focus = Mover o foco entre barra lateral, editor e pesquisa
+shortcut_enter_ai_chat = Entrar no modo chat de IA
+shortcut_back_or_close = Voltar à lista / Fechar janela
+shortcut_left = Mover o cursor para a esquerda
+shortcut_down = Mover o cursor para baixo
+shortcut_up = Mover o cursor para cima
+shortcut_right = Mover o cursor para a direita
+shortcut_ai_chat_mode = Entrar no modo chat de IA
+shortcut_insert_table = Inserir tabela Markdown
+shortcut_insert_image = Inserir imagem
+shortcut_insert_sketch = Desenhar um esboço
+shortcut_insert_footnote = Inserir nota de rodapé
+shortcut_saveas = Salvar uma cópia da nota e abri-la
+shortcut_split_by_heading = Transformar cada seção em uma nota
+shortcut_navigate_history = Voltar / avançar pelas notas abertas
+shortcut_toggle_highlight = Destacar a seleção (repita para mudar a cor)
+shortcut_paste_plain_text = Colar como texto simples
+shortcut_copy_as_html = Copiar como texto formatado (HTML)
+shortcut_tab_autocomplete = Tab / Completar tag ou @
+shortcut_exit_chat = Sair do chat (voltar ao modo normal)
+shortcut_exit_chat_insert = Sair do chat e entrar no modo de inserção
+shortcut_send_message = Enviar mensagem
+shortcut_navigate_suggestions = Navegar pelas sugestões
+shortcut_accept_suggestion = Aceitar sugestão
+shortcut_next_note = Próxima nota
+shortcut_prev_note = Nota anterior
+shortcut_open_note = Abrir a nota ou pasta selecionada
+shortcut_focus_editor = Devolver o foco ao editor
+shortcut_toggle_semantic = Alternar pesquisa semântica (IA)
+shortcut_navigate_results = Navegar pelos resultados
+shortcut_open_selected = Abrir a nota selecionada
+
+## Sobre
+app_description = Editor de notas markdown no estilo Vim
+website = Site
+authors = Autores
+version = Versão
+license = Licença
+
+## Visualizador de imagens
+image_viewer = Visualizador de imagens
+open_file_location = Abrir local
+
+## YouTube
+transcribe_youtube = Transcrever o vídeo do YouTube?
+youtube_detected = Foi detectado um link do YouTube
+only_link = Só o link
+link_title_found = Título do link
+use_page_title = Usar título
+keep_bare_link = Manter link
+transcribe_and_insert = Transcrever e inserir
+downloading_transcript = Baixando a transcrição...
+loading_transcript = Carregando a transcrição...
+transcript_error = Erro ao obter a transcrição
+transcript_unavailable = Transcrição indisponível para este vídeo
+transcript_section = 📝 Transcrição
+youtube_unavailable = A transcrição do YouTube não está disponível no momento...
+
+## Player de música
+music_player = Player de música
+music_player_title = Player de música
+music_search_placeholder = Pesquisar música no YouTube...
+no_music_playing = Nenhuma música tocando
+music_play_pause = Reproduzir/Pausar (Espaço)
+music_stop = Parar
+music_seek_back = Voltar 5 s
+music_seek_forward = Avançar 5 s
+music_volume_down = Diminuir volume
+music_volume_up = Aumentar volume
+music_previous_song = Música anterior
+music_next_song = Próxima música
+music_repeat_off = Repetir: NÃO
+music_repeat_one = Repetir: UMA
+music_repeat_all = Repetir: TODAS
+music_shuffle_off = Aleatório: NÃO
+music_shuffle_on = Aleatório: SIM
+music_manage_playlists = Gerenciar playlists
+music_playback_queue = Fila de reprodução
+music_loading = Carregando...
+music_add_to_queue = Adicionar à fila
+music_remove_from_queue = Remover da fila
+music_new_playlist = Nova playlist
+music_load_playlist = Carregar playlist
+music_save_playlist = Salvar playlist
+music_playlist_name = Nome da playlist
+playlist_new = 🎵 Nova
+playlist_save = 💾 Salvar
+playlist_clear = 🗑️ Limpar
+playlist_queue = Fila de reprodução
+playlist_queue_empty = Fila vazia
+playlist_saved_playlists = Playlists salvas
+playlist_save_title = Salvar playlist
+playlist_name_prompt = Nome da playlist:
+playlist_name_example = ex.: Música relaxante
+playlist_no_loaded = Nenhuma playlist carregada
+playlist_no_saved = Nenhuma playlist salva
+playlist_add_to = Adicionar à playlist
+playlist_delete = Excluir playlist
+play = Reproduzir
+remove = Remover
+
+## Chat de IA e modo agente
+chat_model_default = Modelo: OpenAI GPT-4
+chat_subtitle = Combine suas notas com o assistente em tempo real
+chat_attach_note_dialog_title = Anexar nota ao contexto
+chat_clear_history_confirm_title = Excluir todo o histórico do chat?
+chat_clear_history_confirm_message = Esta ação exclui permanentemente todo o histórico de conversas salvo. Não é possível desfazer.
+chat_history_cleared = Histórico excluído
+chat_history_cleared_message = Todo o histórico de conversas foi excluído
+chat_mode_chat = Modo: Chat
+chat_toggle_mode_tooltip = Alternar: modo agente (com ferramentas) / chat normal (sem ferramentas)
+chat_agent_thinking = 💭 Pensamento
+chat_agent_action = 🔧 Ação
+chat_agent_observation = 👁️ Observação
+chat_agent_answer = ✅ Resposta
+ai_chat_placeholder = Escreva uma mensagem para o assistente de IA...
+ai_send_message = Enviar mensagem
+ai_model = Modelo
+ai_temperature = Temperatura
+ai_api_key = Chave de API
+ai_openai = OpenAI
+ai_openrouter = OpenRouter
+ai_api_key_placeholder = Digite sua chave de API...
+ai_save = Salvar
+ai_cancel = Cancelar
+ai_no_key_configured = Nenhuma chave de API configurada
+ai_configure_key = Configure a chave de API nas Preferências
+ai_free_models = ═══ MODELOS GRATUITOS ═══
+ai_paid_models = ═══ MODELOS PAGOS ═══
+agent_mode_activated =
+    Modo agente ativado
+    O assistente pode pesquisar nas notas e executar ações
+chat_mode_activated =
+    Chat normal ativado
+    Conversa direta sem acesso a ferramentas
+analyzing_task = Analisando a tarefa...
+no_notes_in_context = Nenhuma nota no contexto
+remove_from_context = Remover do contexto
+
+## Configurações de IA
+temperature_label = Temperatura:
+max_tokens_label = Máx. de tokens:
+unlimited = Ilimitado
+save_history_label = Salvar histórico:
+model_label = Modelo:
+refresh_models_tooltip = Atualizar a lista de modelos do OpenRouter
+search_model_placeholder = Pesquisar modelo...
+no_models_found = Nenhum modelo encontrado
+semantic_search_title = 🧠 Pesquisa semântica (embeddings)
+semantic_search_description = Configure os embeddings para pesquisar por significado com o OpenRouter
+enable_embeddings = Ativar embeddings:
+index_all_notes = 📄 Indexar todas as notas
+indexing = ⏳ Indexando...
+indexing_completed = Indexação concluída
+estimated_cost = Custo estimado: ~US$ 0,01 por 10.000 notas
+get_api_key_openrouter = Obter chave de API no OpenRouter
+
+## Mensagens MCP
+mcp_note_created = ✓ Nota '{}' criada com sucesso
+mcp_note_read = ✓ Nota '{}' lida com sucesso
+mcp_note_updated = ✓ Nota '{}' atualizada com sucesso
+mcp_note_deleted = ✓ Nota '{}' excluída com sucesso
+mcp_content_appended = ✓ Conteúdo adicionado a '{}' com sucesso
+mcp_notes_found = { $count ->
+    [one] ✓ { $count } nota encontrada
+   *[other] ✓ { $count } notas encontradas
+}
+mcp_search_results = { $count ->
+    [one] ✓ { $count } resultado para '{ $query }'
+   *[other] ✓ { $count } resultados para '{ $query }'
+}
+mcp_notes_with_tag = { $count ->
+    [one] ✓ { $count } nota com a tag #{ $tag }
+   *[other] ✓ { $count } notas com a tag #{ $tag }
+}
+mcp_tags_found = { $count ->
+    [one] ✓ { $count } tag encontrada
+   *[other] ✓ { $count } tags encontradas
+}
+mcp_tags_added = ✓ Tags adicionadas a '{}'
+mcp_note_renamed = ✓ Nota renomeada de '{}' para '{}'
+mcp_note_duplicated = ✓ Nota '{}' duplicada como '{}'
+mcp_folder_created = ✓ Pasta '{}' criada com sucesso
+mcp_project_created = ✓ Projeto '{ $folder }' criado a partir do modelo
+mcp_note_not_found = Nota '{}' não encontrada
+mcp_folders_found = { $count ->
+    [one] ✓ { $count } pasta encontrada
+   *[other] ✓ { $count } pastas encontradas
+}
+mcp_create_reminder_desc = Criar um lembrete com data, hora e prioridade
+mcp_list_reminders_desc = Listar todos os lembretes ou filtrar por estado
+mcp_update_reminder_desc = Atualizar um lembrete existente
+mcp_delete_reminder_desc = Excluir um lembrete
+mcp_snooze_reminder_desc = Adiar um lembrete por um tempo determinado
+mcp_complete_reminder_desc = Marcar um lembrete como concluído
+mcp_reminder_title_desc = Título do lembrete
+mcp_reminder_date_desc = Data e hora do lembrete (ex.: '2025-11-20 15:00', 'today 18:00', 'tomorrow')
+mcp_server_title = Servidor MCP - Model Context Protocol
+mcp_server_active = Servidor MCP ativo
+mcp_server_subtitle = Expondo as ferramentas do NotNative via HTTP
+mcp_status = Estado
+mcp_endpoints_available = Endpoints disponíveis
+status_active = 🟢 Ativo
+copy_url = 📋 Copiar URL
+view_docs = 📖 Ver documentação
+copied = ✓ Copiado!
+
+## Lembretes e notas rápidas
+reminder_linked_note_label = Nota vinculada
+reminders_count = { $count ->
+    [one] { $count } pendente
+   *[other] { $count } pendentes
+}
+quick_note_autosaved = 💾 Salvo automaticamente
+
+## Pesquisa e interface
+semantic_results = Resultados por similaridade semântica
+ai_analyzing = 🔄 O assistente de IA está analisando os resultados...
+assistant_response = 🧠 Resposta do assistente
+tooltip_change_search_mode = Ctrl para mudar de modo
+images_filter = Imagens
+unknown_error = Erro desconhecido
+operation_success = ✓ Operação concluída
+operation_failed = ✗ A operação falhou
+adding_content = Adicionando conteúdo...
+adding_tag = Adicionando tag...
+adding_tags = Adicionando tags...
+generating_index = Gerando índice...
+extracting_code = Extraindo código...
+
+## Bancos de dados
+base_add_filter = Adicionar filtro
+base_sort = Ordenar
+base_columns = Colunas
+base_columns_config = Configurar colunas
+base_data_source = Modo da fonte de dados
+base_formula_rows = Linhas de fórmulas
+base_formula_rows_title = Linhas de fórmulas
+base_add_formula_row = Adicionar linha de totais
+base_formula_row_label = Rótulo
+base_formula_help = Use fórmulas como no Excel: =SUM(B:B), =AVG(C1:C10)
+base_export_xlsx = Exportar para Excel
+base_export_xlsx_success = Exportado com sucesso
+base_export_xlsx_error = Erro ao exportar
+base_show_graph = Mostrar grafo de relações
+base_no_filters = Sem filtros
+base_current_columns = Colunas visíveis
+base_available_properties = Propriedades disponíveis
+base_toggle_visibility = Alternar visibilidade
+base_remove_column = Remover coluna
+base_add_column = Propriedades disponíveis
+base_properties_hint = Clique em + para adicionar como coluna
+base_add_as_column = Adicionar como coluna
+base_move_up = Mover para cima
+base_move_down = Mover para baixo
+base_data_source_title = Fonte de dados
+base_notes_mode = Notas
+base_grouped_mode = Registros agrupados
+base_grouped_hint =
+    Os registros agrupados mostram grupos de propriedades
+    inline como [game::X, bought::Y] como linhas
+base_search_placeholder = Pesquisar na tabela...
+base_no_notes = Nenhuma nota encontrada
+base_items = itens
+base_items_of = de
+base_title = Título
+base_created = Criado
+base_modified = Modificado
+base_tags = Tags
+base_search_properties = Pesquisar propriedades...
+base_no_available_props = Não há mais propriedades disponíveis
+base_sort_by = Ordenar por
+base_no_sorting = Sem ordenação
+base_sort_ascending = Ordem crescente
+base_sort_descending = Ordem decrescente
+base_add_filter_title = Adicionar filtro
+base_property = Propriedade
+base_operator = Operador
+base_value = Valor
+base_filter_value_placeholder = Valor do filtro...
+base_cancel = Cancelar
+base_apply_filter = Adicionar filtro
+filter_op_equals = igual a
+filter_op_not_equals = diferente de
+filter_op_contains = contém
+filter_op_not_contains = não contém
+filter_op_greater_than = maior que
+filter_op_greater_or_equal = maior ou igual a
+filter_op_less_than = menor que
+filter_op_less_or_equal = menor ou igual a
+filter_op_starts_with = começa com
+filter_op_ends_with = termina com
+filter_op_is_empty = está vazio
+filter_op_is_not_empty = não está vazio
@@ -10,6 +10,11 @@ fn main() {
         return;
     }
 
+    // Sin la interfaz (modo headless) no se enlaza libmpv
+    if std::env::var_os("CARGO_FEATURE_GUI").is_none() {
+        return;
+    }
+
     // Prevent the linker from dropping libmujs before libmpv references it.
     println!("cargo:rustc-link-arg=-Wl,--push-state,--no-as-needed");
 
@@ -0,0 +1,248 @@
+//! Plan de pasos del agente para las peticiones complejas
+//!
+//! Antes de actuar, el agente escribe el plan como lista numerada, con la
+//! herramienta de cada paso entre corchetes si usa una:
+//!
+//! ```text
+//! 1. [search_notes] Buscar las notas sobre Rust
+//! 2. [create_note] Crear el índice con enlaces a ellas
+//! 3. Resumir lo hecho
+//! ```
+//!
+//! El chat lo enseña como lista de tareas y marca cada paso cuando se ejecuta
+//! su herramienta.
+
+use serde::{Deserialize, Serialize};
+
+/// Máximo de pasos que se aceptan en un plan
+const MAX_STEPS: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StepStatus {
+    Pending,
+    Done,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlanStep {
+    pub description: String,
+    /// Herramienta que completa el paso (`None` = razonar o responder)
+    pub tool: Option<String>,
+    pub status: StepStatus,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AgentPlan {
+    pub steps: Vec<PlanStep>,
+}
+
+impl AgentPlan {
+    /// Lee el plan de la respuesta del modelo. `None` si no hay al menos dos
+    /// pasos (para una tarea de un paso no hace falta plan).
+    pub fn parse(text: &str) -> Option<Self> {
+        let steps: Vec<PlanStep> = text
+            .lines()
+            .filter_map(parse_step)
+            .take(MAX_STEPS)
+            .collect();
+        (steps.len() >= 2).then_some(Self { steps })
+    }
+
+    /// Marca el primer paso pendiente de `tool` (y los pasos sin herramienta
+    /// de antes, que ya han pasado). `false` si la herramienta no estaba en
+    /// el plan.
+    pub fn complete_tool(&mut self, tool: &str) -> bool {
+        let Some(index) = self.steps.iter().position(|step| {
+            step.status == StepStatus::Pending
+                && step
+                    .tool
+                    .as_deref()
+                    .is_some_and(|name| name.eq_ignore_ascii_case(tool))
+        }) else {
+            return false;
+        };
+        for step in &mut self.steps[..index] {
+            if step.tool.is_none() {
+                step.status = StepStatus::Done;
+            }
+        }
+        self.steps[index].status = StepStatus::Done;
+        true
+    }
+
+    /// Al responder quedan hechos los pasos sin herramienta; los que
+    /// necesitaban una y no se ejecutó siguen pendientes
+    pub fn finish(&mut self) {
+        for step in &mut self.steps {
+            if step.tool.is_none() {
+                step.status = StepStatus::Done;
+            }
+        }
+    }
+
+    pub fn done_count(&self) -> usize {
+        self.steps
+            .iter()
+            .filter(|step| step.status == StepStatus::Done)
+            .count()
+    }
+
+    /// Lista de tareas en markdown (`- [x] paso`)
+    pub fn to_checklist(&self) -> String {
+        self.steps
+            .iter()
+            .map(|step| {
+                let mark = match step.status {
+                    StepStatus::Done => 'x',
+                    StepStatus::Pending => ' ',
+                };
+                format!("- [{}] {}", mark, step.description)
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// `1. [tool] descripción` o `2) descripción`
+fn parse_step(line: &str) -> Option<PlanStep> {
+    let line = line.trim();
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    if digits == 0 {
+        return None;
+    }
+    let rest = line[digits..]
+        .strip_prefix('.')
+        .or_else(|| line[digits..].strip_prefix(')'))?
+        .trim_start();
+    // Sin los adornos de markdown que a veces añade el modelo
+    let rest = rest.trim_start_matches("**").trim_start();
+
+    let (tool, description) = match rest
+        .strip_prefix('[')
+        .and_then(|inner| inner.split_once(']'))
+    {
+        Some((tool, description)) => {
+            let tool = tool.trim().trim_matches('`');
+            (
+                (!tool.is_empty()).then(|| tool.to_string()),
+                description.trim(),
+            )
+        }
+        None => (None, rest),
+    };
+    let description = description.trim_matches('*').trim();
+    if description.is_empty() {
+        return None;
+    }
+    Some(PlanStep {
+        description: description.to_string(),
+        tool,
+        status: StepStatus::Pending,
+    })
+}
+
+/// ¿Pide la tarea varias cosas seguidas? Entonces merece un plan. Se decide
+/// por palabras de secuencia ("luego", "después", "then"...) o por venir ya
+/// como lista.
+pub fn is_complex_task(task: &str) -> bool {
+    const SEQUENCE_WORDS: &[&str] = &[
+        "luego",
+        "después",
+        "despues",
+        "además",
+        "ademas",
+        "finalmente",
+        "por último",
+        "y también",
+        "then",
+        "after that",
+        "afterwards",
+        "finally",
+        "and also",
+    ];
+
+    let lowered = task.to_lowercase();
+    let words = lowered.split_whitespace().count();
+    let list_items = task
+        .lines()
+        .filter(|line| {
+            let line = line.trim_start();
+            line.starts_with("- ") || line.starts_with("* ") || parse_step(line).is_some()
+        })
+        .count();
+    let sequenced = SEQUENCE_WORDS.iter().any(|word| {
+        lowered.match_indices(word).any(|(start, _)| {
+            let end = start + word.len();
+            !lowered[..start]
+                .chars()
+                .next_back()
+                .is_some_and(char::is_alphanumeric)
+                && !lowered[end..]
+                    .chars()
+                    .next()
+                    .is_some_and(char::is_alphanumeric)
+        })
+    });
+
+    list_items >= 2 || (sequenced && words >= 8) || words >= 40
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PLAN: &str = "Este es el plan:\n\
+        1. [search_notes] Buscar las notas sobre Rust\n\
+        2) **[create_note]** Crear el índice\n\
+        3. [`add_tag`] Etiquetar el índice\n\
+        4. Resumir lo hecho\n";
+
+    #[test]
+    fn test_parse_plan() {
+        let plan = AgentPlan::parse(PLAN).unwrap();
+        assert_eq!(plan.steps.len(), 4);
+        assert_eq!(plan.steps[0].tool.as_deref(), Some("search_notes"));
+        assert_eq!(plan.steps[0].description, "Buscar las notas sobre Rust");
+        assert_eq!(plan.steps[1].tool.as_deref(), Some("create_note"));
+        assert_eq!(plan.steps[2].tool.as_deref(), Some("add_tag"));
+        assert_eq!(plan.steps[3].tool, None);
+
+        assert!(AgentPlan::parse("1. Solo un paso").is_none());
+        assert!(AgentPlan::parse("Sin lista").is_none());
+    }
+
+    #[test]
+    fn test_complete_steps() {
+        let mut plan = AgentPlan::parse(PLAN).unwrap();
+        assert!(!plan.complete_tool("delete_note"));
+        assert!(plan.complete_tool("create_note"));
+        assert_eq!(plan.done_count(), 1);
+        // Un paso de herramienta saltado sigue pendiente
+        assert_eq!(plan.steps[0].status, StepStatus::Pending);
+
+        plan.finish();
+        assert_eq!(
+            plan.to_checklist(),
+            "- [ ] Buscar las notas sobre Rust\n\
+             - [x] Crear el índice\n\
+             - [ ] Etiquetar el índice\n\
+             - [x] Resumir lo hecho"
+        );
+    }
+
+    #[test]
+    fn test_is_complex_task() {
+        assert!(is_complex_task(
+            "Busca mis notas de Rust y luego crea un índice con enlaces a todas"
+        ));
+        assert!(is_complex_task(
+            "Haz esto:\n- ordena la carpeta\n- etiqueta"
+        ));
+        assert!(!is_complex_task("Crea una nota sobre Rust"));
+        assert!(!is_complex_task("¿Qué dice mi nota del viaje?"));
+        // "luego" dentro de otra palabra no cuenta
+        assert!(!is_complex_task(
+            "Resume la nota Luegones sobre los viajes a Italia"
+        ));
+    }
+}
@@ -0,0 +1,111 @@
+//! Notas que no ve la IA
+//!
+//! Con `ai: false` en el frontmatter una nota queda fuera de los embeddings,
+//! de lo que leen las herramientas del agente y de las sugerencias para
+//! adjuntar al chat; con `ai: false` en el `.folder.yaml` de una carpeta,
+//! todas las notas de dentro y de sus subcarpetas. A diferencia del color y
+//! el icono, `inherit: false` no corta la exclusión: una carpeta privada lo es
+//! entera.
+
+use std::path::Path;
+
+use crate::folder_meta::FolderMeta;
+use crate::frontmatter::Frontmatter;
+
+/// Campo del frontmatter (y de `.folder.yaml`)
+pub const AI_KEY: &str = "ai";
+
+/// La nota pide quedar fuera (`ai: false`; también `no` u `off`)
+pub fn note_ai_excluded(content: &str) -> bool {
+    let (frontmatter, _) = Frontmatter::parse_or_empty(content);
+    match frontmatter.custom.get(AI_KEY) {
+        Some(serde_yaml::Value::Bool(allowed)) => !allowed,
+        Some(serde_yaml::Value::String(value)) => {
+            matches!(value.trim().to_lowercase().as_str(), "false" | "no" | "off")
+        }
+        _ => false,
+    }
+}
+
+/// La carpeta `folder` (`"a/b"`, relativa a `notes_root`) o alguna de sus
+/// superiores tiene `ai: false`
+pub fn folder_ai_excluded(notes_root: &Path, folder: &str) -> bool {
+    let mut path = folder;
+    while !path.is_empty() {
+        if FolderMeta::load(&notes_root.join(path)).is_some_and(|meta| !meta.ai) {
+            return true;
+        }
+        path = path.rsplit_once('/').map_or("", |(parent, _)| parent);
+    }
+    false
+}
+
+/// La nota `note_name` (`"carpeta/nota"`) con contenido `content` queda fuera,
+/// por ella misma o por su carpeta
+pub fn is_ai_excluded(notes_root: &Path, note_name: &str, content: &str) -> bool {
+    note_ai_excluded(content)
+        || note_name
+            .rsplit_once('/')
+            .is_some_and(|(folder, _)| folder_ai_excluded(notes_root, folder))
+}
+
+/// Como [`is_ai_excluded`], pero a partir de la ruta del archivo. Si no se
+/// puede leer solo cuenta la carpeta.
+pub fn path_ai_excluded(notes_root: &Path, path: &Path) -> bool {
+    let content = std::fs::read_to_string(path).unwrap_or_default();
+    note_ai_excluded(&content)
+        || path
+            .parent()
+            .and_then(|dir| dir.strip_prefix(notes_root).ok())
+            .is_some_and(|folder| folder_ai_excluded(notes_root, &folder.to_string_lossy()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_note_ai_excluded() {
+        assert!(note_ai_excluded("---\nai: false\n---\n# Diario\n"));
+        assert!(note_ai_excluded("---\ntags: [a]\nai: no\n---\n"));
+        assert!(!note_ai_excluded("---\nai: true\n---\n"));
+        assert!(!note_ai_excluded("# Sin frontmatter\nai: false\n"));
+    }
+
+    #[test]
+    fn test_folder_ai_excluded() {
+        let root = std::env::temp_dir().join("test_ai_exclusion");
+        std::fs::remove_dir_all(&root).ok();
+        std::fs::create_dir_all(root.join("Personal/Salud")).unwrap();
+        std::fs::create_dir_all(root.join("Trabajo")).unwrap();
+        // `inherit: false` en la subcarpeta no la saca de la exclusión
+        FolderMeta {
+            ai: false,
+            ..Default::default()
+        }
+        .save(&root.join("Personal"))
+        .unwrap();
+        FolderMeta {
+            inherit: false,
+            ..Default::default()
+        }
+        .save(&root.join("Personal/Salud"))
+        .unwrap();
+
+        assert!(folder_ai_excluded(&root, "Personal/Salud"));
+        assert!(is_ai_excluded(&root, "Personal/Salud/analítica", "# x"));
+        assert!(!is_ai_excluded(&root, "Trabajo/plan", "# x"));
+        assert!(is_ai_excluded(&root, "plan", "---\nai: false\n---\n"));
+
+        let note = root.join("Trabajo/privada.md");
+        std::fs::write(&note, "---\nai: off\n---\n").unwrap();
+        assert!(path_ai_excluded(&root, &note));
+        assert!(path_ai_excluded(
+            &root,
+            &root.join("Personal/Salud/nueva.md")
+        ));
+        assert!(!path_ai_excluded(&root, &root.join("Trabajo/otra.md")));
+
+        std::fs::remove_dir_all(&root).ok();
+    }
+}
@@ -0,0 +1,162 @@
+//! Instrucciones de IA propias de una nota o carpeta
+//!
+//! Una nota puede pedir un trato concreto al asistente con `ai_system` en su
+//! frontmatter (`ai_system: "Eres un editor estricto..."`); una carpeta, con
+//! el mismo campo en su `.folder.yaml` o en su `.notnative.toml`
+//! ([`crate::folder_defaults`]), para todas las notas de dentro. La
+//! de la nota manda sobre la de su carpeta y la de una carpeta sobre las de
+//! las superiores. Estas instrucciones se añaden al prompt de sistema general,
+//! no lo sustituyen.
+
+use std::path::Path;
+
+use crate::folder_defaults::FolderDefaults;
+use crate::folder_meta::FolderMeta;
+use crate::frontmatter::Frontmatter;
+
+/// Campo del frontmatter (y de `.folder.yaml`) con las instrucciones
+pub const AI_SYSTEM_KEY: &str = "ai_system";
+
+/// Instrucciones del frontmatter de la nota, si las tiene
+pub fn note_ai_system(content: &str) -> Option<String> {
+    let (frontmatter, _) = Frontmatter::parse_or_empty(content);
+    frontmatter
+        .custom
+        .get(AI_SYSTEM_KEY)
+        .and_then(|value| value.as_str())
+        .map(|text| text.trim().to_string())
+        .filter(|text| !text.is_empty())
+}
+
+/// Instrucciones de la carpeta `folder` (`"a/b"`, relativa a `notes_root`)
+/// o de la más cercana de sus superiores que las defina
+pub fn folder_ai_system(notes_root: &Path, folder: &str) -> Option<String> {
+    let mut path = folder;
+    while !path.is_empty() {
+        let dir = notes_root.join(path);
+        let instructions = FolderMeta::load(&dir)
+            .and_then(|meta| meta.ai_system)
+            .or_else(|| FolderDefaults::load(&dir).and_then(|defaults| defaults.ai_system))
+            .map(|text| text.trim().to_string())
+            .filter(|text| !text.is_empty());
+        if instructions.is_some() {
+            return instructions;
+        }
+        path = path.rsplit_once('/').map_or("", |(parent, _)| parent);
+    }
+    None
+}
+
+/// Instrucciones que valen para la nota `note_name` (`"carpeta/nota"`) con
+/// contenido `content`: las suyas o las de su carpeta
+pub fn note_persona(notes_root: &Path, note_name: &str, content: &str) -> Option<String> {
+    note_ai_system(content).or_else(|| {
+        let (folder, _) = note_name.rsplit_once('/')?;
+        folder_ai_system(notes_root, folder)
+    })
+}
+
+/// Bloque para el prompt de sistema con las instrucciones de cada nota
+/// (nombre, instrucciones). Las repetidas, p. ej. de notas de la misma
+/// carpeta, salen una vez con todos sus nombres. `None` si no hay ninguna.
+pub fn persona_prompt(personas: &[(String, String)]) -> Option<String> {
+    let mut grouped: Vec<(Vec<&str>, &str)> = Vec::new();
+    for (name, instructions) in personas {
+        match grouped.iter_mut().find(|(_, text)| *text == instructions) {
+            Some((names, _)) => names.push(name),
+            None => grouped.push((vec![name], instructions)),
+        }
+    }
+    if grouped.is_empty() {
+        return None;
+    }
+
+    let mut prompt = String::from(
+        "Instrucciones específicas de las notas con las que trabajas \
+         (tienen prioridad sobre las generales cuando se refieran a esas notas):",
+    );
+    for (names, instructions) in grouped {
+        prompt.push_str(&format!("\n\n[{}]\n{}", names.join(", "), instructions));
+    }
+    Some(prompt)
+}
+
+/// Prompt de sistema general con las instrucciones de las notas detrás
+pub fn merge_system_prompt(base: &str, personas: &[(String, String)]) -> String {
+    match persona_prompt(personas) {
+        Some(persona) if base.is_empty() => persona,
+        Some(persona) => format!("{}\n\n{}", base, persona),
+        None => base.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_note_ai_system() {
+        let note = "---\nai_system: \"Eres un editor estricto.\"\ntags: [a]\n---\n# Texto\n";
+        assert_eq!(
+            note_ai_system(note).as_deref(),
+            Some("Eres un editor estricto.")
+        );
+        assert_eq!(note_ai_system("# Sin frontmatter\n"), None);
+        assert_eq!(note_ai_system("---\nai_system: \"  \"\n---\nx"), None);
+    }
+
+    #[test]
+    fn test_note_persona_falls_back_to_folders() {
+        let root = std::env::temp_dir().join("test_ai_persona");
+        std::fs::remove_dir_all(&root).ok();
+        std::fs::create_dir_all(root.join("Libro/Capítulos")).unwrap();
+        FolderMeta {
+            ai_system: Some("Corrige el estilo.".to_string()),
+            ..Default::default()
+        }
+        .save(&root.join("Libro"))
+        .unwrap();
+
+        assert_eq!(
+            note_persona(&root, "Libro/Capítulos/uno", "# Uno").as_deref(),
+            Some("Corrige el estilo.")
+        );
+        // La de la nota manda
+        assert_eq!(
+            note_persona(&root, "Libro/dos", "---\nai_system: Sé breve.\n---\n").as_deref(),
+            Some("Sé breve.")
+        );
+        assert_eq!(note_persona(&root, "suelta", "# Suelta"), None);
+
+        // También desde el `.notnative.toml` de la carpeta
+        std::fs::create_dir_all(root.join("Diario")).unwrap();
+        std::fs::write(
+            root.join("Diario")
+                .join(crate::folder_defaults::FOLDER_DEFAULTS_FILE),
+            "ai_system = \"Sé amable.\"",
+        )
+        .unwrap();
+        assert_eq!(
+            note_persona(&root, "Diario/hoy", "# Hoy").as_deref(),
+            Some("Sé amable.")
+        );
+
+        std::fs::remove_dir_all(&root).ok();
+    }
+
+    #[test]
+    fn test_merge_system_prompt() {
+        assert_eq!(merge_system_prompt("Base", &[]), "Base");
+
+        let personas = vec![
+            ("Libro/uno".to_string(), "Corrige el estilo.".to_string()),
+            ("Libro/dos".to_string(), "Corrige el estilo.".to_string()),
+            ("diario".to_string(), "Sé amable.".to_string()),
+        ];
+        let merged = merge_system_prompt("Base", &personas);
+        assert!(merged.starts_with("Base\n\nInstrucciones específicas"));
+        assert!(merged.contains("[Libro/uno, Libro/dos]\nCorrige el estilo."));
+        assert!(merged.ends_with("[diario]\nSé amable."));
+        assert_eq!(merged.matches("Corrige el estilo.").count(), 1);
+    }
+}
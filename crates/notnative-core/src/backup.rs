@@ -0,0 +1,482 @@
+//! Copias de seguridad programadas del vault
+//!
+//! Cada copia es un `.tar.gz` con la carpeta de notas y una instantánea de
+//! la base de datos (`VACUUM INTO`, así no importa que la app la tenga
+//! abierta). Se guardan en una carpeta configurable, que puede estar en otro
+//! disco o en un remoto montado (sshfs, rclone...). Tras cada copia se borran
+//! las que ya no hacen falta según la retención: la última de cada uno de
+//! los últimos N días, semanas y meses.
+//!
+//! Al restaurar, la carpeta de notas actual se aparta (no se borra) y la
+//! base de datos se copia sobre la abierta con la API de backup de SQLite
+//! ([`apply_pending_restore_live`]). Si eso falla, queda preparada para el
+//! siguiente arranque.
+
+use anyhow::{Context, Result};
+use chrono::{Datelike, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const FILE_PREFIX: &str = "notnative-backup-";
+const FILE_SUFFIX: &str = ".tar.gz";
+const TIMESTAMP_FORMAT: &str = "%Y-%m-%d_%H-%M-%S";
+
+/// Nombre de la instantánea de la base de datos dentro de la copia
+const DB_ENTRY: &str = "notes.db";
+
+/// Configuración de las copias (`backup` en la configuración)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BackupConfig {
+    pub enabled: bool,
+    /// Horas entre copias
+    pub interval_hours: u32,
+    /// Carpeta de las copias; sin ella, `~/.local/share/notnative/backups`
+    pub directory: Option<String>,
+    pub keep_daily: usize,
+    pub keep_weekly: usize,
+    pub keep_monthly: usize,
+}
+
+impl Default for BackupConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval_hours: 24,
+            directory: None,
+            keep_daily: 7,
+            keep_weekly: 4,
+            keep_monthly: 6,
+        }
+    }
+}
+
+impl BackupConfig {
+    /// Carpeta donde se guardan las copias
+    pub fn backup_dir(&self) -> PathBuf {
+        match self.directory.as_deref().map(str::trim) {
+            Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+            _ => default_backup_dir(),
+        }
+    }
+
+    /// ¿Toca hacer una copia? Sí si no hay ninguna o la última tiene más
+    /// de `interval_hours`
+    pub fn is_due(&self, latest: Option<NaiveDateTime>, now: NaiveDateTime) -> bool {
+        latest.is_none_or(|latest| {
+            now.signed_duration_since(latest).num_minutes()
+                >= i64::from(self.interval_hours.max(1)) * 60
+        })
+    }
+}
+
+pub fn default_backup_dir() -> PathBuf {
+    dirs::data_local_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("notnative")
+        .join("backups")
+}
+
+/// Copia existente
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackupInfo {
+    pub path: PathBuf,
+    pub created: NaiveDateTime,
+    /// Tamaño en bytes
+    pub size: u64,
+}
+
+pub fn backup_file_name(created: NaiveDateTime) -> String {
+    format!(
+        "{}{}{}",
+        FILE_PREFIX,
+        created.format(TIMESTAMP_FORMAT),
+        FILE_SUFFIX
+    )
+}
+
+/// Fecha de una copia a partir del nombre de su archivo
+pub fn parse_backup_file_name(name: &str) -> Option<NaiveDateTime> {
+    let stamp = name.strip_prefix(FILE_PREFIX)?.strip_suffix(FILE_SUFFIX)?;
+    NaiveDateTime::parse_from_str(stamp, TIMESTAMP_FORMAT).ok()
+}
+
+/// Copias de una carpeta, de la más reciente a la más antigua
+pub fn list_backups(dir: &Path) -> Result<Vec<BackupInfo>> {
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut backups = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        let Some(created) = parse_backup_file_name(&name.to_string_lossy()) else {
+            continue;
+        };
+        backups.push(BackupInfo {
+            path: entry.path(),
+            created,
+            size: entry.metadata().map(|meta| meta.len()).unwrap_or(0),
+        });
+    }
+    backups.sort_by_key(|backup| Reverse(backup.created));
+    Ok(backups)
+}
+
+/// Copias que sobran según la retención (de `dates`, devuelve los índices).
+/// Se queda la más reciente de cada uno de los últimos `keep_daily` días,
+/// `keep_weekly` semanas y `keep_monthly` meses con copia, y siempre la
+/// última.
+pub fn backups_to_prune(dates: &[NaiveDateTime], config: &BackupConfig) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..dates.len()).collect();
+    order.sort_by(|a, b| dates[*b].cmp(&dates[*a]));
+
+    let mut keep: HashSet<usize> = order.first().copied().into_iter().collect();
+    let mut keep_newest_per = |limit: usize, period: &dyn Fn(&NaiveDateTime) -> (i32, u32)| {
+        let mut seen = Vec::new();
+        for &index in &order {
+            let key = period(&dates[index]);
+            if seen.contains(&key) {
+                continue;
+            }
+            if seen.len() == limit {
+                break;
+            }
+            seen.push(key);
+            keep.insert(index);
+        }
+    };
+    keep_newest_per(config.keep_daily, &|date| (date.year(), date.ordinal()));
+    keep_newest_per(config.keep_weekly, &|date| {
+        let week = date.iso_week();
+        (week.year(), week.week())
+    });
+    keep_newest_per(config.keep_monthly, &|date| (date.year(), date.month()));
+
+    let mut prune: Vec<usize> = (0..dates.len())
+        .filter(|index| !keep.contains(index))
+        .collect();
+    prune.sort_unstable();
+    prune
+}
+
+/// Crea una copia de `notes_root` y `db_path` en `backup_dir`
+pub fn create_backup(
+    notes_root: &Path,
+    db_path: &Path,
+    backup_dir: &Path,
+    now: NaiveDateTime,
+) -> Result<PathBuf> {
+    let vault_parent = notes_root
+        .parent()
+        .context("La carpeta de notas no tiene carpeta padre")?;
+    let vault_name = notes_root
+        .file_name()
+        .context("La carpeta de notas no tiene nombre")?;
+    fs::create_dir_all(backup_dir).context("No se pudo crear la carpeta de copias")?;
+
+    let archive = backup_dir.join(backup_file_name(now));
+    let partial = archive.with_extension("partial");
+    let staging = backup_dir.join(format!(".staging-{}", std::process::id()));
+    fs::create_dir_all(&staging)?;
+
+    let result = (|| -> Result<()> {
+        let mut tar = Command::new("tar");
+        tar.arg("-czf")
+            .arg(&partial)
+            .arg("-C")
+            .arg(vault_parent)
+            .arg(vault_name);
+
+        if db_path.exists() {
+            let snapshot = staging.join(DB_ENTRY);
+            rusqlite::Connection::open(db_path)?
+                .execute(
+                    "VACUUM INTO ?1",
+                    rusqlite::params![snapshot.to_string_lossy()],
+                )
+                .context("No se pudo copiar la base de datos")?;
+            tar.arg("-C").arg(&staging).arg(DB_ENTRY);
+        }
+
+        let output = tar.output().context("No se pudo ejecutar tar")?;
+        if !output.status.success() {
+            anyhow::bail!(
+                "tar falló: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        fs::rename(&partial, &archive)?;
+        Ok(())
+    })();
+
+    fs::remove_dir_all(&staging).ok();
+    if result.is_err() {
+        fs::remove_file(&partial).ok();
+    }
+    result.map(|()| archive)
+}
+
+/// Borra las copias que sobran según la retención; devuelve cuántas
+pub fn prune_backups(backup_dir: &Path, config: &BackupConfig) -> Result<usize> {
+    let backups = list_backups(backup_dir)?;
+    let dates: Vec<NaiveDateTime> = backups.iter().map(|backup| backup.created).collect();
+    let prune = backups_to_prune(&dates, config);
+    for &index in &prune {
+        fs::remove_file(&backups[index].path)
+            .with_context(|| format!("No se pudo borrar {}", backups[index].path.display()))?;
+    }
+    Ok(prune.len())
+}
+
+/// Base de datos restaurada que se aplica en el siguiente arranque
+pub fn pending_restore_path(db_path: &Path) -> PathBuf {
+    db_path.with_extension("db.restore")
+}
+
+/// Restaura una copia. La carpeta de notas actual se renombra (se devuelve
+/// su nueva ruta) y la base de datos queda pendiente hasta
+/// [`apply_pending_restore_live`] o [`apply_pending_restore`].
+pub fn restore_backup(archive: &Path, notes_root: &Path, db_path: &Path) -> Result<PathBuf> {
+    let vault_parent = notes_root
+        .parent()
+        .context("La carpeta de notas no tiene carpeta padre")?;
+    let staging = vault_parent.join(format!(".notnative-restore-{}", std::process::id()));
+    fs::create_dir_all(&staging)?;
+
+    let result = (|| -> Result<PathBuf> {
+        let output = Command::new("tar")
+            .arg("-xzf")
+            .arg(archive)
+            .arg("-C")
+            .arg(&staging)
+            .output()
+            .context("No se pudo ejecutar tar")?;
+        if !output.status.success() {
+            anyhow::bail!(
+                "tar falló: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+
+        // La única carpeta de la copia es el vault
+        let restored_vault = fs::read_dir(&staging)?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .find(|path| path.is_dir())
+            .context("La copia no contiene la carpeta de notas")?;
+
+        let stamp = chrono::Local::now().format(TIMESTAMP_FORMAT);
+        let previous = vault_parent.join(format!(
+            "{}.before-restore-{}",
+            notes_root
+                .file_name()
+                .map(|name| name.to_string_lossy())
+                .unwrap_or_default(),
+            stamp
+        ));
+        let moved_aside = notes_root.exists();
+        if moved_aside {
+            fs::rename(notes_root, &previous)
+                .context("No se pudo apartar la carpeta de notas actual")?;
+        }
+        if let Err(e) = fs::rename(&restored_vault, notes_root) {
+            // Sin la copia en su sitio, la carpeta de notas vuelve a estar donde estaba
+            if moved_aside {
+                fs::rename(&previous, notes_root)
+                    .context("No se pudo devolver la carpeta de notas a su sitio")?;
+            }
+            return Err(e).context("No se pudo poner en su sitio la carpeta restaurada");
+        }
+
+        let restored_db = staging.join(DB_ENTRY);
+        if restored_db.exists() {
+            fs::copy(&restored_db, pending_restore_path(db_path))?;
+        }
+        Ok(previous)
+    })();
+
+    fs::remove_dir_all(&staging).ok();
+    result
+}
+
+/// Pone en su sitio la base de datos de una restauración, antes de abrirla.
+/// Devuelve si había una pendiente.
+pub fn apply_pending_restore(db_path: &Path) -> Result<bool> {
+    let pending = pending_restore_path(db_path);
+    if !pending.exists() {
+        return Ok(false);
+    }
+    for suffix in ["-wal", "-shm"] {
+        let mut journal = db_path.as_os_str().to_owned();
+        journal.push(suffix);
+        fs::remove_file(PathBuf::from(journal)).ok();
+    }
+    fs::rename(&pending, db_path).context("No se pudo aplicar la base de datos restaurada")?;
+    Ok(true)
+}
+
+/// Copia la base de datos de una restauración sobre la que está abierta, sin
+/// reiniciar: las demás conexiones (app, servidor MCP, file watcher) ven el
+/// contenido restaurado. Si falla, sigue pendiente para el próximo arranque.
+/// Devuelve si había una pendiente.
+pub fn apply_pending_restore_live(db_path: &Path) -> Result<bool> {
+    let pending = pending_restore_path(db_path);
+    if !pending.exists() {
+        return Ok(false);
+    }
+    let mut conn = crate::db_pool::open_connection(db_path)?;
+    conn.restore(
+        rusqlite::DatabaseName::Main,
+        &pending,
+        None::<fn(rusqlite::backup::Progress)>,
+    )
+    .context("No se pudo aplicar la base de datos restaurada")?;
+    drop(conn);
+    // Una copia hecha con una versión anterior trae un esquema más viejo
+    crate::NotesDatabase::new(db_path)?;
+    fs::remove_file(&pending)?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(text: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(text, "%Y-%m-%d %H:%M").unwrap()
+    }
+
+    #[test]
+    fn test_backup_file_name() {
+        let created = date("2025-03-09 14:05");
+        let name = backup_file_name(created);
+        assert_eq!(name, "notnative-backup-2025-03-09_14-05-00.tar.gz");
+        assert_eq!(parse_backup_file_name(&name), Some(created));
+        assert_eq!(parse_backup_file_name("otra-cosa.tar.gz"), None);
+    }
+
+    #[test]
+    fn test_is_due() {
+        let config = BackupConfig {
+            interval_hours: 6,
+            ..Default::default()
+        };
+        let now = date("2025-03-09 12:00");
+        assert!(config.is_due(None, now));
+        assert!(!config.is_due(Some(date("2025-03-09 07:00")), now));
+        assert!(config.is_due(Some(date("2025-03-09 06:00")), now));
+    }
+
+    #[test]
+    fn test_retention() {
+        let config = BackupConfig {
+            keep_daily: 2,
+            keep_weekly: 2,
+            keep_monthly: 2,
+            ..Default::default()
+        };
+        let dates = vec![
+            date("2025-03-09 18:00"), // 0: la última
+            date("2025-03-09 09:00"), // 1: mismo día, sobra
+            date("2025-03-08 18:00"), // 2: segundo día
+            date("2025-03-07 18:00"), // 3: sobra (ya hay 2 días y es la misma semana)
+            date("2025-03-01 18:00"), // 4: la semana anterior
+            date("2025-02-20 18:00"), // 5: febrero
+            date("2025-01-20 18:00"), // 6: sobra (ya hay 2 meses)
+        ];
+        assert_eq!(backups_to_prune(&dates, &config), vec![1, 3, 6]);
+
+        // Sin retención se queda al menos la última
+        let none = BackupConfig {
+            keep_daily: 0,
+            keep_weekly: 0,
+            keep_monthly: 0,
+            ..Default::default()
+        };
+        assert_eq!(backups_to_prune(&dates, &none), vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_create_and_restore() {
+        let base =
+            std::env::temp_dir().join(format!("notnative-backup-test-{}", std::process::id()));
+        fs::remove_dir_all(&base).ok();
+        let notes = base.join("notes");
+        fs::create_dir_all(notes.join("ideas")).unwrap();
+        fs::write(notes.join("ideas/plan.md"), "# Plan").unwrap();
+        let db_path = base.join("notes.db");
+        rusqlite::Connection::open(&db_path)
+            .unwrap()
+            .execute_batch("CREATE TABLE t (x); INSERT INTO t VALUES (1);")
+            .unwrap();
+        let backup_dir = base.join("backups");
+
+        let archive =
+            create_backup(&notes, &db_path, &backup_dir, date("2025-03-09 12:00")).unwrap();
+        let backups = list_backups(&backup_dir).unwrap();
+        assert_eq!(backups.len(), 1);
+        assert_eq!(backups[0].path, archive);
+
+        // Cambios después de la copia
+        fs::write(notes.join("ideas/plan.md"), "# Cambiado").unwrap();
+
+        let previous = restore_backup(&archive, &notes, &db_path).unwrap();
+        assert_eq!(
+            fs::read_to_string(notes.join("ideas/plan.md")).unwrap(),
+            "# Plan"
+        );
+        assert_eq!(
+            fs::read_to_string(previous.join("ideas/plan.md")).unwrap(),
+            "# Cambiado"
+        );
+        assert!(apply_pending_restore(&db_path).unwrap());
+        assert!(!apply_pending_restore(&db_path).unwrap());
+        let count: i64 = rusqlite::Connection::open(&db_path)
+            .unwrap()
+            .query_row("SELECT COUNT(*) FROM t", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 1);
+
+        fs::remove_dir_all(&base).ok();
+    }
+
+    #[test]
+    fn test_apply_pending_restore_live() {
+        let base =
+            std::env::temp_dir().join(format!("notnative-backup-live-test-{}", std::process::id()));
+        fs::remove_dir_all(&base).ok();
+        fs::create_dir_all(&base).unwrap();
+        let db_path = base.join("notes.db");
+        let db = crate::NotesDatabase::new(&db_path).unwrap();
+        db.index_note("vieja", "/notes/vieja.md", "# Vieja", None)
+            .unwrap();
+        // Otra conexión abierta, como la del file watcher
+        let other = db.clone_connection();
+
+        let restored_path = base.join("restored.db");
+        let restored = crate::NotesDatabase::new(&restored_path).unwrap();
+        restored
+            .index_note("restaurada", "/notes/restaurada.md", "# Restaurada", None)
+            .unwrap();
+        rusqlite::Connection::open(&restored_path)
+            .unwrap()
+            .execute(
+                "VACUUM INTO ?1",
+                rusqlite::params![pending_restore_path(&db_path).to_string_lossy()],
+            )
+            .unwrap();
+
+        assert!(apply_pending_restore_live(&db_path).unwrap());
+        assert!(!pending_restore_path(&db_path).exists());
+        assert!(other.get_note("restaurada").unwrap().is_some());
+        assert!(other.get_note("vieja").unwrap().is_none());
+        assert!(!apply_pending_restore(&db_path).unwrap());
+
+        fs::remove_dir_all(&base).ok();
+    }
+}
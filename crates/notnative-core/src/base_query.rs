@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 use std::path::Path;
 
-use super::base::{Base, BaseView, FilterGroup, SortConfig, SortDirection};
+use super::base::{Base, BaseView, SortConfig, SortDirection};
 use super::database::{NoteMetadata, NotesDatabase, Result as DbResult};
 use super::property::PropertyValue;
 
@@ -36,6 +36,10 @@ impl NoteWithProperties {
 /// Motor de queries para Bases
 pub struct BaseQueryEngine<'a> {
     db: &'a NotesDatabase,
+    #[allow(
+        dead_code,
+        reason = "las vistas aún no resuelven rutas relativas a la raíz"
+    )]
     notes_root: &'a Path,
 }
 
@@ -368,7 +372,7 @@ pub struct PropertyAggregation {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::base::{Filter, FilterGroup, FilterOperator};
+    use crate::base::{Filter, FilterGroup};
 
     fn make_test_note(name: &str, props: HashMap<String, PropertyValue>) -> NoteWithProperties {
         NoteWithProperties {
@@ -5,8 +5,8 @@
 //! a los archivos .md correspondientes.
 
 use std::fs;
-use std::path::Path;
 use thiserror::Error;
+use tracing::{error, warn};
 
 use super::database::NotesDatabase;
 use super::inline_property::InlinePropertyParser;
@@ -98,8 +98,8 @@ impl<'a> BaseWriter<'a> {
             let char_end = char_end as usize;
 
             if char_end > content.len() || char_start >= char_end {
-                eprintln!(
-                    "⚠️ Posiciones inválidas para grupo: {}..{} en contenido de {} bytes",
+                warn!(
+                    "Posiciones inválidas para grupo: {}..{} en contenido de {} bytes",
                     char_start,
                     char_end,
                     content.len()
@@ -112,8 +112,8 @@ impl<'a> BaseWriter<'a> {
 
             // Verificar que es un grupo válido (empieza con [ y termina con ])
             if !group_text.starts_with('[') || !group_text.ends_with(']') {
-                eprintln!(
-                    "⚠️ Grupo inválido en posición {}..{}: '{}'",
+                warn!(
+                    "Grupo inválido en posición {}..{}: '{}'",
                     char_start, char_end, group_text
                 );
                 continue;
@@ -130,7 +130,7 @@ impl<'a> BaseWriter<'a> {
                     content = new_content;
                 }
                 Err(e) => {
-                    eprintln!("⚠️ Error al reemplazar propiedad en grupo: {:?}", e);
+                    error!("Error al reemplazar propiedad en grupo: {:?}", e);
                     continue;
                 }
             }
@@ -342,8 +342,6 @@ impl<'a> BaseWriter<'a> {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
     /// Función auxiliar para testear la lógica pura de append_property_to_group
     /// sin necesidad de una instancia de BaseWriter
     fn append_property_to_group_pure(group_text: &str, key: &str, value: &str) -> String {
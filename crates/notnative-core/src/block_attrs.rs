@@ -0,0 +1,186 @@
+//! Atributos de bloque estilo pandoc
+//!
+//! `## Encabezado {#id .clase}` y los bloques `::: aviso` ... `:::` llevan
+//! sus clases al HTML del preview, para darles estilo desde el CSS propio
+//! (`preview.css`, ver [`crate::NotesConfig::preview_css_path`]). Solo se
+//! aceptan IDs y clases con letras, números, `-` y `_`, así que nunca hace
+//! falta escaparlos.
+
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// `::: clase`, `::: {.clase #id}` (con `:::` opcionales al final)
+static FENCED_DIV_OPEN_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^:{3,}\s*(\{[^}]*\}|[\w-]+)\s*:*\s*$").unwrap());
+
+/// Línea que cierra el último bloque abierto
+static FENCED_DIV_CLOSE_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^:{3,}\s*$").unwrap());
+
+/// `{#id .clase}` al final de un texto
+static TRAILING_ATTRIBUTES_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\s*(\{[^{}]*\})\s*$").unwrap());
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BlockAttributes {
+    pub id: Option<String>,
+    pub classes: Vec<String>,
+}
+
+fn is_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+}
+
+impl BlockAttributes {
+    /// `{#id .clase .otra clave=valor}`. Los `clave=valor` se ignoran; `None`
+    /// si hay algo más o no queda ningún ID ni clase.
+    pub fn parse(text: &str) -> Option<Self> {
+        let inner = text.trim().strip_prefix('{')?.strip_suffix('}')?;
+        let mut attributes = Self::default();
+        for token in inner.split_whitespace() {
+            if let Some(id) = token.strip_prefix('#') {
+                if !is_name(id) {
+                    return None;
+                }
+                attributes.id = Some(id.to_string());
+            } else if let Some(class) = token.strip_prefix('.') {
+                if !is_name(class) {
+                    return None;
+                }
+                attributes.classes.push(class.to_string());
+            } else if !token.contains('=') {
+                return None;
+            }
+        }
+        (attributes.id.is_some() || !attributes.classes.is_empty()).then_some(attributes)
+    }
+
+    /// ` id="..." class="..."` para abrir una etiqueta HTML
+    fn to_html(&self) -> String {
+        let mut html = String::new();
+        if let Some(id) = &self.id {
+            html.push_str(&format!(r#" id="{}""#, id));
+        }
+        if !self.classes.is_empty() {
+            html.push_str(&format!(r#" class="{}""#, self.classes.join(" ")));
+        }
+        html
+    }
+}
+
+/// Separa `Texto {.clase}` en el texto y sus atributos. Si lo del final no
+/// son atributos válidos, el texto queda entero.
+pub fn split_attributes(text: &str) -> (&str, Option<BlockAttributes>) {
+    if let Some(caps) = TRAILING_ATTRIBUTES_RE.captures(text) {
+        if let Some(attributes) = BlockAttributes::parse(&caps[1]) {
+            let start = caps.get(0).map_or(text.len(), |m| m.start());
+            return (&text[..start], Some(attributes));
+        }
+    }
+    (text, None)
+}
+
+/// Reescribe los bloques `::: clase` como `<div class="clase">` que envuelve
+/// su cuerpo, separado por líneas en blanco para que se siga procesando como
+/// markdown (igual que los callouts). Se pueden anidar; los que quedan sin
+/// cerrar se cierran al final. Los bloques de código se respetan.
+pub fn render_fenced_divs(markdown: &str) -> String {
+    if !markdown.contains(":::") {
+        return markdown.to_string();
+    }
+
+    let mut output: Vec<String> = Vec::new();
+    let mut in_code_block = false;
+    let mut open = 0;
+
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+        }
+        if in_code_block {
+            output.push(line.to_string());
+            continue;
+        }
+
+        if open > 0 && FENCED_DIV_CLOSE_RE.is_match(line) {
+            open -= 1;
+            output.push(String::new());
+            output.push("</div>".to_string());
+            output.push(String::new());
+            continue;
+        }
+
+        let attributes = FENCED_DIV_OPEN_RE.captures(line).and_then(|caps| {
+            let spec = &caps[1];
+            if spec.starts_with('{') {
+                BlockAttributes::parse(spec)
+            } else {
+                Some(BlockAttributes {
+                    id: None,
+                    classes: vec![spec.to_string()],
+                })
+            }
+        });
+        match attributes {
+            Some(attributes) => {
+                open += 1;
+                output.push(String::new());
+                output.push(format!("<div{}>", attributes.to_html()));
+                output.push(String::new());
+            }
+            None => output.push(line.to_string()),
+        }
+    }
+
+    for _ in 0..open {
+        output.push(String::new());
+        output.push("</div>".to_string());
+    }
+
+    let mut result = output.join("\n");
+    if markdown.ends_with('\n') {
+        result.push('\n');
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_attributes() {
+        let attributes = BlockAttributes::parse("{#intro .special .wide lang=es}").unwrap();
+        assert_eq!(attributes.id.as_deref(), Some("intro"));
+        assert_eq!(attributes.classes, vec!["special", "wide"]);
+        assert_eq!(BlockAttributes::parse("{.a\"b}"), None);
+        assert_eq!(BlockAttributes::parse("{no es}"), None);
+
+        let (text, attributes) = split_attributes("Título {.special}");
+        assert_eq!(text, "Título");
+        assert_eq!(attributes.unwrap().classes, vec!["special"]);
+        assert_eq!(
+            split_attributes("Llaves {sin más}"),
+            ("Llaves {sin más}", None)
+        );
+    }
+
+    #[test]
+    fn test_fenced_divs() {
+        let md = "Antes\n\n::: warning\nTexto **fuerte**\n\n:::: {.inner #nota}\nDentro\n::::\n:::\n\nDespués\n";
+        let html = render_fenced_divs(md);
+        assert!(html.contains("<div class=\"warning\">\n\nTexto **fuerte**"));
+        assert!(html.contains("<div id=\"nota\" class=\"inner\">"));
+        assert_eq!(html.matches("<div").count(), html.matches("</div>").count());
+        assert!(html.ends_with("Después\n"));
+
+        // Sin cerrar, dentro de código o con atributos inválidos
+        assert!(render_fenced_divs("::: aviso\nTexto").ends_with("</div>"));
+        let code = "```\n::: aviso\n```\n";
+        assert_eq!(render_fenced_divs(code), code);
+        assert_eq!(render_fenced_divs("::: {x\"y}\n"), "::: {x\"y}\n");
+        assert_eq!(render_fenced_divs(":::\n"), ":::\n");
+    }
+}
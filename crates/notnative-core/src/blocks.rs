@@ -0,0 +1,220 @@
+//! Bloques de una nota para reordenarlos arrastrando desde el preview
+//!
+//! Cada bloque de primer nivel (párrafo, encabezado, tabla, cita...) es una
+//! unidad, salvo las listas: en ellas cada elemento se arrastra por separado,
+//! con sus sublistas. El preview numera sus elementos con la misma regla, así
+//! que un índice del preview corresponde a un rango de líneas del markdown.
+
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+use std::ops::Range;
+
+/// Un bloque arrastrable y las líneas que ocupa (sin líneas en blanco finales)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Block {
+    pub lines: Range<usize>,
+    /// Índice de la lista a la que pertenece, si es un elemento de lista
+    pub list: Option<usize>,
+}
+
+/// Bloques del markdown en orden. Las definiciones de notas al pie no cuentan:
+/// el preview las muestra todas juntas al final.
+pub fn blocks(markdown: &str) -> Vec<Block> {
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    options.insert(Options::ENABLE_TASKLISTS);
+    options.insert(Options::ENABLE_FOOTNOTES);
+
+    // Inicio (en bytes) de cada línea
+    let line_starts: Vec<usize> = std::iter::once(0)
+        .chain(markdown.match_indices('\n').map(|(index, _)| index + 1))
+        .collect();
+    let line_of = |offset: usize| line_starts.partition_point(|start| *start <= offset) - 1;
+    let lines: Vec<&str> = markdown.split('\n').collect();
+    let to_lines = |range: Range<usize>| {
+        let start = line_of(range.start);
+        let mut end = line_of(range.end.saturating_sub(1).max(range.start)) + 1;
+        while end > start + 1 && lines[end - 1].trim().is_empty() {
+            end -= 1;
+        }
+        start..end
+    };
+
+    let mut result = Vec::new();
+    let mut depth = 0usize;
+    let mut lists = 0usize;
+    let mut in_list = false;
+    let mut skip_depth: Option<usize> = None;
+
+    for (event, range) in Parser::new_ext(markdown, options).into_offset_iter() {
+        match event {
+            Event::Start(tag) => {
+                if skip_depth.is_none() {
+                    match tag {
+                        Tag::FootnoteDefinition(_) if depth == 0 => skip_depth = Some(depth),
+                        Tag::List(_) if depth == 0 => {
+                            in_list = true;
+                            lists += 1;
+                        }
+                        Tag::Item if depth == 1 && in_list => result.push(Block {
+                            lines: to_lines(range),
+                            list: Some(lists - 1),
+                        }),
+                        _ if depth == 0 => result.push(Block {
+                            lines: to_lines(range),
+                            list: None,
+                        }),
+                        _ => {}
+                    }
+                }
+                depth += 1;
+            }
+            Event::End(tag) => {
+                depth -= 1;
+                if skip_depth == Some(depth) {
+                    skip_depth = None;
+                }
+                if depth == 0 && matches!(tag, TagEnd::List(_)) {
+                    in_list = false;
+                }
+            }
+            // Bloques sin contenido (---) y HTML suelto
+            Event::Rule | Event::Html(_) if depth == 0 && skip_depth.is_none() => {
+                result.push(Block {
+                    lines: to_lines(range),
+                    list: None,
+                });
+            }
+            _ => {}
+        }
+    }
+    result
+}
+
+/// Mueve el bloque `from` delante del bloque `to` (o al final si `to` es el
+/// número de bloques). Un bloque que no es de lista no puede quedar entre dos
+/// elementos de una lista: se coloca delante de la lista entera.
+pub fn move_block(markdown: &str, from: usize, to: usize) -> Option<String> {
+    let blocks = blocks(markdown);
+    let moved = blocks.get(from)?.clone();
+    if to > blocks.len() || to == from || to == from + 1 {
+        return None;
+    }
+
+    let mut target = blocks.get(to).cloned();
+    if moved.list.is_none() {
+        if let Some(list) = target.as_ref().and_then(|block| block.list) {
+            target = blocks
+                .iter()
+                .find(|block| block.list == Some(list))
+                .cloned();
+        }
+    }
+    // Un elemento junto a otro de lista no lleva línea en blanco en medio
+    let joins_list = moved.list.is_some() && target.as_ref().is_some_and(|t| t.list.is_some());
+
+    let mut lines: Vec<&str> = markdown.split('\n').collect();
+    let trailing_newline = markdown.ends_with('\n');
+    if trailing_newline {
+        lines.pop();
+    }
+
+    let moved_lines: Vec<&str> = lines[moved.lines.clone()].to_vec();
+    // Quitar también la línea en blanco que lo separaba del siguiente bloque.
+    // En los elementos de lista no: es la que separa la lista de lo que sigue.
+    let mut removed = moved.lines.clone();
+    if moved.list.is_none() {
+        if removed.end < lines.len() && lines[removed.end].trim().is_empty() {
+            removed.end += 1;
+        } else if removed.start > 0 && lines[removed.start - 1].trim().is_empty() {
+            removed.start -= 1;
+        }
+    }
+    let removed_len = removed.len();
+    let removed_start = removed.start;
+    lines.drain(removed);
+
+    let mut insert: Vec<&str> = moved_lines;
+    match target {
+        Some(target) => {
+            let mut at = target.lines.start;
+            if at > removed_start {
+                at -= removed_len;
+            }
+            if !joins_list {
+                insert.push("");
+            }
+            lines.splice(at..at, insert);
+        }
+        None => {
+            if lines.last().is_some_and(|line| !line.trim().is_empty()) && !joins_list {
+                lines.push("");
+            }
+            lines.extend(insert);
+        }
+    }
+
+    let mut result = lines.join("\n");
+    if trailing_newline {
+        result.push('\n');
+    }
+    (result != markdown).then_some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOTE: &str = "# Título\n\nPárrafo uno\nsigue.\n\n- a\n- b\n  - b.1\n- c\n\n---\n\nFinal[^1]\n\n[^1]: Nota.\n";
+
+    #[test]
+    fn test_blocks() {
+        let lines: Vec<_> = blocks(NOTE)
+            .into_iter()
+            .map(|b| (b.lines, b.list))
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                (0..1, None),
+                (2..4, None),
+                (5..6, Some(0)),
+                (6..8, Some(0)),
+                (8..9, Some(0)),
+                (10..11, None),
+                (12..13, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_move_paragraph_and_items() {
+        // El párrafo final delante del título
+        let moved = move_block(NOTE, 6, 0).unwrap();
+        assert!(moved.starts_with("Final[^1]\n\n# Título\n\nPárrafo uno"));
+        assert!(moved.contains("- c\n\n---\n\n[^1]: Nota.\n"));
+
+        // Un elemento de lista con su sublista, dentro de la misma lista
+        let moved = move_block(NOTE, 3, 2).unwrap();
+        assert!(moved.contains("- b\n  - b.1\n- a\n- c\n"));
+
+        // El último elemento no se lleva la línea en blanco que cierra la lista
+        let moved = move_block(NOTE, 4, 2).unwrap();
+        assert!(moved.contains("- c\n- a\n- b\n  - b.1\n\n---\n"));
+
+        // El título entre dos elementos de lista va delante de la lista
+        let moved = move_block(NOTE, 0, 3).unwrap();
+        assert!(moved.starts_with("Párrafo uno\nsigue.\n\n# Título\n\n- a\n"));
+    }
+
+    #[test]
+    fn test_move_to_end_and_noops() {
+        let moved = move_block(NOTE, 0, blocks(NOTE).len()).unwrap();
+        assert!(moved.starts_with("Párrafo uno"));
+        assert!(moved.ends_with("[^1]: Nota.\n\n# Título\n"));
+
+        assert_eq!(move_block(NOTE, 1, 1), None);
+        assert_eq!(move_block(NOTE, 1, 2), None);
+        assert_eq!(move_block(NOTE, 42, 0), None);
+    }
+}
@@ -0,0 +1,178 @@
+//! Callouts estilo Obsidian (`> [!warning] Título`)
+//!
+//! Un blockquote cuya primera línea es `[!tipo]` se convierte en una caja con
+//! icono y color según el tipo. Con `[!tipo]-` empieza plegada y con `[!tipo]+`
+//! desplegada pero plegable. El cuerpo sigue siendo markdown normal.
+
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// `> [!tipo]+ Título` (el título y el `+`/`-` son opcionales)
+static CALLOUT_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^>\s?\[!([A-Za-z][\w-]*)\]([+-]?)\s*(.*)$").unwrap());
+
+/// Tipo canónico e icono de cada tipo de callout, incluidos los alias de Obsidian
+fn kind_and_icon(kind: &str) -> (&'static str, &'static str) {
+    match kind.to_lowercase().as_str() {
+        "abstract" | "summary" | "tldr" => ("abstract", "📋"),
+        "info" => ("info", "ℹ️"),
+        "todo" => ("todo", "☑️"),
+        "tip" | "hint" | "important" => ("tip", "💡"),
+        "success" | "check" | "done" => ("success", "✅"),
+        "question" | "help" | "faq" => ("question", "❓"),
+        "warning" | "caution" | "attention" => ("warning", "⚠️"),
+        "failure" | "fail" | "missing" => ("failure", "❌"),
+        "danger" | "error" => ("danger", "⚡"),
+        "bug" => ("bug", "🐞"),
+        "example" => ("example", "🧪"),
+        "ocr" => ("abstract", "🔍"),
+        "quote" | "cite" => ("quote", "❝"),
+        _ => ("note", "📝"),
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Reescribe los callouts del markdown como HTML que envuelve su cuerpo. El
+/// cuerpo queda separado por líneas en blanco para que se siga procesando como
+/// markdown (CommonMark termina el bloque HTML en la línea en blanco). Los
+/// bloques de código se respetan y los callouts anidados también se convierten.
+pub fn render_callouts(markdown: &str) -> String {
+    if !markdown.contains("[!") {
+        return markdown.to_string();
+    }
+
+    let lines: Vec<&str> = markdown.lines().collect();
+    let mut output: Vec<String> = Vec::with_capacity(lines.len());
+    let mut in_code_block = false;
+    let mut index = 0;
+
+    while index < lines.len() {
+        let line = lines[index];
+        index += 1;
+
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+        }
+        let captures = if in_code_block {
+            None
+        } else {
+            CALLOUT_RE.captures(line)
+        };
+        let Some(caps) = captures else {
+            output.push(line.to_string());
+            continue;
+        };
+
+        // El cuerpo son las líneas siguientes que siguen dentro del blockquote
+        let mut body: Vec<&str> = Vec::new();
+        while index < lines.len() && lines[index].starts_with('>') {
+            let content = &lines[index][1..];
+            body.push(content.strip_prefix(' ').unwrap_or(content));
+            index += 1;
+        }
+
+        let (kind, icon) = kind_and_icon(&caps[1]);
+        let title = match caps[3].trim() {
+            "" => {
+                let mut chars = caps[1].chars();
+                chars
+                    .next()
+                    .map(|first| first.to_uppercase().chain(chars).collect())
+                    .unwrap_or_default()
+            }
+            title => title.to_string(),
+        };
+        let title = format!(
+            r#"<span class="callout-icon">{icon}</span><span class="callout-title-text">{}</span>"#,
+            escape_html(&title)
+        );
+
+        let (open_tag, title_tag, close_tag) = match &caps[2] {
+            "+" => (
+                format!(r#"<details class="callout callout-{kind}" open>"#),
+                format!(r#"<summary class="callout-title">{title}</summary>"#),
+                "</details>",
+            ),
+            "-" => (
+                format!(r#"<details class="callout callout-{kind}">"#),
+                format!(r#"<summary class="callout-title">{title}</summary>"#),
+                "</details>",
+            ),
+            _ => (
+                format!(r#"<div class="callout callout-{kind}">"#),
+                format!(r#"<div class="callout-title">{title}</div>"#),
+                "</div>",
+            ),
+        };
+
+        output.push(String::new());
+        output.push(open_tag);
+        output.push(title_tag);
+        output.push(r#"<div class="callout-content">"#.to_string());
+        output.push(String::new());
+        output.push(render_callouts(&body.join("\n")));
+        output.push(String::new());
+        output.push("</div>".to_string());
+        output.push(close_tag.to_string());
+        output.push(String::new());
+    }
+
+    let mut result = output.join("\n");
+    if markdown.ends_with('\n') {
+        result.push('\n');
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plain_callout() {
+        let html =
+            render_callouts("Antes\n> [!warning] Cuidado <aquí>\n> Texto **importante**\nDespués");
+        assert!(html.contains(r#"<div class="callout callout-warning">"#));
+        assert!(html.contains("⚠️"));
+        assert!(html.contains("Cuidado &lt;aquí&gt;"));
+        // El cuerpo sale del blockquote y queda como markdown entre líneas en blanco
+        assert!(html.contains("\n\nTexto **importante**\n\n</div>"));
+        assert!(html.ends_with("</div>\n\nDespués"));
+    }
+
+    #[test]
+    fn test_collapsible_and_aliases() {
+        let folded = render_callouts("> [!faq]- Pregunta\n> Respuesta");
+        assert!(folded.contains(r#"<details class="callout callout-question">"#));
+        assert!(folded.contains(r#"<summary class="callout-title">"#));
+
+        let open = render_callouts("> [!tip]+\n> Consejo");
+        assert!(open.contains(r#"<details class="callout callout-tip" open>"#));
+        // Sin título se usa el tipo
+        assert!(open.contains(r#"<span class="callout-title-text">Tip</span>"#));
+
+        // El texto sacado de una imagen
+        let ocr = render_callouts("> [!ocr]- Texto de la imagen\n> Total: 42");
+        assert!(ocr.contains(r#"<details class="callout callout-abstract">"#));
+        assert!(ocr.contains("🔍"));
+
+        let unknown = render_callouts("> [!custom] Algo");
+        assert!(unknown.contains("callout-note"));
+    }
+
+    #[test]
+    fn test_nested_and_code_blocks_untouched() {
+        let nested = render_callouts("> [!note] Fuera\n> > [!bug] Dentro\n> > Detalle");
+        assert!(nested.contains("callout-note"));
+        assert!(nested.contains("callout-bug"));
+
+        let code = "```\n> [!note] No es un callout\n```";
+        assert_eq!(render_callouts(code), code);
+        assert_eq!(render_callouts("> Cita normal"), "> Cita normal");
+    }
+}
@@ -0,0 +1,174 @@
+//! Imágenes adjuntas a un mensaje del chat
+//!
+//! Los modelos con visión reciben las imágenes dentro del mensaje como
+//! `data:` URL en base64 (formato de OpenAI, que también entienden OpenRouter
+//! y Ollama). Aquí está lo que no depende del cliente: qué ficheros son
+//! imágenes, qué modelos las aceptan y la codificación.
+
+use std::path::Path;
+
+/// Tamaño máximo de una imagen adjunta (límite de la API de OpenAI)
+pub const MAX_IMAGE_BYTES: u64 = 20 * 1024 * 1024;
+
+/// Tipo MIME de una imagen que aceptan los modelos, por la extensión
+pub fn image_media_type(path: &Path) -> Option<&'static str> {
+    let extension = path.extension()?.to_str()?.to_lowercase();
+    match extension.as_str() {
+        "png" => Some("image/png"),
+        "jpg" | "jpeg" => Some("image/jpeg"),
+        "gif" => Some("image/gif"),
+        "webp" => Some("image/webp"),
+        _ => None,
+    }
+}
+
+/// ¿Acepta imágenes el modelo? Se decide por el nombre: no hay forma común de
+/// preguntarlo a todos los proveedores.
+pub fn model_supports_vision(model: &str) -> bool {
+    let model = model.to_lowercase();
+    // Quita el prefijo de proveedor de OpenRouter ("openai/gpt-4o")
+    let name = model.rsplit('/').next().unwrap_or(&model);
+
+    const VISION_MARKERS: &[&str] = &[
+        "gpt-4o",
+        "gpt-4.1",
+        "gpt-4-turbo",
+        "gpt-5",
+        "claude-3",
+        "claude-sonnet-4",
+        "claude-opus-4",
+        "claude-haiku-4",
+        "gemini",
+        "gemma-3",
+        "llava",
+        "bakllava",
+        "pixtral",
+        "qwen2.5-vl",
+        "qwen-vl",
+        "llama-3.2-11b-vision",
+        "llama-3.2-90b-vision",
+        "llama-4",
+        "vision",
+        "-vl",
+    ];
+    if VISION_MARKERS.iter().any(|marker| name.contains(marker)) {
+        return true;
+    }
+    // Razonamiento de OpenAI con visión (o1, o3, o4-mini), no o1-mini/o3-mini
+    ["o1", "o3", "o4"].iter().any(|prefix| {
+        name == *prefix
+            || (name.starts_with(&format!("{}-", prefix))
+                && (*prefix == "o4" || !name.starts_with(&format!("{}-mini", prefix))))
+    })
+}
+
+/// Codifica en base64 estándar (con relleno)
+pub fn base64_encode(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b0 = chunk[0] as u32;
+        let b1 = chunk.get(1).copied().unwrap_or(0) as u32;
+        let b2 = chunk.get(2).copied().unwrap_or(0) as u32;
+        let triple = (b0 << 16) | (b1 << 8) | b2;
+
+        encoded.push(ALPHABET[(triple >> 18) as usize & 63] as char);
+        encoded.push(ALPHABET[(triple >> 12) as usize & 63] as char);
+        encoded.push(if chunk.len() > 1 {
+            ALPHABET[(triple >> 6) as usize & 63] as char
+        } else {
+            '='
+        });
+        encoded.push(if chunk.len() > 2 {
+            ALPHABET[triple as usize & 63] as char
+        } else {
+            '='
+        });
+    }
+    encoded
+}
+
+/// Lee la imagen y la devuelve como `data:` URL
+pub fn image_data_url(path: &Path) -> std::io::Result<String> {
+    let media_type = image_media_type(path).ok_or_else(|| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!("{} no es una imagen compatible", path.display()),
+        )
+    })?;
+    let size = std::fs::metadata(path)?.len();
+    if size > MAX_IMAGE_BYTES {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!(
+                "{} pesa {} MB (máximo {} MB)",
+                path.display(),
+                size / (1024 * 1024),
+                MAX_IMAGE_BYTES / (1024 * 1024)
+            ),
+        ));
+    }
+    let bytes = std::fs::read(path)?;
+    Ok(format!(
+        "data:{};base64,{}",
+        media_type,
+        base64_encode(&bytes)
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_base64_encode() {
+        assert_eq!(base64_encode(b""), "");
+        assert_eq!(base64_encode(b"f"), "Zg==");
+        assert_eq!(base64_encode(b"fo"), "Zm8=");
+        assert_eq!(base64_encode(b"foo"), "Zm9v");
+        assert_eq!(base64_encode(b"foobar"), "Zm9vYmFy");
+        assert_eq!(base64_encode(&[0xff, 0xfe, 0x00]), "//4A");
+    }
+
+    #[test]
+    fn test_image_media_type() {
+        assert_eq!(
+            image_media_type(&PathBuf::from("a/captura.PNG")),
+            Some("image/png")
+        );
+        assert_eq!(
+            image_media_type(&PathBuf::from("foto.jpeg")),
+            Some("image/jpeg")
+        );
+        assert_eq!(image_media_type(&PathBuf::from("dibujo.svg")), None);
+        assert_eq!(image_media_type(&PathBuf::from("sin_extension")), None);
+    }
+
+    #[test]
+    fn test_model_supports_vision() {
+        assert!(model_supports_vision("gpt-4o-mini"));
+        assert!(model_supports_vision("openai/gpt-4.1"));
+        assert!(model_supports_vision("anthropic/claude-3.5-sonnet"));
+        assert!(model_supports_vision("google/gemini-2.0-flash-001"));
+        assert!(model_supports_vision("llava:13b"));
+        assert!(model_supports_vision("qwen/qwen2.5-vl-72b-instruct"));
+        assert!(model_supports_vision("o4-mini"));
+        assert!(model_supports_vision("o1"));
+        assert!(!model_supports_vision("o3-mini"));
+        assert!(!model_supports_vision("gpt-4"));
+        assert!(!model_supports_vision("gpt-3.5-turbo"));
+        assert!(!model_supports_vision("llama3.1:8b"));
+    }
+
+    #[test]
+    fn test_image_data_url() {
+        let path = std::env::temp_dir().join("test_chat_image.png");
+        std::fs::write(&path, b"foo").unwrap();
+        assert_eq!(image_data_url(&path).unwrap(), "data:image/png;base64,Zm9v");
+        std::fs::remove_file(&path).ok();
+
+        assert!(image_data_url(&PathBuf::from("nota.md")).is_err());
+    }
+}
@@ -0,0 +1,790 @@
+use crate::EditorMode;
+
+/// Acciones que el editor puede realizar en respuesta a comandos
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EditorAction {
+    /// Cambiar de modo
+    ChangeMode(EditorMode),
+
+    /// Movimientos del cursor
+    MoveCursorLeft,
+    MoveCursorRight,
+    MoveCursorUp,
+    MoveCursorDown,
+    MoveCursorLineStart,
+    MoveCursorLineEnd,
+    MoveCursorDocStart,
+    MoveCursorDocEnd,
+
+    /// Edición
+    InsertChar(char),
+    InsertNewline,
+    DeleteCharBefore,
+    DeleteCharAfter,
+    DeleteLine,
+    DeleteSelection,
+
+    /// Insertar imagen
+    InsertImage,
+    /// Dibujar un boceto a mano alzada e insertarlo
+    InsertSketch,
+    /// Capturar una zona de la pantalla, anotarla e insertarla
+    /// (`:screenshot`, Ctrl+Alt+P)
+    CaptureScreenshot,
+    /// Sacar el texto de la imagen de la línea del cursor (`:ocr`)
+    ExtractImageText,
+    /// Insertar tabla
+    InsertTable,
+    /// Insertar nota al pie y saltar a su definición
+    InsertFootnote,
+    /// Ciclar el estado de la tarea de la línea actual ([ ] → [/] → [x] → [-])
+    CycleTaskState,
+    /// Resaltar la selección (==texto==) o cambiar el color del resaltado
+    ToggleHighlight,
+    /// Volver a la nota (o encabezado) anterior del historial
+    NavigateBack,
+    /// Avanzar en el historial de navegación
+    NavigateForward,
+
+    /// Undo/Redo
+    Undo,
+    Redo,
+
+    /// Portapapeles
+    Copy,
+    /// Copiar la selección como texto enriquecido (HTML y texto plano)
+    CopyAsHtml,
+    /// Copiar la selección (o la nota) como texto plano, sin sintaxis markdown
+    CopyAsPlainText,
+    Cut,
+    Paste,
+    /// Pegar el texto tal cual, sin convertir HTML ni URLs
+    PastePlainText,
+
+    /// Comandos ex-style
+    Save,
+    Quit,
+    SaveAndQuit,
+    ForceQuit,
+    /// Guardar una copia de la nota con otro nombre y abrirla (`:saveas`)
+    SaveAs(String),
+    /// Pasar cada sección de la nota a una nota propia
+    /// (`:split-by-heading [nivel] [carpeta]`, nivel 2 por defecto)
+    SplitByHeading {
+        level: usize,
+        folder: Option<String>,
+    },
+    /// Renombrar un tag en todo el vault, con vista previa (`:rename-tag old new`)
+    RenameTag {
+        from: String,
+        to: String,
+    },
+    /// Cambiar en todo el vault las menciones y wikilinks a una nota
+    /// (`:rename-mention Old New`; los nombres con espacios, entre comillas)
+    RenameMention {
+        from: String,
+        to: String,
+    },
+    /// Abrir o cerrar el borrador que no se guarda (`:scratch`, Ctrl+Alt+S)
+    ToggleScratch,
+    /// Ventana pequeña solo con el editor, o volver a la normal (`:mini`, Ctrl+Alt+M)
+    ToggleCompactMode,
+
+    /// Búsqueda
+    Search(String),
+
+    /// Sidebar
+    OpenSidebar,
+    OpenBasesSidebar,
+    CloseSidebar,
+
+    /// Crear nueva nota
+    CreateNote,
+
+    /// Sin acción
+    None,
+}
+
+/// Parser de comandos estilo vim
+#[derive(Debug)]
+pub struct CommandParser {
+    /// Buffer acumulativo para comandos multi-tecla (ej: "dd", "2w")
+    pending: String,
+}
+
+impl CommandParser {
+    pub fn new() -> Self {
+        Self {
+            pending: String::new(),
+        }
+    }
+
+    /// Procesa una tecla del editor según el modo. Solo Normal e Insert producen
+    /// acciones aquí: la línea de comandos y el chat se gestionan aparte.
+    pub fn parse_key(
+        &mut self,
+        mode: EditorMode,
+        key: &str,
+        modifiers: KeyModifiers,
+    ) -> EditorAction {
+        match mode {
+            EditorMode::Normal => self.parse_normal_mode(key, modifiers),
+            EditorMode::Insert => self.parse_insert_mode(key, modifiers),
+            _ => EditorAction::None,
+        }
+    }
+
+    /// Procesa una tecla en modo Normal y devuelve una acción
+    pub fn parse_normal_mode(&mut self, key: &str, modifiers: KeyModifiers) -> EditorAction {
+        // Comandos con modificadores (Ctrl, Alt)
+        if modifiers.ctrl {
+            if modifiers.shift {
+                return match key {
+                    "c" | "C" => EditorAction::CopyAsHtml,
+                    _ => EditorAction::None,
+                };
+            }
+            if modifiers.alt {
+                return match key {
+                    "c" | "C" => EditorAction::CopyAsPlainText,
+                    "s" | "S" => EditorAction::ToggleScratch,
+                    "m" | "M" => EditorAction::ToggleCompactMode,
+                    "p" | "P" => EditorAction::CaptureScreenshot,
+                    _ => EditorAction::None,
+                };
+            }
+
+            return match key {
+                "s" => EditorAction::Save,
+                "z" => EditorAction::Undo,
+                "r" => EditorAction::Redo,
+                "c" => EditorAction::Copy,
+                "x" => EditorAction::Cut,
+                "Return" => EditorAction::CycleTaskState,
+                // Ctrl+V deshabilitado en modo Normal (solo Insert)
+                _ => EditorAction::None,
+            };
+        }
+
+        if modifiers.alt {
+            if let Some(action) = Self::parse_navigation(key) {
+                return action;
+            }
+        }
+
+        // Comandos de una sola tecla
+        match key {
+            "i" => EditorAction::ChangeMode(EditorMode::Insert),
+            "a" => EditorAction::ChangeMode(EditorMode::ChatAI),
+            ":" | "colon" => EditorAction::ChangeMode(EditorMode::Command),
+            "v" => EditorAction::ChangeMode(EditorMode::Visual),
+            "t" => EditorAction::OpenSidebar,
+            "b" => EditorAction::OpenBasesSidebar,
+            "n" => EditorAction::CreateNote,
+            "equal" => EditorAction::ToggleHighlight,
+
+            // Movimientos básicos (vim-style)
+            "h" | "Left" => EditorAction::MoveCursorLeft,
+            "j" | "Down" => EditorAction::MoveCursorDown,
+            "k" | "Up" => EditorAction::MoveCursorUp,
+            "l" | "Right" => EditorAction::MoveCursorRight,
+
+            // Movimientos de línea
+            "0" => EditorAction::MoveCursorLineStart,
+            "$" => EditorAction::MoveCursorLineEnd,
+            "g" if self.pending == "g" => {
+                self.pending.clear();
+                EditorAction::MoveCursorDocStart
+            }
+            "g" => {
+                self.pending.push_str("g");
+                EditorAction::None
+            }
+            "G" => EditorAction::MoveCursorDocEnd,
+
+            // Edición
+            "x" => EditorAction::DeleteCharAfter,
+            "d" if self.pending == "d" => {
+                self.pending.clear();
+                EditorAction::DeleteLine
+            }
+            "d" => {
+                self.pending.push_str("d");
+                EditorAction::None
+            }
+
+            "u" => EditorAction::Undo,
+
+            // ESC en modo Normal: cerrar sidebar si está abierto
+            "Escape" => {
+                self.pending.clear();
+                EditorAction::CloseSidebar
+            }
+
+            _ => {
+                self.pending.clear();
+                EditorAction::None
+            }
+        }
+    }
+
+    /// Procesa entrada en modo Insert
+    /// Alt+←/→: historial de navegación entre notas (igual en todos los modos)
+    fn parse_navigation(key: &str) -> Option<EditorAction> {
+        match key {
+            "Left" => Some(EditorAction::NavigateBack),
+            "Right" => Some(EditorAction::NavigateForward),
+            _ => None,
+        }
+    }
+
+    pub fn parse_insert_mode(&mut self, key: &str, modifiers: KeyModifiers) -> EditorAction {
+        if key == "Escape" {
+            return EditorAction::ChangeMode(EditorMode::Normal);
+        }
+
+        if modifiers.ctrl {
+            if modifiers.shift {
+                return match key {
+                    "i" | "I" => EditorAction::InsertImage,
+                    "d" | "D" => EditorAction::InsertSketch,
+                    "f" | "F" => EditorAction::InsertFootnote,
+                    "c" | "C" => EditorAction::CopyAsHtml,
+                    "v" | "V" => EditorAction::PastePlainText,
+                    _ => EditorAction::None,
+                };
+            }
+            if modifiers.alt {
+                return match key {
+                    "c" | "C" => EditorAction::CopyAsPlainText,
+                    "s" | "S" => EditorAction::ToggleScratch,
+                    "m" | "M" => EditorAction::ToggleCompactMode,
+                    "p" | "P" => EditorAction::CaptureScreenshot,
+                    _ => EditorAction::None,
+                };
+            }
+
+            return match key {
+                "s" => EditorAction::Save,
+                "c" => EditorAction::Copy,
+                "x" => EditorAction::Cut,
+                "v" => EditorAction::Paste,
+                "z" => EditorAction::Undo,
+                "r" => EditorAction::Redo,
+                "t" => EditorAction::InsertTable,
+                _ => EditorAction::None,
+            };
+        }
+
+        if modifiers.alt {
+            if let Some(action) = Self::parse_navigation(key) {
+                return action;
+            }
+        }
+
+        match key {
+            "Return" | "Enter" => EditorAction::InsertNewline,
+            "BackSpace" => EditorAction::DeleteCharBefore,
+            "Delete" => EditorAction::DeleteCharAfter,
+            "Left" => EditorAction::MoveCursorLeft,
+            "Right" => EditorAction::MoveCursorRight,
+            "Up" => EditorAction::MoveCursorUp,
+            "Down" => EditorAction::MoveCursorDown,
+            "space" => EditorAction::InsertChar(' '),
+            "Tab" => EditorAction::InsertChar('\t'),
+
+            // Caracteres especiales comunes que GTK reporta con nombres específicos
+            "period" => EditorAction::InsertChar('.'),
+            "comma" => EditorAction::InsertChar(','),
+            "semicolon" => EditorAction::InsertChar(';'),
+            "colon" => EditorAction::InsertChar(':'),
+            "exclam" => EditorAction::InsertChar('!'),
+            "question" => EditorAction::InsertChar('?'),
+            "slash" => EditorAction::InsertChar('/'),
+            "backslash" => EditorAction::InsertChar('\\'),
+            "minus" => EditorAction::InsertChar('-'),
+            "underscore" => EditorAction::InsertChar('_'),
+            "equal" => EditorAction::InsertChar('='),
+            "plus" => EditorAction::InsertChar('+'),
+            "asterisk" => EditorAction::InsertChar('*'),
+            "ampersand" => EditorAction::InsertChar('&'),
+            "percent" => EditorAction::InsertChar('%'),
+            "numbersign" => EditorAction::InsertChar('#'),
+            "at" => EditorAction::InsertChar('@'),
+            "dollar" => EditorAction::InsertChar('$'),
+            "parenleft" => EditorAction::InsertChar('('),
+            "parenright" => EditorAction::InsertChar(')'),
+            "bracketleft" => EditorAction::InsertChar('['),
+            "bracketright" => EditorAction::InsertChar(']'),
+            "braceleft" => EditorAction::InsertChar('{'),
+            "braceright" => EditorAction::InsertChar('}'),
+            "less" => EditorAction::InsertChar('<'),
+            "greater" => EditorAction::InsertChar('>'),
+            "quotedbl" => EditorAction::InsertChar('"'),
+            "apostrophe" => EditorAction::InsertChar('\''),
+            "grave" => EditorAction::InsertChar('`'),
+            "asciitilde" => EditorAction::InsertChar('~'),
+            "bar" => EditorAction::InsertChar('|'),
+            "asciicircum" => EditorAction::InsertChar('^'),
+
+            _ => {
+                // Si es un carácter imprimible de longitud 1, insertarlo
+                if key.chars().count() == 1 {
+                    if let Some(ch) = key.chars().next() {
+                        return EditorAction::InsertChar(ch);
+                    }
+                }
+                EditorAction::None
+            }
+        }
+    }
+
+    /// Procesa entrada en modo Command (para comandos ex-style como :w, :q)
+    pub fn parse_command_mode(&mut self, command: &str) -> EditorAction {
+        let trimmed = command.trim();
+        match trimmed {
+            "w" | "write" => EditorAction::Save,
+            "q" | "quit" => EditorAction::Quit,
+            "wq" | "x" => EditorAction::SaveAndQuit,
+            "q!" => EditorAction::ForceQuit,
+            "scratch" => EditorAction::ToggleScratch,
+            "mini" => EditorAction::ToggleCompactMode,
+            "screenshot" => EditorAction::CaptureScreenshot,
+            "ocr" => EditorAction::ExtractImageText,
+            _ if trimmed.starts_with('/') => EditorAction::Search(trimmed[1..].to_string()),
+            _ if trimmed == "split-by-heading" || trimmed.starts_with("split-by-heading ") => {
+                Self::parse_split_by_heading(trimmed["split-by-heading".len()..].trim())
+            }
+            _ if trimmed.starts_with("rename-tag ") => {
+                match Self::two_args(&trimmed["rename-tag ".len()..]) {
+                    Some((from, to)) => EditorAction::RenameTag { from, to },
+                    None => EditorAction::None,
+                }
+            }
+            _ if trimmed.starts_with("rename-mention ") => {
+                match Self::two_args(&trimmed["rename-mention ".len()..]) {
+                    Some((from, to)) => EditorAction::RenameMention { from, to },
+                    None => EditorAction::None,
+                }
+            }
+            _ if trimmed.starts_with("saveas ") => {
+                let name = trimmed["saveas ".len()..].trim();
+                if name.is_empty() {
+                    EditorAction::None
+                } else {
+                    EditorAction::SaveAs(name.to_string())
+                }
+            }
+            _ => EditorAction::None,
+        }
+    }
+
+    /// Argumentos de `:split-by-heading`: un nivel opcional (`1`, `h2`...) y el
+    /// resto como carpeta destino
+    fn parse_split_by_heading(args: &str) -> EditorAction {
+        let (first, rest) = args.split_once(' ').unwrap_or((args, ""));
+        let digits = first.trim_start_matches(['h', 'H']);
+        let (level, folder) = match digits.parse::<usize>() {
+            Ok(level) if (1..=6).contains(&level) => (level, rest.trim()),
+            Ok(_) => return EditorAction::None,
+            Err(_) => (2, args),
+        };
+        EditorAction::SplitByHeading {
+            level,
+            folder: (!folder.is_empty()).then(|| folder.trim_matches('/').to_string()),
+        }
+    }
+
+    /// Exactamente dos argumentos separados por espacios; entre comillas
+    /// pueden llevarlos dentro (`"Ana López" Ana`)
+    fn two_args(args: &str) -> Option<(String, String)> {
+        let mut parts = Vec::new();
+        let mut rest = args.trim();
+        while !rest.is_empty() {
+            let (part, after) = match rest.strip_prefix('"') {
+                Some(quoted) => quoted.split_once('"')?,
+                None => rest.split_once(char::is_whitespace).unwrap_or((rest, "")),
+            };
+            parts.push(part.to_string());
+            rest = after.trim_start();
+        }
+        match <[String; 2]>::try_from(parts) {
+            Ok([from, to]) if !from.is_empty() && !to.is_empty() => Some((from, to)),
+            _ => None,
+        }
+    }
+
+    /// Limpia el buffer de comandos pendientes
+    pub fn clear_pending(&mut self) {
+        self.pending.clear();
+    }
+}
+
+impl Default for CommandParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Modificadores de teclado
+#[derive(Debug, Clone, Copy, Default)]
+pub struct KeyModifiers {
+    pub ctrl: bool,
+    pub alt: bool,
+    pub shift: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normal_mode_basic() {
+        let mut parser = CommandParser::new();
+        let mods = KeyModifiers::default();
+
+        assert_eq!(
+            parser.parse_normal_mode("i", mods),
+            EditorAction::ChangeMode(EditorMode::Insert)
+        );
+        assert_eq!(
+            parser.parse_normal_mode("h", mods),
+            EditorAction::MoveCursorLeft
+        );
+        assert_eq!(
+            parser.parse_normal_mode("l", mods),
+            EditorAction::MoveCursorRight
+        );
+    }
+
+    #[test]
+    fn test_normal_mode_multi_key() {
+        let mut parser = CommandParser::new();
+        let mods = KeyModifiers::default();
+
+        // Primer 'd' no hace nada
+        assert_eq!(parser.parse_normal_mode("d", mods), EditorAction::None);
+        // Segundo 'd' ejecuta DeleteLine
+        assert_eq!(
+            parser.parse_normal_mode("d", mods),
+            EditorAction::DeleteLine
+        );
+    }
+
+    #[test]
+    fn test_insert_mode() {
+        let mut parser = CommandParser::new();
+        let mods = KeyModifiers::default();
+
+        assert_eq!(
+            parser.parse_insert_mode("a", mods),
+            EditorAction::InsertChar('a')
+        );
+        assert_eq!(
+            parser.parse_insert_mode("Return", mods),
+            EditorAction::InsertNewline
+        );
+        assert_eq!(
+            parser.parse_insert_mode("Escape", mods),
+            EditorAction::ChangeMode(EditorMode::Normal)
+        );
+    }
+
+    #[test]
+    fn test_command_mode() {
+        let mut parser = CommandParser::new();
+
+        assert_eq!(parser.parse_command_mode("w"), EditorAction::Save);
+        assert_eq!(parser.parse_command_mode("q"), EditorAction::Quit);
+        assert_eq!(parser.parse_command_mode("wq"), EditorAction::SaveAndQuit);
+        assert_eq!(
+            parser.parse_command_mode("/search"),
+            EditorAction::Search("search".to_string())
+        );
+    }
+
+    #[test]
+    fn test_split_by_heading_command() {
+        let mut parser = CommandParser::new();
+
+        assert_eq!(
+            parser.parse_command_mode("split-by-heading"),
+            EditorAction::SplitByHeading {
+                level: 2,
+                folder: None
+            }
+        );
+        assert_eq!(
+            parser.parse_command_mode("split-by-heading h1 Proyectos/Plan 2026/"),
+            EditorAction::SplitByHeading {
+                level: 1,
+                folder: Some("Proyectos/Plan 2026".to_string())
+            }
+        );
+        assert_eq!(
+            parser.parse_command_mode("split-by-heading Capítulos"),
+            EditorAction::SplitByHeading {
+                level: 2,
+                folder: Some("Capítulos".to_string())
+            }
+        );
+        assert_eq!(
+            parser.parse_command_mode("split-by-heading 9"),
+            EditorAction::None
+        );
+    }
+
+    #[test]
+    fn test_rename_refactor_commands() {
+        let mut parser = CommandParser::new();
+
+        assert_eq!(
+            parser.parse_command_mode("rename-tag  #idea  plan"),
+            EditorAction::RenameTag {
+                from: "#idea".to_string(),
+                to: "plan".to_string()
+            }
+        );
+        assert_eq!(
+            parser.parse_command_mode("rename-mention \"Ana López\" Ana"),
+            EditorAction::RenameMention {
+                from: "Ana López".to_string(),
+                to: "Ana".to_string()
+            }
+        );
+        assert_eq!(
+            parser.parse_command_mode("rename-tag idea"),
+            EditorAction::None
+        );
+        assert_eq!(
+            parser.parse_command_mode("rename-mention \"Ana Ana"),
+            EditorAction::None
+        );
+    }
+
+    #[test]
+    fn test_navigation_history_keys() {
+        let mut parser = CommandParser::new();
+        let alt = KeyModifiers {
+            ctrl: false,
+            alt: true,
+            shift: false,
+        };
+
+        assert_eq!(
+            parser.parse_insert_mode("Left", alt),
+            EditorAction::NavigateBack
+        );
+        assert_eq!(
+            parser.parse_normal_mode("Right", alt),
+            EditorAction::NavigateForward
+        );
+        // Sin Alt las flechas siguen moviendo el cursor
+        assert_eq!(
+            parser.parse_normal_mode("Left", KeyModifiers::default()),
+            EditorAction::MoveCursorLeft
+        );
+    }
+
+    #[test]
+    fn test_scratch_buffer_commands() {
+        let mut parser = CommandParser::new();
+        let ctrl_alt = KeyModifiers {
+            ctrl: true,
+            alt: true,
+            shift: false,
+        };
+
+        assert_eq!(
+            parser.parse_insert_mode("s", ctrl_alt),
+            EditorAction::ToggleScratch
+        );
+        assert_eq!(
+            parser.parse_command_mode("scratch"),
+            EditorAction::ToggleScratch
+        );
+    }
+
+    #[test]
+    fn test_compact_mode_commands() {
+        let mut parser = CommandParser::new();
+        let ctrl_alt = KeyModifiers {
+            ctrl: true,
+            alt: true,
+            shift: false,
+        };
+
+        assert_eq!(
+            parser.parse_normal_mode("m", ctrl_alt),
+            EditorAction::ToggleCompactMode
+        );
+        assert_eq!(
+            parser.parse_command_mode("mini"),
+            EditorAction::ToggleCompactMode
+        );
+    }
+
+    #[test]
+    fn test_screenshot_commands() {
+        let mut parser = CommandParser::new();
+        let ctrl_alt = KeyModifiers {
+            ctrl: true,
+            alt: true,
+            shift: false,
+        };
+
+        assert_eq!(
+            parser.parse_insert_mode("P", ctrl_alt),
+            EditorAction::CaptureScreenshot
+        );
+        assert_eq!(
+            parser.parse_command_mode("screenshot"),
+            EditorAction::CaptureScreenshot
+        );
+    }
+
+    #[test]
+    fn test_copy_as_plain_text_keys() {
+        let mut parser = CommandParser::new();
+        let ctrl_alt = KeyModifiers {
+            ctrl: true,
+            alt: true,
+            shift: false,
+        };
+
+        assert_eq!(
+            parser.parse_normal_mode("c", ctrl_alt),
+            EditorAction::CopyAsPlainText
+        );
+        assert_eq!(
+            parser.parse_insert_mode("c", ctrl_alt),
+            EditorAction::CopyAsPlainText
+        );
+    }
+
+    #[test]
+    fn test_copy_as_html_key() {
+        let mut parser = CommandParser::new();
+        let ctrl_shift = KeyModifiers {
+            ctrl: true,
+            alt: false,
+            shift: true,
+        };
+
+        assert_eq!(
+            parser.parse_normal_mode("C", ctrl_shift),
+            EditorAction::CopyAsHtml
+        );
+    }
+
+    #[test]
+    fn test_cycle_task_key() {
+        let mut parser = CommandParser::new();
+        let ctrl = KeyModifiers {
+            ctrl: true,
+            alt: false,
+            shift: false,
+        };
+
+        assert_eq!(
+            parser.parse_normal_mode("Return", ctrl),
+            EditorAction::CycleTaskState
+        );
+    }
+
+    #[test]
+    fn test_paste_plain_text_key() {
+        let mut parser = CommandParser::new();
+        let ctrl_shift = KeyModifiers {
+            ctrl: true,
+            alt: false,
+            shift: true,
+        };
+
+        assert_eq!(
+            parser.parse_insert_mode("V", ctrl_shift),
+            EditorAction::PastePlainText
+        );
+    }
+
+    #[test]
+    fn test_insert_sketch_key() {
+        let mut parser = CommandParser::new();
+        let ctrl_shift = KeyModifiers {
+            ctrl: true,
+            alt: false,
+            shift: true,
+        };
+
+        assert_eq!(
+            parser.parse_insert_mode("D", ctrl_shift),
+            EditorAction::InsertSketch
+        );
+    }
+
+    #[test]
+    fn test_insert_footnote_key() {
+        let mut parser = CommandParser::new();
+        let ctrl_shift = KeyModifiers {
+            ctrl: true,
+            alt: false,
+            shift: true,
+        };
+
+        assert_eq!(
+            parser.parse_insert_mode("F", ctrl_shift),
+            EditorAction::InsertFootnote
+        );
+    }
+
+    #[test]
+    fn test_save_as_command() {
+        let mut parser = CommandParser::new();
+
+        assert_eq!(
+            parser.parse_command_mode("saveas  Ideas/Copia de plan "),
+            EditorAction::SaveAs("Ideas/Copia de plan".to_string())
+        );
+        assert_eq!(parser.parse_command_mode("saveas "), EditorAction::None);
+    }
+
+    #[test]
+    fn test_ocr_command() {
+        let mut parser = CommandParser::new();
+
+        assert_eq!(
+            parser.parse_command_mode("ocr"),
+            EditorAction::ExtractImageText
+        );
+    }
+
+    #[test]
+    fn test_toggle_highlight_key() {
+        let mut parser = CommandParser::new();
+        let mods = KeyModifiers::default();
+
+        assert_eq!(
+            parser.parse_normal_mode("equal", mods),
+            EditorAction::ToggleHighlight
+        );
+    }
+
+    #[test]
+    fn test_parse_key_by_mode() {
+        let mut parser = CommandParser::new();
+        let mods = KeyModifiers::default();
+
+        assert_eq!(
+            parser.parse_key(EditorMode::Normal, "x", mods),
+            EditorAction::DeleteCharAfter
+        );
+        assert_eq!(
+            parser.parse_key(EditorMode::Insert, "x", mods),
+            EditorAction::InsertChar('x')
+        );
+        assert_eq!(
+            parser.parse_key(EditorMode::Command, "x", mods),
+            EditorAction::None
+        );
+    }
+}
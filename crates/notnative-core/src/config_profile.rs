@@ -0,0 +1,126 @@
+//! Perfiles de configuración
+//!
+//! Un perfil («Trabajo», «Personal»...) agrupa lo que suele cambiar junto: la
+//! carpeta de notas, el proveedor de IA y sus embeddings, el tema y las copias
+//! de seguridad. Al cambiar de perfil los ajustes actuales se guardan en el
+//! perfil activo y se aplican los del nuevo; el resto de la configuración
+//! (idioma, atajos, preview...) es común a todos.
+
+use serde::{Deserialize, Serialize};
+
+use crate::backup::BackupConfig;
+use crate::embedding_config::EmbeddingConfig;
+use crate::notes_config::AIConfig;
+
+/// Tema guardado en un perfil
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ProfileTheme {
+    /// El del sistema
+    #[default]
+    System,
+    Light,
+    Dark,
+}
+
+/// Perfil de configuración con nombre
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigProfile {
+    pub name: String,
+    /// Carpeta de notas; `None` es la de por defecto
+    #[serde(default)]
+    pub workspace_dir: Option<String>,
+    /// Las claves de API van al llavero con [`profile_secret_name`]
+    #[serde(default)]
+    pub ai_config: AIConfig,
+    #[serde(default)]
+    pub embedding_config: EmbeddingConfig,
+    #[serde(default)]
+    pub theme: ProfileTheme,
+    #[serde(default)]
+    pub backup: BackupConfig,
+}
+
+/// Nombre en el llavero de una credencial de un perfil
+pub fn profile_secret_name(secret: &str, profile: &str) -> String {
+    format!("{}@{}", secret, profile)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notes_config::NotesConfig;
+    use crate::secrets::{AI_API_KEY, SecretStore};
+
+    #[test]
+    fn test_switch_profile() {
+        let mut config = NotesConfig::new();
+        config.workspace_dir = Some("/home/ana/trabajo".into());
+        config.ai_config.provider = "openai".into();
+        config.save_profile("Trabajo", ProfileTheme::Dark);
+        assert_eq!(config.active_profile.as_deref(), Some("Trabajo"));
+
+        // Un perfil nuevo parte de los ajustes actuales
+        config.workspace_dir = None;
+        config.ai_config.provider = "ollama".into();
+        config.save_profile("Personal", ProfileTheme::Light);
+        assert_eq!(config.profile_names(), vec!["Personal", "Trabajo"]);
+
+        // Los cambios hechos con «Personal» activo se guardan en él al salir
+        config.ai_config.model = "llama3".into();
+        let work = config
+            .switch_profile("Trabajo", ProfileTheme::Dark)
+            .unwrap();
+        assert_eq!(work.theme, ProfileTheme::Dark);
+        assert_eq!(config.workspace_dir.as_deref(), Some("/home/ana/trabajo"));
+        assert_eq!(config.ai_config.provider, "openai");
+        assert_eq!(
+            config.profile("Personal").unwrap().ai_config.model,
+            "llama3"
+        );
+        assert_eq!(
+            config.profile("Personal").unwrap().theme,
+            ProfileTheme::Dark
+        );
+
+        assert!(config.switch_profile("Otro", ProfileTheme::Dark).is_none());
+        assert_eq!(config.active_profile.as_deref(), Some("Trabajo"));
+
+        assert!(config.remove_profile("Trabajo"));
+        assert_eq!(config.active_profile, None);
+        assert_eq!(config.profile_names(), vec!["Personal"]);
+        // Los ajustes en uso no cambian al borrar el perfil
+        assert_eq!(config.ai_config.provider, "openai");
+    }
+
+    #[test]
+    fn test_profile_api_keys() {
+        let dir = std::env::temp_dir().join(format!("test_config_profile_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let secrets = SecretStore::encrypted_file(&dir);
+        let path = dir.join("config.json");
+
+        let mut config = NotesConfig::new();
+        config.ai_config.api_key = Some("sk-trabajo".into());
+        config.save_profile("Trabajo", ProfileTheme::System);
+        config.ai_config.api_key = Some("sk-personal".into());
+        config.save_profile("Personal", ProfileTheme::System);
+        config.save_with_secrets(&path, &secrets).unwrap();
+
+        let saved = std::fs::read_to_string(&path).unwrap();
+        assert!(!saved.contains("sk-"));
+        assert_eq!(
+            secrets
+                .get(&profile_secret_name(AI_API_KEY, "Trabajo"))
+                .as_deref(),
+            Some("sk-trabajo")
+        );
+
+        let mut loaded = NotesConfig::load_with_secrets(&path, &secrets).unwrap();
+        assert_eq!(loaded.ai_config.api_key.as_deref(), Some("sk-personal"));
+        loaded.switch_profile("Trabajo", ProfileTheme::System);
+        assert_eq!(loaded.ai_config.api_key.as_deref(), Some("sk-trabajo"));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,131 @@
+//! Reparto de un presupuesto de caracteres entre varias notas
+//!
+//! Al adjuntar al chat una carpeta o un tag entran muchas notas de golpe. Para
+//! no pasarse de la ventana del modelo se reparte el presupuesto a partes
+//! iguales: las notas cortas entran enteras y lo que no gastan se reparte
+//! entre las largas, que se recortan. Si ni siquiera caben
+//! [`MIN_DOC_CHARS`] por nota, se quedan fuera las últimas.
+//!
+//! El historial del chat también se ajusta: cuando no cabe, los mensajes más
+//! antiguos se resumen ([`messages_to_summarize`]).
+
+/// Por debajo de esto un trozo de nota no aporta nada
+pub const MIN_DOC_CHARS: usize = 200;
+
+/// Se añade al final de una nota recortada
+pub const TRUNCATION_MARK: &str = "\n[…]";
+
+/// Nota tal y como entra en el contexto
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContextDoc {
+    pub name: String,
+    pub text: String,
+    pub truncated: bool,
+}
+
+/// Ajusta `docs` (nombre, contenido), en orden de preferencia, a
+/// `budget_chars` caracteres en total. Devuelve las que entran, en el mismo
+/// orden.
+pub fn fit_to_budget(docs: Vec<(String, String)>, budget_chars: usize) -> Vec<ContextDoc> {
+    let max_docs = (budget_chars / MIN_DOC_CHARS).max(1);
+    let docs: Vec<(String, String)> = docs.into_iter().take(max_docs).collect();
+
+    // Reparto por niveles: de la más corta a la más larga, cada una se lleva
+    // lo que necesita o su parte de lo que queda
+    let lengths: Vec<usize> = docs.iter().map(|(_, text)| text.chars().count()).collect();
+    let mut order: Vec<usize> = (0..docs.len()).collect();
+    order.sort_by_key(|&index| lengths[index]);
+
+    let mut allowed = vec![0; docs.len()];
+    let mut remaining = budget_chars;
+    for (position, &index) in order.iter().enumerate() {
+        let share = remaining / (docs.len() - position);
+        allowed[index] = lengths[index].min(share);
+        remaining -= allowed[index];
+    }
+
+    docs.into_iter()
+        .zip(lengths)
+        .zip(allowed)
+        .map(|(((name, text), length), allowed)| {
+            if allowed >= length {
+                ContextDoc {
+                    name,
+                    text,
+                    truncated: false,
+                }
+            } else {
+                let mut text: String = text.chars().take(allowed).collect();
+                text.push_str(TRUNCATION_MARK);
+                ContextDoc {
+                    name,
+                    text,
+                    truncated: true,
+                }
+            }
+        })
+        .collect()
+}
+
+/// Cuántos de los mensajes más antiguos hay que resumir para que el
+/// historial (`tokens` de cada mensaje, del más antiguo al más reciente) baje
+/// a `target` tokens. Los `keep_recent` últimos no se resumen nunca. 0 si ya
+/// cabe.
+pub fn messages_to_summarize(tokens: &[usize], target: usize, keep_recent: usize) -> usize {
+    let summarizable = tokens.len().saturating_sub(keep_recent);
+    let mut total: usize = tokens.iter().sum();
+    let mut count = 0;
+    while total > target && count < summarizable {
+        total -= tokens[count];
+        count += 1;
+    }
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn doc(name: &str, len: usize) -> (String, String) {
+        (name.to_string(), "x".repeat(len))
+    }
+
+    #[test]
+    fn test_everything_fits() {
+        let fitted = fit_to_budget(vec![doc("a", 300), doc("b", 500)], 1000);
+        assert_eq!(fitted.len(), 2);
+        assert!(fitted.iter().all(|doc| !doc.truncated));
+    }
+
+    #[test]
+    fn test_short_notes_leave_room_for_long_ones() {
+        let fitted = fit_to_budget(vec![doc("long", 5000), doc("short", 100)], 1000);
+        assert_eq!(fitted[0].name, "long");
+        assert!(fitted[0].truncated);
+        assert_eq!(
+            fitted[0].text.chars().count(),
+            900 + TRUNCATION_MARK.chars().count()
+        );
+        assert_eq!(fitted[1].text.len(), 100);
+        assert!(!fitted[1].truncated);
+    }
+
+    #[test]
+    fn test_messages_to_summarize() {
+        let tokens = [400, 300, 200, 100, 50];
+        assert_eq!(messages_to_summarize(&tokens, 2000, 2), 0);
+        assert_eq!(messages_to_summarize(&tokens, 500, 2), 2);
+        // Los dos últimos se quedan aunque no quepan
+        assert_eq!(messages_to_summarize(&tokens, 10, 2), 3);
+        assert_eq!(messages_to_summarize(&[900], 10, 2), 0);
+    }
+
+    #[test]
+    fn test_drops_notes_beyond_minimum_share() {
+        let docs = (0..10).map(|i| doc(&i.to_string(), 1000)).collect();
+        let fitted = fit_to_budget(docs, 1000);
+        let names: Vec<&str> = fitted.iter().map(|doc| doc.name.as_str()).collect();
+        assert_eq!(names, vec!["0", "1", "2", "3", "4"]);
+        assert!(fitted.iter().all(|doc| doc.truncated));
+    }
+}
@@ -0,0 +1,330 @@
+//! Agentes del asistente definidos por el usuario
+//!
+//! Además de los agentes integrados, `agents.yaml` (junto a `config.json`)
+//! puede definir agentes propios con su prompt de sistema, las herramientas
+//! que pueden usar y, si se quiere, otro modelo; y reglas que mandan a cada
+//! uno los mensajes que contienen ciertas palabras. El archivo se vuelve a
+//! leer cuando cambia, sin reiniciar la app.
+//!
+//! ```yaml
+//! agents:
+//!   - name: editor
+//!     description: Corrige el estilo de las notas
+//!     system_prompt: Eres un editor estricto. Señala cada frase floja.
+//!     tools: [read_note, update_note]
+//!     model: anthropic/claude-3.5-sonnet
+//! rules:
+//!   - agent: editor
+//!     keywords: [corrige, revisa el estilo]
+//! ```
+//!
+//! Un agente sin `tools` solo conversa. Sin regla que encaje decide el router
+//! integrado.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// Archivo con los agentes y reglas del usuario
+pub const CUSTOM_AGENTS_FILE: &str = "agents.yaml";
+
+/// Se escribe al abrir el archivo por primera vez, como guía
+const EXAMPLE_FILE: &str = "\
+# Agentes propios del asistente. Se aplican al guardar, sin reiniciar.
+#
+# agents:
+#   - name: editor
+#     description: Corrige el estilo de las notas
+#     system_prompt: Eres un editor estricto. Señala cada frase floja.
+#     tools: [read_note, update_note]     # sin tools: solo conversa
+#     model: anthropic/claude-3.5-sonnet  # opcional, si no el configurado
+# rules:
+#   - agent: editor
+#     keywords: [corrige, revisa el estilo]
+agents: []
+rules: []
+";
+
+/// Contenido de `agents.yaml`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomAgents {
+    #[serde(default)]
+    pub agents: Vec<CustomAgent>,
+    #[serde(default)]
+    pub rules: Vec<RoutingRule>,
+}
+
+/// Agente definido por el usuario
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomAgent {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub system_prompt: String,
+    /// Herramientas MCP que puede usar (por nombre, p. ej. `create_note`)
+    #[serde(default)]
+    pub tools: Vec<String>,
+    /// Modelo propio en lugar del configurado
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+}
+
+/// Manda a `agent` los mensajes que contienen alguna de `keywords`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoutingRule {
+    pub agent: String,
+    #[serde(default)]
+    pub keywords: Vec<String>,
+}
+
+impl CustomAgents {
+    /// `agents.yaml` junto a la configuración de la app
+    pub fn default_path() -> PathBuf {
+        dirs::data_local_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("notnative")
+            .join(CUSTOM_AGENTS_FILE)
+    }
+
+    pub fn from_yaml(yaml: &str) -> Result<Self, serde_yaml::Error> {
+        // Un archivo con solo comentarios es un documento nulo
+        Ok(serde_yaml::from_str::<Option<Self>>(yaml)?.unwrap_or_default())
+    }
+
+    /// Lee el archivo; si no existe no hay agentes propios
+    pub fn load(path: &Path) -> Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(yaml) => Self::from_yaml(&yaml)
+                .with_context(|| format!("Archivo de agentes no válido: {:?}", path)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Escribe un archivo de ejemplo si aún no hay uno, para poder editarlo
+    pub fn ensure_file(path: &Path) -> Result<()> {
+        if path.exists() {
+            return Ok(());
+        }
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, EXAMPLE_FILE)?;
+        Ok(())
+    }
+
+    /// Agente por nombre (sin distinguir mayúsculas)
+    pub fn get(&self, name: &str) -> Option<&CustomAgent> {
+        self.agents
+            .iter()
+            .find(|agent| agent.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Agente de la primera regla con alguna palabra clave en `task`
+    pub fn route(&self, task: &str) -> Option<&CustomAgent> {
+        let task = task.to_lowercase();
+        self.rules
+            .iter()
+            .find(|rule| {
+                rule.keywords
+                    .iter()
+                    .any(|keyword| contains_words(&task, &keyword.to_lowercase()))
+            })
+            .and_then(|rule| self.get(&rule.agent))
+    }
+
+    /// Errores de configuración, para avisar al usuario: nombres repetidos,
+    /// reglas a agentes que no existen, herramientas desconocidas…
+    pub fn problems(&self, known_tools: &[String]) -> Vec<String> {
+        let mut problems = Vec::new();
+        for (index, agent) in self.agents.iter().enumerate() {
+            if agent.name.trim().is_empty() {
+                problems.push(format!("El agente {} no tiene nombre", index + 1));
+            } else if self.agents[..index]
+                .iter()
+                .any(|other| other.name.eq_ignore_ascii_case(&agent.name))
+            {
+                problems.push(format!("Agente repetido: {}", agent.name));
+            }
+            if agent.system_prompt.trim().is_empty() {
+                problems.push(format!("{}: falta system_prompt", agent.name));
+            }
+            for tool in &agent.tools {
+                if !known_tools.contains(tool) {
+                    problems.push(format!("{}: herramienta desconocida {}", agent.name, tool));
+                }
+            }
+        }
+        for rule in &self.rules {
+            if self.get(&rule.agent).is_none() {
+                problems.push(format!(
+                    "Regla para un agente que no existe: {}",
+                    rule.agent
+                ));
+            }
+            if rule
+                .keywords
+                .iter()
+                .all(|keyword| keyword.trim().is_empty())
+            {
+                problems.push(format!("Regla sin palabras clave: {}", rule.agent));
+            }
+        }
+        problems
+    }
+}
+
+/// ¿Aparece `needle` en `haystack` como palabras completas? ("poema" no
+/// encaja en "poemario")
+fn contains_words(haystack: &str, needle: &str) -> bool {
+    let needle = needle.trim();
+    if needle.is_empty() {
+        return false;
+    }
+    haystack.match_indices(needle).any(|(start, _)| {
+        let end = start + needle.len();
+        let before = haystack[..start].chars().next_back();
+        let after = haystack[end..].chars().next();
+        !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+    })
+}
+
+/// `agents.yaml` que se vuelve a leer cuando cambia en disco
+#[derive(Debug, Clone)]
+pub struct CustomAgentsFile {
+    path: PathBuf,
+    modified: Option<SystemTime>,
+    agents: CustomAgents,
+}
+
+impl CustomAgentsFile {
+    pub fn new(path: PathBuf) -> Self {
+        let mut file = Self {
+            path,
+            modified: None,
+            agents: CustomAgents::default(),
+        };
+        file.reload_if_changed();
+        file
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Los agentes actuales, releyendo el archivo si cambió. Si la nueva
+    /// versión no es válida se siguen usando los anteriores.
+    pub fn current(&mut self) -> &CustomAgents {
+        self.reload_if_changed();
+        &self.agents
+    }
+
+    fn reload_if_changed(&mut self) {
+        let modified = std::fs::metadata(&self.path)
+            .and_then(|metadata| metadata.modified())
+            .ok();
+        if modified == self.modified {
+            return;
+        }
+        self.modified = modified;
+        match CustomAgents::load(&self.path) {
+            Ok(agents) => {
+                tracing::info!(
+                    "Agentes propios cargados: {} agentes, {} reglas",
+                    agents.agents.len(),
+                    agents.rules.len()
+                );
+                self.agents = agents;
+            }
+            Err(e) => tracing::warn!("{:#}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const YAML: &str = "
+agents:
+  - name: editor
+    system_prompt: Eres un editor estricto.
+    tools: [read_note, update_note]
+    model: anthropic/claude-3.5-sonnet
+  - name: poeta
+    system_prompt: Responde en verso.
+rules:
+  - agent: Editor
+    keywords: [corrige, revisa el estilo]
+  - agent: poeta
+    keywords: [poema]
+";
+
+    #[test]
+    fn test_route_by_keywords() {
+        let agents = CustomAgents::from_yaml(YAML).unwrap();
+        assert_eq!(agents.route("Corrige esta nota").unwrap().name, "editor");
+        assert_eq!(
+            agents
+                .route("¿Puedes revisa el estilo, porfa?")
+                .unwrap()
+                .name,
+            "editor"
+        );
+        assert_eq!(agents.route("Escríbeme un poema").unwrap().name, "poeta");
+        // Palabras completas
+        assert!(agents.route("Lo corrigen en el poemario").is_none());
+        assert!(agents.route("Busca mis notas de Rust").is_none());
+
+        let poeta = agents.get("POETA").unwrap();
+        assert!(poeta.tools.is_empty());
+        assert!(poeta.model.is_none());
+    }
+
+    #[test]
+    fn test_problems() {
+        let known: Vec<String> = vec!["read_note".to_string()];
+        let mut agents = CustomAgents::from_yaml(YAML).unwrap();
+        agents.rules.push(RoutingRule {
+            agent: "nadie".to_string(),
+            keywords: vec![],
+        });
+        let problems = agents.problems(&known);
+        assert_eq!(problems.len(), 3);
+        assert!(problems[0].contains("update_note"));
+        assert!(problems[1].contains("nadie"));
+
+        assert!(
+            CustomAgents::from_yaml(EXAMPLE_FILE)
+                .unwrap()
+                .problems(&known)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_file_reloads_when_changed() {
+        let path = std::env::temp_dir().join("test_custom_agents.yaml");
+        std::fs::remove_file(&path).ok();
+
+        let mut file = CustomAgentsFile::new(path.clone());
+        assert!(file.current().agents.is_empty());
+
+        CustomAgents::ensure_file(&path).unwrap();
+        assert!(file.current().agents.is_empty());
+
+        std::fs::write(&path, YAML).unwrap();
+        // Forzar otra fecha de modificación aunque el sistema de archivos
+        // tenga poca resolución
+        file.modified = Some(SystemTime::UNIX_EPOCH);
+        assert_eq!(file.current().agents.len(), 2);
+
+        // Un archivo roto no borra los agentes que había
+        std::fs::write(&path, "agents: [").unwrap();
+        file.modified = Some(SystemTime::UNIX_EPOCH);
+        assert_eq!(file.current().agents.len(), 2);
+
+        std::fs::remove_file(&path).ok();
+    }
+}
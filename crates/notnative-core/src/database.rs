@@ -2,6 +2,14 @@ use chrono::{DateTime, Utc};
 use rusqlite::{Connection, OptionalExtension, Result as SqliteResult, params};
 use std::path::{Path, PathBuf};
 use thiserror::Error;
+use tracing::{debug, error, info, warn};
+
+use super::chat_export::{ExportedMessage, ExportedSession, ExportedStep, attach_steps};
+use super::db_pool::{DbPool, PooledConnection};
+use super::flashcards::{ReviewGrade, Sm2State};
+use super::migrations::{self, Migration};
+use super::text_chunker::chunk_source;
+use super::word_goal::{GoalCompletion, WordGoal};
 
 #[derive(Debug, Error)]
 pub enum DatabaseError {
@@ -16,6 +24,15 @@ pub enum DatabaseError {
 
     #[error("Tag not found: {0}")]
     TagNotFound(String),
+
+    #[error("Chat session not found: {0}")]
+    ChatSessionNotFound(i64),
+
+    #[error("Flashcard not found: {0}")]
+    FlashcardNotFound(i64),
+
+    #[error("Database actor stopped")]
+    ActorStopped,
 }
 
 pub type Result<T> = std::result::Result<T, DatabaseError>;
@@ -88,10 +105,84 @@ pub struct GlobalEmbedding {
 #[derive(Debug, Clone, Default)]
 pub struct SearchQuery {
     pub text: Option<String>,
+    /// Tags que deben estar todos (también valen sus sub-tags)
     pub tags: Vec<String>,
+    /// Carpeta (incluye sus subcarpetas)
     pub folder: Option<String>,
     pub date_from: Option<DateTime<Utc>>,
     pub date_to: Option<DateTime<Utc>>,
+    /// Solo notas con alguna tarea sin marcar (`- [ ]`)
+    pub has_todo: bool,
+    /// Solo notas con algún recordatorio sin completar
+    pub has_reminder: bool,
+    pub sort: SearchSort,
+    /// Máximo de resultados (por defecto 50)
+    pub limit: Option<usize>,
+}
+
+impl SearchQuery {
+    /// ¿Hay algún filtro u orden distinto del de por defecto (aparte del texto)?
+    pub fn has_filters(&self) -> bool {
+        !self.tags.is_empty()
+            || self.folder.is_some()
+            || self.date_from.is_some()
+            || self.date_to.is_some()
+            || self.has_todo
+            || self.has_reminder
+            || self.sort != SearchSort::Relevance
+    }
+}
+
+/// Orden de los resultados de búsqueda
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SearchSort {
+    /// Coincidencia del texto (sin texto, por nombre)
+    #[default]
+    Relevance,
+    /// Modificadas más recientemente primero
+    Modified,
+    /// Creadas más recientemente primero
+    Created,
+    /// Por nombre, A-Z
+    Title,
+}
+
+impl SearchSort {
+    pub const ALL: [SearchSort; 4] = [
+        SearchSort::Relevance,
+        SearchSort::Modified,
+        SearchSort::Created,
+        SearchSort::Title,
+    ];
+}
+
+/// Posición de lectura guardada de una nota
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReadingPosition {
+    /// Offset del cursor en caracteres
+    pub cursor_offset: usize,
+    /// Scroll como fracción (0.0 arriba, 1.0 abajo del todo)
+    pub scroll_fraction: f64,
+}
+
+/// Nota de la cola de lectura
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReadingQueueEntry {
+    pub name: String,
+    pub added_at: DateTime<Utc>,
+    /// Lo más lejos que se ha llegado leyendo (0.0 a 1.0)
+    pub progress: f64,
+}
+
+/// Tarjeta de repaso con su nota y su estado SM-2
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeckCard {
+    pub id: i64,
+    pub note_name: String,
+    pub question: String,
+    pub answer: String,
+    pub state: Sm2State,
+    pub due_at: DateTime<Utc>,
 }
 
 /// Fila de propiedad inline de la base de datos
@@ -178,7 +269,8 @@ impl GroupedRecord {
 
 /// Base de datos SQLite para indexar notas
 pub struct NotesDatabase {
-    conn: Connection,
+    conn: PooledConnection,
+    pool: DbPool,
     path: PathBuf,
 }
 
@@ -192,7 +284,106 @@ impl std::fmt::Debug for NotesDatabase {
 
 impl NotesDatabase {
     /// Versión actual del esquema
-    const SCHEMA_VERSION: i32 = 10;
+    const SCHEMA_VERSION: i32 = 19;
+
+    /// Migraciones del esquema, en orden (ver [`migrations`])
+    const MIGRATIONS: &'static [Migration] = &[
+        Migration {
+            version: 1,
+            description: "Esquema base (notas, tags, FTS, chat y embeddings)",
+            apply: Self::initialize_schema,
+        },
+        Migration {
+            version: 2,
+            description: "Tabla de embeddings",
+            apply: Self::migrate_to_v2,
+        },
+        Migration {
+            version: 3,
+            description: "Tabla de caché de queries",
+            apply: Self::migrate_to_v3,
+        },
+        Migration {
+            version: 4,
+            description: "Tabla de recordatorios",
+            apply: Self::migrate_to_v4,
+        },
+        Migration {
+            version: 5,
+            description: "Tabla FTS sin Porter (mejor búsqueda por prefijo)",
+            apply: Self::migrate_to_v5,
+        },
+        Migration {
+            version: 6,
+            description: "Iconos de notas y tabla de carpetas",
+            apply: Self::migrate_to_v6,
+        },
+        Migration {
+            version: 7,
+            description: "Color de iconos en notas y carpetas",
+            apply: Self::migrate_to_v7,
+        },
+        Migration {
+            version: 8,
+            description: "Tablas para Bases",
+            apply: Self::migrate_to_v8,
+        },
+        Migration {
+            version: 9,
+            description: "Sistema de propiedades inline",
+            apply: Self::migrate_to_v9,
+        },
+        Migration {
+            version: 10,
+            description: "Propiedades agrupadas con group_id",
+            apply: Self::migrate_to_v10,
+        },
+        Migration {
+            version: 11,
+            description: "Posición de lectura por nota",
+            apply: Self::migrate_to_v11,
+        },
+        Migration {
+            version: 12,
+            description: "Historial de recordatorios disparados",
+            apply: Self::migrate_to_v12,
+        },
+        Migration {
+            version: 13,
+            description: "Nota y línea de origen de los recordatorios",
+            apply: Self::migrate_to_v13,
+        },
+        Migration {
+            version: 14,
+            description: "Ramas de las sesiones de chat",
+            apply: Self::migrate_to_v14,
+        },
+        Migration {
+            version: 15,
+            description: "Pasos del agente en las sesiones de chat",
+            apply: Self::migrate_to_v15,
+        },
+        Migration {
+            version: 16,
+            description: "Alias de las notas",
+            apply: Self::migrate_to_v16,
+        },
+        Migration {
+            version: 17,
+            description: "Cola de lectura",
+            apply: Self::migrate_to_v17,
+        },
+        Migration {
+            version: 18,
+            description: "Tarjetas de repaso",
+            apply: Self::migrate_to_v18,
+        },
+        Migration {
+            version: 19,
+            description: "Objetivos de palabras alcanzados",
+            apply: Self::migrate_to_v19,
+        },
+    ];
 
     /// Crear o abrir base de datos en la ruta especificada
     pub fn new(path: &Path) -> Result<Self> {
@@ -201,33 +392,46 @@ impl NotesDatabase {
             std::fs::create_dir_all(parent)?;
         }
 
-        let conn = Connection::open(path)?;
+        let pool = DbPool::shared(path);
+        pool.reset_if_missing();
+
+        let conn = pool.get()?;
+        let version = migrations::run(&conn, Self::MIGRATIONS)?;
+        if version > Self::SCHEMA_VERSION {
+            warn!(
+                "La base de datos usa el esquema v{} (esta versión conoce hasta v{})",
+                version,
+                Self::SCHEMA_VERSION
+            );
+        }
 
-        let mut db = Self {
+        Ok(Self {
             conn,
+            pool,
             path: path.to_path_buf(),
-        };
-
-        // Primero crear tabla de versión si no existe
-        db.ensure_version_table()?;
-
-        // Inicializar esquema completo (antes de migraciones para que existan las tablas base)
-        db.initialize_schema()?;
-
-        // Verificar versión actual y migrar si es necesario
-        db.migrate_if_needed()?;
-
-        Ok(db)
+        })
     }
 
-    /// Clona la conexión abriendo una nueva conexión a la misma base de datos
+    /// Otra conexión a la misma base de datos, tomada del pool compartido
     pub fn clone_connection(&self) -> Self {
-        let conn =
-            Connection::open(&self.path).expect("No se pudo clonar la conexión a la base de datos");
-        Self {
+        Self::from_pool(self.pool.clone())
+            .expect("No se pudo clonar la conexión a la base de datos")
+    }
+
+    /// Conexión del pool de una base de datos ya abierta (y migrada) con
+    /// [`NotesDatabase::new`]
+    pub fn from_pool(pool: DbPool) -> Result<Self> {
+        let conn = pool.get()?;
+        Ok(Self {
             conn,
-            path: self.path.clone(),
-        }
+            path: pool.path().to_path_buf(),
+            pool,
+        })
+    }
+
+    /// Pool de conexiones compartido de esta base de datos
+    pub fn pool(&self) -> &DbPool {
+        &self.pool
     }
 
     /// Obtiene el path de la base de datos
@@ -253,6 +457,33 @@ impl NotesDatabase {
         Ok(())
     }
 
+    /// Si hay una transacción abierta en esta conexión
+    pub fn in_transaction(&self) -> bool {
+        !self.conn.is_autocommit()
+    }
+
+    /// Ejecuta `f` en una transacción: se confirma si devuelve `Ok` y se
+    /// revierte si no. Con una transacción ya abierta, `f` pasa a formar
+    /// parte de ella.
+    pub fn transaction<T>(&self, f: impl FnOnce(&Self) -> Result<T>) -> Result<T> {
+        if self.in_transaction() {
+            return f(self);
+        }
+        self.begin_transaction()?;
+        match f(self) {
+            Ok(value) => {
+                self.commit_transaction()?;
+                Ok(value)
+            }
+            Err(e) => {
+                if let Err(rollback) = self.rollback_transaction() {
+                    error!("Error revirtiendo la transacción: {}", rollback);
+                }
+                Err(e)
+            }
+        }
+    }
+
     /// Verifica si una nota necesita re-indexarse basándose en el timestamp del archivo
     /// Retorna true si el archivo fue modificado después del último indexado
     pub fn needs_reindex(&self, path: &str, file_mtime: i64) -> Result<bool> {
@@ -271,36 +502,9 @@ impl NotesDatabase {
         }
     }
 
-    /// Asegurar que existe la tabla de versión
-    fn ensure_version_table(&mut self) -> Result<()> {
-        self.conn.execute_batch(
-            r#"
-            CREATE TABLE IF NOT EXISTS schema_version (
-                version INTEGER PRIMARY KEY
-            );
-            "#,
-        )?;
-
-        // Verificar si hay una versión registrada
-        let version_exists: bool = self
-            .conn
-            .query_row("SELECT COUNT(*) FROM schema_version", [], |row| {
-                row.get::<_, i64>(0)
-            })
-            .map(|count| count > 0)?;
-
-        // Si no hay versión, insertar la versión 1 (asumimos base de datos nueva o antigua)
-        if !version_exists {
-            self.conn
-                .execute("INSERT INTO schema_version (version) VALUES (1)", [])?;
-        }
-
-        Ok(())
-    }
-
     /// Inicializar esquema de base de datos
-    fn initialize_schema(&mut self) -> Result<()> {
-        self.conn.execute_batch(
+    fn initialize_schema(conn: &Connection) -> SqliteResult<()> {
+        conn.execute_batch(
             r#"
             -- Tabla principal de notas
             CREATE TABLE IF NOT EXISTS notes (
@@ -403,91 +607,9 @@ impl NotesDatabase {
         Ok(())
     }
 
-    /// Verificar y ejecutar migraciones si es necesario
-    fn migrate_if_needed(&mut self) -> Result<()> {
-        let current_version: i32 =
-            self.conn
-                .query_row("SELECT version FROM schema_version", [], |row| row.get(0))?;
-
-        if current_version < Self::SCHEMA_VERSION {
-            // Solo mostrar mensaje si realmente necesitamos migrar
-            println!(
-                "Migrando base de datos de v{} a v{}",
-                current_version,
-                Self::SCHEMA_VERSION
-            );
-
-            // Migración v1 -> v2: Agregar tabla de embeddings
-            if current_version < 2 {
-                self.migrate_to_v2()?;
-            }
-
-            // Migración v2 -> v3: Agregar tabla de caché de queries
-            if current_version < 3 {
-                self.migrate_to_v3()?;
-            }
-
-            // Migración v3 -> v4: Agregar tabla de recordatorios
-            if current_version < 4 {
-                self.migrate_to_v4()?;
-            }
-
-            // Migración v4 -> v5: Recrear tabla FTS sin Porter (mejor búsqueda por prefijo)
-            if current_version < 5 {
-                self.migrate_to_v5()?;
-            }
-
-            // Migración v5 -> v6: Agregar iconos a notas y tabla de carpetas
-            if current_version < 6 {
-                self.migrate_to_v6()?;
-            }
-
-            // Migración v6 -> v7: Agregar icon_color a notas y carpetas
-            if current_version < 7 {
-                self.migrate_to_v7()?;
-            }
-
-            // Migración v7 -> v8: Agregar tablas para Bases (vistas de base de datos)
-            if current_version < 8 {
-                self.migrate_to_v8()?;
-            }
-
-            // Migración v8 -> v9: Nuevo sistema de propiedades inline
-            if current_version < 9 {
-                self.migrate_to_v9()?;
-            }
-
-            // Migración v9 -> v10: Propiedades agrupadas con group_id
-            if current_version < 10 {
-                self.migrate_to_v10()?;
-            }
-
-            println!(
-                "✅ Migraciones completadas - BD actualizada a v{}",
-                Self::SCHEMA_VERSION
-            );
-        }
-
-        Ok(())
-    }
-
     /// Migración a versión 2: Agregar tabla de embeddings
-    fn migrate_to_v2(&mut self) -> Result<()> {
-        // Verificar si la tabla ya existe
-        let table_exists: bool = self
-            .conn
-            .query_row(
-                "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='note_embeddings'",
-                [],
-                |row| row.get::<_, i64>(0),
-            )
-            .map(|count| count > 0)?;
-
-        if !table_exists {
-            println!("Aplicando migración v2: Agregando tabla de embeddings");
-        }
-
-        self.conn.execute_batch(
+    fn migrate_to_v2(conn: &Connection) -> SqliteResult<()> {
+        conn.execute_batch(
             r#"
             -- Tabla de embeddings para búsqueda semántica
             CREATE TABLE IF NOT EXISTS note_embeddings (
@@ -508,30 +630,12 @@ impl NotesDatabase {
             "#,
         )?;
 
-        // Actualizar versión usando REPLACE (elimina e inserta)
-        self.conn
-            .execute("REPLACE INTO schema_version (version) VALUES (2)", [])?;
-
         Ok(())
     }
 
     /// Migración a versión 3: Agregar tabla de caché de queries
-    fn migrate_to_v3(&mut self) -> Result<()> {
-        // Verificar si la tabla ya existe
-        let table_exists: bool = self
-            .conn
-            .query_row(
-                "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='query_cache'",
-                [],
-                |row| row.get::<_, i64>(0),
-            )
-            .map(|count| count > 0)?;
-
-        if !table_exists {
-            println!("Aplicando migración v3: Agregando tabla de caché de queries");
-        }
-
-        self.conn.execute_batch(
+    fn migrate_to_v3(conn: &Connection) -> SqliteResult<()> {
+        conn.execute_batch(
             r#"
             -- Tabla de caché para queries de búsqueda semántica
             CREATE TABLE IF NOT EXISTS query_cache (
@@ -550,30 +654,12 @@ impl NotesDatabase {
             "#,
         )?;
 
-        // Actualizar versión
-        self.conn
-            .execute("REPLACE INTO schema_version (version) VALUES (3)", [])?;
-
         Ok(())
     }
 
     /// Migración a versión 4: Agregar tabla de recordatorios
-    fn migrate_to_v4(&mut self) -> Result<()> {
-        // Verificar si la tabla ya existe
-        let table_exists: bool = self
-            .conn
-            .query_row(
-                "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='reminders'",
-                [],
-                |row| row.get::<_, i64>(0),
-            )
-            .map(|count| count > 0)?;
-
-        if !table_exists {
-            println!("Aplicando migración v4: Agregando tabla de recordatorios");
-        }
-
-        self.conn.execute_batch(
+    fn migrate_to_v4(conn: &Connection) -> SqliteResult<()> {
+        conn.execute_batch(
             r#"
             -- Tabla de recordatorios
             CREATE TABLE IF NOT EXISTS reminders (
@@ -597,23 +683,14 @@ impl NotesDatabase {
             "#,
         )?;
 
-        // Actualizar versión
-        self.conn
-            .execute("REPLACE INTO schema_version (version) VALUES (4)", [])?;
-
         Ok(())
     }
 
     /// Migración a versión 5: Recrear tabla FTS sin tokenizer Porter
     /// El tokenizer Porter causa problemas con búsqueda por prefijo (ej: "key" no encuentra "keybindings")
-    fn migrate_to_v5(&mut self) -> Result<()> {
-        println!(
-            "Aplicando migración v5: Recreando tabla FTS sin Porter para mejor búsqueda por prefijo"
-        );
-
+    fn migrate_to_v5(conn: &Connection) -> SqliteResult<()> {
         // Verificar si la tabla notes_fts existe
-        let fts_exists: bool = self
-            .conn
+        let fts_exists: bool = conn
             .query_row(
                 "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='notes_fts'",
                 [],
@@ -623,9 +700,7 @@ impl NotesDatabase {
 
         // 1. Obtener todos los datos actuales de la tabla FTS (solo si existe)
         let notes_data: Vec<(i64, String, String)> = if fts_exists {
-            let mut stmt = self
-                .conn
-                .prepare("SELECT rowid, name, content FROM notes_fts")?;
+            let mut stmt = conn.prepare("SELECT rowid, name, content FROM notes_fts")?;
             stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?
                 .filter_map(|r| r.ok())
                 .collect()
@@ -633,13 +708,13 @@ impl NotesDatabase {
             Vec::new()
         };
 
-        println!("  📦 Respaldando {} entradas de FTS", notes_data.len());
+        debug!("Respaldando {} entradas de FTS", notes_data.len());
 
         // 2. Eliminar la tabla FTS antigua (si existe)
-        self.conn.execute("DROP TABLE IF EXISTS notes_fts", [])?;
+        conn.execute("DROP TABLE IF EXISTS notes_fts", [])?;
 
         // 3. Crear la nueva tabla FTS con unicode61 (sin Porter)
-        self.conn.execute_batch(
+        conn.execute_batch(
             r#"
             CREATE VIRTUAL TABLE notes_fts USING fts5(
                 name,
@@ -651,28 +726,21 @@ impl NotesDatabase {
 
         // 4. Reinsertar los datos
         for (rowid, name, content) in &notes_data {
-            self.conn.execute(
+            conn.execute(
                 "INSERT INTO notes_fts (rowid, name, content) VALUES (?1, ?2, ?3)",
                 params![rowid, name, content],
             )?;
         }
 
-        println!("  ✅ Tabla FTS recreada con {} entradas", notes_data.len());
-
-        // Actualizar versión
-        self.conn
-            .execute("REPLACE INTO schema_version (version) VALUES (5)", [])?;
+        debug!("Tabla FTS recreada con {} entradas", notes_data.len());
 
         Ok(())
     }
 
     /// Migración a versión 6: Agregar iconos a notas y tabla de carpetas
-    fn migrate_to_v6(&mut self) -> Result<()> {
-        println!("Aplicando migración v6: Agregando soporte de iconos personalizados");
-
+    fn migrate_to_v6(conn: &Connection) -> SqliteResult<()> {
         // Verificar si la tabla notes existe
-        let notes_exists: bool = self
-            .conn
+        let notes_exists: bool = conn
             .query_row(
                 "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='notes'",
                 [],
@@ -682,8 +750,7 @@ impl NotesDatabase {
 
         // 1. Agregar columna icon a la tabla notes (si existe y no tiene la columna)
         if notes_exists {
-            let has_icon_column: bool = self
-                .conn
+            let has_icon_column: bool = conn
                 .query_row(
                     "SELECT COUNT(*) FROM pragma_table_info('notes') WHERE name='icon'",
                     [],
@@ -692,14 +759,13 @@ impl NotesDatabase {
                 .map(|count| count > 0)?;
 
             if !has_icon_column {
-                self.conn
-                    .execute("ALTER TABLE notes ADD COLUMN icon TEXT", [])?;
-                println!("  📦 Columna 'icon' agregada a tabla notes");
+                conn.execute("ALTER TABLE notes ADD COLUMN icon TEXT", [])?;
+                debug!("Columna 'icon' agregada a tabla notes");
             }
         }
 
         // 2. Crear tabla de carpetas para iconos y metadatos
-        self.conn.execute_batch(
+        conn.execute_batch(
             r#"
             -- Tabla de carpetas con iconos personalizados
             CREATE TABLE IF NOT EXISTS folders (
@@ -717,22 +783,15 @@ impl NotesDatabase {
             CREATE INDEX IF NOT EXISTS idx_folders_order ON folders(order_index);
             "#,
         )?;
-        println!("  📁 Tabla 'folders' creada");
-
-        // Actualizar versión
-        self.conn
-            .execute("REPLACE INTO schema_version (version) VALUES (6)", [])?;
+        debug!("Tabla 'folders' creada");
 
         Ok(())
     }
 
     /// Migración a versión 7: Agregar icon_color a notas y carpetas
-    fn migrate_to_v7(&mut self) -> Result<()> {
-        println!("Aplicando migración v7: Agregando soporte de color para iconos");
-
+    fn migrate_to_v7(conn: &Connection) -> SqliteResult<()> {
         // Verificar si la tabla notes existe
-        let notes_exists: bool = self
-            .conn
+        let notes_exists: bool = conn
             .query_row(
                 "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='notes'",
                 [],
@@ -741,8 +800,7 @@ impl NotesDatabase {
             .map(|count| count > 0)?;
 
         // Verificar si la tabla folders existe
-        let folders_exists: bool = self
-            .conn
+        let folders_exists: bool = conn
             .query_row(
                 "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='folders'",
                 [],
@@ -752,8 +810,7 @@ impl NotesDatabase {
 
         // 1. Agregar columna icon_color a la tabla notes (si existe y no tiene la columna)
         if notes_exists {
-            let has_icon_color_in_notes: bool = self
-                .conn
+            let has_icon_color_in_notes: bool = conn
                 .query_row(
                     "SELECT COUNT(*) FROM pragma_table_info('notes') WHERE name='icon_color'",
                     [],
@@ -762,16 +819,14 @@ impl NotesDatabase {
                 .map(|count| count > 0)?;
 
             if !has_icon_color_in_notes {
-                self.conn
-                    .execute("ALTER TABLE notes ADD COLUMN icon_color TEXT", [])?;
-                println!("  🎨 Columna 'icon_color' agregada a tabla notes");
+                conn.execute("ALTER TABLE notes ADD COLUMN icon_color TEXT", [])?;
+                debug!("Columna 'icon_color' agregada a tabla notes");
             }
         }
 
         // 2. Agregar columna icon_color a la tabla folders (si existe y no tiene la columna)
         if folders_exists {
-            let has_icon_color_in_folders: bool = self
-                .conn
+            let has_icon_color_in_folders: bool = conn
                 .query_row(
                     "SELECT COUNT(*) FROM pragma_table_info('folders') WHERE name='icon_color'",
                     [],
@@ -780,24 +835,17 @@ impl NotesDatabase {
                 .map(|count| count > 0)?;
 
             if !has_icon_color_in_folders {
-                self.conn
-                    .execute("ALTER TABLE folders ADD COLUMN icon_color TEXT", [])?;
-                println!("  🎨 Columna 'icon_color' agregada a tabla folders");
+                conn.execute("ALTER TABLE folders ADD COLUMN icon_color TEXT", [])?;
+                debug!("Columna 'icon_color' agregada a tabla folders");
             }
         }
 
-        // Actualizar versión
-        self.conn
-            .execute("REPLACE INTO schema_version (version) VALUES (7)", [])?;
-
         Ok(())
     }
 
     /// Migración a versión 8: Agregar tablas para Bases (vistas tipo Obsidian)
-    fn migrate_to_v8(&mut self) -> Result<()> {
-        println!("Aplicando migración v8: Agregando tablas para Bases");
-
-        self.conn.execute_batch(
+    fn migrate_to_v8(conn: &Connection) -> SqliteResult<()> {
+        conn.execute_batch(
             r#"
             -- Tabla de propiedades indexadas de notas
             -- Permite queries rápidas sobre propiedades del frontmatter
@@ -840,24 +888,17 @@ impl NotesDatabase {
             "#,
         )?;
 
-        println!("  📊 Tablas 'note_properties' y 'bases' creadas");
-
-        // Actualizar versión
-        self.conn
-            .execute("REPLACE INTO schema_version (version) VALUES (8)", [])?;
+        debug!("Tablas 'note_properties' y 'bases' creadas");
 
         Ok(())
     }
 
     /// Migración a versión 9: Nuevo sistema de propiedades inline [campo::valor]
-    fn migrate_to_v9(&mut self) -> Result<()> {
-        println!("Aplicando migración v9: Sistema de propiedades inline");
-
+    fn migrate_to_v9(conn: &Connection) -> SqliteResult<()> {
         // Eliminar la tabla vieja note_properties (basada en frontmatter, nunca usada)
-        self.conn
-            .execute("DROP TABLE IF EXISTS note_properties", [])?;
+        conn.execute("DROP TABLE IF EXISTS note_properties", [])?;
 
-        self.conn.execute_batch(
+        conn.execute_batch(
             r#"
             -- Nueva tabla para propiedades inline [campo::valor]
             -- Permite múltiples propiedades con la misma clave en una nota
@@ -889,21 +930,15 @@ impl NotesDatabase {
             "#,
         )?;
 
-        println!("  🏷️ Tabla 'inline_properties' creada (reemplaza note_properties)");
-
-        // Actualizar versión
-        self.conn
-            .execute("REPLACE INTO schema_version (version) VALUES (9)", [])?;
+        debug!("Tabla 'inline_properties' creada (reemplaza note_properties)");
 
         Ok(())
     }
 
     /// Migración a versión 10: Propiedades agrupadas [campo1::val1, campo2::val2]
-    fn migrate_to_v10(&mut self) -> Result<()> {
-        println!("Aplicando migración v10: Propiedades agrupadas con group_id");
-
+    fn migrate_to_v10(conn: &Connection) -> SqliteResult<()> {
         // Verificar si la columna group_id ya existe
-        let column_exists: bool = self.conn.query_row(
+        let column_exists: bool = conn.query_row(
             "SELECT COUNT(*) > 0 FROM pragma_table_info('inline_properties') WHERE name = 'group_id'",
             [],
             |row| row.get(0),
@@ -912,115 +947,413 @@ impl NotesDatabase {
         if !column_exists {
             // Añadir columna group_id para agrupar propiedades relacionadas
             // NULL significa propiedad individual, un número agrupa propiedades del mismo "registro"
-            self.conn.execute(
+            conn.execute(
                 "ALTER TABLE inline_properties ADD COLUMN group_id INTEGER",
                 [],
             )?;
-            println!("  🔗 Columna 'group_id' añadida a inline_properties");
+            debug!("Columna 'group_id' añadida a inline_properties");
         } else {
-            println!("  ℹ️ Columna 'group_id' ya existe, saltando...");
+            debug!("ℹ️ Columna 'group_id' ya existe, saltando...");
         }
 
         // Índice para consultas por grupo (IF NOT EXISTS es seguro)
-        self.conn.execute(
+        conn.execute(
             "CREATE INDEX IF NOT EXISTS idx_inline_props_group ON inline_properties(note_id, group_id)",
             [],
         )?;
 
-        // Actualizar versión
-        self.conn
-            .execute("REPLACE INTO schema_version (version) VALUES (10)", [])?;
-
         Ok(())
     }
 
-    /// Indexar una nota en la base de datos
-    pub fn index_note(
-        &self,
-        name: &str,
-        path: &str,
-        content: &str,
-        folder: Option<&str>,
-    ) -> Result<i64> {
-        let now = Utc::now().timestamp();
-
-        // Insertar o actualizar nota (manejar conflictos tanto en name como en path)
-        self.conn.execute(
+    /// Migración a versión 11: Posición de lectura (cursor y scroll) de cada nota
+    fn migrate_to_v11(conn: &Connection) -> SqliteResult<()> {
+        conn.execute_batch(
             r#"
-            INSERT INTO notes (name, path, folder, created_at, updated_at)
-            VALUES (?1, ?2, ?3, ?4, ?5)
-            ON CONFLICT(path) DO UPDATE SET
-                name = excluded.name,
-                folder = excluded.folder,
-                updated_at = excluded.updated_at
+            CREATE TABLE IF NOT EXISTS reading_positions (
+                note_id INTEGER PRIMARY KEY,
+                cursor_offset INTEGER NOT NULL,
+                scroll_fraction REAL NOT NULL,
+                updated_at INTEGER NOT NULL,
+                FOREIGN KEY (note_id) REFERENCES notes(id) ON DELETE CASCADE
+            );
             "#,
-            params![name, path, folder, now, now],
         )?;
 
-        // Obtener el ID de la nota (puede ser nueva o existente)
-        let note_id: i64 = self.conn.query_row(
-            "SELECT id FROM notes WHERE path = ?1",
-            params![path],
-            |row| row.get(0),
-        )?;
+        debug!("Tabla 'reading_positions' creada");
 
-        // Indexar en FTS5
-        self.conn.execute(
-            "INSERT OR REPLACE INTO notes_fts (rowid, name, content) VALUES (?1, ?2, ?3)",
-            params![note_id, name, content],
-        )?;
+        Ok(())
+    }
 
-        // Sincronizar propiedades inline del contenido
-        self.sync_inline_properties(note_id, content)?;
+    /// Migración a versión 12: Historial de recordatorios que saltaron y cómo
+    /// acabaron. Sin clave foránea: la entrada sobrevive al recordatorio.
+    fn migrate_to_v12(conn: &Connection) -> SqliteResult<()> {
+        conn.execute_batch(
+            r#"
+            CREATE TABLE IF NOT EXISTS reminder_history (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                reminder_id INTEGER NOT NULL UNIQUE,
+                note_id INTEGER,
+                title TEXT NOT NULL,
+                due_date INTEGER NOT NULL,
+                fired_at INTEGER NOT NULL,
+                snooze_count INTEGER NOT NULL DEFAULT 0,
+                outcome INTEGER NOT NULL DEFAULT 0,
+                resolved_at INTEGER
+            );
 
-        // Sincronizar tags del contenido (frontmatter + inline #tags)
-        self.sync_note_tags(note_id, content)?;
+            CREATE INDEX IF NOT EXISTS idx_reminder_history_fired ON reminder_history(fired_at);
+            "#,
+        )?;
 
-        Ok(note_id)
+        debug!("Tabla 'reminder_history' creada");
+
+        Ok(())
     }
 
-    /// Sincronizar tags de una nota (elimina antiguos y añade nuevos)
-    fn sync_note_tags(&self, note_id: i64, content: &str) -> Result<()> {
-        use super::frontmatter::extract_all_tags;
+    /// Migración a versión 13: Nota y línea de donde sale cada recordatorio
+    /// escrito en una nota (NULL en los creados desde el popover o el chat)
+    fn migrate_to_v13(conn: &Connection) -> SqliteResult<()> {
+        conn.execute_batch(
+            r#"
+            ALTER TABLE reminders ADD COLUMN source_path TEXT;
+            ALTER TABLE reminders ADD COLUMN source_line INTEGER;
+            "#,
+        )?;
 
-        // Extraer tags del contenido (frontmatter + inline)
-        let tags = extract_all_tags(content);
+        debug!("Columnas 'source_path' y 'source_line' añadidas a reminders");
 
-        // Obtener tags actuales de la nota
-        let current_tags: Vec<String> = self
-            .get_note_tags(note_id)?
-            .into_iter()
-            .map(|t| t.name)
-            .collect();
+        Ok(())
+    }
 
-        // Tags a eliminar (están en current pero no en tags)
-        for tag in &current_tags {
-            if !tags.iter().any(|t| t.to_lowercase() == tag.to_lowercase()) {
-                let _ = self.remove_tag(note_id, tag);
-            }
-        }
+    /// Migración a versión 14: Sesión de la que sale cada rama del chat y
+    /// último mensaje que comparten (NULL en las sesiones normales)
+    fn migrate_to_v14(conn: &Connection) -> SqliteResult<()> {
+        conn.execute_batch(
+            r#"
+            ALTER TABLE chat_sessions ADD COLUMN parent_session_id INTEGER;
+            ALTER TABLE chat_sessions ADD COLUMN branched_from_message_id INTEGER;
+            "#,
+        )?;
 
-        // Tags a añadir (están en tags pero no en current)
-        for tag in &tags {
-            if !current_tags
-                .iter()
-                .any(|t| t.to_lowercase() == tag.to_lowercase())
-            {
-                let _ = self.add_tag(note_id, tag);
-            }
-        }
+        debug!("Columnas de ramas añadidas a chat_sessions");
 
         Ok(())
     }
 
-    /// Sincronizar propiedades inline [campo::valor] de una nota
-    pub fn sync_inline_properties(&self, note_id: i64, content: &str) -> Result<()> {
-        use super::inline_property::InlinePropertyParser;
-        use super::property::PropertyValue;
+    /// Migración a versión 15: Pasos del agente (razonamiento, herramientas y
+    /// resultados) con el mensaje tras el que ocurrieron, para exportarlos
+    fn migrate_to_v15(conn: &Connection) -> SqliteResult<()> {
+        conn.execute_batch(
+            r#"
+            CREATE TABLE IF NOT EXISTS chat_agent_steps (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                session_id INTEGER NOT NULL,
+                after_message_id INTEGER,
+                kind TEXT NOT NULL,
+                tool TEXT,
+                content TEXT NOT NULL,
+                created_at INTEGER NOT NULL
+            );
+            CREATE INDEX IF NOT EXISTS idx_chat_agent_steps_session
+                ON chat_agent_steps(session_id);
+            "#,
+        )?;
 
-        let now = Utc::now().timestamp();
+        debug!("Tabla 'chat_agent_steps' creada");
 
-        // Parsear propiedades del contenido
+        Ok(())
+    }
+
+    /// Migración a versión 16: Alias del frontmatter (`aliases:`) de cada
+    /// nota. Van por ID, así que siguen apuntando a la nota al renombrarla.
+    fn migrate_to_v16(conn: &Connection) -> SqliteResult<()> {
+        conn.execute_batch(
+            r#"
+            CREATE TABLE IF NOT EXISTS note_aliases (
+                note_id INTEGER NOT NULL,
+                alias TEXT NOT NULL COLLATE NOCASE,
+                PRIMARY KEY (note_id, alias),
+                FOREIGN KEY (note_id) REFERENCES notes(id) ON DELETE CASCADE
+            );
+            CREATE INDEX IF NOT EXISTS idx_note_aliases_alias ON note_aliases(alias);
+            "#,
+        )?;
+
+        debug!("Tabla 'note_aliases' creada");
+
+        Ok(())
+    }
+
+    /// Migración a versión 17: Notas marcadas para leer más tarde y cuánto
+    /// se ha leído de cada una
+    fn migrate_to_v17(conn: &Connection) -> SqliteResult<()> {
+        conn.execute_batch(
+            r#"
+            CREATE TABLE IF NOT EXISTS reading_queue (
+                note_id INTEGER PRIMARY KEY,
+                added_at INTEGER NOT NULL,
+                progress REAL NOT NULL DEFAULT 0,
+                FOREIGN KEY (note_id) REFERENCES notes(id) ON DELETE CASCADE
+            );
+            "#,
+        )?;
+
+        debug!("Tabla 'reading_queue' creada");
+
+        Ok(())
+    }
+
+    /// Migración a versión 18: Tarjetas de estudio sacadas de las notas y su
+    /// estado de repaso (SM-2). Se identifican por nota y pregunta, así que
+    /// editar la respuesta no pierde el historial.
+    fn migrate_to_v18(conn: &Connection) -> SqliteResult<()> {
+        conn.execute_batch(
+            r#"
+            CREATE TABLE IF NOT EXISTS flashcards (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                note_id INTEGER NOT NULL,
+                question TEXT NOT NULL,
+                answer TEXT NOT NULL,
+                ease REAL NOT NULL DEFAULT 2.5,
+                interval_days INTEGER NOT NULL DEFAULT 0,
+                repetitions INTEGER NOT NULL DEFAULT 0,
+                due_at INTEGER NOT NULL,
+                reviewed_at INTEGER,
+                UNIQUE (note_id, question),
+                FOREIGN KEY (note_id) REFERENCES notes(id) ON DELETE CASCADE
+            );
+            CREATE INDEX IF NOT EXISTS idx_flashcards_due ON flashcards(due_at);
+            "#,
+        )?;
+
+        debug!("Tabla 'flashcards' creada");
+
+        Ok(())
+    }
+
+    /// Migración a versión 19: Notas que llegaron a su objetivo de palabras
+    /// (`goal:` en el frontmatter), una vez por nota y objetivo. Sin clave
+    /// foránea: las estadísticas sobreviven a la nota.
+    fn migrate_to_v19(conn: &Connection) -> SqliteResult<()> {
+        conn.execute_batch(
+            r#"
+            CREATE TABLE IF NOT EXISTS goal_completions (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                note_name TEXT NOT NULL,
+                goal INTEGER NOT NULL,
+                words INTEGER NOT NULL,
+                completed_at INTEGER NOT NULL,
+                UNIQUE (note_name, goal)
+            );
+            CREATE INDEX IF NOT EXISTS idx_goal_completions_at ON goal_completions(completed_at);
+            "#,
+        )?;
+
+        debug!("Tabla 'goal_completions' creada");
+
+        Ok(())
+    }
+
+    /// Indexar una nota en la base de datos
+    pub fn index_note(
+        &self,
+        name: &str,
+        path: &str,
+        content: &str,
+        folder: Option<&str>,
+    ) -> Result<i64> {
+        // La nota, el FTS, las propiedades, los tags, los alias y las tarjetas
+        // se actualizan juntos o no se actualizan
+        self.transaction(|db| db.write_note_index(name, path, content, folder))
+    }
+
+    fn write_note_index(
+        &self,
+        name: &str,
+        path: &str,
+        content: &str,
+        folder: Option<&str>,
+    ) -> Result<i64> {
+        let now = Utc::now().timestamp();
+
+        // Insertar o actualizar nota (manejar conflictos tanto en name como en path)
+        self.conn.execute(
+            r#"
+            INSERT INTO notes (name, path, folder, created_at, updated_at)
+            VALUES (?1, ?2, ?3, ?4, ?5)
+            ON CONFLICT(path) DO UPDATE SET
+                name = excluded.name,
+                folder = excluded.folder,
+                updated_at = excluded.updated_at
+            "#,
+            params![name, path, folder, now, now],
+        )?;
+
+        // Obtener el ID de la nota (puede ser nueva o existente)
+        let note_id: i64 = self.conn.query_row(
+            "SELECT id FROM notes WHERE path = ?1",
+            params![path],
+            |row| row.get(0),
+        )?;
+
+        // Indexar en FTS5
+        self.conn.execute(
+            "INSERT OR REPLACE INTO notes_fts (rowid, name, content) VALUES (?1, ?2, ?3)",
+            params![note_id, name, content],
+        )?;
+
+        // Sincronizar propiedades inline del contenido
+        self.sync_inline_properties(note_id, content)?;
+
+        // Sincronizar tags del contenido (frontmatter + inline #tags)
+        self.sync_note_tags(note_id, content)?;
+
+        // Sincronizar alias del frontmatter
+        self.sync_note_aliases(note_id, name, content)?;
+
+        // Sincronizar tarjetas de repaso
+        self.sync_note_flashcards(note_id, content)?;
+
+        Ok(note_id)
+    }
+
+    /// Sincronizar tags de una nota (elimina antiguos y añade nuevos)
+    fn sync_note_tags(&self, note_id: i64, content: &str) -> Result<()> {
+        use super::frontmatter::extract_all_tags;
+
+        // Extraer tags del contenido (frontmatter + inline)
+        let tags = extract_all_tags(content);
+
+        // Obtener tags actuales de la nota
+        let current_tags: Vec<String> = self
+            .get_note_tags(note_id)?
+            .into_iter()
+            .map(|t| t.name)
+            .collect();
+
+        // Tags a eliminar (están en current pero no en tags)
+        for tag in &current_tags {
+            if !tags.iter().any(|t| t.to_lowercase() == tag.to_lowercase()) {
+                let _ = self.remove_tag(note_id, tag);
+            }
+        }
+
+        // Tags a añadir (están en tags pero no en current)
+        for tag in &tags {
+            if !current_tags
+                .iter()
+                .any(|t| t.to_lowercase() == tag.to_lowercase())
+            {
+                let _ = self.add_tag(note_id, tag);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Sincronizar los alias de una nota. En FTS van junto al nombre, para
+    /// encontrarla también buscando por ellos.
+    fn sync_note_aliases(&self, note_id: i64, name: &str, content: &str) -> Result<()> {
+        use super::frontmatter::extract_aliases;
+
+        self.conn.execute(
+            "DELETE FROM note_aliases WHERE note_id = ?1",
+            params![note_id],
+        )?;
+        for alias in extract_aliases(content) {
+            self.conn.execute(
+                "INSERT OR IGNORE INTO note_aliases (note_id, alias) VALUES (?1, ?2)",
+                params![note_id, alias],
+            )?;
+        }
+
+        self.update_fts_name(note_id, name)
+    }
+
+    /// Nombre de la nota en FTS: el nombre y sus alias
+    fn update_fts_name(&self, note_id: i64, name: &str) -> Result<()> {
+        let aliases: Vec<String> = {
+            let mut stmt = self
+                .conn
+                .prepare("SELECT alias FROM note_aliases WHERE note_id = ?1")?;
+            stmt.query_map(params![note_id], |row| row.get(0))?
+                .collect::<SqliteResult<Vec<_>>>()?
+        };
+        let fts_name = std::iter::once(name.to_string())
+            .chain(aliases)
+            .collect::<Vec<_>>()
+            .join(" ");
+        self.conn.execute(
+            "UPDATE notes_fts SET name = ?1 WHERE rowid = ?2",
+            params![fts_name, note_id],
+        )?;
+        Ok(())
+    }
+
+    /// Alias de una nota
+    pub fn get_note_aliases(&self, name: &str) -> Result<Vec<String>> {
+        let mut stmt = self.conn.prepare(
+            r#"
+            SELECT a.alias
+            FROM note_aliases a
+            JOIN notes n ON a.note_id = n.id
+            WHERE n.name = ?1
+            ORDER BY a.alias
+            "#,
+        )?;
+        let aliases = stmt
+            .query_map(params![name], |row| row.get(0))?
+            .collect::<SqliteResult<Vec<_>>>()?;
+        Ok(aliases)
+    }
+
+    /// Todos los alias con el nombre actual de su nota: `(alias, nota)`.
+    /// Sin las notas de la papelera ni del historial.
+    pub fn get_all_aliases(&self) -> Result<Vec<(String, String)>> {
+        let mut stmt = self.conn.prepare(
+            r#"
+            SELECT a.alias, n.name
+            FROM note_aliases a
+            JOIN notes n ON a.note_id = n.id
+            WHERE n.folder IS NULL
+               OR (n.folder NOT LIKE '.trash%' AND n.folder NOT LIKE '.history%')
+            ORDER BY a.alias COLLATE NOCASE
+            "#,
+        )?;
+        let aliases = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<SqliteResult<Vec<_>>>()?;
+        Ok(aliases)
+    }
+
+    /// Nota a la que apunta un alias (sin distinguir mayúsculas)
+    pub fn resolve_alias(&self, alias: &str) -> Result<Option<String>> {
+        let name = self
+            .conn
+            .query_row(
+                r#"
+                SELECT n.name
+                FROM note_aliases a
+                JOIN notes n ON a.note_id = n.id
+                WHERE a.alias = ?1
+                ORDER BY n.name
+                LIMIT 1
+                "#,
+                params![alias.trim()],
+                |row| row.get(0),
+            )
+            .optional()?;
+        Ok(name)
+    }
+
+    /// Sincronizar propiedades inline [campo::valor] de una nota
+    pub fn sync_inline_properties(&self, note_id: i64, content: &str) -> Result<()> {
+        use super::inline_property::InlinePropertyParser;
+        use super::property::PropertyValue;
+
+        let now = Utc::now().timestamp();
+
+        // Parsear propiedades del contenido
         let properties = InlinePropertyParser::parse(content);
 
         // Eliminar propiedades anteriores de esta nota
@@ -1410,7 +1743,7 @@ impl NotesDatabase {
         }
 
         // Ordenar por char_start descendente (para procesar de fin a inicio)
-        matching_groups.sort_by(|a, b| b.1.cmp(&a.1));
+        matching_groups.sort_by_key(|group| std::cmp::Reverse(group.1));
 
         Ok(matching_groups)
     }
@@ -1735,6 +2068,12 @@ impl NotesDatabase {
         // Sincronizar propiedades inline
         self.sync_inline_properties(note_id, content)?;
 
+        // Sincronizar tags (frontmatter + inline #tags)
+        self.sync_note_tags(note_id, content)?;
+
+        // Sincronizar alias del frontmatter
+        self.sync_note_aliases(note_id, name, content)?;
+
         Ok(())
     }
 
@@ -1756,6 +2095,24 @@ impl NotesDatabase {
                 params![id],
             )?;
 
+            // Eliminar la posición de lectura
+            self.conn.execute(
+                "DELETE FROM reading_positions WHERE note_id = ?1",
+                params![id],
+            )?;
+
+            // Eliminar los alias
+            self.conn
+                .execute("DELETE FROM note_aliases WHERE note_id = ?1", params![id])?;
+
+            // Quitarla de la cola de lectura
+            self.conn
+                .execute("DELETE FROM reading_queue WHERE note_id = ?1", params![id])?;
+
+            // Eliminar sus tarjetas de repaso
+            self.conn
+                .execute("DELETE FROM flashcards WHERE note_id = ?1", params![id])?;
+
             // Eliminar de tabla principal
             self.conn
                 .execute("DELETE FROM notes WHERE id = ?1", params![id])?;
@@ -1770,7 +2127,7 @@ impl NotesDatabase {
                 params![path],
             )?;
 
-            println!("🗑️ Nota '{}' eliminada de BD (incluidos embeddings)", name);
+            info!("Nota '{}' eliminada de BD (incluidos embeddings)", name);
         }
 
         Ok(())
@@ -1790,15 +2147,15 @@ impl NotesDatabase {
         for (name, path) in all_notes {
             // Verificar si el path existe en la lista de archivos actuales
             if !existing_paths.contains(&path) {
-                println!("🧹 Limpiando nota huérfana: '{}' (path: {})", name, path);
+                info!("Limpiando nota huérfana: '{}' (path: {})", name, path);
                 self.delete_note(&name)?;
                 deleted_count += 1;
             }
         }
 
         if deleted_count > 0 {
-            println!(
-                "✅ Limpieza completada: {} notas huérfanas eliminadas",
+            info!(
+                "Limpieza completada: {} notas huérfanas eliminadas",
                 deleted_count
             );
         }
@@ -1806,6 +2163,33 @@ impl NotesDatabase {
         Ok(deleted_count)
     }
 
+    /// Limpiar embeddings de rutas que ya no son de ninguna nota
+    pub fn cleanup_orphaned_embeddings(&self) -> Result<usize> {
+        let deleted = self.conn.execute(
+            "DELETE FROM note_embeddings WHERE note_path NOT IN (SELECT path FROM notes)",
+            [],
+        )?;
+        if deleted > 0 {
+            info!("Limpiados {} embeddings huérfanos", deleted);
+        }
+        Ok(deleted)
+    }
+
+    /// Limpiar entradas del índice de búsqueda sin nota
+    pub fn cleanup_orphaned_fts(&self) -> Result<usize> {
+        let deleted = self.conn.execute(
+            "DELETE FROM notes_fts WHERE rowid NOT IN (SELECT id FROM notes)",
+            [],
+        )?;
+        if deleted > 0 {
+            info!(
+                "Limpiadas {} entradas huérfanas del índice de búsqueda",
+                deleted
+            );
+        }
+        Ok(deleted)
+    }
+
     /// Limpiar propiedades inline huérfanas (cuya nota ya no existe)
     pub fn cleanup_orphaned_inline_properties(&self) -> Result<usize> {
         let deleted = self.conn.execute(
@@ -1817,7 +2201,7 @@ impl NotesDatabase {
         )?;
 
         if deleted > 0 {
-            println!("🧹 Limpiadas {} propiedades inline huérfanas", deleted);
+            info!("Limpiadas {} propiedades inline huérfanas", deleted);
         }
 
         Ok(deleted)
@@ -1879,6 +2263,16 @@ impl NotesDatabase {
         Ok(result)
     }
 
+    /// Nota a la que pertenece un fragmento de la búsqueda semántica
+    /// (`ruta#índice`, o `nombre#índice` si se indexó por nombre)
+    pub fn note_for_chunk(&self, chunk_id: &str) -> Result<Option<NoteMetadata>> {
+        let source = chunk_source(chunk_id);
+        match self.get_note_by_path(source)? {
+            Some(note) => Ok(Some(note)),
+            None => self.get_note(source),
+        }
+    }
+
     /// Listar todas las notas, opcionalmente filtradas por carpeta
     /// Excluye notas de .history y .trash
     pub fn list_notes(&self, folder: Option<&str>) -> Result<Vec<NoteMetadata>> {
@@ -1926,55 +2320,194 @@ impl NotesDatabase {
 
     /// Buscar notas usando FTS5 y filtros opcionales
     pub fn search(&self, query: &SearchQuery) -> Result<Vec<SearchResult>> {
-        if query.text.is_none() && query.tags.is_empty() {
-            // Sin filtros, devolver todas las notas
-            let notes = self
-                .list_notes(query.folder.as_deref())?
-                .into_iter()
-                .map(|note| SearchResult {
-                    note_id: note.id,
-                    note_name: note.name,
-                    note_path: note.path,
-                    snippet: String::new(),
-                    relevance: 0.0,
-                    matched_tags: vec![],
-                    similarity: None,
-                })
-                .collect();
-            return Ok(notes);
+        // "#tag" en el texto es un filtro de tag más, como en search_notes
+        let mut tags: Vec<String> = query.tags.iter().map(|tag| tag.to_lowercase()).collect();
+        let mut text = query
+            .text
+            .as_deref()
+            .map(str::trim)
+            .filter(|text| !text.is_empty())
+            .map(str::to_string);
+        if let Some(tag) = text.as_deref().and_then(|text| text.strip_prefix('#')) {
+            let tag = tag.trim().to_lowercase();
+            if !tag.is_empty() {
+                tags.push(tag);
+            }
+            text = None;
         }
 
-        // TODO: Implementar búsqueda FTS5 completa con snippets
-        Ok(vec![])
-    }
+        let fts_query = text.as_deref().map(Self::build_fts_query);
+        if fts_query
+            .as_deref()
+            .is_some_and(|fts| fts.trim().is_empty())
+        {
+            return Ok(vec![]);
+        }
 
-    /// Construye una query FTS5 inteligente desde el texto del usuario
-    fn build_fts_query(query_text: &str) -> String {
-        let trimmed = query_text.trim();
+        let results = self.search_filtered(query, fts_query.as_deref(), None, &tags)?;
 
-        // Si el usuario usa comillas, buscar literalmente
-        if trimmed.starts_with('"') && trimmed.ends_with('"') && trimmed.len() > 2 {
-            let literal = &trimmed[1..trimmed.len() - 1];
-            return format!("\"{}\"", literal.replace('"', "\"\""));
+        // Como en search_notes: si FTS5 no encuentra nada, probar con LIKE
+        match text {
+            Some(text) if results.is_empty() && text.chars().count() >= 2 => {
+                let like = format!("%{}%", text.to_lowercase());
+                self.search_filtered(query, None, Some(&like), &tags)
+            }
+            _ => Ok(results),
         }
+    }
 
-        // Separar por palabras y construir query con OR
-        let words: Vec<&str> = trimmed.split_whitespace().collect();
+    /// Consulta de `search` con un texto FTS5 o un patrón LIKE (o ninguno) y
+    /// el resto de filtros y el orden de `query`
+    fn search_filtered(
+        &self,
+        query: &SearchQuery,
+        fts_query: Option<&str>,
+        like_pattern: Option<&str>,
+        tags: &[String],
+    ) -> Result<Vec<SearchResult>> {
+        let mut conditions = vec![
+            "(notes.folder IS NULL OR (notes.folder NOT LIKE '.trash%' AND notes.folder NOT LIKE '.history%'))"
+                .to_string(),
+        ];
+        let mut values: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
+
+        let (snippet, relevance) = if let Some(fts) = fts_query {
+            values.push(Box::new(fts.to_string()));
+            conditions.push(format!("notes_fts MATCH ?{}", values.len()));
+            (
+                "snippet(notes_fts, -1, '<mark>', '</mark>', '...', 16)",
+                "rank",
+            )
+        } else if let Some(like) = like_pattern {
+            values.push(Box::new(like.to_string()));
+            conditions.push(format!(
+                "(LOWER(notes.name) LIKE ?{0} OR LOWER(notes_fts.content) LIKE ?{0} \
+                 OR notes.id IN (SELECT note_id FROM note_aliases WHERE LOWER(alias) LIKE ?{0}))",
+                values.len()
+            ));
+            ("substr(notes_fts.content, 1, 100)", "1.0")
+        } else {
+            ("''", "0.0")
+        };
 
-        if words.is_empty() {
-            return String::new();
+        if let Some(folder) = &query.folder {
+            values.push(Box::new(folder.clone()));
+            let n = values.len();
+            conditions.push(format!(
+                "(notes.folder = ?{0} OR substr(notes.folder, 1, length(?{0}) + 1) = ?{0} || '/')",
+                n
+            ));
         }
-
-        // Si es una sola palabra, usar wildcard para prefijos
-        if words.len() == 1 {
-            let word = Self::sanitize_fts_word(words[0]);
-            if word.is_empty() {
-                return String::new();
-            }
-            return format!("{}*", word);
+        for tag in tags {
+            values.push(Box::new(tag.clone()));
+            conditions.push(format!(
+                r#"EXISTS (
+                    SELECT 1 FROM note_tags
+                    JOIN tags ON note_tags.tag_id = tags.id
+                    WHERE note_tags.note_id = notes.id
+                      AND (LOWER(tags.name) = ?{0}
+                           OR substr(LOWER(tags.name), 1, length(?{0}) + 1) = ?{0} || '/')
+                )"#,
+                values.len()
+            ));
         }
-
-        // Para múltiples palabras, buscar todas con AND (deben estar todas presentes)
+        if let Some(from) = query.date_from {
+            values.push(Box::new(from.timestamp()));
+            conditions.push(format!("notes.updated_at >= ?{}", values.len()));
+        }
+        if let Some(to) = query.date_to {
+            values.push(Box::new(to.timestamp()));
+            conditions.push(format!("notes.updated_at <= ?{}", values.len()));
+        }
+        if query.has_todo {
+            conditions.push(
+                "(notes_fts.content LIKE '%- [ ]%' OR notes_fts.content LIKE '%* [ ]%')"
+                    .to_string(),
+            );
+        }
+        if query.has_reminder {
+            // status 1 = completado
+            conditions.push(
+                r#"EXISTS (
+                    SELECT 1 FROM reminders
+                    WHERE (reminders.note_id = notes.id OR reminders.source_path = notes.path)
+                      AND reminders.status != 1
+                )"#
+                .to_string(),
+            );
+        }
+
+        let order = match query.sort {
+            SearchSort::Relevance if fts_query.is_some() => "rank",
+            SearchSort::Relevance | SearchSort::Title => "notes.name COLLATE NOCASE",
+            SearchSort::Modified => "notes.updated_at DESC, notes.name COLLATE NOCASE",
+            SearchSort::Created => "notes.created_at DESC, notes.name COLLATE NOCASE",
+        };
+
+        let sql = format!(
+            r#"
+            SELECT notes.id, notes.name, notes.path, {} AS snippet, {} AS relevance
+            FROM notes
+            JOIN notes_fts ON notes_fts.rowid = notes.id
+            WHERE {}
+            ORDER BY {}
+            LIMIT {}
+            "#,
+            snippet,
+            relevance,
+            conditions.join(" AND "),
+            order,
+            query.limit.unwrap_or(50)
+        );
+
+        let mut stmt = self.conn.prepare(&sql)?;
+        let results = stmt
+            .query_map(
+                rusqlite::params_from_iter(values.iter().map(|value| value.as_ref())),
+                |row| {
+                    Ok(SearchResult {
+                        note_id: row.get(0)?,
+                        note_name: row.get(1)?,
+                        note_path: row.get(2)?,
+                        snippet: row.get(3)?,
+                        relevance: row.get::<_, f64>(4)? as f32,
+                        matched_tags: tags.to_vec(),
+                        similarity: None,
+                    })
+                },
+            )?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(results)
+    }
+
+    /// Construye una query FTS5 inteligente desde el texto del usuario
+    fn build_fts_query(query_text: &str) -> String {
+        let trimmed = query_text.trim();
+
+        // Si el usuario usa comillas, buscar literalmente
+        if trimmed.starts_with('"') && trimmed.ends_with('"') && trimmed.len() > 2 {
+            let literal = &trimmed[1..trimmed.len() - 1];
+            return format!("\"{}\"", literal.replace('"', "\"\""));
+        }
+
+        // Separar por palabras y construir query con OR
+        let words: Vec<&str> = trimmed.split_whitespace().collect();
+
+        if words.is_empty() {
+            return String::new();
+        }
+
+        // Si es una sola palabra, usar wildcard para prefijos
+        if words.len() == 1 {
+            let word = Self::sanitize_fts_word(words[0]);
+            if word.is_empty() {
+                return String::new();
+            }
+            return format!("{}*", word);
+        }
+
+        // Para múltiples palabras, buscar todas con AND (deben estar todas presentes)
         let sanitized_words: Vec<String> = words
             .iter()
             .map(|w| Self::sanitize_fts_word(w))
@@ -2022,7 +2555,7 @@ impl NotesDatabase {
                 return Ok(vec![]);
             }
 
-            // Buscar notas que tengan exactamente este tag
+            // Buscar notas que tengan este tag o uno que cuelgue de él (#tag/...)
             let mut stmt = self.conn.prepare(
                 r#"
                 SELECT DISTINCT
@@ -2034,7 +2567,8 @@ impl NotesDatabase {
                 FROM notes
                 JOIN note_tags ON notes.id = note_tags.note_id
                 JOIN tags ON note_tags.tag_id = tags.id
-                WHERE LOWER(tags.name) = ?1
+                WHERE (LOWER(tags.name) = ?1
+                       OR substr(LOWER(tags.name), 1, length(?1) + 1) = ?1 || '/')
                   AND (notes.folder IS NULL OR (
                       notes.folder NOT LIKE '.trash%' AND 
                       notes.folder NOT LIKE '.history%'
@@ -2244,6 +2778,97 @@ impl NotesDatabase {
         tags.collect::<SqliteResult<Vec<_>>>().map_err(Into::into)
     }
 
+    /// Notas que usa cada tag, contadas sobre `note_tags` (el `usage_count`
+    /// de la tabla puede desviarse), ordenado por nombre
+    pub fn tag_usage_counts(&self) -> Result<Vec<(String, usize)>> {
+        let mut stmt = self.conn.prepare(
+            r#"
+            SELECT t.name, COUNT(nt.note_id)
+            FROM tags t
+            INNER JOIN note_tags nt ON t.id = nt.tag_id
+            GROUP BY t.id
+            ORDER BY t.name
+            "#,
+        )?;
+
+        let counts = stmt.query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)? as usize))
+        })?;
+
+        counts.collect::<SqliteResult<Vec<_>>>().map_err(Into::into)
+    }
+
+    /// Nombres de las notas con el tag `tag` o alguno que cuelgue de él
+    /// (`tag/...`)
+    pub fn notes_with_tag(&self, tag: &str) -> Result<Vec<String>> {
+        let mut stmt = self.conn.prepare(
+            r#"
+            SELECT DISTINCT n.name
+            FROM notes n
+            INNER JOIN note_tags nt ON n.id = nt.note_id
+            INNER JOIN tags t ON t.id = nt.tag_id
+            WHERE t.name = ?1 OR substr(t.name, 1, length(?1) + 1) = ?1 || '/'
+            ORDER BY n.name
+            "#,
+        )?;
+
+        let names = stmt.query_map(params![tag], |row| row.get(0))?;
+
+        names
+            .collect::<SqliteResult<Vec<String>>>()
+            .map_err(Into::into)
+    }
+
+    /// Rutas de las notas con el tag `tag` o alguno que cuelgue de él
+    pub fn note_paths_with_tag(&self, tag: &str) -> Result<Vec<String>> {
+        let mut stmt = self.conn.prepare(
+            r#"
+            SELECT DISTINCT n.path
+            FROM notes n
+            INNER JOIN note_tags nt ON n.id = nt.note_id
+            INNER JOIN tags t ON t.id = nt.tag_id
+            WHERE t.name = ?1 OR substr(t.name, 1, length(?1) + 1) = ?1 || '/'
+            ORDER BY n.path
+            "#,
+        )?;
+
+        let paths = stmt.query_map(params![tag], |row| row.get(0))?;
+
+        paths
+            .collect::<SqliteResult<Vec<String>>>()
+            .map_err(Into::into)
+    }
+
+    /// Rutas de las notas cuyo texto indexado contiene `text` tal cual. Sin
+    /// límite ni ranking, a diferencia de la búsqueda: son las candidatas de
+    /// una reescritura en todo el vault.
+    pub fn note_paths_containing(&self, text: &str) -> Result<Vec<String>> {
+        let mut stmt = self.conn.prepare(
+            r#"
+            SELECT n.path
+            FROM notes_fts
+            JOIN notes n ON notes_fts.rowid = n.id
+            WHERE instr(notes_fts.content, ?1) > 0
+            ORDER BY n.path
+            "#,
+        )?;
+
+        let paths = stmt.query_map(params![text], |row| row.get(0))?;
+
+        paths
+            .collect::<SqliteResult<Vec<String>>>()
+            .map_err(Into::into)
+    }
+
+    /// Borra los tags que ya no usa ninguna nota (p. ej. tras renombrarlos)
+    pub fn delete_unused_tags(&self) -> Result<usize> {
+        let deleted = self.conn.execute(
+            "DELETE FROM tags WHERE id NOT IN (SELECT tag_id FROM note_tags)",
+            [],
+        )?;
+        Ok(deleted)
+    }
+
     /// Actualizar el orden de una nota
     pub fn update_note_order(&self, note_id: i64, new_order: i32) -> Result<()> {
         self.conn.execute(
@@ -2253,17 +2878,31 @@ impl NotesDatabase {
         Ok(())
     }
 
-    /// Mover una nota a una carpeta diferente
+    /// Mover una nota a una carpeta diferente (sus embeddings la siguen)
     pub fn move_note_to_folder(
         &self,
         note_id: i64,
         new_folder: Option<&str>,
         new_path: &str,
     ) -> Result<()> {
+        let old_path: Option<String> = self
+            .conn
+            .query_row(
+                "SELECT path FROM notes WHERE id = ?1",
+                params![note_id],
+                |row| row.get(0),
+            )
+            .optional()?;
         self.conn.execute(
             "UPDATE notes SET folder = ?1, path = ?2 WHERE id = ?3",
             params![new_folder, new_path, note_id],
         )?;
+        if let Some(old_path) = old_path {
+            self.conn.execute(
+                "UPDATE note_embeddings SET note_path = ?1 WHERE note_path = ?2",
+                params![new_path, old_path],
+            )?;
+        }
         Ok(())
     }
 
@@ -2321,7 +2960,7 @@ impl NotesDatabase {
             SELECT role, content, created_at
             FROM chat_messages
             WHERE session_id = ?1
-            ORDER BY created_at ASC
+            ORDER BY created_at ASC, id ASC
             "#,
         )?;
 
@@ -2338,6 +2977,206 @@ impl NotesDatabase {
         Ok(messages)
     }
 
+    /// Nueva sesión que comparte los `keep_messages` primeros mensajes de
+    /// `session_id` (y sus notas de contexto). La original no se toca.
+    pub fn fork_chat_session(&self, session_id: i64, keep_messages: usize) -> Result<i64> {
+        let now = Utc::now().timestamp();
+
+        let branched_from: Option<i64> = if keep_messages == 0 {
+            None
+        } else {
+            self.conn
+                .query_row(
+                    r#"
+                    SELECT id FROM chat_messages
+                    WHERE session_id = ?1
+                    ORDER BY created_at ASC, id ASC
+                    LIMIT 1 OFFSET ?2
+                    "#,
+                    params![session_id, keep_messages as i64 - 1],
+                    |row| row.get(0),
+                )
+                .optional()?
+        };
+
+        self.begin_transaction()?;
+        let result = (|| -> Result<i64> {
+            let inserted = self.conn.execute(
+                r#"
+                INSERT INTO chat_sessions (created_at, updated_at, model, provider, temperature,
+                                           max_tokens, parent_session_id, branched_from_message_id)
+                SELECT ?1, ?1, model, provider, temperature, max_tokens, id, ?2
+                FROM chat_sessions WHERE id = ?3
+                "#,
+                params![now, branched_from, session_id],
+            )?;
+            if inserted == 0 {
+                return Err(DatabaseError::ChatSessionNotFound(session_id));
+            }
+            let fork_id = self.conn.last_insert_rowid();
+
+            self.conn.execute(
+                r#"
+                INSERT INTO chat_messages (session_id, role, content, created_at)
+                SELECT ?1, role, content, created_at
+                FROM chat_messages
+                WHERE session_id = ?2
+                ORDER BY created_at ASC, id ASC
+                LIMIT ?3
+                "#,
+                params![fork_id, session_id, keep_messages as i64],
+            )?;
+            self.conn.execute(
+                r#"
+                INSERT INTO chat_context_notes (session_id, note_id, added_at)
+                SELECT ?1, note_id, added_at FROM chat_context_notes WHERE session_id = ?2
+                "#,
+                params![fork_id, session_id],
+            )?;
+            Ok(fork_id)
+        })();
+
+        match result {
+            Ok(fork_id) => {
+                self.commit_transaction()?;
+                Ok(fork_id)
+            }
+            Err(e) => {
+                self.rollback_transaction()?;
+                Err(e)
+            }
+        }
+    }
+
+    /// Guarda un paso del agente, asociado al último mensaje de la sesión
+    pub fn save_chat_agent_step(
+        &self,
+        session_id: i64,
+        kind: &str,
+        tool: Option<&str>,
+        content: &str,
+    ) -> Result<i64> {
+        let now = Utc::now().timestamp();
+
+        self.conn.execute(
+            r#"
+            INSERT INTO chat_agent_steps (session_id, after_message_id, kind, tool, content,
+                                          created_at)
+            VALUES (?1, (SELECT MAX(id) FROM chat_messages WHERE session_id = ?1), ?2, ?3, ?4, ?5)
+            "#,
+            params![session_id, kind, tool, content, now],
+        )?;
+
+        Ok(self.conn.last_insert_rowid())
+    }
+
+    /// Ids de todas las sesiones de chat, de la más antigua a la más nueva
+    pub fn get_chat_session_ids(&self) -> Result<Vec<i64>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT id FROM chat_sessions ORDER BY created_at ASC, id ASC")?;
+        let ids = stmt
+            .query_map([], |row| row.get(0))?
+            .collect::<SqliteResult<Vec<i64>>>()?;
+
+        Ok(ids)
+    }
+
+    /// Sesión completa para exportarla: mensajes y pasos del agente (sin
+    /// redactar)
+    pub fn export_chat_session(&self, session_id: i64) -> Result<ExportedSession> {
+        let session = self
+            .conn
+            .query_row(
+                r#"
+                SELECT created_at, model, provider, parent_session_id
+                FROM chat_sessions WHERE id = ?1
+                "#,
+                params![session_id],
+                |row| {
+                    Ok((
+                        row.get::<_, i64>(0)?,
+                        row.get::<_, String>(1)?,
+                        row.get::<_, String>(2)?,
+                        row.get::<_, Option<i64>>(3)?,
+                    ))
+                },
+            )
+            .optional()?;
+        let Some((created_at, model, provider, parent_session_id)) = session else {
+            return Err(DatabaseError::ChatSessionNotFound(session_id));
+        };
+        let timestamp = |seconds: i64| DateTime::from_timestamp(seconds, 0).unwrap_or_default();
+
+        let mut stmt = self.conn.prepare(
+            r#"
+            SELECT id, role, content, created_at
+            FROM chat_messages
+            WHERE session_id = ?1
+            ORDER BY created_at ASC, id ASC
+            "#,
+        )?;
+        let messages = stmt
+            .query_map(params![session_id], |row| {
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    ExportedMessage {
+                        role: row.get(1)?,
+                        content: row.get(2)?,
+                        created_at: timestamp(row.get(3)?),
+                        steps: Vec::new(),
+                    },
+                ))
+            })?
+            .collect::<SqliteResult<Vec<_>>>()?;
+
+        let mut stmt = self.conn.prepare(
+            r#"
+            SELECT after_message_id, kind, tool, content, created_at
+            FROM chat_agent_steps
+            WHERE session_id = ?1 AND after_message_id IS NOT NULL
+            ORDER BY id ASC
+            "#,
+        )?;
+        let steps = stmt
+            .query_map(params![session_id], |row| {
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    ExportedStep {
+                        kind: row.get(1)?,
+                        tool: row.get(2)?,
+                        content: row.get(3)?,
+                        created_at: timestamp(row.get(4)?),
+                    },
+                ))
+            })?
+            .collect::<SqliteResult<Vec<_>>>()?;
+
+        Ok(ExportedSession {
+            id: session_id,
+            created_at: timestamp(created_at),
+            model,
+            provider,
+            parent_session_id,
+            messages: attach_steps(messages, steps),
+        })
+    }
+
+    /// Sesión de la que salió `session_id` como rama (`None` si no es una
+    /// rama o la original se borró)
+    pub fn get_chat_session_parent(&self, session_id: i64) -> Result<Option<i64>> {
+        let parent = self
+            .conn
+            .query_row(
+                "SELECT parent_session_id FROM chat_sessions WHERE id = ?1",
+                params![session_id],
+                |row| row.get::<_, Option<i64>>(0),
+            )
+            .optional()?;
+
+        Ok(parent.flatten())
+    }
+
     /// Adjuntar una nota al contexto de una sesión
     pub fn attach_note_to_chat(&self, session_id: i64, note_id: i64) -> Result<()> {
         let now = Utc::now().timestamp();
@@ -2358,7 +3197,7 @@ impl NotesDatabase {
     pub fn get_chat_context_notes(&self, session_id: i64) -> Result<Vec<NoteMetadata>> {
         let mut stmt = self.conn.prepare(
             r#"
-            SELECT n.id, n.name, n.path, n.folder, n.order_index, n.created_at, n.updated_at
+            SELECT n.id, n.name, n.path, n.folder, n.order_index, n.icon, n.created_at, n.updated_at
             FROM notes n
             INNER JOIN chat_context_notes ccn ON n.id = ccn.note_id
             WHERE ccn.session_id = ?1
@@ -2393,6 +3232,15 @@ impl NotesDatabase {
             "DELETE FROM chat_sessions WHERE id = ?1",
             params![session_id],
         )?;
+        // Sus ramas siguen existiendo, ya sin original
+        self.conn.execute(
+            "UPDATE chat_sessions SET parent_session_id = NULL WHERE parent_session_id = ?1",
+            params![session_id],
+        )?;
+        self.conn.execute(
+            "DELETE FROM chat_agent_steps WHERE session_id = ?1",
+            params![session_id],
+        )?;
         // Los mensajes y contexto se eliminan por CASCADE
         Ok(())
     }
@@ -2400,6 +3248,7 @@ impl NotesDatabase {
     /// Eliminar todo el historial de chat
     pub fn clear_all_chat_history(&self) -> Result<()> {
         self.conn.execute("DELETE FROM chat_sessions", [])?;
+        self.conn.execute("DELETE FROM chat_agent_steps", [])?;
         Ok(())
     }
 
@@ -2586,7 +3435,7 @@ impl NotesDatabase {
 
             // Deserializar embedding
             let embedding: Vec<f32> = bincode::deserialize(&embedding_blob)
-                .map_err(|e| DatabaseError::Sqlite(rusqlite::Error::InvalidQuery))?;
+                .map_err(|_| DatabaseError::Sqlite(rusqlite::Error::InvalidQuery))?;
 
             Ok(Some(embedding))
         } else {
@@ -2604,7 +3453,7 @@ impl NotesDatabase {
 
         // Serializar embedding
         let embedding_blob = bincode::serialize(embedding)
-            .map_err(|e| DatabaseError::Sqlite(rusqlite::Error::InvalidQuery))?;
+            .map_err(|_| DatabaseError::Sqlite(rusqlite::Error::InvalidQuery))?;
 
         let now = Utc::now().timestamp();
 
@@ -2732,21 +3581,30 @@ impl NotesDatabase {
             self.conn
                 .execute("DELETE FROM notes_fts WHERE rowid = ?1", params![id])?;
 
+            // Eliminar los alias
+            self.conn
+                .execute("DELETE FROM note_aliases WHERE note_id = ?1", params![id])?;
+
+            // Quitarla de la cola de lectura
+            self.conn
+                .execute("DELETE FROM reading_queue WHERE note_id = ?1", params![id])?;
+
+            // Eliminar sus tarjetas de repaso
+            self.conn
+                .execute("DELETE FROM flashcards WHERE note_id = ?1", params![id])?;
+
             // Eliminar embeddings asociados
             self.conn.execute(
                 "DELETE FROM note_embeddings WHERE note_path = ?1",
                 params![path],
             )?;
 
-            println!(
-                "🗑️ Nota '{}' eliminada de BD (carpeta: {})",
-                name, folder_path
-            );
+            info!("Nota '{}' eliminada de BD (carpeta: {})", name, folder_path);
         }
 
         if count > 0 {
-            println!(
-                "🗑️ Total de {} notas eliminadas de la carpeta '{}'",
+            info!(
+                "Total de {} notas eliminadas de la carpeta '{}'",
                 count, folder_path
             );
         }
@@ -2792,8 +3650,6 @@ impl NotesDatabase {
         let mut total_updated = 0;
 
         // Buscar todas las notas afectadas (directas y en subcarpetas)
-        let pattern = format!("{}%", old_folder); // folder LIKE 'old%'
-
         let notes_to_update: Vec<(i64, String, String)> = self
             .conn
             .prepare("SELECT id, folder, path FROM notes WHERE folder = ?1 OR folder LIKE ?2")?
@@ -2844,64 +3700,365 @@ impl NotesDatabase {
             total_updated += 1;
         }
 
-        if total_updated > 0 {
-            println!(
-                "📝 {} notas actualizadas: carpeta '{}' → '{}' (incluidos paths y embeddings)",
-                total_updated, old_folder, new_folder
-            );
-        }
+        if total_updated > 0 {
+            info!(
+                "{} notas actualizadas: carpeta '{}' → '{}' (incluidos paths y embeddings)",
+                total_updated, old_folder, new_folder
+            );
+        }
+
+        Ok(total_updated)
+    }
+
+    /// Renombrar una nota actualizando todas las tablas (notes, fts, embeddings)
+    pub fn rename_note(
+        &self,
+        old_name: &str,
+        new_name: &str,
+        new_path: &str,
+        new_folder: Option<&str>,
+    ) -> Result<()> {
+        let now = Utc::now().timestamp();
+
+        // Obtener datos de la nota original
+        let note_data: Option<(i64, String)> = self
+            .conn
+            .query_row(
+                "SELECT id, path FROM notes WHERE name = ?1",
+                params![old_name],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?;
+
+        if let Some((id, old_path)) = note_data {
+            // 1. Actualizar tabla principal
+            self.conn.execute(
+                "UPDATE notes SET name = ?1, path = ?2, folder = ?3, updated_at = ?4 WHERE id = ?5",
+                params![new_name, new_path, new_folder, now, id],
+            )?;
+
+            // 2. Actualizar FTS (solo el nombre, el contenido se actualiza por separado si cambió).
+            // Los alias van por ID y siguen apuntando a la nota.
+            self.update_fts_name(id, new_name)?;
+
+            // 3. Actualizar paths en embeddings
+            self.conn.execute(
+                "UPDATE note_embeddings SET note_path = ?1 WHERE note_path = ?2",
+                params![new_path, old_path],
+            )?;
+
+            info!(
+                "Nota renombrada: '{}' -> '{}' (incluidos embeddings)",
+                old_name, new_name
+            );
+        } else {
+            return Err(DatabaseError::NoteNotFound(old_name.to_string()));
+        }
+
+        Ok(())
+    }
+
+    // ==================== POSICIÓN DE LECTURA ====================
+
+    /// Guardar dónde se dejó de leer una nota. Va por id, así que sobrevive a
+    /// renombrados y movimientos entre carpetas.
+    pub fn save_reading_position(&self, note_name: &str, position: ReadingPosition) -> Result<()> {
+        let now = Utc::now().timestamp();
+        let rows = self.conn.execute(
+            r#"
+            INSERT INTO reading_positions (note_id, cursor_offset, scroll_fraction, updated_at)
+            SELECT id, ?1, ?2, ?3 FROM notes WHERE name = ?4
+            ON CONFLICT(note_id) DO UPDATE SET
+                cursor_offset = excluded.cursor_offset,
+                scroll_fraction = excluded.scroll_fraction,
+                updated_at = excluded.updated_at
+            "#,
+            params![
+                position.cursor_offset as i64,
+                position.scroll_fraction.clamp(0.0, 1.0),
+                now,
+                note_name
+            ],
+        )?;
+
+        if rows == 0 {
+            return Err(DatabaseError::NoteNotFound(note_name.to_string()));
+        }
+
+        Ok(())
+    }
+
+    /// Obtener la posición de lectura guardada de una nota
+    pub fn get_reading_position(&self, note_name: &str) -> Result<Option<ReadingPosition>> {
+        let position = self
+            .conn
+            .query_row(
+                r#"
+                SELECT rp.cursor_offset, rp.scroll_fraction
+                FROM reading_positions rp
+                JOIN notes n ON rp.note_id = n.id
+                WHERE n.name = ?1
+                "#,
+                params![note_name],
+                |row| {
+                    Ok(ReadingPosition {
+                        cursor_offset: row.get::<_, i64>(0)?.max(0) as usize,
+                        scroll_fraction: row.get(1)?,
+                    })
+                },
+            )
+            .optional()?;
+
+        Ok(position)
+    }
+
+    // ==================== COLA DE LECTURA ====================
+
+    /// Marcar una nota para leer más tarde (si ya lo estaba no cambia nada)
+    pub fn add_to_reading_queue(&self, note_name: &str) -> Result<()> {
+        if self.get_note(note_name)?.is_none() {
+            return Err(DatabaseError::NoteNotFound(note_name.to_string()));
+        }
+        self.conn.execute(
+            r#"
+            INSERT OR IGNORE INTO reading_queue (note_id, added_at, progress)
+            SELECT id, ?1, 0 FROM notes WHERE name = ?2
+            "#,
+            params![Utc::now().timestamp(), note_name],
+        )?;
+        Ok(())
+    }
+
+    /// Quitar una nota de la cola de lectura
+    pub fn remove_from_reading_queue(&self, note_name: &str) -> Result<()> {
+        self.conn.execute(
+            "DELETE FROM reading_queue WHERE note_id = (SELECT id FROM notes WHERE name = ?1)",
+            params![note_name],
+        )?;
+        Ok(())
+    }
+
+    pub fn is_in_reading_queue(&self, note_name: &str) -> Result<bool> {
+        let count: i64 = self.conn.query_row(
+            "SELECT COUNT(*) FROM reading_queue rq JOIN notes n ON rq.note_id = n.id WHERE n.name = ?1",
+            params![note_name],
+            |row| row.get(0),
+        )?;
+        Ok(count > 0)
+    }
+
+    /// Apuntar hasta dónde se ha leído (scroll del preview). El progreso no
+    /// retrocede al volver arriba; las notas fuera de la cola se ignoran.
+    pub fn update_reading_progress(&self, note_name: &str, fraction: f64) -> Result<()> {
+        self.conn.execute(
+            r#"
+            UPDATE reading_queue SET progress = MAX(progress, ?1)
+            WHERE note_id = (SELECT id FROM notes WHERE name = ?2)
+            "#,
+            params![fraction.clamp(0.0, 1.0), note_name],
+        )?;
+        Ok(())
+    }
+
+    /// Notas de la cola de lectura, de la última añadida a la primera
+    pub fn get_reading_queue(&self) -> Result<Vec<ReadingQueueEntry>> {
+        let mut stmt = self.conn.prepare(
+            r#"
+            SELECT n.name, rq.added_at, rq.progress
+            FROM reading_queue rq
+            JOIN notes n ON rq.note_id = n.id
+            ORDER BY rq.added_at DESC, n.name
+            "#,
+        )?;
+        let entries = stmt
+            .query_map([], |row| {
+                Ok(ReadingQueueEntry {
+                    name: row.get(0)?,
+                    added_at: DateTime::from_timestamp(row.get(1)?, 0).unwrap_or_default(),
+                    progress: row.get(2)?,
+                })
+            })?
+            .collect::<SqliteResult<Vec<_>>>()?;
+        Ok(entries)
+    }
+
+    // ==================== TARJETAS DE REPASO ====================
+
+    /// Sincronizar las tarjetas de una nota: las nuevas quedan pendientes ya,
+    /// las que siguen conservan su estado y las que desaparecen se borran
+    fn sync_note_flashcards(&self, note_id: i64, content: &str) -> Result<()> {
+        use super::flashcards::extract_flashcards;
+
+        let cards = extract_flashcards(content);
+        let current: Vec<String> = {
+            let mut stmt = self
+                .conn
+                .prepare("SELECT question FROM flashcards WHERE note_id = ?1")?;
+            stmt.query_map(params![note_id], |row| row.get(0))?
+                .collect::<SqliteResult<Vec<_>>>()?
+        };
+        for question in current {
+            if !cards.iter().any(|card| card.question == question) {
+                self.conn.execute(
+                    "DELETE FROM flashcards WHERE note_id = ?1 AND question = ?2",
+                    params![note_id, question],
+                )?;
+            }
+        }
+
+        let now = Utc::now().timestamp();
+        for card in cards {
+            self.conn.execute(
+                r#"
+                INSERT INTO flashcards (note_id, question, answer, due_at)
+                VALUES (?1, ?2, ?3, ?4)
+                ON CONFLICT(note_id, question) DO UPDATE SET answer = excluded.answer
+                "#,
+                params![note_id, card.question, card.answer, now],
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Volver a sacar las tarjetas de una nota ya indexada
+    pub fn sync_flashcards(&self, note_name: &str, content: &str) -> Result<()> {
+        let note_id: i64 = self
+            .conn
+            .query_row(
+                "SELECT id FROM notes WHERE name = ?1",
+                params![note_name],
+                |row| row.get(0),
+            )
+            .optional()?
+            .ok_or_else(|| DatabaseError::NoteNotFound(note_name.to_string()))?;
+        self.sync_note_flashcards(note_id, content)
+    }
+
+    /// Tarjetas que tocan repasar en `now`, de la más atrasada a la más reciente
+    pub fn get_due_flashcards(&self, now: DateTime<Utc>) -> Result<Vec<DeckCard>> {
+        let mut stmt = self.conn.prepare(
+            r#"
+            SELECT f.id, n.name, f.question, f.answer, f.ease, f.interval_days,
+                   f.repetitions, f.due_at
+            FROM flashcards f
+            JOIN notes n ON f.note_id = n.id
+            WHERE f.due_at <= ?1
+            ORDER BY f.due_at, n.name, f.id
+            "#,
+        )?;
+        let cards = stmt
+            .query_map(params![now.timestamp()], |row| {
+                Ok(DeckCard {
+                    id: row.get(0)?,
+                    note_name: row.get(1)?,
+                    question: row.get(2)?,
+                    answer: row.get(3)?,
+                    state: Sm2State {
+                        ease: row.get(4)?,
+                        interval_days: row.get(5)?,
+                        repetitions: row.get(6)?,
+                    },
+                    due_at: DateTime::from_timestamp(row.get(7)?, 0).unwrap_or_default(),
+                })
+            })?
+            .collect::<SqliteResult<Vec<_>>>()?;
+        Ok(cards)
+    }
 
-        Ok(total_updated)
+    /// Total de tarjetas y cuántas tocan repasar en `now`
+    pub fn count_flashcards(&self, now: DateTime<Utc>) -> Result<(usize, usize)> {
+        let counts: (i64, i64) = self.conn.query_row(
+            "SELECT COUNT(*), COUNT(CASE WHEN due_at <= ?1 THEN 1 END) FROM flashcards",
+            params![now.timestamp()],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )?;
+        Ok((counts.0 as usize, counts.1 as usize))
     }
 
-    /// Renombrar una nota actualizando todas las tablas (notes, fts, embeddings)
-    pub fn rename_note(
+    /// Apuntar la respuesta a una tarjeta y programar su próximo repaso
+    pub fn record_flashcard_review(
         &self,
-        old_name: &str,
-        new_name: &str,
-        new_path: &str,
-        new_folder: Option<&str>,
-    ) -> Result<()> {
-        let now = Utc::now().timestamp();
-
-        // Obtener datos de la nota original
-        let note_data: Option<(i64, String)> = self
+        card_id: i64,
+        grade: ReviewGrade,
+        now: DateTime<Utc>,
+    ) -> Result<Sm2State> {
+        let state = self
             .conn
             .query_row(
-                "SELECT id, path FROM notes WHERE name = ?1",
-                params![old_name],
-                |row| Ok((row.get(0)?, row.get(1)?)),
+                "SELECT ease, interval_days, repetitions FROM flashcards WHERE id = ?1",
+                params![card_id],
+                |row| {
+                    Ok(Sm2State {
+                        ease: row.get(0)?,
+                        interval_days: row.get(1)?,
+                        repetitions: row.get(2)?,
+                    })
+                },
             )
-            .optional()?;
-
-        if let Some((id, old_path)) = note_data {
-            // 1. Actualizar tabla principal
-            self.conn.execute(
-                "UPDATE notes SET name = ?1, path = ?2, folder = ?3, updated_at = ?4 WHERE id = ?5",
-                params![new_name, new_path, new_folder, now, id],
-            )?;
+            .optional()?
+            .ok_or(DatabaseError::FlashcardNotFound(card_id))?;
 
-            // 2. Actualizar FTS (solo el nombre, el contenido se actualiza por separado si cambió)
-            self.conn.execute(
-                "UPDATE notes_fts SET name = ?1 WHERE rowid = ?2",
-                params![new_name, id],
-            )?;
+        let next = state.review(grade);
+        let due_at = now + chrono::Duration::days(next.interval_days as i64);
+        self.conn.execute(
+            r#"
+            UPDATE flashcards
+            SET ease = ?1, interval_days = ?2, repetitions = ?3, due_at = ?4, reviewed_at = ?5
+            WHERE id = ?6
+            "#,
+            params![
+                next.ease,
+                next.interval_days,
+                next.repetitions,
+                due_at.timestamp(),
+                now.timestamp(),
+                card_id
+            ],
+        )?;
+        Ok(next)
+    }
 
-            // 3. Actualizar paths en embeddings
-            self.conn.execute(
-                "UPDATE note_embeddings SET note_path = ?1 WHERE note_path = ?2",
-                params![new_path, old_path],
-            )?;
+    // ==================== OBJETIVOS DE PALABRAS ====================
 
-            println!(
-                "📝 Nota renombrada: '{}' -> '{}' (incluidos embeddings)",
-                old_name, new_name
-            );
-        } else {
-            return Err(DatabaseError::NoteNotFound(old_name.to_string()));
-        }
+    /// Apunta que la nota llegó a su objetivo. `false` si ya estaba apuntado
+    /// (cada objetivo de cada nota cuenta una vez).
+    pub fn record_goal_completion(&self, note_name: &str, goal: &WordGoal) -> Result<bool> {
+        let inserted = self.conn.execute(
+            r#"
+            INSERT OR IGNORE INTO goal_completions (note_name, goal, words, completed_at)
+            VALUES (?1, ?2, ?3, ?4)
+            "#,
+            params![
+                note_name,
+                goal.goal as i64,
+                goal.words as i64,
+                Utc::now().timestamp()
+            ],
+        )?;
+        Ok(inserted > 0)
+    }
 
-        Ok(())
+    /// Objetivos alcanzados desde `since` (timestamp Unix), del más reciente
+    /// al más antiguo
+    pub fn goal_completions_since(&self, since: i64) -> Result<Vec<GoalCompletion>> {
+        let mut stmt = self.conn.prepare(
+            r#"
+            SELECT note_name, goal, words, completed_at FROM goal_completions
+            WHERE completed_at >= ?1
+            ORDER BY completed_at DESC, id DESC
+            "#,
+        )?;
+        let completions = stmt
+            .query_map(params![since], |row| {
+                Ok(GoalCompletion {
+                    note_name: row.get(0)?,
+                    goal: row.get::<_, i64>(1)? as usize,
+                    words: row.get::<_, i64>(2)? as usize,
+                    completed_at: row.get(3)?,
+                })
+            })?
+            .collect::<SqliteResult<Vec<_>>>()?;
+        Ok(completions)
     }
 
     // ==================== FUNCIONES DE ICONOS ====================
@@ -3465,7 +4622,7 @@ mod tests {
         let temp_dir = std::env::temp_dir();
         let db_path = temp_dir.join("test_notes.db");
 
-        let db = NotesDatabase::new(&db_path).unwrap();
+        let _db = NotesDatabase::new(&db_path).unwrap();
         assert!(db_path.exists());
 
         // Cleanup
@@ -3521,6 +4678,468 @@ mod tests {
         std::fs::remove_file(db_path).ok();
     }
 
+    #[test]
+    fn test_update_note_syncs_tags() {
+        let temp_dir = std::env::temp_dir();
+        let db_path = temp_dir.join("test_notes_update_tags.db");
+        std::fs::remove_file(&db_path).ok();
+
+        let db = NotesDatabase::new(&db_path).unwrap();
+        let note_id = db
+            .index_note("retagged", "/path/to/retagged.md", "#rust #gtk", None)
+            .unwrap();
+
+        db.update_note("retagged", "#rust #sqlite").unwrap();
+
+        let names: Vec<String> = db
+            .get_note_tags(note_id)
+            .unwrap()
+            .into_iter()
+            .map(|tag| tag.name)
+            .collect();
+        assert_eq!(names, vec!["rust", "sqlite"]);
+
+        // Cleanup
+        std::fs::remove_file(db_path).ok();
+    }
+
+    #[test]
+    fn test_nested_tag_queries() {
+        let db_path = std::env::temp_dir().join("test_notes_nested_tags.db");
+        std::fs::remove_file(&db_path).ok();
+
+        let db = NotesDatabase::new(&db_path).unwrap();
+        db.index_note("a", "/path/to/a.md", "#work #work/acme", None)
+            .unwrap();
+        db.index_note("b", "/path/to/b.md", "#work/acme", None)
+            .unwrap();
+        db.index_note("c", "/path/to/c.md", "#workshop", None)
+            .unwrap();
+
+        assert_eq!(
+            db.tag_usage_counts().unwrap(),
+            vec![
+                ("work".to_string(), 1),
+                ("work/acme".to_string(), 2),
+                ("workshop".to_string(), 1)
+            ]
+        );
+        assert_eq!(db.notes_with_tag("work").unwrap(), vec!["a", "b"]);
+        assert_eq!(db.notes_with_tag("work/acme").unwrap(), vec!["a", "b"]);
+
+        // Al quitar #workshop de la nota su tag queda sin usar
+        db.update_note("c", "sin tags").unwrap();
+        assert_eq!(db.delete_unused_tags().unwrap(), 1);
+        assert_eq!(db.get_tags().unwrap().len(), 2);
+
+        std::fs::remove_file(db_path).ok();
+    }
+
+    #[test]
+    fn test_search_filters_and_sort() {
+        let db_path = std::env::temp_dir().join("test_notes_search_filters.db");
+        std::fs::remove_file(&db_path).ok();
+
+        let db = NotesDatabase::new(&db_path).unwrap();
+        db.index_note(
+            "Work/plan",
+            "/n/Work/plan.md",
+            "Plan #work/acme\n- [ ] revisar",
+            Some("Work"),
+        )
+        .unwrap();
+        let ideas = db
+            .index_note("ideas", "/n/ideas.md", "Ideas de plan #personal", None)
+            .unwrap();
+        db.index_note(
+            "Work/Old/acta",
+            "/n/Work/Old/acta.md",
+            "- [x] hecho",
+            Some("Work/Old"),
+        )
+        .unwrap();
+        db.conn
+            .execute(
+                "UPDATE notes SET updated_at = id * 10, created_at = 100 - id",
+                [],
+            )
+            .unwrap();
+        db.conn
+            .execute(
+                "INSERT INTO reminders (note_id, title, due_date, status, created_at, updated_at) \
+                 VALUES (?1, 'llamar', 0, 0, 0, 0)",
+                params![ideas],
+            )
+            .unwrap();
+
+        let names = |query: SearchQuery| -> Vec<String> {
+            db.search(&query)
+                .unwrap()
+                .into_iter()
+                .map(|result| result.note_name)
+                .collect()
+        };
+
+        assert_eq!(
+            names(SearchQuery {
+                sort: SearchSort::Modified,
+                ..Default::default()
+            }),
+            vec!["Work/Old/acta", "ideas", "Work/plan"]
+        );
+        assert_eq!(
+            names(SearchQuery {
+                sort: SearchSort::Created,
+                ..Default::default()
+            }),
+            vec!["Work/plan", "ideas", "Work/Old/acta"]
+        );
+        assert_eq!(
+            names(SearchQuery {
+                text: Some("plan".to_string()),
+                sort: SearchSort::Title,
+                ..Default::default()
+            }),
+            vec!["ideas", "Work/plan"]
+        );
+        assert_eq!(
+            names(SearchQuery {
+                folder: Some("Work".to_string()),
+                ..Default::default()
+            }),
+            vec!["Work/Old/acta", "Work/plan"]
+        );
+        assert_eq!(
+            names(SearchQuery {
+                text: Some("#work".to_string()),
+                ..Default::default()
+            }),
+            vec!["Work/plan"]
+        );
+        assert_eq!(
+            names(SearchQuery {
+                has_todo: true,
+                ..Default::default()
+            }),
+            vec!["Work/plan"]
+        );
+        assert_eq!(
+            names(SearchQuery {
+                has_reminder: true,
+                ..Default::default()
+            }),
+            vec!["ideas"]
+        );
+        std::fs::remove_file(db_path).ok();
+    }
+
+    #[test]
+    fn test_flashcards() {
+        let db_path = std::env::temp_dir().join("test_notes_flashcards.db");
+        std::fs::remove_file(&db_path).ok();
+
+        let db = NotesDatabase::new(&db_path).unwrap();
+        db.index_note(
+            "estudio",
+            "/path/to/estudio.md",
+            "Q:: 2 + 2 A:: 4\n\nQ:: Capital de Italia\nA:: Roma",
+            None,
+        )
+        .unwrap();
+
+        let now = Utc::now();
+        let due = db.get_due_flashcards(now).unwrap();
+        assert_eq!(due.len(), 2);
+        assert_eq!(due[0].note_name, "estudio");
+        assert_eq!(db.count_flashcards(now).unwrap(), (2, 2));
+
+        // Tras acertar, no vuelve hasta mañana
+        let card = due.iter().find(|card| card.question == "2 + 2").unwrap();
+        let state = db
+            .record_flashcard_review(card.id, ReviewGrade::Good, now)
+            .unwrap();
+        assert_eq!(state.interval_days, 1);
+        assert_eq!(db.count_flashcards(now).unwrap(), (2, 1));
+        let tomorrow = now + chrono::Duration::days(1);
+        assert_eq!(db.get_due_flashcards(tomorrow).unwrap().len(), 2);
+
+        // Editar la respuesta conserva el repaso; quitar una tarjeta la borra
+        db.index_note(
+            "estudio",
+            "/path/to/estudio.md",
+            "Q:: 2 + 2 A:: cuatro",
+            None,
+        )
+        .unwrap();
+        let due = db.get_due_flashcards(tomorrow).unwrap();
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].answer, "cuatro");
+        assert_eq!(due[0].state.repetitions, 1);
+
+        db.delete_note("estudio").unwrap();
+        assert_eq!(db.count_flashcards(tomorrow).unwrap(), (0, 0));
+
+        std::fs::remove_file(db_path).ok();
+    }
+
+    #[test]
+    fn test_reading_queue() {
+        let db_path = std::env::temp_dir().join("test_notes_reading_queue.db");
+        std::fs::remove_file(&db_path).ok();
+
+        let db = NotesDatabase::new(&db_path).unwrap();
+        db.index_note("articulo", "/path/to/articulo.md", "# Artículo", None)
+            .unwrap();
+        db.index_note("otra", "/path/to/otra.md", "# Otra", None)
+            .unwrap();
+        assert!(db.add_to_reading_queue("nada").is_err());
+
+        db.add_to_reading_queue("articulo").unwrap();
+        assert!(db.is_in_reading_queue("articulo").unwrap());
+        assert!(!db.is_in_reading_queue("otra").unwrap());
+
+        // El progreso solo avanza, y las notas fuera de la cola no cuentan
+        db.update_reading_progress("articulo", 0.6).unwrap();
+        db.update_reading_progress("articulo", 0.2).unwrap();
+        db.update_reading_progress("otra", 0.5).unwrap();
+        // Volver a añadirla no reinicia el progreso
+        db.add_to_reading_queue("articulo").unwrap();
+        let queue = db.get_reading_queue().unwrap();
+        assert_eq!(queue.len(), 1);
+        assert_eq!(queue[0].name, "articulo");
+        assert_eq!(queue[0].progress, 0.6);
+
+        db.remove_from_reading_queue("articulo").unwrap();
+        assert!(db.get_reading_queue().unwrap().is_empty());
+
+        db.add_to_reading_queue("otra").unwrap();
+        db.delete_note("otra").unwrap();
+        assert!(db.get_reading_queue().unwrap().is_empty());
+
+        std::fs::remove_file(db_path).ok();
+    }
+
+    #[test]
+    fn test_goal_completions() {
+        let db_path = std::env::temp_dir().join("test_notes_goal_completions.db");
+        std::fs::remove_file(&db_path).ok();
+
+        let db = NotesDatabase::new(&db_path).unwrap();
+        let goal = WordGoal {
+            goal: 1500,
+            words: 1512,
+        };
+        assert!(db.record_goal_completion("Novela/Cap 1", &goal).unwrap());
+        // La misma nota con el mismo objetivo no vuelve a contar
+        assert!(!db.record_goal_completion("Novela/Cap 1", &goal).unwrap());
+        let higher = WordGoal {
+            goal: 2000,
+            words: 2001,
+        };
+        assert!(db.record_goal_completion("Novela/Cap 1", &higher).unwrap());
+
+        let completions = db.goal_completions_since(0).unwrap();
+        assert_eq!(completions.len(), 2);
+        assert_eq!(completions[0].note_name, "Novela/Cap 1");
+        assert_eq!(completions[1].words, 1512);
+        let tomorrow = (Utc::now() + chrono::Duration::days(1)).timestamp();
+        assert!(db.goal_completions_since(tomorrow).unwrap().is_empty());
+
+        std::fs::remove_file(db_path).ok();
+    }
+
+    #[test]
+    fn test_reading_position() {
+        let db_path = std::env::temp_dir().join("test_notes_reading_position.db");
+        std::fs::remove_file(&db_path).ok();
+
+        let db = NotesDatabase::new(&db_path).unwrap();
+        db.index_note("larga", "/path/to/larga.md", "# Larga", None)
+            .unwrap();
+        assert_eq!(db.get_reading_position("larga").unwrap(), None);
+
+        let position = ReadingPosition {
+            cursor_offset: 1200,
+            scroll_fraction: 0.4,
+        };
+        db.save_reading_position("larga", position).unwrap();
+        db.save_reading_position(
+            "larga",
+            ReadingPosition {
+                cursor_offset: 1500,
+                scroll_fraction: 1.7,
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            db.get_reading_position("larga").unwrap(),
+            Some(ReadingPosition {
+                cursor_offset: 1500,
+                scroll_fraction: 1.0,
+            })
+        );
+
+        // Se conserva al renombrar y se borra con la nota
+        db.rename_note(
+            "larga",
+            "archivo/larga",
+            "/path/to/archivo/larga.md",
+            Some("archivo"),
+        )
+        .unwrap();
+        assert!(db.get_reading_position("archivo/larga").unwrap().is_some());
+        db.delete_note("archivo/larga").unwrap();
+        assert_eq!(db.get_reading_position("archivo/larga").unwrap(), None);
+        assert!(db.save_reading_position("archivo/larga", position).is_err());
+
+        std::fs::remove_file(db_path).ok();
+    }
+
+    #[test]
+    fn test_note_aliases() {
+        let db_path = std::env::temp_dir().join("test_notes_aliases.db");
+        std::fs::remove_file(&db_path).ok();
+
+        let db = NotesDatabase::new(&db_path).unwrap();
+        let content = "---\naliases: [MCP, Servidor de herramientas]\n---\n# Conexión\n\nTexto";
+        db.index_note("conexion", "/path/to/conexion.md", content, None)
+            .unwrap();
+        assert_eq!(
+            db.get_note_aliases("conexion").unwrap(),
+            vec!["MCP", "Servidor de herramientas"]
+        );
+        assert_eq!(
+            db.resolve_alias("mcp").unwrap(),
+            Some("conexion".to_string())
+        );
+        assert_eq!(db.resolve_alias("Otra").unwrap(), None);
+
+        // El índice de búsqueda también encuentra la nota por sus alias
+        let results = db
+            .search(&SearchQuery {
+                text: Some("herramientas".to_string()),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].note_name, "conexion");
+
+        // Al renombrar el alias sigue apuntando a la nota
+        db.rename_note(
+            "conexion",
+            "ia/conexion",
+            "/path/to/ia/conexion.md",
+            Some("ia"),
+        )
+        .unwrap();
+        assert_eq!(
+            db.get_all_aliases().unwrap(),
+            vec![
+                ("MCP".to_string(), "ia/conexion".to_string()),
+                (
+                    "Servidor de herramientas".to_string(),
+                    "ia/conexion".to_string()
+                ),
+            ]
+        );
+
+        // Quitarlos del frontmatter los quita de la tabla
+        db.update_note("ia/conexion", "# Conexión").unwrap();
+        assert_eq!(db.resolve_alias("MCP").unwrap(), None);
+
+        std::fs::remove_file(db_path).ok();
+    }
+
+    #[test]
+    fn test_fork_chat_session() {
+        let db_path = std::env::temp_dir().join("test_notes_chat_fork.db");
+        std::fs::remove_file(&db_path).ok();
+
+        let db = NotesDatabase::new(&db_path).unwrap();
+        let note_id = db
+            .index_note("viaje", "/path/to/viaje.md", "# Viaje", None)
+            .unwrap();
+        let original = db
+            .create_chat_session("gpt-4", "openai", 0.7, 2000)
+            .unwrap();
+        db.attach_note_to_chat(original, note_id).unwrap();
+        for (role, content) in [
+            ("user", "uno"),
+            ("assistant", "dos"),
+            ("user", "tres"),
+            ("assistant", "cuatro"),
+        ] {
+            db.save_chat_message(original, role, content).unwrap();
+        }
+
+        let fork = db.fork_chat_session(original, 2).unwrap();
+        assert_ne!(fork, original);
+        let contents = |session| {
+            db.get_chat_messages(session)
+                .unwrap()
+                .into_iter()
+                .map(|(_, content, _)| content)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(contents(fork), vec!["uno", "dos"]);
+        assert_eq!(contents(original).len(), 4);
+        assert_eq!(db.get_chat_context_notes(fork).unwrap().len(), 1);
+        assert_eq!(db.get_chat_session_parent(fork).unwrap(), Some(original));
+        assert_eq!(db.get_chat_session_parent(original).unwrap(), None);
+
+        // La rama sigue por su cuenta
+        db.save_chat_message(fork, "user", "otra pregunta").unwrap();
+        assert_eq!(contents(original).len(), 4);
+
+        // Borrar la original no se lleva la rama
+        db.delete_chat_session(original).unwrap();
+        assert_eq!(contents(fork).len(), 3);
+        assert_eq!(db.get_chat_session_parent(fork).unwrap(), None);
+        assert!(db.fork_chat_session(original, 1).is_err());
+
+        std::fs::remove_file(db_path).ok();
+    }
+
+    #[test]
+    fn test_export_chat_session_with_agent_steps() {
+        let db_path = std::env::temp_dir().join("test_notes_chat_export.db");
+        std::fs::remove_file(&db_path).ok();
+
+        let db = NotesDatabase::new(&db_path).unwrap();
+        let session = db
+            .create_chat_session("gpt-4", "openai", 0.7, 2000)
+            .unwrap();
+        db.save_chat_message(session, "user", "Crea una nota")
+            .unwrap();
+        db.save_chat_agent_step(session, "tool_call", Some("create_note"), "{}")
+            .unwrap();
+        db.save_chat_agent_step(session, "observation", Some("create_note"), "Creada")
+            .unwrap();
+        db.save_chat_message(session, "assistant", "Hecho").unwrap();
+
+        assert_eq!(db.get_chat_session_ids().unwrap(), vec![session]);
+        let exported = db.export_chat_session(session).unwrap();
+        assert_eq!(exported.model, "gpt-4");
+        assert_eq!(exported.messages.len(), 2);
+        assert!(exported.messages[0].steps.is_empty());
+        let steps = &exported.messages[1].steps;
+        assert_eq!(steps.len(), 2);
+        assert_eq!(steps[0].tool.as_deref(), Some("create_note"));
+        assert_eq!(steps[1].content, "Creada");
+
+        db.delete_chat_session(session).unwrap();
+        assert!(db.export_chat_session(session).is_err());
+        let remaining: i64 = db
+            .conn
+            .query_row("SELECT COUNT(*) FROM chat_agent_steps", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(remaining, 0);
+
+        std::fs::remove_file(db_path).ok();
+    }
+
     #[test]
     fn test_embeddings() {
         let temp_dir = std::env::temp_dir();
@@ -3610,4 +5229,58 @@ mod tests {
         // Cleanup
         std::fs::remove_file(db_path).ok();
     }
+
+    #[test]
+    fn test_migrations_are_versioned() {
+        let db_path = std::env::temp_dir().join("test_notes_migrations.db");
+        std::fs::remove_file(&db_path).ok();
+
+        let last = NotesDatabase::MIGRATIONS.last().unwrap().version;
+        assert_eq!(last, NotesDatabase::SCHEMA_VERSION);
+
+        let db = NotesDatabase::new(&db_path).unwrap();
+        assert_eq!(
+            migrations::current_version(&db.conn).unwrap(),
+            NotesDatabase::SCHEMA_VERSION
+        );
+        db.index_note("persist", "/persist.md", "# Persist", None)
+            .unwrap();
+        drop(db);
+
+        // Reabrir no vuelve a ejecutar migraciones (la v5 recrearía la tabla FTS)
+        let db = NotesDatabase::new(&db_path).unwrap();
+        assert_eq!(db.search_notes("Persist").unwrap().len(), 1);
+
+        std::fs::remove_file(db_path).ok();
+    }
+
+    #[test]
+    fn test_legacy_schema_version_is_adopted() {
+        let db_path = std::env::temp_dir().join("test_notes_legacy_version.db");
+        std::fs::remove_file(&db_path).ok();
+
+        {
+            let conn = Connection::open(&db_path).unwrap();
+            NotesDatabase::initialize_schema(&conn).unwrap();
+            conn.execute_batch(
+                "CREATE TABLE schema_version (version INTEGER PRIMARY KEY);
+                 INSERT INTO schema_version VALUES (1), (2), (3);",
+            )
+            .unwrap();
+        }
+
+        let db = NotesDatabase::new(&db_path).unwrap();
+        assert_eq!(
+            migrations::current_version(&db.conn).unwrap(),
+            NotesDatabase::SCHEMA_VERSION
+        );
+        // v4 en adelante se aplicaron
+        db.conn
+            .query_row("SELECT COUNT(*) FROM reminders", [], |row| {
+                row.get::<_, i64>(0)
+            })
+            .unwrap();
+
+        std::fs::remove_file(db_path).ok();
+    }
 }
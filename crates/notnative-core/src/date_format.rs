@@ -0,0 +1,377 @@
+//! Formateo de fechas y horas según el idioma de la interfaz
+//!
+//! Combina el idioma de i18n con la región de `LC_TIME` (si coincide el idioma)
+//! para elegir orden día/mes, nombres de meses y reloj de 12/24 horas.
+//! La instancia global se actualiza al cambiar idioma o preferencia de hora.
+
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use serde::{Deserialize, Serialize};
+use std::sync::{LazyLock, RwLock};
+
+/// Preferencia de reloj
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum HourFormat {
+    /// Según la región (12h en en_US, 24h en el resto)
+    #[default]
+    Auto,
+    H24,
+    H12,
+}
+
+/// Orden de los componentes en fechas numéricas
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DateOrder {
+    DayMonthYear,
+    MonthDayYear,
+    YearMonthDay,
+}
+
+/// Formateador de fechas dependiente del locale
+#[derive(Debug, Clone)]
+pub struct DateFormatter {
+    language: String,
+    region: Option<String>,
+    hour_format: HourFormat,
+}
+
+static GLOBAL_FORMATTER: LazyLock<RwLock<DateFormatter>> =
+    LazyLock::new(|| RwLock::new(DateFormatter::new("es", HourFormat::Auto)));
+
+impl Default for DateFormatter {
+    fn default() -> Self {
+        Self::new("es", HourFormat::Auto)
+    }
+}
+
+impl DateFormatter {
+    /// Crea un formateador para un locale tipo "es", "en_US" o "pt-BR".
+    /// Si `LC_TIME` tiene el mismo idioma, se usa su región.
+    pub fn new(locale: &str, hour_format: HourFormat) -> Self {
+        let (language, mut region) = split_locale(locale);
+
+        if region.is_none() {
+            if let Some(lc_time) = lc_time_locale() {
+                let (lc_language, lc_region) = split_locale(&lc_time);
+                if lc_language == language {
+                    region = lc_region;
+                }
+            }
+        }
+
+        Self {
+            language,
+            region,
+            hour_format,
+        }
+    }
+
+    /// Formateador global (configurado por la app según idioma y preferencias)
+    pub fn global() -> DateFormatter {
+        GLOBAL_FORMATTER
+            .read()
+            .map(|f| f.clone())
+            .unwrap_or_default()
+    }
+
+    /// Reemplaza el formateador global
+    pub fn set_global(formatter: DateFormatter) {
+        if let Ok(mut global) = GLOBAL_FORMATTER.write() {
+            *global = formatter;
+        }
+    }
+
+    /// Indica si se usa reloj de 24 horas
+    pub fn uses_24h(&self) -> bool {
+        match self.hour_format {
+            HourFormat::H24 => true,
+            HourFormat::H12 => false,
+            HourFormat::Auto => {
+                !(self.language == "en"
+                    && matches!(
+                        self.region.as_deref(),
+                        None | Some("US" | "CA" | "AU" | "PH")
+                    ))
+            }
+        }
+    }
+
+    fn date_order(&self) -> DateOrder {
+        match (self.language.as_str(), self.region.as_deref()) {
+            ("en", None | Some("US" | "PH")) => DateOrder::MonthDayYear,
+            ("en", Some("CA")) => DateOrder::YearMonthDay,
+            _ => DateOrder::DayMonthYear,
+        }
+    }
+
+    /// Fecha corta numérica: 15/11/2025, 11/15/2025, 15.11.2025...
+    pub fn format_date_short(&self, date: NaiveDate) -> String {
+        let (d, m, y) = (date.day(), date.month(), date.year());
+        match (self.date_order(), self.language.as_str()) {
+            (DateOrder::YearMonthDay, _) => format!("{:04}-{:02}-{:02}", y, m, d),
+            (DateOrder::MonthDayYear, _) => format!("{:02}/{:02}/{:04}", m, d, y),
+            (DateOrder::DayMonthYear, "de") => format!("{:02}.{:02}.{:04}", d, m, y),
+            (DateOrder::DayMonthYear, _) => format!("{:02}/{:02}/{:04}", d, m, y),
+        }
+    }
+
+    /// Fecha con nombre de mes abreviado: "15 nov 2025", "Nov 15, 2025", "15. Nov. 2025"
+    pub fn format_date(&self, date: NaiveDate) -> String {
+        let month = self.month_abbr(date.month());
+        match (self.date_order(), self.language.as_str()) {
+            (DateOrder::MonthDayYear, _) => format!("{} {}, {}", month, date.day(), date.year()),
+            (_, "de") => format!("{}. {} {}", date.day(), month, date.year()),
+            (_, "pt") => format!("{} de {} de {}", date.day(), month, date.year()),
+            _ => format!("{} {} {}", date.day(), month, date.year()),
+        }
+    }
+
+    /// Mes y año: "oct 2026", "Oct 2026"
+    pub fn format_month(&self, date: NaiveDate) -> String {
+        format!("{} {}", self.month_abbr(date.month()), date.year())
+    }
+
+    /// Hora: "14:05" o "2:05 PM"
+    pub fn format_time(&self, time: NaiveTime) -> String {
+        if self.uses_24h() {
+            format!("{:02}:{:02}", time.hour(), time.minute())
+        } else {
+            let (is_pm, hour) = time.hour12();
+            format!(
+                "{}:{:02} {}",
+                hour,
+                time.minute(),
+                if is_pm { "PM" } else { "AM" }
+            )
+        }
+    }
+
+    /// Hora con segundos: "14:05:09" o "2:05:09 PM"
+    pub fn format_time_seconds(&self, time: NaiveTime) -> String {
+        if self.uses_24h() {
+            format!(
+                "{:02}:{:02}:{:02}",
+                time.hour(),
+                time.minute(),
+                time.second()
+            )
+        } else {
+            let (is_pm, hour) = time.hour12();
+            format!(
+                "{}:{:02}:{:02} {}",
+                hour,
+                time.minute(),
+                time.second(),
+                if is_pm { "PM" } else { "AM" }
+            )
+        }
+    }
+
+    /// Fecha y hora: "15 nov 2025, 14:05"
+    pub fn format_datetime(&self, datetime: NaiveDateTime) -> String {
+        format!(
+            "{}, {}",
+            self.format_date(datetime.date()),
+            self.format_time(datetime.time())
+        )
+    }
+
+    /// Fecha y hora local desde cualquier zona horaria
+    pub fn format_local<Tz: chrono::TimeZone>(&self, datetime: &DateTime<Tz>) -> String {
+        self.format_datetime(datetime.with_timezone(&Local).naive_local())
+    }
+
+    /// Fecha relativa al día actual: "Hoy a las 14:05", "Mañana a las 9:00", "Ayer a las..."
+    pub fn format_relative(&self, datetime: NaiveDateTime, now: NaiveDateTime) -> String {
+        let today = now.date();
+        let date = datetime.date();
+        let time = self.format_time(datetime.time());
+
+        let day_word = if date == today {
+            Some(self.word(Word::Today))
+        } else if date == today + Duration::days(1) {
+            Some(self.word(Word::Tomorrow))
+        } else if date == today - Duration::days(1) {
+            Some(self.word(Word::Yesterday))
+        } else {
+            None
+        };
+
+        match day_word {
+            Some(word) => format!("{} {} {}", word, self.word(Word::At), time),
+            None => self.format_datetime(datetime),
+        }
+    }
+
+    fn month_abbr(&self, month: u32) -> &'static str {
+        let names: [&str; 12] = match self.language.as_str() {
+            "en" => [
+                "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+            ],
+            "fr" => [
+                "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.",
+                "nov.", "déc.",
+            ],
+            "de" => [
+                "Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.",
+                "Nov.", "Dez.",
+            ],
+            "pt" => [
+                "jan", "fev", "mar", "abr", "mai", "jun", "jul", "ago", "set", "out", "nov", "dez",
+            ],
+            "it" => [
+                "gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic",
+            ],
+            _ => [
+                "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic",
+            ],
+        };
+        names[(month.clamp(1, 12) - 1) as usize]
+    }
+
+    fn word(&self, word: Word) -> &'static str {
+        match (self.language.as_str(), word) {
+            ("en", Word::Today) => "Today",
+            ("en", Word::Tomorrow) => "Tomorrow",
+            ("en", Word::Yesterday) => "Yesterday",
+            ("en", Word::At) => "at",
+            ("fr", Word::Today) => "Aujourd'hui",
+            ("fr", Word::Tomorrow) => "Demain",
+            ("fr", Word::Yesterday) => "Hier",
+            ("fr", Word::At) => "à",
+            ("de", Word::Today) => "Heute",
+            ("de", Word::Tomorrow) => "Morgen",
+            ("de", Word::Yesterday) => "Gestern",
+            ("de", Word::At) => "um",
+            ("pt", Word::Today) => "Hoje",
+            ("pt", Word::Tomorrow) => "Amanhã",
+            ("pt", Word::Yesterday) => "Ontem",
+            ("pt", Word::At) => "às",
+            ("it", Word::Today) => "Oggi",
+            ("it", Word::Tomorrow) => "Domani",
+            ("it", Word::Yesterday) => "Ieri",
+            ("it", Word::At) => "alle",
+            (_, Word::Today) => "Hoy",
+            (_, Word::Tomorrow) => "Mañana",
+            (_, Word::Yesterday) => "Ayer",
+            (_, Word::At) => "a las",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Word {
+    Today,
+    Tomorrow,
+    Yesterday,
+    At,
+}
+
+/// Divide "pt_BR.UTF-8" en ("pt", Some("BR"))
+fn split_locale(locale: &str) -> (String, Option<String>) {
+    let base = locale.split(['.', '@']).next().unwrap_or(locale);
+    let mut parts = base.split(['_', '-']);
+    let language = parts.next().unwrap_or("").to_lowercase();
+    let region = parts
+        .next()
+        .filter(|r| !r.is_empty())
+        .map(|r| r.to_uppercase());
+    (language, region)
+}
+
+/// Locale de fechas del sistema (LC_ALL > LC_TIME > LANG)
+fn lc_time_locale() -> Option<String> {
+    ["LC_ALL", "LC_TIME", "LANG"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|value| !value.is_empty() && value != "C" && value != "POSIX")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_short_dates_per_locale() {
+        let d = date(2025, 11, 5);
+        assert_eq!(
+            DateFormatter::new("es_ES", HourFormat::Auto).format_date_short(d),
+            "05/11/2025"
+        );
+        assert_eq!(
+            DateFormatter::new("en_US", HourFormat::Auto).format_date_short(d),
+            "11/05/2025"
+        );
+        assert_eq!(
+            DateFormatter::new("en_GB", HourFormat::Auto).format_date_short(d),
+            "05/11/2025"
+        );
+        assert_eq!(
+            DateFormatter::new("de_DE", HourFormat::Auto).format_date_short(d),
+            "05.11.2025"
+        );
+    }
+
+    #[test]
+    fn test_month_names() {
+        let d = date(2025, 8, 15);
+        assert_eq!(
+            DateFormatter::new("fr_FR", HourFormat::Auto).format_date(d),
+            "15 août 2025"
+        );
+        assert_eq!(
+            DateFormatter::new("en_US", HourFormat::Auto).format_date(d),
+            "Aug 15, 2025"
+        );
+        assert_eq!(
+            DateFormatter::new("pt_BR", HourFormat::Auto).format_date(d),
+            "15 de ago de 2025"
+        );
+    }
+
+    #[test]
+    fn test_hour_format() {
+        let t = NaiveTime::from_hms_opt(14, 5, 0).unwrap();
+        assert_eq!(
+            DateFormatter::new("en_US", HourFormat::Auto).format_time(t),
+            "2:05 PM"
+        );
+        assert_eq!(
+            DateFormatter::new("en_GB", HourFormat::Auto).format_time(t),
+            "14:05"
+        );
+        assert_eq!(
+            DateFormatter::new("es_ES", HourFormat::H12).format_time(t),
+            "2:05 PM"
+        );
+        assert_eq!(
+            DateFormatter::new("en_US", HourFormat::H24).format_time(t),
+            "14:05"
+        );
+    }
+
+    #[test]
+    fn test_relative_days() {
+        let f = DateFormatter::new("es_ES", HourFormat::H24);
+        let now = date(2025, 11, 5).and_hms_opt(10, 0, 0).unwrap();
+
+        let tomorrow = date(2025, 11, 6).and_hms_opt(9, 30, 0).unwrap();
+        assert_eq!(f.format_relative(tomorrow, now), "Mañana a las 09:30");
+
+        let later = date(2025, 11, 20).and_hms_opt(9, 30, 0).unwrap();
+        assert_eq!(f.format_relative(later, now), "20 nov 2025, 09:30");
+    }
+
+    #[test]
+    fn test_split_locale() {
+        assert_eq!(
+            split_locale("pt_BR.UTF-8"),
+            ("pt".to_string(), Some("BR".to_string()))
+        );
+        assert_eq!(split_locale("de"), ("de".to_string(), None));
+    }
+}
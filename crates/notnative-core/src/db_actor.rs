@@ -0,0 +1,244 @@
+//! Actor de la base de datos
+//!
+//! Un hilo dedicado es el dueño de una conexión y atiende las peticiones en
+//! orden de llegada. El servidor MCP y el vigilante de archivos le mandan
+//! mensajes en lugar de compartir una conexión tras un `Mutex`: nadie espera
+//! a que otro suelte el candado y las actualizaciones de varias tablas (nota,
+//! tags, enlaces...) van en una sola transacción sin intercalarse con otras.
+//!
+//! - [`DbActor::call`]: desde código async (Tokio).
+//! - [`DbActor::call_blocking`]: desde hilos normales.
+//! - [`DbActor::connection`]: conexión propia del mismo pool para trabajo
+//!   largo (red, embeddings) que no debe ocupar el hilo del actor.
+
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc;
+use tokio::sync::oneshot;
+use tracing::{debug, error};
+
+use crate::database::{DatabaseError, NotesDatabase, Result};
+use crate::db_pool::DbPool;
+
+type Request = Box<dyn FnOnce(&mut NotesDatabase) + Send>;
+
+#[derive(Debug, Clone)]
+pub struct DbActor {
+    requests: mpsc::Sender<Request>,
+    pool: DbPool,
+}
+
+impl DbActor {
+    /// Arranca el hilo del actor con su conexión a la base de datos
+    pub fn start(db: NotesDatabase) -> std::io::Result<Self> {
+        let (requests, receiver) = mpsc::channel::<Request>();
+        let pool = db.pool().clone();
+        let mut db = db;
+
+        std::thread::Builder::new()
+            .name("notnative-db".to_string())
+            .spawn(move || {
+                for request in receiver {
+                    // Un cierre que entra en pánico no puede tumbar el actor:
+                    // quien lo mandó recibe `ActorStopped` y el resto sigue
+                    let outcome = panic::catch_unwind(AssertUnwindSafe(|| request(&mut db)));
+                    if let Err(payload) = outcome {
+                        let message = payload
+                            .downcast_ref::<&str>()
+                            .map(|s| s.to_string())
+                            .or_else(|| payload.downcast_ref::<String>().cloned())
+                            .unwrap_or_default();
+                        error!(
+                            "Petición a la base de datos abortada por un pánico: {}",
+                            message
+                        );
+                    }
+                }
+                debug!("Actor de la base de datos finalizado");
+            })?;
+
+        Ok(Self { requests, pool })
+    }
+
+    /// Conexión del pool del actor, para usarla fuera de él (en un hilo de
+    /// `spawn_blocking`, por ejemplo). Lo escrito por una se ve en la otra.
+    pub fn connection(&self) -> Result<NotesDatabase> {
+        NotesDatabase::from_pool(self.pool.clone())
+    }
+
+    fn send<T, F>(&self, f: F, reply: impl FnOnce(T) + Send + 'static) -> Result<()>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut NotesDatabase) -> T + Send + 'static,
+    {
+        let request: Request = Box::new(move |db| reply(f(db)));
+        self.requests
+            .send(request)
+            .map_err(|_| DatabaseError::ActorStopped)
+    }
+
+    /// Ejecuta `f` con la conexión del actor y espera su resultado
+    pub async fn call<T, F>(&self, f: F) -> Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut NotesDatabase) -> T + Send + 'static,
+    {
+        let (tx, rx) = oneshot::channel();
+        self.send(f, move |value| {
+            let _ = tx.send(value);
+        })?;
+        rx.await.map_err(|_| DatabaseError::ActorStopped)
+    }
+
+    /// Como [`DbActor::call`], bloqueando el hilo actual hasta la respuesta.
+    /// No usar desde el propio hilo del actor ni dentro de Tokio.
+    pub fn call_blocking<T, F>(&self, f: F) -> Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut NotesDatabase) -> T + Send + 'static,
+    {
+        let (tx, rx) = mpsc::sync_channel(1);
+        self.send(f, move |value| {
+            let _ = tx.send(value);
+        })?;
+        rx.recv().map_err(|_| DatabaseError::ActorStopped)
+    }
+
+    /// Ejecuta `f` en una transacción (ver [`NotesDatabase::transaction`])
+    pub async fn transaction<T, F>(&self, f: F) -> Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&NotesDatabase) -> Result<T> + Send + 'static,
+    {
+        self.call(move |db| db.transaction(f)).await?
+    }
+
+    /// Indexa una nota con sus tags, propiedades y alias
+    pub async fn index_note(
+        &self,
+        name: &str,
+        path: &str,
+        content: &str,
+        folder: Option<&str>,
+    ) -> Result<i64> {
+        let (name, path, content) = (name.to_string(), path.to_string(), content.to_string());
+        let folder = folder.map(str::to_string);
+        self.call(move |db| db.index_note(&name, &path, &content, folder.as_deref()))
+            .await?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::temp_db;
+
+    fn runtime() -> tokio::runtime::Runtime {
+        tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(2)
+            .enable_all()
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_concurrent_calls() {
+        let (db, path) = temp_db("concurrent");
+        let actor = DbActor::start(db).unwrap();
+
+        runtime().block_on(async {
+            let tasks: Vec<_> = (0..20)
+                .map(|i| {
+                    let actor = actor.clone();
+                    tokio::spawn(async move {
+                        let content = format!("Nota {} #tag{}", i, i % 3);
+                        actor
+                            .index_note(&format!("n{}", i), &format!("/n{}.md", i), &content, None)
+                            .await
+                    })
+                })
+                .collect();
+            for task in tasks {
+                task.await.unwrap().unwrap();
+            }
+
+            let notes = actor.call(|db| db.list_notes(None)).await.unwrap().unwrap();
+            assert_eq!(notes.len(), 20);
+        });
+
+        // También desde un hilo normal
+        let tags = std::thread::spawn(move || actor.call_blocking(|db| db.get_tags()))
+            .join()
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        assert_eq!(tags.len(), 3);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_transaction_rolls_back() {
+        let (db, path) = temp_db("rollback");
+        let actor = DbActor::start(db).unwrap();
+
+        runtime().block_on(async {
+            let result: Result<()> = actor
+                .transaction(|db| {
+                    db.index_note("a", "/a.md", "#uno", None)?;
+                    Err(DatabaseError::NoteNotFound("b".into()))
+                })
+                .await;
+            assert!(result.is_err());
+
+            let note = actor.call(|db| db.get_note("a")).await.unwrap().unwrap();
+            assert!(note.is_none());
+            let in_transaction = actor.call(|db| db.in_transaction()).await.unwrap();
+            assert!(!in_transaction);
+        });
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_survives_panicking_request() {
+        let (db, path) = temp_db("panic");
+        let actor = DbActor::start(db).unwrap();
+
+        let result = actor.call_blocking(|_| -> () { panic!("fallo en la petición") });
+        assert!(matches!(result, Err(DatabaseError::ActorStopped)));
+
+        // El actor sigue atendiendo
+        actor
+            .call_blocking(|db| db.index_note("a", "/a.md", "# A", None))
+            .unwrap()
+            .unwrap();
+        runtime().block_on(async {
+            let note = actor.call(|db| db.get_note("a")).await.unwrap().unwrap();
+            assert!(note.is_some());
+        });
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_connection_outside_actor() {
+        let (db, path) = temp_db("connection");
+        let actor = DbActor::start(db).unwrap();
+
+        // Lo que escribe una conexión del pool lo ve el actor, y al revés
+        let other = actor.connection().unwrap();
+        std::thread::spawn(move || other.index_note("a", "/a.md", "# A", None).unwrap())
+            .join()
+            .unwrap();
+        let note = actor.call_blocking(|db| db.get_note("a")).unwrap().unwrap();
+        assert!(note.is_some());
+
+        actor
+            .call_blocking(|db| db.index_note("b", "/b.md", "# B", None))
+            .unwrap()
+            .unwrap();
+        assert!(actor.connection().unwrap().get_note("b").unwrap().is_some());
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,305 @@
+//! Pool de conexiones SQLite compartido
+//!
+//! La app, el servidor MCP, los recordatorios y el file watcher abren la misma
+//! base de datos. Todas las conexiones se configuran igual (WAL + `busy_timeout`)
+//! para que lectores y escritores no se bloqueen entre sí, y se reutilizan a
+//! través de un pool por ruta en lugar de abrir una conexión nueva cada vez.
+
+use rusqlite::Connection;
+use std::collections::HashMap;
+use std::ops::{Deref, DerefMut};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+use tracing::warn;
+
+/// Tiempo que espera una conexión a que otra libere el bloqueo de escritura
+pub const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Conexiones inactivas que se conservan por base de datos
+const MAX_IDLE: usize = 4;
+
+static POOLS: OnceLock<Mutex<HashMap<PathBuf, DbPool>>> = OnceLock::new();
+
+/// Aplica la configuración común a una conexión recién abierta
+pub fn configure_connection(conn: &Connection) -> rusqlite::Result<()> {
+    conn.busy_timeout(BUSY_TIMEOUT)?;
+
+    // WAL es persistente en el archivo; en memoria SQLite responde "memory"
+    let mode: String = conn.query_row("PRAGMA journal_mode = WAL", [], |row| row.get(0))?;
+    if !mode.eq_ignore_ascii_case("wal") && !mode.eq_ignore_ascii_case("memory") {
+        warn!("No se pudo activar WAL (journal_mode = {})", mode);
+    }
+
+    // Con WAL, NORMAL es seguro ante cierres inesperados y evita un fsync por commit
+    conn.execute_batch("PRAGMA synchronous = NORMAL;")?;
+    Ok(())
+}
+
+/// Abre una conexión configurada fuera de cualquier pool
+pub fn open_connection(path: &Path) -> rusqlite::Result<Connection> {
+    let conn = Connection::open(path)?;
+    configure_connection(&conn)?;
+    Ok(conn)
+}
+
+struct PoolInner {
+    path: PathBuf,
+    idle: Mutex<Vec<Connection>>,
+}
+
+/// Pool pequeño de conexiones a una base de datos
+#[derive(Clone)]
+pub struct DbPool {
+    inner: Arc<PoolInner>,
+}
+
+impl std::fmt::Debug for DbPool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DbPool")
+            .field("path", &self.inner.path)
+            .field("idle", &self.idle_count())
+            .finish()
+    }
+}
+
+impl DbPool {
+    /// Crea un pool independiente (no registrado)
+    pub fn new(path: &Path) -> Self {
+        Self {
+            inner: Arc::new(PoolInner {
+                path: path.to_path_buf(),
+                idle: Mutex::new(Vec::new()),
+            }),
+        }
+    }
+
+    /// Pool compartido por todo el proceso para la ruta indicada
+    pub fn shared(path: &Path) -> Self {
+        let pools = POOLS.get_or_init(|| Mutex::new(HashMap::new()));
+        let mut pools = pools.lock().unwrap_or_else(|e| e.into_inner());
+        pools
+            .entry(path.to_path_buf())
+            .or_insert_with(|| Self::new(path))
+            .clone()
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.inner.path
+    }
+
+    /// Toma una conexión inactiva o abre una nueva
+    pub fn get(&self) -> rusqlite::Result<PooledConnection> {
+        let idle = self
+            .inner
+            .idle
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .pop();
+
+        let conn = match idle {
+            Some(conn) => conn,
+            None => open_connection(&self.inner.path)?,
+        };
+
+        Ok(PooledConnection {
+            conn: Some(conn),
+            pool: Some(self.inner.clone()),
+        })
+    }
+
+    /// Cierra las conexiones inactivas (p. ej. si el archivo se borró y va a recrearse)
+    pub fn clear(&self) {
+        self.inner
+            .idle
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+    }
+
+    /// Prepara el pool para una base de datos que ya no existe en disco: cierra las
+    /// conexiones al archivo anterior y borra su `-wal`/`-shm`, que SQLite aplicaría
+    /// sobre el archivo nuevo
+    pub fn reset_if_missing(&self) {
+        if self.inner.path.exists() {
+            return;
+        }
+        self.clear();
+        for suffix in ["-wal", "-shm"] {
+            let mut sidecar = self.inner.path.clone().into_os_string();
+            sidecar.push(suffix);
+            let _ = std::fs::remove_file(sidecar);
+        }
+    }
+
+    pub fn idle_count(&self) -> usize {
+        self.inner
+            .idle
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .len()
+    }
+}
+
+/// Conexión prestada por un [`DbPool`]; vuelve al pool al soltarse
+pub struct PooledConnection {
+    conn: Option<Connection>,
+    pool: Option<Arc<PoolInner>>,
+}
+
+impl std::fmt::Debug for PooledConnection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PooledConnection")
+            .field("pooled", &self.pool.is_some())
+            .finish()
+    }
+}
+
+/// Conexión suelta que se cierra al soltarse (bases en memoria, tests...)
+impl From<Connection> for PooledConnection {
+    fn from(conn: Connection) -> Self {
+        Self {
+            conn: Some(conn),
+            pool: None,
+        }
+    }
+}
+
+impl Deref for PooledConnection {
+    type Target = Connection;
+
+    fn deref(&self) -> &Connection {
+        self.conn.as_ref().expect("conexión ya devuelta al pool")
+    }
+}
+
+impl DerefMut for PooledConnection {
+    fn deref_mut(&mut self) -> &mut Connection {
+        self.conn.as_mut().expect("conexión ya devuelta al pool")
+    }
+}
+
+impl Drop for PooledConnection {
+    fn drop(&mut self) {
+        let (Some(conn), Some(pool)) = (self.conn.take(), self.pool.take()) else {
+            return;
+        };
+
+        // Una conexión con una transacción abierta no se reutiliza
+        if !conn.is_autocommit() {
+            return;
+        }
+
+        let mut idle = pool.idle.lock().unwrap_or_else(|e| e.into_inner());
+        if idle.len() < MAX_IDLE {
+            idle.push(conn);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("notnative-pool-{}-{}.db", name, std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn test_connections_use_wal_and_busy_timeout() {
+        let path = temp_path("wal");
+        let pool = DbPool::new(&path);
+        let conn = pool.get().unwrap();
+
+        let mode: String = conn
+            .query_row("PRAGMA journal_mode", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(mode.to_lowercase(), "wal");
+
+        let timeout: i64 = conn
+            .query_row("PRAGMA busy_timeout", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(timeout, BUSY_TIMEOUT.as_millis() as i64);
+
+        drop(conn);
+        pool.clear();
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_connections_are_reused() {
+        let path = temp_path("reuse");
+        let pool = DbPool::new(&path);
+
+        let a = pool.get().unwrap();
+        let b = pool.get().unwrap();
+        assert_eq!(pool.idle_count(), 0);
+        drop(a);
+        drop(b);
+        assert_eq!(pool.idle_count(), 2);
+
+        let _c = pool.get().unwrap();
+        assert_eq!(pool.idle_count(), 1);
+
+        // Una conexión con transacción abierta se descarta
+        let d = pool.get().unwrap();
+        d.execute_batch("BEGIN").unwrap();
+        drop(d);
+        assert_eq!(pool.idle_count(), 0);
+
+        pool.clear();
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_shared_pool_is_per_path() {
+        let path = temp_path("shared");
+        let a = DbPool::shared(&path);
+        let b = DbPool::shared(&path);
+
+        drop(a.get().unwrap());
+        assert_eq!(b.idle_count(), 1);
+
+        a.clear();
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_concurrent_writers_wait_instead_of_failing() {
+        let path = temp_path("concurrent");
+        let pool = DbPool::new(&path);
+        pool.get()
+            .unwrap()
+            .execute_batch("CREATE TABLE t (n INTEGER)")
+            .unwrap();
+
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                let pool = pool.clone();
+                std::thread::spawn(move || {
+                    let conn = pool.get().unwrap();
+                    for n in 0..50 {
+                        conn.execute("INSERT INTO t (n) VALUES (?1)", [i * 100 + n])
+                            .unwrap();
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let count: i64 = pool
+            .get()
+            .unwrap()
+            .query_row("SELECT COUNT(*) FROM t", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 200);
+
+        pool.clear();
+        let _ = std::fs::remove_file(&path);
+    }
+}
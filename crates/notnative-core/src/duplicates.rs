@@ -0,0 +1,265 @@
+//! Notas casi repetidas
+//!
+//! Antes de crear o importar una nota se buscan otras que ya digan lo mismo:
+//! un título muy parecido (sin contar mayúsculas, acentos, signos ni carpeta)
+//! o, con embeddings, un contenido muy similar. Así se puede abrir o fusionar
+//! la que existe en lugar de llenar el vault de copias.
+
+use crate::frontmatter::Frontmatter;
+use crate::text_chunker::chunk_source;
+
+/// Parecido mínimo entre títulos (coeficiente de Dice sobre pares de letras)
+pub const TITLE_THRESHOLD: f32 = 0.8;
+
+/// Similitud mínima de embeddings para dar el contenido por repetido
+pub const CONTENT_THRESHOLD: f32 = 0.9;
+
+/// Notas parecidas que se muestran como máximo
+pub const MAX_CANDIDATES: usize = 3;
+
+/// Por qué se parece a la nota nueva
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DuplicateReason {
+    Title,
+    Content,
+}
+
+/// Nota existente que parece la misma que la nueva
+#[derive(Debug, Clone, PartialEq)]
+pub struct DuplicateCandidate {
+    pub name: String,
+    pub reason: DuplicateReason,
+    /// Parecido del título o similitud de embeddings (0 a 1)
+    pub score: f32,
+}
+
+/// Título de un contenido importado: su primer `# Encabezado`, tras el
+/// frontmatter
+pub fn content_title(content: &str) -> Option<String> {
+    let (_, body) = Frontmatter::parse_or_empty(content);
+    body.lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .and_then(|line| line.strip_prefix("# "))
+        .map(|title| title.trim().to_string())
+        .filter(|title| !title.is_empty())
+}
+
+/// Notas de `existing` con un título casi igual a `title`, de más a menos
+/// parecida
+pub fn title_duplicates<'a>(
+    title: &str,
+    existing: impl IntoIterator<Item = &'a str>,
+) -> Vec<DuplicateCandidate> {
+    let mut candidates: Vec<DuplicateCandidate> = existing
+        .into_iter()
+        .filter_map(|name| {
+            let score = title_similarity(title, name);
+            (score >= TITLE_THRESHOLD).then(|| DuplicateCandidate {
+                name: name.to_string(),
+                reason: DuplicateReason::Title,
+                score,
+            })
+        })
+        .collect();
+    sort_and_truncate(&mut candidates);
+    candidates
+}
+
+/// Notas con un contenido casi igual según los embeddings: `(similitud,
+/// nombre)` tal como los devuelve la búsqueda semántica, que puede repetir
+/// nota con varios fragmentos (`nota#2`)
+pub fn content_duplicates(
+    results: impl IntoIterator<Item = (f32, String)>,
+) -> Vec<DuplicateCandidate> {
+    let mut candidates: Vec<DuplicateCandidate> = Vec::new();
+    for (score, name) in results {
+        if score < CONTENT_THRESHOLD {
+            continue;
+        }
+        let name = chunk_source(&name).to_string();
+        match candidates.iter_mut().find(|c| c.name == name) {
+            Some(existing) => existing.score = existing.score.max(score),
+            None => candidates.push(DuplicateCandidate {
+                name,
+                reason: DuplicateReason::Content,
+                score,
+            }),
+        }
+    }
+    sort_and_truncate(&mut candidates);
+    candidates
+}
+
+/// Junta las parecidas por título y por contenido, sin repetir nota (el
+/// título va primero: es el aviso más claro)
+pub fn merge_candidates(
+    by_title: Vec<DuplicateCandidate>,
+    by_content: Vec<DuplicateCandidate>,
+) -> Vec<DuplicateCandidate> {
+    let mut candidates = by_title;
+    for candidate in by_content {
+        if !candidates.iter().any(|c| c.name == candidate.name) {
+            candidates.push(candidate);
+        }
+    }
+    candidates.truncate(MAX_CANDIDATES);
+    candidates
+}
+
+/// Parecido entre dos títulos de 0 a 1. Se compara el nombre sin carpeta,
+/// sin mayúsculas, acentos ni signos. Si los números no coinciden no se
+/// parecen (`Diario 2024-05-01` y `Diario 2024-05-02` son notas distintas).
+pub fn title_similarity(a: &str, b: &str) -> f32 {
+    let a = normalize_title(a);
+    let b = normalize_title(b);
+    if a.is_empty() || b.is_empty() || numbers(&a) != numbers(&b) {
+        return 0.0;
+    }
+    if a == b {
+        return 1.0;
+    }
+
+    let a = bigrams(&a);
+    let b = bigrams(&b);
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    let mut remaining = b.clone();
+    let mut common = 0;
+    for pair in &a {
+        if let Some(index) = remaining.iter().position(|other| other == pair) {
+            remaining.swap_remove(index);
+            common += 1;
+        }
+    }
+    (2 * common) as f32 / (a.len() + b.len()) as f32
+}
+
+/// Último componente del nombre en minúsculas, sin acentos y con las
+/// palabras separadas por un espacio
+fn normalize_title(title: &str) -> String {
+    let base = title.rsplit('/').next().unwrap_or(title);
+    let base = base.strip_suffix(".md").unwrap_or(base);
+    base.to_lowercase()
+        .chars()
+        .map(fold_accent)
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn fold_accent(c: char) -> char {
+    match c {
+        'á' | 'à' | 'ä' | 'â' | 'ã' => 'a',
+        'é' | 'è' | 'ë' | 'ê' => 'e',
+        'í' | 'ì' | 'ï' | 'î' => 'i',
+        'ó' | 'ò' | 'ö' | 'ô' | 'õ' => 'o',
+        'ú' | 'ù' | 'ü' | 'û' => 'u',
+        'ñ' => 'n',
+        'ç' => 'c',
+        _ => c,
+    }
+}
+
+fn numbers(title: &str) -> Vec<&str> {
+    title
+        .split(|c: char| !c.is_ascii_digit())
+        .filter(|run| !run.is_empty())
+        .collect()
+}
+
+/// Pares de letras seguidas de cada palabra
+fn bigrams(title: &str) -> Vec<(char, char)> {
+    title
+        .split(' ')
+        .flat_map(|word| {
+            let chars: Vec<char> = word.chars().collect();
+            if chars.len() == 1 {
+                vec![(chars[0], ' ')]
+            } else {
+                chars.windows(2).map(|pair| (pair[0], pair[1])).collect()
+            }
+        })
+        .collect()
+}
+
+fn sort_and_truncate(candidates: &mut Vec<DuplicateCandidate>) {
+    candidates.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    candidates.truncate(MAX_CANDIDATES);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_title_similarity() {
+        assert_eq!(
+            title_similarity("Reunión semanal", "trabajo/reunion-semanal"),
+            1.0
+        );
+        assert!(title_similarity("Ideas de proyecto", "Ideas del proyecto") >= TITLE_THRESHOLD);
+        assert!(title_similarity("Ideas", "Recetas") < TITLE_THRESHOLD);
+        // Las notas diarias solo se diferencian en la fecha
+        assert_eq!(
+            title_similarity("Diario 2024-05-01", "Diario 2024-05-02"),
+            0.0
+        );
+        assert_eq!(title_similarity("", "Ideas"), 0.0);
+    }
+
+    #[test]
+    fn test_title_duplicates() {
+        let existing = ["Ideas de proyecto", "Recetas", "archivo/Ideas del proyecto"];
+        let candidates = title_duplicates("ideas de proyecto", existing);
+        assert_eq!(candidates.len(), 2);
+        assert_eq!(candidates[0].name, "Ideas de proyecto");
+        assert_eq!(candidates[0].score, 1.0);
+        assert_eq!(candidates[1].name, "archivo/Ideas del proyecto");
+        assert!(
+            candidates
+                .iter()
+                .all(|c| c.reason == DuplicateReason::Title)
+        );
+    }
+
+    #[test]
+    fn test_content_duplicates() {
+        let results = vec![
+            (0.95, "Plan#0".to_string()),
+            (0.97, "Plan#3".to_string()),
+            (0.92, "Notas#1".to_string()),
+            (0.5, "Otra".to_string()),
+        ];
+        let candidates = content_duplicates(results);
+        assert_eq!(candidates.len(), 2);
+        assert_eq!(candidates[0].name, "Plan");
+        assert_eq!(candidates[0].score, 0.97);
+        assert_eq!(candidates[1].name, "Notas");
+
+        // Las que ya salen por título no se repiten
+        let merged = merge_candidates(title_duplicates("plan", ["Plan"]), candidates);
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[0].reason, DuplicateReason::Title);
+    }
+
+    #[test]
+    fn test_content_title() {
+        assert_eq!(
+            content_title("# Plan de viaje\n\nTexto").as_deref(),
+            Some("Plan de viaje")
+        );
+        assert_eq!(
+            content_title("---\ntags: [viaje]\n---\n\n# Plan\n").as_deref(),
+            Some("Plan")
+        );
+        assert_eq!(content_title("Texto sin título\n# Más tarde"), None);
+    }
+}
@@ -0,0 +1,321 @@
+//! Edición modal sin widgets: lo que cada [`EditorAction`] de edición hace con el
+//! buffer y el cursor
+//!
+//! La app GTK resuelve aquí las acciones de texto y de cursor después de
+//! sincronizar el cursor con el `TextView` (y de borrar la selección, si la hay);
+//! el modo headless las aplica directamente, así que los tests de integración
+//! recorren el mismo código que el editor.
+
+use crate::command::EditorAction;
+use crate::note_buffer::NoteBuffer;
+
+/// Qué hizo [`apply_edit`] con la acción
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditOutcome {
+    /// Cambió el texto (hay cambios sin guardar)
+    Modified,
+    /// Solo se movió el cursor, o no había nada que hacer
+    Unchanged,
+    /// No es una acción de edición; la resuelve quien llama
+    Ignored,
+}
+
+/// Aplica una acción de edición o de movimiento a `buffer`. `cursor` es la
+/// posición en caracteres y queda siempre dentro del texto.
+pub fn apply_edit(
+    buffer: &mut NoteBuffer,
+    cursor: &mut usize,
+    action: &EditorAction,
+) -> EditOutcome {
+    *cursor = (*cursor).min(buffer.len_chars());
+
+    match action {
+        EditorAction::InsertChar(ch) => {
+            buffer.insert(*cursor, &ch.to_string());
+            *cursor += 1;
+            EditOutcome::Modified
+        }
+        EditorAction::InsertNewline => {
+            insert_newline(buffer, cursor);
+            EditOutcome::Modified
+        }
+        EditorAction::DeleteCharBefore if *cursor > 0 => {
+            buffer.delete(*cursor - 1..*cursor);
+            *cursor -= 1;
+            EditOutcome::Modified
+        }
+        EditorAction::DeleteCharAfter if *cursor < buffer.len_chars() => {
+            buffer.delete(*cursor..*cursor + 1);
+            EditOutcome::Modified
+        }
+        EditorAction::DeleteCharBefore | EditorAction::DeleteCharAfter => EditOutcome::Unchanged,
+        EditorAction::DeleteLine => {
+            let line = buffer.rope().char_to_line(*cursor);
+            let start = buffer.rope().line_to_char(line);
+            let end = if line + 1 < buffer.len_lines() {
+                buffer.rope().line_to_char(line + 1)
+            } else {
+                buffer.len_chars()
+            };
+            if start == end {
+                return EditOutcome::Unchanged;
+            }
+            buffer.delete(start..end);
+            *cursor = start.min(buffer.len_chars());
+            EditOutcome::Modified
+        }
+
+        EditorAction::MoveCursorLeft => {
+            *cursor = cursor.saturating_sub(1);
+            EditOutcome::Unchanged
+        }
+        EditorAction::MoveCursorRight => {
+            *cursor = (*cursor + 1).min(buffer.len_chars());
+            EditOutcome::Unchanged
+        }
+        EditorAction::MoveCursorUp => {
+            let line = buffer.rope().char_to_line(*cursor);
+            if line > 0 {
+                *cursor = same_column(buffer, *cursor, line, line - 1);
+            }
+            EditOutcome::Unchanged
+        }
+        EditorAction::MoveCursorDown => {
+            let line = buffer.rope().char_to_line(*cursor);
+            if line + 1 < buffer.len_lines() {
+                *cursor = same_column(buffer, *cursor, line, line + 1);
+            }
+            EditOutcome::Unchanged
+        }
+        EditorAction::MoveCursorLineStart => {
+            let line = buffer.rope().char_to_line(*cursor);
+            *cursor = buffer.rope().line_to_char(line);
+            EditOutcome::Unchanged
+        }
+        EditorAction::MoveCursorLineEnd => {
+            let line = buffer.rope().char_to_line(*cursor);
+            *cursor = line_end(buffer, line);
+            EditOutcome::Unchanged
+        }
+        EditorAction::MoveCursorDocStart => {
+            *cursor = 0;
+            EditOutcome::Unchanged
+        }
+        EditorAction::MoveCursorDocEnd => {
+            *cursor = buffer.len_chars();
+            EditOutcome::Unchanged
+        }
+
+        EditorAction::Undo | EditorAction::Redo => {
+            let changed = if *action == EditorAction::Undo {
+                buffer.undo()
+            } else {
+                buffer.redo()
+            };
+            *cursor = (*cursor).min(buffer.len_chars());
+            if changed {
+                EditOutcome::Modified
+            } else {
+                EditOutcome::Unchanged
+            }
+        }
+
+        _ => EditOutcome::Ignored,
+    }
+}
+
+/// Salto de línea que continúa la lista de la línea actual; en un elemento vacío
+/// la lista termina y se quita el prefijo
+fn insert_newline(buffer: &mut NoteBuffer, cursor: &mut usize) {
+    let line_idx = buffer.rope().char_to_line(*cursor);
+    let current_line = buffer.line(line_idx).unwrap_or_default();
+
+    match list_prefix(current_line.trim_end_matches('\n')) {
+        Some((_, true)) => {
+            // La línea solo tiene el prefijo, eliminarla y terminar la lista
+            let line_start = buffer.rope().line_to_char(line_idx);
+            let line_end = line_start + current_line.chars().count();
+            buffer.delete(line_start..line_end);
+            *cursor = line_start;
+        }
+        Some((prefix, false)) => {
+            let new_content = format!("\n{}", prefix);
+            buffer.insert(*cursor, &new_content);
+            *cursor += new_content.chars().count();
+        }
+        None => {
+            buffer.insert(*cursor, "\n");
+            *cursor += 1;
+        }
+    }
+}
+
+/// Fin de la línea `line`, sin el salto de línea
+fn line_end(buffer: &NoteBuffer, line: usize) -> usize {
+    if line + 1 < buffer.len_lines() {
+        buffer.rope().line_to_char(line + 1).saturating_sub(1)
+    } else {
+        buffer.len_chars()
+    }
+}
+
+/// Posición en `target` con la columna de `cursor` en `line`, sin pasar del final
+fn same_column(buffer: &NoteBuffer, cursor: usize, line: usize, target: usize) -> usize {
+    let column = cursor - buffer.rope().line_to_char(line);
+    let start = buffer.rope().line_to_char(target);
+    start + column.min(line_end(buffer, target) - start)
+}
+
+/// Detecta si una línea tiene un prefijo de lista markdown y retorna el prefijo a usar
+/// para la siguiente línea. También indica si la línea solo contiene el prefijo (está vacía).
+///
+/// Soporta:
+/// - Listas con guión: `- item` o `- ` (vacío)
+/// - Listas con asterisco: `* item` o `* ` (vacío)
+/// - Listas con checkbox vacío: `- [ ] item` o `- [ ] ` (vacío)
+/// - Listas con checkbox marcado: `- [x] item` o `- [x] ` (vacío)
+/// - Listas numeradas: `1. item`, `2. item`, etc.
+///
+/// Retorna: Some((prefijo_para_nueva_línea, es_item_vacío))
+pub fn list_prefix(line: &str) -> Option<(String, bool)> {
+    // Capturar la indentación inicial (espacios/tabs)
+    let indent: String = line.chars().take_while(|c| c.is_whitespace()).collect();
+    let content = line.trim_start();
+
+    // Checkbox vacío: - [ ] o * [ ]
+    if content.starts_with("- [ ] ") || content.starts_with("* [ ] ") {
+        let rest = &content[6..]; // Después de "- [ ] "
+        let is_empty = rest.trim().is_empty();
+        return Some((format!("{}- [ ] ", indent), is_empty));
+    }
+    if content == "- [ ]" || content == "* [ ]" {
+        return Some((format!("{}- [ ] ", indent), true));
+    }
+
+    // Checkbox marcado: - [x] o - [X] o * [x] o * [X]
+    if content.starts_with("- [x] ")
+        || content.starts_with("- [X] ")
+        || content.starts_with("* [x] ")
+        || content.starts_with("* [X] ")
+    {
+        let rest = &content[6..];
+        let is_empty = rest.trim().is_empty();
+        // La siguiente línea debería ser checkbox vacío
+        return Some((format!("{}- [ ] ", indent), is_empty));
+    }
+    if content == "- [x]" || content == "- [X]" || content == "* [x]" || content == "* [X]" {
+        return Some((format!("{}- [ ] ", indent), true));
+    }
+
+    // Lista numerada: 1. , 2. , etc.
+    if let Some(dot_pos) = content.find(". ") {
+        let num_part = &content[..dot_pos];
+        if let Ok(num) = num_part.parse::<u32>() {
+            let rest = &content[dot_pos + 2..];
+            let is_empty = rest.trim().is_empty();
+            return Some((format!("{}{}. ", indent, num + 1), is_empty));
+        }
+    }
+    // Lista numerada sin contenido: "1."
+    if content.ends_with('.') && content.len() > 1 {
+        let num_part = &content[..content.len() - 1];
+        if let Ok(num) = num_part.parse::<u32>() {
+            return Some((format!("{}{}. ", indent, num + 1), true));
+        }
+    }
+
+    // Lista con guión: - item
+    if content.starts_with("- ") {
+        let rest = &content[2..];
+        let is_empty = rest.trim().is_empty();
+        return Some((format!("{}- ", indent), is_empty));
+    }
+    if content == "-" {
+        return Some((format!("{}- ", indent), true));
+    }
+
+    // Lista con asterisco: * item
+    if content.starts_with("* ") {
+        let rest = &content[2..];
+        let is_empty = rest.trim().is_empty();
+        return Some((format!("{}* ", indent), is_empty));
+    }
+    if content == "*" {
+        return Some((format!("{}* ", indent), true));
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply(text: &str, cursor: usize, actions: &[EditorAction]) -> (String, usize) {
+        let mut buffer = NoteBuffer::from_text(text);
+        let mut cursor = cursor;
+        for action in actions {
+            apply_edit(&mut buffer, &mut cursor, action);
+        }
+        (buffer.to_string(), cursor)
+    }
+
+    #[test]
+    fn test_newline_continues_and_ends_lists() {
+        assert_eq!(
+            apply("- uno", 5, &[EditorAction::InsertNewline]),
+            ("- uno\n- ".to_string(), 8)
+        );
+        assert_eq!(
+            apply("1. uno", 6, &[EditorAction::InsertNewline]),
+            ("1. uno\n2. ".to_string(), 10)
+        );
+        // Elemento vacío: se quita el prefijo
+        assert_eq!(
+            apply("- uno\n- ", 8, &[EditorAction::InsertNewline]),
+            ("- uno\n".to_string(), 6)
+        );
+        assert_eq!(
+            apply("texto", 5, &[EditorAction::InsertNewline]),
+            ("texto\n".to_string(), 6)
+        );
+    }
+
+    #[test]
+    fn test_vertical_moves_keep_column() {
+        let text = "larga línea\ncorta\notra línea";
+        let (_, cursor) = apply(text, 8, &[EditorAction::MoveCursorDown]);
+        assert_eq!(cursor, 17); // final de "corta"
+        let (_, cursor) = apply(text, 14, &[EditorAction::MoveCursorUp]);
+        assert_eq!(cursor, 2);
+        let (_, cursor) = apply(text, 2, &[EditorAction::MoveCursorUp]);
+        assert_eq!(cursor, 2);
+        let (_, cursor) = apply(text, 14, &[EditorAction::MoveCursorLineEnd]);
+        assert_eq!(cursor, 17);
+    }
+
+    #[test]
+    fn test_delete_line_and_undo() {
+        assert_eq!(
+            apply("uno\ndos\ntres", 5, &[EditorAction::DeleteLine]),
+            ("uno\ntres".to_string(), 4)
+        );
+        assert_eq!(
+            apply("uno\ndos", 5, &[EditorAction::DeleteLine]),
+            ("uno\n".to_string(), 4)
+        );
+
+        let mut buffer = NoteBuffer::from_text("hola");
+        let mut cursor = 4;
+        apply_edit(&mut buffer, &mut cursor, &EditorAction::InsertChar('!'));
+        assert_eq!(
+            apply_edit(&mut buffer, &mut cursor, &EditorAction::Undo),
+            EditOutcome::Modified
+        );
+        assert_eq!((buffer.to_string(), cursor), ("hola".to_string(), 4));
+        assert_eq!(
+            apply_edit(&mut buffer, &mut cursor, &EditorAction::Save),
+            EditOutcome::Ignored
+        );
+    }
+}
@@ -12,7 +12,8 @@ pub struct EmbeddingConfig {
     /// Modelo a usar (ej: "qwen/qwen3-embedding-8b")
     pub model: String,
 
-    /// API key para el proveedor (si es necesario)
+    /// API key para el proveedor (si es necesario; se guarda en el llavero)
+    #[serde(default, skip_serializing)]
     pub api_key: Option<String>,
 
     /// URL base de la API
@@ -168,6 +169,10 @@ mod tests {
     use super::*;
 
     #[test]
+    #[allow(
+        clippy::bool_assert_comparison,
+        reason = "se compara con el valor por defecto, igual que el resto de campos"
+    )]
     fn test_default_config() {
         let config = EmbeddingConfig::default();
         assert_eq!(config.enabled, true); // Habilitado por defecto
@@ -0,0 +1,294 @@
+//! Tarjetas de estudio y repaso espaciado (SM-2)
+//!
+//! Las tarjetas salen del propio texto de las notas:
+//!
+//! - `Q:: pregunta` seguida de `A:: respuesta` (en la misma línea o en la
+//!   siguiente). La respuesta sigue en las líneas de debajo hasta una línea
+//!   en blanco, otro `Q::` o un encabezado.
+//! - Un encabezado que termina en `?`: la pregunta es el encabezado y la
+//!   respuesta su sección, hasta el siguiente encabezado del mismo nivel o
+//!   superior.
+//!
+//! El calendario de repaso sigue SM-2: cada respuesta se califica y de ahí
+//! salen la facilidad de la tarjeta y los días hasta el próximo repaso.
+
+use crate::frontmatter::frontmatter_lines;
+use crate::merge::heading_level;
+
+/// Facilidad con la que empieza una tarjeta nueva
+pub const INITIAL_EASE: f64 = 2.5;
+
+/// Facilidad mínima; por debajo las tarjetas se repetirían sin fin
+pub const MIN_EASE: f64 = 1.3;
+
+const QUESTION_MARKER: &str = "Q::";
+const ANSWER_MARKER: &str = "A::";
+
+/// Pregunta y respuesta sacadas de una nota
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Flashcard {
+    pub question: String,
+    pub answer: String,
+}
+
+/// Calificación de una respuesta en el repaso
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReviewGrade {
+    /// No se sabía: vuelve a empezar
+    Again,
+    Hard,
+    Good,
+    Easy,
+}
+
+impl ReviewGrade {
+    pub const ALL: [ReviewGrade; 4] = [
+        ReviewGrade::Again,
+        ReviewGrade::Hard,
+        ReviewGrade::Good,
+        ReviewGrade::Easy,
+    ];
+
+    /// Calidad de la respuesta en la escala 0-5 de SM-2
+    fn quality(self) -> f64 {
+        match self {
+            ReviewGrade::Again => 1.0,
+            ReviewGrade::Hard => 3.0,
+            ReviewGrade::Good => 4.0,
+            ReviewGrade::Easy => 5.0,
+        }
+    }
+}
+
+/// Estado de repaso de una tarjeta
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sm2State {
+    pub ease: f64,
+    /// Días hasta el próximo repaso
+    pub interval_days: u32,
+    /// Repasos seguidos acertados
+    pub repetitions: u32,
+}
+
+impl Default for Sm2State {
+    fn default() -> Self {
+        Self {
+            ease: INITIAL_EASE,
+            interval_days: 0,
+            repetitions: 0,
+        }
+    }
+}
+
+impl Sm2State {
+    /// Estado tras responder con `grade`
+    pub fn review(self, grade: ReviewGrade) -> Self {
+        let quality = grade.quality();
+        let ease =
+            (self.ease + 0.1 - (5.0 - quality) * (0.08 + (5.0 - quality) * 0.02)).max(MIN_EASE);
+
+        if grade == ReviewGrade::Again {
+            return Self {
+                ease,
+                interval_days: 1,
+                repetitions: 0,
+            };
+        }
+
+        let interval_days = match self.repetitions {
+            0 => 1,
+            1 => 6,
+            _ => (self.interval_days as f64 * self.ease).round() as u32,
+        };
+        Self {
+            ease,
+            interval_days,
+            repetitions: self.repetitions + 1,
+        }
+    }
+}
+
+/// Tarjetas de una nota, en orden. No cuentan el frontmatter ni los bloques
+/// de código.
+pub fn extract_flashcards(content: &str) -> Vec<Flashcard> {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut cards: Vec<Flashcard> = Vec::new();
+    let mut in_code_block = false;
+    // Tarjeta `Q::` a medias: pregunta y, si ya empezó, respuesta
+    let mut pending: Option<(String, Option<String>)> = None;
+    // Tarjeta de encabezado abierta: nivel, pregunta y líneas de respuesta
+    let mut heading_card: Option<(usize, String, Vec<&str>)> = None;
+
+    for line in lines.iter().skip(frontmatter_lines(content)) {
+        let trimmed = line.trim();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_code_block = !in_code_block;
+        }
+        if in_code_block || trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            if let Some((_, _, answer)) = heading_card.as_mut() {
+                answer.push(line);
+            }
+            continue;
+        }
+
+        if let Some(level) = heading_level(line) {
+            finish_pending(&mut pending, &mut cards);
+            if let Some((open_level, question, answer)) = heading_card.take() {
+                if level > open_level {
+                    heading_card = Some((open_level, question, answer));
+                } else {
+                    push_card(&mut cards, &question, &answer.join("\n"));
+                }
+            }
+            if heading_card.is_none() {
+                let title = line[level..].trim();
+                if title.ends_with('?') {
+                    heading_card = Some((level, title.to_string(), Vec::new()));
+                }
+            }
+            if let Some((open_level, _, answer)) = heading_card.as_mut() {
+                if *open_level != level {
+                    answer.push(line);
+                }
+            }
+            continue;
+        }
+
+        if let Some((_, _, answer)) = heading_card.as_mut() {
+            answer.push(line);
+        }
+
+        if let Some(rest) = strip_marker(trimmed, QUESTION_MARKER) {
+            finish_pending(&mut pending, &mut cards);
+            pending = Some(match rest.split_once(ANSWER_MARKER) {
+                Some((question, answer)) => {
+                    (question.trim().to_string(), Some(answer.trim().to_string()))
+                }
+                None => (rest.trim().to_string(), None),
+            });
+        } else if let Some(rest) = strip_marker(trimmed, ANSWER_MARKER) {
+            if let Some((_, answer)) = pending.as_mut() {
+                if answer.is_none() {
+                    *answer = Some(rest.trim().to_string());
+                }
+            }
+        } else if trimmed.is_empty() {
+            finish_pending(&mut pending, &mut cards);
+        } else if let Some((question, answer)) = pending.as_mut() {
+            // Continuación de la respuesta o, si aún no hay, de la pregunta
+            let text = answer.as_mut().unwrap_or(question);
+            if !text.is_empty() {
+                text.push('\n');
+            }
+            text.push_str(trimmed);
+        }
+    }
+
+    finish_pending(&mut pending, &mut cards);
+    if let Some((_, question, answer)) = heading_card {
+        push_card(&mut cards, &question, &answer.join("\n"));
+    }
+    cards
+}
+
+/// Cierra la tarjeta `Q::` a medias (se descarta si no llegó a tener respuesta)
+fn finish_pending(pending: &mut Option<(String, Option<String>)>, cards: &mut Vec<Flashcard>) {
+    if let Some((question, Some(answer))) = pending.take() {
+        push_card(cards, &question, &answer);
+    }
+}
+
+/// Texto tras el marcador (`Q::`, `A::`), también dentro de una lista
+fn strip_marker<'a>(line: &'a str, marker: &str) -> Option<&'a str> {
+    let line = line
+        .strip_prefix("- ")
+        .or_else(|| line.strip_prefix("* "))
+        .unwrap_or(line);
+    line.strip_prefix(marker)
+}
+
+/// Añade la tarjeta si tiene pregunta y respuesta y la pregunta no estaba ya
+fn push_card(cards: &mut Vec<Flashcard>, question: &str, answer: &str) {
+    let question = question.trim();
+    let answer = answer.trim();
+    if question.is_empty()
+        || answer.is_empty()
+        || cards.iter().any(|card| card.question == question)
+    {
+        return;
+    }
+    cards.push(Flashcard {
+        question: question.to_string(),
+        answer: answer.to_string(),
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_flashcards() {
+        let content = "---\ntags: [estudio]\n---\n# Biología\n\n\
+                       Q:: ¿Qué produce la mitocondria?\n\
+                       A:: ATP\n\
+                       mediante respiración celular\n\n\
+                       - Q:: Capital de Francia A:: París\n\n\
+                       Q:: Sin respuesta\n\n\
+                       ## ¿Qué es la fotosíntesis?\n\n\
+                       Convertir luz en energía química.\n\n\
+                       ### Detalle\n\nOcurre en los cloroplastos.\n\n\
+                       ```\nQ:: no cuenta A:: código\n```\n\n\
+                       ## Notas\n\nNada más.\n";
+
+        let cards = extract_flashcards(content);
+        assert_eq!(
+            cards,
+            vec![
+                Flashcard {
+                    question: "¿Qué produce la mitocondria?".into(),
+                    answer: "ATP\nmediante respiración celular".into(),
+                },
+                Flashcard {
+                    question: "Capital de Francia".into(),
+                    answer: "París".into(),
+                },
+                Flashcard {
+                    question: "¿Qué es la fotosíntesis?".into(),
+                    answer: "Convertir luz en energía química.\n\n### Detalle\n\n\
+                             Ocurre en los cloroplastos.\n\n```\nQ:: no cuenta A:: código\n```"
+                        .into(),
+                },
+            ]
+        );
+        assert!(extract_flashcards("# Título\n\nSin tarjetas.").is_empty());
+    }
+
+    #[test]
+    fn test_sm2_review() {
+        let new = Sm2State::default();
+
+        let first = new.review(ReviewGrade::Good);
+        assert_eq!((first.interval_days, first.repetitions), (1, 1));
+        assert!((first.ease - INITIAL_EASE).abs() < 1e-9);
+
+        let second = first.review(ReviewGrade::Good);
+        assert_eq!(second.interval_days, 6);
+
+        let third = second.review(ReviewGrade::Easy);
+        assert_eq!(third.interval_days, 15);
+        assert!(third.ease > second.ease);
+
+        // Fallar reinicia la racha y baja la facilidad
+        let failed = third.review(ReviewGrade::Again);
+        assert_eq!((failed.interval_days, failed.repetitions), (1, 0));
+        assert!(failed.ease < third.ease);
+
+        // La facilidad nunca baja del mínimo
+        let mut state = new;
+        for _ in 0..20 {
+            state = state.review(ReviewGrade::Again);
+        }
+        assert_eq!(state.ease, MIN_EASE);
+    }
+}
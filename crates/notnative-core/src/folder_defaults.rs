@@ -0,0 +1,350 @@
+//! Valores por defecto de las notas nuevas de una carpeta
+//!
+//! Un `.notnative.toml` dentro de cualquier carpeta (también en la raíz)
+//! define cómo empiezan las notas que se crean allí o en sus subcarpetas;
+//! vale el de la carpeta más cercana. La plantilla es un archivo relativo a
+//! la carpeta del `.notnative.toml` y, como en las plantillas de carpeta,
+//! `{{name}}` y `{{date}}` se cambian por el nombre de la nota y la fecha.
+//! `ai_system` son instrucciones para el asistente ([`crate::ai_persona`]).
+//!
+//! ```toml
+//! template = "plantillas/reunion.md"
+//! tags = ["reunión", "trabajo"]
+//! ai_system = "Resume en viñetas y destaca las decisiones."
+//!
+//! [frontmatter]
+//! status = "borrador"
+//! created = "{{date}}"
+//! ```
+
+use anyhow::{Context, Result, bail};
+use chrono::NaiveDate;
+use serde_yaml::{Mapping, Value};
+use std::path::{Path, PathBuf};
+use toml_edit::{DocumentMut, Item};
+use tracing::warn;
+
+use crate::folder_template::fill_placeholders;
+
+/// Archivo de valores por defecto dentro de cada carpeta
+pub const FOLDER_DEFAULTS_FILE: &str = ".notnative.toml";
+
+/// Contenido de `.notnative.toml`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FolderDefaults {
+    /// Plantilla de las notas nuevas, relativa a `source_dir`
+    pub template: Option<String>,
+    /// Tags iniciales
+    pub tags: Vec<String>,
+    /// Campos que se añaden al frontmatter, en orden
+    pub frontmatter: Mapping,
+    /// Instrucciones para el asistente con las notas de la carpeta
+    pub ai_system: Option<String>,
+    /// Carpeta del `.notnative.toml`
+    pub source_dir: PathBuf,
+}
+
+impl FolderDefaults {
+    pub fn from_toml(text: &str) -> Result<Self> {
+        let document: DocumentMut = text.parse()?;
+        let string = |key: &str| -> Result<Option<String>> {
+            match document.get(key) {
+                None => Ok(None),
+                Some(item) => match item.as_str() {
+                    Some(text) => Ok(Some(text.to_string())),
+                    None => bail!("`{}` debe ser un texto", key),
+                },
+            }
+        };
+
+        let tags = match document.get("tags") {
+            None => Vec::new(),
+            Some(item) => match item.as_array() {
+                Some(array) => array
+                    .iter()
+                    .map(|tag| {
+                        tag.as_str()
+                            .map(|tag| tag.trim_start_matches('#').to_string())
+                    })
+                    .collect::<Option<Vec<_>>>()
+                    .context("`tags` debe ser una lista de textos")?,
+                None => bail!("`tags` debe ser una lista de textos"),
+            },
+        };
+
+        let frontmatter = match document.get("frontmatter") {
+            None => Mapping::new(),
+            Some(item) => match yaml_item(item) {
+                Some(Value::Mapping(fields)) => fields,
+                _ => bail!("`frontmatter` debe ser una tabla"),
+            },
+        };
+
+        Ok(Self {
+            template: string("template")?,
+            tags,
+            frontmatter,
+            ai_system: string("ai_system")?
+                .map(|text| text.trim().to_string())
+                .filter(|text| !text.is_empty()),
+            source_dir: PathBuf::new(),
+        })
+    }
+
+    /// Lee el `.notnative.toml` de `dir`; `None` si no hay o no es válido
+    pub fn load(dir: &Path) -> Option<Self> {
+        let path = dir.join(FOLDER_DEFAULTS_FILE);
+        let text = std::fs::read_to_string(&path).ok()?;
+        match Self::from_toml(&text) {
+            Ok(defaults) => Some(Self {
+                source_dir: dir.to_path_buf(),
+                ..defaults
+            }),
+            Err(e) => {
+                warn!("{:?} no es válido: {}", path, e);
+                None
+            }
+        }
+    }
+
+    /// Los de la carpeta `folder` (`"a/b"`, relativa a `notes_root`) o los de
+    /// la más cercana de sus superiores, hasta la raíz
+    pub fn nearest(notes_root: &Path, folder: &str) -> Option<Self> {
+        let mut path = folder.trim_matches('/');
+        loop {
+            if let Some(defaults) = Self::load(&notes_root.join(path)) {
+                return Some(defaults);
+            }
+            if path.is_empty() {
+                return None;
+            }
+            path = path.rsplit_once('/').map_or("", |(parent, _)| parent);
+        }
+    }
+
+    /// Texto de la nota nueva `name`: `content` o, si viene vacío, la
+    /// plantilla (o un título), con los tags y campos de la carpeta añadidos
+    /// al frontmatter. Lo que ya traiga el frontmatter se respeta.
+    pub fn apply(&self, name: &str, content: &str, date: NaiveDate) -> Result<String> {
+        let text = if !content.trim().is_empty() {
+            content.to_string()
+        } else if let Some(template) = &self.template {
+            let path = self.source_dir.join(template);
+            let raw = std::fs::read_to_string(&path)
+                .with_context(|| format!("No se pudo leer la plantilla {:?}", path))?;
+            fill_placeholders(&raw, name, date)
+        } else {
+            format!("# {}\n\n", name)
+        };
+        if self.tags.is_empty() && self.frontmatter.is_empty() {
+            return Ok(text);
+        }
+        // Con un frontmatter que no se entiende, mejor no tocar nada
+        let Some((existing, body)) = split_frontmatter(&text) else {
+            return Ok(text);
+        };
+
+        let mut fields = Mapping::new();
+        if !self.tags.is_empty() {
+            let mut tags = match existing.get("tags") {
+                Some(Value::Sequence(tags)) => tags.clone(),
+                _ => Vec::new(),
+            };
+            for tag in &self.tags {
+                let tag = Value::String(tag.clone());
+                if !tags.contains(&tag) {
+                    tags.push(tag);
+                }
+            }
+            fields.insert("tags".into(), Value::Sequence(tags));
+        }
+        for (key, value) in existing {
+            if !fields.contains_key(&key) {
+                fields.insert(key, value);
+            }
+        }
+        for (key, value) in &self.frontmatter {
+            if !fields.contains_key(key) {
+                fields.insert(key.clone(), fill_value(value, name, date));
+            }
+        }
+
+        Ok(format!(
+            "---\n{}---\n\n{}",
+            serde_yaml::to_string(&fields)?,
+            body
+        ))
+    }
+}
+
+/// Campos del frontmatter de `text` y el resto del texto. Sin frontmatter,
+/// campos vacíos; `None` si lo tiene pero no es YAML válido.
+fn split_frontmatter(text: &str) -> Option<(Mapping, &str)> {
+    let Some(rest) = text.strip_prefix("---\n") else {
+        return Some((Mapping::new(), text));
+    };
+    let end = rest.find("\n---")?;
+    let fields = match serde_yaml::from_str(&rest[..end]).ok()? {
+        Value::Mapping(fields) => fields,
+        Value::Null => Mapping::new(),
+        _ => return None,
+    };
+    let body = rest[end + 4..].trim_start_matches(['\r', '\n']);
+    Some((fields, body))
+}
+
+/// Valor de TOML como valor de YAML (las fechas, como texto)
+fn yaml_item(item: &Item) -> Option<Value> {
+    match item {
+        Item::None => None,
+        Item::Value(value) => Some(yaml_value(value)),
+        Item::Table(table) => Some(Value::Mapping(
+            table
+                .iter()
+                .filter_map(|(key, item)| Some((Value::from(key), yaml_item(item)?)))
+                .collect(),
+        )),
+        Item::ArrayOfTables(tables) => Some(Value::Sequence(
+            tables
+                .iter()
+                .map(|table| yaml_item(&Item::Table(table.clone())).unwrap_or_default())
+                .collect(),
+        )),
+    }
+}
+
+fn yaml_value(value: &toml_edit::Value) -> Value {
+    use toml_edit::Value as Toml;
+    match value {
+        Toml::String(text) => Value::String(text.value().clone()),
+        Toml::Integer(number) => Value::from(*number.value()),
+        Toml::Float(number) => Value::from(*number.value()),
+        Toml::Boolean(flag) => Value::Bool(*flag.value()),
+        Toml::Datetime(date) => Value::String(date.value().to_string()),
+        Toml::Array(array) => Value::Sequence(array.iter().map(yaml_value).collect()),
+        Toml::InlineTable(table) => Value::Mapping(
+            table
+                .iter()
+                .map(|(key, value)| (Value::from(key), yaml_value(value)))
+                .collect(),
+        ),
+    }
+}
+
+/// Cambia `{{name}}` y `{{date}}` en los textos de un valor
+fn fill_value(value: &Value, name: &str, date: NaiveDate) -> Value {
+    match value {
+        Value::String(text) => Value::String(fill_placeholders(text, name, date)),
+        Value::Sequence(items) => Value::Sequence(
+            items
+                .iter()
+                .map(|item| fill_value(item, name, date))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, 9).unwrap()
+    }
+
+    #[test]
+    fn test_parse_defaults() {
+        let defaults = FolderDefaults::from_toml(
+            "template = \"plantilla.md\"\ntags = [\"#reunión\", \"trabajo\"]\nai_system = \" Sé breve. \"\n\n[frontmatter]\nstatus = \"borrador\"\npriority = 2\nfecha = 2026-03-09\n",
+        )
+        .unwrap();
+        assert_eq!(defaults.template.as_deref(), Some("plantilla.md"));
+        assert_eq!(defaults.tags, vec!["reunión", "trabajo"]);
+        assert_eq!(defaults.ai_system.as_deref(), Some("Sé breve."));
+        let keys: Vec<&str> = defaults
+            .frontmatter
+            .keys()
+            .filter_map(Value::as_str)
+            .collect();
+        assert_eq!(keys, vec!["status", "priority", "fecha"]);
+        assert_eq!(defaults.frontmatter["priority"], Value::from(2));
+        assert_eq!(defaults.frontmatter["fecha"], Value::from("2026-03-09"));
+
+        assert!(FolderDefaults::from_toml("tags = \"uno\"").is_err());
+        assert!(FolderDefaults::from_toml("frontmatter = 3").is_err());
+        assert!(FolderDefaults::from_toml("tags = [").is_err());
+    }
+
+    #[test]
+    fn test_apply_defaults() {
+        let defaults = FolderDefaults {
+            tags: vec!["trabajo".to_string()],
+            frontmatter: FolderDefaults::from_toml(
+                "[frontmatter]\nstatus = \"borrador\"\ncreated = \"{{date}}\"",
+            )
+            .unwrap()
+            .frontmatter,
+            ..Default::default()
+        };
+        assert_eq!(
+            defaults.apply("Plan", "", date()).unwrap(),
+            "---\ntags:\n- trabajo\nstatus: borrador\ncreated: 2026-03-09\n---\n\n# Plan\n\n"
+        );
+        // Lo que ya trae la nota se respeta
+        assert_eq!(
+            defaults
+                .apply(
+                    "Plan",
+                    "---\nstatus: hecho\ntags: [idea]\n---\nTexto",
+                    date()
+                )
+                .unwrap(),
+            "---\ntags:\n- idea\n- trabajo\nstatus: hecho\ncreated: 2026-03-09\n---\n\nTexto"
+        );
+        assert_eq!(
+            defaults.apply("Plan", "---\n: [\n---\nx", date()).unwrap(),
+            "---\n: [\n---\nx"
+        );
+        assert_eq!(
+            FolderDefaults::default().apply("Plan", "", date()).unwrap(),
+            "# Plan\n\n"
+        );
+    }
+
+    #[test]
+    fn test_nearest_defaults_with_template() {
+        let root = std::env::temp_dir().join(format!(
+            "notnative_test_folder_defaults_{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("Trabajo/Reuniones/2026")).unwrap();
+        std::fs::create_dir_all(root.join("Personal")).unwrap();
+        std::fs::write(root.join(FOLDER_DEFAULTS_FILE), "tags = [\"inbox\"]").unwrap();
+        std::fs::write(
+            root.join("Trabajo/Reuniones").join(FOLDER_DEFAULTS_FILE),
+            "template = \"acta.md\"\ntags = [\"reunión\"]",
+        )
+        .unwrap();
+        std::fs::write(root.join("Trabajo/Reuniones/acta.md"), "# Acta: {{name}}\n").unwrap();
+
+        let defaults = FolderDefaults::nearest(&root, "Trabajo/Reuniones/2026").unwrap();
+        assert_eq!(
+            defaults.apply("Lunes", "", date()).unwrap(),
+            "---\ntags:\n- reunión\n---\n\n# Acta: Lunes\n"
+        );
+        let defaults = FolderDefaults::nearest(&root, "Personal").unwrap();
+        assert_eq!(defaults.tags, vec!["inbox"]);
+
+        // Una plantilla que falta es un error, no una nota vacía
+        std::fs::remove_file(root.join("Trabajo/Reuniones/acta.md")).unwrap();
+        let defaults = FolderDefaults::nearest(&root, "Trabajo/Reuniones").unwrap();
+        assert!(defaults.apply("Lunes", "", date()).is_err());
+
+        std::fs::remove_file(root.join(FOLDER_DEFAULTS_FILE)).unwrap();
+        assert!(FolderDefaults::nearest(&root, "Personal").is_none());
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
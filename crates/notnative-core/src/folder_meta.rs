@@ -0,0 +1,178 @@
+//! Metadatos de carpeta: color, descripción e icono de sus notas
+//!
+//! Se guardan en un `.folder.yaml` dentro de la propia carpeta, así que viajan
+//! con ella al moverla, renombrarla o sincronizar el directorio de notas. El
+//! color se muestra como una muestra junto a la carpeta en el sidebar y la
+//! descripción como tooltip. Las notas (y subcarpetas) sin icono o color
+//! propio heredan los de la carpeta más cercana que los defina. También puede
+//! llevar instrucciones para el asistente ([`crate::ai_persona`]) o dejar
+//! sus notas fuera de la IA ([`crate::ai_exclusion`]).
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Archivo de metadatos dentro de cada carpeta
+pub const FOLDER_META_FILE: &str = ".folder.yaml";
+
+/// Contenido de `.folder.yaml`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FolderMeta {
+    /// Color de la carpeta (`#rrggbb`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// Descripción corta (tooltip de la carpeta en el sidebar)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Icono por defecto de las notas de dentro (nombre de icono o emoji)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note_icon: Option<String>,
+    /// Instrucciones para el asistente al trabajar con notas de la carpeta
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ai_system: Option<String>,
+    /// Si lo de dentro hereda el color y el icono. Con `false` la carpeta corta
+    /// también la herencia de las carpetas superiores.
+    #[serde(default = "default_inherit")]
+    pub inherit: bool,
+    /// Si la IA puede usar las notas de dentro (y de las subcarpetas)
+    #[serde(default = "default_ai", skip_serializing_if = "is_true")]
+    pub ai: bool,
+}
+
+fn default_inherit() -> bool {
+    true
+}
+
+fn default_ai() -> bool {
+    true
+}
+
+fn is_true(value: &bool) -> bool {
+    *value
+}
+
+impl Default for FolderMeta {
+    fn default() -> Self {
+        Self {
+            color: None,
+            description: None,
+            note_icon: None,
+            ai_system: None,
+            inherit: true,
+            ai: true,
+        }
+    }
+}
+
+impl FolderMeta {
+    /// Sin nada que guardar (no hace falta el archivo)
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    pub fn from_yaml(yaml: &str) -> Result<Self, serde_yaml::Error> {
+        serde_yaml::from_str(yaml)
+    }
+
+    /// Lee el `.folder.yaml` de `dir`; `None` si no hay o no se puede leer
+    pub fn load(dir: &Path) -> Option<Self> {
+        let yaml = std::fs::read_to_string(dir.join(FOLDER_META_FILE)).ok()?;
+        Self::from_yaml(&yaml).ok()
+    }
+
+    /// Escribe el `.folder.yaml` de `dir`, o lo borra si no queda nada
+    pub fn save(&self, dir: &Path) -> std::io::Result<()> {
+        let path = dir.join(FOLDER_META_FILE);
+        if self.is_empty() {
+            return match std::fs::remove_file(&path) {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e),
+                _ => Ok(()),
+            };
+        }
+        let yaml = serde_yaml::to_string(self).map_err(std::io::Error::other)?;
+        std::fs::write(path, yaml)
+    }
+}
+
+/// Icono y color que hereda lo que hay dentro de una carpeta
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InheritedStyle {
+    pub icon: Option<String>,
+    pub color: Option<String>,
+}
+
+/// Estilo que hereda el contenido de `folder` (`"a/b"`): cada valor sale de
+/// la carpeta más cercana que lo define, subiendo hasta la primera que tenga
+/// `inherit: false`
+pub fn inherited_style(folder: &str, metas: &HashMap<String, FolderMeta>) -> InheritedStyle {
+    let mut style = InheritedStyle::default();
+    let mut path = folder;
+    while !path.is_empty() {
+        if let Some(meta) = metas.get(path) {
+            if !meta.inherit {
+                break;
+            }
+            style.icon = style.icon.or_else(|| meta.note_icon.clone());
+            style.color = style.color.or_else(|| meta.color.clone());
+        }
+        path = path.rsplit_once('/').map_or("", |(parent, _)| parent);
+    }
+    style
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_folder_meta_yaml() {
+        let meta = FolderMeta::from_yaml("color: '#ff6b6b'\ndescription: Ideas sueltas\n").unwrap();
+        assert_eq!(meta.color.as_deref(), Some("#ff6b6b"));
+        assert!(meta.inherit);
+        assert!(!meta.is_empty());
+        assert!(FolderMeta::from_yaml("{}").unwrap().is_empty());
+
+        // Solo se escribe lo que tiene valor
+        let yaml = serde_yaml::to_string(&meta).unwrap();
+        assert!(!yaml.contains("note_icon"));
+        assert!(!yaml.contains("ai:"));
+        assert_eq!(FolderMeta::from_yaml(&yaml).unwrap(), meta);
+    }
+
+    #[test]
+    fn test_inherited_style() {
+        let mut metas = HashMap::new();
+        metas.insert(
+            "work".to_string(),
+            FolderMeta {
+                color: Some("#54a0ff".into()),
+                note_icon: Some("📁".into()),
+                ..Default::default()
+            },
+        );
+        metas.insert(
+            "work/clients".to_string(),
+            FolderMeta {
+                color: Some("#1dd1a1".into()),
+                ..Default::default()
+            },
+        );
+        metas.insert(
+            "work/private".to_string(),
+            FolderMeta {
+                inherit: false,
+                ..Default::default()
+            },
+        );
+
+        let clients = inherited_style("work/clients/acme", &metas);
+        assert_eq!(clients.color.as_deref(), Some("#1dd1a1"));
+        assert_eq!(clients.icon.as_deref(), Some("📁"));
+
+        assert_eq!(
+            inherited_style("work/private", &metas),
+            InheritedStyle::default()
+        );
+        assert_eq!(inherited_style("other", &metas), InheritedStyle::default());
+    }
+}
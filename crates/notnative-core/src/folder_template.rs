@@ -0,0 +1,364 @@
+//! Plantillas de carpeta para proyectos nuevos
+//!
+//! Un manifiesto YAML (`folder_templates.yaml`, junto a `config.json`) define
+//! plantillas con nombre: la lista de subcarpetas y notas que se crean dentro
+//! de la carpeta nueva. Cada nota lleva su texto en `content` o lo toma de un
+//! archivo (`from`, relativo al manifiesto). En los textos, `{{name}}` se
+//! cambia por el nombre del proyecto y `{{date}}` por la fecha de creación.
+//!
+//! ```yaml
+//! templates:
+//!   - name: Project
+//!     entries:
+//!       - path: Overview.md
+//!         content: "# {{name}}"
+//!       - path: Meetings/
+//!       - path: Tasks.md
+//!         from: templates/tasks.md
+//! ```
+//!
+//! Sin manifiesto se usa una plantilla "Project" integrada.
+
+use anyhow::{Context, Result, bail};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::path::{Component, Path, PathBuf};
+
+/// Archivo del manifiesto de plantillas
+pub const FOLDER_TEMPLATES_FILE: &str = "folder_templates.yaml";
+
+/// Contenido de `folder_templates.yaml`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FolderTemplates {
+    #[serde(default)]
+    pub templates: Vec<FolderTemplate>,
+    /// Carpeta del manifiesto, de donde salen los `from`
+    #[serde(skip)]
+    pub source_dir: PathBuf,
+}
+
+/// Estructura de carpeta que se crea de una vez
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FolderTemplate {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub entries: Vec<TemplateEntry>,
+}
+
+/// Subcarpeta (`path` acabado en `/`) o nota de la plantilla
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TemplateEntry {
+    /// Ruta dentro de la carpeta del proyecto; sin extensión se añade `.md`
+    pub path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// Archivo del que sale el texto, relativo al manifiesto
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+}
+
+impl TemplateEntry {
+    fn folder(path: &str) -> Self {
+        Self {
+            path: path.to_string(),
+            content: None,
+            from: None,
+        }
+    }
+
+    fn note(path: &str, content: &str) -> Self {
+        Self {
+            path: path.to_string(),
+            content: Some(content.to_string()),
+            from: None,
+        }
+    }
+
+    pub fn is_folder(&self) -> bool {
+        self.path.ends_with('/')
+    }
+
+    /// Ruta relativa ya comprobada: nada de `..` ni rutas absolutas
+    fn relative_path(&self) -> Result<PathBuf> {
+        let trimmed = self.path.trim_end_matches('/');
+        let mut path = PathBuf::new();
+        for component in Path::new(trimmed).components() {
+            match component {
+                Component::Normal(part) => path.push(part),
+                Component::CurDir => {}
+                _ => bail!("Ruta no válida en la plantilla: {}", self.path),
+            }
+        }
+        if path.as_os_str().is_empty() {
+            bail!("Ruta vacía en la plantilla");
+        }
+        if !self.is_folder() && path.extension().is_none() {
+            path.set_extension("md");
+        }
+        Ok(path)
+    }
+}
+
+impl Default for FolderTemplates {
+    fn default() -> Self {
+        Self {
+            templates: vec![FolderTemplate {
+                name: "Project".to_string(),
+                description: Some("Overview, Meetings/ y Tasks".to_string()),
+                entries: vec![
+                    TemplateEntry::note(
+                        "Overview.md",
+                        "---\ntags: [project]\ncreated: {{date}}\n---\n\n# {{name}}\n\n## Objetivo\n\n## Estado\n\n## Enlaces\n",
+                    ),
+                    TemplateEntry::folder("Meetings/"),
+                    TemplateEntry::note("Tasks.md", "# Tareas — {{name}}\n\n- [ ] \n"),
+                ],
+            }],
+            source_dir: PathBuf::new(),
+        }
+    }
+}
+
+impl FolderTemplates {
+    /// `folder_templates.yaml` junto a la configuración de la app
+    pub fn default_path() -> PathBuf {
+        dirs::data_local_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("notnative")
+            .join(FOLDER_TEMPLATES_FILE)
+    }
+
+    pub fn from_yaml(yaml: &str) -> Result<Self, serde_yaml::Error> {
+        serde_yaml::from_str(yaml)
+    }
+
+    /// Lee el manifiesto; si no existe devuelve las plantillas integradas
+    pub fn load(path: &Path) -> Result<Self> {
+        let mut templates = match std::fs::read_to_string(path) {
+            Ok(yaml) => Self::from_yaml(&yaml)
+                .with_context(|| format!("Manifiesto de plantillas no válido: {:?}", path))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Self::default(),
+            Err(e) => return Err(e.into()),
+        };
+        templates.source_dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+        Ok(templates)
+    }
+
+    /// Escribe el manifiesto integrado si aún no hay uno, para poder editarlo
+    pub fn ensure_file(path: &Path) -> Result<()> {
+        if path.exists() {
+            return Ok(());
+        }
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_yaml::to_string(&Self::default())?)?;
+        Ok(())
+    }
+
+    /// Plantilla por nombre (sin distinguir mayúsculas)
+    pub fn get(&self, name: &str) -> Option<&FolderTemplate> {
+        self.templates
+            .iter()
+            .find(|template| template.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Crea el proyecto `folder` (relativo a `notes_root`; el último tramo es
+    /// su nombre) con la plantilla `template`, o la primera si es `None`.
+    /// Devuelve las notas creadas.
+    pub fn create_project(
+        &self,
+        template: Option<&str>,
+        notes_root: &Path,
+        folder: &str,
+        date: NaiveDate,
+    ) -> Result<Vec<PathBuf>> {
+        let template = match template {
+            Some(name) => self
+                .get(name)
+                .with_context(|| format!("No existe la plantilla '{}'", name))?,
+            None => self
+                .templates
+                .first()
+                .context("El manifiesto no tiene plantillas")?,
+        };
+        let relative = TemplateEntry::folder(&format!("{}/", folder)).relative_path()?;
+        let project_name = relative
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or(folder);
+        if project_name.starts_with('.') {
+            bail!("El nombre del proyecto no puede empezar por un punto");
+        }
+        template.instantiate(
+            &notes_root.join(&relative),
+            project_name,
+            date,
+            &self.source_dir,
+        )
+    }
+}
+
+impl FolderTemplate {
+    /// Crea `target` con todo lo de la plantilla. `sources` es la carpeta del
+    /// manifiesto (para `from`). No toca nada si `target` ya existe o si alguna
+    /// ruta no es válida. Devuelve las notas creadas.
+    pub fn instantiate(
+        &self,
+        target: &Path,
+        project_name: &str,
+        date: NaiveDate,
+        sources: &Path,
+    ) -> Result<Vec<PathBuf>> {
+        if target.exists() {
+            bail!("Ya existe: {:?}", target);
+        }
+
+        // Comprobar y leer todo antes de escribir nada
+        let mut planned = Vec::new();
+        for entry in &self.entries {
+            let path = target.join(entry.relative_path()?);
+            let text = if entry.is_folder() {
+                None
+            } else {
+                let raw = match (&entry.content, &entry.from) {
+                    (Some(content), _) => content.clone(),
+                    (None, Some(from)) => std::fs::read_to_string(sources.join(from))
+                        .with_context(|| format!("No se pudo leer la plantilla {}", from))?,
+                    (None, None) => String::new(),
+                };
+                Some(fill_placeholders(&raw, project_name, date))
+            };
+            planned.push((path, text));
+        }
+
+        std::fs::create_dir_all(target)?;
+        let mut notes = Vec::new();
+        for (path, text) in planned {
+            match text {
+                None => std::fs::create_dir_all(&path)?,
+                Some(text) => {
+                    if let Some(parent) = path.parent() {
+                        std::fs::create_dir_all(parent)?;
+                    }
+                    std::fs::write(&path, text)?;
+                    if path.extension().and_then(|e| e.to_str()) == Some("md") {
+                        notes.push(path);
+                    }
+                }
+            }
+        }
+        Ok(notes)
+    }
+}
+
+/// Cambia `{{name}}` y `{{date}}` (`AAAA-MM-DD`)
+pub fn fill_placeholders(text: &str, project_name: &str, date: NaiveDate) -> String {
+    text.replace("{{name}}", project_name)
+        .replace("{{date}}", &date.format("%Y-%m-%d").to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, 9).unwrap()
+    }
+
+    #[test]
+    fn test_parse_manifest() {
+        let yaml = "templates:\n  - name: Research\n    entries:\n      - path: Sources/\n      - path: Notes\n        content: \"# {{name}}\"\n";
+        let templates = FolderTemplates::from_yaml(yaml).unwrap();
+
+        let research = templates.get("research").unwrap();
+        assert_eq!(research.entries.len(), 2);
+        assert!(research.entries[0].is_folder());
+        assert_eq!(
+            research.entries[1].relative_path().unwrap(),
+            PathBuf::from("Notes.md")
+        );
+        assert!(templates.get("Project").is_none());
+    }
+
+    #[test]
+    fn test_instantiate_default_project() {
+        let root = env::temp_dir().join("notnative_test_folder_template");
+        let _ = std::fs::remove_dir_all(&root);
+        let target = root.join("Web");
+
+        let templates = FolderTemplates::default();
+        let project = templates.get("Project").unwrap();
+        let notes = project.instantiate(&target, "Web", date(), &root).unwrap();
+
+        assert_eq!(notes.len(), 2);
+        assert!(target.join("Meetings").is_dir());
+        let overview = std::fs::read_to_string(target.join("Overview.md")).unwrap();
+        assert!(overview.contains("# Web"));
+        assert!(overview.contains("created: 2026-03-09"));
+
+        // Una segunda vez no pisa el proyecto
+        assert!(project.instantiate(&target, "Web", date(), &root).is_err());
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_instantiate_rejects_escaping_paths() {
+        let root = env::temp_dir().join("notnative_test_folder_template_escape");
+        let _ = std::fs::remove_dir_all(&root);
+        let target = root.join("Proyecto");
+
+        let template = FolderTemplate {
+            name: "Mala".to_string(),
+            description: None,
+            entries: vec![
+                TemplateEntry::note("Bien.md", "ok"),
+                TemplateEntry::note("../fuera.md", "no"),
+            ],
+        };
+        assert!(template.instantiate(&target, "X", date(), &root).is_err());
+        assert!(!target.exists());
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_create_project_from_manifest() {
+        let root = env::temp_dir().join("notnative_test_folder_template_manifest");
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("plantillas")).unwrap();
+        std::fs::write(root.join("plantillas/acta.md"), "# Acta de {{name}}").unwrap();
+        let manifest = root.join(FOLDER_TEMPLATES_FILE);
+        std::fs::write(
+            &manifest,
+            "templates:\n  - name: Cliente\n    entries:\n      - path: Actas/Primera\n        from: plantillas/acta.md\n",
+        )
+        .unwrap();
+
+        let templates = FolderTemplates::load(&manifest).unwrap();
+        let notes_root = root.join("notas");
+        let notes = templates
+            .create_project(Some("cliente"), &notes_root, "Clientes/ACME", date())
+            .unwrap();
+
+        let first = notes_root.join("Clientes/ACME/Actas/Primera.md");
+        assert_eq!(notes, vec![first.clone()]);
+        assert_eq!(std::fs::read_to_string(first).unwrap(), "# Acta de ACME");
+        assert!(
+            templates
+                .create_project(None, &notes_root, "../fuera", date())
+                .is_err()
+        );
+        assert!(
+            templates
+                .create_project(Some("Nada"), &notes_root, "Otro", date())
+                .is_err()
+        );
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
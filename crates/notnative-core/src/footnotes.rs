@@ -0,0 +1,199 @@
+//! Notas al pie (`[^1]`): renderizado con enlaces de vuelta e inserción desde el editor
+
+use pulldown_cmark::{CowStr, Event, Tag, TagEnd};
+use regex::Regex;
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+/// Definiciones `[^etiqueta]:` al inicio de línea
+static DEFINITION_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?m)^\[\^([^\]\s]+)\]:").unwrap());
+
+/// Identificador HTML de una etiqueta (solo caracteres seguros en un id)
+fn slug(label: &str) -> String {
+    label
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect()
+}
+
+/// Saca las definiciones de notas al pie del flujo de eventos y las añade al
+/// final en una sección numerada por orden de aparición de las referencias,
+/// cada una con enlaces de vuelta a sus referencias. Las referencias llevan
+/// `data-footnote` para que el preview muestre el texto al pasar el ratón.
+pub fn render_footnotes<'a>(events: Vec<Event<'a>>) -> Vec<Event<'a>> {
+    let mut output = Vec::with_capacity(events.len());
+    let mut definitions: HashMap<String, Vec<Event<'a>>> = HashMap::new();
+    // Etiquetas en orden de primera referencia y referencias de cada una
+    let mut order: Vec<String> = Vec::new();
+    let mut references: HashMap<String, usize> = HashMap::new();
+    let mut current: Option<(String, Vec<Event<'a>>)> = None;
+
+    for event in events {
+        match event {
+            Event::Start(Tag::FootnoteDefinition(label)) => {
+                current = Some((label.to_string(), Vec::new()));
+            }
+            Event::End(TagEnd::FootnoteDefinition) => {
+                if let Some((label, events)) = current.take() {
+                    definitions.entry(label).or_insert(events);
+                }
+            }
+            Event::FootnoteReference(label) => {
+                let label = label.to_string();
+                if !references.contains_key(&label) {
+                    order.push(label.clone());
+                }
+                let count = references.entry(label.clone()).or_insert(0);
+                *count += 1;
+                let number = order.iter().position(|l| *l == label).unwrap_or(0) + 1;
+                let id = slug(&label);
+                let html = format!(
+                    r##"<sup class="footnote-ref" id="fnref-{id}-{count}"><a href="#fn-{id}" data-footnote="fn-{id}">{number}</a></sup>"##,
+                );
+                match current.as_mut() {
+                    Some((_, events)) => events.push(Event::Html(CowStr::from(html))),
+                    None => output.push(Event::Html(CowStr::from(html))),
+                }
+            }
+            event => match current.as_mut() {
+                Some((_, events)) => events.push(event),
+                None => output.push(event),
+            },
+        }
+    }
+
+    // Definiciones citadas en orden; luego las que nadie cita
+    let mut labels = order.clone();
+    let mut uncited: Vec<String> = definitions
+        .keys()
+        .filter(|label| !references.contains_key(*label))
+        .cloned()
+        .collect();
+    uncited.sort();
+    labels.extend(uncited);
+
+    let labels: Vec<String> = labels
+        .into_iter()
+        .filter(|label| definitions.contains_key(label))
+        .collect();
+    if labels.is_empty() {
+        return output;
+    }
+
+    output.push(Event::Html(CowStr::from(
+        "<section class=\"footnotes\"><ol>\n",
+    )));
+    for label in labels {
+        let Some(mut events) = definitions.remove(&label) else {
+            continue;
+        };
+        let id = slug(&label);
+        output.push(Event::Html(CowStr::from(format!("<li id=\"fn-{id}\">"))));
+
+        let backrefs: String = (1..=references.get(&label).copied().unwrap_or(0))
+            .map(|n| {
+                let mark = if n == 1 {
+                    "↩".to_string()
+                } else {
+                    format!("↩<sup>{n}</sup>")
+                };
+                format!(r##" <a href="#fnref-{id}-{n}" class="footnote-backref">{mark}</a>"##)
+            })
+            .collect();
+
+        // Los enlaces de vuelta van dentro del último párrafo, no en una línea aparte
+        let last_paragraph_end = events
+            .iter()
+            .rposition(|event| matches!(event, Event::End(TagEnd::Paragraph)));
+        match last_paragraph_end {
+            Some(index) if index + 1 == events.len() => {
+                events.insert(index, Event::Html(CowStr::from(backrefs)));
+            }
+            _ => events.push(Event::Html(CowStr::from(backrefs))),
+        }
+        output.extend(events);
+        output.push(Event::Html(CowStr::from("</li>\n")));
+    }
+    output.push(Event::Html(CowStr::from("</ol></section>\n")));
+    output
+}
+
+/// Siguiente etiqueta numérica libre (`1`, `2`...) según las definiciones existentes
+pub fn next_label(markdown: &str) -> String {
+    let highest = DEFINITION_RE
+        .captures_iter(markdown)
+        .filter_map(|caps| caps[1].parse::<u32>().ok())
+        .max()
+        .unwrap_or(0);
+    (highest + 1).to_string()
+}
+
+/// Texto a añadir al final de la nota para definir `label`, separado por una
+/// línea en blanco del contenido anterior. El cursor queda al final del texto.
+pub fn definition_block(markdown: &str, label: &str) -> String {
+    let separator = if markdown.is_empty() || markdown.ends_with("\n\n") {
+        ""
+    } else if markdown.ends_with('\n') {
+        "\n"
+    } else {
+        "\n\n"
+    };
+    format!("{separator}[^{label}]: ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pulldown_cmark::{Options, Parser, html};
+
+    fn render(markdown: &str) -> String {
+        let events: Vec<Event> = Parser::new_ext(markdown, Options::ENABLE_FOOTNOTES).collect();
+        let mut output = String::new();
+        html::push_html(&mut output, render_footnotes(events).into_iter());
+        output
+    }
+
+    #[test]
+    fn test_render_numbers_by_reference_order() {
+        let html =
+            render("Uno[^b] y dos[^a] y otra vez[^b].\n\n[^a]: Primera.\n\n[^b]: Segunda.\n");
+        // La primera referencia citada es la 1 aunque se defina después
+        assert!(html.contains(r##"<a href="#fn-b" data-footnote="fn-b">1</a>"##));
+        assert!(html.contains(r##"<a href="#fn-a" data-footnote="fn-a">2</a>"##));
+        assert!(html.contains(r#"id="fnref-b-2""#));
+
+        let section = &html[html.find("<section class=\"footnotes\">").unwrap()..];
+        assert!(section.find("fn-b").unwrap() < section.find("fn-a").unwrap());
+        // Dos enlaces de vuelta para la nota citada dos veces, dentro del párrafo
+        assert!(section.contains(
+            r##"Segunda. <a href="#fnref-b-1" class="footnote-backref">↩</a> <a href="#fnref-b-2""##
+        ));
+    }
+
+    #[test]
+    fn test_render_without_footnotes_is_unchanged() {
+        let html = render("Solo texto.\n");
+        assert_eq!(html, "<p>Solo texto.</p>\n");
+    }
+
+    #[test]
+    fn test_next_label_and_definition_block() {
+        assert_eq!(next_label("Texto sin notas"), "1");
+        assert_eq!(
+            next_label("a[^1] b[^nota]\n\n[^1]: x\n[^nota]: y\n[^3]: z"),
+            "4"
+        );
+
+        assert_eq!(definition_block("", "1"), "[^1]: ");
+        assert_eq!(definition_block("texto", "2"), "\n\n[^2]: ");
+        assert_eq!(definition_block("texto\n", "2"), "\n[^2]: ");
+        assert_eq!(definition_block("texto\n\n", "2"), "[^2]: ");
+    }
+}
@@ -1336,7 +1336,7 @@ impl CellGrid {
 
     /// Parsear datetime completo
     fn parse_datetime(s: &str) -> Option<chrono::NaiveDateTime> {
-        use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+        use chrono::{NaiveDateTime, NaiveTime};
         let s = s.trim();
 
         let formats = [
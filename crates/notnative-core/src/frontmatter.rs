@@ -119,7 +119,13 @@ impl Frontmatter {
     }
 }
 
-/// Extraer tags inline del contenido (patrón #palabra)
+/// Caracteres que forman parte de un tag inline. La `/` separa niveles en los
+/// tags anidados (`#proyecto/cliente-a`).
+pub fn is_tag_char(ch: char) -> bool {
+    ch.is_alphanumeric() || ch == '_' || ch == '-' || ch == '/'
+}
+
+/// Extraer tags inline del contenido (patrón #palabra o #padre/hijo)
 ///
 /// Detecta patrones como #rust #programming pero NO:
 /// - # Heading (# seguido de espacio)
@@ -147,14 +153,12 @@ pub fn extract_inline_tags(content: &str) -> Vec<String> {
                 in_tag = true;
                 current_tag.clear();
             } else if in_tag {
-                if ch.is_alphanumeric() || ch == '_' || ch == '-' {
+                if is_tag_char(ch) {
                     current_tag.push(ch);
                 } else {
                     // Fin del tag
-                    if !current_tag.is_empty() {
-                        tags.push(current_tag.trim().to_lowercase());
-                        current_tag.clear();
-                    }
+                    push_inline_tag(&mut tags, &current_tag);
+                    current_tag.clear();
                     in_tag = false;
                 }
             }
@@ -162,8 +166,8 @@ pub fn extract_inline_tags(content: &str) -> Vec<String> {
         }
 
         // Si la línea termina con un tag
-        if in_tag && !current_tag.is_empty() {
-            tags.push(current_tag.trim().to_lowercase());
+        if in_tag {
+            push_inline_tag(&mut tags, &current_tag);
         }
     }
 
@@ -174,6 +178,14 @@ pub fn extract_inline_tags(content: &str) -> Vec<String> {
     tags
 }
 
+/// Añade un tag leído del texto, sin `/` sueltas en los extremos (`#a/` es `a`)
+fn push_inline_tag(tags: &mut Vec<String>, raw: &str) {
+    let tag = raw.trim_matches('/');
+    if !tag.is_empty() {
+        tags.push(tag.to_lowercase());
+    }
+}
+
 /// Extraer tags de una nota (parseando el frontmatter)
 pub fn extract_tags(content: &str) -> Vec<String> {
     match Frontmatter::parse(content) {
@@ -199,6 +211,33 @@ pub fn extract_all_tags(content: &str) -> Vec<String> {
     all_tags
 }
 
+/// Nombres alternativos de la nota (`aliases:` del frontmatter), por los que
+/// también se la puede mencionar con `@` o `[[...]]`. Acepta una lista o un
+/// solo nombre, y también la clave `alias:`.
+pub fn extract_aliases(content: &str) -> Vec<String> {
+    let Ok((frontmatter, _)) = Frontmatter::parse(content) else {
+        return Vec::new();
+    };
+
+    let mut aliases: Vec<String> = Vec::new();
+    for key in ["aliases", "alias"] {
+        let values: Vec<&str> = match frontmatter.custom.get(key) {
+            Some(serde_yaml::Value::Sequence(items)) => {
+                items.iter().filter_map(|item| item.as_str()).collect()
+            }
+            Some(serde_yaml::Value::String(alias)) => alias.split(',').collect(),
+            _ => Vec::new(),
+        };
+        for alias in values {
+            let alias = alias.trim();
+            if !alias.is_empty() && !aliases.iter().any(|a| a.eq_ignore_ascii_case(alias)) {
+                aliases.push(alias.to_string());
+            }
+        }
+    }
+    aliases
+}
+
 /// Actualizar tags en el contenido de una nota
 ///
 /// Si ya tiene frontmatter, actualiza los tags.
@@ -217,6 +256,46 @@ pub fn update_tags(content: &str, new_tags: Vec<String>) -> Result<String> {
     }
 }
 
+/// Líneas que ocupa el frontmatter (0 si no hay)
+pub fn frontmatter_lines(content: &str) -> usize {
+    let mut lines = content.lines();
+    if lines.next().map(str::trim_end) != Some("---") {
+        return 0;
+    }
+    lines
+        .position(|line| line.trim_end() == "---")
+        .map_or(0, |close| close + 2)
+}
+
+/// Pone `created: <fecha>` en el frontmatter (p. ej. al duplicar una nota).
+///
+/// Se edita línea a línea para no reordenar ni reformatear el resto de campos.
+/// Si la nota no tiene frontmatter se devuelve tal cual.
+pub fn set_created_date(content: &str, date: &str) -> String {
+    let created = format!("created: {}", date);
+    let mut lines: Vec<&str> = content.split('\n').collect();
+    if lines.first().map(|line| line.trim_end()) != Some("---") {
+        return content.to_string();
+    }
+    let Some(close) = lines
+        .iter()
+        .skip(1)
+        .position(|line| line.trim_end() == "---")
+        .map(|index| index + 1)
+    else {
+        return content.to_string();
+    };
+
+    match lines[1..close]
+        .iter()
+        .position(|line| line.starts_with("created:"))
+    {
+        Some(index) => lines[index + 1] = &created,
+        None => lines.insert(close, &created),
+    }
+    lines.join("\n")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -337,6 +416,12 @@ tags: [rust, gtk, markdown]
         assert_eq!(tags, vec!["rust", "gtk", "markdown"]);
     }
 
+    #[test]
+    fn test_extract_nested_inline_tags() {
+        let tags = extract_inline_tags("Ver #Proyecto/Cliente-A y #idea/, no #/ ni a#b\n# Título");
+        assert_eq!(tags, vec!["idea", "proyecto/cliente-a"]);
+    }
+
     #[test]
     fn test_update_tags() {
         let content = r#"---
@@ -368,4 +453,48 @@ title: My Note
         assert_eq!(frontmatter.tags, vec!["notes", "rust"]); // Normalizados (sorted)
         assert!(body.contains("Just content"));
     }
+    #[test]
+    fn test_frontmatter_lines() {
+        assert_eq!(frontmatter_lines("---\ntags: [a]\n---\n# Título\n"), 3);
+        assert_eq!(frontmatter_lines("# Sin frontmatter\n---\n"), 0);
+        // Sin cierre no hay frontmatter
+        assert_eq!(frontmatter_lines("---\ntags: [a]\n"), 0);
+    }
+
+    #[test]
+    fn test_set_created_date() {
+        let content = "---\ntags: [plan]\ncreated: 2024-01-05\nestado: borrador\n---\n\n# Plan\n";
+        assert_eq!(
+            set_created_date(content, "2026-10-16"),
+            "---\ntags: [plan]\ncreated: 2026-10-16\nestado: borrador\n---\n\n# Plan\n"
+        );
+
+        // Sin campo created se añade al final del frontmatter
+        assert_eq!(
+            set_created_date("---\ntitle: Plan\n---\nTexto", "2026-10-16"),
+            "---\ntitle: Plan\ncreated: 2026-10-16\n---\nTexto"
+        );
+
+        // Sin frontmatter (o sin cerrar) no se toca
+        assert_eq!(set_created_date("# Plan\n", "2026-10-16"), "# Plan\n");
+        assert_eq!(
+            set_created_date("---\nabierto", "2026-10-16"),
+            "---\nabierto"
+        );
+    }
+
+    #[test]
+    fn test_extract_aliases() {
+        let content =
+            "---\naliases: [MCP, Servidor MCP, mcp]\nalias: Conexión\ntags: [ia]\n---\n# Nota";
+        assert_eq!(
+            extract_aliases(content),
+            vec!["MCP", "Servidor MCP", "Conexión"]
+        );
+        assert_eq!(
+            extract_aliases("---\naliases: Diario, Bitácora\n---\n"),
+            vec!["Diario", "Bitácora"]
+        );
+        assert!(extract_aliases("# Sin frontmatter").is_empty());
+    }
 }
@@ -0,0 +1,447 @@
+//! Bloques de hábitos (```` ```habit ````)
+//!
+//! Un bloque de código `habit` describe un hábito y guarda los días cumplidos
+//! en la propia nota:
+//!
+//! ````text
+//! ```habit
+//! name: Ejercicio
+//! schedule: daily
+//! done: 2026-10-01, 2026-10-02
+//! ```
+//! ````
+//!
+//! `schedule` admite `daily`, `weekdays`, `weekly` o una lista de días
+//! (`mon, wed, fri` / `lun, mié, vie`). En el preview se dibuja el mes en
+//! curso con un botón por día; pulsarlo envía `habit-toggle` con el número
+//! del bloque y la fecha, y la app reescribe la línea `done:` con
+//! [`toggle_habit_day`].
+
+use crate::date_format::DateFormatter;
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use std::collections::BTreeSet;
+
+/// Lenguaje del bloque de código
+pub const HABIT_LANGUAGE: &str = "habit";
+
+/// Días en que toca el hábito
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HabitSchedule {
+    Daily,
+    /// De lunes a viernes
+    Weekdays,
+    /// Una vez por semana, cualquier día
+    Weekly,
+    /// Solo esos días de la semana
+    Days(Vec<Weekday>),
+}
+
+impl HabitSchedule {
+    /// Interpreta el valor de `schedule:`; si no se entiende, a diario
+    pub fn parse(value: &str) -> Self {
+        let value = value.trim().to_lowercase();
+        match value.as_str() {
+            "" | "daily" | "diario" | "every day" | "todos los días" => Self::Daily,
+            "weekdays" | "laborables" | "entre semana" => Self::Weekdays,
+            "weekly" | "semanal" => Self::Weekly,
+            _ => {
+                let days: Vec<Weekday> = value
+                    .split(|c: char| c == ',' || c.is_whitespace())
+                    .filter_map(parse_weekday)
+                    .collect();
+                if days.is_empty() {
+                    Self::Daily
+                } else {
+                    Self::Days(days)
+                }
+            }
+        }
+    }
+
+    fn includes(&self, day: NaiveDate) -> bool {
+        match self {
+            Self::Daily | Self::Weekly => true,
+            Self::Weekdays => !matches!(day.weekday(), Weekday::Sat | Weekday::Sun),
+            Self::Days(days) => days.contains(&day.weekday()),
+        }
+    }
+}
+
+fn parse_weekday(name: &str) -> Option<Weekday> {
+    let name = name.trim().trim_end_matches('.');
+    let prefix: String = name.chars().take(3).collect();
+    Some(match prefix.as_str() {
+        "mon" | "lun" => Weekday::Mon,
+        "tue" | "mar" => Weekday::Tue,
+        "wed" | "mié" | "mie" => Weekday::Wed,
+        "thu" | "jue" => Weekday::Thu,
+        "fri" | "vie" => Weekday::Fri,
+        "sat" | "sáb" | "sab" => Weekday::Sat,
+        "sun" | "dom" => Weekday::Sun,
+        _ => return None,
+    })
+}
+
+/// Un hábito con los días cumplidos
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Habit {
+    pub name: String,
+    pub schedule: HabitSchedule,
+    pub done: BTreeSet<NaiveDate>,
+}
+
+impl Habit {
+    /// Lee el cuerpo de un bloque `habit` (líneas `clave: valor`)
+    pub fn parse(body: &str) -> Self {
+        let mut habit = Self {
+            name: String::new(),
+            schedule: HabitSchedule::Daily,
+            done: BTreeSet::new(),
+        };
+        for line in body.lines() {
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            match key.trim().to_lowercase().as_str() {
+                "name" | "nombre" => habit.name = value.trim().to_string(),
+                "schedule" | "frecuencia" => habit.schedule = HabitSchedule::parse(value),
+                "done" | "hecho" => habit.done.extend(parse_dates(value)),
+                _ => {}
+            }
+        }
+        habit
+    }
+
+    /// Racha actual hasta `today`, en días que tocaba (o semanas si es
+    /// semanal). Que hoy aún no esté hecho no la rompe.
+    pub fn current_streak(&self, today: NaiveDate) -> usize {
+        let Some(first) = self.done.first().copied() else {
+            return 0;
+        };
+
+        if self.schedule == HabitSchedule::Weekly {
+            let mut week = week_start(today);
+            if !self.done_in_week(week) {
+                week -= Duration::days(7);
+            }
+            let mut streak = 0;
+            while week >= week_start(first) && self.done_in_week(week) {
+                streak += 1;
+                week -= Duration::days(7);
+            }
+            return streak;
+        }
+
+        let mut day = today;
+        if !self.done.contains(&today) {
+            day -= Duration::days(1);
+        }
+        let mut streak = 0;
+        while day >= first {
+            if self.schedule.includes(day) {
+                if !self.done.contains(&day) {
+                    break;
+                }
+                streak += 1;
+            }
+            day -= Duration::days(1);
+        }
+        streak
+    }
+
+    /// Racha más larga hasta `today`
+    pub fn best_streak(&self, today: NaiveDate) -> usize {
+        let Some(first) = self.done.first().copied() else {
+            return 0;
+        };
+
+        let (step, mut current, end) = if self.schedule == HabitSchedule::Weekly {
+            (7, week_start(first), week_start(today))
+        } else {
+            (1, first, today)
+        };
+        let (mut best, mut run) = (0, 0);
+        while current <= end {
+            let done = if step == 7 {
+                Some(self.done_in_week(current))
+            } else if self.schedule.includes(current) {
+                Some(self.done.contains(&current))
+            } else {
+                None
+            };
+            match done {
+                Some(true) => {
+                    run += 1;
+                    best = best.max(run);
+                }
+                // Hoy aún está a tiempo
+                Some(false) if current != end => run = 0,
+                _ => {}
+            }
+            current += Duration::days(step);
+        }
+        best
+    }
+
+    fn done_in_week(&self, monday: NaiveDate) -> bool {
+        self.done
+            .range(monday..monday + Duration::days(7))
+            .next()
+            .is_some()
+    }
+}
+
+fn week_start(day: NaiveDate) -> NaiveDate {
+    day - Duration::days(day.weekday().num_days_from_monday() as i64)
+}
+
+fn parse_dates(value: &str) -> impl Iterator<Item = NaiveDate> + '_ {
+    value
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter_map(|date| NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d").ok())
+}
+
+/// Bloque `habit` dentro de la nota: índices de las líneas de apertura y de
+/// cierre
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct HabitBlock {
+    open: usize,
+    close: usize,
+}
+
+/// Bloques `habit` cerrados, en orden. Los que van dentro de otro bloque de
+/// código no cuentan.
+fn habit_blocks(lines: &[&str]) -> Vec<HabitBlock> {
+    let mut blocks = Vec::new();
+    let mut fence: Option<(usize, bool)> = None;
+    for (index, line) in lines.iter().enumerate() {
+        let trimmed = line.trim_start();
+        if !trimmed.starts_with("```") {
+            continue;
+        }
+        match fence {
+            None => {
+                let language = trimmed.trim_start_matches('`').trim();
+                fence = Some((index, language.eq_ignore_ascii_case(HABIT_LANGUAGE)));
+            }
+            Some((open, is_habit)) => {
+                if trimmed.trim_end() == "```" {
+                    if is_habit {
+                        blocks.push(HabitBlock { open, close: index });
+                    }
+                    fence = None;
+                }
+            }
+        }
+    }
+    blocks
+}
+
+/// Marca o desmarca `date` en el bloque `habit` número `index` (desde 0),
+/// reescribiendo su línea `done:` en orden. `None` si no existe el bloque.
+pub fn toggle_habit_day(markdown: &str, index: usize, date: NaiveDate) -> Option<String> {
+    let lines: Vec<&str> = markdown.lines().collect();
+    let block = *habit_blocks(&lines).get(index)?;
+
+    let done_line = (block.open + 1..block.close).find(|&line| {
+        lines[line]
+            .split_once(':')
+            .is_some_and(|(key, _)| matches!(key.trim().to_lowercase().as_str(), "done" | "hecho"))
+    });
+    let mut done: BTreeSet<NaiveDate> = done_line
+        .map(|line| parse_dates(lines[line].split_once(':').map_or("", |(_, v)| v)).collect())
+        .unwrap_or_default();
+    if !done.remove(&date) {
+        done.insert(date);
+    }
+    let key = done_line
+        .and_then(|line| lines[line].split_once(':'))
+        .map_or("done", |(key, _)| key);
+    let new_line = format!(
+        "{}: {}",
+        key,
+        done.iter()
+            .map(|date| date.format("%Y-%m-%d").to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+
+    let mut output: Vec<String> = lines.iter().map(|line| line.to_string()).collect();
+    match done_line {
+        Some(line) => output[line] = new_line,
+        None => output.insert(block.close, new_line),
+    }
+    let mut result = output.join("\n");
+    if markdown.ends_with('\n') {
+        result.push('\n');
+    }
+    Some(result)
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Sustituye cada bloque `habit` por su calendario del mes de `today`. Cada
+/// bloque queda en una sola línea de HTML seguida de una en blanco, para que
+/// CommonMark lo trate como bloque HTML.
+pub fn render_habits(markdown: &str, today: NaiveDate) -> String {
+    if !markdown.contains("```habit") && !markdown.contains("```Habit") {
+        return markdown.to_string();
+    }
+    let lines: Vec<&str> = markdown.lines().collect();
+    let blocks = habit_blocks(&lines);
+    if blocks.is_empty() {
+        return markdown.to_string();
+    }
+
+    let mut output: Vec<String> = Vec::with_capacity(lines.len());
+    let mut next = 0;
+    for (index, block) in blocks.iter().enumerate() {
+        output.extend(lines[next..block.open].iter().map(|line| line.to_string()));
+        let habit = Habit::parse(&lines[block.open + 1..block.close].join("\n"));
+        output.push(habit_html(&habit, index, today));
+        output.push(String::new());
+        next = block.close + 1;
+    }
+    output.extend(lines[next..].iter().map(|line| line.to_string()));
+
+    let mut result = output.join("\n");
+    if markdown.ends_with('\n') {
+        result.push('\n');
+    }
+    result
+}
+
+/// Calendario de un hábito: nombre, rachas y una rejilla de lunes a domingo
+fn habit_html(habit: &Habit, index: usize, today: NaiveDate) -> String {
+    let first = today.with_day(1).unwrap_or(today);
+    let days_in_month = (first + Duration::days(31)).with_day(1).unwrap_or(today) - first;
+
+    let mut cells = String::new();
+    for _ in 0..first.weekday().num_days_from_monday() {
+        cells.push_str(r#"<span class="habit-day empty"></span>"#);
+    }
+    for offset in 0..days_in_month.num_days() {
+        let day = first + Duration::days(offset);
+        let mut classes = vec!["habit-day"];
+        if habit.done.contains(&day) {
+            classes.push("done");
+        }
+        if !habit.schedule.includes(day) {
+            classes.push("off");
+        }
+        if day == today {
+            classes.push("today");
+        }
+        if day > today {
+            classes.push("future");
+            cells.push_str(&format!(
+                r#"<span class="{}">{}</span>"#,
+                classes.join(" "),
+                day.day()
+            ));
+        } else {
+            cells.push_str(&format!(
+                r#"<button class="{}" onclick="notifyRust('habit-toggle', {}, '{}')">{}</button>"#,
+                classes.join(" "),
+                index,
+                day.format("%Y-%m-%d"),
+                day.day()
+            ));
+        }
+    }
+
+    let name = if habit.name.is_empty() {
+        HABIT_LANGUAGE.to_string()
+    } else {
+        escape_html(&habit.name)
+    };
+    format!(
+        r#"<div class="habit" data-habit="{}"><div class="habit-header"><span class="habit-name">{}</span><span class="habit-streak">🔥 {} · 🏆 {}</span></div><div class="habit-month">{}</div><div class="habit-grid">{}</div></div>"#,
+        index,
+        name,
+        habit.current_streak(today),
+        habit.best_streak(today),
+        DateFormatter::global().format_month(today),
+        cells
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(text: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(text, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_habit_streaks() {
+        // 2026-10-16 es viernes
+        let today = date("2026-10-16");
+        let habit = Habit::parse(
+            "name: Ejercicio\nschedule: daily\ndone: 2026-10-01, 2026-10-02, 2026-10-03, 2026-10-14 2026-10-15",
+        );
+        assert_eq!(habit.name, "Ejercicio");
+        // Hoy aún no, pero la racha sigue
+        assert_eq!(habit.current_streak(today), 2);
+        assert_eq!(habit.best_streak(today), 3);
+
+        // Entre semana el fin de semana no rompe la racha
+        let habit = Habit::parse("schedule: weekdays\ndone: 2026-10-09, 2026-10-12, 2026-10-16");
+        assert_eq!(habit.schedule, HabitSchedule::Weekdays);
+        assert_eq!(habit.current_streak(today), 1);
+        let habit = Habit::parse(
+            "schedule: weekdays\ndone: 2026-10-09, 2026-10-12, 2026-10-13, 2026-10-14, 2026-10-15",
+        );
+        assert_eq!(habit.current_streak(today), 5);
+
+        let habit = Habit::parse("schedule: lun, mié, vie\ndone: 2026-10-12, 2026-10-14");
+        assert_eq!(
+            habit.schedule,
+            HabitSchedule::Days(vec![Weekday::Mon, Weekday::Wed, Weekday::Fri])
+        );
+        assert_eq!(habit.current_streak(today), 2);
+
+        // Semanal: semanas seguidas con al menos un día
+        let habit = Habit::parse("schedule: weekly\ndone: 2026-09-30, 2026-10-06, 2026-10-13");
+        assert_eq!(habit.current_streak(today), 3);
+        assert_eq!(habit.current_streak(date("2026-10-26")), 0);
+    }
+
+    #[test]
+    fn test_toggle_habit_day() {
+        let note = "# Hábitos\n\n```habit\nname: Leer\ndone: 2026-10-02\n```\n\n```habit\nname: Correr\n```\n";
+        let toggled = toggle_habit_day(note, 0, date("2026-10-01")).unwrap();
+        assert!(toggled.contains("done: 2026-10-01, 2026-10-02\n"));
+
+        let untoggled = toggle_habit_day(&toggled, 0, date("2026-10-01")).unwrap();
+        assert_eq!(untoggled, note);
+
+        // Sin línea `done:` se añade antes del cierre
+        let second = toggle_habit_day(note, 1, date("2026-10-05")).unwrap();
+        assert!(second.ends_with("name: Correr\ndone: 2026-10-05\n```\n"));
+        assert_eq!(toggle_habit_day(note, 2, date("2026-10-05")), None);
+    }
+
+    #[test]
+    fn test_render_habits() {
+        let note = "Antes\n```habit\nname: <Leer>\ndone: 2026-10-01\n```\nDespués\n\n```rust\n```habit\n```\n";
+        let html = render_habits(note, date("2026-10-02"));
+        assert!(html.starts_with("Antes\n<div class=\"habit\" data-habit=\"0\">"));
+        assert!(html.contains("&lt;Leer&gt;"));
+        assert!(html.contains(
+            r#"<button class="habit-day done" onclick="notifyRust('habit-toggle', 0, '2026-10-01')">1</button>"#
+        ));
+        assert!(html.contains(r#"<span class="habit-day future">3</span>"#));
+        // Octubre de 2026 empieza en jueves: tres huecos antes del día 1
+        assert_eq!(html.matches("habit-day empty").count(), 3);
+        assert!(html.contains("</div>\n\nDespués"));
+        // Dentro de otro bloque de código se deja tal cual
+        assert!(html.ends_with("```rust\n```habit\n```\n"));
+    }
+}
@@ -0,0 +1,263 @@
+//! Resaltados `==texto==` y sus variantes de color `=={red}texto==`
+//!
+//! Se muestran en el editor y en el preview, y el panel de anotaciones los
+//! reúne de todas las notas. Dentro de bloques y spans de código no cuentan.
+
+use regex::Regex;
+use std::ops::Range;
+use std::sync::LazyLock;
+
+/// `==texto==` o `=={color}texto==`, sin espacios en los extremos del texto
+static HIGHLIGHT_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"==(?:\{([a-z]+)\})?([^\s=](?:[^=\n]*[^\s=])?)==").unwrap());
+
+/// Color de un resaltado
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum HighlightColor {
+    #[default]
+    Yellow,
+    Red,
+    Green,
+    Blue,
+    Purple,
+}
+
+impl HighlightColor {
+    pub const ALL: [HighlightColor; 5] = [
+        Self::Yellow,
+        Self::Red,
+        Self::Green,
+        Self::Blue,
+        Self::Purple,
+    ];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|color| color.name() == name)
+    }
+
+    /// Nombre en la sintaxis (`=={red}...==`) y en las clases CSS
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Yellow => "yellow",
+            Self::Red => "red",
+            Self::Green => "green",
+            Self::Blue => "blue",
+            Self::Purple => "purple",
+        }
+    }
+
+    /// Clase CSS del preview y nombre del tag del editor
+    pub fn class_name(self) -> &'static str {
+        match self {
+            Self::Yellow => "highlight-yellow",
+            Self::Red => "highlight-red",
+            Self::Green => "highlight-green",
+            Self::Blue => "highlight-blue",
+            Self::Purple => "highlight-purple",
+        }
+    }
+
+    /// Color base (RGB); el editor y el preview lo usan semitransparente
+    pub fn rgb(self) -> (u8, u8, u8) {
+        match self {
+            Self::Yellow => (0xf9, 0xd9, 0x33),
+            Self::Red => (0xee, 0x55, 0x4f),
+            Self::Green => (0x73, 0xd2, 0x4d),
+            Self::Blue => (0x4d, 0x9a, 0xf2),
+            Self::Purple => (0xb3, 0x73, 0xf2),
+        }
+    }
+
+    /// Siguiente color al repetir el comando; tras el último se quita el resaltado
+    pub fn next(self) -> Option<Self> {
+        let index = Self::ALL.iter().position(|color| *color == self)?;
+        Self::ALL.get(index + 1).copied()
+    }
+}
+
+/// Un resaltado encontrado en una nota
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Highlight {
+    /// Rango en bytes de toda la sintaxis, marcas incluidas
+    pub range: Range<usize>,
+    /// Rango en bytes del texto resaltado
+    pub text_range: Range<usize>,
+    pub color: HighlightColor,
+    pub text: String,
+    /// Línea (desde 0) donde empieza
+    pub line: usize,
+}
+
+/// Resaltados del markdown en orden, fuera de bloques y spans de código.
+/// Un color desconocido (`=={rosa}x==`) no es un resaltado.
+pub fn find_highlights(markdown: &str) -> Vec<Highlight> {
+    if !markdown.contains("==") {
+        return Vec::new();
+    }
+
+    let mut result = Vec::new();
+    let mut in_code_block = false;
+    let mut offset = 0;
+
+    for (line_index, line) in markdown.split_inclusive('\n').enumerate() {
+        let line_offset = offset;
+        offset += line.len();
+
+        if line.trim_start().starts_with("```") || line.trim_start().starts_with("~~~") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block || !line.contains("==") {
+            continue;
+        }
+
+        let code_spans = code_spans(line);
+        for caps in HIGHLIGHT_RE.captures_iter(line) {
+            let (Some(whole), Some(text)) = (caps.get(0), caps.get(2)) else {
+                continue;
+            };
+            if code_spans
+                .iter()
+                .any(|span| whole.start() < span.end && span.start < whole.end())
+            {
+                continue;
+            }
+            let color = match caps.get(1) {
+                Some(name) => match HighlightColor::from_name(name.as_str()) {
+                    Some(color) => color,
+                    None => continue,
+                },
+                None => HighlightColor::Yellow,
+            };
+            result.push(Highlight {
+                range: line_offset + whole.start()..line_offset + whole.end(),
+                text_range: line_offset + text.start()..line_offset + text.end(),
+                color,
+                text: text.as_str().to_string(),
+                line: line_index,
+            });
+        }
+    }
+    result
+}
+
+/// Rangos de los spans de código `...` de una línea
+pub(crate) fn code_spans(line: &str) -> Vec<Range<usize>> {
+    let mut spans = Vec::new();
+    let mut open: Option<usize> = None;
+    for (index, ch) in line.char_indices() {
+        if ch == '`' {
+            match open.take() {
+                Some(start) => spans.push(start..index + 1),
+                None => open = Some(index),
+            }
+        }
+    }
+    spans
+}
+
+/// Sustituye los resaltados por `<mark>` para el preview. El texto de dentro
+/// sigue siendo markdown (negritas, links...).
+pub fn render_highlights(markdown: &str) -> String {
+    let highlights = find_highlights(markdown);
+    if highlights.is_empty() {
+        return markdown.to_string();
+    }
+
+    let mut output = String::with_capacity(markdown.len() + highlights.len() * 40);
+    let mut last = 0;
+    for highlight in highlights {
+        output.push_str(&markdown[last..highlight.range.start]);
+        output.push_str(&format!(
+            r#"<mark class="highlight {}">"#,
+            highlight.color.class_name()
+        ));
+        output.push_str(&highlight.text);
+        output.push_str("</mark>");
+        last = highlight.range.end;
+    }
+    output.push_str(&markdown[last..]);
+    output
+}
+
+/// Aplica el comando de resaltar a un texto seleccionado: lo resalta en
+/// amarillo, o si ya es un resaltado pasa al siguiente color o lo quita
+pub fn toggle_highlight(selection: &str) -> String {
+    let whole = find_highlights(selection)
+        .into_iter()
+        .find(|highlight| highlight.range == (0..selection.len()));
+
+    match whole {
+        Some(highlight) => match highlight.color.next() {
+            Some(color) => format!("=={{{}}}{}==", color.name(), highlight.text),
+            None => highlight.text,
+        },
+        None => {
+            // Los espacios de los extremos quedan fuera de las marcas
+            let trimmed = selection.trim();
+            if trimmed.is_empty() {
+                return selection.to_string();
+            }
+            let leading = &selection[..selection.len() - selection.trim_start().len()];
+            let trailing = &selection[selection.trim_end().len()..];
+            format!("{leading}=={trimmed}=={trailing}")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_highlights() {
+        let text = "Un ==dato clave== y =={red}un error==.\n\
+                    `==no==` ni == esto == ni =={rosa}esto==\n\
+                    ```\n==tampoco==\n```\n=={blue}último==";
+        let found = find_highlights(text);
+        let summary: Vec<_> = found
+            .iter()
+            .map(|h| (h.text.as_str(), h.color, h.line))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("dato clave", HighlightColor::Yellow, 0),
+                ("un error", HighlightColor::Red, 0),
+                ("último", HighlightColor::Blue, 5),
+            ]
+        );
+        assert_eq!(&text[found[1].range.clone()], "=={red}un error==");
+        assert_eq!(&text[found[2].text_range.clone()], "último");
+    }
+
+    #[test]
+    fn test_render_highlights() {
+        assert_eq!(
+            render_highlights("Texto ==**importante**== y =={green}bien=="),
+            r#"Texto <mark class="highlight highlight-yellow">**importante**</mark> y <mark class="highlight highlight-green">bien</mark>"#
+        );
+        assert_eq!(render_highlights("a == b"), "a == b");
+    }
+
+    #[test]
+    fn test_toggle_highlight_cycles_colors() {
+        let mut seen = vec![toggle_highlight(" frase ")];
+        let mut inner = "==frase==".to_string();
+        for _ in 0..5 {
+            inner = toggle_highlight(&inner);
+            seen.push(inner.clone());
+        }
+        assert_eq!(
+            seen,
+            [
+                " ==frase== ",
+                "=={red}frase==",
+                "=={green}frase==",
+                "=={blue}frase==",
+                "=={purple}frase==",
+                "frase",
+            ]
+        );
+    }
+}
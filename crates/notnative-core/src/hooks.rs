@@ -0,0 +1,343 @@
+//! Hooks de eventos: comandos de shell configurados por el usuario que se
+//! ejecutan al guardar, crear o borrar una nota o al saltar un recordatorio.
+//!
+//! Cada comando se lanza con `sh -c` en segundo plano. Los datos del evento
+//! llegan como variables de entorno (`NOTNATIVE_EVENT`, `NOTNATIVE_NOTE_PATH`...)
+//! y como JSON por la entrada estándar.
+
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+use tracing::{debug, error, info, warn};
+
+/// Tiempo máximo que puede tardar un hook antes de matarlo
+pub const HOOK_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Eventos a los que se puede enganchar un hook
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HookEvent {
+    NoteSaved,
+    NoteCreated,
+    NoteDeleted,
+    ReminderFired,
+}
+
+impl HookEvent {
+    pub const ALL: [HookEvent; 4] = [
+        HookEvent::NoteSaved,
+        HookEvent::NoteCreated,
+        HookEvent::NoteDeleted,
+        HookEvent::ReminderFired,
+    ];
+
+    /// Nombre estable del evento (configuración, entorno y plugins)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HookEvent::NoteSaved => "note_saved",
+            HookEvent::NoteCreated => "note_created",
+            HookEvent::NoteDeleted => "note_deleted",
+            HookEvent::ReminderFired => "reminder_fired",
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|event| event.as_str() == name)
+    }
+}
+
+/// Comando de shell asociado a un evento (`hooks` en la configuración)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventHook {
+    pub event: HookEvent,
+    /// Comando ejecutado con `sh -c`
+    pub command: String,
+    #[serde(default = "default_hook_enabled")]
+    pub enabled: bool,
+}
+
+fn default_hook_enabled() -> bool {
+    true
+}
+
+/// Datos de un evento que reciben los hooks
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct HookPayload {
+    pub event: Option<HookEvent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note_path: Option<PathBuf>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub folder: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reminder_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reminder_title: Option<String>,
+    /// Momento del evento (segundos Unix)
+    pub timestamp: i64,
+}
+
+impl HookPayload {
+    /// Evento sobre una nota. `content` se usa solo para extraer los tags.
+    pub fn note(
+        event: HookEvent,
+        name: &str,
+        path: &Path,
+        folder: Option<String>,
+        content: Option<&str>,
+    ) -> Self {
+        Self {
+            event: Some(event),
+            note_name: Some(name.to_string()),
+            note_path: Some(path.to_path_buf()),
+            folder,
+            tags: content
+                .map(super::frontmatter::extract_all_tags)
+                .unwrap_or_default(),
+            timestamp: chrono::Utc::now().timestamp(),
+            ..Default::default()
+        }
+    }
+
+    /// Recordatorio que acaba de saltar (con la nota asociada si la tiene)
+    pub fn reminder(id: Option<i64>, title: &str, note_name: Option<String>) -> Self {
+        Self {
+            event: Some(HookEvent::ReminderFired),
+            note_name,
+            reminder_id: id,
+            reminder_title: Some(title.to_string()),
+            timestamp: chrono::Utc::now().timestamp(),
+            ..Default::default()
+        }
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+
+    /// Variables de entorno con las que se lanza el comando
+    pub fn env_vars(&self) -> Vec<(&'static str, String)> {
+        let mut vars = vec![
+            (
+                "NOTNATIVE_EVENT",
+                self.event.map(|e| e.as_str()).unwrap_or("").to_string(),
+            ),
+            ("NOTNATIVE_TIMESTAMP", self.timestamp.to_string()),
+            ("NOTNATIVE_PAYLOAD", self.to_json().to_string()),
+        ];
+        if let Some(name) = &self.note_name {
+            vars.push(("NOTNATIVE_NOTE_NAME", name.clone()));
+        }
+        if let Some(path) = &self.note_path {
+            vars.push(("NOTNATIVE_NOTE_PATH", path.to_string_lossy().to_string()));
+        }
+        if let Some(folder) = &self.folder {
+            vars.push(("NOTNATIVE_NOTE_FOLDER", folder.clone()));
+        }
+        if !self.tags.is_empty() {
+            vars.push(("NOTNATIVE_NOTE_TAGS", self.tags.join(",")));
+        }
+        if let Some(id) = self.reminder_id {
+            vars.push(("NOTNATIVE_REMINDER_ID", id.to_string()));
+        }
+        if let Some(title) = &self.reminder_title {
+            vars.push(("NOTNATIVE_REMINDER_TITLE", title.clone()));
+        }
+        vars
+    }
+}
+
+/// Resultado de un hook ejecutado
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HookOutput {
+    /// `None` si se mató por superar el tiempo máximo
+    pub exit_code: Option<i32>,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+/// Lanza los hooks configurados. Se puede clonar y compartir entre hilos; al
+/// cambiar la configuración basta con `set_hooks`.
+#[derive(Debug, Clone, Default)]
+pub struct HookRunner {
+    hooks: Arc<RwLock<Vec<EventHook>>>,
+}
+
+impl HookRunner {
+    pub fn new(hooks: Vec<EventHook>) -> Self {
+        Self {
+            hooks: Arc::new(RwLock::new(hooks)),
+        }
+    }
+
+    pub fn set_hooks(&self, hooks: Vec<EventHook>) {
+        *self.hooks.write().unwrap_or_else(|e| e.into_inner()) = hooks;
+    }
+
+    /// Comandos activos para un evento
+    pub fn commands_for(&self, event: HookEvent) -> Vec<String> {
+        self.hooks
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .filter(|hook| hook.enabled && hook.event == event)
+            .map(|hook| hook.command.clone())
+            .collect()
+    }
+
+    /// Ejecuta en segundo plano los hooks del evento sin esperar a que terminen
+    pub fn fire(&self, payload: HookPayload) {
+        let Some(event) = payload.event else {
+            return;
+        };
+        for command in self.commands_for(event) {
+            let payload = payload.clone();
+            let spawned = std::thread::Builder::new()
+                .name("notnative-hook".to_string())
+                .spawn(move || match run_hook(&command, &payload, HOOK_TIMEOUT) {
+                    Ok(output) if output.exit_code == Some(0) => {
+                        debug!("Hook '{}' ({}) terminó", command, event.as_str());
+                    }
+                    Ok(output) => warn!(
+                        "Hook '{}' ({}) falló ({:?}): {}",
+                        command,
+                        event.as_str(),
+                        output.exit_code,
+                        output.stderr.trim()
+                    ),
+                    Err(e) => error!("No se pudo ejecutar el hook '{}': {}", command, e),
+                });
+            if let Err(e) = spawned {
+                error!("No se pudo lanzar el hilo del hook: {}", e);
+            }
+        }
+    }
+}
+
+/// Ejecuta un hook y espera su resultado (como mucho `timeout`)
+pub fn run_hook(
+    command: &str,
+    payload: &HookPayload,
+    timeout: Duration,
+) -> std::io::Result<HookOutput> {
+    info!("Ejecutando hook: {}", command);
+    let mut child = Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .envs(payload.env_vars())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    // El comando puede ignorar la entrada; un error de escritura no es fatal
+    if let Some(mut stdin) = child.stdin.take() {
+        let _ = stdin.write_all(payload.to_json().to_string().as_bytes());
+    }
+
+    // Leer la salida en paralelo para que un hook verboso no se bloquee
+    let stdout = read_in_background(child.stdout.take());
+    let stderr = read_in_background(child.stderr.take());
+
+    let started = Instant::now();
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(HookOutput {
+                exit_code: status.code(),
+                stdout: stdout.join().unwrap_or_default(),
+                stderr: stderr.join().unwrap_or_default(),
+            });
+        }
+        if started.elapsed() >= timeout {
+            warn!("Hook '{}' superó {:?}, se detiene", command, timeout);
+            let _ = child.kill();
+            let _ = child.wait();
+            // Los procesos hijos del comando pueden seguir con la salida abierta:
+            // no se espera a los lectores
+            return Ok(HookOutput {
+                exit_code: None,
+                stdout: String::new(),
+                stderr: String::new(),
+            });
+        }
+        std::thread::sleep(Duration::from_millis(20));
+    }
+}
+
+fn read_in_background(pipe: Option<impl Read + Send + 'static>) -> std::thread::JoinHandle<String> {
+    std::thread::spawn(move || {
+        let mut output = String::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_string(&mut output);
+        }
+        output
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hook_config_serde() {
+        let hooks: Vec<EventHook> = serde_json::from_str(
+            r#"[{"event": "note_saved", "command": "echo hola"},
+                {"event": "reminder_fired", "command": "true", "enabled": false}]"#,
+        )
+        .unwrap();
+        assert_eq!(hooks[0].event, HookEvent::NoteSaved);
+        assert!(hooks[0].enabled);
+        assert!(!hooks[1].enabled);
+
+        let runner = HookRunner::new(hooks);
+        assert_eq!(runner.commands_for(HookEvent::NoteSaved), vec!["echo hola"]);
+        assert!(runner.commands_for(HookEvent::ReminderFired).is_empty());
+        assert_eq!(
+            HookEvent::parse("note_deleted"),
+            Some(HookEvent::NoteDeleted)
+        );
+    }
+
+    #[test]
+    fn test_run_hook_receives_env_and_json() {
+        let payload = HookPayload::note(
+            HookEvent::NoteSaved,
+            "Proyectos/Blog",
+            Path::new("/tmp/notas/Proyectos/Blog.md"),
+            Some("Proyectos".to_string()),
+            Some("Publicar #blog"),
+        );
+
+        let output = run_hook(
+            r#"echo "$NOTNATIVE_EVENT|$NOTNATIVE_NOTE_NAME|$NOTNATIVE_NOTE_TAGS"; cat"#,
+            &payload,
+            HOOK_TIMEOUT,
+        )
+        .unwrap();
+        assert_eq!(output.exit_code, Some(0));
+
+        let mut lines = output.stdout.lines();
+        assert_eq!(lines.next(), Some("note_saved|Proyectos/Blog|blog"));
+        let json: serde_json::Value = serde_json::from_str(lines.next().unwrap()).unwrap();
+        assert_eq!(json["event"], "note_saved");
+        assert_eq!(json["note_path"], "/tmp/notas/Proyectos/Blog.md");
+        assert_eq!(json["folder"], "Proyectos");
+    }
+
+    #[test]
+    fn test_run_hook_timeout_and_failure() {
+        let payload = HookPayload::reminder(Some(7), "Llamar", None);
+
+        let failed = run_hook("exit 3", &payload, HOOK_TIMEOUT).unwrap();
+        assert_eq!(failed.exit_code, Some(3));
+
+        let killed = run_hook("sleep 5", &payload, Duration::from_millis(100)).unwrap();
+        assert_eq!(killed.exit_code, None);
+    }
+}
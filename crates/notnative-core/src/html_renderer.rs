@@ -0,0 +1,2867 @@
+//! Módulo de renderizado Markdown → HTML para el modo Normal (preview)
+//!
+//! Convierte el contenido Markdown a HTML completo con:
+//! - Checkboxes interactivos para TODOs
+//! - Links internos [[nota]] clickeables
+//! - Syntax highlighting en code blocks (highlight.js)
+//! - Soporte para tema claro/oscuro
+
+use crate::tag_tree::tag_color;
+use crate::tasks::TaskState;
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd, html};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
+use std::sync::LazyLock;
+
+// ============================================================================
+// REGEX ESTÁTICOS - Compilados una sola vez para mejor rendimiento
+// ============================================================================
+
+/// Regex para detectar contenido entre corchetes [algo]
+static BRACKET_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\[([^\]]+)\]").unwrap());
+
+/// Regex para parsear pares key::value o key:::value
+static PROP_PAIR_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"([^,:\s]+)(:::?)([^,]*)").unwrap());
+
+/// Regex para links internos [[nota]]
+static INTERNAL_LINK_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\[\[([^\]]+)\]\]").unwrap());
+
+/// Regex para tags #tag y anidados #padre/hijo
+static TAG_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?m)(^|[\s\(\[,])#([a-zA-Z][a-zA-Z0-9_-]*(?:/[a-zA-Z0-9_-]+)*)").unwrap()
+});
+
+/// Regex para YouTube watch URLs
+static YOUTUBE_WATCH_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"https?://(?:www\.)?youtube\.com/watch\?v=([a-zA-Z0-9_-]{11})").unwrap()
+});
+
+/// Regex para YouTube short URLs (youtu.be)
+static YOUTUBE_SHORT_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"https?://youtu\.be/([a-zA-Z0-9_-]{11})").unwrap());
+
+/// Regex para YouTube Shorts
+static YOUTUBE_SHORTS_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"https?://(?:www\.)?youtube\.com/shorts/([a-zA-Z0-9_-]{11})").unwrap()
+});
+
+/// Videos embebidos cargados a la vez en el preview si no se configura otro límite
+pub const DEFAULT_EMBED_BUDGET: usize = 3;
+
+/// Marcador de un video de YouTube: el iframe lo crea el JavaScript del preview
+/// cuando el video entra en pantalla (ver `manageEmbeds`)
+fn youtube_placeholder(video_id: &str) -> String {
+    format!(
+        r#"<div class="youtube-embed" data-video-id="{id}" style="background-image: url('https://i.ytimg.com/vi/{id}/hqdefault.jpg')"><button class="youtube-play" title="Reproducir">▶</button></div>"#,
+        id = video_id
+    )
+}
+
+/// Regex para recordatorios con emoji
+static REMINDER_EMOJI_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?m)^(📅|⏰|🔔)\s*(.+)$").unwrap());
+
+/// Regex para !!RECORDAR/!!REMIND
+static RECORDAR_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"!!(?:RECORDAR|REMIND)\(([^,]+),\s*([^)]+)\)").unwrap());
+
+/// Regex para checkboxes deshabilitados en HTML, con el estado extendido
+/// (ver `mark_task_states`) que pueda seguirles
+static CHECKBOX_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"<input\s+[^>]*(?:type\s*=\s*["']checkbox["'][^>]*disabled|disabled[^>]*type\s*=\s*["']checkbox["'])[^>]*/?\s*>(?:\s*<span class="task-state" data-state="([a-z-]+)"></span>)?"#).unwrap()
+});
+
+/// Marca con el estado extendido de una tarea (`[/]`, `[-]`)
+static TASK_STATE_TAG_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"^<span class="task-state" data-state="([a-z-]+)">$"#).unwrap());
+
+/// Regex para links internos en HTML
+static INTERNAL_LINK_HTML_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"<a href="notnative://note/([^"]+)">([^<]+)</a>"#).unwrap());
+
+/// Regex para links de tags en HTML
+static TAG_LINK_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"<a href="notnative://tag/([^"]+)">([^<]+)</a>"#).unwrap());
+
+/// Regex para imágenes en HTML
+static IMG_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"<img src="([^"]+)""#).unwrap());
+
+/// Regex para imágenes con opt-in/opt-out de ajuste en modo oscuro: ![alt](src){.no-invert}
+static IMG_ADJUST_ATTR_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"!\[([^\]]*)\]\(([^)\s]+)(?:\s+"[^"]*")?\)\{\.(no-invert|invert)\}"#).unwrap()
+});
+
+/// Sustituye `![alt](src){.no-invert}` y `{.invert}` por `<img>` con la clase,
+/// salvo dentro de bloques y spans de código (ahí se ven tal cual)
+fn render_image_adjust_attrs(markdown: &str) -> String {
+    if !markdown.contains("invert}") {
+        return markdown.to_string();
+    }
+
+    let mut output = String::with_capacity(markdown.len());
+    let mut in_code_block = false;
+    for line in markdown.split_inclusive('\n') {
+        if line.trim_start().starts_with("```") || line.trim_start().starts_with("~~~") {
+            in_code_block = !in_code_block;
+            output.push_str(line);
+            continue;
+        }
+        if in_code_block || !line.contains("invert}") {
+            output.push_str(line);
+            continue;
+        }
+
+        let code_spans = crate::highlights::code_spans(line);
+        let mut last = 0;
+        for caps in IMG_ADJUST_ATTR_RE.captures_iter(line) {
+            let Some(whole) = caps.get(0) else {
+                continue;
+            };
+            if code_spans
+                .iter()
+                .any(|span| whole.start() < span.end && span.start < whole.end())
+            {
+                continue;
+            }
+            output.push_str(&line[last..whole.start()]);
+            output.push_str(&format!(
+                r#"<img src="{}" alt="{}" class="{}">"#,
+                &caps[2],
+                caps[1].replace('"', "&quot;"),
+                &caps[3]
+            ));
+            last = whole.end();
+        }
+        output.push_str(&line[last..]);
+    }
+    output
+}
+
+/// Decodifica una cadena URL-encoded (percent-encoded)
+fn url_decode(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == '%' {
+            // Intentar leer dos caracteres hexadecimales
+            let hex: String = chars.by_ref().take(2).collect();
+            if hex.len() == 2 {
+                if let Ok(byte) = u8::from_str_radix(&hex, 16) {
+                    // Para UTF-8, necesitamos acumular bytes
+                    let mut bytes = vec![byte];
+
+                    // Verificar si hay más bytes UTF-8
+                    while chars.peek() == Some(&'%') {
+                        chars.next(); // consumir '%'
+                        let next_hex: String = chars.by_ref().take(2).collect();
+                        if next_hex.len() == 2 {
+                            if let Ok(next_byte) = u8::from_str_radix(&next_hex, 16) {
+                                if next_byte & 0xC0 == 0x80 {
+                                    // Es un byte de continuación UTF-8
+                                    bytes.push(next_byte);
+                                } else {
+                                    // No es continuación, devolver al flujo
+                                    result.push_str(&format!("%{}", next_hex));
+                                    break;
+                                }
+                            }
+                        }
+                    }
+
+                    if let Ok(decoded) = String::from_utf8(bytes) {
+                        result.push_str(&decoded);
+                    } else {
+                        result.push(c);
+                        result.push_str(&hex);
+                    }
+                } else {
+                    result.push(c);
+                    result.push_str(&hex);
+                }
+            } else {
+                result.push(c);
+                result.push_str(&hex);
+            }
+        } else if c == '+' {
+            result.push(' ');
+        } else {
+            result.push(c);
+        }
+    }
+
+    result
+}
+
+/// Tema de colores para el preview
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreviewTheme {
+    Light,
+    Dark,
+}
+
+impl Default for PreviewTheme {
+    fn default() -> Self {
+        PreviewTheme::Dark
+    }
+}
+
+/// Espaciado del preview
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum PreviewDensity {
+    #[default]
+    Comfortable,
+    /// Menos margen y espacio entre bloques: cabe más en pantalla
+    Compact,
+}
+
+/// ID de un encabezado para enlazarlo con `#id`
+/// Convierte "Conexión al MCP Server" → "conexión-al-mcp-server"
+/// (con `{#id}` al final manda ese ID, y `{.clase}` no cuenta)
+pub fn heading_id(text: &str) -> String {
+    let (text, attributes) = crate::block_attrs::split_attributes(text);
+    if let Some(id) = attributes.and_then(|attributes| attributes.id) {
+        return id;
+    }
+    text.to_lowercase()
+        .chars()
+        .filter_map(|c| {
+            if c.is_alphanumeric() {
+                Some(c)
+            } else if c.is_whitespace() || c == '-' {
+                Some('-')
+            } else {
+                // Eliminar caracteres especiales
+                None
+            }
+        })
+        .collect::<String>()
+        // Eliminar guiones duplicados
+        .split('-')
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Colores dinámicos para el preview (extraídos del tema GTK)
+#[derive(Debug, Clone)]
+pub struct PreviewColors {
+    pub bg_primary: String,
+    pub bg_secondary: String,
+    pub bg_tertiary: String,
+    pub fg_primary: String,
+    pub fg_secondary: String,
+    pub fg_muted: String,
+    pub accent: String,
+    pub border: String,
+}
+
+impl Default for PreviewColors {
+    fn default() -> Self {
+        // Colores Catppuccin Mocha por defecto
+        Self {
+            bg_primary: "#1e1e2e".to_string(),
+            bg_secondary: "#313244".to_string(),
+            bg_tertiary: "#45475a".to_string(),
+            fg_primary: "#cdd6f4".to_string(),
+            fg_secondary: "#a6adc8".to_string(),
+            fg_muted: "#6c7086".to_string(),
+            accent: "#89b4fa".to_string(),
+            border: "#45475a".to_string(),
+        }
+    }
+}
+
+impl PreviewColors {
+    /// Indica si el fondo principal es oscuro (luminancia relativa < 0.5)
+    pub fn is_dark(&self) -> bool {
+        let hex = self.bg_primary.trim().trim_start_matches('#');
+        let channel = |i: usize| {
+            hex.get(i..i + 2)
+                .and_then(|c| u8::from_str_radix(c, 16).ok())
+                .unwrap_or(0) as f64
+        };
+        let luminance = (0.299 * channel(0) + 0.587 * channel(2) + 0.114 * channel(4)) / 255.0;
+        luminance < 0.5
+    }
+}
+
+/// Renderer de Markdown a HTML
+pub struct HtmlRenderer {
+    theme: PreviewTheme,
+    base_path: Option<PathBuf>, // Directorio base para resolver rutas relativas de imágenes
+    colors: Option<PreviewColors>, // Colores dinámicos del tema GTK
+    adjust_images: bool,        // Invertir imágenes claras (diagramas/capturas) en modo oscuro
+    embed_budget: usize,        // Máximo de videos embebidos cargados a la vez
+    tag_colors: HashMap<String, String>, // Colores elegidos para los tags
+    source_map: bool,           // Marcar el origen de cada bloque para editarlo en el preview
+    heading_ids: bool,          // ID en cada encabezado, destino de los enlaces @Nota#encabezado
+    density: PreviewDensity,    // Espaciado (cómodo o compacto)
+    heading_anchors: bool,      // Enlace # visible junto a cada encabezado
+    print_friendly: bool,       // Sin embeds ni controles, en blanco y negro
+    custom_css: Option<String>, // CSS propio del usuario, tras el del tema
+}
+
+impl Default for HtmlRenderer {
+    fn default() -> Self {
+        Self::new(PreviewTheme::default())
+    }
+}
+
+impl HtmlRenderer {
+    pub fn new(theme: PreviewTheme) -> Self {
+        Self {
+            theme,
+            base_path: None,
+            colors: None,
+            adjust_images: false,
+            embed_budget: DEFAULT_EMBED_BUDGET,
+            tag_colors: HashMap::new(),
+            source_map: false,
+            heading_ids: false,
+            density: PreviewDensity::default(),
+            heading_anchors: false,
+            print_friendly: false,
+            custom_css: None,
+        }
+    }
+
+    /// Crea un renderer con un directorio base para resolver imágenes
+    pub fn with_base_path(theme: PreviewTheme, base_path: PathBuf) -> Self {
+        Self {
+            theme,
+            base_path: Some(base_path),
+            colors: None,
+            adjust_images: false,
+            embed_budget: DEFAULT_EMBED_BUDGET,
+            tag_colors: HashMap::new(),
+            source_map: false,
+            heading_ids: false,
+            density: PreviewDensity::default(),
+            heading_anchors: false,
+            print_friendly: false,
+            custom_css: None,
+        }
+    }
+
+    /// Crea un renderer con colores dinámicos del tema GTK
+    pub fn with_colors(theme: PreviewTheme, base_path: PathBuf, colors: PreviewColors) -> Self {
+        Self {
+            theme,
+            base_path: Some(base_path),
+            colors: Some(colors),
+            adjust_images: false,
+            embed_budget: DEFAULT_EMBED_BUDGET,
+            tag_colors: HashMap::new(),
+            source_map: false,
+            heading_ids: false,
+            density: PreviewDensity::default(),
+            heading_anchors: false,
+            print_friendly: false,
+            custom_css: None,
+        }
+    }
+
+    /// Establece los colores dinámicos
+    pub fn set_colors(&mut self, colors: PreviewColors) {
+        self.colors = Some(colors);
+    }
+
+    /// Activa el ajuste automático de imágenes claras en modo oscuro
+    pub fn set_adjust_images(&mut self, enabled: bool) {
+        self.adjust_images = enabled;
+    }
+
+    /// Establece cuántos videos embebidos pueden estar cargados a la vez
+    pub fn set_embed_budget(&mut self, budget: usize) {
+        self.embed_budget = budget;
+    }
+
+    /// Establece los colores elegidos para los tags (el resto usa la paleta)
+    pub fn set_tag_colors(&mut self, tag_colors: HashMap<String, String>) {
+        self.tag_colors = tag_colors;
+    }
+
+    /// Marca cada párrafo, encabezado, elemento de lista y celda con su rango
+    /// en el markdown, para la edición en el preview (ver `preview_edit`)
+    pub fn set_source_map(&mut self, enabled: bool) {
+        self.source_map = enabled;
+    }
+
+    /// Da a cada encabezado su ID (`heading_id`) para saltar a él desde un
+    /// enlace `@Nota#encabezado`
+    pub fn set_heading_ids(&mut self, enabled: bool) {
+        self.heading_ids = enabled;
+    }
+
+    /// Establece el espaciado del preview
+    pub fn set_density(&mut self, density: PreviewDensity) {
+        self.density = density;
+    }
+
+    /// Muestra un enlace `#` junto a cada encabezado (y les da ID para enlazarlos)
+    pub fn set_heading_anchors(&mut self, enabled: bool) {
+        self.heading_anchors = enabled;
+    }
+
+    /// Estilo para imprimir: sin embeds ni controles, texto oscuro sobre blanco
+    pub fn set_print_friendly(&mut self, enabled: bool) {
+        self.print_friendly = enabled;
+    }
+
+    /// CSS propio que se añade tras el del tema (para las clases de
+    /// `{.clase}` y `::: clase`, ver `block_attrs`)
+    pub fn set_custom_css(&mut self, css: Option<String>) {
+        self.custom_css = css;
+    }
+
+    /// URI base para cargar el HTML (`file://` del directorio de las notas).
+    /// Con un origen `file://` las imágenes locales son del mismo origen que la
+    /// página y `detectLightImages` puede leer sus píxeles; cargado sin base el
+    /// canvas queda contaminado y el análisis falla siempre.
+    pub fn base_uri(&self) -> Option<String> {
+        let base = self.base_path.as_ref()?;
+        let mut uri = String::from("file://");
+        for byte in base.to_string_lossy().bytes() {
+            match byte {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'/' | b'-' | b'_' | b'.' | b'~' => {
+                    uri.push(byte as char)
+                }
+                _ => uri.push_str(&format!("%{:02X}", byte)),
+            }
+        }
+        if !uri.ends_with('/') {
+            uri.push('/');
+        }
+        Some(uri)
+    }
+
+    /// Determina si el preview se muestra con fondo oscuro
+    fn is_dark_preview(&self) -> bool {
+        match self.colors {
+            Some(ref colors) => colors.is_dark(),
+            None => self.theme == PreviewTheme::Dark,
+        }
+    }
+
+    /// Renderiza Markdown a HTML completo (documento completo con estilos)
+    pub fn render(&self, markdown: &str) -> String {
+        let body_html = self.render_body(markdown);
+        self.wrap_in_document(&body_html)
+    }
+
+    /// Renderiza solo el body HTML (sin wrapper del documento)
+    pub fn render_body(&self, markdown: &str) -> String {
+        // Pre-procesar para TODOs y links internos
+        let processed = self.preprocess_markdown(markdown);
+
+        let parser = Parser::new_ext(&processed, crate::preview_edit::parser_options());
+        let events: Vec<Event> = if self.source_map {
+            let events: Vec<_> = parser.into_offset_iter().collect();
+            let map = crate::preview_edit::source_map(markdown, &events);
+            Self::insert_source_markers(events, &map, markdown)
+        } else {
+            parser.collect()
+        };
+
+        // Procesar eventos para añadir atributos custom
+        let parser = self.process_events(events);
+
+        // Generar HTML
+        let mut html_output = String::new();
+        html::push_html(&mut html_output, parser.into_iter());
+
+        // Post-procesar para añadir data attributes y handlers
+        self.postprocess_html(&html_output)
+    }
+
+    /// Pre-procesa el markdown para convertir sintaxis custom
+    fn preprocess_markdown(&self, markdown: &str) -> String {
+        // Hábitos ```habit: su calendario, antes de que nada toque el bloque
+        let mut result = crate::habits::render_habits(markdown, chrono::Local::now().date_naive());
+        // Bloques ::: clase y callouts > [!tipo]: antes de que se toquen los corchetes
+        result = crate::block_attrs::render_fenced_divs(&result);
+        result = crate::callouts::render_callouts(&result);
+        result = Self::mark_task_states(&result);
+        result = crate::highlights::render_highlights(&result);
+
+        // Procesar propiedades inline [campo::valor] y [campo:::valor]
+        // También soporta grupos: [campo1::val1, campo2:::val2]
+        // Procesamos línea por línea para preservar saltos de línea
+        let processed_lines: Vec<String> = result.lines().map(|line| {
+            // Verificar si la línea contiene algo que parece propiedad inline
+            if line.contains("::") && line.contains('[') {
+                let processed = BRACKET_RE.replace_all(line, |caps: &regex::Captures| {
+                    let content = &caps[1];
+
+                    // Verificar si contiene :: (es una propiedad inline)
+                    if !content.contains("::") {
+                        // No es propiedad inline, devolver original
+                        return format!("[{}]", content);
+                    }
+
+                    // Procesar cada par key::value o key:::value separado por comas
+                    let mut html_parts: Vec<String> = Vec::new();
+                    let mut has_visible = false;
+
+                    for prop_cap in PROP_PAIR_RE.captures_iter(content) {
+                        let key = prop_cap.get(1).map(|m| m.as_str()).unwrap_or("");
+                        let separator = prop_cap.get(2).map(|m| m.as_str()).unwrap_or("::");
+                        let value = prop_cap.get(3).map(|m| m.as_str().trim()).unwrap_or("");
+                        let is_hidden = separator == ":::";
+
+                        if !is_hidden {
+                            has_visible = true;
+                            html_parts.push(format!(
+                                r#"<span class="inline-property"><span class="prop-key">{}</span><span class="prop-value">{}</span></span>"#,
+                                key.trim(), value
+                            ));
+                        }
+                        // Las propiedades ocultas simplemente no se agregan
+                    }
+
+                    if has_visible {
+                        html_parts.join(" ")
+                    } else {
+                        // Todo oculto, no mostrar nada
+                        String::new()
+                    }
+                }).to_string();
+
+                // Si la línea procesada solo contiene spans de propiedades (y espacios),
+                // envolverla en un div para forzar que sea su propia línea
+                let trimmed = processed.trim();
+                if trimmed.starts_with("<span class=\"inline-property\"") ||
+                   trimmed.starts_with("<div") ||
+                   trimmed.is_empty() {
+                    format!("<div class=\"inline-props-line\">{}</div>", processed)
+                } else {
+                    processed
+                }
+            } else {
+                line.to_string()
+            }
+        }).collect();
+
+        result = processed_lines.join("\n");
+
+        // Imágenes con atributo {.no-invert} / {.invert}: emitir <img> con la clase
+        // (la ruta se resuelve después en postprocess_html igual que el resto)
+        result = render_image_adjust_attrs(&result);
+
+        // Convertir [[nota]] a links especiales (placeholder que post-procesaremos)
+        // URL-encode el nombre para manejar espacios y caracteres especiales
+        result = INTERNAL_LINK_RE
+            .replace_all(&result, |caps: &regex::Captures| {
+                let note_name = &caps[1];
+                let encoded_name = note_name.replace(' ', "%20");
+                format!(r#"[{}](notnative://note/{})"#, note_name, encoded_name)
+            })
+            .to_string();
+
+        // Convertir #tags a links clickeables (pero no # de headings)
+        // Patrón: # seguido de letras/números/guiones, precedido por espacio o inicio de línea
+        result = TAG_RE
+            .replace_all(&result, r#"$1[#$2](notnative://tag/$2)"#)
+            .to_string();
+
+        // NOTA: Ya no convertimos items de lista (- palabra) a tags automáticamente
+        // Los tags deben tener # explícito: #tag
+
+        // Embeber videos de YouTube como iframes usando regex estáticos
+        // YouTube watch URLs
+        result = YOUTUBE_WATCH_RE
+            .replace_all(&result, |caps: &regex::Captures| {
+                let video_id = &caps[1];
+                youtube_placeholder(video_id)
+            })
+            .to_string();
+
+        // YouTube short URLs (youtu.be)
+        result = YOUTUBE_SHORT_RE
+            .replace_all(&result, |caps: &regex::Captures| {
+                let video_id = &caps[1];
+                youtube_placeholder(video_id)
+            })
+            .to_string();
+
+        // YouTube Shorts
+        result = YOUTUBE_SHORTS_RE
+            .replace_all(&result, |caps: &regex::Captures| {
+                let video_id = &caps[1];
+                youtube_placeholder(video_id)
+            })
+            .to_string();
+
+        // Convertir recordatorios con formato especial
+        // Detectar patrones como: 📅 2025-01-15 10:00 - Recordatorio texto
+        // o: ⏰ mañana 9:00 - Recordatorio texto
+        result = REMINDER_EMOJI_RE
+            .replace_all(&result, r#"<span class="reminder">$1 $2</span>"#)
+            .to_string();
+
+        // Convertir recordatorios con formato !!RECORDAR(fecha prioridad repeat, texto) o !!REMIND(...)
+        // Formato V2: !!RECORDAR(2025-11-28 10:00 medium repeat=daily, Texto del recordatorio)
+        result = RECORDAR_RE
+            .replace_all(&result, |caps: &regex::Captures| {
+                let params = caps[1].trim();
+                let text = caps[2].trim();
+
+                // Parsear los parámetros para extraer fecha, prioridad y repetición
+                let parts: Vec<&str> = params.split_whitespace().collect();
+
+                // Detectar fecha (formato YYYY-MM-DD o palabras como "hoy", "mañana")
+                let mut date_parts: Vec<&str> = Vec::new();
+                let mut priority = "";
+                let mut repeat = "";
+
+                for part in parts.iter() {
+                    if part.starts_with("repeat=") || part.starts_with("repetir=") {
+                        repeat = part.split('=').nth(1).unwrap_or("");
+                    } else if *part == "low" || *part == "medium" || *part == "high" || *part == "urgent"
+                             || *part == "baja" || *part == "media" || *part == "alta" || *part == "urgente" {
+                        priority = part;
+                    } else {
+                        date_parts.push(part);
+                    }
+                }
+
+                let date_str = date_parts.join(" ");
+
+                // Determinar el icono basado en la prioridad
+                let (icon, priority_class) = match priority {
+                    "urgent" | "urgente" => ("🔴", "priority-urgent"),
+                    "high" | "alta" => ("🟠", "priority-high"),
+                    "medium" | "media" => ("🟡", "priority-medium"),
+                    "low" | "baja" => ("🟢", "priority-low"),
+                    _ => ("🔔", "priority-normal"),
+                };
+
+                // Construir información de repetición si existe
+                let repeat_info = if !repeat.is_empty() {
+                    format!(r#" <span class="reminder-repeat">🔄 {}</span>"#, repeat)
+                } else {
+                    String::new()
+                };
+
+                format!(
+                    r#"<div class="reminder-widget {}"><span class="reminder-icon">{}</span><span class="reminder-date">📅 {}</span><span class="reminder-text">{}</span>{}</div>"#,
+                    priority_class, icon, date_str, text, repeat_info
+                )
+            })
+            .to_string();
+
+        result
+    }
+
+    /// pulldown-cmark solo reconoce `[ ]` y `[x]`: las tareas en progreso
+    /// (`[/]`) y canceladas (`[-]`) se convierten en `[ ]` seguidas de una
+    /// marca vacía con su estado, que `postprocess_html` lleva al checkbox
+    fn mark_task_states(markdown: &str) -> String {
+        if !markdown.contains("[/]") && !markdown.contains("[-]") {
+            return markdown.to_string();
+        }
+
+        let mut in_code_block = false;
+        let lines: Vec<String> = markdown
+            .lines()
+            .map(|line| {
+                if line.trim_start().starts_with("```") {
+                    in_code_block = !in_code_block;
+                }
+                let task = if in_code_block {
+                    None
+                } else {
+                    crate::tasks::parse_task_line(line)
+                };
+                match task {
+                    Some((marker, state))
+                        if matches!(state, TaskState::InProgress | TaskState::Cancelled) =>
+                    {
+                        let before: String = line.chars().take(marker).collect();
+                        let after: String = line.chars().skip(marker + 2).collect();
+                        format!(
+                            "{} ] <span class=\"task-state\" data-state=\"{}\"></span>{}",
+                            before,
+                            state.name(),
+                            after.trim_start()
+                        )
+                    }
+                    _ => line.to_string(),
+                }
+            })
+            .collect();
+
+        let mut result = lines.join("\n");
+        if markdown.ends_with('\n') {
+            result.push('\n');
+        }
+        result
+    }
+
+    /// Pone tras la apertura de cada bloque emparejado una marca vacía con su
+    /// rango y su línea en el markdown; el script los pasa al bloque y la quita
+    fn insert_source_markers<'a>(
+        events: Vec<(Event<'a>, std::ops::Range<usize>)>,
+        map: &[(usize, std::ops::Range<usize>)],
+        markdown: &str,
+    ) -> Vec<Event<'a>> {
+        let mut map = map.iter().peekable();
+        let mut output = Vec::with_capacity(events.len() + map.len());
+        // Los bloques van en orden: las líneas se cuentan desde el anterior
+        let (mut counted, mut line) = (0, 1);
+        for (index, (event, _)) in events.into_iter().enumerate() {
+            output.push(event);
+            if let Some((_, range)) = map.next_if(|(start, _)| *start == index) {
+                if range.start >= counted {
+                    line +=
+                        crate::preview_edit::line_at(&markdown[counted..range.start], usize::MAX)
+                            - 1;
+                } else {
+                    line = crate::preview_edit::line_at(markdown, range.start);
+                }
+                counted = range.start;
+                output.push(Event::InlineHtml(
+                    format!(
+                        r#"<span class="source-map" data-source="{}-{}" data-line="{}"></span>"#,
+                        range.start, range.end, line
+                    )
+                    .into(),
+                ));
+            }
+        }
+        output
+    }
+
+    /// Procesa eventos del parser para personalizar el output
+    fn process_events<'a>(&self, events: Vec<Event<'a>>) -> Vec<Event<'a>> {
+        let events = Self::insert_task_progress(events);
+        let events = if self.heading_ids || self.heading_anchors {
+            Self::insert_heading_anchors(events, self.heading_anchors)
+        } else {
+            events
+        };
+
+        // Notas al pie numeradas, con enlaces de vuelta y popup al pasar el ratón
+        crate::footnotes::render_footnotes(events)
+    }
+
+    /// Da a cada encabezado su ID, para los enlaces `@Nota#encabezado`, y si
+    /// `visible` un enlace `#` al final
+    fn insert_heading_anchors(events: Vec<Event<'_>>, visible: bool) -> Vec<Event<'_>> {
+        let mut output = Vec::with_capacity(events.len());
+        // Posición en `output` del encabezado abierto y su texto
+        let mut heading: Option<(usize, String)> = None;
+        for event in events {
+            match &event {
+                Event::Start(Tag::Heading { .. }) => {
+                    heading = Some((output.len(), String::new()));
+                }
+                Event::Text(text) | Event::Code(text) => {
+                    if let Some((_, heading_text)) = heading.as_mut() {
+                        heading_text.push_str(text);
+                    }
+                }
+                Event::End(TagEnd::Heading(_)) => {
+                    if let Some((start, text)) = heading.take() {
+                        let mut id = heading_id(&text);
+                        if !id.is_empty() {
+                            // Un `{#id}` escrito en el encabezado tiene preferencia
+                            if let Event::Start(Tag::Heading { id: heading_id, .. }) =
+                                &mut output[start]
+                            {
+                                id = heading_id
+                                    .get_or_insert_with(|| id.clone().into())
+                                    .to_string();
+                            }
+                            if visible {
+                                output.push(Event::InlineHtml(
+                                    format!(
+                                        r##"<a class="heading-anchor" href="#{}" aria-hidden="true">#</a>"##,
+                                        id
+                                    )
+                                    .into(),
+                                ));
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+            output.push(event);
+        }
+        output
+    }
+
+    /// Añade una barra de progreso bajo cada encabezado cuya sección (hasta el
+    /// siguiente encabezado) contiene checkboxes
+    fn insert_task_progress(events: Vec<Event<'_>>) -> Vec<Event<'_>> {
+        // (índice tras el fin del encabezado, completadas, total) de cada sección
+        let mut sections: Vec<(usize, usize, usize)> = Vec::new();
+        for (index, event) in events.iter().enumerate() {
+            match event {
+                Event::End(TagEnd::Heading(_)) => sections.push((index + 1, 0, 0)),
+                Event::TaskListMarker(checked) => {
+                    // Las canceladas no cuentan para el progreso
+                    let cancelled = matches!(
+                        events.get(index + 1),
+                        Some(Event::InlineHtml(html)) if TASK_STATE_TAG_RE
+                            .captures(html)
+                            .is_some_and(|caps| &caps[1] == TaskState::Cancelled.name())
+                    );
+                    if cancelled {
+                        continue;
+                    }
+                    if let Some((_, completed, total)) = sections.last_mut() {
+                        *total += 1;
+                        if *checked {
+                            *completed += 1;
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let mut sections = sections
+            .into_iter()
+            .filter(|(_, _, total)| *total > 0)
+            .peekable();
+        if sections.peek().is_none() {
+            return events;
+        }
+
+        let mut output = Vec::with_capacity(events.len());
+        for (index, event) in events.into_iter().enumerate() {
+            if let Some((_, completed, total)) = sections.next_if(|(at, _, _)| *at == index) {
+                output.push(Event::Html(task_progress_html(completed, total).into()));
+            }
+            output.push(event);
+        }
+        output
+    }
+
+    /// Post-procesa el HTML para añadir interactividad
+    fn postprocess_html(&self, html: &str) -> String {
+        let mut result = html.to_string();
+
+        // Añadir data-line a los checkboxes de tareas y hacerlos interactivos
+        // pulldown-cmark puede generar varios formatos - usamos CHECKBOX_RE estático
+
+        let mut line_counter = 0;
+
+        // Reemplazar todos los checkboxes deshabilitados con versiones interactivas
+        result = CHECKBOX_RE
+            .replace_all(&result, |caps: &regex::Captures| {
+                line_counter += 1;
+                let original = caps.get(0).map(|m| m.as_str()).unwrap_or("");
+                let is_checked = original.contains("checked");
+                let checked_attr = if is_checked { " checked" } else { "" };
+                let state = match caps.get(1) {
+                    Some(state) => state.as_str(),
+                    None if is_checked => TaskState::Done.name(),
+                    None => TaskState::Open.name(),
+                };
+                format!(
+                    r#"<input type="checkbox" class="todo-checkbox" data-line="{}" data-state="{}" onclick="handleTodoClick(event, {}, this.checked)"{}>"#,
+                    line_counter, state, line_counter, checked_attr
+                )
+            })
+            .to_string();
+
+        // Convertir links internos notnative://note/nombre a clickeables
+        // El note_name puede venir URL-encoded (ej: My%20Note), hay que decodificarlo
+        result = INTERNAL_LINK_HTML_RE
+            .replace_all(&result, |caps: &regex::Captures| {
+                let note_name_encoded = &caps[1];
+                let note_name = url_decode(note_name_encoded);
+                let display_text = &caps[2];
+                format!(
+                    "<a href=\"#\" class=\"internal-link\" data-note=\"{}\" onclick=\"notifyRust(&quot;open-note&quot;, &quot;{}&quot;); return false;\">{}</a>",
+                    note_name, note_name, display_text
+                )
+            })
+            .to_string();
+
+        // Convertir links de tags notnative://tag/nombre a clickeables
+        // El tag_name puede venir URL-encoded (ej: programaci%C3%B3n), hay que decodificarlo
+        result = TAG_LINK_RE
+            .replace_all(&result, |caps: &regex::Captures| {
+                let tag_name_encoded = &caps[1];
+                let tag_name = url_decode(tag_name_encoded);
+                let display_text = &caps[2];
+                let style = tag_pill_style(&tag_color(&tag_name.to_lowercase(), &self.tag_colors));
+                format!(
+                    "<a href=\"#\" class=\"tag-link\" data-tag=\"{}\" style=\"{}\" onclick=\"notifyRust(&quot;search-tag&quot;, &quot;{}&quot;); return false;\">{}</a>",
+                    tag_name, style, tag_name, display_text
+                )
+            })
+            .to_string();
+
+        // Convertir rutas de imágenes locales a file:// URLs
+        // Detectar <img src="path"> donde path no empieza con http:// o https://
+        result = IMG_RE
+            .replace_all(&result, |caps: &regex::Captures| {
+                let src = &caps[1];
+                // Si ya es una URL http/https, dejarla como está
+                if src.starts_with("http://")
+                    || src.starts_with("https://")
+                    || src.starts_with("file://")
+                {
+                    format!(r#"<img src="{}""#, src)
+                } else if src.starts_with('/') {
+                    // Ruta absoluta: añadir file://
+                    format!(r#"<img src="file://{}""#, src)
+                } else if let Some(ref base) = self.base_path {
+                    // Ruta relativa: resolver contra base_path
+                    let full_path = base.join(src);
+                    format!(r#"<img src="file://{}""#, full_path.display())
+                } else {
+                    // Sin base_path, intentar como ruta relativa con file://
+                    format!(r#"<img src="file://{}""#, src)
+                }
+            })
+            .to_string();
+
+        // Añadir wrapper de contenido (sin handler de click - los keybindings controlan el modo)
+        result = format!(r#"<div class="content">{}</div>"#, result);
+
+        result
+    }
+
+    /// Clase CSS del `<body>` según el tema
+    fn theme_class(&self) -> String {
+        let mut theme_class = match self.theme {
+            PreviewTheme::Light => "light",
+            PreviewTheme::Dark => "dark",
+        }
+        .to_string();
+        if self.adjust_images && self.is_dark_preview() {
+            theme_class.push_str(" adjust-images");
+        }
+        if self.density == PreviewDensity::Compact {
+            theme_class.push_str(" compact");
+        }
+        if self.heading_anchors {
+            theme_class.push_str(" heading-anchors");
+        }
+        if self.print_friendly {
+            theme_class.push_str(" print-friendly");
+        }
+        theme_class
+    }
+
+    /// Identifica el documento que rodea al contenido (estilos, tema, scripts): si no
+    /// cambia, basta con reemplazar el contenido en lugar de recargar la página
+    pub fn document_key(&self) -> u64 {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        self.get_css().hash(&mut hasher);
+        self.theme_class().hash(&mut hasher);
+        self.embed_budget.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Envuelve el body HTML en un documento completo con estilos y scripts
+    pub fn wrap_in_document(&self, body: &str) -> String {
+        let css = self.get_css();
+        let js = self.get_javascript();
+        let theme_class = self.theme_class();
+
+        format!(
+            r#"<!DOCTYPE html>
+<html lang="es">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0">
+    <style>
+{css}
+    </style>
+</head>
+<body class="{theme_class}" data-embed-budget="{embed_budget}">
+    {body}
+    <script>
+{js}
+    </script>
+</body>
+</html>"#,
+            css = css,
+            body = body,
+            js = js,
+            theme_class = theme_class,
+            embed_budget = self.embed_budget
+        )
+    }
+
+    /// Retorna el CSS para el preview
+    fn get_css(&self) -> String {
+        // Si tenemos colores dinámicos, usarlos
+        let mut css = match self.colors {
+            Some(ref colors) => self.get_dynamic_css(colors),
+            // Fallback a colores estáticos basados en tema
+            None => self.get_static_css(),
+        };
+        if let Some(custom_css) = &self.custom_css {
+            css.push('\n');
+            css.push_str(custom_css);
+        }
+        css
+    }
+
+    /// CSS con colores dinámicos del tema GTK
+    fn get_dynamic_css(&self, colors: &PreviewColors) -> String {
+        format!(
+            r#"
+:root {{
+    --bg-primary: {bg_primary};
+    --bg-secondary: {bg_secondary};
+    --bg-tertiary: {bg_tertiary};
+    --fg-primary: {fg_primary};
+    --fg-secondary: {fg_secondary};
+    --fg-muted: {fg_muted};
+    --accent: {accent};
+    --accent-hover: {accent};
+    --green: #a6e3a1;
+    --red: #f38ba8;
+    --yellow: #f9e2af;
+    --peach: #fab387;
+    --code-bg: {bg_secondary};
+    --border: {border};
+    --link: {accent};
+    --link-internal: {accent};
+}}
+{common_css}"#,
+            bg_primary = colors.bg_primary,
+            bg_secondary = colors.bg_secondary,
+            bg_tertiary = colors.bg_tertiary,
+            fg_primary = colors.fg_primary,
+            fg_secondary = colors.fg_secondary,
+            fg_muted = colors.fg_muted,
+            accent = colors.accent,
+            border = colors.border,
+            common_css = Self::get_common_css(),
+        )
+    }
+
+    /// CSS con colores estáticos (fallback)
+    fn get_static_css(&self) -> String {
+        format!(
+            r#"
+:root {{
+    --bg-primary: #1e1e2e;
+    --bg-secondary: #313244;
+    --bg-tertiary: #45475a;
+    --fg-primary: #cdd6f4;
+    --fg-secondary: #a6adc8;
+    --fg-muted: #6c7086;
+    --accent: #89b4fa;
+    --accent-hover: #b4befe;
+    --green: #a6e3a1;
+    --red: #f38ba8;
+    --yellow: #f9e2af;
+    --peach: #fab387;
+    --code-bg: #181825;
+    --border: #45475a;
+    --link: #89dceb;
+    --link-internal: #cba6f7;
+}}
+
+body.light {{
+    --bg-primary: #eff1f5;
+    --bg-secondary: #e6e9ef;
+    --bg-tertiary: #ccd0da;
+    --fg-primary: #4c4f69;
+    --fg-secondary: #5c5f77;
+    --fg-muted: #8c8fa1;
+    --accent: #1e66f5;
+    --accent-hover: #7287fd;
+    --green: #40a02b;
+    --red: #d20f39;
+    --yellow: #df8e1d;
+    --peach: #fe640b;
+    --code-bg: #dce0e8;
+    --border: #bcc0cc;
+    --link: #209fb5;
+    --link-internal: #8839ef;
+}}
+{common_css}"#,
+            common_css = Self::get_common_css(),
+        )
+    }
+
+    /// CSS común que no depende del tema
+    fn get_common_css() -> &'static str {
+        r#"
+*  {
+    box-sizing: border-box;
+    margin: 0;
+    padding: 0;
+}
+
+body {
+    font-family: 'Inter', -apple-system, BlinkMacSystemFont, 'Segoe UI', Roboto, sans-serif;
+    font-size: 16px;
+    line-height: 1.7;
+    color: var(--fg-primary);
+    background-color: var(--bg-primary);
+    padding: 24px;
+    max-width: 100%;
+    overflow-x: hidden;
+}
+
+.content {
+    width: 100%;
+    max-width: 900px;
+    margin: 0 auto;
+}
+
+/* Headings */
+h1, h2, h3, h4, h5, h6 {
+    font-weight: 600;
+    margin-top: 1.5em;
+    margin-bottom: 0.5em;
+    color: var(--fg-primary);
+    line-height: 1.3;
+}
+
+h1 {
+    font-size: 2em;
+    border-bottom: 2px solid var(--border);
+    padding-bottom: 0.3em;
+}
+
+h2 {
+    font-size: 1.5em;
+    border-bottom: 1px solid var(--border);
+    padding-bottom: 0.2em;
+}
+
+h3 { font-size: 1.25em; }
+h4 { font-size: 1.1em; }
+h5 { font-size: 1em; }
+h6 { font-size: 0.9em; color: var(--fg-secondary); }
+
+/* Paragraphs */
+p {
+    margin-bottom: 1em;
+}
+
+/* Links */
+a {
+    color: var(--link);
+    text-decoration: none;
+    border-bottom: 1px solid transparent;
+    transition: border-color 0.2s, color 0.2s;
+}
+
+a:hover {
+    border-bottom-color: var(--link);
+}
+
+a.internal-link {
+    color: var(--link-internal);
+    background-color: rgba(139, 92, 246, 0.1);
+    padding: 0 4px;
+    border-radius: 4px;
+}
+
+a.internal-link:hover {
+    background-color: rgba(139, 92, 246, 0.2);
+}
+
+/* Tags (#tag): pastilla con el color del tag (estilo en línea) */
+a.tag-link {
+    color: var(--yellow);
+    background-color: rgba(249, 226, 175, 0.15);
+    padding: 1px 8px;
+    border-radius: 999px;
+    font-size: 0.9em;
+    font-weight: 500;
+    border: none;
+}
+
+a.tag-link:hover {
+    filter: brightness(1.15);
+    border: none;
+}
+
+/* Asa para reordenar bloques arrastrando */
+.block-handle {
+    position: absolute;
+    z-index: 900;
+    width: 18px;
+    line-height: 1.7;
+    text-align: center;
+    color: var(--fg-muted);
+    cursor: grab;
+    user-select: none;
+    touch-action: none;
+    opacity: 0;
+    transition: opacity 0.15s;
+}
+
+.block-handle.visible {
+    opacity: 0.6;
+}
+
+.block-handle:hover {
+    opacity: 1;
+    color: var(--accent);
+}
+
+body.block-dragging,
+body.block-dragging * {
+    cursor: grabbing !important;
+    user-select: none;
+}
+
+.block-drag-source {
+    opacity: 0.4;
+}
+
+.block-drop-indicator {
+    position: absolute;
+    z-index: 900;
+    height: 3px;
+    border-radius: 2px;
+    background-color: var(--accent);
+    pointer-events: none;
+    display: none;
+}
+
+.block-drop-indicator.visible {
+    display: block;
+}
+
+/* Edición en el preview (doble clic) */
+.preview-editing {
+    outline: 2px solid var(--accent);
+    outline-offset: 2px;
+    border-radius: 3px;
+    cursor: text;
+}
+
+/* Resaltados ==texto== */
+mark.highlight {
+    color: inherit;
+    padding: 0 2px;
+    border-radius: 3px;
+    background-color: rgba(249, 217, 51, 0.4);
+}
+
+mark.highlight-red { background-color: rgba(238, 85, 79, 0.35); }
+mark.highlight-green { background-color: rgba(115, 210, 77, 0.35); }
+mark.highlight-blue { background-color: rgba(77, 154, 242, 0.35); }
+mark.highlight-purple { background-color: rgba(179, 115, 242, 0.35); }
+
+/* Notas al pie */
+sup.footnote-ref {
+    font-size: 0.75em;
+    line-height: 0;
+}
+
+sup.footnote-ref a {
+    padding: 0 2px;
+}
+
+section.footnotes {
+    margin-top: 2em;
+    padding-top: 0.5em;
+    border-top: 1px solid var(--border);
+    font-size: 0.9em;
+    color: var(--fg-secondary);
+}
+
+section.footnotes li p {
+    margin-bottom: 0.4em;
+}
+
+a.footnote-backref {
+    font-size: 0.85em;
+}
+
+.footnote-popup {
+    position: absolute;
+    z-index: 1000;
+    max-width: 360px;
+    padding: 8px 12px;
+    font-size: 0.9em;
+    line-height: 1.5;
+    color: var(--fg-primary);
+    background-color: var(--bg-secondary);
+    border: 1px solid var(--border);
+    border-radius: 6px;
+    box-shadow: 0 4px 12px rgba(0, 0, 0, 0.25);
+}
+
+.footnote-popup p {
+    margin: 0;
+}
+
+/* Propiedades inline [campo::valor] */
+.inline-property {
+    display: inline-flex;
+    align-items: center;
+    background: linear-gradient(135deg, rgba(137, 180, 250, 0.15) 0%, rgba(137, 180, 250, 0.08) 100%);
+    border: 1px solid rgba(137, 180, 250, 0.3);
+    border-radius: 6px;
+    padding: 2px 8px;
+    margin: 2px 4px;
+    font-size: 0.9em;
+    gap: 4px;
+    line-height: 1.4;
+}
+
+.inline-property .prop-key {
+    color: var(--accent);
+    font-weight: 600;
+}
+
+.inline-property .prop-value {
+    color: var(--fg-primary);
+}
+
+.inline-props-line {
+    display: block;
+    margin: 4px 0;
+}
+
+/* Recordatorios simples (con emojis) */
+.reminder {
+    display: inline-block;
+    background-color: rgba(166, 227, 161, 0.15);
+    border-left: 3px solid var(--green);
+    padding: 4px 12px;
+    border-radius: 0 6px 6px 0;
+    margin: 4px 0;
+    font-size: 0.95em;
+}
+
+/* Recordatorios con formato !!RECORDAR() */
+.reminder-widget {
+    display: flex;
+    align-items: center;
+    gap: 10px;
+    background: linear-gradient(135deg, var(--bg-secondary) 0%, var(--bg-tertiary) 100%);
+    border: 1px solid var(--border);
+    border-radius: 10px;
+    padding: 12px 16px;
+    margin: 12px 0;
+    box-shadow: 0 2px 8px rgba(0, 0, 0, 0.15);
+    flex-wrap: wrap;
+}
+
+.reminder-widget.priority-urgent {
+    border-left: 4px solid #f38ba8;
+    background: linear-gradient(135deg, rgba(243, 139, 168, 0.1) 0%, var(--bg-secondary) 100%);
+}
+
+.reminder-widget.priority-high {
+    border-left: 4px solid #fab387;
+    background: linear-gradient(135deg, rgba(250, 179, 135, 0.1) 0%, var(--bg-secondary) 100%);
+}
+
+.reminder-widget.priority-medium {
+    border-left: 4px solid #f9e2af;
+    background: linear-gradient(135deg, rgba(249, 226, 175, 0.1) 0%, var(--bg-secondary) 100%);
+}
+
+.reminder-widget.priority-low {
+    border-left: 4px solid #a6e3a1;
+    background: linear-gradient(135deg, rgba(166, 227, 161, 0.1) 0%, var(--bg-secondary) 100%);
+}
+
+.reminder-widget.priority-normal {
+    border-left: 4px solid var(--accent);
+}
+
+.reminder-icon {
+    font-size: 1.4em;
+    flex-shrink: 0;
+}
+
+.reminder-date {
+    color: var(--fg-secondary);
+    font-size: 0.9em;
+    font-weight: 500;
+    white-space: nowrap;
+}
+
+.reminder-text {
+    color: var(--fg-primary);
+    font-weight: 500;
+    flex-grow: 1;
+}
+
+.reminder-repeat {
+    color: var(--accent);
+    font-size: 0.85em;
+    background: rgba(137, 180, 250, 0.15);
+    padding: 2px 8px;
+    border-radius: 12px;
+    white-space: nowrap;
+}
+
+/* Code */
+code {
+    font-family: 'JetBrains Mono', 'Fira Code', 'Consolas', monospace;
+    font-size: 0.9em;
+    background-color: var(--code-bg);
+    padding: 2px 6px;
+    border-radius: 4px;
+    color: var(--peach);
+}
+
+pre {
+    background-color: var(--code-bg);
+    padding: 16px;
+    border-radius: 8px;
+    overflow-x: auto;
+    margin: 1em 0;
+    border: 1px solid var(--border);
+}
+
+pre code {
+    background: none;
+    padding: 0;
+    font-size: 0.85em;
+    color: var(--fg-primary);
+}
+
+/* Blockquotes */
+blockquote {
+    border-left: 4px solid var(--accent);
+    margin: 1em 0;
+    padding: 0.5em 1em;
+    background-color: var(--bg-secondary);
+    border-radius: 0 8px 8px 0;
+    color: var(--fg-secondary);
+    font-style: italic;
+}
+
+blockquote p {
+    margin-bottom: 0.5em;
+}
+
+blockquote p:last-child {
+    margin-bottom: 0;
+}
+
+/* Callouts > [!tipo] */
+.callout {
+    --callout-color: var(--accent);
+    margin: 1em 0;
+    border-left: 4px solid var(--callout-color);
+    border-radius: 0 8px 8px 0;
+    background-color: color-mix(in srgb, var(--callout-color) 10%, var(--bg-primary));
+    overflow: hidden;
+}
+
+.callout-title {
+    display: flex;
+    align-items: center;
+    gap: 8px;
+    padding: 0.5em 1em;
+    font-weight: 600;
+    color: var(--callout-color);
+}
+
+summary.callout-title {
+    cursor: pointer;
+    list-style: none;
+}
+
+summary.callout-title::-webkit-details-marker {
+    display: none;
+}
+
+summary.callout-title::after {
+    content: '▸';
+    margin-left: auto;
+    transition: transform 0.2s;
+}
+
+details.callout[open] > summary.callout-title::after {
+    transform: rotate(90deg);
+}
+
+.callout-content {
+    padding: 0 1em 0.5em 1em;
+}
+
+.callout-content > :last-child {
+    margin-bottom: 0;
+}
+
+.callout-tip, .callout-success { --callout-color: var(--green); }
+.callout-question, .callout-warning { --callout-color: var(--yellow); }
+.callout-failure, .callout-danger, .callout-bug { --callout-color: var(--red); }
+.callout-abstract, .callout-todo { --callout-color: var(--link); }
+.callout-example { --callout-color: var(--link-internal); }
+.callout-quote { --callout-color: var(--fg-muted); }
+
+/* Lists */
+ul, ol {
+    margin: 1em 0;
+    padding-left: 2em;
+}
+
+li {
+    margin-bottom: 0.5em;
+}
+
+li > ul, li > ol {
+    margin: 0.25em 0;
+}
+
+/* Progreso de las tareas de cada sección */
+.task-progress {
+    display: flex;
+    align-items: center;
+    gap: 10px;
+    margin: -0.25em 0 0.75em 0;
+    font-size: 0.8em;
+    color: var(--fg-muted);
+}
+
+.task-progress-bar {
+    flex: 1;
+    max-width: 240px;
+    height: 6px;
+    border-radius: 3px;
+    background-color: var(--bg-tertiary);
+    overflow: hidden;
+}
+
+.task-progress-bar span {
+    display: block;
+    height: 100%;
+    border-radius: 3px;
+    background-color: var(--accent);
+    transition: width 0.3s;
+}
+
+.task-progress.complete .task-progress-bar span {
+    background-color: var(--green);
+}
+
+/* Hábitos (```habit) */
+.habit {
+    margin: 1em 0;
+    padding: 12px 14px;
+    border: 1px solid var(--border);
+    border-radius: 8px;
+    max-width: 340px;
+}
+
+.habit-header {
+    display: flex;
+    align-items: baseline;
+    justify-content: space-between;
+    gap: 12px;
+}
+
+.habit-name {
+    font-weight: 600;
+}
+
+.habit-streak,
+.habit-month {
+    font-size: 0.8em;
+    color: var(--fg-muted);
+}
+
+.habit-month {
+    margin: 2px 0 8px 0;
+}
+
+.habit-grid {
+    display: grid;
+    grid-template-columns: repeat(7, 1fr);
+    gap: 4px;
+}
+
+.habit-day {
+    aspect-ratio: 1;
+    display: flex;
+    align-items: center;
+    justify-content: center;
+    padding: 0;
+    border: 1px solid var(--border);
+    border-radius: 6px;
+    background: none;
+    color: inherit;
+    font: inherit;
+    font-size: 0.75em;
+    cursor: pointer;
+}
+
+.habit-day.empty {
+    border: none;
+}
+
+.habit-day.off,
+.habit-day.future {
+    opacity: 0.45;
+}
+
+.habit-day.future {
+    cursor: default;
+}
+
+.habit-day.today {
+    border-color: var(--accent);
+}
+
+.habit-day.done {
+    border-color: var(--green);
+    background-color: var(--green);
+    color: var(--bg-primary);
+}
+
+body.print-friendly .habit-day {
+    pointer-events: none;
+}
+
+/* Task lists (TODOs) */
+ul.contains-task-list,
+li.task-list-item {
+    list-style: none;
+}
+
+ul.contains-task-list {
+    padding-left: 0;
+    margin-left: 0;
+}
+
+li.task-list-item {
+    position: relative;
+    padding-left: 32px;
+    margin-bottom: 4px;
+}
+
+input.todo-checkbox,
+input[type="checkbox"] {
+    position: absolute;
+    left: 0;
+    top: 2px;
+    width: 20px;
+    height: 20px;
+    cursor: pointer;
+    accent-color: var(--green);
+    margin: 0;
+    padding: 0;
+    appearance: auto;
+    -webkit-appearance: checkbox;
+    z-index: 10;
+}
+
+input.todo-checkbox:hover,
+input[type="checkbox"]:hover {
+    transform: scale(1.1);
+    box-shadow: 0 0 4px var(--accent);
+}
+
+input[type="checkbox"]:checked + *,
+li.task-list-item:has(input:checked) > span,
+li.task-list-item:has(input:checked) > p {
+    text-decoration: line-through;
+    color: var(--fg-muted);
+}
+
+/* Estados extendidos: [/] en progreso y [-] cancelada */
+input.todo-checkbox[data-state="in-progress"] {
+    accent-color: var(--yellow);
+}
+
+li.task-list-item:has(> input[data-state="in-progress"]) {
+    color: var(--fg-primary);
+    font-weight: 500;
+}
+
+input.todo-checkbox[data-state="cancelled"] {
+    accent-color: var(--fg-muted);
+    opacity: 0.6;
+}
+
+li.task-list-item:has(> input[data-state="cancelled"]),
+li.task-list-item:has(> p > input[data-state="cancelled"]) {
+    text-decoration: line-through;
+    color: var(--fg-muted);
+    font-style: italic;
+}
+
+/* Tables */
+table {
+    width: 100%;
+    border-collapse: collapse;
+    margin: 1em 0;
+    font-size: 0.95em;
+}
+
+th, td {
+    border: 1px solid var(--border);
+    padding: 10px 14px;
+    text-align: left;
+}
+
+th {
+    background-color: var(--bg-secondary);
+    font-weight: 600;
+}
+
+tr:nth-child(even) {
+    background-color: var(--bg-secondary);
+}
+
+tr:hover {
+    background-color: var(--bg-tertiary);
+}
+
+/* Images */
+img {
+    max-width: 100%;
+    height: auto;
+    border-radius: 8px;
+    margin: 1em 0;
+    display: block;
+}
+
+/* Ajuste de imágenes claras en modo oscuro (ver detectLightImages en JS) */
+body.adjust-images img.light-image {
+    border: 1px solid var(--border);
+}
+
+body.adjust-images img.light-image:not(.no-invert),
+body.adjust-images img.invert {
+    filter: invert(0.88) hue-rotate(180deg);
+}
+
+body.adjust-images img.light-image:not(.no-invert):hover,
+body.adjust-images img.invert:hover {
+    filter: none;
+}
+
+/* YouTube Embeds */
+.youtube-embed {
+    position: relative;
+    width: 100%;
+    max-width: 640px;
+    margin: 1em 0;
+    padding-bottom: 56.25%; /* 16:9 aspect ratio */
+    height: 0;
+    overflow: hidden;
+    border-radius: 8px;
+    background-color: var(--bg-secondary);
+    background-size: cover;
+    background-position: center;
+}
+
+.youtube-play {
+    position: absolute;
+    top: 50%;
+    left: 50%;
+    transform: translate(-50%, -50%);
+    width: 64px;
+    height: 64px;
+    border: none;
+    border-radius: 50%;
+    background-color: rgba(0, 0, 0, 0.6);
+    color: #fff;
+    font-size: 24px;
+    cursor: pointer;
+}
+
+.youtube-play:hover {
+    background-color: var(--accent);
+}
+
+.youtube-embed iframe {
+    position: absolute;
+    top: 0;
+    left: 0;
+    width: 100%;
+    height: 100%;
+    border: none;
+    border-radius: 8px;
+}
+
+/* Horizontal rule */
+hr {
+    border: none;
+    border-top: 2px solid var(--border);
+    margin: 2em 0;
+}
+
+/* Strikethrough */
+del {
+    color: var(--fg-muted);
+    text-decoration: line-through;
+}
+
+/* Strong and emphasis */
+strong {
+    font-weight: 600;
+    color: var(--fg-primary);
+}
+
+em {
+    font-style: italic;
+}
+
+/* Footnotes */
+.footnote-definition {
+    font-size: 0.9em;
+    color: var(--fg-secondary);
+    margin-top: 2em;
+    padding-top: 1em;
+    border-top: 1px solid var(--border);
+}
+
+/* Selection */
+::selection {
+    background-color: var(--accent);
+    color: var(--bg-primary);
+}
+
+/* Scrollbar */
+::-webkit-scrollbar {
+    width: 8px;
+    height: 8px;
+}
+
+::-webkit-scrollbar-track {
+    background: var(--bg-secondary);
+}
+
+::-webkit-scrollbar-thumb {
+    background: var(--fg-muted);
+    border-radius: 4px;
+}
+
+::-webkit-scrollbar-thumb:hover {
+    background: var(--fg-secondary);
+}
+
+/* Highlight.js theme integration */
+.hljs {
+    background: transparent !important;
+}
+
+/* Densidad compacta */
+body.compact {
+    padding: 12px;
+    line-height: 1.5;
+}
+
+body.compact h1, body.compact h2, body.compact h3,
+body.compact h4, body.compact h5, body.compact h6 {
+    margin-top: 1em;
+    margin-bottom: 0.3em;
+}
+
+body.compact p, body.compact ul, body.compact ol,
+body.compact pre, body.compact blockquote, body.compact table {
+    margin-bottom: 0.5em;
+}
+
+/* Enlaces # de los encabezados */
+.heading-anchor {
+    margin-left: 0.4em;
+    color: var(--fg-muted);
+    font-weight: normal;
+    opacity: 0.5;
+}
+
+h1:hover > .heading-anchor, h2:hover > .heading-anchor, h3:hover > .heading-anchor,
+h4:hover > .heading-anchor, h5:hover > .heading-anchor, h6:hover > .heading-anchor {
+    opacity: 1;
+}
+
+/* Para imprimir: sin embeds ni controles, texto oscuro sobre blanco */
+body.print-friendly {
+    --bg-primary: #ffffff;
+    --bg-secondary: #f4f4f4;
+    --bg-tertiary: #e8e8e8;
+    --fg-primary: #111111;
+    --fg-secondary: #333333;
+    --fg-muted: #666666;
+    --code-bg: #f4f4f4;
+    --border: #cccccc;
+    --link: #111111;
+    --link-internal: #111111;
+}
+
+body.print-friendly .youtube-embed,
+body.print-friendly iframe,
+body.print-friendly .block-handle,
+body.print-friendly .block-drop-indicator,
+body.print-friendly .footnote-popup,
+body.print-friendly .heading-anchor,
+body.print-friendly .reminder-widget,
+body.print-friendly .task-progress {
+    display: none !important;
+}
+
+body.print-friendly a {
+    text-decoration: underline;
+}
+
+body.print-friendly img {
+    filter: none !important;
+}
+
+body.print-friendly input {
+    pointer-events: none;
+}
+"#
+    }
+
+    /// Retorna el JavaScript para interactividad
+    fn get_javascript(&self) -> String {
+        r#"
+// Bridge de comunicación con Rust via WebKit UserContentManager
+function notifyRust(action, ...args) {
+    try {
+        // WebKit espera un mensaje en formato específico
+        const message = JSON.stringify({
+            action: action,
+            args: args
+        });
+
+        // Enviar al handler registrado en Rust
+        if (window.webkit && window.webkit.messageHandlers && window.webkit.messageHandlers.notnative) {
+            window.webkit.messageHandlers.notnative.postMessage(message);
+        } else {
+            console.warn('WebKit message handler not available:', action, args);
+        }
+    } catch (e) {
+        console.error('Error sending message to Rust:', e);
+    }
+}
+
+// Avisar a Rust del scroll (ya parado) para recordar la posición de lectura
+// y saber qué líneas se ven
+let scrollReportTimer = null;
+window.addEventListener('scroll', () => {
+    clearTimeout(scrollReportTimer);
+    scrollReportTimer = setTimeout(() => {
+        const max = document.documentElement.scrollHeight - window.innerHeight;
+        notifyRust('scroll', max > 0 ? window.scrollY / max : 0);
+        notifyRust('visible-lines', ...visibleLines(), true);
+    }, 250);
+}, { passive: true });
+
+// Al imprimir, siempre con el estilo para imprimir
+let printFriendlyBefore = false;
+window.addEventListener('beforeprint', () => {
+    printFriendlyBefore = document.body.classList.contains('print-friendly');
+    document.body.classList.add('print-friendly');
+});
+window.addEventListener('afterprint', () => {
+    document.body.classList.toggle('print-friendly', printFriendlyBefore);
+});
+
+// Ctrl+clic en un bloque: editarlo en el editor, en su línea
+document.addEventListener('click', function(e) {
+    if (!e.ctrlKey || e.target.closest('a, input, button')) {
+        return;
+    }
+    const block = e.target.closest('.source-block');
+    if (block) {
+        e.preventDefault();
+        notifyRust('line-click', Number(block.dataset.line));
+    }
+});
+
+// Clic derecho en un encabezado: menú para copiar un enlace a él
+document.addEventListener('contextmenu', function(e) {
+    const heading = e.target.closest('h1[id], h2[id], h3[id], h4[id], h5[id], h6[id]');
+    if (heading && !heading.classList.contains('preview-editing')) {
+        e.preventDefault();
+        notifyRust('heading-menu', heading.id, e.clientX, e.clientY);
+    }
+});
+
+// Handler para clicks en checkboxes de TODOs
+function handleTodoClick(event, lineNum, isChecked) {
+    event.stopPropagation(); // Evitar que el click se propague
+    console.log('TODO clicked:', lineNum, isChecked);
+    notifyRust('todo-toggle', lineNum, isChecked);
+}
+
+// Marca como .light-image las imágenes con fondo mayoritariamente blanco
+// (diagramas, capturas) para que el CSS las invierta en modo oscuro
+function detectLightImages() {
+    if (!document.body.classList.contains('adjust-images')) {
+        return;
+    }
+
+    function analyze(img) {
+        if (img.classList.contains('no-invert') || img.classList.contains('invert')) {
+            return;
+        }
+        try {
+            const size = 32;
+            const canvas = document.createElement('canvas');
+            canvas.width = size;
+            canvas.height = size;
+            const ctx = canvas.getContext('2d');
+            ctx.drawImage(img, 0, 0, size, size);
+            const data = ctx.getImageData(0, 0, size, size).data;
+
+            let bright = 0;
+            let opaque = 0;
+            for (let i = 0; i < data.length; i += 4) {
+                if (data[i + 3] < 32) continue;
+                opaque++;
+                const lum = 0.299 * data[i] + 0.587 * data[i + 1] + 0.114 * data[i + 2];
+                if (lum > 220) bright++;
+            }
+
+            if (opaque > 0 && bright / opaque > 0.6) {
+                img.classList.add('light-image');
+            }
+        } catch (e) {
+            // Canvas contaminado (origen distinto): dejar la imagen como está
+            console.warn('No se pudo analizar la imagen:', img.src, e);
+        }
+    }
+
+    document.querySelectorAll('img').forEach(function(img) {
+        if (img.complete && img.naturalWidth > 0) {
+            analyze(img);
+        } else {
+            img.addEventListener('load', function() { analyze(img); }, { once: true });
+        }
+    });
+}
+
+// Videos embebidos: cada iframe de YouTube es un documento completo, así que solo
+// se cargan los que están en pantalla y nunca más de data-embed-budget a la vez
+const embedState = { observer: null, visible: new Set(), loaded: [] };
+
+function embedBudget() {
+    const budget = parseInt(document.body.dataset.embedBudget, 10);
+    return isNaN(budget) ? 3 : budget;
+}
+
+function reportEmbeds() {
+    notifyRust('embed-stats', embedState.loaded.length,
+        document.querySelectorAll('.youtube-embed').length);
+}
+
+function loadEmbed(el) {
+    const index = embedState.loaded.indexOf(el);
+    if (index >= 0) {
+        // Ya cargado: pasa a ser el más reciente
+        embedState.loaded.splice(index, 1);
+        embedState.loaded.push(el);
+        return;
+    }
+    while (embedState.loaded.length > 0 && embedState.loaded.length >= Math.max(embedBudget(), 1)) {
+        unloadEmbed(embedState.loaded[0]);
+    }
+
+    const iframe = document.createElement('iframe');
+    iframe.src = 'https://www.youtube.com/embed/' + el.dataset.videoId;
+    iframe.setAttribute('frameborder', '0');
+    iframe.setAttribute('allowfullscreen', '');
+    el.appendChild(iframe);
+    embedState.loaded.push(el);
+    reportEmbeds();
+}
+
+function unloadEmbed(el) {
+    el.querySelectorAll('iframe').forEach(function(iframe) { iframe.remove(); });
+    embedState.loaded = embedState.loaded.filter(function(other) { return other !== el; });
+    reportEmbeds();
+}
+
+function syncEmbeds() {
+    embedState.loaded
+        .filter(function(el) { return !el.isConnected || !embedState.visible.has(el); })
+        .forEach(unloadEmbed);
+
+    document.querySelectorAll('.youtube-embed').forEach(function(el) {
+        if (embedState.visible.has(el) && embedState.loaded.length < embedBudget()) {
+            loadEmbed(el);
+        }
+    });
+}
+
+function manageEmbeds() {
+    if (embedState.observer) {
+        embedState.observer.disconnect();
+    }
+    embedState.visible.clear();
+
+    const embeds = document.querySelectorAll('.youtube-embed');
+    embeds.forEach(function(el) {
+        // Con presupuesto 0 (o por encima del límite) el video se carga al pulsarlo
+        const play = el.querySelector('.youtube-play');
+        if (play) {
+            play.addEventListener('click', function(e) {
+                e.stopPropagation();
+                loadEmbed(el);
+            });
+        }
+    });
+
+    embedState.observer = new IntersectionObserver(function(entries) {
+        entries.forEach(function(entry) {
+            if (entry.isIntersecting) {
+                embedState.visible.add(entry.target);
+            } else {
+                embedState.visible.delete(entry.target);
+            }
+        });
+        syncEmbeds();
+    }, { rootMargin: '200px 0px' });
+    embeds.forEach(function(el) { embedState.observer.observe(el); });
+    syncEmbeds();
+}
+
+// Popup con el texto de la nota al pie al pasar el ratón por la referencia
+function showFootnotePopup(link) {
+    hideFootnotePopup();
+    const definition = document.getElementById(link.dataset.footnote);
+    if (!definition) {
+        return;
+    }
+
+    const popup = document.createElement('div');
+    popup.className = 'footnote-popup';
+    popup.innerHTML = definition.innerHTML;
+    popup.querySelectorAll('.footnote-backref').forEach(function(backref) { backref.remove(); });
+    document.body.appendChild(popup);
+
+    const rect = link.getBoundingClientRect();
+    const left = Math.min(rect.left, window.innerWidth - popup.offsetWidth - 8);
+    popup.style.left = (window.scrollX + Math.max(left, 8)) + 'px';
+    popup.style.top = (window.scrollY + rect.bottom + 6) + 'px';
+}
+
+function hideFootnotePopup() {
+    document.querySelectorAll('.footnote-popup').forEach(function(popup) { popup.remove(); });
+}
+
+function manageFootnotes() {
+    hideFootnotePopup();
+    document.querySelectorAll('.footnote-ref a').forEach(function(link) {
+        link.addEventListener('mouseenter', function() { showFootnotePopup(link); });
+        link.addEventListener('mouseleave', hideFootnotePopup);
+        link.addEventListener('click', hideFootnotePopup);
+    });
+}
+
+// Reordenar bloques arrastrando un asa. Los bloques se numeran igual que en
+// blocks.rs: cada hijo del contenido, salvo las listas, cuyos elementos van por
+// separado. Rust comprueba el total antes de mover nada.
+const blockDrag = { handle: null, indicator: null, hovered: null, from: null, to: null };
+
+function blockElements() {
+    const content = document.querySelector('body > .content');
+    if (!content) {
+        return [];
+    }
+    const blocks = [];
+    Array.from(content.children).forEach(function(el) {
+        if (el.matches('.task-progress, section.footnotes')) {
+            return;
+        }
+        if (el.matches('ul, ol')) {
+            Array.from(el.children).forEach(function(li) {
+                if (li.tagName === 'LI') {
+                    blocks.push(li);
+                }
+            });
+        } else {
+            blocks.push(el);
+        }
+    });
+    return blocks;
+}
+
+function showBlockHandle(block) {
+    blockDrag.hovered = block;
+    const rect = block.getBoundingClientRect();
+    blockDrag.handle.style.left = (window.scrollX + rect.left - 22) + 'px';
+    blockDrag.handle.style.top = (window.scrollY + rect.top) + 'px';
+    blockDrag.handle.classList.add('visible');
+}
+
+function dropTarget(blocks, y) {
+    for (let i = 0; i < blocks.length; i++) {
+        const rect = blocks[i].getBoundingClientRect();
+        if (y < rect.top + rect.height / 2) {
+            return i;
+        }
+    }
+    return blocks.length;
+}
+
+function moveDropIndicator(blocks, to) {
+    const reference = blocks[Math.min(to, blocks.length - 1)].getBoundingClientRect();
+    const y = to < blocks.length ? reference.top - 3 : reference.bottom + 3;
+    blockDrag.indicator.style.left = (window.scrollX + reference.left) + 'px';
+    blockDrag.indicator.style.width = reference.width + 'px';
+    blockDrag.indicator.style.top = (window.scrollY + y) + 'px';
+    blockDrag.indicator.classList.add('visible');
+}
+
+function endBlockDrag() {
+    document.body.classList.remove('block-dragging');
+    document.querySelectorAll('.block-drag-source').forEach(function(el) {
+        el.classList.remove('block-drag-source');
+    });
+    blockDrag.indicator.classList.remove('visible');
+    blockDrag.from = null;
+    blockDrag.to = null;
+}
+
+function manageBlockDrag() {
+    if (!blockDrag.handle) {
+        blockDrag.handle = document.createElement('div');
+        blockDrag.handle.className = 'block-handle';
+        blockDrag.handle.textContent = '⠿';
+        blockDrag.indicator = document.createElement('div');
+        blockDrag.indicator.className = 'block-drop-indicator';
+        document.body.appendChild(blockDrag.handle);
+        document.body.appendChild(blockDrag.indicator);
+
+        document.addEventListener('mouseover', function(e) {
+            if (blockDrag.from !== null || e.target === blockDrag.handle) {
+                return;
+            }
+            const block = blockElements().find(function(el) { return el.contains(e.target); });
+            if (block) {
+                showBlockHandle(block);
+            }
+        });
+
+        const handle = blockDrag.handle;
+        handle.addEventListener('pointerdown', function(e) {
+            const blocks = blockElements();
+            const from = blocks.indexOf(blockDrag.hovered);
+            if (from < 0) {
+                return;
+            }
+            e.preventDefault();
+            handle.setPointerCapture(e.pointerId);
+            blockDrag.from = from;
+            blockDrag.to = from;
+            blocks[from].classList.add('block-drag-source');
+            document.body.classList.add('block-dragging');
+        });
+        handle.addEventListener('pointermove', function(e) {
+            if (blockDrag.from === null) {
+                return;
+            }
+            const blocks = blockElements();
+            blockDrag.to = dropTarget(blocks, e.clientY);
+            moveDropIndicator(blocks, blockDrag.to);
+        });
+        handle.addEventListener('pointerup', function() {
+            if (blockDrag.from === null) {
+                return;
+            }
+            const from = blockDrag.from;
+            const to = blockDrag.to;
+            const total = blockElements().length;
+            endBlockDrag();
+            if (to !== from && to !== from + 1) {
+                notifyRust('move-block', from, to, total);
+            }
+        });
+        handle.addEventListener('pointercancel', endBlockDrag);
+    }
+
+    // El contenido cambió: el bloque bajo el asa puede no existir ya
+    blockDrag.hovered = null;
+    blockDrag.handle.classList.remove('visible');
+    endBlockDrag();
+}
+
+// Edición ligera: doble clic en un bloque con origen conocido (la marca
+// .source-map del renderer) para corregirlo en el sitio. Enter guarda y Escape
+// cancela; Rust lleva el cambio al markdown y vuelve a pintar.
+function manageInlineEditing() {
+    document.querySelectorAll('.source-map').forEach(function(marker) {
+        const block = marker.parentElement;
+        marker.remove();
+        if (!block) {
+            return;
+        }
+        block.dataset.line = marker.dataset.line;
+        block.classList.add('source-block');
+        // Con sublistas o párrafos dentro, solo se editan esos
+        if (block.querySelector('ul, ol, p, table')) {
+            return;
+        }
+        block.dataset.source = marker.dataset.source;
+        block.classList.add('preview-editable');
+        block.addEventListener('dblclick', function(e) {
+            if (!e.target.closest('a, input, button')) {
+                startInlineEdit(block);
+            }
+        });
+    });
+}
+
+// Sincronización con el editor: cada .source-block sabe su línea del
+// markdown; entre dos bloques se interpola
+function sourceBlocks() {
+    return Array.from(document.querySelectorAll('.source-block')).map(function(block) {
+        return { line: Number(block.dataset.line), top: block.getBoundingClientRect().top + window.scrollY };
+    });
+}
+
+// Posición vertical (en el documento) de una línea del markdown
+function lineTop(line) {
+    const blocks = sourceBlocks();
+    let before = null;
+    let after = null;
+    for (const block of blocks) {
+        if (block.line <= line) {
+            before = block;
+        } else {
+            after = block;
+            break;
+        }
+    }
+    if (!before) {
+        return after ? after.top : 0;
+    }
+    if (!after || after.line === before.line) {
+        return before.top;
+    }
+    const ratio = (line - before.line) / (after.line - before.line);
+    return before.top + (after.top - before.top) * ratio;
+}
+
+// Línea del markdown que se ve a una altura del documento
+function lineAt(y) {
+    const blocks = sourceBlocks();
+    let before = null;
+    for (const block of blocks) {
+        if (block.top > y) {
+            if (!before) {
+                return block.line;
+            }
+            const ratio = (y - before.top) / Math.max(1, block.top - before.top);
+            return Math.floor(before.line + (block.line - before.line) * ratio);
+        }
+        before = block;
+    }
+    return before ? before.line : 1;
+}
+
+// Llevar al preview una línea del editor (usada por Rust)
+function scrollToLine(line) {
+    window.scrollTo(0, Math.max(0, lineTop(line) - window.innerHeight / 3));
+}
+
+// Líneas visibles: primera y última
+function visibleLines() {
+    return [lineAt(window.scrollY), lineAt(window.scrollY + window.innerHeight)];
+}
+
+// Una línea arriba del todo, como en el editor de al lado (usada por Rust)
+function scrollLineToTop(line) {
+    window.scrollTo(0, lineTop(line));
+}
+
+// Salto a un encabezado por su ID, para los enlaces @Nota#encabezado (usada por Rust)
+function scrollToHeading(id) {
+    const heading = document.getElementById(id);
+    if (heading) {
+        heading.scrollIntoView({ block: 'start' });
+    }
+}
+
+// Como scrollToLine, pero sin moverse si la línea ya se ve (usada por Rust)
+function revealLine(line) {
+    const lines = visibleLines();
+    if (line < lines[0] || line > lines[1]) {
+        scrollToLine(line);
+    }
+}
+
+function startInlineEdit(block) {
+    if (block.isContentEditable) {
+        return;
+    }
+    const originalHtml = block.innerHTML;
+    const originalText = block.textContent;
+    let finished = false;
+
+    function onKey(e) {
+        // Que no lleguen a los atajos del preview
+        e.stopPropagation();
+        if (e.key === 'Enter') {
+            e.preventDefault();
+            finish(true);
+        } else if (e.key === 'Escape') {
+            e.preventDefault();
+            finish(false);
+        }
+    }
+
+    function finish(save) {
+        if (finished) {
+            return;
+        }
+        finished = true;
+        const newText = block.textContent;
+        block.removeEventListener('keydown', onKey);
+        block.contentEditable = 'false';
+        block.classList.remove('preview-editing');
+        // Vuelve a como estaba: si Rust aplica el cambio, pinta el bloque nuevo
+        block.innerHTML = originalHtml;
+        notifyRust('edit-end');
+        if (save && newText !== originalText) {
+            const range = block.dataset.source.split('-').map(Number);
+            notifyRust('preview-edit', range[0], range[1], originalText, newText);
+        }
+    }
+
+    notifyRust('edit-start');
+    block.contentEditable = 'plaintext-only';
+    block.classList.add('preview-editing');
+    block.addEventListener('keydown', onKey);
+    block.addEventListener('blur', function() { finish(true); }, { once: true });
+    block.focus();
+}
+
+// Inicialización del contenido (al cargar y tras cada parche)
+function initContent() {
+    detectLightImages();
+    manageEmbeds();
+    manageFootnotes();
+    manageBlockDrag();
+    manageInlineEditing();
+    notifyRust('visible-lines', ...visibleLines(), false);
+
+    // Prevenir arrastrar links
+    document.querySelectorAll('a').forEach(function(link) {
+        link.addEventListener('dragstart', function(e) {
+            e.preventDefault();
+        });
+    });
+
+    // [/] y [-] se muestran como checkbox indeterminado (con su color por CSS)
+    document.querySelectorAll('input.todo-checkbox').forEach(function(checkbox) {
+        const state = checkbox.dataset.state;
+        checkbox.indeterminate = state === 'in-progress' || state === 'cancelled';
+    });
+
+    // Añadir clase a listas con tasks
+    document.querySelectorAll('li').forEach(function(li) {
+        if (li.querySelector('input[type="checkbox"]')) {
+            li.classList.add('task-list-item');
+            li.parentElement.classList.add('contains-task-list');
+        }
+    });
+}
+
+document.addEventListener('DOMContentLoaded', initContent);
+
+// Reemplaza el contenido sin recargar la página (usada por Rust); conserva el scroll
+function patchContent(html) {
+    const content = document.querySelector('body > .content');
+    if (!content) {
+        return false;
+    }
+    // Una edición a medias se pierde con el contenido viejo
+    if (content.querySelector('.preview-editing')) {
+        notifyRust('edit-end');
+    }
+    content.outerHTML = html;
+    initContent();
+    return true;
+}
+
+// Función para obtener posición de scroll (usada por Rust)
+function getScrollPosition() {
+    return {
+        x: window.scrollX,
+        y: window.scrollY,
+        maxY: document.body.scrollHeight - window.innerHeight
+    };
+}
+
+// Función para establecer posición de scroll (usada por Rust)
+function setScrollPosition(y) {
+    window.scrollTo(0, y);
+}
+
+// Función para scroll a porcentaje (usada por Rust)
+function setScrollPercent(percent) {
+    const maxScroll = document.body.scrollHeight - window.innerHeight;
+    window.scrollTo(0, maxScroll * percent);
+}
+"#
+        .to_string()
+    }
+}
+
+/// Barra de progreso de las tareas de una sección
+fn task_progress_html(completed: usize, total: usize) -> String {
+    let percentage = completed * 100 / total;
+    let state = if completed == total { " complete" } else { "" };
+    format!(
+        r#"<div class="task-progress{state}"><div class="task-progress-bar"><span style="width: {percentage}%"></span></div><span class="task-progress-label">{completed}/{total} · {percentage}%</span></div>
+"#
+    )
+}
+
+/// Color del texto y fondo translúcido de la pastilla de un tag
+fn tag_pill_style(color: &str) -> String {
+    let hex = color.trim_start_matches('#');
+    let channel = |range: std::ops::Range<usize>| {
+        hex.get(range)
+            .and_then(|part| u8::from_str_radix(part, 16).ok())
+    };
+    match (hex.len(), channel(0..2), channel(2..4), channel(4..6)) {
+        (6, Some(r), Some(g), Some(b)) => format!(
+            "color: {}; background-color: rgba({}, {}, {}, 0.18)",
+            color, r, g, b
+        ),
+        _ => format!("color: {}", color),
+    }
+}
+
+/// Renderiza markdown a HTML con el tema por defecto
+pub fn render_markdown_to_html(markdown: &str) -> String {
+    HtmlRenderer::default().render(markdown)
+}
+
+/// Renderiza markdown a HTML con tema específico
+pub fn render_markdown_to_html_themed(markdown: &str, theme: PreviewTheme) -> String {
+    HtmlRenderer::new(theme).render(markdown)
+}
+
+/// Renderiza un fragmento de markdown como HTML para el portapapeles, sin
+/// estilos ni scripts del preview: lo que entienden los clientes de correo y
+/// los procesadores de texto. Los links internos `[[nota|alias]]` quedan como
+/// su texto, porque fuera de la app no llevan a ningún sitio.
+pub fn render_markdown_to_clipboard_html(markdown: &str) -> String {
+    let markdown = INTERNAL_LINK_RE.replace_all(markdown, |caps: &regex::Captures| {
+        let target = &caps[1];
+        target
+            .split_once('|')
+            .map_or(target, |(_, alias)| alias)
+            .to_string()
+    });
+
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    options.insert(Options::ENABLE_TASKLISTS);
+    options.insert(Options::ENABLE_FOOTNOTES);
+    options.insert(Options::ENABLE_HEADING_ATTRIBUTES);
+
+    let mut html_output = String::from("<meta charset=\"utf-8\">\n");
+    html::push_html(&mut html_output, Parser::new_ext(&markdown, options));
+    html_output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clipboard_html() {
+        let html = render_markdown_to_clipboard_html(
+            "## Lista\n\n- **uno** y [[Otra nota|otra]]\n- `dos`\n",
+        );
+        assert!(html.starts_with("<meta charset=\"utf-8\">"));
+        assert!(html.contains("<h2>Lista</h2>"));
+        assert!(html.contains("<li><strong>uno</strong> y otra</li>"));
+        assert!(html.contains("<code>dos</code>"));
+        // Nada del preview: ni scripts ni estilos
+        assert!(!html.contains("<script") && !html.contains("<style"));
+    }
+
+    #[test]
+    fn test_basic_rendering() {
+        let md = "# Hello World\n\nThis is a **test**.";
+        let html = render_markdown_to_html(md);
+
+        assert!(html.contains("<h1>"));
+        assert!(html.contains("Hello World"));
+        assert!(html.contains("<strong>"));
+    }
+
+    #[test]
+    fn test_todo_checkboxes() {
+        let md = "- [ ] Unchecked task\n- [x] Checked task";
+        let html = render_markdown_to_html(md);
+
+        assert!(html.contains(r#"type="checkbox""#));
+        assert!(html.contains("onclick"));
+        assert!(html.contains("notifyRust"));
+    }
+
+    #[test]
+    fn test_habit_block() {
+        let md = "# Rutina\n\n```habit\nname: Ejercicio\nschedule: daily\n```\n\n- [ ] Tarea";
+        let html = render_markdown_to_html(md);
+
+        assert!(html.contains(r#"<div class="habit" data-habit="0">"#));
+        assert!(html.contains("notifyRust('habit-toggle', 0, "));
+        assert!(!html.contains("<code class=\"language-habit\">"));
+        // Las tareas siguen numerándose igual
+        assert!(html.contains(r#"data-line="1""#));
+    }
+
+    #[test]
+    fn test_internal_links() {
+        let md = "Link to [[My Note]] here.";
+        let html = render_markdown_to_html(md);
+
+        assert!(html.contains("internal-link"));
+        assert!(html.contains("data-note"));
+        assert!(html.contains("My Note"));
+    }
+
+    #[test]
+    fn test_nested_tag_pills() {
+        let mut renderer = HtmlRenderer::default();
+        let mut colors = HashMap::new();
+        colors.insert("proyecto".to_string(), "#123456".to_string());
+        renderer.set_tag_colors(colors);
+
+        let html = renderer.render_body("Ver #proyecto/cliente-a hoy");
+        assert!(html.contains(r#"data-tag="proyecto/cliente-a""#));
+        assert!(html.contains("color: #123456; background-color: rgba(18, 52, 86, 0.18)"));
+        assert!(html.contains(">#proyecto/cliente-a</a>"));
+    }
+
+    #[test]
+    fn test_code_blocks() {
+        let md = "```rust\nfn main() {}\n```";
+        let html = render_markdown_to_html(md);
+
+        assert!(html.contains("<pre>"));
+        assert!(html.contains("<code"));
+    }
+
+    #[test]
+    fn test_tables() {
+        let md = "| A | B |\n|---|---|\n| 1 | 2 |";
+        let html = render_markdown_to_html(md);
+
+        assert!(html.contains("<table>"));
+        assert!(html.contains("<th>"));
+        assert!(html.contains("<td>"));
+    }
+
+    #[test]
+    fn test_theme_class() {
+        let md = "# Test";
+
+        let light = HtmlRenderer::new(PreviewTheme::Light).render(md);
+        assert!(light.contains(r#"class="light""#));
+
+        let dark = HtmlRenderer::new(PreviewTheme::Dark).render(md);
+        assert!(dark.contains(r#"class="dark""#));
+    }
+
+    #[test]
+    fn test_adjust_images_only_in_dark() {
+        let md = "![diagram](diagram.png)";
+
+        let mut dark = HtmlRenderer::new(PreviewTheme::Dark);
+        dark.set_adjust_images(true);
+        assert!(dark.render(md).contains(r#"class="dark adjust-images""#));
+
+        let mut light = HtmlRenderer::new(PreviewTheme::Light);
+        light.set_adjust_images(true);
+        assert!(!light.render(md).contains("adjust-images\""));
+
+        let mut colors = PreviewColors::default();
+        colors.bg_primary = "#fafafa".to_string();
+        assert!(!colors.is_dark());
+        assert!(PreviewColors::default().is_dark());
+    }
+
+    #[test]
+    fn test_image_no_invert_attribute() {
+        let md = "![shot](/tmp/shot.png){.no-invert}";
+        let html = render_markdown_to_html(md);
+
+        assert!(html.contains(r#"<img src="file:///tmp/shot.png" alt="shot" class="no-invert">"#));
+        assert!(!html.contains("{.no-invert}"));
+    }
+
+    #[test]
+    fn test_base_uri() {
+        let renderer =
+            HtmlRenderer::with_base_path(PreviewTheme::Dark, PathBuf::from("/home/ana/Mis notas"));
+        assert_eq!(
+            renderer.base_uri().as_deref(),
+            Some("file:///home/ana/Mis%20notas/")
+        );
+        assert_eq!(HtmlRenderer::new(PreviewTheme::Dark).base_uri(), None);
+    }
+
+    #[test]
+    fn test_image_attribute_left_alone_in_code() {
+        let md =
+            "`![a](a.png){.invert}`\n\n```\n![b](b.png){.no-invert}\n```\n\n![c](c.png){.invert}\n";
+        let rendered = render_image_adjust_attrs(md);
+
+        assert!(rendered.starts_with("`![a](a.png){.invert}`\n"));
+        assert!(rendered.contains("```\n![b](b.png){.no-invert}\n```"));
+        assert!(rendered.ends_with("<img src=\"c.png\" alt=\"c\" class=\"invert\">\n"));
+    }
+
+    #[test]
+    fn test_youtube_embeds_are_lazy() {
+        let md = "https://www.youtube.com/watch?v=dQw4w9WgXcQ";
+        let mut renderer = HtmlRenderer::new(PreviewTheme::Dark);
+        let html = renderer.render(md);
+
+        // El iframe lo crea el JavaScript al entrar en pantalla
+        assert!(html.contains(r#"data-video-id="dQw4w9WgXcQ""#));
+        assert!(!html.contains("<iframe"));
+        assert!(html.contains(r#"data-embed-budget="3""#));
+
+        let key = renderer.document_key();
+        renderer.set_embed_budget(1);
+        assert!(renderer.render(md).contains(r#"data-embed-budget="1""#));
+        assert_ne!(renderer.document_key(), key);
+    }
+
+    #[test]
+    fn test_footnotes() {
+        let md = "Texto con nota[^1].\n\n[^1]: La nota.\n";
+        let html = render_markdown_to_html(md);
+
+        assert!(html.contains(r#"class="footnote-ref""#));
+        assert!(html.contains(r#"data-footnote="fn-1""#));
+        assert!(html.contains(r#"<li id="fn-1">"#));
+        assert!(html.contains("footnote-backref"));
+    }
+
+    #[test]
+    fn test_callouts() {
+        let md = "> [!warning]- Cuidado\n> Texto con **negrita**.\n\nDespués.";
+        let html = render_markdown_to_html(md);
+
+        assert!(html.contains(r#"<details class="callout callout-warning">"#));
+        assert!(html.contains("<strong>negrita</strong>"));
+        assert!(!html.contains("<blockquote>"));
+        assert!(html.contains("<p>Después.</p>"));
+    }
+
+    #[test]
+    fn test_highlights() {
+        let html = render_markdown_to_html(
+            "Esto ==es **clave**== y =={blue}esto también==.\n\n`==código==`",
+        );
+
+        assert!(html.contains(
+            r#"<mark class="highlight highlight-yellow">es <strong>clave</strong></mark>"#
+        ));
+        assert!(html.contains(r#"<mark class="highlight highlight-blue">esto también</mark>"#));
+        assert!(html.contains("<code>==código==</code>"));
+    }
+
+    #[test]
+    fn test_task_progress_under_headings() {
+        let md = "# Compras\n\n- [x] Pan\n- [ ] Leche\n\n## Notas\n\nSin tareas\n\n## Hecho\n\n- [x] Todo\n";
+        let html = render_markdown_to_html(md);
+
+        assert_eq!(html.matches("task-progress-label").count(), 2);
+        assert!(html.contains(r#"<span style="width: 50%"></span>"#));
+        assert!(html.contains("1/2 · 50%"));
+        assert!(html.contains(r#"class="task-progress complete""#));
+        // La barra va justo debajo de su encabezado
+        assert!(html.find("Compras</h1>").unwrap() < html.find("1/2").unwrap());
+    }
+
+    #[test]
+    fn test_block_attributes() {
+        let md = "## Plan {.special}\n\n### Otro {#mio .a .b}\n\n::: warning\nCuidado con **esto**\n:::\n";
+        let mut renderer = HtmlRenderer::new(PreviewTheme::Dark);
+        renderer.set_heading_anchors(true);
+        renderer.set_custom_css(Some(".warning { color: red; }".to_string()));
+        let html = renderer.render(md);
+
+        assert!(html.contains(r#"<h2 id="plan" class="special">Plan"#));
+        assert!(html.contains(r#"<h3 id="mio" class="a b">Otro"#));
+        assert!(html.contains(r##"href="#mio""##));
+        assert!(!html.contains("{.special}"));
+        assert!(html.contains(r#"<div class="warning">"#));
+        assert!(html.contains("<strong>esto</strong>"));
+        assert!(html.contains(".warning { color: red; }"));
+        assert_eq!(heading_id("Otro {#mio .a}"), "mio");
+        assert_eq!(heading_id("Plan {.special}"), "plan");
+    }
+
+    #[test]
+    fn test_extended_task_states() {
+        let md = "# Tareas\n\n- [ ] Pendiente\n- [/] En curso\n- [x] Hecha\n- [-] Cancelada\n\n```\n- [-] en código\n```\n";
+        let html = render_markdown_to_html(md);
+
+        assert!(html.contains(r#"data-line="1" data-state="open""#));
+        assert!(html.contains(r#"data-line="2" data-state="in-progress""#));
+        assert!(html.contains(r#"data-line="3" data-state="done""#));
+        assert!(html.contains(r#"data-line="4" data-state="cancelled""#));
+        assert!(!html.contains("task-state"));
+        assert!(html.contains("- [-] en código"));
+        // La cancelada no cuenta: 1 de 3
+        assert!(html.contains("1/3 · 33%"));
+    }
+
+    #[test]
+    fn test_view_options() {
+        let md = "# Conexión al MCP\n\nTexto\n";
+        let mut renderer = HtmlRenderer::new(PreviewTheme::Light);
+        let plain = renderer.render(md);
+        assert!(plain.contains("<h1>Conexión al MCP</h1>"));
+        assert!(plain.contains(r#"<body class="light" "#));
+
+        renderer.set_density(PreviewDensity::Compact);
+        renderer.set_heading_anchors(true);
+        renderer.set_print_friendly(true);
+        let html = renderer.render(md);
+        assert!(html.contains(r##"<h1 id="conexión-al-mcp">Conexión al MCP<a class="heading-anchor" href="#conexión-al-mcp" aria-hidden="true">#</a></h1>"##));
+        assert!(html.contains(r#"<body class="light compact heading-anchors print-friendly" "#));
+        // Otro documento: el preview se recarga en lugar de parchear el contenido
+        assert_ne!(
+            renderer.document_key(),
+            HtmlRenderer::new(PreviewTheme::Light).document_key()
+        );
+
+        // Con IDs pero sin anclas visibles: destino de los enlaces @Nota#encabezado
+        let mut renderer = HtmlRenderer::new(PreviewTheme::Light);
+        renderer.set_heading_ids(true);
+        let html = renderer.render(md);
+        assert!(html.contains(r#"<h1 id="conexión-al-mcp">Conexión al MCP</h1>"#));
+    }
+
+    #[test]
+    fn test_source_map_markers() {
+        let md = "# Título\n\nHola [[Nota]]\n";
+        let mut renderer = HtmlRenderer::new(PreviewTheme::Light);
+        assert!(!renderer.render_body(md).contains("source-map"));
+
+        renderer.set_source_map(true);
+        let html = renderer.render_body(md);
+        assert!(
+            html.contains(
+                r#"<span class="source-map" data-source="2-9" data-line="1"></span>Título"#
+            )
+        );
+        assert!(html.contains(r#"data-source="11-24" data-line="3""#));
+    }
+}
@@ -0,0 +1,695 @@
+//! Conversión de HTML pegado (desde un navegador o un documento) a markdown
+//!
+//! No pretende ser un parser HTML completo: construye un árbol tolerante con
+//! etiquetas mal cerradas y convierte lo que tiene equivalente en markdown
+//! (encabezados, énfasis, links, imágenes, listas, citas, código y tablas).
+//! El resto de etiquetas se atraviesan y solo queda su texto.
+
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// Imágenes remotas `![alt](https://...)` del markdown convertido
+static REMOTE_IMAGE_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"!\[[^\]]*\]\((https?://[^)\s]+)\)").unwrap());
+
+/// Etiquetas sin cierre
+const VOID_TAGS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+    "wbr",
+];
+
+/// Etiquetas cuyo contenido no es parte del texto pegado
+const SKIPPED_TAGS: &[&str] = &[
+    "head", "script", "style", "title", "noscript", "template", "svg",
+];
+
+const BLOCK_TAGS: &[&str] = &[
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "body",
+    "dd",
+    "div",
+    "dl",
+    "dt",
+    "figcaption",
+    "figure",
+    "footer",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hr",
+    "html",
+    "li",
+    "main",
+    "nav",
+    "ol",
+    "p",
+    "pre",
+    "section",
+    "table",
+    "ul",
+];
+
+/// Atributos de un elemento, en orden: `(nombre, valor)`
+type Attrs = Vec<(String, String)>;
+
+/// Elemento abierto mientras se parsea: `(nombre, atributos, hijos)`
+type OpenElement = (String, Attrs, Vec<Node>);
+
+#[derive(Debug)]
+enum Node {
+    Text(String),
+    Element {
+        name: String,
+        attrs: Attrs,
+        children: Vec<Node>,
+    },
+}
+
+impl Node {
+    fn attr(&self, key: &str) -> Option<&str> {
+        match self {
+            Node::Element { attrs, .. } => attrs
+                .iter()
+                .find(|(name, _)| name == key)
+                .map(|(_, value)| value.as_str()),
+            Node::Text(_) => None,
+        }
+    }
+
+    fn is_block(&self) -> bool {
+        match self {
+            Node::Element { name, children, .. } => {
+                BLOCK_TAGS.contains(&name.as_str()) || children.iter().any(Node::is_block)
+            }
+            Node::Text(_) => false,
+        }
+    }
+}
+
+/// Convierte HTML en markdown limpio
+pub fn html_to_markdown(html: &str) -> String {
+    let nodes = parse(html);
+    let mut blocks = Vec::new();
+    render_blocks(&nodes, &mut blocks);
+    blocks.join("\n\n").trim().to_string()
+}
+
+/// URLs de las imágenes remotas del markdown, en orden y sin repetir, para
+/// descargarlas a los adjuntos de la nota
+pub fn remote_image_urls(markdown: &str) -> Vec<String> {
+    let mut urls: Vec<String> = Vec::new();
+    for caps in REMOTE_IMAGE_RE.captures_iter(markdown) {
+        if !urls.iter().any(|url| url == &caps[1]) {
+            urls.push(caps[1].to_string());
+        }
+    }
+    urls
+}
+
+/// Título de una página web: `<title>` o, si no hay, `og:title`
+pub fn page_title(html: &str) -> Option<String> {
+    static TITLE_RE: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"(?is)<title[^>]*>(.*?)</title>").unwrap());
+    static OG_TITLE_RE: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r#"(?is)<meta[^>]+property=["']og:title["'][^>]+content=["']([^"']*)["']"#)
+            .unwrap()
+    });
+
+    [&TITLE_RE, &OG_TITLE_RE]
+        .iter()
+        .filter_map(|re| re.captures(html))
+        .map(|caps| {
+            collapse_whitespace(&decode_entities(&caps[1]))
+                .trim()
+                .to_string()
+        })
+        .find(|title| !title.is_empty())
+}
+
+/// Link markdown `[título](url)`, escapando los corchetes del título
+pub fn markdown_link(title: &str, url: &str) -> String {
+    let title = title.replace('[', "\\[").replace(']', "\\]");
+    format!("[{title}]({})", url.replace(' ', "%20"))
+}
+
+// ============================================================================
+// Árbol HTML
+// ============================================================================
+
+fn parse(html: &str) -> Vec<Node> {
+    // Pila de elementos abiertos
+    let mut stack: Vec<OpenElement> = vec![(String::new(), Vec::new(), Vec::new())];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(lt) = rest.find('<') else {
+            push_text(&mut stack, rest);
+            break;
+        };
+        push_text(&mut stack, &rest[..lt]);
+        rest = &rest[lt..];
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+        let Some(gt) = rest.find('>') else {
+            push_text(&mut stack, rest);
+            break;
+        };
+        let tag = &rest[1..gt];
+        rest = &rest[gt + 1..];
+
+        if tag.starts_with('!') || tag.starts_with('?') {
+            continue;
+        }
+        if let Some(name) = tag.strip_prefix('/') {
+            close(&mut stack, &name.trim().to_lowercase());
+            continue;
+        }
+
+        let self_closing = tag.ends_with('/');
+        let tag = tag.trim_end_matches('/');
+        let name_end = tag.find(|c: char| c.is_whitespace()).unwrap_or(tag.len());
+        let name = tag[..name_end].to_lowercase();
+        if name.is_empty() {
+            continue;
+        }
+
+        if SKIPPED_TAGS.contains(&name.as_str()) {
+            let closing = format!("</{name}");
+            rest = match rest.to_ascii_lowercase().find(&closing) {
+                Some(end) => rest[end..].find('>').map_or("", |gt| &rest[end + gt + 1..]),
+                None => "",
+            };
+            continue;
+        }
+
+        // Un <li> o <p> nuevo cierra el anterior que siga abierto
+        if matches!(name.as_str(), "li" | "p" | "tr" | "td" | "th")
+            && stack.last().is_some_and(|(open, _, _)| *open == name)
+        {
+            close(&mut stack, &name);
+        }
+
+        let attrs = parse_attrs(&tag[name_end..]);
+        if self_closing || VOID_TAGS.contains(&name.as_str()) {
+            if let Some((_, _, children)) = stack.last_mut() {
+                children.push(Node::Element {
+                    name,
+                    attrs,
+                    children: Vec::new(),
+                });
+            }
+        } else {
+            stack.push((name, attrs, Vec::new()));
+        }
+    }
+
+    while stack.len() > 1 {
+        let open = stack
+            .last()
+            .map(|(name, _, _)| name.clone())
+            .unwrap_or_default();
+        close(&mut stack, &open);
+    }
+    stack
+        .pop()
+        .map(|(_, _, children)| children)
+        .unwrap_or_default()
+}
+
+fn push_text(stack: &mut [OpenElement], text: &str) {
+    if text.is_empty() {
+        return;
+    }
+    if let Some((_, _, children)) = stack.last_mut() {
+        children.push(Node::Text(decode_entities(text)));
+    }
+}
+
+/// Cierra `name` y lo que quede abierto dentro; un cierre sin apertura se ignora
+fn close(stack: &mut Vec<OpenElement>, name: &str) {
+    let Some(position) = stack.iter().rposition(|(open, _, _)| open == name) else {
+        return;
+    };
+    if position == 0 {
+        return;
+    }
+    while stack.len() > position {
+        let Some((name, attrs, children)) = stack.pop() else {
+            break;
+        };
+        if let Some((_, _, parent)) = stack.last_mut() {
+            parent.push(Node::Element {
+                name,
+                attrs,
+                children,
+            });
+        }
+    }
+}
+
+fn parse_attrs(text: &str) -> Attrs {
+    static ATTR_RE: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r#"([^\s=/"']+)(?:\s*=\s*(?:"([^"]*)"|'([^']*)'|([^\s>]+)))?"#).unwrap()
+    });
+    ATTR_RE
+        .captures_iter(text)
+        .map(|caps| {
+            let value = caps
+                .get(2)
+                .or(caps.get(3))
+                .or(caps.get(4))
+                .map_or("", |m| m.as_str());
+            (caps[1].to_lowercase(), decode_entities(value))
+        })
+        .collect()
+}
+
+fn decode_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+    let mut output = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(amp) = rest.find('&') {
+        output.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        let entity = rest[1..]
+            .find(';')
+            .filter(|end| *end <= 10)
+            .map(|end| &rest[1..end + 1]);
+        let decoded = entity.and_then(|entity| match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => {
+                let number = entity.strip_prefix('#')?;
+                let code = match number.strip_prefix(['x', 'X']) {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                    None => number.parse().ok()?,
+                };
+                char::from_u32(code)
+            }
+        });
+        match (entity, decoded) {
+            (Some(entity), Some(ch)) => {
+                output.push(ch);
+                rest = &rest[entity.len() + 2..];
+            }
+            _ => {
+                output.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    output.push_str(rest);
+    output
+}
+
+// ============================================================================
+// Markdown
+// ============================================================================
+
+/// Añade a `blocks` los bloques markdown de `nodes`. El texto suelto entre
+/// bloques se agrupa en un párrafo.
+fn render_blocks(nodes: &[Node], blocks: &mut Vec<String>) {
+    let mut pending: Vec<&Node> = Vec::new();
+    for node in nodes {
+        if node.is_block() {
+            flush_inline(&mut pending, blocks);
+            render_block(node, blocks);
+        } else {
+            pending.push(node);
+        }
+    }
+    flush_inline(&mut pending, blocks);
+}
+
+fn flush_inline(pending: &mut Vec<&Node>, blocks: &mut Vec<String>) {
+    let text: String = pending.iter().map(|node| render_inline(node)).collect();
+    pending.clear();
+    let text = tidy_inline(&text);
+    if !text.is_empty() {
+        blocks.push(text);
+    }
+}
+
+fn render_block(node: &Node, blocks: &mut Vec<String>) {
+    let Node::Element { name, children, .. } = node else {
+        return;
+    };
+    match name.as_str() {
+        "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+            let level = name[1..].parse::<usize>().unwrap_or(1);
+            let text = tidy_inline(&children.iter().map(render_inline).collect::<String>());
+            if !text.is_empty() {
+                blocks.push(format!("{} {}", "#".repeat(level), text.replace('\n', " ")));
+            }
+        }
+        "hr" => blocks.push("---".to_string()),
+        "pre" => {
+            // El lenguaje suele venir en <code class="language-x">
+            let language = children
+                .iter()
+                .find_map(|child| child.attr("class"))
+                .and_then(|class| {
+                    class
+                        .split_whitespace()
+                        .find_map(|c| c.strip_prefix("language-").or(c.strip_prefix("lang-")))
+                })
+                .unwrap_or("");
+            let code = plain_text(children);
+            let fence = if code.contains("```") { "~~~" } else { "```" };
+            blocks.push(format!(
+                "{fence}{language}\n{}\n{fence}",
+                code.trim_end_matches('\n')
+            ));
+        }
+        "blockquote" => {
+            let mut inner = Vec::new();
+            render_blocks(children, &mut inner);
+            let quoted: Vec<String> = inner
+                .join("\n\n")
+                .lines()
+                .map(|line| {
+                    if line.is_empty() {
+                        ">".to_string()
+                    } else {
+                        format!("> {line}")
+                    }
+                })
+                .collect();
+            if !quoted.is_empty() {
+                blocks.push(quoted.join("\n"));
+            }
+        }
+        "ul" | "ol" => {
+            let list = render_list(node);
+            if !list.is_empty() {
+                blocks.push(list);
+            }
+        }
+        "table" => {
+            let table = render_table(node);
+            if !table.is_empty() {
+                blocks.push(table);
+            }
+        }
+        _ => render_blocks(children, blocks),
+    }
+}
+
+fn render_list(node: &Node) -> String {
+    let Node::Element { name, children, .. } = node else {
+        return String::new();
+    };
+    let ordered = name == "ol";
+    let mut number = node
+        .attr("start")
+        .and_then(|start| start.parse::<usize>().ok())
+        .unwrap_or(1);
+
+    let mut items = Vec::new();
+    for child in children {
+        let item_children: &[Node] = match child {
+            Node::Element { name, children, .. } if name == "li" => children,
+            // Listas anidadas directamente en la lista (HTML inválido pero común)
+            Node::Element { name, .. } if name == "ul" || name == "ol" => {
+                if let Some(last) = items.last_mut() {
+                    let nested = render_list(child);
+                    push_item_continuation(last, &nested, 2);
+                }
+                continue;
+            }
+            _ => continue,
+        };
+
+        let marker = if ordered {
+            let marker = format!("{number}. ");
+            number += 1;
+            marker
+        } else {
+            "- ".to_string()
+        };
+
+        let mut blocks = Vec::new();
+        render_blocks(item_children, &mut blocks);
+        let mut item = marker.clone();
+        for (index, block) in blocks.iter().enumerate() {
+            if index == 0 {
+                item.push_str(&indent_rest(block, marker.len()));
+            } else {
+                push_item_continuation(&mut item, block, marker.len());
+            }
+        }
+        items.push(item.trim_end().to_string());
+    }
+    items.join("\n")
+}
+
+/// Añade un bloque al elemento de lista, sangrado bajo su marcador
+fn push_item_continuation(item: &mut String, block: &str, width: usize) {
+    item.push('\n');
+    item.push_str(&" ".repeat(width));
+    item.push_str(&indent_rest(block, width));
+}
+
+/// Sangra todas las líneas menos la primera
+fn indent_rest(text: &str, width: usize) -> String {
+    let padding = " ".repeat(width);
+    text.lines()
+        .enumerate()
+        .map(|(index, line)| {
+            if index == 0 || line.is_empty() {
+                line.to_string()
+            } else {
+                format!("{padding}{line}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn render_table(node: &Node) -> String {
+    fn collect_rows<'a>(nodes: &'a [Node], rows: &mut Vec<&'a [Node]>) {
+        for node in nodes {
+            if let Node::Element { name, children, .. } = node {
+                match name.as_str() {
+                    "tr" => rows.push(children),
+                    "table" => {} // Tablas anidadas: no caben en markdown
+                    _ => collect_rows(children, rows),
+                }
+            }
+        }
+    }
+
+    let Node::Element { children, .. } = node else {
+        return String::new();
+    };
+    let mut rows = Vec::new();
+    collect_rows(children, &mut rows);
+
+    let rows: Vec<Vec<String>> = rows
+        .into_iter()
+        .map(|cells| {
+            cells
+                .iter()
+                .filter(|cell| {
+                    matches!(cell, Node::Element { name, .. } if name == "td" || name == "th")
+                })
+                .map(|cell| {
+                    tidy_inline(&render_inline(cell))
+                        .replace('\n', " ")
+                        .replace('|', "\\|")
+                })
+                .collect()
+        })
+        .filter(|cells: &Vec<String>| !cells.is_empty())
+        .collect();
+    let columns = rows.iter().map(Vec::len).max().unwrap_or(0);
+    if columns == 0 {
+        return String::new();
+    }
+
+    let line = |cells: &[String]| {
+        let mut cells = cells.to_vec();
+        cells.resize(columns, String::new());
+        format!("| {} |", cells.join(" | "))
+    };
+    let mut lines = vec![line(&rows[0]), format!("|{}", "---|".repeat(columns))];
+    lines.extend(rows[1..].iter().map(|row| line(row)));
+    lines.join("\n")
+}
+
+fn render_inline(node: &Node) -> String {
+    let (name, children) = match node {
+        Node::Text(text) => return collapse_whitespace(text),
+        Node::Element { name, children, .. } => (name.as_str(), children),
+    };
+    let inner = || children.iter().map(render_inline).collect::<String>();
+
+    match name {
+        "br" => "\n".to_string(),
+        "strong" | "b" => wrap_emphasis(&inner(), "**"),
+        "em" | "i" => wrap_emphasis(&inner(), "*"),
+        "del" | "s" | "strike" => wrap_emphasis(&inner(), "~~"),
+        "code" | "kbd" | "samp" => {
+            let code = plain_text(children);
+            let fence = if code.contains('`') { "``" } else { "`" };
+            format!("{fence}{code}{fence}")
+        }
+        "a" => {
+            let text = inner();
+            match node.attr("href") {
+                Some(href)
+                    if !href.is_empty()
+                        && !href.starts_with('#')
+                        && !href.starts_with("javascript:") =>
+                {
+                    let text = text.trim();
+                    if text.is_empty() {
+                        format!("<{href}>")
+                    } else {
+                        format!("[{text}]({})", href.replace(' ', "%20"))
+                    }
+                }
+                _ => text,
+            }
+        }
+        "img" => match node.attr("src") {
+            Some(src) if !src.is_empty() && !src.starts_with("data:") => {
+                let alt = node.attr("alt").unwrap_or("").replace(['[', ']'], "");
+                format!("![{alt}]({})", src.replace(' ', "%20"))
+            }
+            _ => String::new(),
+        },
+        // Bloques dentro de una línea (celdas de tabla, links): solo su texto
+        _ if BLOCK_TAGS.contains(&name) => format!(" {} ", inner()),
+        _ => inner(),
+    }
+}
+
+/// Envuelve en `**`/`*`/`~~` dejando fuera los espacios de los extremos, que
+/// en markdown anularían el énfasis
+fn wrap_emphasis(text: &str, mark: &str) -> String {
+    let trimmed = text.trim();
+    if trimmed.is_empty() {
+        return text.to_string();
+    }
+    let leading = &text[..text.len() - text.trim_start().len()];
+    let trailing = &text[text.trim_end().len()..];
+    format!("{leading}{mark}{trimmed}{mark}{trailing}")
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut last_space = false;
+    for ch in text.chars() {
+        if ch.is_whitespace() && ch != '\u{a0}' {
+            if !last_space {
+                output.push(' ');
+            }
+            last_space = true;
+        } else {
+            output.push(if ch == '\u{a0}' { ' ' } else { ch });
+            last_space = false;
+        }
+    }
+    output
+}
+
+/// Quita los espacios de cada línea de un bloque de texto en línea
+fn tidy_inline(text: &str) -> String {
+    text.lines()
+        .map(str::trim)
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
+
+/// Texto literal (para código), sin tocar espacios ni saltos de línea
+fn plain_text(nodes: &[Node]) -> String {
+    nodes
+        .iter()
+        .map(|node| match node {
+            Node::Text(text) => text.clone(),
+            Node::Element { name, .. } if name == "br" => "\n".to_string(),
+            Node::Element { children, .. } => plain_text(children),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inline_and_headings() {
+        let html = "<meta charset='utf-8'><!--StartFragment--><h2>Título &amp; más</h2>\
+                    <p>Texto <b>negrita </b>y <em>cursiva</em>, <code>a &lt; b</code> y \
+                    <a href=\"https://example.com/a b\">un link</a>.</p><!--EndFragment-->";
+        assert_eq!(
+            html_to_markdown(html),
+            "## Título & más\n\nTexto **negrita** y *cursiva*, `a < b` y \
+             [un link](https://example.com/a%20b)."
+        );
+    }
+
+    #[test]
+    fn test_lists_quotes_and_code() {
+        let html = "<ul><li>Uno<ul><li>Uno.a</li></ul></li><li><p>Dos</p></li></ul>\
+                    <ol start=\"3\"><li>Tres<li>Cuatro</ol>\
+                    <blockquote><p>Cita</p><p>Otra</p></blockquote>\
+                    <pre><code class=\"language-rust\">fn main() {\n    x();\n}\n</code></pre>";
+        assert_eq!(
+            html_to_markdown(html),
+            "- Uno\n  - Uno.a\n- Dos\n\n3. Tres\n4. Cuatro\n\n> Cita\n>\n> Otra\n\n\
+             ```rust\nfn main() {\n    x();\n}\n```"
+        );
+    }
+
+    #[test]
+    fn test_tables_images_and_noise() {
+        let html = "<html><head><style>p{}</style></head><body>\
+                    <table><thead><tr><th>Nombre</th><th>Valor</th></tr></thead>\
+                    <tbody><tr><td>a|b</td><td><strong>1</strong></td></tr><tr><td>c</td></tr></tbody></table>\
+                    <div>Suelto <img src=\"https://x.com/foto.png\" alt=\"Foto\"> \
+                    <img src=\"data:image/png;base64,AAA\"><script>alert(1)</script></div>\
+                    </body></html>";
+        let markdown = html_to_markdown(html);
+        assert_eq!(
+            markdown,
+            "| Nombre | Valor |\n|---|---|\n| a\\|b | **1** |\n| c |  |\n\n\
+             Suelto ![Foto](https://x.com/foto.png)"
+        );
+        assert_eq!(remote_image_urls(&markdown), ["https://x.com/foto.png"]);
+    }
+
+    #[test]
+    fn test_page_title_and_link() {
+        let html = "<html><head><meta charset=\"utf-8\">\n<TITLE>\n  Rust &amp; GTK\n  | Blog</TITLE></head>";
+        assert_eq!(page_title(html).as_deref(), Some("Rust & GTK | Blog"));
+
+        let og = r#"<title> </title><meta property="og:title" content="Solo OG">"#;
+        assert_eq!(page_title(og).as_deref(), Some("Solo OG"));
+        assert_eq!(page_title("<p>Sin título</p>"), None);
+
+        assert_eq!(
+            markdown_link("[RFC] Propuesta", "https://a.com/x y"),
+            r"[\[RFC\] Propuesta](https://a.com/x%20y)"
+        );
+    }
+}
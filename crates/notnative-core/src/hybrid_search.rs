@@ -0,0 +1,237 @@
+//! Búsqueda híbrida: texto (FTS5/BM25) y embeddings a la vez
+//!
+//! Las puntuaciones de las dos búsquedas no son comparables (el `rank` de FTS5
+//! es negativo y la similitud va de 0 a 1), así que se funden por posición con
+//! Reciprocal Rank Fusion: cada lista suma `1 / (k + puesto)` a sus notas. Una
+//! nota que aparece arriba en las dos gana a la que solo destaca en una.
+//!
+//! Los embeddings devuelven fragmentos (`ruta#índice`), no notas:
+//! [`semantic_results`] los pasa a notas del índice antes de fundir nada.
+
+use std::collections::HashMap;
+
+use crate::database::{NotesDatabase, Result, SearchResult};
+
+/// `k` habitual de RRF; cuanto más alto, menos pesa ir primero
+pub const RRF_K: f32 = 60.0;
+
+/// Modo de la búsqueda global. Ctrl en la barra de búsqueda pasa al siguiente.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SearchMode {
+    /// Texto y embeddings fundidos (sin embeddings, solo texto)
+    #[default]
+    Hybrid,
+    /// Solo embeddings, con la respuesta del asistente
+    Semantic,
+    /// Solo texto
+    Normal,
+}
+
+impl SearchMode {
+    pub fn next(self) -> Self {
+        match self {
+            SearchMode::Hybrid => SearchMode::Semantic,
+            SearchMode::Semantic => SearchMode::Normal,
+            SearchMode::Normal => SearchMode::Hybrid,
+        }
+    }
+
+    /// ¿Consulta los embeddings?
+    pub fn uses_embeddings(self) -> bool {
+        self != SearchMode::Normal
+    }
+}
+
+/// Pasa los aciertos de los embeddings, `(similitud, fragmento)`, a resultados
+/// por nota. Cada nota sale una vez, con la similitud de su mejor fragmento y
+/// ordenadas de más a menos parecida; los fragmentos de notas que ya no están
+/// en el índice se descartan.
+pub fn semantic_results(
+    db: &NotesDatabase,
+    hits: impl IntoIterator<Item = (f32, String)>,
+) -> Result<Vec<SearchResult>> {
+    let mut results: Vec<SearchResult> = Vec::new();
+    for (score, chunk_id) in hits {
+        let Some(note) = db.note_for_chunk(&chunk_id)? else {
+            continue;
+        };
+        match results.iter_mut().find(|r| r.note_id == note.id) {
+            Some(existing) => {
+                if score > existing.relevance {
+                    existing.relevance = score;
+                    existing.similarity = Some(score);
+                    existing.snippet = format!("Relevancia: {:.2}", score);
+                }
+            }
+            None => results.push(SearchResult {
+                note_id: note.id,
+                note_name: note.name,
+                note_path: note.path,
+                snippet: format!("Relevancia: {:.2}", score),
+                relevance: score,
+                matched_tags: vec![],
+                similarity: Some(score),
+            }),
+        }
+    }
+    results.sort_by(|a, b| {
+        b.relevance
+            .partial_cmp(&a.relevance)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    Ok(results)
+}
+
+/// Funde listas ya ordenadas (mejor primero) con Reciprocal Rank Fusion. Las
+/// notas repetidas salen una vez, con el fragmento de texto si lo hay y la
+/// similitud si alguna lista la trae; `relevance` pasa a ser la puntuación
+/// fundida.
+pub fn reciprocal_rank_fusion(lists: &[Vec<SearchResult>], k: f32) -> Vec<SearchResult> {
+    let mut fused: Vec<SearchResult> = Vec::new();
+    let mut positions: HashMap<(i64, String), usize> = HashMap::new();
+
+    for list in lists {
+        for (rank, result) in list.iter().enumerate() {
+            let score = 1.0 / (k + rank as f32 + 1.0);
+            // Las notas sin id (no indexadas) se distinguen por su ruta
+            let key = if result.note_id != 0 {
+                (result.note_id, String::new())
+            } else {
+                (0, result.note_path.clone())
+            };
+
+            match positions.get(&key) {
+                Some(&index) => {
+                    let existing = &mut fused[index];
+                    existing.relevance += score;
+                    if existing.similarity.is_none() {
+                        existing.similarity = result.similarity;
+                    } else if result.similarity.is_none() {
+                        // El fragmento de FTS dice más que "Relevancia: 0.83"
+                        existing.snippet = result.snippet.clone();
+                    }
+                    for tag in &result.matched_tags {
+                        if !existing.matched_tags.contains(tag) {
+                            existing.matched_tags.push(tag.clone());
+                        }
+                    }
+                }
+                None => {
+                    positions.insert(key, fused.len());
+                    fused.push(SearchResult {
+                        relevance: score,
+                        ..result.clone()
+                    });
+                }
+            }
+        }
+    }
+
+    // Estable: a igual puntuación queda el orden de llegada
+    fused.sort_by(|a, b| {
+        b.relevance
+            .partial_cmp(&a.relevance)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    fused
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(note_id: i64, name: &str, similarity: Option<f32>) -> SearchResult {
+        SearchResult {
+            note_id,
+            note_name: name.to_string(),
+            note_path: format!("/n/{}.md", name),
+            snippet: if similarity.is_some() {
+                "Relevancia".to_string()
+            } else {
+                format!("<mark>{}</mark>", name)
+            },
+            relevance: -1.0,
+            matched_tags: vec![],
+            similarity,
+        }
+    }
+
+    #[test]
+    fn test_fusion_rewards_notes_found_by_both() {
+        let text = vec![result(1, "a", None), result(2, "b", None)];
+        let semantic = vec![result(3, "c", Some(0.9)), result(2, "b", Some(0.8))];
+
+        let fused = reciprocal_rank_fusion(&[text, semantic], RRF_K);
+        let names: Vec<&str> = fused.iter().map(|r| r.note_name.as_str()).collect();
+        assert_eq!(names, vec!["b", "a", "c"]);
+
+        // "b" sale una vez, con el fragmento de texto y la similitud
+        assert_eq!(fused[0].snippet, "<mark>b</mark>");
+        assert_eq!(fused[0].similarity, Some(0.8));
+        assert!(fused.iter().all(|r| r.relevance > 0.0));
+    }
+
+    #[test]
+    fn test_fusion_of_single_list_keeps_order() {
+        let text = vec![
+            result(5, "x", None),
+            result(0, "y", None),
+            result(0, "z", None),
+        ];
+        let fused = reciprocal_rank_fusion(&[text, vec![]], RRF_K);
+        let names: Vec<&str> = fused.iter().map(|r| r.note_name.as_str()).collect();
+        assert_eq!(names, vec!["x", "y", "z"]);
+    }
+
+    #[test]
+    fn test_semantic_chunks_fuse_with_text_hits() {
+        let db_path = std::env::temp_dir().join("test_hybrid_chunks.db");
+        std::fs::remove_file(&db_path).ok();
+        let db = NotesDatabase::new(&db_path).unwrap();
+        db.index_note("Work/plan", "/v/Work/plan.md", "plan", Some("Work"))
+            .unwrap();
+        db.index_note("ideas", "/v/ideas.md", "ideas", None)
+            .unwrap();
+        let plan = db.get_note("Work/plan").unwrap().unwrap();
+
+        // Varios fragmentos de la misma nota y uno de una nota borrada
+        let hits = vec![
+            (0.91, "/v/Work/plan.md#3".to_string()),
+            (0.85, "/v/ideas.md#0".to_string()),
+            (0.80, "/v/Work/plan.md#0".to_string()),
+            (0.75, "/v/gone.md#1".to_string()),
+            (0.70, "ideas#2".to_string()),
+        ];
+        let semantic = semantic_results(&db, hits).unwrap();
+        let names: Vec<&str> = semantic.iter().map(|r| r.note_name.as_str()).collect();
+        assert_eq!(names, vec!["Work/plan", "ideas"]);
+        assert_eq!(semantic[0].note_id, plan.id);
+        assert_eq!(semantic[0].note_path, "/v/Work/plan.md");
+        assert_eq!(semantic[0].similarity, Some(0.91));
+
+        let text = vec![SearchResult {
+            note_id: plan.id,
+            note_name: plan.name.clone(),
+            note_path: plan.path.clone(),
+            snippet: "<mark>plan</mark>".to_string(),
+            relevance: -2.0,
+            matched_tags: vec![],
+            similarity: None,
+        }];
+        let fused = reciprocal_rank_fusion(&[text, semantic], RRF_K);
+        let names: Vec<&str> = fused.iter().map(|r| r.note_name.as_str()).collect();
+        assert_eq!(names, vec!["Work/plan", "ideas"]);
+        assert_eq!(fused[0].snippet, "<mark>plan</mark>");
+        assert_eq!(fused[0].similarity, Some(0.91));
+
+        std::fs::remove_file(db_path).ok();
+    }
+
+    #[test]
+    fn test_search_mode_cycle() {
+        assert_eq!(SearchMode::default().next(), SearchMode::Semantic);
+        assert_eq!(SearchMode::Semantic.next(), SearchMode::Normal);
+        assert_eq!(SearchMode::Normal.next(), SearchMode::Hybrid);
+        assert!(!SearchMode::Normal.uses_embeddings());
+    }
+}
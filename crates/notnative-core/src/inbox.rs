@@ -0,0 +1,80 @@
+//! Bandeja de capturas (`Inbox`)
+//!
+//! Un atajo global (`echo 'capture' > /tmp/notnative.control`) añade lo que
+//! haya en el portapapeles al final de la nota `Inbox`, cada captura bajo su
+//! propio encabezado con la hora. Las capturas que siguen ahí están sin
+//! procesar: al moverlas a su sitio o borrarlas desaparecen de la cuenta.
+
+/// Nota (en la raíz del vault) que recibe las capturas
+pub const INBOX_NOTE: &str = "Inbox";
+
+/// Inicio del encabezado de cada captura (`## 📥 2026-10-16 09:30`)
+const CAPTURE_PREFIX: &str = "## 📥 ";
+
+/// Lo que se captura del portapapeles
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Capture {
+    Text(String),
+    /// Imagen ya guardada en assets (ruta del archivo)
+    Image(String),
+}
+
+/// Añade `capture` al final de `inbox` con la hora `timestamp`. Si la nota
+/// está vacía empieza con el título `# Inbox`.
+pub fn append_capture(inbox: &str, capture: &Capture, timestamp: &str) -> String {
+    let mut output = inbox.trim_end().to_string();
+    if output.is_empty() {
+        output.push_str(&format!("# {}", INBOX_NOTE));
+    }
+    output.push_str(&format!("\n\n{}{}\n\n", CAPTURE_PREFIX, timestamp));
+    match capture {
+        Capture::Text(text) => output.push_str(text.trim()),
+        Capture::Image(path) => output.push_str(&format!("![captura]({})", path)),
+    }
+    output.push('\n');
+    output
+}
+
+/// Capturas que quedan por procesar en `inbox`
+pub fn pending_captures(inbox: &str) -> usize {
+    inbox
+        .lines()
+        .filter(|line| line.starts_with(CAPTURE_PREFIX))
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_append_capture() {
+        let inbox = append_capture(
+            "",
+            &Capture::Text("  https://example.com\n".into()),
+            "2026-10-16 09:30",
+        );
+        assert_eq!(
+            inbox,
+            "# Inbox\n\n## 📥 2026-10-16 09:30\n\nhttps://example.com\n"
+        );
+
+        let inbox = append_capture(
+            &inbox,
+            &Capture::Image("/assets/clipboard_1.png".into()),
+            "2026-10-16 10:00",
+        );
+        assert!(inbox.ends_with(
+            "example.com\n\n## 📥 2026-10-16 10:00\n\n![captura](/assets/clipboard_1.png)\n"
+        ));
+        assert_eq!(pending_captures(&inbox), 2);
+    }
+
+    #[test]
+    fn test_pending_captures() {
+        // Solo cuentan los encabezados de captura
+        let inbox = "# Inbox\n\n## Ideas\n\n## 📥 2026-10-16 09:30\n\nTexto\n";
+        assert_eq!(pending_captures(inbox), 1);
+        assert_eq!(pending_captures("# Inbox\n"), 0);
+    }
+}
@@ -0,0 +1,129 @@
+//! Nombres de notas y carpetas al renombrarlas
+//!
+//! Un nombre no puede llevar separadores de ruta ni caracteres que otros
+//! sistemas de archivos no aceptan, ni empezar por `.` (quedaría oculto como
+//! `.trash`). Si ya existe, se propone el primer `nombre (2)`, `nombre (3)`...
+//! libre.
+
+/// Caracteres que no se aceptan en el nombre de una nota o carpeta
+pub const ILLEGAL_NAME_CHARS: [char; 9] = ['/', '\\', ':', '*', '?', '"', '<', '>', '|'];
+
+/// Por qué no vale un nombre
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NameProblem {
+    Empty,
+    IllegalChar(char),
+    /// Empieza por `.`
+    Hidden,
+    /// Ya hay una nota o carpeta con ese nombre; `suggestion` está libre
+    Taken {
+        suggestion: String,
+    },
+}
+
+/// Problema de `name` (ya sin espacios alrededor) o `None` si vale. `exists`
+/// dice si un nombre ya está ocupado en la carpeta.
+pub fn check_item_name(name: &str, exists: impl Fn(&str) -> bool) -> Option<NameProblem> {
+    if name.is_empty() {
+        return Some(NameProblem::Empty);
+    }
+    if let Some(c) = name
+        .chars()
+        .find(|c| ILLEGAL_NAME_CHARS.contains(c) || c.is_control())
+    {
+        return Some(NameProblem::IllegalChar(c));
+    }
+    if name.starts_with('.') {
+        return Some(NameProblem::Hidden);
+    }
+    if exists(name) {
+        return Some(NameProblem::Taken {
+            suggestion: free_name(name, exists),
+        });
+    }
+    None
+}
+
+/// Primer `base (n)` libre, con `n` desde 2. Si `name` ya acaba en ` (n)`, se
+/// sigue contando desde ahí.
+pub fn free_name(name: &str, exists: impl Fn(&str) -> bool) -> String {
+    let (base, mut number) = split_number(name).unwrap_or((name, 1));
+    loop {
+        number += 1;
+        let candidate = format!("{} ({})", base, number);
+        if !exists(&candidate) {
+            return candidate;
+        }
+    }
+}
+
+/// Nombre de `name` tras renombrar `old` a `new`: la propia nota, o lo que
+/// haya dentro si `old` es una carpeta. `None` si no le afecta.
+pub fn renamed_item(name: &str, old: &str, new: &str) -> Option<String> {
+    if name == old {
+        return Some(new.to_string());
+    }
+    let rest = name.strip_prefix(old)?.strip_prefix('/')?;
+    Some(format!("{}/{}", new, rest))
+}
+
+/// `"Idea (3)"` → `("Idea", 3)`
+fn split_number(name: &str) -> Option<(&str, usize)> {
+    let rest = name.strip_suffix(')')?;
+    let open = rest.rfind(" (")?;
+    let number = rest[open + 2..].parse().ok()?;
+    Some((&rest[..open], number))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_item_name() {
+        let taken = ["Idea", "Idea (2)"];
+        let exists = |name: &str| taken.contains(&name);
+
+        assert_eq!(check_item_name("Nueva idea", exists), None);
+        assert_eq!(check_item_name("", exists), Some(NameProblem::Empty));
+        assert_eq!(
+            check_item_name("a/b", exists),
+            Some(NameProblem::IllegalChar('/'))
+        );
+        assert_eq!(
+            check_item_name("¿qué?", exists),
+            Some(NameProblem::IllegalChar('?'))
+        );
+        assert_eq!(
+            check_item_name(".oculta", exists),
+            Some(NameProblem::Hidden)
+        );
+        assert_eq!(
+            check_item_name("Idea", exists),
+            Some(NameProblem::Taken {
+                suggestion: "Idea (3)".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_renamed_item() {
+        assert_eq!(
+            renamed_item("Docs/plan", "Docs", "Proyecto").as_deref(),
+            Some("Proyecto/plan")
+        );
+        assert_eq!(
+            renamed_item("idea", "idea", "idea final").as_deref(),
+            Some("idea final")
+        );
+        assert_eq!(renamed_item("Docs2/plan", "Docs", "Proyecto"), None);
+    }
+
+    #[test]
+    fn test_free_name() {
+        assert_eq!(free_name("Plan", |_| false), "Plan (2)");
+        assert_eq!(free_name("Plan (4)", |_| false), "Plan (5)");
+        assert_eq!(free_name("Plan (x)", |_| false), "Plan (x) (2)");
+        assert_eq!(free_name("Plan", |name| name == "Plan (2)"), "Plan (3)");
+    }
+}
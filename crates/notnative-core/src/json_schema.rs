@@ -0,0 +1,249 @@
+//! Validación de valores JSON contra un JSON Schema
+//!
+//! Cubre la parte del estándar que usan las definiciones de herramientas:
+//! `type`, `properties`, `required`, `additionalProperties`, `enum`, `items`,
+//! `minimum` y `maximum`. Es estricta con las propiedades: una que el esquema
+//! no declara es un error salvo que `additionalProperties` lo permita. Un
+//! `null` en una propiedad opcional vale como si no estuviera.
+//!
+//! ```
+//! use notnative_core::validate_json;
+//! use serde_json::json;
+//!
+//! let schema = json!({
+//!     "type": "object",
+//!     "properties": { "name": { "type": "string" } },
+//!     "required": ["name"]
+//! });
+//! assert!(validate_json(&schema, &json!({ "name": "Ideas" })).is_empty());
+//! assert_eq!(
+//!     validate_json(&schema, &json!({ "name": 3 })),
+//!     vec!["name: se esperaba string, llegó number"]
+//! );
+//! ```
+
+use serde_json::Value;
+
+/// Errores de `value` frente a `schema` (vacío si es válido), con la ruta
+/// de cada uno (`notes[2].name: ...`)
+pub fn validate_json(schema: &Value, value: &Value) -> Vec<String> {
+    let mut errors = Vec::new();
+    validate_at(schema, value, "", &mut errors);
+    errors
+}
+
+fn validate_at(schema: &Value, value: &Value, path: &str, errors: &mut Vec<String>) {
+    let at = |message: String| {
+        if path.is_empty() {
+            message
+        } else {
+            format!("{}: {}", path, message)
+        }
+    };
+
+    if let Some(expected) = schema.get("type") {
+        let types: Vec<&str> = match expected {
+            Value::String(name) => vec![name.as_str()],
+            Value::Array(names) => names.iter().filter_map(Value::as_str).collect(),
+            _ => Vec::new(),
+        };
+        if !types.is_empty() && !types.iter().any(|name| has_type(value, name)) {
+            errors.push(at(format!(
+                "se esperaba {}, llegó {}",
+                types.join(" o "),
+                type_name(value)
+            )));
+            return;
+        }
+    }
+
+    if let Some(allowed) = schema.get("enum").and_then(Value::as_array) {
+        if !allowed.contains(value) {
+            let options: Vec<String> = allowed.iter().map(Value::to_string).collect();
+            errors.push(at(format!(
+                "{} no es uno de los valores permitidos ({})",
+                value,
+                options.join(", ")
+            )));
+        }
+    }
+
+    if let Some(number) = value.as_f64() {
+        if let Some(minimum) = schema.get("minimum").and_then(Value::as_f64) {
+            if number < minimum {
+                errors.push(at(format!("{} es menor que el mínimo {}", number, minimum)));
+            }
+        }
+        if let Some(maximum) = schema.get("maximum").and_then(Value::as_f64) {
+            if number > maximum {
+                errors.push(at(format!("{} es mayor que el máximo {}", number, maximum)));
+            }
+        }
+    }
+
+    if let (Some(items), Some(array)) = (schema.get("items"), value.as_array()) {
+        for (index, item) in array.iter().enumerate() {
+            validate_at(items, item, &format!("{}[{}]", path, index), errors);
+        }
+    }
+
+    let Some(object) = value.as_object() else {
+        return;
+    };
+    let child = |key: &str| {
+        if path.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}.{}", path, key)
+        }
+    };
+    let properties = schema.get("properties").and_then(Value::as_object);
+    let required: Vec<&str> = schema
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|names| names.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+
+    for name in &required {
+        if object.get(*name).is_none_or(Value::is_null) {
+            errors.push(format!("{}: falta el argumento obligatorio", child(name)));
+        }
+    }
+
+    for (key, item) in object {
+        match properties.and_then(|properties| properties.get(key)) {
+            Some(_) if item.is_null() && !required.contains(&key.as_str()) => {}
+            Some(property) => validate_at(property, item, &child(key), errors),
+            None => match schema.get("additionalProperties") {
+                Some(Value::Bool(true)) => {}
+                Some(extra @ Value::Object(_)) => validate_at(extra, item, &child(key), errors),
+                // Sin `properties` el objeto es libre (p. ej. un mapa de valores)
+                None if properties.is_none() => {}
+                _ => errors.push(format!("{}: argumento desconocido", child(key))),
+            },
+        }
+    }
+}
+
+fn has_type(value: &Value, name: &str) -> bool {
+    match name {
+        "string" => value.is_string(),
+        "boolean" => value.is_boolean(),
+        "array" => value.is_array(),
+        "object" => value.is_object(),
+        "null" => value.is_null(),
+        "number" => value.is_number(),
+        "integer" => {
+            value.is_i64() || value.is_u64() || value.as_f64().is_some_and(|n| n.fract() == 0.0)
+        }
+        // Tipos que no conocemos no se comprueban
+        _ => true,
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "name": { "type": "string" },
+                "limit": { "type": "integer", "minimum": 1, "maximum": 50 },
+                "mode": { "type": "string", "enum": ["fast", "full"] },
+                "tags": { "type": "array", "items": { "type": "string" } },
+                "folder": { "type": "string" }
+            },
+            "required": ["name"]
+        })
+    }
+
+    #[test]
+    fn test_valid_arguments() {
+        assert!(validate_json(&schema(), &json!({ "name": "Ideas" })).is_empty());
+        assert!(
+            validate_json(
+                &schema(),
+                &json!({
+                    "name": "Ideas",
+                    "limit": 10.0,
+                    "mode": "full",
+                    "tags": ["rust", "gtk"],
+                    "folder": null
+                })
+            )
+            .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_reports_every_problem_with_its_path() {
+        let errors = validate_json(
+            &schema(),
+            &json!({
+                "limit": "10",
+                "mode": "slow",
+                "tags": ["rust", 3],
+                "colour": "red"
+            }),
+        );
+        assert_eq!(
+            errors,
+            vec![
+                "name: falta el argumento obligatorio",
+                "colour: argumento desconocido",
+                "limit: se esperaba integer, llegó string",
+                "mode: \"slow\" no es uno de los valores permitidos (\"fast\", \"full\")",
+                "tags[1]: se esperaba string, llegó number",
+            ]
+        );
+
+        assert_eq!(
+            validate_json(&schema(), &json!({ "name": "x", "limit": 0 })),
+            vec!["limit: 0 es menor que el mínimo 1"]
+        );
+        assert_eq!(
+            validate_json(&schema(), &json!("Ideas")),
+            vec!["se esperaba object, llegó string"]
+        );
+    }
+
+    #[test]
+    fn test_free_objects() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "values": { "type": "object" },
+                "extra": {
+                    "type": "object",
+                    "properties": {},
+                    "additionalProperties": { "type": "number" }
+                }
+            }
+        });
+        assert!(
+            validate_json(
+                &schema,
+                &json!({ "values": { "status": "done" }, "extra": { "a": 1 } })
+            )
+            .is_empty()
+        );
+        assert_eq!(
+            validate_json(&schema, &json!({ "extra": { "a": "uno" } })),
+            vec!["extra.a: se esperaba number, llegó string"]
+        );
+    }
+}
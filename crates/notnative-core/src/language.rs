@@ -0,0 +1,75 @@
+//! Idiomas soportados (interfaz y parser de recordatorios)
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    Spanish,
+    English,
+    French,
+    German,
+    Portuguese,
+    Italian,
+}
+
+impl Language {
+    /// Idiomas con catálogo incluido en el binario
+    pub const ALL: [Language; 6] = [
+        Language::Spanish,
+        Language::English,
+        Language::French,
+        Language::German,
+        Language::Portuguese,
+        Language::Italian,
+    ];
+
+    pub fn from_code(code: &str) -> Self {
+        Self::try_from_code(code).unwrap_or(Language::Spanish) // Default
+    }
+
+    /// Detecta el idioma por código ISO (acepta variantes como "pt_BR")
+    pub fn try_from_code(code: &str) -> Option<Self> {
+        let prefix = code
+            .split(['_', '-', '.'])
+            .next()
+            .unwrap_or("")
+            .to_lowercase();
+        match prefix.as_str() {
+            "es" => Some(Language::Spanish),
+            "en" => Some(Language::English),
+            "fr" => Some(Language::French),
+            "de" => Some(Language::German),
+            "pt" => Some(Language::Portuguese),
+            "it" => Some(Language::Italian),
+            _ => None,
+        }
+    }
+
+    pub fn from_env() -> Self {
+        std::env::var("LANG")
+            .ok()
+            .and_then(|lang| lang.split('.').next().map(String::from))
+            .map(|code| Self::from_code(&code))
+            .unwrap_or(Language::Spanish)
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::Spanish => "es",
+            Language::English => "en",
+            Language::French => "fr",
+            Language::German => "de",
+            Language::Portuguese => "pt",
+            Language::Italian => "it",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Language::Spanish => "Español",
+            Language::English => "English",
+            Language::French => "Français",
+            Language::German => "Deutsch",
+            Language::Portuguese => "Português",
+            Language::Italian => "Italiano",
+        }
+    }
+}
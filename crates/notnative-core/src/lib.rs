@@ -0,0 +1,255 @@
+//! Núcleo de NotNative sin dependencias de GTK
+//!
+//! Todo lo que no es interfaz: el buffer de texto ([`NoteBuffer`]), el parser de
+//! comandos modal ([`CommandParser`]), el estilado de markdown, el frontmatter y
+//! los tags, las notas en disco ([`NotesDirectory`]), el índice SQLite
+//! ([`NotesDatabase`]) y el modelo y parser de recordatorios ([`reminders`]).
+//! La app GTK, el servidor MCP y herramientas de terceros lo usan igual:
+//!
+//! ```
+//! use notnative_core::{CommandParser, EditorAction, KeyModifiers, NoteBuffer, extract_all_tags};
+//!
+//! let mut buffer = NoteBuffer::from_text("# Ideas\n");
+//! buffer.insert(buffer.len_chars(), "Probar #rust");
+//! assert_eq!(extract_all_tags(&buffer.to_string()), vec!["rust"]);
+//!
+//! let mut parser = CommandParser::new();
+//! let action = parser.parse_normal_mode("i", KeyModifiers::default());
+//! assert!(matches!(action, EditorAction::ChangeMode(_)));
+//! ```
+//!
+//! La base de datos usa el SQLite del sistema; con la feature `sqlite-bundled`
+//! se compila uno propio.
+
+#![allow(
+    clippy::collapsible_if,
+    clippy::needless_borrows_for_generic_args,
+    clippy::op_ref,
+    clippy::manual_strip,
+    clippy::needless_option_as_deref,
+    clippy::double_ended_iterator_last,
+    clippy::inherent_to_string,
+    clippy::derivable_impls,
+    clippy::single_char_add_str,
+    clippy::only_used_in_recursion,
+    clippy::while_let_on_iterator,
+    clippy::if_same_then_else,
+    clippy::match_result_ok,
+    clippy::clone_on_copy,
+    clippy::len_zero,
+    clippy::unnecessary_map_or,
+    clippy::unwrap_or_default,
+    clippy::field_reassign_with_default
+)]
+
+pub mod agent_plan;
+pub mod ai_exclusion;
+pub mod ai_persona;
+pub mod backup;
+pub mod base;
+pub mod base_query;
+pub mod base_writer;
+pub mod block_attrs;
+pub mod blocks;
+pub mod callouts;
+pub mod chat_export;
+pub mod chat_image;
+pub mod command;
+pub mod config_profile;
+pub mod context_budget;
+pub mod custom_agents;
+pub mod database;
+pub mod date_format;
+pub mod db_actor;
+pub mod db_pool;
+pub mod duplicates;
+pub mod editing;
+pub mod editor_mode;
+pub mod embedding_config;
+pub mod flashcards;
+pub mod folder_defaults;
+pub mod folder_meta;
+pub mod folder_template;
+pub mod footnotes;
+pub mod formula;
+pub mod frontmatter;
+pub mod habits;
+pub mod highlights;
+pub mod hooks;
+pub mod html_renderer;
+pub mod html_to_markdown;
+pub mod hybrid_search;
+pub mod inbox;
+pub mod inline_property;
+pub mod item_name;
+pub mod json_schema;
+pub mod language;
+pub mod links;
+pub mod markdown;
+pub mod meetings;
+pub mod merge;
+pub mod migrations;
+pub mod navigation;
+pub mod note_buffer;
+pub mod note_file;
+pub mod note_store;
+pub mod notes_config;
+pub mod ocr;
+pub mod offline;
+pub mod open_target;
+pub mod pango_markup;
+pub mod plain_text;
+pub mod preview_cache;
+pub mod preview_edit;
+pub mod property;
+pub mod quick_note;
+pub mod refactor;
+pub mod reminders;
+pub mod resilience;
+pub mod resource_stats;
+pub mod scratch;
+pub mod screenshot;
+pub mod search_scope;
+pub mod secrets;
+pub mod sidebar_tree;
+pub mod sketch;
+pub mod split;
+pub mod startup_profile;
+pub mod tag_suggest;
+pub mod tag_tree;
+pub mod tasks;
+#[cfg(test)]
+mod test_support;
+pub mod text_chunker;
+pub mod text_diff;
+pub mod text_stats;
+pub mod vault_health;
+pub mod watch_events;
+pub mod weekly_review;
+pub mod window_state;
+pub mod word_goal;
+pub mod worker;
+pub mod xlsx_export;
+
+pub use agent_plan::{AgentPlan, PlanStep, StepStatus, is_complex_task};
+pub use ai_exclusion::{
+    AI_KEY, folder_ai_excluded, is_ai_excluded, note_ai_excluded, path_ai_excluded,
+};
+pub use ai_persona::{
+    AI_SYSTEM_KEY, folder_ai_system, merge_system_prompt, note_ai_system, note_persona,
+    persona_prompt,
+};
+pub use backup::{
+    BackupConfig, BackupInfo, apply_pending_restore, apply_pending_restore_live, create_backup,
+    list_backups, prune_backups, restore_backup,
+};
+pub use base::{
+    Base, BaseView, CellFormat, ColumnConfig, Filter, FilterGroup, FilterOperator, SortConfig,
+    SortDirection, SourceType, SpecialCellContent, SpecialRow, ViewType,
+};
+pub use base_query::{BaseQueryEngine, NoteWithProperties, PropertyAggregation};
+pub use base_writer::BaseWriter;
+pub use chat_export::{
+    ExportFormat, ExportedMessage, ExportedSession, ExportedStep, Redactor, attach_steps,
+    export_sessions,
+};
+pub use chat_image::{
+    MAX_IMAGE_BYTES, base64_encode, image_data_url, image_media_type, model_supports_vision,
+};
+pub use command::{CommandParser, EditorAction, KeyModifiers};
+pub use config_profile::{ConfigProfile, ProfileTheme, profile_secret_name};
+pub use context_budget::{ContextDoc, fit_to_budget, messages_to_summarize};
+pub use custom_agents::{CustomAgent, CustomAgents, CustomAgentsFile, RoutingRule};
+pub use database::{
+    DeckCard, GroupedRecord, InlinePropertyRow, NoteMetadata, NotesDatabase, ReadingPosition,
+    ReadingQueueEntry, SearchQuery, SearchResult, SearchSort,
+};
+pub use date_format::{DateFormatter, HourFormat};
+pub use db_actor::DbActor;
+pub use db_pool::{DbPool, PooledConnection};
+pub use duplicates::{
+    DuplicateCandidate, DuplicateReason, content_duplicates, content_title, merge_candidates,
+    title_duplicates,
+};
+pub use editing::{EditOutcome, apply_edit, list_prefix};
+pub use editor_mode::EditorMode;
+pub use embedding_config::{EmbeddingConfig, IndexStats};
+pub use flashcards::{Flashcard, ReviewGrade, Sm2State, extract_flashcards};
+pub use folder_defaults::{FOLDER_DEFAULTS_FILE, FolderDefaults};
+pub use folder_meta::{FolderMeta, InheritedStyle, inherited_style};
+pub use folder_template::{FolderTemplate, FolderTemplates, TemplateEntry, fill_placeholders};
+pub use formula::{CellGrid, CellRange, CellRef, CellValue, FormulaError};
+pub use frontmatter::{
+    extract_aliases, extract_all_tags, extract_inline_tags, extract_tags, frontmatter_lines,
+    is_tag_char, set_created_date,
+};
+pub use habits::{Habit, HabitSchedule, render_habits, toggle_habit_day};
+pub use highlights::HighlightColor;
+pub use hooks::{EventHook, HookEvent, HookPayload, HookRunner};
+pub use html_renderer::{HtmlRenderer, PreviewColors, PreviewDensity, PreviewTheme};
+pub use hybrid_search::{RRF_K, SearchMode, reciprocal_rank_fusion, semantic_results};
+pub use inbox::{Capture, INBOX_NOTE, append_capture, pending_captures};
+pub use inline_property::{InlineProperty, InlinePropertyParser};
+pub use item_name::{NameProblem, check_item_name, free_name, renamed_item};
+pub use json_schema::validate_json;
+pub use language::Language;
+pub use links::{heading_link, retarget_links, split_link_target};
+pub use markdown::{IncrementalStyles, MarkdownParser, StyleType};
+pub use meetings::{
+    ActionItem, MeetingLabels, append_action_items, extract_action_items, meeting_note,
+    meeting_note_name, parse_attendees,
+};
+pub use merge::merge_into;
+pub use navigation::{Location, NavigationHistory, RecentNote};
+pub use note_buffer::NoteBuffer;
+pub use note_file::{FolderMount, NoteFile, NotesDirectory, Visited, move_file};
+pub use note_store::{ConsistencyReport, NoteStore};
+pub use notes_config::{NotesConfig, UrlPasteBehavior};
+pub use ocr::{
+    OCR_PROMPT, OcrEdit, OcrEngine, clean_ocr_text, image_on_line, is_ocr_candidate, ocr_block,
+    ocr_edit,
+};
+pub use offline::{OfflineQueue, search_keywords};
+pub use open_target::{OPEN_COMMAND_PREFIX, OpenTarget, note_uri, vault_note_name};
+pub use pango_markup::markdown_to_pango;
+pub use plain_text::markdown_to_plain_text;
+pub use preview_cache::{PreviewCache, PreviewUpdate};
+pub use property::{Property, PropertyValue};
+pub use quick_note::{QuickNoteTarget, append_block, journal_note_name, quick_note_body};
+pub use refactor::{LineChange, NoteRewrite, Refactor, RefactorPlan, undo_refactor};
+pub use resilience::{CircuitBreaker, CircuitState, RateLimiter, RetryPolicy, is_retryable_error};
+pub use resource_stats::ResourceStats;
+pub use scratch::ScratchBuffer;
+pub use screenshot::{
+    Annotation, AnnotationKind, SCREENSHOT_PREFIX, ScreenshotTarget, image_markdown, parse_region,
+};
+pub use search_scope::SearchScope;
+pub use secrets::SecretStore;
+pub use sidebar_tree::{SidebarEntry, SidebarRow};
+pub use sketch::{Sketch, SketchPoint, Stroke, is_sketch_path};
+pub use split::{Section, note_name_from_title, replace_sections, split_sections};
+pub use tag_suggest::{neighbor_tags, suggest_tags};
+pub use tag_tree::{TagNode, build_tag_tree, rename_tag_in_content, tag_color};
+pub use tasks::TaskState;
+pub use text_chunker::{ChunkConfig, TextChunk, TextChunker, chunk_source};
+pub use text_diff::{
+    DiffHunk, DiffLine, SideBySideRow, compact_diff, diff_hunks, diff_lines, diff_stats,
+    diff_words, side_by_side,
+};
+pub use text_stats::TextStats;
+pub use vault_health::{
+    HealthEntry, HealthMetric, NoteSummary, VaultHealth, embedded_paths, outgoing_links,
+};
+pub use watch_events::{
+    EventDebouncer, WATCH_DEBOUNCE, WatchAction, WatchEvent, WatchIgnore, apply_watch_actions,
+    default_watch_ignore,
+};
+pub use weekly_review::{
+    ReviewLabels, WeekSummary, review_note, review_note_name, week_overview, week_start,
+};
+pub use window_state::{
+    COMPACT_SIZE, COMPACT_TITLE, DEFAULT_MAIN_SIZE, DEFAULT_QUICK_NOTE_SIZE, DEFAULT_SIDEBAR_WIDTH,
+    WindowGeometry, clamp_sidebar_width,
+};
+pub use word_goal::{GOAL_FIELD, GoalCompletion, WordGoal, completions_in};
+pub use worker::{BackgroundWorker, WorkerContext};
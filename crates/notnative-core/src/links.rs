@@ -0,0 +1,133 @@
+//! Enlaces entre notas: menciones `@Nota` y wikilinks `[[Nota]]`
+//!
+//! Al fusionar o mover una nota, los enlaces que apuntaban a ella se
+//! reescriben para que apunten a la nota nueva. Ambos pueden llevar un
+//! encabezado de destino: `@Nota#encabezado` o `[[Nota#Encabezado]]`.
+
+use crate::html_renderer::heading_id;
+
+/// Caracteres que forman parte del nombre en una mención (como en el editor)
+fn is_name_char(ch: char) -> bool {
+    ch.is_alphanumeric() || ch == '-' || ch == '_' || ch == '/'
+}
+
+/// Cambia los enlaces a `from` por enlaces a `to`: menciones `@from` y
+/// wikilinks `[[from]]`, `[[from|alias]]` o `[[from#encabezado]]`.
+/// Devuelve `None` si el contenido no enlaza a `from`.
+pub fn retarget_links(content: &str, from: &str, to: &str) -> Option<String> {
+    if from.is_empty() || from == to || !content.contains(from) {
+        return None;
+    }
+
+    let mention = format!("@{}", from);
+    let wikilink = format!("[[{}", from);
+    let mut output = String::with_capacity(content.len());
+    let mut last = 0;
+    let mut changed = false;
+
+    let mut index = 0;
+    while index < content.len() {
+        let rest = &content[index..];
+        let replacement = if rest.starts_with(&mention) {
+            // La mención empieza tras un espacio o signo y no sigue con más nombre
+            let starts = content[..index]
+                .chars()
+                .next_back()
+                .is_none_or(|prev| prev.is_whitespace() || matches!(prev, '(' | '[' | ','));
+            let ends = !rest[mention.len()..]
+                .chars()
+                .next()
+                .is_some_and(is_name_char);
+            (starts && ends).then(|| (mention.len(), format!("@{}", to)))
+        } else if rest.starts_with(&wikilink) {
+            let after = &rest[wikilink.len()..];
+            (after.starts_with("]]") || after.starts_with('|') || after.starts_with('#'))
+                .then(|| (wikilink.len(), format!("[[{}", to)))
+        } else {
+            None
+        };
+
+        match replacement {
+            Some((len, text)) => {
+                output.push_str(&content[last..index]);
+                output.push_str(&text);
+                index += len;
+                last = index;
+                changed = true;
+            }
+            None => {
+                index += rest.chars().next().map_or(1, char::len_utf8);
+            }
+        }
+    }
+
+    if !changed {
+        return None;
+    }
+    output.push_str(&content[last..]);
+    Some(output)
+}
+
+/// Enlace a un encabezado de una nota, listo para pegar: `@Nota#id`
+pub fn heading_link(note: &str, heading: &str) -> String {
+    format!("@{}#{}", note, heading_id(heading))
+}
+
+/// Separa el destino de un enlace en la nota y el ID del encabezado, si lo
+/// lleva. Vale tanto el ID (`Nota#mi-titulo`) como el texto del encabezado
+/// (`Nota#Mi título`).
+pub fn split_link_target(target: &str) -> (&str, Option<String>) {
+    match target.split_once('#') {
+        Some((note, heading)) => {
+            let id = heading_id(heading);
+            (note.trim_end(), (!id.is_empty()).then_some(id))
+        }
+        None => (target, None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retarget_mentions_and_wikilinks() {
+        let content = "Ver @Ideas, (@Ideas) y @Ideas\n\
+                       [[Ideas]], [[Ideas|las ideas]] y [[Ideas#Lista]]\n";
+        assert_eq!(
+            retarget_links(content, "Ideas", "Proyectos/Plan").unwrap(),
+            "Ver @Proyectos/Plan, (@Proyectos/Plan) y @Proyectos/Plan\n\
+             [[Proyectos/Plan]], [[Proyectos/Plan|las ideas]] y [[Proyectos/Plan#Lista]]\n"
+        );
+    }
+
+    #[test]
+    fn test_retarget_ignores_other_names() {
+        // Otras notas que empiezan igual, correos y wikilinks más largos
+        let content = "@Ideas2 @Ideas-viejas correo@Ideas [[Ideas viejas]] ñ";
+        assert_eq!(retarget_links(content, "Ideas", "Plan"), None);
+        assert_eq!(retarget_links("sin enlaces", "Ideas", "Plan"), None);
+    }
+
+    #[test]
+    fn test_heading_links() {
+        let link = heading_link("Proyectos/Plan", "Conexión al MCP");
+        assert_eq!(link, "@Proyectos/Plan#conexión-al-mcp");
+        assert_eq!(
+            split_link_target(&link[1..]),
+            ("Proyectos/Plan", Some("conexión-al-mcp".to_string()))
+        );
+        assert_eq!(
+            split_link_target("Ideas#Lista de tareas"),
+            ("Ideas", Some("lista-de-tareas".to_string()))
+        );
+        assert_eq!(split_link_target("Ideas"), ("Ideas", None));
+        assert_eq!(split_link_target("Ideas#"), ("Ideas", None));
+
+        // Al mover la nota, el enlace conserva el encabezado
+        assert_eq!(
+            retarget_links("Ver @Ideas#lista.", "Ideas", "Plan").unwrap(),
+            "Ver @Plan#lista."
+        );
+    }
+}
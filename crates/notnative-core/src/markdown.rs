@@ -0,0 +1,587 @@
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+use std::ops::Range;
+
+use crate::highlights::{HighlightColor, find_highlights};
+
+/// Información de estilo para aplicar a un rango de texto
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextStyle {
+    pub start: usize,
+    pub end: usize,
+    pub style_type: StyleType,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StyleType {
+    Heading1,
+    Heading2,
+    Heading3,
+    Bold,
+    Italic,
+    Code,
+    CodeBlock,
+    Link,
+    Quote,
+    Image { src: String, alt: String },
+    Highlight(HighlightColor),
+    YouTubeVideo { video_id: String, url: String },
+}
+
+/// Parser de markdown que extrae información de estilo
+pub struct MarkdownParser {
+    text: String,
+}
+
+impl MarkdownParser {
+    pub fn new(text: String) -> Self {
+        Self { text }
+    }
+
+    /// Parsea el texto markdown usando pulldown-cmark con offsets precisos
+    pub fn parse(&self) -> Vec<TextStyle> {
+        let mut styles = Vec::new();
+
+        // Configurar parser con offsets habilitados
+        let mut options = Options::empty();
+        options.insert(Options::ENABLE_STRIKETHROUGH);
+
+        // Crear parser con offsets
+        let parser = Parser::new_ext(&self.text, options).into_offset_iter();
+
+        // Stack para trackear contexto anidado
+        let mut heading_range: Option<(usize, StyleType)> = None;
+        let mut strong_range: Option<usize> = None;
+        let mut emphasis_range: Option<usize> = None;
+        let mut code_block_range: Option<usize> = None;
+        let mut image_range: Option<(usize, String, String)> = None; // (start, src, alt)
+
+        for (event, range) in parser {
+            match event {
+                Event::Start(tag) => match tag {
+                    Tag::Heading { level, .. } => {
+                        let style = match level {
+                            pulldown_cmark::HeadingLevel::H1 => StyleType::Heading1,
+                            pulldown_cmark::HeadingLevel::H2 => StyleType::Heading2,
+                            pulldown_cmark::HeadingLevel::H3 => StyleType::Heading3,
+                            _ => StyleType::Heading3,
+                        };
+                        heading_range = Some((range.start, style));
+                    }
+                    Tag::Strong => {
+                        strong_range = Some(range.start);
+                    }
+                    Tag::Emphasis => {
+                        emphasis_range = Some(range.start);
+                    }
+                    Tag::CodeBlock(_) => {
+                        code_block_range = Some(range.start);
+                    }
+                    Tag::Image {
+                        dest_url, title, ..
+                    } => {
+                        image_range = Some((range.start, dest_url.to_string(), title.to_string()));
+                    }
+                    _ => {}
+                },
+                Event::End(tag_end) => {
+                    match tag_end {
+                        TagEnd::Heading(_) => {
+                            if let Some((start, style)) = heading_range.take() {
+                                // Para headings, aplicar a toda la línea incluyendo el #
+                                styles.push(TextStyle {
+                                    start,
+                                    end: range.end,
+                                    style_type: style,
+                                });
+                            }
+                        }
+                        TagEnd::Strong => {
+                            if let Some(start) = strong_range.take() {
+                                // Excluir los ** delimitadores
+                                let content_start = start + 2; // Skip "**"
+                                let content_end = if range.end >= 2 {
+                                    range.end - 2
+                                } else {
+                                    range.end
+                                }; // Skip "**"
+
+                                if content_start < content_end {
+                                    styles.push(TextStyle {
+                                        start: content_start,
+                                        end: content_end,
+                                        style_type: StyleType::Bold,
+                                    });
+                                }
+                            }
+                        }
+                        TagEnd::Emphasis => {
+                            if let Some(start) = emphasis_range.take() {
+                                // Excluir los * delimitadores
+                                let content_start = start + 1; // Skip "*"
+                                let content_end = if range.end >= 1 {
+                                    range.end - 1
+                                } else {
+                                    range.end
+                                }; // Skip "*"
+
+                                if content_start < content_end {
+                                    styles.push(TextStyle {
+                                        start: content_start,
+                                        end: content_end,
+                                        style_type: StyleType::Italic,
+                                    });
+                                }
+                            }
+                        }
+                        TagEnd::CodeBlock => {
+                            if let Some(start) = code_block_range.take() {
+                                styles.push(TextStyle {
+                                    start,
+                                    end: range.end,
+                                    style_type: StyleType::CodeBlock,
+                                });
+                            }
+                        }
+                        TagEnd::Image => {
+                            if let Some((start, src, alt)) = image_range.take() {
+                                styles.push(TextStyle {
+                                    start,
+                                    end: range.end,
+                                    style_type: StyleType::Image { src, alt },
+                                });
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                // Excluir los backticks del rango
+                // El rango incluye los ` delimitadores, necesitamos solo el contenido
+                Event::Code(_) if range.start < range.end => {
+                    // Buscar el primer backtick después de range.start
+                    let content_start = self.text[range.start..range.end]
+                        .find('`')
+                        .map(|pos| range.start + pos + 1)
+                        .unwrap_or(range.start);
+
+                    // Buscar el último backtick antes de range.end
+                    let content_end = self.text[range.start..range.end]
+                        .rfind('`')
+                        .map(|pos| range.start + pos)
+                        .unwrap_or(range.end);
+
+                    if content_start < content_end {
+                        styles.push(TextStyle {
+                            start: content_start,
+                            end: content_end,
+                            style_type: StyleType::Code,
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // Resaltados ==texto== (pulldown-cmark no los conoce)
+        styles.extend(
+            find_highlights(&self.text)
+                .into_iter()
+                .map(|highlight| TextStyle {
+                    start: highlight.range.start,
+                    end: highlight.range.end,
+                    style_type: StyleType::Highlight(highlight.color),
+                }),
+        );
+
+        styles
+    }
+
+    /// Parsea solo un rango de bytes de `text` (debe empezar y terminar en límite
+    /// de línea) y devuelve los estilos con offsets relativos al texto completo
+    pub fn parse_block(text: &str, range: Range<usize>) -> Vec<TextStyle> {
+        MarkdownParser::new(text[range.clone()].to_string())
+            .parse()
+            .into_iter()
+            .map(|style| TextStyle {
+                start: style.start + range.start,
+                end: style.end + range.start,
+                ..style
+            })
+            .collect()
+    }
+}
+
+/// ¿Es una línea de apertura/cierre de bloque de código?
+fn is_fence_line(line: &str) -> bool {
+    line.trim_start().starts_with("```")
+}
+
+/// Offsets de inicio de las líneas que abren o cierran bloques de código
+fn fence_positions(text: &str, offset: usize) -> Vec<usize> {
+    let mut positions = Vec::new();
+    let mut line_start = 0;
+    for line in text.split_inclusive('\n') {
+        if is_fence_line(line) {
+            positions.push(offset + line_start);
+        }
+        line_start += line.len();
+    }
+    positions
+}
+
+/// Inicio de la línea que contiene `pos`
+fn line_start(text: &str, pos: usize) -> usize {
+    text[..pos].rfind('\n').map_or(0, |i| i + 1)
+}
+
+/// Fin de la línea que contiene `pos` (incluyendo el salto de línea)
+fn line_end(text: &str, pos: usize) -> usize {
+    text[pos..].find('\n').map_or(text.len(), |i| pos + i + 1)
+}
+
+/// Rango de bytes del bloque markdown que contiene `range`.
+///
+/// Se extiende hasta la línea en blanco anterior y la siguiente (los estilos
+/// inline y los encabezados no cruzan párrafos) y, si cae dentro de un bloque
+/// de código, hasta sus vallas de apertura y cierre.
+pub fn block_range(text: &str, range: Range<usize>) -> Range<usize> {
+    expand_block(text, range, &fence_positions(text, 0))
+}
+
+/// Igual que [`block_range`] pero con las vallas de código ya calculadas
+fn expand_block(text: &str, range: Range<usize>, fences: &[usize]) -> Range<usize> {
+    let is_blank = |line: &str| line.trim().is_empty();
+
+    // Inicio: retroceder línea a línea hasta una línea en blanco
+    let mut start = line_start(text, range.start.min(text.len()));
+    while start > 0 {
+        let prev_start = line_start(text, start - 1);
+        if is_blank(&text[prev_start..start]) {
+            break;
+        }
+        start = prev_start;
+    }
+
+    // Fin: avanzar hasta después de la siguiente línea en blanco
+    let mut end = line_end(text, range.end.min(text.len()));
+    while end < text.len() {
+        let next_end = line_end(text, end);
+        let blank = is_blank(&text[end..next_end]);
+        end = next_end;
+        if blank {
+            break;
+        }
+    }
+
+    // Dentro de un bloque de código: abarcar el bloque completo
+    let fences_before = fences.partition_point(|&f| f < start);
+    if fences_before % 2 == 1 {
+        start = fences[fences_before - 1];
+        if let Some(&closing) = fences.get(fences_before) {
+            end = end.max(line_end(text, closing));
+        } else {
+            end = text.len();
+        }
+    }
+
+    start..end
+}
+
+/// Estilos de un documento que se mantienen actualizados por bloques.
+///
+/// Cada edición solo vuelve a parsear el bloque afectado (ver [`block_range`])
+/// y desplaza el resto de estilos, en lugar de parsear todo el documento. Los
+/// rangos modificados se acumulan hasta que la vista los recoge con
+/// [`IncrementalStyles::take_dirty`], de modo que puede re-aplicar los tags
+/// con debounce y solo en esas líneas.
+#[derive(Debug, Default)]
+pub struct IncrementalStyles {
+    styles: Vec<TextStyle>,
+    fences: Vec<usize>,
+    dirty: Option<Range<usize>>,
+}
+
+impl IncrementalStyles {
+    /// Parsea el documento completo
+    pub fn new(text: &str) -> Self {
+        let mut styles = MarkdownParser::new(text.to_string()).parse();
+        styles.sort_by_key(|s| (s.start, s.end));
+        Self {
+            styles,
+            fences: fence_positions(text, 0),
+            dirty: Some(0..text.len()),
+        }
+    }
+
+    /// Estilos actuales, ordenados por posición
+    pub fn styles(&self) -> &[TextStyle] {
+        &self.styles
+    }
+
+    /// Estilos que se solapan con un rango de bytes
+    pub fn styles_in(&self, range: Range<usize>) -> impl Iterator<Item = &TextStyle> {
+        self.styles
+            .iter()
+            .filter(move |s| s.start < range.end && s.end > range.start)
+    }
+
+    /// Actualiza los estilos tras una edición. `edit` es el rango de bytes
+    /// reemplazado en el texto anterior, `inserted_len` la longitud en bytes del
+    /// texto insertado y `text` el documento ya editado.
+    pub fn apply_edit(&mut self, text: &str, edit: Range<usize>, inserted_len: usize) {
+        let removed_len = edit.end - edit.start;
+        let shift = |pos: usize| {
+            if pos >= edit.end {
+                pos + inserted_len - removed_len
+            } else {
+                pos.min(edit.start + inserted_len)
+            }
+        };
+
+        // Líneas tocadas por la edición (en el texto nuevo y en el anterior)
+        let lines_start = line_start(text, edit.start);
+        let lines_end = line_end(text, edit.start + inserted_len);
+        let old_lines_end = lines_end + removed_len - inserted_len;
+
+        // Abrir o cerrar un bloque de código cambia el resto del documento
+        let new_fences = fence_positions(&text[lines_start..lines_end], lines_start);
+        let old_fences = self
+            .fences
+            .iter()
+            .filter(|&&f| f >= lines_start && f < old_lines_end)
+            .count();
+        if new_fences.len() != old_fences {
+            *self = Self::new(text);
+            return;
+        }
+        self.fences
+            .retain(|&f| f < lines_start || f >= old_lines_end);
+        for fence in &mut self.fences {
+            if *fence >= old_lines_end {
+                *fence = shift(*fence);
+            }
+        }
+        let at = self.fences.partition_point(|&f| f < lines_start);
+        self.fences.splice(at..at, new_fences);
+
+        let block = expand_block(text, edit.start..edit.start + inserted_len, &self.fences);
+        let old_block_end = block.end + removed_len - inserted_len;
+
+        self.styles
+            .retain(|s| s.end <= block.start || s.start >= old_block_end);
+        for style in &mut self.styles {
+            if style.start >= old_block_end {
+                style.start = shift(style.start);
+                style.end = shift(style.end);
+            }
+        }
+
+        let reparsed = MarkdownParser::parse_block(text, block.clone());
+        let insert_at = self.styles.partition_point(|s| s.start < block.start);
+        self.styles.splice(insert_at..insert_at, reparsed);
+        let reparsed_end = self.styles.partition_point(|s| s.start < block.end);
+        self.styles[insert_at..reparsed_end].sort_by_key(|s| (s.start, s.end));
+
+        self.dirty = Some(match self.dirty.take() {
+            Some(dirty) => shift(dirty.start).min(block.start)..shift(dirty.end).max(block.end),
+            None => block,
+        });
+    }
+
+    /// Rango de bytes cuyos estilos cambiaron desde la última llamada
+    pub fn take_dirty(&mut self) -> Option<Range<usize>> {
+        self.dirty.take()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_heading_detection() {
+        let text = "# Heading 1\n## Heading 2\n### Heading 3".to_string();
+        let parser = MarkdownParser::new(text);
+        let styles = parser.parse();
+
+        assert!(styles.iter().any(|s| s.style_type == StyleType::Heading1));
+        assert!(styles.iter().any(|s| s.style_type == StyleType::Heading2));
+        assert!(styles.iter().any(|s| s.style_type == StyleType::Heading3));
+    }
+
+    #[test]
+    fn test_bold_italic() {
+        let text = "**bold** and *italic*".to_string();
+        let parser = MarkdownParser::new(text);
+        let styles = parser.parse();
+
+        assert!(styles.iter().any(|s| s.style_type == StyleType::Bold));
+        assert!(styles.iter().any(|s| s.style_type == StyleType::Italic));
+    }
+
+    #[test]
+    fn test_code() {
+        let text = "inline `code` here".to_string();
+        let parser = MarkdownParser::new(text);
+        let styles = parser.parse();
+
+        assert!(styles.iter().any(|s| s.style_type == StyleType::Code));
+    }
+
+    #[test]
+    fn test_highlight() {
+        let text = "Algo ==importante== y =={red}urgente==";
+        let parser = MarkdownParser::new(text.to_string());
+        let styles = parser.parse();
+
+        let highlights: Vec<_> = styles
+            .iter()
+            .filter_map(|s| match s.style_type {
+                StyleType::Highlight(color) => Some((&text[s.start..s.end], color)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            highlights,
+            [
+                ("==importante==", HighlightColor::Yellow),
+                ("=={red}urgente==", HighlightColor::Red)
+            ]
+        );
+    }
+
+    fn sorted(mut styles: Vec<TextStyle>) -> Vec<TextStyle> {
+        styles.sort_by_key(|s| (s.start, s.end));
+        styles
+    }
+
+    #[test]
+    fn test_block_range() {
+        let text = "# Title\n\npara one\nline two\n\nlast";
+        let para = text.find("line two").unwrap();
+        assert_eq!(
+            &text[block_range(text, para..para)],
+            "para one\nline two\n\n"
+        );
+
+        let code = "intro\n\n```\nfn a() {}\n\nfn b() {}\n```\nafter";
+        let inner = code.find("fn b").unwrap();
+        assert_eq!(
+            &code[block_range(code, inner..inner)],
+            "```\nfn a() {}\n\nfn b() {}\n```\nafter"
+        );
+    }
+
+    #[test]
+    fn test_incremental_matches_full_parse() {
+        let mut text =
+            "# Title\n\nsome **bold** text\n\n*italic* and `code`\n\n## End\n".to_string();
+        let mut styles = IncrementalStyles::new(&text);
+
+        // Insertar negrita en el segundo párrafo
+        let pos = text.find("and").unwrap();
+        text.insert_str(pos, "**new** ");
+        styles.apply_edit(&text, pos..pos, "**new** ".len());
+        let full = sorted(MarkdownParser::new(text.clone()).parse());
+        assert_eq!(styles.styles(), full.as_slice());
+
+        // Borrar la negrita del primer párrafo
+        let start = text.find("**bold**").unwrap();
+        text.replace_range(start..start + "**bold**".len(), "bold");
+        styles.apply_edit(&text, start..start + "**bold**".len(), "bold".len());
+        let full = sorted(MarkdownParser::new(text.clone()).parse());
+        assert_eq!(styles.styles(), full.as_slice());
+
+        // Abrir un bloque de código re-parsea todo el documento
+        text.insert_str(0, "```\n");
+        styles.apply_edit(&text, 0..0, 4);
+        let full = sorted(MarkdownParser::new(text.clone()).parse());
+        assert_eq!(styles.styles(), full.as_slice());
+        assert_eq!(styles.take_dirty(), Some(0..text.len()));
+    }
+
+    #[test]
+    fn test_dirty_range_accumulates_edits() {
+        let mut text = "first\n\nsecond\n\nthird\n".to_string();
+        let mut styles = IncrementalStyles::new(&text);
+        styles.take_dirty();
+
+        let pos = text.find("third").unwrap();
+        text.insert_str(pos, "*x*");
+        styles.apply_edit(&text, pos..pos, 3);
+        text.insert_str(0, "# ");
+        styles.apply_edit(&text, 0..0, 2);
+
+        let dirty = styles.take_dirty().unwrap();
+        assert_eq!(dirty.start, 0);
+        assert_eq!(dirty.end, text.len());
+        assert!(styles.take_dirty().is_none());
+    }
+
+    /// Nota grande (~5000 líneas) con encabezados, énfasis, código y bloques
+    fn large_fixture() -> String {
+        let mut text = String::new();
+        for i in 0..500 {
+            text.push_str(&format!("## Sección {}\n\n", i));
+            text.push_str("Texto con **negrita**, *cursiva* y `código` en línea.\n");
+            text.push_str("Otra línea del mismo párrafo.\n\n");
+            text.push_str("```rust\nfn main() {}\n```\n\n");
+            text.push_str("- elemento\n\n");
+        }
+        text
+    }
+
+    #[test]
+    fn test_large_note_incremental_edits_stay_local() {
+        let mut text = large_fixture();
+        assert!(text.lines().count() >= 5000);
+
+        let mut styles = IncrementalStyles::new(&text);
+        assert_eq!(styles.take_dirty(), Some(0..text.len()));
+
+        // Escribir carácter a carácter en mitad de la nota: cada edición solo
+        // re-parsea el párrafo editado, nunca el documento
+        let paragraph = text.match_indices("Otra").nth(250).unwrap().0;
+        let mut pos = paragraph;
+        for c in "escribiendo **rápido**".chars() {
+            text.insert(pos, c);
+            styles.apply_edit(&text, pos..pos, c.len_utf8());
+            pos += c.len_utf8();
+
+            let dirty = styles.take_dirty().unwrap();
+            assert!(dirty.start <= pos && dirty.end >= pos);
+            assert!(dirty.end - dirty.start < 200);
+        }
+
+        let full = sorted(MarkdownParser::new(text.clone()).parse());
+        assert_eq!(styles.styles(), full.as_slice());
+    }
+
+    /// Comparación de tiempos con el parseo completo; depende de la máquina,
+    /// así que no corre por defecto (`cargo test -- --ignored`)
+    #[test]
+    #[ignore]
+    fn bench_large_note_incremental() {
+        let mut text = large_fixture();
+
+        let started = std::time::Instant::now();
+        let mut styles = IncrementalStyles::new(&text);
+        let full_time = started.elapsed();
+
+        let mut pos = text.match_indices("Otra").nth(250).unwrap().0;
+        let started = std::time::Instant::now();
+        for c in "escribiendo **rápido**".chars() {
+            text.insert(pos, c);
+            styles.apply_edit(&text, pos..pos, c.len_utf8());
+            pos += c.len_utf8();
+        }
+        let incremental_time = started.elapsed();
+
+        // Cada una de las 22 ediciones cuesta menos que un parseo completo
+        assert!(
+            incremental_time / 22 < full_time,
+            "completo: {:?}, incremental: {:?}",
+            full_time,
+            incremental_time
+        );
+    }
+}
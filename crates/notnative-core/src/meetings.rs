@@ -0,0 +1,376 @@
+//! Notas de reunión
+//!
+//! Una nota de reunión sale de una plantilla con la fecha, los asistentes
+//! (como menciones `@Persona`) y secciones para el orden del día, las notas y
+//! las acciones. Después de la reunión, [`extract_action_items`] saca las
+//! tareas pendientes de la nota con sus responsables (las menciones de la
+//! línea) y su fecha límite (`📅 2026-10-20` o `due:2026-10-20`) para
+//! convertirlas en recordatorios.
+
+use crate::merge::heading_level;
+use crate::tasks::{TaskState, parse_task_line};
+use chrono::{Duration, NaiveDate, NaiveDateTime};
+
+/// Carpeta de las notas de reunión (`Meetings/2026-10-16 Revisión`)
+pub const MEETINGS_FOLDER: &str = "Meetings";
+
+/// Hora del recordatorio de una acción
+pub const REMINDER_HOUR: u32 = 9;
+
+/// Tag que llevan las notas de reunión
+pub const MEETING_TAG: &str = "meeting";
+
+/// Encabezados de la plantilla, ya traducidos
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MeetingLabels {
+    pub agenda: String,
+    pub notes: String,
+    pub action_items: String,
+}
+
+/// Asistentes escritos separados por comas, sin `@` delante y sin repetir
+pub fn parse_attendees(text: &str) -> Vec<String> {
+    let mut attendees: Vec<String> = Vec::new();
+    for name in text.split([',', ';', '\n']) {
+        let name = name.trim().trim_start_matches('@').trim();
+        if !name.is_empty()
+            && !attendees
+                .iter()
+                .any(|known| known.eq_ignore_ascii_case(name))
+        {
+            attendees.push(name.to_string());
+        }
+    }
+    attendees
+}
+
+/// Mención a una persona: `@Nombre`, o `[[Nombre Apellido]]` si lleva
+/// espacios (una mención acaba en el primer espacio)
+pub fn person_mention(name: &str) -> String {
+    if name.contains(char::is_whitespace) {
+        format!("[[{}]]", name)
+    } else {
+        format!("@{}", name)
+    }
+}
+
+/// Nombre de la nota de una reunión: fecha y título dentro de
+/// [`MEETINGS_FOLDER`]
+pub fn meeting_note_name(title: &str, date: NaiveDate) -> String {
+    format!(
+        "{}/{} {}",
+        MEETINGS_FOLDER,
+        date.format("%Y-%m-%d"),
+        title.trim()
+    )
+}
+
+/// Contenido inicial de una nota de reunión
+pub fn meeting_note(
+    title: &str,
+    when: NaiveDateTime,
+    attendees: &[String],
+    labels: &MeetingLabels,
+) -> String {
+    let mut note = format!(
+        "---\ntags: [{}]\ndate: {}\n",
+        MEETING_TAG,
+        when.format("%Y-%m-%d %H:%M")
+    );
+    if !attendees.is_empty() {
+        note.push_str(&format!("attendees: [{}]\n", attendees.join(", ")));
+    }
+    note.push_str(&format!("---\n\n# {}\n\n", title.trim()));
+    if !attendees.is_empty() {
+        let mentions: Vec<String> = attendees.iter().map(|name| person_mention(name)).collect();
+        note.push_str(&format!("👥 {}\n\n", mentions.join(", ")));
+    }
+    note.push_str(&format!(
+        "## {}\n\n1. \n\n## {}\n\n\n\n## {}\n\n- [ ] \n",
+        labels.agenda, labels.notes, labels.action_items
+    ));
+    note
+}
+
+/// Tarea pendiente de una reunión
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActionItem {
+    /// Texto de la tarea sin la fecha límite
+    pub text: String,
+    /// Menciones `@Persona` de la línea
+    pub assignees: Vec<String>,
+    pub due: Option<NaiveDate>,
+    /// Línea de la nota (desde 0)
+    pub line: usize,
+    /// Ya hay un `!!RECORDAR` con este texto en la nota
+    pub has_reminder: bool,
+}
+
+impl ActionItem {
+    /// Cuándo avisar: el día límite a las [`REMINDER_HOUR`] o, sin fecha, al
+    /// día siguiente de `today`
+    pub fn reminder_at(&self, today: NaiveDate) -> NaiveDateTime {
+        self.due
+            .unwrap_or(today + Duration::days(1))
+            .and_hms_opt(REMINDER_HOUR, 0, 0)
+            .unwrap_or_default()
+    }
+}
+
+/// Tareas pendientes o en progreso de la nota, fuera de los bloques de código.
+/// Las tareas vacías de la plantilla no cuentan.
+pub fn extract_action_items(content: &str) -> Vec<ActionItem> {
+    let mut items = Vec::new();
+    let mut in_code_block = false;
+    for (index, line) in content.lines().enumerate() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            continue;
+        }
+        let Some((marker, state)) = parse_task_line(line) else {
+            continue;
+        };
+        if !matches!(state, TaskState::Open | TaskState::InProgress) {
+            continue;
+        }
+
+        let body: String = line.chars().skip(marker + 2).collect();
+        let mut due = None;
+        let mut words: Vec<&str> = Vec::new();
+        let mut tokens = body.split_whitespace().peekable();
+        while let Some(token) = tokens.next() {
+            if token == "📅" {
+                if let Some(date) = tokens.peek().and_then(|next| parse_date(next)) {
+                    due = Some(date);
+                    tokens.next();
+                    continue;
+                }
+            } else if let Some(date) = token
+                .strip_prefix("📅")
+                .or_else(|| token.strip_prefix("due:"))
+                .and_then(parse_date)
+            {
+                due = Some(date);
+                continue;
+            }
+            words.push(token);
+        }
+        let text = words.join(" ");
+        if text.is_empty() {
+            continue;
+        }
+
+        items.push(ActionItem {
+            assignees: mentions(&text),
+            due,
+            line: index,
+            has_reminder: has_reminder(content, &text),
+            text,
+        });
+    }
+    items
+}
+
+fn parse_date(text: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(text.trim_end_matches([',', '.', ';']), "%Y-%m-%d").ok()
+}
+
+/// Personas mencionadas con `@` en un texto, sin repetir
+fn mentions(text: &str) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    for word in text.split_whitespace() {
+        let Some(name) = word.strip_prefix('@') else {
+            continue;
+        };
+        let name = name.trim_end_matches(|ch: char| !(ch.is_alphanumeric() || ch == '_'));
+        if !name.is_empty() && !names.iter().any(|known| known == name) {
+            names.push(name.to_string());
+        }
+    }
+    names
+}
+
+/// Hay un `!!RECORDAR(..., texto)` para esta tarea
+fn has_reminder(content: &str, text: &str) -> bool {
+    let ending = format!(", {})", text);
+    content
+        .lines()
+        .any(|line| line.contains("!!RECORDAR(") && line.trim_end().ends_with(&ending))
+}
+
+/// Añade tareas `- [ ]` al final de la sección `heading` (se crea al final de
+/// la nota si no existe). Las que ya están en la nota se saltan y la tarea
+/// vacía de la plantilla se sustituye.
+pub fn append_action_items(content: &str, heading: &str, items: &[String]) -> String {
+    let existing: Vec<String> = extract_action_items(content)
+        .into_iter()
+        .map(|item| item.text.to_lowercase())
+        .collect();
+    let mut new_lines: Vec<String> = Vec::new();
+    for item in items {
+        let item = item.trim();
+        if !item.is_empty()
+            && !existing.contains(&item.to_lowercase())
+            && !new_lines
+                .iter()
+                .any(|line| line[6..].eq_ignore_ascii_case(item))
+        {
+            new_lines.push(format!("- [ ] {}", item));
+        }
+    }
+    if new_lines.is_empty() {
+        return content.to_string();
+    }
+
+    let mut lines: Vec<String> = content.lines().map(String::from).collect();
+    let section = lines.iter().position(|line| {
+        heading_level(line).is_some_and(|level| line[level..].trim().eq_ignore_ascii_case(heading))
+    });
+    let Some(start) = section else {
+        return crate::quick_note::append_block(
+            content,
+            &format!("## {}\n\n{}", heading, new_lines.join("\n")),
+        );
+    };
+
+    let level = heading_level(&lines[start]).unwrap_or(2);
+    let end = lines[start + 1..]
+        .iter()
+        .position(|line| heading_level(line).is_some_and(|other| other <= level))
+        .map_or(lines.len(), |offset| start + 1 + offset);
+    let last = (start + 1..end)
+        .rev()
+        .find(|&index| !lines[index].trim().is_empty());
+    let at = match last {
+        Some(index) if lines[index].trim() == "- [ ]" => {
+            lines.remove(index);
+            index
+        }
+        Some(index) => index + 1,
+        None => {
+            lines.insert(start + 1, String::new());
+            start + 2
+        }
+    };
+    for (offset, line) in new_lines.into_iter().enumerate() {
+        lines.insert(at + offset, line);
+    }
+
+    let mut result = lines.join("\n");
+    if content.ends_with('\n') {
+        result.push('\n');
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels() -> MeetingLabels {
+        MeetingLabels {
+            agenda: "Orden del día".into(),
+            notes: "Notas".into(),
+            action_items: "Acciones".into(),
+        }
+    }
+
+    #[test]
+    fn test_meeting_note() {
+        let attendees = parse_attendees("@Ana, Luis Pérez, ana,, @Marta");
+        assert_eq!(attendees, vec!["Ana", "Luis Pérez", "Marta"]);
+
+        let date = NaiveDate::from_ymd_opt(2026, 10, 16).unwrap();
+        assert_eq!(
+            meeting_note_name("Revisión ", date),
+            "Meetings/2026-10-16 Revisión"
+        );
+
+        let note = meeting_note(
+            "Revisión",
+            date.and_hms_opt(10, 30, 0).unwrap(),
+            &attendees,
+            &labels(),
+        );
+        assert!(note.starts_with(
+            "---\ntags: [meeting]\ndate: 2026-10-16 10:30\nattendees: [Ana, Luis Pérez, Marta]\n---\n\n# Revisión\n"
+        ));
+        assert!(note.contains("👥 @Ana, [[Luis Pérez]], @Marta\n"));
+        assert!(note.ends_with("## Acciones\n\n- [ ] \n"));
+        // La tarea vacía de la plantilla no es una acción
+        assert!(extract_action_items(&note).is_empty());
+    }
+
+    #[test]
+    fn test_extract_action_items() {
+        let content = "# Reunión\n\n\
+                       - [ ] Enviar el informe @Ana 📅 2026-10-20\n\
+                       - [x] Reservar sala @Luis\n\
+                       - [/] Revisar presupuesto @Luis, @Marta due:2026-10-23\n\
+                       ```\n- [ ] dentro de código\n```\n\
+                       - [ ] Llamar al proveedor\n\
+                       !!RECORDAR(2026-10-17 09:00, Llamar al proveedor)\n";
+        let items = extract_action_items(content);
+        assert_eq!(items.len(), 3);
+
+        assert_eq!(items[0].text, "Enviar el informe @Ana");
+        assert_eq!(items[0].assignees, vec!["Ana"]);
+        assert_eq!(items[0].due, NaiveDate::from_ymd_opt(2026, 10, 20));
+        assert_eq!(items[0].line, 2);
+
+        assert_eq!(items[1].text, "Revisar presupuesto @Luis, @Marta");
+        assert_eq!(items[1].assignees, vec!["Luis", "Marta"]);
+        assert_eq!(items[1].due, NaiveDate::from_ymd_opt(2026, 10, 23));
+        assert!(!items[1].has_reminder);
+
+        assert_eq!(items[2].due, None);
+        assert!(items[2].has_reminder);
+
+        let today = NaiveDate::from_ymd_opt(2026, 10, 16).unwrap();
+        assert_eq!(
+            items[0].reminder_at(today),
+            NaiveDate::from_ymd_opt(2026, 10, 20)
+                .unwrap()
+                .and_hms_opt(9, 0, 0)
+                .unwrap()
+        );
+        assert_eq!(
+            items[2].reminder_at(today),
+            NaiveDate::from_ymd_opt(2026, 10, 17)
+                .unwrap()
+                .and_hms_opt(9, 0, 0)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_append_action_items() {
+        let date = NaiveDate::from_ymd_opt(2026, 10, 16).unwrap();
+        let note = meeting_note("R", date.and_hms_opt(9, 0, 0).unwrap(), &[], &labels());
+        let items = vec![
+            "Preparar demo @Ana".to_string(),
+            "preparar demo @ana".to_string(),
+            " ".to_string(),
+        ];
+        let updated = append_action_items(&note, "Acciones", &items);
+        assert!(updated.ends_with("## Acciones\n\n- [ ] Preparar demo @Ana\n"));
+        // Las que ya están no se repiten
+        assert_eq!(append_action_items(&updated, "Acciones", &items), updated);
+
+        // Antes de la siguiente sección
+        let content = "## Acciones\n\n- [ ] Uno\n\n## Otros\n\nTexto";
+        assert_eq!(
+            append_action_items(content, "acciones", &["Dos".to_string()]),
+            "## Acciones\n\n- [ ] Uno\n- [ ] Dos\n\n## Otros\n\nTexto"
+        );
+        // Sin la sección, se crea al final
+        assert_eq!(
+            append_action_items("# Nota\n", "Acciones", &["Dos".to_string()]),
+            "# Nota\n\n## Acciones\n\n- [ ] Dos\n"
+        );
+    }
+}
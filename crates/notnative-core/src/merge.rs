@@ -0,0 +1,144 @@
+//! Fusión de notas
+//!
+//! El contenido de una nota pasa al final de otra bajo un encabezado nuevo,
+//! con un comentario HTML que recuerda de dónde vino. El frontmatter de la
+//! nota fusionada se descarta y sus encabezados bajan de nivel para quedar
+//! dentro de la sección nueva.
+
+use crate::frontmatter::Frontmatter;
+
+/// Nivel del encabezado de la sección que recibe la nota fusionada
+const SECTION_LEVEL: usize = 2;
+
+/// Nivel de un encabezado ATX (`## Título`), si la línea lo es
+pub(crate) fn heading_level(line: &str) -> Option<usize> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    let rest = &line[level..];
+    ((1..=6).contains(&level) && (rest.is_empty() || rest.starts_with(' '))).then_some(level)
+}
+
+/// Añade `source` (la nota `source_name`) al final de `target` bajo un
+/// encabezado con su título. Si la nota empieza por un `# Título` se usa ese,
+/// si no su nombre sin carpeta.
+pub fn merge_into(target: &str, source_name: &str, source: &str, date: &str) -> String {
+    let (_, body) = Frontmatter::parse_or_empty(source);
+    let body = body.trim_start();
+
+    let first_line = body.lines().next().unwrap_or("");
+    let (title, body) = match first_line.strip_prefix("# ") {
+        Some(title) if !title.trim().is_empty() => {
+            (title.trim().to_string(), &body[first_line.len()..])
+        }
+        _ => (
+            source_name
+                .rsplit('/')
+                .next()
+                .unwrap_or(source_name)
+                .to_string(),
+            body,
+        ),
+    };
+    let body = demote_headings(body.trim(), SECTION_LEVEL + 1);
+
+    let mut output = target.trim_end().to_string();
+    if !output.is_empty() {
+        output.push_str("\n\n");
+    }
+    output.push_str(&format!(
+        "{} {}\n\n<!-- merged from \"{}\" on {} -->\n",
+        "#".repeat(SECTION_LEVEL),
+        title,
+        source_name,
+        date
+    ));
+    if !body.is_empty() {
+        output.push('\n');
+        output.push_str(&body);
+        output.push('\n');
+    }
+    output
+}
+
+/// Baja los encabezados (fuera de bloques de código) para que el más alto
+/// quede en `min_level`, sin pasar de `######`
+fn demote_headings(markdown: &str, min_level: usize) -> String {
+    let mut in_code_block = false;
+    let mut top: Option<usize> = None;
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") || line.trim_start().starts_with("~~~") {
+            in_code_block = !in_code_block;
+        } else if !in_code_block {
+            if let Some(level) = heading_level(line) {
+                top = Some(top.map_or(level, |top| top.min(level)));
+            }
+        }
+    }
+    let shift = match top {
+        Some(top) if top < min_level => min_level - top,
+        _ => return markdown.to_string(),
+    };
+
+    in_code_block = false;
+    markdown
+        .lines()
+        .map(|line| {
+            if line.trim_start().starts_with("```") || line.trim_start().starts_with("~~~") {
+                in_code_block = !in_code_block;
+            } else if !in_code_block {
+                if let Some(level) = heading_level(line) {
+                    let new_level = (level + shift).min(6);
+                    return format!("{}{}", "#".repeat(new_level), &line[level..]);
+                }
+            }
+            line.to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_into() {
+        let source = "---\ntags: [idea]\n---\n\n# Idea suelta\n\nTexto.\n\n## Detalle\n\n```\n# no es título\n```\n";
+        let merged = merge_into(
+            "# Ideas\n\nPrimera.\n\n",
+            "Inbox/Idea",
+            source,
+            "2026-10-16",
+        );
+        assert_eq!(
+            merged,
+            "# Ideas\n\nPrimera.\n\n## Idea suelta\n\n\
+             <!-- merged from \"Inbox/Idea\" on 2026-10-16 -->\n\n\
+             Texto.\n\n### Detalle\n\n```\n# no es título\n```\n"
+        );
+    }
+
+    #[test]
+    fn test_merge_into_without_title() {
+        // Sin `# Título` se usa el nombre; los H1 del cuerpo bajan a H3
+        let merged = merge_into("", "Inbox/Llamada", "Notas\n# Parte\n", "2026-10-16");
+        assert_eq!(
+            merged,
+            "## Llamada\n\n<!-- merged from \"Inbox/Llamada\" on 2026-10-16 -->\n\n\
+             Notas\n### Parte\n"
+        );
+
+        let merged = merge_into("Algo", "Vacía", "", "2026-10-16");
+        assert_eq!(
+            merged,
+            "Algo\n\n## Vacía\n\n<!-- merged from \"Vacía\" on 2026-10-16 -->\n"
+        );
+    }
+
+    #[test]
+    fn test_heading_level() {
+        assert_eq!(heading_level("## Dos"), Some(2));
+        assert_eq!(heading_level("#"), Some(1));
+        assert_eq!(heading_level("#tag"), None);
+        assert_eq!(heading_level("####### Siete"), None);
+    }
+}
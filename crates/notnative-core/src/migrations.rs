@@ -0,0 +1,150 @@
+//! Migraciones versionadas del esquema SQLite
+//!
+//! La versión aplicada se guarda en `PRAGMA user_version`. Cada migración se
+//! ejecuta una sola vez, dentro de su propia transacción, y actualiza la versión
+//! al confirmarse: si falla, la base de datos queda en la versión anterior.
+
+use rusqlite::{Connection, OptionalExtension, Transaction, TransactionBehavior};
+use tracing::info;
+
+/// Una actualización del esquema
+pub struct Migration {
+    /// Versión del esquema tras aplicar la migración (consecutivas desde 1)
+    pub version: i32,
+    pub description: &'static str,
+    pub apply: fn(&Connection) -> rusqlite::Result<()>,
+}
+
+/// Versión del esquema registrada en la base de datos
+pub fn current_version(conn: &Connection) -> rusqlite::Result<i32> {
+    conn.query_row("PRAGMA user_version", [], |row| row.get(0))
+}
+
+/// Versión de bases de datos anteriores a `user_version`, guardada en la
+/// tabla `schema_version` (acumulaba una fila por migración)
+fn legacy_version(conn: &Connection) -> rusqlite::Result<Option<i32>> {
+    let has_table: bool = conn.query_row(
+        "SELECT COUNT(*) > 0 FROM sqlite_master WHERE type = 'table' AND name = 'schema_version'",
+        [],
+        |row| row.get(0),
+    )?;
+    if !has_table {
+        return Ok(None);
+    }
+
+    conn.query_row("SELECT MAX(version) FROM schema_version", [], |row| {
+        row.get(0)
+    })
+    .optional()
+    .map(Option::flatten)
+}
+
+/// Aplica las migraciones pendientes y devuelve la versión resultante
+pub fn run(conn: &Connection, migrations: &[Migration]) -> rusqlite::Result<i32> {
+    let legacy = if current_version(conn)? == 0 {
+        legacy_version(conn)?
+    } else {
+        None
+    };
+    if let Some(legacy) = legacy {
+        info!("Adoptando versión de esquema heredada v{}", legacy);
+        conn.pragma_update(None, "user_version", legacy)?;
+    }
+
+    let mut version = current_version(conn)?;
+    for migration in migrations {
+        if migration.version <= version {
+            continue;
+        }
+
+        // IMMEDIATE toma el bloqueo de escritura antes de volver a leer la versión:
+        // si otro proceso migró mientras tanto, no se repite la migración
+        let tx = Transaction::new_unchecked(conn, TransactionBehavior::Immediate)?;
+        if current_version(&tx)? >= migration.version {
+            continue;
+        }
+
+        info!(
+            "Aplicando migración v{}: {}",
+            migration.version, migration.description
+        );
+        (migration.apply)(&tx)?;
+        tx.pragma_update(None, "user_version", migration.version)?;
+        tx.commit()?;
+        version = migration.version;
+    }
+
+    Ok(version)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MIGRATIONS: &[Migration] = &[
+        Migration {
+            version: 1,
+            description: "tabla base",
+            apply: |conn| conn.execute_batch("CREATE TABLE items (id INTEGER PRIMARY KEY)"),
+        },
+        Migration {
+            version: 2,
+            description: "columna nombre",
+            apply: |conn| conn.execute_batch("ALTER TABLE items ADD COLUMN name TEXT"),
+        },
+    ];
+
+    #[test]
+    fn test_migrations_run_once() {
+        let conn = Connection::open_in_memory().unwrap();
+        assert_eq!(run(&conn, MIGRATIONS).unwrap(), 2);
+        // Una segunda ejecución no repite el ALTER TABLE (fallaría)
+        assert_eq!(run(&conn, MIGRATIONS).unwrap(), 2);
+        conn.execute("INSERT INTO items (name) VALUES ('a')", [])
+            .unwrap();
+    }
+
+    #[test]
+    fn test_failed_migration_keeps_previous_version() {
+        let conn = Connection::open_in_memory().unwrap();
+        let broken = [
+            Migration {
+                version: 1,
+                description: "tabla base",
+                apply: |conn| conn.execute_batch("CREATE TABLE items (id INTEGER PRIMARY KEY)"),
+            },
+            Migration {
+                version: 2,
+                description: "rota",
+                apply: |conn| {
+                    conn.execute_batch("CREATE TABLE other (id INTEGER)")?;
+                    conn.execute_batch("ALTER TABLE missing ADD COLUMN x TEXT")
+                },
+            },
+        ];
+
+        assert!(run(&conn, &broken).is_err());
+        assert_eq!(current_version(&conn).unwrap(), 1);
+        let other_exists: bool = conn
+            .query_row(
+                "SELECT COUNT(*) > 0 FROM sqlite_master WHERE name = 'other'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert!(!other_exists);
+    }
+
+    #[test]
+    fn test_adopts_legacy_schema_version_table() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE schema_version (version INTEGER PRIMARY KEY);
+             INSERT INTO schema_version VALUES (1), (2);
+             CREATE TABLE items (id INTEGER PRIMARY KEY, name TEXT);",
+        )
+        .unwrap();
+
+        assert_eq!(run(&conn, MIGRATIONS).unwrap(), 2);
+    }
+}
@@ -0,0 +1,214 @@
+//! Navegación entre notas como en un navegador
+//!
+//! El historial de atrás/adelante vive solo durante la sesión e incluye los
+//! saltos a encabezados (`#anchor`) dentro de una nota. Las notas recientes se
+//! guardan en la configuración con la hora del último acceso.
+
+use serde::{Deserialize, Serialize};
+
+use crate::item_name::renamed_item;
+
+/// Entradas máximas del historial de atrás
+pub const MAX_HISTORY: usize = 100;
+
+/// Notas recientes que se recuerdan
+pub const MAX_RECENT_NOTES: usize = 20;
+
+/// Un punto del historial: una nota y, si se llegó a él, un encabezado
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    pub note: String,
+    pub anchor: Option<String>,
+}
+
+impl Location {
+    pub fn note(note: impl Into<String>) -> Self {
+        Self {
+            note: note.into(),
+            anchor: None,
+        }
+    }
+}
+
+/// Historial de atrás/adelante
+#[derive(Debug, Clone, Default)]
+pub struct NavigationHistory {
+    back: Vec<Location>,
+    forward: Vec<Location>,
+}
+
+impl NavigationHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Se deja `from` para ir a otro sitio: pasa al historial de atrás y el de
+    /// adelante se descarta, como al seguir un enlace en un navegador
+    pub fn visit(&mut self, from: Location) {
+        if self.back.last() != Some(&from) {
+            self.back.push(from);
+            if self.back.len() > MAX_HISTORY {
+                self.back.remove(0);
+            }
+        }
+        self.forward.clear();
+    }
+
+    /// Vuelve al punto anterior; `current` queda para ir adelante
+    pub fn back(&mut self, current: Location) -> Option<Location> {
+        let target = Self::pop_other(&mut self.back, &current)?;
+        self.forward.push(current);
+        Some(target)
+    }
+
+    /// Avanza al punto siguiente; `current` queda para volver atrás
+    pub fn forward(&mut self, current: Location) -> Option<Location> {
+        let target = Self::pop_other(&mut self.forward, &current)?;
+        self.back.push(current);
+        Some(target)
+    }
+
+    pub fn can_go_back(&self) -> bool {
+        !self.back.is_empty()
+    }
+
+    pub fn can_go_forward(&self) -> bool {
+        !self.forward.is_empty()
+    }
+
+    /// Cambia el nombre de una nota renombrada en todo el historial. Con una
+    /// carpeta (`old` sin `.md`) cambia también las notas de dentro.
+    pub fn rename(&mut self, old: &str, new: &str) {
+        for location in self.back.iter_mut().chain(self.forward.iter_mut()) {
+            if let Some(renamed) = renamed_item(&location.note, old, new) {
+                location.note = renamed;
+            }
+        }
+    }
+
+    /// Quita una nota borrada de todo el historial
+    pub fn remove_note(&mut self, note: &str) {
+        self.back.retain(|location| location.note != note);
+        self.forward.retain(|location| location.note != note);
+    }
+
+    /// Último punto de la pila que no sea el actual
+    fn pop_other(stack: &mut Vec<Location>, current: &Location) -> Option<Location> {
+        while let Some(location) = stack.pop() {
+            if location != *current {
+                return Some(location);
+            }
+        }
+        None
+    }
+}
+
+/// Nota abierta recientemente
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecentNote {
+    pub name: String,
+    /// Último acceso (timestamp Unix en segundos)
+    pub opened_at: i64,
+}
+
+/// Apunta `name` como abierta en `opened_at`: pasa a ser la primera y la
+/// lista no crece más de [`MAX_RECENT_NOTES`]
+pub fn touch_recent(recent: &mut Vec<RecentNote>, name: &str, opened_at: i64) {
+    recent.retain(|note| note.name != name);
+    recent.insert(
+        0,
+        RecentNote {
+            name: name.to_string(),
+            opened_at,
+        },
+    );
+    recent.truncate(MAX_RECENT_NOTES);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(note: &str, anchor: Option<&str>) -> Location {
+        Location {
+            note: note.to_string(),
+            anchor: anchor.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_back_and_forward() {
+        let mut history = NavigationHistory::new();
+        assert!(!history.can_go_back());
+
+        // a → b → b#uso → c
+        history.visit(at("a", None));
+        history.visit(at("b", None));
+        history.visit(at("b", Some("uso")));
+        let current = at("c", None);
+
+        let current = history.back(current).unwrap();
+        assert_eq!(current, at("b", Some("uso")));
+        let current = history.back(current).unwrap();
+        assert_eq!(current, at("b", None));
+        assert!(history.can_go_forward());
+
+        let current = history.forward(current).unwrap();
+        assert_eq!(current, at("b", Some("uso")));
+
+        // Una navegación nueva descarta lo que había adelante
+        history.visit(current);
+        assert!(!history.can_go_forward());
+        assert_eq!(history.back(at("d", None)), Some(at("b", Some("uso"))));
+    }
+
+    #[test]
+    fn test_history_skips_current_and_removed_notes() {
+        let mut history = NavigationHistory::new();
+        history.visit(at("a", None));
+        history.visit(at("a", None));
+        history.visit(at("borrada", None));
+        history.visit(at("b", None));
+
+        history.remove_note("borrada");
+        // Volver desde "b" no se queda en "b" ni en la nota borrada
+        assert_eq!(history.back(at("b", None)), Some(at("a", None)));
+        assert_eq!(history.back(at("a", None)), None);
+    }
+
+    #[test]
+    fn test_history_follows_renames() {
+        let mut history = NavigationHistory::new();
+        history.visit(at("Docs/plan", Some("fechas")));
+        history.visit(at("idea", None));
+        history.visit(at("Docs2/otra", None));
+
+        history.rename("idea", "idea final");
+        history.rename("Docs", "Proyecto");
+        let current = history.back(at("c", None)).unwrap();
+        assert_eq!(current, at("Docs2/otra", None));
+        let current = history.back(current).unwrap();
+        assert_eq!(current, at("idea final", None));
+        assert_eq!(
+            history.back(current),
+            Some(at("Proyecto/plan", Some("fechas")))
+        );
+    }
+
+    #[test]
+    fn test_touch_recent() {
+        let mut recent = Vec::new();
+        for (index, name) in ["a", "b", "c", "a"].into_iter().enumerate() {
+            touch_recent(&mut recent, name, index as i64);
+        }
+        let names: Vec<_> = recent.iter().map(|note| note.name.as_str()).collect();
+        assert_eq!(names, ["a", "c", "b"]);
+        assert_eq!(recent[0].opened_at, 3);
+
+        for index in 0..30 {
+            touch_recent(&mut recent, &format!("nota {index}"), 10 + index);
+        }
+        assert_eq!(recent.len(), MAX_RECENT_NOTES);
+        assert_eq!(recent[0].name, "nota 29");
+    }
+}
@@ -0,0 +1,855 @@
+use anyhow::{Context, Result};
+use std::cmp::Reverse;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Nombre de la carpeta de papelera
+const TRASH_DIR: &str = ".trash";
+/// Nombre de la carpeta de historial
+const HISTORY_DIR: &str = ".history";
+
+/// Mueve un archivo. Entre sistemas de archivos distintos (una carpeta
+/// montada y la papelera del vault, por ejemplo) `rename` no funciona y se
+/// copia y borra el original.
+pub fn move_file(from: &Path, to: &Path) -> Result<()> {
+    if fs::rename(from, to).is_ok() {
+        return Ok(());
+    }
+    if fs::symlink_metadata(from)?.is_dir() {
+        anyhow::bail!("No se puede mover la carpeta {:?} a otro disco", from);
+    }
+    fs::copy(from, to).context("No se pudo copiar el archivo")?;
+    if let Err(e) = fs::remove_file(from) {
+        // Mejor no dejarlo duplicado
+        let _ = fs::remove_file(to);
+        return Err(e).context("No se pudo quitar el original");
+    }
+    Ok(())
+}
+
+/// Carpetas ya recorridas (por su ruta real), para no entrar dos veces en la
+/// misma ni dar vueltas con enlaces que apuntan hacia arriba. Solo se entra en
+/// el vault y en sus carpetas montadas: un enlace a cualquier otro sitio se salta.
+#[derive(Debug)]
+pub struct Visited {
+    seen: HashSet<PathBuf>,
+    /// Rutas reales donde se puede entrar
+    allowed: Vec<PathBuf>,
+}
+
+impl Visited {
+    fn within(allowed: Vec<PathBuf>) -> Self {
+        Self {
+            seen: HashSet::new(),
+            allowed,
+        }
+    }
+
+    /// La ruta real queda dentro del vault o de una carpeta montada
+    pub fn allows(&self, path: &Path) -> bool {
+        path.canonicalize()
+            .is_ok_and(|real| self.allowed.iter().any(|root| real.starts_with(root)))
+    }
+
+    /// `true` la primera vez; los enlaces rotos o que salen del vault no se
+    /// recorren
+    pub fn enter(&mut self, dir: &Path) -> bool {
+        match dir.canonicalize() {
+            Ok(real) if self.allowed.iter().any(|root| real.starts_with(root)) => {
+                self.seen.insert(real)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Carpeta externa montada en el vault: un enlace simbólico en la raíz que
+/// apunta a ella
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FolderMount {
+    /// Nombre de la carpeta en el vault
+    pub name: String,
+    /// Carpeta externa (ruta real)
+    pub target: PathBuf,
+    /// Ruta dentro del vault
+    pub path: PathBuf,
+    /// La carpeta externa existe (un disco o una carpeta sincronizada pueden
+    /// no estar)
+    pub available: bool,
+}
+
+impl FolderMount {
+    /// Ruta dentro del vault de una ruta real de la carpeta externa
+    pub fn vault_path(&self, real: &Path) -> Option<PathBuf> {
+        real.strip_prefix(&self.target)
+            .ok()
+            .map(|rest| self.path.join(rest))
+    }
+}
+
+/// Gestor de archivos .md para notas
+#[derive(Debug, Clone)]
+pub struct NoteFile {
+    /// Ruta absoluta al archivo .md
+    path: PathBuf,
+    /// Nombre de la nota (sin extensión, puede incluir ruta relativa como "Docs VS/nota")
+    pub(crate) name: String,
+}
+
+impl NoteFile {
+    /// Crea una referencia a un archivo de nota existente
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+
+        if !path.exists() {
+            anyhow::bail!("El archivo no existe: {:?}", path);
+        }
+
+        if path.extension().and_then(|s| s.to_str()) != Some("md") {
+            anyhow::bail!("El archivo debe tener extensión .md");
+        }
+
+        let name = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .context("No se pudo obtener el nombre del archivo")?
+            .to_string();
+
+        Ok(Self { path, name })
+    }
+
+    /// Crea un nuevo archivo de nota
+    pub fn create<P: AsRef<Path>>(path: P, initial_content: &str) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+
+        // Crear directorios padres si no existen
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).context("No se pudo crear el directorio padre")?;
+        }
+
+        // Escribir contenido inicial
+        fs::write(&path, initial_content).context("No se pudo escribir el archivo")?;
+
+        Self::open(path)
+    }
+
+    /// Lee el contenido del archivo
+    pub fn read(&self) -> Result<String> {
+        fs::read_to_string(&self.path).context("No se pudo leer el archivo")
+    }
+
+    /// Escribe contenido al archivo
+    pub fn write(&self, content: &str) -> Result<()> {
+        fs::write(&self.path, content).context("No se pudo escribir el archivo")
+    }
+
+    /// Devuelve la ruta del archivo
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Devuelve el nombre de la nota
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Renombra el archivo
+    pub fn rename<P: AsRef<Path>>(&mut self, new_name: P) -> Result<()> {
+        let new_path = self
+            .path
+            .parent()
+            .context("No se pudo obtener el directorio padre")?
+            .join(new_name.as_ref());
+
+        fs::rename(&self.path, &new_path).context("No se pudo renombrar el archivo")?;
+
+        self.path = new_path;
+        self.name = self
+            .path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .context("No se pudo obtener el nuevo nombre")?
+            .to_string();
+
+        Ok(())
+    }
+
+    /// Mueve el archivo a la papelera
+    /// Devuelve la ruta en la papelera (para poder deshacerlo).
+    pub fn trash(self, notes_dir: &NotesDirectory) -> Result<PathBuf> {
+        let trash_path = notes_dir.trash_path();
+        if !trash_path.exists() {
+            fs::create_dir_all(&trash_path)
+                .context("No se pudo crear el directorio de papelera")?;
+        }
+
+        // Generar un nombre único con timestamp para evitar colisiones
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+
+        // Calcular la ruta relativa completa desde notes_dir
+        // Ej: /home/user/notes/Proyectos/Joyeria/idea.md -> Proyectos_Joyeria_idea
+        let relative_name = self
+            .path
+            .strip_prefix(notes_dir.root())
+            .ok()
+            .and_then(|p| p.with_extension("").to_str().map(|s| s.to_string()))
+            .unwrap_or_else(|| self.name.clone());
+
+        let safe_name = relative_name.replace('/', "_");
+        let trash_filename = format!("{}_{}.md", safe_name, timestamp);
+        let dest_path = trash_path.join(trash_filename);
+
+        move_file(&self.path, &dest_path).context("No se pudo mover el archivo a la papelera")?;
+        Ok(dest_path)
+    }
+
+    /// Crea una copia de seguridad del archivo actual en el historial.
+    /// Devuelve la ruta de la copia (`None` si el archivo no existe).
+    pub fn backup(&self, notes_dir: &NotesDirectory) -> Result<Option<PathBuf>> {
+        if !self.path.exists() {
+            return Ok(None);
+        }
+
+        let history_path = notes_dir.root().join(HISTORY_DIR);
+        if !history_path.exists() {
+            fs::create_dir_all(&history_path)
+                .context("No se pudo crear directorio de historial")?;
+        }
+
+        let mut timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+
+        // Calcular la ruta relativa completa desde notes_dir
+        // Ej: /home/user/notes/Proyectos/Joyeria/idea.md -> Proyectos/Joyeria/idea
+        let relative_name = self
+            .path
+            .strip_prefix(notes_dir.root())
+            .ok()
+            .and_then(|p| p.with_extension("").to_str().map(|s| s.to_string()))
+            .unwrap_or_else(|| self.name.clone());
+
+        let safe_name = relative_name.replace('/', "_");
+        // Dos copias en el mismo segundo no se pisan: la segunda va al siguiente
+        let mut dest_path = history_path.join(format!("{}_{}.md", safe_name, timestamp));
+        while dest_path.exists() {
+            timestamp += 1;
+            dest_path = history_path.join(format!("{}_{}.md", safe_name, timestamp));
+        }
+
+        fs::copy(&self.path, &dest_path).context("No se pudo crear backup")?;
+
+        Ok(Some(dest_path))
+    }
+
+    /// Elimina el archivo permanentemente
+    pub fn delete(self) -> Result<()> {
+        fs::remove_file(&self.path).context("No se pudo eliminar el archivo")
+    }
+}
+
+/// Gestor del directorio de notas
+#[derive(Debug, Clone)]
+pub struct NotesDirectory {
+    /// Ruta al directorio raíz de notas
+    root: PathBuf,
+}
+
+impl NotesDirectory {
+    /// Crea o abre un directorio de notas
+    pub fn new<P: AsRef<Path>>(root: P) -> Result<Self> {
+        let root = root.as_ref().to_path_buf();
+
+        if !root.exists() {
+            fs::create_dir_all(&root).context("No se pudo crear el directorio de notas")?;
+        }
+
+        Ok(Self { root })
+    }
+
+    /// Obtiene la ruta al directorio raíz
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Obtiene la ruta al directorio de papelera
+    pub fn trash_path(&self) -> PathBuf {
+        self.root.join(TRASH_DIR)
+    }
+
+    /// Devuelve a `original` una nota o carpeta movida a la papelera. Falla si
+    /// ya hay algo en esa ruta, para no pisarlo.
+    pub fn restore_from_trash(&self, trashed: &Path, original: &Path) -> Result<()> {
+        if original.exists() {
+            anyhow::bail!("Ya existe {:?}", original);
+        }
+        if let Some(parent) = original.parent() {
+            fs::create_dir_all(parent).context("No se pudo crear la carpeta de destino")?;
+        }
+        move_file(trashed, original).context("No se pudo restaurar desde la papelera")
+    }
+
+    /// Monta una carpeta externa como carpeta `name` de la raíz. Sus notas se
+    /// indexan, se buscan y se editan como las demás, pero siguen en su sitio.
+    pub fn mount(&self, name: &str, target: &Path) -> Result<FolderMount> {
+        if name.is_empty() || name.starts_with('.') || name.contains('/') {
+            anyhow::bail!("Nombre de carpeta no válido: {:?}", name);
+        }
+        let link = self.root.join(name);
+        if fs::symlink_metadata(&link).is_ok() {
+            anyhow::bail!("Ya existe {:?}", link);
+        }
+        let target = target
+            .canonicalize()
+            .with_context(|| format!("No existe la carpeta {:?}", target))?;
+        if !target.is_dir() {
+            anyhow::bail!("{:?} no es una carpeta", target);
+        }
+        let root = self.root.canonicalize()?;
+        // Dentro del vault saldría dos veces; conteniéndolo, el vault se
+        // contendría a sí mismo
+        if target.starts_with(&root) || root.starts_with(&target) {
+            anyhow::bail!("{:?} no puede montarse en {:?}", target, root);
+        }
+
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(&target, &link).context("No se pudo crear el enlace")?;
+        #[cfg(not(unix))]
+        anyhow::bail!("Montar carpetas solo está disponible en Unix");
+
+        #[allow(unreachable_code)]
+        Ok(FolderMount {
+            name: name.to_string(),
+            target,
+            path: link,
+            available: true,
+        })
+    }
+
+    /// Desmonta una carpeta: quita el enlace, nunca su contenido
+    pub fn unmount(&self, name: &str) -> Result<()> {
+        let link = self.root.join(name);
+        let is_link = fs::symlink_metadata(&link)
+            .map(|meta| meta.file_type().is_symlink())
+            .unwrap_or(false);
+        if name.contains('/') || !is_link {
+            anyhow::bail!("{:?} no es una carpeta montada", name);
+        }
+        fs::remove_file(&link).context("No se pudo quitar el enlace")
+    }
+
+    /// Carpetas montadas (enlaces a carpetas en la raíz), por nombre
+    pub fn mounts(&self) -> Vec<FolderMount> {
+        let Ok(entries) = fs::read_dir(&self.root) else {
+            return Vec::new();
+        };
+        let mut mounts: Vec<FolderMount> = entries
+            .flatten()
+            .filter(|entry| entry.file_type().is_ok_and(|kind| kind.is_symlink()))
+            .filter_map(|entry| {
+                let path = entry.path();
+                let name = entry.file_name().to_string_lossy().to_string();
+                let target = fs::read_link(&path).ok()?;
+                let target = self.root.join(target);
+                let real = target.canonicalize();
+                // Los enlaces a archivos no son carpetas montadas
+                if real.as_ref().is_ok_and(|real| !real.is_dir()) {
+                    return None;
+                }
+                Some(FolderMount {
+                    name,
+                    available: real.is_ok(),
+                    target: real.unwrap_or(target),
+                    path,
+                })
+            })
+            .collect();
+        mounts.sort_by(|a, b| a.name.cmp(&b.name));
+        mounts
+    }
+
+    /// Si la carpeta (relativa a la raíz) es una carpeta montada
+    pub fn is_mount(&self, folder: &str) -> bool {
+        !folder.contains('/')
+            && fs::symlink_metadata(self.root.join(folder))
+                .is_ok_and(|meta| meta.file_type().is_symlink())
+    }
+
+    /// Recorrido del vault: la raíz y las carpetas montadas disponibles (las que
+    /// contienen el vault no cuentan, lo abarcarían todo)
+    pub fn visited(&self) -> Visited {
+        let Ok(root) = self.root.canonicalize() else {
+            return Visited::within(Vec::new());
+        };
+        let mut allowed: Vec<PathBuf> = self
+            .mounts()
+            .into_iter()
+            .filter(|mount| mount.available && !root.starts_with(&mount.target))
+            .map(|mount| mount.target)
+            .collect();
+        allowed.push(root);
+        Visited::within(allowed)
+    }
+
+    /// Todas las carpetas, relativas a la raíz y ordenadas. Sigue los enlaces
+    /// sin entrar dos veces en la misma carpeta real ni salir del vault y sus
+    /// carpetas montadas.
+    /// Las ocultas se saltan, salvo la papelera si `include_trash`.
+    pub fn folders(&self, include_trash: bool) -> Vec<String> {
+        fn walk(
+            dir: &Path,
+            root: &Path,
+            include_trash: bool,
+            visited: &mut Visited,
+            folders: &mut Vec<String>,
+        ) {
+            let Ok(entries) = fs::read_dir(dir) else {
+                return;
+            };
+            for entry in entries.flatten() {
+                let path = entry.path();
+                let name = entry.file_name().to_string_lossy().to_string();
+                if name.starts_with('.') && !(include_trash && name == TRASH_DIR) {
+                    continue;
+                }
+                if !path.is_dir() || !visited.enter(&path) {
+                    continue;
+                }
+                if let Ok(relative) = path.strip_prefix(root) {
+                    folders.push(relative.to_string_lossy().to_string());
+                }
+                walk(&path, root, include_trash, visited, folders);
+            }
+        }
+
+        let mut visited = self.visited();
+        visited.enter(&self.root);
+        let mut folders = Vec::new();
+        walk(
+            &self.root,
+            &self.root,
+            include_trash,
+            &mut visited,
+            &mut folders,
+        );
+        folders.sort();
+        folders
+    }
+
+    /// Versiones guardadas de una nota en el historial (`.history/<nombre con
+    /// _ en vez de />_<timestamp>.md`): timestamp Unix y ruta, de la más
+    /// reciente a la más antigua
+    pub fn history_versions(&self, name: &str) -> Vec<(u64, PathBuf)> {
+        let prefix = format!("{}_", name.replace('/', "_"));
+        let Ok(entries) = fs::read_dir(self.root.join(HISTORY_DIR)) else {
+            return Vec::new();
+        };
+        let mut versions: Vec<(u64, PathBuf)> = entries
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| {
+                let path = entry.path();
+                let timestamp = path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .and_then(|name| name.strip_prefix(&prefix))
+                    .and_then(|rest| rest.strip_suffix(".md"))
+                    .and_then(|rest| rest.parse::<u64>().ok())?;
+                Some((timestamp, path))
+            })
+            .collect();
+        versions.sort_by_key(|(timestamp, _)| Reverse(*timestamp));
+        versions
+    }
+
+    /// Obtiene la ruta al archivo de base de datos
+    pub fn db_path(&self) -> PathBuf {
+        self.root.parent().unwrap_or(&self.root).join("notes.db")
+    }
+
+    /// Obtiene la carpeta relativa de una nota (si está en una subcarpeta)
+    pub fn relative_folder(&self, note_path: &Path) -> Option<String> {
+        note_path
+            .parent()
+            .and_then(|p| p.strip_prefix(&self.root).ok())
+            .filter(|p| p != &Path::new(""))
+            .map(|p| p.to_string_lossy().to_string())
+    }
+
+    /// Lista todas las notas en el directorio (recursivo)
+    pub fn list_notes(&self) -> Result<Vec<NoteFile>> {
+        let mut notes = Vec::new();
+        let mut visited = self.visited();
+        self.scan_directory(&self.root, &mut notes, &mut visited)?;
+        Ok(notes)
+    }
+
+    fn scan_directory(
+        &self,
+        dir: &Path,
+        notes: &mut Vec<NoteFile>,
+        visited: &mut Visited,
+    ) -> Result<()> {
+        // Los enlaces pueden llevar a una carpeta ya recorrida o rota
+        if !dir.is_dir() || !visited.enter(dir) {
+            return Ok(());
+        }
+
+        // Ignorar la carpeta de papelera si estamos escaneando dentro de ella (no debería pasar con la lógica de abajo, pero por seguridad)
+        if dir.ends_with(TRASH_DIR) || dir.ends_with(HISTORY_DIR) {
+            return Ok(());
+        }
+
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            let path = entry.path();
+
+            // Ignorar la carpeta de papelera y historial
+            if path.file_name().and_then(|s| s.to_str()) == Some(TRASH_DIR)
+                || path.file_name().and_then(|s| s.to_str()) == Some(HISTORY_DIR)
+            {
+                continue;
+            }
+
+            if path.is_dir() {
+                self.scan_directory(&path, notes, visited)?;
+            } else if path.extension().and_then(|s| s.to_str()) == Some("md")
+                && visited.allows(&path)
+            {
+                if let Ok(mut note) = NoteFile::open(&path) {
+                    // Si la nota está en una subcarpeta, ajustar su nombre para incluir la ruta relativa
+                    if let Ok(relative_path) = path.strip_prefix(&self.root) {
+                        if let Some(parent) = relative_path.parent() {
+                            if parent != Path::new("") {
+                                // Reconstruir el nombre con la carpeta: "Docs VS/nombre"
+                                let folder = parent.to_string_lossy();
+                                let file_stem =
+                                    path.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+                                note.name = format!("{}/{}", folder, file_stem);
+                            }
+                        }
+                    }
+                    notes.push(note);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Crea una nueva nota en el directorio raíz
+    pub fn create_note(&self, name: &str, content: &str) -> Result<NoteFile> {
+        let filename = format!("{}.md", name);
+        let path = self.root.join(filename);
+        NoteFile::create(path, content)
+    }
+
+    /// Crea una nueva nota en una subcarpeta
+    pub fn create_note_in_folder(
+        &self,
+        folder: &str,
+        name: &str,
+        content: &str,
+    ) -> Result<NoteFile> {
+        let filename = format!("{}.md", name);
+        let folder_path = self.root.join(folder);
+
+        // Crear la carpeta si no existe (incluyendo carpetas padre)
+        std::fs::create_dir_all(&folder_path)?;
+
+        let path = folder_path.join(filename);
+        NoteFile::create(path, content)
+    }
+
+    /// Busca una nota por nombre
+    pub fn find_note(&self, name: &str) -> Result<Option<NoteFile>> {
+        // Si el nombre empieza por .trash/, buscar directamente allí sin usar list_notes
+        if name.starts_with(".trash/") {
+            let path = self.root.join(format!("{}.md", name));
+            if path.exists() {
+                let mut note = NoteFile::open(&path)?;
+                note.name = name.to_string(); // Forzar el nombre correcto con carpeta
+                return Ok(Some(note));
+            }
+        }
+
+        let notes = self.list_notes()?;
+
+        // Primero intentar coincidencia exacta por nombre
+        if let Some(note) = notes.iter().find(|n| n.name() == name).cloned() {
+            return Ok(Some(note));
+        }
+
+        // Si el nombre tiene '/', intentar construir la ruta y buscar por ruta
+        if name.contains('/') {
+            let target_path = self.root.join(format!("{}.md", name));
+            if let Some(note) = notes.iter().find(|n| n.path() == target_path).cloned() {
+                return Ok(Some(note));
+            }
+        }
+
+        // Si no se encuentra, buscar solo por el nombre base (sin carpeta)
+        let base_name = name.split('/').last().unwrap_or(name);
+        Ok(notes.into_iter().find(|n| {
+            let note_base = n.name().split('/').last().unwrap_or(n.name());
+            note_base == base_name
+        }))
+    }
+}
+
+impl Default for NotesDirectory {
+    fn default() -> Self {
+        // Por defecto usar ~/.local/share/notnative/notes
+        let home = dirs::home_dir().expect("No se pudo obtener el directorio home");
+        let root = home.join(".local/share/notnative/notes");
+        Self::new(root).expect("No se pudo crear el directorio de notas por defecto")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_create_and_read_note() {
+        let temp_dir = env::temp_dir().join("notnative_test");
+        let notes_dir = NotesDirectory::new(&temp_dir).unwrap();
+
+        let note = notes_dir
+            .create_note("test", "# Test Note\n\nHello World")
+            .unwrap();
+        assert_eq!(note.name(), "test");
+
+        let content = note.read().unwrap();
+        assert_eq!(content, "# Test Note\n\nHello World");
+
+        // Cleanup
+        note.delete().unwrap();
+        let _ = fs::remove_dir_all(temp_dir);
+    }
+
+    #[test]
+    fn test_list_notes() {
+        let temp_dir = env::temp_dir().join("notnative_test_list");
+        let notes_dir = NotesDirectory::new(&temp_dir).unwrap();
+
+        notes_dir.create_note("note1", "Content 1").unwrap();
+        notes_dir.create_note("note2", "Content 2").unwrap();
+
+        let notes = notes_dir.list_notes().unwrap();
+        assert_eq!(notes.len(), 2);
+
+        // Cleanup
+        for note in notes {
+            note.delete().unwrap();
+        }
+        let _ = fs::remove_dir_all(temp_dir);
+    }
+
+    #[test]
+    fn test_history_versions() {
+        let temp_dir = env::temp_dir().join("notnative_test_history");
+        let _ = fs::remove_dir_all(&temp_dir);
+        let notes_dir = NotesDirectory::new(&temp_dir).unwrap();
+
+        let history = temp_dir.join(HISTORY_DIR);
+        fs::create_dir_all(&history).unwrap();
+        for file in [
+            "Docs_plan_100.md",
+            "Docs_plan_300.md",
+            "Docs_plan_final_200.md",
+            "Docs_plan_x.md",
+        ] {
+            fs::write(history.join(file), "").unwrap();
+        }
+
+        let versions = notes_dir.history_versions("Docs/plan");
+        assert_eq!(
+            versions,
+            vec![
+                (300, history.join("Docs_plan_300.md")),
+                (100, history.join("Docs_plan_100.md")),
+            ]
+        );
+        assert!(notes_dir.history_versions("otra").is_empty());
+
+        let _ = fs::remove_dir_all(temp_dir);
+    }
+
+    #[test]
+    fn test_trash_note() {
+        let temp_dir = env::temp_dir().join("notnative_test_trash");
+        let notes_dir = NotesDirectory::new(&temp_dir).unwrap();
+
+        let note = notes_dir.create_note("trash_me", "Delete me").unwrap();
+
+        let note_path = note.path().to_path_buf();
+        assert!(note_path.exists());
+
+        // Trash the note
+        let trashed = note.trash(&notes_dir).unwrap();
+        assert!(trashed.exists());
+
+        // Original file should not exist
+        assert!(!note_path.exists());
+
+        // Trash directory should exist
+        let trash_dir = notes_dir.trash_path();
+        assert!(trash_dir.exists());
+
+        // Should be a file in trash
+        let entries: Vec<_> = fs::read_dir(&trash_dir)
+            .unwrap()
+            .map(|res| res.unwrap().path())
+            .collect();
+
+        assert_eq!(entries.len(), 1);
+        let trashed_file = &entries[0];
+        assert!(
+            trashed_file
+                .file_name()
+                .unwrap()
+                .to_string_lossy()
+                .starts_with("trash_me_")
+        );
+
+        // Cleanup
+        let _ = fs::remove_dir_all(temp_dir);
+    }
+
+    #[test]
+    fn test_restore_from_trash() {
+        let temp_dir = env::temp_dir().join("notnative_test_restore");
+        let _ = fs::remove_dir_all(&temp_dir);
+        let notes_dir = NotesDirectory::new(&temp_dir).unwrap();
+
+        let note = notes_dir
+            .create_note_in_folder("Docs", "restore_me", "Vuelve")
+            .unwrap();
+        let note_path = note.path().to_path_buf();
+        let trashed = note.trash(&notes_dir).unwrap();
+        fs::remove_dir(temp_dir.join("Docs")).unwrap();
+
+        notes_dir.restore_from_trash(&trashed, &note_path).unwrap();
+        assert!(!trashed.exists());
+        assert_eq!(fs::read_to_string(&note_path).unwrap(), "Vuelve");
+
+        // No pisa una nota que ya ocupa la ruta
+        let again = NoteFile::open(&note_path)
+            .unwrap()
+            .trash(&notes_dir)
+            .unwrap();
+        fs::write(&note_path, "Otra").unwrap();
+        assert!(notes_dir.restore_from_trash(&again, &note_path).is_err());
+        assert!(again.exists());
+
+        let _ = fs::remove_dir_all(temp_dir);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_mount_external_folder() {
+        let base = env::temp_dir().join(format!("notnative_test_mount_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&base);
+        let notes_dir = NotesDirectory::new(base.join("vault")).unwrap();
+        notes_dir.create_note("local", "").unwrap();
+        let docs = base.join("repo/docs");
+        fs::create_dir_all(docs.join("guias")).unwrap();
+        fs::write(docs.join("guias/instalar.md"), "Pasos").unwrap();
+        // Un enlace que vuelve hacia arriba no se recorre dos veces
+        std::os::unix::fs::symlink(&docs, docs.join("guias/bucle")).unwrap();
+
+        let mount = notes_dir.mount("Docs", &docs).unwrap();
+        assert_eq!(mount.target, docs.canonicalize().unwrap());
+        assert!(notes_dir.mount("Docs", &docs).is_err());
+        assert!(notes_dir.mount("Vault", notes_dir.root()).is_err());
+        assert!(notes_dir.mount("Todo", &base).is_err());
+        assert!(notes_dir.is_mount("Docs"));
+        assert!(!notes_dir.is_mount("Docs/guias"));
+
+        let mut names: Vec<String> = notes_dir
+            .list_notes()
+            .unwrap()
+            .iter()
+            .map(|note| note.name().to_string())
+            .collect();
+        names.sort();
+        assert_eq!(names, vec!["Docs/guias/instalar", "local"]);
+        assert_eq!(notes_dir.folders(false), vec!["Docs", "Docs/guias"]);
+        assert_eq!(
+            mount.vault_path(&docs.canonicalize().unwrap().join("guias/instalar.md")),
+            Some(notes_dir.root().join("Docs/guias/instalar.md"))
+        );
+
+        // A la papelera del vault (aunque esté en otro disco) y de vuelta
+        let note = notes_dir.find_note("Docs/guias/instalar").unwrap().unwrap();
+        let original = note.path().to_path_buf();
+        let trashed = note.trash(&notes_dir).unwrap();
+        assert!(!docs.join("guias/instalar.md").exists());
+        notes_dir.restore_from_trash(&trashed, &original).unwrap();
+        assert!(docs.join("guias/instalar.md").exists());
+
+        // Desmontar no toca el contenido; un enlace roto no se recorre
+        notes_dir.unmount("Docs").unwrap();
+        assert!(docs.join("guias/instalar.md").exists());
+        assert!(notes_dir.unmount("local.md").is_err());
+        std::os::unix::fs::symlink(base.join("no-existe"), notes_dir.root().join("Roto")).unwrap();
+        let mounts = notes_dir.mounts();
+        assert_eq!(mounts.len(), 1);
+        assert!(!mounts[0].available);
+        assert_eq!(notes_dir.list_notes().unwrap().len(), 1);
+
+        let _ = fs::remove_dir_all(base);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_links_outside_vault_are_skipped() {
+        let base = env::temp_dir().join(format!("notnative_test_escape_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&base);
+        let notes_dir = NotesDirectory::new(base.join("vault")).unwrap();
+        notes_dir
+            .create_note_in_folder("Proyectos", "plan", "")
+            .unwrap();
+        let outside = base.join("privado");
+        fs::create_dir_all(&outside).unwrap();
+        fs::write(outside.join("claves.md"), "secreto").unwrap();
+
+        // Enlaces dentro del vault que apuntan fuera (sin montarlos)
+        let root = notes_dir.root();
+        std::os::unix::fs::symlink(&outside, root.join("Proyectos/fuera")).unwrap();
+        std::os::unix::fs::symlink(outside.join("claves.md"), root.join("claves.md")).unwrap();
+        // Un enlace que no sale del vault sí se sigue
+        std::os::unix::fs::symlink(root.join("Proyectos/plan.md"), root.join("atajo.md")).unwrap();
+
+        let mut names: Vec<String> = notes_dir
+            .list_notes()
+            .unwrap()
+            .iter()
+            .map(|note| note.name().to_string())
+            .collect();
+        names.sort();
+        assert_eq!(names, vec!["Proyectos/plan", "atajo"]);
+        assert_eq!(notes_dir.folders(false), vec!["Proyectos"]);
+
+        // Montada, la carpeta externa ya forma parte del vault (una vez, por
+        // la primera ruta que llegue a ella)
+        notes_dir.mount("Privado", &outside).unwrap();
+        let names: Vec<String> = notes_dir
+            .list_notes()
+            .unwrap()
+            .iter()
+            .map(|note| note.name().to_string())
+            .collect();
+        assert_eq!(names.len(), 4);
+        assert!(names.contains(&"claves".to_string()));
+
+        let _ = fs::remove_dir_all(base);
+    }
+}
@@ -0,0 +1,382 @@
+//! Operaciones sobre notas que tocan el archivo y el índice a la vez
+//!
+//! Crear, renombrar, mover o mandar a la papelera una nota (o mover una
+//! carpeta entera) cambia el archivo `.md` y varias tablas de la base de datos
+//! (nota, búsqueda, tags, propiedades, embeddings). [`NoteStore`] hace primero el cambio en disco y después
+//! actualiza la base de datos en una sola transacción; si esa parte falla,
+//! deshace el cambio en disco para que archivo e índice no se contradigan.
+//!
+//! [`NoteStore::check_consistency`] repara al arrancar lo que pudiera haber
+//! quedado a medias (filas sin archivo, embeddings o entradas de búsqueda sin
+//! nota).
+
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::{error, info, warn};
+
+use crate::database::NotesDatabase;
+use crate::note_file::{NoteFile, NotesDirectory, move_file};
+
+/// Lo que reparó [`NoteStore::check_consistency`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ConsistencyReport {
+    /// Notas del índice cuyo archivo ya no existe
+    pub orphan_notes: usize,
+    pub orphan_embeddings: usize,
+    pub orphan_search_entries: usize,
+    pub orphan_properties: usize,
+}
+
+impl ConsistencyReport {
+    pub fn total(&self) -> usize {
+        self.orphan_notes
+            + self.orphan_embeddings
+            + self.orphan_search_entries
+            + self.orphan_properties
+    }
+
+    pub fn is_clean(&self) -> bool {
+        self.total() == 0
+    }
+}
+
+/// Notas de una carpeta junto con su índice
+pub struct NoteStore<'a> {
+    dir: &'a NotesDirectory,
+    db: &'a NotesDatabase,
+}
+
+impl<'a> NoteStore<'a> {
+    pub fn new(dir: &'a NotesDirectory, db: &'a NotesDatabase) -> Self {
+        Self { dir, db }
+    }
+
+    /// Crea la nota y la indexa. Nunca pisa una nota existente.
+    pub fn create(&self, folder: Option<&str>, name: &str, content: &str) -> Result<NoteFile> {
+        let path = match folder {
+            Some(folder) => self.dir.root().join(folder),
+            None => self.dir.root().to_path_buf(),
+        }
+        .join(format!("{}.md", name));
+        if path.exists() {
+            anyhow::bail!("Ya existe {:?}", path);
+        }
+
+        let note = NoteFile::create(&path, content)?;
+        let indexed = self.index(&note, content);
+        self.undo_on_error(indexed, || fs::remove_file(&path).map_err(Into::into))?;
+        Ok(note)
+    }
+
+    /// Renombra la nota dentro de su carpeta. Devuelve (ruta anterior, ruta
+    /// nueva).
+    pub fn rename(&self, name: &str, new_name: &str) -> Result<(PathBuf, PathBuf)> {
+        let note = self.find(name)?;
+        let old_path = note.path().to_path_buf();
+        let new_path = old_path.with_file_name(format!("{}.md", new_name));
+        self.relocate(&old_path, &new_path, |db, old| {
+            let folder = self.dir.relative_folder(&new_path);
+            let new_path = new_path.to_string_lossy();
+            match old {
+                Some(meta) => {
+                    // Se respeta cómo está guardado el nombre (con o sin carpeta)
+                    let db_name = match meta.name.rsplit_once('/') {
+                        Some((prefix, _)) => format!("{}/{}", prefix, new_name),
+                        None => new_name.to_string(),
+                    };
+                    db.rename_note(&meta.name, &db_name, &new_path, folder.as_deref())
+                }
+                None => self.index_path(db, new_name, &new_path, folder.as_deref()),
+            }
+        })?;
+        Ok((old_path, new_path))
+    }
+
+    /// Mueve la nota a `folder` (`None` es la raíz). Devuelve (ruta anterior,
+    /// ruta nueva), o `None` si ya estaba ahí.
+    pub fn move_to_folder(
+        &self,
+        name: &str,
+        folder: Option<&str>,
+    ) -> Result<Option<(PathBuf, PathBuf)>> {
+        let note = self.find(name)?;
+        let old_path = note.path().to_path_buf();
+        let file_name = old_path
+            .file_name()
+            .context("La nota no tiene nombre de archivo")?;
+        let new_path = match folder {
+            Some(folder) => self.dir.root().join(folder),
+            None => self.dir.root().to_path_buf(),
+        }
+        .join(file_name);
+        if new_path == old_path {
+            return Ok(None);
+        }
+
+        self.relocate(&old_path, &new_path, |db, old| {
+            let new_path = new_path.to_string_lossy();
+            match old {
+                Some(meta) => db.move_note_to_folder(meta.id, folder, &new_path),
+                None => self.index_path(db, note.name(), &new_path, folder),
+            }
+        })?;
+        Ok(Some((old_path, new_path)))
+    }
+
+    /// Renombra o mueve la carpeta `folder` a `new_folder` (rutas relativas a
+    /// la raíz) con todas sus notas y subcarpetas. Devuelve (ruta anterior,
+    /// ruta nueva).
+    pub fn move_folder(&self, folder: &str, new_folder: &str) -> Result<(PathBuf, PathBuf)> {
+        let old_path = self.dir.root().join(folder);
+        if !old_path.is_dir() {
+            anyhow::bail!("No existe la carpeta {:?}", old_path);
+        }
+        let new_path = self.dir.root().join(new_folder);
+        let root = self.dir.root().to_string_lossy();
+
+        self.relocate(&old_path, &new_path, |db, _| {
+            db.update_notes_folder(folder, new_folder, &root)
+                .map(|_| ())
+        })?;
+        Ok((old_path, new_path))
+    }
+
+    /// Manda la nota a la papelera y la quita del índice. Devuelve (ruta en
+    /// la papelera, ruta original) para poder deshacerlo.
+    pub fn trash(&self, name: &str) -> Result<(PathBuf, PathBuf)> {
+        let note = self.find(name)?;
+        let original = note.path().to_path_buf();
+        let trashed = note.trash(self.dir)?;
+
+        let removed =
+            self.db.transaction(
+                |db| match db.get_note_by_path(&original.to_string_lossy())? {
+                    Some(meta) => db.delete_note(&meta.name),
+                    None => db.delete_note(name),
+                },
+            );
+        self.undo_on_error(removed.map_err(Into::into), || {
+            self.dir.restore_from_trash(&trashed, &original)
+        })?;
+        Ok((trashed, original))
+    }
+
+    /// Repara el índice comparándolo con las notas que hay en disco
+    /// (`existing_paths`, rutas absolutas)
+    pub fn check_consistency(&self, existing_paths: &[String]) -> Result<ConsistencyReport> {
+        let report = self.db.transaction(|db| {
+            // Primero las notas: sus embeddings y entradas quedan huérfanos
+            let orphan_notes = db.cleanup_orphaned_notes(existing_paths)?;
+            Ok(ConsistencyReport {
+                orphan_notes,
+                orphan_embeddings: db.cleanup_orphaned_embeddings()?,
+                orphan_search_entries: db.cleanup_orphaned_fts()?,
+                orphan_properties: db.cleanup_orphaned_inline_properties()?,
+            })
+        })?;
+        if !report.is_clean() {
+            info!("Índice reparado: {:?}", report);
+        }
+        Ok(report)
+    }
+
+    fn find(&self, name: &str) -> Result<NoteFile> {
+        self.dir
+            .find_note(name)?
+            .with_context(|| format!("Nota no encontrada: {}", name))
+    }
+
+    fn index(&self, note: &NoteFile, content: &str) -> Result<()> {
+        let folder = self.dir.relative_folder(note.path());
+        self.db.index_note(
+            note.name(),
+            &note.path().to_string_lossy(),
+            content,
+            folder.as_deref(),
+        )?;
+        Ok(())
+    }
+
+    fn index_path(
+        &self,
+        db: &NotesDatabase,
+        name: &str,
+        path: &str,
+        folder: Option<&str>,
+    ) -> crate::database::Result<()> {
+        let content = fs::read_to_string(path)?;
+        db.index_note(name, path, &content, folder)?;
+        Ok(())
+    }
+
+    /// Mueve el archivo y actualiza su fila (la que tenía en `old_path`, si
+    /// estaba indexada) en una transacción; si falla, lo devuelve a su sitio
+    fn relocate(
+        &self,
+        old_path: &Path,
+        new_path: &Path,
+        update: impl FnOnce(
+            &NotesDatabase,
+            Option<crate::database::NoteMetadata>,
+        ) -> crate::database::Result<()>,
+    ) -> Result<()> {
+        if new_path.exists() {
+            anyhow::bail!("Ya existe {:?}", new_path);
+        }
+        if let Some(parent) = new_path.parent() {
+            fs::create_dir_all(parent).context("No se pudo crear la carpeta de destino")?;
+        }
+        move_file(old_path, new_path).context("No se pudo mover el archivo")?;
+
+        let updated = self.db.transaction(|db| {
+            let old = db.get_note_by_path(&old_path.to_string_lossy())?;
+            update(db, old)
+        });
+        self.undo_on_error(updated.map_err(Into::into), || {
+            move_file(new_path, old_path)
+        })
+    }
+
+    /// Si `result` es un error, deshace el cambio en disco con `undo` y
+    /// devuelve el error original
+    fn undo_on_error<T>(&self, result: Result<T>, undo: impl FnOnce() -> Result<()>) -> Result<T> {
+        match result {
+            Ok(value) => Ok(value),
+            Err(e) => match undo() {
+                Ok(()) => {
+                    warn!("Cambio en disco deshecho al fallar el índice: {}", e);
+                    Err(e)
+                }
+                Err(undo_error) => {
+                    error!("No se pudo deshacer el cambio en disco: {}", undo_error);
+                    Err(e.context(format!("y no se pudo deshacer: {}", undo_error)))
+                }
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::temp_vault;
+
+    /// Hace fallar las escrituras en `notes` con un trigger
+    fn break_notes(db: &NotesDatabase, event: &str) {
+        let conn = rusqlite::Connection::open(db.path()).unwrap();
+        conn.execute_batch(&format!(
+            "CREATE TRIGGER romper_{0} BEFORE {0} ON notes BEGIN SELECT RAISE(ABORT, 'roto'); END;",
+            event
+        ))
+        .unwrap();
+    }
+
+    #[test]
+    fn test_operations_keep_file_and_index_together() {
+        let (dir, db, base) = temp_vault("ok");
+        let store = NoteStore::new(&dir, &db);
+
+        let note = store.create(None, "idea", "#tag Hola").unwrap();
+        assert!(store.create(None, "idea", "otra").is_err());
+        db.insert_embedding(&note.path().to_string_lossy(), 0, "Hola", &[0.1], 1)
+            .unwrap();
+
+        let (_, renamed) = store.rename("idea", "plan").unwrap();
+        assert!(renamed.exists() && !note.path().exists());
+        assert_eq!(
+            db.get_note("plan").unwrap().unwrap().path,
+            renamed.to_string_lossy()
+        );
+
+        let (_, moved) = store
+            .move_to_folder("plan", Some("Proyectos"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(moved, dir.root().join("Proyectos/plan.md"));
+        let meta = db.get_note("plan").unwrap().unwrap();
+        assert_eq!(meta.folder.as_deref(), Some("Proyectos"));
+        assert_eq!(
+            db.get_embeddings_by_note(&moved.to_string_lossy())
+                .unwrap()
+                .len(),
+            1
+        );
+        assert!(
+            store
+                .move_to_folder("plan", Some("Proyectos"))
+                .unwrap()
+                .is_none()
+        );
+
+        let (_, folder) = store.move_folder("Proyectos", "Archivo/2024").unwrap();
+        assert!(folder.join("plan.md").exists());
+        let meta = db.get_note("plan").unwrap().unwrap();
+        assert_eq!(meta.folder.as_deref(), Some("Archivo/2024"));
+        assert_eq!(meta.path, folder.join("plan.md").to_string_lossy());
+        let moved = folder.join("plan.md");
+
+        let (trashed, original) = store.trash("plan").unwrap();
+        assert!(trashed.exists() && !original.exists());
+        assert!(db.get_note("plan").unwrap().is_none());
+        assert!(
+            db.get_embeddings_by_note(&moved.to_string_lossy())
+                .unwrap()
+                .is_empty()
+        );
+
+        let _ = fs::remove_dir_all(&base);
+    }
+
+    #[test]
+    fn test_failed_index_update_restores_file() {
+        let (dir, db, base) = temp_vault("rollback");
+        let store = NoteStore::new(&dir, &db);
+        let note = store.create(None, "idea", "Hola").unwrap();
+        let inside = store.create(Some("Carpeta"), "dentro", "Hola").unwrap();
+
+        break_notes(&db, "UPDATE");
+        assert!(store.rename("idea", "plan").is_err());
+        assert!(note.path().exists());
+        assert!(!dir.root().join("plan.md").exists());
+        assert!(store.move_to_folder("idea", Some("Otra")).is_err());
+        assert!(note.path().exists());
+        assert!(store.move_folder("Carpeta", "Nueva").is_err());
+        assert!(inside.path().exists());
+        assert!(!dir.root().join("Nueva").exists());
+
+        break_notes(&db, "DELETE");
+        assert!(store.trash("idea").is_err());
+        assert!(note.path().exists());
+        assert!(db.get_note("idea").unwrap().is_some());
+
+        break_notes(&db, "INSERT");
+        assert!(store.create(None, "nueva", "x").is_err());
+        assert!(!dir.root().join("nueva.md").exists());
+
+        let _ = fs::remove_dir_all(&base);
+    }
+
+    #[test]
+    fn test_check_consistency() {
+        let (dir, db, base) = temp_vault("check");
+        let store = NoteStore::new(&dir, &db);
+        let kept = store.create(None, "queda", "Hola").unwrap();
+        let lost = store.create(None, "perdida", "Adiós").unwrap();
+        db.insert_embedding(&lost.path().to_string_lossy(), 0, "Adiós", &[0.1], 1)
+            .unwrap();
+        db.insert_embedding("/no/existe.md", 0, "x", &[0.1], 1)
+            .unwrap();
+
+        // El archivo desapareció sin pasar por la app
+        fs::remove_file(lost.path()).unwrap();
+        let existing = vec![kept.path().to_string_lossy().to_string()];
+        let report = store.check_consistency(&existing).unwrap();
+        assert_eq!(report.orphan_notes, 1);
+        assert_eq!(report.orphan_embeddings, 1);
+        assert!(store.check_consistency(&existing).unwrap().is_clean());
+        assert!(db.get_note("queda").unwrap().is_some());
+
+        let _ = fs::remove_dir_all(&base);
+    }
+}
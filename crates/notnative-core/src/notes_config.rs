@@ -0,0 +1,1083 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use tracing::{error, info};
+
+use super::backup::BackupConfig;
+use super::config_profile::{ConfigProfile, ProfileTheme, profile_secret_name};
+use super::date_format::HourFormat;
+use super::embedding_config::EmbeddingConfig;
+use super::hooks::EventHook;
+use super::html_renderer::PreviewDensity;
+use super::item_name::renamed_item;
+use super::navigation::{self, RecentNote};
+use super::ocr::OcrEngine;
+use super::secrets::{AI_API_KEY, EMBEDDING_API_KEY, SecretStore};
+use super::tag_tree;
+use super::watch_events::default_watch_ignore;
+use super::window_state::{self, WindowGeometry};
+
+/// Configuración del asistente AI
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AIConfig {
+    /// API Key para el proveedor de AI (se guarda en el llavero, no en el archivo)
+    #[serde(default, skip_serializing)]
+    pub api_key: Option<String>,
+    /// Proveedor de AI (openai, anthropic, ollama)
+    #[serde(default = "default_ai_provider")]
+    pub provider: String,
+    /// Modelo a utilizar (gpt-4, gpt-3.5-turbo, claude-3, etc.)
+    #[serde(default = "default_ai_model")]
+    pub model: String,
+    /// Temperatura para la generación (0.0 - 2.0)
+    #[serde(default = "default_temperature")]
+    pub temperature: f32,
+    /// Máximo de tokens en la respuesta
+    #[serde(default = "default_max_tokens")]
+    pub max_tokens: u32,
+    /// Guardar historial de chat en la base de datos
+    #[serde(default = "default_save_history")]
+    pub save_history: bool,
+    /// URL base personalizada para APIs (útil para Ollama local)
+    #[serde(default)]
+    pub custom_api_url: Option<String>,
+    /// Peticiones por minuto por proveedor (`openrouter`, `openai`, `ollama`,
+    /// `embeddings`...). Un proveedor sin entrada no tiene límite.
+    #[serde(default)]
+    pub rate_limits: HashMap<String, u32>,
+    /// Reintentos ante límites de peticiones (429) y errores del servidor
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+}
+
+fn default_ai_provider() -> String {
+    "openrouter".to_string()
+}
+
+fn default_ai_model() -> String {
+    "google/gemini-flash-1.5".to_string()
+}
+
+fn default_temperature() -> f32 {
+    0.2
+}
+
+fn default_max_tokens() -> u32 {
+    2000
+}
+
+fn default_save_history() -> bool {
+    true
+}
+
+fn default_max_retries() -> u32 {
+    3
+}
+
+impl Default for AIConfig {
+    fn default() -> Self {
+        Self {
+            api_key: None,
+            provider: default_ai_provider(),
+            model: default_ai_model(),
+            temperature: default_temperature(),
+            max_tokens: default_max_tokens(),
+            save_history: default_save_history(),
+            custom_api_url: None,
+            rate_limits: HashMap::new(),
+            max_retries: default_max_retries(),
+        }
+    }
+}
+
+/// Qué hacer al pegar un enlace suelto en modo Insert
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum UrlPasteBehavior {
+    /// Buscar el título de la página y preguntar si usarlo
+    #[default]
+    Ask,
+    /// Convertir siempre en `[Título](url)` cuando se encuentra el título
+    Title,
+    /// Dejar el enlace tal cual, sin conectarse
+    Bare,
+}
+
+/// Configuración del orden y organización de notas
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotesConfig {
+    /// Orden personalizado de las notas (nota -> posición)
+    pub order: HashMap<String, usize>,
+    /// Carpetas que están expandidas
+    pub expanded_folders: Vec<String>,
+    /// Preferencia de idioma (código ISO 639-1: "es", "en", etc.)
+    #[serde(default)]
+    pub language: Option<String>,
+    /// Directorio de trabajo personalizado (notas y assets)
+    #[serde(default)]
+    pub workspace_dir: Option<String>,
+    /// Salida de audio preferida (sink de PulseAudio)
+    #[serde(default)]
+    pub audio_output_sink: Option<String>,
+    /// Última nota abierta
+    #[serde(default)]
+    pub last_opened_note: Option<String>,
+    /// Notas abiertas recientemente, de la más reciente a la más antigua
+    #[serde(default)]
+    pub recent_notes: Vec<RecentNote>,
+    /// Notas fijadas arriba de su carpeta en el sidebar
+    #[serde(default)]
+    pub pinned_notes: Vec<String>,
+    /// Colores elegidos para los tags (`tag` → `#rrggbb`); el resto usa la paleta
+    #[serde(default)]
+    pub tag_colors: HashMap<String, String>,
+    /// Iniciar aplicación en segundo plano (minimizado a la bandeja)
+    #[serde(default)]
+    pub start_in_background: bool,
+    /// Configuración del asistente AI
+    #[serde(default)]
+    pub ai_config: AIConfig,
+    /// Configuración de embeddings para búsqueda semántica
+    #[serde(default)]
+    pub embedding_config: EmbeddingConfig,
+    /// Flag para saber si ya se completó el onboarding (crear nota de keybindings)
+    #[serde(default)]
+    pub onboarding_completed: bool,
+    /// Última versión que el usuario ha visto (para mostrar novedades en actualizaciones)
+    #[serde(default)]
+    pub last_seen_version: Option<String>,
+    /// Mostrar barra de herramientas de formato en modo INSERT
+    #[serde(default = "default_show_format_toolbar")]
+    pub show_format_toolbar: bool,
+    /// Invertir imágenes claras (diagramas, capturas) en el preview con tema oscuro
+    #[serde(default)]
+    pub preview_adjust_images: bool,
+    /// Formato de hora preferido (automático según región, 24h o 12h)
+    #[serde(default)]
+    pub hour_format: HourFormat,
+    /// Nivel de log (error, warn, info, debug, trace)
+    #[serde(default = "default_log_level")]
+    pub log_level: String,
+    /// Videos embebidos que el preview mantiene cargados a la vez
+    #[serde(default = "default_embed_budget")]
+    pub embed_budget: usize,
+    /// Comandos de shell que se ejecutan en eventos (guardar, crear, borrar...)
+    #[serde(default)]
+    pub hooks: Vec<EventHook>,
+    /// Escala del texto del preview (se ajusta con el gesto de pellizco)
+    #[serde(default = "default_preview_font_scale")]
+    pub preview_font_scale: f64,
+    /// Botones y filas más grandes para pantallas táctiles
+    #[serde(default)]
+    pub large_tap_targets: bool,
+    /// Convertir a markdown el HTML pegado (desde un navegador o un documento)
+    #[serde(default = "default_paste_html_as_markdown")]
+    pub paste_html_as_markdown: bool,
+    /// Comportamiento al pegar un enlace suelto
+    #[serde(default)]
+    pub url_paste_behavior: UrlPasteBehavior,
+    /// Al copiar como texto plano, dejar la URL de los enlaces tras su texto
+    #[serde(default = "default_plain_text_link_urls")]
+    pub plain_text_link_urls: bool,
+    /// Copia del borrador (`:scratch`) para recuperarlo si la app se cierra de golpe
+    #[serde(default = "default_scratch_backup")]
+    pub scratch_backup: bool,
+    /// Abrir el editor de anotaciones antes de insertar una captura de pantalla
+    #[serde(default = "default_screenshot_annotate")]
+    pub screenshot_annotate: bool,
+    /// Ofrecer sacar el texto de las imágenes al insertarlas
+    #[serde(default = "default_ocr_offer")]
+    pub ocr_offer: bool,
+    /// Con qué se saca el texto de las imágenes
+    #[serde(default)]
+    pub ocr_engine: OcrEngine,
+    /// Idiomas para tesseract (`-l`, p. ej. `spa+eng`); sin ellos, los suyos por defecto
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ocr_languages: Option<String>,
+    /// Sugerir tags existentes al guardar una nota
+    #[serde(default = "default_suggest_tags")]
+    pub suggest_tags: bool,
+    /// Editor y preview uno al lado del otro
+    #[serde(default)]
+    pub split_preview: bool,
+    /// Espaciado del preview
+    #[serde(default)]
+    pub preview_density: PreviewDensity,
+    /// Enlace # visible junto a los encabezados del preview
+    #[serde(default)]
+    pub preview_heading_anchors: bool,
+    /// Preview con el estilo para imprimir (sin embeds ni controles)
+    #[serde(default)]
+    pub preview_print_friendly: bool,
+    /// Copias de seguridad programadas
+    #[serde(default)]
+    pub backup: BackupConfig,
+    /// Perfiles de configuración guardados
+    #[serde(default)]
+    pub profiles: Vec<ConfigProfile>,
+    /// Nombre del perfil en uso; `None` si no se ha creado ninguno
+    #[serde(default)]
+    pub active_profile: Option<String>,
+    /// Rutas que el vigilante de archivos y el escaneo no indexan (`.git`,
+    /// `*.tmp`, `Archivo/2020`...)
+    #[serde(default = "default_watch_ignore")]
+    pub watch_ignore: Vec<String>,
+    /// Tamaño, estado y monitor de la ventana principal en la última sesión
+    #[serde(default)]
+    pub main_window: Option<WindowGeometry>,
+    /// Tamaño de la ventana de Quick Note en la última sesión
+    #[serde(default)]
+    pub quick_note_window: Option<WindowGeometry>,
+    /// Ancho del sidebar de notas
+    #[serde(default = "default_sidebar_width")]
+    pub sidebar_width: i32,
+}
+
+/// Límites de la escala del texto del preview
+pub const MIN_PREVIEW_FONT_SCALE: f64 = 0.5;
+pub const MAX_PREVIEW_FONT_SCALE: f64 = 3.0;
+
+fn default_preview_font_scale() -> f64 {
+    1.0
+}
+
+fn default_plain_text_link_urls() -> bool {
+    true
+}
+
+fn default_scratch_backup() -> bool {
+    true
+}
+
+fn default_screenshot_annotate() -> bool {
+    true
+}
+
+fn default_ocr_offer() -> bool {
+    true
+}
+
+fn default_sidebar_width() -> i32 {
+    window_state::DEFAULT_SIDEBAR_WIDTH
+}
+
+fn default_paste_html_as_markdown() -> bool {
+    true
+}
+
+fn default_suggest_tags() -> bool {
+    true
+}
+
+fn default_show_format_toolbar() -> bool {
+    true
+}
+
+fn default_log_level() -> String {
+    "info".to_string()
+}
+
+fn default_embed_budget() -> usize {
+    super::html_renderer::DEFAULT_EMBED_BUDGET
+}
+
+impl Default for NotesConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NotesConfig {
+    /// Crea una nueva configuración vacía
+    pub fn new() -> Self {
+        Self {
+            order: HashMap::new(),
+            expanded_folders: Vec::new(),
+            language: None,
+            workspace_dir: None,
+            audio_output_sink: None,
+            last_opened_note: None,
+            recent_notes: Vec::new(),
+            pinned_notes: Vec::new(),
+            tag_colors: HashMap::new(),
+            start_in_background: false,
+            ai_config: AIConfig::default(),
+            embedding_config: EmbeddingConfig::default(),
+            onboarding_completed: false,
+            last_seen_version: None,
+            show_format_toolbar: default_show_format_toolbar(),
+            preview_adjust_images: false,
+            hour_format: HourFormat::default(),
+            log_level: default_log_level(),
+            embed_budget: default_embed_budget(),
+            hooks: Vec::new(),
+            preview_font_scale: default_preview_font_scale(),
+            large_tap_targets: false,
+            paste_html_as_markdown: default_paste_html_as_markdown(),
+            suggest_tags: default_suggest_tags(),
+            url_paste_behavior: UrlPasteBehavior::default(),
+            plain_text_link_urls: default_plain_text_link_urls(),
+            scratch_backup: default_scratch_backup(),
+            screenshot_annotate: default_screenshot_annotate(),
+            ocr_offer: default_ocr_offer(),
+            ocr_engine: OcrEngine::default(),
+            ocr_languages: None,
+            split_preview: false,
+            preview_density: PreviewDensity::default(),
+            preview_heading_anchors: false,
+            preview_print_friendly: false,
+            backup: BackupConfig::default(),
+            profiles: Vec::new(),
+            active_profile: None,
+            watch_ignore: default_watch_ignore(),
+            main_window: None,
+            quick_note_window: None,
+            sidebar_width: default_sidebar_width(),
+        }
+    }
+
+    /// Carga la configuración desde un archivo (credenciales desde el llavero)
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::load_with_secrets(path, SecretStore::global())
+    }
+
+    /// Carga la configuración usando el almacén de credenciales indicado.
+    /// Las claves de API que sigan en texto plano se migran al almacén y se
+    /// eliminan del archivo.
+    pub fn load_with_secrets<P: AsRef<Path>>(path: P, secrets: &SecretStore) -> Result<Self> {
+        let content = std::fs::read_to_string(path.as_ref())?;
+        let mut config: NotesConfig = serde_json::from_str(&content)?;
+
+        let has_plaintext_keys = config.ai_config.api_key.is_some()
+            || config.embedding_config.api_key.is_some()
+            || config.profiles.iter().any(|profile| {
+                profile.ai_config.api_key.is_some() || profile.embedding_config.api_key.is_some()
+            });
+        if has_plaintext_keys {
+            match config.save_with_secrets(path.as_ref(), secrets) {
+                Ok(()) => info!("Claves de API migradas al almacén seguro"),
+                Err(e) => error!("Error migrando claves de API: {}", e),
+            }
+        }
+
+        // Si la migración falló se conserva el valor en memoria
+        config.ai_config.api_key = secrets.get(AI_API_KEY).or(config.ai_config.api_key);
+        config.embedding_config.api_key = secrets
+            .get(EMBEDDING_API_KEY)
+            .or(config.embedding_config.api_key);
+        for profile in &mut config.profiles {
+            profile.ai_config.api_key = secrets
+                .get(&profile_secret_name(AI_API_KEY, &profile.name))
+                .or(profile.ai_config.api_key.take());
+            profile.embedding_config.api_key = secrets
+                .get(&profile_secret_name(EMBEDDING_API_KEY, &profile.name))
+                .or(profile.embedding_config.api_key.take());
+        }
+        Ok(config)
+    }
+
+    /// Guarda la configuración a un archivo (credenciales en el llavero)
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        self.save_with_secrets(path, SecretStore::global())
+    }
+
+    /// Guarda la configuración; las claves de API nunca se escriben en el archivo.
+    /// Primero van al almacén: si alguna falla, el archivo no se toca y no se
+    /// pierde la copia que pudiera tener (la migración depende de ello).
+    pub fn save_with_secrets<P: AsRef<Path>>(&self, path: P, secrets: &SecretStore) -> Result<()> {
+        secrets.set(AI_API_KEY, self.ai_config.api_key.as_deref())?;
+        secrets.set(EMBEDDING_API_KEY, self.embedding_config.api_key.as_deref())?;
+        for profile in &self.profiles {
+            secrets.set(
+                &profile_secret_name(AI_API_KEY, &profile.name),
+                profile.ai_config.api_key.as_deref(),
+            )?;
+            secrets.set(
+                &profile_secret_name(EMBEDDING_API_KEY, &profile.name),
+                profile.embedding_config.api_key.as_deref(),
+            )?;
+        }
+
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, content)?;
+        Ok(())
+    }
+
+    /// Obtiene la posición de una nota en el orden personalizado
+    pub fn get_position(&self, note_name: &str) -> Option<usize> {
+        self.order.get(note_name).copied()
+    }
+
+    /// Establece la posición de una nota
+    pub fn set_position(&mut self, note_name: String, position: usize) {
+        self.order.insert(note_name, position);
+    }
+
+    /// Remueve una nota del orden
+    pub fn remove_note(&mut self, note_name: &str) {
+        self.order.remove(note_name);
+    }
+
+    /// Mueve una nota a una nueva posición, reordenando las demás
+    pub fn move_note(&mut self, note_name: &str, new_position: usize) {
+        // Obtener posición actual
+        let old_position = self.get_position(note_name);
+
+        // Actualizar posiciones de todas las notas afectadas
+        if let Some(old_pos) = old_position {
+            if old_pos < new_position {
+                // Moviendo hacia abajo: decrementar posiciones entre old y new
+                for (_name, pos) in self.order.iter_mut() {
+                    if *pos > old_pos && *pos <= new_position {
+                        *pos -= 1;
+                    }
+                }
+            } else if old_pos > new_position {
+                // Moviendo hacia arriba: incrementar posiciones entre new y old
+                for (_name, pos) in self.order.iter_mut() {
+                    if *pos >= new_position && *pos < old_pos {
+                        *pos += 1;
+                    }
+                }
+            }
+        } else {
+            // Nueva nota: incrementar todas las posiciones >= new_position
+            for (_name, pos) in self.order.iter_mut() {
+                if *pos >= new_position {
+                    *pos += 1;
+                }
+            }
+        }
+
+        // Establecer nueva posición
+        self.order.insert(note_name.to_string(), new_position);
+    }
+
+    /// Verifica si una carpeta está expandida
+    pub fn is_folder_expanded(&self, folder: &str) -> bool {
+        self.expanded_folders.contains(&folder.to_string())
+    }
+
+    /// Alterna el estado de expansión de una carpeta
+    pub fn toggle_folder(&mut self, folder: String) {
+        if let Some(pos) = self.expanded_folders.iter().position(|f| f == &folder) {
+            self.expanded_folders.remove(pos);
+        } else {
+            self.expanded_folders.push(folder);
+        }
+    }
+
+    /// Obtiene la preferencia de idioma
+    pub fn get_language(&self) -> Option<&str> {
+        self.language.as_deref()
+    }
+
+    /// Establece la preferencia de idioma
+    pub fn set_language(&mut self, lang: Option<String>) {
+        self.language = lang;
+    }
+
+    /// Obtiene el directorio de trabajo personalizado
+    pub fn get_workspace_dir(&self) -> Option<&str> {
+        self.workspace_dir.as_deref()
+    }
+
+    /// Establece el directorio de trabajo personalizado
+    pub fn set_workspace_dir(&mut self, dir: Option<String>) {
+        self.workspace_dir = dir;
+    }
+
+    /// Nombres de los perfiles, ordenados
+    pub fn profile_names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.profiles.iter().map(|p| p.name.as_str()).collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// Perfil con ese nombre
+    pub fn profile(&self, name: &str) -> Option<&ConfigProfile> {
+        self.profiles.iter().find(|p| p.name == name)
+    }
+
+    /// Los ajustes en uso como perfil
+    fn current_profile(&self, name: &str, theme: ProfileTheme) -> ConfigProfile {
+        ConfigProfile {
+            name: name.to_string(),
+            workspace_dir: self.workspace_dir.clone(),
+            ai_config: self.ai_config.clone(),
+            embedding_config: self.embedding_config.clone(),
+            theme,
+            backup: self.backup.clone(),
+        }
+    }
+
+    /// Guarda los ajustes en uso como el perfil `name` (sustituyéndolo si ya
+    /// existe) y lo deja activo
+    pub fn save_profile(&mut self, name: &str, theme: ProfileTheme) {
+        let profile = self.current_profile(name, theme);
+        match self.profiles.iter_mut().find(|p| p.name == name) {
+            Some(existing) => *existing = profile,
+            None => self.profiles.push(profile),
+        }
+        self.active_profile = Some(name.to_string());
+    }
+
+    /// Cambia al perfil `name`: los ajustes en uso (con el tema `theme`) se
+    /// guardan en el perfil activo y se aplican los del nuevo. Devuelve el
+    /// perfil aplicado, o `None` si no existe.
+    pub fn switch_profile(&mut self, name: &str, theme: ProfileTheme) -> Option<ConfigProfile> {
+        let target = self.profile(name)?.clone();
+        if let Some(active) = self.active_profile.clone() {
+            if self.profile(&active).is_some() {
+                self.save_profile(&active, theme);
+            }
+        }
+
+        self.workspace_dir = target.workspace_dir.clone();
+        self.ai_config = target.ai_config.clone();
+        self.embedding_config = target.embedding_config.clone();
+        self.backup = target.backup.clone();
+        self.active_profile = Some(name.to_string());
+        Some(target)
+    }
+
+    /// Borra un perfil. Los ajustes en uso se quedan como están; si era el
+    /// activo, ya no hay perfil activo.
+    pub fn remove_profile(&mut self, name: &str) -> bool {
+        let before = self.profiles.len();
+        self.profiles.retain(|p| p.name != name);
+        if self.active_profile.as_deref() == Some(name) {
+            self.active_profile = None;
+        }
+        self.profiles.len() != before
+    }
+
+    /// Obtiene la salida de audio preferida
+    pub fn get_audio_output_sink(&self) -> Option<&str> {
+        self.audio_output_sink.as_deref()
+    }
+
+    /// Establece la salida de audio preferida
+    pub fn set_audio_output_sink(&mut self, sink: Option<String>) {
+        self.audio_output_sink = sink;
+    }
+
+    /// Obtiene la última nota abierta
+    pub fn get_last_opened_note(&self) -> Option<&str> {
+        self.last_opened_note.as_deref()
+    }
+
+    /// Establece la última nota abierta
+    pub fn set_last_opened_note(&mut self, note: Option<String>) {
+        self.last_opened_note = note;
+    }
+
+    /// Notas abiertas recientemente (la primera es la más reciente)
+    pub fn recent_notes(&self) -> &[RecentNote] {
+        &self.recent_notes
+    }
+
+    /// Apunta una nota como abierta ahora
+    pub fn touch_recent_note(&mut self, name: &str) {
+        navigation::touch_recent(&mut self.recent_notes, name, chrono::Utc::now().timestamp());
+    }
+
+    /// Si la nota está fijada en el sidebar
+    pub fn is_pinned(&self, name: &str) -> bool {
+        self.pinned_notes.iter().any(|pinned| pinned == name)
+    }
+
+    /// Fija o suelta una nota en el sidebar
+    pub fn set_pinned(&mut self, name: &str, pinned: bool) {
+        self.pinned_notes.retain(|note| note != name);
+        if pinned {
+            self.pinned_notes.push(name.to_string());
+        }
+    }
+
+    /// Orden, recientes, fijadas y última nota abierta siguen a una nota o
+    /// carpeta renombrada
+    pub fn rename_note_refs(&mut self, old: &str, new: &str) {
+        let order: Vec<(String, String)> = self
+            .order
+            .keys()
+            .filter_map(|name| renamed_item(name, old, new).map(|renamed| (name.clone(), renamed)))
+            .collect();
+        for (name, renamed) in order {
+            if let Some(position) = self.order.remove(&name) {
+                self.order.insert(renamed, position);
+            }
+        }
+        for recent in &mut self.recent_notes {
+            if let Some(renamed) = renamed_item(&recent.name, old, new) {
+                recent.name = renamed;
+            }
+        }
+        for pinned in &mut self.pinned_notes {
+            if let Some(renamed) = renamed_item(pinned, old, new) {
+                *pinned = renamed;
+            }
+        }
+        if let Some(last) = &mut self.last_opened_note {
+            if let Some(renamed) = renamed_item(last, old, new) {
+                *last = renamed;
+            }
+        }
+    }
+
+    /// Elige el color de un tag (`None` vuelve al de la paleta)
+    pub fn set_tag_color(&mut self, tag: &str, color: Option<String>) {
+        match color {
+            Some(color) => {
+                self.tag_colors.insert(tag.to_string(), color);
+            }
+            None => {
+                self.tag_colors.remove(tag);
+            }
+        }
+    }
+
+    /// Los colores de `from` y de sus hijos pasan a `to` al renombrar el tag
+    pub fn rename_tag_colors(&mut self, from: &str, to: &str) {
+        let renamed: Vec<(String, String)> = self
+            .tag_colors
+            .keys()
+            .filter_map(|tag| tag_tree::renamed_tag(tag, from, to).map(|new| (tag.clone(), new)))
+            .collect();
+        for (old, new) in renamed {
+            if let Some(color) = self.tag_colors.remove(&old) {
+                // Al fusionar manda el color que ya tenía el destino
+                self.tag_colors.entry(new).or_insert(color);
+            }
+        }
+    }
+
+    /// Obtiene si debe iniciar en segundo plano
+    pub fn get_start_in_background(&self) -> bool {
+        self.start_in_background
+    }
+
+    /// Establece si debe iniciar en segundo plano
+    pub fn set_start_in_background(&mut self, start_in_background: bool) {
+        self.start_in_background = start_in_background;
+    }
+
+    /// Ruta por defecto del archivo de configuración
+    pub fn default_path() -> PathBuf {
+        dirs::data_local_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("notnative")
+            .join("config.json")
+    }
+
+    /// CSS propio para el preview, junto a la configuración. Sirve para dar
+    /// estilo a las clases de `{.clase}` y `::: clase`.
+    pub fn preview_css_path() -> PathBuf {
+        dirs::data_local_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("notnative")
+            .join("preview.css")
+    }
+
+    /// Obtiene la carpeta de assets para las notas
+    pub fn assets_dir() -> PathBuf {
+        dirs::data_local_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("notnative")
+            .join("assets")
+    }
+
+    /// Asegura que el directorio de assets exista
+    pub fn ensure_assets_dir() -> Result<PathBuf> {
+        let assets_dir = Self::assets_dir();
+        std::fs::create_dir_all(&assets_dir)?;
+        Ok(assets_dir)
+    }
+
+    /// Obtiene la configuración de AI
+    pub fn get_ai_config(&self) -> &AIConfig {
+        &self.ai_config
+    }
+
+    /// Obtiene la configuración de AI mutable
+    pub fn get_ai_config_mut(&mut self) -> &mut AIConfig {
+        &mut self.ai_config
+    }
+
+    /// Establece la API key del asistente AI
+    pub fn set_ai_api_key(&mut self, api_key: Option<String>) {
+        self.ai_config.api_key = api_key;
+    }
+
+    /// Establece el proveedor de AI
+    pub fn set_ai_provider(&mut self, provider: String) {
+        self.ai_config.provider = provider;
+    }
+
+    /// Establece el modelo de AI
+    pub fn set_ai_model(&mut self, model: String) {
+        self.ai_config.model = model;
+    }
+
+    /// Establece la temperatura de AI
+    pub fn set_ai_temperature(&mut self, temperature: f32) {
+        self.ai_config.temperature = temperature.clamp(0.0, 2.0);
+    }
+
+    /// Establece el máximo de tokens
+    pub fn set_ai_max_tokens(&mut self, max_tokens: u32) {
+        self.ai_config.max_tokens = max_tokens;
+    }
+
+    /// Establece si se debe guardar el historial
+    pub fn set_ai_save_history(&mut self, save_history: bool) {
+        self.ai_config.save_history = save_history;
+    }
+
+    /// Obtiene la configuración de embeddings
+    pub fn get_embedding_config(&self) -> &EmbeddingConfig {
+        &self.embedding_config
+    }
+
+    /// Obtiene la configuración de embeddings mutable
+    pub fn get_embedding_config_mut(&mut self) -> &mut EmbeddingConfig {
+        &mut self.embedding_config
+    }
+
+    /// Habilita o deshabilita el sistema de embeddings
+    pub fn set_embeddings_enabled(&mut self, enabled: bool) {
+        self.embedding_config.enabled = enabled;
+    }
+
+    /// Establece la API key para embeddings
+    pub fn set_embedding_api_key(&mut self, api_key: Option<String>) {
+        self.embedding_config.api_key = api_key;
+    }
+
+    /// Establece el proveedor de embeddings
+    pub fn set_embedding_provider(&mut self, provider: String) {
+        self.embedding_config.provider = provider;
+    }
+
+    /// Establece el modelo de embeddings
+    pub fn set_embedding_model(&mut self, model: String) {
+        self.embedding_config.model = model;
+    }
+
+    /// Obtiene si los embeddings están habilitados
+    pub fn get_embeddings_enabled(&self) -> bool {
+        self.embedding_config.enabled
+    }
+
+    /// Obtiene la API key de embeddings
+    pub fn get_embeddings_api_key(&self) -> Option<String> {
+        self.embedding_config.api_key.clone()
+    }
+
+    /// Obtiene el modelo de embeddings
+    pub fn get_embeddings_model(&self) -> String {
+        self.embedding_config.model.clone()
+    }
+
+    /// Establece la API key de embeddings (alias para compatibilidad)
+    pub fn set_embeddings_api_key(&mut self, api_key: Option<String>) {
+        self.embedding_config.api_key = api_key;
+    }
+
+    /// Establece el modelo de embeddings (alias para compatibilidad)
+    pub fn set_embeddings_model(&mut self, model: String) {
+        self.embedding_config.model = model.clone();
+        // Actualizar dimensión según el modelo
+        self.embedding_config.dimension = match model.as_str() {
+            "qwen/qwen3-embedding-8b" => 4096,
+            "text-embedding-3-small" => 1536,
+            "text-embedding-3-large" => 3072,
+            _ => 4096, // Por defecto qwen3
+        };
+    }
+
+    /// Verifica si el onboarding (crear nota de keybindings) ya se completó
+    pub fn is_onboarding_completed(&self) -> bool {
+        self.onboarding_completed
+    }
+
+    /// Marca el onboarding como completado
+    pub fn set_onboarding_completed(&mut self, completed: bool) {
+        self.onboarding_completed = completed;
+    }
+
+    /// Obtiene la última versión vista por el usuario
+    pub fn get_last_seen_version(&self) -> Option<&str> {
+        self.last_seen_version.as_deref()
+    }
+
+    /// Actualiza la última versión vista
+    pub fn set_last_seen_version(&mut self, version: &str) {
+        self.last_seen_version = Some(version.to_string());
+    }
+
+    /// Verifica si hay una nueva versión disponible comparando con la versión actual
+    pub fn is_new_version(&self, current_version: &str) -> bool {
+        match &self.last_seen_version {
+            None => true, // Primera vez o migración desde versión anterior
+            Some(last) => last != current_version,
+        }
+    }
+
+    /// Obtiene si la barra de herramientas de formato debe mostrarse
+    pub fn show_format_toolbar(&self) -> bool {
+        self.show_format_toolbar
+    }
+
+    /// Establece si la barra de herramientas de formato debe mostrarse
+    pub fn set_show_format_toolbar(&mut self, show: bool) {
+        self.show_format_toolbar = show;
+    }
+
+    /// Obtiene si las imágenes claras se ajustan en el preview oscuro
+    pub fn preview_adjust_images(&self) -> bool {
+        self.preview_adjust_images
+    }
+
+    /// Establece si las imágenes claras se ajustan en el preview oscuro
+    pub fn set_preview_adjust_images(&mut self, enabled: bool) {
+        self.preview_adjust_images = enabled;
+    }
+
+    /// Obtiene el formato de hora preferido
+    pub fn hour_format(&self) -> HourFormat {
+        self.hour_format
+    }
+
+    /// Establece el formato de hora preferido
+    pub fn set_hour_format(&mut self, hour_format: HourFormat) {
+        self.hour_format = hour_format;
+    }
+
+    /// Obtiene el nivel de log
+    pub fn log_level(&self) -> &str {
+        &self.log_level
+    }
+
+    /// Establece el nivel de log
+    pub fn set_log_level(&mut self, level: String) {
+        self.log_level = level;
+    }
+
+    /// Obtiene el presupuesto de contenido embebido (videos cargados a la vez)
+    pub fn embed_budget(&self) -> usize {
+        self.embed_budget
+    }
+
+    /// Establece el presupuesto de contenido embebido
+    pub fn set_embed_budget(&mut self, budget: usize) {
+        self.embed_budget = budget;
+    }
+
+    /// Hooks de eventos configurados
+    pub fn hooks(&self) -> &[EventHook] {
+        &self.hooks
+    }
+
+    /// Reemplaza los hooks de eventos
+    pub fn set_hooks(&mut self, hooks: Vec<EventHook>) {
+        self.hooks = hooks;
+    }
+
+    /// Obtiene la escala del texto del preview
+    pub fn preview_font_scale(&self) -> f64 {
+        self.preview_font_scale
+            .clamp(MIN_PREVIEW_FONT_SCALE, MAX_PREVIEW_FONT_SCALE)
+    }
+
+    /// Establece la escala del texto del preview (limitada a un rango legible)
+    pub fn set_preview_font_scale(&mut self, scale: f64) {
+        self.preview_font_scale = if scale.is_finite() {
+            scale.clamp(MIN_PREVIEW_FONT_SCALE, MAX_PREVIEW_FONT_SCALE)
+        } else {
+            default_preview_font_scale()
+        };
+    }
+
+    /// Obtiene si se usan botones y filas más grandes (modo táctil)
+    pub fn large_tap_targets(&self) -> bool {
+        self.large_tap_targets
+    }
+
+    /// Establece si se usan botones y filas más grandes (modo táctil)
+    pub fn set_large_tap_targets(&mut self, enabled: bool) {
+        self.large_tap_targets = enabled;
+    }
+
+    /// Obtiene si el HTML pegado se convierte a markdown
+    pub fn paste_html_as_markdown(&self) -> bool {
+        self.paste_html_as_markdown
+    }
+
+    /// Establece si el HTML pegado se convierte a markdown
+    pub fn set_paste_html_as_markdown(&mut self, enabled: bool) {
+        self.paste_html_as_markdown = enabled;
+    }
+
+    /// Obtiene si el editor y el preview se muestran a la vez
+    pub fn split_preview(&self) -> bool {
+        self.split_preview
+    }
+
+    /// Establece si el editor y el preview se muestran a la vez
+    pub fn set_split_preview(&mut self, enabled: bool) {
+        self.split_preview = enabled;
+    }
+
+    /// Obtiene el espaciado del preview
+    pub fn preview_density(&self) -> PreviewDensity {
+        self.preview_density
+    }
+
+    /// Establece el espaciado del preview
+    pub fn set_preview_density(&mut self, density: PreviewDensity) {
+        self.preview_density = density;
+    }
+
+    /// Obtiene si los encabezados del preview muestran su enlace #
+    pub fn preview_heading_anchors(&self) -> bool {
+        self.preview_heading_anchors
+    }
+
+    /// Establece si los encabezados del preview muestran su enlace #
+    pub fn set_preview_heading_anchors(&mut self, enabled: bool) {
+        self.preview_heading_anchors = enabled;
+    }
+
+    /// Obtiene si el preview usa el estilo para imprimir
+    pub fn preview_print_friendly(&self) -> bool {
+        self.preview_print_friendly
+    }
+
+    /// Establece si el preview usa el estilo para imprimir
+    pub fn set_preview_print_friendly(&mut self, enabled: bool) {
+        self.preview_print_friendly = enabled;
+    }
+
+    /// Obtiene la configuración de las copias de seguridad
+    pub fn backup_config(&self) -> &BackupConfig {
+        &self.backup
+    }
+
+    /// Establece la configuración de las copias de seguridad
+    pub fn set_backup_config(&mut self, config: BackupConfig) {
+        self.backup = config;
+    }
+
+    /// Obtiene si se sugieren tags al guardar
+    pub fn suggest_tags(&self) -> bool {
+        self.suggest_tags
+    }
+
+    /// Establece si se sugieren tags al guardar
+    pub fn set_suggest_tags(&mut self, enabled: bool) {
+        self.suggest_tags = enabled;
+    }
+
+    /// Obtiene el comportamiento al pegar un enlace
+    pub fn url_paste_behavior(&self) -> UrlPasteBehavior {
+        self.url_paste_behavior
+    }
+
+    /// Establece el comportamiento al pegar un enlace
+    pub fn set_url_paste_behavior(&mut self, behavior: UrlPasteBehavior) {
+        self.url_paste_behavior = behavior;
+    }
+
+    /// Obtiene si "copiar como texto plano" deja las URL de los enlaces
+    pub fn plain_text_link_urls(&self) -> bool {
+        self.plain_text_link_urls
+    }
+
+    /// Establece si "copiar como texto plano" deja las URL de los enlaces
+    pub fn set_plain_text_link_urls(&mut self, enabled: bool) {
+        self.plain_text_link_urls = enabled;
+    }
+
+    /// Obtiene si el borrador guarda una copia de recuperación
+    pub fn scratch_backup(&self) -> bool {
+        self.scratch_backup
+    }
+
+    /// Establece si el borrador guarda una copia de recuperación
+    pub fn set_scratch_backup(&mut self, enabled: bool) {
+        self.scratch_backup = enabled;
+    }
+
+    /// Obtiene si las capturas de pantalla se anotan antes de insertarlas
+    pub fn screenshot_annotate(&self) -> bool {
+        self.screenshot_annotate
+    }
+
+    /// Establece si las capturas de pantalla se anotan antes de insertarlas
+    pub fn set_screenshot_annotate(&mut self, enabled: bool) {
+        self.screenshot_annotate = enabled;
+    }
+
+    /// Obtiene si se ofrece sacar el texto de las imágenes al insertarlas
+    pub fn ocr_offer(&self) -> bool {
+        self.ocr_offer
+    }
+
+    /// Establece si se ofrece sacar el texto de las imágenes al insertarlas
+    pub fn set_ocr_offer(&mut self, enabled: bool) {
+        self.ocr_offer = enabled;
+    }
+
+    /// Obtiene con qué se saca el texto de las imágenes
+    pub fn ocr_engine(&self) -> OcrEngine {
+        self.ocr_engine
+    }
+
+    /// Establece con qué se saca el texto de las imágenes
+    pub fn set_ocr_engine(&mut self, engine: OcrEngine) {
+        self.ocr_engine = engine;
+    }
+
+    /// Idiomas para tesseract, si se configuraron
+    pub fn ocr_languages(&self) -> Option<&str> {
+        self.ocr_languages.as_deref()
+    }
+
+    /// Ventana principal de la última sesión (920×680 la primera vez)
+    pub fn main_window(&self) -> WindowGeometry {
+        self.main_window.clone().unwrap_or_else(|| {
+            let (width, height) = window_state::DEFAULT_MAIN_SIZE;
+            WindowGeometry::new(width, height)
+        })
+    }
+
+    /// Recuerda la ventana principal para la próxima sesión
+    pub fn set_main_window(&mut self, geometry: WindowGeometry) {
+        self.main_window = Some(geometry);
+    }
+
+    /// Ventana de Quick Note de la última sesión
+    pub fn quick_note_window(&self) -> WindowGeometry {
+        self.quick_note_window.clone().unwrap_or_else(|| {
+            let (width, height) = window_state::DEFAULT_QUICK_NOTE_SIZE;
+            WindowGeometry::new(width, height)
+        })
+    }
+
+    /// Recuerda la ventana de Quick Note para la próxima vez
+    pub fn set_quick_note_window(&mut self, geometry: WindowGeometry) {
+        self.quick_note_window = Some(geometry);
+    }
+
+    /// Ancho del sidebar de notas
+    pub fn sidebar_width(&self) -> i32 {
+        window_state::clamp_sidebar_width(self.sidebar_width)
+    }
+
+    /// Recuerda el ancho del sidebar
+    pub fn set_sidebar_width(&mut self, width: i32) {
+        self.sidebar_width = window_state::clamp_sidebar_width(width);
+    }
+}
@@ -0,0 +1,206 @@
+//! Texto de las imágenes (OCR)
+//!
+//! Al insertar una imagen se ofrece sacar su texto con `tesseract` o con un
+//! modelo con visión. El texto va debajo de la imagen en un callout plegado
+//! (`> [!ocr]- Texto de la imagen`), así que forma parte de la nota y la
+//! búsqueda lo encuentra. Volver a extraerlo reemplaza el bloque anterior.
+
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+use std::path::Path;
+
+/// Marca del callout con el texto de una imagen
+pub const OCR_CALLOUT: &str = "[!ocr]-";
+
+/// Lo que se pide al modelo con visión
+pub const OCR_PROMPT: &str = "Transcribe all the text in this image exactly as written, keeping its \
+     line breaks and language. Reply with the text only, without comments or formatting. If the \
+     image has no text, reply with nothing.";
+
+/// Con qué se saca el texto
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OcrEngine {
+    /// `tesseract` local, sin enviar la imagen a ningún sitio
+    #[default]
+    Tesseract,
+    /// El modelo configurado para el chat, si tiene visión
+    Ai,
+}
+
+/// ¿Tiene sentido sacar texto de esta imagen? (los SVG, como los bocetos,
+/// no son mapas de bits)
+pub fn is_ocr_candidate(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| {
+            ["png", "jpg", "jpeg", "webp", "bmp", "gif", "tif", "tiff"]
+                .iter()
+                .any(|candidate| ext.eq_ignore_ascii_case(candidate))
+        })
+}
+
+/// Limpia la salida del OCR: sin saltos de página, espacios al final de
+/// línea ni más de una línea en blanco seguida
+pub fn clean_ocr_text(raw: &str) -> String {
+    let mut lines: Vec<&str> = Vec::new();
+    for line in raw.split(['\n', '\x0c']) {
+        let line = line.trim_end();
+        if line.is_empty() && lines.last().is_none_or(|last| last.is_empty()) {
+            continue;
+        }
+        lines.push(line);
+    }
+    while lines.last().is_some_and(|last| last.is_empty()) {
+        lines.pop();
+    }
+    lines.join("\n")
+}
+
+/// Callout plegado con `text`; `None` si no hay texto
+pub fn ocr_block(text: &str, title: &str) -> Option<String> {
+    let text = clean_ocr_text(text);
+    if text.is_empty() {
+        return None;
+    }
+    let mut block = format!("> {} {}", OCR_CALLOUT, title);
+    for line in text.lines() {
+        block.push('\n');
+        block.push('>');
+        if !line.is_empty() {
+            block.push(' ');
+            block.push_str(line);
+        }
+    }
+    Some(block)
+}
+
+/// Ruta de la primera imagen (`![alt](ruta)`) de una línea
+pub fn image_on_line(line: &str) -> Option<String> {
+    let start = line.find("![")?;
+    let rest = &line[start..];
+    let open = rest.find("](")? + 2;
+    let close = rest[open..].find(')')? + open;
+    let path = rest[open..close].trim();
+    (!path.is_empty()).then(|| path.to_string())
+}
+
+/// Cambio en la nota para poner un bloque de OCR: `range` en caracteres
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OcrEdit {
+    pub range: Range<usize>,
+    pub text: String,
+}
+
+impl OcrEdit {
+    /// Aplica el cambio a `content`
+    pub fn apply(&self, content: &str) -> String {
+        let byte = |char_idx: usize| {
+            content
+                .char_indices()
+                .nth(char_idx)
+                .map_or(content.len(), |(i, _)| i)
+        };
+        let (start, end) = (byte(self.range.start), byte(self.range.end));
+        format!("{}{}{}", &content[..start], self.text, &content[end..])
+    }
+}
+
+/// Dónde va `block` en `content`: tras la línea que enlaza `image`, en lugar
+/// del bloque de OCR que ya tuviera. `None` si la imagen no está en la nota.
+pub fn ocr_edit(content: &str, image: &str, block: &str) -> Option<OcrEdit> {
+    let lines: Vec<&str> = content.split('\n').collect();
+    let link = format!("]({})", image);
+    let image_line = lines.iter().position(|line| line.contains(&link))?;
+
+    // Carácter donde acaba cada línea (sin contar el salto)
+    let mut line_ends = Vec::with_capacity(lines.len());
+    let mut offset = 0;
+    for line in &lines {
+        offset += line.chars().count();
+        line_ends.push(offset);
+        offset += 1;
+    }
+
+    let start = line_ends[image_line];
+    let next = (image_line + 1..lines.len()).find(|&i| !lines[i].trim().is_empty());
+    let existing = next.filter(|&i| lines[i].trim_start().starts_with("> [!ocr]"));
+    if let Some(first) = existing {
+        let last = (first..lines.len())
+            .take_while(|&i| lines[i].starts_with('>'))
+            .last()
+            .unwrap_or(first);
+        return Some(OcrEdit {
+            range: start..line_ends[last],
+            text: format!("\n\n{}", block),
+        });
+    }
+
+    let followed_by_text = lines
+        .get(image_line + 1)
+        .is_some_and(|line| !line.trim().is_empty());
+    Some(OcrEdit {
+        range: start..start,
+        text: format!("\n\n{}{}", block, if followed_by_text { "\n" } else { "" }),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ocr_block() {
+        assert_eq!(
+            clean_ocr_text("Factura  \n\n\n Total: 42 €\n\x0c\n"),
+            "Factura\n\n Total: 42 €"
+        );
+        assert_eq!(
+            ocr_block("Factura\n\nTotal: 42 €\n", "Texto de la imagen").as_deref(),
+            Some("> [!ocr]- Texto de la imagen\n> Factura\n>\n> Total: 42 €")
+        );
+        assert_eq!(ocr_block(" \n\x0c", "Texto"), None);
+
+        assert!(is_ocr_candidate(Path::new("/a/screenshot.PNG")));
+        assert!(!is_ocr_candidate(Path::new("/a/sketch_1.svg")));
+        assert_eq!(
+            image_on_line("Mira ![captura](/a/b c.png) aquí").as_deref(),
+            Some("/a/b c.png")
+        );
+        assert_eq!(image_on_line("[enlace](nota.md)"), None);
+    }
+
+    #[test]
+    fn test_ocr_edit() {
+        let block = "> [!ocr]- Texto\n> Hola";
+        let content = "# Día\n![captura](/a/c.png)\nSigue el texto";
+        let edit = ocr_edit(content, "/a/c.png", block).unwrap();
+        let updated = edit.apply(content);
+        assert_eq!(
+            updated,
+            "# Día\n![captura](/a/c.png)\n\n> [!ocr]- Texto\n> Hola\n\nSigue el texto"
+        );
+
+        // Volver a extraerlo reemplaza el bloque
+        let again = ocr_edit(&updated, "/a/c.png", "> [!ocr]- Texto\n> Adiós").unwrap();
+        assert_eq!(
+            again.apply(&updated),
+            "# Día\n![captura](/a/c.png)\n\n> [!ocr]- Texto\n> Adiós\n\nSigue el texto"
+        );
+
+        // Al final de la nota, y con una línea en blanco detrás
+        assert_eq!(
+            ocr_edit("![c](/a/c.png)", "/a/c.png", block)
+                .unwrap()
+                .apply("![c](/a/c.png)"),
+            "![c](/a/c.png)\n\n> [!ocr]- Texto\n> Hola"
+        );
+        let spaced = "![c](/a/c.png)\n\nMás";
+        assert_eq!(
+            ocr_edit(spaced, "/a/c.png", block).unwrap().apply(spaced),
+            "![c](/a/c.png)\n\n> [!ocr]- Texto\n> Hola\n\nMás"
+        );
+
+        assert_eq!(ocr_edit(content, "/otra.png", block), None);
+    }
+}
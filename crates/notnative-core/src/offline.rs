@@ -0,0 +1,125 @@
+//! Apoyo para el modo sin conexión
+//!
+//! Sin red la app no llama a la IA en la nube: el chat responde con una
+//! búsqueda local y los embeddings esperan en una [`OfflineQueue`] hasta que
+//! vuelva la conexión.
+
+/// Máximo de trabajos pendientes (se descartan los más antiguos)
+const MAX_PENDING: usize = 500;
+
+/// Trabajos pendientes hasta que vuelva la conexión, uno por clave: si la
+/// misma nota se guarda dos veces solo cuenta la última versión.
+#[derive(Debug, Clone)]
+pub struct OfflineQueue<T> {
+    items: Vec<(String, T)>,
+}
+
+impl<T> Default for OfflineQueue<T> {
+    fn default() -> Self {
+        Self { items: Vec::new() }
+    }
+}
+
+impl<T> OfflineQueue<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Apunta `job` para `key`, sustituyendo el que hubiera (y pasándolo al
+    /// final de la cola)
+    pub fn push(&mut self, key: impl Into<String>, job: T) {
+        let key = key.into();
+        self.items.retain(|(existing, _)| *existing != key);
+        self.items.push((key, job));
+        if self.items.len() > MAX_PENDING {
+            self.items.remove(0);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Saca todos los trabajos en el orden en que se apuntaron
+    pub fn drain(&mut self) -> Vec<(String, T)> {
+        std::mem::take(&mut self.items)
+    }
+}
+
+/// Palabras con contenido de una pregunta, para buscarlas en las notas una a
+/// una (la búsqueda de texto exige todas las palabras a la vez, y una
+/// pregunta entera casi nunca aparece tal cual)
+pub fn search_keywords(message: &str) -> Vec<String> {
+    const STOPWORDS: &[&str] = &[
+        // Español
+        "que", "qué", "los", "las", "del", "con", "por", "para", "una", "uno", "unos", "unas",
+        "como", "cómo", "cual", "cuál", "cuando", "cuándo", "donde", "dónde", "sobre", "tengo",
+        "tiene", "hay", "mis", "tus", "sus", "este", "esta", "esto", "ese", "esa", "eso", "pero",
+        "más", "mas", "muy", "también", "sin", "dime", "busca", "buscar", "nota", "notas",
+        "puedes", "quiero", "algo", "todo", "todas", "todos", "ser", "son", "está", "están",
+        // Inglés
+        "the", "and", "for", "with", "what", "which", "when", "where", "about", "have", "has",
+        "are", "was", "were", "this", "that", "these", "those", "from", "your", "you", "can",
+        "could", "would", "should", "tell", "find", "show", "note", "notes", "any", "all", "how",
+        "why", "who", "does", "did", "there", "into", "some",
+    ];
+    const MAX_KEYWORDS: usize = 6;
+
+    let mut keywords: Vec<String> = Vec::new();
+    for word in message.split(|c: char| !c.is_alphanumeric() && c != '#' && c != '_') {
+        let word = word.to_lowercase();
+        if word.chars().count() < 3 || STOPWORDS.contains(&word.as_str()) {
+            continue;
+        }
+        if !keywords.contains(&word) {
+            keywords.push(word);
+        }
+        if keywords.len() == MAX_KEYWORDS {
+            break;
+        }
+    }
+    keywords
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_queue_keeps_latest_job_per_key() {
+        let mut queue = OfflineQueue::new();
+        queue.push("a.md", 1);
+        queue.push("b.md", 2);
+        queue.push("a.md", 3);
+        assert_eq!(queue.len(), 2);
+        assert_eq!(
+            queue.drain(),
+            vec![("b.md".to_string(), 2), ("a.md".to_string(), 3)]
+        );
+        assert!(queue.is_empty());
+
+        for i in 0..MAX_PENDING + 5 {
+            queue.push(format!("{}.md", i), i);
+        }
+        let jobs = queue.drain();
+        assert_eq!(jobs.len(), MAX_PENDING);
+        assert_eq!(jobs[0].1, 5);
+    }
+
+    #[test]
+    fn test_search_keywords() {
+        assert_eq!(
+            search_keywords("¿Qué notas tengo sobre el viaje a Japón y el #presupuesto?"),
+            vec!["viaje", "japón", "#presupuesto"]
+        );
+        assert_eq!(
+            search_keywords("What did I write about Rust, rust and GTK?"),
+            vec!["write", "rust", "gtk"]
+        );
+        assert!(search_keywords("¿y eso?").is_empty());
+    }
+}
@@ -0,0 +1,237 @@
+//! Abrir NotNative desde fuera: archivos `.md` y enlaces `notnative://`
+//!
+//! El `.desktop` registra la app para `text/markdown` y para el esquema
+//! `notnative`, así que el gestor de archivos o un enlace en otra app la
+//! lanzan con el archivo o la URI como argumento. Si ya hay una instancia, se
+//! le pasa por el archivo de control como `open:<destino>`.
+//!
+//! - `notnative://open?note=Proyectos/Plan&heading=Tareas`
+//! - `notnative://note/Proyectos%2FPlan` (la forma de los enlaces del preview)
+//! - `/ruta/a/nota.md` o `file:///ruta/a/nota.md`
+
+use std::path::{Path, PathBuf};
+
+/// Esquema de los enlaces a notas
+pub const URI_SCHEME: &str = "notnative";
+
+/// Prefijo del comando de control que abre un destino en la instancia activa
+pub const OPEN_COMMAND_PREFIX: &str = "open:";
+
+/// Qué abrir al lanzar la app con un argumento
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OpenTarget {
+    /// Nota del vault, y un encabezado al que saltar
+    Note {
+        name: String,
+        heading: Option<String>,
+    },
+    /// Archivo markdown, dentro o fuera del vault (ruta absoluta)
+    File(PathBuf),
+}
+
+fn is_markdown(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("md") || ext.eq_ignore_ascii_case("markdown"))
+}
+
+/// Deshace los `%XX` (bytes UTF-8)
+fn percent_decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Valor de un parámetro de la query (`+` también es un espacio)
+fn query_value(value: &str) -> String {
+    percent_decode(&value.replace('+', " "))
+}
+
+impl OpenTarget {
+    /// Destino de un argumento de la línea de comandos; `None` si no es una
+    /// URI `notnative://` ni un archivo markdown
+    pub fn parse(arg: &str) -> Option<Self> {
+        let arg = arg.trim();
+        if let Some(rest) = arg.strip_prefix(&format!("{}://", URI_SCHEME)) {
+            return Self::parse_uri(rest);
+        }
+
+        let path = match arg.strip_prefix("file://") {
+            Some(path) => PathBuf::from(percent_decode(path)),
+            None => PathBuf::from(arg),
+        };
+        if arg.is_empty() || !is_markdown(&path) {
+            return None;
+        }
+        // La instancia que lo abre puede tener otro directorio de trabajo
+        let path = std::path::absolute(&path).unwrap_or(path);
+        Some(Self::File(path))
+    }
+
+    /// Lo que va tras `notnative://`
+    fn parse_uri(rest: &str) -> Option<Self> {
+        if let Some(query) = rest.strip_prefix("open?") {
+            let mut name = None;
+            let mut heading = None;
+            for pair in query.split('&') {
+                let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+                match key {
+                    "note" => name = Some(query_value(value)),
+                    "heading" => heading = Some(query_value(value)),
+                    _ => {}
+                }
+            }
+            let name = name.filter(|name| !name.trim().is_empty())?;
+            return Some(Self::Note {
+                name: name.trim().to_string(),
+                heading: heading.filter(|heading| !heading.trim().is_empty()),
+            });
+        }
+
+        let target = rest.strip_prefix("note/")?;
+        let (name, heading) = match target.split_once('#') {
+            Some((name, heading)) => (name, Some(percent_decode(heading))),
+            None => (target, None),
+        };
+        let name = percent_decode(name.trim_end_matches('/'));
+        (!name.is_empty()).then_some(Self::Note { name, heading })
+    }
+
+    /// Comando para el archivo de control de la instancia que ya está abierta
+    pub fn control_command(&self) -> String {
+        match self {
+            Self::Note { name, heading } => {
+                format!(
+                    "{}{}",
+                    OPEN_COMMAND_PREFIX,
+                    note_uri(name, heading.as_deref())
+                )
+            }
+            Self::File(path) => format!("{}{}", OPEN_COMMAND_PREFIX, path.display()),
+        }
+    }
+}
+
+/// Codifica lo que no sea alfanumérico ni `-_.~/` para una query
+fn url_encode(text: &str) -> String {
+    let mut encoded = String::with_capacity(text.len());
+    for byte in text.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'/' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+/// `notnative://open?note=...` para enlazar una nota (y un encabezado) desde
+/// otras apps
+pub fn note_uri(name: &str, heading: Option<&str>) -> String {
+    let mut uri = format!("{}://open?note={}", URI_SCHEME, url_encode(name));
+    if let Some(heading) = heading {
+        uri.push_str(&format!("&heading={}", url_encode(heading)));
+    }
+    uri
+}
+
+/// Nombre de la nota si `file` es un markdown dentro del vault `root`
+pub fn vault_note_name(root: &Path, file: &Path) -> Option<String> {
+    let root = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
+    let file = file.canonicalize().unwrap_or_else(|_| file.to_path_buf());
+    let relative = file.strip_prefix(&root).ok()?;
+    if !is_markdown(relative) {
+        return None;
+    }
+    let name = relative.with_extension("");
+    let parts: Vec<&str> = name
+        .components()
+        .map(|component| component.as_os_str().to_str())
+        .collect::<Option<_>>()?;
+    Some(parts.join("/"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_uri() {
+        assert_eq!(
+            OpenTarget::parse(
+                "notnative://open?note=Proyectos/Plan%20de%20viaje&heading=D%C3%ADa+1"
+            ),
+            Some(OpenTarget::Note {
+                name: "Proyectos/Plan de viaje".into(),
+                heading: Some("Día 1".into()),
+            })
+        );
+        assert_eq!(
+            OpenTarget::parse("notnative://note/Mi%20Nota"),
+            Some(OpenTarget::Note {
+                name: "Mi Nota".into(),
+                heading: None,
+            })
+        );
+        assert_eq!(OpenTarget::parse("notnative://open?heading=x"), None);
+        assert_eq!(OpenTarget::parse("--profile-startup"), None);
+        assert_eq!(OpenTarget::parse("foto.png"), None);
+
+        // Ida y vuelta por el archivo de control
+        let target = OpenTarget::Note {
+            name: "Ideas & planes".into(),
+            heading: Some("Qué hacer".into()),
+        };
+        let command = target.control_command();
+        let uri = command.strip_prefix(OPEN_COMMAND_PREFIX).unwrap();
+        assert_eq!(OpenTarget::parse(uri), Some(target));
+    }
+
+    #[test]
+    fn test_parse_file() {
+        assert_eq!(
+            OpenTarget::parse("file:///home/ana/Mis%20notas/idea.md"),
+            Some(OpenTarget::File(PathBuf::from(
+                "/home/ana/Mis notas/idea.md"
+            )))
+        );
+        match OpenTarget::parse("relativa.MD") {
+            Some(OpenTarget::File(path)) => assert!(path.is_absolute()),
+            other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_vault_note_name() {
+        let root = std::env::temp_dir().join(format!("notnative-open-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("Proyectos")).unwrap();
+        let file = root.join("Proyectos/Plan.md");
+        std::fs::write(&file, "# Plan").unwrap();
+
+        assert_eq!(
+            vault_note_name(&root, &file).as_deref(),
+            Some("Proyectos/Plan")
+        );
+        assert_eq!(vault_note_name(&root, Path::new("/otra/Plan.md")), None);
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
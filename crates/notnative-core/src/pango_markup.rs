@@ -0,0 +1,163 @@
+//! Vista previa ligera: markdown a marcado Pango
+//!
+//! Para ventanas pequeñas (Quick Notes) donde un WebView sobra: encabezados,
+//! énfasis, código, enlaces, citas y listas con casillas en un `gtk::Label`.
+//! Tablas, imágenes y HTML quedan como texto.
+
+use pulldown_cmark::{Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+
+/// Marcado Pango (`Label::set_markup`) equivalente a `markdown`
+pub fn markdown_to_pango(markdown: &str) -> String {
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    options.insert(Options::ENABLE_TASKLISTS);
+
+    let mut output = String::new();
+    // Contador de cada lista abierta (`None` = con viñetas)
+    let mut lists: Vec<Option<u64>> = Vec::new();
+    // Viñeta del elemento actual, hasta saber si es una casilla
+    let mut pending_bullet: Option<String> = None;
+    let mut quote_depth = 0usize;
+
+    for event in Parser::new_ext(markdown, options) {
+        if let Event::TaskListMarker(checked) = event {
+            if let Some(bullet) = pending_bullet.take() {
+                let indent = bullet.len() - bullet.trim_start().len();
+                output.push_str(&" ".repeat(indent));
+                output.push_str(if checked { "☑ " } else { "☐ " });
+            }
+            continue;
+        }
+        if let Some(bullet) = pending_bullet.take() {
+            output.push_str(&bullet);
+        }
+
+        match event {
+            Event::Start(tag) => match tag {
+                Tag::Heading { level, .. } => {
+                    let size = match level {
+                        HeadingLevel::H1 => "x-large",
+                        HeadingLevel::H2 => "large",
+                        _ => "medium",
+                    };
+                    output.push_str(&format!("<span size=\"{}\" weight=\"bold\">", size));
+                }
+                Tag::Paragraph if quote_depth > 0 && lists.is_empty() => {
+                    output.push_str(&"▎ ".repeat(quote_depth));
+                }
+                Tag::BlockQuote => {
+                    quote_depth += 1;
+                    output.push_str("<i>");
+                }
+                Tag::CodeBlock(_) => output.push_str("<tt>"),
+                Tag::List(start) => {
+                    if !lists.is_empty() && !output.ends_with('\n') {
+                        output.push('\n');
+                    }
+                    lists.push(start);
+                }
+                Tag::Item => {
+                    let indent = "  ".repeat(lists.len().saturating_sub(1));
+                    let bullet = match lists.last_mut() {
+                        Some(Some(number)) => {
+                            *number += 1;
+                            format!("{}. ", *number - 1)
+                        }
+                        _ => "• ".to_string(),
+                    };
+                    pending_bullet = Some(format!("{}{}", indent, bullet));
+                }
+                Tag::Emphasis => output.push_str("<i>"),
+                Tag::Strong => output.push_str("<b>"),
+                Tag::Strikethrough => output.push_str("<s>"),
+                Tag::Link { dest_url, .. } => {
+                    output.push_str(&format!("<a href=\"{}\">", escape(&dest_url)));
+                }
+                _ => {}
+            },
+            Event::End(tag) => match tag {
+                TagEnd::Heading(_) => output.push_str("</span>\n\n"),
+                TagEnd::Paragraph if lists.is_empty() => output.push_str("\n\n"),
+                TagEnd::Paragraph => output.push('\n'),
+                TagEnd::BlockQuote => {
+                    quote_depth -= 1;
+                    trim_newlines(&mut output);
+                    output.push_str("</i>\n\n");
+                }
+                TagEnd::CodeBlock => {
+                    trim_newlines(&mut output);
+                    output.push_str("</tt>\n\n");
+                }
+                TagEnd::List(_) => {
+                    lists.pop();
+                    if lists.is_empty() {
+                        trim_newlines(&mut output);
+                        output.push_str("\n\n");
+                    }
+                }
+                TagEnd::Item if !output.ends_with('\n') => output.push('\n'),
+                TagEnd::Emphasis => output.push_str("</i>"),
+                TagEnd::Strong => output.push_str("</b>"),
+                TagEnd::Strikethrough => output.push_str("</s>"),
+                TagEnd::Link => output.push_str("</a>"),
+                _ => {}
+            },
+            Event::Text(text) | Event::Html(text) | Event::InlineHtml(text) => {
+                output.push_str(&escape(&text));
+            }
+            Event::Code(code) => output.push_str(&format!("<tt>{}</tt>", escape(&code))),
+            Event::SoftBreak | Event::HardBreak => {
+                output.push('\n');
+                if quote_depth > 0 {
+                    output.push_str(&"▎ ".repeat(quote_depth));
+                }
+            }
+            Event::Rule => output.push_str("──────────\n\n"),
+            _ => {}
+        }
+    }
+
+    output.trim_end().to_string()
+}
+
+/// Escapa el texto para el marcado de Pango
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn trim_newlines(output: &mut String) {
+    while output.ends_with('\n') {
+        output.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_markdown_to_pango() {
+        let markdown = "# Título\n\nTexto con **negrita**, *cursiva* y `a<b`.\n\n\
+                        - [x] hecho\n- [ ] pendiente\n  1. uno\n  2. dos\n\n\
+                        > cita\n\n[web](https://example.com?a=1&b=2)";
+        assert_eq!(
+            markdown_to_pango(markdown),
+            "<span size=\"x-large\" weight=\"bold\">Título</span>\n\n\
+             Texto con <b>negrita</b>, <i>cursiva</i> y <tt>a&lt;b</tt>.\n\n\
+             ☑ hecho\n☐ pendiente\n  1. uno\n  2. dos\n\n\
+             <i>▎ cita</i>\n\n\
+             <a href=\"https://example.com?a=1&amp;b=2\">web</a>"
+        );
+    }
+
+    #[test]
+    fn test_markdown_to_pango_code_block() {
+        assert_eq!(
+            markdown_to_pango("```\nfn <main>()\n```\n\nFin"),
+            "<tt>fn &lt;main&gt;()</tt>\n\nFin"
+        );
+    }
+}
@@ -0,0 +1,176 @@
+//! Markdown a texto plano para pegar donde no se renderiza (chats, formularios)
+//!
+//! Se quitan el frontmatter, los marcadores de énfasis, encabezados y citas,
+//! y los `[[enlaces]]` se quedan en su texto. Las listas conservan su forma
+//! con `-` (o su número) y la sangría de las anidadas; las tablas quedan con
+//! sus celdas separadas por `|` y el código tal cual.
+
+use pulldown_cmark::{Event, Parser, Tag, TagEnd};
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// `[[Nota]]` o `[[Nota|alias]]`
+static WIKILINK_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\[\[([^\]]+)\]\]").unwrap());
+
+/// `==resaltado==`
+static HIGHLIGHT_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"==([^=\n]+)==").unwrap());
+
+/// Marca de callout `> [!tipo]+` al principio de una cita
+static CALLOUT_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?m)^(>\s?)\[![A-Za-z][\w-]*\][+-]?\s*").unwrap());
+
+/// El texto sin el frontmatter `---` inicial, si lo tiene
+fn without_frontmatter(markdown: &str) -> &str {
+    let Some(rest) = markdown.strip_prefix("---\n") else {
+        return markdown;
+    };
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        offset += line.len();
+        if line.trim_end() == "---" {
+            return &rest[offset..];
+        }
+    }
+    markdown
+}
+
+/// Termina la línea actual y, si `blank`, deja una línea en blanco
+fn block_break(output: &mut String, blank: bool) {
+    if output.is_empty() {
+        return;
+    }
+    while output.ends_with(' ') {
+        output.pop();
+    }
+    let wanted = if blank { "\n\n" } else { "\n" };
+    while !output.ends_with(wanted) {
+        output.push('\n');
+    }
+}
+
+/// Convierte markdown a texto plano. Con `link_urls`, los enlaces llevan su
+/// URL entre paréntesis tras el texto (si no es la misma URL).
+pub fn markdown_to_plain_text(markdown: &str, link_urls: bool) -> String {
+    let markdown = without_frontmatter(markdown);
+    let markdown = WIKILINK_RE.replace_all(markdown, |caps: &regex::Captures| {
+        let target = &caps[1];
+        target
+            .split_once('|')
+            .map_or(target, |(_, alias)| alias)
+            .to_string()
+    });
+    let markdown = HIGHLIGHT_RE.replace_all(&markdown, "$1");
+    let markdown = CALLOUT_RE.replace_all(&markdown, "$1");
+
+    let mut output = String::new();
+    // Siguiente número de cada lista abierta (`None` si no es numerada)
+    let mut lists: Vec<Option<u64>> = Vec::new();
+    // Dónde empieza el texto de cada enlace abierto, y su URL
+    let mut links: Vec<(usize, String)> = Vec::new();
+    let mut item_start = false;
+    let mut first_cell = true;
+
+    for event in Parser::new_ext(&markdown, crate::preview_edit::parser_options()) {
+        match event {
+            Event::Start(Tag::Paragraph) => {
+                if item_start {
+                    item_start = false;
+                } else {
+                    block_break(&mut output, lists.is_empty());
+                    output.push_str(&"  ".repeat(lists.len()));
+                }
+            }
+            Event::Start(Tag::Heading { .. })
+            | Event::Start(Tag::BlockQuote)
+            | Event::Start(Tag::CodeBlock(_))
+            | Event::Start(Tag::Table(_))
+            | Event::Rule => block_break(&mut output, true),
+            Event::End(TagEnd::CodeBlock) => {
+                while output.ends_with('\n') {
+                    output.pop();
+                }
+            }
+            Event::Start(Tag::List(start)) => {
+                block_break(&mut output, lists.is_empty());
+                lists.push(start);
+            }
+            Event::End(TagEnd::List(_)) => {
+                lists.pop();
+            }
+            Event::Start(Tag::Item) => {
+                block_break(&mut output, false);
+                output.push_str(&"  ".repeat(lists.len().saturating_sub(1)));
+                match lists.last_mut() {
+                    Some(Some(number)) => {
+                        output.push_str(&format!("{}. ", number));
+                        *number += 1;
+                    }
+                    _ => output.push_str("- "),
+                }
+                item_start = true;
+            }
+            Event::End(TagEnd::Item) => item_start = false,
+            Event::TaskListMarker(checked) => {
+                output.push_str(if checked { "[x] " } else { "[ ] " });
+            }
+            Event::Start(Tag::TableHead) | Event::Start(Tag::TableRow) => {
+                block_break(&mut output, false);
+                first_cell = true;
+            }
+            Event::Start(Tag::TableCell) => {
+                if !first_cell {
+                    output.push_str(" | ");
+                }
+                first_cell = false;
+            }
+            Event::Start(Tag::Link { dest_url, .. }) => {
+                links.push((output.len(), dest_url.to_string()));
+            }
+            Event::End(TagEnd::Link) => {
+                if let Some((start, url)) = links.pop() {
+                    let text = output[start..].trim();
+                    if link_urls && !url.is_empty() && text != url && !url.starts_with('#') {
+                        output.push_str(&format!(" ({})", url));
+                    }
+                }
+            }
+            Event::FootnoteReference(label) => output.push_str(&format!("[{}]", label)),
+            Event::Start(Tag::FootnoteDefinition(label)) => {
+                block_break(&mut output, true);
+                output.push_str(&format!("[{}] ", label));
+                item_start = true;
+            }
+            Event::Text(text) | Event::Code(text) => output.push_str(&text),
+            Event::SoftBreak | Event::HardBreak => {
+                output.push('\n');
+                if !lists.is_empty() {
+                    output.push_str(&"  ".repeat(lists.len()));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    output.trim_start_matches('\n').trim_end().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plain_text() {
+        let md = "---\ntags: [a]\n---\n# Plan {.special}\n\nTexto **fuerte** y `código`, ver [[Ideas|las ideas]] y [web](https://ejemplo.com).\n\n- Uno\n  - Dentro\n- [x] Hecho\n\n1. Primero\n2. Segundo\n\n> [!tip] Consejo\n> ==Importante==\n\n```rust\nfn main() {}\n```\n\n| A | B |\n|---|---|\n| 1 | 2 |\n";
+        let text = markdown_to_plain_text(md, true);
+        assert_eq!(
+            text,
+            "Plan\n\nTexto fuerte y código, ver las ideas y web (https://ejemplo.com).\n\n\
+             - Uno\n  - Dentro\n- [x] Hecho\n\n1. Primero\n2. Segundo\n\n\
+             Consejo\nImportante\n\nfn main() {}\n\nA | B\n1 | 2"
+        );
+
+        let text =
+            markdown_to_plain_text("Ver [web](https://ejemplo.com) y <https://a.com>", false);
+        assert_eq!(text, "Ver web y https://a.com");
+    }
+}
@@ -0,0 +1,177 @@
+//! Caché del HTML del preview
+//!
+//! Al volver a modo Normal no hace falta regenerar el HTML si el contenido no
+//! cambió. Si cambió pero el documento (estilos, tema) es el mismo que ya muestra
+//! el WebView, se reemplaza solo el contenido con JavaScript: no hay recarga,
+//! no parpadea y se conserva la posición de scroll.
+
+use std::collections::VecDeque;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use super::html_renderer::HtmlRenderer;
+
+/// Contenidos renderizados que se conservan (notas visitadas recientemente)
+const MAX_ENTRIES: usize = 16;
+
+/// Qué hay que hacer con el WebView para mostrar el contenido actual
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PreviewUpdate {
+    /// El WebView ya muestra este contenido
+    Unchanged,
+    /// Script que reemplaza el contenido de la página actual
+    Patch(String),
+    /// Documento completo para `load_html`
+    Load(String),
+}
+
+#[derive(Debug, Default)]
+pub struct PreviewCache {
+    /// (documento, hash del markdown) → body renderizado, de más antiguo a más reciente
+    entries: VecDeque<((u64, u64), String)>,
+    /// Documento y contenido que muestra el WebView
+    shown: Option<(u64, u64)>,
+}
+
+fn content_hash(markdown: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    markdown.hash(&mut hasher);
+    hasher.finish()
+}
+
+impl PreviewCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Calcula la actualización del WebView para `markdown`.
+    ///
+    /// `can_patch` indica si la página actual terminó de cargar (si no, los scripts
+    /// no están disponibles y se recarga el documento completo).
+    pub fn update(
+        &mut self,
+        renderer: &HtmlRenderer,
+        markdown: &str,
+        can_patch: bool,
+    ) -> PreviewUpdate {
+        let key = (renderer.document_key(), content_hash(markdown));
+        if self.shown == Some(key) {
+            return PreviewUpdate::Unchanged;
+        }
+
+        let body = self.body(key, || renderer.render_body(markdown));
+        let same_document = self.shown.is_some_and(|(document, _)| document == key.0);
+        self.shown = Some(key);
+
+        if same_document && can_patch {
+            let html = serde_json::to_string(&body).unwrap_or_default();
+            PreviewUpdate::Patch(format!(
+                "if (!patchContent({})) {{ location.reload(); }}",
+                html
+            ))
+        } else {
+            PreviewUpdate::Load(renderer.wrap_in_document(&body))
+        }
+    }
+
+    /// Olvida lo que muestra el WebView: la próxima actualización recarga la página
+    /// (nota nueva, WebView recreado o en mal estado). El HTML renderizado se conserva.
+    pub fn invalidate_view(&mut self) {
+        self.shown = None;
+    }
+
+    fn body(&mut self, key: (u64, u64), render: impl FnOnce() -> String) -> String {
+        if let Some(pos) = self.entries.iter().position(|(k, _)| *k == key) {
+            // Mover al final (usado más recientemente)
+            let entry = self.entries.remove(pos).expect("posición válida");
+            let body = entry.1.clone();
+            self.entries.push_back(entry);
+            return body;
+        }
+
+        let body = render();
+        if self.entries.len() >= MAX_ENTRIES {
+            self.entries.pop_front();
+        }
+        self.entries.push_back((key, body.clone()));
+        body
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html_renderer::PreviewTheme;
+
+    #[test]
+    fn test_unchanged_content_is_not_rerendered() {
+        let renderer = HtmlRenderer::new(PreviewTheme::Dark);
+        let mut cache = PreviewCache::new();
+
+        assert!(matches!(
+            cache.update(&renderer, "# Hola", true),
+            PreviewUpdate::Load(_)
+        ));
+        assert_eq!(
+            cache.update(&renderer, "# Hola", true),
+            PreviewUpdate::Unchanged
+        );
+    }
+
+    #[test]
+    fn test_changed_content_is_patched() {
+        let renderer = HtmlRenderer::new(PreviewTheme::Dark);
+        let mut cache = PreviewCache::new();
+        cache.update(&renderer, "# Hola", true);
+
+        match cache.update(&renderer, "# Hola\n\nMundo", true) {
+            PreviewUpdate::Patch(script) => {
+                assert!(script.starts_with("if (!patchContent("));
+                assert!(script.contains("Mundo"));
+            }
+            other => panic!("se esperaba un parche: {:?}", other),
+        }
+
+        // Sin la página cargada no se puede parchear
+        assert!(matches!(
+            cache.update(&renderer, "# Otro", false),
+            PreviewUpdate::Load(_)
+        ));
+    }
+
+    #[test]
+    fn test_theme_change_reloads_document() {
+        let mut cache = PreviewCache::new();
+        cache.update(&HtmlRenderer::new(PreviewTheme::Dark), "# Hola", true);
+
+        assert!(matches!(
+            cache.update(&HtmlRenderer::new(PreviewTheme::Light), "# Hola", true),
+            PreviewUpdate::Load(_)
+        ));
+    }
+
+    #[test]
+    fn test_invalidate_view_reloads_from_cache() {
+        let renderer = HtmlRenderer::new(PreviewTheme::Dark);
+        let mut cache = PreviewCache::new();
+        let PreviewUpdate::Load(first) = cache.update(&renderer, "# Hola", true) else {
+            panic!("se esperaba una carga completa");
+        };
+
+        cache.invalidate_view();
+        assert_eq!(
+            cache.update(&renderer, "# Hola", true),
+            PreviewUpdate::Load(first)
+        );
+        assert_eq!(cache.entries.len(), 1);
+    }
+
+    #[test]
+    fn test_cache_is_bounded() {
+        let renderer = HtmlRenderer::new(PreviewTheme::Dark);
+        let mut cache = PreviewCache::new();
+        for i in 0..MAX_ENTRIES + 5 {
+            cache.update(&renderer, &format!("nota {}", i), true);
+        }
+        assert_eq!(cache.entries.len(), MAX_ENTRIES);
+    }
+}
@@ -0,0 +1,349 @@
+//! Edición ligera en el preview
+//!
+//! Los párrafos, encabezados, elementos de lista y celdas de tabla del
+//! preview se pueden corregir en el sitio. El renderer marca cada uno con el
+//! rango de bytes del markdown del que sale ([`source_map`]) y, al terminar
+//! de editar, el preview devuelve ese rango con el texto de antes y el de
+//! después. [`apply_preview_edit`] busca el tramo cambiado dentro del rango
+//! (con algo de texto alrededor para no confundirlo con otro igual) y lo
+//! sustituye, dejando intacto el formato de alrededor.
+//!
+//! El mismo emparejamiento da la línea de cada bloque, con la que el editor y
+//! el preview se sincronizan al cambiar de modo.
+
+use pulldown_cmark::{Event, Options, Parser, Tag};
+use std::ops::Range;
+use thiserror::Error;
+
+/// Caracteres de contexto a cada lado del cambio para situarlo en el markdown
+const CONTEXT: usize = 24;
+
+/// Bloques que se pueden emparejar más adelante si el preview tiene alguno
+/// que el markdown no (un callout, una línea de propiedades)
+const LOOKAHEAD: usize = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditableKind {
+    Paragraph,
+    Heading,
+    ListItem,
+    TableCell,
+    CodeBlock,
+}
+
+/// Bloque con texto propio (el de sus sublistas o párrafos va aparte)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EditableBlock {
+    pub kind: EditableKind,
+    /// Índice del evento que abre el bloque
+    pub start_event: usize,
+    /// Desde el primer texto del bloque hasta el último
+    pub range: Range<usize>,
+    /// Texto tal como se ve
+    pub text: String,
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum PreviewEditError {
+    #[error("El rango no corresponde a la nota")]
+    OutOfRange,
+    #[error("Solo se puede editar una línea cada vez")]
+    MultiLine,
+    #[error("No se encontró el texto editado en la nota")]
+    NotFound,
+    #[error("El texto editado aparece varias veces en el bloque")]
+    Ambiguous,
+}
+
+/// Las mismas opciones que el renderer
+pub fn parser_options() -> Options {
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    options.insert(Options::ENABLE_TASKLISTS);
+    options.insert(Options::ENABLE_FOOTNOTES);
+    options.insert(Options::ENABLE_HEADING_ATTRIBUTES);
+    options
+}
+
+/// Bloques editables de una lista de eventos con sus rangos
+pub fn editable_blocks_in(events: &[(Event<'_>, Range<usize>)]) -> Vec<EditableBlock> {
+    let mut blocks: Vec<EditableBlock> = Vec::new();
+    // Por cada etiqueta abierta, el bloque que abre (si es editable)
+    let mut open: Vec<Option<usize>> = Vec::new();
+
+    for (index, (event, range)) in events.iter().enumerate() {
+        let text = match event {
+            Event::Start(tag) => {
+                let kind = match tag {
+                    Tag::Paragraph => Some(EditableKind::Paragraph),
+                    Tag::Heading { .. } => Some(EditableKind::Heading),
+                    Tag::Item => Some(EditableKind::ListItem),
+                    Tag::TableCell => Some(EditableKind::TableCell),
+                    Tag::CodeBlock(_) => Some(EditableKind::CodeBlock),
+                    _ => None,
+                };
+                open.push(kind.map(|kind| {
+                    blocks.push(EditableBlock {
+                        kind,
+                        start_event: index,
+                        range: range.start..range.start,
+                        text: String::new(),
+                    });
+                    blocks.len() - 1
+                }));
+                continue;
+            }
+            Event::End(_) => {
+                open.pop();
+                continue;
+            }
+            Event::Text(text) | Event::Code(text) => text.as_ref(),
+            Event::SoftBreak | Event::HardBreak => "\n",
+            _ => continue,
+        };
+        let Some(block) = open.iter().rev().find_map(|block| *block) else {
+            continue;
+        };
+        let block = &mut blocks[block];
+        if block.text.is_empty() {
+            block.range = range.clone();
+        } else {
+            block.range.end = block.range.end.max(range.end);
+        }
+        block.text.push_str(text);
+    }
+
+    blocks.retain(|block| !block.text.trim().is_empty());
+    blocks
+}
+
+/// Bloques editables del markdown
+pub fn editable_blocks(markdown: &str) -> Vec<EditableBlock> {
+    let events: Vec<_> = Parser::new_ext(markdown, parser_options())
+        .into_offset_iter()
+        .collect();
+    editable_blocks_in(&events)
+}
+
+/// Línea (desde 1) en la que está `offset`
+pub fn line_at(markdown: &str, offset: usize) -> usize {
+    markdown.as_bytes()[..offset.min(markdown.len())]
+        .iter()
+        .filter(|&&b| b == b'\n')
+        .count()
+        + 1
+}
+
+/// Solo letras y números: los enlaces `[[nota]]`, tags y resaltados se ven
+/// distintos de como se escriben
+fn comparable(text: &str) -> String {
+    text.chars().filter(|c| c.is_alphanumeric()).collect()
+}
+
+/// Empareja los bloques del preview (`rendered`, eventos del markdown ya
+/// preprocesado) con los del markdown original. Devuelve, por cada bloque
+/// emparejado, el evento que lo abre en `rendered` y su rango en `original`.
+/// Los que no tienen pareja segura no se pueden editar.
+pub fn source_map(
+    original: &str,
+    rendered: &[(Event<'_>, Range<usize>)],
+) -> Vec<(usize, Range<usize>)> {
+    let original = editable_blocks(original);
+    let mut next = 0;
+    let mut map = Vec::new();
+
+    for block in editable_blocks_in(rendered) {
+        let text = comparable(&block.text);
+        if text.is_empty() {
+            continue;
+        }
+        let found =
+            original
+                .iter()
+                .enumerate()
+                .skip(next)
+                .take(LOOKAHEAD)
+                .find(|(_, candidate)| {
+                    candidate.kind == block.kind && comparable(&candidate.text) == text
+                });
+        if let Some((index, candidate)) = found {
+            map.push((block.start_event, candidate.range.clone()));
+            next = index + 1;
+        }
+    }
+    map
+}
+
+/// Aplica al markdown la edición hecha en el preview: `old_text` y `new_text`
+/// son el texto del bloque antes y después, y `range` su rango en el markdown
+pub fn apply_preview_edit(
+    markdown: &str,
+    range: Range<usize>,
+    old_text: &str,
+    new_text: &str,
+) -> Result<String, PreviewEditError> {
+    if range.start > range.end
+        || range.end > markdown.len()
+        || !markdown.is_char_boundary(range.start)
+        || !markdown.is_char_boundary(range.end)
+    {
+        return Err(PreviewEditError::OutOfRange);
+    }
+
+    let old: Vec<char> = old_text.chars().collect();
+    let new: Vec<char> = new_text.chars().collect();
+    let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let removed: String = old[prefix..old.len() - suffix].iter().collect();
+    let inserted: String = new[prefix..new.len() - suffix].iter().collect();
+    if removed.is_empty() && inserted.is_empty() {
+        return Ok(markdown.to_string());
+    }
+    if inserted.contains('\n') {
+        return Err(PreviewEditError::MultiLine);
+    }
+
+    // Se busca el cambio con todo el contexto posible; si el formato lo corta
+    // (un `**` en medio), con menos
+    let source = &markdown[range.clone()];
+    let mut before = prefix.min(CONTEXT);
+    let mut after = suffix.min(CONTEXT);
+    loop {
+        let context_before: String = old[prefix - before..prefix].iter().collect();
+        let context_after: String = old[old.len() - suffix..old.len() - suffix + after]
+            .iter()
+            .collect();
+        let needle = format!("{}{}{}", context_before, removed, context_after);
+        let matches: Vec<usize> = source.match_indices(&needle).map(|(at, _)| at).collect();
+
+        match matches.as_slice() {
+            [at] => {
+                let start = range.start + at + context_before.len();
+                let mut result = String::with_capacity(markdown.len() + inserted.len());
+                result.push_str(&markdown[..start]);
+                result.push_str(&inserted);
+                result.push_str(&markdown[start + removed.len()..]);
+                return Ok(result);
+            }
+            [] if before == 0 && after == 0 => return Err(PreviewEditError::NotFound),
+            [] if before >= after => before -= 1,
+            [] => after -= 1,
+            _ => return Err(PreviewEditError::Ambiguous),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edit(markdown: &str, block: usize, new_text: &str) -> Result<String, PreviewEditError> {
+        let block = &editable_blocks(markdown)[block];
+        apply_preview_edit(markdown, block.range.clone(), &block.text, new_text)
+    }
+
+    #[test]
+    fn test_editable_blocks() {
+        let markdown =
+            "# Título\n\nUn **párrafo**\n\n- uno\n  - dos\n\n| a | b |\n|---|---|\n| c | d |\n";
+        let blocks = editable_blocks(markdown);
+        let summary: Vec<(EditableKind, &str)> = blocks
+            .iter()
+            .map(|block| (block.kind, block.text.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (EditableKind::Heading, "Título"),
+                (EditableKind::Paragraph, "Un párrafo"),
+                (EditableKind::ListItem, "uno"),
+                (EditableKind::ListItem, "dos"),
+                (EditableKind::TableCell, "a"),
+                (EditableKind::TableCell, "b"),
+                (EditableKind::TableCell, "c"),
+                (EditableKind::TableCell, "d"),
+            ]
+        );
+        assert_eq!(&markdown[blocks[1].range.clone()], "Un **párrafo");
+    }
+
+    #[test]
+    fn test_fix_typo_keeps_formatting() {
+        assert_eq!(
+            edit("Un **párafo** largo\n", 0, "Un párrafo largo").unwrap(),
+            "Un **párrafo** largo\n"
+        );
+        assert_eq!(
+            edit("Texto con `codgo` y más", 0, "Texto con código y más").unwrap(),
+            "Texto con `código` y más"
+        );
+        // Añadir al final del texto resaltado
+        assert_eq!(
+            edit("Hola **mundo**", 0, "Hola mundo!").unwrap(),
+            "Hola **mundo!**"
+        );
+    }
+
+    #[test]
+    fn test_table_cell_and_list_item() {
+        let markdown = "| Nombre | Precio |\n|---|---|\n| Pan | 1 |\n| Leche | 1 |\n";
+        assert_eq!(
+            edit(markdown, 5, "2").unwrap(),
+            "| Nombre | Precio |\n|---|---|\n| Pan | 1 |\n| Leche | 2 |\n"
+        );
+        assert_eq!(
+            edit("- compar pan\n- leche\n", 0, "comprar pan").unwrap(),
+            "- comprar pan\n- leche\n"
+        );
+    }
+
+    #[test]
+    fn test_rejected_edits() {
+        assert_eq!(
+            edit("**la** x **la**", 0, "la x le").unwrap_err(),
+            PreviewEditError::Ambiguous
+        );
+        assert_eq!(
+            edit("Una línea", 0, "Una\nlínea").unwrap_err(),
+            PreviewEditError::MultiLine
+        );
+        assert_eq!(
+            apply_preview_edit("abc", 2..9, "c", "d").unwrap_err(),
+            PreviewEditError::OutOfRange
+        );
+        assert_eq!(edit("Igual", 0, "Igual").unwrap(), "Igual");
+    }
+
+    #[test]
+    fn test_source_map_skips_unmatched_blocks() {
+        let original = "Hola [[Nota]]\n\n[estado::hecho]\n\nAdiós #tag\n";
+        // Así llega al parser del preview tras el preprocesado
+        let rendered = "Hola [Nota](notnative://note/Nota)\n\n<div class=\"inline-props-line\"></div>\n\nAdiós [#tag](notnative://tag/tag)\n";
+        let events: Vec<_> = Parser::new_ext(rendered, parser_options())
+            .into_offset_iter()
+            .collect();
+        let map = source_map(original, &events);
+        let ranges: Vec<&str> = map
+            .iter()
+            .map(|(_, range)| &original[range.clone()])
+            .collect();
+        assert_eq!(ranges, vec!["Hola [[Nota]]", "Adiós #tag"]);
+        assert!(matches!(events[map[1].0].0, Event::Start(Tag::Paragraph)));
+    }
+
+    #[test]
+    fn test_code_block_lines() {
+        let markdown = "Texto\n\n```rust\nfn main() {}\n```\n";
+        let blocks = editable_blocks(markdown);
+        assert_eq!(blocks[1].kind, EditableKind::CodeBlock);
+        assert_eq!(line_at(markdown, blocks[0].range.start), 1);
+        assert_eq!(line_at(markdown, blocks[1].range.start), 4);
+        assert_eq!(line_at(markdown, markdown.len() + 10), 6);
+    }
+}
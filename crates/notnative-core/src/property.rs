@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+use super::date_format::DateFormatter;
+
 /// Tipos de propiedades soportados (similar a Obsidian)
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type", content = "value")]
@@ -165,7 +167,7 @@ impl PropertyValue {
     fn format_date_friendly(date_str: &str) -> String {
         // Intentar parsear varios formatos
         if let Ok(date) = chrono::NaiveDate::parse_from_str(date_str, "%Y-%m-%d") {
-            return date.format("%d %b %Y").to_string();
+            return DateFormatter::global().format_date(date);
         }
         // Si no se puede parsear, devolver original
         date_str.to_string()
@@ -174,31 +176,31 @@ impl PropertyValue {
     /// Formatear datetime de forma amigable
     fn format_datetime_friendly(dt_str: &str) -> String {
         let dt_str = dt_str.trim();
+        let formatter = DateFormatter::global();
 
         // Intentar parsear formato ISO con timezone (2025-12-02T19:30:53+00:00)
         if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(dt_str) {
-            let local = dt.with_timezone(&chrono::Local);
-            return local.format("%d %b %Y, %H:%M").to_string();
+            return formatter.format_local(&dt);
         }
 
         // Intentar ISO sin timezone (2025-12-02T19:30:53)
         if let Ok(dt) = chrono::NaiveDateTime::parse_from_str(dt_str, "%Y-%m-%dT%H:%M:%S") {
-            return dt.format("%d %b %Y, %H:%M").to_string();
+            return formatter.format_datetime(dt);
         }
 
         // Intentar con milisegundos
         if let Ok(dt) = chrono::NaiveDateTime::parse_from_str(dt_str, "%Y-%m-%dT%H:%M:%S%.f") {
-            return dt.format("%d %b %Y, %H:%M").to_string();
+            return formatter.format_datetime(dt);
         }
 
         // Intentar formato simple (2025-12-02 19:30:53)
         if let Ok(dt) = chrono::NaiveDateTime::parse_from_str(dt_str, "%Y-%m-%d %H:%M:%S") {
-            return dt.format("%d %b %Y, %H:%M").to_string();
+            return formatter.format_datetime(dt);
         }
 
         // Intentar formato sin segundos
         if let Ok(dt) = chrono::NaiveDateTime::parse_from_str(dt_str, "%Y-%m-%d %H:%M") {
-            return dt.format("%d %b %Y, %H:%M").to_string();
+            return formatter.format_datetime(dt);
         }
 
         // Si no se puede parsear, devolver original
@@ -369,6 +371,10 @@ mod tests {
     }
 
     #[test]
+    #[allow(
+        clippy::approx_constant,
+        reason = "un número con decimales cualquiera, no se usa como π"
+    )]
     fn test_display() {
         assert_eq!(PropertyValue::Checkbox(true).to_display_string(), "✓");
         assert_eq!(PropertyValue::Number(42.0).to_display_string(), "42");
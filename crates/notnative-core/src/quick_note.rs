@@ -0,0 +1,150 @@
+//! Quick Notes: enviar su texto a otra nota y autocompletar `@` y `#`
+//!
+//! El texto de una quick note (sin la cabecera que se genera al crearla) puede
+//! ir al diario de hoy, al final de una nota existente o a una nota nueva.
+
+use crate::frontmatter::is_tag_char;
+use chrono::NaiveDate;
+
+/// Carpeta de las notas del diario (`Journal/2026-10-16`)
+pub const JOURNAL_FOLDER: &str = "Journal";
+
+/// Dónde acaba el texto de una quick note
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QuickNoteTarget {
+    /// Nota del diario de hoy (se crea si no existe)
+    Journal,
+    /// Al final de una nota existente
+    Note(String),
+    /// Nota nueva con el texto
+    NewNote,
+}
+
+/// Nombre de la nota del diario de `date`
+pub fn journal_note_name(date: NaiveDate) -> String {
+    format!("{}/{}", JOURNAL_FOLDER, date.format("%Y-%m-%d"))
+}
+
+/// Texto de la quick note sin la cabecera automática (`# Quick Note` y la
+/// línea `> ...` con la fecha de creación)
+pub fn quick_note_body(content: &str) -> &str {
+    let mut rest = content.trim_start();
+    if let Some(after) = rest.strip_prefix("# Quick Note") {
+        if after.is_empty() || after.starts_with('\n') {
+            rest = after.trim_start();
+            if rest.starts_with('>') {
+                rest = rest.split_once('\n').map_or("", |(_, body)| body);
+            }
+        }
+    }
+    rest.trim()
+}
+
+/// `block` al final de `target`, separado por una línea en blanco
+pub fn append_block(target: &str, block: &str) -> String {
+    let mut output = target.trim_end().to_string();
+    if !output.is_empty() {
+        output.push_str("\n\n");
+    }
+    output.push_str(block.trim());
+    output.push('\n');
+    output
+}
+
+/// Autocompletado pendiente al final de `before_cursor`: `@` (nota) o `#`
+/// (tag) y lo escrito detrás. Un `#` seguido de espacio es un encabezado.
+pub fn completion_prefix(before_cursor: &str) -> Option<(char, &str)> {
+    let token_start = before_cursor.rfind(char::is_whitespace).map_or(0, |index| {
+        index
+            + before_cursor[index..]
+                .chars()
+                .next()
+                .map_or(1, char::len_utf8)
+    });
+    let token = &before_cursor[token_start..];
+    let mut chars = token.chars();
+    let trigger = chars.next()?;
+    let prefix = chars.as_str();
+    match trigger {
+        '#' if prefix.chars().all(is_tag_char) => Some(('#', prefix)),
+        '@' if !prefix.contains('@') => Some(('@', prefix)),
+        _ => None,
+    }
+}
+
+/// Puntuación de `candidate` para lo escrito (`None` si no encaja): exacta,
+/// por el principio, contenida o con las letras en orden
+pub fn fuzzy_score(candidate: &str, query: &str) -> Option<i32> {
+    let candidate = candidate.to_lowercase();
+    let query = query.to_lowercase();
+    if query.is_empty() {
+        return Some(0);
+    }
+    if candidate == query {
+        return Some(1000);
+    }
+    if candidate.starts_with(&query) {
+        return Some(500);
+    }
+    if let Some(position) = candidate.find(&query) {
+        return Some(250 - position.min(200) as i32);
+    }
+
+    let mut query_chars = query.chars().peekable();
+    for ch in candidate.chars() {
+        if query_chars.peek() == Some(&ch) {
+            query_chars.next();
+        }
+    }
+    query_chars.peek().is_none().then_some(100)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quick_note_body() {
+        let content = "# Quick Note\n\n> Creada: 16/10/2026 09:30\n\nComprar pan\n";
+        assert_eq!(quick_note_body(content), "Comprar pan");
+        assert_eq!(
+            quick_note_body("# Quick Notes sueltas\nA"),
+            "# Quick Notes sueltas\nA"
+        );
+        assert_eq!(quick_note_body("# Quick Note\n\n> Creada: hoy\n"), "");
+    }
+
+    #[test]
+    fn test_append_block_and_journal() {
+        let date = NaiveDate::from_ymd_opt(2026, 10, 16).unwrap();
+        assert_eq!(journal_note_name(date), "Journal/2026-10-16");
+        assert_eq!(
+            append_block("# Nota\n\n", "  texto \n"),
+            "# Nota\n\ntexto\n"
+        );
+        assert_eq!(append_block("", "texto"), "texto\n");
+    }
+
+    #[test]
+    fn test_completion_prefix() {
+        assert_eq!(completion_prefix("ver @Proy"), Some(('@', "Proy")));
+        assert_eq!(
+            completion_prefix("idea #proyecto/cli"),
+            Some(('#', "proyecto/cli"))
+        );
+        assert_eq!(completion_prefix("#"), Some(('#', "")));
+        assert_eq!(completion_prefix("# Título"), None);
+        // Una dirección de correo no es una mención
+        assert_eq!(completion_prefix("correo a@b.com"), None);
+        assert_eq!(completion_prefix("texto "), None);
+    }
+
+    #[test]
+    fn test_fuzzy_score() {
+        assert_eq!(fuzzy_score("Proyecto", "proyecto"), Some(1000));
+        assert_eq!(fuzzy_score("Proyecto", "pro"), Some(500));
+        assert_eq!(fuzzy_score("Mi proyecto", "proy"), Some(247));
+        assert_eq!(fuzzy_score("Presupuesto", "pst"), Some(100));
+        assert_eq!(fuzzy_score("Presupuesto", "xyz"), None);
+    }
+}
@@ -0,0 +1,418 @@
+//! Renombrados en todo el vault: tags y menciones
+//!
+//! `:rename-tag viejo nuevo` y `:rename-mention Vieja Nueva` reescriben el
+//! texto de todas las notas afectadas. Las candidatas salen del índice y se
+//! comprueban leyendo cada archivo; antes de tocar nada se enseñan las líneas
+//! que cambian ([`NoteRewrite::changed_lines`]). [`RefactorPlan::apply`]
+//! escribe todo o nada: guarda cada nota en el historial, y si una escritura
+//! o el índice fallan, las ya escritas vuelven a su texto anterior. Con esas
+//! copias del historial se deshace el renombrado entero de una vez
+//! ([`undo_refactor`]).
+
+use anyhow::{Context, Result, bail};
+use std::fs;
+use std::path::PathBuf;
+use tracing::{error, info};
+
+use crate::database::NotesDatabase;
+use crate::frontmatter::is_tag_char;
+use crate::links::retarget_links;
+use crate::note_file::{NoteFile, NotesDirectory};
+use crate::tag_tree::rename_tag_in_content;
+use crate::text_diff::{DiffLine, diff_lines};
+
+/// Renombrado que se aplica a todo el vault
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refactor {
+    /// `#from` (y `#from/...`) pasa a `#to`, inline y en el frontmatter
+    RenameTag { from: String, to: String },
+    /// `@From` y `[[From]]` pasan a apuntar a `To`
+    RenameMention { from: String, to: String },
+}
+
+impl Refactor {
+    /// Renombrado de tag con los nombres normalizados (sin `#`, en
+    /// minúsculas). `None` si no es válido o no cambia nada.
+    pub fn rename_tag(from: &str, to: &str) -> Option<Self> {
+        let from = from.trim().trim_start_matches('#').to_lowercase();
+        let to = to
+            .trim()
+            .trim_start_matches('#')
+            .trim_matches('/')
+            .to_lowercase();
+        if from.is_empty() || to.is_empty() || from == to || !to.chars().all(is_tag_char) {
+            return None;
+        }
+        Some(Self::RenameTag { from, to })
+    }
+
+    /// Renombrado de menciones (sin `@` ni `[[ ]]`). `None` si no es válido
+    /// o no cambia nada.
+    pub fn rename_mention(from: &str, to: &str) -> Option<Self> {
+        let clean = |name: &str| {
+            name.trim()
+                .trim_start_matches('@')
+                .trim_start_matches("[[")
+                .trim_end_matches("]]")
+                .to_string()
+        };
+        let (from, to) = (clean(from), clean(to));
+        if from.is_empty() || to.is_empty() || from == to {
+            return None;
+        }
+        Some(Self::RenameMention { from, to })
+    }
+
+    /// Texto de una nota con el renombrado hecho; `None` si no le afecta
+    pub fn rewrite(&self, content: &str) -> Option<String> {
+        match self {
+            Self::RenameTag { from, to } => rename_tag_in_content(content, from, to),
+            Self::RenameMention { from, to } => retarget_links(content, from, to),
+        }
+    }
+
+    /// Rutas de las notas que pueden verse afectadas, según el índice
+    fn candidates(&self, db: &NotesDatabase) -> Result<Vec<String>> {
+        Ok(match self {
+            Self::RenameTag { from, .. } => db.note_paths_with_tag(from)?,
+            Self::RenameMention { from, .. } => db.note_paths_containing(from)?,
+        })
+    }
+}
+
+/// Línea que cambia: número en la nota actual (desde 1) y su texto antes y
+/// después. Las que solo desaparecen o solo aparecen llevan un lado vacío.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineChange {
+    pub line: usize,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+/// Nota que se reescribe
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteRewrite {
+    /// Nombre con el que está en el índice
+    pub name: String,
+    pub path: PathBuf,
+    pub original: String,
+    pub updated: String,
+}
+
+impl NoteRewrite {
+    pub fn changed_lines(&self) -> Vec<LineChange> {
+        let before: Vec<&str> = self.original.split('\n').collect();
+        let after: Vec<&str> = self.updated.split('\n').collect();
+        // Lo normal: cada línea cambia en su sitio
+        if before.len() == after.len() {
+            return before
+                .iter()
+                .zip(&after)
+                .enumerate()
+                .filter(|(_, (old, new))| old != new)
+                .map(|(index, (old, new))| LineChange {
+                    line: index + 1,
+                    before: Some(old.to_string()),
+                    after: Some(new.to_string()),
+                })
+                .collect();
+        }
+
+        // El frontmatter reescrito puede cambiar de número de líneas
+        let mut line = 0;
+        let mut changes = Vec::new();
+        for diff in diff_lines(&self.original, &self.updated) {
+            match diff {
+                DiffLine::Same(_) => line += 1,
+                DiffLine::Skipped(count) => line += count,
+                DiffLine::Removed(text) => {
+                    line += 1;
+                    changes.push(LineChange {
+                        line,
+                        before: Some(text),
+                        after: None,
+                    });
+                }
+                DiffLine::Added(text) => changes.push(LineChange {
+                    line: line.max(1),
+                    before: None,
+                    after: Some(text),
+                }),
+            }
+        }
+        changes
+    }
+}
+
+/// Renombrado listo para aplicar: las notas afectadas con su texto nuevo
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RefactorPlan {
+    pub refactor: Refactor,
+    pub notes: Vec<NoteRewrite>,
+}
+
+impl RefactorPlan {
+    /// Busca en el índice las notas afectadas y prepara su texto nuevo
+    pub fn new(db: &NotesDatabase, refactor: Refactor) -> Result<Self> {
+        let mut notes = Vec::new();
+        for path in refactor.candidates(db)? {
+            // El índice puede ir por detrás del disco: manda el archivo
+            let Ok(original) = fs::read_to_string(&path) else {
+                continue;
+            };
+            let Some(updated) = refactor.rewrite(&original) else {
+                continue;
+            };
+            let name = match db.get_note_by_path(&path)? {
+                Some(meta) => meta.name,
+                None => continue,
+            };
+            notes.push(NoteRewrite {
+                name,
+                path: PathBuf::from(path),
+                original,
+                updated,
+            });
+        }
+        Ok(Self { refactor, notes })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.notes.is_empty()
+    }
+
+    /// Líneas que cambian en total
+    pub fn line_count(&self) -> usize {
+        self.notes
+            .iter()
+            .map(|note| note.changed_lines().len())
+            .sum()
+    }
+
+    /// Escribe todas las notas y las reindexa en una transacción, o no deja
+    /// nada cambiado. Falla sin tocar nada si alguna nota cambió desde que se
+    /// preparó el plan. Devuelve (copia en el historial, nota) de cada una,
+    /// para [`undo_refactor`].
+    pub fn apply(
+        &self,
+        db: &NotesDatabase,
+        dir: &NotesDirectory,
+    ) -> Result<Vec<(PathBuf, PathBuf)>> {
+        for note in &self.notes {
+            let current = fs::read_to_string(&note.path)
+                .with_context(|| format!("No se pudo leer {:?}", note.path))?;
+            if current != note.original {
+                bail!("'{}' ha cambiado desde la vista previa", note.name);
+            }
+        }
+
+        let mut versions = Vec::new();
+        for note in &self.notes {
+            let version = NoteFile::open(&note.path)?
+                .backup(dir)?
+                .with_context(|| format!("No se pudo guardar '{}' en el historial", note.name))?;
+            versions.push((version, note.path.clone()));
+        }
+
+        let rewrites: Vec<(&NoteRewrite, &str, &str)> = self
+            .notes
+            .iter()
+            .map(|note| (note, note.original.as_str(), note.updated.as_str()))
+            .collect();
+        write_all(db, dir, &rewrites)?;
+        info!(
+            "Renombrado aplicado en {} notas: {:?}",
+            self.notes.len(),
+            self.refactor
+        );
+        Ok(versions)
+    }
+}
+
+/// Escribe (nota, texto actual, texto nuevo) y reindexa todo en una
+/// transacción. Si algo falla, lo ya escrito vuelve a su texto actual.
+fn write_all(
+    db: &NotesDatabase,
+    dir: &NotesDirectory,
+    rewrites: &[(&NoteRewrite, &str, &str)],
+) -> Result<()> {
+    let mut written = Vec::new();
+    let mut result: Result<()> = Ok(());
+    for (note, current, new) in rewrites {
+        if let Err(e) = fs::write(&note.path, new) {
+            result = Err(e).with_context(|| format!("No se pudo escribir '{}'", note.name));
+            break;
+        }
+        written.push((&note.path, *current));
+    }
+
+    if result.is_ok() {
+        result = db
+            .transaction(|db| {
+                for (note, _, new) in rewrites {
+                    let folder = dir.relative_folder(&note.path);
+                    db.index_note(
+                        &note.name,
+                        &note.path.to_string_lossy(),
+                        new,
+                        folder.as_deref(),
+                    )?;
+                }
+                Ok(())
+            })
+            .map_err(Into::into);
+    }
+
+    if let Err(e) = result {
+        for (path, current) in written {
+            if let Err(undo_error) = fs::write(path, current) {
+                error!("No se pudo devolver {:?} a su texto: {}", path, undo_error);
+            }
+        }
+        return Err(e);
+    }
+    Ok(())
+}
+
+/// Deshace un renombrado: cada nota vuelve a su copia del historial (la
+/// versión actual también se guarda antes en el historial). Todo o nada,
+/// como [`RefactorPlan::apply`]. Devuelve cuántas notas se restauraron.
+pub fn undo_refactor(
+    db: &NotesDatabase,
+    dir: &NotesDirectory,
+    versions: &[(PathBuf, PathBuf)],
+) -> Result<usize> {
+    let mut notes = Vec::new();
+    for (version, path) in versions {
+        let previous = fs::read_to_string(version)
+            .with_context(|| format!("No se encontró la copia {:?}", version))?;
+        let current = fs::read_to_string(path).unwrap_or_default();
+        let name = match db.get_note_by_path(&path.to_string_lossy())? {
+            Some(meta) => meta.name,
+            None => NoteFile::open(path)?.name().to_string(),
+        };
+        notes.push(NoteRewrite {
+            name,
+            path: path.clone(),
+            original: current,
+            updated: previous,
+        });
+    }
+
+    for note in &notes {
+        NoteFile::open(&note.path)?.backup(dir)?;
+    }
+    let rewrites: Vec<(&NoteRewrite, &str, &str)> = notes
+        .iter()
+        .map(|note| (note, note.original.as_str(), note.updated.as_str()))
+        .collect();
+    write_all(db, dir, &rewrites)?;
+    Ok(notes.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::temp_vault;
+
+    fn add(dir: &NotesDirectory, db: &NotesDatabase, name: &str, content: &str) -> PathBuf {
+        let note = dir.create_note(name, content).unwrap();
+        db.index_note(name, &note.path().to_string_lossy(), content, None)
+            .unwrap();
+        note.path().to_path_buf()
+    }
+
+    #[test]
+    fn test_parse_refactor_names() {
+        assert_eq!(
+            Refactor::rename_tag("#Proyecto", "#trabajo/"),
+            Some(Refactor::RenameTag {
+                from: "proyecto".into(),
+                to: "trabajo".into()
+            })
+        );
+        assert_eq!(Refactor::rename_tag("a", "A"), None);
+        assert_eq!(Refactor::rename_tag("a", "b c"), None);
+        assert_eq!(
+            Refactor::rename_mention("@Ana", "[[Ana López]]"),
+            Some(Refactor::RenameMention {
+                from: "Ana".into(),
+                to: "Ana López".into()
+            })
+        );
+        assert_eq!(Refactor::rename_mention("Ana", "@Ana"), None);
+    }
+
+    #[test]
+    fn test_plan_apply_and_undo() {
+        let (dir, db, base) = temp_vault("apply");
+        let uno = add(&dir, &db, "uno", "Hola #idea\nVer @Ana y [[Ana|ella]]\n");
+        let dos = add(&dir, &db, "dos", "---\ntags: [idea/vieja]\n---\nTexto\n");
+        add(&dir, &db, "tres", "Nada que ver con @Anabel\n");
+
+        let plan = RefactorPlan::new(&db, Refactor::rename_tag("idea", "plan").unwrap()).unwrap();
+        assert_eq!(plan.notes.len(), 2);
+        let changes = plan.notes[1].changed_lines();
+        assert_eq!(
+            changes,
+            vec![LineChange {
+                line: 1,
+                before: Some("Hola #idea".into()),
+                after: Some("Hola #plan".into())
+            }]
+        );
+
+        let versions = plan.apply(&db, &dir).unwrap();
+        assert_eq!(versions.len(), 2);
+        assert!(fs::read_to_string(&dos).unwrap().contains("plan/vieja"));
+        assert_eq!(
+            db.note_paths_with_tag("plan").unwrap().len(),
+            2,
+            "el índice sigue a los archivos"
+        );
+
+        let mentions =
+            RefactorPlan::new(&db, Refactor::rename_mention("Ana", "Ana López").unwrap()).unwrap();
+        assert_eq!(mentions.notes.len(), 1);
+        assert_eq!(mentions.line_count(), 1);
+        mentions.apply(&db, &dir).unwrap();
+        assert_eq!(
+            fs::read_to_string(&uno).unwrap(),
+            "Hola #plan\nVer @Ana López y [[Ana López|ella]]\n"
+        );
+
+        // Deshacer el renombrado del tag de una vez
+        assert_eq!(undo_refactor(&db, &dir, &versions).unwrap(), 2);
+        assert!(fs::read_to_string(&dos).unwrap().contains("idea/vieja"));
+        assert_eq!(db.note_paths_with_tag("idea").unwrap().len(), 2);
+
+        let _ = fs::remove_dir_all(&base);
+    }
+
+    #[test]
+    fn test_apply_is_all_or_nothing() {
+        let (dir, db, base) = temp_vault("atomic");
+        let uno = add(&dir, &db, "uno", "#idea uno\n");
+        let dos = add(&dir, &db, "dos", "#idea dos\n");
+        let refactor = Refactor::rename_tag("idea", "plan").unwrap();
+
+        // Una nota cambió después de la vista previa: no se toca ninguna
+        let plan = RefactorPlan::new(&db, refactor.clone()).unwrap();
+        fs::write(&dos, "#idea dos, editada\n").unwrap();
+        assert!(plan.apply(&db, &dir).is_err());
+        assert_eq!(fs::read_to_string(&uno).unwrap(), "#idea uno\n");
+
+        // Si el índice falla, los archivos vuelven a su texto
+        let plan = RefactorPlan::new(&db, refactor).unwrap();
+        let conn = rusqlite::Connection::open(db.path()).unwrap();
+        conn.execute_batch(
+            "CREATE TRIGGER romper BEFORE UPDATE ON notes BEGIN SELECT RAISE(ABORT, 'roto'); END;",
+        )
+        .unwrap();
+        assert!(plan.apply(&db, &dir).is_err());
+        assert_eq!(fs::read_to_string(&uno).unwrap(), "#idea uno\n");
+        assert_eq!(fs::read_to_string(&dos).unwrap(), "#idea dos, editada\n");
+
+        let _ = fs::remove_dir_all(&base);
+    }
+}
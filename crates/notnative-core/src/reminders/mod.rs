@@ -0,0 +1,12 @@
+//! Modelo de recordatorios y parser de la sintaxis `[!recordatorio ...]`
+//!
+//! La base de datos, el planificador y las notificaciones viven en la app.
+
+pub mod models;
+pub mod parser;
+
+pub use models::{
+    Priority, Reminder, ReminderDraft, ReminderHistoryEntry, ReminderOutcome, ReminderStatus,
+    RepeatPattern, history_to_csv,
+};
+pub use parser::{ParsedReminder, ReminderParser};
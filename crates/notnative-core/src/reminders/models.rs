@@ -0,0 +1,465 @@
+use chrono::{DateTime, Duration, Local, Utc};
+
+use crate::DateFormatter;
+use serde::{Deserialize, Serialize};
+
+/// Prioridad de un recordatorio
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Priority {
+    Low,
+    Medium,
+    High,
+    Urgent,
+}
+
+impl Priority {
+    /// Prioridad escrita en español o inglés (`alta`, `high`...); media si no se reconoce
+    pub fn from_label(s: &str) -> Self {
+        let s_lower = s.to_lowercase();
+        match s_lower.as_str() {
+            "baja" | "low" => Self::Low,
+            "media" | "medium" => Self::Medium,
+            "alta" | "high" => Self::High,
+            "urgente" | "urgent" => Self::Urgent,
+            _ => Self::Medium,
+        }
+    }
+
+    pub fn to_str(self, spanish: bool) -> &'static str {
+        match (self, spanish) {
+            (Self::Low, true) => "baja",
+            (Self::Low, false) => "low",
+            (Self::Medium, true) => "media",
+            (Self::Medium, false) => "medium",
+            (Self::High, true) => "alta",
+            (Self::High, false) => "high",
+            (Self::Urgent, true) => "urgente",
+            (Self::Urgent, false) => "urgent",
+        }
+    }
+
+    pub fn to_i32(self) -> i32 {
+        match self {
+            Self::Low => 0,
+            Self::Medium => 1,
+            Self::High => 2,
+            Self::Urgent => 3,
+        }
+    }
+
+    pub fn from_i32(value: i32) -> Self {
+        match value {
+            0 => Self::Low,
+            1 => Self::Medium,
+            2 => Self::High,
+            3 => Self::Urgent,
+            _ => Self::Medium,
+        }
+    }
+
+    /// Color GTK para la UI
+    pub fn color(&self) -> &'static str {
+        match self {
+            Self::Low => "#4a90e2",    // Azul
+            Self::Medium => "#f5a623", // Naranja
+            Self::High => "#e74c3c",   // Rojo
+            Self::Urgent => "#8e44ad", // Púrpura
+        }
+    }
+}
+
+/// Patrón de repetición
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RepeatPattern {
+    None,
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+impl RepeatPattern {
+    /// Repetición escrita en español o inglés (`diario`, `weekly`...); ninguna si no se reconoce
+    pub fn from_label(s: &str) -> Self {
+        let s_lower = s.to_lowercase();
+        match s_lower.as_str() {
+            "diario" | "daily" | "diariamente" => Self::Daily,
+            "semanal" | "weekly" | "semanalmente" => Self::Weekly,
+            "mensual" | "monthly" | "mensualmente" => Self::Monthly,
+            "ninguno" | "none" | "no" => Self::None,
+            _ => Self::None,
+        }
+    }
+
+    pub fn to_str(self, spanish: bool) -> &'static str {
+        match (self, spanish) {
+            (Self::None, true) => "ninguno",
+            (Self::None, false) => "none",
+            (Self::Daily, true) => "diario",
+            (Self::Daily, false) => "daily",
+            (Self::Weekly, true) => "semanal",
+            (Self::Weekly, false) => "weekly",
+            (Self::Monthly, true) => "mensual",
+            (Self::Monthly, false) => "monthly",
+        }
+    }
+
+    pub fn to_i32(self) -> i32 {
+        match self {
+            Self::None => 0,
+            Self::Daily => 1,
+            Self::Weekly => 2,
+            Self::Monthly => 3,
+        }
+    }
+
+    pub fn from_i32(value: i32) -> Self {
+        match value {
+            0 => Self::None,
+            1 => Self::Daily,
+            2 => Self::Weekly,
+            3 => Self::Monthly,
+            _ => Self::None,
+        }
+    }
+}
+
+/// Estado de un recordatorio
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReminderStatus {
+    Pending,
+    Completed,
+    Snoozed,
+}
+
+impl ReminderStatus {
+    pub fn to_str(self, spanish: bool) -> &'static str {
+        match (self, spanish) {
+            (Self::Pending, true) => "pendiente",
+            (Self::Pending, false) => "pending",
+            (Self::Completed, true) => "completado",
+            (Self::Completed, false) => "completed",
+            (Self::Snoozed, true) => "pospuesto",
+            (Self::Snoozed, false) => "snoozed",
+        }
+    }
+
+    pub fn to_i32(self) -> i32 {
+        match self {
+            Self::Pending => 0,
+            Self::Completed => 1,
+            Self::Snoozed => 2,
+        }
+    }
+
+    pub fn from_i32(value: i32) -> Self {
+        match value {
+            0 => Self::Pending,
+            1 => Self::Completed,
+            2 => Self::Snoozed,
+            _ => Self::Pending,
+        }
+    }
+}
+
+/// Recordatorio completo
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Reminder {
+    pub id: i64,
+    pub note_id: Option<i64>,
+    pub title: String,
+    pub description: Option<String>,
+    pub due_date: DateTime<Utc>,
+    pub priority: Priority,
+    pub status: ReminderStatus,
+    pub snooze_until: Option<DateTime<Utc>>,
+    pub repeat_pattern: RepeatPattern,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    /// Nota donde está escrito el recordatorio (si salió de una nota)
+    pub source_path: Option<String>,
+    /// Línea (desde 1) del recordatorio dentro de `source_path`
+    pub source_line: Option<usize>,
+}
+
+impl Reminder {
+    /// Verifica si el recordatorio debe dispararse ahora
+    pub fn should_trigger(&self) -> bool {
+        if self.status == ReminderStatus::Completed {
+            return false;
+        }
+
+        let now = Utc::now();
+
+        // Si está pospuesto, verificar la fecha de snooze
+        if let Some(snooze) = self.snooze_until {
+            return now >= snooze;
+        }
+
+        // Verificar la fecha de vencimiento
+        now >= self.due_date
+    }
+
+    /// Calcula la próxima fecha según el patrón de repetición
+    pub fn next_occurrence(&self) -> Option<DateTime<Utc>> {
+        use chrono::Duration;
+
+        match self.repeat_pattern {
+            RepeatPattern::None => None,
+            RepeatPattern::Daily => Some(self.due_date + Duration::days(1)),
+            RepeatPattern::Weekly => Some(self.due_date + Duration::weeks(1)),
+            RepeatPattern::Monthly => {
+                // Aproximación: 30 días
+                Some(self.due_date + Duration::days(30))
+            }
+        }
+    }
+
+    /// Formatea la fecha para mostrar en UI según el locale activo
+    /// ("Hoy a las 10:00", "Tomorrow at 9:00 AM", "15 nov 2025, 10:00")
+    pub fn format_due_date(&self) -> String {
+        use chrono::Local;
+
+        let local_time = self.due_date.with_timezone(&Local).naive_local();
+        let now = Local::now().naive_local();
+
+        DateFormatter::global().format_relative(local_time, now)
+    }
+
+    /// Verifica si está vencido
+    pub fn is_overdue(&self) -> bool {
+        if self.status == ReminderStatus::Completed {
+            return false;
+        }
+
+        let now = Utc::now();
+
+        if let Some(snooze) = self.snooze_until {
+            return now > snooze;
+        }
+
+        now > self.due_date
+    }
+}
+
+/// Recordatorio que propone el agente del chat: el usuario lo revisa (y puede
+/// editarlo) en una tarjeta antes de que se escriba en la nota
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReminderDraft {
+    /// Nota donde se añadirá la línea del recordatorio
+    pub note_name: String,
+    pub title: String,
+    pub due_date: DateTime<Utc>,
+    pub priority: Priority,
+    pub repeat_pattern: RepeatPattern,
+}
+
+impl ReminderDraft {
+    /// Línea `!!RECORDAR(...)` con la fecha absoluta en hora local; la
+    /// prioridad media y "sin repetición" se omiten
+    pub fn to_note_line(&self) -> String {
+        let mut params = self
+            .due_date
+            .with_timezone(&Local)
+            .format("%Y-%m-%d %H:%M")
+            .to_string();
+        if self.priority != Priority::Medium {
+            params.push(' ');
+            params.push_str(self.priority.to_str(true));
+        }
+        if self.repeat_pattern != RepeatPattern::None {
+            params.push_str(" repetir=");
+            params.push_str(self.repeat_pattern.to_str(true));
+        }
+        format!("!!RECORDAR({}, {})", params, self.title)
+    }
+}
+
+/// Horas que puede quedar sin resolver un recordatorio que saltó antes de
+/// contar como ignorado
+pub const MISSED_AFTER_HOURS: i64 = 24;
+
+/// Cómo acabó un recordatorio que saltó
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReminderOutcome {
+    /// Saltó y todavía no se ha hecho nada
+    Open,
+    Completed,
+    /// Se eliminó (o se quitó de la nota) sin completarlo
+    Dismissed,
+    /// Siguió sin resolver pasadas [`MISSED_AFTER_HOURS`] horas
+    Missed,
+}
+
+impl ReminderOutcome {
+    pub fn to_str(self, spanish: bool) -> &'static str {
+        match (self, spanish) {
+            (Self::Open, true) => "sin resolver",
+            (Self::Open, false) => "open",
+            (Self::Completed, true) => "completado",
+            (Self::Completed, false) => "completed",
+            (Self::Dismissed, true) => "descartado",
+            (Self::Dismissed, false) => "dismissed",
+            (Self::Missed, true) => "ignorado",
+            (Self::Missed, false) => "missed",
+        }
+    }
+
+    pub fn to_i32(self) -> i32 {
+        match self {
+            Self::Open => 0,
+            Self::Completed => 1,
+            Self::Dismissed => 2,
+            Self::Missed => 3,
+        }
+    }
+
+    pub fn from_i32(value: i32) -> Self {
+        match value {
+            1 => Self::Completed,
+            2 => Self::Dismissed,
+            3 => Self::Missed,
+            _ => Self::Open,
+        }
+    }
+}
+
+/// Entrada del historial: un recordatorio que saltó y qué se hizo con él.
+/// Se conserva aunque el recordatorio se elimine.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReminderHistoryEntry {
+    pub id: i64,
+    pub reminder_id: i64,
+    pub note_id: Option<i64>,
+    pub title: String,
+    pub due_date: DateTime<Utc>,
+    pub fired_at: DateTime<Utc>,
+    /// Veces que se pospuso después de saltar
+    pub snooze_count: u32,
+    /// Resultado guardado (`Open` mientras nadie lo resuelva)
+    pub outcome: ReminderOutcome,
+    pub resolved_at: Option<DateTime<Utc>>,
+}
+
+impl ReminderHistoryEntry {
+    /// Resultado en `now`: uno abierto desde hace más de
+    /// [`MISSED_AFTER_HOURS`] horas cuenta como ignorado
+    pub fn outcome_at(&self, now: DateTime<Utc>) -> ReminderOutcome {
+        if self.outcome == ReminderOutcome::Open
+            && now - self.fired_at > Duration::hours(MISSED_AFTER_HOURS)
+        {
+            ReminderOutcome::Missed
+        } else {
+            self.outcome
+        }
+    }
+
+    /// Búsqueda en el historial: por título o por resultado
+    pub fn matches(&self, query: &str, now: DateTime<Utc>, spanish: bool) -> bool {
+        let query = query.trim().to_lowercase();
+        query.is_empty()
+            || self.title.to_lowercase().contains(&query)
+            || self.outcome_at(now).to_str(spanish).contains(&query)
+    }
+}
+
+/// Historial en CSV (título, vencimiento, disparo, veces pospuesto, resultado
+/// y resolución), con las fechas en hora local
+pub fn history_to_csv(
+    entries: &[ReminderHistoryEntry],
+    now: DateTime<Utc>,
+    spanish: bool,
+) -> String {
+    fn local(date: DateTime<Utc>) -> String {
+        date.with_timezone(&chrono::Local)
+            .format("%Y-%m-%d %H:%M")
+            .to_string()
+    }
+
+    fn field(value: &str) -> String {
+        if value.contains([',', '"', '\n']) {
+            format!("\"{}\"", value.replace('"', "\"\""))
+        } else {
+            value.to_string()
+        }
+    }
+
+    let mut csv = String::from("title,due_date,fired_at,snoozed,outcome,resolved_at\n");
+    for entry in entries {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{}\n",
+            field(&entry.title),
+            local(entry.due_date),
+            local(entry.fired_at),
+            entry.snooze_count,
+            entry.outcome_at(now).to_str(spanish),
+            entry.resolved_at.map(local).unwrap_or_default()
+        ));
+    }
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(title: &str, fired_hours_ago: i64, outcome: ReminderOutcome) -> ReminderHistoryEntry {
+        let now = Utc::now();
+        ReminderHistoryEntry {
+            id: 1,
+            reminder_id: 1,
+            note_id: None,
+            title: title.to_string(),
+            due_date: now - Duration::hours(fired_hours_ago),
+            fired_at: now - Duration::hours(fired_hours_ago),
+            snooze_count: 2,
+            outcome,
+            resolved_at: None,
+        }
+    }
+
+    #[test]
+    fn test_history_outcome() {
+        let now = Utc::now();
+        assert_eq!(
+            entry("Hoy", 2, ReminderOutcome::Open).outcome_at(now),
+            ReminderOutcome::Open
+        );
+        assert_eq!(
+            entry("Semana pasada", 24 * 7, ReminderOutcome::Open).outcome_at(now),
+            ReminderOutcome::Missed
+        );
+        // Lo resuelto no caduca
+        assert_eq!(
+            entry("Hecho", 24 * 7, ReminderOutcome::Completed).outcome_at(now),
+            ReminderOutcome::Completed
+        );
+        for outcome in [
+            ReminderOutcome::Open,
+            ReminderOutcome::Completed,
+            ReminderOutcome::Dismissed,
+            ReminderOutcome::Missed,
+        ] {
+            assert_eq!(ReminderOutcome::from_i32(outcome.to_i32()), outcome);
+        }
+    }
+
+    #[test]
+    fn test_history_search_and_csv() {
+        let now = Utc::now();
+        let missed = entry("Llamar, al \"banco\"", 48, ReminderOutcome::Open);
+        assert!(missed.matches("BANCO", now, true));
+        assert!(missed.matches("ignorado", now, true));
+        assert!(missed.matches("missed", now, false));
+        assert!(!missed.matches("completado", now, true));
+
+        let csv = history_to_csv(&[missed], now, false);
+        let mut lines = csv.lines();
+        assert_eq!(
+            lines.next(),
+            Some("title,due_date,fired_at,snoozed,outcome,resolved_at")
+        );
+        let row = lines.next().unwrap();
+        assert!(row.starts_with("\"Llamar, al \"\"banco\"\"\","));
+        assert!(row.ends_with(",2,missed,"));
+    }
+}
@@ -1,12 +1,10 @@
 use anyhow::{Result, anyhow};
-use chrono::{
-    DateTime, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike, Utc,
-};
+use chrono::{DateTime, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
 use regex::Regex;
 use std::sync::LazyLock;
 
-use super::models::{Priority, RepeatPattern};
-use crate::i18n::Language;
+use super::models::{Priority, ReminderDraft, RepeatPattern};
+use crate::language::Language;
 
 // ============================================================================
 // REGEX ESTÁTICOS - Compilados una sola vez para mejor rendimiento
@@ -32,6 +30,13 @@ pub struct ParsedReminder {
     pub priority: Priority,
     pub repeat_pattern: RepeatPattern,
     pub original_text: String,
+    /// Línea (desde 1) del texto donde está escrito
+    pub line: usize,
+}
+
+/// Línea (desde 1) en la que cae el byte `offset` de `text`
+fn line_at(text: &str, offset: usize) -> usize {
+    text[..offset].matches('\n').count() + 1
 }
 
 /// Parser de recordatorios en markdown
@@ -52,9 +57,10 @@ impl ReminderParser {
             let params = cap.get(1).map_or("", |m| m.as_str());
             let title = cap.get(2).map_or("", |m| m.as_str()).trim();
             let original = cap.get(0).map_or("", |m| m.as_str());
+            let line = cap.get(0).map_or(1, |m| line_at(text, m.start()));
 
             // Usar idioma actual o intentar detectar (el formato interno es agnóstico)
-            if let Ok(parsed) = self.parse_params(params, title, original, language) {
+            if let Ok(parsed) = self.parse_params(params, title, original, line, language) {
                 reminders.push(parsed);
             }
         }
@@ -64,8 +70,9 @@ impl ReminderParser {
             let params = cap.get(1).map_or("", |m| m.as_str());
             let title = cap.get(2).map_or("", |m| m.as_str()).trim();
             let original = cap.get(0).map_or("", |m| m.as_str());
+            let line = cap.get(0).map_or(1, |m| line_at(text, m.start()));
 
-            if let Ok(parsed) = self.parse_params(params, title, original, language) {
+            if let Ok(parsed) = self.parse_params(params, title, original, line, language) {
                 reminders.push(parsed);
             }
         }
@@ -75,8 +82,10 @@ impl ReminderParser {
             let params = cap.get(1).map_or("", |m| m.as_str());
             let title = cap.get(2).map_or("", |m| m.as_str()).trim();
             let original = cap.get(0).map_or("", |m| m.as_str());
+            let line = cap.get(0).map_or(1, |m| line_at(text, m.start()));
 
-            if let Ok(parsed) = self.parse_params(params, title, original, Language::English) {
+            if let Ok(parsed) = self.parse_params(params, title, original, line, Language::English)
+            {
                 reminders.push(parsed);
             }
         }
@@ -84,12 +93,50 @@ impl ReminderParser {
         reminders
     }
 
+    /// Borrador de recordatorio con lo que pide el agente del chat: la fecha en
+    /// texto libre (`mañana 10:00`, `2026-10-20 15:00`) y la prioridad y la
+    /// repetición opcionales. Error si la fecha no se entiende.
+    pub fn parse_draft(
+        &self,
+        note_name: &str,
+        title: &str,
+        date: &str,
+        priority: Option<&str>,
+        repeat: Option<&str>,
+        language: Language,
+    ) -> Result<ReminderDraft> {
+        let title = title.trim();
+        if title.is_empty() {
+            return Err(anyhow!("El recordatorio no tiene texto"));
+        }
+
+        let mut params = date.trim().to_string();
+        if let Some(priority) = priority.map(str::trim).filter(|p| !p.is_empty()) {
+            params.push(' ');
+            params.push_str(priority);
+        }
+        if let Some(repeat) = repeat.map(str::trim).filter(|r| !r.is_empty()) {
+            params.push_str(" repeat=");
+            params.push_str(repeat);
+        }
+
+        let parsed = self.parse_params(&params, title, "", 1, language)?;
+        Ok(ReminderDraft {
+            note_name: note_name.trim().to_string(),
+            title: parsed.title,
+            due_date: parsed.due_date,
+            priority: parsed.priority,
+            repeat_pattern: parsed.repeat_pattern,
+        })
+    }
+
     /// Parsea los parámetros de un recordatorio
     fn parse_params(
         &self,
         params: &str,
         title: &str,
         original: &str,
+        line: usize,
         language: Language,
     ) -> Result<ParsedReminder> {
         let parts: Vec<&str> = params.split_whitespace().collect();
@@ -113,19 +160,19 @@ impl ReminderParser {
                 part_lower.as_str(),
                 "baja" | "low" | "media" | "medium" | "alta" | "high" | "urgente" | "urgent"
             ) {
-                priority = Priority::from_str(part);
+                priority = Priority::from_label(part);
             }
 
             // Detectar patrón de repetición
             if part_lower.starts_with("repetir=") || part_lower.starts_with("repeat=") {
                 if let Some(pattern_str) = part_lower.split('=').nth(1) {
-                    repeat_pattern = RepeatPattern::from_str(pattern_str);
+                    repeat_pattern = RepeatPattern::from_label(pattern_str);
                 }
             } else if matches!(
                 part_lower.as_str(),
                 "diario" | "daily" | "semanal" | "weekly" | "mensual" | "monthly"
             ) {
-                repeat_pattern = RepeatPattern::from_str(part);
+                repeat_pattern = RepeatPattern::from_label(part);
             }
         }
 
@@ -135,6 +182,7 @@ impl ReminderParser {
             priority,
             repeat_pattern,
             original_text: original.to_string(),
+            line,
         })
     }
 
@@ -284,6 +332,56 @@ mod tests {
         assert_eq!(reminders[0].priority, Priority::Medium);
     }
 
+    #[test]
+    fn test_parse_source_line() {
+        let parser = ReminderParser::new();
+        let text = "# Tareas\n\n- !!RECORDAR(hoy 15:00, Llamar)\n\n!!REMIND(tomorrow 09:00, Call)";
+
+        let reminders = parser.extract_reminders(text, Language::Spanish);
+        assert_eq!(reminders.len(), 2);
+        assert_eq!(reminders[0].line, 3);
+        assert_eq!(reminders[1].line, 5);
+    }
+
+    #[test]
+    fn test_parse_draft() {
+        let parser = ReminderParser::new();
+        let draft = parser
+            .parse_draft(
+                "Casa",
+                " Regar plantas ",
+                "2026-10-20 18:30",
+                Some("high"),
+                Some("weekly"),
+                Language::English,
+            )
+            .unwrap();
+        assert_eq!(draft.title, "Regar plantas");
+        assert_eq!(draft.priority, Priority::High);
+        assert_eq!(draft.repeat_pattern, RepeatPattern::Weekly);
+        assert_eq!(
+            draft.to_note_line(),
+            "!!RECORDAR(2026-10-20 18:30 alta repetir=semanal, Regar plantas)"
+        );
+
+        // La línea que se escribe en la nota vuelve a dar el mismo recordatorio
+        let reminders = parser.extract_reminders(&draft.to_note_line(), Language::Spanish);
+        assert_eq!(reminders.len(), 1);
+        assert_eq!(reminders[0].due_date, draft.due_date);
+        assert_eq!(reminders[0].repeat_pattern, RepeatPattern::Weekly);
+
+        assert!(
+            parser
+                .parse_draft("Casa", "Algo", "el viernes", None, None, Language::Spanish)
+                .is_err()
+        );
+        assert!(
+            parser
+                .parse_draft("Casa", " ", "hoy 10:00", None, None, Language::Spanish)
+                .is_err()
+        );
+    }
+
     #[test]
     fn test_parse_english_tomorrow() {
         let parser = ReminderParser::new();
@@ -0,0 +1,275 @@
+//! Reintentos, límite de peticiones y cortacircuitos para los proveedores de IA
+//!
+//! Solo la lógica, sin red ni reloj propio: cada método recibe el instante
+//! actual, así que se puede probar sin esperar. La app la usa alrededor de
+//! cada llamada al proveedor:
+//!
+//! - [`RetryPolicy`]: cuánto esperar antes de reintentar un 429 o un 5xx
+//!   (espera exponencial con tope).
+//! - [`RateLimiter`]: peticiones por minuto que permite el proveedor.
+//! - [`CircuitBreaker`]: tras varios fallos seguidos deja de llamar un rato
+//!   en lugar de insistir; luego deja pasar una petición de prueba.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// Reintentos ante errores pasajeros
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Espera antes del reintento número `attempt` (0 = el primero):
+    /// `base_delay`, el doble, el cuádruple... hasta `max_delay`
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let factor = 1u32.checked_shl(attempt).unwrap_or(u32::MAX);
+        self.base_delay
+            .checked_mul(factor)
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay)
+    }
+}
+
+/// ¿Merece la pena reintentar? Límite de peticiones (429), errores del
+/// servidor (5xx) y cortes de red. Los errores de clave o de petición mal
+/// formada fallarían igual.
+pub fn is_retryable_error(message: &str) -> bool {
+    let message = message.to_lowercase();
+    const STATUS_CODES: &[&str] = &["429", "500", "502", "503", "504", "529"];
+    const PHRASES: &[&str] = &[
+        "rate limit",
+        "too many requests",
+        "overloaded",
+        "timed out",
+        "timeout",
+        "connection",
+        "temporarily unavailable",
+        "service unavailable",
+        "bad gateway",
+    ];
+
+    STATUS_CODES.iter().any(|code| {
+        message.match_indices(code).any(|(start, _)| {
+            let end = start + code.len();
+            !message[..start]
+                .chars()
+                .next_back()
+                .is_some_and(|c| c.is_ascii_digit())
+                && !message[end..]
+                    .chars()
+                    .next()
+                    .is_some_and(|c| c.is_ascii_digit())
+        })
+    }) || PHRASES.iter().any(|phrase| message.contains(phrase))
+}
+
+/// Ventana deslizante de un minuto
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    per_minute: u32,
+    sent: VecDeque<Instant>,
+}
+
+const WINDOW: Duration = Duration::from_secs(60);
+
+impl RateLimiter {
+    pub fn new(per_minute: u32) -> Self {
+        Self {
+            per_minute: per_minute.max(1),
+            sent: VecDeque::new(),
+        }
+    }
+
+    pub fn per_minute(&self) -> u32 {
+        self.per_minute
+    }
+
+    /// Apunta una petición si cabe en el último minuto; si no, cuánto falta
+    /// para que quepa
+    pub fn try_acquire(&mut self, now: Instant) -> Result<(), Duration> {
+        while self
+            .sent
+            .front()
+            .is_some_and(|sent| now.duration_since(*sent) >= WINDOW)
+        {
+            self.sent.pop_front();
+        }
+        if self.sent.len() < self.per_minute as usize {
+            self.sent.push_back(now);
+            return Ok(());
+        }
+        let oldest = self.sent.front().copied().unwrap_or(now);
+        Err(WINDOW.saturating_sub(now.duration_since(oldest)))
+    }
+}
+
+/// Estado del cortacircuitos
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Las peticiones pasan
+    Closed,
+    /// Demasiados fallos: no se llama hasta que pase `retry_in`
+    Open { retry_in: Duration },
+    /// Pasó la pausa: se deja una petición de prueba
+    HalfOpen,
+}
+
+#[derive(Debug, Clone)]
+pub struct CircuitBreaker {
+    threshold: u32,
+    cooldown: Duration,
+    failures: u32,
+    opened_at: Option<Instant>,
+    probing: bool,
+}
+
+impl Default for CircuitBreaker {
+    /// Se abre con 5 fallos seguidos, durante 30 segundos
+    fn default() -> Self {
+        Self::new(5, Duration::from_secs(30))
+    }
+}
+
+impl CircuitBreaker {
+    pub fn new(threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            threshold: threshold.max(1),
+            cooldown,
+            failures: 0,
+            opened_at: None,
+            probing: false,
+        }
+    }
+
+    pub fn state(&self, now: Instant) -> CircuitState {
+        match self.opened_at {
+            None => CircuitState::Closed,
+            Some(opened_at) => {
+                let elapsed = now.duration_since(opened_at);
+                if elapsed >= self.cooldown {
+                    CircuitState::HalfOpen
+                } else {
+                    CircuitState::Open {
+                        retry_in: self.cooldown - elapsed,
+                    }
+                }
+            }
+        }
+    }
+
+    /// ¿Puede salir una petición? Con el circuito medio abierto solo pasa
+    /// una hasta que se sepa cómo acaba.
+    pub fn allow(&mut self, now: Instant) -> bool {
+        match self.state(now) {
+            CircuitState::Closed => true,
+            CircuitState::Open { .. } => false,
+            CircuitState::HalfOpen if self.probing => false,
+            CircuitState::HalfOpen => {
+                self.probing = true;
+                true
+            }
+        }
+    }
+
+    pub fn record_success(&mut self) {
+        self.failures = 0;
+        self.opened_at = None;
+        self.probing = false;
+    }
+
+    /// Un fallo más; al llegar al umbral (o si falla la prueba) se abre
+    pub fn record_failure(&mut self, now: Instant) {
+        self.failures += 1;
+        if self.failures >= self.threshold || self.probing {
+            self.opened_at = Some(now);
+        }
+        self.probing = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_is_exponential_and_capped() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.delay(0), Duration::from_secs(1));
+        assert_eq!(policy.delay(1), Duration::from_secs(2));
+        assert_eq!(policy.delay(3), Duration::from_secs(8));
+        assert_eq!(policy.delay(10), Duration::from_secs(30));
+        assert_eq!(policy.delay(40), Duration::from_secs(30));
+    }
+
+    #[test]
+    fn test_retryable_errors() {
+        assert!(is_retryable_error(
+            "OpenRouter respondió 429 Too Many Requests: {}"
+        ));
+        assert!(is_retryable_error("HTTP status server error (503)"));
+        assert!(is_retryable_error(
+            "error sending request: connection reset"
+        ));
+        assert!(!is_retryable_error("OpenRouter respondió 401 Unauthorized"));
+        assert!(!is_retryable_error("invalid model id"));
+        // Números que solo contienen el código no cuentan
+        assert!(!is_retryable_error("max_tokens 4290 excede el límite"));
+    }
+
+    #[test]
+    fn test_rate_limiter_window() {
+        let start = Instant::now();
+        let mut limiter = RateLimiter::new(2);
+        assert!(limiter.try_acquire(start).is_ok());
+        assert!(limiter.try_acquire(start + Duration::from_secs(10)).is_ok());
+        assert_eq!(
+            limiter.try_acquire(start + Duration::from_secs(20)),
+            Err(Duration::from_secs(40))
+        );
+        // Al minuto de la primera vuelve a haber hueco
+        assert!(limiter.try_acquire(start + Duration::from_secs(60)).is_ok());
+    }
+
+    #[test]
+    fn test_circuit_breaker() {
+        let start = Instant::now();
+        let mut breaker = CircuitBreaker::new(2, Duration::from_secs(30));
+        breaker.record_failure(start);
+        assert!(breaker.allow(start));
+        breaker.record_failure(start);
+        assert_eq!(
+            breaker.state(start + Duration::from_secs(10)),
+            CircuitState::Open {
+                retry_in: Duration::from_secs(20)
+            }
+        );
+        assert!(!breaker.allow(start + Duration::from_secs(10)));
+
+        // Tras la pausa pasa una sola petición de prueba
+        let later = start + Duration::from_secs(30);
+        assert!(breaker.allow(later));
+        assert!(!breaker.allow(later));
+        // Si falla, otra pausa entera
+        breaker.record_failure(later);
+        assert!(!breaker.allow(later + Duration::from_secs(29)));
+
+        let recovered = later + Duration::from_secs(30);
+        assert!(breaker.allow(recovered));
+        breaker.record_success();
+        assert_eq!(breaker.state(recovered), CircuitState::Closed);
+        assert!(breaker.allow(recovered));
+    }
+}
@@ -0,0 +1,86 @@
+//! Estadísticas de recursos para el modo de perfilado de memoria
+//!
+//! Con `--profile-memory` (o `NOTNATIVE_PROFILE_MEMORY=1`) la app muestra un panel
+//! con el número de widgets y WebViews vivos, los videos embebidos cargados y la
+//! memoria residente del proceso.
+
+/// Contadores mostrados en el panel de depuración
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResourceStats {
+    pub widgets: usize,
+    pub webviews: usize,
+    pub embeds_loaded: usize,
+    pub embeds_total: usize,
+    pub embed_budget: usize,
+    /// Memoria residente (VmRSS) en KiB, si se pudo leer
+    pub rss_kb: Option<u64>,
+}
+
+impl ResourceStats {
+    /// Texto del panel, una métrica por línea
+    pub fn summary(&self) -> String {
+        let rss = match self.rss_kb {
+            Some(kb) => format!("{:.1} MiB", kb as f64 / 1024.0),
+            None => "?".to_string(),
+        };
+        format!(
+            "Widgets: {}\nWebViews: {}\nEmbeds: {}/{} (límite {})\nRSS: {}",
+            self.widgets,
+            self.webviews,
+            self.embeds_loaded,
+            self.embeds_total,
+            self.embed_budget,
+            rss
+        )
+    }
+}
+
+/// Si se pidió el modo de perfilado de memoria al arrancar
+pub fn profiling_requested() -> bool {
+    std::env::args().any(|arg| arg == "--profile-memory")
+        || std::env::var("NOTNATIVE_PROFILE_MEMORY").is_ok_and(|value| value != "0")
+}
+
+/// Extrae VmRSS (KiB) del contenido de `/proc/<pid>/status`
+pub fn parse_vm_rss(status: &str) -> Option<u64> {
+    status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmRSS:"))
+        .and_then(|value| value.split_whitespace().next())
+        .and_then(|kb| kb.parse().ok())
+}
+
+/// Memoria residente del proceso actual en KiB (solo Linux)
+pub fn resident_memory_kb() -> Option<u64> {
+    std::fs::read_to_string("/proc/self/status")
+        .ok()
+        .and_then(|status| parse_vm_rss(&status))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_vm_rss() {
+        let status = "Name:\tnotnative\nVmPeak:\t  912000 kB\nVmRSS:\t  204800 kB\nThreads:\t12\n";
+        assert_eq!(parse_vm_rss(status), Some(204800));
+        assert_eq!(parse_vm_rss("Name:\tnotnative\n"), None);
+    }
+
+    #[test]
+    fn test_summary() {
+        let stats = ResourceStats {
+            widgets: 120,
+            webviews: 2,
+            embeds_loaded: 1,
+            embeds_total: 5,
+            embed_budget: 3,
+            rss_kb: Some(204800),
+        };
+        let summary = stats.summary();
+        assert!(summary.contains("WebViews: 2"));
+        assert!(summary.contains("Embeds: 1/5 (límite 3)"));
+        assert!(summary.contains("RSS: 200.0 MiB"));
+    }
+}
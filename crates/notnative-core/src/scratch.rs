@@ -0,0 +1,123 @@
+//! Nota borrador (`:scratch`)
+//!
+//! Texto de usar y tirar que solo vive en memoria: no es un archivo del vault,
+//! así que no sale en el sidebar ni en la búsqueda, no se indexa y la IA no lo
+//! ve. Con copia de recuperación se guarda además en la caché, para no
+//! perderlo si la app se cierra de golpe; al salir bien se borra.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::warn;
+
+#[derive(Debug, Default)]
+pub struct ScratchBuffer {
+    text: String,
+    /// Copia para recuperar el texto tras un cierre inesperado
+    backup: Option<PathBuf>,
+}
+
+impl ScratchBuffer {
+    /// Ruta de la copia de recuperación
+    pub fn default_backup_path() -> PathBuf {
+        dirs::cache_dir()
+            .unwrap_or_else(std::env::temp_dir)
+            .join("notnative")
+            .join("scratch.md")
+    }
+
+    /// Borrador vacío, o con el texto que quedó en `backup` si la app no se
+    /// cerró bien. Sin `backup`, solo en memoria.
+    pub fn new(backup: Option<PathBuf>) -> Self {
+        let text = backup
+            .as_deref()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .unwrap_or_default();
+        Self { text, backup }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Guarda el texto del borrador (y su copia, si la tiene)
+    pub fn set_text(&mut self, text: &str) {
+        if self.text == text {
+            return;
+        }
+        self.text = text.to_string();
+        if let Some(path) = &self.backup {
+            if let Err(e) = write_backup(path, text) {
+                warn!("No se pudo guardar la copia del borrador: {}", e);
+            }
+        }
+    }
+
+    /// Activa o quita la copia de recuperación
+    pub fn set_backup(&mut self, backup: Option<PathBuf>) {
+        self.discard_backup();
+        self.backup = backup;
+        if let Some(path) = &self.backup {
+            if let Err(e) = write_backup(path, &self.text) {
+                warn!("No se pudo guardar la copia del borrador: {}", e);
+            }
+        }
+    }
+
+    /// Borra la copia de recuperación (al cerrar la app sin problemas)
+    pub fn discard_backup(&self) {
+        if let Some(path) = &self.backup {
+            let _ = fs::remove_file(path);
+        }
+    }
+}
+
+/// Un borrador vacío no deja archivo
+fn write_backup(path: &Path, text: &str) -> std::io::Result<()> {
+    if text.is_empty() {
+        return match fs::remove_file(path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        };
+    }
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scratch_backup() {
+        let path = std::env::temp_dir().join(format!(
+            "notnative-scratch-{}/scratch.md",
+            std::process::id()
+        ));
+        let _ = fs::remove_file(&path);
+
+        let mut scratch = ScratchBuffer::new(Some(path.clone()));
+        assert_eq!(scratch.text(), "");
+        scratch.set_text("idea suelta");
+        assert_eq!(fs::read_to_string(&path).unwrap(), "idea suelta");
+
+        // Tras un cierre inesperado se recupera
+        let mut restored = ScratchBuffer::new(Some(path.clone()));
+        assert_eq!(restored.text(), "idea suelta");
+        restored.set_text("");
+        assert!(!path.exists());
+
+        // Solo en memoria
+        restored.set_text("otra");
+        restored.set_backup(None);
+        assert!(!path.exists());
+        assert_eq!(restored.text(), "otra");
+        restored.set_backup(Some(path.clone()));
+        assert!(path.exists());
+        restored.discard_backup();
+        assert!(!path.exists());
+
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+}
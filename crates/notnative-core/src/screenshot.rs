@@ -0,0 +1,160 @@
+//! Capturas de pantalla a una nota
+//!
+//! Se elige una zona con `slurp` y se captura con `grim` (las herramientas que
+//! usa Omarchy); sin ellas, con el portal de capturas. La imagen se guarda en
+//! assets (`screenshot_20261016_093000.png`) y, antes de insertarla, se le
+//! pueden dibujar flechas, recuadros y textos encima.
+
+use std::path::Path;
+
+/// Inicio del nombre de archivo de las capturas
+pub const SCREENSHOT_PREFIX: &str = "screenshot_";
+
+/// Dónde va la captura
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScreenshotTarget {
+    /// En la posición del cursor de la nota abierta
+    Cursor,
+    /// En una quick note nueva (desde la bandeja o el archivo de control)
+    QuickNote,
+}
+
+/// Qué dibuja una anotación
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnnotationKind {
+    /// Flecha de `from` a `to` (la punta en `to`)
+    Arrow,
+    /// Recuadro con esquinas opuestas en `from` y `to`
+    Rect,
+    /// Texto con la esquina inferior izquierda en `from`
+    Text(String),
+}
+
+/// Anotación sobre la captura, en píxeles de la imagen
+#[derive(Debug, Clone, PartialEq)]
+pub struct Annotation {
+    pub kind: AnnotationKind,
+    pub from: (f64, f64),
+    pub to: (f64, f64),
+    /// Color CSS
+    pub color: String,
+}
+
+impl Annotation {
+    /// Extremos de los dos lados de la punta de una flecha de `size` píxeles;
+    /// `None` si la flecha no tiene largo
+    pub fn arrow_head(&self, size: f64) -> Option<[(f64, f64); 2]> {
+        let (dx, dy) = (self.to.0 - self.from.0, self.to.1 - self.from.1);
+        if dx.hypot(dy) < f64::EPSILON {
+            return None;
+        }
+        let angle = dy.atan2(dx);
+        let side = |offset: f64| {
+            (
+                self.to.0 - size * (angle + offset).cos(),
+                self.to.1 - size * (angle + offset).sin(),
+            )
+        };
+        let spread = std::f64::consts::PI / 7.0;
+        Some([side(spread), side(-spread)])
+    }
+
+    /// Recuadro como `(x, y, ancho, alto)` con ancho y alto positivos,
+    /// se haya arrastrado en la dirección que sea
+    pub fn rect(&self) -> (f64, f64, f64, f64) {
+        (
+            self.from.0.min(self.to.0),
+            self.from.1.min(self.to.1),
+            (self.to.0 - self.from.0).abs(),
+            (self.to.1 - self.from.1).abs(),
+        )
+    }
+
+    /// Un arrastre de menos de `min` píxeles no deja flecha ni recuadro
+    pub fn is_too_small(&self, min: f64) -> bool {
+        match self.kind {
+            AnnotationKind::Text(ref text) => text.trim().is_empty(),
+            _ => (self.to.0 - self.from.0).hypot(self.to.1 - self.from.1) < min,
+        }
+    }
+}
+
+/// Zona que devuelve `slurp` (`x,y anchoxalto`) lista para `grim -g`;
+/// `None` si no se entiende o no tiene área
+pub fn parse_region(output: &str) -> Option<String> {
+    let region = output.trim();
+    let (position, size) = region.split_once(' ')?;
+    let (x, y) = position.split_once(',')?;
+    let (width, height) = size.split_once('x')?;
+    x.parse::<i32>().ok()?;
+    y.parse::<i32>().ok()?;
+    let (width, height) = (width.parse::<u32>().ok()?, height.parse::<u32>().ok()?);
+    (width > 0 && height > 0).then(|| region.to_string())
+}
+
+/// Markdown con el que se enlaza una captura guardada en `path`
+pub fn image_markdown(path: &Path) -> String {
+    format!(
+        "![{}]({})",
+        path.file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or("screenshot"),
+        path.to_string_lossy()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn annotation(kind: AnnotationKind, from: (f64, f64), to: (f64, f64)) -> Annotation {
+        Annotation {
+            kind,
+            from,
+            to,
+            color: "#d20f39".into(),
+        }
+    }
+
+    #[test]
+    fn test_annotation_geometry() {
+        // Flecha hacia la derecha: la punta se abre hacia atrás, simétrica
+        let arrow = annotation(AnnotationKind::Arrow, (0.0, 50.0), (100.0, 50.0));
+        let [upper, lower] = arrow.arrow_head(10.0).unwrap();
+        assert!(upper.0 < 100.0 && lower.0 < 100.0);
+        assert!((upper.0 - lower.0).abs() < 1e-9);
+        assert!((upper.1 - 50.0 + (lower.1 - 50.0)).abs() < 1e-9);
+        assert_eq!(
+            annotation(AnnotationKind::Arrow, (5.0, 5.0), (5.0, 5.0)).arrow_head(10.0),
+            None
+        );
+
+        // Recuadro arrastrado hacia arriba a la izquierda
+        let rect = annotation(AnnotationKind::Rect, (80.0, 60.0), (20.0, 10.0));
+        assert_eq!(rect.rect(), (20.0, 10.0, 60.0, 50.0));
+        assert!(!rect.is_too_small(4.0));
+        assert!(annotation(AnnotationKind::Rect, (1.0, 1.0), (2.0, 2.0)).is_too_small(4.0));
+        assert!(
+            annotation(AnnotationKind::Text("  ".into()), (0.0, 0.0), (0.0, 0.0)).is_too_small(4.0)
+        );
+    }
+
+    #[test]
+    fn test_parse_region() {
+        assert_eq!(
+            parse_region("120,-40 640x480\n").as_deref(),
+            Some("120,-40 640x480")
+        );
+        assert_eq!(parse_region("0,0 0x480"), None);
+        assert_eq!(parse_region(""), None);
+        assert_eq!(parse_region("selection cancelled"), None);
+    }
+
+    #[test]
+    fn test_image_markdown() {
+        assert_eq!(
+            image_markdown(Path::new("/notas/assets/screenshot_20261016_093000.png")),
+            "![screenshot_20261016_093000](/notas/assets/screenshot_20261016_093000.png)"
+        );
+    }
+}
@@ -0,0 +1,152 @@
+//! Ámbito de la respuesta de la búsqueda semántica
+//!
+//! La respuesta del asistente puede limitarse a una carpeta, a un tag o a un
+//! puñado de notas (las adjuntas al chat). Los resultados de la búsqueda se
+//! recortan al ámbito antes de dárselos al agente. Los de los embeddings
+//! tienen que llegar ya pasados a notas ([`semantic_results`]): el ámbito mira
+//! el nombre de la nota, no el fragmento.
+//!
+//! [`semantic_results`]: crate::hybrid_search::semantic_results
+
+use std::collections::HashSet;
+
+use crate::database::{NotesDatabase, Result, SearchResult};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum SearchScope {
+    /// Todas las notas
+    #[default]
+    Vault,
+    /// Una carpeta y sus subcarpetas
+    Folder(String),
+    /// Notas con el tag o alguno de sus sub-tags
+    Tag(String),
+    /// Solo estas notas (por nombre completo)
+    Notes(Vec<String>),
+}
+
+impl SearchScope {
+    pub fn is_vault(&self) -> bool {
+        *self == SearchScope::Vault
+    }
+
+    /// Deja en `results` solo las notas del ámbito, en el mismo orden
+    pub fn retain(&self, db: &NotesDatabase, results: &mut Vec<SearchResult>) -> Result<()> {
+        match self {
+            SearchScope::Vault => {}
+            SearchScope::Folder(folder) => {
+                let prefix = format!("{}/", folder);
+                results.retain(|result| result.note_name.starts_with(&prefix));
+            }
+            SearchScope::Tag(tag) => {
+                let names: HashSet<String> = db.notes_with_tag(tag)?.into_iter().collect();
+                results.retain(|result| names.contains(&result.note_name));
+            }
+            SearchScope::Notes(names) => {
+                results.retain(|result| names.contains(&result.note_name));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hybrid_search::semantic_results;
+
+    fn result(name: &str) -> SearchResult {
+        SearchResult {
+            note_id: 0,
+            note_name: name.to_string(),
+            note_path: format!("/n/{}.md", name),
+            snippet: String::new(),
+            relevance: 0.0,
+            matched_tags: vec![],
+            similarity: None,
+        }
+    }
+
+    fn names(results: &[SearchResult]) -> Vec<&str> {
+        results.iter().map(|r| r.note_name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_retain_by_scope() {
+        let db_path = std::env::temp_dir().join("test_search_scope.db");
+        std::fs::remove_file(&db_path).ok();
+        let db = NotesDatabase::new(&db_path).unwrap();
+        db.index_note("Work/plan", "/n/Work/plan.md", "#acme/web", Some("Work"))
+            .unwrap();
+        db.index_note("Workshop", "/n/Workshop.md", "#acme", None)
+            .unwrap();
+        db.index_note("ideas", "/n/ideas.md", "#personal", None)
+            .unwrap();
+
+        let all = vec![result("Work/plan"), result("Workshop"), result("ideas")];
+
+        let mut results = all.clone();
+        SearchScope::Vault.retain(&db, &mut results).unwrap();
+        assert_eq!(results.len(), 3);
+
+        let mut results = all.clone();
+        SearchScope::Folder("Work".to_string())
+            .retain(&db, &mut results)
+            .unwrap();
+        assert_eq!(names(&results), vec!["Work/plan"]);
+
+        let mut results = all.clone();
+        SearchScope::Tag("acme".to_string())
+            .retain(&db, &mut results)
+            .unwrap();
+        assert_eq!(names(&results), vec!["Work/plan", "Workshop"]);
+
+        let mut results = all;
+        SearchScope::Notes(vec!["ideas".to_string()])
+            .retain(&db, &mut results)
+            .unwrap();
+        assert_eq!(names(&results), vec!["ideas"]);
+
+        std::fs::remove_file(db_path).ok();
+    }
+
+    #[test]
+    fn test_retain_semantic_chunks() {
+        let db_path = std::env::temp_dir().join("test_search_scope_chunks.db");
+        std::fs::remove_file(&db_path).ok();
+        let db = NotesDatabase::new(&db_path).unwrap();
+        db.index_note("Work/plan", "/v/Work/plan.md", "#acme", Some("Work"))
+            .unwrap();
+        db.index_note("ideas", "/v/ideas.md", "#personal", None)
+            .unwrap();
+
+        // Fragmentos tal como los devuelve la búsqueda semántica
+        let hits = || {
+            vec![
+                (0.9, "/v/Work/plan.md#2".to_string()),
+                (0.8, "/v/ideas.md#0".to_string()),
+                (0.7, "/v/Work/plan.md#0".to_string()),
+            ]
+        };
+
+        let mut results = semantic_results(&db, hits()).unwrap();
+        SearchScope::Folder("Work".to_string())
+            .retain(&db, &mut results)
+            .unwrap();
+        assert_eq!(names(&results), vec!["Work/plan"]);
+
+        let mut results = semantic_results(&db, hits()).unwrap();
+        SearchScope::Tag("personal".to_string())
+            .retain(&db, &mut results)
+            .unwrap();
+        assert_eq!(names(&results), vec!["ideas"]);
+
+        let mut results = semantic_results(&db, hits()).unwrap();
+        SearchScope::Notes(vec!["Work/plan".to_string()])
+            .retain(&db, &mut results)
+            .unwrap();
+        assert_eq!(names(&results), vec!["Work/plan"]);
+
+        std::fs::remove_file(db_path).ok();
+    }
+}
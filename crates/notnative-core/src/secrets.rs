@@ -0,0 +1,388 @@
+//! Almacenamiento seguro de credenciales (claves de API, tokens)
+//!
+//! Las credenciales se guardan en el llavero del escritorio (Secret Service, vía
+//! `secret-tool` de libsecret). Si no hay llavero disponible, o si falla, se usa un
+//! archivo cifrado con ChaCha20-Poly1305 en el directorio de datos; su clave se guarda
+//! en un archivo aparte con permisos 0600.
+
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::{LazyLock, Mutex};
+use thiserror::Error;
+use tracing::{error, info};
+
+/// Clave de API del asistente AI
+pub const AI_API_KEY: &str = "ai_api_key";
+/// Clave de API del proveedor de embeddings
+pub const EMBEDDING_API_KEY: &str = "embedding_api_key";
+
+/// Atributo `application` con el que se guardan los secretos en el llavero
+const KEYRING_APPLICATION: &str = "notnative";
+const SECRETS_FILE: &str = "secrets.enc";
+const KEY_FILE: &str = "secrets.key";
+const NONCE_LEN: usize = 12;
+
+#[derive(Debug, Error)]
+pub enum SecretError {
+    #[error("Error del llavero: {0}")]
+    Keyring(String),
+    #[error("Error de E/S: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("No se pudo descifrar el archivo de credenciales")]
+    Decrypt,
+    #[error("Error de serialización: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+static GLOBAL_STORE: LazyLock<SecretStore> = LazyLock::new(|| {
+    let dir = dirs::data_local_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("notnative");
+    SecretStore::detect(dir)
+});
+
+/// Almacén de credenciales: llavero del sistema con respaldo en archivo cifrado
+pub struct SecretStore {
+    use_keyring: bool,
+    fallback_dir: PathBuf,
+    /// Caché en memoria para no consultar el llavero en cada guardado
+    cache: Mutex<HashMap<String, Option<String>>>,
+}
+
+impl SecretStore {
+    /// Usa el llavero si el Secret Service responde; si no, el archivo cifrado en `fallback_dir`
+    pub fn detect(fallback_dir: impl Into<PathBuf>) -> Self {
+        let use_keyring = keyring_available();
+        if !use_keyring {
+            info!("Llavero no disponible, usando archivo cifrado para credenciales");
+        }
+        Self {
+            use_keyring,
+            fallback_dir: fallback_dir.into(),
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Almacén que solo usa el archivo cifrado (sin llavero)
+    pub fn encrypted_file(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            use_keyring: false,
+            fallback_dir: dir.into(),
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Almacén compartido de la aplicación
+    pub fn global() -> &'static SecretStore {
+        &GLOBAL_STORE
+    }
+
+    /// Indica si las credenciales se guardan en el llavero del sistema
+    pub fn uses_keyring(&self) -> bool {
+        self.use_keyring
+    }
+
+    /// Obtiene una credencial
+    pub fn get(&self, name: &str) -> Option<String> {
+        if let Some(cached) = self.cache.lock().unwrap().get(name) {
+            return cached.clone();
+        }
+
+        let mut value = None;
+        if self.use_keyring {
+            value = keyring_lookup(name);
+        }
+        if value.is_none() {
+            value = match read_encrypted(&self.fallback_dir) {
+                Ok(mut secrets) => secrets.remove(name),
+                Err(e) => {
+                    error!("Error leyendo credenciales cifradas: {}", e);
+                    None
+                }
+            };
+        }
+
+        self.cache
+            .lock()
+            .unwrap()
+            .insert(name.to_string(), value.clone());
+        value
+    }
+
+    /// Guarda (o borra, si es `None` o vacío) una credencial
+    pub fn set(&self, name: &str, value: Option<&str>) -> Result<(), SecretError> {
+        let value = value.filter(|v| !v.is_empty());
+        if self.get(name).as_deref() == value {
+            return Ok(());
+        }
+
+        let mut stored_in_keyring = false;
+        if self.use_keyring {
+            let result = match value {
+                Some(secret) => keyring_store(name, secret),
+                None => keyring_clear(name),
+            };
+            match result {
+                Ok(()) => stored_in_keyring = true,
+                Err(e) => info!("{}, usando archivo cifrado", e),
+            }
+        }
+
+        // El archivo cifrado guarda la credencial si el llavero no pudo hacerlo,
+        // y nunca debe conservar una copia antigua
+        let mut secrets = read_encrypted(&self.fallback_dir)?;
+        let changed = match value {
+            Some(secret) if !stored_in_keyring => {
+                secrets.insert(name.to_string(), secret.to_string());
+                true
+            }
+            _ => secrets.remove(name).is_some(),
+        };
+        if changed {
+            write_encrypted(&self.fallback_dir, &secrets)?;
+        }
+
+        self.cache
+            .lock()
+            .unwrap()
+            .insert(name.to_string(), value.map(str::to_string));
+        Ok(())
+    }
+}
+
+/// Comprueba que `secret-tool` existe y que el Secret Service responde
+fn keyring_available() -> bool {
+    Command::new("secret-tool")
+        .args([
+            "lookup",
+            "application",
+            KEYRING_APPLICATION,
+            "key",
+            "__probe__",
+        ])
+        .stdin(Stdio::null())
+        .output()
+        // Sin coincidencias sale con código 1 pero sin errores en stderr
+        .map(|output| output.stderr.is_empty())
+        .unwrap_or(false)
+}
+
+fn keyring_lookup(name: &str) -> Option<String> {
+    let output = Command::new("secret-tool")
+        .args(["lookup", "application", KEYRING_APPLICATION, "key", name])
+        .stdin(Stdio::null())
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let secret = String::from_utf8(output.stdout).ok()?;
+    let secret = secret.trim_end_matches('\n');
+    (!secret.is_empty()).then(|| secret.to_string())
+}
+
+fn keyring_store(name: &str, secret: &str) -> Result<(), SecretError> {
+    let mut child = Command::new("secret-tool")
+        .args([
+            "store",
+            &format!("--label=NotNative: {}", name),
+            "application",
+            KEYRING_APPLICATION,
+            "key",
+            name,
+        ])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(secret.as_bytes())?;
+    }
+
+    let output = child.wait_with_output()?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(SecretError::Keyring(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ))
+    }
+}
+
+fn keyring_clear(name: &str) -> Result<(), SecretError> {
+    let output = Command::new("secret-tool")
+        .args(["clear", "application", KEYRING_APPLICATION, "key", name])
+        .stdin(Stdio::null())
+        .output()?;
+    if output.status.success() || output.stderr.is_empty() {
+        Ok(())
+    } else {
+        Err(SecretError::Keyring(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ))
+    }
+}
+
+/// Escribe un archivo accesible solo por el usuario
+fn write_private(path: &Path, data: &[u8]) -> std::io::Result<()> {
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    options.open(path)?.write_all(data)
+}
+
+/// Lee la clave del archivo cifrado; con `create` la genera si no existe
+fn load_key(dir: &Path, create: bool) -> Result<Option<Key>, SecretError> {
+    let path = dir.join(KEY_FILE);
+    match std::fs::read(&path) {
+        Ok(bytes) => match <[u8; 32]>::try_from(bytes) {
+            Ok(key) => Ok(Some(key.into())),
+            Err(_) => Err(SecretError::Decrypt),
+        },
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound && create => {
+            std::fs::create_dir_all(dir)?;
+            let key = ChaCha20Poly1305::generate_key(&mut OsRng);
+            write_private(&path, &key)?;
+            Ok(Some(key))
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+fn read_encrypted(dir: &Path) -> Result<HashMap<String, String>, SecretError> {
+    let data = match std::fs::read(dir.join(SECRETS_FILE)) {
+        Ok(data) => data,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(HashMap::new()),
+        Err(e) => return Err(e.into()),
+    };
+    let Some(key) = load_key(dir, false)? else {
+        return Err(SecretError::Decrypt);
+    };
+    if data.len() < NONCE_LEN {
+        return Err(SecretError::Decrypt);
+    }
+
+    let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+    let nonce: [u8; NONCE_LEN] = nonce.try_into().map_err(|_| SecretError::Decrypt)?;
+    let plaintext = ChaCha20Poly1305::new(&key)
+        .decrypt(&Nonce::from(nonce), ciphertext)
+        .map_err(|_| SecretError::Decrypt)?;
+    Ok(serde_json::from_slice(&plaintext)?)
+}
+
+fn write_encrypted(dir: &Path, secrets: &HashMap<String, String>) -> Result<(), SecretError> {
+    let key = load_key(dir, true)?.ok_or(SecretError::Decrypt)?;
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let plaintext = serde_json::to_vec(secrets)?;
+    let ciphertext = ChaCha20Poly1305::new(&key)
+        .encrypt(&nonce, plaintext.as_slice())
+        .map_err(|_| SecretError::Decrypt)?;
+
+    let mut data = nonce.to_vec();
+    data.extend_from_slice(&ciphertext);
+
+    // Escritura atómica para no perder credenciales si se interrumpe
+    let tmp_path = dir.join(format!("{}.tmp", SECRETS_FILE));
+    write_private(&tmp_path, &data)?;
+    std::fs::rename(tmp_path, dir.join(SECRETS_FILE))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NotesConfig;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("notnative-secrets-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_encrypted_file_roundtrip() {
+        let dir = temp_dir("roundtrip");
+        let store = SecretStore::encrypted_file(&dir);
+        assert_eq!(store.get(AI_API_KEY), None);
+
+        store.set(AI_API_KEY, Some("sk-or-secret-123")).unwrap();
+        let raw = std::fs::read(dir.join(SECRETS_FILE)).unwrap();
+        assert!(!String::from_utf8_lossy(&raw).contains("sk-or-secret-123"));
+
+        // Una instancia nueva (sin caché) lee lo mismo del disco
+        let reopened = SecretStore::encrypted_file(&dir);
+        assert_eq!(
+            reopened.get(AI_API_KEY).as_deref(),
+            Some("sk-or-secret-123")
+        );
+
+        reopened.set(AI_API_KEY, Some("")).unwrap();
+        assert_eq!(SecretStore::encrypted_file(&dir).get(AI_API_KEY), None);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_config_migrates_plaintext_keys() {
+        let dir = temp_dir("migrate");
+        std::fs::create_dir_all(&dir).unwrap();
+        let config_path = dir.join("config.json");
+
+        let mut legacy = serde_json::to_value(NotesConfig::new()).unwrap();
+        legacy["ai_config"]["api_key"] = "sk-plain".into();
+        std::fs::write(&config_path, legacy.to_string()).unwrap();
+
+        let store = SecretStore::encrypted_file(&dir);
+        let config = NotesConfig::load_with_secrets(&config_path, &store).unwrap();
+        assert_eq!(config.get_ai_config().api_key.as_deref(), Some("sk-plain"));
+
+        // La clave ya no está en texto plano y se recupera desde el almacén
+        let content = std::fs::read_to_string(&config_path).unwrap();
+        assert!(!content.contains("sk-plain"));
+        let reloaded =
+            NotesConfig::load_with_secrets(&config_path, &SecretStore::encrypted_file(&dir))
+                .unwrap();
+        assert_eq!(
+            reloaded.get_ai_config().api_key.as_deref(),
+            Some("sk-plain")
+        );
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_failed_migration_keeps_plaintext_key() {
+        let dir = temp_dir("migrate-fail");
+        std::fs::create_dir_all(&dir).unwrap();
+        let config_path = dir.join("config.json");
+
+        let mut legacy = serde_json::to_value(NotesConfig::new()).unwrap();
+        legacy["ai_config"]["api_key"] = "sk-plain".into();
+        std::fs::write(&config_path, legacy.to_string()).unwrap();
+
+        // Un archivo de credenciales que no se puede descifrar: el almacén falla
+        std::fs::write(dir.join(KEY_FILE), b"corrupta").unwrap();
+        std::fs::write(dir.join(SECRETS_FILE), b"basura cifrada").unwrap();
+
+        let store = SecretStore::encrypted_file(&dir);
+        let config = NotesConfig::load_with_secrets(&config_path, &store).unwrap();
+        assert_eq!(config.get_ai_config().api_key.as_deref(), Some("sk-plain"));
+
+        // La clave sigue en el archivo hasta que el almacén la guarde
+        let content = std::fs::read_to_string(&config_path).unwrap();
+        assert!(content.contains("sk-plain"));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,236 @@
+//! Modelo del árbol del sidebar (carpetas y notas visibles)
+//!
+//! Separa el cálculo de qué filas se muestran (orden de carpetas, carpetas
+//! ocultas o contraídas, papelera al final) de la creación de widgets.
+
+use std::collections::{HashMap, HashSet};
+
+/// Carpeta de la papelera (se muestra siempre al final)
+pub const TRASH_FOLDER: &str = ".trash";
+/// Carpeta raíz del directorio de notas
+pub const ROOT_FOLDER: &str = "/";
+
+/// Fila visible del sidebar
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SidebarEntry {
+    Folder { path: String, expanded: bool },
+    Note { name: String, folder: String },
+}
+
+impl SidebarEntry {
+    /// Identificador estable de la fila (no cambia al expandir o contraer)
+    pub fn key(&self) -> String {
+        match self {
+            SidebarEntry::Folder { path, .. } => format!("folder:{}", path),
+            SidebarEntry::Note { name, folder } => format!("note:{}/{}", folder, name),
+        }
+    }
+
+    /// Nivel de indentación de la fila
+    pub fn depth(&self) -> usize {
+        match self {
+            SidebarEntry::Folder { path, .. } => path.matches('/').count(),
+            SidebarEntry::Note { folder, .. } if folder == ROOT_FOLDER => 0,
+            SidebarEntry::Note { folder, .. } => folder.matches('/').count() + 1,
+        }
+    }
+}
+
+/// Contenido de una fila del sidebar
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SidebarRow {
+    pub entry: SidebarEntry,
+    /// Icono personalizado (nombre de icono o emoji) y color opcional
+    pub icon: Option<(String, Option<String>)>,
+    pub renaming: bool,
+    /// Nota fijada arriba de su carpeta (se muestra con un pin)
+    pub pinned: bool,
+    /// Color de la carpeta (propio o heredado), se muestra como una muestra
+    pub swatch: Option<String>,
+    pub label: String,
+    pub tooltip: Option<String>,
+}
+
+/// Timestamp con el que se renombran las notas al moverlas a la papelera (`nombre_1700000000`)
+pub fn trash_timestamp(name: &str) -> u64 {
+    name.rsplit('_')
+        .next()
+        .and_then(|s| s.parse::<u64>().ok())
+        .unwrap_or(0)
+}
+
+/// Pone primero las notas fijadas, sin cambiar el orden dentro de cada grupo
+pub fn pinned_first(notes: &mut [String], pinned: &HashSet<String>) {
+    notes.sort_by_key(|name| !pinned.contains(name));
+}
+
+/// Deja solo las notas de `keep` (filtro por tags) y las carpetas que las
+/// contienen, con sus carpetas superiores para que el árbol siga completo
+pub fn retain_notes(by_folder: &mut HashMap<String, Vec<String>>, keep: &HashSet<String>) {
+    for notes in by_folder.values_mut() {
+        notes.retain(|name| keep.contains(name));
+    }
+    let mut needed: HashSet<String> = HashSet::new();
+    for (folder, notes) in by_folder.iter() {
+        if notes.is_empty() || folder == ROOT_FOLDER {
+            continue;
+        }
+        let mut path = folder.as_str();
+        needed.insert(path.to_string());
+        while let Some((parent, _)) = path.rsplit_once('/') {
+            needed.insert(parent.to_string());
+            path = parent;
+        }
+    }
+    by_folder.retain(|folder, _| folder == ROOT_FOLDER || needed.contains(folder));
+}
+
+/// Calcula las filas visibles del sidebar.
+///
+/// - Carpetas en orden alfabético, con la papelera al final
+/// - Se omiten carpetas ocultas (`.history`...) y las que ya no existen
+/// - Las carpetas con algún padre contraído no se muestran
+/// - Las notas solo aparecen dentro de carpetas expandidas (las de la raíz siempre);
+///   mantienen el orden recibido salvo en la papelera, que va de más reciente a más antigua
+pub fn visible_entries(
+    by_folder: &HashMap<String, Vec<String>>,
+    expanded: &HashSet<String>,
+    folder_exists: impl Fn(&str) -> bool,
+) -> Vec<SidebarEntry> {
+    let mut folders: Vec<&String> = by_folder.keys().collect();
+    folders.sort_by(|a, b| {
+        if *a == TRASH_FOLDER {
+            std::cmp::Ordering::Greater
+        } else if *b == TRASH_FOLDER {
+            std::cmp::Ordering::Less
+        } else {
+            a.cmp(b)
+        }
+    });
+
+    let mut entries = Vec::new();
+    for folder in folders {
+        if folder != TRASH_FOLDER && folder.split('/').any(|p| p.starts_with('.')) {
+            continue;
+        }
+
+        if folder != ROOT_FOLDER {
+            if !folder_exists(folder) {
+                continue;
+            }
+
+            // Si `test` está contraída, `test/test2` no se muestra
+            let parts: Vec<&str> = folder.split('/').collect();
+            let parent_collapsed =
+                (1..parts.len()).any(|i| !expanded.contains(&parts[..i].join("/")));
+            if parent_collapsed {
+                continue;
+            }
+
+            let is_expanded = expanded.contains(folder);
+            entries.push(SidebarEntry::Folder {
+                path: folder.clone(),
+                expanded: is_expanded,
+            });
+            if !is_expanded {
+                continue;
+            }
+        }
+
+        let mut notes = by_folder[folder].clone();
+        if folder == TRASH_FOLDER {
+            notes.sort_by_key(|name| std::cmp::Reverse(trash_timestamp(name)));
+        }
+        entries.extend(notes.into_iter().map(|name| SidebarEntry::Note {
+            name,
+            folder: folder.clone(),
+        }));
+    }
+
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn folders(entries: &[(&str, &[&str])]) -> HashMap<String, Vec<String>> {
+        entries
+            .iter()
+            .map(|(folder, notes)| {
+                (
+                    folder.to_string(),
+                    notes.iter().map(|n| n.to_string()).collect(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_visible_entries_respects_expanded_folders() {
+        let by_folder = folders(&[
+            ("/", &["inbox"]),
+            ("work", &["plan"]),
+            ("work/old", &["archive"]),
+            (".history", &["x"]),
+            (".trash", &["a_100", "b_300", "c_200"]),
+        ]);
+
+        let collapsed = visible_entries(&by_folder, &HashSet::new(), |_| true);
+        let keys: Vec<String> = collapsed.iter().map(|e| e.key()).collect();
+        assert_eq!(keys, vec!["note://inbox", "folder:work", "folder:.trash"]);
+
+        let expanded: HashSet<String> = ["work".to_string(), ".trash".to_string()].into();
+        let entries = visible_entries(&by_folder, &expanded, |_| true);
+        let keys: Vec<String> = entries.iter().map(|e| e.key()).collect();
+        assert_eq!(
+            keys,
+            vec![
+                "note://inbox",
+                "folder:work",
+                "note:work/plan",
+                "folder:work/old",
+                "folder:.trash",
+                "note:.trash/b_300",
+                "note:.trash/c_200",
+                "note:.trash/a_100",
+            ]
+        );
+        assert_eq!(entries[3].depth(), 1);
+        assert_eq!(entries[2].depth(), 1);
+        assert_eq!(entries[0].depth(), 0);
+    }
+
+    #[test]
+    fn test_visible_entries_skips_missing_folders() {
+        let by_folder = folders(&[("/", &[]), ("gone", &["n"])]);
+        let entries = visible_entries(&by_folder, &HashSet::new(), |f| f != "gone");
+        assert!(entries.is_empty());
+    }
+
+    #[test]
+    fn test_pinned_first() {
+        let mut notes: Vec<String> = ["a", "b", "c", "d"].map(String::from).to_vec();
+        let pinned: HashSet<String> = ["d".to_string(), "b".to_string()].into();
+        pinned_first(&mut notes, &pinned);
+        assert_eq!(notes, ["b", "d", "a", "c"]);
+    }
+
+    #[test]
+    fn test_retain_notes() {
+        let mut by_folder: HashMap<String, Vec<String>> = HashMap::new();
+        by_folder.insert(ROOT_FOLDER.into(), vec!["raiz".into(), "otra".into()]);
+        by_folder.insert("a".into(), vec![]);
+        by_folder.insert("a/b".into(), vec!["a/b/nota".into(), "a/b/x".into()]);
+        by_folder.insert("c".into(), vec!["c/nota".into()]);
+
+        let keep: HashSet<String> = ["raiz".to_string(), "a/b/nota".to_string()].into();
+        retain_notes(&mut by_folder, &keep);
+
+        let mut folders: Vec<&String> = by_folder.keys().collect();
+        folders.sort();
+        assert_eq!(folders, ["/", "a", "a/b"]);
+        assert_eq!(by_folder["a/b"], ["a/b/nota"]);
+        assert_eq!(by_folder[ROOT_FOLDER], ["raiz"]);
+    }
+}
@@ -0,0 +1,235 @@
+//! Bocetos a mano alzada dentro de las notas
+//!
+//! Un boceto se guarda en assets como SVG (`sketch_20261016_093000.svg`) y la
+//! nota lo enlaza como cualquier otra imagen. Cada trazo lleva además sus
+//! puntos con la presión en `data-points` (y el `<svg>` la marca
+//! `data-notnative="sketch"`), así se puede volver a abrir en el lienzo y
+//! seguir dibujando.
+
+/// Inicio del nombre de archivo de los bocetos
+pub const SKETCH_PREFIX: &str = "sketch_";
+
+/// Tamaño del lienzo de un boceto nuevo
+pub const DEFAULT_SKETCH_SIZE: (f64, f64) = (800.0, 500.0);
+
+/// Punto de un trazo con la presión del lápiz (0.0 a 1.0; 1.0 con ratón)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SketchPoint {
+    pub x: f64,
+    pub y: f64,
+    pub pressure: f64,
+}
+
+/// Trazo continuo: color CSS y grosor a presión máxima
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stroke {
+    pub color: String,
+    pub width: f64,
+    pub points: Vec<SketchPoint>,
+}
+
+impl Stroke {
+    /// Grosor del trazo en un punto: con poca presión no baja de un tercio
+    pub fn width_at(&self, pressure: f64) -> f64 {
+        self.width * (0.3 + 0.7 * pressure.clamp(0.0, 1.0))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sketch {
+    pub width: f64,
+    pub height: f64,
+    pub strokes: Vec<Stroke>,
+}
+
+impl Default for Sketch {
+    fn default() -> Self {
+        Self {
+            width: DEFAULT_SKETCH_SIZE.0,
+            height: DEFAULT_SKETCH_SIZE.1,
+            strokes: Vec::new(),
+        }
+    }
+}
+
+impl Sketch {
+    /// SVG con fondo blanco (se lee igual con tema oscuro). Cada tramo es una
+    /// línea con el grosor de su presión; un punto suelto, un círculo.
+    pub fn to_svg(&self) -> String {
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" data-notnative=\"sketch\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\">\n\
+             <rect width=\"100%\" height=\"100%\" fill=\"#ffffff\"/>\n",
+            w = self.width,
+            h = self.height
+        );
+        for stroke in &self.strokes {
+            let points: Vec<String> = stroke
+                .points
+                .iter()
+                .map(|p| format!("{:.1},{:.1},{:.2}", p.x, p.y, p.pressure))
+                .collect();
+            svg.push_str(&format!(
+                "<g stroke=\"{color}\" fill=\"{color}\" stroke-linecap=\"round\" data-width=\"{}\" data-points=\"{}\">\n",
+                stroke.width,
+                points.join(" "),
+                color = escape_attribute(&stroke.color)
+            ));
+            match stroke.points.as_slice() {
+                [] => {}
+                [point] => svg.push_str(&format!(
+                    "<circle cx=\"{:.1}\" cy=\"{:.1}\" r=\"{:.2}\"/>\n",
+                    point.x,
+                    point.y,
+                    stroke.width_at(point.pressure) / 2.0
+                )),
+                points => {
+                    for pair in points.windows(2) {
+                        svg.push_str(&format!(
+                            "<line x1=\"{:.1}\" y1=\"{:.1}\" x2=\"{:.1}\" y2=\"{:.1}\" stroke-width=\"{:.2}\"/>\n",
+                            pair[0].x,
+                            pair[0].y,
+                            pair[1].x,
+                            pair[1].y,
+                            stroke.width_at(pair[1].pressure)
+                        ));
+                    }
+                }
+            }
+            svg.push_str("</g>\n");
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// Boceto guardado con [`Sketch::to_svg`]. `None` si el SVG no es un
+    /// boceto de NotNative (p. ej. una imagen SVG cualquiera).
+    pub fn from_svg(svg: &str) -> Option<Self> {
+        let root = tag_at(svg, svg.find("<svg")?)?;
+        if attribute(root, "data-notnative") != Some("sketch") {
+            return None;
+        }
+        let width = attribute(root, "width")?.parse().ok()?;
+        let height = attribute(root, "height")?.parse().ok()?;
+
+        let mut strokes = Vec::new();
+        let mut rest = svg;
+        while let Some(start) = rest.find("<g ") {
+            let tag = tag_at(rest, start)?;
+            rest = &rest[start + tag.len()..];
+            let Some(data) = attribute(tag, "data-points") else {
+                continue;
+            };
+            let points = data
+                .split_whitespace()
+                .map(|point| {
+                    let mut values = point.split(',').map(str::parse::<f64>);
+                    Some(SketchPoint {
+                        x: values.next()?.ok()?,
+                        y: values.next()?.ok()?,
+                        pressure: values.next().and_then(Result::ok).unwrap_or(1.0),
+                    })
+                })
+                .collect::<Option<Vec<_>>>()?;
+            strokes.push(Stroke {
+                color: unescape_attribute(attribute(tag, "stroke").unwrap_or("#000000")),
+                width: attribute(tag, "data-width")
+                    .and_then(|w| w.parse().ok())
+                    .unwrap_or(3.0),
+                points,
+            });
+        }
+
+        Some(Self {
+            width,
+            height,
+            strokes,
+        })
+    }
+}
+
+/// La imagen es un boceto (`.../sketch_*.svg`)
+pub fn is_sketch_path(path: &str) -> bool {
+    let file_name = path.rsplit('/').next().unwrap_or(path);
+    file_name.starts_with(SKETCH_PREFIX) && file_name.ends_with(".svg")
+}
+
+/// Etiqueta que empieza en `start` (hasta su `>`)
+fn tag_at(text: &str, start: usize) -> Option<&str> {
+    let end = text[start..].find('>')?;
+    Some(&text[start..=start + end])
+}
+
+/// Valor de `name="..."` dentro de una etiqueta
+fn attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let needle = format!(" {}=\"", name);
+    let start = tag.find(&needle)? + needle.len();
+    let end = tag[start..].find('"')?;
+    Some(&tag[start..start + end])
+}
+
+fn escape_attribute(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+}
+
+fn unescape_attribute(value: &str) -> String {
+    value
+        .replace("&lt;", "<")
+        .replace("&quot;", "\"")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(x: f64, y: f64, pressure: f64) -> SketchPoint {
+        SketchPoint { x, y, pressure }
+    }
+
+    #[test]
+    fn test_sketch_svg_roundtrip() {
+        let sketch = Sketch {
+            width: 400.0,
+            height: 300.0,
+            strokes: vec![
+                Stroke {
+                    color: "#1e66f5".to_string(),
+                    width: 4.0,
+                    points: vec![point(10.0, 20.0, 0.5), point(30.5, 40.0, 1.0)],
+                },
+                Stroke {
+                    color: "#000000".to_string(),
+                    width: 2.0,
+                    points: vec![point(5.0, 5.0, 1.0)],
+                },
+            ],
+        };
+        let svg = sketch.to_svg();
+        assert!(svg.contains(
+            "<line x1=\"10.0\" y1=\"20.0\" x2=\"30.5\" y2=\"40.0\" stroke-width=\"4.00\"/>"
+        ));
+        assert!(svg.contains("<circle cx=\"5.0\" cy=\"5.0\" r=\"1.00\"/>"));
+        assert_eq!(Sketch::from_svg(&svg), Some(sketch));
+    }
+
+    #[test]
+    fn test_from_svg_rejects_other_images() {
+        let svg = "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"10\" height=\"10\">\
+                   <g fill=\"red\"><circle cx=\"5\" cy=\"5\" r=\"5\"/></g></svg>";
+        assert_eq!(Sketch::from_svg(svg), None);
+        assert_eq!(
+            Sketch::from_svg(&Sketch::default().to_svg()),
+            Some(Sketch::default())
+        );
+    }
+
+    #[test]
+    fn test_is_sketch_path() {
+        assert!(is_sketch_path("/home/u/assets/sketch_20261016_093000.svg"));
+        assert!(!is_sketch_path("/home/u/assets/logo.svg"));
+        assert!(!is_sketch_path("/home/u/sketch_dir/foto.png"));
+    }
+}
@@ -0,0 +1,193 @@
+//! División de una nota en varias, una por sección (`:split-by-heading`)
+//!
+//! Cada encabezado del nivel elegido abre una sección que llega hasta el
+//! siguiente encabezado de ese nivel o superior. Las secciones pasan a notas
+//! propias y en la original quedan menciones `@Nota` en su lugar. Lo que hay
+//! antes de la primera sección y los encabezados de nivel superior se quedan.
+
+use crate::frontmatter::frontmatter_lines;
+use crate::merge::heading_level;
+use std::ops::Range;
+
+/// Una sección de la nota original
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section {
+    /// Texto del encabezado, sin las `#`
+    pub title: String,
+    /// Líneas que ocupa en la original (sin líneas en blanco finales)
+    pub lines: Range<usize>,
+    /// Contenido de la nota nueva: el encabezado pasa a `#` y los de dentro
+    /// suben lo mismo
+    pub content: String,
+}
+
+/// Secciones de `markdown` que empiezan con un encabezado de nivel `level`.
+/// No cuentan los encabezados de bloques de código ni el frontmatter.
+pub fn split_sections(markdown: &str, level: usize) -> Vec<Section> {
+    let lines: Vec<&str> = markdown.lines().collect();
+    let mut sections = Vec::new();
+    let mut current: Option<(usize, String)> = None;
+    let mut in_code_block = false;
+
+    let body_start = frontmatter_lines(markdown);
+    for (index, line) in lines.iter().enumerate().skip(body_start) {
+        if is_fence(line) {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            continue;
+        }
+        let Some(heading) = heading_level(line) else {
+            continue;
+        };
+        if heading <= level {
+            if let Some((start, title)) = current.take() {
+                sections.push(section(&lines, start..index, title, level));
+            }
+            if heading == level {
+                current = Some((index, line[heading..].trim().to_string()));
+            }
+        }
+    }
+    if let Some((start, title)) = current {
+        sections.push(section(&lines, start..lines.len(), title, level));
+    }
+    sections
+}
+
+/// Sustituye cada sección por `- @nombre` (uno por sección, en orden). Las
+/// secciones seguidas quedan como una sola lista.
+pub fn replace_sections(markdown: &str, sections: &[Section], names: &[String]) -> String {
+    let lines: Vec<&str> = markdown.lines().collect();
+    let mut output: Vec<String> = Vec::with_capacity(lines.len());
+    let mut next = 0;
+
+    for (position, (section, name)) in sections.iter().zip(names).enumerate() {
+        output.extend(
+            lines[next..section.lines.start]
+                .iter()
+                .map(|l| l.to_string()),
+        );
+        output.push(format!("- @{}", name));
+        next = section.lines.end;
+
+        // Quitar el hueco hasta la siguiente sección si no hay nada en medio
+        let mut gap_end = next;
+        while gap_end < lines.len() && lines[gap_end].trim().is_empty() {
+            gap_end += 1;
+        }
+        if sections
+            .get(position + 1)
+            .is_some_and(|following| following.lines.start == gap_end)
+        {
+            next = gap_end;
+        }
+    }
+    output.extend(lines[next..].iter().map(|l| l.to_string()));
+
+    let mut result = output.join("\n");
+    if markdown.ends_with('\n') {
+        result.push('\n');
+    }
+    result
+}
+
+/// Nombre de archivo a partir del título de una sección: sin signos que no
+/// caben en una mención ni en una ruta
+pub fn note_name_from_title(title: &str) -> String {
+    let cleaned: String = title
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || matches!(c, '-' | '_') {
+                c
+            } else {
+                ' '
+            }
+        })
+        .collect();
+    cleaned.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn section(lines: &[&str], range: Range<usize>, title: String, level: usize) -> Section {
+    let mut end = range.end;
+    while end > range.start + 1 && lines[end - 1].trim().is_empty() {
+        end -= 1;
+    }
+
+    // El encabezado de la sección queda como `#` y los de dentro suben igual
+    let shift = level - 1;
+    let mut in_code_block = false;
+    let content: Vec<String> = lines[range.start..end]
+        .iter()
+        .map(|line| {
+            if is_fence(line) {
+                in_code_block = !in_code_block;
+            } else if !in_code_block {
+                if let Some(heading) = heading_level(line) {
+                    let new_level = heading.saturating_sub(shift).max(1);
+                    return format!("{}{}", "#".repeat(new_level), &line[heading..]);
+                }
+            }
+            line.to_string()
+        })
+        .collect();
+
+    Section {
+        title,
+        lines: range.start..end,
+        content: format!("{}\n", content.join("\n")),
+    }
+}
+
+fn is_fence(line: &str) -> bool {
+    line.trim_start().starts_with("```") || line.trim_start().starts_with("~~~")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOTE: &str = "---\n# no es título: yaml\n---\n# Proyecto\n\nIntro.\n\n## Fase 1: diseño\n\nBocetos.\n\n### Detalle\n\n```\n## no es sección\n```\n\n## Fase 2\n\nCódigo.\n\n# Anexo\n\nFin.\n";
+
+    #[test]
+    fn test_split_sections() {
+        let sections = split_sections(NOTE, 2);
+        let summary: Vec<_> = sections
+            .iter()
+            .map(|s| (s.title.as_str(), s.lines.clone()))
+            .collect();
+        assert_eq!(summary, [("Fase 1: diseño", 7..16), ("Fase 2", 17..20)]);
+        assert_eq!(
+            sections[0].content,
+            "# Fase 1: diseño\n\nBocetos.\n\n## Detalle\n\n```\n## no es sección\n```\n"
+        );
+
+        let h1: Vec<_> = split_sections(NOTE, 1)
+            .into_iter()
+            .map(|s| s.title)
+            .collect();
+        assert_eq!(h1, ["Proyecto", "Anexo"]);
+    }
+
+    #[test]
+    fn test_replace_sections() {
+        let sections = split_sections(NOTE, 2);
+        let names = vec![
+            "Proyecto/Fase 1 diseño".to_string(),
+            "Proyecto/Fase 2".to_string(),
+        ];
+        assert_eq!(
+            replace_sections(NOTE, &sections, &names),
+            "---\n# no es título: yaml\n---\n# Proyecto\n\nIntro.\n\n\
+             - @Proyecto/Fase 1 diseño\n- @Proyecto/Fase 2\n\n# Anexo\n\nFin.\n"
+        );
+    }
+
+    #[test]
+    fn test_note_name_from_title() {
+        assert_eq!(note_name_from_title("Fase 1: diseño"), "Fase 1 diseño");
+        assert_eq!(note_name_from_title("**Plan** / v2"), "Plan v2");
+        assert_eq!(note_name_from_title("!!!"), "");
+    }
+}
@@ -0,0 +1,190 @@
+//! Perfilado del arranque
+//!
+//! Mide cuánto tarda cada fase del arranque (CSS, interfaz, base de datos,
+//! MCP...) hasta que la ventana se ve y acepta texto, y cuándo terminan las
+//! que se aplazan a después del primer dibujado (bandeja, asistente y
+//! embeddings, servidor MCP). Con `--profile-startup` (o
+//! `NOTNATIVE_PROFILE_STARTUP=1`) se imprime la tabla completa; si no, solo
+//! el tiempo hasta la ventana.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Objetivo hasta una ventana editable con un vault ya indexado
+pub const STARTUP_TARGET: Duration = Duration::from_millis(500);
+
+/// Si se pidió la tabla de tiempos del arranque
+pub fn profiling_requested() -> bool {
+    std::env::args().any(|arg| arg == "--profile-startup")
+        || std::env::var("NOTNATIVE_PROFILE_STARTUP").is_ok_and(|value| value != "0")
+}
+
+/// Una fase medida
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StartupPhase {
+    pub name: String,
+    pub duration: Duration,
+    /// Desde el inicio del proceso hasta el final de la fase
+    pub end: Duration,
+    /// Se hizo después de mostrar la ventana
+    pub deferred: bool,
+}
+
+/// Cronómetro de las fases del arranque
+#[derive(Debug, Clone)]
+pub struct StartupTimer {
+    start: Instant,
+    last: Instant,
+    phases: Vec<StartupPhase>,
+    ready: Option<Duration>,
+}
+
+impl StartupTimer {
+    pub fn new(start: Instant) -> Self {
+        Self {
+            start,
+            last: start,
+            phases: Vec::new(),
+            ready: None,
+        }
+    }
+
+    /// Cierra la fase que empezó al cerrar la anterior (o al arrancar)
+    pub fn phase(&mut self, name: &str, now: Instant) {
+        self.phases.push(StartupPhase {
+            name: name.to_string(),
+            duration: now.saturating_duration_since(self.last),
+            end: now.saturating_duration_since(self.start),
+            deferred: self.ready.is_some(),
+        });
+        self.last = now;
+    }
+
+    /// Apunta una tarea aplazada que empezó en `started`
+    pub fn deferred(&mut self, name: &str, started: Instant, now: Instant) {
+        self.phases.push(StartupPhase {
+            name: name.to_string(),
+            duration: now.saturating_duration_since(started),
+            end: now.saturating_duration_since(self.start),
+            deferred: true,
+        });
+    }
+
+    /// La ventana ya se ve: devuelve el tiempo desde el arranque
+    pub fn window_ready(&mut self, now: Instant) -> Duration {
+        self.phase("first_paint", now);
+        let ready = now.saturating_duration_since(self.start);
+        self.ready = Some(ready);
+        ready
+    }
+
+    /// Tiempo hasta la ventana, si ya se mostró
+    pub fn ready(&self) -> Option<Duration> {
+        self.ready
+    }
+
+    pub fn phases(&self) -> &[StartupPhase] {
+        &self.phases
+    }
+
+    /// Tabla de tiempos: primero las fases hasta la ventana y después las
+    /// aplazadas
+    pub fn report(&self) -> String {
+        let ms = |d: Duration| d.as_secs_f64() * 1000.0;
+        let mut text = match self.ready {
+            Some(ready) => format!(
+                "Arranque: ventana lista en {:.0} ms (objetivo {} ms)\n",
+                ms(ready),
+                STARTUP_TARGET.as_millis()
+            ),
+            None => "Arranque: la ventana aún no se ha mostrado\n".to_string(),
+        };
+        let row = |phase: &StartupPhase| {
+            format!(
+                "  {:<20} {:>8.1} ms  @ {:>6.0} ms\n",
+                phase.name,
+                ms(phase.duration),
+                ms(phase.end)
+            )
+        };
+        for phase in self.phases.iter().filter(|p| !p.deferred) {
+            text.push_str(&row(phase));
+        }
+        if self.phases.iter().any(|p| p.deferred) {
+            text.push_str("Aplazado tras la ventana:\n");
+            for phase in self.phases.iter().filter(|p| p.deferred) {
+                text.push_str(&row(phase));
+            }
+        }
+        text
+    }
+}
+
+static TIMER: Mutex<Option<StartupTimer>> = Mutex::new(None);
+
+/// Empieza a medir el arranque del proceso
+pub fn start() {
+    *TIMER.lock().unwrap() = Some(StartupTimer::new(Instant::now()));
+}
+
+/// Cierra una fase del arranque (no hace nada si no se llamó a [`start`])
+pub fn mark(name: &str) {
+    if let Some(timer) = TIMER.lock().unwrap().as_mut() {
+        timer.phase(name, Instant::now());
+    }
+}
+
+/// Apunta una tarea aplazada que empezó en `started`
+pub fn mark_deferred(name: &str, started: Instant) {
+    if let Some(timer) = TIMER.lock().unwrap().as_mut() {
+        timer.deferred(name, started, Instant::now());
+    }
+}
+
+/// La ventana ya se ve; devuelve el tiempo desde el arranque
+pub fn window_ready() -> Option<Duration> {
+    TIMER
+        .lock()
+        .unwrap()
+        .as_mut()
+        .map(|timer| timer.window_ready(Instant::now()))
+}
+
+/// Termina la medición y devuelve la tabla de tiempos. Lo que se apunte
+/// después ya no cuenta.
+pub fn finish() -> Option<String> {
+    TIMER.lock().unwrap().take().map(|timer| timer.report())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_startup_timer() {
+        let start = Instant::now();
+        let at = |ms: u64| start + Duration::from_millis(ms);
+        let mut timer = StartupTimer::new(start);
+        timer.phase("css", at(20));
+        timer.phase("database", at(120));
+        assert_eq!(timer.window_ready(at(300)), Duration::from_millis(300));
+        timer.phase("tray", at(340));
+        timer.deferred("mcp_server", at(310), at(360));
+
+        let phases = timer.phases();
+        assert_eq!(phases[1].duration, Duration::from_millis(100));
+        assert_eq!(phases[1].end, Duration::from_millis(120));
+        assert_eq!(phases[2].name, "first_paint");
+        assert_eq!(phases[2].duration, Duration::from_millis(180));
+        assert!(!phases[2].deferred);
+        assert!(phases[3].deferred);
+        assert_eq!(phases[3].duration, Duration::from_millis(40));
+        assert_eq!(phases[4].duration, Duration::from_millis(50));
+
+        let report = timer.report();
+        assert!(report.starts_with("Arranque: ventana lista en 300 ms (objetivo 500 ms)\n"));
+        let (ready, deferred) = report.split_once("Aplazado tras la ventana:\n").unwrap();
+        assert!(ready.contains("  database                100.0 ms  @    120 ms\n"));
+        assert!(deferred.contains("mcp_server") && !ready.contains("tray"));
+    }
+}
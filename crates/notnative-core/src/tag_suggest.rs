@@ -0,0 +1,227 @@
+//! Sugerencias de tags mientras se escribe
+//!
+//! Al guardar una nota se proponen tags que ya existen en el vault: los que el
+//! texto nombra como palabras y los que llevan las notas más parecidas (por
+//! embeddings, si están activados). Nunca se inventan tags nuevos, así no se
+//! llena el vault de variantes del mismo.
+
+use std::collections::HashMap;
+
+use crate::database::{NotesDatabase, Result};
+use crate::hybrid_search::semantic_results;
+
+/// Tags sugeridos como máximo para una nota
+pub const MAX_SUGGESTIONS: usize = 3;
+
+/// Puntuación mínima: una mención en el texto o notas parecidas que sumen lo
+/// mismo de similitud
+const MIN_SCORE: f32 = 1.0;
+
+/// Partes más cortas de un tag no cuentan como palabra (`cliente-a` → `cliente`)
+const MIN_WORD_LEN: usize = 3;
+
+/// Hasta [`MAX_SUGGESTIONS`] tags de `vault_tags` (`(tag, notas)`) para una
+/// nota con `content` que ya lleva `current`. `neighbors` son las notas más
+/// parecidas (similitud y sus tags). De más a menos probable.
+pub fn suggest_tags(
+    content: &str,
+    current: &[String],
+    vault_tags: &[(String, usize)],
+    neighbors: &[(f32, Vec<String>)],
+) -> Vec<String> {
+    let words = word_counts(content);
+    let current: Vec<String> = current.iter().map(|tag| tag.to_lowercase()).collect();
+
+    let mut scored: Vec<(f32, usize, &str)> = vault_tags
+        .iter()
+        .filter(|(tag, _)| !already_covered(tag, &current))
+        .filter_map(|(tag, count)| {
+            let mut score = mentions(tag, &words) as f32;
+            score += neighbors
+                .iter()
+                .filter(|(_, tags)| tags.iter().any(|t| t.eq_ignore_ascii_case(tag)))
+                .map(|(similarity, _)| similarity)
+                .sum::<f32>();
+            (score >= MIN_SCORE).then_some((score, *count, tag.as_str()))
+        })
+        .collect();
+
+    scored.sort_by(|a, b| {
+        b.0.total_cmp(&a.0)
+            .then_with(|| b.1.cmp(&a.1))
+            .then_with(|| a.2.cmp(b.2))
+    });
+    scored
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, _, tag)| tag.to_string())
+        .collect()
+}
+
+/// Notas parecidas para [`suggest_tags`] a partir de los aciertos de los
+/// embeddings (`(similitud, fragmento)`): una por nota, con sus tags del índice
+/// y sin la propia nota (`note_path`)
+pub fn neighbor_tags(
+    db: &NotesDatabase,
+    hits: impl IntoIterator<Item = (f32, String)>,
+    note_path: &str,
+) -> Result<Vec<(f32, Vec<String>)>> {
+    semantic_results(db, hits)?
+        .into_iter()
+        .filter(|result| result.note_path != note_path)
+        .map(|result| {
+            let tags = db.get_note_tags(result.note_id)?;
+            Ok((result.relevance, tags.into_iter().map(|t| t.name).collect()))
+        })
+        .collect()
+}
+
+/// La nota ya lleva el tag o uno que cuelga de él (`proyecto` sobra si ya
+/// tiene `proyecto/cliente-a`)
+fn already_covered(tag: &str, current: &[String]) -> bool {
+    let tag = tag.to_lowercase();
+    current.iter().any(|existing| {
+        *existing == tag
+            || existing
+                .strip_prefix(tag.as_str())
+                .is_some_and(|rest| rest.starts_with('/'))
+    })
+}
+
+/// Veces que el texto nombra el último nivel del tag: todas sus partes como
+/// palabras (o en plural con `s`); cuenta la parte que menos aparece
+fn mentions(tag: &str, words: &HashMap<String, usize>) -> usize {
+    let leaf = tag.rsplit('/').next().unwrap_or(tag).to_lowercase();
+    let parts: Vec<&str> = leaf
+        .split(['-', '_'])
+        .filter(|part| part.chars().count() >= MIN_WORD_LEN)
+        .collect();
+    parts
+        .iter()
+        .map(|part| {
+            words.get(*part).copied().unwrap_or(0)
+                + words.get(&format!("{}s", part)).copied().unwrap_or(0)
+        })
+        .min()
+        .unwrap_or(0)
+}
+
+/// Palabras del texto en minúsculas con sus apariciones. Los `#tags` no
+/// cuentan: si están escritos ya son tags de la nota.
+fn word_counts(content: &str) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+    for token in content.split_whitespace() {
+        if token.starts_with('#') {
+            continue;
+        }
+        for word in token.split(|c: char| !c.is_alphanumeric()) {
+            if word.chars().count() >= MIN_WORD_LEN {
+                *counts.entry(word.to_lowercase()).or_insert(0) += 1;
+            }
+        }
+    }
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vault() -> Vec<(String, usize)> {
+        [
+            ("rust", 12),
+            ("gtk", 4),
+            ("recetas", 3),
+            ("proyecto/cliente-acme", 2),
+            ("proyecto", 5),
+            ("viajes", 1),
+        ]
+        .into_iter()
+        .map(|(tag, count)| (tag.to_string(), count))
+        .collect()
+    }
+
+    #[test]
+    fn test_suggest_from_mentions() {
+        let content = "Reunión con el Cliente de ACME sobre el port a Rust.\n\
+                       El cliente quiere la app en rust y #gtk. Receta: ninguna.";
+        let suggestions = suggest_tags(content, &["gtk".to_string()], &vault(), &[]);
+        // rust (2 menciones) antes que cliente-acme (1); gtk ya está
+        assert_eq!(suggestions, ["rust", "proyecto/cliente-acme"]);
+    }
+
+    #[test]
+    fn test_suggest_skips_parents_of_current_tags() {
+        let content = "Proyecto nuevo: proyecto grande, otro proyecto";
+        let current = vec!["proyecto/cliente-acme".to_string()];
+        assert!(suggest_tags(content, &current, &vault(), &[]).is_empty());
+        // En plural también cuenta
+        assert_eq!(
+            suggest_tags("Tres proyectos", &[], &vault(), &[]),
+            ["proyecto"]
+        );
+    }
+
+    #[test]
+    fn test_suggest_from_neighbors() {
+        let neighbors = vec![
+            (0.7, vec!["viajes".to_string(), "gtk".to_string()]),
+            (0.6, vec!["viajes".to_string()]),
+            (0.5, vec!["recetas".to_string()]),
+        ];
+        // viajes suma 1.3; gtk y recetas no llegan al mínimo
+        assert_eq!(
+            suggest_tags("Sin palabras clave", &[], &vault(), &neighbors),
+            ["viajes"]
+        );
+    }
+
+    #[test]
+    fn test_neighbor_tags_from_chunks() {
+        let db_path = std::env::temp_dir().join("test_neighbor_tags.db");
+        std::fs::remove_file(&db_path).ok();
+        let db = NotesDatabase::new(&db_path).unwrap();
+        db.index_note(
+            "Viajes/roma",
+            "/v/Viajes/roma.md",
+            "#viajes #italia",
+            Some("Viajes"),
+        )
+        .unwrap();
+        db.index_note("pasta", "/v/pasta.md", "#recetas", None)
+            .unwrap();
+        db.index_note("borrador", "/v/borrador.md", "#viajes", None)
+            .unwrap();
+
+        // Fragmentos tal como los devuelve la búsqueda, con la nota actual
+        let hits = vec![
+            (0.95, "/v/borrador.md#0".to_string()),
+            (0.8, "/v/Viajes/roma.md#2".to_string()),
+            (0.7, "/v/Viajes/roma.md#0".to_string()),
+            (0.6, "/v/pasta.md#1".to_string()),
+        ];
+        let mut neighbors = neighbor_tags(&db, hits, "/v/borrador.md").unwrap();
+        for (_, tags) in &mut neighbors {
+            tags.sort();
+        }
+        assert_eq!(
+            neighbors,
+            vec![
+                (0.8, vec!["italia".to_string(), "viajes".to_string()]),
+                (0.6, vec!["recetas".to_string()]),
+            ]
+        );
+
+        std::fs::remove_file(db_path).ok();
+    }
+
+    #[test]
+    fn test_suggest_limit() {
+        // Con la misma puntuación van primero los tags más usados
+        let content = "rust gtk recetas viajes proyecto";
+        assert_eq!(
+            suggest_tags(content, &[], &vault(), &[]),
+            ["rust", "proyecto", "gtk"]
+        );
+    }
+}
@@ -0,0 +1,280 @@
+//! Tags anidados (`#proyecto/cliente-a`) y operaciones sobre todo el vault
+//!
+//! Cada `/` de un tag abre un nivel: `proyecto/cliente-a` cuelga de
+//! `proyecto` aunque ninguna nota use `proyecto` a secas. Renombrar un tag
+//! renombra también los que cuelgan de él, y fusionar dos tags es renombrar
+//! uno al nombre del otro.
+
+use crate::frontmatter::{Frontmatter, frontmatter_lines, is_tag_char, update_tags};
+use std::collections::HashMap;
+
+/// Colores de los tags sin color elegido (la paleta de los iconos)
+pub const TAG_PALETTE: [&str; 10] = [
+    "#ff6b6b", "#ff9f43", "#feca57", "#48dbfb", "#1dd1a1", "#5f27cd", "#ff6b9d", "#00d2d3",
+    "#54a0ff", "#c8d6e5",
+];
+
+/// Un nivel del árbol de tags
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagNode {
+    /// Último segmento (`cliente-a`)
+    pub name: String,
+    /// Tag completo (`proyecto/cliente-a`)
+    pub path: String,
+    /// Notas con exactamente este tag
+    pub count: usize,
+    /// Usos de este tag y de todos los que cuelgan de él
+    pub total: usize,
+    pub children: Vec<TagNode>,
+}
+
+/// Árbol de tags a partir de `(tag, notas)`, ordenado por nombre en cada
+/// nivel. Los niveles intermedios que nadie usa aparecen con `count` 0.
+pub fn build_tag_tree(tags: &[(String, usize)]) -> Vec<TagNode> {
+    let mut roots: Vec<TagNode> = Vec::new();
+    for (tag, count) in tags {
+        let segments: Vec<&str> = tag.split('/').filter(|s| !s.is_empty()).collect();
+        let mut level = &mut roots;
+        let mut path = String::new();
+        for (depth, segment) in segments.iter().enumerate() {
+            if !path.is_empty() {
+                path.push('/');
+            }
+            path.push_str(segment);
+            let index = match level.iter().position(|node| node.name == *segment) {
+                Some(index) => index,
+                None => {
+                    level.push(TagNode {
+                        name: segment.to_string(),
+                        path: path.clone(),
+                        count: 0,
+                        total: 0,
+                        children: Vec::new(),
+                    });
+                    level.len() - 1
+                }
+            };
+            level[index].total += count;
+            if depth + 1 == segments.len() {
+                level[index].count += count;
+            }
+            level = &mut level[index].children;
+        }
+    }
+    sort_nodes(&mut roots);
+    roots
+}
+
+fn sort_nodes(nodes: &mut [TagNode]) {
+    nodes.sort_by(|a, b| a.name.cmp(&b.name));
+    for node in nodes {
+        sort_nodes(&mut node.children);
+    }
+}
+
+/// Color de un tag: el elegido para él o para el tag del que cuelga más
+/// cercano y, si no hay, uno de la paleta que sale siempre igual del primer
+/// nivel (`proyecto/a` y `proyecto/b` comparten color)
+pub fn tag_color(tag: &str, custom: &HashMap<String, String>) -> String {
+    let mut path = tag;
+    loop {
+        if let Some(color) = custom.get(path) {
+            return color.clone();
+        }
+        match path.rsplit_once('/') {
+            Some((parent, _)) => path = parent,
+            None => break,
+        }
+    }
+
+    // FNV-1a: estable entre ejecuciones y versiones (no así `DefaultHasher`)
+    let root = tag.split('/').next().unwrap_or(tag);
+    let hash = root.bytes().fold(0x811c9dc5_u32, |hash, byte| {
+        (hash ^ byte as u32).wrapping_mul(0x01000193)
+    });
+    TAG_PALETTE[hash as usize % TAG_PALETTE.len()].to_string()
+}
+
+/// Nuevo nombre de `tag` al renombrar `from` a `to`: el propio tag y los que
+/// cuelgan de él (`from/x` pasa a `to/x`). `None` si no le afecta.
+pub fn renamed_tag(tag: &str, from: &str, to: &str) -> Option<String> {
+    if tag == from {
+        return Some(to.to_string());
+    }
+    tag.strip_prefix(from)
+        .and_then(|rest| rest.strip_prefix('/'))
+        .map(|rest| format!("{}/{}", to, rest))
+}
+
+/// Renombra el tag `from` (y sus hijos) a `to` en una nota: tags inline y
+/// `tags:` del frontmatter. Si `to` ya existía queda uno solo (fusión).
+/// Devuelve `None` si la nota no usa `from`.
+pub fn rename_tag_in_content(content: &str, from: &str, to: &str) -> Option<String> {
+    let from = from.trim().trim_start_matches('#').to_lowercase();
+    let to = to.trim().trim_start_matches('#').to_lowercase();
+    if from.is_empty() || to.is_empty() || from == to {
+        return None;
+    }
+
+    let mut changed = false;
+    let mut lines: Vec<String> = Vec::new();
+    let mut frontmatter_end = frontmatter_lines(content);
+    for line in content.split('\n') {
+        if frontmatter_end > 0 {
+            frontmatter_end -= 1;
+            lines.push(line.to_string());
+            continue;
+        }
+        match rename_inline(line, &from, &to) {
+            Some(renamed) => {
+                changed = true;
+                lines.push(renamed);
+            }
+            None => lines.push(line.to_string()),
+        }
+    }
+    let mut output = lines.join("\n");
+
+    if let Ok((frontmatter, _)) = Frontmatter::parse(&output) {
+        let mut renamed_any = false;
+        let tags: Vec<String> = frontmatter
+            .tags
+            .iter()
+            .map(|tag| match renamed_tag(&tag.to_lowercase(), &from, &to) {
+                Some(renamed) => {
+                    renamed_any = true;
+                    renamed
+                }
+                None => tag.clone(),
+            })
+            .collect();
+        if renamed_any {
+            output = update_tags(&output, tags).ok()?;
+            changed = true;
+        }
+    }
+
+    changed.then_some(output)
+}
+
+/// Renombra los tags inline de una línea, con las mismas reglas con las que
+/// se extraen (`extract_inline_tags`)
+fn rename_inline(line: &str, from: &str, to: &str) -> Option<String> {
+    let trimmed = line.trim_start();
+    if trimmed.starts_with('#') && trimmed.chars().nth(1) == Some(' ') {
+        return None;
+    }
+
+    let mut output = String::with_capacity(line.len());
+    let mut changed = false;
+    let mut last = 0;
+    let mut prev = ' ';
+    let mut index = 0;
+    while index < line.len() {
+        let ch = line[index..].chars().next().unwrap_or(' ');
+        if ch == '#' && (prev.is_whitespace() || prev == '(' || prev == '[') {
+            let start = index + 1;
+            let end = line[start..]
+                .find(|c: char| !is_tag_char(c))
+                .map_or(line.len(), |offset| start + offset);
+            let tag = line[start..end].trim_end_matches('/');
+            if let Some(renamed) = renamed_tag(&tag.to_lowercase(), from, to) {
+                output.push_str(&line[last..start]);
+                output.push_str(&renamed);
+                last = start + tag.len();
+                changed = true;
+            }
+            prev = line[..end].chars().next_back().unwrap_or('#');
+            index = end;
+            continue;
+        }
+        prev = ch;
+        index += ch.len_utf8();
+    }
+
+    if !changed {
+        return None;
+    }
+    output.push_str(&line[last..]);
+    Some(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_tag_tree() {
+        let tags = vec![
+            ("proyecto/cliente-b".to_string(), 1),
+            ("idea".to_string(), 4),
+            ("proyecto/cliente-a".to_string(), 2),
+            ("proyecto/cliente-a/facturas".to_string(), 1),
+        ];
+        let tree = build_tag_tree(&tags);
+        assert_eq!(tree.len(), 2);
+        assert_eq!((tree[0].name.as_str(), tree[0].count), ("idea", 4));
+
+        let proyecto = &tree[1];
+        assert_eq!((proyecto.count, proyecto.total), (0, 4));
+        let children: Vec<_> = proyecto
+            .children
+            .iter()
+            .map(|c| (c.path.as_str(), c.count, c.total))
+            .collect();
+        assert_eq!(
+            children,
+            [("proyecto/cliente-a", 2, 3), ("proyecto/cliente-b", 1, 1)]
+        );
+        assert_eq!(
+            proyecto.children[0].children[0].path,
+            "proyecto/cliente-a/facturas"
+        );
+    }
+
+    #[test]
+    fn test_tag_color() {
+        let mut custom = HashMap::new();
+        let default = tag_color("proyecto", &custom);
+        assert!(TAG_PALETTE.contains(&default.as_str()));
+        assert_eq!(tag_color("proyecto/cliente-a", &custom), default);
+
+        custom.insert("proyecto/cliente-a".to_string(), "#123456".to_string());
+        assert_eq!(tag_color("proyecto/cliente-a/facturas", &custom), "#123456");
+        assert_eq!(tag_color("proyecto/cliente-b", &custom), default);
+    }
+
+    #[test]
+    fn test_renamed_tag() {
+        assert_eq!(renamed_tag("work", "work", "job").as_deref(), Some("job"));
+        assert_eq!(
+            renamed_tag("work/acme", "work", "clientes").as_deref(),
+            Some("clientes/acme")
+        );
+        assert_eq!(renamed_tag("workshop", "work", "job"), None);
+    }
+
+    #[test]
+    fn test_rename_tag_in_content() {
+        let content = "---\ntags: [Work, otro]\n---\n# Work\n\nHoy #work y #work/acme, no #workshop ni a#work.\n(#Work)";
+        let renamed = rename_tag_in_content(content, "#work", "trabajo").unwrap();
+        let (frontmatter, body) = Frontmatter::parse(&renamed).unwrap();
+        assert_eq!(frontmatter.tags, vec!["otro", "trabajo"]);
+        assert_eq!(
+            body,
+            "# Work\n\nHoy #trabajo y #trabajo/acme, no #workshop ni a#work.\n(#trabajo)"
+        );
+
+        assert_eq!(rename_tag_in_content("Sin tags", "work", "job"), None);
+        assert_eq!(rename_tag_in_content("#work", "work", "work"), None);
+    }
+
+    #[test]
+    fn test_rename_tag_merges_into_existing() {
+        let content = "---\ntags: [idea, ideas]\n---\nTexto #ideas";
+        let merged = rename_tag_in_content(content, "ideas", "idea").unwrap();
+        let (frontmatter, body) = Frontmatter::parse(&merged).unwrap();
+        assert_eq!(frontmatter.tags, vec!["idea"]);
+        assert_eq!(body, "Texto #idea");
+    }
+}
@@ -0,0 +1,137 @@
+//! Estados de las tareas: `[ ]` pendiente, `[/]` en progreso, `[x]` hecha y `[-]` cancelada
+
+/// Estado de una tarea según el carácter entre corchetes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskState {
+    Open,
+    InProgress,
+    Done,
+    Cancelled,
+}
+
+impl TaskState {
+    pub fn from_marker(marker: char) -> Option<Self> {
+        match marker {
+            ' ' => Some(Self::Open),
+            '/' => Some(Self::InProgress),
+            'x' | 'X' => Some(Self::Done),
+            '-' => Some(Self::Cancelled),
+            _ => None,
+        }
+    }
+
+    pub fn marker(self) -> char {
+        match self {
+            Self::Open => ' ',
+            Self::InProgress => '/',
+            Self::Done => 'x',
+            Self::Cancelled => '-',
+        }
+    }
+
+    /// Siguiente estado al ciclar: pendiente → en progreso → hecha → cancelada → pendiente
+    pub fn next(self) -> Self {
+        match self {
+            Self::Open => Self::InProgress,
+            Self::InProgress => Self::Done,
+            Self::Done => Self::Cancelled,
+            Self::Cancelled => Self::Open,
+        }
+    }
+
+    /// Nombre usado en las clases CSS y atributos `data-state` del preview
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Open => "open",
+            Self::InProgress => "in-progress",
+            Self::Done => "done",
+            Self::Cancelled => "cancelled",
+        }
+    }
+
+    /// Las canceladas no cuentan para el progreso (ni hechas ni pendientes)
+    pub fn counts_for_progress(self) -> bool {
+        self != Self::Cancelled
+    }
+}
+
+/// Si la línea es una tarea (`- [?] ` o `* [?] `, con indentación opcional),
+/// devuelve su estado y la posición (en caracteres) del marcador entre corchetes
+pub fn parse_task_line(line: &str) -> Option<(usize, TaskState)> {
+    let indent = line.chars().take_while(|c| *c == ' ' || *c == '\t').count();
+    let chars: Vec<char> = line.chars().skip(indent).take(6).collect();
+
+    if chars.len() < 5
+        || !matches!(chars[0], '-' | '*')
+        || chars[1] != ' '
+        || chars[2] != '['
+        || chars[4] != ']'
+    {
+        return None;
+    }
+    // Tras el corchete solo puede venir un espacio o el fin de línea
+    if chars.get(5).is_some_and(|c| !c.is_whitespace()) {
+        return None;
+    }
+    TaskState::from_marker(chars[3]).map(|state| (indent + 3, state))
+}
+
+/// La línea con la tarea en el siguiente estado, o `None` si no es una tarea
+pub fn cycle_task_line(line: &str) -> Option<String> {
+    let (marker, state) = parse_task_line(line)?;
+    Some(
+        line.chars()
+            .enumerate()
+            .map(|(index, c)| {
+                if index == marker {
+                    state.next().marker()
+                } else {
+                    c
+                }
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_task_line() {
+        assert_eq!(parse_task_line("- [ ] Pan"), Some((3, TaskState::Open)));
+        assert_eq!(
+            parse_task_line("    * [/] Leche"),
+            Some((7, TaskState::InProgress))
+        );
+        assert_eq!(parse_task_line("- [X]"), Some((3, TaskState::Done)));
+        assert_eq!(
+            parse_task_line("- [-] Nada"),
+            Some((3, TaskState::Cancelled))
+        );
+
+        assert_eq!(parse_task_line("- [?] Otro"), None);
+        assert_eq!(parse_task_line("- [ ]pegado"), None);
+        assert_eq!(parse_task_line("Texto - [ ] suelto"), None);
+    }
+
+    #[test]
+    fn test_cycle_task_line() {
+        let mut line = "  - [ ] Tarea ñ".to_string();
+        let mut seen = Vec::new();
+        for _ in 0..4 {
+            line = cycle_task_line(&line).unwrap();
+            seen.push(line.clone());
+        }
+        assert_eq!(
+            seen,
+            [
+                "  - [/] Tarea ñ",
+                "  - [x] Tarea ñ",
+                "  - [-] Tarea ñ",
+                "  - [ ] Tarea ñ"
+            ]
+        );
+        assert_eq!(cycle_task_line("Sin tarea"), None);
+    }
+}
@@ -0,0 +1,25 @@
+//! Utilidades compartidas por los tests del crate
+
+use std::fs;
+use std::path::PathBuf;
+
+use crate::{NotesDatabase, NotesDirectory};
+
+/// Vault temporal vacío con su base de datos; devuelve también la carpeta raíz
+/// para que el test la borre al terminar
+pub(crate) fn temp_vault(name: &str) -> (NotesDirectory, NotesDatabase, PathBuf) {
+    let base =
+        std::env::temp_dir().join(format!("notnative-vault-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&base);
+    let dir = NotesDirectory::new(base.join("notes")).unwrap();
+    let db = NotesDatabase::new(&dir.db_path()).unwrap();
+    (dir, db, base)
+}
+
+/// Base de datos temporal vacía fuera de cualquier vault
+pub(crate) fn temp_db(name: &str) -> (NotesDatabase, PathBuf) {
+    let path =
+        std::env::temp_dir().join(format!("notnative-db-{}-{}.db", name, std::process::id()));
+    let _ = fs::remove_file(&path);
+    (NotesDatabase::new(&path).unwrap(), path)
+}
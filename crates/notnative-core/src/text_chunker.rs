@@ -15,6 +15,15 @@ pub struct TextChunk {
     pub token_count: usize,
 }
 
+/// Nota de la que sale un fragmento indexado. Los embeddings guardan cada
+/// trozo como `ruta#índice` (o `nombre#índice` al reindexarlo todo desde MCP).
+pub fn chunk_source(chunk_id: &str) -> &str {
+    match chunk_id.rsplit_once('#') {
+        Some((source, index)) if index.parse::<usize>().is_ok() => source,
+        _ => chunk_id,
+    }
+}
+
 /// Configuración para el chunking
 #[derive(Debug, Clone)]
 pub struct ChunkConfig {
@@ -195,7 +204,7 @@ impl TextChunker {
         let mut current_start = 0;
         let mut index = 0;
 
-        for (i, paragraph) in paragraphs.iter().enumerate() {
+        for paragraph in &paragraphs {
             let para_tokens = self.config.estimate_tokens(paragraph);
             let current_tokens = self.config.estimate_tokens(&current_chunk);
 
@@ -293,6 +302,15 @@ mod tests {
         assert_eq!(chunks[0].end_pos, text.len());
     }
 
+    #[test]
+    fn test_chunk_source() {
+        assert_eq!(chunk_source("/vault/Work/plan.md#3"), "/vault/Work/plan.md");
+        assert_eq!(chunk_source("Work/plan#0"), "Work/plan");
+        // Un # que no va seguido de un índice es parte del nombre
+        assert_eq!(chunk_source("C#"), "C#");
+        assert_eq!(chunk_source("notas#rust"), "notas#rust");
+    }
+
     #[test]
     fn test_empty_text() {
         let chunker = TextChunker::new();
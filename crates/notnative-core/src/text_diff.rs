@@ -0,0 +1,483 @@
+//! Diferencias línea a línea entre dos versiones de un texto
+//!
+//! Para enseñar al usuario qué cambiaría en una nota antes de hacerlo. Es un
+//! diff sencillo (subsecuencia común más larga) pensado para notas, no para
+//! ficheros enormes: por encima de cierto tamaño el bloque cambiado se
+//! muestra entero como borrado y añadido.
+//!
+//! Para comparar dos notas el diff se parte en bloques de cambios
+//! ([`diff_hunks`]), que se pueden ver en dos columnas ([`side_by_side`]) y
+//! resaltar palabra a palabra ([`diff_words`]).
+
+/// Línea de un diff
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffLine {
+    Same(String),
+    Added(String),
+    Removed(String),
+    /// Líneas iguales omitidas por `compact_diff`
+    Skipped(usize),
+}
+
+/// Celdas máximas de la tabla de la subsecuencia común (líneas × líneas)
+const MAX_LCS_CELLS: usize = 4_000_000;
+
+/// Diff de `old` a `new` por líneas
+pub fn diff_lines(old: &str, new: &str) -> Vec<DiffLine> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    // El principio y el final comunes no necesitan la tabla
+    let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let old_middle = &old[prefix..old.len() - suffix];
+    let new_middle = &new[prefix..new.len() - suffix];
+
+    let mut diff: Vec<DiffLine> = old[..prefix]
+        .iter()
+        .map(|line| DiffLine::Same(line.to_string()))
+        .collect();
+    diff.extend(diff_middle(old_middle, new_middle));
+    diff.extend(
+        old[old.len() - suffix..]
+            .iter()
+            .map(|line| DiffLine::Same(line.to_string())),
+    );
+    diff
+}
+
+fn diff_middle(old: &[&str], new: &[&str]) -> Vec<DiffLine> {
+    let removed = |line: &&str| DiffLine::Removed(line.to_string());
+    let added = |line: &&str| DiffLine::Added(line.to_string());
+
+    if old.is_empty() || new.is_empty() || old.len() * new.len() > MAX_LCS_CELLS {
+        return old
+            .iter()
+            .map(removed)
+            .chain(new.iter().map(added))
+            .collect();
+    }
+
+    // lcs[i][j]: longitud de la subsecuencia común de old[i..] y new[j..]
+    let width = new.len() + 1;
+    let mut lcs = vec![0u32; (old.len() + 1) * width];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i * width + j] = if old[i] == new[j] {
+                lcs[(i + 1) * width + j + 1] + 1
+            } else {
+                lcs[(i + 1) * width + j].max(lcs[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut diff = Vec::with_capacity(old.len() + new.len());
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            diff.push(DiffLine::Same(old[i].to_string()));
+            i += 1;
+            j += 1;
+        } else if lcs[(i + 1) * width + j] >= lcs[i * width + j + 1] {
+            diff.push(removed(&old[i]));
+            i += 1;
+        } else {
+            diff.push(added(&new[j]));
+            j += 1;
+        }
+    }
+    diff.extend(old[i..].iter().map(removed));
+    diff.extend(new[j..].iter().map(added));
+    diff
+}
+
+/// Deja solo `context` líneas iguales alrededor de cada cambio; el resto se
+/// resume en `DiffLine::Skipped`
+pub fn compact_diff(diff: Vec<DiffLine>, context: usize) -> Vec<DiffLine> {
+    let changed: Vec<usize> = diff
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| matches!(line, DiffLine::Added(_) | DiffLine::Removed(_)))
+        .map(|(index, _)| index)
+        .collect();
+    let near_change = |index: usize| {
+        changed
+            .iter()
+            .any(|&change| index + context >= change && index <= change + context)
+    };
+
+    let mut compacted = Vec::new();
+    let mut skipped = 0;
+    for (index, line) in diff.into_iter().enumerate() {
+        if matches!(line, DiffLine::Same(_)) && !near_change(index) {
+            skipped += 1;
+            continue;
+        }
+        if skipped > 0 {
+            compacted.push(DiffLine::Skipped(skipped));
+            skipped = 0;
+        }
+        compacted.push(line);
+    }
+    if skipped > 0 {
+        compacted.push(DiffLine::Skipped(skipped));
+    }
+    compacted
+}
+
+/// Diff por palabras entre dos líneas. Cada `DiffLine` es un trozo (palabras
+/// y espacios seguidos del mismo tipo); nunca sale `Skipped`.
+pub fn diff_words(old: &str, new: &str) -> Vec<DiffLine> {
+    let old_words = split_words(old);
+    let new_words = split_words(new);
+
+    let mut pieces: Vec<DiffLine> = Vec::new();
+    for piece in diff_middle(&old_words, &new_words) {
+        match (pieces.last_mut(), piece) {
+            (Some(DiffLine::Same(last)), DiffLine::Same(text))
+            | (Some(DiffLine::Added(last)), DiffLine::Added(text))
+            | (Some(DiffLine::Removed(last)), DiffLine::Removed(text)) => last.push_str(&text),
+            (_, piece) => pieces.push(piece),
+        }
+    }
+    pieces
+}
+
+/// Palabras y espacios, sin perder nada: juntas dan el texto original
+fn split_words(text: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    let mut start = 0;
+    let mut in_space: Option<bool> = None;
+    for (index, ch) in text.char_indices() {
+        let space = ch.is_whitespace();
+        if in_space.is_some_and(|in_space| in_space != space) {
+            words.push(&text[start..index]);
+            start = index;
+        }
+        in_space = Some(space);
+    }
+    if start < text.len() {
+        words.push(&text[start..]);
+    }
+    words
+}
+
+/// Bloque de cambios con sus líneas de contexto
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffHunk {
+    /// Primera línea del bloque en el texto viejo (desde 1)
+    pub old_start: usize,
+    /// Primera línea del bloque en el texto nuevo (desde 1)
+    pub new_start: usize,
+    pub lines: Vec<DiffLine>,
+}
+
+impl DiffHunk {
+    /// El bloque como estaba (contexto y líneas borradas)
+    pub fn old_text(&self) -> String {
+        self.side_text(|line| !matches!(line, DiffLine::Added(_)))
+    }
+
+    /// El bloque como queda (contexto y líneas añadidas)
+    pub fn new_text(&self) -> String {
+        self.side_text(|line| !matches!(line, DiffLine::Removed(_)))
+    }
+
+    fn side_text(&self, keep: impl Fn(&DiffLine) -> bool) -> String {
+        self.lines
+            .iter()
+            .filter(|line| keep(line))
+            .filter_map(|line| match line {
+                DiffLine::Same(text) | DiffLine::Added(text) | DiffLine::Removed(text) => {
+                    Some(text.as_str())
+                }
+                DiffLine::Skipped(_) => None,
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// El bloque en formato diff unificado (`@@ -1,3 +1,4 @@`)
+    pub fn unified(&self) -> String {
+        let old_len = self
+            .lines
+            .iter()
+            .filter(|line| matches!(line, DiffLine::Same(_) | DiffLine::Removed(_)))
+            .count();
+        let new_len = self
+            .lines
+            .iter()
+            .filter(|line| matches!(line, DiffLine::Same(_) | DiffLine::Added(_)))
+            .count();
+        let mut text = format!(
+            "@@ -{},{} +{},{} @@",
+            self.old_start, old_len, self.new_start, new_len
+        );
+        for line in &self.lines {
+            let (sign, content) = match line {
+                DiffLine::Same(content) => (' ', content),
+                DiffLine::Added(content) => ('+', content),
+                DiffLine::Removed(content) => ('-', content),
+                DiffLine::Skipped(_) => continue,
+            };
+            text.push('\n');
+            text.push(sign);
+            text.push_str(content);
+        }
+        text
+    }
+}
+
+/// Agrupa los cambios de `diff` en bloques con `context` líneas iguales
+/// alrededor; los cambios con menos de `2 * context` líneas iguales entre
+/// medias van en el mismo bloque
+pub fn diff_hunks(diff: &[DiffLine], context: usize) -> Vec<DiffHunk> {
+    let mut hunks: Vec<DiffHunk> = Vec::new();
+    for group in
+        compact_diff(diff.to_vec(), context).split(|line| matches!(line, DiffLine::Skipped(_)))
+    {
+        if group.is_empty() {
+            continue;
+        }
+        hunks.push(DiffHunk {
+            old_start: 0,
+            new_start: 0,
+            lines: group.to_vec(),
+        });
+    }
+
+    // Números de línea: se recorre el diff completo contando en cada lado
+    let (mut old_line, mut new_line) = (1, 1);
+    let mut next = 0;
+    let mut index = 0;
+    while next < hunks.len() && index < diff.len() {
+        if diff[index..].starts_with(&hunks[next].lines) {
+            hunks[next].old_start = old_line;
+            hunks[next].new_start = new_line;
+            next += 1;
+        }
+        match diff[index] {
+            DiffLine::Same(_) => {
+                old_line += 1;
+                new_line += 1;
+            }
+            DiffLine::Removed(_) => old_line += 1,
+            DiffLine::Added(_) => new_line += 1,
+            DiffLine::Skipped(count) => {
+                old_line += count;
+                new_line += count;
+            }
+        }
+        index += 1;
+    }
+    hunks
+}
+
+/// Fila de un diff en dos columnas: la línea vieja a la izquierda y la nueva
+/// a la derecha (`None` donde un lado no tiene línea)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SideBySideRow {
+    pub left: Option<String>,
+    pub right: Option<String>,
+}
+
+impl SideBySideRow {
+    pub fn changed(&self) -> bool {
+        self.left != self.right
+    }
+}
+
+/// Pone en paralelo cada tanda de líneas borradas con las añadidas que la
+/// siguen, para comparar una línea con su nueva versión
+pub fn side_by_side(lines: &[DiffLine]) -> Vec<SideBySideRow> {
+    let mut rows = Vec::new();
+    let mut removed: Vec<String> = Vec::new();
+    let mut added: Vec<String> = Vec::new();
+    let flush =
+        |rows: &mut Vec<SideBySideRow>, removed: &mut Vec<String>, added: &mut Vec<String>| {
+            let count = removed.len().max(added.len());
+            let mut removed = removed.drain(..);
+            let mut added = added.drain(..);
+            for _ in 0..count {
+                rows.push(SideBySideRow {
+                    left: removed.next(),
+                    right: added.next(),
+                });
+            }
+        };
+
+    for line in lines {
+        match line {
+            DiffLine::Removed(text) => {
+                // Un borrado después de añadidos empieza otra tanda
+                if !added.is_empty() {
+                    flush(&mut rows, &mut removed, &mut added);
+                }
+                removed.push(text.clone());
+            }
+            DiffLine::Added(text) => added.push(text.clone()),
+            DiffLine::Same(text) => {
+                flush(&mut rows, &mut removed, &mut added);
+                rows.push(SideBySideRow {
+                    left: Some(text.clone()),
+                    right: Some(text.clone()),
+                });
+            }
+            DiffLine::Skipped(_) => flush(&mut rows, &mut removed, &mut added),
+        }
+    }
+    flush(&mut rows, &mut removed, &mut added);
+    rows
+}
+
+/// Líneas (añadidas, borradas)
+pub fn diff_stats(diff: &[DiffLine]) -> (usize, usize) {
+    diff.iter()
+        .fold((0, 0), |(added, removed), line| match line {
+            DiffLine::Added(_) => (added + 1, removed),
+            DiffLine::Removed(_) => (added, removed + 1),
+            _ => (added, removed),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn same(line: &str) -> DiffLine {
+        DiffLine::Same(line.to_string())
+    }
+    fn added(line: &str) -> DiffLine {
+        DiffLine::Added(line.to_string())
+    }
+    fn removed(line: &str) -> DiffLine {
+        DiffLine::Removed(line.to_string())
+    }
+
+    #[test]
+    fn test_diff_lines() {
+        let diff = diff_lines(
+            "# Nota\nuno\ndos\ntres\n",
+            "# Nota\nuno\nDOS\ntres\ncuatro\n",
+        );
+        assert_eq!(
+            diff,
+            vec![
+                same("# Nota"),
+                same("uno"),
+                removed("dos"),
+                added("DOS"),
+                same("tres"),
+                added("cuatro"),
+            ]
+        );
+        assert_eq!(diff_stats(&diff), (2, 1));
+
+        // Nota nueva y nota borrada
+        assert_eq!(diff_lines("", "a\nb"), vec![added("a"), added("b")]);
+        assert_eq!(diff_lines("a", ""), vec![removed("a")]);
+        assert!(
+            diff_lines("igual", "igual")
+                .iter()
+                .all(|l| *l == same("igual"))
+        );
+    }
+
+    #[test]
+    fn test_diff_keeps_common_lines_in_the_middle() {
+        let diff = diff_lines("a\nx\nb\ny\nc", "a\nb\nc");
+        assert_eq!(
+            diff,
+            vec![same("a"), removed("x"), same("b"), removed("y"), same("c")]
+        );
+    }
+
+    #[test]
+    fn test_diff_words() {
+        assert_eq!(
+            diff_words("el gato negro duerme", "el perro negro  duerme mucho"),
+            vec![
+                same("el "),
+                removed("gato"),
+                added("perro"),
+                same(" negro"),
+                removed(" "),
+                added("  "),
+                same("duerme"),
+                added(" mucho"),
+            ]
+        );
+        assert_eq!(diff_words("", "hola"), vec![added("hola")]);
+        assert!(diff_words("igual", "igual") == vec![same("igual")]);
+    }
+
+    #[test]
+    fn test_diff_hunks() {
+        let old: String = (1..=12).map(|n| format!("{}\n", n)).collect();
+        let new = old.replace("3\n", "tres\n").replace("11\n", "");
+        let diff = diff_lines(&old, &new);
+        let hunks = diff_hunks(&diff, 1);
+        assert_eq!(hunks.len(), 2);
+
+        assert_eq!((hunks[0].old_start, hunks[0].new_start), (2, 2));
+        assert_eq!(hunks[0].old_text(), "2\n3\n4");
+        assert_eq!(hunks[0].new_text(), "2\ntres\n4");
+        assert_eq!(hunks[0].unified(), "@@ -2,3 +2,3 @@\n 2\n-3\n+tres\n 4");
+
+        assert_eq!((hunks[1].old_start, hunks[1].new_start), (10, 10));
+        assert_eq!(hunks[1].unified(), "@@ -10,3 +10,2 @@\n 10\n-11\n 12");
+
+        assert!(diff_hunks(&diff_lines("a", "a"), 3).is_empty());
+    }
+
+    #[test]
+    fn test_side_by_side() {
+        let rows = side_by_side(&[
+            same("a"),
+            removed("b"),
+            removed("c"),
+            added("B"),
+            same("d"),
+            added("e"),
+        ]);
+        let pair = |left: Option<&str>, right: Option<&str>| SideBySideRow {
+            left: left.map(String::from),
+            right: right.map(String::from),
+        };
+        assert_eq!(
+            rows,
+            vec![
+                pair(Some("a"), Some("a")),
+                pair(Some("b"), Some("B")),
+                pair(Some("c"), None),
+                pair(Some("d"), Some("d")),
+                pair(None, Some("e")),
+            ]
+        );
+        assert!(!rows[0].changed());
+        assert!(rows[2].changed());
+    }
+
+    #[test]
+    fn test_compact() {
+        let old: String = (1..=10).map(|n| format!("{}\n", n)).collect();
+        let new = old.replace("5\n", "cinco\n");
+        let diff = compact_diff(diff_lines(&old, &new), 1);
+        assert_eq!(
+            diff,
+            vec![
+                DiffLine::Skipped(3),
+                same("4"),
+                removed("5"),
+                added("cinco"),
+                same("6"),
+                DiffLine::Skipped(4),
+            ]
+        );
+    }
+}
@@ -0,0 +1,107 @@
+//! Estadísticas de texto de una nota (barra de estado y popover de estadísticas)
+
+use pulldown_cmark::{Event, Options, Parser, Tag};
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// Palabras por minuto para estimar el tiempo de lectura
+pub const READING_WORDS_PER_MINUTE: usize = 200;
+
+/// Links internos [[nota]], que pulldown-cmark no reconoce como links
+static WIKI_LINK_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\[\[([^\]]+)\]\]").unwrap());
+
+/// Recuento de palabras, caracteres y elementos markdown de un texto
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TextStats {
+    pub words: usize,
+    pub characters: usize,
+    pub characters_no_spaces: usize,
+    pub paragraphs: usize,
+    pub headings: usize,
+    /// Links markdown, autolinks y links internos [[nota]]
+    pub links: usize,
+    pub code_blocks: usize,
+    pub images: usize,
+}
+
+impl TextStats {
+    /// Solo palabras y caracteres: barato, se recalcula en cada cambio de selección
+    pub fn counts(text: &str) -> Self {
+        let mut stats = Self {
+            words: text.split_whitespace().count(),
+            ..Self::default()
+        };
+        for ch in text.chars() {
+            stats.characters += 1;
+            if !ch.is_whitespace() {
+                stats.characters_no_spaces += 1;
+            }
+        }
+        stats
+    }
+
+    /// Todas las estadísticas, recorriendo el markdown
+    pub fn from_markdown(text: &str) -> Self {
+        let mut stats = Self::counts(text);
+
+        let mut options = Options::empty();
+        options.insert(Options::ENABLE_TABLES);
+        options.insert(Options::ENABLE_STRIKETHROUGH);
+        options.insert(Options::ENABLE_TASKLISTS);
+        for event in Parser::new_ext(text, options) {
+            if let Event::Start(tag) = event {
+                match tag {
+                    Tag::Paragraph => stats.paragraphs += 1,
+                    Tag::Heading { .. } => stats.headings += 1,
+                    Tag::Link { .. } => stats.links += 1,
+                    Tag::Image { .. } => stats.images += 1,
+                    Tag::CodeBlock(_) => stats.code_blocks += 1,
+                    _ => {}
+                }
+            }
+        }
+        stats.links += WIKI_LINK_RE.find_iter(text).count();
+        stats
+    }
+
+    /// Minutos de lectura estimados (redondeando hacia arriba; 0 si no hay texto)
+    pub fn reading_minutes(&self) -> usize {
+        self.words.div_ceil(READING_WORDS_PER_MINUTE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counts() {
+        let stats = TextStats::counts("Hola  mundo\nañó");
+        assert_eq!(stats.words, 3);
+        assert_eq!(stats.characters, 15);
+        assert_eq!(stats.characters_no_spaces, 12);
+        assert_eq!(TextStats::counts("").reading_minutes(), 0);
+    }
+
+    #[test]
+    fn test_from_markdown() {
+        let text = "# Título\n\nUn párrafo con [link](https://a.com) y [[Otra nota]].\n\n\
+                    ## Código\n\n```rust\nfn main() {}\n```\n\n![img](foto.png)\n";
+        let stats = TextStats::from_markdown(text);
+        assert_eq!(stats.headings, 2);
+        assert_eq!(stats.links, 2);
+        assert_eq!(stats.code_blocks, 1);
+        assert_eq!(stats.images, 1);
+        // El de texto y el que contiene la imagen
+        assert_eq!(stats.paragraphs, 2);
+    }
+
+    #[test]
+    fn test_reading_minutes() {
+        let stats = TextStats {
+            words: READING_WORDS_PER_MINUTE + 1,
+            ..TextStats::default()
+        };
+        assert_eq!(stats.reading_minutes(), 2);
+    }
+}
@@ -0,0 +1,327 @@
+//! Estado del vault (panel de salud)
+//!
+//! Resume el conjunto de notas: cuántas hay y cuántas palabras suman, las
+//! más grandes, las que nadie toca desde hace más de un año, las que no
+//! tienen tags, las huérfanas (sin enlaces ni de entrada ni de salida) y las
+//! que faltan en el índice de búsqueda o de embeddings. Cada métrica guarda
+//! su lista de notas para poder abrirlas desde el panel.
+
+use crate::frontmatter::extract_all_tags;
+use crate::links::split_link_target;
+use crate::text_stats::TextStats;
+use std::collections::{HashMap, HashSet};
+
+/// Notas que se listan en "las más grandes"
+pub const LARGEST_COUNT: usize = 10;
+
+/// Días sin modificar a partir de los que una nota se considera olvidada
+pub const STALE_AFTER_DAYS: i64 = 365;
+
+/// Lo que hace falta de cada nota para el resumen
+#[derive(Debug, Clone, PartialEq)]
+pub struct NoteSummary {
+    pub name: String,
+    pub path: String,
+    pub words: usize,
+    /// Última modificación (segundos Unix)
+    pub modified: i64,
+    pub tagged: bool,
+    /// Destinos de sus menciones `@Nota` y wikilinks `[[Nota]]`, sin
+    /// encabezado ni alias
+    pub links: Vec<String>,
+}
+
+impl NoteSummary {
+    pub fn from_content(name: &str, path: &str, content: &str, modified: i64) -> Self {
+        Self {
+            name: name.to_string(),
+            path: path.to_string(),
+            words: TextStats::counts(content).words,
+            modified,
+            tagged: !extract_all_tags(content).is_empty(),
+            links: outgoing_links(content),
+        }
+    }
+}
+
+/// Métricas del panel; cada una abre su lista de notas
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthMetric {
+    Largest,
+    Stale,
+    Untagged,
+    Orphans,
+    /// Notas en disco que no están en el índice de búsqueda
+    NotIndexed,
+    /// Notas sin embeddings (solo con la búsqueda semántica activada)
+    NoEmbeddings,
+}
+
+impl HealthMetric {
+    pub const ALL: [HealthMetric; 6] = [
+        HealthMetric::Largest,
+        HealthMetric::Stale,
+        HealthMetric::Untagged,
+        HealthMetric::Orphans,
+        HealthMetric::NotIndexed,
+        HealthMetric::NoEmbeddings,
+    ];
+}
+
+/// Nota dentro de la lista de una métrica, con el dato que la explica
+/// (palabras, fecha...)
+#[derive(Debug, Clone, PartialEq)]
+pub struct HealthEntry {
+    pub name: String,
+    pub words: usize,
+    pub modified: i64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VaultHealth {
+    pub total_notes: usize,
+    pub total_words: usize,
+    pub largest: Vec<HealthEntry>,
+    pub stale: Vec<HealthEntry>,
+    pub untagged: Vec<HealthEntry>,
+    pub orphans: Vec<HealthEntry>,
+    pub not_indexed: Vec<HealthEntry>,
+    /// `None` si la búsqueda semántica está desactivada
+    pub no_embeddings: Option<Vec<HealthEntry>>,
+}
+
+impl VaultHealth {
+    /// Calcula el resumen.
+    ///
+    /// - `aliases`: `(alias, nota)` para resolver enlaces que usan un alias.
+    /// - `indexed`: nombres de las notas en el índice de búsqueda.
+    /// - `embedded`: rutas de las notas con embeddings, o `None` si la
+    ///   búsqueda semántica está desactivada.
+    pub fn compute(
+        notes: &[NoteSummary],
+        aliases: &[(String, String)],
+        indexed: &HashSet<String>,
+        embedded: Option<&HashSet<String>>,
+        now: i64,
+    ) -> Self {
+        let entry = |note: &NoteSummary| HealthEntry {
+            name: note.name.clone(),
+            words: note.words,
+            modified: note.modified,
+        };
+
+        // Destino de un enlace: nombre completo, nombre sin carpeta o alias
+        let mut targets: HashMap<String, &str> = HashMap::new();
+        for note in notes {
+            let base = note.name.rsplit('/').next().unwrap_or(&note.name);
+            targets
+                .entry(base.to_lowercase())
+                .or_insert(note.name.as_str());
+        }
+        for (alias, name) in aliases {
+            if let Some(note) = notes.iter().find(|note| &note.name == name) {
+                targets
+                    .entry(alias.to_lowercase())
+                    .or_insert(note.name.as_str());
+            }
+        }
+        for note in notes {
+            targets.insert(note.name.to_lowercase(), note.name.as_str());
+        }
+
+        // Notas que enlazan a otra o que reciben algún enlace
+        let mut linked: HashSet<&str> = HashSet::new();
+        for note in notes {
+            for link in &note.links {
+                if let Some(target) = targets.get(&link.to_lowercase()) {
+                    if *target != note.name {
+                        linked.insert(target);
+                        linked.insert(&note.name);
+                    }
+                }
+            }
+        }
+
+        let stale_before = now - STALE_AFTER_DAYS * 24 * 60 * 60;
+        let mut health = Self {
+            total_notes: notes.len(),
+            total_words: notes.iter().map(|note| note.words).sum(),
+            no_embeddings: embedded.map(|_| Vec::new()),
+            ..Self::default()
+        };
+        for note in notes {
+            if note.modified < stale_before {
+                health.stale.push(entry(note));
+            }
+            if !note.tagged {
+                health.untagged.push(entry(note));
+            }
+            if !linked.contains(note.name.as_str()) {
+                health.orphans.push(entry(note));
+            }
+            if !indexed.contains(&note.name) {
+                health.not_indexed.push(entry(note));
+            }
+            if let (Some(embedded), Some(missing)) = (embedded, health.no_embeddings.as_mut()) {
+                if !embedded.contains(&note.path) {
+                    missing.push(entry(note));
+                }
+            }
+        }
+
+        health.largest = notes.iter().map(entry).collect();
+        health
+            .largest
+            .sort_by(|a, b| b.words.cmp(&a.words).then_with(|| a.name.cmp(&b.name)));
+        health.largest.truncate(LARGEST_COUNT);
+        // Las más olvidadas primero
+        health.stale.sort_by_key(|entry| entry.modified);
+        health
+    }
+
+    /// Notas de una métrica
+    pub fn entries(&self, metric: HealthMetric) -> &[HealthEntry] {
+        match metric {
+            HealthMetric::Largest => &self.largest,
+            HealthMetric::Stale => &self.stale,
+            HealthMetric::Untagged => &self.untagged,
+            HealthMetric::Orphans => &self.orphans,
+            HealthMetric::NotIndexed => &self.not_indexed,
+            HealthMetric::NoEmbeddings => self.no_embeddings.as_deref().unwrap_or_default(),
+        }
+    }
+}
+
+/// Rutas de las notas con embeddings a partir de los IDs de sus fragmentos
+/// (`ruta#n`)
+pub fn embedded_paths(chunk_ids: impl IntoIterator<Item = String>) -> HashSet<String> {
+    chunk_ids
+        .into_iter()
+        .map(|id| match id.rsplit_once('#') {
+            Some((path, chunk)) if chunk.parse::<usize>().is_ok() => path.to_string(),
+            _ => id,
+        })
+        .collect()
+}
+
+/// Destinos de las menciones `@Nota` y wikilinks `[[Nota]]` de un texto, sin
+/// el encabezado (`#...`) ni el alias (`|...`) y sin repetir
+pub fn outgoing_links(content: &str) -> Vec<String> {
+    let mut links: Vec<String> = Vec::new();
+    let mut push = |target: &str| {
+        let (note, _) = split_link_target(target);
+        let note = note.trim();
+        if !note.is_empty() && !links.iter().any(|link| link == note) {
+            links.push(note.to_string());
+        }
+    };
+
+    let mut prev: Option<char> = None;
+    let mut index = 0;
+    while index < content.len() {
+        let rest = &content[index..];
+        if let Some(inner) = rest.strip_prefix("[[") {
+            if let Some(end) = inner.find("]]") {
+                let target = &inner[..end];
+                if !target.contains('\n') {
+                    push(target.split('|').next().unwrap_or(target));
+                    index += 2 + end + 2;
+                    prev = Some(']');
+                    continue;
+                }
+            }
+        } else if let Some(after) = rest.strip_prefix('@') {
+            // Como en el editor: tras un espacio o signo, no en un email
+            let starts =
+                prev.is_none_or(|prev| prev.is_whitespace() || matches!(prev, '(' | '[' | ','));
+            let len = after
+                .find(|ch: char| !(ch.is_alphanumeric() || matches!(ch, '-' | '_' | '/')))
+                .unwrap_or(after.len());
+            if starts && len > 0 {
+                push(after[..len].trim_end_matches('/'));
+                index += 1 + len;
+                prev = after[..len].chars().next_back();
+                continue;
+            }
+        }
+        let ch = rest.chars().next().unwrap_or(' ');
+        prev = Some(ch);
+        index += ch.len_utf8();
+    }
+    links
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: i64 = 24 * 60 * 60;
+
+    #[test]
+    fn test_outgoing_links() {
+        let content = "Ver @Ideas y @proyectos/Plan#fechas, escribir a yo@correo.es\n\
+                       [[Recetas|mis recetas]], [[Ideas]] y [[Viaje#Día 1]]";
+        assert_eq!(
+            outgoing_links(content),
+            vec!["Ideas", "proyectos/Plan", "Recetas", "Viaje"]
+        );
+        assert!(outgoing_links("Sin enlaces, ni @ suelta").is_empty());
+    }
+
+    #[test]
+    fn test_vault_health() {
+        let now = 1000 * DAY;
+        let notes = vec![
+            NoteSummary::from_content(
+                "Inicio",
+                "/n/Inicio.md",
+                "#portada Ver @Plan y [[MCP]]",
+                now,
+            ),
+            NoteSummary::from_content(
+                "trabajo/Plan",
+                "/n/trabajo/Plan.md",
+                "Un plan largo con muchas palabras",
+                now - 400 * DAY,
+            ),
+            NoteSummary::from_content("Conexión", "/n/Conexión.md", "Texto", now - 10 * DAY),
+            NoteSummary::from_content("Suelta", "/n/Suelta.md", "Nada #idea", now - 800 * DAY),
+        ];
+        let aliases = vec![("mcp".to_string(), "Conexión".to_string())];
+        let indexed: HashSet<String> = ["Inicio", "trabajo/Plan", "Conexión"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        let embedded = embedded_paths(vec!["/n/Inicio.md#0".into(), "/n/Inicio.md#1".into()]);
+
+        let health = VaultHealth::compute(&notes, &aliases, &indexed, Some(&embedded), now);
+        let names = |metric| {
+            health
+                .entries(metric)
+                .iter()
+                .map(|entry| entry.name.as_str())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(health.total_notes, 4);
+        assert_eq!(health.total_words, 5 + 6 + 1 + 2);
+        assert_eq!(names(HealthMetric::Largest)[0], "trabajo/Plan");
+        assert_eq!(names(HealthMetric::Stale), vec!["Suelta", "trabajo/Plan"]);
+        assert_eq!(
+            names(HealthMetric::Untagged),
+            vec!["trabajo/Plan", "Conexión"]
+        );
+        // La mención sin carpeta y el alias cuentan como enlaces
+        assert_eq!(names(HealthMetric::Orphans), vec!["Suelta"]);
+        assert_eq!(names(HealthMetric::NotIndexed), vec!["Suelta"]);
+        assert_eq!(
+            names(HealthMetric::NoEmbeddings),
+            vec!["trabajo/Plan", "Conexión", "Suelta"]
+        );
+
+        // Sin búsqueda semántica no hay nada que medir
+        let health = VaultHealth::compute(&notes, &aliases, &indexed, None, now);
+        assert_eq!(health.no_embeddings, None);
+        assert!(health.entries(HealthMetric::NoEmbeddings).is_empty());
+    }
+}
@@ -0,0 +1,611 @@
+//! Cambios vistos por el vigilante de archivos
+//!
+//! Los eventos del sistema llegan a ráfagas (un `git checkout` toca cientos
+//! de archivos a la vez) y un renombrado puede llegar como un único evento o
+//! como dos sueltos (origen y destino). [`EventDebouncer`] los junta hasta que
+//! hay un momento de calma y los resume en [`WatchAction`]s: un renombrado se
+//! queda en renombrado (la nota conserva id, tags y posición de lectura) y
+//! varios cambios del mismo archivo se quedan en uno.
+//!
+//! [`WatchIgnore`] descarta las rutas que no son notas (papelera, `.git`,
+//! adjuntos...), con los patrones de `NotesConfig::watch_ignore`.
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use tracing::{error, info};
+
+use crate::database::{NotesDatabase, Result};
+
+/// Calma que se espera antes de procesar una ráfaga
+pub const WATCH_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Patrones ignorados por defecto
+pub fn default_watch_ignore() -> Vec<String> {
+    [".trash", ".history", ".git", "attachments"]
+        .iter()
+        .map(|pattern| pattern.to_string())
+        .collect()
+}
+
+/// `*` es cualquier texto y `?` cualquier carácter
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star = Some((p, t));
+            p += 1;
+        } else if let Some((star_p, star_t)) = star {
+            p = star_p + 1;
+            t = star_t + 1;
+            star = Some((star_p, star_t + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Rutas que el vigilante no tiene en cuenta
+#[derive(Debug, Clone, Default)]
+pub struct WatchIgnore {
+    patterns: Vec<String>,
+}
+
+impl WatchIgnore {
+    pub fn new(patterns: &[String]) -> Self {
+        Self {
+            patterns: patterns
+                .iter()
+                .map(|pattern| pattern.trim().trim_matches('/').to_string())
+                .filter(|pattern| !pattern.is_empty())
+                .collect(),
+        }
+    }
+
+    /// Un patrón sin `/` se compara con cada parte de la ruta (`.git` ignora
+    /// cualquier carpeta `.git`, `*.tmp` cualquier archivo así); uno con `/`
+    /// se compara con el principio de la ruta relativa (`Archivo/2020`)
+    pub fn is_ignored(&self, relative: &Path) -> bool {
+        let parts: Vec<String> = relative
+            .components()
+            .map(|part| part.as_os_str().to_string_lossy().to_string())
+            .collect();
+        self.patterns.iter().any(|pattern| {
+            if pattern.contains('/') {
+                let pattern_parts: Vec<&str> = pattern.split('/').collect();
+                pattern_parts.len() <= parts.len()
+                    && pattern_parts
+                        .iter()
+                        .zip(&parts)
+                        .all(|(pattern, part)| glob_match(pattern, part))
+            } else {
+                parts.iter().any(|part| glob_match(pattern, part))
+            }
+        })
+    }
+}
+
+/// Evento tal y como llega del sistema
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WatchEvent {
+    /// Creado o modificado
+    Changed(PathBuf),
+    Removed(PathBuf),
+    /// Renombrado con origen y destino en el mismo evento
+    Renamed(PathBuf, PathBuf),
+    /// Origen de un renombrado; el destino llega en el siguiente evento
+    RenamedFrom(PathBuf),
+    RenamedTo(PathBuf),
+}
+
+/// Lo que hay que hacer en el índice
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WatchAction {
+    Index(PathBuf),
+    Remove(PathBuf),
+    Rename {
+        from: PathBuf,
+        to: PathBuf,
+    },
+    RenameFolder {
+        from: PathBuf,
+        to: PathBuf,
+    },
+    RemoveFolder(PathBuf),
+    /// Una carpeta que aparece de golpe (movida desde fuera o desde la
+    /// papelera): hay que indexar sus notas
+    IndexFolder(PathBuf),
+}
+
+/// Junta los eventos de una ráfaga
+#[derive(Debug)]
+pub struct EventDebouncer {
+    root: PathBuf,
+    ignore: WatchIgnore,
+    delay: Duration,
+    actions: Vec<WatchAction>,
+    pending_from: Option<PathBuf>,
+    last_event: Option<Instant>,
+}
+
+impl EventDebouncer {
+    pub fn new(root: PathBuf, ignore: WatchIgnore, delay: Duration) -> Self {
+        Self {
+            root,
+            ignore,
+            delay,
+            actions: Vec::new(),
+            pending_from: None,
+            last_event: None,
+        }
+    }
+
+    /// Dentro de la carpeta de notas y no ignorada
+    fn is_watched(&self, path: &Path) -> bool {
+        path.strip_prefix(&self.root).is_ok_and(|relative| {
+            !relative.as_os_str().is_empty() && !self.ignore.is_ignored(relative)
+        })
+    }
+
+    fn is_note(&self, path: &Path) -> bool {
+        path.extension().is_some_and(|ext| ext == "md") && self.is_watched(path)
+    }
+
+    /// Sin extensión: puede ser una carpeta (quien aplica la acción lo comprueba)
+    fn is_folder(&self, path: &Path) -> bool {
+        path.extension().is_none() && self.is_watched(path)
+    }
+
+    pub fn push(&mut self, event: WatchEvent, now: Instant) {
+        self.last_event = Some(now);
+        match event {
+            WatchEvent::Changed(path) => self.changed(path),
+            WatchEvent::Removed(path) => self.removed(path),
+            WatchEvent::Renamed(from, to) => self.renamed(from, to),
+            WatchEvent::RenamedFrom(from) => {
+                if let Some(previous) = self.pending_from.replace(from) {
+                    self.removed(previous);
+                }
+            }
+            WatchEvent::RenamedTo(to) => match self.pending_from.take() {
+                Some(from) => self.renamed(from, to),
+                None => self.appeared(to),
+            },
+        }
+    }
+
+    /// Hay algo pendiente y ya pasó la ráfaga
+    pub fn is_due(&self, now: Instant) -> bool {
+        (!self.actions.is_empty() || self.pending_from.is_some())
+            && self
+                .last_event
+                .is_some_and(|last| now.saturating_duration_since(last) >= self.delay)
+    }
+
+    /// Acciones acumuladas, en orden. Un origen de renombrado sin destino es
+    /// algo que salió de la carpeta.
+    pub fn take(&mut self) -> Vec<WatchAction> {
+        if let Some(from) = self.pending_from.take() {
+            self.removed(from);
+        }
+        self.last_event = None;
+        std::mem::take(&mut self.actions)
+    }
+
+    fn position(&self, action: &WatchAction) -> Option<usize> {
+        self.actions.iter().position(|a| a == action)
+    }
+
+    /// Algunos sistemas avisan de un renombrado dos veces (por separado y
+    /// con origen y destino juntos)
+    fn push_once(&mut self, action: WatchAction) {
+        if self.position(&action).is_none() {
+            self.actions.push(action);
+        }
+    }
+
+    fn changed(&mut self, path: PathBuf) {
+        if !self.is_note(&path) {
+            return;
+        }
+        if let Some(i) = self.position(&WatchAction::Remove(path.clone())) {
+            self.actions.remove(i);
+        }
+        self.push_once(WatchAction::Index(path));
+    }
+
+    fn removed(&mut self, path: PathBuf) {
+        if self.is_folder(&path) {
+            self.push_once(WatchAction::RemoveFolder(path));
+            return;
+        }
+        if !self.is_note(&path) {
+            return;
+        }
+        self.actions
+            .retain(|action| *action != WatchAction::Index(path.clone()));
+        let renamed_into = self
+            .actions
+            .iter()
+            .position(|action| matches!(action, WatchAction::Rename { to, .. } if *to == path));
+        let removed = match renamed_into {
+            Some(i) => match self.actions.remove(i) {
+                WatchAction::Rename { from, .. } => from,
+                _ => unreachable!(),
+            },
+            None => path,
+        };
+        self.push_once(WatchAction::Remove(removed));
+    }
+
+    /// Algo que entra en la carpeta desde fuera
+    fn appeared(&mut self, path: PathBuf) {
+        if self.is_folder(&path) {
+            self.push_once(WatchAction::IndexFolder(path));
+        } else {
+            self.changed(path);
+        }
+    }
+
+    fn renamed(&mut self, from: PathBuf, to: PathBuf) {
+        match (self.is_note(&from), self.is_note(&to)) {
+            (true, true) => {}
+            (true, false) => return self.removed(from),
+            (false, true) => return self.changed(to),
+            (false, false) => {
+                match (self.is_folder(&from), self.is_folder(&to)) {
+                    (true, true) => self.push_once(WatchAction::RenameFolder { from, to }),
+                    (true, false) => self.removed(from),
+                    (false, true) => self.appeared(to),
+                    (false, false) => {}
+                }
+                return;
+            }
+        }
+
+        let rename = WatchAction::Rename {
+            from: from.clone(),
+            to: to.clone(),
+        };
+        if self.actions.contains(&rename) {
+            return;
+        }
+
+        let had_changes = self.actions.contains(&WatchAction::Index(from.clone()));
+        self.actions
+            .retain(|action| *action != WatchAction::Index(from.clone()));
+        // Lo que había en el destino se sustituye
+        self.actions
+            .retain(|action| *action != WatchAction::Remove(to.clone()));
+
+        // a → b y luego b → c es a → c
+        let chained = self
+            .actions
+            .iter()
+            .position(|action| matches!(action, WatchAction::Rename { to, .. } if *to == from));
+        let origin = match chained {
+            Some(i) => match self.actions.remove(i) {
+                WatchAction::Rename { from, .. } => from,
+                _ => unreachable!(),
+            },
+            None => from,
+        };
+        if origin != to {
+            self.actions.push(WatchAction::Rename {
+                from: origin,
+                to: to.clone(),
+            });
+        }
+        if had_changes {
+            self.actions.push(WatchAction::Index(to));
+        }
+    }
+}
+
+fn note_name(path: &Path) -> String {
+    path.file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+fn relative_folder(root: &Path, path: &Path) -> Option<String> {
+    path.parent()
+        .and_then(|parent| parent.strip_prefix(root).ok())
+        .filter(|parent| !parent.as_os_str().is_empty())
+        .map(|parent| parent.to_string_lossy().to_string())
+}
+
+fn relative_path(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .to_string()
+}
+
+fn index_file(db: &NotesDatabase, root: &Path, path: &Path) -> Result<()> {
+    let content = std::fs::read_to_string(path)?;
+    db.index_note(
+        &note_name(path),
+        &path.to_string_lossy(),
+        &content,
+        relative_folder(root, path).as_deref(),
+    )?;
+    Ok(())
+}
+
+fn index_folder(
+    db: &NotesDatabase,
+    root: &Path,
+    folder: &Path,
+    ignore: &WatchIgnore,
+) -> Result<usize> {
+    let mut indexed = 0;
+    for entry in std::fs::read_dir(folder)?.flatten() {
+        let path = entry.path();
+        let ignored = path
+            .strip_prefix(root)
+            .is_ok_and(|relative| ignore.is_ignored(relative));
+        if ignored {
+            continue;
+        }
+        if path.is_dir() {
+            indexed += index_folder(db, root, &path, ignore)?;
+        } else if path.extension().is_some_and(|ext| ext == "md") {
+            index_file(db, root, &path)?;
+            indexed += 1;
+        }
+    }
+    Ok(indexed)
+}
+
+/// Aplica una acción al índice, en su propia transacción
+pub fn apply_watch_action(
+    db: &NotesDatabase,
+    root: &Path,
+    ignore: &WatchIgnore,
+    action: &WatchAction,
+) -> Result<()> {
+    db.transaction(|db| {
+        match action {
+            WatchAction::Index(path) => {
+                if path.is_file() {
+                    index_file(db, root, path)?;
+                }
+            }
+            WatchAction::Remove(path) => {
+                if let Some(meta) = db.get_note_by_path(&path.to_string_lossy())? {
+                    db.delete_note(&meta.name)?;
+                }
+            }
+            WatchAction::Rename { from, to } => {
+                let folder = relative_folder(root, to);
+                match db.get_note_by_path(&from.to_string_lossy())? {
+                    // Misma fila: se conservan id, tags y posición de lectura
+                    Some(meta) => db.rename_note(
+                        &meta.name,
+                        &note_name(to),
+                        &to.to_string_lossy(),
+                        folder.as_deref(),
+                    )?,
+                    // Si la app ya lo renombró, la fila está en el destino
+                    None if db.get_note_by_path(&to.to_string_lossy())?.is_some() => {}
+                    None => index_file(db, root, to)?,
+                }
+            }
+            WatchAction::RenameFolder { from, to } => {
+                if to.is_dir() {
+                    db.update_notes_folder(
+                        &relative_path(root, from),
+                        &relative_path(root, to),
+                        &root.to_string_lossy(),
+                    )?;
+                }
+            }
+            WatchAction::RemoveFolder(path) => {
+                if !path.exists() {
+                    db.delete_notes_in_folder(&relative_path(root, path))?;
+                }
+            }
+            WatchAction::IndexFolder(path) => {
+                if path.is_dir() {
+                    let indexed = index_folder(db, root, path, ignore)?;
+                    info!("Carpeta {:?} indexada: {} notas", path, indexed);
+                }
+            }
+        }
+        Ok(())
+    })
+}
+
+/// Aplica las acciones de una ráfaga; un fallo no impide aplicar las demás.
+/// Devuelve cuántas se aplicaron.
+pub fn apply_watch_actions(
+    db: &NotesDatabase,
+    root: &Path,
+    ignore: &WatchIgnore,
+    actions: &[WatchAction],
+) -> usize {
+    actions
+        .iter()
+        .filter(
+            |action| match apply_watch_action(db, root, ignore, action) {
+                Ok(()) => true,
+                Err(e) => {
+                    error!("Error aplicando {:?} al índice: {}", action, e);
+                    false
+                }
+            },
+        )
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn debouncer(root: &Path) -> EventDebouncer {
+        EventDebouncer::new(
+            root.to_path_buf(),
+            WatchIgnore::new(&default_watch_ignore()),
+            WATCH_DEBOUNCE,
+        )
+    }
+
+    #[test]
+    fn test_watch_ignore() {
+        let ignore = WatchIgnore::new(&[
+            ".git".to_string(),
+            "*.tmp".to_string(),
+            "Archivo/20??/".to_string(),
+        ]);
+        assert!(ignore.is_ignored(Path::new(".git/HEAD")));
+        assert!(ignore.is_ignored(Path::new("Proyectos/.git/config")));
+        assert!(ignore.is_ignored(Path::new("Proyectos/borrador.tmp")));
+        assert!(ignore.is_ignored(Path::new("Archivo/2020/nota.md")));
+        assert!(!ignore.is_ignored(Path::new("Archivo/nota.md")));
+        assert!(!ignore.is_ignored(Path::new("Proyectos/git.md")));
+        assert!(glob_match("a*b*c", "axxbyyc"));
+        assert!(!glob_match("a*b", "axxc"));
+    }
+
+    #[test]
+    fn test_debounce_burst() {
+        let root = Path::new("/notas");
+        let mut events = debouncer(root);
+        let start = Instant::now();
+        for i in 0..50 {
+            events.push(
+                WatchEvent::Changed(root.join(format!("n{}.md", i % 5))),
+                start + Duration::from_millis(i),
+            );
+        }
+        events.push(WatchEvent::Changed(root.join(".git/index")), start);
+        events.push(WatchEvent::Changed(root.join("foto.png")), start);
+
+        assert!(!events.is_due(start + Duration::from_millis(100)));
+        assert!(events.is_due(start + Duration::from_millis(49) + WATCH_DEBOUNCE));
+        let actions = events.take();
+        assert_eq!(actions.len(), 5);
+        assert_eq!(actions[0], WatchAction::Index(root.join("n0.md")));
+        assert!(!events.is_due(start + Duration::from_secs(10)));
+    }
+
+    #[test]
+    fn test_pair_renames() {
+        let root = Path::new("/notas");
+        let now = Instant::now();
+        let (a, b, c) = (root.join("a.md"), root.join("b.md"), root.join("c.md"));
+
+        // Origen y destino en eventos separados (y repetidos juntos), encadenados
+        let mut events = debouncer(root);
+        events.push(WatchEvent::RenamedFrom(a.clone()), now);
+        events.push(WatchEvent::RenamedTo(b.clone()), now);
+        events.push(WatchEvent::Renamed(a.clone(), b.clone()), now);
+        events.push(WatchEvent::Renamed(b.clone(), c.clone()), now);
+        assert_eq!(
+            events.take(),
+            vec![WatchAction::Rename {
+                from: a.clone(),
+                to: c.clone()
+            }]
+        );
+
+        // Guardado atómico: se escribe un temporal y se renombra encima
+        events.push(WatchEvent::Changed(a.clone()), now);
+        events.push(WatchEvent::Renamed(a.clone(), b.clone()), now);
+        assert_eq!(
+            events.take(),
+            vec![
+                WatchAction::Rename {
+                    from: a.clone(),
+                    to: b.clone()
+                },
+                WatchAction::Index(b.clone())
+            ]
+        );
+
+        // Renombrado y borrado: se borra el original
+        events.push(WatchEvent::Renamed(a.clone(), b.clone()), now);
+        events.push(WatchEvent::Removed(b.clone()), now);
+        assert_eq!(events.take(), vec![WatchAction::Remove(a.clone())]);
+
+        // A la papelera es borrar; un origen sin destino también
+        events.push(
+            WatchEvent::Renamed(a.clone(), root.join(".trash/a_1.md")),
+            now,
+        );
+        events.push(WatchEvent::RenamedFrom(b.clone()), now);
+        assert_eq!(
+            events.take(),
+            vec![
+                WatchAction::Remove(a.clone()),
+                WatchAction::Remove(b.clone())
+            ]
+        );
+
+        // Carpetas
+        events.push(
+            WatchEvent::Renamed(root.join("Viejo"), root.join("Nuevo")),
+            now,
+        );
+        events.push(WatchEvent::RenamedTo(root.join("Restaurada")), now);
+        assert_eq!(
+            events.take(),
+            vec![
+                WatchAction::RenameFolder {
+                    from: root.join("Viejo"),
+                    to: root.join("Nuevo")
+                },
+                WatchAction::IndexFolder(root.join("Restaurada"))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_apply_rename_keeps_note() {
+        let root = std::env::temp_dir().join(format!("notnative-watch-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("Proyectos")).unwrap();
+        let db = NotesDatabase::new(&root.join("notes.db")).unwrap();
+        let ignore = WatchIgnore::new(&default_watch_ignore());
+
+        let (a, b) = (root.join("a.md"), root.join("Proyectos/b.md"));
+        std::fs::write(&a, "#plan Hola").unwrap();
+        apply_watch_actions(&db, &root, &ignore, &[WatchAction::Index(a.clone())]);
+        let id = db.get_note("a").unwrap().unwrap().id;
+        let position = crate::database::ReadingPosition {
+            cursor_offset: 12,
+            scroll_fraction: 0.5,
+        };
+        db.save_reading_position("a", position).unwrap();
+
+        std::fs::rename(&a, &b).unwrap();
+        let applied = apply_watch_actions(
+            &db,
+            &root,
+            &ignore,
+            &[WatchAction::Rename {
+                from: a.clone(),
+                to: b.clone(),
+            }],
+        );
+        assert_eq!(applied, 1);
+        let meta = db.get_note("b").unwrap().unwrap();
+        assert_eq!(meta.id, id);
+        assert_eq!(meta.folder.as_deref(), Some("Proyectos"));
+        assert_eq!(db.get_note_tags(id).unwrap().len(), 1);
+        assert!(db.get_reading_position("b").unwrap().is_some());
+
+        std::fs::remove_file(&b).unwrap();
+        apply_watch_actions(&db, &root, &ignore, &[WatchAction::Remove(b.clone())]);
+        assert!(db.get_note("b").unwrap().is_none());
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
@@ -0,0 +1,294 @@
+//! Revisión semanal
+//!
+//! Reúne lo que pasó en la semana (de lunes a domingo): notas creadas y
+//! modificadas, tareas que siguen abiertas en ellas, recordatorios
+//! completados y cuántas palabras suman. Con eso y las respuestas del
+//! usuario a cada sección se escribe la nota `Reviews/2026-W42`.
+
+use crate::meetings::extract_action_items;
+use crate::quick_note::JOURNAL_FOLDER;
+use crate::text_stats::TextStats;
+use chrono::{Datelike, Duration, NaiveDate};
+
+/// Carpeta de las notas de revisión
+pub const REVIEWS_FOLDER: &str = "Reviews";
+
+/// Tag que llevan las notas de revisión
+pub const REVIEW_TAG: &str = "review";
+
+/// Lunes de la semana de `date`
+pub fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(date.weekday().num_days_from_monday() as i64)
+}
+
+/// Nombre de la nota de revisión de la semana: año y semana ISO
+pub fn review_note_name(week_start: NaiveDate) -> String {
+    let week = week_start.iso_week();
+    format!("{}/{}-W{:02}", REVIEWS_FOLDER, week.year(), week.week())
+}
+
+/// Lo que pasó en una semana
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WeekSummary {
+    /// Lunes de la semana
+    pub start: NaiveDate,
+    pub created: Vec<String>,
+    /// Modificadas pero no creadas esta semana
+    pub modified: Vec<String>,
+    /// Tareas abiertas de las notas de la semana: `(nota, tarea)`
+    pub open_tasks: Vec<(String, String)>,
+    pub completed_reminders: Vec<String>,
+    /// Palabras de las notas creadas o modificadas
+    pub words: usize,
+    /// Días con nota en el diario
+    pub journal_days: usize,
+}
+
+impl WeekSummary {
+    pub fn new(start: NaiveDate) -> Self {
+        Self {
+            start: week_start(start),
+            created: Vec::new(),
+            modified: Vec::new(),
+            open_tasks: Vec::new(),
+            completed_reminders: Vec::new(),
+            words: 0,
+            journal_days: 0,
+        }
+    }
+
+    /// El día cae dentro de la semana
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        date >= self.start && date < self.start + Duration::days(7)
+    }
+
+    /// Cuenta la nota si se creó o modificó esta semana. Las revisiones no
+    /// cuentan.
+    pub fn add_note(&mut self, name: &str, created: NaiveDate, modified: NaiveDate, content: &str) {
+        if name.starts_with(&format!("{}/", REVIEWS_FOLDER)) {
+            return;
+        }
+        if self.contains(created) {
+            self.created.push(name.to_string());
+        } else if self.contains(modified) {
+            self.modified.push(name.to_string());
+        } else {
+            return;
+        }
+
+        self.words += TextStats::counts(content).words;
+        if name.starts_with(&format!("{}/", JOURNAL_FOLDER)) {
+            self.journal_days += 1;
+        }
+        self.open_tasks.extend(
+            extract_action_items(content)
+                .into_iter()
+                .map(|item| (name.to_string(), item.text)),
+        );
+    }
+
+    /// Cuenta el recordatorio si se completó esta semana
+    pub fn add_completed_reminder(&mut self, title: &str, completed: NaiveDate) {
+        if self.contains(completed) {
+            self.completed_reminders.push(title.to_string());
+        }
+    }
+
+    /// Ordena las listas por nombre
+    pub fn sort(&mut self) {
+        self.created.sort();
+        self.modified.sort();
+        self.open_tasks.sort();
+        self.completed_reminders.sort();
+    }
+}
+
+/// Textos de la nota, ya traducidos
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReviewLabels {
+    pub title: String,
+    pub stats: String,
+    pub created: String,
+    pub modified: String,
+    pub words: String,
+    pub journal_days: String,
+    pub open_tasks: String,
+    pub completed_reminders: String,
+    /// Para las listas vacías
+    pub none: String,
+}
+
+/// Resumen de la semana en Markdown: estadísticas y listas, cada una con su
+/// encabezado de nivel 2
+pub fn week_overview(summary: &WeekSummary, labels: &ReviewLabels) -> String {
+    let mut text = format!(
+        "## {}\n\n- {}: {}\n- {}: {}\n- {}: {}\n- {}: {}\n",
+        labels.stats,
+        labels.created,
+        summary.created.len(),
+        labels.modified,
+        summary.modified.len(),
+        labels.words,
+        summary.words,
+        labels.journal_days,
+        summary.journal_days,
+    );
+
+    let links = |names: &[String]| -> Vec<String> {
+        names.iter().map(|name| format!("- [[{}]]", name)).collect()
+    };
+    let mut section = |title: &str, lines: Vec<String>| {
+        text.push_str(&format!("\n## {}\n\n", title));
+        if lines.is_empty() {
+            text.push_str(&format!("_{}_\n", labels.none));
+        } else {
+            text.push_str(&lines.join("\n"));
+            text.push('\n');
+        }
+    };
+    section(&labels.created, links(&summary.created));
+    section(&labels.modified, links(&summary.modified));
+    section(
+        &labels.open_tasks,
+        summary
+            .open_tasks
+            .iter()
+            .map(|(note, task)| format!("- [ ] {} ([[{}]])", task, note))
+            .collect(),
+    );
+    section(
+        &labels.completed_reminders,
+        summary
+            .completed_reminders
+            .iter()
+            .map(|title| format!("- [x] {}", title))
+            .collect(),
+    );
+    text
+}
+
+/// Nota de revisión completa: frontmatter, las respuestas del usuario por
+/// sección (`(encabezado, texto)`, las vacías se quedan con el encabezado) y
+/// el resumen de la semana al final
+pub fn review_note(
+    summary: &WeekSummary,
+    labels: &ReviewLabels,
+    answers: &[(String, String)],
+) -> String {
+    let week = summary.start.iso_week();
+    let end = summary.start + Duration::days(6);
+    let mut note = format!(
+        "---\ntags: [{}]\nweek: {}-W{:02}\n---\n\n# {} {}-W{:02}\n\n> {} → {}\n",
+        REVIEW_TAG,
+        week.year(),
+        week.week(),
+        labels.title,
+        week.year(),
+        week.week(),
+        summary.start.format("%Y-%m-%d"),
+        end.format("%Y-%m-%d"),
+    );
+    for (heading, answer) in answers {
+        note.push_str(&format!("\n## {}\n\n", heading));
+        if !answer.trim().is_empty() {
+            note.push_str(answer.trim());
+            note.push('\n');
+        }
+    }
+    note.push('\n');
+    note.push_str(&week_overview(summary, labels));
+    note
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(text: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(text, "%Y-%m-%d").unwrap()
+    }
+
+    fn labels() -> ReviewLabels {
+        ReviewLabels {
+            title: "Revisión semanal".into(),
+            stats: "Estadísticas".into(),
+            created: "Notas creadas".into(),
+            modified: "Notas modificadas".into(),
+            words: "Palabras".into(),
+            journal_days: "Días de diario".into(),
+            open_tasks: "Tareas abiertas".into(),
+            completed_reminders: "Recordatorios completados".into(),
+            none: "Nada".into(),
+        }
+    }
+
+    #[test]
+    fn test_week_summary() {
+        // 2026-10-16 es viernes; la semana empieza el lunes 12
+        let mut summary = WeekSummary::new(date("2026-10-16"));
+        assert_eq!(summary.start, date("2026-10-12"));
+        assert_eq!(review_note_name(summary.start), "Reviews/2026-W42");
+
+        summary.add_note(
+            "Plan",
+            date("2026-10-13"),
+            date("2026-10-15"),
+            "Dos palabras\n- [ ] Llamar @Ana\n- [x] Hecha",
+        );
+        summary.add_note("Antigua", date("2026-01-02"), date("2026-10-18"), "una");
+        summary.add_note("Olvidada", date("2026-01-02"), date("2026-10-11"), "nada");
+        summary.add_note(
+            "Journal/2026-10-14",
+            date("2026-10-14"),
+            date("2026-10-14"),
+            "",
+        );
+        summary.add_note(
+            "Reviews/2026-W41",
+            date("2026-10-12"),
+            date("2026-10-12"),
+            "x",
+        );
+        summary.add_completed_reminder("Pagar", date("2026-10-12"));
+        summary.add_completed_reminder("Viejo", date("2026-10-05"));
+        summary.sort();
+
+        assert_eq!(summary.created, vec!["Journal/2026-10-14", "Plan"]);
+        assert_eq!(summary.modified, vec!["Antigua"]);
+        assert_eq!(
+            summary.open_tasks,
+            vec![("Plan".to_string(), "Llamar @Ana".to_string())]
+        );
+        assert_eq!(summary.completed_reminders, vec!["Pagar"]);
+        assert_eq!(summary.journal_days, 1);
+        // Las de "Plan" y la de "Antigua"
+        assert_eq!(
+            summary.words,
+            TextStats::counts("Dos palabras\n- [ ] Llamar @Ana\n- [x] Hecha").words + 1
+        );
+    }
+
+    #[test]
+    fn test_review_note() {
+        let mut summary = WeekSummary::new(date("2026-10-12"));
+        summary.add_note(
+            "Plan",
+            date("2026-10-13"),
+            date("2026-10-13"),
+            "- [ ] Llamar",
+        );
+        let answers = vec![
+            ("Balance".to_string(), "  Buena semana.\n".to_string()),
+            ("Próxima semana".to_string(), String::new()),
+        ];
+        let note = review_note(&summary, &labels(), &answers);
+        assert!(note.starts_with(
+            "---\ntags: [review]\nweek: 2026-W42\n---\n\n# Revisión semanal 2026-W42\n\n> 2026-10-12 → 2026-10-18\n\n\
+             ## Balance\n\nBuena semana.\n\n## Próxima semana\n\n\n## Estadísticas\n\n- Notas creadas: 1\n"
+        ));
+        assert!(note.contains("## Notas creadas\n\n- [[Plan]]\n"));
+        assert!(note.contains("## Notas modificadas\n\n_Nada_\n"));
+        assert!(note.contains("## Tareas abiertas\n\n- [ ] Llamar ([[Plan]])\n"));
+        assert!(note.ends_with("## Recordatorios completados\n\n_Nada_\n"));
+    }
+}
@@ -0,0 +1,99 @@
+//! Tamaño y estado de las ventanas entre sesiones
+//!
+//! Se guardan en la configuración el tamaño de la ventana principal (sin
+//! maximizar), si estaba maximizada, en qué monitor y el ancho del sidebar, y
+//! aparte el tamaño de la ventana de Quick Note. GTK4 no deja colocar una
+//! ventana en una posición, así que el monitor sirve para ajustar el tamaño
+//! al abrir: si ese monitor ya no está o es más pequeño, la ventana no queda
+//! más grande que la pantalla.
+
+use serde::{Deserialize, Serialize};
+
+/// Tamaño de la ventana principal la primera vez
+pub const DEFAULT_MAIN_SIZE: (i32, i32) = (920, 680);
+/// Tamaño de la ventana de Quick Note la primera vez
+pub const DEFAULT_QUICK_NOTE_SIZE: (i32, i32) = (450, 400);
+/// Tamaño de la ventana en modo compacto
+pub const COMPACT_SIZE: (i32, i32) = (380, 460);
+/// Título de la ventana en modo compacto, para fijarla encima de las demás con
+/// las reglas del gestor de ventanas (`windowrulev2 = pin, title:^(NotNative Mini)$`)
+pub const COMPACT_TITLE: &str = "NotNative Mini";
+/// Ancho del sidebar de notas la primera vez
+pub const DEFAULT_SIDEBAR_WIDTH: i32 = 250;
+/// Límites del ancho del sidebar (el mínimo es el `width_request` del panel)
+pub const MIN_SIDEBAR_WIDTH: i32 = 200;
+pub const MAX_SIDEBAR_WIDTH: i32 = 600;
+
+/// Nunca se restaura una ventana más pequeña que esto
+const MIN_SIZE: (i32, i32) = (360, 300);
+
+/// Tamaño y estado de una ventana
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WindowGeometry {
+    pub width: i32,
+    pub height: i32,
+    #[serde(default)]
+    pub maximized: bool,
+    /// Conector del monitor (`DP-1`, `eDP-1`...) en el que estaba
+    #[serde(default)]
+    pub monitor: Option<String>,
+}
+
+impl WindowGeometry {
+    pub fn new(width: i32, height: i32) -> Self {
+        Self {
+            width,
+            height,
+            maximized: false,
+            monitor: None,
+        }
+    }
+
+    /// Tamaño con el que abrir la ventana en un monitor de `monitor` píxeles
+    /// (si se conoce): nunca más grande que él ni más pequeño que el mínimo
+    pub fn fitted(&self, monitor: Option<(i32, i32)>) -> (i32, i32) {
+        let (mut width, mut height) = (self.width.max(MIN_SIZE.0), self.height.max(MIN_SIZE.1));
+        if let Some((max_width, max_height)) = monitor {
+            if max_width > 0 && max_height > 0 {
+                width = width.min(max_width);
+                height = height.min(max_height);
+            }
+        }
+        (width, height)
+    }
+}
+
+/// Ancho del sidebar dentro de sus límites
+pub fn clamp_sidebar_width(width: i32) -> i32 {
+    width.clamp(MIN_SIDEBAR_WIDTH, MAX_SIDEBAR_WIDTH)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fitted() {
+        let geometry = WindowGeometry::new(2400, 1300);
+        assert_eq!(geometry.fitted(None), (2400, 1300));
+        // En un portátil más pequeño que el monitor donde se guardó
+        assert_eq!(geometry.fitted(Some((1366, 768))), (1366, 768));
+        assert_eq!(WindowGeometry::new(100, 50).fitted(None), MIN_SIZE);
+        assert_eq!(
+            WindowGeometry::new(800, 600).fitted(Some((0, 0))),
+            (800, 600)
+        );
+
+        assert_eq!(clamp_sidebar_width(20), MIN_SIDEBAR_WIDTH);
+        assert_eq!(clamp_sidebar_width(320), 320);
+        assert_eq!(clamp_sidebar_width(5000), MAX_SIDEBAR_WIDTH);
+    }
+
+    #[test]
+    fn test_geometry_serde() {
+        // Los campos opcionales pueden faltar en configuraciones viejas
+        let geometry: WindowGeometry =
+            serde_json::from_str(r#"{"width":1000,"height":700}"#).unwrap();
+        assert_eq!(geometry, WindowGeometry::new(1000, 700));
+    }
+}
@@ -0,0 +1,127 @@
+//! Objetivo de palabras de una nota
+//!
+//! Con `goal: 1500` en el frontmatter, la barra de estado enseña cuánto falta
+//! (las palabras del frontmatter no cuentan). La primera vez que una nota
+//! llega a su objetivo se apunta en la base de datos
+//! ([`crate::NotesDatabase::record_goal_completion`]) para las estadísticas
+//! de cada día.
+
+use chrono::{Local, NaiveDate, TimeZone};
+
+use crate::frontmatter::Frontmatter;
+use crate::text_stats::TextStats;
+
+/// Campo del frontmatter con el objetivo
+pub const GOAL_FIELD: &str = "goal";
+
+/// Objetivo de una nota y sus palabras actuales
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WordGoal {
+    pub goal: usize,
+    pub words: usize,
+}
+
+impl WordGoal {
+    /// Objetivo del frontmatter de la nota, si tiene uno mayor que cero
+    pub fn from_note(content: &str) -> Option<Self> {
+        if !content.trim_start().starts_with("---") {
+            return None;
+        }
+        let (frontmatter, body) = Frontmatter::parse(content).ok()?;
+        let goal = match frontmatter.custom.get(GOAL_FIELD)? {
+            serde_yaml::Value::Number(number) => number.as_u64()?,
+            serde_yaml::Value::String(text) => text.trim().parse().ok()?,
+            _ => return None,
+        };
+        (goal > 0).then(|| Self {
+            goal: goal as usize,
+            words: TextStats::counts(&body).words,
+        })
+    }
+
+    /// Parte del objetivo alcanzada, de 0 a 1
+    pub fn fraction(&self) -> f64 {
+        (self.words as f64 / self.goal as f64).min(1.0)
+    }
+
+    pub fn is_met(&self) -> bool {
+        self.words >= self.goal
+    }
+}
+
+/// Una nota que llegó a su objetivo
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GoalCompletion {
+    pub note_name: String,
+    pub goal: usize,
+    pub words: usize,
+    /// Timestamp Unix
+    pub completed_at: i64,
+}
+
+impl GoalCompletion {
+    /// Día (hora local) en que se alcanzó
+    pub fn day(&self) -> Option<NaiveDate> {
+        Local
+            .timestamp_opt(self.completed_at, 0)
+            .single()
+            .map(|time| time.date_naive())
+    }
+}
+
+/// Objetivos alcanzados en `day` y en los `days` días que acaban en él
+pub fn completions_in(completions: &[GoalCompletion], day: NaiveDate, days: i64) -> (usize, usize) {
+    let first = day - chrono::Duration::days(days - 1);
+    completions
+        .iter()
+        .filter_map(GoalCompletion::day)
+        .fold((0, 0), |(today, period), completed| {
+            (
+                today + usize::from(completed == day),
+                period + usize::from(completed >= first && completed <= day),
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_word_goal() {
+        let goal =
+            WordGoal::from_note("---\ngoal: 4\ntags: [novela]\n---\nUna dos tres\n").unwrap();
+        assert_eq!(goal, WordGoal { goal: 4, words: 3 });
+        assert!(!goal.is_met());
+        assert_eq!(goal.fraction(), 0.75);
+
+        let goal = WordGoal::from_note("---\ngoal: \"2\"\n---\nUna dos tres\n").unwrap();
+        assert!(goal.is_met());
+        assert_eq!(goal.fraction(), 1.0);
+
+        assert_eq!(WordGoal::from_note("Sin frontmatter"), None);
+        assert_eq!(WordGoal::from_note("---\ngoal: 0\n---\nTexto"), None);
+        assert_eq!(WordGoal::from_note("---\ngoal: mucho\n---\nTexto"), None);
+    }
+
+    #[test]
+    fn test_completions_in() {
+        let at = |day: NaiveDate| GoalCompletion {
+            note_name: "Capítulo".into(),
+            goal: 10,
+            words: 12,
+            completed_at: Local
+                .from_local_datetime(&day.and_hms_opt(12, 0, 0).unwrap())
+                .unwrap()
+                .timestamp(),
+        };
+        let today = NaiveDate::from_ymd_opt(2026, 11, 10).unwrap();
+        let completions = vec![
+            at(today),
+            at(today),
+            at(today - chrono::Duration::days(3)),
+            at(today - chrono::Duration::days(9)),
+        ];
+        assert_eq!(completions_in(&completions, today, 7), (2, 3));
+    }
+}
@@ -0,0 +1,149 @@
+//! Trabajo en segundo plano fuera del hilo de GTK
+//!
+//! - [`BackgroundWorker::run`]: trabajos bloqueantes (consultas a la base de datos,
+//!   escaneos de archivos, indexado tras guardar) en un hilo dedicado, en orden de
+//!   llegada y con su propia conexión a la base de datos.
+//! - [`BackgroundWorker::spawn`]: trabajos asíncronos (embeddings, llamadas de red)
+//!   en un runtime de Tokio compartido, sin bloquear la cola anterior.
+//!
+//! El resultado se entrega a un callback que se ejecuta en el hilo de trabajo; en
+//! la app ese callback solo reenvía un `AppMsg` con `sender.input(...)`.
+
+use std::future::Future;
+use std::sync::mpsc;
+use tokio::runtime::{Handle, Runtime};
+use tracing::{debug, error};
+
+use super::database::NotesDatabase;
+
+/// Recursos disponibles para los trabajos bloqueantes
+pub struct WorkerContext {
+    /// Conexión propia del hilo de trabajo
+    pub db: NotesDatabase,
+    /// Runtime compartido, para esperar código async con `block_on`
+    pub runtime: Handle,
+}
+
+type Job = Box<dyn FnOnce(&mut WorkerContext) + Send>;
+
+#[derive(Debug, Clone)]
+pub struct BackgroundWorker {
+    jobs: mpsc::Sender<Job>,
+    runtime: Handle,
+}
+
+impl BackgroundWorker {
+    /// Arranca el hilo de trabajo con su conexión a la base de datos
+    pub fn start(db: NotesDatabase) -> std::io::Result<Self> {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(2)
+            .thread_name("notnative-async")
+            .enable_all()
+            .build()?;
+        let handle = runtime.handle().clone();
+
+        let (jobs, receiver) = mpsc::channel::<Job>();
+        let mut context = WorkerContext {
+            db,
+            runtime: handle.clone(),
+        };
+
+        std::thread::Builder::new()
+            .name("notnative-worker".to_string())
+            .spawn(move || {
+                // El runtime vive mientras viva el hilo de trabajo
+                let _runtime: Runtime = runtime;
+                for job in receiver {
+                    job(&mut context);
+                }
+                debug!("Hilo de trabajo finalizado");
+            })?;
+
+        Ok(Self {
+            jobs,
+            runtime: handle,
+        })
+    }
+
+    /// Runtime compartido (para `block_on` desde código síncrono fuera de Tokio)
+    pub fn runtime(&self) -> &Handle {
+        &self.runtime
+    }
+
+    /// Ejecuta un trabajo bloqueante en el hilo de trabajo y pasa su resultado a `done`
+    pub fn run<T, F, D>(&self, job: F, done: D)
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut WorkerContext) -> T + Send + 'static,
+        D: FnOnce(T) + Send + 'static,
+    {
+        let job: Job = Box::new(move |context| done(job(context)));
+        if self.jobs.send(job).is_err() {
+            error!("El hilo de trabajo no está disponible");
+        }
+    }
+
+    /// Ejecuta un trabajo asíncrono en el runtime compartido y pasa su resultado a `done`
+    pub fn spawn<T, F, D>(&self, future: F, done: D)
+    where
+        T: Send + 'static,
+        F: Future<Output = T> + Send + 'static,
+        D: FnOnce(T) + Send + 'static,
+    {
+        self.runtime.spawn(async move {
+            done(future.await);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::temp_db;
+    use std::time::Duration;
+
+    #[test]
+    fn test_run_keeps_order_and_returns_results() {
+        let (db, path) = temp_db("order");
+        let worker = BackgroundWorker::start(db).unwrap();
+        let (tx, rx) = mpsc::channel();
+
+        for i in 0..5 {
+            let tx = tx.clone();
+            worker.run(
+                move |ctx| {
+                    ctx.db
+                        .list_notes(None)
+                        .map(|notes| (i, notes.len()))
+                        .unwrap()
+                },
+                move |result| tx.send(result).unwrap(),
+            );
+        }
+
+        let results: Vec<(i32, usize)> = (0..5)
+            .map(|_| rx.recv_timeout(Duration::from_secs(5)).unwrap())
+            .collect();
+        assert_eq!(results, (0..5).map(|i| (i, 0)).collect::<Vec<_>>());
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_spawn_async_job() {
+        let (db, path) = temp_db("async");
+        let worker = BackgroundWorker::start(db).unwrap();
+        let (tx, rx) = mpsc::channel();
+
+        worker.spawn(
+            async {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                42
+            },
+            move |value| tx.send(value).unwrap(),
+        );
+
+        assert_eq!(rx.recv_timeout(Duration::from_secs(5)).unwrap(), 42);
+        let _ = std::fs::remove_file(&path);
+    }
+}
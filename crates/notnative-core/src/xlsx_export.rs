@@ -3,14 +3,11 @@
 //! Este módulo permite exportar las tablas de Base a archivos Excel (.xlsx)
 //! preservando las fórmulas para que funcionen directamente en Excel.
 
-use rust_xlsxwriter::{
-    Color, Format, FormatAlign, FormatBorder, Formula, Workbook, Worksheet, XlsxError,
-};
+use rust_xlsxwriter::{Color, Format, FormatAlign, FormatBorder, Formula, Workbook, XlsxError};
 use std::path::Path;
 
-use super::base::{CellFormat, ColumnConfig, SpecialCellContent, SpecialRow};
+use super::base::{CellFormat, ColumnConfig, SpecialRow};
 use super::base_query::NoteWithProperties;
-use super::formula::col_to_letters;
 
 /// Exportar tabla a XLSX
 pub fn export_to_xlsx(
@@ -17,17 +17,22 @@ case "$1" in
         echo "toggle" > "$CONTROL_FILE"
         echo "📱 Alternando NotNative..."
         ;;
+    capture)
+        echo "capture" > "$CONTROL_FILE"
+        echo "📥 Portapapeles enviado a la Inbox..."
+        ;;
     quit)
         echo "quit" > "$CONTROL_FILE"
         echo "👋 Cerrando NotNative..."
         ;;
     *)
-        echo "Uso: $0 {show|hide|toggle|quit}"
+        echo "Uso: $0 {show|hide|toggle|capture|quit}"
         echo ""
         echo "Ejemplos:"
         echo "  $0 show    - Mostrar la ventana"
         echo "  $0 hide    - Ocultar la ventana"
         echo "  $0 toggle  - Alternar entre mostrar/ocultar"
+        echo "  $0 capture - Añadir el portapapeles a la nota Inbox"
         echo "  $0 quit    - Cerrar completamente"
         echo ""
         echo "Para usar en waybar, agrega un módulo custom:"
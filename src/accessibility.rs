@@ -0,0 +1,126 @@
+//! Accesibilidad: nombres para lectores de pantalla y animaciones reducidas
+//!
+//! Casi todos los botones de la interfaz son solo un icono con tooltip. GTK no
+//! usa el tooltip como nombre accesible, así que Orca los anuncia como "botón"
+//! sin más. En lugar de repetir el texto en cada botón, se recorre cada ventana
+//! al mostrarse (y cada popover al abrirse) y se copia el tooltip como nombre.
+
+use relm4::gtk::{self, glib, prelude::*};
+
+const LABELLED_KEY: &str = "notnative-a11y-labelled";
+const WATCHED_KEY: &str = "notnative-a11y-watched";
+
+/// Si el sistema permite animaciones (GNOME: "Reducir animaciones" desactivado)
+pub fn animations_enabled() -> bool {
+    gtk::Settings::default().is_none_or(|settings| settings.is_gtk_enable_animations())
+}
+
+/// Nombre accesible explícito para un widget sin texto visible
+pub fn set_label(widget: &impl IsA<gtk::Accessible>, label: &str) {
+    widget.update_property(&[gtk::accessible::Property::Label(label)]);
+}
+
+/// Etiqueta los botones de solo icono de todas las ventanas de la app, también
+/// las que se abran más adelante (preferencias, diálogos)
+pub fn watch_toplevels() {
+    let toplevels = gtk::Window::toplevels();
+    for index in 0..toplevels.n_items() {
+        if let Some(window) = toplevels.item(index).and_downcast::<gtk::Window>() {
+            watch_window(&window);
+        }
+    }
+    toplevels.connect_items_changed(|toplevels, position, _removed, added| {
+        for index in position..position + added {
+            if let Some(window) = toplevels.item(index).and_downcast::<gtk::Window>() {
+                watch_window(&window);
+            }
+        }
+    });
+}
+
+fn watch_window(window: &gtk::Window) {
+    if !mark_once(window, WATCHED_KEY) {
+        return;
+    }
+    label_icon_buttons(window.upcast_ref());
+    // El contenido de muchos diálogos se construye después de crear la ventana
+    window.connect_map(|window| label_icon_buttons(window.upcast_ref()));
+}
+
+/// Da nombre accesible (su tooltip) a los botones de solo icono bajo `root`
+pub fn label_icon_buttons(root: &gtk::Widget) {
+    if let Some(popover) = root.downcast_ref::<gtk::Popover>() {
+        // Las filas de los popovers (playlists, tags) se crean al abrirlos
+        if mark_once(popover, WATCHED_KEY) {
+            popover.connect_show(|popover| label_icon_buttons(popover.upcast_ref()));
+        }
+    }
+
+    if is_icon_only(root) {
+        label_from_tooltip(root);
+    }
+
+    let mut child = root.first_child();
+    while let Some(current) = child {
+        label_icon_buttons(&current);
+        child = current.next_sibling();
+    }
+}
+
+fn is_icon_only(widget: &gtk::Widget) -> bool {
+    if let Some(button) = widget.downcast_ref::<gtk::MenuButton>() {
+        return button.label().is_none_or(|label| label.is_empty());
+    }
+    if let Some(button) = widget.downcast_ref::<gtk::Button>() {
+        return button.label().is_none_or(|label| label.is_empty())
+            && button.child().is_none_or(|child| child.is::<gtk::Image>());
+    }
+    false
+}
+
+fn label_from_tooltip(widget: &gtk::Widget) {
+    if let Some(tooltip) = widget.tooltip_text() {
+        set_label(widget, &tooltip);
+    }
+    // Los tooltips cambian con el idioma o el estado (reproducir/pausar)
+    if mark_once(widget, LABELLED_KEY) {
+        widget.connect_tooltip_text_notify(|widget| {
+            if let Some(tooltip) = widget.tooltip_text() {
+                set_label(widget, &tooltip);
+            }
+        });
+    }
+}
+
+/// `true` la primera vez que se llama para un objeto con esa clave
+fn mark_once(object: &impl IsA<glib::Object>, key: &str) -> bool {
+    unsafe {
+        if object.data::<bool>(key).is_some() {
+            return false;
+        }
+        object.set_data(key, true);
+    }
+    true
+}
+
+/// Aplica `transition` al stack salvo que el sistema pida reducir animaciones,
+/// y la actualiza si el usuario cambia el ajuste con la app abierta
+pub fn follow_motion_preference(stack: &gtk::Stack, transition: gtk::StackTransitionType) {
+    let apply = move |stack: &gtk::Stack| {
+        stack.set_transition_type(if animations_enabled() {
+            transition
+        } else {
+            gtk::StackTransitionType::None
+        });
+    };
+    apply(stack);
+
+    if let Some(settings) = gtk::Settings::default() {
+        let stack = stack.downgrade();
+        settings.connect_gtk_enable_animations_notify(move |_| {
+            if let Some(stack) = stack.upgrade() {
+                apply(&stack);
+            }
+        });
+    }
+}
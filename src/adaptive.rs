@@ -0,0 +1,119 @@
+//! Diseño adaptable a ventanas estrechas
+//!
+//! En mosaico a media o un tercio de pantalla la ventana queda por debajo de
+//! `NARROW_WIDTH`: el sidebar pasa a flotar sobre el contenido, el chat muestra
+//! el contexto o la conversación (no ambos) y la barra de estado se compacta.
+//! La columna del editor es siempre fluida gracias a `Clamp`.
+
+use relm4::gtk::{self, glib, prelude::*, subclass::prelude::*};
+use std::cell::{Cell, RefCell};
+
+/// Ancho de ventana por debajo del cual se usa el diseño estrecho
+pub const NARROW_WIDTH: i32 = 800;
+/// Ancho máximo de la columna del editor (igual que max-width: 900px del preview)
+pub const EDITOR_MAX_WIDTH: i32 = 900;
+
+/// Llama a `on_change` con `true` al pasar a diseño estrecho y `false` al
+/// volver al ancho; también una vez al conectar con el estado inicial
+pub fn watch_breakpoint(window: &gtk::ApplicationWindow, on_change: impl Fn(bool) + 'static) {
+    let narrow = Cell::new(None::<bool>);
+    let check = move |window: &gtk::ApplicationWindow| {
+        // En GTK4 `default-width` sigue el tamaño real al redimensionar la ventana
+        let width = window.default_width();
+        let is_narrow = width > 0 && width < NARROW_WIDTH;
+        if narrow.replace(Some(is_narrow)) != Some(is_narrow) {
+            on_change(is_narrow);
+        }
+    };
+    check(window);
+    window.connect_default_width_notify(check);
+}
+
+mod imp {
+    use super::*;
+
+    #[derive(Default)]
+    pub struct Clamp {
+        pub child: RefCell<Option<gtk::Widget>>,
+        pub maximum_width: Cell<i32>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for Clamp {
+        const NAME: &'static str = "NotNativeClamp";
+        type Type = super::Clamp;
+        type ParentType = gtk::Widget;
+    }
+
+    impl ObjectImpl for Clamp {
+        fn dispose(&self) {
+            if let Some(child) = self.child.take() {
+                child.unparent();
+            }
+        }
+    }
+
+    impl WidgetImpl for Clamp {
+        fn request_mode(&self) -> gtk::SizeRequestMode {
+            gtk::SizeRequestMode::HeightForWidth
+        }
+
+        fn measure(&self, orientation: gtk::Orientation, for_size: i32) -> (i32, i32, i32, i32) {
+            let Some(child) = self.child.borrow().clone() else {
+                return (0, 0, -1, -1);
+            };
+            let maximum = self.maximum_width.get();
+
+            if orientation == gtk::Orientation::Horizontal {
+                let (minimum, natural, _, _) = child.measure(orientation, for_size);
+                // Nunca pide más del máximo, pero tampoco menos que el hijo
+                (minimum, natural.min(maximum).max(minimum), -1, -1)
+            } else {
+                // El alto depende del ancho que tendrá el hijo (texto con ajuste de línea)
+                let for_size = if for_size >= 0 {
+                    for_size.min(maximum)
+                } else {
+                    for_size
+                };
+                let (minimum, natural, _, _) = child.measure(orientation, for_size);
+                (minimum, natural, -1, -1)
+            }
+        }
+
+        fn size_allocate(&self, width: i32, height: i32, baseline: i32) {
+            let Some(child) = self.child.borrow().clone() else {
+                return;
+            };
+            let (child_minimum, _, _, _) = child.measure(gtk::Orientation::Horizontal, -1);
+            let child_width = width.min(self.maximum_width.get()).max(child_minimum);
+            let x = ((width - child_width) / 2).max(0);
+            child.size_allocate(&gtk::Allocation::new(x, 0, child_width, height), baseline);
+        }
+    }
+}
+
+glib::wrapper! {
+    /// Centra a su hijo y limita su ancho; por debajo del máximo lo ocupa todo
+    pub struct Clamp(ObjectSubclass<imp::Clamp>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl Clamp {
+    pub fn new(maximum_width: i32) -> Self {
+        let clamp: Self = glib::Object::builder().build();
+        clamp.imp().maximum_width.set(maximum_width);
+        clamp
+    }
+
+    pub fn set_child(&self, child: Option<&impl IsA<gtk::Widget>>) {
+        if let Some(previous) = self.imp().child.take() {
+            previous.unparent();
+        }
+        if let Some(child) = child {
+            child.set_parent(self);
+            self.imp().child.replace(Some(child.clone().upcast()));
+        }
+        self.queue_resize();
+    }
+}
@@ -1,9 +1,11 @@
 use anyhow::Result;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
-use crate::ai::executors::react::{ReActExecutor, ReActStep};
+use crate::ai::executors::react::{AgentPause, ReActExecutor, ReActStep};
+use crate::ai::tools_traced::StepSink;
 use crate::ai_chat::{ChatMessage, MessageRole};
 use crate::ai_client::AIClient;
+use crate::core::CustomAgent;
 use crate::mcp::{MCPToolExecutor, get_all_tool_definitions};
 
 /// Tipo de ejecutor que usa un agente
@@ -22,6 +24,8 @@ pub struct Agent {
     pub instructions: String,
     pub allowed_tools: Vec<String>, // Nombres de herramientas MCP permitidas
     executor_type: ExecutorType,
+    /// Solo puede usar `allowed_tools` (los agentes propios del usuario)
+    restrict_tools: bool,
 }
 
 impl Agent {
@@ -32,21 +36,24 @@ impl Agent {
         context: &str,
         llm: Arc<dyn AIClient>,
         mcp_executor: &MCPToolExecutor,
+        pause: &AgentPause,
         step_callback: F,
     ) -> Result<String>
     where
         F: FnMut(&ReActStep) + Send + 'static,
     {
-        // Extraer el último mensaje como la tarea actual
-        let task = messages.last().map(|m| m.content.as_str()).unwrap_or("");
         match self.executor_type {
             ExecutorType::ReAct => {
                 // Usar ReAct executor con herramientas (pasar historial completo)
-                let executor = ReActExecutor::new(
+                let mut executor = ReActExecutor::new(
                     10, // max iterations
                     llm,
                     mcp_executor.clone(),
-                );
+                )
+                .with_pause(pause.clone());
+                if self.restrict_tools {
+                    executor = executor.with_agent(&self.instructions, &self.allowed_tools);
+                }
 
                 let steps = executor.run(messages, context, step_callback).await?;
 
@@ -97,12 +104,35 @@ impl Agent {
             }
             ExecutorType::Rig => {
                 use crate::ai::executors::RigExecutor;
-                return RigExecutor::run(llm.clone(), messages, context, mcp_executor).await;
+                // Las herramientas avisan de sus llamadas y resultados
+                let steps: StepSink = Arc::new(Mutex::new(step_callback));
+                return RigExecutor::run(llm.clone(), messages, context, mcp_executor, steps).await;
             }
         }
     }
 }
 
+// ==================== AGENTES PROPIOS ====================
+
+impl Agent {
+    /// Agente definido por el usuario en `agents.yaml`: con herramientas usa
+    /// ReAct limitado a ellas; sin herramientas solo conversa
+    pub fn custom(definition: &CustomAgent) -> Self {
+        Self {
+            name: definition.name.clone(),
+            description: definition.description.clone().unwrap_or_default(),
+            instructions: definition.system_prompt.clone(),
+            allowed_tools: definition.tools.clone(),
+            executor_type: if definition.tools.is_empty() {
+                ExecutorType::Basic
+            } else {
+                ExecutorType::ReAct
+            },
+            restrict_tools: true,
+        }
+    }
+}
+
 // ==================== AGENTES PREDEFINIDOS ====================
 
 impl Agent {
@@ -138,6 +168,7 @@ Este agente debe siempre producir UNA sola nota final por tarea, y nunca entrar
                 "add_multiple_tags".to_string(),
             ],
             executor_type: ExecutorType::ReAct,
+            restrict_tools: false,
         }
     }
 
@@ -161,6 +192,7 @@ Este agente debe siempre producir UNA sola nota final por tarea, y nunca entrar
                 "get_recent_notes".to_string(),
             ],
             executor_type: ExecutorType::ReAct,
+            restrict_tools: false,
         }
     }
 
@@ -178,6 +210,7 @@ Este agente debe siempre producir UNA sola nota final por tarea, y nunca entrar
                 "get_all_tags".to_string(),
             ],
             executor_type: ExecutorType::ReAct,
+            restrict_tools: false,
         }
     }
 
@@ -195,6 +228,7 @@ Este agente debe siempre producir UNA sola nota final por tarea, y nunca entrar
             instructions: "Eres un planificador experto. Descompones tareas complejas en pasos, ejecutas herramientas en secuencia y sintetizas resultados. PROHIBIDO repetir acciones completadas. Espera el resultado de cada herramienta antes de continuar. Al finalizar, responde con una única respuesta final clara en Markdown. Si has creado o modificado una nota, incluye un enlace a ella en tu respuesta final.".to_string(),
             allowed_tools: all_tools,
             executor_type: ExecutorType::ReAct,
+            restrict_tools: false,
         }
     }
 
@@ -206,6 +240,7 @@ Este agente debe siempre producir UNA sola nota final por tarea, y nunca entrar
             instructions: "Eres un asistente amigable de NotNative. Responde de forma concisa a saludos, conversación casual y preguntas generales. Si preguntan sobre herramientas, menciona que NotNative puede crear, buscar y organizar notas, analizar contenido, gestionar tags y usar búsqueda semántica. Sé breve pero amigable.".to_string(),
             allowed_tools: Vec::new(),
             executor_type: ExecutorType::Basic,
+            restrict_tools: false,
         }
     }
 
@@ -217,6 +252,7 @@ Este agente debe siempre producir UNA sola nota final por tarea, y nunca entrar
             instructions: "Eres un asistente inteligente potenciado por RIG.".to_string(),
             allowed_tools: vec![], // Las herramientas se definen en el ejecutor RIG
             executor_type: ExecutorType::Rig,
+            restrict_tools: false,
         }
     }
 }
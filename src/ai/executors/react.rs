@@ -1,9 +1,12 @@
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tracing::{debug, error, info, warn};
 
 use crate::ai_chat::{ChatMessage, MessageRole};
 use crate::ai_client::AIClient;
+use crate::core::{AgentPlan, is_complex_task};
 use crate::mcp::{MCPToolCall, MCPToolExecutor, MCPToolRegistry};
 
 /// Representa un paso en el loop ReAct (Reasoning + Acting)
@@ -13,6 +16,33 @@ pub enum ReActStep {
     Action(MCPToolCall), // Ejecuta una herramienta MCP
     Observation(String), // Resultado de la acción ejecutada
     Answer(String),      // Respuesta final al usuario
+    Plan(AgentPlan),     // Plan de pasos (se repite cada vez que se marca uno)
+    // Llamada rechazada porque sus argumentos no cumplen el esquema
+    InvalidArguments {
+        tool: String,
+        errors: Vec<String>,
+    },
+    // Herramienta llamada por el agente RIG, con sus argumentos tal cual
+    ToolCall {
+        tool: String,
+        arguments: serde_json::Value,
+    },
+}
+
+/// Petición de pausa para el agente en marcha: se atiende al terminar el
+/// paso actual. Las copias comparten la petición.
+#[derive(Debug, Clone, Default)]
+pub struct AgentPause(Arc<AtomicBool>);
+
+impl AgentPause {
+    pub fn request(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    /// ¿Se pidió pausa? La petición queda atendida
+    pub fn take(&self) -> bool {
+        self.0.swap(false, Ordering::SeqCst)
+    }
 }
 
 /// Ejecutor que implementa el patrón ReAct (Reason + Act)
@@ -22,6 +52,11 @@ pub struct ReActExecutor {
     llm: Arc<dyn AIClient>,
     mcp_executor: MCPToolExecutor,
     mcp_registry: MCPToolRegistry,
+    /// Instrucciones del agente, delante del prompt de sistema
+    instructions: Option<String>,
+    /// Herramientas permitidas (`None` = todas)
+    allowed_tools: Option<Vec<String>>,
+    pause: AgentPause,
 }
 
 impl ReActExecutor {
@@ -36,6 +71,66 @@ impl ReActExecutor {
             llm,
             mcp_executor,
             mcp_registry: MCPToolRegistry::new(),
+            instructions: None,
+            allowed_tools: None,
+            pause: AgentPause::default(),
+        }
+    }
+
+    /// Permite pausar el ejecutor entre pasos desde fuera
+    pub fn with_pause(mut self, pause: AgentPause) -> Self {
+        self.pause = pause;
+        self
+    }
+
+    /// Limita el ejecutor a un agente: sus instrucciones y solo sus herramientas
+    pub fn with_agent(mut self, instructions: &str, allowed_tools: &[String]) -> Self {
+        self.instructions = Some(instructions.to_string());
+        self.mcp_registry = MCPToolRegistry::only(allowed_tools);
+        self.allowed_tools = Some(allowed_tools.to_vec());
+        self
+    }
+
+    /// ¿Puede usar este ejecutor la herramienta? El nombre viene en
+    /// PascalCase (`CreateNote`) y las permitidas en snake_case (`create_note`).
+    fn is_tool_allowed(&self, tool_call: &MCPToolCall) -> bool {
+        let Some(allowed) = &self.allowed_tools else {
+            return true;
+        };
+        tool_name(tool_call).is_some_and(|name| allowed.contains(&name))
+    }
+
+    /// Para las tareas complejas, pide al modelo un plan antes de actuar
+    async fn make_plan(&self, task: &str) -> Option<AgentPlan> {
+        if !is_complex_task(task) {
+            return None;
+        }
+        let tools = self
+            .mcp_registry
+            .get_tools()
+            .iter()
+            .filter_map(|tool| tool["function"]["name"].as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let prompt = format!(
+            "Antes de actuar, divide la tarea del usuario en pasos (entre 2 y 6). \
+             Responde SOLO con una lista numerada, un paso por línea. Si un paso usa \
+             una herramienta, pon su nombre entre corchetes al principio, por ejemplo:\n\
+             1. [search_notes] Buscar las notas sobre X\n\
+             2. Resumir lo encontrado\n\n\
+             No ejecutes nada todavía. Herramientas: {}",
+            tools
+        );
+        let messages = vec![
+            ChatMessage::new(MessageRole::System, prompt, Vec::new()),
+            ChatMessage::new(MessageRole::User, task.to_string(), Vec::new()),
+        ];
+        match self.llm.send_message(&messages, "").await {
+            Ok(text) => AgentPlan::parse(&text),
+            Err(e) => {
+                warn!("No se pudo obtener el plan: {}", e);
+                None
+            }
         }
     }
 
@@ -68,6 +163,10 @@ impl ReActExecutor {
         let mut limit_blocks_count = 0;
         const MAX_LIMIT_BLOCKS: usize = 10;
 
+        // Veces seguidas que se ha pedido al modelo corregir argumentos no válidos
+        let mut argument_retries = 0;
+        const MAX_ARGUMENT_RETRIES: usize = 2;
+
         // Extraer la tarea actual (último mensaje del usuario)
         let task = chat_messages
             .last()
@@ -80,6 +179,7 @@ impl ReActExecutor {
             content: self.build_system_prompt(context),
             timestamp: chrono::Utc::now(),
             context_notes: Vec::new(),
+            images: Vec::new(),
         }];
 
         // Agregar historial anterior del chat (excepto el último mensaje que es la tarea)
@@ -93,19 +193,36 @@ impl ReActExecutor {
             content: task.to_string(),
             timestamp: chrono::Utc::now(),
             context_notes: Vec::new(),
+            images: Vec::new(),
         });
 
+        // Una pausa pedida cuando ya no había nada en marcha no cuenta
+        self.pause.take();
+
+        // Las tareas complejas empiezan con un plan visible
+        let mut plan = self.make_plan(task).await;
+        if let Some(plan) = &plan {
+            info!("Plan de {} pasos", plan.steps.len());
+            let plan_step = ReActStep::Plan(plan.clone());
+            steps.push(plan_step.clone());
+            step_callback(&plan_step);
+            messages.push(ChatMessage::new(
+                MessageRole::System,
+                format!(
+                    "Plan a seguir, paso a paso:\n{}\n\nEjecuta los pasos en orden.",
+                    plan.to_checklist()
+                ),
+                Vec::new(),
+            ));
+        }
+
         for iteration in 0..self.max_iterations {
-            println!(
-                "🔄 ReAct iteration {}/{}",
-                iteration + 1,
-                self.max_iterations
-            );
+            info!("ReAct iteration {}/{}", iteration + 1, self.max_iterations);
 
             // Verificar si se alcanzó el límite de bloqueos
             if limit_blocks_count >= MAX_LIMIT_BLOCKS {
-                println!(
-                    "⛔ Límite de bloqueos alcanzado ({}/{}). Deteniendo ejecución.",
+                info!(
+                    "Límite de bloqueos alcanzado ({}/{}). Deteniendo ejecución.",
                     limit_blocks_count, MAX_LIMIT_BLOCKS
                 );
                 let final_message = format!(
@@ -129,11 +246,11 @@ impl ReActExecutor {
                     // Detectar errores de red transitorios
                     let error_msg = e.to_string();
                     if error_msg.contains("timeout") || error_msg.contains("connection") {
-                        println!("⚠️ Error de red transitorio: {}", error_msg);
+                        error!("Error de red transitorio: {}", error_msg);
 
                         // Reintentar una vez después de 2 segundos
                         tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
-                        println!("🔄 Reintentando llamada al LLM...");
+                        info!("Reintentando llamada al LLM...");
 
                         match self
                             .llm
@@ -147,7 +264,7 @@ impl ReActExecutor {
                                     Por favor verifica tu conexión a internet y la configuración de la API.",
                                     retry_err
                                 );
-                                println!("❌ {}", final_error);
+                                error!("{}", final_error);
                                 let answer_step = ReActStep::Answer(format!("❌ {}", final_error));
                                 steps.push(answer_step.clone());
                                 step_callback(&answer_step);
@@ -166,7 +283,7 @@ impl ReActExecutor {
                             format!("Error del LLM: {}", error_msg)
                         };
 
-                        println!("❌ {}", final_error);
+                        error!("{}", final_error);
                         let answer_step = ReActStep::Answer(format!("❌ {}", final_error));
                         steps.push(answer_step.clone());
                         step_callback(&answer_step);
@@ -181,7 +298,7 @@ impl ReActExecutor {
                     // Solo si NO hay tool calls, este es un pensamiento
                     // Si hay tool calls, el texto es parte de la acción
                     if response.tool_calls.is_empty() {
-                        println!("💭 Thought: {}", content);
+                        info!("Thought: {}", content);
                         let thought_step = ReActStep::Thought(content.clone());
                         steps.push(thought_step.clone());
                         step_callback(&thought_step); // ✨ Notificar a la UI
@@ -195,11 +312,71 @@ impl ReActExecutor {
                             content: content.clone(),
                             timestamp: chrono::Utc::now(),
                             context_notes: Vec::new(),
+                            images: Vec::new(),
                         });
                     }
                 }
             }
 
+            // Argumentos que no cumplen el esquema: se enseñan en el pensamiento
+            // y se pide al modelo que repita la llamada bien
+            if response.invalid_tool_calls.is_empty() {
+                argument_retries = 0;
+            } else {
+                for invalid in &response.invalid_tool_calls {
+                    let invalid_step = ReActStep::InvalidArguments {
+                        tool: invalid.name.clone(),
+                        errors: invalid.errors.clone(),
+                    };
+                    steps.push(invalid_step.clone());
+                    step_callback(&invalid_step);
+                }
+
+                if argument_retries < MAX_ARGUMENT_RETRIES {
+                    argument_retries += 1;
+                    info!(
+                        "Reintento por argumentos no válidos {}/{}",
+                        argument_retries, MAX_ARGUMENT_RETRIES
+                    );
+                    let details: Vec<String> = response
+                        .invalid_tool_calls
+                        .iter()
+                        .map(|invalid| {
+                            format!(
+                                "- {} con {}:\n  {}",
+                                invalid.name,
+                                invalid.arguments,
+                                invalid.errors.join("\n  ")
+                            )
+                        })
+                        .collect();
+                    messages.push(ChatMessage::new(
+                        MessageRole::User,
+                        format!(
+                            "⚠️ Estas llamadas NO se ejecutaron porque sus argumentos no son válidos:\n{}\n\n\
+                             Vuelve a llamar a la herramienta con los argumentos corregidos según su esquema \
+                             (nombres exactos, tipos correctos y todos los obligatorios).",
+                            details.join("\n")
+                        ),
+                        Vec::new(),
+                    ));
+                    if response.tool_calls.is_empty() {
+                        continue;
+                    }
+                } else if response.tool_calls.is_empty() {
+                    let invalid = &response.invalid_tool_calls[0];
+                    let answer_step = ReActStep::Answer(format!(
+                        "❌ No se pudo usar `{}`: el modelo mandó argumentos no válidos {} veces seguidas ({}).",
+                        invalid.name,
+                        argument_retries + 1,
+                        invalid.errors.join("; ")
+                    ));
+                    steps.push(answer_step.clone());
+                    step_callback(&answer_step);
+                    return Ok(steps);
+                }
+            }
+
             // 3. Si hay tool calls, ejecutarlos secuencialmente
             if !response.tool_calls.is_empty() {
                 let mut any_tool_executed = false; // Rastrear si se ejecutó alguna herramienta
@@ -210,7 +387,7 @@ impl ReActExecutor {
 
                     // Verificar si ya ejecutamos exactamente este mismo tool call
                     if executed_tools.contains(&tool_signature) {
-                        println!("⚠️ Tool call duplicado detectado: {:?}", tool_call);
+                        warn!("Tool call duplicado detectado: {:?}", tool_call);
                         limit_blocks_count += 1;
 
                         // Mensaje más específico según el tipo de herramienta
@@ -229,6 +406,7 @@ impl ReActExecutor {
                             content: error_msg.clone(),
                             timestamp: chrono::Utc::now(),
                             context_notes: Vec::new(),
+                            images: Vec::new(),
                         });
 
                         steps.push(ReActStep::Observation(format!(
@@ -252,8 +430,8 @@ impl ReActExecutor {
                             let count = note_modifications.entry(note.clone()).or_insert(0);
 
                             if *count >= MAX_MODIFICATIONS_PER_NOTE {
-                                println!(
-                                    "⚠️ Límite de modificaciones alcanzado para nota '{}' ({}/{})",
+                                warn!(
+                                    "Límite de modificaciones alcanzado para nota '{}' ({}/{})",
                                     note, count, MAX_MODIFICATIONS_PER_NOTE
                                 );
                                 limit_blocks_count += 1;
@@ -264,6 +442,7 @@ impl ReActExecutor {
                                         note, count),
                                     timestamp: chrono::Utc::now(),
                                     context_notes: Vec::new(),
+                                    images: Vec::new(),
                                 });
 
                                 steps.push(ReActStep::Observation(
@@ -275,21 +454,39 @@ impl ReActExecutor {
                             }
 
                             *count += 1;
-                            println!(
-                                "📝 Modificación {}/{} para nota '{}'",
+                            info!(
+                                "Modificación {}/{} para nota '{}'",
                                 count, MAX_MODIFICATIONS_PER_NOTE, note
                             );
                         }
                     }
 
+                    // Herramientas fuera de las del agente: avisar en lugar de ejecutar
+                    if !self.is_tool_allowed(&tool_call) {
+                        warn!("Herramienta no permitida para este agente: {:?}", tool_call);
+                        limit_blocks_count += 1;
+                        messages.push(ChatMessage::new(
+                            MessageRole::User,
+                            "⚠️ Esa herramienta no está permitida para este agente. \
+                             Usa solo las herramientas disponibles o responde con lo que ya sabes."
+                                .to_string(),
+                            Vec::new(),
+                        ));
+                        steps.push(ReActStep::Observation(
+                            "{\"success\": false, \"error\": \"Herramienta no permitida para este agente\"}"
+                                .to_string(),
+                        ));
+                        continue;
+                    }
+
                     // Verificar si es semantic_search y si se ha alcanzado el límite
                     let is_semantic_search =
                         matches!(tool_call, MCPToolCall::SemanticSearch { .. });
 
                     if is_semantic_search {
                         if semantic_search_count >= MAX_SEMANTIC_SEARCHES {
-                            println!(
-                                "⚠️ Límite de búsquedas semánticas alcanzado ({}/{})",
+                            warn!(
+                                "Límite de búsquedas semánticas alcanzado ({}/{})",
                                 semantic_search_count, MAX_SEMANTIC_SEARCHES
                             );
                             limit_blocks_count += 1;
@@ -301,6 +498,7 @@ impl ReActExecutor {
                                     semantic_search_count, MAX_SEMANTIC_SEARCHES),
                                 timestamp: chrono::Utc::now(),
                                 context_notes: Vec::new(),
+                                images: Vec::new(),
                             });
 
                             // Registrar en steps que se intentó pero se bloqueó
@@ -312,13 +510,13 @@ impl ReActExecutor {
                             continue; // Saltar esta herramienta (NO cuenta como iteración)
                         }
                         semantic_search_count += 1;
-                        println!(
-                            "🔍 Búsqueda semántica {}/{}",
+                        debug!(
+                            "Búsqueda semántica {}/{}",
                             semantic_search_count, MAX_SEMANTIC_SEARCHES
                         );
                     }
 
-                    println!("🔧 Action: {:?}", tool_call);
+                    info!("Action: {:?}", tool_call);
                     let action_step = ReActStep::Action(tool_call.clone());
                     steps.push(action_step.clone());
                     step_callback(&action_step); // ✨ Notificar a la UI
@@ -330,7 +528,7 @@ impl ReActExecutor {
                     let result = match self.mcp_executor.execute(tool_call.clone()) {
                         Ok(res) => res,
                         Err(e) => {
-                            println!("❌ Error ejecutando herramienta {:?}: {}", tool_call, e);
+                            error!("Error ejecutando herramienta {:?}: {}", tool_call, e);
 
                             // Crear respuesta de error estructurada
                             use crate::mcp::tools::MCPToolResult;
@@ -347,7 +545,7 @@ impl ReActExecutor {
                     any_tool_executed = true; // Se ejecutó al menos una herramienta
 
                     let observation = serde_json::to_string_pretty(&result)?;
-                    println!("👁️ Observation: {}", observation);
+                    info!("Observation: {}", observation);
                     let obs_step = ReActStep::Observation(observation.clone());
                     steps.push(obs_step.clone());
                     step_callback(&obs_step); // ✨ Notificar a la UI
@@ -367,6 +565,17 @@ impl ReActExecutor {
                         false
                     };
 
+                    // Marcar el paso del plan que hacía esta herramienta
+                    if was_successful {
+                        if let (Some(plan), Some(name)) = (plan.as_mut(), tool_name(&tool_call)) {
+                            if plan.complete_tool(&name) {
+                                let plan_step = ReActStep::Plan(plan.clone());
+                                steps.push(plan_step.clone());
+                                step_callback(&plan_step);
+                            }
+                        }
+                    }
+
                     // Agregar observación con instrucción explícita
                     if was_successful {
                         // Verificar si es un resultado de semantic_search para dar instrucciones especiales
@@ -388,6 +597,7 @@ impl ReActExecutor {
                             content: format!("Resultado:\n{}\n\n{}", observation, instruction),
                             timestamp: chrono::Utc::now(),
                             context_notes: Vec::new(),
+                            images: Vec::new(),
                         });
                     } else {
                         messages.push(ChatMessage {
@@ -395,15 +605,25 @@ impl ReActExecutor {
                             content: format!("Resultado:\n{}", observation),
                             timestamp: chrono::Utc::now(),
                             context_notes: Vec::new(),
+                            images: Vec::new(),
                         });
                     }
+
+                    // El usuario pidió pausa: parar tras este paso
+                    if self.pause.take() {
+                        info!("Agente pausado por el usuario");
+                        let answer_step = ReActStep::Answer(paused_answer(plan.as_ref()));
+                        steps.push(answer_step.clone());
+                        step_callback(&answer_step);
+                        return Ok(steps);
+                    }
                 }
 
                 // Si ninguna herramienta se ejecutó (todas bloqueadas), NO avanzar iteración
                 // Continuar el loop para dar otra oportunidad al LLM
                 if !any_tool_executed {
-                    println!(
-                        "⚠️ Todos los tool calls fueron bloqueados. Bloqueos: {}/{}",
+                    warn!(
+                        "Todos los tool calls fueron bloqueados. Bloqueos: {}/{}",
                         limit_blocks_count, MAX_LIMIT_BLOCKS
                     );
                 }
@@ -422,9 +642,7 @@ impl ReActExecutor {
                         || content.contains("<xai:function_call")
                         || content.contains("</xai:function_call>")
                     {
-                        println!(
-                            "⚠️ El modelo escribió XML manualmente en lugar de usar tool calls"
-                        );
+                        warn!("El modelo escribió XML manualmente en lugar de usar tool calls");
 
                         // Agregar mensaje correctivo
                         messages.push(ChatMessage {
@@ -432,6 +650,7 @@ impl ReActExecutor {
                             content: "ERROR: NO escribas XML de ningún tipo (<function_call>, <xai:function_call>, etc.). El sistema NO soporta XML manual. Debes usar ÚNICAMENTE el mecanismo nativo JSON de tool calling. Si no puedes hacer tool calls, simplemente responde la pregunta del usuario con la información que YA OBTUVISTE de las herramientas anteriores. NO repitas llamadas a herramientas en formato XML.".to_string(),
                             timestamp: chrono::Utc::now(),
                             context_notes: Vec::new(),
+                            images: Vec::new(),
                         });
 
                         continue; // Reintentar en la siguiente iteración
@@ -457,7 +676,13 @@ impl ReActExecutor {
 
                     // Si después de limpiar queda contenido válido, es la respuesta final
                     if !cleaned_content.is_empty() {
-                        println!("✅ Answer: {}", cleaned_content);
+                        if let Some(plan) = plan.as_mut() {
+                            plan.finish();
+                            let plan_step = ReActStep::Plan(plan.clone());
+                            steps.push(plan_step.clone());
+                            step_callback(&plan_step);
+                        }
+                        info!("Answer: {}", cleaned_content);
                         let answer_step = ReActStep::Answer(cleaned_content.clone());
                         steps.push(answer_step.clone());
                         step_callback(&answer_step); // ✨ Notificar a la UI
@@ -515,6 +740,14 @@ impl ReActExecutor {
 
     /// Construye el system prompt optimizado para ReAct con OpenRouter
     fn build_system_prompt(&self, context: &str) -> String {
+        let prompt = self.base_system_prompt(context);
+        match &self.instructions {
+            Some(instructions) => format!("{}\n\n{}", instructions, prompt),
+            None => prompt,
+        }
+    }
+
+    fn base_system_prompt(&self, context: &str) -> String {
         let tools_list = self
             .mcp_registry
             .get_tools()
@@ -574,3 +807,26 @@ Herramientas disponibles:
         )
     }
 }
+
+/// Nombre en snake_case de la herramienta (`CreateNote` → `create_note`)
+fn tool_name(tool_call: &MCPToolCall) -> Option<String> {
+    let value = serde_json::to_value(tool_call).ok()?;
+    let name = value["tool"].as_str()?;
+    let mut snake = String::new();
+    for (index, ch) in name.chars().enumerate() {
+        if ch.is_uppercase() && index > 0 {
+            snake.push('_');
+        }
+        snake.extend(ch.to_lowercase());
+    }
+    Some(snake)
+}
+
+/// Respuesta al pausar: lo hecho y lo que falta, para seguir después
+fn paused_answer(plan: Option<&AgentPlan>) -> String {
+    let mut answer = "⏸ Pausado. Escribe «continúa» cuando quieras seguir.".to_string();
+    if let Some(plan) = plan {
+        answer.push_str(&format!("\n\n{}", plan.to_checklist()));
+    }
+    answer
+}
@@ -1,4 +1,4 @@
-use crate::ai::memory::NoteMemory;
+use crate::ai::resilience::{self, Priority};
 use crate::ai::rig_adapter::{RigClient, RigClientBackend};
 use crate::ai::tools::{
     CreateNote, IndexAllNotes, ListNotes, ReadNote, SearchNotes, SemanticSearch,
@@ -6,15 +6,17 @@ use crate::ai::tools::{
 use crate::ai::tools_analysis::{
     AnalyzeNoteStructure, ExtractCodeBlocks, FuzzySearch, GenerateToc, GetWordCount,
 };
+use crate::ai::tools_dry_run::DryRunTool;
 use crate::ai::tools_extended::{
     AppendToNote, DeleteNote, GetAllTags, GetNotesWithTag, GetRecentNotes, UpdateNote,
 };
 use crate::ai::tools_folders::{
-    BatchCreateFolders, BatchMoveNotes, BatchRenameNotes, CreateFolder, DeleteFolder, ListFolders,
-    MoveNote, RenameNote,
+    BatchCreateFolders, BatchMoveNotes, BatchRenameNotes, CreateFolder, CreateProjectFolder,
+    DeleteFolder, ListFolders, MoveNote, RenameNote,
 };
 use crate::ai::tools_reminders::{CreateReminder, DeleteReminder, ModifyReminder};
 use crate::ai::tools_tags::{AddTag, DuplicateNote, MergeNotes, RemoveTag};
+use crate::ai::tools_traced::{StepSink, TracedTool};
 use crate::ai::tools_utility::{
     CreateDailyNote, FindAndReplace, GetAppInfo, GetSystemDateTime, GetWorkspacePath,
 };
@@ -24,11 +26,10 @@ use crate::ai_client::AIClient;
 use crate::mcp::MCPToolExecutor;
 use anyhow::Result;
 use rig::client::CompletionClient;
-use rig::client::EmbeddingsClient;
 use rig::completion::Prompt;
-use rig::providers::openai::EmbeddingModel as OpenAIEmbeddingModel;
 use rig::tool::Tool; // Import Tool trait to call .call()
 use std::sync::Arc;
+use tracing::{debug, error, info, warn};
 
 pub struct RigExecutor;
 
@@ -38,8 +39,9 @@ impl RigExecutor {
         messages: &[ChatMessage],
         context: &str,
         mcp_executor: &MCPToolExecutor,
+        steps: StepSink,
     ) -> Result<String> {
-        println!("🔧 [RigExecutor::run] Iniciando ejecución");
+        info!("[RigExecutor::run] Iniciando ejecución");
 
         // Intentar hacer downcast a RigClient
         let client = llm.as_any().downcast_ref::<RigClient>().ok_or_else(|| {
@@ -50,7 +52,7 @@ impl RigExecutor {
             )
         })?;
 
-        println!("✅ [RigExecutor::run] Cliente RIG obtenido correctamente");
+        info!("[RigExecutor::run] Cliente RIG obtenido correctamente");
 
         // Initialize paths
         let db_path = mcp_executor.get_db_path();
@@ -79,8 +81,8 @@ impl RigExecutor {
         };
 
         let preloaded_context = format!("{}\n{}", notes_context, folders_context);
-        println!(
-            "📝 [RigExecutor] Contexto pre-cargado: {} caracteres",
+        info!(
+            "[RigExecutor] Contexto pre-cargado: {} caracteres",
             preloaded_context.len()
         );
 
@@ -108,11 +110,14 @@ impl RigExecutor {
             }
         }
 
-        println!(
-            "📝 [RigExecutor::run] Prompt construido: {} caracteres",
+        info!(
+            "[RigExecutor::run] Prompt construido: {} caracteres",
             prompt.len()
         );
 
+        // En modo simulación las herramientas que escriben solo se apuntan
+        let dry_run = mcp_executor.dry_run();
+
         // Run agent based on backend
         let response = match &client.backend {
             RigClientBackend::OpenAI(oa_client) => {
@@ -122,37 +127,42 @@ impl RigExecutor {
                 // Initialize tools
                 let create_note =
                     CreateNote::new(db_path.clone(), notes_path.clone(), memory.clone());
-                let read_note = ReadNote::new(db_path.clone());
-                let search_notes = SearchNotes::new(db_path.clone());
+                let read_note = ReadNote::new(db_path.clone(), notes_path.clone());
+                let search_notes = SearchNotes::new(db_path.clone(), notes_path.clone());
                 let list_notes = ListNotes::new(db_path.clone());
                 let update_note = UpdateNote::new(db_path.clone());
-                let append_to_note = AppendToNote::new(db_path.clone());
+                let append_to_note = AppendToNote::new(db_path.clone(), notes_path.clone());
                 let delete_note = DeleteNote::new(db_path.clone());
-                let get_notes_with_tag = GetNotesWithTag::new(db_path.clone());
+                let get_notes_with_tag = GetNotesWithTag::new(db_path.clone(), notes_path.clone());
                 let get_all_tags = GetAllTags::new(db_path.clone());
                 let get_recent_notes = GetRecentNotes::new(db_path.clone());
-                let get_word_count = GetWordCount::new(db_path.clone());
-                let generate_toc = GenerateToc::new(db_path.clone());
-                let extract_code_blocks = ExtractCodeBlocks::new(db_path.clone());
-                let analyze_note_structure = AnalyzeNoteStructure::new(db_path.clone());
+                let get_word_count = GetWordCount::new(db_path.clone(), notes_path.clone());
+                let generate_toc = GenerateToc::new(db_path.clone(), notes_path.clone());
+                let extract_code_blocks =
+                    ExtractCodeBlocks::new(db_path.clone(), notes_path.clone());
+                let analyze_note_structure =
+                    AnalyzeNoteStructure::new(db_path.clone(), notes_path.clone());
                 let fuzzy_search = FuzzySearch::new(db_path.clone());
                 let list_folders = ListFolders::new(db_path.clone(), notes_path.clone());
                 let create_folder = CreateFolder::new(notes_path.clone());
                 let batch_create_folders = BatchCreateFolders::new(notes_path.clone());
+                let create_project_folder =
+                    CreateProjectFolder::new(db_path.clone(), notes_path.clone());
                 let delete_folder = DeleteFolder::new(db_path.clone(), notes_path.clone());
                 let move_note = MoveNote::new(db_path.clone(), notes_path.clone());
                 let batch_move_notes = BatchMoveNotes::new(db_path.clone(), notes_path.clone());
-                let rename_note = RenameNote::new(db_path.clone());
-                let batch_rename_notes = BatchRenameNotes::new(db_path.clone());
-                let add_tag = AddTag::new(db_path.clone());
-                let remove_tag = RemoveTag::new(db_path.clone());
-                let duplicate_note = DuplicateNote::new(db_path.clone());
-                let merge_notes = MergeNotes::new(db_path.clone());
-                let find_and_replace = FindAndReplace::new(db_path.clone());
+                let rename_note = RenameNote::new(db_path.clone(), notes_path.clone());
+                let batch_rename_notes = BatchRenameNotes::new(db_path.clone(), notes_path.clone());
+                let add_tag = AddTag::new(db_path.clone(), notes_path.clone());
+                let remove_tag = RemoveTag::new(db_path.clone(), notes_path.clone());
+                let duplicate_note = DuplicateNote::new(db_path.clone(), notes_path.clone());
+                let merge_notes = MergeNotes::new(db_path.clone(), notes_path.clone());
+                let find_and_replace = FindAndReplace::new(db_path.clone(), notes_path.clone());
                 let create_daily_note = CreateDailyNote::new(db_path.clone(), notes_path.clone());
-                let create_reminder = CreateReminder::new(db_path.clone());
-                let delete_reminder = DeleteReminder::new(db_path.clone());
-                let modify_reminder = ModifyReminder::new(db_path.clone());
+                let create_reminder = CreateReminder::new(db_path.clone(), notes_path.clone())
+                    .with_confirmation(mcp_executor.change_listener());
+                let delete_reminder = DeleteReminder::new(db_path.clone(), notes_path.clone());
+                let modify_reminder = ModifyReminder::new(db_path.clone(), notes_path.clone());
                 let get_system_date_time = GetSystemDateTime::new();
                 let get_app_info = GetAppInfo::new(notes_path.clone());
                 let get_workspace_path = GetWorkspacePath::new(notes_path.clone());
@@ -189,55 +199,66 @@ IMPORTANT: DO NOT create new notes unless the user explicitly asks you to (e.g.,
 If the user asks for a summary, a search, or an explanation, JUST provide the answer in the chat. DO NOT create a note with the result.
 
 LANGUAGE INSTRUCTION: You must answer in the same language as the user's request. If the user speaks Spanish, you MUST answer in Spanish.")
-                    .tool(create_note)
-                    .tool(read_note)
-                    .tool(update_note)
-                    .tool(append_to_note)
-                    .tool(delete_note)
-                    .tool(rename_note)
-                    .tool(batch_rename_notes)
-                    .tool(duplicate_note)
-                    .tool(merge_notes)
-                    .tool(search_notes)
-                    .tool(fuzzy_search)
-                    .tool(list_notes)
-                    .tool(get_recent_notes)
-                    .tool(get_notes_with_tag)
-                    .tool(get_all_tags)
-                    .tool(add_tag)
-                    .tool(remove_tag)
-                    .tool(get_word_count)
-                    .tool(generate_toc)
-                    .tool(extract_code_blocks)
-                    .tool(analyze_note_structure)
-                    .tool(list_folders)
-                    .tool(create_folder)
-                    .tool(batch_create_folders)
-                    .tool(delete_folder)
-                    .tool(move_note)
-                    .tool(batch_move_notes)
-                    .tool(find_and_replace)
-                    .tool(create_daily_note)
-                    .tool(create_reminder)
-                    .tool(delete_reminder)
-                    .tool(modify_reminder)
-                    .tool(get_system_date_time)
-                    .tool(get_app_info)
-                    .tool(get_workspace_path)
-                    .tool(web_search)
+                    .tool(TracedTool::new(DryRunTool::new(create_note, &dry_run), &steps))
+                    .tool(TracedTool::new(read_note, &steps))
+                    .tool(TracedTool::new(DryRunTool::new(update_note, &dry_run), &steps))
+                    .tool(TracedTool::new(DryRunTool::new(append_to_note, &dry_run), &steps))
+                    .tool(TracedTool::new(DryRunTool::new(delete_note, &dry_run), &steps))
+                    .tool(TracedTool::new(DryRunTool::new(rename_note, &dry_run), &steps))
+                    .tool(TracedTool::new(DryRunTool::new(batch_rename_notes, &dry_run), &steps))
+                    .tool(TracedTool::new(DryRunTool::new(duplicate_note, &dry_run), &steps))
+                    .tool(TracedTool::new(DryRunTool::new(merge_notes, &dry_run), &steps))
+                    .tool(TracedTool::new(search_notes, &steps))
+                    .tool(TracedTool::new(fuzzy_search, &steps))
+                    .tool(TracedTool::new(list_notes, &steps))
+                    .tool(TracedTool::new(get_recent_notes, &steps))
+                    .tool(TracedTool::new(get_notes_with_tag, &steps))
+                    .tool(TracedTool::new(get_all_tags, &steps))
+                    .tool(TracedTool::new(DryRunTool::new(add_tag, &dry_run), &steps))
+                    .tool(TracedTool::new(DryRunTool::new(remove_tag, &dry_run), &steps))
+                    .tool(TracedTool::new(get_word_count, &steps))
+                    .tool(TracedTool::new(generate_toc, &steps))
+                    .tool(TracedTool::new(extract_code_blocks, &steps))
+                    .tool(TracedTool::new(analyze_note_structure, &steps))
+                    .tool(TracedTool::new(list_folders, &steps))
+                    .tool(TracedTool::new(DryRunTool::new(create_folder, &dry_run), &steps))
+                    .tool(TracedTool::new(DryRunTool::new(batch_create_folders, &dry_run), &steps))
+                    .tool(TracedTool::new(DryRunTool::new(create_project_folder, &dry_run), &steps))
+                    .tool(TracedTool::new(DryRunTool::new(delete_folder, &dry_run), &steps))
+                    .tool(TracedTool::new(DryRunTool::new(move_note, &dry_run), &steps))
+                    .tool(TracedTool::new(DryRunTool::new(batch_move_notes, &dry_run), &steps))
+                    .tool(TracedTool::new(DryRunTool::new(find_and_replace, &dry_run), &steps))
+                    .tool(TracedTool::new(DryRunTool::new(create_daily_note, &dry_run), &steps))
+                    .tool(TracedTool::new(DryRunTool::new(create_reminder, &dry_run), &steps))
+                    .tool(TracedTool::new(
+                        DryRunTool::new(delete_reminder, &dry_run).with_reminders(db_path.clone()),
+                        &steps,
+                    ))
+                    .tool(TracedTool::new(
+                        DryRunTool::new(modify_reminder, &dry_run).with_reminders(db_path.clone()),
+                        &steps,
+                    ))
+                    .tool(TracedTool::new(get_system_date_time, &steps))
+                    .tool(TracedTool::new(get_app_info, &steps))
+                    .tool(TracedTool::new(get_workspace_path, &steps))
+                    .tool(TracedTool::new(web_search, &steps))
                     .tool(fetch_url);
 
                 if let Some(mem) = memory {
                     let semantic_search = SemanticSearch {
                         memory: mem.clone(),
+                        db_path: db_path.clone(),
                     };
-                    let index_all = IndexAllNotes::new(db_path.clone(), mem.clone());
+                    let index_all =
+                        IndexAllNotes::new(db_path.clone(), notes_path.clone(), mem.clone());
 
-                    agent_builder = agent_builder.tool(semantic_search).tool(index_all);
+                    agent_builder = agent_builder
+                        .tool(TracedTool::new(semantic_search, &steps))
+                        .tool(TracedTool::new(index_all, &steps));
                 }
 
                 let agent = agent_builder.build();
-                println!("🤖 [RigExecutor] Agente OpenAI construido, llamando a prompt()...");
+                info!("[RigExecutor] Agente OpenAI construido, llamando a prompt()...");
 
                 // Log prompt preview
                 let preview = if prompt.len() > 500 {
@@ -245,13 +266,17 @@ LANGUAGE INSTRUCTION: You must answer in the same language as the user's request
                 } else {
                     prompt.clone()
                 };
-                println!("📝 [RigExecutor] Prompt preview:\n{}", preview);
+                info!("[RigExecutor] Prompt preview:\n{}", preview);
 
-                let result = agent.prompt(&prompt).multi_turn(30).await?;
+                // Sin reintentos: el agente pudo ejecutar herramientas antes de fallar
+                let result = resilience::call_once("openai", Priority::Interactive, async {
+                    Ok(agent.prompt(&prompt).multi_turn(30).await?)
+                })
+                .await?;
 
                 // Si el resultado viene vacío, intentar obtener un resumen
                 if result.is_empty() || result.trim().is_empty() {
-                    println!("⚠️ [RigExecutor] Respuesta vacía de OpenAI. Solicitando resumen...");
+                    warn!("[RigExecutor] Respuesta vacía de OpenAI. Solicitando resumen...");
 
                     let summary_prompt = format!(
                         "{}\n\nIMPORTANTE: Las herramientas ya se ejecutaron. Proporciona un RESUMEN BREVE de lo que hiciste.",
@@ -266,9 +291,13 @@ LANGUAGE INSTRUCTION: You must answer in the same language as the user's request
                         )
                         .build();
 
-                    match simple_agent.prompt(&summary_prompt).await {
+                    match resilience::call("openai", Priority::Interactive, || async {
+                        Ok(simple_agent.prompt(&summary_prompt).await?)
+                    })
+                    .await
+                    {
                         Ok(summary) if !summary.is_empty() => {
-                            println!("✅ [RigExecutor] Resumen obtenido: {} chars", summary.len());
+                            info!("[RigExecutor] Resumen obtenido: {} chars", summary.len());
                             summary
                         }
                         _ => {
@@ -276,8 +305,8 @@ LANGUAGE INSTRUCTION: You must answer in the same language as the user's request
                         }
                     }
                 } else {
-                    println!(
-                        "✅ [RigExecutor] Respuesta recibida de OpenAI: {} caracteres",
+                    info!(
+                        "[RigExecutor] Respuesta recibida de OpenAI: {} caracteres",
                         result.len()
                     );
                     result
@@ -292,37 +321,42 @@ LANGUAGE INSTRUCTION: You must answer in the same language as the user's request
 
                 let create_note: CreateNote<EmbeddingModel> =
                     CreateNote::new(db_path.clone(), notes_path.clone(), memory.clone());
-                let read_note = ReadNote::new(db_path.clone());
-                let search_notes = SearchNotes::new(db_path.clone());
+                let read_note = ReadNote::new(db_path.clone(), notes_path.clone());
+                let search_notes = SearchNotes::new(db_path.clone(), notes_path.clone());
                 let list_notes = ListNotes::new(db_path.clone());
                 let update_note = UpdateNote::new(db_path.clone());
-                let append_to_note = AppendToNote::new(db_path.clone());
+                let append_to_note = AppendToNote::new(db_path.clone(), notes_path.clone());
                 let delete_note = DeleteNote::new(db_path.clone());
-                let get_notes_with_tag = GetNotesWithTag::new(db_path.clone());
+                let get_notes_with_tag = GetNotesWithTag::new(db_path.clone(), notes_path.clone());
                 let get_all_tags = GetAllTags::new(db_path.clone());
                 let get_recent_notes = GetRecentNotes::new(db_path.clone());
-                let get_word_count = GetWordCount::new(db_path.clone());
-                let generate_toc = GenerateToc::new(db_path.clone());
-                let extract_code_blocks = ExtractCodeBlocks::new(db_path.clone());
-                let analyze_note_structure = AnalyzeNoteStructure::new(db_path.clone());
+                let get_word_count = GetWordCount::new(db_path.clone(), notes_path.clone());
+                let generate_toc = GenerateToc::new(db_path.clone(), notes_path.clone());
+                let extract_code_blocks =
+                    ExtractCodeBlocks::new(db_path.clone(), notes_path.clone());
+                let analyze_note_structure =
+                    AnalyzeNoteStructure::new(db_path.clone(), notes_path.clone());
                 let fuzzy_search = FuzzySearch::new(db_path.clone());
                 let list_folders = ListFolders::new(db_path.clone(), notes_path.clone());
                 let create_folder = CreateFolder::new(notes_path.clone());
                 let batch_create_folders = BatchCreateFolders::new(notes_path.clone());
+                let create_project_folder =
+                    CreateProjectFolder::new(db_path.clone(), notes_path.clone());
                 let delete_folder = DeleteFolder::new(db_path.clone(), notes_path.clone());
                 let move_note = MoveNote::new(db_path.clone(), notes_path.clone());
                 let batch_move_notes = BatchMoveNotes::new(db_path.clone(), notes_path.clone());
-                let rename_note = RenameNote::new(db_path.clone());
-                let batch_rename_notes = BatchRenameNotes::new(db_path.clone());
-                let add_tag = AddTag::new(db_path.clone());
-                let remove_tag = RemoveTag::new(db_path.clone());
-                let duplicate_note = DuplicateNote::new(db_path.clone());
-                let merge_notes = MergeNotes::new(db_path.clone());
-                let find_and_replace = FindAndReplace::new(db_path.clone());
+                let rename_note = RenameNote::new(db_path.clone(), notes_path.clone());
+                let batch_rename_notes = BatchRenameNotes::new(db_path.clone(), notes_path.clone());
+                let add_tag = AddTag::new(db_path.clone(), notes_path.clone());
+                let remove_tag = RemoveTag::new(db_path.clone(), notes_path.clone());
+                let duplicate_note = DuplicateNote::new(db_path.clone(), notes_path.clone());
+                let merge_notes = MergeNotes::new(db_path.clone(), notes_path.clone());
+                let find_and_replace = FindAndReplace::new(db_path.clone(), notes_path.clone());
                 let create_daily_note = CreateDailyNote::new(db_path.clone(), notes_path.clone());
-                let create_reminder = CreateReminder::new(db_path.clone());
-                let delete_reminder = DeleteReminder::new(db_path.clone());
-                let modify_reminder = ModifyReminder::new(db_path.clone());
+                let create_reminder = CreateReminder::new(db_path.clone(), notes_path.clone())
+                    .with_confirmation(mcp_executor.change_listener());
+                let delete_reminder = DeleteReminder::new(db_path.clone(), notes_path.clone());
+                let modify_reminder = ModifyReminder::new(db_path.clone(), notes_path.clone());
                 let get_system_date_time = GetSystemDateTime::new();
                 let get_app_info = GetAppInfo::new(notes_path.clone());
                 let get_workspace_path = GetWorkspacePath::new(notes_path.clone());
@@ -358,56 +392,67 @@ IMPORTANT: DO NOT create new notes unless the user explicitly asks you to (e.g.,
 If the user asks for a summary, a search, or an explanation, JUST provide the answer in the chat. DO NOT create a note with the result.
 
 LANGUAGE INSTRUCTION: You must answer in the same language as the user's request. If the user speaks Spanish, you MUST answer in Spanish.")
-                    .tool(create_note)
-                    .tool(read_note)
-                    .tool(update_note)
-                    .tool(append_to_note)
-                    .tool(delete_note)
-                    .tool(rename_note)
-                    .tool(batch_rename_notes)
-                    .tool(duplicate_note)
-                    .tool(merge_notes)
-                    .tool(search_notes)
-                    .tool(fuzzy_search)
-                    .tool(list_notes)
-                    .tool(get_recent_notes)
-                    .tool(get_notes_with_tag)
-                    .tool(get_all_tags)
-                    .tool(add_tag)
-                    .tool(remove_tag)
-                    .tool(get_word_count)
-                    .tool(generate_toc)
-                    .tool(extract_code_blocks)
-                    .tool(analyze_note_structure)
-                    .tool(list_folders)
-                    .tool(create_folder)
-                    .tool(batch_create_folders)
-                    .tool(delete_folder)
-                    .tool(move_note)
-                    .tool(batch_move_notes)
-                    .tool(find_and_replace)
-                    .tool(create_daily_note)
-                    .tool(create_reminder)
-                    .tool(delete_reminder)
-                    .tool(modify_reminder)
-                    .tool(get_system_date_time)
-                    .tool(get_app_info)
-                    .tool(get_workspace_path)
-                    .tool(web_search)
+                    .tool(TracedTool::new(DryRunTool::new(create_note, &dry_run), &steps))
+                    .tool(TracedTool::new(read_note, &steps))
+                    .tool(TracedTool::new(DryRunTool::new(update_note, &dry_run), &steps))
+                    .tool(TracedTool::new(DryRunTool::new(append_to_note, &dry_run), &steps))
+                    .tool(TracedTool::new(DryRunTool::new(delete_note, &dry_run), &steps))
+                    .tool(TracedTool::new(DryRunTool::new(rename_note, &dry_run), &steps))
+                    .tool(TracedTool::new(DryRunTool::new(batch_rename_notes, &dry_run), &steps))
+                    .tool(TracedTool::new(DryRunTool::new(duplicate_note, &dry_run), &steps))
+                    .tool(TracedTool::new(DryRunTool::new(merge_notes, &dry_run), &steps))
+                    .tool(TracedTool::new(search_notes, &steps))
+                    .tool(TracedTool::new(fuzzy_search, &steps))
+                    .tool(TracedTool::new(list_notes, &steps))
+                    .tool(TracedTool::new(get_recent_notes, &steps))
+                    .tool(TracedTool::new(get_notes_with_tag, &steps))
+                    .tool(TracedTool::new(get_all_tags, &steps))
+                    .tool(TracedTool::new(DryRunTool::new(add_tag, &dry_run), &steps))
+                    .tool(TracedTool::new(DryRunTool::new(remove_tag, &dry_run), &steps))
+                    .tool(TracedTool::new(get_word_count, &steps))
+                    .tool(TracedTool::new(generate_toc, &steps))
+                    .tool(TracedTool::new(extract_code_blocks, &steps))
+                    .tool(TracedTool::new(analyze_note_structure, &steps))
+                    .tool(TracedTool::new(list_folders, &steps))
+                    .tool(TracedTool::new(DryRunTool::new(create_folder, &dry_run), &steps))
+                    .tool(TracedTool::new(DryRunTool::new(batch_create_folders, &dry_run), &steps))
+                    .tool(TracedTool::new(DryRunTool::new(create_project_folder, &dry_run), &steps))
+                    .tool(TracedTool::new(DryRunTool::new(delete_folder, &dry_run), &steps))
+                    .tool(TracedTool::new(DryRunTool::new(move_note, &dry_run), &steps))
+                    .tool(TracedTool::new(DryRunTool::new(batch_move_notes, &dry_run), &steps))
+                    .tool(TracedTool::new(DryRunTool::new(find_and_replace, &dry_run), &steps))
+                    .tool(TracedTool::new(DryRunTool::new(create_daily_note, &dry_run), &steps))
+                    .tool(TracedTool::new(DryRunTool::new(create_reminder, &dry_run), &steps))
+                    .tool(TracedTool::new(
+                        DryRunTool::new(delete_reminder, &dry_run).with_reminders(db_path.clone()),
+                        &steps,
+                    ))
+                    .tool(TracedTool::new(
+                        DryRunTool::new(modify_reminder, &dry_run).with_reminders(db_path.clone()),
+                        &steps,
+                    ))
+                    .tool(TracedTool::new(get_system_date_time, &steps))
+                    .tool(TracedTool::new(get_app_info, &steps))
+                    .tool(TracedTool::new(get_workspace_path, &steps))
+                    .tool(TracedTool::new(web_search, &steps))
                     .tool(fetch_url);
 
                 if let Some(mem) = memory {
                     let semantic_search = SemanticSearch {
                         memory: mem.clone(),
+                        db_path: db_path.clone(),
                     };
-                    let index_all = IndexAllNotes::new(db_path.clone(), mem.clone());
+                    let index_all =
+                        IndexAllNotes::new(db_path.clone(), notes_path.clone(), mem.clone());
 
-                    agent_builder = agent_builder.tool(semantic_search).tool(index_all);
+                    agent_builder = agent_builder
+                        .tool(TracedTool::new(semantic_search, &steps))
+                        .tool(TracedTool::new(index_all, &steps));
                 }
 
                 let agent = agent_builder.build();
-                println!(
-                    "🤖 [RigExecutor] Agente OpenRouter construido (Model: {}), llamando a prompt()...",
+                info!(
+                    "[RigExecutor] Agente OpenRouter construido (Model: {}), llamando a prompt()...",
                     client.model
                 );
 
@@ -417,16 +462,20 @@ LANGUAGE INSTRUCTION: You must answer in the same language as the user's request
                 } else {
                     prompt.clone()
                 };
-                println!("📝 [RigExecutor] Prompt preview:\n{}", preview);
+                info!("[RigExecutor] Prompt preview:\n{}", preview);
 
-                let result = agent.prompt(&prompt).multi_turn(30).await?;
+                // Sin reintentos: el agente pudo ejecutar herramientas antes de fallar
+                let result = resilience::call_once("openrouter", Priority::Interactive, async {
+                    Ok(agent.prompt(&prompt).multi_turn(30).await?)
+                })
+                .await?;
 
-                println!("🔍 [RigExecutor] Raw result length: {}", result.len());
+                debug!("[RigExecutor] Raw result length: {}", result.len());
 
                 // Si el resultado viene vacío, puede que el modelo ejecutó herramientas pero no dio respuesta final
                 // Intentamos hacer una llamada adicional pidiendo un resumen
                 if result.is_empty() || result.trim().is_empty() {
-                    println!("⚠️ [RigExecutor] Respuesta vacía. Solicitando resumen al modelo...");
+                    warn!("[RigExecutor] Respuesta vacía. Solicitando resumen al modelo...");
 
                     // Intentar obtener un resumen simple sin herramientas
                     let summary_prompt = format!(
@@ -440,22 +489,26 @@ LANGUAGE INSTRUCTION: You must answer in the same language as the user's request
                         .preamble("Eres un asistente que resume acciones completadas. Responde en el mismo idioma que el usuario.")
                         .build();
 
-                    match simple_agent.prompt(&summary_prompt).await {
+                    match resilience::call("openrouter", Priority::Interactive, || async {
+                        Ok(simple_agent.prompt(&summary_prompt).await?)
+                    })
+                    .await
+                    {
                         Ok(summary) if !summary.is_empty() => {
-                            println!(
-                                "✅ [RigExecutor] Resumen obtenido: {} caracteres",
+                            info!(
+                                "[RigExecutor] Resumen obtenido: {} caracteres",
                                 summary.len()
                             );
                             summary
                         }
                         _ => {
-                            println!("⚠️ [RigExecutor] No se pudo obtener resumen");
+                            error!("[RigExecutor] No se pudo obtener resumen");
                             "✅ Las operaciones se completaron. Verifica los cambios en tu workspace.".to_string()
                         }
                     }
                 } else {
-                    println!(
-                        "✅ [RigExecutor] Respuesta recibida de OpenRouter: {} caracteres",
+                    info!(
+                        "[RigExecutor] Respuesta recibida de OpenRouter: {} caracteres",
                         result.len()
                     );
                     result
@@ -463,7 +516,7 @@ LANGUAGE INSTRUCTION: You must answer in the same language as the user's request
             }
         };
 
-        println!("✅ [RigExecutor::run] Ejecución completada exitosamente");
+        info!("[RigExecutor::run] Ejecución completada exitosamente");
         Ok(response)
     }
 }
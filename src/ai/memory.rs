@@ -5,9 +5,15 @@ use rig::embeddings::EmbeddingModel;
 use rig::vector_store::VectorSearchRequest;
 use rig::vector_store::VectorStoreIndex;
 use rig_sqlite::{Column, SqliteVectorStore, SqliteVectorStoreTable};
-use rusqlite::Error as RusqliteError;
 use tokio::sync::RwLock;
 use tokio_rusqlite::{Connection, Error as TokioSqliteError};
+use tracing::{debug, error, info, warn};
+
+use std::path::{Path, PathBuf};
+
+use crate::ai::resilience::{self, EMBEDDINGS_PROVIDER, Priority};
+use crate::core::{note_ai_excluded, path_ai_excluded};
+
 #[derive(Debug, serde::Serialize, serde::Deserialize, Clone)]
 pub struct NoteDocument {
     pub id: String,
@@ -49,6 +55,8 @@ pub struct NoteMemory<M: EmbeddingModel + Sync + Send + Clone + 'static> {
     store: RwLock<SqliteVectorStore<M, NoteDocument>>,
     embedding_model: M,
     conn: Connection,
+    /// Directorio de notas, para saber qué carpetas tienen `ai: false`
+    notes_root: Option<PathBuf>,
 }
 
 impl<M: EmbeddingModel + Sync + Send + Clone + 'static> std::fmt::Debug for NoteMemory<M> {
@@ -57,6 +65,7 @@ impl<M: EmbeddingModel + Sync + Send + Clone + 'static> std::fmt::Debug for Note
             .field("store", &"SqliteVectorStore")
             .field("embedding_model", &"EmbeddingModel")
             .field("conn", &"Connection")
+            .field("notes_root", &self.notes_root)
             .finish()
     }
 }
@@ -80,6 +89,11 @@ impl<M: EmbeddingModel + Sync + Send + Clone + 'static> NoteMemory<M> {
         }
 
         let conn = Connection::open(db_path).await?;
+        // Misma configuración que el resto de conexiones (WAL + busy_timeout)
+        conn.call(|c| {
+            crate::core::db_pool::configure_connection(c).map_err(TokioSqliteError::from)
+        })
+        .await?;
         let store_conn = conn.clone();
         let store = SqliteVectorStore::new(store_conn, &embedding_model).await?;
 
@@ -87,12 +101,44 @@ impl<M: EmbeddingModel + Sync + Send + Clone + 'static> NoteMemory<M> {
             store: RwLock::new(store),
             embedding_model,
             conn,
+            notes_root: None,
         })
     }
 
+    /// Directorio de notas: con él también se respetan las carpetas excluidas
+    /// de la IA, no solo el `ai: false` de cada nota
+    pub fn with_notes_root(mut self, notes_root: PathBuf) -> Self {
+        self.notes_root = Some(notes_root);
+        self
+    }
+
+    /// La nota queda fuera de la IA (`ai: false` en ella o en su carpeta). Se
+    /// identifica por la ruta (`path`) o por nombre y carpeta (`name`,
+    /// `folder`) de los metadatos; el contenido puede ser solo un trozo.
+    fn is_excluded(&self, content: &str, metadata: &serde_json::Value) -> bool {
+        if note_ai_excluded(content) {
+            return true;
+        }
+        let Some(root) = &self.notes_root else {
+            return false;
+        };
+        if let Some(path) = metadata.get("path").and_then(|path| path.as_str()) {
+            return path_ai_excluded(root, Path::new(path));
+        }
+        let Some(name) = metadata.get("name").and_then(|name| name.as_str()) else {
+            return false;
+        };
+        let mut path = root.clone();
+        if let Some(folder) = metadata.get("folder").and_then(|folder| folder.as_str()) {
+            path.push(folder);
+        }
+        path.push(format!("{}.md", name));
+        path_ai_excluded(root, &path)
+    }
+
     /// Clear all indexed notes - useful for reindexing from scratch
     pub async fn clear_all(&self) -> Result<()> {
-        eprintln!("🗑️ [NoteMemory::clear_all] Limpiando todas las notas indexadas...");
+        info!("[NoteMemory::clear_all] Limpiando todas las notas indexadas...");
 
         let base_table = NoteDocument::name().to_string();
         let embeddings_prefix = format!("{}_embeddings%", NoteDocument::name());
@@ -122,7 +168,7 @@ impl<M: EmbeddingModel + Sync + Send + Clone + 'static> NoteMemory<M> {
                 conn.execute(&format!("DROP TABLE IF EXISTS {}", base_table), [])
                     .map_err(TokioSqliteError::from)?;
 
-                eprintln!("   ✅ Tablas eliminadas");
+                debug!("Tablas eliminadas");
                 Ok::<_, TokioSqliteError>(())
             })
             .await?;
@@ -140,35 +186,44 @@ impl<M: EmbeddingModel + Sync + Send + Clone + 'static> NoteMemory<M> {
         content: &str,
         metadata: serde_json::Value,
     ) -> Result<()> {
-        eprintln!(
-            "🔍 [NoteMemory::index_note] Iniciando indexación de: {}",
+        debug!(
+            "[NoteMemory::index_note] Iniciando indexación de: {}",
             note_id
         );
 
+        // Las notas excluidas no se mandan a generar embeddings; si ya
+        // estaban indexadas se quitan
+        if self.is_excluded(content, &metadata) {
+            info!("Nota excluida de la IA, no se indexa: {}", note_id);
+            return self.remove_note(note_id).await;
+        }
+
         // Truncate content to avoid context length limits
         let truncated_content = if content.len() > 25000 {
-            eprintln!(
-                "⚠️ Contenido truncado de {} a 25000 caracteres",
-                content.len()
-            );
+            warn!("Contenido truncado de {} a 25000 caracteres", content.len());
             &content[..25000]
         } else {
             content
         };
 
-        eprintln!("   Contenido: {} chars", truncated_content.len());
+        debug!("Contenido: {} chars", truncated_content.len());
 
-        // Generate embedding
-        let embedding = match self.embedding_model.embed_text(truncated_content).await {
-            Ok(emb) => {
-                eprintln!("✅ Embedding generado: {} dimensiones", emb.vec.len());
-                emb
-            }
-            Err(e) => {
-                eprintln!("❌ Error generando embedding: {}", e);
-                return Err(anyhow::anyhow!("Error generando embedding: {}", e));
-            }
-        };
+        // Generate embedding (en segundo plano: cede el paso al chat)
+        let embedding =
+            match resilience::call(EMBEDDINGS_PROVIDER, Priority::Background, || async {
+                Ok(self.embedding_model.embed_text(truncated_content).await?)
+            })
+            .await
+            {
+                Ok(emb) => {
+                    info!("Embedding generado: {} dimensiones", emb.vec.len());
+                    emb
+                }
+                Err(e) => {
+                    error!("Error generando embedding: {}", e);
+                    return Err(anyhow::anyhow!("Error generando embedding: {}", e));
+                }
+            };
 
         let doc = NoteDocument {
             id: note_id.to_string(),
@@ -176,7 +231,7 @@ impl<M: EmbeddingModel + Sync + Send + Clone + 'static> NoteMemory<M> {
             metadata,
         };
 
-        eprintln!("🔍 Insertando documento en SQLite...");
+        debug!("Insertando documento en SQLite...");
         let store = self.store.read().await;
         let result = store
             .add_rows(vec![(doc, rig::OneOrMany::one(embedding))])
@@ -185,21 +240,18 @@ impl<M: EmbeddingModel + Sync + Send + Clone + 'static> NoteMemory<M> {
 
         match result {
             Ok(_) => {
-                eprintln!("✅ Documento insertado exitosamente");
+                info!("Documento insertado exitosamente");
                 Ok(())
             }
             Err(e) => {
-                eprintln!("❌ Error insertando documento: {}", e);
+                error!("Error insertando documento: {}", e);
                 Err(anyhow::anyhow!("Error insertando documento: {}", e))
             }
         }
     }
 
     pub async fn remove_note(&self, note_id: &str) -> Result<()> {
-        eprintln!(
-            "🗑️ [NoteMemory::remove_note] Intentando eliminar: {}",
-            note_id
-        );
+        info!("[NoteMemory::remove_note] Intentando eliminar: {}", note_id);
         let id = note_id.to_string();
         self.conn
             .call(move |conn| {
@@ -213,24 +265,24 @@ impl<M: EmbeddingModel + Sync + Send + Clone + 'static> NoteMemory<M> {
                     .ok();
 
                 if let Some(rid) = rowid {
-                    eprintln!("   Encontrado rowid: {}, eliminando embeddings...", rid);
+                    debug!("Encontrado rowid: {}, eliminando embeddings...", rid);
                     // Delete from embeddings table manually
                     match conn.execute("DELETE FROM rig_note_embeddings WHERE rowid = ?", [rid]) {
-                        Ok(n) => eprintln!("   ✅ Eliminadas {} filas de embeddings", n),
-                        Err(e) => eprintln!("   ⚠️ Error eliminando embeddings: {}", e),
+                        Ok(n) => debug!("Eliminadas {} filas de embeddings", n),
+                        Err(e) => error!("Error eliminando embeddings: {}", e),
                     }
                 } else {
-                    eprintln!("   ℹ️ No se encontró nota con id: {}", id);
+                    warn!("ℹ️ No se encontró nota con id: {}", id);
                 }
 
                 // Delete from main table
                 match conn.execute("DELETE FROM rig_note WHERE id = ?", [id.as_str()]) {
                     Ok(n) => {
-                        eprintln!("   ✅ Eliminadas {} filas de rig_note", n);
+                        debug!("Eliminadas {} filas de rig_note", n);
                         Ok(())
                     }
                     Err(e) => {
-                        eprintln!("   ❌ Error eliminando de rig_note: {}", e);
+                        error!("Error eliminando de rig_note: {}", e);
                         Err(TokioSqliteError::Rusqlite(e))
                     }
                 }
@@ -244,8 +296,8 @@ impl<M: EmbeddingModel + Sync + Send + Clone + 'static> NoteMemory<M> {
         query: &str,
         limit: usize,
     ) -> Result<Vec<(f32, String, serde_json::Value, String)>> {
-        eprintln!(
-            "🔍 [NoteMemory::search] Buscando: '{}' (limit: {})",
+        debug!(
+            "[NoteMemory::search] Buscando: '{}' (limit: {})",
             query, limit
         );
 
@@ -256,20 +308,28 @@ impl<M: EmbeddingModel + Sync + Send + Clone + 'static> NoteMemory<M> {
 
         let index = store_clone.index(self.embedding_model.clone());
 
-        let request = VectorSearchRequest::builder()
-            .query(query.to_string())
-            .samples(limit as u64)
-            .build()?;
-
-        let results = index.top_n::<NoteDocument>(request).await?;
+        // La consulta también genera un embedding
+        let results = resilience::call(EMBEDDINGS_PROVIDER, Priority::Interactive, || async {
+            let request = VectorSearchRequest::builder()
+                .query(query.to_string())
+                .samples(limit as u64)
+                .build()?;
+            Ok(index.top_n::<NoteDocument>(request).await?)
+        })
+        .await?;
 
-        eprintln!("   ✅ Encontrados {} resultados brutos", results.len());
+        debug!("Encontrados {} resultados brutos", results.len());
         for (score, id, _) in &results {
-            eprintln!("      - {} (score: {})", id, score);
+            debug!("- {} (score: {})", id, score);
         }
 
+        // Las que se excluyeron después de indexarlas tampoco salen
         let mut mapped_results = Vec::new();
         for (score, id, doc) in results {
+            if self.is_excluded(&doc.content, &doc.metadata) {
+                debug!("Resultado excluido de la IA: {}", id);
+                continue;
+            }
             mapped_results.push((score as f32, id, doc.metadata, doc.content));
         }
 
@@ -20,6 +20,12 @@ pub mod tools_utility;
 
 pub mod tools_reminders;
 
+pub mod tools_dry_run;
+
+pub mod tools_traced;
+
+pub mod resilience;
+
 pub mod tools_web;
 
 pub use agent::{Agent, ExecutorType};
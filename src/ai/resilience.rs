@@ -0,0 +1,296 @@
+//! Capa de resiliencia para las llamadas a los proveedores de IA
+//!
+//! Todas las peticiones pasan por [`call`] (o [`call_once`] si tienen efectos
+//! y no se pueden repetir). Por proveedor se aplica:
+//!
+//! - el límite de peticiones por minuto de la configuración (`rate_limits`);
+//! - reintentos con espera exponencial ante 429 y errores 5xx;
+//! - un cortacircuitos que, tras varios fallos seguidos, deja de llamar un
+//!   rato. La barra de estado lo enseña con [`open_circuits`].
+//!
+//! Las tareas de fondo (embeddings, resúmenes) esperan a que no haya
+//! peticiones del chat en marcha, salvo que las lance el propio chat (una
+//! herramienta del agente que indexa una nota).
+
+use anyhow::Result;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+use tracing::{info, warn};
+
+use crate::ai_chat::{AIModelConfig, AIProvider};
+use crate::core::{CircuitBreaker, CircuitState, RateLimiter, RetryPolicy, is_retryable_error};
+
+/// Proveedor de los embeddings (tiene su propio límite)
+pub const EMBEDDINGS_PROVIDER: &str = "embeddings";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Priority {
+    /// Lo que el usuario está esperando (el chat)
+    Interactive,
+    /// Embeddings, resúmenes: pueden esperar
+    Background,
+}
+
+#[derive(Debug, Default)]
+struct ProviderGuard {
+    limiter: Option<RateLimiter>,
+    breaker: CircuitBreaker,
+}
+
+#[derive(Debug, Default)]
+struct Resilience {
+    policy: RetryPolicy,
+    limits: HashMap<String, u32>,
+    providers: HashMap<String, ProviderGuard>,
+}
+
+impl Resilience {
+    fn guard(&mut self, provider: &str) -> &mut ProviderGuard {
+        let limit = self.limits.get(provider).copied();
+        let guard = self.providers.entry(provider.to_string()).or_default();
+        // El límite puede haber cambiado en la configuración
+        if guard.limiter.as_ref().map(RateLimiter::per_minute) != limit {
+            guard.limiter = limit.map(RateLimiter::new);
+        }
+        guard
+    }
+}
+
+static STATE: LazyLock<Mutex<Resilience>> = LazyLock::new(Mutex::default);
+
+/// Sin red: las llamadas a proveedores en la nube fallan sin intentarlo
+static OFFLINE: AtomicBool = AtomicBool::new(false);
+
+/// Proveedores que no necesitan internet
+const LOCAL_PROVIDERS: &[&str] = &["ollama"];
+
+/// Peticiones interactivas en marcha y aviso para las de fondo cuando acaban
+static INTERACTIVE: AtomicUsize = AtomicUsize::new(0);
+static INTERACTIVE_DONE: LazyLock<tokio::sync::Notify> = LazyLock::new(tokio::sync::Notify::new);
+
+tokio::task_local! {
+    /// Dentro de una petición interactiva (las de fondo que lance no esperan)
+    static IN_INTERACTIVE: ();
+}
+
+/// Aplica los límites y reintentos de la configuración
+pub fn configure(limits: &HashMap<String, u32>, max_retries: u32) {
+    if let Ok(mut state) = STATE.lock() {
+        state.limits = limits
+            .iter()
+            .map(|(provider, limit)| (provider.to_lowercase(), *limit))
+            .collect();
+        state.policy.max_retries = max_retries;
+    }
+}
+
+/// Lo cambia la app cuando el sistema avisa de que se fue o volvió la red
+pub fn set_offline(offline: bool) {
+    OFFLINE.store(offline, Ordering::SeqCst);
+}
+
+pub fn is_offline() -> bool {
+    OFFLINE.load(Ordering::SeqCst)
+}
+
+/// ¿Hace falta internet para este proveedor?
+pub fn is_cloud_provider(provider: &str) -> bool {
+    !LOCAL_PROVIDERS.contains(&provider.to_lowercase().as_str())
+}
+
+/// Proveedores con el circuito abierto y cuándo se vuelve a probar
+pub fn open_circuits() -> Vec<(String, Duration)> {
+    let now = Instant::now();
+    let Ok(state) = STATE.lock() else {
+        return Vec::new();
+    };
+    let mut open: Vec<(String, Duration)> = state
+        .providers
+        .iter()
+        .filter_map(|(provider, guard)| match guard.breaker.state(now) {
+            CircuitState::Open { retry_in } => Some((provider.clone(), retry_in)),
+            _ => None,
+        })
+        .collect();
+    open.sort();
+    open
+}
+
+/// Nombre del proveedor de un modelo de chat, como en `rate_limits`
+pub fn provider_name(config: &AIModelConfig, api_key: &str) -> &'static str {
+    match config.provider {
+        AIProvider::OpenAI if api_key.starts_with("sk-or-") => "openrouter",
+        AIProvider::OpenAI => "openai",
+        AIProvider::Anthropic => "anthropic",
+        AIProvider::Ollama => "ollama",
+        AIProvider::Custom => "custom",
+    }
+}
+
+/// Ejecuta `request` con límites, reintentos y cortacircuitos
+pub async fn call<T, F, Fut>(provider: &str, priority: Priority, request: F) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    run(provider, priority, true, request).await
+}
+
+/// Como [`call`] pero sin reintentar: para peticiones con efectos (un agente
+/// que ya pudo ejecutar herramientas antes de fallar)
+pub async fn call_once<T, Fut>(provider: &str, priority: Priority, request: Fut) -> Result<T>
+where
+    Fut: Future<Output = Result<T>>,
+{
+    let mut request = Some(request);
+    run(provider, priority, false, move || {
+        let request = request.take();
+        async move {
+            match request {
+                Some(request) => request.await,
+                None => Err(anyhow::anyhow!("La petición ya se envió")),
+            }
+        }
+    })
+    .await
+}
+
+async fn run<T, F, Fut>(
+    provider: &str,
+    priority: Priority,
+    retry: bool,
+    mut request: F,
+) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let provider = provider.to_lowercase();
+    if is_offline() && is_cloud_provider(&provider) {
+        // No cuenta como fallo del proveedor
+        return Err(anyhow::anyhow!(
+            "Sin conexión: {} no está disponible",
+            provider
+        ));
+    }
+    let nested = IN_INTERACTIVE.try_with(|_| ()).is_ok();
+
+    if priority == Priority::Background && !nested {
+        wait_for_interactive().await;
+    }
+    let _interactive = (priority == Priority::Interactive).then(InteractiveRequest::start);
+
+    let mut attempt = 0;
+    loop {
+        wait_for_slot(&provider).await?;
+
+        let result = if priority == Priority::Interactive {
+            IN_INTERACTIVE.scope((), request()).await
+        } else {
+            request().await
+        };
+
+        let error = match result {
+            Ok(value) => {
+                if let Ok(mut state) = STATE.lock() {
+                    state.guard(&provider).breaker.record_success();
+                }
+                return Ok(value);
+            }
+            Err(e) => e,
+        };
+
+        let message = format!("{:#}", error);
+        if !is_retryable_error(&message) {
+            return Err(error);
+        }
+        let policy = match STATE.lock() {
+            Ok(mut state) => {
+                state
+                    .guard(&provider)
+                    .breaker
+                    .record_failure(Instant::now());
+                state.policy
+            }
+            Err(_) => return Err(error),
+        };
+        if !retry || attempt >= policy.max_retries {
+            return Err(error);
+        }
+
+        let delay = policy.delay(attempt);
+        attempt += 1;
+        warn!(
+            "{} falló ({}); reintento {}/{} en {:?}",
+            provider, message, attempt, policy.max_retries, delay
+        );
+        tokio::time::sleep(delay).await;
+    }
+}
+
+/// Espera a que el límite por minuto deje salir otra petición. Falla sin
+/// esperar si el circuito está abierto.
+async fn wait_for_slot(provider: &str) -> Result<()> {
+    loop {
+        let wait = {
+            let mut state = STATE
+                .lock()
+                .map_err(|_| anyhow::anyhow!("Estado de la capa de resiliencia no disponible"))?;
+            let guard = state.guard(provider);
+            let now = Instant::now();
+            if !guard.breaker.allow(now) {
+                let retry_in = match guard.breaker.state(now) {
+                    CircuitState::Open { retry_in } => retry_in,
+                    _ => Duration::from_secs(1),
+                };
+                return Err(anyhow::anyhow!(
+                    "{} está fallando repetidamente; se volverá a intentar en {} s",
+                    provider,
+                    retry_in.as_secs().max(1)
+                ));
+            }
+            match guard
+                .limiter
+                .as_mut()
+                .map(|limiter| limiter.try_acquire(now))
+            {
+                None | Some(Ok(())) => return Ok(()),
+                Some(Err(wait)) => wait,
+            }
+        };
+        info!("Límite de peticiones de {}: esperando {:?}", provider, wait);
+        tokio::time::sleep(wait).await;
+    }
+}
+
+async fn wait_for_interactive() {
+    loop {
+        // Registrarse antes de mirar el contador para no perder el aviso
+        let done = INTERACTIVE_DONE.notified();
+        if INTERACTIVE.load(Ordering::SeqCst) == 0 {
+            return;
+        }
+        done.await;
+    }
+}
+
+/// Cuenta una petición interactiva mientras vive
+struct InteractiveRequest;
+
+impl InteractiveRequest {
+    fn start() -> Self {
+        INTERACTIVE.fetch_add(1, Ordering::SeqCst);
+        Self
+    }
+}
+
+impl Drop for InteractiveRequest {
+    fn drop(&mut self) {
+        if INTERACTIVE.fetch_sub(1, Ordering::SeqCst) == 1 {
+            INTERACTIVE_DONE.notify_waiters();
+        }
+    }
+}
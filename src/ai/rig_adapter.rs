@@ -5,9 +5,10 @@
 use anyhow::Result;
 use async_trait::async_trait;
 use rig::client::CompletionClient;
-use rig::completion::{Completion, Prompt};
-use rig::providers::openai::{Client as OpenAIClient, CompletionModel};
+use rig::completion::Prompt;
+use rig::providers::openai::Client as OpenAIClient;
 use rig::providers::openrouter;
+use tracing::{debug, info};
 
 use crate::ai_chat::{AIModelConfig, ChatMessage, MessageRole};
 use crate::ai_client::{AIClient, AIResponse};
@@ -54,16 +55,15 @@ impl RigClient {
     pub fn create_openrouter_embedding_client(api_key: &str) -> OpenAIClient {
         use rig::providers::openai::ClientBuilder;
 
-        eprintln!("🔧 Creando cliente de embeddings para OpenRouter...");
-        eprintln!("   API Key: {}...", &api_key[..15]);
-        eprintln!("   URL: https://openrouter.ai/api/v1");
+        info!("Creando cliente de embeddings para OpenRouter...");
+        debug!("URL: https://openrouter.ai/api/v1");
 
         // Crear cliente simple sin headers personalizados - dejar que RIG maneje todo
         let client = ClientBuilder::new(api_key)
             .base_url("https://openrouter.ai/api/v1")
             .build();
 
-        eprintln!("✅ Cliente creado");
+        info!("Cliente creado");
         client
     }
 }
@@ -107,10 +107,7 @@ impl AIClient for RigClient {
             }
         };
 
-        Ok(AIResponse {
-            content: Some(response),
-            tool_calls: Vec::new(),
-        })
+        Ok(AIResponse::text(response))
     }
 
     async fn send_message_streaming(
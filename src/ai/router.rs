@@ -1,11 +1,13 @@
 use anyhow::Result;
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use tracing::{info, warn};
 
-use crate::ai::agent::{Agent, ExecutorType};
-use crate::ai::executors::react::ReActStep;
-use crate::ai_chat::{ChatMessage, MessageRole};
+use crate::ai::agent::Agent;
+use crate::ai::executors::react::{AgentPause, ReActStep};
+use crate::ai_chat::{AIModelConfig, ChatMessage};
 use crate::ai_client::AIClient;
+use crate::core::{CustomAgent, CustomAgents, CustomAgentsFile};
 use crate::mcp::MCPToolExecutor;
 
 /// Clasificación de la intención del usuario
@@ -20,6 +22,12 @@ pub struct IntentClassification {
 pub struct RouterAgent {
     llm: Arc<dyn AIClient>,
     agents: HashMap<String, Agent>,
+    /// Agentes y reglas del usuario (`agents.yaml`), releídos al cambiar
+    custom_agents: Arc<Mutex<CustomAgentsFile>>,
+    /// Configuración y API key del cliente, para los agentes con otro modelo
+    client_config: Option<(AIModelConfig, String)>,
+    /// Pausa del agente en marcha (compartida con las copias del router)
+    pause: AgentPause,
 }
 
 impl std::fmt::Debug for RouterAgent {
@@ -27,6 +35,7 @@ impl std::fmt::Debug for RouterAgent {
         f.debug_struct("RouterAgent")
             .field("llm", &"Arc<dyn AIClient>")
             .field("agents", &self.agents)
+            .field("custom_agents", &self.custom_agents)
             .finish()
     }
 }
@@ -46,7 +55,51 @@ impl RouterAgent {
         agents.insert("execute".to_string(), Agent::multi_step_agent());
         agents.insert("chat".to_string(), Agent::chat_agent());
 
-        Self { llm, agents }
+        Self {
+            llm,
+            agents,
+            custom_agents: Arc::new(Mutex::new(CustomAgentsFile::new(
+                CustomAgents::default_path(),
+            ))),
+            client_config: None,
+            pause: AgentPause::default(),
+        }
+    }
+
+    /// Para pedir que el agente en marcha se detenga tras el paso actual
+    pub fn pause_handle(&self) -> AgentPause {
+        self.pause.clone()
+    }
+
+    /// Permite crear clientes con otro modelo para los agentes que lo piden
+    pub fn with_client_config(mut self, config: AIModelConfig, api_key: String) -> Self {
+        self.client_config = Some((config, api_key));
+        self
+    }
+
+    /// Agente propio del usuario cuyas reglas encajan con la tarea
+    fn custom_agent_for(&self, task: &str) -> Option<CustomAgent> {
+        let mut file = self.custom_agents.lock().ok()?;
+        file.current().route(task).cloned()
+    }
+
+    /// Cliente para `model`, o el del router si no hay modelo propio o no
+    /// se puede crear
+    fn llm_for_model(&self, model: Option<&str>) -> Arc<dyn AIClient> {
+        let (Some(model), Some((config, api_key))) = (model, &self.client_config) else {
+            return self.llm.clone();
+        };
+        let config = AIModelConfig {
+            model: model.to_string(),
+            ..config.clone()
+        };
+        match crate::ai_client::create_client(&config, api_key) {
+            Ok(client) => Arc::from(client),
+            Err(e) => {
+                warn!("No se pudo crear el cliente para {}: {}", model, e);
+                self.llm.clone()
+            }
+        }
     }
 
     /// Obtiene una referencia al cliente LLM
@@ -68,11 +121,27 @@ impl RouterAgent {
         // Extraer el último mensaje del usuario como la tarea actual
         let task = messages.last().map(|m| m.content.as_str()).unwrap_or("");
 
+        // 0. Las reglas del usuario tienen prioridad sobre la clasificación
+        if let Some(definition) = self.custom_agent_for(task) {
+            info!("Regla propia: usando el agente '{}'", definition.name);
+            let llm = self.llm_for_model(definition.model.as_deref());
+            return Agent::custom(&definition)
+                .run(
+                    messages,
+                    context,
+                    llm,
+                    mcp_executor,
+                    &self.pause,
+                    step_callback,
+                )
+                .await;
+        }
+
         // 1. Clasificar la intención
         let classification = self.classify_intent(task).await?;
 
-        println!(
-            "🎯 Intent classified as: {} (confidence: {:.2})",
+        info!(
+            "Intent classified as: {} (confidence: {:.2})",
             classification.agent_type, classification.confidence
         );
 
@@ -81,7 +150,7 @@ impl RouterAgent {
             anyhow::anyhow!("Agente no encontrado: {}", classification.agent_type)
         })?;
 
-        println!("🤖 Using agent: {}", agent.name);
+        info!("Using agent: {}", agent.name);
 
         // 3. Ejecutar con el agente seleccionado (pasando historial completo y callback)
         agent
@@ -90,6 +159,7 @@ impl RouterAgent {
                 context,
                 self.llm.clone(),
                 mcp_executor,
+                &self.pause,
                 step_callback,
             )
             .await
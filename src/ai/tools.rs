@@ -2,12 +2,15 @@
 
 use crate::ai::memory::NoteMemory;
 use crate::core::database::NotesDatabase;
+use crate::core::database::SearchResult;
+use crate::core::{FolderDefaults, chunk_source, is_ai_excluded, path_ai_excluded};
 use anyhow::Result;
 use rig::embeddings::EmbeddingModel;
 use rig::tool::Tool;
-use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use tracing::{error, info};
 
 #[derive(Debug, thiserror::Error)]
 #[error("Tool error: {0}")]
@@ -19,6 +22,33 @@ impl From<anyhow::Error> for ToolError {
     }
 }
 
+/// Contenido de la nota en `path` para una herramienta del agente. Las notas
+/// excluidas de la IA (`ai: false` en ellas o en su carpeta) no se leen: todas
+/// las herramientas pasan por aquí en vez de leer el archivo directamente.
+pub(crate) fn read_allowed_note(notes_dir: &Path, path: &str) -> Result<String> {
+    let path = Path::new(path);
+    let content = std::fs::read_to_string(path)?;
+    let name = path
+        .strip_prefix(notes_dir)
+        .unwrap_or(path)
+        .with_extension("");
+    if is_ai_excluded(notes_dir, &name.to_string_lossy(), &content) {
+        return Err(anyhow::anyhow!("Note excluded from AI access"));
+    }
+    Ok(content)
+}
+
+/// Quita de unos resultados de búsqueda las notas excluidas de la IA
+pub(crate) fn allowed_search_results(
+    notes_dir: &Path,
+    results: Vec<SearchResult>,
+) -> Vec<SearchResult> {
+    results
+        .into_iter()
+        .filter(|result| !path_ai_excluded(notes_dir, Path::new(&result.note_path)))
+        .collect()
+}
+
 // --- SearchNotes (FTS) ---
 
 #[derive(Deserialize)]
@@ -28,6 +58,7 @@ pub struct SearchArgs {
 
 pub struct SearchNotes {
     pub db_path: PathBuf,
+    pub notes_dir: PathBuf,
 }
 
 impl Tool for SearchNotes {
@@ -56,10 +87,14 @@ impl Tool for SearchNotes {
 
     async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
         let db_path = self.db_path.clone();
+        let notes_dir = self.notes_dir.clone();
         // Run blocking DB operation in a blocking task
         let results = tokio::task::spawn_blocking(move || {
             let db = NotesDatabase::new(&db_path).map_err(|e| anyhow::anyhow!(e))?;
-            db.search_notes(&args.query).map_err(|e| anyhow::anyhow!(e))
+            let results = db
+                .search_notes(&args.query)
+                .map_err(|e| anyhow::anyhow!(e))?;
+            Ok::<_, anyhow::Error>(allowed_search_results(&notes_dir, results))
         })
         .await
         .map_err(|e| ToolError(e.to_string()))??;
@@ -80,8 +115,8 @@ impl Tool for SearchNotes {
 }
 
 impl SearchNotes {
-    pub fn new(db_path: PathBuf) -> Self {
-        Self { db_path }
+    pub fn new(db_path: PathBuf, notes_dir: PathBuf) -> Self {
+        Self { db_path, notes_dir }
     }
 }
 
@@ -94,6 +129,7 @@ pub struct SemanticSearchArgs {
 
 pub struct SemanticSearch<M: EmbeddingModel + Sync + Send + 'static> {
     pub memory: Arc<NoteMemory<M>>,
+    pub db_path: PathBuf,
 }
 
 impl<M: EmbeddingModel + Sync + Send + 'static> Tool for SemanticSearch<M> {
@@ -131,24 +167,30 @@ impl<M: EmbeddingModel + Sync + Send + 'static> Tool for SemanticSearch<M> {
             return Ok("No semantically similar notes found.".to_string());
         }
 
-        // De-duplicar resultados por nombre de nota (sin el #chunk_number)
-        // y quedarnos con el mejor score de cada nota
+        // Los resultados son fragmentos (`ruta#índice`): pasar a nombre de nota
+        let db_path = self.db_path.clone();
+        let results = tokio::task::spawn_blocking(move || {
+            let db = NotesDatabase::new(&db_path).map_err(|e| anyhow::anyhow!(e))?;
+            results
+                .into_iter()
+                .map(|(score, id, _metadata, content)| {
+                    let note_name = match db.note_for_chunk(&id) {
+                        Ok(Some(note)) => note.name,
+                        Ok(None) => chunk_source(&id).to_string(),
+                        Err(e) => return Err(anyhow::anyhow!(e)),
+                    };
+                    Ok((score, note_name, content))
+                })
+                .collect::<Result<Vec<_>>>()
+        })
+        .await
+        .map_err(|e| ToolError(e.to_string()))??;
+
+        // De-duplicar resultados por nota y quedarnos con el mejor score de cada una
         let mut seen_notes: std::collections::HashMap<String, (f32, String)> =
             std::collections::HashMap::new();
 
-        for (score, id, _metadata, content) in results {
-            // Extraer nombre de nota sin el sufijo #número
-            let note_name = if let Some(pos) = id.rfind('#') {
-                // Verificar que lo que está después del # es un número
-                if id[pos + 1..].parse::<usize>().is_ok() {
-                    id[..pos].to_string()
-                } else {
-                    id.clone()
-                }
-            } else {
-                id.clone()
-            };
-
+        for (score, note_name, content) in results {
             // Solo guardar si es el primer resultado de esta nota (mejor score)
             seen_notes.entry(note_name).or_insert_with(|| {
                 // Limpiar saltos de línea excesivos para el snippet
@@ -191,6 +233,7 @@ pub struct ReadNoteArgs {
 
 pub struct ReadNote {
     pub db_path: PathBuf,
+    pub notes_dir: PathBuf,
 }
 
 impl Tool for ReadNote {
@@ -219,12 +262,13 @@ impl Tool for ReadNote {
 
     async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
         let db_path = self.db_path.clone();
+        let notes_dir = self.notes_dir.clone();
         let content = tokio::task::spawn_blocking(move || {
             let db = NotesDatabase::new(&db_path).map_err(|e| anyhow::anyhow!(e))?;
             let metadata = db.get_note(&args.name).map_err(|e| anyhow::anyhow!(e))?;
 
             if let Some(meta) = metadata {
-                std::fs::read_to_string(&meta.path).map_err(|e| anyhow::anyhow!(e))
+                read_allowed_note(&notes_dir, &meta.path)
             } else {
                 Err(anyhow::anyhow!("Note not found"))
             }
@@ -237,8 +281,8 @@ impl Tool for ReadNote {
 }
 
 impl ReadNote {
-    pub fn new(db_path: PathBuf) -> Self {
-        Self { db_path }
+    pub fn new(db_path: PathBuf, notes_dir: PathBuf) -> Self {
+        Self { db_path, notes_dir }
     }
 }
 
@@ -308,27 +352,40 @@ impl<M: EmbeddingModel + Sync + Send + Clone + 'static> Tool for CreateNote<M> {
             }
             file_path.push(format!("{}.md", args_for_closure.name));
 
+            // Apply the folder defaults (.notnative.toml): template, tags, frontmatter
+            let content = FolderDefaults::nearest(
+                &notes_dir,
+                args_for_closure.folder.as_deref().unwrap_or(""),
+            )
+            .unwrap_or_default()
+            .apply(
+                &args_for_closure.name,
+                &args_for_closure.content,
+                chrono::Local::now().date_naive(),
+            )?;
+
             // Write file
-            std::fs::write(&file_path, &args_for_closure.content)
-                .map_err(|e| anyhow::anyhow!(e))?;
+            std::fs::write(&file_path, &content).map_err(|e| anyhow::anyhow!(e))?;
 
             // Index in DB
             let path_str = file_path.to_string_lossy().to_string();
             db.index_note(
                 &args_for_closure.name,
                 &path_str,
-                &args_for_closure.content,
+                &content,
                 args_for_closure.folder.as_deref(),
             )
             .map_err(|e| anyhow::anyhow!(e))?;
 
-            Ok::<String, anyhow::Error>(format!(
+            let message = format!(
                 "Note '{}' created successfully at {}. Link: [{}]( {})",
                 args_for_closure.name, path_str, args_for_closure.name, args_for_closure.name
-            ))
+            );
+            Ok::<(String, String), anyhow::Error>((message, content))
         })
         .await
         .map_err(|e| ToolError(e.to_string()))??;
+        let (result, content) = result;
 
         // Index in Memory (Semantic Search) if available
         if let Some(mem) = memory {
@@ -336,8 +393,8 @@ impl<M: EmbeddingModel + Sync + Send + Clone + 'static> Tool for CreateNote<M> {
                 "name": args.name,
                 "folder": args.folder
             });
-            if let Err(e) = mem.index_note(&args.name, &args.content, metadata).await {
-                eprintln!("Failed to index note in vector store: {}", e);
+            if let Err(e) = mem.index_note(&args.name, &content, metadata).await {
+                error!("Failed to index note in vector store: {}", e);
                 // Don't fail the tool call, just log the error
             }
         }
@@ -431,6 +488,7 @@ pub struct IndexAllNotesArgs {}
 
 pub struct IndexAllNotes<M: EmbeddingModel + Sync + Send + Clone + 'static> {
     pub db_path: PathBuf,
+    pub notes_dir: PathBuf,
     pub memory: Arc<NoteMemory<M>>,
 }
 
@@ -454,8 +512,9 @@ impl<M: EmbeddingModel + Sync + Send + Clone + 'static> Tool for IndexAllNotes<M
     }
 
     async fn call(&self, _args: Self::Args) -> Result<Self::Output, Self::Error> {
-        eprintln!("🚀 [IndexAllNotes] Iniciando reindexación completa (versión corregida)...");
+        info!("[IndexAllNotes] Iniciando reindexación completa (versión corregida)...");
         let db_path = self.db_path.clone();
+        let notes_dir = self.notes_dir.clone();
         let memory = self.memory.clone();
 
         // Clear all existing indexes first
@@ -471,10 +530,10 @@ impl<M: EmbeddingModel + Sync + Send + Clone + 'static> Tool for IndexAllNotes<M
             let db = NotesDatabase::new(&db_path).map_err(|e| anyhow::anyhow!(e))?;
             let notes = db.list_notes(None).map_err(|e| anyhow::anyhow!(e))?;
 
-            // Read content for each note
+            // Read content for each note (excluded notes are skipped)
             let mut notes_with_content = Vec::new();
             for note in notes {
-                if let Ok(content) = std::fs::read_to_string(&note.path) {
+                if let Ok(content) = read_allowed_note(&notes_dir, &note.path) {
                     notes_with_content.push((note, content));
                 }
             }
@@ -485,7 +544,6 @@ impl<M: EmbeddingModel + Sync + Send + Clone + 'static> Tool for IndexAllNotes<M
         .await
         .map_err(|e| ToolError(e.to_string()))??;
 
-        let total = notes.len();
         let mut indexed = 0;
         let mut errors = 0;
 
@@ -498,7 +556,7 @@ impl<M: EmbeddingModel + Sync + Send + Clone + 'static> Tool for IndexAllNotes<M
             match memory.index_note(&note.name, &content, metadata).await {
                 Ok(_) => indexed += 1,
                 Err(e) => {
-                    eprintln!("Failed to index note '{}': {}", note.name, e);
+                    error!("Failed to index note '{}': {}", note.name, e);
                     errors += 1;
                 }
             }
@@ -512,7 +570,70 @@ impl<M: EmbeddingModel + Sync + Send + Clone + 'static> Tool for IndexAllNotes<M
 }
 
 impl<M: EmbeddingModel + Sync + Send + Clone + 'static> IndexAllNotes<M> {
-    pub fn new(db_path: PathBuf, memory: Arc<NoteMemory<M>>) -> Self {
-        Self { db_path, memory }
+    pub fn new(db_path: PathBuf, notes_dir: PathBuf, memory: Arc<NoteMemory<M>>) -> Self {
+        Self {
+            db_path,
+            notes_dir,
+            memory,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::FolderMeta;
+
+    /// Bóveda con una carpeta `Privado` excluida de la IA, una nota con
+    /// `ai: false` y otra normal; todas contienen "Presupuesto"
+    fn vault() -> (PathBuf, PathBuf) {
+        let dir = std::env::temp_dir().join(format!("notnative-ai-tools-{}", std::process::id()));
+        std::fs::remove_dir_all(&dir).ok();
+        std::fs::create_dir_all(dir.join("Privado")).unwrap();
+        FolderMeta {
+            ai: false,
+            ..Default::default()
+        }
+        .save(&dir.join("Privado"))
+        .unwrap();
+
+        let db_path = dir.join("notes.db");
+        let db = NotesDatabase::new(&db_path).unwrap();
+        for (name, folder, content) in [
+            ("diario", Some("Privado"), "# Presupuesto\n"),
+            ("salud", None, "---\nai: false\n---\n# Presupuesto\n"),
+            ("plan", None, "# Presupuesto\n"),
+        ] {
+            let path = match folder {
+                Some(folder) => dir.join(folder).join(format!("{}.md", name)),
+                None => dir.join(format!("{}.md", name)),
+            };
+            std::fs::write(&path, content).unwrap();
+            db.index_note(name, path.to_str().unwrap(), content, folder)
+                .unwrap();
+        }
+        (dir, db_path)
+    }
+
+    #[tokio::test]
+    async fn test_tools_skip_ai_excluded_notes() {
+        let (dir, db_path) = vault();
+
+        assert!(read_allowed_note(&dir, dir.join("plan.md").to_str().unwrap()).is_ok());
+        assert!(read_allowed_note(&dir, dir.join("salud.md").to_str().unwrap()).is_err());
+        assert!(read_allowed_note(&dir, dir.join("Privado/diario.md").to_str().unwrap()).is_err());
+
+        // La búsqueda solo devuelve la nota permitida
+        let output = SearchNotes::new(db_path, dir.clone())
+            .call(SearchArgs {
+                query: "Presupuesto".to_string(),
+            })
+            .await
+            .unwrap();
+        assert!(output.contains("plan"));
+        assert!(!output.contains("salud"));
+        assert!(!output.contains("diario"));
+
+        std::fs::remove_dir_all(dir).ok();
     }
 }
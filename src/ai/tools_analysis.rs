@@ -2,7 +2,7 @@
 //!
 //! Incluye análisis estructural, conteo de palabras, generación de TOC, etc.
 
-use crate::ai::tools::ToolError;
+use crate::ai::tools::{ToolError, read_allowed_note};
 use crate::core::database::NotesDatabase;
 use anyhow::Result;
 use rig::tool::Tool;
@@ -18,6 +18,7 @@ pub struct GetWordCountArgs {
 
 pub struct GetWordCount {
     pub db_path: PathBuf,
+    pub notes_dir: PathBuf,
 }
 
 impl Tool for GetWordCount {
@@ -47,14 +48,14 @@ impl Tool for GetWordCount {
 
     async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
         let db_path = self.db_path.clone();
+        let notes_dir = self.notes_dir.clone();
 
         let result = tokio::task::spawn_blocking(move || -> anyhow::Result<String> {
             let db = NotesDatabase::new(&db_path).map_err(|e| anyhow::anyhow!(e))?;
             let metadata = db.get_note(&args.name).map_err(|e| anyhow::anyhow!(e))?;
 
             if let Some(meta) = metadata {
-                let content =
-                    std::fs::read_to_string(&meta.path).map_err(|e| anyhow::anyhow!(e))?;
+                let content = read_allowed_note(&notes_dir, &meta.path)?;
 
                 let word_count = content.split_whitespace().count();
                 let line_count = content.lines().count();
@@ -76,8 +77,8 @@ impl Tool for GetWordCount {
 }
 
 impl GetWordCount {
-    pub fn new(db_path: PathBuf) -> Self {
-        Self { db_path }
+    pub fn new(db_path: PathBuf, notes_dir: PathBuf) -> Self {
+        Self { db_path, notes_dir }
     }
 }
 
@@ -90,6 +91,7 @@ pub struct GenerateTocArgs {
 
 pub struct GenerateToc {
     pub db_path: PathBuf,
+    pub notes_dir: PathBuf,
 }
 
 impl Tool for GenerateToc {
@@ -118,14 +120,14 @@ impl Tool for GenerateToc {
 
     async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
         let db_path = self.db_path.clone();
+        let notes_dir = self.notes_dir.clone();
 
         let result = tokio::task::spawn_blocking(move || -> anyhow::Result<String> {
             let db = NotesDatabase::new(&db_path).map_err(|e| anyhow::anyhow!(e))?;
             let metadata = db.get_note(&args.name).map_err(|e| anyhow::anyhow!(e))?;
 
             if let Some(meta) = metadata {
-                let content =
-                    std::fs::read_to_string(&meta.path).map_err(|e| anyhow::anyhow!(e))?;
+                let content = read_allowed_note(&notes_dir, &meta.path)?;
 
                 let mut toc = String::from("Table of Contents:\n\n");
                 let mut found_headings = false;
@@ -157,8 +159,8 @@ impl Tool for GenerateToc {
 }
 
 impl GenerateToc {
-    pub fn new(db_path: PathBuf) -> Self {
-        Self { db_path }
+    pub fn new(db_path: PathBuf, notes_dir: PathBuf) -> Self {
+        Self { db_path, notes_dir }
     }
 }
 
@@ -171,6 +173,7 @@ pub struct ExtractCodeBlocksArgs {
 
 pub struct ExtractCodeBlocks {
     pub db_path: PathBuf,
+    pub notes_dir: PathBuf,
 }
 
 impl Tool for ExtractCodeBlocks {
@@ -200,14 +203,14 @@ impl Tool for ExtractCodeBlocks {
 
     async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
         let db_path = self.db_path.clone();
+        let notes_dir = self.notes_dir.clone();
 
         let result = tokio::task::spawn_blocking(move || -> anyhow::Result<String> {
             let db = NotesDatabase::new(&db_path).map_err(|e| anyhow::anyhow!(e))?;
             let metadata = db.get_note(&args.name).map_err(|e| anyhow::anyhow!(e))?;
 
             if let Some(meta) = metadata {
-                let content =
-                    std::fs::read_to_string(&meta.path).map_err(|e| anyhow::anyhow!(e))?;
+                let content = read_allowed_note(&notes_dir, &meta.path)?;
 
                 let mut result = String::from("Code blocks found:\n\n");
                 let mut in_code_block = false;
@@ -258,8 +261,8 @@ impl Tool for ExtractCodeBlocks {
 }
 
 impl ExtractCodeBlocks {
-    pub fn new(db_path: PathBuf) -> Self {
-        Self { db_path }
+    pub fn new(db_path: PathBuf, notes_dir: PathBuf) -> Self {
+        Self { db_path, notes_dir }
     }
 }
 
@@ -272,6 +275,7 @@ pub struct AnalyzeNoteStructureArgs {
 
 pub struct AnalyzeNoteStructure {
     pub db_path: PathBuf,
+    pub notes_dir: PathBuf,
 }
 
 impl Tool for AnalyzeNoteStructure {
@@ -302,14 +306,14 @@ impl Tool for AnalyzeNoteStructure {
 
     async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
         let db_path = self.db_path.clone();
+        let notes_dir = self.notes_dir.clone();
 
         let result = tokio::task::spawn_blocking(move || -> anyhow::Result<String> {
             let db = NotesDatabase::new(&db_path).map_err(|e| anyhow::anyhow!(e))?;
             let metadata = db.get_note(&args.name).map_err(|e| anyhow::anyhow!(e))?;
 
             if let Some(meta) = metadata {
-                let content =
-                    std::fs::read_to_string(&meta.path).map_err(|e| anyhow::anyhow!(e))?;
+                let content = read_allowed_note(&notes_dir, &meta.path)?;
 
                 let mut h1_count = 0;
                 let mut h2_count = 0;
@@ -390,8 +394,8 @@ impl Tool for AnalyzeNoteStructure {
 }
 
 impl AnalyzeNoteStructure {
-    pub fn new(db_path: PathBuf) -> Self {
-        Self { db_path }
+    pub fn new(db_path: PathBuf, notes_dir: PathBuf) -> Self {
+        Self { db_path, notes_dir }
     }
 }
 
@@ -0,0 +1,387 @@
+//! Herramientas de escritura del agente RIG en modo simulación
+//!
+//! `DryRunTool` envuelve una herramienta que modifica notas. Fuera del modo
+//! simulación la llama tal cual; dentro, traduce los argumentos a llamadas
+//! MCP equivalentes y las apunta en el plan, que luego aplica el
+//! `MCPToolExecutor` si el usuario lo aprueba.
+
+use crate::ai::tools::ToolError;
+use crate::ai::tools_utility::DAILY_NOTE_TEMPLATE;
+use crate::core::database::NotesDatabase;
+use crate::i18n::Language;
+use crate::mcp::dry_run::simulated_result;
+use crate::mcp::{DryRun, MCPToolCall};
+use crate::reminders::{ReminderDatabase, ReminderDraft, ReminderParser};
+use rig::tool::Tool;
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+
+pub struct DryRunTool<T: Tool> {
+    inner: T,
+    dry_run: DryRun,
+    /// Base de datos donde buscar el id de los recordatorios que se borran o
+    /// modifican (las herramientas los identifican por su texto)
+    reminders_db: Option<PathBuf>,
+}
+
+impl<T: Tool> DryRunTool<T> {
+    pub fn new(inner: T, dry_run: &DryRun) -> Self {
+        Self {
+            inner,
+            dry_run: dry_run.clone(),
+            reminders_db: None,
+        }
+    }
+
+    pub fn with_reminders(mut self, db_path: PathBuf) -> Self {
+        self.reminders_db = Some(db_path);
+        self
+    }
+}
+
+impl<T: Tool> Tool for DryRunTool<T> {
+    const NAME: &'static str = T::NAME;
+
+    type Args = Value;
+    type Output = Value;
+    type Error = ToolError;
+
+    async fn definition(&self, prompt: String) -> rig::completion::ToolDefinition {
+        self.inner.definition(prompt).await
+    }
+
+    async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
+        if self.dry_run.is_enabled() {
+            let calls = planned_calls(T::NAME, args, self.reminders_db.clone())
+                .await?
+                .ok_or_else(|| {
+                    ToolError(format!(
+                        "'{}' no se puede simular (sin equivalente o no se encontró lo que \
+                         cambia). Dile al usuario que desactive el modo simulación si \
+                         quiere hacerlo.",
+                        T::NAME
+                    ))
+                })?;
+            for call in calls {
+                self.dry_run.record(call);
+            }
+            return Ok(simulated_result().data.unwrap_or_default());
+        }
+
+        let args: T::Args =
+            serde_json::from_value(args).map_err(|e| ToolError(format!("Argumentos: {}", e)))?;
+        let output = self
+            .inner
+            .call(args)
+            .await
+            .map_err(|e| ToolError(e.to_string()))?;
+        serde_json::to_value(output).map_err(|e| ToolError(e.to_string()))
+    }
+}
+
+/// `mcp_calls` fuera del hilo async: buscar recordatorios abre la base de datos
+async fn planned_calls(
+    tool: &'static str,
+    args: Value,
+    reminders_db: Option<PathBuf>,
+) -> Result<Option<Vec<MCPToolCall>>, ToolError> {
+    tokio::task::spawn_blocking(move || {
+        mcp_calls(tool, &args, |note, text| {
+            find_reminder_id(reminders_db.as_deref()?, note, text)
+        })
+    })
+    .await
+    .map_err(|e| ToolError(e.to_string()))
+}
+
+/// Id del recordatorio de la nota `note_name` cuyo título contiene
+/// `text_match`, como lo buscan las herramientas de recordatorios
+fn find_reminder_id(db_path: &Path, note_name: &str, text_match: &str) -> Option<i64> {
+    let db = NotesDatabase::new(db_path).ok()?;
+    let note = db.get_note(note_name).ok()??;
+    let reminders = ReminderDatabase::new(db.pool().get().ok()?)
+        .list_reminders_by_note(note.id)
+        .ok()?;
+    reminders
+        .into_iter()
+        .find(|reminder| reminder.title.contains(text_match))
+        .map(|reminder| reminder.id)
+}
+
+/// Llamadas MCP que hacen lo mismo que la herramienta RIG `tool` con `args`
+/// (los nombres de los argumentos no coinciden siempre). `None` si no hay
+/// equivalente o faltan argumentos. `reminder_id` resuelve nota y texto al
+/// id del recordatorio.
+fn mcp_calls(
+    tool: &str,
+    args: &Value,
+    reminder_id: impl Fn(&str, &str) -> Option<i64>,
+) -> Option<Vec<MCPToolCall>> {
+    let text = |key: &str| args.get(key).and_then(Value::as_str).map(str::to_string);
+    let list = |key: &str| {
+        args.get(key).and_then(Value::as_array).map(|items| {
+            items
+                .iter()
+                .filter_map(Value::as_str)
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        })
+    };
+    // Las fechas relativas ("mañana 10:00") se resuelven ya, como al proponer
+    let draft = |note: &str, title: &str, date: &str, priority: &str, repeat: &str| {
+        ReminderParser::new()
+            .parse_draft(
+                note,
+                title,
+                date,
+                text(priority).as_deref(),
+                text(repeat).as_deref(),
+                Language::Spanish,
+            )
+            .ok()
+    };
+
+    let calls = match tool {
+        "create_note" => vec![MCPToolCall::CreateNote {
+            name: text("name")?,
+            content: text("content")?,
+            folder: text("folder"),
+        }],
+        "update_note" => vec![MCPToolCall::UpdateNote {
+            name: text("name")?,
+            content: text("content")?,
+        }],
+        "append_to_note" => vec![MCPToolCall::AppendToNote {
+            name: text("name")?,
+            content: text("content")?,
+        }],
+        "delete_note" => vec![MCPToolCall::DeleteNote {
+            name: text("name")?,
+        }],
+        "rename_note" => vec![MCPToolCall::RenameNote {
+            old_name: text("old_name")?,
+            new_name: text("new_name")?,
+        }],
+        "batch_rename_notes" => args
+            .get("renames")?
+            .as_array()?
+            .iter()
+            .map(|pair| {
+                Some(MCPToolCall::RenameNote {
+                    old_name: pair.get("old_name")?.as_str()?.to_string(),
+                    new_name: pair.get("new_name")?.as_str()?.to_string(),
+                })
+            })
+            .collect::<Option<Vec<_>>>()?,
+        "duplicate_note" => vec![MCPToolCall::DuplicateNote {
+            name: text("name")?,
+            new_name: text("new_name")?,
+        }],
+        "merge_notes" => vec![MCPToolCall::MergeNotes {
+            note_names: list("names")?,
+            output_name: text("target_name")?,
+        }],
+        "move_note" => vec![MCPToolCall::MoveNote {
+            name: text("name")?,
+            folder: text("folder")?,
+        }],
+        "batch_move_notes" => {
+            let folder = text("folder")?;
+            list("notes")?
+                .into_iter()
+                .map(|name| MCPToolCall::MoveNote {
+                    name,
+                    folder: folder.clone(),
+                })
+                .collect()
+        }
+        "add_tag" => vec![MCPToolCall::AddTag {
+            note: text("name")?,
+            tag: text("tag")?,
+        }],
+        "remove_tag" => vec![MCPToolCall::RemoveTag {
+            note: text("name")?,
+            tag: text("tag")?,
+        }],
+        "find_and_replace" => vec![MCPToolCall::FindAndReplace {
+            find: text("find")?,
+            replace: text("replace")?,
+            note_names: Some(vec![text("name")?]),
+        }],
+        "create_folder" => vec![MCPToolCall::CreateFolder {
+            name: text("path")?,
+            parent: None,
+        }],
+        "batch_create_folders" => list("folders")?
+            .into_iter()
+            .map(|name| MCPToolCall::CreateFolder { name, parent: None })
+            .collect(),
+        "delete_folder" => vec![MCPToolCall::DeleteFolder {
+            name: text("path")?,
+            recursive: args.get("recursive").and_then(Value::as_bool),
+        }],
+        "create_project_folder" => vec![MCPToolCall::CreateProjectFolder {
+            name: text("name")?,
+            template: text("template"),
+            parent: text("parent"),
+        }],
+        "create_daily_note" => vec![MCPToolCall::CreateDailyNote {
+            template: Some(DAILY_NOTE_TEMPLATE.to_string()),
+            folder: text("folder"),
+        }],
+        "create_reminder" => {
+            let note_name = text("note_name")?;
+            let ReminderDraft {
+                title,
+                due_date,
+                priority,
+                repeat_pattern,
+                ..
+            } = draft(
+                &note_name,
+                &text("text")?,
+                &text("date")?,
+                "priority",
+                "repeat",
+            )?;
+            vec![MCPToolCall::CreateReminder {
+                title,
+                due_date: due_date.to_rfc3339(),
+                description: None,
+                priority: Some(priority.to_str(false).to_string()),
+                repeat: Some(repeat_pattern.to_str(false).to_string()),
+                note_name: Some(note_name),
+            }]
+        }
+        "delete_reminder" => vec![MCPToolCall::DeleteReminder {
+            id: reminder_id(&text("note_name")?, &text("text_match")?)?,
+        }],
+        "modify_reminder" => {
+            let note_name = text("note_name")?;
+            let new = draft(
+                &note_name,
+                &text("new_text")?,
+                &text("new_date")?,
+                "new_priority",
+                "new_repeat",
+            )?;
+            vec![MCPToolCall::UpdateReminder {
+                id: reminder_id(&note_name, &text("original_text_match")?)?,
+                title: Some(new.title),
+                description: None,
+                due_date: Some(new.due_date.to_rfc3339()),
+                priority: text("new_priority").map(|_| new.priority.to_str(false).to_string()),
+                repeat: text("new_repeat").map(|_| new.repeat_pattern.to_str(false).to_string()),
+            }]
+        }
+        _ => return None,
+    };
+    Some(calls)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_every_wrapped_tool_has_mcp_calls() {
+        // Todas las herramientas que rig_executor envuelve en DryRunTool
+        let cases = [
+            ("create_note", json!({"name": "a", "content": "x"})),
+            ("update_note", json!({"name": "a", "content": "x"})),
+            ("append_to_note", json!({"name": "a", "content": "x"})),
+            ("delete_note", json!({"name": "a"})),
+            ("rename_note", json!({"old_name": "a", "new_name": "b"})),
+            (
+                "batch_rename_notes",
+                json!({"renames": [{"old_name": "a", "new_name": "b"}]}),
+            ),
+            ("duplicate_note", json!({"name": "a", "new_name": "b"})),
+            (
+                "merge_notes",
+                json!({"names": ["a", "b"], "target_name": "c"}),
+            ),
+            ("add_tag", json!({"name": "a", "tag": "t"})),
+            ("remove_tag", json!({"name": "a", "tag": "t"})),
+            ("create_folder", json!({"path": "f"})),
+            ("batch_create_folders", json!({"folders": ["f", "g"]})),
+            ("create_project_folder", json!({"name": "p"})),
+            ("delete_folder", json!({"path": "f"})),
+            ("move_note", json!({"name": "a", "folder": "f"})),
+            (
+                "batch_move_notes",
+                json!({"notes": ["a", "b"], "folder": "f"}),
+            ),
+            (
+                "find_and_replace",
+                json!({"name": "a", "find": "x", "replace": "y"}),
+            ),
+            ("create_daily_note", json!({})),
+            (
+                "create_reminder",
+                json!({"note_name": "a", "text": "Llamar", "date": "2030-01-02 10:00"}),
+            ),
+            (
+                "delete_reminder",
+                json!({"note_name": "a", "text_match": "Llamar"}),
+            ),
+            (
+                "modify_reminder",
+                json!({
+                    "note_name": "a",
+                    "original_text_match": "Llamar",
+                    "new_date": "2030-01-03 11:00",
+                    "new_text": "Llamar otra vez",
+                    "new_priority": "high"
+                }),
+            ),
+        ];
+
+        for (tool, args) in cases {
+            let calls = mcp_calls(tool, &args, |_, _| Some(7));
+            assert!(
+                calls.is_some_and(|calls| !calls.is_empty()),
+                "'{}' no tiene equivalente MCP",
+                tool
+            );
+        }
+    }
+
+    #[test]
+    fn test_reminder_calls() {
+        let calls = mcp_calls(
+            "modify_reminder",
+            &json!({
+                "note_name": "a",
+                "original_text_match": "Llamar",
+                "new_date": "2030-01-03 11:00",
+                "new_text": "Llamar otra vez",
+                "new_priority": "high"
+            }),
+            |note, text| (note == "a" && text == "Llamar").then_some(7),
+        )
+        .unwrap();
+        match &calls[..] {
+            [
+                MCPToolCall::UpdateReminder {
+                    id,
+                    title,
+                    priority,
+                    repeat,
+                    ..
+                },
+            ] => {
+                assert_eq!(*id, 7);
+                assert_eq!(title.as_deref(), Some("Llamar otra vez"));
+                assert_eq!(priority.as_deref(), Some("high"));
+                assert_eq!(*repeat, None);
+            }
+            other => panic!("{:?}", other),
+        }
+
+        // Sin recordatorio que coincida no hay nada que simular
+        let missing = json!({"note_name": "a", "text_match": "Otro"});
+        assert!(mcp_calls("delete_reminder", &missing, |_, _| None).is_none());
+    }
+}
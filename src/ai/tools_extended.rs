@@ -3,7 +3,7 @@
 //! Este módulo contiene implementaciones adicionales de herramientas MCP
 //! como herramientas nativas de RIG para mejor rendimiento.
 
-use crate::ai::tools::ToolError;
+use crate::ai::tools::{ToolError, read_allowed_note};
 use crate::core::database::NotesDatabase;
 use anyhow::Result;
 use rig::tool::Tool;
@@ -100,6 +100,7 @@ pub struct AppendToNoteArgs {
 
 pub struct AppendToNote {
     pub db_path: PathBuf,
+    pub notes_dir: PathBuf,
 }
 
 impl Tool for AppendToNote {
@@ -132,14 +133,14 @@ impl Tool for AppendToNote {
 
     async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
         let db_path = self.db_path.clone();
+        let notes_dir = self.notes_dir.clone();
 
         let result = tokio::task::spawn_blocking(move || -> anyhow::Result<String> {
             let db = NotesDatabase::new(&db_path).map_err(|e| anyhow::anyhow!(e))?;
             let metadata = db.get_note(&args.name).map_err(|e| anyhow::anyhow!(e))?;
 
             if let Some(meta) = metadata {
-                let mut current_content =
-                    std::fs::read_to_string(&meta.path).map_err(|e| anyhow::anyhow!(e))?;
+                let mut current_content = read_allowed_note(&notes_dir, &meta.path)?;
 
                 current_content.push_str(&args.content);
 
@@ -170,8 +171,8 @@ impl Tool for AppendToNote {
 }
 
 impl AppendToNote {
-    pub fn new(db_path: PathBuf) -> Self {
-        Self { db_path }
+    pub fn new(db_path: PathBuf, notes_dir: PathBuf) -> Self {
+        Self { db_path, notes_dir }
     }
 }
 
@@ -196,7 +197,7 @@ impl Tool for DeleteNote {
     async fn definition(&self, _prompt: String) -> rig::completion::ToolDefinition {
         rig::completion::ToolDefinition {
             name: "delete_note".to_string(),
-            description: "Permanently delete a note. Be careful, this action cannot be undone!"
+            description: "Move a note to the trash and remove it from the index. It can be restored from the trash."
                 .to_string(),
             parameters: serde_json::json!({
                 "type": "object",
@@ -251,6 +252,7 @@ pub struct GetNotesWithTagArgs {
 
 pub struct GetNotesWithTag {
     pub db_path: PathBuf,
+    pub notes_dir: PathBuf,
 }
 
 impl Tool for GetNotesWithTag {
@@ -279,6 +281,7 @@ impl Tool for GetNotesWithTag {
 
     async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
         let db_path = self.db_path.clone();
+        let notes_dir = self.notes_dir.clone();
         let tag_clone = args.tag.clone();
 
         let notes = tokio::task::spawn_blocking(
@@ -290,7 +293,7 @@ impl Tool for GetNotesWithTag {
                 let filtered: Vec<_> = all_notes
                     .into_iter()
                     .filter(|note| {
-                        if let Ok(content) = std::fs::read_to_string(&note.path) {
+                        if let Ok(content) = read_allowed_note(&notes_dir, &note.path) {
                             use crate::core::frontmatter::extract_all_tags;
                             let tags = extract_all_tags(&content);
                             tags.iter().any(|t| t == &tag_clone)
@@ -323,8 +326,8 @@ impl Tool for GetNotesWithTag {
 }
 
 impl GetNotesWithTag {
-    pub fn new(db_path: PathBuf) -> Self {
-        Self { db_path }
+    pub fn new(db_path: PathBuf, notes_dir: PathBuf) -> Self {
+        Self { db_path, notes_dir }
     }
 }
 
@@ -435,7 +438,7 @@ impl Tool for GetRecentNotes {
             move || -> anyhow::Result<Vec<crate::core::database::NoteMetadata>> {
                 let db = NotesDatabase::new(&db_path).map_err(|e| anyhow::anyhow!(e))?;
                 let mut all_notes = db.list_notes(None).map_err(|e| anyhow::anyhow!(e))?;
-                all_notes.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
+                all_notes.sort_by_key(|note| std::cmp::Reverse(note.updated_at));
                 all_notes.truncate(args.limit);
                 Ok(all_notes)
             },
@@ -2,10 +2,12 @@
 
 use crate::ai::tools::ToolError;
 use crate::core::database::NotesDatabase;
+use crate::core::{FolderTemplates, NoteStore, NotesDirectory};
 use anyhow::Result;
 use rig::tool::Tool;
 use serde::Deserialize;
 use std::path::PathBuf;
+use tracing::info;
 
 // ==================== LIST FOLDERS ====================
 
@@ -36,7 +38,7 @@ impl Tool for ListFolders {
     }
 
     async fn call(&self, _args: Self::Args) -> Result<Self::Output, Self::Error> {
-        println!("🔧 [ListFolders] Calling tool...");
+        info!("[ListFolders] Calling tool...");
         let db_path = self.db_path.clone();
         let notes_dir = self.notes_dir.clone();
 
@@ -166,7 +168,7 @@ impl Tool for CreateFolder {
     }
 
     async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        println!("🔧 [CreateFolder] Creating folder: {}", args.path);
+        info!("[CreateFolder] Creating folder: {}", args.path);
         let notes_dir = self.notes_dir.clone();
 
         let result = tokio::task::spawn_blocking(move || -> anyhow::Result<String> {
@@ -194,6 +196,128 @@ impl CreateFolder {
     }
 }
 
+// ==================== CREATE PROJECT FOLDER ====================
+
+#[derive(Deserialize)]
+pub struct CreateProjectFolderArgs {
+    pub name: String,
+    #[serde(default)]
+    pub template: Option<String>,
+    #[serde(default)]
+    pub parent: Option<String>,
+}
+
+pub struct CreateProjectFolder {
+    pub db_path: PathBuf,
+    pub notes_dir: PathBuf,
+}
+
+impl Tool for CreateProjectFolder {
+    const NAME: &'static str = "create_project_folder";
+
+    type Args = CreateProjectFolderArgs;
+    type Output = String;
+    type Error = ToolError;
+
+    async fn definition(&self, _prompt: String) -> rig::completion::ToolDefinition {
+        let templates = FolderTemplates::load(&FolderTemplates::default_path())
+            .map(|templates| {
+                templates
+                    .templates
+                    .iter()
+                    .map(|template| template.name.clone())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .unwrap_or_default();
+
+        rig::completion::ToolDefinition {
+            name: "create_project_folder".to_string(),
+            description: format!(
+                "Scaffold a new project folder from a folder template (subfolders and pre-filled notes such as Overview.md, Meetings/ and Tasks.md). Use it when the user starts a new project. Available templates: {}",
+                templates
+            ),
+            parameters: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "name": {
+                        "type": "string",
+                        "description": "Project name, used as the folder name"
+                    },
+                    "template": {
+                        "type": "string",
+                        "description": "Template name (optional, defaults to the first one)"
+                    },
+                    "parent": {
+                        "type": "string",
+                        "description": "Folder to create the project in (optional, defaults to the root)"
+                    }
+                },
+                "required": ["name"]
+            }),
+        }
+    }
+
+    async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
+        info!("[CreateProjectFolder] Creating project: {}", args.name);
+        let db_path = self.db_path.clone();
+        let notes_dir = self.notes_dir.clone();
+
+        let result = tokio::task::spawn_blocking(move || -> anyhow::Result<String> {
+            let templates = FolderTemplates::load(&FolderTemplates::default_path())?;
+            let folder = match &args.parent {
+                Some(parent) => format!("{}/{}", parent.trim_end_matches('/'), args.name),
+                None => args.name.clone(),
+            };
+            let notes = templates.create_project(
+                args.template.as_deref(),
+                &notes_dir,
+                &folder,
+                chrono::Local::now().date_naive(),
+            )?;
+
+            // Index the new notes
+            let db = NotesDatabase::new(&db_path).map_err(|e| anyhow::anyhow!(e))?;
+            let mut created = Vec::new();
+            for path in &notes {
+                let Ok(relative) = path.strip_prefix(&notes_dir) else {
+                    continue;
+                };
+                let note_name = relative.with_extension("").to_string_lossy().to_string();
+                let note_folder = relative
+                    .parent()
+                    .map(|p| p.to_string_lossy().to_string())
+                    .filter(|p| !p.is_empty());
+                let content = std::fs::read_to_string(path)?;
+                db.index_note(
+                    &note_name,
+                    path.to_str().unwrap_or(""),
+                    &content,
+                    note_folder.as_deref(),
+                )
+                .map_err(|e| anyhow::anyhow!(e))?;
+                created.push(format!("[{}]({})", note_name, note_name));
+            }
+
+            Ok(format!(
+                "Project '{}' created with: {}",
+                folder,
+                created.join(", ")
+            ))
+        })
+        .await
+        .map_err(|e| ToolError(e.to_string()))??;
+
+        Ok(result)
+    }
+}
+
+impl CreateProjectFolder {
+    pub fn new(db_path: PathBuf, notes_dir: PathBuf) -> Self {
+        Self { db_path, notes_dir }
+    }
+}
+
 // ==================== MOVE NOTE ====================
 
 #[derive(Deserialize)]
@@ -237,8 +361,8 @@ impl Tool for MoveNote {
     }
 
     async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        println!(
-            "🔧 [MoveNote] Moving note '{}' to '{}'",
+        info!(
+            "[MoveNote] Moving note '{}' to '{}'",
             args.name, args.folder
         );
         let db_path = self.db_path.clone();
@@ -246,53 +370,22 @@ impl Tool for MoveNote {
 
         let result = tokio::task::spawn_blocking(move || -> anyhow::Result<String> {
             let db = NotesDatabase::new(&db_path).map_err(|e| anyhow::anyhow!(e))?;
-            let metadata = db.get_note(&args.name).map_err(|e| anyhow::anyhow!(e))?;
-
-            if let Some(meta) = metadata {
-                let new_dir = if args.folder.is_empty() {
-                    notes_dir.clone()
-                } else {
-                    notes_dir.join(&args.folder)
-                };
-
-                // Create folder if it doesn't exist
-                if !new_dir.exists() {
-                    std::fs::create_dir_all(&new_dir)
-                        .map_err(|e| anyhow::anyhow!("Failed to create folder: {}", e))?;
-                }
-
-                let new_path = new_dir.join(format!("{}.md", args.name));
-
-                if new_path.exists() {
-                    return Err(anyhow::anyhow!(
-                        "A note named '{}' already exists in folder '{}'",
-                        args.name,
-                        args.folder
-                    ));
-                }
-
-                // Move the file
-                std::fs::rename(&meta.path, &new_path)
-                    .map_err(|e| anyhow::anyhow!("Failed to move note: {}", e))?;
-
-                // Update database
-                let content = std::fs::read_to_string(&new_path).map_err(|e| anyhow::anyhow!(e))?;
+            let notes_dir = NotesDirectory::new(&notes_dir)?;
+            let folder = if args.folder.is_empty() {
+                None
+            } else {
+                Some(args.folder.as_str())
+            };
 
-                let new_folder = if args.folder.is_empty() {
-                    None
-                } else {
-                    Some(args.folder.as_str())
-                };
-                db.index_note(&args.name, new_path.to_str().unwrap(), &content, new_folder)
-                    .map_err(|e| anyhow::anyhow!(e))?;
+            // File and index row move together
+            NoteStore::new(&notes_dir, &db)
+                .move_to_folder(&args.name, folder)
+                .map_err(|e| anyhow::anyhow!("Failed to move note '{}': {}", args.name, e))?;
 
-                Ok(format!(
-                    "Note '{}' moved to folder '{}'",
-                    args.name, args.folder
-                ))
-            } else {
-                Err(anyhow::anyhow!("Note '{}' not found", args.name))
-            }
+            Ok(format!(
+                "Note '{}' moved to folder '{}'",
+                args.name, args.folder
+            ))
         })
         .await
         .map_err(|e| ToolError(e.to_string()))??;
@@ -317,6 +410,7 @@ pub struct RenameNoteArgs {
 
 pub struct RenameNote {
     pub db_path: PathBuf,
+    pub notes_dir: PathBuf,
 }
 
 impl Tool for RenameNote {
@@ -349,57 +443,33 @@ impl Tool for RenameNote {
 
     async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
         let db_path = self.db_path.clone();
+        let notes_dir = self.notes_dir.clone();
 
         let result = tokio::task::spawn_blocking(move || -> anyhow::Result<String> {
             let db = NotesDatabase::new(&db_path).map_err(|e| anyhow::anyhow!(e))?;
-            let metadata = db
-                .get_note(&args.old_name)
-                .map_err(|e| anyhow::anyhow!(e))?;
-
-            if let Some(meta) = metadata {
-                // Check if new name already exists
-                if db
-                    .get_note(&args.new_name)
-                    .map_err(|e| anyhow::anyhow!(e))?
-                    .is_some()
-                {
-                    return Err(anyhow::anyhow!(
-                        "A note named '{}' already exists",
-                        args.new_name
-                    ));
-                }
-
-                let note_path = PathBuf::from(&meta.path);
-                let parent_dir = note_path
-                    .parent()
-                    .ok_or_else(|| anyhow::anyhow!("Invalid path"))?;
-                let new_path = parent_dir.join(format!("{}.md", args.new_name));
-
-                // Rename the file
-                std::fs::rename(&meta.path, &new_path)
-                    .map_err(|e| anyhow::anyhow!("Failed to rename note: {}", e))?;
-
-                // Update database - delete old, insert new
-                db.delete_note(&args.old_name)
-                    .map_err(|e| anyhow::anyhow!(e))?;
-
-                let content = std::fs::read_to_string(&new_path).map_err(|e| anyhow::anyhow!(e))?;
+            let notes_dir = NotesDirectory::new(&notes_dir)?;
+
+            // Check if new name already exists
+            if db
+                .get_note(&args.new_name)
+                .map_err(|e| anyhow::anyhow!(e))?
+                .is_some()
+            {
+                return Err(anyhow::anyhow!(
+                    "A note named '{}' already exists",
+                    args.new_name
+                ));
+            }
 
-                db.index_note(
-                    &args.new_name,
-                    new_path.to_str().unwrap(),
-                    &content,
-                    meta.folder.as_deref(),
-                )
-                .map_err(|e| anyhow::anyhow!(e))?;
+            // File and index row (with its embeddings) are renamed together
+            NoteStore::new(&notes_dir, &db)
+                .rename(&args.old_name, &args.new_name)
+                .map_err(|e| anyhow::anyhow!("Failed to rename note: {}", e))?;
 
-                Ok(format!(
-                    "Note renamed from '{}' to '{}'",
-                    args.old_name, args.new_name
-                ))
-            } else {
-                Err(anyhow::anyhow!("Note '{}' not found", args.old_name))
-            }
+            Ok(format!(
+                "Note renamed from '{}' to '{}'",
+                args.old_name, args.new_name
+            ))
         })
         .await
         .map_err(|e| ToolError(e.to_string()))??;
@@ -409,8 +479,8 @@ impl Tool for RenameNote {
 }
 
 impl RenameNote {
-    pub fn new(db_path: PathBuf) -> Self {
-        Self { db_path }
+    pub fn new(db_path: PathBuf, notes_dir: PathBuf) -> Self {
+        Self { db_path, notes_dir }
     }
 }
 
@@ -429,6 +499,7 @@ pub struct BatchRenameNotesArgs {
 
 pub struct BatchRenameNotes {
     pub db_path: PathBuf,
+    pub notes_dir: PathBuf,
 }
 
 impl Tool for BatchRenameNotes {
@@ -464,14 +535,14 @@ impl Tool for BatchRenameNotes {
     }
 
     async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        println!(
-            "🔧 [BatchRenameNotes] Renaming {} notes",
-            args.renames.len()
-        );
+        info!("[BatchRenameNotes] Renaming {} notes", args.renames.len());
         let db_path = self.db_path.clone();
+        let notes_dir = self.notes_dir.clone();
 
         let result = tokio::task::spawn_blocking(move || -> anyhow::Result<String> {
             let db = NotesDatabase::new(&db_path).map_err(|e| anyhow::anyhow!(e))?;
+            let notes_dir = NotesDirectory::new(&notes_dir)?;
+            let store = NoteStore::new(&notes_dir, &db);
 
             let mut renamed_count = 0;
             let mut errors = Vec::new();
@@ -489,53 +560,10 @@ impl Tool for BatchRenameNotes {
                     continue;
                 }
 
-                if let Ok(Some(meta)) = db.get_note(&old_name) {
-                    let note_path = PathBuf::from(&meta.path);
-                    let parent_dir = match note_path.parent() {
-                        Some(p) => p,
-                        None => {
-                            errors.push(format!("Invalid path for note '{}'", old_name));
-                            continue;
-                        }
-                    };
-                    let new_path = parent_dir.join(format!("{}.md", new_name));
-
-                    // Rename the file
-                    if let Err(e) = std::fs::rename(&meta.path, &new_path) {
-                        errors.push(format!("Failed to rename '{}': {}", old_name, e));
-                        continue;
-                    }
-
-                    // Update database - delete old, insert new
-                    if let Err(e) = db.delete_note(&old_name) {
-                        errors.push(format!(
-                            "Failed to delete old index for '{}': {}",
-                            old_name, e
-                        ));
-                        // Try to revert file rename? Too risky/complex for now.
-                        continue;
-                    }
-
-                    let content = match std::fs::read_to_string(&new_path) {
-                        Ok(c) => c,
-                        Err(e) => {
-                            errors.push(format!("Failed to read new file '{}': {}", new_name, e));
-                            continue;
-                        }
-                    };
-
-                    if let Err(e) = db.index_note(
-                        &new_name,
-                        new_path.to_str().unwrap(),
-                        &content,
-                        meta.folder.as_deref(),
-                    ) {
-                        errors.push(format!("Failed to index new note '{}': {}", new_name, e));
-                    } else {
-                        renamed_count += 1;
-                    }
-                } else {
-                    errors.push(format!("Note '{}' not found", old_name));
+                // A failed index update puts the file back
+                match store.rename(&old_name, &new_name) {
+                    Ok(_) => renamed_count += 1,
+                    Err(e) => errors.push(format!("Failed to rename '{}': {}", old_name, e)),
                 }
             }
 
@@ -557,8 +585,8 @@ impl Tool for BatchRenameNotes {
 }
 
 impl BatchRenameNotes {
-    pub fn new(db_path: PathBuf) -> Self {
-        Self { db_path }
+    pub fn new(db_path: PathBuf, notes_dir: PathBuf) -> Self {
+        Self { db_path, notes_dir }
     }
 }
 
@@ -605,8 +633,8 @@ impl Tool for BatchMoveNotes {
     }
 
     async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        println!(
-            "🔧 [BatchMoveNotes] Moving {} notes to '{}'",
+        info!(
+            "[BatchMoveNotes] Moving {} notes to '{}'",
             args.notes.len(),
             args.folder
         );
@@ -615,55 +643,22 @@ impl Tool for BatchMoveNotes {
 
         let result = tokio::task::spawn_blocking(move || -> anyhow::Result<String> {
             let db = NotesDatabase::new(&db_path).map_err(|e| anyhow::anyhow!(e))?;
-
-            let new_dir = if args.folder.is_empty() {
-                notes_dir.clone()
+            let notes_dir = NotesDirectory::new(&notes_dir)?;
+            let store = NoteStore::new(&notes_dir, &db);
+            let folder = if args.folder.is_empty() {
+                None
             } else {
-                notes_dir.join(&args.folder)
+                Some(args.folder.as_str())
             };
 
-            // Create folder if it doesn't exist
-            if !new_dir.exists() {
-                std::fs::create_dir_all(&new_dir)
-                    .map_err(|e| anyhow::anyhow!("Failed to create folder: {}", e))?;
-            }
-
             let mut moved_count = 0;
             let mut errors = Vec::new();
 
-            for note_name in args.notes {
-                if let Ok(Some(meta)) = db.get_note(&note_name) {
-                    let new_path = new_dir.join(format!("{}.md", note_name));
-
-                    if new_path.exists() {
-                        errors.push(format!("Note '{}' already exists in target folder", note_name));
-                        continue;
-                    }
-
-                    // Move the file
-                    if let Err(e) = std::fs::rename(&meta.path, &new_path) {
-                        errors.push(format!("Failed to move '{}': {}", note_name, e));
-                        continue;
-                    }
-
-                    // Update database
-                    if let Ok(content) = std::fs::read_to_string(&new_path) {
-                        let new_folder = if args.folder.is_empty() {
-                            None
-                        } else {
-                            Some(args.folder.as_str())
-                        };
-
-                        if let Err(e) = db.index_note(&note_name, new_path.to_str().unwrap(), &content, new_folder) {
-                            errors.push(format!("Failed to update DB for '{}': {}", note_name, e));
-                        } else {
-                            moved_count += 1;
-                        }
-                    } else {
-                        errors.push(format!("Failed to read moved note '{}'", note_name));
-                    }
-                } else {
-                    errors.push(format!("Note '{}' not found", note_name));
+            for note_name in &args.notes {
+                // A failed index update puts the file back
+                match store.move_to_folder(note_name, folder) {
+                    Ok(_) => moved_count += 1,
+                    Err(e) => errors.push(format!("Failed to move '{}': {}", note_name, e)),
                 }
             }
 
@@ -729,8 +724,8 @@ impl Tool for BatchCreateFolders {
     }
 
     async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        println!(
-            "🔧 [BatchCreateFolders] Creating {} folders",
+        info!(
+            "[BatchCreateFolders] Creating {} folders",
             args.folders.len()
         );
         let notes_dir = self.notes_dir.clone();
@@ -827,8 +822,8 @@ impl Tool for DeleteFolder {
     }
 
     async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        println!(
-            "🔧 [DeleteFolder] Deleting folder '{}' (recursive: {}, force: {:?})",
+        info!(
+            "[DeleteFolder] Deleting folder '{}' (recursive: {}, force: {:?})",
             args.path, args.recursive, args.force
         );
         let db_path = self.db_path.clone();
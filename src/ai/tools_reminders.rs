@@ -1,5 +1,8 @@
-use crate::ai::tools::ToolError;
+use crate::ai::tools::{ToolError, read_allowed_note};
 use crate::core::database::NotesDatabase;
+use crate::i18n::Language;
+use crate::mcp::{MCPChange, MCPChangeListener};
+use crate::reminders::ReminderParser;
 use anyhow::Result;
 use chrono::{Duration, Local};
 use rig::tool::Tool;
@@ -46,6 +49,9 @@ pub struct CreateReminderArgs {
 
 pub struct CreateReminder {
     pub db_path: PathBuf,
+    pub notes_dir: PathBuf,
+    /// Con receptor, el recordatorio se propone en el chat en vez de escribirse
+    pub proposals: Option<MCPChangeListener>,
 }
 
 impl Tool for CreateReminder {
@@ -89,7 +95,12 @@ impl Tool for CreateReminder {
     }
 
     async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
+        if let Some(proposals) = self.proposals.clone() {
+            return self.propose(args, proposals).await;
+        }
+
         let db_path = self.db_path.clone();
+        let notes_dir = self.notes_dir.clone();
 
         let result = tokio::task::spawn_blocking(move || -> anyhow::Result<String> {
             let db = NotesDatabase::new(&db_path).map_err(|e| anyhow::anyhow!(e))?;
@@ -119,8 +130,7 @@ impl Tool for CreateReminder {
                 let reminder_line = format!("\n!!RECORDAR({}, {})\n", params, args.text);
 
                 // Append to note
-                let mut current_content =
-                    std::fs::read_to_string(&meta.path).map_err(|e| anyhow::anyhow!(e))?;
+                let mut current_content = read_allowed_note(&notes_dir, &meta.path)?;
 
                 current_content.push_str(&reminder_line);
 
@@ -152,8 +162,62 @@ impl Tool for CreateReminder {
 }
 
 impl CreateReminder {
-    pub fn new(db_path: PathBuf) -> Self {
-        Self { db_path }
+    pub fn new(db_path: PathBuf, notes_dir: PathBuf) -> Self {
+        Self {
+            db_path,
+            notes_dir,
+            proposals: None,
+        }
+    }
+
+    /// Propone los recordatorios al usuario (tarjeta en el chat) en vez de
+    /// escribirlos directamente en la nota
+    pub fn with_confirmation(mut self, proposals: Option<MCPChangeListener>) -> Self {
+        self.proposals = proposals;
+        self
+    }
+
+    /// Valida la nota y la fecha y manda el borrador a la UI
+    async fn propose(
+        &self,
+        args: CreateReminderArgs,
+        proposals: MCPChangeListener,
+    ) -> Result<String, ToolError> {
+        let db_path = self.db_path.clone();
+
+        let result = tokio::task::spawn_blocking(move || -> anyhow::Result<String> {
+            let db = NotesDatabase::new(&db_path).map_err(|e| anyhow::anyhow!(e))?;
+            if db
+                .get_note(&args.note_name)
+                .map_err(|e| anyhow::anyhow!(e))?
+                .is_none()
+            {
+                return Err(anyhow::anyhow!("Note '{}' not found", args.note_name));
+            }
+
+            // El parser entiende las palabras relativas en los dos idiomas
+            let draft = ReminderParser::new().parse_draft(
+                &args.note_name,
+                &args.text,
+                &args.date,
+                args.priority.as_deref(),
+                args.repeat.as_deref(),
+                Language::Spanish,
+            )?;
+            let line = draft.to_note_line();
+            proposals.notify(MCPChange::ReminderProposed(draft));
+
+            Ok(format!(
+                "Reminder proposal shown to the user in the chat: {}. It is NOT created yet: \
+                 the user will confirm, edit or cancel it from the card. Do not call \
+                 create_reminder again for it; ask the user to review the card.",
+                line
+            ))
+        })
+        .await
+        .map_err(|e| ToolError(e.to_string()))??;
+
+        Ok(result)
     }
 }
 
@@ -167,6 +231,7 @@ pub struct DeleteReminderArgs {
 
 pub struct DeleteReminder {
     pub db_path: PathBuf,
+    pub notes_dir: PathBuf,
 }
 
 impl Tool for DeleteReminder {
@@ -199,6 +264,7 @@ impl Tool for DeleteReminder {
 
     async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
         let db_path = self.db_path.clone();
+        let notes_dir = self.notes_dir.clone();
 
         let result = tokio::task::spawn_blocking(move || -> anyhow::Result<String> {
             let db = NotesDatabase::new(&db_path).map_err(|e| anyhow::anyhow!(e))?;
@@ -207,8 +273,7 @@ impl Tool for DeleteReminder {
                 .map_err(|e| anyhow::anyhow!(e))?;
 
             if let Some(meta) = metadata {
-                let current_content =
-                    std::fs::read_to_string(&meta.path).map_err(|e| anyhow::anyhow!(e))?;
+                let current_content = read_allowed_note(&notes_dir, &meta.path)?;
 
                 let mut new_lines = Vec::new();
                 let mut found = false;
@@ -257,8 +322,8 @@ impl Tool for DeleteReminder {
 }
 
 impl DeleteReminder {
-    pub fn new(db_path: PathBuf) -> Self {
-        Self { db_path }
+    pub fn new(db_path: PathBuf, notes_dir: PathBuf) -> Self {
+        Self { db_path, notes_dir }
     }
 }
 
@@ -276,6 +341,7 @@ pub struct ModifyReminderArgs {
 
 pub struct ModifyReminder {
     pub db_path: PathBuf,
+    pub notes_dir: PathBuf,
 }
 
 impl Tool for ModifyReminder {
@@ -324,6 +390,7 @@ impl Tool for ModifyReminder {
 
     async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
         let db_path = self.db_path.clone();
+        let notes_dir = self.notes_dir.clone();
 
         let result = tokio::task::spawn_blocking(move || -> anyhow::Result<String> {
             let db = NotesDatabase::new(&db_path).map_err(|e| anyhow::anyhow!(e))?;
@@ -332,8 +399,7 @@ impl Tool for ModifyReminder {
                 .map_err(|e| anyhow::anyhow!(e))?;
 
             if let Some(meta) = metadata {
-                let current_content =
-                    std::fs::read_to_string(&meta.path).map_err(|e| anyhow::anyhow!(e))?;
+                let current_content = read_allowed_note(&notes_dir, &meta.path)?;
 
                 let mut new_lines = Vec::new();
                 let mut found = false;
@@ -401,7 +467,7 @@ impl Tool for ModifyReminder {
 }
 
 impl ModifyReminder {
-    pub fn new(db_path: PathBuf) -> Self {
-        Self { db_path }
+    pub fn new(db_path: PathBuf, notes_dir: PathBuf) -> Self {
+        Self { db_path, notes_dir }
     }
 }
@@ -1,12 +1,13 @@
 //! Herramientas de gestión de tags para el agente RIG
 
-use crate::ai::tools::ToolError;
+use crate::ai::tools::{ToolError, read_allowed_note};
 use crate::core::database::NotesDatabase;
 use crate::core::frontmatter::{extract_all_tags, update_tags};
 use anyhow::Result;
 use rig::tool::Tool;
 use serde::Deserialize;
 use std::path::PathBuf;
+use tracing::warn;
 
 // ==================== ADD TAG ====================
 
@@ -18,6 +19,7 @@ pub struct AddTagArgs {
 
 pub struct AddTag {
     pub db_path: PathBuf,
+    pub notes_dir: PathBuf,
 }
 
 impl Tool for AddTag {
@@ -50,14 +52,14 @@ impl Tool for AddTag {
 
     async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
         let db_path = self.db_path.clone();
+        let notes_dir = self.notes_dir.clone();
 
         let result = tokio::task::spawn_blocking(move || -> anyhow::Result<String> {
             let db = NotesDatabase::new(&db_path).map_err(|e| anyhow::anyhow!(e))?;
             let metadata = db.get_note(&args.name).map_err(|e| anyhow::anyhow!(e))?;
 
             if let Some(meta) = metadata {
-                let content =
-                    std::fs::read_to_string(&meta.path).map_err(|e| anyhow::anyhow!(e))?;
+                let content = read_allowed_note(&notes_dir, &meta.path)?;
 
                 let mut tags = extract_all_tags(&content);
 
@@ -94,8 +96,8 @@ impl Tool for AddTag {
 }
 
 impl AddTag {
-    pub fn new(db_path: PathBuf) -> Self {
-        Self { db_path }
+    pub fn new(db_path: PathBuf, notes_dir: PathBuf) -> Self {
+        Self { db_path, notes_dir }
     }
 }
 
@@ -109,6 +111,7 @@ pub struct RemoveTagArgs {
 
 pub struct RemoveTag {
     pub db_path: PathBuf,
+    pub notes_dir: PathBuf,
 }
 
 impl Tool for RemoveTag {
@@ -141,14 +144,14 @@ impl Tool for RemoveTag {
 
     async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
         let db_path = self.db_path.clone();
+        let notes_dir = self.notes_dir.clone();
 
         let result = tokio::task::spawn_blocking(move || -> anyhow::Result<String> {
             let db = NotesDatabase::new(&db_path).map_err(|e| anyhow::anyhow!(e))?;
             let metadata = db.get_note(&args.name).map_err(|e| anyhow::anyhow!(e))?;
 
             if let Some(meta) = metadata {
-                let content =
-                    std::fs::read_to_string(&meta.path).map_err(|e| anyhow::anyhow!(e))?;
+                let content = read_allowed_note(&notes_dir, &meta.path)?;
 
                 let mut tags = extract_all_tags(&content);
 
@@ -190,8 +193,8 @@ impl Tool for RemoveTag {
 }
 
 impl RemoveTag {
-    pub fn new(db_path: PathBuf) -> Self {
-        Self { db_path }
+    pub fn new(db_path: PathBuf, notes_dir: PathBuf) -> Self {
+        Self { db_path, notes_dir }
     }
 }
 
@@ -205,6 +208,7 @@ pub struct DuplicateNoteArgs {
 
 pub struct DuplicateNote {
     pub db_path: PathBuf,
+    pub notes_dir: PathBuf,
 }
 
 impl Tool for DuplicateNote {
@@ -237,6 +241,7 @@ impl Tool for DuplicateNote {
 
     async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
         let db_path = self.db_path.clone();
+        let notes_dir = self.notes_dir.clone();
 
         let result = tokio::task::spawn_blocking(move || -> anyhow::Result<String> {
             let db = NotesDatabase::new(&db_path).map_err(|e| anyhow::anyhow!(e))?;
@@ -255,8 +260,7 @@ impl Tool for DuplicateNote {
                     ));
                 }
 
-                let content =
-                    std::fs::read_to_string(&meta.path).map_err(|e| anyhow::anyhow!(e))?;
+                let content = read_allowed_note(&notes_dir, &meta.path)?;
 
                 // Create new file in same folder
                 let note_path = PathBuf::from(&meta.path);
@@ -293,8 +297,8 @@ impl Tool for DuplicateNote {
 }
 
 impl DuplicateNote {
-    pub fn new(db_path: PathBuf) -> Self {
-        Self { db_path }
+    pub fn new(db_path: PathBuf, notes_dir: PathBuf) -> Self {
+        Self { db_path, notes_dir }
     }
 }
 
@@ -308,6 +312,7 @@ pub struct MergeNotesArgs {
 
 pub struct MergeNotes {
     pub db_path: PathBuf,
+    pub notes_dir: PathBuf,
 }
 
 impl Tool for MergeNotes {
@@ -342,6 +347,7 @@ impl Tool for MergeNotes {
 
     async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
         let db_path = self.db_path.clone();
+        let notes_dir = self.notes_dir.clone();
 
         let result = tokio::task::spawn_blocking(move || -> anyhow::Result<String> {
             let db = NotesDatabase::new(&db_path).map_err(|e| anyhow::anyhow!(e))?;
@@ -378,14 +384,14 @@ impl Tool for MergeNotes {
                         notes_path = path_buf.parent().map(|p| p.to_path_buf());
                     }
 
-                    let content = std::fs::read_to_string(&meta.path)
+                    let content = read_allowed_note(&notes_dir, &meta.path)
                         .map_err(|e| anyhow::anyhow!("Failed to read '{}': {}", note_name, e))?;
 
                     merged_content.push_str(&format!("## From: {}\n\n", note_name));
                     merged_content.push_str(&content);
                     merged_content.push_str("\n\n---\n\n");
                 } else {
-                    eprintln!("Warning: Note '{}' not found, skipping", note_name);
+                    warn!("Warning: Note '{}' not found, skipping", note_name);
                 }
             }
 
@@ -420,7 +426,7 @@ impl Tool for MergeNotes {
 }
 
 impl MergeNotes {
-    pub fn new(db_path: PathBuf) -> Self {
-        Self { db_path }
+    pub fn new(db_path: PathBuf, notes_dir: PathBuf) -> Self {
+        Self { db_path, notes_dir }
     }
 }
@@ -0,0 +1,71 @@
+//! Pasos del agente RIG para la interfaz y el historial
+//!
+//! El agente RIG encadena las herramientas dentro de `multi_turn` y solo
+//! devuelve el texto final. `TracedTool` envuelve cada herramienta y avisa de
+//! la llamada (`ReActStep::ToolCall`) y de su resultado
+//! (`ReActStep::Observation`), igual que hace el ejecutor ReAct.
+
+use crate::ai::executors::react::ReActStep;
+use crate::ai::tools::ToolError;
+use rig::tool::Tool;
+use serde_json::Value;
+use std::sync::{Arc, Mutex};
+
+/// Destino de los pasos, compartido por todas las herramientas del agente
+pub type StepSink = Arc<Mutex<dyn FnMut(&ReActStep) + Send>>;
+
+pub struct TracedTool<T: Tool> {
+    inner: T,
+    sink: StepSink,
+}
+
+impl<T: Tool> TracedTool<T> {
+    pub fn new(inner: T, sink: &StepSink) -> Self {
+        Self {
+            inner,
+            sink: sink.clone(),
+        }
+    }
+
+    fn emit(&self, step: ReActStep) {
+        if let Ok(mut sink) = self.sink.lock() {
+            sink(&step);
+        }
+    }
+}
+
+impl<T: Tool> Tool for TracedTool<T> {
+    const NAME: &'static str = T::NAME;
+
+    type Args = Value;
+    type Output = Value;
+    type Error = ToolError;
+
+    async fn definition(&self, prompt: String) -> rig::completion::ToolDefinition {
+        self.inner.definition(prompt).await
+    }
+
+    async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
+        self.emit(ReActStep::ToolCall {
+            tool: T::NAME.to_string(),
+            arguments: args.clone(),
+        });
+
+        let result = match serde_json::from_value::<T::Args>(args) {
+            Ok(args) => match self.inner.call(args).await {
+                Ok(output) => serde_json::to_value(output).map_err(|e| ToolError(e.to_string())),
+                Err(e) => Err(ToolError(e.to_string())),
+            },
+            Err(e) => Err(ToolError(format!("Argumentos: {}", e))),
+        };
+
+        let observation = match &result {
+            Ok(Value::String(text)) => text.clone(),
+            Ok(output) => output.to_string(),
+            Err(e) => format!("Error: {}", e.0),
+        };
+        self.emit(ReActStep::Observation(observation));
+
+        result
+    }
+}
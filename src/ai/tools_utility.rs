@@ -1,6 +1,6 @@
 //! Herramientas de utilidad general para el agente RIG
 
-use crate::ai::tools::ToolError;
+use crate::ai::tools::{ToolError, read_allowed_note};
 use crate::core::database::NotesDatabase;
 use anyhow::Result;
 use chrono::Local;
@@ -19,6 +19,7 @@ pub struct FindAndReplaceArgs {
 
 pub struct FindAndReplace {
     pub db_path: PathBuf,
+    pub notes_dir: PathBuf,
 }
 
 impl Tool for FindAndReplace {
@@ -55,14 +56,14 @@ impl Tool for FindAndReplace {
 
     async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
         let db_path = self.db_path.clone();
+        let notes_dir = self.notes_dir.clone();
 
         let result = tokio::task::spawn_blocking(move || -> anyhow::Result<String> {
             let db = NotesDatabase::new(&db_path).map_err(|e| anyhow::anyhow!(e))?;
             let metadata = db.get_note(&args.name).map_err(|e| anyhow::anyhow!(e))?;
 
             if let Some(meta) = metadata {
-                let content =
-                    std::fs::read_to_string(&meta.path).map_err(|e| anyhow::anyhow!(e))?;
+                let content = read_allowed_note(&notes_dir, &meta.path)?;
 
                 let occurrences = content.matches(&args.find).count();
 
@@ -97,13 +98,16 @@ impl Tool for FindAndReplace {
 }
 
 impl FindAndReplace {
-    pub fn new(db_path: PathBuf) -> Self {
-        Self { db_path }
+    pub fn new(db_path: PathBuf, notes_dir: PathBuf) -> Self {
+        Self { db_path, notes_dir }
     }
 }
 
 // ==================== CREATE DAILY NOTE ====================
 
+/// Contenido de la nota diaria; `{date}` es la fecha del día
+pub(crate) const DAILY_NOTE_TEMPLATE: &str = "---\ntags: [daily]\ndate: {date}\n---\n\n# {date}\n\n## Tasks\n- [ ] \n\n## Notes\n\n## Journal\n\n";
+
 #[derive(Deserialize)]
 pub struct CreateDailyNoteArgs {
     #[serde(default)]
@@ -148,7 +152,11 @@ impl Tool for CreateDailyNote {
             let today = Local::now().format("%Y-%m-%d").to_string();
 
             // Check if daily note already exists
-            if db.get_note(&today).map_err(|e| anyhow::anyhow!(e))?.is_some() {
+            if db
+                .get_note(&today)
+                .map_err(|e| anyhow::anyhow!(e))?
+                .is_some()
+            {
                 return Ok(format!("Daily note for {} already exists", today));
             }
 
@@ -164,20 +172,24 @@ impl Tool for CreateDailyNote {
             let file_path = target_dir.join(format!("{}.md", today));
 
             // Create daily note template
-            let content = format!(
-                "---\ntags: [daily]\ndate: {}\n---\n\n# {}\n\n## Tasks\n- [ ] \n\n## Notes\n\n## Journal\n\n",
-                today, today
-            );
+            let content = DAILY_NOTE_TEMPLATE.replace("{date}", &today);
 
             std::fs::write(&file_path, &content)
                 .map_err(|e| anyhow::anyhow!("Failed to create daily note: {}", e))?;
 
             // Index note
-            db.index_note(&today, file_path.to_str().unwrap(), &content, args.folder.as_deref())
-                .map_err(|e| anyhow::anyhow!(e))?;
+            db.index_note(
+                &today,
+                file_path.to_str().unwrap(),
+                &content,
+                args.folder.as_deref(),
+            )
+            .map_err(|e| anyhow::anyhow!(e))?;
 
             Ok(format!("Daily note '{}' created successfully", today))
-        }).await.map_err(|e| ToolError(e.to_string()))??;
+        })
+        .await
+        .map_err(|e| ToolError(e.to_string()))??;
 
         Ok(result)
     }
@@ -241,6 +253,12 @@ impl GetSystemDateTime {
     }
 }
 
+impl Default for GetSystemDateTime {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 // ==================== GET APP INFO ====================
 
 #[derive(Deserialize)]
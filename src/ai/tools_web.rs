@@ -3,6 +3,7 @@ use anyhow::Result;
 use rig::tool::Tool;
 use scraper::{Html, Selector};
 use serde::Deserialize;
+use tracing::info;
 
 #[derive(Deserialize)]
 pub struct WebSearchArgs {
@@ -36,7 +37,7 @@ impl Tool for WebSearch {
     }
 
     async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        println!("🔧 [WebSearch] Searching for: '{}'", args.query);
+        info!("[WebSearch] Searching for: '{}'", args.query);
 
         let result = tokio::task::spawn_blocking(move || -> anyhow::Result<String> {
             let client = reqwest::blocking::Client::builder()
@@ -102,6 +103,12 @@ impl WebSearch {
     }
 }
 
+impl Default for WebSearch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[derive(Deserialize)]
 pub struct FetchUrlArgs {
     pub url: String,
@@ -134,7 +141,7 @@ impl Tool for FetchUrl {
     }
 
     async fn call(&self, args: Self::Args) -> Result<Self::Output, Self::Error> {
-        println!("🔧 [FetchUrl] Fetching: '{}'", args.url);
+        info!("[FetchUrl] Fetching: '{}'", args.url);
 
         let result = tokio::task::spawn_blocking(move || -> anyhow::Result<String> {
             let client = reqwest::blocking::Client::builder()
@@ -155,11 +162,7 @@ impl Tool for FetchUrl {
 
             let document = Html::parse_document(&html_content);
 
-            // Remove unwanted elements
-            let unwanted_tags = ["script", "style", "noscript", "header", "footer", "nav", "svg", "iframe"];
-
-            // We can't easily remove elements from the DOM in scraper, so we'll just traverse and ignore them
-            // Or we can select the body and traverse.
+            // scraper can't remove elements from the DOM, so we just take the body text
 
             let body_selector = Selector::parse("body").unwrap();
             let body = document.select(&body_selector).next();
@@ -200,3 +203,9 @@ impl FetchUrl {
         Self
     }
 }
+
+impl Default for FetchUrl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,8 +1,11 @@
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
 
-use crate::core::NoteFile;
+use crate::core::{
+    ContextDoc, NoteFile, fit_to_budget, messages_to_summarize, model_supports_vision, note_persona,
+};
 
 /// Rol de un mensaje en el chat
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -48,6 +51,9 @@ pub struct ChatMessage {
     pub content: String,
     pub timestamp: DateTime<Utc>,
     pub context_notes: Vec<String>, // Nombres de notas adjuntas al momento del mensaje
+    /// Imágenes adjuntas (solo mensajes del usuario a modelos con visión)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub images: Vec<PathBuf>,
 }
 
 impl ChatMessage {
@@ -57,10 +63,35 @@ impl ChatMessage {
             content,
             timestamp: Utc::now(),
             context_notes,
+            images: Vec::new(),
         }
     }
 }
 
+/// Origen de un grupo de notas adjuntado de una vez
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContextGroupKind {
+    Folder(String),
+    Tag(String),
+}
+
+impl ContextGroupKind {
+    pub fn label(&self) -> String {
+        match self {
+            ContextGroupKind::Folder(folder) => format!("📁 {}", folder),
+            ContextGroupKind::Tag(tag) => format!("#{}", tag),
+        }
+    }
+}
+
+/// Carpeta o tag adjuntado al contexto, ya expandido a sus notas (las más
+/// recientes primero, que son las que se quedan si no caben todas)
+#[derive(Debug, Clone)]
+pub struct ContextGroup {
+    pub kind: ContextGroupKind,
+    pub notes: Vec<NoteFile>,
+}
+
 /// Sesión de chat con la IA
 #[derive(Debug, Clone)]
 pub struct ChatSession {
@@ -68,10 +99,20 @@ pub struct ChatSession {
     pub name: Option<String>,
     pub messages: Vec<ChatMessage>,
     pub attached_notes: Vec<NoteFile>,
+    pub attached_groups: Vec<ContextGroup>,
     pub model_config: AIModelConfig,
     pub created_at: DateTime<Utc>,
+    /// Resumen de los mensajes antiguos que ya no están en `messages`
+    pub summary: Option<String>,
+    /// Hay un resumen en marcha (no lanzar otro)
+    pub summarizing: bool,
+    /// Imágenes que irán con el próximo mensaje del usuario
+    pub pending_images: Vec<PathBuf>,
 }
 
+/// Mensajes recientes que nunca se resumen
+const KEEP_RECENT_MESSAGES: usize = 4;
+
 impl ChatSession {
     /// Crea una nueva sesión de chat
     pub fn new(config: AIModelConfig) -> Self {
@@ -80,21 +121,40 @@ impl ChatSession {
             name: None,
             messages: Vec::new(),
             attached_notes: Vec::new(),
+            attached_groups: Vec::new(),
             model_config: config,
             created_at: Utc::now(),
+            summary: None,
+            summarizing: false,
+            pending_images: Vec::new(),
         }
     }
 
     /// Agrega un mensaje a la sesión
     pub fn add_message(&mut self, role: MessageRole, content: String) {
-        let note_names: Vec<String> = self
-            .attached_notes
-            .iter()
-            .map(|n| n.name().to_string())
-            .collect();
+        let note_names = self.context_note_names();
 
-        self.messages
-            .push(ChatMessage::new(role, content, note_names));
+        let mut message = ChatMessage::new(role, content, note_names);
+        if role == MessageRole::User {
+            message.images = std::mem::take(&mut self.pending_images);
+        }
+        self.messages.push(message);
+    }
+
+    /// ¿Acepta imágenes el modelo configurado?
+    pub fn supports_vision(&self) -> bool {
+        model_supports_vision(&self.model_config.model)
+    }
+
+    /// Añade una imagen al próximo mensaje (una vez cada una)
+    pub fn attach_image(&mut self, path: PathBuf) {
+        if !self.pending_images.contains(&path) {
+            self.pending_images.push(path);
+        }
+    }
+
+    pub fn detach_image(&mut self, path: &Path) {
+        self.pending_images.retain(|image| image != path);
     }
 
     /// Adjunta una nota al contexto
@@ -121,22 +181,85 @@ impl ChatSession {
         self.attached_notes.retain(|n| n.name() != note_name);
     }
 
+    /// Adjunta una carpeta o un tag (sustituye al mismo grupo si ya estaba)
+    pub fn attach_group(&mut self, group: ContextGroup) {
+        self.attached_groups.retain(|g| g.kind != group.kind);
+        self.attached_groups.push(group);
+    }
+
+    /// Quita una carpeta o un tag del contexto
+    pub fn detach_group(&mut self, kind: &ContextGroupKind) {
+        self.attached_groups.retain(|g| &g.kind != kind);
+    }
+
     /// Limpia todas las notas del contexto
     pub fn clear_context(&mut self) {
         self.attached_notes.clear();
+        self.attached_groups.clear();
+        self.pending_images.clear();
+    }
+
+    /// Nombres de todas las notas del contexto, sueltas o de grupos
+    pub fn context_note_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .attached_notes
+            .iter()
+            .map(|n| n.name().to_string())
+            .collect();
+        for note in self.attached_groups.iter().flat_map(|g| &g.notes) {
+            if !names.iter().any(|name| name == note.name()) {
+                names.push(note.name().to_string());
+            }
+        }
+        names
+    }
+
+    /// Caracteres para las notas de cada grupo: la mitad de la ventana, a
+    /// partes iguales entre los grupos (1 token ≈ 4 caracteres)
+    fn group_budget_chars(&self) -> usize {
+        self.model_config.max_tokens * 4 / 2 / self.attached_groups.len().max(1)
+    }
+
+    /// Lo que entra de un grupo en el contexto: sus notas recortadas al
+    /// presupuesto del grupo
+    pub fn group_documents(&self, group: &ContextGroup) -> Vec<ContextDoc> {
+        let docs = group
+            .notes
+            .iter()
+            .filter_map(|note| Some((note.name().to_string(), note.read().ok()?)))
+            .collect();
+        fit_to_budget(docs, self.group_budget_chars())
+    }
+
+    /// Notas del contexto (nombre, contenido): las sueltas enteras y las de
+    /// los grupos recortadas, sin repetir
+    pub fn context_documents(&self) -> Vec<(String, String)> {
+        let mut documents: Vec<(String, String)> = self
+            .attached_notes
+            .iter()
+            .filter_map(|note| Some((note.name().to_string(), note.read().ok()?)))
+            .collect();
+        for group in &self.attached_groups {
+            for doc in self.group_documents(group) {
+                if !documents.iter().any(|(name, _)| *name == doc.name) {
+                    documents.push((doc.name, doc.text));
+                }
+            }
+        }
+        documents
     }
 
     /// Calcula el total aproximado de tokens en el contexto
     /// Estimación: 1 token ≈ 4 caracteres
     pub fn total_context_tokens(&self) -> usize {
         let notes_chars: usize = self
-            .attached_notes
+            .context_documents()
             .iter()
-            .filter_map(|n| n.read().ok())
-            .map(|content| content.len())
+            .map(|(_, content)| content.len())
             .sum();
 
-        let messages_chars: usize = self.messages.iter().map(|m| m.content.len()).sum();
+        let messages_chars: usize = self.messages.iter().map(|m| m.content.len()).sum::<usize>()
+            + self.summary.as_ref().map_or(0, String::len);
 
         (notes_chars + messages_chars) / 4
     }
@@ -145,13 +268,8 @@ impl ChatSession {
     pub fn build_context(&self) -> Result<String> {
         let mut context = String::new();
 
-        for note in &self.attached_notes {
-            let content = note.read()?;
-            context.push_str(&format!(
-                "# Nota: {}\n\n{}\n\n---\n\n",
-                note.name(),
-                content
-            ));
+        for (name, content) in self.context_documents() {
+            context.push_str(&format!("# Nota: {}\n\n{}\n\n---\n\n", name, content));
         }
 
         Ok(context)
@@ -171,13 +289,88 @@ impl ChatSession {
 
     /// Obtiene el número de notas adjuntas
     pub fn context_count(&self) -> usize {
-        self.attached_notes.len()
+        self.context_note_names().len()
     }
 
     /// Limpia el historial de mensajes
     pub fn clear_history(&mut self) {
         self.messages.clear();
+        self.summary = None;
     }
+
+    /// Cuántos mensajes antiguos hay que resumir: 0 mientras el contexto esté
+    /// por debajo del 80% de la ventana; si no, los necesarios para dejar el
+    /// historial en la mitad de lo que dejan libre las notas
+    pub fn messages_to_summarize(&self) -> usize {
+        let max = self.model_config.max_tokens;
+        if self.total_context_tokens() * 10 <= max * 8 {
+            return 0;
+        }
+        let notes_tokens: usize = self
+            .context_documents()
+            .iter()
+            .map(|(_, content)| content.len() / 4)
+            .sum();
+        let target = max.saturating_sub(notes_tokens) / 2;
+        let tokens: Vec<usize> = self.messages.iter().map(|m| m.content.len() / 4).collect();
+        messages_to_summarize(&tokens, target, KEEP_RECENT_MESSAGES)
+    }
+
+    /// Texto a resumir: el resumen anterior y los `count` mensajes más antiguos
+    pub fn summary_source(&self, count: usize) -> String {
+        let mut source = String::new();
+        if let Some(summary) = &self.summary {
+            source.push_str(&format!("Resumen anterior:\n{}\n\n", summary));
+        }
+        for message in self.messages.iter().take(count) {
+            let role = match message.role {
+                MessageRole::User => "Usuario",
+                MessageRole::Assistant => "Asistente",
+                MessageRole::System => "Sistema",
+            };
+            source.push_str(&format!("{}: {}\n\n", role, message.content));
+        }
+        source
+    }
+
+    /// Sustituye los `count` mensajes más antiguos por `summary`
+    pub fn compact_history(&mut self, count: usize, summary: String) {
+        self.messages.drain(..count.min(self.messages.len()));
+        self.summary = Some(summary);
+    }
+
+    /// Mensaje de sistema con el resumen, para ir delante del historial
+    pub fn summary_message(&self) -> Option<ChatMessage> {
+        self.summary.as_ref().map(|summary| {
+            ChatMessage::new(
+                MessageRole::System,
+                format!("Resumen de la conversación anterior:\n{}", summary),
+                vec![],
+            )
+        })
+    }
+
+    /// Historial tal y como se manda al modelo: el resumen y los mensajes
+    pub fn history_for_model(&self) -> Vec<ChatMessage> {
+        self.summary_message()
+            .into_iter()
+            .chain(self.messages.iter().cloned())
+            .collect()
+    }
+}
+
+/// Instrucciones propias (`ai_system`) de las notas del contexto, de su
+/// frontmatter o de su carpeta: (nombre de la nota, instrucciones)
+pub fn context_personas(
+    documents: &[(String, String)],
+    notes_root: &Path,
+) -> Vec<(String, String)> {
+    documents
+        .iter()
+        .filter_map(|(name, content)| {
+            note_persona(notes_root, name, content).map(|persona| (name.clone(), persona))
+        })
+        .collect()
 }
 
 /// Información de un modelo de OpenRouter
@@ -2,15 +2,28 @@ use anyhow::Result;
 use async_trait::async_trait;
 use serde::Deserialize;
 use serde_json::{Value, json};
+use tracing::{error, info, warn};
 
+use crate::ai::resilience::{self, Priority};
 use crate::ai_chat::{AIModelConfig, AIProvider, ChatMessage, MessageRole};
-use crate::mcp::{MCPToolCall, MCPToolRegistry, MCPToolResult};
+use crate::core::image_data_url;
+use crate::mcp::{MCPToolCall, MCPToolRegistry, validate_tool_arguments};
 
 /// Respuesta de la IA que puede incluir llamadas a funciones
 #[derive(Debug, Clone)]
 pub struct AIResponse {
     pub content: Option<String>,
     pub tool_calls: Vec<MCPToolCall>,
+    /// Llamadas con argumentos que no cumplen el esquema (no se ejecutan)
+    pub invalid_tool_calls: Vec<InvalidToolCall>,
+}
+
+/// Llamada a herramienta rechazada antes de ejecutarla
+#[derive(Debug, Clone)]
+pub struct InvalidToolCall {
+    pub name: String,
+    pub arguments: String,
+    pub errors: Vec<String>,
 }
 
 impl AIResponse {
@@ -18,6 +31,7 @@ impl AIResponse {
         Self {
             content: Some(content),
             tool_calls: Vec::new(),
+            invalid_tool_calls: Vec::new(),
         }
     }
 
@@ -25,6 +39,7 @@ impl AIResponse {
         Self {
             content,
             tool_calls,
+            invalid_tool_calls: Vec::new(),
         }
     }
 }
@@ -147,7 +162,8 @@ impl AIClient for OpenAIClient {
         }
 
         // Agregar historial de mensajes
-        for msg in messages {
+        let image_index = last_image_message(messages);
+        for (index, msg) in messages.iter().enumerate() {
             match msg.role {
                 MessageRole::User => {
                     let user_msg = ChatCompletionRequestUserMessageArgs::default()
@@ -156,7 +172,7 @@ impl AIClient for OpenAIClient {
                     api_messages.push(ChatCompletionRequestMessage::User(user_msg));
                     raw_messages.push(json!({
                         "role": "user",
-                        "content": msg.content.clone(),
+                        "content": user_content(msg, Some(index) == image_index)?,
                     }));
                 }
                 MessageRole::Assistant => {
@@ -301,14 +317,14 @@ impl AIClient for OpenAIClient {
                                 }
                             }
                             Err(e) => {
-                                eprintln!("Error en stream: {}", e);
+                                error!("Error en stream: {}", e);
                                 break;
                             }
                         }
                     }
                 }
                 Err(e) => {
-                    eprintln!("Error creando stream: {}", e);
+                    error!("Error creando stream: {}", e);
                 }
             }
         });
@@ -343,9 +359,6 @@ impl OpenAIClient {
 
         #[derive(Deserialize, Debug)]
         struct ToolCallData {
-            id: String,
-            #[serde(rename = "type")]
-            call_type: String,
             function: FunctionCall,
         }
 
@@ -380,8 +393,8 @@ impl OpenAIClient {
         if let Some(messages_array) = request_body["messages"].as_array() {
             if let Some(first_msg) = messages_array.first() {
                 if let Some(content) = first_msg["content"].as_str() {
-                    println!(
-                        "📋 Mensaje de sistema (primeros 200 chars):\n{}",
+                    info!(
+                        "Mensaje de sistema (primeros 200 chars):\n{}",
                         content.chars().take(200).collect::<String>()
                     );
                 }
@@ -414,8 +427,10 @@ impl OpenAIClient {
             .map(|c| &c.message)
             .ok_or_else(|| anyhow::anyhow!("OpenRouter no devolvió mensaje"))?;
 
-        // Parsear tool calls si existen
+        // Parsear tool calls si existen (las que no cumplen el esquema se
+        // devuelven aparte para que el agente pida corregirlas)
         let mut parsed_tool_calls = Vec::new();
+        let mut invalid_tool_calls = Vec::new();
         if let Some(tool_calls) = &message.tool_calls {
             for tc in tool_calls {
                 // El arguments viene como JSON string, necesitamos parsearlo y agregar el campo "type" con el nombre de la función
@@ -423,6 +438,19 @@ impl OpenAIClient {
                 // Necesitamos construir el objeto completo con "tool" y "args"
                 match serde_json::from_str::<Value>(&tc.function.arguments) {
                     Ok(args) => {
+                        if let Err(errors) = validate_tool_arguments(&tc.function.name, &args) {
+                            warn!(
+                                "Argumentos no válidos para '{}': {:?}",
+                                tc.function.name, errors
+                            );
+                            invalid_tool_calls.push(InvalidToolCall {
+                                name: tc.function.name.clone(),
+                                arguments: tc.function.arguments.clone(),
+                                errors,
+                            });
+                            continue;
+                        }
+
                         // Convertir snake_case a PascalCase para el nombre del tool
                         let tool_name = tc
                             .function
@@ -448,25 +476,32 @@ impl OpenAIClient {
                         // Ahora intentar parsear como MCPToolCall
                         match serde_json::from_value::<MCPToolCall>(tool_call_obj) {
                             Ok(tool_call) => {
-                                println!(
-                                    "✓ Tool call parseado: {} → {:?}",
-                                    tc.function.name, tool_name
-                                );
+                                info!("Tool call parseado: {} → {:?}", tc.function.name, tool_name);
                                 parsed_tool_calls.push(tool_call);
                             }
                             Err(e) => {
-                                eprintln!(
-                                    "⚠️ No se pudo parsear tool call '{}': {} - Args: {}",
+                                error!(
+                                    "No se pudo parsear tool call '{}': {} - Args: {}",
                                     tc.function.name, e, tc.function.arguments
                                 );
+                                invalid_tool_calls.push(InvalidToolCall {
+                                    name: tc.function.name.clone(),
+                                    arguments: tc.function.arguments.clone(),
+                                    errors: vec![e.to_string()],
+                                });
                             }
                         }
                     }
                     Err(e) => {
-                        eprintln!(
-                            "⚠️ Arguments no es JSON válido: {} - {}",
+                        warn!(
+                            "Arguments no es JSON válido: {} - {}",
                             e, tc.function.arguments
                         );
+                        invalid_tool_calls.push(InvalidToolCall {
+                            name: tc.function.name.clone(),
+                            arguments: tc.function.arguments.clone(),
+                            errors: vec![format!("los argumentos no son JSON válido: {}", e)],
+                        });
                     }
                 }
             }
@@ -475,11 +510,16 @@ impl OpenAIClient {
         Ok(AIResponse {
             content: message.content.clone(),
             tool_calls: parsed_tool_calls,
+            invalid_tool_calls,
         })
     }
 }
 
 /// Cliente para Anthropic (Claude) - stub para implementación futura
+#[allow(
+    dead_code,
+    reason = "stub: los campos se usarán al implementar el cliente"
+)]
 pub struct AnthropicClient {
     api_key: String,
     model: String,
@@ -526,6 +566,10 @@ impl AIClient for AnthropicClient {
 }
 
 /// Cliente para Ollama (modelos locales) - stub para implementación futura
+#[allow(
+    dead_code,
+    reason = "stub: los campos se usarán al implementar el cliente"
+)]
 pub struct OllamaClient {
     endpoint: String,
     model: String,
@@ -574,8 +618,173 @@ impl AIClient for OllamaClient {
     }
 }
 
+/// Índice del último mensaje con imágenes: solo ese las lleva. Reenviar las
+/// de mensajes anteriores en cada turno gastaría la ventana del modelo.
+fn last_image_message(messages: &[ChatMessage]) -> Option<usize> {
+    messages.iter().rposition(|msg| !msg.images.is_empty())
+}
+
+/// Contenido de un mensaje del usuario en formato OpenAI: el texto y, con
+/// `with_images`, sus imágenes como `data:` URL. Si no se envían, se nombran.
+fn user_content(msg: &ChatMessage, with_images: bool) -> Result<Value> {
+    if msg.images.is_empty() {
+        return Ok(json!(msg.content));
+    }
+
+    if !with_images {
+        let names: Vec<String> = msg
+            .images
+            .iter()
+            .map(|path| {
+                path.file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+                    .unwrap_or_default()
+            })
+            .collect();
+        return Ok(json!(format!(
+            "{}\n[Imágenes adjuntas: {}]",
+            msg.content,
+            names.join(", ")
+        )));
+    }
+
+    let mut parts = vec![json!({ "type": "text", "text": msg.content })];
+    for path in &msg.images {
+        let url = image_data_url(path)
+            .map_err(|e| anyhow::anyhow!("No se pudo leer la imagen: {}", e))?;
+        parts.push(json!({ "type": "image_url", "image_url": { "url": url } }));
+    }
+    Ok(Value::Array(parts))
+}
+
+/// Envía una conversación con imágenes a un modelo con visión. Los clientes
+/// de RIG solo manejan texto, así que se habla directamente con la API
+/// compatible con OpenAI del proveedor (OpenAI, OpenRouter u Ollama).
+pub async fn send_message_with_images(
+    config: &AIModelConfig,
+    api_key: &str,
+    messages: &[ChatMessage],
+) -> Result<String> {
+    let url = match config.provider {
+        AIProvider::OpenAI if api_key.starts_with("sk-or-") => {
+            "https://openrouter.ai/api/v1/chat/completions"
+        }
+        AIProvider::OpenAI => "https://api.openai.com/v1/chat/completions",
+        AIProvider::Ollama => "http://localhost:11434/v1/chat/completions",
+        _ => {
+            return Err(anyhow::anyhow!(
+                "El proveedor configurado no admite imágenes todavía"
+            ));
+        }
+    };
+
+    let image_index = last_image_message(messages);
+    let mut raw_messages = Vec::with_capacity(messages.len());
+    for (index, msg) in messages.iter().enumerate() {
+        let (role, content) = match msg.role {
+            MessageRole::User => ("user", user_content(msg, Some(index) == image_index)?),
+            MessageRole::Assistant => ("assistant", json!(msg.content)),
+            MessageRole::System => ("system", json!(msg.content)),
+        };
+        raw_messages.push(json!({ "role": role, "content": content }));
+    }
+
+    let request_body = json!({
+        "model": config.model,
+        "messages": raw_messages,
+        "temperature": config.temperature,
+        "max_tokens": config.max_tokens,
+    });
+
+    let mut request = reqwest::Client::new()
+        .post(url)
+        .header("Content-Type", "application/json")
+        .header("HTTP-Referer", "https://github.com/k4ditano/notnative-app")
+        .header("X-Title", "NotNative");
+    if !api_key.is_empty() {
+        request = request.bearer_auth(api_key);
+    }
+    let response = request.json(&request_body).send().await?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "<sin cuerpo>".to_string());
+        return Err(anyhow::anyhow!("El modelo respondió {}: {}", status, body));
+    }
+
+    let completion: Value = response.json().await?;
+    completion["choices"][0]["message"]["content"]
+        .as_str()
+        .map(str::to_string)
+        .ok_or_else(|| anyhow::anyhow!("No se recibió respuesta de la IA"))
+}
+
 /// Factory para crear clientes de IA según la configuración
 pub fn create_client(config: &AIModelConfig, api_key: &str) -> Result<Box<dyn AIClient>> {
+    Ok(Box::new(ResilientClient {
+        inner: build_client(config, api_key)?,
+        provider: resilience::provider_name(config, api_key),
+        priority: Priority::Interactive,
+    }))
+}
+
+/// Cliente para tareas de fondo (resúmenes): espera a que el chat no tenga
+/// peticiones en marcha
+pub fn create_background_client(
+    config: &AIModelConfig,
+    api_key: &str,
+) -> Result<Box<dyn AIClient>> {
+    Ok(Box::new(ResilientClient {
+        inner: build_client(config, api_key)?,
+        provider: resilience::provider_name(config, api_key),
+        priority: Priority::Background,
+    }))
+}
+
+/// Envuelve un cliente con la capa de resiliencia (límites, reintentos y
+/// cortacircuitos del proveedor)
+struct ResilientClient {
+    inner: Box<dyn AIClient>,
+    provider: &'static str,
+    priority: Priority,
+}
+
+#[async_trait]
+impl AIClient for ResilientClient {
+    async fn send_message_with_tools(
+        &self,
+        messages: &[ChatMessage],
+        context: &str,
+        tools: Option<&MCPToolRegistry>,
+    ) -> Result<AIResponse> {
+        resilience::call(self.provider, self.priority, || {
+            self.inner.send_message_with_tools(messages, context, tools)
+        })
+        .await
+    }
+
+    async fn send_message_streaming(
+        &self,
+        messages: &[ChatMessage],
+        context: &str,
+    ) -> Result<tokio::sync::mpsc::UnboundedReceiver<String>> {
+        // Solo se reintenta abrir el stream: aún no ha llegado nada
+        resilience::call(self.provider, self.priority, || {
+            self.inner.send_message_streaming(messages, context)
+        })
+        .await
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        // Los que buscan el cliente concreto (RIG) lo encuentran igual
+        self.inner.as_any()
+    }
+}
+
+fn build_client(config: &AIModelConfig, api_key: &str) -> Result<Box<dyn AIClient>> {
     // Si es OpenAI y usa clave de OpenRouter, usar el cliente de OpenRouter de RIG
     if matches!(config.provider, AIProvider::OpenAI) && api_key.starts_with("sk-or-") {
         use crate::ai::rig_adapter::RigClient;
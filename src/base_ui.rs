@@ -6,11 +6,12 @@ use std::collections::HashMap;
 use std::fmt;
 use std::path::Path;
 use std::rc::Rc;
+use tracing::{debug, error, info};
 use webkit6::prelude::WebViewExt;
 
 use crate::core::{
     Base, BaseQueryEngine, BaseView, CellFormat, CellGrid, CellRef, CellValue, ColumnConfig,
-    Filter, FilterGroup, FilterOperator, GroupedRecord, HtmlRenderer, NoteMetadata,
+    DateFormatter, Filter, FilterGroup, FilterOperator, GroupedRecord, HtmlRenderer, NoteMetadata,
     NoteWithProperties, NotesDatabase, PreviewTheme, PropertyValue, SortConfig, SortDirection,
     SourceType, SpecialCellContent, SpecialRow, ViewType,
 };
@@ -453,8 +454,8 @@ impl BaseTableWidget {
                     let new_value = json.get("value").and_then(|v| v.as_str()).unwrap_or("");
 
                     if !property.is_empty() {
-                        println!(
-                            "📝 Cell edit: note_id={}, group_id={}, {}::{}",
+                        info!(
+                            "Cell edit: note_id={}, group_id={}, {}::{}",
                             note_id, group_id, property, new_value
                         );
                         if let Some(ref callback) = *on_cell_edit_clone.borrow() {
@@ -462,7 +463,7 @@ impl BaseTableWidget {
                         }
                     }
                 } else {
-                    eprintln!("⚠️ Error parsing cellEdit JSON: {}", clean_msg);
+                    error!("Error parsing cellEdit JSON: {}", clean_msg);
                 }
             });
 
@@ -979,7 +980,7 @@ impl BaseTableWidget {
                 self.update_status_bar(notes.len());
             }
             Err(e) => {
-                eprintln!("Error loading grouped records: {}", e);
+                error!("Error loading grouped records: {}", e);
             }
         }
     }
@@ -990,7 +991,7 @@ impl BaseTableWidget {
         let filter_property = match &base.filter_property {
             Some(prop) => prop.clone(),
             None => {
-                eprintln!("PropertyRecords mode requires filter_property");
+                info!("PropertyRecords mode requires filter_property");
                 return;
             }
         };
@@ -1092,7 +1093,7 @@ impl BaseTableWidget {
                 self.update_status_bar(notes.len());
             }
             Err(e) => {
-                eprintln!("Error loading property records: {}", e);
+                error!("Error loading property records: {}", e);
             }
         }
     }
@@ -1112,7 +1113,7 @@ impl BaseTableWidget {
                         graph_view.start_simulation();
                     }
                     Err(e) => {
-                        eprintln!("Error loading grouped records: {}", e);
+                        error!("Error loading grouped records: {}", e);
                     }
                 }
                 content_stack.set_visible_child_name("graph");
@@ -1176,7 +1177,7 @@ impl BaseTableWidget {
                 self.update_columns(&view.columns);
             }
             Err(e) => {
-                eprintln!("Error executing Base query: {}", e);
+                error!("Error executing Base query: {}", e);
             }
         }
     }
@@ -1242,7 +1243,7 @@ impl BaseTableWidget {
             // Serializar y guardar
             if let Ok(yaml) = base.serialize() {
                 if let Err(e) = db.update_base(*id, &yaml, base.active_view as i32) {
-                    eprintln!("Error saving Base config: {}", e);
+                    error!("Error saving Base config: {}", e);
                 }
             }
         }
@@ -1711,7 +1712,7 @@ impl BaseTableWidget {
                                     if let Err(e) =
                                         db.update_base(*id, &yaml, base_ref.active_view as i32)
                                     {
-                                        eprintln!("Error saving Base config: {}", e);
+                                        error!("Error saving Base config: {}", e);
                                     }
                                 }
                             }
@@ -2001,13 +2002,10 @@ impl BaseTableWidget {
         let i18n = I18n::new(Language::from_env());
 
         // Debug: mostrar propiedades disponibles
-        eprintln!(
-            "DEBUG columns modal - available_props: {:?}",
-            available_props
-        );
+        debug!("columns modal - available_props: {:?}", available_props);
         if let Ok(base) = base_ref.try_borrow() {
             if let Some(b) = base.as_ref() {
-                eprintln!("DEBUG columns modal - source_type: {:?}", b.source_type);
+                debug!("columns modal - source_type: {:?}", b.source_type);
             }
         }
 
@@ -2824,11 +2822,11 @@ impl BaseTableWidget {
                                 );
                             }
                             _ => {
-                                eprintln!("Unknown special row action: {}", action);
+                                info!("Unknown special row action: {}", action);
                             }
                         }
                     } else {
-                        eprintln!("⚠️ Error parsing specialRowAction JSON: {}", clean_msg);
+                        error!("Error parsing specialRowAction JSON: {}", clean_msg);
                     }
                 },
             );
@@ -3170,10 +3168,10 @@ impl BaseTableWidget {
                                         &sheet_name,
                                     ) {
                                         Ok(()) => {
-                                            eprintln!("XLSX exported successfully to {:?}", path);
+                                            info!("XLSX exported successfully to {:?}", path);
                                         }
                                         Err(e) => {
-                                            eprintln!("Error exporting XLSX: {}", e);
+                                            error!("Error exporting XLSX: {}", e);
                                         }
                                     }
                                 } else {
@@ -3187,10 +3185,10 @@ impl BaseTableWidget {
                                         &sheet_name,
                                     ) {
                                         Ok(()) => {
-                                            eprintln!("XLSX exported successfully to {:?}", path);
+                                            info!("XLSX exported successfully to {:?}", path);
                                         }
                                         Err(e) => {
-                                            eprintln!("Error exporting XLSX: {}", e);
+                                            error!("Error exporting XLSX: {}", e);
                                         }
                                     }
                                 }
@@ -3198,7 +3196,7 @@ impl BaseTableWidget {
                         }
                     }
                     Err(e) => {
-                        eprintln!("File dialog error: {}", e);
+                        error!("File dialog error: {}", e);
                     }
                 }
             });
@@ -3220,7 +3218,7 @@ impl BaseTableWidget {
             if let (Some(id), Some(db)) = (base_id.borrow().as_ref(), notes_db.borrow().as_ref()) {
                 if let Ok(yaml) = base.serialize() {
                     if let Err(e) = db.update_base(*id, &yaml, base.active_view as i32) {
-                        eprintln!("Error saving Base source_type: {}", e);
+                        error!("Error saving Base source_type: {}", e);
                     }
                 }
             }
@@ -3262,13 +3260,13 @@ impl BaseTableWidget {
                 let chip_widget = chip.clone();
 
                 // DEBUG: Verificar estructura del chip
-                eprintln!("DEBUG: Chip creado, buscando last_child...");
+                debug!("Chip creado, buscando last_child...");
                 if let Some(last) = chip.last_child() {
-                    eprintln!("DEBUG: last_child encontrado, tipo: {:?}", last.type_());
+                    debug!("last_child encontrado, tipo: {:?}", last.type_());
                     if let Some(close_btn) = last.downcast_ref::<gtk::Button>() {
-                        eprintln!("DEBUG: Botón encontrado, conectando handler");
+                        debug!("Botón encontrado, conectando handler");
                         close_btn.connect_clicked(move |_| {
-                            eprintln!("DEBUG: ¡CLICK EN BOTÓN X DETECTADO!");
+                            debug!("¡CLICK EN BOTÓN X DETECTADO!");
                             // Eliminar filtro
                             let mut filters_mut = active_filters.borrow_mut();
                             if i < filters_mut.len() {
@@ -3382,19 +3380,19 @@ impl BaseTableWidget {
                             }
 
                             // DEBUG: Verificar que el closure se ejecuta
-                            eprintln!(
-                                "DEBUG: Eliminando filtro, quedan {} filtros",
+                            debug!(
+                                "Eliminando filtro, quedan {} filtros",
                                 filters_snapshot.len()
                             );
 
                             // Eliminar este chip visualmente - limpiar todo y reconstruir
                             while let Some(child) = filters_container.first_child() {
-                                eprintln!("DEBUG: Removiendo child del container");
+                                debug!("Removiendo child del container");
                                 filters_container.remove(&child);
                             }
 
-                            eprintln!(
-                                "DEBUG: Container limpiado, children restantes: {}",
+                            debug!(
+                                "Container limpiado, children restantes: {}",
                                 if filters_container.first_child().is_some() {
                                     "SI"
                                 } else {
@@ -3409,21 +3407,21 @@ impl BaseTableWidget {
                                     .css_classes(["dim-label"])
                                     .build();
                                 filters_container.append(&placeholder);
-                                eprintln!("DEBUG: Placeholder añadido");
+                                debug!("Placeholder añadido");
                             } else {
                                 // Recrear chips restantes (sin handlers, solo visual por ahora)
                                 for (idx, filter) in filters_snapshot.iter().enumerate() {
                                     let new_chip = create_filter_chip(filter, idx);
                                     filters_container.append(&new_chip);
                                 }
-                                eprintln!("DEBUG: {} chips recreados", filters_snapshot.len());
+                                debug!("{} chips recreados", filters_snapshot.len());
                             }
                         });
                     } else {
-                        eprintln!("DEBUG: ERROR - last_child no es un Button");
+                        error!("ERROR - last_child no es un Button");
                     }
                 } else {
-                    eprintln!("DEBUG: ERROR - chip no tiene last_child");
+                    error!("ERROR - chip no tiene last_child");
                 }
 
                 self.filters_container.append(&chip);
@@ -4903,16 +4901,8 @@ document.addEventListener('keydown', function(event) {{
     fn get_property_value(note: &NoteWithProperties, property: &str) -> String {
         match property {
             "title" => note.metadata.name.clone(),
-            "created" => note
-                .metadata
-                .created_at
-                .format("%Y-%m-%d %H:%M")
-                .to_string(),
-            "modified" => note
-                .metadata
-                .updated_at
-                .format("%Y-%m-%d %H:%M")
-                .to_string(),
+            "created" => DateFormatter::global().format_local(&note.metadata.created_at),
+            "modified" => DateFormatter::global().format_local(&note.metadata.updated_at),
             other => {
                 // Buscar en properties
                 note.properties
@@ -1,39 +0,0 @@
-pub mod base;
-pub mod base_query;
-pub mod base_writer;
-pub mod command;
-pub mod database;
-pub mod editor_mode;
-pub mod embedding_config;
-pub mod formula;
-pub mod frontmatter;
-pub mod html_renderer;
-pub mod inline_property;
-pub mod markdown;
-pub mod note_buffer;
-pub mod note_file;
-pub mod notes_config;
-pub mod property;
-pub mod text_chunker;
-pub mod xlsx_export;
-
-pub use base::{
-    Base, BaseView, CellFormat, ColumnConfig, Filter, FilterGroup, FilterOperator, SortConfig,
-    SortDirection, SourceType, SpecialCellContent, SpecialRow, ViewType,
-};
-pub use base_query::{BaseQueryEngine, NoteWithProperties, PropertyAggregation};
-pub use base_writer::BaseWriter;
-pub use command::{CommandParser, EditorAction, KeyModifiers};
-pub use database::{GroupedRecord, InlinePropertyRow, NoteMetadata, NotesDatabase, SearchResult};
-pub use editor_mode::EditorMode;
-pub use embedding_config::{EmbeddingConfig, IndexStats};
-pub use formula::{CellGrid, CellRange, CellRef, CellValue, FormulaError};
-pub use frontmatter::{extract_all_tags, extract_inline_tags, extract_tags};
-pub use html_renderer::{HtmlRenderer, PreviewColors, PreviewTheme};
-pub use inline_property::{InlineProperty, InlinePropertyParser};
-pub use markdown::{MarkdownParser, StyleType};
-pub use note_buffer::NoteBuffer;
-pub use note_file::{NoteFile, NotesDirectory};
-pub use notes_config::NotesConfig;
-pub use property::{Property, PropertyValue};
-pub use text_chunker::{ChunkConfig, TextChunk, TextChunker};
@@ -1,6 +1,13 @@
 use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
 use std::path::{Path, PathBuf};
-use std::sync::{Arc, Mutex};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::time::Instant;
+use tracing::{debug, error, info};
+
+use crate::core::{
+    DbActor, EventDebouncer, FolderMount, WATCH_DEBOUNCE, WatchAction, WatchEvent, WatchIgnore,
+    apply_watch_actions,
+};
 
 pub struct FileWatcher {
     #[allow(dead_code)]
@@ -35,125 +42,148 @@ impl FileWatcher {
     }
 }
 
+/// Traduce un evento de notify a los del vigilante
+fn watch_events(event: Event) -> Vec<WatchEvent> {
+    use notify::EventKind;
+    use notify::event::{ModifyKind, RenameMode};
+
+    let mut paths = event.paths.into_iter();
+    match event.kind {
+        EventKind::Create(_) | EventKind::Modify(ModifyKind::Data(_)) => {
+            paths.map(WatchEvent::Changed).collect()
+        }
+        EventKind::Remove(_) => paths.map(WatchEvent::Removed).collect(),
+        EventKind::Modify(ModifyKind::Name(RenameMode::Both)) => {
+            match (paths.next(), paths.next()) {
+                (Some(from), Some(to)) => vec![WatchEvent::Renamed(from, to)],
+                _ => Vec::new(),
+            }
+        }
+        EventKind::Modify(ModifyKind::Name(RenameMode::From)) => {
+            paths.map(WatchEvent::RenamedFrom).collect()
+        }
+        EventKind::Modify(ModifyKind::Name(RenameMode::To)) => {
+            paths.map(WatchEvent::RenamedTo).collect()
+        }
+        // Sin saber qué lado es: lo que sigue existiendo es el destino
+        EventKind::Modify(ModifyKind::Name(_)) => paths
+            .map(|path| {
+                if path.exists() {
+                    WatchEvent::RenamedTo(path)
+                } else {
+                    WatchEvent::Removed(path)
+                }
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Ruta dentro del vault de una ruta real (las de las carpetas montadas
+/// llegan con la ruta de la carpeta externa)
+fn vault_path(mounts: &[FolderMount], path: PathBuf) -> PathBuf {
+    mounts
+        .iter()
+        .find_map(|mount| mount.vault_path(&path))
+        .unwrap_or(path)
+}
+
 /// Crea un watcher que monitorea cambios en el directorio de notas
-/// y actualiza la base de datos automáticamente
+/// y actualiza la base de datos automáticamente a través del actor.
+/// Las carpetas montadas se vigilan en su sitio real.
+/// Los eventos se juntan por ráfagas en un hilo aparte y se aplican de una
+/// vez; el hilo termina cuando se suelta el watcher.
 pub fn create_notes_watcher(
     notes_path: PathBuf,
-    notes_db: Arc<Mutex<crate::core::database::NotesDatabase>>,
+    mounts: Vec<FolderMount>,
+    notes_db: DbActor,
+    ignore: WatchIgnore,
     sender: relm4::Sender<crate::app::AppMsg>,
 ) -> Result<FileWatcher, notify::Error> {
-    let notes_root = notes_path.clone();
-
-    let mut watcher = FileWatcher::new(move |event| {
-        use notify::EventKind;
-
-        match event.kind {
-            // Detectar creación y modificación de archivos
-            EventKind::Create(_) | EventKind::Modify(notify::event::ModifyKind::Data(_)) => {
-                for path in &event.paths {
-                    // Solo procesar archivos .md
-                    if path.extension().map_or(false, |e| e == "md") {
-                        // Ignorar archivos en .trash o .history
-                        if path.to_string_lossy().contains("/.trash/")
-                            || path.to_string_lossy().contains("/.history/")
-                        {
-                            continue;
-                        }
-
-                        println!("📁 Detectado cambio en: {:?}", path);
-
-                        if let Ok(content) = std::fs::read_to_string(path) {
-                            // Extraer nombre de la nota
-                            let name = path
-                                .file_stem()
-                                .and_then(|s| s.to_str())
-                                .unwrap_or("untitled");
-
-                            // Detectar carpeta (si no está en la raíz)
-                            let folder = path
-                                .parent()
-                                .and_then(|p| p.strip_prefix(&notes_root).ok())
-                                .filter(|p| !p.as_os_str().is_empty())
-                                .and_then(|p| p.to_str())
-                                .map(|s| s.to_string());
-
-                            // Indexar en la base de datos
-                            if let Ok(db) = notes_db.lock() {
-                                if let Err(e) = db.index_note(
-                                    name,
-                                    path.to_str().unwrap_or(""),
-                                    &content,
-                                    folder.as_deref(),
-                                ) {
-                                    eprintln!("⚠️ Error indexando nota automáticamente: {}", e);
-                                } else {
-                                    println!("✅ Nota indexada: {} (carpeta: {:?})", name, folder);
-
-                                    // Indexar embeddings automáticamente - DESACTIVADO para evitar duplicados con autoguardado
-                                    // La app se encarga de indexar embeddings cuando es necesario (guardado manual, cambio de nota, etc.)
-                                    /*
-                                    let _ = sender.send(crate::app::AppMsg::IndexNoteEmbeddings {
-                                        path: path.to_str().unwrap_or("").to_string(),
-                                        content: content.clone(),
-                                    });
-                                    */
-
-                                    // Si es la nota actual, recargarla para mostrar cambios
-                                    let _ = sender.send(
-                                        crate::app::AppMsg::ReloadCurrentNoteIfMatching {
-                                            path: path.to_str().unwrap_or("").to_string(),
-                                        },
-                                    );
+    let (events_tx, events_rx) = mpsc::channel::<WatchEvent>();
+
+    let mounts: Vec<FolderMount> = mounts.into_iter().filter(|mount| mount.available).collect();
+    let targets: Vec<PathBuf> = mounts.iter().map(|mount| mount.target.clone()).collect();
+    let mut watcher = FileWatcher::new(move |mut event| {
+        event.paths = event
+            .paths
+            .into_iter()
+            .map(|path| vault_path(&mounts, path))
+            .collect();
+        for watch_event in watch_events(event) {
+            let _ = events_tx.send(watch_event);
+        }
+    })?;
+    watcher.watch(&notes_path)?;
+    info!("File watcher activado en: {:?}", notes_path);
+    for target in targets {
+        if let Err(e) = watcher.watch(&target) {
+            error!("No se pudo vigilar la carpeta montada {:?}: {}", target, e);
+        }
+    }
 
-                                    // Si está en una carpeta, expandirla automáticamente
-                                    if let Some(ref folder_name) = folder {
-                                        let _ = sender.send(crate::app::AppMsg::ExpandFolder(
-                                            folder_name.clone(),
-                                        ));
-                                    }
+    let notes_root = notes_path.clone();
+    let spawned = std::thread::Builder::new()
+        .name("notnative-watch".to_string())
+        .spawn(move || {
+            let mut debouncer =
+                EventDebouncer::new(notes_root.clone(), ignore.clone(), WATCH_DEBOUNCE);
+            loop {
+                match events_rx.recv_timeout(WATCH_DEBOUNCE / 3) {
+                    Ok(event) => debouncer.push(event, Instant::now()),
+                    Err(RecvTimeoutError::Timeout) => {}
+                    Err(RecvTimeoutError::Disconnected) => break,
+                }
+                if !debouncer.is_due(Instant::now()) {
+                    continue;
+                }
 
-                                    // Refrescar sidebar
-                                    let _ = sender.send(crate::app::AppMsg::RefreshSidebar);
-                                }
-                            }
-                        }
+                let actions = debouncer.take();
+                info!("Cambios detectados: {} acciones", actions.len());
+                let applied = {
+                    let (root, ignore, actions) =
+                        (notes_root.clone(), ignore.clone(), actions.clone());
+                    notes_db
+                        .call_blocking(move |db| apply_watch_actions(db, &root, &ignore, &actions))
+                };
+                match applied {
+                    Ok(applied) => debug!("{} cambios aplicados al índice", applied),
+                    Err(e) => {
+                        error!("Error aplicando cambios al índice: {}", e);
+                        continue;
                     }
                 }
-            }
 
-            // Detectar eliminación de archivos
-            EventKind::Remove(_) => {
-                for path in &event.paths {
-                    if path.extension().map_or(false, |e| e == "md") {
-                        // Ignorar archivos en .trash o .history
-                        if path.to_string_lossy().contains("/.trash/")
-                            || path.to_string_lossy().contains("/.history/")
-                        {
-                            continue;
-                        }
-
-                        if let Some(name) = path.file_stem().and_then(|s| s.to_str()) {
-                            println!("🗑️ Detectada eliminación: {}", name);
-
-                            if let Ok(db) = notes_db.lock() {
-                                if let Err(e) = db.delete_note(name) {
-                                    eprintln!("⚠️ Error eliminando nota de BD: {}", e);
-                                } else {
-                                    println!("✅ Nota eliminada de BD: {}", name);
-                                    let _ = sender.send(crate::app::AppMsg::RefreshSidebar);
-                                }
-                            }
-                        }
-                    }
+                let mut folders = std::collections::BTreeSet::new();
+                for action in &actions {
+                    let changed = match action {
+                        WatchAction::Index(path) => path,
+                        WatchAction::Rename { to, .. } => to,
+                        _ => continue,
+                    };
+                    // Si es la nota actual, recargarla para mostrar cambios
+                    let _ = sender.send(crate::app::AppMsg::ReloadCurrentNoteIfMatching {
+                        path: changed.to_string_lossy().to_string(),
+                    });
+                    // Si está en una carpeta, expandirla automáticamente
+                    let folder = changed
+                        .parent()
+                        .and_then(|p| p.strip_prefix(&notes_root).ok())
+                        .filter(|p| !p.as_os_str().is_empty())
+                        .map(|p| p.to_string_lossy().to_string());
+                    folders.extend(folder);
                 }
+                for folder in folders {
+                    let _ = sender.send(crate::app::AppMsg::ExpandFolder(folder));
+                }
+                // Un solo refresco del sidebar por ráfaga
+                let _ = sender.send(crate::app::AppMsg::RefreshSidebar);
             }
-
-            _ => {}
-        }
-    })?;
-
-    watcher.watch(&notes_path)?;
-    println!("👁️ File watcher activado en: {:?}", notes_path);
+            debug!("Hilo del file watcher finalizado");
+        });
+    if let Err(e) = spawned {
+        error!("No se pudo iniciar el hilo del file watcher: {}", e);
+    }
 
     Ok(watcher)
 }
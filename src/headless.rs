@@ -0,0 +1,223 @@
+//! Modo headless: la lógica de la app sin widgets GTK
+//!
+//! `HeadlessApp` reúne lo mismo que `MainApp` usa por debajo (buffer, parser de
+//! comandos, base de datos y ejecutor MCP) sobre un directorio de notas, para que
+//! los tests de integración puedan reproducir flujos completos sin servidor gráfico.
+//! Las teclas pasan por [`CommandParser::parse_key`] y las acciones de edición por
+//! [`apply_edit`], igual que en el editor; lo que aquí se resuelve aparte es solo
+//! lo que en la app depende de widgets (modo, guardado).
+
+use anyhow::{Context, Result};
+use std::cell::RefCell;
+use std::path::Path;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+use crate::core::{
+    CommandParser, EditOutcome, EditorAction, EditorMode, KeyModifiers, NoteBuffer, NoteFile,
+    NotesConfig, NotesDatabase, NotesDirectory, SearchResult, apply_edit,
+};
+use crate::i18n::{I18n, Language};
+use crate::mcp::{MCPChange, MCPChangeListener, MCPToolCall, MCPToolExecutor, MCPToolResult};
+use crate::reminders::ReminderDatabase;
+
+pub struct HeadlessApp {
+    notes_dir: NotesDirectory,
+    notes_db: Rc<RefCell<NotesDatabase>>,
+    executor: MCPToolExecutor,
+    /// Cambios notificados por las herramientas MCP desde el último `take_changes`
+    changes: Arc<Mutex<Vec<MCPChange>>>,
+    parser: CommandParser,
+    mode: EditorMode,
+    buffer: NoteBuffer,
+    /// Posición del cursor en caracteres
+    cursor: usize,
+    current_note: Option<NoteFile>,
+}
+
+impl HeadlessApp {
+    /// Abre (o crea) un directorio de notas con su base de datos junto a él,
+    /// igual que la app. La configuración es la de por defecto y no se guarda.
+    pub fn new<P: AsRef<Path>>(root: P) -> Result<Self> {
+        let notes_dir = NotesDirectory::new(root)?;
+        let notes_db = Rc::new(RefCell::new(NotesDatabase::new(&notes_dir.db_path())?));
+        let notes_config = Rc::new(RefCell::new(NotesConfig::new()));
+        let i18n = Rc::new(RefCell::new(I18n::new(Language::Spanish)));
+
+        let changes = Arc::new(Mutex::new(Vec::new()));
+        let mut executor =
+            MCPToolExecutor::new(notes_dir.clone(), notes_db.clone(), notes_config, i18n);
+        let sink = changes.clone();
+        executor.set_change_listener(MCPChangeListener::new(move |change| {
+            sink.lock().unwrap_or_else(|e| e.into_inner()).push(change);
+        }));
+
+        Ok(Self {
+            notes_dir,
+            notes_db,
+            executor,
+            changes,
+            parser: CommandParser::new(),
+            mode: EditorMode::Normal,
+            buffer: NoteBuffer::new(),
+            cursor: 0,
+            current_note: None,
+        })
+    }
+
+    pub fn notes_dir(&self) -> &NotesDirectory {
+        &self.notes_dir
+    }
+
+    pub fn notes_db(&self) -> std::cell::Ref<'_, NotesDatabase> {
+        self.notes_db.borrow()
+    }
+
+    pub fn mode(&self) -> EditorMode {
+        self.mode
+    }
+
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Texto actual del buffer
+    pub fn text(&self) -> String {
+        self.buffer.to_string()
+    }
+
+    pub fn current_note(&self) -> Option<&NoteFile> {
+        self.current_note.as_ref()
+    }
+
+    /// Crea una nota (opcionalmente en una carpeta) y la indexa
+    pub fn create_note(&self, name: &str, content: &str, folder: Option<&str>) -> Result<NoteFile> {
+        let note = match folder {
+            Some(folder) => self
+                .notes_dir
+                .create_note_in_folder(folder, name, content)?,
+            None => self.notes_dir.create_note(name, content)?,
+        };
+        self.index(&note, content)?;
+        Ok(note)
+    }
+
+    /// Carga una nota en el buffer (cursor al inicio, modo Normal)
+    pub fn open_note(&mut self, name: &str) -> Result<()> {
+        let note = self
+            .notes_dir
+            .find_note(name)?
+            .with_context(|| format!("Nota '{}' no encontrada", name))?;
+        let content = note.read()?;
+
+        self.buffer = NoteBuffer::from_text(&content);
+        self.cursor = 0;
+        self.mode = EditorMode::Normal;
+        self.parser.clear_pending();
+        self.current_note = Some(note);
+        Ok(())
+    }
+
+    /// Guarda el buffer en la nota abierta y actualiza el índice, como al guardar en la app
+    pub fn save(&self) -> Result<()> {
+        let note = self
+            .current_note
+            .as_ref()
+            .context("No hay ninguna nota abierta")?;
+        let content = self.buffer.to_string();
+        note.write(&content)?;
+
+        let indexed = self.notes_db.borrow().get_note(note.name())?.is_some();
+        if indexed {
+            self.notes_db.borrow().update_note(note.name(), &content)?;
+        } else {
+            self.index(note, &content)?;
+        }
+        Ok(())
+    }
+
+    /// Borra la nota del disco y del índice
+    pub fn delete_note(&mut self, name: &str) -> Result<()> {
+        let note = self
+            .notes_dir
+            .find_note(name)?
+            .with_context(|| format!("Nota '{}' no encontrada", name))?;
+        std::fs::remove_file(note.path())?;
+        self.notes_db.borrow().delete_note(note.name())?;
+
+        if self
+            .current_note
+            .as_ref()
+            .is_some_and(|current| current.path() == note.path())
+        {
+            self.current_note = None;
+            self.buffer = NoteBuffer::new();
+            self.cursor = 0;
+        }
+        Ok(())
+    }
+
+    pub fn search(&self, query: &str) -> Result<Vec<SearchResult>> {
+        Ok(self.notes_db.borrow().search_notes(query)?)
+    }
+
+    /// Procesa una tecla (nombres de GTK: "i", "Escape", "Return", "BackSpace"...)
+    /// según el modo actual y aplica la acción resultante
+    pub fn press(&mut self, key: &str, modifiers: KeyModifiers) -> Result<EditorAction> {
+        let action = self.parser.parse_key(self.mode, key, modifiers);
+        self.apply(action.clone())?;
+        Ok(action)
+    }
+
+    /// Teclea un texto carácter a carácter (en modo Insert se inserta)
+    pub fn type_text(&mut self, text: &str) -> Result<()> {
+        for ch in text.chars() {
+            let key = match ch {
+                '\n' => "Return".to_string(),
+                ' ' => "space".to_string(),
+                ch => ch.to_string(),
+            };
+            self.press(&key, KeyModifiers::default())?;
+        }
+        Ok(())
+    }
+
+    fn apply(&mut self, action: EditorAction) -> Result<()> {
+        if apply_edit(&mut self.buffer, &mut self.cursor, &action) != EditOutcome::Ignored {
+            return Ok(());
+        }
+        match action {
+            EditorAction::ChangeMode(mode) => self.mode = mode,
+            EditorAction::Save => self.save()?,
+            // Portapapeles, diálogos y sidebar dependen de la UI
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Ejecuta una herramienta MCP como lo haría el asistente o el servidor MCP
+    pub fn execute_tool(&self, tool: MCPToolCall) -> Result<MCPToolResult> {
+        self.executor.execute(tool)
+    }
+
+    /// Cambios notificados por las herramientas desde la última llamada
+    pub fn take_changes(&self) -> Vec<MCPChange> {
+        std::mem::take(&mut *self.changes.lock().unwrap_or_else(|e| e.into_inner()))
+    }
+
+    /// Base de datos de recordatorios sobre la misma base que las notas
+    pub fn reminders(&self) -> Result<ReminderDatabase> {
+        let conn = self.notes_db.borrow().pool().get()?;
+        Ok(ReminderDatabase::new(conn))
+    }
+
+    fn index(&self, note: &NoteFile, content: &str) -> Result<i64> {
+        let path = note.path().to_string_lossy();
+        let folder = self.notes_dir.relative_folder(note.path());
+        let note_id =
+            self.notes_db
+                .borrow()
+                .index_note(note.name(), &path, content, folder.as_deref())?;
+        Ok(note_id)
+    }
+}
@@ -0,0 +1,47 @@
+//! Núcleo de NotNative sin interfaz gráfica
+//!
+//! Recordatorios, herramientas MCP, plugins y agentes de IA sobre el núcleo
+//! [`notnative_core`] (reexportado como [`core`]). El binario GTK (`main.rs`)
+//! construye la interfaz encima; con la feature `headless` se expone además
+//! [`headless::HeadlessApp`] para los tests de integración, que se ejecutan sin
+//! servidor gráfico:
+//!
+//! ```text
+//! cargo test --no-default-features --features sqlite-bundled,headless
+//! ```
+
+#![allow(
+    clippy::collapsible_if,
+    clippy::needless_borrows_for_generic_args,
+    clippy::op_ref,
+    clippy::manual_strip,
+    clippy::needless_option_as_deref,
+    clippy::double_ended_iterator_last,
+    clippy::inherent_to_string,
+    clippy::derivable_impls,
+    clippy::single_char_add_str,
+    clippy::only_used_in_recursion,
+    clippy::while_let_on_iterator,
+    clippy::if_same_then_else,
+    clippy::match_result_ok,
+    clippy::clone_on_copy,
+    clippy::len_zero,
+    clippy::unnecessary_map_or,
+    clippy::unwrap_or_default,
+    clippy::field_reassign_with_default
+)]
+
+pub mod ai;
+pub mod ai_chat;
+pub mod ai_client;
+pub mod i18n;
+pub mod logging;
+pub mod mcp;
+pub mod plugins;
+pub mod reminders;
+
+/// Núcleo sin GTK, en su propio crate (`crates/notnative-core`)
+pub use notnative_core as core;
+
+#[cfg(feature = "headless")]
+pub mod headless;
@@ -0,0 +1,182 @@
+//! Logging estructurado con `tracing`
+//!
+//! Los mensajes van a stderr y a un archivo rotativo diario en
+//! `~/.local/share/notnative/logs/` (se conservan los últimos 7 días).
+//! El nivel se elige en Preferencias → Avanzado y puede forzarse con las
+//! variables de entorno `NOTNATIVE_LOG` o `RUST_LOG` (sintaxis de `EnvFilter`).
+
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{EnvFilter, Registry, fmt, reload};
+
+/// Niveles disponibles, de menos a más detallado
+pub const LEVELS: [&str; 5] = ["error", "warn", "info", "debug", "trace"];
+pub const DEFAULT_LEVEL: &str = "info";
+
+const LOG_FILE_PREFIX: &str = "notnative";
+const LOG_FILE_SUFFIX: &str = "log";
+const MAX_LOG_FILES: usize = 7;
+
+static FILTER_HANDLE: OnceLock<reload::Handle<EnvFilter, Registry>> = OnceLock::new();
+
+/// Directorio donde se guardan los archivos de log
+pub fn log_dir() -> PathBuf {
+    dirs::data_local_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("notnative")
+        .join("logs")
+}
+
+/// Devuelve el nivel si es válido, o el nivel por defecto
+pub fn normalize_level(level: &str) -> &'static str {
+    let level = level.trim().to_lowercase();
+    LEVELS
+        .iter()
+        .find(|l| **l == level)
+        .copied()
+        .unwrap_or(DEFAULT_LEVEL)
+}
+
+/// Filtro: el nivel elegido para la aplicación y solo avisos para las dependencias
+fn filter_directive(level: &str) -> String {
+    format!("warn,notnative_app={}", normalize_level(level))
+}
+
+/// Inicializa el logging. El guard devuelto debe vivir hasta el final de `main`
+/// para que se vacíe el buffer del archivo al salir.
+pub fn init(level: &str) -> Option<WorkerGuard> {
+    let env_filter = std::env::var("NOTNATIVE_LOG")
+        .or_else(|_| std::env::var("RUST_LOG"))
+        .ok()
+        .and_then(|directives| EnvFilter::try_new(directives).ok())
+        .unwrap_or_else(|| EnvFilter::new(filter_directive(level)));
+    let (filter, handle) = reload::Layer::new(env_filter);
+
+    let stderr_layer = fmt::layer().with_writer(std::io::stderr).with_target(false);
+
+    let mut guard = None;
+    let file_layer = RollingFileAppender::builder()
+        .rotation(Rotation::DAILY)
+        .filename_prefix(LOG_FILE_PREFIX)
+        .filename_suffix(LOG_FILE_SUFFIX)
+        .max_log_files(MAX_LOG_FILES)
+        .build(log_dir())
+        .map_err(|e| eprintln!("No se pudo crear el archivo de log: {}", e))
+        .ok()
+        .map(|appender| {
+            let (writer, worker_guard) = tracing_appender::non_blocking(appender);
+            guard = Some(worker_guard);
+            fmt::layer().with_writer(writer).with_ansi(false)
+        });
+
+    if tracing_subscriber::registry()
+        .with(filter)
+        .with(stderr_layer)
+        .with(file_layer)
+        .try_init()
+        .is_ok()
+    {
+        let _ = FILTER_HANDLE.set(handle);
+    }
+
+    guard
+}
+
+/// Cambia el nivel de log en caliente
+pub fn set_level(level: &str) {
+    if let Some(handle) = FILTER_HANDLE.get() {
+        if let Err(e) = handle.modify(|filter| *filter = EnvFilter::new(filter_directive(level))) {
+            tracing::error!("Error cambiando el nivel de log: {}", e);
+        } else {
+            tracing::info!("Nivel de log: {}", normalize_level(level));
+        }
+    }
+}
+
+/// Archivo de log más reciente
+pub fn current_log_file() -> Option<PathBuf> {
+    std::fs::read_dir(log_dir())
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            entry
+                .file_name()
+                .to_string_lossy()
+                .starts_with(LOG_FILE_PREFIX)
+        })
+        .max_by_key(|entry| entry.metadata().and_then(|m| m.modified()).ok())
+        .map(|entry| entry.path())
+}
+
+/// Últimas `max_lines` líneas de un texto
+pub fn tail_lines(text: &str, max_lines: usize) -> &str {
+    if max_lines == 0 {
+        return "";
+    }
+    let trimmed = text.trim_end_matches('\n');
+    match trimmed.rmatch_indices('\n').nth(max_lines - 1) {
+        Some((idx, _)) => &trimmed[idx + 1..],
+        None => trimmed,
+    }
+}
+
+/// Lee las últimas líneas de un archivo de log sin cargarlo entero
+pub fn read_tail(path: &Path, max_lines: usize) -> std::io::Result<String> {
+    // Suficiente para varios cientos de líneas
+    const MAX_BYTES: u64 = 256 * 1024;
+
+    let mut file = std::fs::File::open(path)?;
+    let len = file.metadata()?.len();
+    let start = len.saturating_sub(MAX_BYTES);
+    file.seek(SeekFrom::Start(start))?;
+
+    let mut bytes = Vec::new();
+    file.read_to_end(&mut bytes)?;
+    let text = String::from_utf8_lossy(&bytes);
+
+    // Descartar la primera línea si quedó cortada
+    let text = if start > 0 {
+        text.split_once('\n').map(|(_, rest)| rest).unwrap_or("")
+    } else {
+        text.as_ref()
+    };
+    Ok(tail_lines(text, max_lines).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_level() {
+        assert_eq!(normalize_level("DEBUG"), "debug");
+        assert_eq!(normalize_level(" warn "), "warn");
+        assert_eq!(normalize_level("verbose"), DEFAULT_LEVEL);
+        assert_eq!(filter_directive("trace"), "warn,notnative_app=trace");
+    }
+
+    #[test]
+    fn test_tail_lines() {
+        let text = "a\nb\nc\nd\n";
+        assert_eq!(tail_lines(text, 2), "c\nd");
+        assert_eq!(tail_lines(text, 10), "a\nb\nc\nd");
+        assert_eq!(tail_lines(text, 0), "");
+    }
+
+    #[test]
+    fn test_read_tail() {
+        let path = std::env::temp_dir().join(format!("notnative-log-{}.log", std::process::id()));
+        let content: String = (0..100).map(|i| format!("line {}\n", i)).collect();
+        std::fs::write(&path, content).unwrap();
+
+        let tail = read_tail(&path, 3).unwrap();
+        assert_eq!(tail, "line 97\nline 98\nline 99");
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -22,27 +22,31 @@
     unused_imports
 )]
 
-mod ai;
-mod ai_chat;
-mod ai_client;
+mod accessibility;
+mod adaptive;
 mod app;
 mod base_ui;
-mod core;
 mod file_watcher;
 mod graph_view;
-mod i18n;
-mod mcp;
 mod music_player;
 mod quick_note;
-mod reminders;
+mod screenshot;
+mod sketch_canvas;
 mod system_tray;
+mod toast;
+mod touch;
+mod window_state;
 mod youtube_server;
 mod youtube_transcript;
 
+// Lógica sin GTK (compartida con el modo headless y los tests de integración)
+use notnative_app::{ai, ai_chat, ai_client, core, i18n, logging, mcp, plugins, reminders};
+
 use relm4::{
     RelmApp,
     gtk::{self, gio, glib, prelude::*},
 };
+use tracing::{debug, error, info, warn};
 
 use crate::app::{APP_ID, MainApp, ThemePreference};
 
@@ -70,16 +74,14 @@ fn load_theme_css() -> (String, bool) {
 
     // Cargar el CSS de la aplicación
     // Prioridad: 1) Desarrollo local, 2) Sistema instalado
-    println!("🔍 [main.rs] Intentando cargar CSS...");
+    debug!("[main.rs] Intentando cargar CSS...");
     let app_css = std::fs::read_to_string("assets/style.css")
-        .inspect(|_| println!("✅ [main.rs] CSS cargado desde: assets/style.css"))
+        .inspect(|_| info!("[main.rs] CSS cargado desde: assets/style.css"))
         .ok()
         .or_else(|| {
-            println!("🔍 [main.rs] Intentando ./notnative-app/assets/style.css");
+            debug!("[main.rs] Intentando ./notnative-app/assets/style.css");
             std::fs::read_to_string("./notnative-app/assets/style.css")
-                .inspect(|_| {
-                    println!("✅ [main.rs] CSS cargado desde: ./notnative-app/assets/style.css")
-                })
+                .inspect(|_| info!("[main.rs] CSS cargado desde: ./notnative-app/assets/style.css"))
                 .ok()
         })
         .or_else(|| {
@@ -92,9 +94,9 @@ fn load_theme_css() -> (String, bool) {
                     .map(|p| p.join("assets/style.css"));
 
                 if let Some(ref path) = css_path {
-                    println!("🔍 [main.rs] Intentando ruta exe: {:?}", path);
+                    debug!("[main.rs] Intentando ruta exe: {:?}", path);
                     if let Ok(content) = std::fs::read_to_string(path) {
-                        println!("✅ [main.rs] CSS cargado desde ruta exe: {:?}", path);
+                        info!("[main.rs] CSS cargado desde ruta exe: {:?}", path);
                         return Some(content);
                     }
                 }
@@ -102,22 +104,18 @@ fn load_theme_css() -> (String, bool) {
             None
         })
         .or_else(|| {
-            println!("🔍 [main.rs] Intentando /usr/share/notnative-app/assets/style.css");
+            debug!("[main.rs] Intentando /usr/share/notnative-app/assets/style.css");
             std::fs::read_to_string("/usr/share/notnative-app/assets/style.css")
                 .inspect(|_| {
-                    println!(
-                        "✅ [main.rs] CSS cargado desde: /usr/share/notnative-app/assets/style.css"
-                    )
+                    info!("[main.rs] CSS cargado desde: /usr/share/notnative-app/assets/style.css")
                 })
                 .ok()
         })
         .or_else(|| {
-            println!("🔍 [main.rs] Intentando /usr/share/notnative/assets/style.css (fallback)");
+            debug!("[main.rs] Intentando /usr/share/notnative/assets/style.css (fallback)");
             std::fs::read_to_string("/usr/share/notnative/assets/style.css")
                 .inspect(|_| {
-                    println!(
-                        "✅ [main.rs] CSS cargado desde: /usr/share/notnative/assets/style.css"
-                    )
+                    info!("[main.rs] CSS cargado desde: /usr/share/notnative/assets/style.css")
                 })
                 .ok()
         });
@@ -141,11 +139,37 @@ fn load_theme_css() -> (String, bool) {
 }
 
 fn main() -> anyhow::Result<()> {
+    // Tiempos de cada fase hasta la ventana (--profile-startup)
+    core::startup_profile::start();
+
+    // Exportar plantilla de traducción y salir (para contribuidores de idiomas)
+    let args: Vec<String> = std::env::args().collect();
+    if let Some(pos) = args.iter().position(|a| a == "--export-locale-template") {
+        let output = args
+            .get(pos + 1)
+            .cloned()
+            .unwrap_or_else(|| "template.ftl".to_string());
+        let template = i18n::I18n::new(i18n::Language::English).export_template();
+        std::fs::write(&output, template)?;
+        println!("✅ Plantilla de traducción escrita en: {}", output);
+        return Ok(());
+    }
+
+    // Logging: stderr + archivo rotativo, con el nivel guardado en la configuración
+    let log_level = crate::core::NotesConfig::load(crate::core::NotesConfig::default_path())
+        .map(|config| config.log_level().to_string())
+        .unwrap_or_else(|_| logging::DEFAULT_LEVEL.to_string());
+    let _log_guard = logging::init(&log_level);
+    core::startup_profile::mark("logging");
+
     // Build timestamp to verify fresh compilation
-    println!(
-        "🚀 [BUILD] NotNative compiled at: {} UTC",
-        env!("BUILD_TIMESTAMP")
-    );
+    info!("NotNative compiled at: {} UTC", env!("BUILD_TIMESTAMP"));
+
+    // Archivo .md o enlace notnative:// con el que se lanza (ver core::open_target)
+    let open_target = args
+        .iter()
+        .skip(1)
+        .find_map(|arg| core::OpenTarget::parse(arg));
 
     // Single instance detection
     let lock_file_path = "/tmp/notnative.lock";
@@ -160,14 +184,19 @@ fn main() -> anyhow::Result<()> {
                 let proc_path = format!("/proc/{}", pid);
                 if std::path::Path::new(&proc_path).exists() {
                     // El proceso existe, enviar comando para mostrar la ventana
-                    println!("✅ NotNative ya está corriendo (PID: {})", pid);
-                    println!("📱 Mostrando ventana existente...");
-
-                    // Enviar comando "show" a través del archivo de control
-                    if let Err(e) = std::fs::write(control_file_path, "show") {
-                        eprintln!("⚠️ Error enviando comando show: {}", e);
-                        eprintln!("💡 Puedes mostrar la ventana manualmente con:");
-                        eprintln!("   echo 'show' > {}", control_file_path);
+                    info!("NotNative ya está corriendo (PID: {})", pid);
+                    info!("Mostrando ventana existente...");
+
+                    // Enviar comando "show" (o "open:...") a través del archivo de control
+                    let command = open_target
+                        .as_ref()
+                        .map_or_else(|| "show".to_string(), |target| target.control_command());
+                    if let Err(e) = std::fs::write(control_file_path, &command) {
+                        error!("Error enviando comando '{}': {}", command, e);
+                        warn!(
+                            "Puedes mostrar la ventana manualmente con: echo 'show' > {}",
+                            control_file_path
+                        );
                     }
 
                     std::process::exit(0);
@@ -192,6 +221,7 @@ fn main() -> anyhow::Result<()> {
     // Inicializar GTK primero
     gtk::init().expect("No se pudo inicializar GTK");
     glib::set_application_name("NotNative");
+    core::startup_profile::mark("gtk_init");
 
     // Cargar tema inicial
     let (combined_css, theme_loaded) = load_theme_css();
@@ -206,15 +236,20 @@ fn main() -> anyhow::Result<()> {
         );
 
         if theme_loaded {
-            println!("✓ Tema Omarchy cargado desde ~/.config/omarchy/current/theme/");
+            info!("Tema Omarchy cargado desde ~/.config/omarchy/current/theme/");
         } else {
-            println!("⚠ Tema Omarchy no encontrado, usando estilos por defecto");
+            warn!("Tema Omarchy no encontrado, usando estilos por defecto");
         }
 
         // Debug: mostrar tamaño del CSS cargado
-        println!("  CSS size: {} bytes", combined_css.len());
+        debug!("CSS size: {} bytes", combined_css.len());
     } else {
-        println!("⚠ No se pudo cargar ningún CSS");
+        error!("No se pudo cargar ningún CSS");
+    }
+    core::startup_profile::mark("css");
+
+    if let Some(target) = open_target {
+        crate::app::set_startup_target(target);
     }
 
     // Usar GTK Application en lugar de Adwaita Application
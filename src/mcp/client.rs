@@ -1,6 +1,7 @@
 use anyhow::{Result, anyhow};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use tracing::error;
 
 /// Cliente para conectar a servidores MCP externos
 pub struct MCPClient {
@@ -21,8 +22,6 @@ struct JsonRpcRequest<T> {
 /// Response JSON-RPC genérico
 #[derive(Debug, Deserialize)]
 struct JsonRpcResponse<T> {
-    jsonrpc: String,
-    id: i32,
     #[serde(skip_serializing_if = "Option::is_none")]
     result: Option<T>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -93,7 +92,11 @@ impl MCPClient {
             .await?;
 
         if let Some(error) = response.error {
-            return Err(anyhow!("Error del servidor: {}", error.message));
+            return Err(anyhow!(
+                "Error del servidor ({}): {}",
+                error.code,
+                error.message
+            ));
         }
 
         Ok(response.result.map(|r| r.tools).unwrap_or_default())
@@ -125,7 +128,11 @@ impl MCPClient {
             .await?;
 
         if let Some(error) = response.error {
-            return Err(anyhow!("Error del servidor: {}", error.message));
+            return Err(anyhow!(
+                "Error del servidor ({}): {}",
+                error.code,
+                error.message
+            ));
         }
 
         response
@@ -139,6 +146,12 @@ pub struct MCPClientManager {
     clients: Vec<(String, MCPClient)>,
 }
 
+impl Default for MCPClientManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl MCPClientManager {
     pub fn new() -> Self {
         Self {
@@ -180,7 +193,7 @@ impl MCPClientManager {
                     all_tools.push((name.clone(), tools));
                 }
                 Err(e) => {
-                    eprintln!("⚠️  Error descubriendo tools de '{}': {}", name, e);
+                    error!("Error descubriendo tools de '{}': {}", name, e);
                 }
             }
         }
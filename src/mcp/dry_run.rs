@@ -0,0 +1,79 @@
+//! Modo simulación del agente
+//!
+//! Con el modo activado, las herramientas que escriben (crear, modificar o
+//! borrar notas, carpetas, etiquetas y recordatorios) no tocan nada: la
+//! llamada se apunta en un plan y el modelo recibe un resultado simulado. Al
+//! terminar, el chat enseña el plan como diff y, si el usuario lo aprueba,
+//! `MCPToolExecutor::apply_planned` lo ejecuta de verdad.
+
+use serde_json::json;
+use std::sync::{Arc, Mutex};
+
+use crate::core::DiffLine;
+use crate::mcp::events::MCPChange;
+use crate::mcp::tools::{MCPToolCall, MCPToolResult};
+
+#[derive(Debug, Default)]
+struct DryRunState {
+    enabled: bool,
+    planned: Vec<MCPToolCall>,
+}
+
+/// Estado compartido del modo simulación: lo ven todas las copias del
+/// ejecutor y las herramientas de RIG, que corren en otros hilos
+#[derive(Debug, Clone, Default)]
+pub struct DryRun(Arc<Mutex<DryRunState>>);
+
+impl DryRun {
+    pub fn is_enabled(&self) -> bool {
+        self.0.lock().map(|state| state.enabled).unwrap_or(false)
+    }
+
+    /// Al desactivarlo se descarta lo que quedase planeado
+    pub fn set_enabled(&self, enabled: bool) {
+        if let Ok(mut state) = self.0.lock() {
+            state.enabled = enabled;
+            if !enabled {
+                state.planned.clear();
+            }
+        }
+    }
+
+    pub fn record(&self, tool: MCPToolCall) {
+        if let Ok(mut state) = self.0.lock() {
+            state.planned.push(tool);
+        }
+    }
+
+    /// Saca el plan acumulado (queda vacío para la siguiente petición)
+    pub fn take_planned(&self) -> Vec<MCPToolCall> {
+        self.0
+            .lock()
+            .map(|mut state| std::mem::take(&mut state.planned))
+            .unwrap_or_default()
+    }
+}
+
+/// ¿Modifica algo la herramienta? (las de solo lectura se ejecutan siempre)
+pub fn is_write_tool(tool: &MCPToolCall) -> bool {
+    MCPChange::from_tool_call(tool).is_some()
+}
+
+/// Lo que recibe el modelo en lugar del resultado real
+pub fn simulated_result() -> MCPToolResult {
+    MCPToolResult::success(json!({
+        "dry_run": true,
+        "message": "Modo simulación: el cambio NO se ha aplicado. Queda en el plan \
+                    que el usuario revisará antes de aplicarlo. Continúa como si se \
+                    hubiera hecho y resume al final los cambios planeados."
+    }))
+}
+
+/// Cambio del plan listo para enseñar en el chat
+#[derive(Debug, Clone)]
+pub struct PlannedChange {
+    pub tool: MCPToolCall,
+    pub title: String,
+    /// Diff del contenido de la nota; vacío si el cambio no es de contenido
+    pub diff: Vec<DiffLine>,
+}
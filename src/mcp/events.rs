@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use crate::mcp::tools::MCPToolCall;
+
+/// Cambio realizado por una herramienta MCP que la UI debe reflejar
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MCPChange {
+    NoteCreated(String),
+    NoteModified(String),
+    NoteDeleted(String),
+    NoteRenamed {
+        from: String,
+        to: String,
+    },
+    /// Cambios sin una nota concreta (carpetas, nota diaria, reemplazos en varias notas)
+    NotesChanged,
+    RemindersChanged,
+    /// El agente del chat propone un recordatorio: no se crea hasta que el
+    /// usuario lo confirma en la tarjeta del chat
+    ReminderProposed(crate::reminders::ReminderDraft),
+}
+
+impl MCPChange {
+    /// Cambio que produce una herramienta si se ejecuta con éxito (`None` = solo lectura)
+    pub fn from_tool_call(tool: &MCPToolCall) -> Option<Self> {
+        let change = match tool {
+            MCPToolCall::CreateNote { name, .. } => Self::NoteCreated(name.clone()),
+            MCPToolCall::DuplicateNote { new_name, .. } => Self::NoteCreated(new_name.clone()),
+            MCPToolCall::MergeNotes { output_name, .. } => Self::NoteCreated(output_name.clone()),
+
+            MCPToolCall::UpdateNote { name, .. }
+            | MCPToolCall::AppendToNote { name, .. }
+            | MCPToolCall::AnalyzeAndTagNote { name, .. } => Self::NoteModified(name.clone()),
+            MCPToolCall::AddTag { note, .. }
+            | MCPToolCall::RemoveTag { note, .. }
+            | MCPToolCall::AddMultipleTags { note, .. } => Self::NoteModified(note.clone()),
+
+            MCPToolCall::DeleteNote { name } => Self::NoteDeleted(name.clone()),
+            MCPToolCall::RenameNote { old_name, new_name } => Self::NoteRenamed {
+                from: old_name.clone(),
+                to: new_name.clone(),
+            },
+
+            MCPToolCall::MoveNote { .. }
+            | MCPToolCall::CreateFolder { .. }
+            | MCPToolCall::DeleteFolder { .. }
+            | MCPToolCall::RenameFolder { .. }
+            | MCPToolCall::MoveFolder { .. }
+            | MCPToolCall::CreateProjectFolder { .. }
+            | MCPToolCall::CreateDailyNote { .. }
+            | MCPToolCall::FindAndReplace { .. } => Self::NotesChanged,
+
+            MCPToolCall::CreateReminder { .. }
+            | MCPToolCall::CompleteReminder { .. }
+            | MCPToolCall::SnoozeReminder { .. }
+            | MCPToolCall::UpdateReminder { .. }
+            | MCPToolCall::DeleteReminder { .. } => Self::RemindersChanged,
+
+            _ => return None,
+        };
+        Some(change)
+    }
+
+    /// Si el cambio puede afectar al contenido de la nota `note_name` abierta
+    pub fn affects_note(&self, note_name: &str) -> bool {
+        // Las herramientas aceptan "carpeta/nota" y "nota.md"
+        let matches = |name: &str| {
+            let name = name.strip_suffix(".md").unwrap_or(name);
+            name == note_name || name.rsplit('/').next() == Some(note_name)
+        };
+
+        match self {
+            Self::NoteModified(name) => matches(name),
+            Self::NotesChanged => true,
+            _ => false,
+        }
+    }
+}
+
+/// Receptor de cambios MCP (la app reenvía cada cambio como un `AppMsg`)
+#[derive(Clone)]
+pub struct MCPChangeListener(Arc<dyn Fn(MCPChange) + Send + Sync>);
+
+impl MCPChangeListener {
+    pub fn new(listener: impl Fn(MCPChange) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(listener))
+    }
+
+    pub fn notify(&self, change: MCPChange) {
+        (self.0)(change)
+    }
+}
+
+impl std::fmt::Debug for MCPChangeListener {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("MCPChangeListener")
+    }
+}
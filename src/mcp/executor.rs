@@ -1,14 +1,26 @@
 use anyhow::Result;
 use serde_json::json;
 use std::cell::RefCell;
-use std::path::PathBuf;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
 use std::rc::Rc;
 use std::sync::Arc;
+use tracing::{error, info, warn};
 
-use crate::core::{NotesConfig, NotesDatabase, NotesDirectory};
+use crate::core::{
+    FolderDefaults, FolderTemplates, NoteStore, NotesConfig, NotesDatabase, NotesDirectory,
+    diff_lines, is_ai_excluded, path_ai_excluded, semantic_results,
+};
 use crate::i18n::I18n;
+use crate::mcp::dry_run::{self, DryRun, PlannedChange};
+use crate::mcp::events::{MCPChange, MCPChangeListener};
 use crate::mcp::tools::{MCPToolCall, MCPToolResult};
 
+/// Nombre de nota tal como lo guardan las herramientas, sin `.md`
+fn normalized_note_name(name: &str) -> &str {
+    name.strip_suffix(".md").unwrap_or(name)
+}
+
 /// Ejecutor de herramientas MCP
 #[derive(Debug, Clone)]
 pub struct MCPToolExecutor {
@@ -19,6 +31,8 @@ pub struct MCPToolExecutor {
     note_memory: Rc<
         RefCell<Option<Arc<crate::ai::memory::NoteMemory<rig::providers::openai::EmbeddingModel>>>>,
     >,
+    change_listener: Option<MCPChangeListener>,
+    dry_run: DryRun,
 }
 
 impl MCPToolExecutor {
@@ -34,6 +48,8 @@ impl MCPToolExecutor {
             notes_config,
             i18n,
             note_memory: Rc::new(RefCell::new(None)),
+            change_listener: None,
+            dry_run: DryRun::default(),
         }
     }
 
@@ -48,8 +64,234 @@ impl MCPToolExecutor {
         self.note_memory = memory;
     }
 
-    /// Ejecuta una llamada de herramienta y devuelve el resultado
+    /// Cambia la carpeta de notas y su base de datos (al cambiar de perfil)
+    pub fn set_workspace(
+        &mut self,
+        notes_dir: NotesDirectory,
+        notes_db: Rc<RefCell<NotesDatabase>>,
+    ) {
+        self.notes_dir = notes_dir;
+        self.notes_db = notes_db;
+    }
+
+    /// Recibe los cambios que hacen las herramientas (notas, carpetas, recordatorios)
+    pub fn set_change_listener(&mut self, listener: MCPChangeListener) {
+        self.change_listener = Some(listener);
+    }
+
+    /// Receptor de cambios configurado (las herramientas del agente lo usan
+    /// para proponer recordatorios)
+    pub fn change_listener(&self) -> Option<MCPChangeListener> {
+        self.change_listener.clone()
+    }
+
+    /// Modo simulación (compartido con las copias de este ejecutor)
+    pub fn dry_run(&self) -> DryRun {
+        self.dry_run.clone()
+    }
+
+    /// Ejecuta una llamada de herramienta y devuelve el resultado. En modo
+    /// simulación las que escriben solo se apuntan en el plan.
     pub fn execute(&self, tool: MCPToolCall) -> Result<MCPToolResult> {
+        if self.dry_run.is_enabled() && dry_run::is_write_tool(&tool) {
+            info!("Simulación: {:?}", tool);
+            self.dry_run.record(tool);
+            return Ok(dry_run::simulated_result());
+        }
+        self.execute_now(tool)
+    }
+
+    /// Ejecuta el plan que el usuario ha aprobado, aunque siga en modo simulación
+    pub fn apply_planned(&self, planned: Vec<MCPToolCall>) -> Vec<MCPToolResult> {
+        planned
+            .into_iter()
+            .map(|tool| {
+                self.execute_now(tool)
+                    .unwrap_or_else(|e| MCPToolResult::error(e.to_string()))
+            })
+            .collect()
+    }
+
+    /// Cómo quedaría lo que hace `tool`, para enseñarlo antes de aplicarlo
+    pub fn preview(&self, tool: &MCPToolCall) -> PlannedChange {
+        let i18n = self.i18n.borrow();
+        let current = |name: &str| {
+            self.notes_dir
+                .find_note(name)
+                .ok()
+                .flatten()
+                .and_then(|note| note.read().ok())
+                .unwrap_or_default()
+        };
+
+        let (title, diff) = match tool {
+            MCPToolCall::CreateNote { name, content, .. } => (
+                i18n.t_args("dry_run_create_note", &[("name", name)]),
+                diff_lines("", content),
+            ),
+            MCPToolCall::UpdateNote { name, content } => (
+                i18n.t_args("dry_run_update_note", &[("name", name)]),
+                diff_lines(&current(name), content),
+            ),
+            MCPToolCall::AppendToNote { name, content } => {
+                let old = current(name);
+                let new = if old.is_empty() {
+                    content.clone()
+                } else {
+                    format!("{}\n\n{}", old, content)
+                };
+                (
+                    i18n.t_args("dry_run_append_note", &[("name", name)]),
+                    diff_lines(&old, &new),
+                )
+            }
+            MCPToolCall::DeleteNote { name } => (
+                i18n.t_args("dry_run_delete_note", &[("name", name)]),
+                diff_lines(&current(name), ""),
+            ),
+            _ => {
+                // Nombre de la herramienta y sus argumentos tal cual
+                let value = serde_json::to_value(tool).unwrap_or_default();
+                let name = value["tool"].as_str().unwrap_or_default().to_string();
+                let title = match value.get("args") {
+                    Some(args) => format!("{} {}", name, args),
+                    None => name,
+                };
+                (title, Vec::new())
+            }
+        };
+
+        PlannedChange {
+            tool: tool.clone(),
+            title,
+            diff,
+        }
+    }
+
+    fn execute_now(&self, tool: MCPToolCall) -> Result<MCPToolResult> {
+        // Las notas con `ai: false` (o en una carpeta excluida) no se leen
+        let read = Self::notes_read_by(&tool);
+        let excluded = self.ai_excluded_among(read.iter().copied());
+        if let Some(name) = read
+            .into_iter()
+            .find(|name| excluded.contains(normalized_note_name(name)))
+        {
+            info!("Herramienta bloqueada, nota excluida de la IA: {}", name);
+            return Ok(MCPToolResult::error(
+                self.i18n
+                    .borrow()
+                    .t_args("mcp_note_ai_excluded", &[("name", name)]),
+            ));
+        }
+
+        let change = MCPChange::from_tool_call(&tool);
+        let mut result = self.execute_tool(tool)?;
+        self.hide_excluded_notes(&mut result);
+
+        // Avisar a la UI en cuanto una herramienta modifica algo
+        if let (true, Some(change), Some(listener)) =
+            (result.success, change, &self.change_listener)
+        {
+            listener.notify(change);
+        }
+
+        Ok(result)
+    }
+
+    /// Notas cuyo contenido lee la herramienta para devolvérselo al agente
+    fn notes_read_by(tool: &MCPToolCall) -> Vec<&str> {
+        match tool {
+            MCPToolCall::ReadNote { name }
+            | MCPToolCall::AnalyzeNoteStructure { name }
+            | MCPToolCall::GetWordCount { name }
+            | MCPToolCall::SuggestRelatedNotes { name, .. }
+            | MCPToolCall::GenerateTableOfContents { name, .. }
+            | MCPToolCall::ExtractCodeBlocks { name, .. }
+            | MCPToolCall::AnalyzeAndTagNote { name, .. } => vec![name.as_str()],
+            MCPToolCall::FindSimilarNotes { note_path, .. } => vec![note_path.as_str()],
+            MCPToolCall::MergeNotes { note_names, .. } => {
+                note_names.iter().map(String::as_str).collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// De los nombres `names`, los de notas que quedan fuera de la IA
+    /// (`ai: false` en ellas o en su carpeta). Recorre la bóveda una sola vez
+    /// y solo lee las notas que aparecen en `names`.
+    fn ai_excluded_among<'a>(&self, names: impl IntoIterator<Item = &'a str>) -> HashSet<String> {
+        let wanted: HashSet<&str> = names.into_iter().map(normalized_note_name).collect();
+        let mut excluded = HashSet::new();
+        if wanted.is_empty() {
+            return excluded;
+        }
+
+        for note in self.notes_dir.list_notes().unwrap_or_default() {
+            // Las herramientas aceptan la nota con carpeta o solo por su nombre
+            let base = note.name().rsplit('/').next().unwrap_or(note.name());
+            let matches: Vec<&str> = [note.name(), base]
+                .into_iter()
+                .filter(|name| wanted.contains(name))
+                .collect();
+            if !matches.is_empty()
+                && is_ai_excluded(
+                    self.notes_dir.root(),
+                    note.name(),
+                    &note.read().unwrap_or_default(),
+                )
+            {
+                excluded.extend(matches.into_iter().map(str::to_string));
+            }
+        }
+        excluded
+    }
+
+    /// Quita de las listas del resultado (`notes`, `results`) las notas
+    /// excluidas de la IA, sean nombres u objetos con `name`/`note_name`
+    fn hide_excluded_notes(&self, result: &mut MCPToolResult) {
+        let Some(serde_json::Value::Object(data)) = result.data.as_mut() else {
+            return;
+        };
+        let item_name = |item: &serde_json::Value| -> Option<String> {
+            item.as_str()
+                .or_else(|| {
+                    item.get("note_name")
+                        .or_else(|| item.get("name"))
+                        .and_then(|name| name.as_str())
+                })
+                .map(str::to_string)
+        };
+
+        let names: Vec<String> = ["notes", "results"]
+            .into_iter()
+            .filter_map(|key| data.get(key).and_then(|items| items.as_array()))
+            .flatten()
+            .filter_map(item_name)
+            .collect();
+        let excluded = self.ai_excluded_among(names.iter().map(String::as_str));
+        if excluded.is_empty() {
+            return;
+        }
+
+        let mut hidden = 0;
+        for key in ["notes", "results"] {
+            let Some(serde_json::Value::Array(items)) = data.get_mut(key) else {
+                continue;
+            };
+            let before = items.len();
+            items.retain(|item| {
+                !item_name(item).is_some_and(|name| excluded.contains(normalized_note_name(&name)))
+            });
+            hidden += before - items.len();
+        }
+        for key in ["count", "total"] {
+            if let Some(count) = data.get(key).and_then(|count| count.as_u64()) {
+                data.insert(key.to_string(), json!(count.saturating_sub(hidden as u64)));
+            }
+        }
+    }
+
+    fn execute_tool(&self, tool: MCPToolCall) -> Result<MCPToolResult> {
         match tool {
             // === Gestión de notas ===
             MCPToolCall::CreateNote {
@@ -106,6 +348,12 @@ impl MCPToolExecutor {
             MCPToolCall::MoveFolder { name, new_parent } => {
                 self.move_folder(&name, new_parent.as_deref())
             }
+            MCPToolCall::ListFolderTemplates { .. } => self.list_folder_templates(),
+            MCPToolCall::CreateProjectFolder {
+                name,
+                template,
+                parent,
+            } => self.create_project_folder(&name, template.as_deref(), parent.as_deref()),
             MCPToolCall::ListFolders { .. } => self.list_folders(),
             MCPToolCall::FindEmptyItems { item_type } => {
                 self.find_empty_items(item_type.as_deref())
@@ -121,8 +369,8 @@ impl MCPToolExecutor {
             }
 
             // === Automatización ===
-            MCPToolCall::CreateDailyNote { template } => {
-                self.create_daily_note(template.as_deref())
+            MCPToolCall::CreateDailyNote { template, folder } => {
+                self.create_daily_note(template.as_deref(), folder.as_deref())
             }
             MCPToolCall::FindAndReplace {
                 find,
@@ -150,6 +398,13 @@ impl MCPToolExecutor {
             MCPToolCall::IndexNote { note_path } => self.index_note(note_path),
             MCPToolCall::ReindexAllNotes => self.reindex_all_notes(),
 
+            // === Chat ===
+            MCPToolCall::ExportChatSessions {
+                session_id,
+                format,
+                output_path,
+            } => self.export_chat_sessions(session_id, format.as_deref(), output_path.as_deref()),
+
             // === Recordatorios ===
             MCPToolCall::CreateReminder {
                 title,
@@ -177,6 +432,22 @@ impl MCPToolExecutor {
 
             MCPToolCall::SnoozeReminder { id, minutes } => self.snooze_reminder(id, minutes as i64),
 
+            MCPToolCall::UpdateReminder {
+                id,
+                title,
+                description,
+                due_date,
+                priority,
+                repeat,
+            } => self.update_reminder(
+                id,
+                title.as_deref(),
+                description.as_deref(),
+                due_date.as_deref(),
+                priority.as_deref(),
+                repeat.as_deref(),
+            ),
+
             MCPToolCall::DeleteReminder { id } => self.delete_reminder(id),
 
             // === Bases (Vistas de Base de Datos sobre Notas) ===
@@ -282,17 +553,31 @@ impl MCPToolExecutor {
             self.notes_dir.root().join(format!("{}.md", base_name))
         };
 
-        // Escribir el contenido directamente
-        std::fs::write(&file_path, content)?;
-
-        // Indexar en BD (el nombre debe ser solo el base_name, sin carpeta)
-        if let Err(e) = self.notes_db.borrow().index_note(
-            base_name,
-            file_path.to_str().unwrap_or(""),
-            content,
-            final_folder,
-        ) {
-            eprintln!("Error indexando nota: {}", e);
+        if file_path.exists() {
+            // Sobrescribir la existente y reindexarla
+            std::fs::write(&file_path, content)?;
+            if let Err(e) = self.notes_db.borrow().index_note(
+                base_name,
+                file_path.to_str().unwrap_or(""),
+                content,
+                final_folder,
+            ) {
+                error!("Error indexando nota: {}", e);
+            }
+        } else {
+            // Nota nueva con los valores por defecto de su carpeta
+            // (`.notnative.toml`): plantilla si viene vacía, tags y frontmatter
+            let content =
+                FolderDefaults::nearest(self.notes_dir.root(), final_folder.unwrap_or(""))
+                    .unwrap_or_default()
+                    .apply(base_name, content, chrono::Local::now().date_naive())?;
+            // Si no se puede indexar no se deja el archivo (el nombre en la BD
+            // es solo el base_name, sin carpeta)
+            NoteStore::new(&self.notes_dir, &self.notes_db.borrow()).create(
+                final_folder,
+                base_name,
+                &content,
+            )?;
         }
 
         Ok(MCPToolResult::success(json!({
@@ -381,7 +666,7 @@ impl MCPToolExecutor {
                             content,
                             None,
                         ) {
-                            eprintln!("Error reindexando nota: {}", e);
+                            error!("Error reindexando nota: {}", e);
                         }
 
                         Ok(MCPToolResult::success(json!({
@@ -430,7 +715,7 @@ impl MCPToolExecutor {
                                     &new_content,
                                     None,
                                 ) {
-                                    eprintln!("Error reindexando nota: {}", e);
+                                    error!("Error reindexando nota: {}", e);
                                 }
 
                                 Ok(MCPToolResult::success(json!({
@@ -465,19 +750,13 @@ impl MCPToolExecutor {
 
     fn delete_note(&self, name: &str) -> Result<MCPToolResult> {
         match self.notes_dir.find_note(name) {
-            Ok(Some(note)) => {
-                match std::fs::remove_file(note.path()) {
-                    Ok(_) => {
-                        // Eliminar de BD
-                        if let Err(e) = self.notes_db.borrow().delete_note(name) {
-                            eprintln!("Error eliminando nota de BD: {}", e);
-                        }
-
-                        Ok(MCPToolResult::success(json!({
-                            "note_name": name,
-                            "message": self.i18n.borrow().t("mcp_note_deleted").replace("{}", name)
-                        })))
-                    }
+            Ok(Some(_)) => {
+                // A la papelera y fuera del índice, o nada si falla alguno
+                match NoteStore::new(&self.notes_dir, &self.notes_db.borrow()).trash(name) {
+                    Ok(_) => Ok(MCPToolResult::success(json!({
+                        "note_name": name,
+                        "message": self.i18n.borrow().t("mcp_note_deleted").replace("{}", name)
+                    }))),
                     Err(e) => Ok(MCPToolResult::error(format!(
                         "Error eliminando nota '{}': {}",
                         name, e
@@ -513,7 +792,7 @@ impl MCPToolExecutor {
         Ok(MCPToolResult::success(json!({
             "notes": note_names,
             "count": note_names.len(),
-            "message": self.i18n.borrow().t("mcp_notes_found").replace("{}", &note_names.len().to_string())
+            "message": self.i18n.borrow().t_args("mcp_notes_found", &[("count", &note_names.len().to_string())])
         })))
     }
 
@@ -581,7 +860,7 @@ impl MCPToolExecutor {
                     }
                 }
                 Err(e) => {
-                    eprintln!("Error en búsqueda FTS: {}", e);
+                    error!("Error en búsqueda FTS: {}", e);
                 }
             }
         }
@@ -601,9 +880,10 @@ impl MCPToolExecutor {
             "results": note_names,
             "count": note_names.len(),
             "query": query,
-            "message": self.i18n.borrow().t("mcp_search_results")
-                .replace("{}", &note_names.len().to_string())
-                .replace("{}", query)
+            "message": self.i18n.borrow().t_args(
+                "mcp_search_results",
+                &[("count", &note_names.len().to_string()), ("query", query)],
+            )
         })))
     }
 
@@ -618,7 +898,10 @@ impl MCPToolExecutor {
                     "notes": note_names,
                     "count": results.len(),
                     "tag": tag,
-                    "message": self.i18n.borrow().t("mcp_notes_with_tag").replace("{}", &results.len().to_string()).replace("{}", tag)
+                    "message": self.i18n.borrow().t_args(
+                        "mcp_notes_with_tag",
+                        &[("count", &results.len().to_string()), ("tag", tag)],
+                    )
                 })))
             }
             Err(e) => Ok(MCPToolResult::error(format!(
@@ -636,7 +919,7 @@ impl MCPToolExecutor {
                 Ok(MCPToolResult::success(json!({
                     "tags": tag_names,
                     "count": tag_names.len(),
-                    "message": self.i18n.borrow().t("mcp_tags_found").replace("{}", &tag_names.len().to_string())
+                    "message": self.i18n.borrow().t_args("mcp_tags_found", &[("count", &tag_names.len().to_string())])
                 })))
             }
             Err(e) => Ok(MCPToolResult::error(format!(
@@ -666,6 +949,80 @@ impl MCPToolExecutor {
         }
     }
 
+    fn list_folder_templates(&self) -> Result<MCPToolResult> {
+        let templates = FolderTemplates::load(&FolderTemplates::default_path())?;
+        let list: Vec<_> = templates
+            .templates
+            .iter()
+            .map(|template| {
+                json!({
+                    "name": template.name,
+                    "description": template.description,
+                    "entries": template.entries.iter().map(|entry| &entry.path).collect::<Vec<_>>(),
+                })
+            })
+            .collect();
+
+        Ok(MCPToolResult::success(json!({
+            "templates": list,
+            "count": list.len(),
+        })))
+    }
+
+    fn create_project_folder(
+        &self,
+        name: &str,
+        template: Option<&str>,
+        parent: Option<&str>,
+    ) -> Result<MCPToolResult> {
+        let templates = FolderTemplates::load(&FolderTemplates::default_path())?;
+        let folder = match parent {
+            Some(parent) => format!("{}/{}", parent.trim_end_matches('/'), name),
+            None => name.to_string(),
+        };
+
+        let notes = match templates.create_project(
+            template,
+            self.notes_dir.root(),
+            &folder,
+            chrono::Local::now().date_naive(),
+        ) {
+            Ok(notes) => notes,
+            Err(e) => {
+                return Ok(MCPToolResult::error(format!(
+                    "Error creando el proyecto '{}': {}",
+                    folder, e
+                )));
+            }
+        };
+
+        // Indexar las notas creadas
+        let mut created = Vec::new();
+        for path in &notes {
+            let Ok(relative) = path.strip_prefix(self.notes_dir.root()) else {
+                continue;
+            };
+            let note_name = relative.with_extension("").to_string_lossy().to_string();
+            let note_folder = self.notes_dir.relative_folder(path);
+            let content = std::fs::read_to_string(path).unwrap_or_default();
+            if let Err(e) = self.notes_db.borrow().index_note(
+                &note_name,
+                path.to_str().unwrap_or(""),
+                &content,
+                note_folder.as_deref(),
+            ) {
+                error!("Error indexando '{}': {}", note_name, e);
+            }
+            created.push(note_name);
+        }
+
+        Ok(MCPToolResult::success(json!({
+            "folder": folder,
+            "notes": created,
+            "message": self.i18n.borrow().t_args("mcp_project_created", &[("folder", &folder)])
+        })))
+    }
+
     fn list_folders(&self) -> Result<MCPToolResult> {
         let base_path = self.notes_dir.root();
         let mut folders = Vec::new();
@@ -687,7 +1044,7 @@ impl MCPToolExecutor {
         Ok(MCPToolResult::success(json!({
             "folders": folders,
             "count": folders.len(),
-            "message": self.i18n.borrow().t("mcp_folders_found").replace("{}", &folders.len().to_string())
+            "message": self.i18n.borrow().t_args("mcp_folders_found", &[("count", &folders.len().to_string())])
         })))
     }
 
@@ -856,29 +1213,11 @@ impl MCPToolExecutor {
         // Generar nombre único si ya existe
         let unique_new_name = self.generate_unique_filename(parent_dir, new_name);
 
-        let new_path = parent_dir.join(&unique_new_name);
-
-        std::fs::rename(&old_path, &new_path)?;
-
-        // Actualizar en la base de datos
-        let content = std::fs::read_to_string(&new_path)?;
-        let folder = old_path
-            .parent()
-            .and_then(|p| p.strip_prefix(self.notes_dir.root()).ok())
-            .filter(|p| !p.as_os_str().is_empty())
-            .and_then(|p| p.to_str());
-
         // Extraer nombre sin extensión para la BD
         let db_name = unique_new_name.trim_end_matches(".md");
 
-        if let Err(e) = self.notes_db.borrow().index_note(
-            db_name,
-            new_path.to_str().unwrap_or(""),
-            &content,
-            folder,
-        ) {
-            eprintln!("⚠️ Error actualizando BD después de renombrar nota: {}", e);
-        }
+        // Archivo y fila de la BD (con sus embeddings) se renombran juntos
+        NoteStore::new(&self.notes_dir, &self.notes_db.borrow()).rename(old_name, db_name)?;
 
         let result_message = if unique_new_name != new_name {
             format!(
@@ -963,7 +1302,7 @@ impl MCPToolExecutor {
             })
             .collect();
 
-        results.sort_by(|a, b| b.1.cmp(&a.1));
+        results.sort_by_key(|entry| std::cmp::Reverse(entry.1));
         results.truncate(limit);
 
         let results: Vec<String> = results.into_iter().map(|(name, _)| name).collect();
@@ -989,7 +1328,7 @@ impl MCPToolExecutor {
             })
             .collect();
 
-        notes.sort_by(|a, b| b.1.cmp(&a.1));
+        notes.sort_by_key(|entry| std::cmp::Reverse(entry.1));
         notes.truncate(limit);
 
         let results: Vec<String> = notes.into_iter().map(|(name, _)| name).collect();
@@ -1089,7 +1428,7 @@ impl MCPToolExecutor {
             })
             .collect();
 
-        scores.sort_by(|a, b| b.1.cmp(&a.1));
+        scores.sort_by_key(|entry| std::cmp::Reverse(entry.1));
         scores.truncate(limit);
 
         let related: Vec<String> = scores
@@ -1196,30 +1535,17 @@ impl MCPToolExecutor {
     }
 
     fn move_note(&self, name: &str, folder: &str) -> Result<MCPToolResult> {
-        let note = self
-            .notes_dir
-            .find_note(name)?
-            .ok_or_else(|| anyhow::anyhow!("Nota no encontrada"))?;
-        let old_path = note.path();
-
-        let folder_path = self.notes_dir.root().join(folder);
-        std::fs::create_dir_all(&folder_path)?;
-
-        let new_path = folder_path.join(format!("{}.md", name.trim_end_matches(".md")));
-        std::fs::rename(&old_path, &new_path)?;
-
-        // Actualizar en la base de datos
-        let content = std::fs::read_to_string(&new_path)?;
-        let new_name = format!("{}/{}", folder, name.trim_end_matches(".md"));
-
-        if let Err(e) = self.notes_db.borrow().index_note(
-            &new_name,
-            new_path.to_str().unwrap_or(""),
-            &content,
-            Some(folder),
-        ) {
-            eprintln!("⚠️ Error actualizando BD después de mover nota: {}", e);
-        }
+        // Archivo y fila de la BD se mueven juntos (sin duplicar la nota)
+        let new_path = match NoteStore::new(&self.notes_dir, &self.notes_db.borrow())
+            .move_to_folder(name, Some(folder))?
+        {
+            Some((_, new_path)) => new_path,
+            None => self
+                .notes_dir
+                .root()
+                .join(folder)
+                .join(format!("{}.md", name.trim_end_matches(".md"))),
+        };
 
         Ok(MCPToolResult::success(json!({
             "message": format!("✓ Nota '{}' movida a carpeta '{}'", name, folder),
@@ -1229,7 +1555,11 @@ impl MCPToolExecutor {
         })))
     }
 
-    fn create_daily_note(&self, template: Option<&str>) -> Result<MCPToolResult> {
+    fn create_daily_note(
+        &self,
+        template: Option<&str>,
+        folder: Option<&str>,
+    ) -> Result<MCPToolResult> {
         use chrono::Local;
 
         let today = Local::now().format("%Y-%m-%d").to_string();
@@ -1244,7 +1574,7 @@ impl MCPToolExecutor {
             )
         };
 
-        self.create_note(&name, &content, None)
+        self.create_note(&name, &content, folder)
     }
 
     fn find_and_replace(
@@ -1329,7 +1659,7 @@ impl MCPToolExecutor {
         // Eliminar todas las notas de esta carpeta de la BD primero
         if recursive {
             if let Err(e) = self.notes_db.borrow().delete_notes_in_folder(name) {
-                eprintln!("⚠️ Error eliminando notas de BD: {}", e);
+                error!("Error eliminando notas de BD: {}", e);
             }
         }
 
@@ -1372,17 +1702,8 @@ impl MCPToolExecutor {
             )));
         }
 
-        // Renombrar en el filesystem
-        std::fs::rename(&old_path, &new_path)?;
-
-        // Actualizar todas las notas de esta carpeta en la BD
-        if let Err(e) = self.notes_db.borrow().update_notes_folder(
-            old_name,
-            new_name,
-            self.notes_dir.root().to_str().unwrap_or(""),
-        ) {
-            eprintln!("⚠️ Error actualizando carpeta en BD: {}", e);
-        }
+        // Carpeta y notas de la BD se renombran juntas
+        NoteStore::new(&self.notes_dir, &self.notes_db.borrow()).move_folder(old_name, new_name)?;
 
         Ok(MCPToolResult::success(json!({
             "message": format!("✓ Carpeta renombrada: '{}' → '{}'", old_name, new_name),
@@ -1437,17 +1758,9 @@ impl MCPToolExecutor {
             folder_name.to_string()
         };
 
-        // Mover en el filesystem
-        std::fs::rename(&old_path, &new_path)?;
-
-        // Actualizar todas las notas de esta carpeta en la BD
-        if let Err(e) = self.notes_db.borrow().update_notes_folder(
-            name,
-            &new_folder_path,
-            self.notes_dir.root().to_str().unwrap_or(""),
-        ) {
-            eprintln!("⚠️ Error actualizando carpeta en BD: {}", e);
-        }
+        // Carpeta y notas de la BD se mueven juntas
+        NoteStore::new(&self.notes_dir, &self.notes_db.borrow())
+            .move_folder(name, &new_folder_path)?;
 
         Ok(MCPToolResult::success(json!({
             "message": format!("✓ Carpeta '{}' movida a '{}'", name, new_parent.unwrap_or("raíz")),
@@ -1615,7 +1928,7 @@ impl MCPToolExecutor {
 
         // Ordenar por frecuencia
         let mut freq_vec: Vec<_> = word_freq.into_iter().collect();
-        freq_vec.sort_by(|a, b| b.1.cmp(&a.1));
+        freq_vec.sort_by_key(|entry| std::cmp::Reverse(entry.1));
 
         // Tomar los top max_tags
         let suggested_tags: Vec<String> = freq_vec
@@ -1669,29 +1982,33 @@ impl MCPToolExecutor {
             })));
         }
 
-        // Filtrar por threshold y folder si es necesario
-        let filtered_results: Vec<_> = results
+        // Los aciertos son fragmentos (`ruta#índice`): pasar a notas, una vez
+        // cada una, y filtrar por threshold y folder si es necesario
+        let hits = results
             .into_iter()
             .filter(|(score, _id, _meta, _content)| *score >= threshold)
-            .filter(|(_, id, _, _)| {
-                if let Some(ref folder_filter) = folder {
-                    id.contains(folder_filter)
-                } else {
-                    true
-                }
+            .map(|(score, chunk_id, _meta, _content)| (score, chunk_id));
+        let folder_prefix = folder.map(|folder| format!("{}/", folder.trim_end_matches('/')));
+        let filtered_results: Vec<_> = semantic_results(&self.notes_db.borrow(), hits)?
+            .into_iter()
+            .filter(|result| match &folder_prefix {
+                Some(prefix) => result.note_name.starts_with(prefix.as_str()),
+                None => true,
             })
+            // Las excluidas de la IA no salen ni en la lista ni en el mensaje
+            .filter(|result| !path_ai_excluded(self.notes_dir.root(), Path::new(&result.note_path)))
             .collect();
 
         let results_json: Vec<_> = filtered_results
             .iter()
             .enumerate()
-            .map(|(i, (score, note_id, metadata, _content))| {
+            .map(|(i, result)| {
                 json!({
                     "rank": i + 1,
-                    "note_id": note_id,
-                    "similarity": format!("{:.2}%", score * 100.0),
-                    "score": score,
-                    "metadata": metadata
+                    "note_name": result.note_name,
+                    "note_path": result.note_path,
+                    "similarity": format!("{:.2}%", result.relevance * 100.0),
+                    "score": result.relevance
                 })
             })
             .collect();
@@ -1699,8 +2016,13 @@ impl MCPToolExecutor {
         let note_list: Vec<String> = filtered_results
             .iter()
             .enumerate()
-            .map(|(i, (score, note_id, _, _))| {
-                format!("{}. {} - Similitud: {:.0}%", i + 1, note_id, score * 100.0)
+            .map(|(i, result)| {
+                format!(
+                    "{}. {} - Similitud: {:.0}%",
+                    i + 1,
+                    result.note_name,
+                    result.relevance * 100.0
+                )
             })
             .collect();
 
@@ -1742,7 +2064,7 @@ impl MCPToolExecutor {
     fn get_embedding_stats(&self) -> Result<MCPToolResult> {
         // Consultar base de datos RIG directamente
         let db_path = self.notes_dir.db_path();
-        let conn = rusqlite::Connection::open(&db_path)?;
+        let conn = crate::core::DbPool::shared(&db_path).get()?;
 
         let notes_count: i64 = conn
             .query_row("SELECT COUNT(*) FROM rig_note", [], |row| row.get(0))
@@ -1769,6 +2091,80 @@ impl MCPToolExecutor {
         })))
     }
 
+    fn export_chat_sessions(
+        &self,
+        session_id: Option<i64>,
+        format: Option<&str>,
+        output_path: Option<&str>,
+    ) -> Result<MCPToolResult> {
+        use crate::core::{ExportFormat, Redactor, export_sessions};
+
+        let format = match format {
+            Some(name) => match ExportFormat::from_name(name) {
+                Some(format) => format,
+                None => {
+                    return Ok(MCPToolResult::error(format!(
+                        "Formato no soportado: '{}'. Usa 'jsonl' o 'json'",
+                        name
+                    )));
+                }
+            },
+            None => ExportFormat::Jsonl,
+        };
+
+        let root = self.notes_dir.root().to_path_buf();
+        let relative = match output_path {
+            Some(path) => PathBuf::from(path),
+            None => PathBuf::from(".exports").join(format!(
+                "chat-{}.{}",
+                chrono::Local::now().format("%Y%m%d-%H%M%S"),
+                format.extension()
+            )),
+        };
+        // Solo dentro de la carpeta de notas
+        if relative.is_absolute()
+            || relative
+                .components()
+                .any(|component| matches!(component, std::path::Component::ParentDir))
+        {
+            return Ok(MCPToolResult::error(
+                "output_path debe ser una ruta relativa dentro de la carpeta de notas".to_string(),
+            ));
+        }
+
+        let mut sessions = {
+            let db = self.notes_db.borrow();
+            let ids = match session_id {
+                Some(id) => vec![id],
+                None => db.get_chat_session_ids()?,
+            };
+            ids.into_iter()
+                .map(|id| db.export_chat_session(id))
+                .collect::<Result<Vec<_>, _>>()?
+        };
+        let redactor = Redactor::for_config(&self.notes_config.borrow(), &root);
+        for session in &mut sessions {
+            session.redact(&redactor);
+        }
+
+        let path = root.join(&relative);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&path, export_sessions(&sessions, format)?)?;
+        info!(
+            "{} sesiones de chat exportadas a {}",
+            sessions.len(),
+            path.display()
+        );
+
+        Ok(MCPToolResult::success(json!({
+            "message": format!("✓ {} sesiones exportadas a {}", sessions.len(), relative.display()),
+            "path": relative.to_string_lossy(),
+            "sessions": sessions.len()
+        })))
+    }
+
     fn index_note(&self, note_path: String) -> Result<MCPToolResult> {
         use crate::core::TextChunker;
 
@@ -1817,7 +2213,7 @@ impl MCPToolExecutor {
             let chunk_id = format!("{}#{}", note_path, i);
             match rt.block_on(memory.index_note(&chunk_id, &chunk.text, metadata.clone())) {
                 Ok(_) => success_count += 1,
-                Err(e) => eprintln!("⚠️ Error indexando chunk {}: {}", i, e),
+                Err(e) => error!("Error indexando chunk {}: {}", i, e),
             }
         }
 
@@ -1845,7 +2241,7 @@ impl MCPToolExecutor {
         let rt = tokio::runtime::Runtime::new()?;
 
         // Clear all existing indexes first
-        eprintln!("🗑️ Limpiando índices existentes antes de reindexar...");
+        info!("Limpiando índices existentes antes de reindexar...");
         if let Err(e) = rt.block_on(memory.clear_all()) {
             return Ok(MCPToolResult::error(format!(
                 "Error limpiando índices: {}",
@@ -1872,7 +2268,7 @@ impl MCPToolExecutor {
             if let Ok(content) = note.read() {
                 use crate::core::frontmatter::Frontmatter;
                 let (frontmatter, _) = Frontmatter::parse_or_empty(&content);
-                let note_name = note.name.clone();
+                let note_name = note.name().to_string();
 
                 let metadata = serde_json::json!({
                     "tags": frontmatter.tags,
@@ -1886,7 +2282,7 @@ impl MCPToolExecutor {
                     match rt.block_on(memory.index_note(&chunk_id, &chunk.text, metadata.clone())) {
                         Ok(_) => total_chunks += 1,
                         Err(e) => {
-                            eprintln!("Failed to index note '{}': {}", note_name, e);
+                            error!("Failed to index note '{}': {}", note_name, e);
                             errors += 1;
                         }
                     }
@@ -1915,23 +2311,9 @@ impl MCPToolExecutor {
         repeat_pattern: Option<&str>,
         note_name: Option<&str>,
     ) -> Result<MCPToolResult> {
-        use crate::reminders::{Priority, Reminder, ReminderStatus, RepeatPattern};
-        use chrono::{DateTime, Utc};
+        use crate::reminders::{Priority, RepeatPattern};
 
-        // Parsear fecha como UTC
-        let due_date_parsed = DateTime::parse_from_rfc3339(due_date)
-            .map(|dt| dt.with_timezone(&Utc))
-            .or_else(|_| {
-                // Intentar formato más simple: "YYYY-MM-DD HH:MM"
-                chrono::NaiveDateTime::parse_from_str(due_date, "%Y-%m-%d %H:%M")
-                    .map(|dt| DateTime::<Utc>::from_naive_utc_and_offset(dt, Utc))
-            })
-            .map_err(|e| {
-                anyhow::anyhow!(
-                    "Formato de fecha inválido: {}. Use 'YYYY-MM-DD HH:MM' o RFC3339",
-                    e
-                )
-            })?;
+        let due_date_parsed = Self::parse_reminder_date(due_date)?;
 
         // Parsear prioridad
         let priority_enum = match priority.as_deref() {
@@ -1980,10 +2362,7 @@ impl MCPToolExecutor {
 
                     // 2b. Si aún no existe, indexar la nota al vuelo
                     if resolved_id.is_none() {
-                        println!(
-                            "⚠️ Nota '{}' encontrada pero no indexada. Indexando...",
-                            name
-                        );
+                        warn!("Nota '{}' encontrada pero no indexada. Indexando...", name);
                         if let Ok(content) = note.read() {
                             // Determinar carpeta relativa
                             let folder = note
@@ -2014,11 +2393,11 @@ impl MCPToolExecutor {
             }
 
             if let Some(id) = resolved_id {
-                println!("✓ Vinculando recordatorio a nota '{}' (ID: {})", name, id);
+                info!("Vinculando recordatorio a nota '{}' (ID: {})", name, id);
                 Some(id)
             } else {
-                println!(
-                    "⚠️ Nota '{}' no encontrada ni en DB ni en disco, creando recordatorio sin vínculo",
+                warn!(
+                    "Nota '{}' no encontrada ni en DB ni en disco, creando recordatorio sin vínculo",
                     name
                 );
                 None
@@ -2028,10 +2407,8 @@ impl MCPToolExecutor {
         };
 
         // Crear recordatorio en la base de datos
-        let db_path = self.notes_dir.root().parent().unwrap().join("reminders.db");
-        let conn = rusqlite::Connection::open(&db_path)?;
+        let conn = self.notes_db.borrow().pool().get()?;
         let reminders_db = crate::reminders::ReminderDatabase::new(conn);
-        reminders_db.ensure_schema()?;
 
         let id = reminders_db.create_reminder(
             note_id,
@@ -2075,10 +2452,7 @@ impl MCPToolExecutor {
                     content.push_str(&reminder_text);
 
                     if let Err(e) = note.write(&content) {
-                        eprintln!(
-                            "⚠️ Error escribiendo recordatorio en nota '{}': {}",
-                            name, e
-                        );
+                        error!("Error escribiendo recordatorio en nota '{}': {}", name, e);
                     } else {
                         // Reindexar nota para actualizar contenido en BD
                         let _ = self.notes_db.borrow().index_note(
@@ -2104,6 +2478,25 @@ impl MCPToolExecutor {
         })))
     }
 
+    /// Fecha de un recordatorio en RFC3339 o "YYYY-MM-DD HH:MM" (UTC)
+    fn parse_reminder_date(due_date: &str) -> Result<chrono::DateTime<chrono::Utc>> {
+        use chrono::{DateTime, Utc};
+
+        DateTime::parse_from_rfc3339(due_date)
+            .map(|dt| dt.with_timezone(&Utc))
+            .or_else(|_| {
+                // Intentar formato más simple: "YYYY-MM-DD HH:MM"
+                chrono::NaiveDateTime::parse_from_str(due_date, "%Y-%m-%d %H:%M")
+                    .map(|dt| DateTime::<Utc>::from_naive_utc_and_offset(dt, Utc))
+            })
+            .map_err(|e| {
+                anyhow::anyhow!(
+                    "Formato de fecha inválido: {}. Use 'YYYY-MM-DD HH:MM' o RFC3339",
+                    e
+                )
+            })
+    }
+
     fn list_reminders(
         &self,
         status: Option<&str>,
@@ -2113,8 +2506,7 @@ impl MCPToolExecutor {
         use crate::reminders::ReminderStatus;
         use chrono::{Duration, Utc};
 
-        let db_path = self.notes_dir.root().parent().unwrap().join("reminders.db");
-        let conn = rusqlite::Connection::open(&db_path)?;
+        let conn = self.notes_db.borrow().pool().get()?;
         let reminders_db = crate::reminders::ReminderDatabase::new(conn);
 
         // Convertir status a enum si se proporciona
@@ -2151,8 +2543,6 @@ impl MCPToolExecutor {
             filtered.truncate(lim as usize);
         }
 
-        let is_spanish = self.i18n.borrow().current_language() == crate::i18n::Language::Spanish;
-
         let reminders_json: Vec<_> = filtered
             .iter()
             .map(|r| {
@@ -2160,7 +2550,7 @@ impl MCPToolExecutor {
                     "id": r.id,
                     "title": r.title,
                     "description": r.description,
-                    "due_date": r.format_due_date(is_spanish),
+                    "due_date": r.format_due_date(),
                     "priority": format!("{:?}", r.priority),
                     "status": format!("{:?}", r.status),
                     "repeat": format!("{:?}", r.repeat_pattern),
@@ -2177,13 +2567,10 @@ impl MCPToolExecutor {
     }
 
     fn complete_reminder(&self, id: i64) -> Result<MCPToolResult> {
-        use crate::reminders::ReminderStatus;
-
-        let db_path = self.notes_dir.root().parent().unwrap().join("reminders.db");
-        let conn = rusqlite::Connection::open(&db_path)?;
+        let conn = self.notes_db.borrow().pool().get()?;
         let reminders_db = crate::reminders::ReminderDatabase::new(conn);
 
-        reminders_db.update_status(id, ReminderStatus::Completed)?;
+        reminders_db.complete_reminder(id)?;
 
         Ok(MCPToolResult::success(json!({
             "message": format!("✓ Recordatorio {} marcado como completado", id),
@@ -2195,8 +2582,7 @@ impl MCPToolExecutor {
     fn snooze_reminder(&self, id: i64, minutes: i64) -> Result<MCPToolResult> {
         use chrono::{Duration, Utc};
 
-        let db_path = self.notes_dir.root().parent().unwrap().join("reminders.db");
-        let conn = rusqlite::Connection::open(&db_path)?;
+        let conn = self.notes_db.borrow().pool().get()?;
         let reminders_db = crate::reminders::ReminderDatabase::new(conn);
 
         // Calcular la nueva fecha de snooze
@@ -2210,11 +2596,51 @@ impl MCPToolExecutor {
         })))
     }
 
+    fn update_reminder(
+        &self,
+        id: i64,
+        title: Option<&str>,
+        description: Option<&str>,
+        due_date: Option<&str>,
+        priority: Option<&str>,
+        repeat_pattern: Option<&str>,
+    ) -> Result<MCPToolResult> {
+        use crate::reminders::{Priority, RepeatPattern};
+
+        let due_date = due_date.map(Self::parse_reminder_date).transpose()?;
+
+        let conn = self.notes_db.borrow().pool().get()?;
+        let reminders_db = crate::reminders::ReminderDatabase::new(conn);
+
+        reminders_db.update_reminder(
+            id,
+            title,
+            description.map(Some),
+            due_date,
+            priority.map(Priority::from_label),
+            repeat_pattern.map(RepeatPattern::from_label),
+        )?;
+
+        let reminder = reminders_db
+            .get_reminder(id)?
+            .ok_or_else(|| anyhow::anyhow!("Recordatorio {} no encontrado", id))?;
+        self.rewrite_reminder_source(&reminder, true)?;
+
+        Ok(MCPToolResult::success(json!({
+            "message": format!("✓ Recordatorio {} actualizado", id),
+            "reminder_id": id,
+            "title": reminder.title,
+            "due_date": reminder.due_date.to_rfc3339()
+        })))
+    }
+
     fn delete_reminder(&self, id: i64) -> Result<MCPToolResult> {
-        let db_path = self.notes_dir.root().parent().unwrap().join("reminders.db");
-        let conn = rusqlite::Connection::open(&db_path)?;
+        let conn = self.notes_db.borrow().pool().get()?;
         let reminders_db = crate::reminders::ReminderDatabase::new(conn);
 
+        if let Some(reminder) = reminders_db.get_reminder(id)? {
+            self.rewrite_reminder_source(&reminder, false)?;
+        }
         reminders_db.delete_reminder(id)?;
 
         Ok(MCPToolResult::success(json!({
@@ -2223,6 +2649,56 @@ impl MCPToolExecutor {
         })))
     }
 
+    /// La nota es la fuente de verdad de los recordatorios: reescribe la
+    /// línea `!!RECORDAR` de la que sale `reminder` con sus datos actuales, o
+    /// la quita si `keep` es false (si no, al guardar la nota volvería)
+    fn rewrite_reminder_source(
+        &self,
+        reminder: &crate::reminders::Reminder,
+        keep: bool,
+    ) -> Result<()> {
+        let (Some(path), Some(line)) = (&reminder.source_path, reminder.source_line) else {
+            return Ok(());
+        };
+
+        let content = std::fs::read_to_string(path)?;
+        let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+        let index = line.saturating_sub(1);
+        let Some(marker) = lines.get(index).and_then(|current| current.find("!!")) else {
+            warn!(
+                "La línea {} de '{}' ya no es un recordatorio, no se toca",
+                line, path
+            );
+            return Ok(());
+        };
+
+        if keep {
+            let draft = crate::reminders::ReminderDraft {
+                note_name: String::new(),
+                title: reminder.title.clone(),
+                due_date: reminder.due_date,
+                priority: reminder.priority,
+                repeat_pattern: reminder.repeat_pattern,
+            };
+            // Se conserva lo que hubiera delante (viñetas, sangría...)
+            lines[index] = format!("{}{}", &lines[index][..marker], draft.to_note_line());
+        } else {
+            lines.remove(index);
+        }
+
+        let mut new_content = lines.join("\n");
+        if content.ends_with('\n') {
+            new_content.push('\n');
+        }
+        std::fs::write(path, &new_content)?;
+
+        let db = self.notes_db.borrow();
+        if let Some(meta) = db.get_note_by_path(path)? {
+            db.index_note(&meta.name, path, &new_content, meta.folder.as_deref())?;
+        }
+        Ok(())
+    }
+
     // ==================== BASES (Vistas de Base de Datos) ====================
 
     fn create_base(
@@ -2231,7 +2707,7 @@ impl MCPToolExecutor {
         description: Option<&str>,
         source_folder: Option<&str>,
     ) -> Result<MCPToolResult> {
-        use crate::core::{Base, BaseView};
+        use crate::core::Base;
 
         // Crear la Base
         let mut base = Base::new(name);
@@ -2267,7 +2743,7 @@ impl MCPToolExecutor {
         let base_data = db.get_base_by_name(name)?;
 
         match base_data {
-            Some((id, name, description, source_folder, config_yaml, active_view)) => {
+            Some((_, name, _, _, config_yaml, _)) => {
                 // Parsear la configuración
                 let mut base = Base::parse(&config_yaml).map_err(|e| anyhow::anyhow!("{}", e))?;
 
@@ -2496,7 +2972,7 @@ impl MCPToolExecutor {
         let base_data = db.get_base_by_name(name)?;
 
         match base_data {
-            Some((id, name, description, source_folder, config_yaml, active_view)) => {
+            Some((_, name, description, source_folder, config_yaml, active_view)) => {
                 let base = Base::parse(&config_yaml).map_err(|e| anyhow::anyhow!("{}", e))?;
 
                 let views_json: Vec<_> = base
@@ -1,4 +1,6 @@
 pub mod client;
+pub mod dry_run;
+pub mod events;
 pub mod executor;
 pub mod protocol;
 pub mod server;
@@ -6,10 +8,13 @@ pub mod tool_schemas;
 pub mod tools;
 
 pub use client::{MCPClient, MCPClientManager};
+pub use dry_run::{DryRun, PlannedChange};
+pub use events::{MCPChange, MCPChangeListener};
 pub use executor::MCPToolExecutor;
 pub use protocol::{MCPError, MCPRequest, MCPResponse, MCPTool};
 pub use server::start_mcp_server;
 pub use tool_schemas::{
     get_all_tool_definitions, get_all_tool_definitions_as_values, get_core_tool_definitions,
+    validate_tool_arguments,
 };
 pub use tools::{MCPToolCall, MCPToolRegistry, MCPToolResult};
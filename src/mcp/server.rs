@@ -8,35 +8,28 @@ use axum::{
 };
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::cell::RefCell;
-use std::fs;
 use std::net::SocketAddr;
-use std::rc::Rc;
 use std::sync::{Arc, Mutex};
 use tower_http::cors::{Any, CorsLayer};
+use tracing::{debug, info};
 
-use crate::core::database::NotesDatabase;
+use crate::core::DbActor;
 use crate::core::note_file::NotesDirectory;
 use crate::i18n::I18n;
-use crate::mcp::{MCPToolCall, MCPToolExecutor};
-
-/// Señaliza cambios en las notas para que la UI se actualice
-fn signal_notes_changed() {
-    let signal_path = std::env::temp_dir().join("notnative_mcp_update.signal");
-    let timestamp = std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .unwrap()
-        .as_secs();
-    let _ = fs::write(&signal_path, timestamp.to_string());
-}
+use crate::mcp::{MCPChangeListener, MCPToolCall, MCPToolExecutor, MCPToolResult};
+use crate::plugins::PluginHost;
 
 /// Estado compartido del servidor MCP (thread-safe)
 #[derive(Clone)]
 pub struct MCPServerState {
     notes_dir: NotesDirectory,
-    notes_db: Arc<Mutex<NotesDatabase>>,
+    notes_db: DbActor,
     notes_config: Arc<Mutex<crate::core::NotesConfig>>,
     i18n: Arc<Mutex<I18n>>,
+    /// Avisa a la app de los cambios hechos por las herramientas
+    on_change: MCPChangeListener,
+    /// Herramientas registradas por plugins
+    plugins: PluginHost,
 }
 
 /// Request para listar herramientas
@@ -88,15 +81,19 @@ pub struct ListToolsResponse {
 /// Inicia el servidor MCP en segundo plano
 pub async fn start_mcp_server(
     notes_dir: NotesDirectory,
-    notes_db: Arc<Mutex<NotesDatabase>>,
+    notes_db: DbActor,
     notes_config: Arc<Mutex<crate::core::NotesConfig>>,
     i18n: Arc<Mutex<I18n>>,
+    on_change: MCPChangeListener,
+    plugins: PluginHost,
 ) -> Result<()> {
     let state = MCPServerState {
         notes_dir,
         notes_db,
         notes_config,
         i18n,
+        on_change,
+        plugins,
     };
 
     // Configurar CORS para permitir requests desde cualquier origen
@@ -113,10 +110,10 @@ pub async fn start_mcp_server(
         .with_state(state);
 
     let addr = SocketAddr::from(([127, 0, 0, 1], 8788));
-    println!("🚀 Servidor MCP escuchando en http://{}", addr);
-    println!("   - GET  /health");
-    println!("   - POST /mcp/list_tools");
-    println!("   - POST /mcp/call_tool");
+    info!("Servidor MCP escuchando en http://{}", addr);
+    debug!("- GET  /health");
+    debug!("- POST /mcp/list_tools");
+    debug!("- POST /mcp/call_tool");
 
     let listener = tokio::net::TcpListener::bind(addr).await?;
     axum::serve(listener, app).await?;
@@ -138,7 +135,15 @@ async fn list_tools(
     State(state): State<MCPServerState>,
     Json(request): Json<ListToolsRequest>,
 ) -> Json<JsonRpcResponse<ListToolsResponse>> {
-    let tools = crate::mcp::tool_schemas::get_all_tool_definitions_as_values();
+    let mut tools = crate::mcp::tool_schemas::get_all_tool_definitions_as_values();
+    tools.extend(
+        state
+            .plugins
+            .registry()
+            .tools
+            .iter()
+            .map(|tool| tool.definition()),
+    );
 
     Json(JsonRpcResponse {
         jsonrpc: "2.0".to_string(),
@@ -153,11 +158,60 @@ async fn call_tool(
     State(state): State<MCPServerState>,
     Json(request): Json<CallToolRequest>,
 ) -> Json<JsonRpcResponse<Value>> {
-    // Crear executor (necesitamos convertir Arc<Mutex> a Rc<RefCell> temporalmente)
-    // Por simplicidad, clonamos la DB para evitar problemas de lifetime
-    let notes_db_clone = {
-        let db = state.notes_db.lock().unwrap();
-        db.clone_connection()
+    // Herramientas de plugins (los nombres integrados no se pueden registrar)
+    if let Some(result) = state
+        .plugins
+        .call_tool(&request.params.tool, request.params.args.clone())
+    {
+        let result = match result {
+            Ok(data) => MCPToolResult::success(data),
+            Err(e) => MCPToolResult::error(format!("Error en el plugin: {}", e)),
+        };
+        return Json(JsonRpcResponse {
+            jsonrpc: "2.0".to_string(),
+            id: request.id,
+            result: Some(serde_json::to_value(result).unwrap_or(serde_json::json!({}))),
+            error: None,
+        });
+    }
+
+    // El executor es síncrono y algunas herramientas esperan a la red o a
+    // los embeddings: corre en un hilo de `spawn_blocking` con su propia
+    // conexión del pool del actor, sin ocupar el actor ni un hilo de Tokio
+    let notes_db = match state.notes_db.connection() {
+        Ok(db) => db,
+        Err(e) => {
+            return Json(JsonRpcResponse {
+                jsonrpc: "2.0".to_string(),
+                id: request.id,
+                result: None,
+                error: Some(JsonRpcError {
+                    code: -32603,
+                    message: format!("Error accediendo a la base de datos: {}", e),
+                }),
+            });
+        }
+    };
+
+    // Intentar parsear la llamada a herramienta
+    let tool_call_json = serde_json::json!({
+        "tool": request.params.tool,
+        "args": request.params.args
+    });
+
+    let tool_call = match serde_json::from_value::<MCPToolCall>(tool_call_json) {
+        Ok(tool_call) => tool_call,
+        Err(e) => {
+            return Json(JsonRpcResponse {
+                jsonrpc: "2.0".to_string(),
+                id: request.id,
+                result: None,
+                error: Some(JsonRpcError {
+                    code: -32602,
+                    message: format!("Parámetros inválidos: {}", e),
+                }),
+            });
+        }
     };
 
     let notes_config_clone = {
@@ -170,67 +224,37 @@ async fn call_tool(
         i18n.clone()
     };
 
-    let executor = MCPToolExecutor::new(
-        state.notes_dir.clone(),
-        std::rc::Rc::new(std::cell::RefCell::new(notes_db_clone)),
-        std::rc::Rc::new(std::cell::RefCell::new(notes_config_clone)),
-        std::rc::Rc::new(std::cell::RefCell::new(i18n_clone)),
-    );
+    let notes_dir = state.notes_dir.clone();
+    let on_change = state.on_change.clone();
+    let outcome = tokio::task::spawn_blocking(move || {
+        let mut executor = MCPToolExecutor::new(
+            notes_dir,
+            std::rc::Rc::new(std::cell::RefCell::new(notes_db)),
+            std::rc::Rc::new(std::cell::RefCell::new(notes_config_clone)),
+            std::rc::Rc::new(std::cell::RefCell::new(i18n_clone)),
+        );
+        executor.set_change_listener(on_change);
 
-    // Intentar parsear la llamada a herramienta
-    let tool_call_json = serde_json::json!({
-        "tool": request.params.tool,
-        "args": request.params.args
-    });
+        // Ejecutar la herramienta (el executor avisa a la app de los cambios)
+        executor.execute(tool_call).map_err(|e| e.to_string())
+    })
+    .await
+    .unwrap_or_else(|e| Err(e.to_string()));
 
-    match serde_json::from_value::<MCPToolCall>(tool_call_json) {
-        Ok(tool_call) => {
-            // Verificar si es una herramienta que modifica archivos
-            let modifies_files = matches!(
-                tool_call,
-                MCPToolCall::CreateNote { .. }
-                    | MCPToolCall::UpdateNote { .. }
-                    | MCPToolCall::AppendToNote { .. }
-                    | MCPToolCall::DeleteNote { .. }
-                    | MCPToolCall::RenameNote { .. }
-                    | MCPToolCall::DuplicateNote { .. }
-                    | MCPToolCall::MoveNote { .. }
-                    | MCPToolCall::CreateFolder { .. }
-            );
-
-            // Ejecutar la herramienta
-            match executor.execute(tool_call) {
-                Ok(result) => {
-                    // Si modificó archivos, señalizar cambio
-                    if modifies_files && result.success {
-                        signal_notes_changed();
-                    }
-
-                    Json(JsonRpcResponse {
-                        jsonrpc: "2.0".to_string(),
-                        id: request.id,
-                        result: Some(serde_json::to_value(result).unwrap_or(serde_json::json!({}))),
-                        error: None,
-                    })
-                }
-                Err(e) => Json(JsonRpcResponse {
-                    jsonrpc: "2.0".to_string(),
-                    id: request.id,
-                    result: None,
-                    error: Some(JsonRpcError {
-                        code: -32603,
-                        message: format!("Error ejecutando herramienta: {}", e),
-                    }),
-                }),
-            }
-        }
+    match outcome {
+        Ok(result) => Json(JsonRpcResponse {
+            jsonrpc: "2.0".to_string(),
+            id: request.id,
+            result: Some(serde_json::to_value(result).unwrap_or(serde_json::json!({}))),
+            error: None,
+        }),
         Err(e) => Json(JsonRpcResponse {
             jsonrpc: "2.0".to_string(),
             id: request.id,
             result: None,
             error: Some(JsonRpcError {
-                code: -32602,
-                message: format!("Parámetros inválidos: {}", e),
+                code: -32603,
+                message: format!("Error ejecutando herramienta: {}", e),
             }),
         }),
     }
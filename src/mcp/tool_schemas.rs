@@ -1,5 +1,9 @@
 use crate::mcp::protocol::MCPTool;
 use serde_json::{Value, json};
+use std::sync::LazyLock;
+
+/// Definiciones para validar argumentos (se construyen una vez)
+static TOOL_DEFINITIONS: LazyLock<Vec<MCPTool>> = LazyLock::new(get_all_tool_definitions);
 
 /// Genera las herramientas MCP más comunes (Core Tools)
 pub fn get_core_tool_definitions() -> Vec<Value> {
@@ -212,7 +216,7 @@ pub fn get_all_tool_definitions() -> Vec<MCPTool> {
         MCPTool {
             name: "delete_note".to_string(),
             description:
-                "Elimina permanentemente una nota. ¡Cuidado, esta acción no se puede deshacer!"
+                "Mueve una nota a la papelera y la quita del índice. Se puede restaurar desde la papelera."
                     .to_string(),
             parameters: json!({
                 "type": "object",
@@ -649,6 +653,36 @@ pub fn get_all_tool_definitions() -> Vec<MCPTool> {
                 "required": ["name"]
             }),
         },
+        MCPTool {
+            name: "list_folder_templates".to_string(),
+            description: "Lista las plantillas de carpeta (estructuras de proyecto) disponibles con las subcarpetas y notas que crea cada una".to_string(),
+            parameters: json!({
+                "type": "object",
+                "properties": {}
+            }),
+        },
+        MCPTool {
+            name: "create_project_folder".to_string(),
+            description: "Crea una carpeta de proyecto a partir de una plantilla de carpeta (por ejemplo Overview.md, Meetings/ y Tasks.md ya rellenas). Úsala cuando el usuario quiera empezar un proyecto nuevo; consulta antes list_folder_templates si no sabes qué plantillas hay.".to_string(),
+            parameters: json!({
+                "type": "object",
+                "properties": {
+                    "name": {
+                        "type": "string",
+                        "description": "Nombre del proyecto (será el nombre de la carpeta)"
+                    },
+                    "template": {
+                        "type": "string",
+                        "description": "Nombre de la plantilla (opcional, por defecto la primera)"
+                    },
+                    "parent": {
+                        "type": "string",
+                        "description": "Carpeta donde crear el proyecto (opcional, por defecto la raíz)"
+                    }
+                },
+                "required": ["name"]
+            }),
+        },
         MCPTool {
             name: "add_tag".to_string(),
             description: "Añade un tag a una nota. Los tags se agregan en el frontmatter YAML.".to_string(),
@@ -749,6 +783,10 @@ pub fn get_all_tool_definitions() -> Vec<MCPTool> {
                     "template": {
                         "type": "string",
                         "description": "Plantilla de contenido para la nota diaria (opcional)"
+                    },
+                    "folder": {
+                        "type": "string",
+                        "description": "Carpeta donde crear la nota (opcional)"
                     }
                 }
             }),
@@ -847,6 +885,29 @@ pub fn get_all_tool_definitions() -> Vec<MCPTool> {
                 "properties": {}
             }),
         },
+        // ==================== CHAT ====================
+        MCPTool {
+            name: "export_chat_sessions".to_string(),
+            description: "Exporta las conversaciones del chat, con los pasos del agente (razonamiento, llamadas a herramientas y resultados), a un archivo JSONL o JSON para analizarlas. Las claves de API y las rutas del equipo se tapan.".to_string(),
+            parameters: json!({
+                "type": "object",
+                "properties": {
+                    "session_id": {
+                        "type": "integer",
+                        "description": "Sesión a exportar (por defecto todas)"
+                    },
+                    "format": {
+                        "type": "string",
+                        "enum": ["jsonl", "json"],
+                        "description": "jsonl: una sesión por línea (por defecto); json: una lista"
+                    },
+                    "output_path": {
+                        "type": "string",
+                        "description": "Archivo de salida, relativo a la carpeta de notas (por defecto .exports/chat-<fecha>.jsonl)"
+                    }
+                }
+            }),
+        },
         // ==================== RECORDATORIOS ====================
         MCPTool {
             name: "CreateReminder".to_string(),
@@ -1084,6 +1145,27 @@ pub fn get_all_tool_definitions() -> Vec<MCPTool> {
     ]
 }
 
+/// Comprueba los argumentos que manda el modelo contra el esquema de la
+/// herramienta antes de ejecutarla. `name` puede venir en snake_case o en
+/// PascalCase. Devuelve la lista de problemas si no son válidos.
+pub fn validate_tool_arguments(name: &str, args: &Value) -> Result<(), Vec<String>> {
+    let normalize = |name: &str| name.replace('_', "").to_lowercase();
+    let wanted = normalize(name);
+    let Some(tool) = TOOL_DEFINITIONS
+        .iter()
+        .find(|tool| normalize(&tool.name) == wanted)
+    else {
+        return Err(vec![format!("herramienta desconocida: {}", name)]);
+    };
+
+    let errors = crate::core::validate_json(&tool.parameters, args);
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
 /// Convierte todas las herramientas al formato OpenAI (Vec<Value>)
 pub fn get_all_tool_definitions_as_values() -> Vec<Value> {
     get_all_tool_definitions()
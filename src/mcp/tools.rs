@@ -1,8 +1,5 @@
-use anyhow::Result;
 use serde::{Deserialize, Serialize};
-use serde_json::{Value, json};
-
-use crate::mcp::protocol::MCPTool;
+use serde_json::Value;
 
 /// Enum con todas las herramientas disponibles
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -109,6 +106,17 @@ pub enum MCPToolCall {
         #[serde(skip_serializing_if = "Option::is_none")]
         new_parent: Option<String>, // None = mover a raíz
     },
+    ListFolderTemplates {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        _dummy: Option<()>,
+    },
+    CreateProjectFolder {
+        name: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        template: Option<String>, // None = la primera del manifiesto
+        #[serde(skip_serializing_if = "Option::is_none")]
+        parent: Option<String>,
+    },
     AddTag {
         note: String,
         tag: String,
@@ -238,6 +246,14 @@ pub enum MCPToolCall {
         #[serde(skip_serializing_if = "Option::is_none")]
         output_path: Option<String>,
     },
+    ExportChatSessions {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        session_id: Option<i64>, // Todas si no se indica
+        #[serde(skip_serializing_if = "Option::is_none")]
+        format: Option<String>, // "jsonl" (por defecto) o "json"
+        #[serde(skip_serializing_if = "Option::is_none")]
+        output_path: Option<String>,
+    },
     ImportFromUrl {
         url: String,
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -263,6 +279,8 @@ pub enum MCPToolCall {
     CreateDailyNote {
         #[serde(skip_serializing_if = "Option::is_none")]
         template: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        folder: Option<String>,
     },
     BatchRename {
         pattern: String,
@@ -431,152 +449,17 @@ impl MCPToolRegistry {
         }
     }
 
-    fn default_tools_deprecated() -> Vec<MCPTool> {
-        vec![
-            MCPTool {
-                name: "create_note".to_string(),
-                description: "Crea una nueva nota en NotNative".to_string(),
-                parameters: json!({
-                    "type": "object",
-                    "properties": {
-                        "name": {
-                            "type": "string",
-                            "description": "Nombre de la nota (con o sin extensión .md)"
-                        },
-                        "content": {
-                            "type": "string",
-                            "description": "Contenido de la nota en formato markdown"
-                        },
-                        "folder": {
-                            "type": "string",
-                            "description": "Carpeta donde crear la nota (opcional)"
-                        }
-                    },
-                    "required": ["name", "content"]
-                }),
-            },
-            MCPTool {
-                name: "read_note".to_string(),
-                description: "Lee el contenido completo de una nota".to_string(),
-                parameters: json!({
-                    "type": "object",
-                    "properties": {
-                        "name": {
-                            "type": "string",
-                            "description": "Nombre de la nota a leer"
-                        }
-                    },
-                    "required": ["name"]
-                }),
-            },
-            MCPTool {
-                name: "update_note".to_string(),
-                description: "Actualiza el contenido de una nota existente".to_string(),
-                parameters: json!({
-                    "type": "object",
-                    "properties": {
-                        "name": {
-                            "type": "string",
-                            "description": "Nombre de la nota"
-                        },
-                        "content": {
-                            "type": "string",
-                            "description": "Nuevo contenido"
-                        }
-                    },
-                    "required": ["name", "content"]
-                }),
-            },
-            MCPTool {
-                name: "delete_note".to_string(),
-                description: "Elimina una nota".to_string(),
-                parameters: json!({
-                    "type": "object",
-                    "properties": {
-                        "name": {
-                            "type": "string",
-                            "description": "Nombre de la nota a eliminar"
-                        }
-                    },
-                    "required": ["name"]
-                }),
-            },
-            MCPTool {
-                name: "list_notes".to_string(),
-                description: "Lista todas las notas o las de una carpeta específica".to_string(),
-                parameters: json!({
-                    "type": "object",
-                    "properties": {
-                        "folder": {
-                            "type": "string",
-                            "description": "Carpeta específica (opcional)"
-                        }
-                    }
-                }),
-            },
-            MCPTool {
-                name: "search_notes".to_string(),
-                description: "Busca notas por contenido o nombre".to_string(),
-                parameters: json!({
-                    "type": "object",
-                    "properties": {
-                        "query": {
-                            "type": "string",
-                            "description": "Texto a buscar"
-                        }
-                    },
-                    "required": ["query"]
-                }),
-            },
-            MCPTool {
-                name: "get_notes_with_tag".to_string(),
-                description: "Obtiene todas las notas que tienen un tag específico".to_string(),
-                parameters: json!({
-                    "type": "object",
-                    "properties": {
-                        "tag": {
-                            "type": "string",
-                            "description": "Tag a buscar (sin #)"
-                        }
-                    },
-                    "required": ["tag"]
-                }),
-            },
-            MCPTool {
-                name: "get_all_tags".to_string(),
-                description: "Obtiene la lista de todos los tags usados en las notas".to_string(),
-                parameters: json!({
-                    "type": "object",
-                    "properties": {}
-                }),
-            },
-            MCPTool {
-                name: "create_folder".to_string(),
-                description: "Crea una nueva carpeta para organizar notas".to_string(),
-                parameters: json!({
-                    "type": "object",
-                    "properties": {
-                        "name": {
-                            "type": "string",
-                            "description": "Nombre de la carpeta"
-                        },
-                        "parent": {
-                            "type": "string",
-                            "description": "Carpeta padre (opcional)"
-                        }
-                    },
-                    "required": ["name"]
-                }),
-            },
-            MCPTool {
-                name: "list_folders".to_string(),
-                description: "Lista todas las carpetas de notas".to_string(),
-                parameters: json!({
-                    "type": "object",
-                    "properties": {}
-                }),
-            },
-        ]
+    /// Crea un registro con solo las herramientas `names` (las desconocidas se ignoran)
+    pub fn only(names: &[String]) -> Self {
+        let tools = crate::mcp::tool_schemas::get_all_tool_definitions_as_values()
+            .into_iter()
+            .filter(|tool| {
+                tool["function"]["name"]
+                    .as_str()
+                    .is_some_and(|name| names.iter().any(|allowed| allowed == name))
+            })
+            .collect();
+        Self { tools }
     }
 
     /// Obtiene todas las herramientas en formato OpenAI (ya listas para enviar a la API)
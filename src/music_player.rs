@@ -5,6 +5,7 @@ use std::fs;
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 use thiserror::Error;
+use tracing::{debug, error, info, warn};
 
 /// Estado del reproductor de música
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -234,7 +235,7 @@ impl YouTubeClient {
 
     /// Busca música en YouTube
     pub async fn search(&self, query: &str) -> Result<Vec<Song>, PlayerError> {
-        println!("Buscando música en YouTube: {}", query);
+        info!("Buscando música en YouTube: {}", query);
 
         // Buscar en YouTube (filtro de música se aplicará en los resultados)
         let results = self
@@ -268,13 +269,13 @@ impl YouTubeClient {
             }
         }
 
-        println!("Encontradas {} canciones", songs.len());
+        info!("Encontradas {} canciones", songs.len());
         Ok(songs)
     }
 
     /// Obtiene la URL del audio de un video
     pub async fn fetch_audio_url(&self, video_id: &str) -> Result<String, PlayerError> {
-        println!("Obteniendo URL de audio para: {}", video_id);
+        info!("Obteniendo URL de audio para: {}", video_id);
 
         // Obtener información del player
         let player = self
@@ -291,7 +292,7 @@ impl YouTubeClient {
             .max_by_key(|s| s.bitrate)
             .ok_or_else(|| PlayerError::YoutubeFetch("No audio stream found".to_string()))?;
 
-        println!(
+        info!(
             "Stream de audio encontrado: bitrate={}, codec={:?}",
             audio_stream.bitrate, audio_stream.mime
         );
@@ -341,97 +342,97 @@ impl MusicPlayer {
             libc::setlocale(libc::LC_NUMERIC, c"C".as_ptr());
         }
 
-        println!("🎵 Inicializando MPV...");
+        info!("Inicializando MPV...");
         let mpv = Mpv::new().map_err(|e| {
-            eprintln!("❌ Error creando instancia MPV: {:?}", e);
+            error!("Error creando instancia MPV: {:?}", e);
             e
         })?;
 
         // Configurar MPV para reproducción de audio (con manejo de errores individual)
         if let Err(e) = mpv.set_property("video", "no") {
-            eprintln!("⚠️  Error configurando 'video': {:?}", e);
+            error!("Error configurando 'video': {:?}", e);
         }
 
         // IMPORTANTE: Habilitar ytdl para soporte de YouTube
         if let Err(e) = mpv.set_property("ytdl", "yes") {
-            eprintln!("⚠️  Error configurando 'ytdl': {:?}", e);
-            eprintln!("    ADVERTENCIA: Sin ytdl, MPV no podrá reproducir URLs de YouTube");
-            eprintln!("    Instala yt-dlp: sudo apt install yt-dlp");
+            error!("Error configurando 'ytdl': {:?}", e);
+            warn!("ADVERTENCIA: Sin ytdl, MPV no podrá reproducir URLs de YouTube");
+            debug!("Instala yt-dlp: sudo apt install yt-dlp");
         } else {
-            println!("✓ ytdl habilitado para soporte de YouTube");
+            info!("ytdl habilitado para soporte de YouTube");
         }
 
         if let Err(e) = mpv.set_property("audio-channels", "stereo") {
-            eprintln!("⚠️  Error configurando 'audio-channels': {:?}", e);
+            error!("Error configurando 'audio-channels': {:?}", e);
         }
 
         if let Err(e) = mpv.set_property("volume", 80) {
-            eprintln!("⚠️  Error configurando 'volume': {:?}", e);
+            error!("Error configurando 'volume': {:?}", e);
         }
 
         // Asegurarse de que el audio no está silenciado
         if let Err(e) = mpv.set_property("mute", false) {
-            eprintln!("⚠️  Error configurando 'mute': {:?}", e);
+            error!("Error configurando 'mute': {:?}", e);
         }
 
         // Configurar salida de audio (PulseAudio)
         // Importante: MPV necesita usar "pulse" como backend primero
         if let Err(e) = mpv.set_property("ao", "pulse") {
-            eprintln!("⚠️  Error configurando 'ao': {:?}", e);
+            error!("Error configurando 'ao': {:?}", e);
         }
 
         // Configuraciones adicionales de PulseAudio
         // pulse-allow-suspended: permite usar PulseAudio incluso si el sink está suspendido
         if let Err(e) = mpv.set_property("pulse-allow-suspended", true) {
-            eprintln!("⚠️  Error configurando 'pulse-allow-suspended': {:?}", e);
+            error!("Error configurando 'pulse-allow-suspended': {:?}", e);
         }
 
         // pulse-latency-hacks: habilita hacks de latencia para PulseAudio
         if let Err(e) = mpv.set_property("pulse-latency-hacks", true) {
-            eprintln!("⚠️  Error configurando 'pulse-latency-hacks': {:?}", e);
+            error!("Error configurando 'pulse-latency-hacks': {:?}", e);
         }
 
         // audio-buffer: tamaño del buffer de audio (en segundos)
         // Un valor más alto puede ayudar con problemas de sincronización
         if let Err(e) = mpv.set_property("audio-buffer", 0.5) {
-            eprintln!("⚠️  Error configurando 'audio-buffer': {:?}", e);
+            error!("Error configurando 'audio-buffer': {:?}", e);
         }
 
         // audio-client-name: nombre del cliente de audio para PulseAudio
         if let Err(e) = mpv.set_property("audio-client-name", "NotNative Music") {
-            eprintln!("⚠️  Error configurando 'audio-client-name': {:?}", e);
+            error!("Error configurando 'audio-client-name': {:?}", e);
         }
 
         // gapless-audio: desactivar para evitar problemas con inicio de audio
         if let Err(e) = mpv.set_property("gapless-audio", "no") {
-            eprintln!("⚠️  Error configurando 'gapless-audio': {:?}", e);
+            error!("Error configurando 'gapless-audio': {:?}", e);
         }
 
         // initial-audio-sync: asegurar que el audio se sincroniza correctamente al inicio
         if let Err(e) = mpv.set_property("initial-audio-sync", true) {
-            eprintln!("⚠️  Error configurando 'initial-audio-sync': {:?}", e);
+            error!("Error configurando 'initial-audio-sync': {:?}", e);
         }
 
         // Configurar dispositivo de audio (usar el sink configurado o auto)
         if let Some(sink) = audio_sink {
             // MPV usa formato "pulse/sink_name" para PulseAudio
             let mpv_audio_device = format!("pulse/{}", sink);
-            println!("🔊 Configurando salida de audio: {}", mpv_audio_device);
+            info!("Configurando salida de audio: {}", mpv_audio_device);
 
             if let Err(e) = mpv.set_property("audio-device", mpv_audio_device.as_str()) {
-                eprintln!(
-                    "⚠️  Error configurando audio-device '{}': {:?}",
+                error!(
+                    "Error configurando audio-device '{}': {:?}",
                     mpv_audio_device, e
                 );
-                eprintln!("    Intentando con 'pulse' (default)...");
+                debug!("Intentando con 'pulse' (default)...");
                 let _ = mpv.set_property("audio-device", "pulse");
             } else {
-                println!("✓ Salida de audio configurada: {}", mpv_audio_device);
+                info!("Salida de audio configurada: {}", mpv_audio_device);
             }
         } else {
-            println!("🔊 Usando salida de audio por defecto de PulseAudio");
+            info!("Usando salida de audio por defecto de PulseAudio");
             if let Err(e) = mpv.set_property("audio-device", "pulse") {
-                eprintln!("⚠️  Error configurando 'audio-device': {:?}", e);
+                error!("Error configurando 'audio-device': {:?}", e);
             }
         }
 
@@ -444,7 +445,7 @@ impl MusicPlayer {
             "User-Agent: Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36",
         );
 
-        println!("✓ MPV inicializado correctamente");
+        info!("MPV inicializado correctamente");
 
         Ok(Self {
             mpv: Arc::new(mpv),
@@ -495,14 +496,14 @@ impl MusicPlayer {
         *self.state.lock().unwrap() = PlayerState::Loading;
         *self.current_song.lock().unwrap() = Some(song.clone());
 
-        println!("⏳ Cargando: {} - {}", song.title, song.artist_names());
+        info!("Cargando: {} - {}", song.title, song.artist_names());
 
         // IMPORTANTE: Despertar el sink de audio si está suspendido
         // Esto es necesario porque MPV no siempre activa sinks suspendidos
         if let Ok(device) = self.mpv.get_property::<String>("audio-device") {
             if device.starts_with("pulse/") {
                 let sink_name = device.strip_prefix("pulse/").unwrap_or("");
-                println!("🔊 Activando sink de audio: {}", sink_name);
+                info!("Activando sink de audio: {}", sink_name);
 
                 // Reproducir silencio brevemente para activar el sink
                 let _ = std::process::Command::new("paplay")
@@ -522,16 +523,16 @@ impl MusicPlayer {
                         child.kill()
                     });
 
-                println!("✓ Sink activado");
+                info!("Sink activado");
             }
         }
 
-        println!("🎵 Reproduciendo: {} - {}", song.title, song.artist_names());
+        info!("Reproduciendo: {} - {}", song.title, song.artist_names());
 
         // CRÍTICO: Usar el video ID directamente - MPV con ytdl manejará la descarga
         // Esto es más confiable que obtener el URL manualmente
         let ytdl_url = format!("https://www.youtube.com/watch?v={}", song.video_id);
-        println!("📺 URL de YouTube: {}", ytdl_url);
+        info!("URL de YouTube: {}", ytdl_url);
 
         // CRÍTICO: Asegurarse de que no está pausado ANTES de cargar
         let _ = self.mpv.set_property("pause", false);
@@ -543,7 +544,7 @@ impl MusicPlayer {
         self.mpv.command("loadfile", &[&ytdl_url, "replace"])?;
 
         // CRITICO: Esperar a que MPV realmente cargue el stream
-        println!("Esperando a que MPV cargue el stream...");
+        info!("Esperando a que MPV cargue el stream...");
         let mut loaded = false;
         for wait_attempt in 1..=20 {
             tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
@@ -551,7 +552,7 @@ impl MusicPlayer {
             // Verificar si MPV ya no esta idle
             if let Ok(idle) = self.mpv.get_property::<bool>("idle-active") {
                 if !idle {
-                    println!("Stream cargado en MPV (intento {})", wait_attempt);
+                    info!("Stream cargado en MPV (intento {})", wait_attempt);
                     loaded = true;
                     break;
                 }
@@ -560,7 +561,7 @@ impl MusicPlayer {
             // Verificar tracks
             if let Ok(track_count) = self.mpv.get_property::<i64>("track-list/count") {
                 if track_count > 0 {
-                    println!(
+                    info!(
                         "Tracks detectados: {} (intento {})",
                         track_count, wait_attempt
                     );
@@ -570,12 +571,12 @@ impl MusicPlayer {
             }
 
             if wait_attempt % 5 == 0 {
-                println!("Cargando... ({}/20)", wait_attempt);
+                info!("Cargando... ({}/20)", wait_attempt);
             }
         }
 
         if !loaded {
-            println!("ADVERTENCIA: MPV no cargo el stream en 4 segundos");
+            warn!("ADVERTENCIA: MPV no cargo el stream en 4 segundos");
         }
 
         // Forzar reproduccion
@@ -584,49 +585,46 @@ impl MusicPlayer {
             tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
         }
         if let Ok(vol) = self.mpv.get_property::<i64>("volume") {
-            println!("🔊 Volumen actual de MPV: {}%", vol);
+            info!("Volumen actual de MPV: {}%", vol);
         }
 
         if let Ok(muted) = self.mpv.get_property::<bool>("mute") {
-            println!("🔇 MPV silenciado: {}", muted);
+            info!("MPV silenciado: {}", muted);
             if muted {
-                println!("⚠️  Desactivando silencio...");
+                warn!("Desactivando silencio...");
                 let _ = self.mpv.set_property("mute", false);
             }
         }
 
         if let Ok(device) = self.mpv.get_property::<String>("audio-device") {
-            println!("🔊 Dispositivo de audio activo: {}", device);
+            info!("Dispositivo de audio activo: {}", device);
         }
 
         // Diagnósticos adicionales
         if let Ok(ao) = self.mpv.get_property::<String>("current-ao") {
-            println!("🔊 Audio output driver actual: {}", ao);
+            info!("Audio output driver actual: {}", ao);
         }
 
         if let Ok(idle) = self.mpv.get_property::<bool>("idle-active") {
-            println!("💤 MPV idle: {}", idle);
+            info!("MPV idle: {}", idle);
         }
 
         if let Ok(core_idle) = self.mpv.get_property::<bool>("core-idle") {
-            println!("💤 MPV core-idle: {}", core_idle);
+            info!("MPV core-idle: {}", core_idle);
         }
 
         if let Ok(demuxer_cache_state) = self.mpv.get_property::<String>("demuxer-cache-state") {
-            println!("📦 Cache state: {}", demuxer_cache_state);
+            info!("Cache state: {}", demuxer_cache_state);
         }
 
         // Verificar si hay audio tracks
         if let Ok(track_list) = self.mpv.get_property::<String>("track-list") {
-            println!("🎵 Track list: {}", track_list);
+            info!("Track list: {}", track_list);
         }
 
         // Verificar si el archivo realmente se cargó
         if let Ok(path) = self.mpv.get_property::<String>("path") {
-            println!(
-                "📁 Path cargado: {}",
-                &path[..std::cmp::min(path.len(), 100)]
-            );
+            info!("Path cargado: {}", &path[..std::cmp::min(path.len(), 100)]);
         }
 
         *self.state.lock().unwrap() = PlayerState::Playing;
@@ -639,7 +637,7 @@ impl MusicPlayer {
         if self.state() == PlayerState::Playing {
             self.mpv.set_property("pause", true)?;
             *self.state.lock().unwrap() = PlayerState::Paused;
-            println!("⏸️ Música pausada");
+            info!("Música pausada");
             Ok(())
         } else {
             Err(PlayerError::NoSong)
@@ -651,7 +649,7 @@ impl MusicPlayer {
         if self.state() == PlayerState::Paused {
             self.mpv.set_property("pause", false)?;
             *self.state.lock().unwrap() = PlayerState::Playing;
-            println!("▶️ Música reanudada");
+            info!("Música reanudada");
             Ok(())
         } else {
             Err(PlayerError::NoSong)
@@ -673,7 +671,7 @@ impl MusicPlayer {
         *self.state.lock().unwrap() = PlayerState::Idle;
         *self.current_song.lock().unwrap() = None;
         *self.current_time.lock().unwrap() = 0.0;
-        println!("⏹️ Reproducción detenida");
+        info!("Reproducción detenida");
         Ok(())
     }
 
@@ -682,7 +680,7 @@ impl MusicPlayer {
         if self.state() == PlayerState::Playing || self.state() == PlayerState::Paused {
             self.mpv
                 .command("seek", &[&seconds.to_string(), "relative"])?;
-            println!("⏩ Avanzando {} segundos", seconds);
+            info!("Avanzando {} segundos", seconds);
             Ok(())
         } else {
             Err(PlayerError::NoSong)
@@ -694,7 +692,7 @@ impl MusicPlayer {
         if self.state() == PlayerState::Playing || self.state() == PlayerState::Paused {
             self.mpv
                 .command("seek", &[&format!("-{}", seconds), "relative"])?;
-            println!("⏪ Retrocediendo {} segundos", seconds);
+            info!("Retrocediendo {} segundos", seconds);
             Ok(())
         } else {
             Err(PlayerError::NoSong)
@@ -706,7 +704,7 @@ impl MusicPlayer {
         let vol = vol.min(100);
         self.mpv.set_property("volume", vol as i64)?;
         *self.volume.lock().unwrap() = vol;
-        println!("🔊 Volumen: {}%", vol);
+        info!("Volumen: {}%", vol);
         Ok(())
     }
 
@@ -0,0 +1,279 @@
+//! Tipos de la API de plugins (lo que registran y lo que devuelven a la app)
+
+use serde_json::{Value, json};
+use std::path::{Path, PathBuf};
+
+use crate::core::KeyModifiers;
+
+/// Versión de la API expuesta a los plugins (`notnative.api_version`).
+/// Solo se incrementa si un cambio rompe plugins existentes.
+pub const API_VERSION: u32 = 1;
+
+/// Directorio de plugins del usuario (`~/.config/notnative/plugins`)
+pub fn plugins_dir() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("notnative")
+        .join("plugins")
+}
+
+/// Script de un plugin encontrado en el directorio de plugins
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PluginSource {
+    pub name: String,
+    pub path: PathBuf,
+}
+
+/// Busca plugins en `dir`: archivos `nombre.lua` y carpetas `nombre/init.lua`,
+/// ordenados por nombre. Se ignoran los que empiezan por `.` o `_` (desactivados).
+pub fn discover(dir: &Path) -> Vec<PluginSource> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut plugins: Vec<PluginSource> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.path();
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            if file_name.starts_with('.') || file_name.starts_with('_') {
+                return None;
+            }
+
+            if path.is_dir() {
+                let init = path.join("init.lua");
+                init.is_file().then_some(PluginSource {
+                    name: file_name,
+                    path: init,
+                })
+            } else {
+                let name = file_name.strip_suffix(".lua")?;
+                Some(PluginSource {
+                    name: name.to_string(),
+                    path,
+                })
+            }
+        })
+        .collect();
+
+    plugins.sort_by(|a, b| a.name.cmp(&b.name));
+    plugins.dedup_by(|a, b| a.name == b.name);
+    plugins
+}
+
+/// Combinación de teclas de un atajo de plugin ("ctrl+shift+k")
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Accel {
+    pub ctrl: bool,
+    pub alt: bool,
+    pub shift: bool,
+    /// Nombre de tecla de GTK en minúsculas ("k", "f5", "return")
+    pub key: String,
+}
+
+impl Accel {
+    pub fn parse(accel: &str) -> Option<Self> {
+        let mut result = Self {
+            ctrl: false,
+            alt: false,
+            shift: false,
+            key: String::new(),
+        };
+
+        for part in accel.split('+').map(str::trim) {
+            match part.to_lowercase().as_str() {
+                "ctrl" | "control" => result.ctrl = true,
+                "alt" => result.alt = true,
+                "shift" => result.shift = true,
+                "" => return None,
+                key if result.key.is_empty() => result.key = key.to_string(),
+                // Dos teclas que no son modificadores
+                _ => return None,
+            }
+        }
+
+        (!result.key.is_empty()).then_some(result)
+    }
+
+    /// Si la tecla pulsada (nombre de GTK) corresponde al atajo
+    pub fn matches(&self, key: &str, modifiers: KeyModifiers) -> bool {
+        self.ctrl == modifiers.ctrl
+            && self.alt == modifiers.alt
+            && self.shift == modifiers.shift
+            && self.key.eq_ignore_ascii_case(key)
+    }
+}
+
+/// Comando registrado con `notnative.register_command`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PluginCommand {
+    pub plugin: String,
+    pub id: String,
+    pub title: String,
+}
+
+impl PluginCommand {
+    /// Identificador único entre todos los plugins (`plugin.comando`)
+    pub fn qualified_id(&self) -> String {
+        format!("{}.{}", self.plugin, self.id)
+    }
+}
+
+/// Atajo registrado con `notnative.register_keybinding`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PluginKeybinding {
+    pub plugin: String,
+    pub accel: Accel,
+    /// Comando del mismo plugin que ejecuta el atajo
+    pub command: String,
+}
+
+/// Herramienta MCP registrada con `notnative.register_tool`
+#[derive(Debug, Clone, PartialEq)]
+pub struct PluginTool {
+    pub plugin: String,
+    pub name: String,
+    pub description: String,
+    /// JSON Schema de los argumentos
+    pub parameters: Value,
+}
+
+impl PluginTool {
+    /// Definición en el mismo formato que las herramientas integradas (function calling)
+    pub fn definition(&self) -> Value {
+        json!({
+            "type": "function",
+            "function": {
+                "name": self.name,
+                "description": self.description,
+                "parameters": self.parameters,
+            }
+        })
+    }
+}
+
+/// Entrada del sidebar añadida con `notnative.add_sidebar_entry` (abre una nota)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PluginSidebarEntry {
+    pub plugin: String,
+    pub label: String,
+    pub note: String,
+}
+
+/// Todo lo registrado por los plugins cargados
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PluginRegistry {
+    /// Plugins cargados correctamente
+    pub plugins: Vec<String>,
+    pub commands: Vec<PluginCommand>,
+    pub keybindings: Vec<PluginKeybinding>,
+    pub tools: Vec<PluginTool>,
+    pub sidebar_entries: Vec<PluginSidebarEntry>,
+    pub post_processors: usize,
+    /// Funciones suscritas a eventos con `notnative.on`
+    pub event_handlers: usize,
+}
+
+impl PluginRegistry {
+    pub fn is_empty(&self) -> bool {
+        self.plugins.is_empty()
+    }
+
+    /// Comando asociado a la tecla pulsada, si algún plugin la registró
+    pub fn command_for_key(&self, key: &str, modifiers: KeyModifiers) -> Option<&PluginCommand> {
+        let binding = self
+            .keybindings
+            .iter()
+            .find(|binding| binding.accel.matches(key, modifiers))?;
+        self.commands
+            .iter()
+            .find(|command| command.plugin == binding.plugin && command.id == binding.command)
+    }
+
+    pub fn tool(&self, name: &str) -> Option<&PluginTool> {
+        self.tools.iter().find(|tool| tool.name == name)
+    }
+}
+
+/// Efecto de un plugin que la app tiene que reflejar
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PluginEvent {
+    Toast(String),
+    NoteWritten {
+        name: String,
+        created: bool,
+    },
+    /// Cambiaron las entradas del sidebar o los comandos registrados
+    RegistryChanged,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accel_parse_and_match() {
+        let accel = Accel::parse("Ctrl+Shift+K").unwrap();
+        assert!(accel.ctrl && accel.shift && !accel.alt);
+        assert_eq!(accel.key, "k");
+
+        let ctrl_shift = KeyModifiers {
+            ctrl: true,
+            shift: true,
+            ..Default::default()
+        };
+        assert!(accel.matches("K", ctrl_shift));
+        assert!(!accel.matches("k", KeyModifiers::default()));
+
+        assert!(Accel::parse("ctrl+").is_none());
+        assert!(Accel::parse("ctrl+a+b").is_none());
+        assert!(Accel::parse("shift").is_none());
+    }
+
+    #[test]
+    fn test_command_for_key() {
+        let registry = PluginRegistry {
+            plugins: vec!["wc".to_string()],
+            commands: vec![PluginCommand {
+                plugin: "wc".to_string(),
+                id: "count".to_string(),
+                title: "Contar palabras".to_string(),
+            }],
+            keybindings: vec![PluginKeybinding {
+                plugin: "wc".to_string(),
+                accel: Accel::parse("alt+w").unwrap(),
+                command: "count".to_string(),
+            }],
+            ..Default::default()
+        };
+
+        let alt = KeyModifiers {
+            alt: true,
+            ..Default::default()
+        };
+        let command = registry.command_for_key("w", alt).unwrap();
+        assert_eq!(command.qualified_id(), "wc.count");
+        assert!(
+            registry
+                .command_for_key("w", KeyModifiers::default())
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_discover_plugins() {
+        let dir = std::env::temp_dir().join(format!("notnative-plugins-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("tareas")).unwrap();
+        std::fs::create_dir_all(dir.join("sin_init")).unwrap();
+        std::fs::write(dir.join("tareas/init.lua"), "").unwrap();
+        std::fs::write(dir.join("contador.lua"), "").unwrap();
+        std::fs::write(dir.join("_desactivado.lua"), "").unwrap();
+        std::fs::write(dir.join("notas.txt"), "").unwrap();
+
+        let names: Vec<String> = discover(&dir).into_iter().map(|p| p.name).collect();
+        assert_eq!(names, vec!["contador", "tareas"]);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
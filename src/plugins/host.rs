@@ -0,0 +1,217 @@
+//! Hilo propio para los plugins
+//!
+//! Los intérpretes Lua no se pueden compartir entre hilos, así que viven en un hilo
+//! dedicado y el resto de la app (UI, servidor MCP) les habla por un canal a
+//! través de `PluginHost`, que sí se puede clonar y enviar.
+
+use anyhow::{Result, anyhow};
+use serde_json::Value;
+use std::path::Path;
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Arc, Mutex};
+use tracing::{error, info};
+
+use super::api::{PluginEvent, PluginRegistry, discover};
+use super::runtime::PluginRuntime;
+use crate::core::{HookPayload, KeyModifiers, NotesDirectory};
+
+enum Request {
+    RunCommand(String, Sender<Result<()>>),
+    PostProcess(String, Sender<String>),
+    CallTool(String, Value, Sender<Result<Value>>),
+    Dispatch(HookPayload),
+}
+
+/// Receptor de los efectos de los plugins (la app los reenvía como `AppMsg`)
+#[derive(Clone)]
+pub struct PluginEventListener(Arc<dyn Fn(PluginEvent) + Send + Sync>);
+
+impl PluginEventListener {
+    pub fn new(listener: impl Fn(PluginEvent) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(listener))
+    }
+
+    pub fn notify(&self, event: PluginEvent) {
+        (self.0)(event)
+    }
+}
+
+impl std::fmt::Debug for PluginEventListener {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("PluginEventListener")
+    }
+}
+
+/// Acceso a los plugins cargados desde cualquier hilo
+#[derive(Clone, Default)]
+pub struct PluginHost {
+    /// `None` si no hay plugins (no se lanza el hilo)
+    requests: Option<Sender<Request>>,
+    registry: Arc<Mutex<PluginRegistry>>,
+}
+
+impl std::fmt::Debug for PluginHost {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PluginHost")
+            .field("running", &self.requests.is_some())
+            .finish()
+    }
+}
+
+impl PluginHost {
+    /// Carga los plugins de `plugins_dir` en un hilo nuevo. Sin plugins no se
+    /// crea ningún hilo y todas las llamadas son no-ops.
+    pub fn start(
+        plugins_dir: &Path,
+        notes_dir: NotesDirectory,
+        listener: PluginEventListener,
+    ) -> Self {
+        let sources = discover(plugins_dir);
+        if sources.is_empty() {
+            return Self::default();
+        }
+
+        let registry = Arc::new(Mutex::new(PluginRegistry::default()));
+        let (requests, receiver) = mpsc::channel::<Request>();
+        let (ready_tx, ready_rx) = mpsc::channel();
+
+        let shared_registry = registry.clone();
+        let spawned = std::thread::Builder::new()
+            .name("notnative-plugins".to_string())
+            .spawn(move || {
+                let mut runtime = PluginRuntime::new(notes_dir);
+                runtime.load_all(&sources);
+                *shared_registry.lock().unwrap_or_else(|e| e.into_inner()) = runtime.registry();
+                let _ = ready_tx.send(());
+                Self::deliver_events(&runtime, &shared_registry, &listener);
+
+                for request in receiver {
+                    match request {
+                        Request::RunCommand(id, reply) => {
+                            let _ = reply.send(runtime.run_command(&id));
+                        }
+                        Request::PostProcess(markdown, reply) => {
+                            let _ = reply.send(runtime.post_process(&markdown));
+                        }
+                        Request::CallTool(name, args, reply) => {
+                            let _ = reply.send(runtime.call_tool(&name, &args));
+                        }
+                        Request::Dispatch(payload) => runtime.dispatch_event(&payload),
+                    }
+                    Self::deliver_events(&runtime, &shared_registry, &listener);
+                }
+            });
+
+        match spawned {
+            Ok(_) => {
+                // Esperar a la carga para que el registro esté completo desde el inicio
+                let _ = ready_rx.recv();
+                info!(
+                    "Plugins cargados: {:?}",
+                    registry.lock().unwrap_or_else(|e| e.into_inner()).plugins
+                );
+                Self {
+                    requests: Some(requests),
+                    registry,
+                }
+            }
+            Err(e) => {
+                error!("No se pudo iniciar el hilo de plugins: {}", e);
+                Self::default()
+            }
+        }
+    }
+
+    fn deliver_events(
+        runtime: &PluginRuntime,
+        registry: &Mutex<PluginRegistry>,
+        listener: &PluginEventListener,
+    ) {
+        for event in runtime.take_events() {
+            if event == PluginEvent::RegistryChanged {
+                *registry.lock().unwrap_or_else(|e| e.into_inner()) = runtime.registry();
+            }
+            listener.notify(event);
+        }
+    }
+
+    /// Lo registrado por los plugins (comandos, atajos, herramientas, sidebar)
+    pub fn registry(&self) -> PluginRegistry {
+        self.registry
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Comando (`plugin.comando`) asociado a la tecla pulsada, sin clonar el registro
+    pub fn command_for_key(&self, key: &str, modifiers: KeyModifiers) -> Option<String> {
+        self.registry
+            .lock()
+            .ok()?
+            .command_for_key(key, modifiers)
+            .map(|command| command.qualified_id())
+    }
+
+    /// Ejecuta un comando (`plugin.comando`) y espera a que termine
+    pub fn run_command(&self, qualified_id: &str) -> Result<()> {
+        let requests = self
+            .requests
+            .as_ref()
+            .ok_or_else(|| anyhow!("no hay plugins cargados"))?;
+        let (reply, response) = mpsc::channel();
+        requests
+            .send(Request::RunCommand(qualified_id.to_string(), reply))
+            .map_err(|_| anyhow!("el hilo de plugins terminó"))?;
+        response
+            .recv()
+            .map_err(|_| anyhow!("el hilo de plugins terminó"))?
+    }
+
+    /// Aplica los post-procesadores de markdown (sin plugins devuelve el original)
+    pub fn post_process(&self, markdown: &str) -> String {
+        let has_post_processors = self
+            .registry
+            .lock()
+            .is_ok_and(|registry| registry.post_processors > 0);
+        let Some(requests) = self.requests.as_ref().filter(|_| has_post_processors) else {
+            return markdown.to_string();
+        };
+
+        let (reply, response) = mpsc::channel();
+        if requests
+            .send(Request::PostProcess(markdown.to_string(), reply))
+            .is_err()
+        {
+            return markdown.to_string();
+        }
+        response.recv().unwrap_or_else(|_| markdown.to_string())
+    }
+
+    /// Ejecuta una herramienta de un plugin; `None` si ningún plugin la registró
+    pub fn call_tool(&self, name: &str, args: Value) -> Option<Result<Value>> {
+        self.registry().tool(name)?;
+        let requests = self.requests.as_ref()?;
+
+        let (reply, response) = mpsc::channel();
+        let result = requests
+            .send(Request::CallTool(name.to_string(), args, reply))
+            .map_err(|_| anyhow!("el hilo de plugins terminó"))
+            .and_then(|_| {
+                response
+                    .recv()
+                    .map_err(|_| anyhow!("el hilo de plugins terminó"))?
+            });
+        Some(result)
+    }
+
+    /// Entrega un evento a los plugins suscritos sin esperar a que terminen
+    pub fn dispatch_event(&self, payload: HookPayload) {
+        let has_handlers = self
+            .registry
+            .lock()
+            .is_ok_and(|registry| registry.event_handlers > 0);
+        if let Some(requests) = self.requests.as_ref().filter(|_| has_handlers) {
+            let _ = requests.send(Request::Dispatch(payload));
+        }
+    }
+}
@@ -0,0 +1,20 @@
+//! Plugins de usuario en Lua
+//!
+//! Se cargan de `~/.config/notnative/plugins` (`nombre.lua` o `nombre/init.lua`).
+//! Cada plugin corre aislado y solo ve la tabla `notnative`:
+//!
+//! - `read_note(nombre)`, `write_note(nombre, contenido)`, `list_notes()`
+//! - `toast(mensaje)`, `add_sidebar_entry(etiqueta, nota)`
+//! - `register_command(id, titulo, fn)`, `register_keybinding("ctrl+alt+k", id)`
+//! - `register_postprocessor(fn(markdown) -> markdown)` (se aplica al preview)
+//! - `register_tool{ name, description, parameters, handler }` (herramienta MCP)
+
+pub mod api;
+pub mod host;
+pub mod runtime;
+
+pub use api::{
+    API_VERSION, Accel, PluginCommand, PluginEvent, PluginRegistry, PluginSidebarEntry, PluginTool,
+    plugins_dir,
+};
+pub use host::{PluginEventListener, PluginHost};
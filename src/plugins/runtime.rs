@@ -0,0 +1,707 @@
+//! Intérprete Lua aislado de cada plugin
+//!
+//! Cada plugin tiene su propio estado Lua con solo las bibliotecas sin acceso al
+//! sistema (`string`, `table`, `math`, `utf8`, `coroutine`), un límite de memoria
+//! y un límite de tiempo por llamada. Todo acceso a las notas pasa por la tabla
+//! global `notnative`.
+
+use anyhow::{Context, Result, anyhow};
+use mlua::{
+    Function, HookTriggers, Lua, LuaOptions, LuaSerdeExt, RegistryKey, StdLib, Table, Variadic,
+};
+use serde_json::{Value, json};
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+use tracing::{info, warn};
+
+use super::api::{
+    API_VERSION, Accel, PluginCommand, PluginEvent, PluginKeybinding, PluginRegistry,
+    PluginSidebarEntry, PluginSource, PluginTool,
+};
+use crate::core::{HookEvent, HookPayload, NoteStore, NotesDatabase, NotesDirectory};
+
+/// Memoria máxima de cada intérprete
+const MEMORY_LIMIT: usize = 32 * 1024 * 1024;
+/// Tiempo máximo de una llamada a un plugin (carga, comando, post-procesador o herramienta)
+pub const CALL_TIMEOUT: Duration = Duration::from_millis(500);
+/// Cada cuántas instrucciones Lua se comprueba el tiempo
+const HOOK_INTERVAL: u32 = 10_000;
+
+/// Lo que registra un plugin, con las funciones Lua asociadas
+#[derive(Default)]
+struct Registrations {
+    commands: Vec<(PluginCommand, RegistryKey)>,
+    keybindings: Vec<PluginKeybinding>,
+    post_processors: Vec<RegistryKey>,
+    tools: Vec<(PluginTool, RegistryKey)>,
+    sidebar_entries: Vec<PluginSidebarEntry>,
+    event_handlers: Vec<(HookEvent, RegistryKey)>,
+}
+
+struct LoadedPlugin {
+    name: String,
+    lua: Lua,
+    registrations: Rc<RefCell<Registrations>>,
+    deadline: Rc<Cell<Option<Instant>>>,
+}
+
+impl LoadedPlugin {
+    /// Ejecuta `f` con el límite de tiempo activo
+    fn guarded<T>(&self, f: impl FnOnce(&Lua) -> mlua::Result<T>) -> mlua::Result<T> {
+        self.deadline.set(Some(Instant::now() + CALL_TIMEOUT));
+        let result = f(&self.lua);
+        self.deadline.set(None);
+        result
+    }
+}
+
+/// Plugins cargados y los efectos pendientes de entregar a la app
+pub struct PluginRuntime {
+    notes_dir: NotesDirectory,
+    plugins: Vec<LoadedPlugin>,
+    events: Rc<RefCell<Vec<PluginEvent>>>,
+}
+
+impl PluginRuntime {
+    pub fn new(notes_dir: NotesDirectory) -> Self {
+        Self {
+            notes_dir,
+            plugins: Vec::new(),
+            events: Rc::new(RefCell::new(Vec::new())),
+        }
+    }
+
+    /// Carga todos los plugins; los que fallan se registran en el log y se omiten
+    pub fn load_all(&mut self, sources: &[PluginSource]) {
+        for source in sources {
+            let loaded = std::fs::read_to_string(&source.path)
+                .with_context(|| format!("No se pudo leer {}", source.path.display()))
+                .and_then(|code| self.load(&source.name, &code));
+            match loaded {
+                Ok(()) => info!("Plugin cargado: {}", source.name),
+                Err(e) => warn!("Plugin '{}' no cargado: {:#}", source.name, e),
+            }
+        }
+    }
+
+    /// Carga un plugin a partir de su código
+    pub fn load(&mut self, name: &str, code: &str) -> Result<()> {
+        if self.plugins.iter().any(|plugin| plugin.name == name) {
+            return Err(anyhow!("ya hay un plugin cargado con el nombre '{}'", name));
+        }
+
+        let plugin = self.create_sandbox(name)?;
+        plugin.guarded(|lua| lua.load(code).set_name(name).exec())?;
+        self.plugins.push(plugin);
+        Ok(())
+    }
+
+    /// Snapshot de lo registrado por todos los plugins
+    pub fn registry(&self) -> PluginRegistry {
+        let mut registry = PluginRegistry::default();
+        for plugin in &self.plugins {
+            let registrations = plugin.registrations.borrow();
+            registry.plugins.push(plugin.name.clone());
+            registry.commands.extend(
+                registrations
+                    .commands
+                    .iter()
+                    .map(|(command, _)| command.clone()),
+            );
+            registry
+                .keybindings
+                .extend(registrations.keybindings.iter().cloned());
+            registry
+                .tools
+                .extend(registrations.tools.iter().map(|(tool, _)| tool.clone()));
+            registry
+                .sidebar_entries
+                .extend(registrations.sidebar_entries.iter().cloned());
+            registry.post_processors += registrations.post_processors.len();
+            registry.event_handlers += registrations.event_handlers.len();
+        }
+        registry
+    }
+
+    /// Ejecuta un comando por su id cualificado (`plugin.comando`)
+    pub fn run_command(&self, qualified_id: &str) -> Result<()> {
+        let (plugin_name, id) = qualified_id
+            .split_once('.')
+            .ok_or_else(|| anyhow!("comando no válido: {}", qualified_id))?;
+        let plugin = self
+            .plugins
+            .iter()
+            .find(|plugin| plugin.name == plugin_name)
+            .ok_or_else(|| anyhow!("plugin no cargado: {}", plugin_name))?;
+
+        plugin.guarded(|lua| {
+            // Soltar el préstamo antes de llamar: el comando puede registrar cosas
+            let handler: Function = {
+                let registrations = plugin.registrations.borrow();
+                let (_, key) = registrations
+                    .commands
+                    .iter()
+                    .find(|(command, _)| command.id == id)
+                    .ok_or_else(|| mlua::Error::runtime(format!("comando desconocido: {}", id)))?;
+                lua.registry_value(key)?
+            };
+            handler.call::<_, ()>(())
+        })?;
+        Ok(())
+    }
+
+    /// Pasa el markdown por los post-procesadores de todos los plugins, en orden.
+    /// Un post-procesador que falla o no devuelve texto deja el markdown como estaba.
+    pub fn post_process(&self, markdown: &str) -> String {
+        let mut markdown = markdown.to_string();
+        for plugin in &self.plugins {
+            let count = plugin.registrations.borrow().post_processors.len();
+            for index in 0..count {
+                let result = plugin.guarded(|lua| {
+                    let handler: Function = {
+                        let registrations = plugin.registrations.borrow();
+                        lua.registry_value(&registrations.post_processors[index])?
+                    };
+                    handler.call::<_, Option<String>>(markdown.as_str())
+                });
+                match result {
+                    Ok(Some(processed)) => markdown = processed,
+                    Ok(None) => {}
+                    Err(e) => warn!("Post-procesador de '{}' falló: {}", plugin.name, e),
+                }
+            }
+        }
+        markdown
+    }
+
+    /// Ejecuta una herramienta MCP registrada por un plugin
+    pub fn call_tool(&self, name: &str, args: &Value) -> Result<Value> {
+        let plugin = self
+            .plugins
+            .iter()
+            .find(|plugin| {
+                plugin
+                    .registrations
+                    .borrow()
+                    .tools
+                    .iter()
+                    .any(|(tool, _)| tool.name == name)
+            })
+            .ok_or_else(|| anyhow!("herramienta desconocida: {}", name))?;
+
+        let result = plugin.guarded(|lua| {
+            let handler: Function = {
+                let registrations = plugin.registrations.borrow();
+                let (_, key) = registrations
+                    .tools
+                    .iter()
+                    .find(|(tool, _)| tool.name == name)
+                    .expect("herramienta encontrada arriba");
+                lua.registry_value(key)?
+            };
+            let result: mlua::Value = handler.call(lua.to_value(args)?)?;
+            lua.from_value::<Value>(result)
+        })?;
+        Ok(result)
+    }
+
+    /// Avisa a los plugins suscritos con `notnative.on` de un evento de la app
+    pub fn dispatch_event(&self, payload: &HookPayload) {
+        let Some(event) = payload.event else {
+            return;
+        };
+        let data = payload.to_json();
+        for plugin in &self.plugins {
+            let count = plugin.registrations.borrow().event_handlers.len();
+            for index in 0..count {
+                let result = plugin.guarded(|lua| {
+                    let handler: Function = {
+                        let registrations = plugin.registrations.borrow();
+                        let (handler_event, key) = &registrations.event_handlers[index];
+                        if *handler_event != event {
+                            return Ok(());
+                        }
+                        lua.registry_value(key)?
+                    };
+                    handler.call::<_, ()>(lua.to_value(&data)?)
+                });
+                if let Err(e) = result {
+                    warn!(
+                        "Manejador de '{}' en '{}' falló: {}",
+                        event.as_str(),
+                        plugin.name,
+                        e
+                    );
+                }
+            }
+        }
+    }
+
+    /// Efectos producidos desde la última llamada
+    pub fn take_events(&self) -> Vec<PluginEvent> {
+        std::mem::take(&mut *self.events.borrow_mut())
+    }
+
+    fn create_sandbox(&self, name: &str) -> Result<LoadedPlugin> {
+        let lua = Lua::new_with(
+            StdLib::TABLE | StdLib::STRING | StdLib::MATH | StdLib::UTF8 | StdLib::COROUTINE,
+            LuaOptions::default(),
+        )?;
+        lua.set_memory_limit(MEMORY_LIMIT)?;
+
+        // La biblioteca base siempre se carga: quitar lo que lee archivos o código externo
+        let globals = lua.globals();
+        for unsafe_global in ["dofile", "loadfile", "load", "require", "collectgarbage"] {
+            globals.set(unsafe_global, mlua::Value::Nil)?;
+        }
+
+        let deadline: Rc<Cell<Option<Instant>>> = Rc::new(Cell::new(None));
+        let hook_deadline = deadline.clone();
+        lua.set_hook(
+            HookTriggers::new().every_nth_instruction(HOOK_INTERVAL),
+            move |_, _| match hook_deadline.get() {
+                Some(limit) if Instant::now() > limit => Err(mlua::Error::runtime(
+                    "el plugin superó el tiempo máximo de ejecución",
+                )),
+                _ => Ok(()),
+            },
+        );
+
+        let plugin_name = name.to_string();
+        globals.set(
+            "print",
+            lua.create_function(move |_, values: Variadic<mlua::Value>| {
+                let parts = values
+                    .iter()
+                    .map(|value| value.to_string())
+                    .collect::<mlua::Result<Vec<_>>>()?;
+                info!("[plugin {}] {}", plugin_name, parts.join("\t"));
+                Ok(())
+            })?,
+        )?;
+
+        let registrations = Rc::new(RefCell::new(Registrations::default()));
+        let api = self.create_api(&lua, name, &registrations)?;
+        globals.set("notnative", api)?;
+        drop(globals);
+
+        Ok(LoadedPlugin {
+            name: name.to_string(),
+            lua,
+            registrations,
+            deadline,
+        })
+    }
+
+    /// Tabla `notnative`: la única superficie que ven los plugins
+    fn create_api<'lua>(
+        &self,
+        lua: &'lua Lua,
+        plugin: &str,
+        registrations: &Rc<RefCell<Registrations>>,
+    ) -> mlua::Result<Table<'lua>> {
+        let api = lua.create_table()?;
+        api.set("api_version", API_VERSION)?;
+
+        // notnative.read_note(nombre) -> contenido | nil
+        let notes_dir = self.notes_dir.clone();
+        api.set(
+            "read_note",
+            lua.create_function(move |_, name: String| {
+                if !is_valid_note_name(&name) {
+                    return Ok(None);
+                }
+                match notes_dir.find_note(&name).map_err(mlua::Error::external)? {
+                    Some(note) => note.read().map(Some).map_err(mlua::Error::external),
+                    None => Ok(None),
+                }
+            })?,
+        )?;
+
+        // notnative.write_note(nombre, contenido): crea la nota si no existe
+        let notes_dir = self.notes_dir.clone();
+        let events = self.events.clone();
+        api.set(
+            "write_note",
+            lua.create_function(move |_, (name, content): (String, String)| {
+                if !is_valid_note_name(&name) {
+                    return Err(mlua::Error::runtime(format!(
+                        "nombre de nota no válido: {}",
+                        name
+                    )));
+                }
+
+                // Ruta exacta: find_note haría coincidir "otra/hoy" con "diario/hoy"
+                let path = notes_dir.root().join(format!("{}.md", name));
+                let created = if path.is_file() {
+                    std::fs::write(&path, &content).map_err(mlua::Error::external)?;
+                    false
+                } else {
+                    // La nota nueva entra ya en el índice (o no se crea)
+                    let (folder, file) = match name.rsplit_once('/') {
+                        Some((folder, file)) => (Some(folder), file),
+                        None => (None, name.as_str()),
+                    };
+                    let db =
+                        NotesDatabase::new(&notes_dir.db_path()).map_err(mlua::Error::external)?;
+                    NoteStore::new(&notes_dir, &db)
+                        .create(folder, file, &content)
+                        .map_err(mlua::Error::external)?;
+                    true
+                };
+                events
+                    .borrow_mut()
+                    .push(PluginEvent::NoteWritten { name, created });
+                Ok(())
+            })?,
+        )?;
+
+        // notnative.list_notes() -> { nombre, ... }
+        let notes_dir = self.notes_dir.clone();
+        api.set(
+            "list_notes",
+            lua.create_function(move |_, ()| {
+                // Rutas relativas sin extensión ("carpeta/nota"), como las acepta read_note
+                let notes = notes_dir.list_notes().map_err(mlua::Error::external)?;
+                Ok(notes
+                    .iter()
+                    .filter_map(|note| {
+                        let relative = note.path().strip_prefix(notes_dir.root()).ok()?;
+                        let name = relative.with_extension("").to_string_lossy().to_string();
+                        is_valid_note_name(&name).then_some(name)
+                    })
+                    .collect::<Vec<_>>())
+            })?,
+        )?;
+
+        // notnative.toast(mensaje)
+        let events = self.events.clone();
+        api.set(
+            "toast",
+            lua.create_function(move |_, message: String| {
+                events.borrow_mut().push(PluginEvent::Toast(message));
+                Ok(())
+            })?,
+        )?;
+
+        // notnative.add_sidebar_entry(etiqueta, nota)
+        let (regs, events, plugin_name) = (
+            registrations.clone(),
+            self.events.clone(),
+            plugin.to_string(),
+        );
+        api.set(
+            "add_sidebar_entry",
+            lua.create_function(move |_, (label, note): (String, String)| {
+                let mut regs = regs.borrow_mut();
+                regs.sidebar_entries.retain(|entry| entry.label != label);
+                regs.sidebar_entries.push(PluginSidebarEntry {
+                    plugin: plugin_name.clone(),
+                    label,
+                    note,
+                });
+                events.borrow_mut().push(PluginEvent::RegistryChanged);
+                Ok(())
+            })?,
+        )?;
+
+        // notnative.register_command(id, titulo, funcion)
+        let (regs, plugin_name) = (registrations.clone(), plugin.to_string());
+        api.set(
+            "register_command",
+            lua.create_function(
+                move |lua, (id, title, handler): (String, String, Function)| {
+                    if id.is_empty() || id.contains('.') {
+                        return Err(mlua::Error::runtime(format!(
+                            "id de comando no válido: '{}'",
+                            id
+                        )));
+                    }
+                    let key = lua.create_registry_value(handler)?;
+                    let mut regs = regs.borrow_mut();
+                    regs.commands.retain(|(command, _)| command.id != id);
+                    regs.commands.push((
+                        PluginCommand {
+                            plugin: plugin_name.clone(),
+                            id,
+                            title,
+                        },
+                        key,
+                    ));
+                    Ok(())
+                },
+            )?,
+        )?;
+
+        // notnative.register_keybinding("ctrl+shift+k", id_comando)
+        let (regs, plugin_name) = (registrations.clone(), plugin.to_string());
+        api.set(
+            "register_keybinding",
+            lua.create_function(move |_, (accel, command): (String, String)| {
+                let parsed = Accel::parse(&accel)
+                    .ok_or_else(|| mlua::Error::runtime(format!("atajo no válido: '{}'", accel)))?;
+                regs.borrow_mut().keybindings.push(PluginKeybinding {
+                    plugin: plugin_name.clone(),
+                    accel: parsed,
+                    command,
+                });
+                Ok(())
+            })?,
+        )?;
+
+        // notnative.register_postprocessor(function(markdown) return markdown end)
+        let regs = registrations.clone();
+        api.set(
+            "register_postprocessor",
+            lua.create_function(move |lua, handler: Function| {
+                let key = lua.create_registry_value(handler)?;
+                regs.borrow_mut().post_processors.push(key);
+                Ok(())
+            })?,
+        )?;
+
+        // notnative.on("note_saved", function(event) ... end)
+        let regs = registrations.clone();
+        api.set(
+            "on",
+            lua.create_function(move |lua, (event, handler): (String, Function)| {
+                let event = HookEvent::parse(&event).ok_or_else(|| {
+                    mlua::Error::runtime(format!("evento desconocido: {}", event))
+                })?;
+                let key = lua.create_registry_value(handler)?;
+                regs.borrow_mut().event_handlers.push((event, key));
+                Ok(())
+            })?,
+        )?;
+
+        // notnative.register_tool{ name, description, parameters, handler }
+        let (regs, plugin_name) = (registrations.clone(), plugin.to_string());
+        api.set(
+            "register_tool",
+            lua.create_function(move |lua, spec: Table| {
+                let name: String = spec.get("name")?;
+                if crate::mcp::get_all_tool_definitions()
+                    .iter()
+                    .any(|tool| tool.name == name)
+                {
+                    return Err(mlua::Error::runtime(format!(
+                        "'{}' ya es una herramienta integrada",
+                        name
+                    )));
+                }
+
+                let description: Option<String> = spec.get("description")?;
+                let parameters = match spec.get::<_, mlua::Value>("parameters")? {
+                    mlua::Value::Nil => json!({ "type": "object", "properties": {} }),
+                    value => lua.from_value::<Value>(value)?,
+                };
+                let handler: Function = spec.get("handler")?;
+                let key = lua.create_registry_value(handler)?;
+
+                let mut regs = regs.borrow_mut();
+                regs.tools.retain(|(tool, _)| tool.name != name);
+                regs.tools.push((
+                    PluginTool {
+                        plugin: plugin_name.clone(),
+                        name,
+                        description: description.unwrap_or_default(),
+                        parameters,
+                    },
+                    key,
+                ));
+                Ok(())
+            })?,
+        )?;
+
+        Ok(api)
+    }
+}
+
+/// Nombre de nota relativo al directorio de notas, sin salir de él ni entrar
+/// en carpetas ocultas (papelera, historial)
+fn is_valid_note_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name.contains('\\')
+        && name
+            .split('/')
+            .all(|part| !part.is_empty() && !part.starts_with('.'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Runtime sobre un vault temporal; devuelve la carpeta de notas. La base
+    /// de datos queda junto a ella, en `base`.
+    fn runtime(name: &str) -> (PluginRuntime, std::path::PathBuf) {
+        let base = std::env::temp_dir().join(format!(
+            "notnative-plugin-runtime-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&base);
+        let notes_dir = NotesDirectory::new(base.join("notes")).unwrap();
+        (PluginRuntime::new(notes_dir), base.join("notes"))
+    }
+
+    #[test]
+    fn test_note_names_stay_inside_notes_dir() {
+        assert!(is_valid_note_name("diario"));
+        assert!(is_valid_note_name("proyectos/ideas"));
+        assert!(!is_valid_note_name("../fuera"));
+        assert!(!is_valid_note_name("/etc/passwd"));
+        assert!(!is_valid_note_name(".trash/borrada"));
+        assert!(!is_valid_note_name(""));
+    }
+
+    #[test]
+    fn test_sandbox_has_no_system_access() {
+        let (mut runtime, root) = runtime("sandbox");
+        for code in [
+            "io.open('/etc/passwd')",
+            "os.execute('true')",
+            "require('os')",
+            "dofile('/tmp/x.lua')",
+        ] {
+            assert!(runtime.load("malo", code).is_err(), "{}", code);
+        }
+        let _ = std::fs::remove_dir_all(root.parent().unwrap());
+    }
+
+    #[test]
+    fn test_infinite_loop_is_interrupted() {
+        let (mut runtime, root) = runtime("timeout");
+        let started = Instant::now();
+        assert!(runtime.load("bucle", "while true do end").is_err());
+        assert!(started.elapsed() < CALL_TIMEOUT * 4);
+        let _ = std::fs::remove_dir_all(root.parent().unwrap());
+    }
+
+    #[test]
+    fn test_commands_notes_and_events() {
+        let (mut runtime, root) = runtime("commands");
+        runtime
+            .load(
+                "diario",
+                r##"
+                notnative.register_command("hoy", "Nota de hoy", function()
+                    notnative.write_note("diario/hoy", "# Hoy")
+                    notnative.toast("creada: " .. notnative.read_note("diario/hoy"))
+                end)
+                notnative.register_keybinding("alt+d", "hoy")
+                notnative.add_sidebar_entry("Hoy", "diario/hoy")
+                "##,
+            )
+            .unwrap();
+
+        let registry = runtime.registry();
+        assert_eq!(registry.commands[0].qualified_id(), "diario.hoy");
+        assert_eq!(registry.keybindings.len(), 1);
+        assert_eq!(registry.sidebar_entries[0].note, "diario/hoy");
+
+        runtime.take_events();
+        runtime.run_command("diario.hoy").unwrap();
+        assert_eq!(
+            runtime.take_events(),
+            vec![
+                PluginEvent::NoteWritten {
+                    name: "diario/hoy".to_string(),
+                    created: true,
+                },
+                PluginEvent::Toast("creada: # Hoy".to_string()),
+            ]
+        );
+        assert!(root.join("diario/hoy.md").exists());
+        assert!(runtime.run_command("diario.otro").is_err());
+
+        let _ = std::fs::remove_dir_all(root.parent().unwrap());
+    }
+
+    #[test]
+    fn test_post_processors_and_tools() {
+        let (mut runtime, root) = runtime("tools");
+        runtime
+            .load(
+                "mayus",
+                r##"
+                notnative.register_postprocessor(function(md) return md:upper() end)
+                notnative.register_postprocessor(function(md) error("roto") end)
+                notnative.register_tool{
+                    name = "sumar",
+                    description = "Suma dos números",
+                    parameters = { type = "object", properties = { a = { type = "number" } } },
+                    handler = function(args) return { total = args.a + args.b } end,
+                }
+                "##,
+            )
+            .unwrap();
+
+        // El post-procesador que falla no rompe el preview
+        assert_eq!(runtime.post_process("hola"), "HOLA");
+
+        let result = runtime
+            .call_tool("sumar", &json!({ "a": 2, "b": 3 }))
+            .unwrap();
+        assert_eq!(result["total"], 5);
+        assert_eq!(
+            runtime.registry().tool("sumar").unwrap().definition()["function"]["name"],
+            "sumar"
+        );
+
+        // No se pueden reemplazar herramientas integradas
+        assert!(
+            runtime
+                .load(
+                    "pirata",
+                    "notnative.register_tool{ name = 'create_note', handler = function() end }",
+                )
+                .is_err()
+        );
+
+        let _ = std::fs::remove_dir_all(root.parent().unwrap());
+    }
+
+    #[test]
+    fn test_event_handlers() {
+        let (mut runtime, root) = runtime("eventos");
+        runtime
+            .load(
+                "blog",
+                r##"
+                notnative.on("note_saved", function(event)
+                    notnative.toast("guardada: " .. event.note_name .. " " .. event.tags[1])
+                end)
+                notnative.on("reminder_fired", function(event)
+                    notnative.toast("aviso: " .. event.reminder_title)
+                end)
+                "##,
+            )
+            .unwrap();
+        assert!(
+            runtime
+                .load("malo", r#"notnative.on("al_abrir", print)"#)
+                .is_err()
+        );
+        assert_eq!(runtime.registry().event_handlers, 2);
+
+        runtime.take_events();
+        runtime.dispatch_event(&HookPayload::note(
+            HookEvent::NoteSaved,
+            "post",
+            &root.join("post.md"),
+            None,
+            Some("Texto #publicar"),
+        ));
+        runtime.dispatch_event(&HookPayload::reminder(Some(1), "Llamar", None));
+        assert_eq!(
+            runtime.take_events(),
+            vec![
+                PluginEvent::Toast("guardada: post publicar".to_string()),
+                PluginEvent::Toast("aviso: Llamar".to_string()),
+            ]
+        );
+
+        let _ = std::fs::remove_dir_all(root.parent().unwrap());
+    }
+}
@@ -9,19 +9,31 @@
 // - Abrir quick notes existentes
 // - Auto-guardado
 // - Diseño minimalista
+// - Vista previa ligera (marcado Pango, sin WebView)
+// - Enviar el texto al diario de hoy, a otra nota o a una nota nueva
+// - Autocompletado de @menciones y #tags
 
 use gtk::prelude::*;
 use relm4::{RelmWidgetExt, gtk};
 use std::cell::RefCell;
 use std::path::PathBuf;
 use std::rc::Rc;
-
-use crate::core::NotesDirectory;
+use tracing::{error, info};
+
+use crate::app::AppMsg;
+use crate::core::quick_note::{completion_prefix, fuzzy_score};
+use crate::core::{
+    NoteStore, NotesDatabase, NotesDirectory, QuickNoteTarget, WindowGeometry, markdown_to_pango,
+    quick_note_body,
+};
 use crate::i18n::I18n;
 
 /// Nombre de la carpeta especial para quick notes
 const QUICK_NOTES_FOLDER: &str = "quick-notes";
 
+/// Sugerencias como máximo en el autocompletado y en la búsqueda de "Enviar a"
+const MAX_SUGGESTIONS: usize = 8;
+
 /// Estructura que representa una Quick Note
 #[derive(Debug, Clone)]
 pub struct QuickNote {
@@ -44,9 +56,9 @@ impl QuickNoteManager {
         // Crear carpeta de quick notes si no existe
         if !quick_notes_path.exists() {
             if let Err(e) = std::fs::create_dir_all(&quick_notes_path) {
-                eprintln!("⚠️ Error creando carpeta de quick notes: {}", e);
+                error!("Error creando carpeta de quick notes: {}", e);
             } else {
-                println!("📁 Carpeta de quick notes creada: {:?}", quick_notes_path);
+                info!("Carpeta de quick notes creada: {:?}", quick_notes_path);
             }
         }
 
@@ -110,19 +122,20 @@ impl QuickNoteManager {
     pub fn create_quick_note(&self) -> Result<QuickNote, String> {
         let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S").to_string();
         let name = format!("qn_{}", timestamp);
-        let path = self.quick_notes_path.join(format!("{}.md", name));
 
         let initial_content = format!(
             "# Quick Note\n\n> Creada: {}\n\n",
             chrono::Local::now().format("%d/%m/%Y %H:%M")
         );
 
-        std::fs::write(&path, &initial_content)
+        // Archivo y fila en la BD juntos
+        let note = self
+            .with_store(|store| store.create(Some(QUICK_NOTES_FOLDER), &name, &initial_content))
             .map_err(|e| format!("Error creando quick note: {}", e))?;
 
         Ok(QuickNote {
             name,
-            path,
+            path: note.path().to_path_buf(),
             preview: initial_content.chars().take(80).collect(),
             modified: chrono::Local::now().format("%d/%m %H:%M").to_string(),
         })
@@ -148,11 +161,44 @@ impl QuickNoteManager {
 
     /// Renombra una quick note
     pub fn rename_quick_note(&self, old_name: &str, new_name: &str) -> Result<(), String> {
-        let old_path = self.quick_notes_path.join(format!("{}.md", old_name));
-        let new_path = self.quick_notes_path.join(format!("{}.md", new_name));
-        std::fs::rename(&old_path, &new_path)
+        let old_name = format!("{}/{}", QUICK_NOTES_FOLDER, old_name);
+        self.with_store(|store| store.rename(&old_name, new_name))
+            .map(|_| ())
             .map_err(|e| format!("Error renombrando quick note: {}", e))
     }
+
+    /// Ejecuta `f` con un [`NoteStore`] sobre el vault y su base de datos
+    fn with_store<T>(&self, f: impl FnOnce(&NoteStore) -> anyhow::Result<T>) -> anyhow::Result<T> {
+        let db = NotesDatabase::new(&self.notes_dir.db_path())?;
+        f(&NoteStore::new(&self.notes_dir, &db))
+    }
+
+    /// Notas del vault (sin las quick notes) que encajan con `query`, de más
+    /// a menos parecidas
+    pub fn search_notes(&self, query: &str) -> Vec<String> {
+        let quick_prefix = format!("{}/", QUICK_NOTES_FOLDER);
+        let mut matches: Vec<(i32, String)> = self
+            .notes_dir
+            .list_notes()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|note| note.name().to_string())
+            .filter(|name| !name.starts_with(&quick_prefix) && !name.starts_with(".trash/"))
+            .filter_map(|name| {
+                // Se compara con el nombre sin carpeta y, si no, con la ruta
+                let base = name.rsplit('/').next().unwrap_or(&name);
+                fuzzy_score(base, query)
+                    .or_else(|| fuzzy_score(&name, query))
+                    .map(|score| (score, name))
+            })
+            .collect();
+        matches.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+        matches
+            .into_iter()
+            .take(MAX_SUGGESTIONS)
+            .map(|(_, name)| name)
+            .collect()
+    }
 }
 
 /// Ventana flotante de Quick Notes
@@ -164,9 +210,15 @@ pub struct QuickNoteWindow {
     notes_list: gtk::ListBox,
     content_stack: gtk::Stack,
     title_label: gtk::Label,
+    back_button: gtk::Button,
+    new_button: gtk::Button,
+    preview_button: gtk::ToggleButton,
+    save_indicator: gtk::Label,
     #[allow(dead_code)]
     i18n: Rc<RefCell<I18n>>,
     has_unsaved_changes: Rc<RefCell<bool>>,
+    /// Tags del vault con sus usos, para el autocompletado de `#`
+    known_tags: Rc<RefCell<Vec<(String, usize)>>>,
 }
 
 // Implementación manual de Debug porque gtk::Window y otros widgets no implementan Debug
@@ -184,10 +236,13 @@ impl QuickNoteWindow {
         _parent: &impl IsA<gtk::Window>,
         notes_dir: NotesDirectory,
         i18n: Rc<RefCell<I18n>>,
+        sender: relm4::Sender<AppMsg>,
+        geometry: WindowGeometry,
     ) -> Self {
         let manager = Rc::new(RefCell::new(QuickNoteManager::new(notes_dir)));
         let current_note: Rc<RefCell<Option<String>>> = Rc::new(RefCell::new(None));
         let has_unsaved_changes = Rc::new(RefCell::new(false));
+        let known_tags: Rc<RefCell<Vec<(String, usize)>>> = Rc::new(RefCell::new(Vec::new()));
 
         // Obtener traducciones
         let t_title = i18n.borrow().t("quick_notes_title");
@@ -199,13 +254,16 @@ impl QuickNoteWindow {
         let t_press_to_create = i18n.borrow().t("quick_note_press_to_create");
         let t_saved = i18n.borrow().t("quick_note_saved");
         let t_shortcut_hint = i18n.borrow().t("quick_note_shortcut_hint");
+        let t_preview = i18n.borrow().t("quick_note_preview");
+        let t_send_to = i18n.borrow().t("quick_note_send_to");
+        let t_send_journal = i18n.borrow().t("quick_note_send_journal");
+        let t_send_new = i18n.borrow().t("quick_note_send_new");
+        let t_send_search = i18n.borrow().t("quick_note_send_search");
 
         // Crear ventana flotante
         // Usamos "Quick Note" (singular) para que coincida con las reglas de Hyprland
         let window = gtk::Window::builder()
             .title("Quick Note")
-            .default_width(450)
-            .default_height(400)
             .modal(false)
             .resizable(true)
             .decorated(true)
@@ -217,6 +275,15 @@ impl QuickNoteWindow {
         // Establecer tamaño máximo y mínimo para evitar que se maximice
         window.set_size_request(400, 350);
 
+        // Mismo tamaño que la última vez, que se guarda al ocultarla
+        crate::window_state::restore(&window, &geometry);
+        let sender_for_hide = sender.clone();
+        window.connect_hide(move |win| {
+            let mut geometry = crate::window_state::capture(win);
+            geometry.maximized = false;
+            sender_for_hide.emit(AppMsg::SaveQuickNoteGeometry(geometry));
+        });
+
         // CSS class para estilos personalizados
         window.add_css_class("quick-note-window");
 
@@ -338,7 +405,30 @@ impl QuickNoteWindow {
 
         let text_buffer = text_view.buffer();
         editor_scroll.set_child(Some(&text_view));
-        editor_page.append(&editor_scroll);
+
+        // Vista previa: el markdown como marcado Pango en una etiqueta
+        let preview_scroll = gtk::ScrolledWindow::new();
+        preview_scroll.set_vexpand(true);
+        preview_scroll.set_policy(gtk::PolicyType::Never, gtk::PolicyType::Automatic);
+
+        let preview_label = gtk::Label::new(None);
+        preview_label.set_wrap(true);
+        preview_label.set_wrap_mode(gtk::pango::WrapMode::WordChar);
+        preview_label.set_xalign(0.0);
+        preview_label.set_valign(gtk::Align::Start);
+        preview_label.set_selectable(true);
+        preview_label.set_margin_start(16);
+        preview_label.set_margin_end(16);
+        preview_label.set_margin_top(12);
+        preview_label.set_margin_bottom(12);
+        preview_label.add_css_class("quick-note-preview");
+        preview_scroll.set_child(Some(&preview_label));
+
+        let editor_stack = gtk::Stack::new();
+        editor_stack.set_transition_type(gtk::StackTransitionType::Crossfade);
+        editor_stack.add_named(&editor_scroll, Some("edit"));
+        editor_stack.add_named(&preview_scroll, Some("preview"));
+        editor_page.append(&editor_stack);
 
         // Barra de estado del editor
         let status_bar = gtk::Box::new(gtk::Orientation::Horizontal, 8);
@@ -359,6 +449,54 @@ impl QuickNoteWindow {
         shortcut_hint.set_opacity(0.7);
         status_bar.append(&shortcut_hint);
 
+        let preview_button = gtk::ToggleButton::new();
+        preview_button.set_icon_name("view-reveal-symbolic");
+        preview_button.set_tooltip_text(Some(&t_preview));
+        preview_button.add_css_class("flat");
+        preview_button.add_css_class("circular");
+        status_bar.append(&preview_button);
+
+        // Enviar a: diario de hoy, nota nueva o una nota buscada
+        let send_box = gtk::Box::new(gtk::Orientation::Vertical, 6);
+        send_box.set_margin_all(8);
+        send_box.set_width_request(260);
+
+        let journal_button = gtk::Button::with_label(&t_send_journal);
+        journal_button.add_css_class("flat");
+        send_box.append(&journal_button);
+
+        let new_note_button = gtk::Button::with_label(&t_send_new);
+        new_note_button.add_css_class("flat");
+        send_box.append(&new_note_button);
+
+        send_box.append(&gtk::Separator::new(gtk::Orientation::Horizontal));
+
+        let send_search = gtk::SearchEntry::new();
+        send_search.set_placeholder_text(Some(&t_send_search));
+        send_box.append(&send_search);
+
+        let send_results = gtk::ListBox::new();
+        send_results.set_selection_mode(gtk::SelectionMode::Single);
+        send_results.add_css_class("quick-notes-list");
+        let send_results_scroll = gtk::ScrolledWindow::new();
+        send_results_scroll.set_policy(gtk::PolicyType::Never, gtk::PolicyType::Automatic);
+        send_results_scroll.set_max_content_height(220);
+        send_results_scroll.set_propagate_natural_height(true);
+        send_results_scroll.set_child(Some(&send_results));
+        send_box.append(&send_results_scroll);
+
+        let send_popover = gtk::Popover::new();
+        send_popover.set_child(Some(&send_box));
+
+        let send_button = gtk::MenuButton::new();
+        send_button.set_icon_name("mail-send-symbolic");
+        send_button.set_tooltip_text(Some(&t_send_to));
+        send_button.add_css_class("flat");
+        send_button.add_css_class("circular");
+        send_button.set_direction(gtk::ArrowType::Up);
+        send_button.set_popover(Some(&send_popover));
+        status_bar.append(&send_button);
+
         editor_page.append(&status_bar);
 
         content_stack.add_named(&editor_page, Some("editor"));
@@ -375,6 +513,186 @@ impl QuickNoteWindow {
             window_clone.set_visible(false);
         });
 
+        // Vista previa (se rehace cada vez que se muestra)
+        let text_buffer_for_preview = text_buffer.clone();
+        let text_view_for_preview = text_view.clone();
+        preview_button.connect_toggled(move |button| {
+            if button.is_active() {
+                let start = text_buffer_for_preview.start_iter();
+                let end = text_buffer_for_preview.end_iter();
+                let content = text_buffer_for_preview.text(&start, &end, false);
+                preview_label.set_markup(&markdown_to_pango(&content));
+                editor_stack.set_visible_child_name("preview");
+            } else {
+                editor_stack.set_visible_child_name("edit");
+                text_view_for_preview.grab_focus();
+            }
+        });
+
+        // Enviar el texto (sin la cabecera automática); la app borra la quick
+        // note cuando el texto ya está en su destino
+        let send: Rc<dyn Fn(QuickNoteTarget)> = {
+            let manager = manager.clone();
+            let current_note = current_note.clone();
+            let text_buffer = text_buffer.clone();
+            let has_unsaved_changes = has_unsaved_changes.clone();
+            let save_indicator = save_indicator.clone();
+            let send_popover = send_popover.clone();
+            let i18n = i18n.clone();
+            Rc::new(move |target| {
+                let Some(name) = current_note.borrow().clone() else {
+                    return;
+                };
+                let start = text_buffer.start_iter();
+                let end = text_buffer.end_iter();
+                let content = text_buffer.text(&start, &end, false).to_string();
+                send_popover.popdown();
+
+                let text = quick_note_body(&content).to_string();
+                if text.is_empty() {
+                    save_indicator.set_label(&i18n.borrow().t("quick_note_nothing_to_send"));
+                    return;
+                }
+                // Guardar antes, por si el envío falla
+                if manager.borrow().save_quick_note(&name, &content).is_ok() {
+                    *has_unsaved_changes.borrow_mut() = false;
+                }
+                sender.emit(AppMsg::SendQuickNote {
+                    quick_note: name,
+                    text,
+                    target,
+                });
+            })
+        };
+
+        let send_for_journal = send.clone();
+        journal_button.connect_clicked(move |_| send_for_journal(QuickNoteTarget::Journal));
+
+        let send_for_new = send.clone();
+        new_note_button.connect_clicked(move |_| send_for_new(QuickNoteTarget::NewNote));
+
+        let manager_for_search = manager.clone();
+        let send_results_for_search = send_results.clone();
+        send_search.connect_search_changed(move |entry| {
+            while let Some(child) = send_results_for_search.first_child() {
+                send_results_for_search.remove(&child);
+            }
+            let query = entry.text();
+            if query.trim().is_empty() {
+                return;
+            }
+            for name in manager_for_search.borrow().search_notes(query.trim()) {
+                let label = gtk::Label::new(Some(&name));
+                label.set_xalign(0.0);
+                label.set_ellipsize(gtk::pango::EllipsizeMode::Start);
+                label.set_margin_all(6);
+                let row = gtk::ListBoxRow::new();
+                row.set_child(Some(&label));
+                unsafe {
+                    row.set_data("note_name", name);
+                }
+                send_results_for_search.append(&row);
+            }
+            if let Some(first) = send_results_for_search.row_at_index(0) {
+                send_results_for_search.select_row(Some(&first));
+            }
+        });
+
+        // Enter en la búsqueda envía a la nota seleccionada
+        let send_results_for_activate = send_results.clone();
+        send_search.connect_activate(move |_| {
+            if let Some(row) = send_results_for_activate.selected_row() {
+                row.activate();
+            }
+        });
+
+        let send_for_note = send.clone();
+        send_results.connect_row_activated(move |_, row| {
+            let note_name: Option<String> =
+                unsafe { row.data::<String>("note_name").map(|d| d.as_ref().clone()) };
+            if let Some(name) = note_name {
+                send_for_note(QuickNoteTarget::Note(name));
+            }
+        });
+
+        let send_search_for_show = send_search.clone();
+        send_popover.connect_show(move |_| {
+            send_search_for_show.set_text("");
+            send_search_for_show.grab_focus();
+        });
+
+        // Autocompletado de @menciones y #tags, como en el editor principal
+        let completion_list = gtk::ListBox::new();
+        completion_list.set_selection_mode(gtk::SelectionMode::Single);
+        let completion_popover = gtk::Popover::new();
+        completion_popover.set_parent(&text_view);
+        completion_popover.set_autohide(false);
+        completion_popover.set_has_arrow(false);
+        completion_popover.set_position(gtk::PositionType::Bottom);
+        completion_popover.add_css_class("quick-note-completion");
+        completion_popover.set_child(Some(&completion_list));
+
+        let text_view_for_completion = text_view.clone();
+        let completion_popover_for_change = completion_popover.clone();
+        let completion_list_for_change = completion_list.clone();
+        let manager_for_completion = manager.clone();
+        let known_tags_for_completion = known_tags.clone();
+        text_buffer.connect_changed(move |_| {
+            update_completion(
+                &text_view_for_completion,
+                &completion_popover_for_change,
+                &completion_list_for_change,
+                &manager_for_completion.borrow(),
+                &known_tags_for_completion.borrow(),
+            );
+        });
+
+        let text_buffer_for_pick = text_buffer.clone();
+        let completion_popover_for_pick = completion_popover.clone();
+        completion_list.connect_row_activated(move |_, row| {
+            apply_completion(&text_buffer_for_pick, &completion_popover_for_pick, row);
+        });
+
+        // Teclas del autocompletado: antes de que las procese el TextView
+        let completion_keys = gtk::EventControllerKey::new();
+        completion_keys.set_propagation_phase(gtk::PropagationPhase::Capture);
+        let completion_popover_for_keys = completion_popover.clone();
+        let completion_list_for_keys = completion_list.clone();
+        let text_buffer_for_keys = text_buffer.clone();
+        completion_keys.connect_key_pressed(move |_, keyval, _, _| {
+            if !completion_popover_for_keys.is_visible() {
+                return gtk::glib::Propagation::Proceed;
+            }
+            let selected = completion_list_for_keys
+                .selected_row()
+                .map_or(0, |row| row.index());
+            match keyval {
+                gtk::gdk::Key::Down | gtk::gdk::Key::Up => {
+                    let next = if keyval == gtk::gdk::Key::Down {
+                        selected + 1
+                    } else {
+                        selected - 1
+                    };
+                    if let Some(row) = completion_list_for_keys.row_at_index(next) {
+                        completion_list_for_keys.select_row(Some(&row));
+                    }
+                    gtk::glib::Propagation::Stop
+                }
+                gtk::gdk::Key::Return | gtk::gdk::Key::KP_Enter | gtk::gdk::Key::Tab => {
+                    if let Some(row) = completion_list_for_keys.selected_row() {
+                        apply_completion(&text_buffer_for_keys, &completion_popover_for_keys, &row);
+                    }
+                    gtk::glib::Propagation::Stop
+                }
+                gtk::gdk::Key::Escape => {
+                    completion_popover_for_keys.popdown();
+                    gtk::glib::Propagation::Stop
+                }
+                _ => gtk::glib::Propagation::Proceed,
+            }
+        });
+        text_view.add_controller(completion_keys);
+
         // Escape para cerrar
         let key_controller = gtk::EventControllerKey::new();
         let window_for_key = window.clone();
@@ -388,6 +706,7 @@ impl QuickNoteWindow {
         let text_buffer_for_key = text_buffer.clone();
         let has_unsaved_for_key = has_unsaved_changes.clone();
         let save_indicator_for_key = save_indicator.clone();
+        let preview_button_for_key = preview_button.clone();
 
         key_controller.connect_key_pressed(move |_, keyval, _, modifiers| {
             let key_name = keyval.name().map(|s| s.to_string()).unwrap_or_default();
@@ -410,6 +729,7 @@ impl QuickNoteWindow {
                         }
 
                         // Volver a la lista
+                        preview_button_for_key.set_active(false);
                         content_stack_for_key.set_visible_child_name("list");
                         back_button_for_key.set_visible(false);
                         new_button_for_key.set_visible(true);
@@ -442,6 +762,13 @@ impl QuickNoteWindow {
                     }
                     gtk::glib::Propagation::Stop
                 }
+                "p" if modifiers.contains(gtk::gdk::ModifierType::CONTROL_MASK) => {
+                    // Ctrl+P: alternar la vista previa
+                    if content_stack_for_key.visible_child_name().as_deref() == Some("editor") {
+                        preview_button_for_key.set_active(!preview_button_for_key.is_active());
+                    }
+                    gtk::glib::Propagation::Stop
+                }
                 _ => gtk::glib::Propagation::Proceed,
             }
         });
@@ -452,9 +779,7 @@ impl QuickNoteWindow {
         pin_button.connect_toggled(move |_btn| {
             // En GTK4 no hay set_keep_above directo
             // La ventana se marca como "always on top" mediante reglas de Hyprland/Sway
-            println!(
-                "📌 Pin toggle - configurar en Hyprland: windowrulev2 = pin, title:^(Quick Note)$"
-            );
+            info!("Pin toggle - configurar en Hyprland: windowrulev2 = pin, title:^(Quick Note)$");
         });
 
         // Nueva quick note
@@ -491,7 +816,7 @@ impl QuickNoteWindow {
                     refresh_notes_list(&notes_list_for_new, &manager_for_new.borrow());
                 }
                 Err(e) => {
-                    eprintln!("❌ Error creando quick note: {}", e);
+                    error!("Error creando quick note: {}", e);
                 }
             }
         });
@@ -506,6 +831,7 @@ impl QuickNoteWindow {
         let notes_list_for_back = notes_list.clone();
         let has_unsaved_for_back = has_unsaved_changes.clone();
         let back_button_clone = back_button.clone();
+        let preview_button_for_back = preview_button.clone();
 
         back_button.connect_clicked(move |btn| {
             // Guardar antes de salir
@@ -521,6 +847,7 @@ impl QuickNoteWindow {
             }
 
             // Volver a la lista
+            preview_button_for_back.set_active(false);
             content_stack_for_back.set_visible_child_name("list");
             btn.set_visible(false);
             new_button_for_back.set_visible(true);
@@ -581,8 +908,13 @@ impl QuickNoteWindow {
             notes_list,
             content_stack,
             title_label,
+            back_button,
+            new_button,
+            preview_button,
+            save_indicator,
             i18n,
             has_unsaved_changes,
+            known_tags,
         };
 
         // Manejar close request (cuando Hyprland intenta cerrar la ventana)
@@ -723,9 +1055,19 @@ impl QuickNoteWindow {
 
     /// Crea una nueva quick note y la abre
     pub fn new_note(&self) {
+        self.new_note_with("");
+    }
+
+    /// Crea una nueva quick note con `text` tras el encabezado y la abre
+    pub fn new_note_with(&self, text: &str) {
         if let Ok(note) = self.manager.borrow().create_quick_note() {
             // Cargar contenido
-            if let Ok(content) = self.manager.borrow().read_quick_note(&note.name) {
+            if let Ok(mut content) = self.manager.borrow().read_quick_note(&note.name) {
+                if !text.is_empty() {
+                    content.push_str(text);
+                    content.push('\n');
+                    let _ = self.manager.borrow().save_quick_note(&note.name, &content);
+                }
                 self.text_buffer.set_text(&content);
             }
 
@@ -745,6 +1087,124 @@ impl QuickNoteWindow {
     pub fn is_visible(&self) -> bool {
         self.window.is_visible()
     }
+
+    /// Tags del vault para el autocompletado (la app los pasa al mostrarla)
+    pub fn set_known_tags(&self, tags: Vec<(String, usize)>) {
+        *self.known_tags.borrow_mut() = tags;
+    }
+
+    /// El texto de `quick_note` ya está en `target_name`: se borra la quick
+    /// note y, si estaba abierta, se vuelve a la lista
+    pub fn finish_sent(&self, quick_note: &str, target_name: &str) {
+        if let Err(e) = self.manager.borrow().delete_quick_note(quick_note) {
+            error!("{}", e);
+        }
+        if self.current_note.borrow().as_deref() == Some(quick_note) {
+            *self.current_note.borrow_mut() = None;
+            *self.has_unsaved_changes.borrow_mut() = false;
+            self.preview_button.set_active(false);
+            self.content_stack.set_visible_child_name("list");
+            self.back_button.set_visible(false);
+            self.new_button.set_visible(true);
+            self.title_label.set_label("Quick Notes");
+        }
+        self.refresh_list();
+        let t = self.i18n.borrow().t("quick_note_sent");
+        self.save_indicator
+            .set_label(&format!("{} {}", t, target_name));
+    }
+}
+
+/// Muestra (o esconde) las sugerencias de `@nota` o `#tag` para lo que se está
+/// escribiendo junto al cursor
+fn update_completion(
+    text_view: &gtk::TextView,
+    popover: &gtk::Popover,
+    list: &gtk::ListBox,
+    manager: &QuickNoteManager,
+    known_tags: &[(String, usize)],
+) {
+    let buffer = text_view.buffer();
+    let cursor = buffer.iter_at_mark(&buffer.get_insert());
+    let mut line_start = cursor.clone();
+    line_start.set_line_offset(0);
+    let before = buffer.text(&line_start, &cursor, false);
+
+    let suggestions: Vec<String> = match completion_prefix(&before) {
+        Some(('#', prefix)) => {
+            let mut tags: Vec<(i32, usize, &String)> = known_tags
+                .iter()
+                .filter_map(|(tag, count)| fuzzy_score(tag, prefix).map(|s| (s, *count, tag)))
+                .collect();
+            tags.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| b.1.cmp(&a.1)));
+            tags.into_iter()
+                .take(MAX_SUGGESTIONS)
+                .map(|(_, _, tag)| format!("#{}", tag))
+                .collect()
+        }
+        Some((_, prefix)) => manager
+            .search_notes(prefix)
+            .into_iter()
+            .map(|name| format!("@{}", name))
+            .collect(),
+        None => Vec::new(),
+    };
+    if suggestions.is_empty() || !text_view.has_focus() {
+        popover.popdown();
+        return;
+    }
+
+    while let Some(child) = list.first_child() {
+        list.remove(&child);
+    }
+    for suggestion in suggestions {
+        let label = gtk::Label::new(Some(&suggestion));
+        label.set_xalign(0.0);
+        label.set_margin_all(6);
+        let row = gtk::ListBoxRow::new();
+        row.set_child(Some(&label));
+        unsafe {
+            row.set_data("completion", suggestion);
+        }
+        list.append(&row);
+    }
+    if let Some(first) = list.row_at_index(0) {
+        list.select_row(Some(&first));
+    }
+
+    // Junto al cursor
+    let location = text_view.iter_location(&cursor);
+    let (x, y) = text_view.buffer_to_window_coords(
+        gtk::TextWindowType::Widget,
+        location.x(),
+        location.y() + location.height(),
+    );
+    popover.set_pointing_to(Some(&gtk::gdk::Rectangle::new(x, y, 1, 1)));
+    popover.popup();
+}
+
+/// Sustituye el `@...`/`#...` a medio escribir por la sugerencia de `row`
+fn apply_completion(buffer: &gtk::TextBuffer, popover: &gtk::Popover, row: &gtk::ListBoxRow) {
+    let completion: Option<String> =
+        unsafe { row.data::<String>("completion").map(|d| d.as_ref().clone()) };
+    popover.popdown();
+    let Some(completion) = completion else {
+        return;
+    };
+
+    let mut cursor = buffer.iter_at_mark(&buffer.get_insert());
+    let mut line_start = cursor.clone();
+    line_start.set_line_offset(0);
+    let before = buffer.text(&line_start, &cursor, false);
+    let Some((_, prefix)) = completion_prefix(&before) else {
+        return;
+    };
+
+    // El prefijo y el disparador (@ o #)
+    let mut start = cursor.clone();
+    start.backward_chars(prefix.chars().count() as i32 + 1);
+    buffer.delete(&mut start, &mut cursor);
+    buffer.insert(&mut start, &format!("{} ", completion));
 }
 
 /// Helper para refrescar la lista de notas
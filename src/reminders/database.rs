@@ -1,47 +1,23 @@
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
-use rusqlite::{Connection, OptionalExtension, params};
+use rusqlite::{OptionalExtension, params};
 
-use super::models::{Priority, Reminder, ReminderStatus, RepeatPattern};
+use crate::core::PooledConnection;
+
+use super::models::{
+    Priority, Reminder, ReminderHistoryEntry, ReminderOutcome, ReminderStatus, RepeatPattern,
+};
 
 /// Operaciones de base de datos para recordatorios
 #[derive(Debug)]
 pub struct ReminderDatabase {
-    conn: Connection,
+    conn: PooledConnection,
 }
 
 impl ReminderDatabase {
-    /// Crea una nueva conexión a la base de datos
-    pub fn new(conn: Connection) -> Self {
-        Self { conn }
-    }
-
-    /// Crea la tabla de recordatorios si no existe
-    pub fn ensure_schema(&self) -> Result<()> {
-        self.conn.execute_batch(
-            r#"
-            CREATE TABLE IF NOT EXISTS reminders (
-                id INTEGER PRIMARY KEY AUTOINCREMENT,
-                note_id INTEGER,
-                title TEXT NOT NULL,
-                description TEXT,
-                due_date INTEGER NOT NULL,
-                priority INTEGER DEFAULT 1,
-                status INTEGER DEFAULT 0,
-                snooze_until INTEGER,
-                repeat_pattern INTEGER DEFAULT 0,
-                created_at INTEGER NOT NULL,
-                updated_at INTEGER NOT NULL,
-                FOREIGN KEY (note_id) REFERENCES notes(id) ON DELETE SET NULL
-            );
-
-            CREATE INDEX IF NOT EXISTS idx_reminders_due_date ON reminders(due_date);
-            CREATE INDEX IF NOT EXISTS idx_reminders_status ON reminders(status);
-            CREATE INDEX IF NOT EXISTS idx_reminders_note_id ON reminders(note_id);
-            "#,
-        )?;
-
-        Ok(())
+    /// Usa una conexión a la base de datos de notas (la tabla la crean sus migraciones)
+    pub fn new(conn: impl Into<PooledConnection>) -> Self {
+        Self { conn: conn.into() }
     }
 
     /// Crea un nuevo recordatorio
@@ -83,7 +59,7 @@ impl ReminderDatabase {
             .query_row(
                 r#"
                 SELECT id, note_id, title, description, due_date, priority, status,
-                       snooze_until, repeat_pattern, created_at, updated_at
+                       snooze_until, repeat_pattern, created_at, updated_at, source_path, source_line
                 FROM reminders
                 WHERE id = ?1
                 "#,
@@ -103,6 +79,10 @@ impl ReminderDatabase {
                         repeat_pattern: RepeatPattern::from_i32(row.get(8)?),
                         created_at: DateTime::from_timestamp(row.get(9)?, 0).unwrap(),
                         updated_at: DateTime::from_timestamp(row.get(10)?, 0).unwrap(),
+                        source_path: row.get(11)?,
+                        source_line: row
+                            .get::<_, Option<i64>>(12)?
+                            .map(|line| line as usize),
                     })
                 },
             )
@@ -117,7 +97,7 @@ impl ReminderDatabase {
             format!(
                 r#"
                 SELECT id, note_id, title, description, due_date, priority, status,
-                       snooze_until, repeat_pattern, created_at, updated_at
+                       snooze_until, repeat_pattern, created_at, updated_at, source_path, source_line
                 FROM reminders
                 WHERE status = {}
                 ORDER BY due_date ASC
@@ -127,7 +107,7 @@ impl ReminderDatabase {
         } else {
             r#"
             SELECT id, note_id, title, description, due_date, priority, status,
-                   snooze_until, repeat_pattern, created_at, updated_at
+                   snooze_until, repeat_pattern, created_at, updated_at, source_path, source_line
             FROM reminders
             ORDER BY due_date ASC
             "#
@@ -151,6 +131,8 @@ impl ReminderDatabase {
                     repeat_pattern: RepeatPattern::from_i32(row.get(8)?),
                     created_at: DateTime::from_timestamp(row.get(9)?, 0).unwrap(),
                     updated_at: DateTime::from_timestamp(row.get(10)?, 0).unwrap(),
+                    source_path: row.get(11)?,
+                    source_line: row.get::<_, Option<i64>>(12)?.map(|line| line as usize),
                 })
             })?
             .collect::<Result<Vec<_>, _>>()?;
@@ -163,7 +145,7 @@ impl ReminderDatabase {
         let mut stmt = self.conn.prepare(
             r#"
             SELECT id, note_id, title, description, due_date, priority, status,
-                   snooze_until, repeat_pattern, created_at, updated_at
+                   snooze_until, repeat_pattern, created_at, updated_at, source_path, source_line
             FROM reminders
             WHERE note_id = ?1
             ORDER BY due_date ASC
@@ -186,6 +168,8 @@ impl ReminderDatabase {
                     repeat_pattern: RepeatPattern::from_i32(row.get(8)?),
                     created_at: DateTime::from_timestamp(row.get(9)?, 0).unwrap(),
                     updated_at: DateTime::from_timestamp(row.get(10)?, 0).unwrap(),
+                    source_path: row.get(11)?,
+                    source_line: row.get::<_, Option<i64>>(12)?.map(|line| line as usize),
                 })
             })?
             .collect::<Result<Vec<_>, _>>()?;
@@ -205,6 +189,23 @@ impl ReminderDatabase {
         Ok(())
     }
 
+    /// Completado por el usuario: cambia el estado y lo anota en el historial
+    /// (el scheduler usa `update_status` al cerrar una repetición)
+    pub fn complete_reminder(&self, id: i64) -> Result<()> {
+        self.update_status(id, ReminderStatus::Completed)?;
+        self.resolve_history(id, ReminderOutcome::Completed)
+    }
+
+    /// Guarda la nota y la línea (desde 1) de donde sale el recordatorio
+    pub fn set_source(&self, id: i64, path: &str, line: usize) -> Result<()> {
+        self.conn.execute(
+            "UPDATE reminders SET source_path = ?1, source_line = ?2 WHERE id = ?3",
+            params![path, line as i64, id],
+        )?;
+
+        Ok(())
+    }
+
     /// Pospone un recordatorio
     pub fn snooze_reminder(&self, id: i64, snooze_until: DateTime<Utc>) -> Result<()> {
         let now = Utc::now().timestamp();
@@ -213,6 +214,10 @@ impl ReminderDatabase {
             "UPDATE reminders SET snooze_until = ?1, status = 2, updated_at = ?2 WHERE id = ?3",
             params![snooze_until.timestamp(), now, id],
         )?;
+        self.conn.execute(
+            "UPDATE reminder_history SET snooze_count = snooze_count + 1 WHERE reminder_id = ?1",
+            params![id],
+        )?;
 
         Ok(())
     }
@@ -255,8 +260,9 @@ impl ReminderDatabase {
         Ok(())
     }
 
-    /// Elimina un recordatorio
+    /// Elimina un recordatorio (si ya saltó, queda descartado en el historial)
     pub fn delete_reminder(&self, id: i64) -> Result<()> {
+        self.resolve_history(id, ReminderOutcome::Dismissed)?;
         self.conn
             .execute("DELETE FROM reminders WHERE id = ?1", params![id])?;
         Ok(())
@@ -269,7 +275,7 @@ impl ReminderDatabase {
         let mut stmt = self.conn.prepare(
             r#"
             SELECT id, note_id, title, description, due_date, priority, status,
-                   snooze_until, repeat_pattern, created_at, updated_at
+                   snooze_until, repeat_pattern, created_at, updated_at, source_path, source_line
             FROM reminders
             WHERE status != 1
               AND (
@@ -296,6 +302,8 @@ impl ReminderDatabase {
                     repeat_pattern: RepeatPattern::from_i32(row.get(8)?),
                     created_at: DateTime::from_timestamp(row.get(9)?, 0).unwrap(),
                     updated_at: DateTime::from_timestamp(row.get(10)?, 0).unwrap(),
+                    source_path: row.get(11)?,
+                    source_line: row.get::<_, Option<i64>>(12)?.map(|line| line as usize),
                 })
             })?
             .collect::<Result<Vec<_>, _>>()?;
@@ -329,7 +337,7 @@ impl ReminderDatabase {
         let mut stmt = self.conn.prepare(
             r#"
             SELECT id, note_id, title, description, due_date, priority, status,
-                   snooze_until, repeat_pattern, created_at, updated_at
+                   snooze_until, repeat_pattern, created_at, updated_at, source_path, source_line
             FROM reminders
             WHERE note_id = ?1
             ORDER BY due_date ASC
@@ -352,10 +360,83 @@ impl ReminderDatabase {
                     repeat_pattern: RepeatPattern::from_i32(row.get(8)?),
                     created_at: DateTime::from_timestamp(row.get(9)?, 0).unwrap(),
                     updated_at: DateTime::from_timestamp(row.get(10)?, 0).unwrap(),
+                    source_path: row.get(11)?,
+                    source_line: row.get::<_, Option<i64>>(12)?.map(|line| line as usize),
                 })
             })?
             .collect::<Result<Vec<_>, _>>()?;
 
         Ok(reminders)
     }
+
+    /// Anota en el historial que un recordatorio saltó. Si vuelve a saltar
+    /// tras posponerlo se mantiene la primera entrada.
+    pub fn record_fired(&self, reminder: &Reminder) -> Result<()> {
+        self.conn.execute(
+            r#"
+            INSERT INTO reminder_history (reminder_id, note_id, title, due_date, fired_at)
+            VALUES (?1, ?2, ?3, ?4, ?5)
+            ON CONFLICT(reminder_id) DO NOTHING
+            "#,
+            params![
+                reminder.id,
+                reminder.note_id,
+                reminder.title,
+                reminder.due_date.timestamp(),
+                Utc::now().timestamp()
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    /// Cierra la entrada del historial de un recordatorio que sigue abierta
+    fn resolve_history(&self, reminder_id: i64, outcome: ReminderOutcome) -> Result<()> {
+        self.conn.execute(
+            r#"
+            UPDATE reminder_history SET outcome = ?1, resolved_at = ?2
+            WHERE reminder_id = ?3 AND outcome = ?4
+            "#,
+            params![
+                outcome.to_i32(),
+                Utc::now().timestamp(),
+                reminder_id,
+                ReminderOutcome::Open.to_i32()
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    /// Historial de recordatorios que saltaron, del más reciente al más antiguo
+    pub fn list_history(&self) -> Result<Vec<ReminderHistoryEntry>> {
+        let mut stmt = self.conn.prepare(
+            r#"
+            SELECT id, reminder_id, note_id, title, due_date, fired_at, snooze_count,
+                   outcome, resolved_at
+            FROM reminder_history
+            ORDER BY fired_at DESC, id DESC
+            "#,
+        )?;
+
+        let entries = stmt
+            .query_map([], |row| {
+                Ok(ReminderHistoryEntry {
+                    id: row.get(0)?,
+                    reminder_id: row.get(1)?,
+                    note_id: row.get(2)?,
+                    title: row.get(3)?,
+                    due_date: DateTime::from_timestamp(row.get(4)?, 0).unwrap(),
+                    fired_at: DateTime::from_timestamp(row.get(5)?, 0).unwrap(),
+                    snooze_count: row.get(6)?,
+                    outcome: ReminderOutcome::from_i32(row.get(7)?),
+                    resolved_at: row
+                        .get::<_, Option<i64>>(8)?
+                        .and_then(|ts| DateTime::from_timestamp(ts, 0)),
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(entries)
+    }
 }
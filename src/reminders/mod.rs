@@ -1,11 +1,15 @@
 pub mod database;
-pub mod models;
 pub mod notifications;
-pub mod parser;
 pub mod scheduler;
 
+// Modelo y parser en el núcleo, compartidos con otras herramientas
+pub use notnative_core::reminders::{models, parser};
+
 pub use database::ReminderDatabase;
-pub use models::{Priority, Reminder, ReminderStatus, RepeatPattern};
+pub use models::{
+    Priority, Reminder, ReminderDraft, ReminderHistoryEntry, ReminderOutcome, ReminderStatus,
+    RepeatPattern,
+};
 pub use notifications::ReminderNotifier;
 pub use parser::{ParsedReminder, ReminderParser};
 pub use scheduler::ReminderScheduler;
@@ -1,28 +1,54 @@
 use super::models::Reminder;
 use crate::i18n::{I18n, Language};
-use relm4::ComponentSender;
-use relm4::gtk::glib;
 use std::sync::{Arc, Mutex};
+use tracing::info;
+
+/// Receptor de los avisos internos (la app los muestra como toast)
+type ToastHandler = Arc<dyn Fn(String) + Send + Sync>;
+/// Receptor de cada recordatorio que salta (hooks de eventos y plugins)
+type FiredHandler = Arc<dyn Fn(&Reminder) + Send + Sync>;
+/// Receptor de "Abrir nota" en la notificación de escritorio (ruta y línea)
+type OpenSourceHandler = Arc<dyn Fn(String, usize) + Send + Sync>;
 
 /// Sistema de notificaciones para recordatorios
-#[derive(Debug)]
 pub struct ReminderNotifier {
-    app_sender: Arc<Mutex<Option<ComponentSender<crate::app::MainApp>>>>,
+    toast_handler: Mutex<Option<ToastHandler>>,
+    fired_handler: Mutex<Option<FiredHandler>>,
+    open_source_handler: Mutex<Option<OpenSourceHandler>>,
     i18n: Arc<Mutex<I18n>>,
 }
 
+impl std::fmt::Debug for ReminderNotifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReminderNotifier")
+            .field("i18n", &self.i18n)
+            .finish_non_exhaustive()
+    }
+}
+
 impl ReminderNotifier {
     pub fn new(i18n: Arc<Mutex<I18n>>) -> Self {
         Self {
-            app_sender: Arc::new(Mutex::new(None)),
+            toast_handler: Mutex::new(None),
+            fired_handler: Mutex::new(None),
+            open_source_handler: Mutex::new(None),
             i18n,
         }
     }
 
-    /// Configura el sender de la app para toast interno
-    pub fn set_app_sender(&self, sender: ComponentSender<crate::app::MainApp>) {
-        let mut app_sender = self.app_sender.lock().unwrap();
-        *app_sender = Some(sender);
+    /// Configura quién muestra el aviso interno (toast de la app)
+    pub fn set_toast_handler(&self, handler: impl Fn(String) + Send + Sync + 'static) {
+        *self.toast_handler.lock().unwrap() = Some(Arc::new(handler));
+    }
+
+    /// Configura quién recibe cada recordatorio que salta, además de los avisos
+    pub fn set_fired_handler(&self, handler: impl Fn(&Reminder) + Send + Sync + 'static) {
+        *self.fired_handler.lock().unwrap() = Some(Arc::new(handler));
+    }
+
+    /// Configura quién abre la nota cuando se pulsa "Abrir nota" en el aviso
+    pub fn set_open_source_handler(&self, handler: impl Fn(String, usize) + Send + Sync + 'static) {
+        *self.open_source_handler.lock().unwrap() = Some(Arc::new(handler));
     }
 
     /// Envía una notificación para un recordatorio
@@ -35,6 +61,11 @@ impl ReminderNotifier {
 
         // 3. Reproducir sonido (opcional - TODO)
         // self.play_notification_sound();
+
+        // 4. Hooks de eventos
+        if let Some(handler) = self.fired_handler.lock().unwrap().as_ref() {
+            handler(reminder);
+        }
     }
 
     /// Envía notificación de escritorio usando notify-rust
@@ -59,23 +90,42 @@ impl ReminderNotifier {
         {
             use notify_rust::{Notification, Timeout};
 
-            if let Err(e) = Notification::new()
+            let mut notification = Notification::new();
+            notification
                 .summary(title)
                 .body(&body)
                 .icon("appointment-soon")
-                .timeout(Timeout::Milliseconds(8000))
-                .show()
-            {
-                eprintln!("⚠️ Error enviando notificación desktop: {}", e);
-            } else {
-                println!("✅ Notificación desktop enviada: {}", reminder.title);
+                .timeout(Timeout::Milliseconds(8000));
+
+            // Si sale de una nota, el aviso ofrece abrirla en esa línea
+            let source = reminder.source_path.clone().zip(reminder.source_line);
+            if source.is_some() {
+                notification.action("open", &i18n.t("reminder_open_note"));
+            }
+
+            match notification.show() {
+                Ok(handle) => {
+                    info!("Notificación desktop enviada: {}", reminder.title);
+                    let handler = self.open_source_handler.lock().unwrap().clone();
+                    if let (Some((path, line)), Some(handler)) = (source, handler) {
+                        // Esperar la respuesta sin bloquear al scheduler
+                        std::thread::spawn(move || {
+                            handle.wait_for_action(|action| {
+                                if action == "open" {
+                                    handler(path, line);
+                                }
+                            });
+                        });
+                    }
+                }
+                Err(e) => error!("Error enviando notificación desktop: {}", e),
             }
         }
 
         #[cfg(not(feature = "notify"))]
         {
-            println!("ℹ️ Notificaciones desktop deshabilitadas (recompila con --features notify)");
-            println!("🔔 {}: {}", title, body);
+            info!("ℹ️ Notificaciones desktop deshabilitadas (recompila con --features notify)");
+            info!("{}: {}", title, body);
         }
     }
 
@@ -91,11 +141,10 @@ impl ReminderNotifier {
         };
 
         // Enviar a través del toast de la app
-        if let Some(sender) = self.app_sender.lock().unwrap().as_ref() {
-            use crate::app::AppMsg;
-            sender.input(AppMsg::ShowNotification(message.clone()));
+        if let Some(handler) = self.toast_handler.lock().unwrap().as_ref() {
+            handler(message.clone());
         }
 
-        println!("{}", message);
+        info!("{}", message);
     }
 }
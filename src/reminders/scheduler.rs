@@ -1,6 +1,6 @@
-use chrono::Utc;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
+use tracing::{debug, error, info};
 
 use super::database::ReminderDatabase;
 use super::models::{Reminder, ReminderStatus};
@@ -27,7 +27,7 @@ impl ReminderScheduler {
     pub fn start(&self) {
         let mut running = self.running.lock().unwrap();
         if *running {
-            println!("⏰ Scheduler ya está corriendo");
+            info!("Scheduler ya está corriendo");
             return;
         }
 
@@ -39,14 +39,14 @@ impl ReminderScheduler {
         let running_flag = Arc::clone(&self.running);
 
         std::thread::spawn(move || {
-            println!("⏰ Scheduler de recordatorios iniciado (check cada 30s)");
+            info!("Scheduler de recordatorios iniciado (check cada 30s)");
 
             loop {
                 // Verificar si debe seguir corriendo
                 {
                     let running = running_flag.lock().unwrap();
                     if !*running {
-                        println!("⏰ Scheduler detenido");
+                        info!("Scheduler detenido");
                         break;
                     }
                 }
@@ -60,7 +60,7 @@ impl ReminderScheduler {
                             }
                         }
                         Err(e) => {
-                            eprintln!("⚠️ Error al obtener recordatorios pendientes: {}", e);
+                            error!("Error al obtener recordatorios pendientes: {}", e);
                         }
                     }
                 }
@@ -73,16 +73,20 @@ impl ReminderScheduler {
 
     /// Procesa un recordatorio que debe dispararse
     fn process_reminder(reminder: &Reminder, db: &ReminderDatabase, notifier: &ReminderNotifier) {
-        println!("🔔 Disparando recordatorio: {}", reminder.title);
+        info!("Disparando recordatorio: {}", reminder.title);
+
+        if let Err(e) = db.record_fired(reminder) {
+            error!("Error anotando el recordatorio en el historial: {}", e);
+        }
 
         // Enviar notificación
         notifier.notify(reminder);
 
         // Si tiene patrón de repetición, crear el siguiente
         if let Some(next_date) = reminder.next_occurrence() {
-            println!("   ↻ Programando próxima ocurrencia: {}", next_date);
+            debug!("Programando próxima ocurrencia: {}", next_date);
 
-            if let Err(e) = db.create_reminder(
+            match db.create_reminder(
                 reminder.note_id,
                 &reminder.title,
                 reminder.description.as_deref(),
@@ -90,16 +94,25 @@ impl ReminderScheduler {
                 reminder.priority,
                 reminder.repeat_pattern,
             ) {
-                eprintln!("   ❌ Error creando repetición: {}", e);
+                Ok(next_id) => {
+                    // La repetición sigue apuntando a la misma línea de la nota
+                    if let (Some(path), Some(line)) = (&reminder.source_path, reminder.source_line)
+                    {
+                        if let Err(e) = db.set_source(next_id, path, line) {
+                            error!("Error copiando el origen de la repetición: {}", e);
+                        }
+                    }
+                }
+                Err(e) => error!("Error creando repetición: {}", e),
             }
 
             // Marcar el actual como completado
             if let Err(e) = db.update_status(reminder.id, ReminderStatus::Completed) {
-                eprintln!("   ❌ Error actualizando estado: {}", e);
+                error!("Error actualizando estado: {}", e);
             }
         } else {
             // Sin repetición, simplemente dejar pendiente (el usuario lo completará manualmente)
-            println!("   ℹ️ Recordatorio sin repetición, queda pendiente");
+            debug!("ℹ️ Recordatorio sin repetición, queda pendiente");
         }
     }
 
@@ -107,7 +120,7 @@ impl ReminderScheduler {
     pub fn stop(&self) {
         let mut running = self.running.lock().unwrap();
         *running = false;
-        println!("⏰ Deteniendo scheduler de recordatorios...");
+        info!("Deteniendo scheduler de recordatorios...");
     }
 
     /// Fuerza una verificación inmediata
@@ -115,8 +128,8 @@ impl ReminderScheduler {
         if let Ok(db_lock) = self.db.lock() {
             match db_lock.get_pending_triggers() {
                 Ok(reminders) => {
-                    println!(
-                        "🔍 Verificación manual: {} recordatorios pendientes",
+                    debug!(
+                        "Verificación manual: {} recordatorios pendientes",
                         reminders.len()
                     );
                     for reminder in reminders {
@@ -124,7 +137,7 @@ impl ReminderScheduler {
                     }
                 }
                 Err(e) => {
-                    eprintln!("⚠️ Error en verificación manual: {}", e);
+                    error!("Error en verificación manual: {}", e);
                 }
             }
         }
@@ -0,0 +1,504 @@
+//! Captura de pantalla y editor de anotaciones
+//!
+//! La zona se elige con `slurp` y se captura con `grim`; si no están (o el
+//! compositor no los admite) se usa el portal de capturas. Luego, si está
+//! activado, se abre la captura en una ventana para dibujar flechas,
+//! recuadros y textos antes de insertarla (ver [`crate::core::screenshot`]).
+
+use gtk::prelude::*;
+use relm4::gtk;
+use std::cell::{Cell, RefCell};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use tracing::{error, info, warn};
+
+use crate::core::{Annotation, AnnotationKind, NotesConfig, SCREENSHOT_PREFIX, parse_region};
+use crate::i18n::I18n;
+use crate::sketch_canvas::parse_color;
+
+/// Colores de las anotaciones (el primero es el de por defecto)
+const ANNOTATION_COLORS: [&str; 4] = ["#d20f39", "#df8e1d", "#40a02b", "#1e66f5"];
+/// Grosor de flechas y recuadros
+const LINE_WIDTH: f64 = 4.0;
+/// Largo de la punta de las flechas
+const ARROW_HEAD_SIZE: f64 = 18.0;
+/// Tamaño de letra de los textos
+const TEXT_SIZE: f64 = 22.0;
+/// Un arrastre más corto que esto no deja flecha ni recuadro
+const MIN_DRAG: f64 = 4.0;
+
+/// Herramienta del editor de anotaciones
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tool {
+    Arrow,
+    Rect,
+    Text,
+}
+
+/// Captura una zona de la pantalla en assets. `on_done` recibe la ruta de la
+/// imagen, o `None` si se canceló la selección o no se pudo capturar.
+pub fn capture(on_done: impl FnOnce(Option<PathBuf>) + 'static) {
+    gtk::glib::spawn_future_local(async move {
+        let path = match new_screenshot_path() {
+            Ok(path) => path,
+            Err(e) => {
+                error!("Error creando el archivo de la captura: {}", e);
+                on_done(None);
+                return;
+            }
+        };
+
+        // slurp espera a que el usuario elija la zona: fuera del hilo de GTK
+        let grim_path = path.clone();
+        let captured = match gtk::gio::spawn_blocking(move || capture_with_grim(&grim_path)).await {
+            Ok(Ok(true)) => Some(path),
+            Ok(Ok(false)) => None,
+            Ok(Err(e)) => {
+                info!("grim/slurp no disponibles ({}), se usa el portal", e);
+                capture_with_portal(&path).await
+            }
+            Err(_) => None,
+        };
+        on_done(captured);
+    });
+}
+
+/// Captura con `slurp` + `grim`. `Ok(false)` si se canceló la selección;
+/// error si faltan las herramientas o `grim` falla.
+fn capture_with_grim(path: &Path) -> std::io::Result<bool> {
+    use std::process::Command;
+
+    let selection = Command::new("slurp").output()?;
+    let Some(region) = selection
+        .status
+        .success()
+        .then(|| parse_region(&String::from_utf8_lossy(&selection.stdout)))
+        .flatten()
+    else {
+        return Ok(false);
+    };
+
+    let status = Command::new("grim")
+        .args(["-g", &region])
+        .arg(path)
+        .status()?;
+    if !status.success() {
+        return Err(std::io::Error::other(format!(
+            "grim terminó con {}",
+            status
+        )));
+    }
+    Ok(true)
+}
+
+/// Captura con el portal (GNOME, KDE...): el usuario elige la zona en el
+/// diálogo del sistema y se copia la imagen a `path`
+async fn capture_with_portal(path: &Path) -> Option<PathBuf> {
+    use ashpd::desktop::screenshot::Screenshot;
+
+    let response = Screenshot::request()
+        .interactive(true)
+        .modal(true)
+        .send()
+        .await
+        .and_then(|request| request.response());
+    let source = match response {
+        Ok(screenshot) => screenshot.uri().to_file_path().ok()?,
+        Err(e) => {
+            warn!("Captura por el portal cancelada o fallida: {}", e);
+            return None;
+        }
+    };
+    match std::fs::copy(&source, path) {
+        Ok(_) => Some(path.to_path_buf()),
+        Err(e) => {
+            error!("Error copiando la captura {}: {}", source.display(), e);
+            None
+        }
+    }
+}
+
+/// Ventana para anotar la captura en `path`. Al insertar se sobrescribe la
+/// imagen con las anotaciones y `on_saved` recibe la ruta; al cerrar sin
+/// insertar se borra la captura.
+pub fn show_annotation_dialog(
+    parent: &gtk::ApplicationWindow,
+    i18n: &I18n,
+    path: PathBuf,
+    on_saved: impl Fn(PathBuf) + 'static,
+) {
+    let pixbuf = match gtk::gdk_pixbuf::Pixbuf::from_file(&path) {
+        Ok(pixbuf) => pixbuf,
+        Err(e) => {
+            // Sin poder abrirla se inserta tal cual
+            error!("Error abriendo la captura {}: {}", path.display(), e);
+            on_saved(path);
+            return;
+        }
+    };
+    let annotations: Rc<RefCell<Vec<Annotation>>> = Rc::new(RefCell::new(Vec::new()));
+    let tool = Rc::new(Cell::new(Tool::Arrow));
+    let color = Rc::new(RefCell::new(ANNOTATION_COLORS[0].to_string()));
+    let saved = Rc::new(Cell::new(false));
+
+    let dialog = gtk::Window::builder()
+        .transient_for(parent)
+        .modal(true)
+        .title(&i18n.t("screenshot_title"))
+        .default_width((pixbuf.width() + 40).clamp(480, 1100))
+        .default_height((pixbuf.height() + 110).clamp(360, 780))
+        .build();
+
+    let canvas = gtk::DrawingArea::new();
+    canvas.set_content_width(pixbuf.width());
+    canvas.set_content_height(pixbuf.height());
+    canvas.set_halign(gtk::Align::Center);
+    canvas.set_valign(gtk::Align::Center);
+    {
+        let pixbuf = pixbuf.clone();
+        let annotations = annotations.clone();
+        canvas
+            .set_draw_func(move |_, cr, _, _| draw_annotations(cr, &pixbuf, &annotations.borrow()));
+    }
+
+    // Flechas y recuadros se dibujan arrastrando
+    let drag = gtk::GestureDrag::new();
+    {
+        let annotations = annotations.clone();
+        let tool = tool.clone();
+        let color = color.clone();
+        drag.connect_drag_begin(move |_, x, y| {
+            let kind = match tool.get() {
+                Tool::Arrow => AnnotationKind::Arrow,
+                Tool::Rect => AnnotationKind::Rect,
+                Tool::Text => return,
+            };
+            annotations.borrow_mut().push(Annotation {
+                kind,
+                from: (x, y),
+                to: (x, y),
+                color: color.borrow().clone(),
+            });
+        });
+    }
+    {
+        let annotations = annotations.clone();
+        let tool = tool.clone();
+        let canvas = canvas.clone();
+        drag.connect_drag_update(move |gesture, offset_x, offset_y| {
+            if tool.get() == Tool::Text {
+                return;
+            }
+            if let Some(annotation) = annotations.borrow_mut().last_mut() {
+                annotation.to = (annotation.from.0 + offset_x, annotation.from.1 + offset_y);
+            }
+            gesture.set_state(gtk::EventSequenceState::Claimed);
+            canvas.queue_draw();
+        });
+    }
+    {
+        let annotations = annotations.clone();
+        let tool = tool.clone();
+        let canvas = canvas.clone();
+        drag.connect_drag_end(move |_, _, _| {
+            if tool.get() == Tool::Text {
+                return;
+            }
+            let mut annotations = annotations.borrow_mut();
+            if annotations
+                .last()
+                .is_some_and(|annotation| annotation.is_too_small(MIN_DRAG))
+            {
+                annotations.pop();
+            }
+            canvas.queue_draw();
+        });
+    }
+    canvas.add_controller(drag);
+
+    // Un clic con la herramienta de texto pide el texto en un popover
+    let click = gtk::GestureClick::new();
+    {
+        let annotations = annotations.clone();
+        let tool = tool.clone();
+        let color = color.clone();
+        let canvas_for_click = canvas.clone();
+        let placeholder = i18n.t("screenshot_text_placeholder");
+        click.connect_pressed(move |_, _, x, y| {
+            if tool.get() != Tool::Text {
+                return;
+            }
+            let entry = gtk::Entry::builder()
+                .placeholder_text(&placeholder)
+                .width_chars(24)
+                .build();
+            let popover = gtk::Popover::builder().child(&entry).build();
+            popover.set_parent(&canvas_for_click);
+            popover.set_pointing_to(Some(&gtk::gdk::Rectangle::new(x as i32, y as i32, 1, 1)));
+            popover.connect_closed(|popover| popover.unparent());
+            {
+                let annotations = annotations.clone();
+                let color = color.clone();
+                let canvas = canvas_for_click.clone();
+                let popover = popover.clone();
+                entry.connect_activate(move |entry| {
+                    let annotation = Annotation {
+                        kind: AnnotationKind::Text(entry.text().trim().to_string()),
+                        from: (x, y),
+                        to: (x, y),
+                        color: color.borrow().clone(),
+                    };
+                    if !annotation.is_too_small(MIN_DRAG) {
+                        annotations.borrow_mut().push(annotation);
+                        canvas.queue_draw();
+                    }
+                    popover.popdown();
+                });
+            }
+            popover.popup();
+            entry.grab_focus();
+        });
+    }
+    canvas.add_controller(click);
+
+    let scrolled = gtk::ScrolledWindow::builder()
+        .vexpand(true)
+        .hexpand(true)
+        .child(&canvas)
+        .build();
+
+    // Barra de herramientas: flecha, recuadro, texto, colores y deshacer
+    let toolbar = gtk::Box::builder()
+        .orientation(gtk::Orientation::Horizontal)
+        .spacing(6)
+        .margin_start(12)
+        .margin_end(12)
+        .margin_top(8)
+        .margin_bottom(8)
+        .build();
+
+    let mut first_tool: Option<gtk::ToggleButton> = None;
+    for (tool_kind, label, tooltip) in [
+        (Tool::Arrow, "→", "screenshot_tool_arrow"),
+        (Tool::Rect, "▭", "screenshot_tool_rect"),
+        (Tool::Text, "T", "screenshot_tool_text"),
+    ] {
+        let button = gtk::ToggleButton::with_label(label);
+        button.add_css_class("flat");
+        button.set_tooltip_text(Some(&i18n.t(tooltip)));
+        match &first_tool {
+            Some(first) => button.set_group(Some(first)),
+            None => {
+                button.set_active(true);
+                first_tool = Some(button.clone());
+            }
+        }
+        let tool = tool.clone();
+        button.connect_toggled(move |button| {
+            if button.is_active() {
+                tool.set(tool_kind);
+            }
+        });
+        toolbar.append(&button);
+    }
+
+    toolbar.append(&gtk::Separator::new(gtk::Orientation::Vertical));
+
+    let mut first_color: Option<gtk::ToggleButton> = None;
+    for annotation_color in ANNOTATION_COLORS {
+        let swatch = gtk::DrawingArea::new();
+        swatch.set_content_width(16);
+        swatch.set_content_height(16);
+        let rgb = parse_color(annotation_color);
+        swatch.set_draw_func(move |_, cr, w, h| {
+            cr.set_source_rgb(rgb.0, rgb.1, rgb.2);
+            cr.arc(
+                f64::from(w) / 2.0,
+                f64::from(h) / 2.0,
+                f64::from(w.min(h)) / 2.0,
+                0.0,
+                std::f64::consts::TAU,
+            );
+            cr.fill().ok();
+        });
+
+        let button = gtk::ToggleButton::builder().child(&swatch).build();
+        button.add_css_class("flat");
+        match &first_color {
+            Some(first) => button.set_group(Some(first)),
+            None => {
+                button.set_active(true);
+                first_color = Some(button.clone());
+            }
+        }
+        let color = color.clone();
+        button.connect_toggled(move |button| {
+            if button.is_active() {
+                *color.borrow_mut() = annotation_color.to_string();
+            }
+        });
+        toolbar.append(&button);
+    }
+
+    let spacer = gtk::Box::new(gtk::Orientation::Horizontal, 0);
+    spacer.set_hexpand(true);
+    toolbar.append(&spacer);
+
+    let undo_button = gtk::Button::from_icon_name("edit-undo-symbolic");
+    undo_button.set_tooltip_text(Some(&i18n.t("sketch_undo")));
+    {
+        let annotations = annotations.clone();
+        let canvas = canvas.clone();
+        undo_button.connect_clicked(move |_| {
+            annotations.borrow_mut().pop();
+            canvas.queue_draw();
+        });
+    }
+    toolbar.append(&undo_button);
+
+    let main_box = gtk::Box::new(gtk::Orientation::Vertical, 0);
+    main_box.append(&toolbar);
+    main_box.append(&scrolled);
+    dialog.set_child(Some(&main_box));
+
+    let header_bar = gtk::HeaderBar::new();
+    let insert_button = gtk::Button::with_label(&i18n.t("screenshot_insert"));
+    insert_button.add_css_class("suggested-action");
+    header_bar.pack_end(&insert_button);
+    dialog.set_titlebar(Some(&header_bar));
+
+    let save: Rc<dyn Fn()> = {
+        let dialog = dialog.clone();
+        let annotations = annotations.clone();
+        let saved = saved.clone();
+        let path = path.clone();
+        Rc::new(move || {
+            let annotations = annotations.borrow();
+            if !annotations.is_empty() {
+                if let Err(e) = save_annotated(&path, &pixbuf, &annotations) {
+                    error!("Error guardando la captura anotada: {}", e);
+                    return;
+                }
+            }
+            saved.set(true);
+            on_saved(path.clone());
+            dialog.close();
+        })
+    };
+    {
+        let save = save.clone();
+        insert_button.connect_clicked(move |_| save());
+    }
+
+    // Cerrar sin insertar descarta la captura
+    dialog.connect_close_request(move |_| {
+        if !saved.get() {
+            let _ = std::fs::remove_file(&path);
+        }
+        gtk::glib::Propagation::Proceed
+    });
+
+    // Esc descarta, Ctrl+S o Ctrl+Enter insertan y Ctrl+Z deshace
+    let key_controller = gtk::EventControllerKey::new();
+    {
+        let dialog = dialog.clone();
+        let undo_button = undo_button.clone();
+        key_controller.connect_key_pressed(move |_, keyval, _, state| {
+            let ctrl = state.contains(gtk::gdk::ModifierType::CONTROL_MASK);
+            match keyval.name().as_deref() {
+                Some("Escape") => dialog.close(),
+                Some("s") | Some("Return") if ctrl => save(),
+                Some("z") if ctrl => undo_button.emit_clicked(),
+                _ => return gtk::glib::Propagation::Proceed,
+            }
+            gtk::glib::Propagation::Stop
+        });
+    }
+    dialog.add_controller(key_controller);
+
+    dialog.present();
+}
+
+fn draw_annotations(
+    cr: &gtk::cairo::Context,
+    pixbuf: &gtk::gdk_pixbuf::Pixbuf,
+    annotations: &[Annotation],
+) {
+    cr.set_source_pixbuf(pixbuf, 0.0, 0.0);
+    cr.paint().ok();
+    cr.set_line_width(LINE_WIDTH);
+    cr.set_line_cap(gtk::cairo::LineCap::Round);
+    cr.set_line_join(gtk::cairo::LineJoin::Round);
+    cr.select_font_face(
+        "Sans",
+        gtk::cairo::FontSlant::Normal,
+        gtk::cairo::FontWeight::Bold,
+    );
+    cr.set_font_size(TEXT_SIZE);
+
+    for annotation in annotations {
+        let (r, g, b) = parse_color(&annotation.color);
+        match &annotation.kind {
+            AnnotationKind::Arrow => {
+                cr.set_source_rgb(r, g, b);
+                cr.move_to(annotation.from.0, annotation.from.1);
+                cr.line_to(annotation.to.0, annotation.to.1);
+                if let Some([left, right]) = annotation.arrow_head(ARROW_HEAD_SIZE) {
+                    cr.move_to(left.0, left.1);
+                    cr.line_to(annotation.to.0, annotation.to.1);
+                    cr.line_to(right.0, right.1);
+                }
+                cr.stroke().ok();
+            }
+            AnnotationKind::Rect => {
+                let (x, y, width, height) = annotation.rect();
+                cr.set_source_rgb(r, g, b);
+                cr.rectangle(x, y, width, height);
+                cr.stroke().ok();
+            }
+            AnnotationKind::Text(text) => {
+                // Con borde blanco para que se lea sobre cualquier fondo
+                cr.move_to(annotation.from.0, annotation.from.1);
+                cr.text_path(text);
+                cr.set_source_rgb(1.0, 1.0, 1.0);
+                cr.stroke_preserve().ok();
+                cr.set_source_rgb(r, g, b);
+                cr.fill().ok();
+            }
+        }
+    }
+}
+
+/// Sobrescribe la captura con las anotaciones dibujadas encima
+fn save_annotated(
+    path: &Path,
+    pixbuf: &gtk::gdk_pixbuf::Pixbuf,
+    annotations: &[Annotation],
+) -> anyhow::Result<()> {
+    let (width, height) = (pixbuf.width(), pixbuf.height());
+    let surface = gtk::cairo::ImageSurface::create(gtk::cairo::Format::ARgb32, width, height)?;
+    {
+        let cr = gtk::cairo::Context::new(&surface)?;
+        draw_annotations(&cr, pixbuf, annotations);
+    }
+    let annotated = gtk::gdk::pixbuf_get_from_surface(&surface, 0, 0, width, height)
+        .ok_or_else(|| anyhow::anyhow!("no se pudo leer la imagen anotada"))?;
+    annotated.savev(path, "png", &[])?;
+    Ok(())
+}
+
+/// Ruta libre en assets para una captura nueva
+fn new_screenshot_path() -> anyhow::Result<PathBuf> {
+    let assets_dir = NotesConfig::ensure_assets_dir()?;
+    let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
+    let mut path = assets_dir.join(format!("{}{}.png", SCREENSHOT_PREFIX, timestamp));
+    let mut counter = 1;
+    while path.exists() {
+        path = assets_dir.join(format!(
+            "{}{}_{}.png",
+            SCREENSHOT_PREFIX, timestamp, counter
+        ));
+        counter += 1;
+    }
+    Ok(path)
+}
@@ -0,0 +1,319 @@
+//! Lienzo para bocetos a mano alzada
+//!
+//! Ventana con un `DrawingArea` que dibuja con ratón, dedo o lápiz. Con lápiz
+//! el grosor sigue la presión (eje `Pressure` del evento). Al guardar el boceto
+//! se escribe como SVG en assets (ver [`crate::core::sketch`]).
+
+use gtk::prelude::*;
+use relm4::gtk;
+use std::cell::{Cell, RefCell};
+use std::path::PathBuf;
+use std::rc::Rc;
+use tracing::error;
+
+use crate::core::{NotesConfig, Sketch, SketchPoint, Stroke};
+use crate::i18n::I18n;
+
+/// Colores del lápiz (el primero es el de por defecto)
+const PEN_COLORS: [&str; 5] = ["#1e1e2e", "#1e66f5", "#d20f39", "#40a02b", "#df8e1d"];
+/// Grosores del lápiz: fino, normal y grueso
+const PEN_WIDTHS: [f64; 3] = [2.0, 4.0, 8.0];
+
+/// Abre el lienzo. Con `existing` se sigue dibujando sobre ese boceto; si no,
+/// se empieza uno en blanco. `on_saved` recibe la ruta del SVG y si es nuevo.
+pub fn show_sketch_dialog(
+    parent: &gtk::ApplicationWindow,
+    i18n: &I18n,
+    existing: Option<PathBuf>,
+    on_saved: impl Fn(PathBuf, bool) + 'static,
+) {
+    let sketch = existing
+        .as_deref()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|svg| Sketch::from_svg(&svg))
+        .unwrap_or_default();
+    let sketch = Rc::new(RefCell::new(sketch));
+    let color = Rc::new(RefCell::new(PEN_COLORS[0].to_string()));
+    let width = Rc::new(Cell::new(PEN_WIDTHS[1]));
+
+    let dialog = gtk::Window::builder()
+        .transient_for(parent)
+        .modal(true)
+        .title(&i18n.t("sketch_title"))
+        .default_width(860)
+        .default_height(640)
+        .build();
+
+    let canvas = gtk::DrawingArea::new();
+    {
+        let sketch = sketch.borrow();
+        canvas.set_content_width(sketch.width as i32);
+        canvas.set_content_height(sketch.height as i32);
+    }
+    canvas.set_halign(gtk::Align::Center);
+    canvas.set_valign(gtk::Align::Center);
+    {
+        let sketch = sketch.clone();
+        canvas.set_draw_func(move |_, cr, _, _| draw_sketch(cr, &sketch.borrow()));
+    }
+
+    // Un trazo por arrastre; el ratón y el dedo dibujan con presión 1.0
+    let drag = gtk::GestureDrag::new();
+    {
+        let sketch = sketch.clone();
+        let color = color.clone();
+        let width = width.clone();
+        let canvas = canvas.clone();
+        drag.connect_drag_begin(move |gesture, x, y| {
+            sketch.borrow_mut().strokes.push(Stroke {
+                color: color.borrow().clone(),
+                width: width.get(),
+                points: vec![SketchPoint {
+                    x,
+                    y,
+                    pressure: current_pressure(gesture),
+                }],
+            });
+            canvas.queue_draw();
+        });
+    }
+    {
+        let sketch = sketch.clone();
+        let canvas = canvas.clone();
+        drag.connect_drag_update(move |gesture, offset_x, offset_y| {
+            let Some((start_x, start_y)) = gesture.start_point() else {
+                return;
+            };
+            if let Some(stroke) = sketch.borrow_mut().strokes.last_mut() {
+                stroke.points.push(SketchPoint {
+                    x: start_x + offset_x,
+                    y: start_y + offset_y,
+                    pressure: current_pressure(gesture),
+                });
+            }
+            canvas.queue_draw();
+        });
+    }
+    canvas.add_controller(drag);
+
+    let scrolled = gtk::ScrolledWindow::builder()
+        .vexpand(true)
+        .hexpand(true)
+        .child(&canvas)
+        .build();
+
+    // Barra de herramientas: colores, grosores, deshacer y borrar todo
+    let toolbar = gtk::Box::builder()
+        .orientation(gtk::Orientation::Horizontal)
+        .spacing(6)
+        .margin_start(12)
+        .margin_end(12)
+        .margin_top(8)
+        .margin_bottom(8)
+        .build();
+
+    let mut first_color: Option<gtk::ToggleButton> = None;
+    for pen_color in PEN_COLORS {
+        let swatch = gtk::DrawingArea::new();
+        swatch.set_content_width(16);
+        swatch.set_content_height(16);
+        let rgba = parse_color(pen_color);
+        swatch.set_draw_func(move |_, cr, w, h| {
+            cr.set_source_rgba(rgba.0, rgba.1, rgba.2, 1.0);
+            cr.arc(
+                f64::from(w) / 2.0,
+                f64::from(h) / 2.0,
+                f64::from(w.min(h)) / 2.0,
+                0.0,
+                std::f64::consts::TAU,
+            );
+            cr.fill().ok();
+        });
+
+        let button = gtk::ToggleButton::builder().child(&swatch).build();
+        button.add_css_class("flat");
+        match &first_color {
+            Some(first) => button.set_group(Some(first)),
+            None => {
+                button.set_active(true);
+                first_color = Some(button.clone());
+            }
+        }
+        let color = color.clone();
+        button.connect_toggled(move |button| {
+            if button.is_active() {
+                *color.borrow_mut() = pen_color.to_string();
+            }
+        });
+        toolbar.append(&button);
+    }
+
+    toolbar.append(&gtk::Separator::new(gtk::Orientation::Vertical));
+
+    let mut first_width: Option<gtk::ToggleButton> = None;
+    for (pen_width, label) in PEN_WIDTHS.into_iter().zip(["S", "M", "L"]) {
+        let button = gtk::ToggleButton::with_label(label);
+        button.add_css_class("flat");
+        match &first_width {
+            Some(first) => button.set_group(Some(first)),
+            None => first_width = Some(button.clone()),
+        }
+        button.set_active(pen_width == width.get());
+        let width = width.clone();
+        button.connect_toggled(move |button| {
+            if button.is_active() {
+                width.set(pen_width);
+            }
+        });
+        toolbar.append(&button);
+    }
+
+    let spacer = gtk::Box::new(gtk::Orientation::Horizontal, 0);
+    spacer.set_hexpand(true);
+    toolbar.append(&spacer);
+
+    let undo_button = gtk::Button::from_icon_name("edit-undo-symbolic");
+    undo_button.set_tooltip_text(Some(&i18n.t("sketch_undo")));
+    {
+        let sketch = sketch.clone();
+        let canvas = canvas.clone();
+        undo_button.connect_clicked(move |_| {
+            sketch.borrow_mut().strokes.pop();
+            canvas.queue_draw();
+        });
+    }
+    toolbar.append(&undo_button);
+
+    let clear_button = gtk::Button::from_icon_name("edit-clear-all-symbolic");
+    clear_button.set_tooltip_text(Some(&i18n.t("sketch_clear")));
+    {
+        let sketch = sketch.clone();
+        let canvas = canvas.clone();
+        clear_button.connect_clicked(move |_| {
+            sketch.borrow_mut().strokes.clear();
+            canvas.queue_draw();
+        });
+    }
+    toolbar.append(&clear_button);
+
+    let main_box = gtk::Box::new(gtk::Orientation::Vertical, 0);
+    main_box.append(&toolbar);
+    main_box.append(&scrolled);
+    dialog.set_child(Some(&main_box));
+
+    let header_bar = gtk::HeaderBar::new();
+    let save_button = gtk::Button::with_label(&i18n.t("sketch_save"));
+    save_button.add_css_class("suggested-action");
+    header_bar.pack_end(&save_button);
+    dialog.set_titlebar(Some(&header_bar));
+
+    let save: Rc<dyn Fn()> = {
+        let dialog = dialog.clone();
+        let sketch = sketch.clone();
+        Rc::new(move || {
+            let is_new = existing.is_none();
+            let path = match existing.clone().map_or_else(new_sketch_path, Ok) {
+                Ok(path) => path,
+                Err(e) => {
+                    error!("Error creando el archivo del boceto: {}", e);
+                    return;
+                }
+            };
+            if let Err(e) = std::fs::write(&path, sketch.borrow().to_svg()) {
+                error!("Error guardando el boceto {}: {}", path.display(), e);
+                return;
+            }
+            on_saved(path, is_new);
+            dialog.close();
+        })
+    };
+    {
+        let save = save.clone();
+        save_button.connect_clicked(move |_| save());
+    }
+
+    // Esc cierra sin guardar, Ctrl+S guarda y Ctrl+Z deshace el último trazo
+    let key_controller = gtk::EventControllerKey::new();
+    {
+        let dialog = dialog.clone();
+        let undo_button = undo_button.clone();
+        key_controller.connect_key_pressed(move |_, keyval, _, state| {
+            let ctrl = state.contains(gtk::gdk::ModifierType::CONTROL_MASK);
+            match keyval.name().as_deref() {
+                Some("Escape") => dialog.close(),
+                Some("s") if ctrl => save(),
+                Some("z") if ctrl => undo_button.emit_clicked(),
+                _ => return gtk::glib::Propagation::Proceed,
+            }
+            gtk::glib::Propagation::Stop
+        });
+    }
+    dialog.add_controller(key_controller);
+
+    dialog.present();
+}
+
+/// Presión del lápiz en el evento actual; 1.0 si el dispositivo no la da
+fn current_pressure(gesture: &gtk::GestureDrag) -> f64 {
+    gesture
+        .current_event()
+        .and_then(|event| event.axis(gtk::gdk::AxisUse::Pressure))
+        .filter(|pressure| *pressure > 0.0)
+        .unwrap_or(1.0)
+}
+
+fn draw_sketch(cr: &gtk::cairo::Context, sketch: &Sketch) {
+    cr.set_source_rgb(1.0, 1.0, 1.0);
+    cr.paint().ok();
+    cr.set_line_cap(gtk::cairo::LineCap::Round);
+
+    for stroke in &sketch.strokes {
+        let (r, g, b) = parse_color(&stroke.color);
+        cr.set_source_rgb(r, g, b);
+        match stroke.points.as_slice() {
+            [] => {}
+            [point] => {
+                cr.arc(
+                    point.x,
+                    point.y,
+                    stroke.width_at(point.pressure) / 2.0,
+                    0.0,
+                    std::f64::consts::TAU,
+                );
+                cr.fill().ok();
+            }
+            points => {
+                for pair in points.windows(2) {
+                    cr.set_line_width(stroke.width_at(pair[1].pressure));
+                    cr.move_to(pair[0].x, pair[0].y);
+                    cr.line_to(pair[1].x, pair[1].y);
+                    cr.stroke().ok();
+                }
+            }
+        }
+    }
+}
+
+/// Color CSS a RGB para cairo (negro si no se entiende)
+pub fn parse_color(color: &str) -> (f64, f64, f64) {
+    gtk::gdk::RGBA::parse(color).map_or((0.0, 0.0, 0.0), |rgba| {
+        (
+            f64::from(rgba.red()),
+            f64::from(rgba.green()),
+            f64::from(rgba.blue()),
+        )
+    })
+}
+
+/// Ruta libre en assets para un boceto nuevo
+fn new_sketch_path() -> anyhow::Result<PathBuf> {
+    let assets_dir = NotesConfig::ensure_assets_dir()?;
+    let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
+    let mut path = assets_dir.join(format!("sketch_{}.svg", timestamp));
+    let mut counter = 1;
+    while path.exists() {
+        path = assets_dir.join(format!("sketch_{}_{}.svg", timestamp, counter));
+        counter += 1;
+    }
+    Ok(path)
+}
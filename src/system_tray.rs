@@ -6,14 +6,16 @@
 //
 // El icono aparece cuando la ventana está oculta y permite:
 // - Click izquierdo: Mostrar/ocultar ventana
-// - Click derecho: Menú con opciones (Mostrar, Ocultar, Salir)
+// - Click derecho: Menú con opciones (Mostrar, Ocultar, perfiles, Salir)
 
 use crate::app::AppMsg;
+use crate::core::{OPEN_COMMAND_PREFIX, OpenTarget, ScreenshotTarget};
 use crate::i18n::I18n;
 use relm4::ComponentSender;
 use relm4::gtk::glib;
-use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use tracing::{debug, info, warn};
 
 const CONTROL_FILE: &str = "/tmp/notnative.control";
 
@@ -22,6 +24,36 @@ struct NotNativeTray {
     sender: ComponentSender<crate::app::MainApp>,
     is_visible: Arc<AtomicBool>,
     i18n: Arc<std::sync::Mutex<I18n>>,
+    /// Perfiles de configuración y si es el activo
+    profiles: Vec<(String, bool)>,
+}
+
+#[derive(Default)]
+struct TrayState {
+    /// Disponible cuando el servicio de la bandeja arranca
+    handle: Option<ksni::Handle<NotNativeTray>>,
+    profiles: Vec<(String, bool)>,
+}
+
+/// Acceso al icono de la bandeja desde la app
+#[derive(Clone, Default)]
+pub struct TrayHandle(Arc<Mutex<TrayState>>);
+
+impl std::fmt::Debug for TrayHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("TrayHandle")
+    }
+}
+
+impl TrayHandle {
+    /// Actualiza el submenú de perfiles
+    pub fn set_profiles(&self, profiles: Vec<(String, bool)>) {
+        let mut state = self.0.lock().unwrap();
+        state.profiles = profiles.clone();
+        if let Some(handle) = state.handle.as_ref() {
+            handle.update(|tray| tray.profiles = profiles);
+        }
+    }
 }
 
 impl ksni::Tray for NotNativeTray {
@@ -63,10 +95,13 @@ impl ksni::Tray for NotNativeTray {
         let i18n = self.i18n.lock().unwrap();
         let show_label = i18n.t("tray_show_window");
         let hide_label = i18n.t("tray_hide_window");
+        let compact_label = i18n.t("tray_compact_mode");
+        let screenshot_label = i18n.t("tray_screenshot");
         let quit_label = i18n.t("tray_quit");
+        let profiles_label = i18n.t("profiles");
         drop(i18n); // Liberar el lock antes de crear el menú
 
-        vec![
+        let mut items = vec![
             StandardItem {
                 label: show_label,
                 icon_name: "window-restore".to_string(),
@@ -87,7 +122,57 @@ impl ksni::Tray for NotNativeTray {
                 ..Default::default()
             }
             .into(),
-            MenuItem::Separator,
+            StandardItem {
+                label: compact_label,
+                icon_name: "view-restore".to_string(),
+                activate: Box::new(|this: &mut Self| {
+                    this.is_visible.store(true, Ordering::Relaxed);
+                    this.sender.input(AppMsg::ToggleCompactMode);
+                }),
+                ..Default::default()
+            }
+            .into(),
+            StandardItem {
+                label: screenshot_label,
+                icon_name: "applets-screenshooter".to_string(),
+                activate: Box::new(|this: &mut Self| {
+                    this.sender
+                        .input(AppMsg::CaptureScreenshot(ScreenshotTarget::QuickNote));
+                }),
+                ..Default::default()
+            }
+            .into(),
+        ];
+
+        if !self.profiles.is_empty() {
+            items.push(MenuItem::Separator);
+            items.push(
+                SubMenu {
+                    label: profiles_label,
+                    submenu: self
+                        .profiles
+                        .iter()
+                        .map(|(name, active)| {
+                            let name = name.clone();
+                            CheckmarkItem {
+                                label: name.clone(),
+                                checked: *active,
+                                activate: Box::new(move |this: &mut Self| {
+                                    this.sender.input(AppMsg::SwitchProfile(name.clone()));
+                                }),
+                                ..Default::default()
+                            }
+                            .into()
+                        })
+                        .collect(),
+                    ..Default::default()
+                }
+                .into(),
+            );
+        }
+
+        items.push(MenuItem::Separator);
+        items.push(
             StandardItem {
                 label: quit_label,
                 icon_name: "application-exit".to_string(),
@@ -97,23 +182,24 @@ impl ksni::Tray for NotNativeTray {
                 ..Default::default()
             }
             .into(),
-        ]
+        );
+        items
     }
 
     fn activate(&mut self, _x: i32, _y: i32) {
         // Click izquierdo: Toggle ventana
         let current = self.is_visible.load(Ordering::Relaxed);
-        println!(
-            "🖱️  Click en icono de bandeja (ventana actualmente {})",
+        info!(
+            "Click en icono de bandeja (ventana actualmente {})",
             if current { "visible" } else { "oculta" }
         );
 
         if current {
-            println!("   → Ocultando ventana");
+            debug!("Ocultando ventana");
             self.is_visible.store(false, Ordering::Relaxed);
             self.sender.input(AppMsg::MinimizeToTray);
         } else {
-            println!("   → Mostrando ventana");
+            debug!("Mostrando ventana");
             self.is_visible.store(true, Ordering::Relaxed);
             self.sender.input(AppMsg::ShowWindow);
         }
@@ -121,7 +207,7 @@ impl ksni::Tray for NotNativeTray {
 
     fn secondary_activate(&mut self, _x: i32, _y: i32) {
         // Click del medio: Siempre mostrar
-        println!("🖱️  Click medio en icono de bandeja → Mostrando ventana");
+        info!("Click medio en icono de bandeja → Mostrando ventana");
         self.is_visible.store(true, Ordering::Relaxed);
         self.sender.input(AppMsg::ShowWindow);
     }
@@ -131,7 +217,7 @@ pub fn create_system_tray(
     sender: ComponentSender<crate::app::MainApp>,
     i18n: std::rc::Rc<std::cell::RefCell<I18n>>,
     window_visible: Arc<AtomicBool>,
-) {
+) -> TrayHandle {
     // Limpiar archivo de control si existe
     let _ = std::fs::remove_file(CONTROL_FILE);
 
@@ -148,29 +234,34 @@ pub fn create_system_tray(
 
     // Intentar crear el icono de bandeja en un thread separado
     let sender_clone = sender.clone();
+    let tray_handle = TrayHandle::default();
+    let tray_state = Arc::clone(&tray_handle.0);
     std::thread::spawn(move || {
-        println!("🔧 Intentando crear icono de bandeja del sistema...");
+        info!("Intentando crear icono de bandeja del sistema...");
 
+        // Con el estado bloqueado no se pierde un cambio de perfiles mientras
+        // arranca el servicio
+        let mut state = tray_state.lock().unwrap();
         let tray = NotNativeTray {
             sender: sender_clone,
             is_visible: is_visible_clone,
             i18n: i18n_clone,
+            profiles: state.profiles.clone(),
         };
 
-        println!("🔧 TrayService creando...");
+        info!("TrayService creando...");
         let service = ksni::TrayService::new(tray);
+        state.handle = Some(service.handle());
+        drop(state);
 
-        println!("✅ Icono de bandeja del sistema inicializado (StatusNotifierItem)");
-        println!("💡 El icono debería aparecer en tu panel/barra de sistema");
-        println!("   Compatible con: waybar, swaybar, KDE Plasma, AGS (con widget systray)");
-        println!();
-        println!("⚠️  Si NO aparece el icono:");
-        println!("   - Verifica que tu barra soporte StatusNotifierItem (SNI)");
-        println!("   - En AGS: Asegúrate de tener el widget 'systemtray' configurado");
-        println!("   - Usa el script de control: notnative-control.sh show");
-        println!(
-            "   - O crea un atajo: bind = SUPER, N, exec, echo 'toggle' > /tmp/notnative.control"
-        );
+        info!("Icono de bandeja del sistema inicializado (StatusNotifierItem)");
+        warn!("El icono debería aparecer en tu panel/barra de sistema");
+        debug!("Compatible con: waybar, swaybar, KDE Plasma, AGS (con widget systray)");
+        warn!("Si NO aparece el icono:");
+        debug!("- Verifica que tu barra soporte StatusNotifierItem (SNI)");
+        debug!("- En AGS: Asegúrate de tener el widget 'systemtray' configurado");
+        debug!("- Usa el script de control: notnative-control.sh show");
+        debug!("- O crea un atajo: bind = SUPER, N, exec, echo 'toggle' > /tmp/notnative.control");
 
         // spawn() no retorna nada, simplemente bloquea el thread
         // Mantener el servicio vivo
@@ -186,44 +277,69 @@ pub fn create_system_tray(
         if std::path::Path::new(CONTROL_FILE).exists() {
             if let Ok(command) = std::fs::read_to_string(CONTROL_FILE) {
                 let command = command.trim();
-                println!("📱 Comando recibido del archivo de control: '{}'", command);
+                info!("Comando recibido del archivo de control: '{}'", command);
 
                 match command {
                     "show" => {
                         is_visible.store(true, Ordering::Relaxed);
                         sender.input(AppMsg::ShowWindow);
-                        println!("   ➜ Ejecutando: Mostrar ventana");
+                        debug!("Ejecutando: Mostrar ventana");
                     }
                     "hide" => {
                         is_visible.store(false, Ordering::Relaxed);
                         sender.input(AppMsg::MinimizeToTray);
-                        println!("   ➜ Ejecutando: Ocultar ventana");
+                        debug!("Ejecutando: Ocultar ventana");
                     }
                     "toggle" => {
                         let current = is_visible.load(Ordering::Relaxed);
                         is_visible.store(!current, Ordering::Relaxed);
                         if current {
                             sender.input(AppMsg::MinimizeToTray);
-                            println!("   ➜ Ejecutando: Ocultar ventana (toggle)");
+                            debug!("Ejecutando: Ocultar ventana (toggle)");
                         } else {
                             sender.input(AppMsg::ShowWindow);
-                            println!("   ➜ Ejecutando: Mostrar ventana (toggle)");
+                            debug!("Ejecutando: Mostrar ventana (toggle)");
                         }
                     }
+                    "mini" => {
+                        is_visible.store(true, Ordering::Relaxed);
+                        sender.input(AppMsg::ToggleCompactMode);
+                        debug!("Ejecutando: Alternar modo compacto");
+                    }
                     "quicknote" => {
                         sender.input(AppMsg::ToggleQuickNote);
-                        println!("   ➜ Ejecutando: Toggle Quick Note");
+                        debug!("Ejecutando: Toggle Quick Note");
                     }
                     "quicknote-new" => {
                         sender.input(AppMsg::NewQuickNote);
-                        println!("   ➜ Ejecutando: Nueva Quick Note");
+                        debug!("Ejecutando: Nueva Quick Note");
+                    }
+                    "capture" => {
+                        sender.input(AppMsg::CaptureClipboard);
+                        debug!("Ejecutando: Capturar portapapeles en la Inbox");
+                    }
+                    "screenshot" => {
+                        sender.input(AppMsg::CaptureScreenshot(ScreenshotTarget::QuickNote));
+                        debug!("Ejecutando: Captura de pantalla a una Quick Note");
                     }
                     "quit" => {
                         sender.input(AppMsg::QuitApp);
-                        println!("   ➜ Ejecutando: Salir");
+                        debug!("Ejecutando: Salir");
                     }
                     _ => {
-                        eprintln!("⚠️  Comando desconocido: '{}'", command);
+                        if let Some(name) = command.strip_prefix("profile:") {
+                            sender.input(AppMsg::SwitchProfile(name.trim().to_string()));
+                            debug!("Ejecutando: Cambiar al perfil '{}'", name.trim());
+                        } else if let Some(target) = command
+                            .strip_prefix(OPEN_COMMAND_PREFIX)
+                            .and_then(OpenTarget::parse)
+                        {
+                            is_visible.store(true, Ordering::Relaxed);
+                            debug!("Ejecutando: Abrir {:?}", target);
+                            sender.input(AppMsg::OpenExternal(target));
+                        } else {
+                            warn!("Comando desconocido: '{}'", command);
+                        }
                     }
                 }
                 // Limpiar el archivo después de leer
@@ -233,18 +349,21 @@ pub fn create_system_tray(
             // Solo mostrar cada 120 iteraciones (cada minuto) para no spamear
             let count = monitor_counter_clone.fetch_add(1, Ordering::Relaxed);
             if count == 0 {
-                println!(
-                    "🔄 Sistema de control por archivo activo (monitoreando /tmp/notnative.control)"
+                info!(
+                    "Sistema de control por archivo activo (monitoreando /tmp/notnative.control)"
                 );
             }
         }
         glib::ControlFlow::Continue
     });
 
-    println!("✅ Sistema de control inicializado");
-    println!(
-        "💡 Controla la app con: echo 'show|hide|toggle|quicknote|quicknote-new|quit' > /tmp/notnative.control"
+    info!("Sistema de control inicializado");
+    warn!(
+        "Controla la app con: echo 'show|hide|toggle|quicknote|quicknote-new|quit' > /tmp/notnative.control"
     );
-    println!("💡 O usa el icono de la bandeja del sistema si está disponible");
-    println!("📝 Quick Notes: echo 'quicknote' > /tmp/notnative.control");
+    warn!("O usa el icono de la bandeja del sistema si está disponible");
+    info!("Quick Notes: echo 'quicknote' > /tmp/notnative.control");
+    info!("Perfiles: echo 'profile:<nombre>' > /tmp/notnative.control");
+
+    tray_handle
 }
@@ -0,0 +1,247 @@
+//! Avisos toast de la ventana principal
+//!
+//! Los avisos se encolan y se muestran de uno en uno sobre el editor. Cada
+//! uno tiene una gravedad (cambia el color y el icono), una acción opcional
+//! (p. ej. "Deshacer" o "Abrir") que envía un `AppMsg`, y su propio tiempo en
+//! pantalla. El botón de cerrar pasa al siguiente aviso de la cola.
+
+use gtk::prelude::*;
+use relm4::gtk;
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+use std::rc::Rc;
+use std::time::Duration;
+
+use crate::app::AppMsg;
+
+/// Avisos que pueden esperar en cola; si llegan más se descartan los viejos
+const MAX_PENDING: usize = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastSeverity {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl ToastSeverity {
+    const ALL: [ToastSeverity; 4] = [Self::Info, Self::Success, Self::Warning, Self::Error];
+
+    fn css_class(self) -> &'static str {
+        match self {
+            Self::Info => "toast-info",
+            Self::Success => "toast-success",
+            Self::Warning => "toast-warning",
+            Self::Error => "toast-error",
+        }
+    }
+
+    fn icon_name(self) -> &'static str {
+        match self {
+            Self::Info => "dialog-information-symbolic",
+            Self::Success => "emblem-ok-symbolic",
+            Self::Warning => "dialog-warning-symbolic",
+            Self::Error => "dialog-error-symbolic",
+        }
+    }
+
+    /// Tiempo por defecto: los errores se quedan más para poder leerlos
+    fn default_timeout(self) -> Duration {
+        match self {
+            Self::Info | Self::Success => Duration::from_secs(3),
+            Self::Warning => Duration::from_secs(5),
+            Self::Error => Duration::from_secs(8),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Toast {
+    pub message: String,
+    pub severity: ToastSeverity,
+    /// Texto del botón y mensaje que envía al pulsarlo
+    pub action: Option<(String, AppMsg)>,
+    /// `None` usa el de la gravedad (8 segundos como mínimo si hay acción)
+    pub timeout: Option<Duration>,
+}
+
+impl Toast {
+    pub fn new(message: impl Into<String>, severity: ToastSeverity) -> Self {
+        Self {
+            message: message.into(),
+            severity,
+            action: None,
+            timeout: None,
+        }
+    }
+
+    pub fn with_action(mut self, label: impl Into<String>, message: AppMsg) -> Self {
+        self.action = Some((label.into(), message));
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    fn duration(&self) -> Duration {
+        self.timeout.unwrap_or_else(|| {
+            let timeout = self.severity.default_timeout();
+            if self.action.is_some() {
+                timeout.max(Duration::from_secs(8))
+            } else {
+                timeout
+            }
+        })
+    }
+}
+
+struct ToastWidgets {
+    revealer: gtk::Revealer,
+    container: gtk::Box,
+    icon: gtk::Image,
+    label: gtk::Label,
+    action_button: gtk::Button,
+}
+
+/// Cola de avisos sobre el revealer de la ventana (barata de clonar)
+#[derive(Clone)]
+pub struct ToastQueue {
+    widgets: Rc<ToastWidgets>,
+    pending: Rc<RefCell<VecDeque<Toast>>>,
+    /// Mensaje del botón del aviso visible
+    action: Rc<RefCell<Option<AppMsg>>>,
+    /// Cada aviso mostrado lo sube: un temporizador viejo no cierra el siguiente
+    serial: Rc<Cell<u32>>,
+    showing: Rc<Cell<bool>>,
+}
+
+impl std::fmt::Debug for ToastQueue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ToastQueue").finish()
+    }
+}
+
+impl ToastQueue {
+    pub fn new(
+        revealer: gtk::Revealer,
+        container: gtk::Box,
+        icon: gtk::Image,
+        label: gtk::Label,
+        action_button: gtk::Button,
+        close_button: gtk::Button,
+        sender: relm4::Sender<AppMsg>,
+    ) -> Self {
+        let queue = Self {
+            widgets: Rc::new(ToastWidgets {
+                revealer,
+                container,
+                icon,
+                label,
+                action_button,
+            }),
+            pending: Rc::new(RefCell::new(VecDeque::new())),
+            action: Rc::new(RefCell::new(None)),
+            serial: Rc::new(Cell::new(0)),
+            showing: Rc::new(Cell::new(false)),
+        };
+
+        {
+            let queue = queue.clone();
+            queue
+                .widgets
+                .action_button
+                .clone()
+                .connect_clicked(move |_| {
+                    if let Some(message) = queue.action.borrow_mut().take() {
+                        sender.emit(message);
+                    }
+                    queue.dismiss();
+                });
+        }
+        {
+            let queue = queue.clone();
+            close_button.connect_clicked(move |_| queue.dismiss());
+        }
+
+        queue
+    }
+
+    /// Muestra el aviso ahora o, si ya hay uno en pantalla, lo pone a la cola
+    pub fn push(&self, toast: Toast) {
+        if !self.showing.get() {
+            self.show(toast);
+            return;
+        }
+        let mut pending = self.pending.borrow_mut();
+        // El mismo aviso repetido seguido no se encola dos veces
+        if pending
+            .back()
+            .is_some_and(|last| last.message == toast.message && last.severity == toast.severity)
+        {
+            return;
+        }
+        if pending.len() >= MAX_PENDING {
+            pending.pop_front();
+        }
+        pending.push_back(toast);
+    }
+
+    /// Cierra el aviso visible y, tras la animación, muestra el siguiente
+    pub fn dismiss(&self) {
+        if !self.widgets.revealer.reveals_child() {
+            return;
+        }
+        self.serial.set(self.serial.get().wrapping_add(1));
+        self.action.borrow_mut().take();
+        self.widgets.revealer.set_reveal_child(false);
+
+        let queue = self.clone();
+        let transition =
+            Duration::from_millis(u64::from(self.widgets.revealer.transition_duration()));
+        gtk::glib::timeout_add_local_once(transition, move || {
+            let next = queue.pending.borrow_mut().pop_front();
+            match next {
+                Some(toast) => queue.show(toast),
+                None => queue.showing.set(false),
+            }
+        });
+    }
+
+    fn show(&self, toast: Toast) {
+        let widgets = &self.widgets;
+        for severity in ToastSeverity::ALL {
+            widgets.container.remove_css_class(severity.css_class());
+        }
+        widgets.container.add_css_class(toast.severity.css_class());
+        widgets.icon.set_icon_name(Some(toast.severity.icon_name()));
+        widgets.label.set_label(&toast.message);
+
+        let duration = toast.duration();
+        match toast.action {
+            Some((label, message)) => {
+                widgets.action_button.set_label(&label);
+                widgets.action_button.set_visible(true);
+                *self.action.borrow_mut() = Some(message);
+            }
+            None => {
+                widgets.action_button.set_visible(false);
+                *self.action.borrow_mut() = None;
+            }
+        }
+
+        self.showing.set(true);
+        widgets.revealer.set_reveal_child(true);
+
+        let serial = self.serial.get().wrapping_add(1);
+        self.serial.set(serial);
+        let queue = self.clone();
+        gtk::glib::timeout_add_local_once(duration, move || {
+            if queue.serial.get() == serial {
+                queue.dismiss();
+            }
+        });
+    }
+}
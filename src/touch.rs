@@ -0,0 +1,126 @@
+//! Gestos táctiles para tablets y 2 en 1
+//!
+//! Los gestos de deslizar y mantener pulsado son solo táctiles (`set_touch_only`),
+//! así que con ratón y teclado la app se comporta igual que siempre. El pellizco
+//! también responde al gesto de dos dedos del touchpad.
+
+use relm4::gtk::{self, prelude::*};
+use std::cell::Cell;
+use std::ops::RangeInclusive;
+use std::rc::Rc;
+
+/// Ancho del borde izquierdo desde el que se puede abrir el sidebar
+const EDGE_WIDTH: f64 = 24.0;
+/// Recorrido horizontal mínimo para abrir o cerrar el sidebar
+const SWIPE_DISTANCE: f64 = 60.0;
+/// Clase CSS de la ventana con botones y filas grandes
+const LARGE_TAP_TARGETS_CLASS: &str = "large-tap-targets";
+
+/// Deslizar desde el borde izquierdo abre el sidebar y deslizar hacia la
+/// izquierda sobre él lo cierra. `sidebar_width` da el ancho actual (0 si está
+/// cerrado) y `on_swipe` recibe `true` para abrir y `false` para cerrar.
+pub fn connect_sidebar_swipe(
+    widget: &impl IsA<gtk::Widget>,
+    sidebar_width: impl Fn() -> i32 + 'static,
+    on_swipe: impl Fn(bool) + 'static,
+) {
+    let gesture = gtk::GestureDrag::new();
+    gesture.set_touch_only(true);
+    // En CAPTURE para verlo antes que el editor y los ScrolledWindow. Solo se
+    // reclama la secuencia si el gesto es claramente horizontal.
+    gesture.set_propagation_phase(gtk::PropagationPhase::Capture);
+
+    // Lo que haría el gesto en curso según dónde empezó (true = abrir)
+    let direction = Rc::new(Cell::new(None::<bool>));
+    {
+        let direction = direction.clone();
+        gesture.connect_drag_begin(move |_, start_x, _| {
+            let width = f64::from(sidebar_width());
+            direction.set(if width <= 0.0 {
+                (start_x <= EDGE_WIDTH).then_some(true)
+            } else {
+                (start_x <= width).then_some(false)
+            });
+        });
+    }
+    gesture.connect_drag_update(move |gesture, offset_x, offset_y| {
+        let Some(open) = direction.get() else {
+            return;
+        };
+        let horizontal = offset_x.abs() > offset_y.abs() * 2.0;
+        let far_enough = if open {
+            offset_x >= SWIPE_DISTANCE
+        } else {
+            offset_x <= -SWIPE_DISTANCE
+        };
+        if horizontal && far_enough {
+            direction.set(None);
+            gesture.set_state(gtk::EventSequenceState::Claimed);
+            on_swipe(open);
+        }
+    });
+    widget.add_controller(gesture);
+}
+
+/// Pellizco para cambiar una escala dentro de `range`. `current` da la escala
+/// al empezar; `apply` recibe la nueva durante el gesto (`false`) y la final
+/// al soltar (`true`, para guardarla).
+pub fn connect_pinch_zoom(
+    widget: &impl IsA<gtk::Widget>,
+    range: RangeInclusive<f64>,
+    current: impl Fn() -> f64 + 'static,
+    apply: impl Fn(f64, bool) + 'static,
+) {
+    let gesture = gtk::GestureZoom::new();
+    // Antes que el widget, para que WebKit no haga su propio zoom de página
+    gesture.set_propagation_phase(gtk::PropagationPhase::Capture);
+
+    let start_scale = Rc::new(Cell::new(1.0));
+    let last_scale = Rc::new(Cell::new(None::<f64>));
+    {
+        let start_scale = start_scale.clone();
+        let last_scale = last_scale.clone();
+        gesture.connect_begin(move |gesture, _| {
+            gesture.set_state(gtk::EventSequenceState::Claimed);
+            start_scale.set(current());
+            last_scale.set(None);
+        });
+    }
+    let apply = Rc::new(apply);
+    {
+        let apply = apply.clone();
+        let last_scale = last_scale.clone();
+        gesture.connect_scale_changed(move |_, delta| {
+            let scale = (start_scale.get() * delta).clamp(*range.start(), *range.end());
+            last_scale.set(Some(scale));
+            apply(scale, false);
+        });
+    }
+    gesture.connect_end(move |_, _| {
+        if let Some(scale) = last_scale.take() {
+            apply(scale, true);
+        }
+    });
+    widget.add_controller(gesture);
+}
+
+/// Mantener pulsado con el dedo equivale al clic derecho. Se reclama la
+/// secuencia para que al levantar el dedo no se active también la fila.
+pub fn connect_long_press(widget: &impl IsA<gtk::Widget>, on_press: impl Fn(f64, f64) + 'static) {
+    let gesture = gtk::GestureLongPress::new();
+    gesture.set_touch_only(true);
+    gesture.connect_pressed(move |gesture, x, y| {
+        gesture.set_state(gtk::EventSequenceState::Claimed);
+        on_press(x, y);
+    });
+    widget.add_controller(gesture);
+}
+
+/// Activa o desactiva los botones y filas grandes (ver `style.css`)
+pub fn set_large_tap_targets(window: &impl IsA<gtk::Widget>, enabled: bool) {
+    if enabled {
+        window.add_css_class(LARGE_TAP_TARGETS_CLASS);
+    } else {
+        window.remove_css_class(LARGE_TAP_TARGETS_CLASS);
+    }
+}
@@ -0,0 +1,60 @@
+//! Recordar el tamaño de las ventanas entre sesiones
+//!
+//! Ver `crate::core::window_state`. Se lee el tamaño sin maximizar
+//! (`default-width`/`default-height`, que en GTK4 siguen al redimensionar) y
+//! el monitor en el que está la ventana; al restaurar, el tamaño se ajusta a
+//! ese monitor, o al primero si ya no está conectado.
+
+use relm4::gtk::{self, gdk, prelude::*};
+
+use crate::core::WindowGeometry;
+
+/// Monitor con ese conector, o el primero si no está
+fn find_monitor(display: &gdk::Display, connector: Option<&str>) -> Option<gdk::Monitor> {
+    let monitors: Vec<gdk::Monitor> = display
+        .monitors()
+        .iter::<gdk::Monitor>()
+        .filter_map(Result::ok)
+        .collect();
+    connector
+        .and_then(|connector| {
+            monitors
+                .iter()
+                .find(|monitor| monitor.connector().as_deref() == Some(connector))
+                .cloned()
+        })
+        .or_else(|| monitors.into_iter().next())
+}
+
+/// Tamaño, estado y monitor actuales de la ventana
+pub fn capture(window: &impl IsA<gtk::Window>) -> WindowGeometry {
+    let window = window.as_ref();
+    let (width, height) = window.default_size();
+    let monitor = window.surface().and_then(|surface| {
+        WidgetExt::display(window)
+            .monitor_at_surface(&surface)
+            .and_then(|monitor| monitor.connector())
+            .map(|connector| connector.to_string())
+    });
+    WindowGeometry {
+        width,
+        height,
+        maximized: window.is_maximized(),
+        monitor,
+    }
+}
+
+/// Abre la ventana con el tamaño guardado, sin pasarse de su monitor
+pub fn restore(window: &impl IsA<gtk::Window>, geometry: &WindowGeometry) {
+    let window = window.as_ref();
+    let monitor =
+        find_monitor(&WidgetExt::display(window), geometry.monitor.as_deref()).map(|monitor| {
+            let area = monitor.geometry();
+            (area.width(), area.height())
+        });
+    let (width, height) = geometry.fitted(monitor);
+    window.set_default_size(width, height);
+    if geometry.maximized {
+        window.maximize();
+    }
+}
@@ -1,12 +1,15 @@
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 use std::thread;
+use tracing::{debug, info, warn};
 
 /// Servidor HTTP ligero para servir páginas de embed de YouTube
 #[derive(Debug)]
 pub struct YouTubeEmbedServer {
     port: u16,
     videos: Arc<Mutex<HashMap<String, String>>>,
+    started: AtomicBool,
 }
 
 impl YouTubeEmbedServer {
@@ -15,11 +18,16 @@ impl YouTubeEmbedServer {
         Self {
             port,
             videos: Arc::new(Mutex::new(HashMap::new())),
+            started: AtomicBool::new(false),
         }
     }
 
-    /// Registra un video y retorna la URL local para cargarlo
+    /// Registra un video y retorna la URL local para cargarlo.
+    /// Arranca el servidor la primera vez (las notas sin videos no lo necesitan).
     pub fn register_video(&self, video_id: String) -> String {
+        if let Err(e) = self.start() {
+            warn!("Error iniciando servidor YouTube: {}", e);
+        }
         let html = Self::generate_embed_html(&video_id);
         self.videos.lock().unwrap().insert(video_id.clone(), html);
         format!("http://localhost:{}/video/{}", self.port, video_id)
@@ -75,27 +83,38 @@ impl YouTubeEmbedServer {
         )
     }
 
-    /// Inicia el servidor en un thread separado
+    /// Inicia el servidor en un thread separado (solo la primera vez)
     pub fn start(&self) -> Result<(), Box<dyn std::error::Error>> {
+        if self.started.swap(true, Ordering::SeqCst) {
+            return Ok(());
+        }
+
         let videos = Arc::clone(&self.videos);
         let port = self.port;
 
-        thread::spawn(move || {
-            let server = tiny_http::Server::http(format!("127.0.0.1:{}", port))
-                .expect("Failed to start YouTube embed server");
+        // Escuchar antes de devolver: el primer video se carga justo después
+        let server = match tiny_http::Server::http(format!("127.0.0.1:{}", port)) {
+            Ok(server) => server,
+            Err(e) => {
+                // Se reintenta con el siguiente video
+                self.started.store(false, Ordering::SeqCst);
+                return Err(e);
+            }
+        };
 
-            println!("YouTube embed server running on http://localhost:{}", port);
+        thread::spawn(move || {
+            info!("YouTube embed server running on http://localhost:{}", port);
 
             for request in server.incoming_requests() {
                 let url = request.url();
-                println!("DEBUG SERVER: Request recibido: {}", url);
+                debug!("SERVER: Request recibido: {}", url);
 
                 // Extraer video_id de la URL /video/{video_id}
                 if let Some(video_id) = url.strip_prefix("/video/") {
                     let videos_lock = videos.lock().unwrap();
 
                     if let Some(html) = videos_lock.get(video_id) {
-                        println!("DEBUG SERVER: Sirviendo video: {}", video_id);
+                        debug!("SERVER: Sirviendo video: {}", video_id);
                         let response = tiny_http::Response::from_string(html.clone())
                             .with_header(
                                 tiny_http::Header::from_bytes(
@@ -120,13 +139,13 @@ impl YouTubeEmbedServer {
                             );
                         let _ = request.respond(response);
                     } else {
-                        println!("DEBUG SERVER: Video no encontrado: {}", video_id);
+                        warn!("SERVER: Video no encontrado: {}", video_id);
                         let response = tiny_http::Response::from_string("Video not found")
                             .with_status_code(404);
                         let _ = request.respond(response);
                     }
                 } else {
-                    println!("DEBUG SERVER: Path no reconocido: {}", url);
+                    debug!("SERVER: Path no reconocido: {}", url);
                     let response =
                         tiny_http::Response::from_string("Not found").with_status_code(404);
                     let _ = request.respond(response);
@@ -0,0 +1,408 @@
+//! Tests de integración en modo headless (sin servidor gráfico)
+//!
+//! cargo test --no-default-features --features sqlite-bundled,headless
+
+use chrono::{Duration, Utc};
+use notnative_app::core::{EditorMode, KeyModifiers, extract_all_tags};
+use notnative_app::headless::HeadlessApp;
+use notnative_app::i18n::{I18n, Language};
+use notnative_app::mcp::{MCPChange, MCPToolCall};
+use notnative_app::reminders::{
+    Priority, ReminderNotifier, ReminderOutcome, ReminderScheduler, ReminderStatus, RepeatPattern,
+};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+/// Directorio temporal propio de cada test; las notas van en `notes/` y la base
+/// de datos queda a su lado, como en la instalación real
+struct TempVault(PathBuf);
+
+impl TempVault {
+    fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!(
+            "notnative-headless-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&path);
+        std::fs::create_dir_all(&path).unwrap();
+        Self(path)
+    }
+
+    fn app(&self) -> HeadlessApp {
+        HeadlessApp::new(self.0.join("notes")).unwrap()
+    }
+}
+
+impl Drop for TempVault {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+fn ctrl() -> KeyModifiers {
+    KeyModifiers {
+        ctrl: true,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_note_crud() {
+    let vault = TempVault::new("crud");
+    let mut app = vault.app();
+
+    app.create_note("Diario", "# Diario\n", None).unwrap();
+    app.create_note("Idea", "Una idea", Some("Proyectos"))
+        .unwrap();
+    assert_eq!(app.notes_dir().list_notes().unwrap().len(), 2);
+    assert!(app.notes_db().get_note("Diario").unwrap().is_some());
+
+    // Editar con el parser de comandos y guardar con Ctrl+S
+    app.open_note("Diario").unwrap();
+    app.press("G", KeyModifiers::default()).unwrap();
+    app.press("i", KeyModifiers::default()).unwrap();
+    assert_eq!(app.mode(), EditorMode::Insert);
+    app.type_text("Hoy llovió").unwrap();
+    app.press("Escape", KeyModifiers::default()).unwrap();
+    assert_eq!(app.mode(), EditorMode::Normal);
+    app.press("s", ctrl()).unwrap();
+
+    let note = app.notes_dir().find_note("Diario").unwrap().unwrap();
+    assert_eq!(note.read().unwrap(), "# Diario\nHoy llovió");
+
+    // Undo y borrado de línea operan sobre el buffer
+    app.press("u", KeyModifiers::default()).unwrap();
+    assert_eq!(app.text(), "# Diario\nHoy llovi");
+    app.press("g", KeyModifiers::default()).unwrap();
+    app.press("g", KeyModifiers::default()).unwrap();
+    app.press("d", KeyModifiers::default()).unwrap();
+    app.press("d", KeyModifiers::default()).unwrap();
+    assert_eq!(app.text(), "Hoy llovi");
+
+    app.delete_note("Diario").unwrap();
+    assert!(app.notes_dir().find_note("Diario").unwrap().is_none());
+    assert!(app.notes_db().get_note("Diario").unwrap().is_none());
+    assert!(app.current_note().is_none());
+}
+
+#[test]
+fn test_lists_continue_like_the_editor() {
+    let vault = TempVault::new("lists");
+    let mut app = vault.app();
+
+    app.create_note("Compra", "- pan", None).unwrap();
+    app.open_note("Compra").unwrap();
+    app.press("$", KeyModifiers::default()).unwrap();
+    app.press("i", KeyModifiers::default()).unwrap();
+    // Enter continúa la lista y un segundo Enter en el elemento vacío la cierra
+    app.type_text("\nleche\n\nFin").unwrap();
+    assert_eq!(app.text(), "- pan\n- leche\nFin");
+}
+
+#[test]
+fn test_search_after_save() {
+    let vault = TempVault::new("search");
+    let mut app = vault.app();
+
+    app.create_note("Receta", "Pan de centeno", None).unwrap();
+    app.create_note("Viaje", "Billetes de tren", None).unwrap();
+
+    let results = app.search("centeno").unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].note_name, "Receta");
+
+    // El contenido guardado desde el editor también se indexa
+    app.open_note("Viaje").unwrap();
+    app.press("$", KeyModifiers::default()).unwrap();
+    app.press("i", KeyModifiers::default()).unwrap();
+    app.type_text(" a Oporto").unwrap();
+    app.save().unwrap();
+
+    let results = app.search("Oporto").unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].note_name, "Viaje");
+}
+
+#[test]
+fn test_tag_extraction_and_search() {
+    let vault = TempVault::new("tags");
+    let mut app = vault.app();
+
+    let content = "---\ntags:\n  - trabajo\n---\nReunión con el equipo #urgente";
+    let mut tags = extract_all_tags(content);
+    tags.sort();
+    assert_eq!(tags, vec!["trabajo", "urgente"]);
+
+    app.create_note("Reunión", content, None).unwrap();
+    assert_eq!(app.search("#trabajo").unwrap().len(), 1);
+    assert_eq!(app.search("#urgente").unwrap().len(), 1);
+
+    // Al guardar, los tags nuevos se sincronizan y los quitados desaparecen
+    app.open_note("Reunión").unwrap();
+    app.press("G", KeyModifiers::default()).unwrap();
+    app.press("d", KeyModifiers::default()).unwrap();
+    app.press("d", KeyModifiers::default()).unwrap();
+    app.press("i", KeyModifiers::default()).unwrap();
+    app.type_text("Revisado #hecho").unwrap();
+    app.save().unwrap();
+
+    assert_eq!(app.search("#hecho").unwrap().len(), 1);
+    assert!(app.search("#urgente").unwrap().is_empty());
+
+    let note_id = app.notes_db().get_note("Reunión").unwrap().unwrap().id;
+    let mut names: Vec<String> = app
+        .notes_db()
+        .get_note_tags(note_id)
+        .unwrap()
+        .into_iter()
+        .map(|tag| tag.name)
+        .collect();
+    names.sort();
+    assert_eq!(names, vec!["hecho", "trabajo"]);
+}
+
+#[test]
+fn test_mcp_round_trip() {
+    let vault = TempVault::new("mcp");
+    let mut app = vault.app();
+
+    let created = app
+        .execute_tool(MCPToolCall::CreateNote {
+            name: "Desde MCP".to_string(),
+            content: "Contenido inicial #mcp".to_string(),
+            folder: None,
+        })
+        .unwrap();
+    assert!(created.success, "{:?}", created.error);
+    assert_eq!(
+        app.take_changes(),
+        vec![MCPChange::NoteCreated("Desde MCP".to_string())]
+    );
+    assert_eq!(app.search("#mcp").unwrap().len(), 1);
+
+    let updated = app
+        .execute_tool(MCPToolCall::UpdateNote {
+            name: "Desde MCP".to_string(),
+            content: "Contenido revisado".to_string(),
+        })
+        .unwrap();
+    assert!(updated.success, "{:?}", updated.error);
+    assert_eq!(
+        app.take_changes(),
+        vec![MCPChange::NoteModified("Desde MCP".to_string())]
+    );
+
+    // La lectura no produce cambios y ve lo escrito por la herramienta anterior
+    let read = app
+        .execute_tool(MCPToolCall::ReadNote {
+            name: "Desde MCP".to_string(),
+        })
+        .unwrap();
+    assert_eq!(read.data.unwrap()["content"], "Contenido revisado");
+    assert!(app.take_changes().is_empty());
+
+    // El editor abre lo que dejó la herramienta
+    app.open_note("Desde MCP").unwrap();
+    assert_eq!(app.text(), "Contenido revisado");
+
+    let tagged = app
+        .execute_tool(MCPToolCall::AddTag {
+            note: "Desde MCP".to_string(),
+            tag: "revisar".to_string(),
+        })
+        .unwrap();
+    assert!(tagged.success, "{:?}", tagged.error);
+    let note = app.notes_dir().find_note("Desde MCP").unwrap().unwrap();
+    assert!(extract_all_tags(&note.read().unwrap()).contains(&"revisar".to_string()));
+    assert_eq!(
+        app.take_changes(),
+        vec![MCPChange::NoteModified("Desde MCP".to_string())]
+    );
+
+    let deleted = app
+        .execute_tool(MCPToolCall::DeleteNote {
+            name: "Desde MCP".to_string(),
+        })
+        .unwrap();
+    assert!(deleted.success, "{:?}", deleted.error);
+    assert_eq!(
+        app.take_changes(),
+        vec![MCPChange::NoteDeleted("Desde MCP".to_string())]
+    );
+}
+
+#[test]
+fn test_reminder_scheduling() {
+    let vault = TempVault::new("reminders");
+    let app = vault.app();
+    app.create_note("Tareas", "Pagar facturas", None).unwrap();
+    let note_id = app.notes_db().get_note("Tareas").unwrap().unwrap().id;
+
+    let reminders = app.reminders().unwrap();
+    let due = Utc::now() - Duration::minutes(1);
+    let daily = reminders
+        .create_reminder(
+            Some(note_id),
+            "Pagar facturas",
+            None,
+            due,
+            Priority::High,
+            RepeatPattern::Daily,
+        )
+        .unwrap();
+    reminders
+        .create_reminder(
+            None,
+            "Más adelante",
+            None,
+            Utc::now() + Duration::days(2),
+            Priority::Low,
+            RepeatPattern::None,
+        )
+        .unwrap();
+    assert_eq!(reminders.get_pending_triggers().unwrap().len(), 1);
+
+    // El scheduler notifica (toast) y programa la siguiente repetición
+    let toasts = Arc::new(Mutex::new(Vec::new()));
+    let notifier = Arc::new(ReminderNotifier::new(Arc::new(Mutex::new(I18n::new(
+        Language::Spanish,
+    )))));
+    let sink = toasts.clone();
+    notifier.set_toast_handler(move |message| sink.lock().unwrap().push(message));
+
+    let scheduler = ReminderScheduler::new(Arc::new(Mutex::new(reminders)), notifier);
+    scheduler.check_now();
+    assert_eq!(toasts.lock().unwrap().len(), 1);
+    assert!(toasts.lock().unwrap()[0].contains("Pagar facturas"));
+
+    let reminders = app.reminders().unwrap();
+    assert_eq!(
+        reminders.get_reminder(daily).unwrap().unwrap().status,
+        ReminderStatus::Completed
+    );
+    let pending = reminders
+        .list_reminders(Some(ReminderStatus::Pending))
+        .unwrap();
+    let next = pending
+        .iter()
+        .find(|reminder| reminder.title == "Pagar facturas")
+        .expect("siguiente repetición");
+    assert_eq!(next.note_id, Some(note_id));
+    // La base de datos guarda segundos
+    assert_eq!(
+        next.due_date.timestamp(),
+        (due + Duration::days(1)).timestamp()
+    );
+    assert!(reminders.get_pending_triggers().unwrap().is_empty());
+
+    // Los recordatorios creados por MCP usan la misma base de datos
+    let result = app
+        .execute_tool(MCPToolCall::CreateReminder {
+            title: "Llamar".to_string(),
+            due_date: "2099-01-01 09:00".to_string(),
+            description: None,
+            priority: Some("alta".to_string()),
+            repeat: None,
+            note_name: Some("Tareas".to_string()),
+        })
+        .unwrap();
+    assert!(result.success, "{:?}", result.error);
+    assert_eq!(app.take_changes(), vec![MCPChange::RemindersChanged]);
+    assert_eq!(reminders.list_reminders_by_note(note_id).unwrap().len(), 3);
+}
+
+#[test]
+fn test_reminder_history() {
+    let vault = TempVault::new("reminder-history");
+    let app = vault.app();
+    let reminders = app.reminders().unwrap();
+    let due = Utc::now() - Duration::minutes(1);
+    let mut ids = Vec::new();
+    for title in ["Regar plantas", "Pagar alquiler", "Llamar a Ana"] {
+        ids.push(
+            reminders
+                .create_reminder(
+                    None,
+                    title,
+                    None,
+                    due,
+                    Priority::Medium,
+                    RepeatPattern::None,
+                )
+                .unwrap(),
+        );
+    }
+    assert!(reminders.list_history().unwrap().is_empty());
+
+    let notifier = Arc::new(ReminderNotifier::new(Arc::new(Mutex::new(I18n::new(
+        Language::Spanish,
+    )))));
+    let scheduler = ReminderScheduler::new(Arc::new(Mutex::new(reminders)), notifier.clone());
+    scheduler.check_now();
+
+    let reminders = app.reminders().unwrap();
+    reminders.complete_reminder(ids[0]).unwrap();
+    reminders
+        .snooze_reminder(ids[1], Utc::now() - Duration::seconds(1))
+        .unwrap();
+    reminders.delete_reminder(ids[2]).unwrap();
+
+    // Vuelve a saltar tras posponerlo: sigue siendo una sola entrada
+    let scheduler = ReminderScheduler::new(Arc::new(Mutex::new(reminders)), notifier);
+    scheduler.check_now();
+
+    let reminders = app.reminders().unwrap();
+    let history = reminders.list_history().unwrap();
+    assert_eq!(history.len(), 3);
+    let outcome = |id: i64| {
+        let entry = history
+            .iter()
+            .find(|entry| entry.reminder_id == id)
+            .unwrap();
+        (entry.outcome, entry.snooze_count)
+    };
+    assert_eq!(outcome(ids[0]), (ReminderOutcome::Completed, 0));
+    assert_eq!(outcome(ids[1]), (ReminderOutcome::Open, 1));
+    // Eliminado sin completar: descartado, y el historial lo conserva
+    assert_eq!(outcome(ids[2]), (ReminderOutcome::Dismissed, 0));
+}
+
+#[test]
+fn test_reminder_source_line() {
+    let vault = TempVault::new("reminder-source");
+    let app = vault.app();
+    let reminders = app.reminders().unwrap();
+    let due = Utc::now() - Duration::minutes(1);
+    let id = reminders
+        .create_reminder(
+            None,
+            "Regar plantas",
+            None,
+            due,
+            Priority::Medium,
+            RepeatPattern::Daily,
+        )
+        .unwrap();
+    reminders.set_source(id, "/notas/Casa.md", 7).unwrap();
+
+    let reminder = reminders.get_reminder(id).unwrap().unwrap();
+    assert_eq!(reminder.source_path.as_deref(), Some("/notas/Casa.md"));
+    assert_eq!(reminder.source_line, Some(7));
+
+    // La repetición de mañana sigue apuntando a la misma línea
+    let notifier = Arc::new(ReminderNotifier::new(Arc::new(Mutex::new(I18n::new(
+        Language::Spanish,
+    )))));
+    let scheduler = ReminderScheduler::new(Arc::new(Mutex::new(reminders)), notifier);
+    scheduler.check_now();
+
+    let reminders = app.reminders().unwrap();
+    let next = reminders
+        .list_reminders(Some(ReminderStatus::Pending))
+        .unwrap()
+        .into_iter()
+        .find(|reminder| reminder.id != id)
+        .unwrap();
+    assert_eq!(next.source_line, Some(7));
+}